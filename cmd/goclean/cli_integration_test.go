@@ -170,6 +170,46 @@ func VeryLongFunctionWithManyViolations(a, b, c, d, e string) {
 	}
 }
 
+// TestCLIQualityGatesInEveryOutputMode checks that the quality gates fail
+// the scan whichever output mode prints the violations
+func TestCLIQualityGatesInEveryOutputMode(t *testing.T) {
+	binaryPath := buildGoCleanBinary(t)
+	defer os.Remove(binaryPath)
+	
+	tempDir := testutils.CreateTempDir(t)
+	srcDir := filepath.Join(tempDir, "src")
+	require.NoError(t, os.MkdirAll(srcDir, 0755))
+	testCode := `package main
+
+func mix(a, b, c, d, e, f, g int) int {
+	return a + b + c + d + e + f + g
+}
+`
+	require.NoError(t, os.WriteFile(filepath.Join(srcDir, "mix.go"), []byte(testCode), 0644))
+	
+	modes := map[string][]string{
+		"console":            {},
+		"locations":          {"--format", "locations"},
+		"quickfix":           {"--format", "quickfix"},
+		"console_violations": {"--console-violations"},
+	}
+	for name, modeArgs := range modes {
+		t.Run(name, func(t *testing.T) {
+			// No violation is critical, so only the health gate can fail the scan
+			args := append([]string{"scan", srcDir, "--fail-on", "critical", "--min-health", "100"}, modeArgs...)
+			cmd := exec.Command(binaryPath, args...)
+			cmd.Dir = tempDir
+			var stdout, stderr bytes.Buffer
+			cmd.Stdout = &stdout
+			cmd.Stderr = &stderr
+			
+			err := cmd.Run()
+			assert.Error(t, err, "The health gate should fail the scan")
+			assert.Contains(t, stderr.String(), "health score below 100", "STDOUT: %s", stdout.String())
+		})
+	}
+}
+
 // TestCLIConfigCommand tests configuration-related CLI commands
 func TestCLIConfigCommand(t *testing.T) {
 	binaryPath := buildGoCleanBinary(t)
@@ -2,20 +2,39 @@
 package main
 
 import (
+	"context"
+	"encoding/json"
 	"fmt"
 	"io"
 	"os"
+	"os/signal"
 	"path/filepath"
 	"sort"
+	"strconv"
 	"strings"
 	"text/tabwriter"
 	"time"
 
 	"github.com/spf13/cobra"
+	"github.com/ericfisherdev/goclean/internal/baseline"
+	"github.com/ericfisherdev/goclean/internal/bench"
 	"github.com/ericfisherdev/goclean/internal/config"
+	"github.com/ericfisherdev/goclean/internal/fixer"
+	"github.com/ericfisherdev/goclean/internal/fpreport"
+	"github.com/ericfisherdev/goclean/internal/gitdiff"
+	"github.com/ericfisherdev/goclean/internal/health"
+	"github.com/ericfisherdev/goclean/internal/history"
+	"github.com/ericfisherdev/goclean/internal/lsp"
+	"github.com/ericfisherdev/goclean/internal/manifest"
 	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/rules"
+	"github.com/ericfisherdev/goclean/internal/sandbox"
 	"github.com/ericfisherdev/goclean/internal/scanner"
+	"github.com/ericfisherdev/goclean/internal/selftest"
 	"github.com/ericfisherdev/goclean/internal/reporters"
+	"github.com/ericfisherdev/goclean/internal/thirdparty"
+	"github.com/ericfisherdev/goclean/internal/violations"
+	"github.com/ericfisherdev/goclean/internal/watch"
 )
 
 var (
@@ -24,6 +43,7 @@ var (
 	
 	// Global flags
 	cfgFile     string
+	profileName string
 	verbose     bool
 	outputPath  string
 	format      string
@@ -34,6 +54,9 @@ var (
 	fileTypes   []string
 	languages   []string
 	thresholds  map[string]int
+	jobs        int
+	failOn      string
+	minHealth   int
 	
 	// Test file handling flags
 	aggressive       bool
@@ -42,12 +65,76 @@ var (
 	
 	// Console output flags
 	consoleViolations bool
+	showReason        bool
+	
+	// HTML report flags
+	selfContained bool
+	
+	// JUnit report flags
+	junitPath    string
+	junitGroupBy string
+	
+	// Monorepo report flags
+	splitReportBy string
+	
+	// Profiling flags
+	timings bool
 	
 	// Rust-specific flags
 	rustOptimizations bool
 	rustCacheSize     int
 	rustCacheTTL      int // in minutes
-
+	rustEdition       string
+	unsafeInventoryPath string
+	panicCensusPath     string
+	maxPanicSurface     int
+	
+	// False-positive report flags
+	fpOutputPath    string
+	fpContextLines  int
+	fpFullFile      bool
+	fpRedactStrings bool
+	fpReplayPath    string
+	
+	// Baseline flags
+	baselinePath       string
+	baselineOutputPath string
+	diffBase           string
+	
+	// History flags
+	historyPath string
+	flakyFormat string
+	
+	// Fix flags
+	fixDryRun bool
+	
+	// Rules flags
+	rulesFormat string
+	
+	// Merge flags
+	mergeOutputPath string
+	mergeForce      bool
+	mergeFailOn     string
+	
+	// Compare flags
+	compareFormat string
+	compareFailOn string
+	compareForce  bool
+	
+	// Watch flags
+	watchInterval time.Duration
+	watchPoll     bool
+	
+	// Self-test flags
+	selftestCorpus string
+	
+	// Bench flags
+	benchCorpora       []string
+	benchIterations    int
+	benchFormat        string
+	benchOutput        string
+	benchCompare       string
+	benchMaxRegression float64
 )
 
 // rootCmd represents the base command when called without any subcommands
@@ -77,18 +164,34 @@ Examples:
   goclean scan ./src
   goclean scan ./src ./internal --exclude vendor/,node_modules/
   goclean scan . --format html --output ./reports/report.html
+  goclean scan . --self-contained  # interactive HTML report to share
   goclean scan . --languages go,rust --verbose
   goclean scan . --languages rust --config ./rust-config.yaml
-  goclean scan . --console-violations  # AI-friendly output`,
+  goclean scan . --console-violations  # AI-friendly output
+  goclean scan . --format codeclimate  # GitLab code quality report
+  goclean scan . --format github-summary  # GitHub Actions job summary
+  goclean scan . --format locations  # file:line:col: message lines
+  vim -q <(goclean scan . --format quickfix)
+  goclean scan . --baseline goclean-baseline.json  # only new violations
+  goclean scan . --diff-base origin/main  # only violations on changed lines
+  goclean scan . --history goclean-history.json  # track flaky findings
+  goclean scan . --jobs 8  # scan 8 files in parallel
+  goclean scan . --profile strict  # tighter thresholds and more rules
+  goclean scan . --fail-on high  # exit 1 only for high or critical violations
+  goclean scan . --min-health 60  # exit 1 when a file's health is below 60
+  goclean scan . --split-report-by owner  # one report per CODEOWNERS team`,
 	Args: cobra.MinimumNArgs(0),
 	Run: func(cmd *cobra.Command, args []string) {
-		if !consoleViolations {
+		// Machine-readable output goes to stdout without banners or progress
+		locationsFormat := reporters.IsLocationsFormat(format)
+		quiet := consoleViolations || locationsFormat
+		if !quiet {
 			fmt.Printf("GoClean v%s - Clean Code Analysis Tool\n", rootCmd.Version)
 			fmt.Println("Starting code analysis...")
 		}
 		
 		// Load configuration
-		cfg, err := config.Load(cfgFile)
+		cfg, err := config.LoadWithProfile(cfgFile, profileName)
 		if err != nil {
 			fmt.Fprintf(os.Stderr, "Failed to load configuration: %v\n", err)
 			os.Exit(1)
@@ -104,6 +207,18 @@ Examples:
 				// Use default path if not configured
 				cfg.Export.JSON.Path = "./reports/violations.json"
 			}
+		} else if format == reporters.FormatCodeClimate {
+			// Write the GitLab code quality report
+			cfg.Export.CodeClimate.Enabled = true
+			if outputPath != "" {
+				cfg.Export.CodeClimate.Path = outputPath
+			}
+		} else if format == reporters.FormatGitHubSummary {
+			// Write the job summary of GitHub Actions, to $GITHUB_STEP_SUMMARY unless --output is given
+			cfg.Export.GitHubSummary.Enabled = true
+			if outputPath != "" {
+				cfg.Export.GitHubSummary.Path = outputPath
+			}
 		} else if outputPath != "" {
 			if format == "html" || format == "" {
 				cfg.Output.HTML.Path = outputPath
@@ -113,6 +228,65 @@ Examples:
 			}
 		}
 		
+		if selfContained {
+			cfg.Output.HTML.SelfContained = true
+		}
+		
+		if junitPath != "" {
+			cfg.Export.JUnit.Enabled = true
+			cfg.Export.JUnit.Path = junitPath
+		}
+		if junitGroupBy != "" {
+			cfg.Export.JUnit.GroupBy = junitGroupBy
+			if err := cfg.Export.JUnit.Validate(); err != nil {
+				fmt.Fprintf(os.Stderr, "%v\n", err)
+				os.Exit(1)
+			}
+		}
+		
+		if splitReportBy != "" {
+			cfg.Output.SplitBy = splitReportBy
+		}
+		if err := config.ValidateSplitBy(cfg.Output.SplitBy); err != nil {
+			fmt.Fprintf(os.Stderr, "%v\n", err)
+			os.Exit(1)
+		}
+		
+		// Rule severities and the failure level are checked before scanning
+		if minHealth > 0 {
+			cfg.Severity.MinFileHealth = minHealth
+		}
+		failSeverity, err := resolveFailSeverity(cfg, failOn)
+		if err != nil {
+			fmt.Fprintf(os.Stderr, "%v\n", err)
+			os.Exit(1)
+		}
+		
+		if unsafeInventoryPath != "" {
+			cfg.Export.UnsafeInventory.Enabled = true
+			cfg.Export.UnsafeInventory.Path = unsafeInventoryPath
+		}
+		
+		if panicCensusPath != "" {
+			cfg.Export.PanicCensus.Enabled = true
+			cfg.Export.PanicCensus.Path = panicCensusPath
+		}
+		if maxPanicSurface > 0 {
+			cfg.Export.PanicCensus.MaxPanicSurface = maxPanicSurface
+		}
+		
+		if rustEdition != "" {
+			if err := config.ValidateRustEdition(rustEdition); err != nil {
+				fmt.Fprintf(os.Stderr, "%v\n", err)
+				os.Exit(1)
+			}
+			cfg.Rust.Edition = rustEdition
+		}
+		
+		if jobs > 0 {
+			cfg.Scan.ConcurrentFiles = jobs
+		}
+		
 		// Handle test file configuration
 		if aggressive || includeTests {
 			cfg.Scan.AggressiveMode = &[]bool{true}[0]
@@ -149,7 +323,7 @@ Examples:
 		}
 		
 		// Display configuration
-		if verbose && !consoleViolations {
+		if verbose && !quiet {
 			fmt.Printf("Scan paths: %v\n", scanPaths)
 			if len(excludePatterns) > 0 {
 				fmt.Printf("Exclude patterns: %v\n", excludePatterns)
@@ -167,8 +341,8 @@ Examples:
 			if outputPath != "" {
 				fmt.Printf("Output path: %s\n", outputPath)
 			}
-			fmt.Printf("Function lines threshold: %d\n", cfg.Thresholds.FunctionLines)
-			fmt.Printf("Cyclomatic complexity threshold: %d\n", cfg.Thresholds.CyclomaticComplexity)
+			fmt.Printf("Function lines threshold: %d\n", cfg.Thresholds.Effective().FunctionLines)
+			fmt.Printf("Cyclomatic complexity threshold: %d\n", cfg.Thresholds.Effective().CyclomaticComplexity)
 		}
 		
 		// Create reporter manager
@@ -181,11 +355,28 @@ Examples:
 		// Create and configure scanner engine with test file configuration
 		engine := scanner.NewEngineWithConfig(scanPaths, excludePatterns, fileTypesList, verbose,
 			cfg.Scan.GetSkipTestFiles(), cfg.Scan.GetAggressiveMode(), cfg.Scan.CustomTestPatterns)
+		detectorConfig := buildDetectorConfig(cfg)
+		for _, rule := range detectorConfig.ScriptRules {
+			if err := violations.ValidateScriptRule(rule); err != nil {
+				fmt.Fprintf(os.Stderr, "Invalid configuration: %v\n", err)
+				os.Exit(1)
+			}
+		}
+		engine.SetViolationDetectorConfig(detectorConfig)
+		if verbose && !quiet {
+			for _, plugin := range detectorConfig.Plugins {
+				fmt.Printf("Plugin %s sandbox: %s\n", plugin.Name, violations.PluginEnforcement(plugin))
+			}
+		}
+		engine.SetRustEdition(cfg.Rust.Edition)
+		if timings {
+			engine.EnableTimings()
+		}
 		
 		// Configure concurrent file processing if specified
 		if cfg.Scan.ConcurrentFiles > 0 {
 			engine.SetMaxWorkers(cfg.Scan.ConcurrentFiles)
-			if verbose && !consoleViolations {
+			if verbose && !quiet {
 				fmt.Printf("Concurrent file processing set to: %d workers\n", cfg.Scan.ConcurrentFiles)
 			}
 		}
@@ -196,7 +387,7 @@ Examples:
 				fmt.Fprintf(os.Stderr, "Invalid max file size configuration: %v\n", err)
 				os.Exit(1)
 			}
-			if verbose && !consoleViolations {
+			if verbose && !quiet {
 				fmt.Printf("Max file size limit set to: %s\n", cfg.Scan.MaxFileSize)
 			}
 		}
@@ -218,14 +409,14 @@ Examples:
 			
 			engine.SetRustCacheConfig(cacheSize, cacheTTL)
 			
-			if verbose && !consoleViolations {
+			if verbose && !quiet {
 				fmt.Printf("Rust optimizations enabled: cache size=%d, TTL=%v\n", cacheSize, cacheTTL)
 			}
 		}
 		
 		// Set progress callback for real-time updates
 		progressCallback := func(message string) {
-			if verbose && !consoleViolations {
+			if verbose && !quiet {
 				fmt.Printf("Progress: %s\n", message)
 			}
 		}
@@ -237,13 +428,106 @@ Examples:
 			fmt.Fprintf(os.Stderr, "Scan failed: %v\n", err)
 			os.Exit(1)
 		}
+		if timings {
+			printDetectorTimings(engine.DetectorTimings())
+		}
+		
+		// Record the findings before filtering to detect findings that come and go on identical content
+		if historyPath != "" {
+			cfg.Scan.History = historyPath
+		}
+		if cfg.Scan.History != "" {
+			recordHistory(cfg.Scan.History, manifest.Hash(rootCmd.Version, detectorConfig), results, quiet)
+		}
+		
+		// Date TODO markers with git blame and escalate the old ones
+		if cfg.Todos.Enabled() {
+			stats := gitdiff.AgeTodos(results, cfg.Todos.MaxTodoAgeDays, time.Now())
+			if stats.Escalated > 0 && !quiet {
+				fmt.Printf("TODO aging: %d of %d markers are older than %d days and were escalated\n",
+					stats.Escalated, stats.Blamed, cfg.Todos.MaxTodoAgeDays)
+			}
+		}
+		
+		// Hide violations already recorded in the baseline
+		if baselinePath != "" {
+			cfg.Scan.Baseline = baselinePath
+		}
+		if cfg.Scan.Baseline != "" {
+			known, err := baseline.Load(cfg.Scan.Baseline)
+			if err != nil {
+				fmt.Fprintf(os.Stderr, "Failed to load baseline: %v\n", err)
+				os.Exit(1)
+			}
+			for _, deprecation := range known.DeprecatedRules() {
+				fmt.Fprintf(os.Stderr, "Warning: baseline %s: %s; regenerate the baseline with goclean baseline\n",
+					cfg.Scan.Baseline, deprecation.Warning())
+			}
+			stats := known.Filter(summary, results)
+			if !quiet {
+				fmt.Printf("Baseline: %d known violations hidden, %d new, %d fixed since the baseline\n",
+					stats.Suppressed, stats.New, stats.Resolved)
+			}
+		}
+		
+		// Hide violations in code the diff against the base revision does not touch
+		if diffBase != "" {
+			repositoryDir := "."
+			if len(scanPaths) > 0 {
+				repositoryDir = gitdiff.RepositoryDir(scanPaths[0])
+			}
+			changes, err := gitdiff.Load(repositoryDir, diffBase)
+			if err != nil {
+				fmt.Fprintf(os.Stderr, "Failed to compute changes since %s: %v\n", diffBase, err)
+				os.Exit(1)
+			}
+			stats := changes.Filter(summary, results)
+			if !quiet {
+				fmt.Printf("Diff: %d violations on lines changed since %s, %d in unchanged code hidden\n",
+					stats.Kept, diffBase, stats.Hidden)
+			}
+		}
+		
+		// Report vendored and third-party code at reduced severity or separately
+		if thirdPartyStats := applyThirdParty(cfg, scanPaths, summary, results); thirdPartyStats.Violations > 0 && !quiet {
+			if cfg.Scan.ThirdParty.Mode == config.ThirdPartyModeSeparate {
+				fmt.Printf("Third-party: %d violations in %d vendored files counted separately\n",
+					thirdPartyStats.Violations, thirdPartyStats.Files)
+			} else {
+				fmt.Printf("Third-party: %d violations in %d vendored files reported at %s severity or below\n",
+					thirdPartyStats.Violations, thirdPartyStats.Files, strings.ToLower(cfg.Scan.ThirdParty.MaxSeverity))
+			}
+		}
+		if summary.InlineSuppressed > 0 && !quiet {
+			fmt.Printf("Inline suppressions: %d violations hidden by goclean:ignore comments\n", summary.InlineSuppressed)
+		}
+		
+		// Mark what is reported as new, so reports tell new, baselined, suppressed and fixed violations apart
+		summary.CountTriage(results)
+		
+		// Add up the estimated time to fix what is reported
+		summary.CountDebt(results)
+		
+		// Score the health of each file from its metrics and violations
+		summary.Health = health.NewScorer(detectorConfig).Files(results)
+		
+		// Write the location list for editors and pickers
+		if locationsFormat {
+			writeLocations(results)
+			gatesFailed := checkQualityGates(cfg, summary, results)
+			if gatesFailed || failingViolations(results, failSeverity) > 0 {
+				os.Exit(1)
+			}
+			return
+		}
 		
 		// Generate console report
 		if consoleViolations {
 			// Generate structured violations output for AI agents
 			generateConsoleViolationsOutput(summary, results)
 			// Exit immediately with appropriate code for console violations mode
-			if summary.TotalViolations > 0 {
+			gatesFailed := checkQualityGates(cfg, summary, results)
+			if gatesFailed || failingViolations(results, failSeverity) > 0 {
 				os.Exit(1)
 			}
 			return
@@ -260,31 +544,26 @@ Examples:
 		if len(configuredReporters) > 0 {
 			fmt.Printf("\nGenerating reports (%s)...\n", configuredReporters)
 			
-			err = reporterManager.GenerateReports(summary, results)
-			if err != nil {
-				fmt.Fprintf(os.Stderr, "Failed to generate reports: %v\n", err)
-				os.Exit(1)
-			}
-			
-			// Display output paths
-			if htmlPath := reporterManager.GetHTMLOutputPath(); htmlPath != "" {
-				fmt.Printf("📊 HTML report generated: %s\n", htmlPath)
-				if cfg.Output.HTML.AutoRefresh {
-					fmt.Printf("   Auto-refresh enabled (every %d seconds)\n", cfg.Output.HTML.RefreshInterval)
+			reporterManager.SetManifest(scanManifest(cfg, scanPaths, summary.Duration))
+			if cfg.Output.SplitBy != "" {
+				generateSplitReports(reporterManager, cfg, scanPaths, summary, results)
+			} else {
+				err = reporterManager.GenerateReports(summary, results)
+				if err != nil {
+					fmt.Fprintf(os.Stderr, "Failed to generate reports: %v\n", err)
+					os.Exit(1)
 				}
+				printReportPaths(reporterManager, cfg)
 			}
-			
-			if markdownPath := reporterManager.GetMarkdownOutputPath(); markdownPath != "" {
-				fmt.Printf("📝 Markdown report generated: %s\n", markdownPath)
-			}
-			
-			if jsonPath := reporterManager.GetJSONOutputPath(); jsonPath != "" {
-				fmt.Printf("📄 JSON report generated: %s\n", jsonPath)
-			}
+		}
+		
+		// Enforce the panic surface, file health and documentation coverage quality gates
+		if checkQualityGates(cfg, summary, results) {
+			os.Exit(1)
 		}
 		
 		// Display Rust performance metrics if verbose and optimizations were enabled
-		if verbose && !consoleViolations && (containsRust(languages, fileTypesList) || rustOptimizations) {
+		if verbose && !quiet && (containsRust(languages, fileTypesList) || rustOptimizations) {
 			if metrics := engine.GetRustPerformanceMetrics(); metrics != nil {
 				fmt.Printf("\n🦀 Rust Performance Metrics:\n")
 				fmt.Printf("   Cache hits: %d, misses: %d (%.1f%% hit rate)\n", 
@@ -293,10 +572,15 @@ Examples:
 			}
 		}
 		
-		// Exit with appropriate code
-		if summary.TotalViolations > 0 {
+		// Exit with appropriate code; only violations at or above the fail-on severity fail the scan
+		if failing := failingViolations(results, failSeverity); failing > 0 {
 			fmt.Printf("\n⚠️  Found %d violations. Review the reports for details.\n", summary.TotalViolations)
+			if failing < summary.TotalViolations {
+				fmt.Printf("   %d at %s severity or above fail the scan.\n", failing, failSeverity)
+			}
 			os.Exit(1)
+		} else if summary.TotalViolations > 0 {
+			fmt.Printf("\n✅ Found %d violations, none at %s severity or above.\n", summary.TotalViolations, failSeverity)
 		} else {
 			fmt.Println("\n🎉 No violations found! Your code follows clean code principles.")
 		}
@@ -360,152 +644,1072 @@ var versionCmd = &cobra.Command{
 	},
 }
 
-// getVersionFromFile reads the version from the VERSION file, falling back to a default if not found
-func getVersionFromFile() string {
-	const defaultVersion = "dev"
-	
-	// Try to find VERSION file in the current directory or parent directories
-	versionPaths := []string{
-		"VERSION",
-		"../VERSION",
-		"../../VERSION",
-	}
-	
-	for _, versionPath := range versionPaths {
-		if file, err := os.Open(versionPath); err == nil {
-			defer file.Close()
-			
-			content, err := io.ReadAll(file)
-			if err == nil {
-				version := strings.TrimSpace(string(content))
-				if version != "" {
-					return version
-				}
-			}
+// rulesCmd lists the rules and thresholds with the values detectors actually use
+var rulesCmd = &cobra.Command{
+	Use:   "rules",
+	Short: "List rules, rule thresholds and their effective values",
+	Long: `List every rule of the registered detectors with its category, default
+severity and languages, and whether the current configuration enables it.
+Then list every rule threshold with its configured value, the group that
+scales it and the effective value used by the detectors.
+
+Group scales such as thresholds.complexity.scale multiply all thresholds of
+the group, so the effective value can differ from the configured one.
+
+Use --format json for a machine-readable listing.`,
+	Run: func(cmd *cobra.Command, args []string) {
+		cfg, err := config.LoadWithProfile(cfgFile, profileName)
+		if err != nil {
+			fmt.Fprintf(os.Stderr, "Failed to load configuration: %v\n", err)
+			os.Exit(1)
 		}
-	}
-	
-	// If VERSION file not found, try to find it relative to the executable
-	if execPath, err := os.Executable(); err == nil {
-		execDir := filepath.Dir(execPath)
-		versionFile := filepath.Join(execDir, "VERSION")
-		if file, err := os.Open(versionFile); err == nil {
-			defer file.Close()
-			
-			content, err := io.ReadAll(file)
-			if err == nil {
-				version := strings.TrimSpace(string(content))
-				if version != "" {
-					return version
-				}
+		
+		listing := ruleListings(buildDetectorConfig(cfg))
+		switch rulesFormat {
+		case "json":
+			data, err := json.MarshalIndent(struct {
+				Rules      []ruleListing             `json:"rules"`
+				Thresholds []config.ThresholdSetting `json:"thresholds"`
+			}{listing, cfg.Thresholds.Settings()}, "", "  ")
+			if err != nil {
+				fmt.Fprintf(os.Stderr, "Failed to encode rules: %v\n", err)
+				os.Exit(1)
 			}
+			fmt.Println(string(data))
+		case "text":
+			printRuleListings(os.Stdout, listing)
+			fmt.Println()
+			printThresholdSettings(os.Stdout, cfg.Thresholds.Settings())
+		default:
+			fmt.Fprintf(os.Stderr, "Unknown format %q (use text or json)\n", rulesFormat)
+			os.Exit(1)
 		}
-	}
-	
-	return defaultVersion
+	},
 }
 
-// generateConsoleViolationsOutput outputs violations in a structured format for AI agents
-func generateConsoleViolationsOutput(summary *models.ScanSummary, results []*models.ScanResult) {
-	// Print basic scan summary first
-	fmt.Printf("=== GoClean Scan Results ===\n")
-	fmt.Printf("Total Files: %d\n", summary.TotalFiles)
-	fmt.Printf("Scanned Files: %d\n", summary.ScannedFiles)
-	fmt.Printf("Total Violations: %d\n", summary.TotalViolations)
-	fmt.Printf("Scan Duration: %v\n\n", summary.Duration)
-	
-	if summary.TotalViolations == 0 {
-		fmt.Println("✅ No violations found!")
-		return
-	}
-	
-	fmt.Println("=== Violations (Structured Format) ===")
-	
-	// Create tab writer for structured output
-	w := tabwriter.NewWriter(os.Stdout, 0, 8, 1, '\t', 0)
-	
-	// Write header
-	fmt.Fprintf(w, "%s\t%s\t%s\t%s\t%s\n", "FILE", "LINE", "TYPE", "LEVEL", "MESSAGE")
-	
-	// Collect all violations from all files
-	var allViolations []*models.Violation
-	for _, result := range results {
-		for _, violation := range result.Violations {
-			allViolations = append(allViolations, violation)
+// reportFPCmd bundles a suspected false positive into a reproduction file
+var reportFPCmd = &cobra.Command{
+	Use:   "report-fp <violation-fingerprint> [paths...]",
+	Short: "Bundle a suspected false positive into a reproduction file",
+	Long: `Re-scan the given paths, find the violation with the given fingerprint and write
+an anonymized reproduction file containing the snippet, rule, relevant
+configuration and tool version. Attach the file to a bug report.
+
+Fingerprints are listed in the JSON report. Maintainers can replay a
+reproduction file against the current detectors with --replay.
+
+Examples:
+  goclean report-fp 3f9a1c2b7d4e5f60 ./src
+  goclean report-fp 3f9a1c2b7d4e5f60 --redact-strings --output fp.json
+  goclean report-fp --replay fp.json`,
+	Args: func(cmd *cobra.Command, args []string) error {
+		if fpReplayPath == "" && len(args) == 0 {
+			return fmt.Errorf("requires a violation fingerprint, or --replay with a reproduction file")
 		}
-	}
-	
-	// Sort violations by file and then by line number
-	sort.Slice(allViolations, func(i, j int) bool {
-		if allViolations[i].File != allViolations[j].File {
-			return allViolations[i].File < allViolations[j].File
+		return nil
+	},
+	Run: func(cmd *cobra.Command, args []string) {
+		// Replays use the configuration recorded in the bundle
+		if fpReplayPath != "" {
+			replayFalsePositive(fpReplayPath)
+			return
 		}
-		return allViolations[i].Line < allViolations[j].Line
-	})
-	
-	// Output each violation in tab-delimited format
-	for _, violation := range allViolations {
-		// Clean up message (remove newlines and tabs but preserve content)
-		message := strings.ReplaceAll(violation.Message, "\n", " ")
-		message = strings.ReplaceAll(message, "\t", " ")
 		
-		fmt.Fprintf(w, "%s\t%d\t%s\t%s\t%s\n",
-			violation.File,
-			violation.Line,
-			string(violation.Type),
-			violation.Severity.String(),
-			message)
-	}
-	
-	// Flush the tabwriter
-	w.Flush()
-	
-	fmt.Printf("\n=== Summary by Type ===\n")
-	// Count violations by type
-	violationCounts := make(map[models.ViolationType]int)
-	for _, violation := range allViolations {
-		violationCounts[violation.Type]++
-	}
-	
-	// Sort by count descending
-	type typeCount struct {
-		Type  models.ViolationType
-		Count int
-	}
-	var sortedTypes []typeCount
-	for vtype, count := range violationCounts {
-		sortedTypes = append(sortedTypes, typeCount{Type: vtype, Count: count})
-	}
-	sort.Slice(sortedTypes, func(i, j int) bool {
-		return sortedTypes[i].Count > sortedTypes[j].Count
-	})
-	
-	for _, tc := range sortedTypes {
-		fmt.Printf("%-30s: %d\n", tc.Type.GetDisplayName(), tc.Count)
-	}
-	
-	fmt.Printf("\n=== Summary by Severity ===\n")
-	// Count violations by severity
-	severityCounts := make(map[models.Severity]int)
-	for _, violation := range allViolations {
-		severityCounts[violation.Severity]++
-	}
-	
-	// Output in order of severity
-	severities := []models.Severity{models.SeverityCritical, models.SeverityHigh, models.SeverityMedium, models.SeverityLow, models.SeverityInfo}
-	for _, severity := range severities {
-		if count, exists := severityCounts[severity]; exists && count > 0 {
-			fmt.Printf("%-10s: %d\n", severity.String(), count)
+		cfg, err := config.LoadWithProfile(cfgFile, profileName)
+		if err != nil {
+			fmt.Fprintf(os.Stderr, "Failed to load configuration: %v\n", err)
+			os.Exit(1)
 		}
-	}
-	
-	fmt.Printf("\n⚠️  Total: %d violations found\n", summary.TotalViolations)
-}
-
-// getLanguageExtensions maps language names to their file extensions
-func getLanguageExtensions(languages []string) []string {
-	languageMap := map[string][]string{
-		"go":         {".go"},
+		
+		fingerprint := args[0]
+		scanPaths := args[1:]
+		if len(scanPaths) == 0 {
+			scanPaths = cfg.Scan.Paths
+		}
+		
+		_, results, err := newConfiguredEngine(cfg, scanPaths).Scan()
+		if err != nil {
+			fmt.Fprintf(os.Stderr, "Scan failed: %v\n", err)
+			os.Exit(1)
+		}
+		
+		violation := findViolationByFingerprint(results, fingerprint)
+		if violation == nil {
+			fmt.Fprintf(os.Stderr, "No violation with fingerprint %s found in %v\n", fingerprint, scanPaths)
+			os.Exit(1)
+		}
+		
+		bundle, err := fpreport.NewBundle(violation, buildDetectorConfig(cfg), rootCmd.Version, fpreport.Options{
+			ContextLines:  fpContextLines,
+			FullFile:      fpFullFile,
+			RedactStrings: fpRedactStrings,
+		})
+		if err != nil {
+			fmt.Fprintf(os.Stderr, "Failed to create reproduction: %v\n", err)
+			os.Exit(1)
+		}
+		
+		outputFile := fpOutputPath
+		if outputFile == "" {
+			outputFile = fmt.Sprintf("goclean-fp-%s.json", fingerprint)
+		}
+		if err := bundle.Save(outputFile); err != nil {
+			fmt.Fprintf(os.Stderr, "%v\n", err)
+			os.Exit(1)
+		}
+		
+		fmt.Printf("✓ Reproduction for %s (%s) written to %s\n", violation.Rule, fingerprint, outputFile)
+		fmt.Println("Review the file before attaching it to a bug report.")
+	},
+}
+
+// baselineCmd snapshots current violations into a baseline file
+var baselineCmd = &cobra.Command{
+	Use:   "baseline [paths...]",
+	Short: "Record current violations in a baseline file",
+	Long: `Scan the given paths and record every violation found in a baseline file.
+Scans run with --baseline then report only violations that are not in the
+baseline, so legacy code can be adopted without fixing everything first.
+
+Violations are matched by fingerprint, so run the baseline and later scans
+with the same paths. Regenerate the baseline after fixing known violations.
+
+Examples:
+  goclean baseline ./src
+  goclean baseline --output .goclean/baseline.json
+  goclean scan ./src --baseline goclean-baseline.json`,
+	Args: cobra.MinimumNArgs(0),
+	Run: func(cmd *cobra.Command, args []string) {
+		cfg, err := config.LoadWithProfile(cfgFile, profileName)
+		if err != nil {
+			fmt.Fprintf(os.Stderr, "Failed to load configuration: %v\n", err)
+			os.Exit(1)
+		}
+		
+		scanPaths := args
+		if len(scanPaths) == 0 {
+			scanPaths = cfg.Scan.Paths
+		}
+		
+		_, results, err := newConfiguredEngine(cfg, scanPaths).Scan()
+		if err != nil {
+			fmt.Fprintf(os.Stderr, "Scan failed: %v\n", err)
+			os.Exit(1)
+		}
+		
+		outputFile := baselineOutputPath
+		if outputFile == "" {
+			outputFile = cfg.Scan.Baseline
+		}
+		if outputFile == "" {
+			outputFile = baseline.DefaultPath
+		}
+		
+		snapshot := baseline.New(results, rootCmd.Version)
+		if err := snapshot.Save(outputFile); err != nil {
+			fmt.Fprintf(os.Stderr, "%v\n", err)
+			os.Exit(1)
+		}
+		
+		fmt.Printf("✓ Recorded %d violations in %s\n", len(snapshot.Entries), outputFile)
+	},
+}
+
+// flakyCmd reports the findings that came and went across scans of identical content
+var flakyCmd = &cobra.Command{
+	Use:   "flaky",
+	Short: "Report findings that come and go across scans of identical content",
+	Long: `Read the history written by scans run with --history (or scan.history) and
+list the flaky findings: violations that appeared or disappeared between
+scans of a file whose content and rule set did not change. A flaky finding
+points at a nondeterministic detector or plugin, such as one depending on
+map iteration order, timing or the environment.
+
+Examples:
+  goclean scan . --history goclean-history.json
+  goclean flaky --history goclean-history.json
+  goclean flaky --format json`,
+	Run: func(cmd *cobra.Command, args []string) {
+		cfg, err := config.LoadWithProfile(cfgFile, profileName)
+		if err != nil {
+			fmt.Fprintf(os.Stderr, "Failed to load configuration: %v\n", err)
+			os.Exit(1)
+		}
+		
+		path := historyPath
+		if path == "" {
+			path = cfg.Scan.History
+		}
+		if path == "" {
+			path = history.DefaultPath
+		}
+		recorded, err := history.Load(path)
+		if err != nil {
+			fmt.Fprintf(os.Stderr, "%v\n", err)
+			os.Exit(1)
+		}
+		
+		flaky := recorded.Flaky()
+		switch flakyFormat {
+		case "json":
+			data, err := json.MarshalIndent(struct {
+				Flaky []history.FlakyFinding `json:"flaky"`
+			}{append([]history.FlakyFinding{}, flaky...)}, "", "  ")
+			if err != nil {
+				fmt.Fprintf(os.Stderr, "Failed to encode flaky findings: %v\n", err)
+				os.Exit(1)
+			}
+			fmt.Println(string(data))
+		case "text":
+			if len(flaky) == 0 {
+				fmt.Printf("No flaky findings in %d files recorded in %s\n", len(recorded.Files), path)
+				return
+			}
+			printFlakyFindings(os.Stdout, flaky)
+		default:
+			fmt.Fprintf(os.Stderr, "Unknown format %q (use text or json)\n", flakyFormat)
+			os.Exit(1)
+		}
+	},
+}
+
+// fixCmd applies the fixes that detectors attach to violations
+var fixCmd = &cobra.Command{
+	Use:   "fix [paths...]",
+	Short: "Automatically fix violations that have a known remediation",
+	Long: `Scan the given paths and apply the fixes of violations that have a
+mechanical remediation:
+
+  - Rust items with non-conventional names are renamed within their file
+  - HTTP status code literals are replaced with net/http constants
+  - Go comments holding commented-out code are deleted
+
+Fixes that overlap another fix in the same file are skipped; run the command
+again to apply them. Use --dry-run to review the changes as a unified diff.
+
+Examples:
+  goclean fix ./src --dry-run
+  goclean fix ./src`,
+	Args: cobra.MinimumNArgs(0),
+	Run: func(cmd *cobra.Command, args []string) {
+		cfg, err := config.LoadWithProfile(cfgFile, profileName)
+		if err != nil {
+			fmt.Fprintf(os.Stderr, "Failed to load configuration: %v\n", err)
+			os.Exit(1)
+		}
+		
+		scanPaths := args
+		if len(scanPaths) == 0 {
+			scanPaths = cfg.Scan.Paths
+		}
+		
+		_, results, err := newConfiguredEngine(cfg, scanPaths).Scan()
+		if err != nil {
+			fmt.Fprintf(os.Stderr, "Scan failed: %v\n", err)
+			os.Exit(1)
+		}
+		
+		report, err := fixer.New(fixDryRun).Apply(results)
+		if err != nil {
+			fmt.Fprintf(os.Stderr, "%v\n", err)
+			os.Exit(1)
+		}
+		
+		for _, file := range report.Files {
+			if fixDryRun {
+				fmt.Print(file.Diff)
+				continue
+			}
+			for _, violation := range file.Applied {
+				fmt.Printf("✓ %s:%d %s\n", file.Path, violation.Line, violation.Fix.Description)
+			}
+		}
+		
+		if fixDryRun {
+			fmt.Printf("\nWould fix %d violations in %d files\n", report.Applied, report.FilesChanged)
+		} else {
+			fmt.Printf("\nFixed %d violations in %d files\n", report.Applied, report.FilesChanged)
+		}
+		if report.Skipped > 0 {
+			fmt.Printf("%d fixes were skipped because they overlap other fixes; run goclean fix again to apply them\n", report.Skipped)
+		}
+	},
+}
+
+// mergeCmd combines the JSON reports of sharded scans
+var mergeCmd = &cobra.Command{
+	Use:   "merge <report.json> [report.json...]",
+	Short: "Merge JSON reports from sharded scans",
+	Long: `Merge the JSON reports of scans that ran in parallel on parts of a codebase,
+such as CI shards of a monorepo, into a single report.
+
+Violations reported by more than one shard are kept once, matched by
+fingerprint, and the summary and statistics are recomputed. Like scan, the
+command exits with code 1 when the merged report has violations at or above
+the fail-on severity, or fails the file health or documentation coverage
+gate. The panic surface gate needs the syntax trees, so each shard's scan
+checks it.
+
+Reports scanned with different rule sets, such as other goclean versions or
+thresholds, are refused unless --force is given: their manifests record the
+hash of the rule settings.
+
+Examples:
+  goclean scan ./services --format json --output shard1.json
+  goclean scan ./libs --format json --output shard2.json
+  goclean merge shard1.json shard2.json -o full.json`,
+	Args: cobra.MinimumNArgs(1),
+	Run: func(cmd *cobra.Command, args []string) {
+		cfg, err := config.LoadWithProfile(cfgFile, profileName)
+		if err != nil {
+			fmt.Fprintf(os.Stderr, "Failed to load configuration: %v\n", err)
+			os.Exit(1)
+		}
+		failSeverity, err := resolveFailSeverity(cfg, mergeFailOn)
+		if err != nil {
+			fmt.Fprintf(os.Stderr, "%v\n", err)
+			os.Exit(1)
+		}
+		
+		var shards []*reporters.JSONReport
+		for _, path := range args {
+			shard, err := reporters.LoadJSONReport(path)
+			if err != nil {
+				fmt.Fprintf(os.Stderr, "%v\n", err)
+				os.Exit(1)
+			}
+			shards = append(shards, shard)
+		}
+		if err := reporters.CheckRuleSets(shards); err != nil {
+			if !mergeForce {
+				fmt.Fprintf(os.Stderr, "Refusing to merge: %v\nRescan the shards with the same goclean version and configuration, or use --force\n", err)
+				os.Exit(1)
+			}
+			fmt.Fprintf(os.Stderr, "Warning: %v\n", err)
+		}
+		
+		reporter := reporters.NewJSONReporter(&config.JSONConfig{Enabled: true, Path: mergeOutputPath, PrettyPrint: true})
+		merged, err := reporter.Merge(shards)
+		if err != nil {
+			fmt.Fprintf(os.Stderr, "Failed to merge reports: %v\n", err)
+			os.Exit(1)
+		}
+		if err := reporter.Write(merged); err != nil {
+			fmt.Fprintf(os.Stderr, "%v\n", err)
+			os.Exit(1)
+		}
+		
+		fmt.Printf("📄 Merged %d reports into %s: %d violations in %d files scanned\n",
+			len(shards), mergeOutputPath, merged.Metadata.TotalViolations, merged.Metadata.FilesScanned)
+		
+		// Apply the same exit rules as scan to the merged report
+		results, err := merged.ScanResults()
+		if err != nil {
+			fmt.Fprintf(os.Stderr, "%v\n", err)
+			os.Exit(1)
+		}
+		summary := &models.ScanSummary{
+			TotalFiles:      merged.Metadata.FilesScanned,
+			TotalViolations: merged.Metadata.TotalViolations,
+			Health:          merged.Health,
+			DocCoverage:     merged.DocCoverage,
+		}
+		gatesFailed := checkQualityGates(cfg, summary, results)
+		if gatesFailed || failingViolations(results, failSeverity) > 0 {
+			os.Exit(1)
+		}
+	},
+}
+
+// compareCmd diffs the violations of two reports or git revisions
+var compareCmd = &cobra.Command{
+	Use:   "compare <base> <head>",
+	Short: "List the violations added and removed between two reports or revisions",
+	Long: `Compare the violations of two scans and list those added, removed and
+unchanged, with counts by severity. Each side is a JSON report written by
+scan --format json, or a git revision that is checked out in a temporary
+worktree and scanned with the current configuration.
+
+Violations are matched by fingerprint, which survives code moving up or
+down. With --fail-on-new the command exits with code 1 when the head has
+more violations of that severity or above than the base: a release gate
+requiring no net-new critical violations uses --fail-on-new critical.
+
+Reports scanned with different rule sets are refused unless --force is
+given, because their violations are not comparable. Revisions are scanned
+from the directory matching the current one, so compare reports scanned
+from the same directory.
+
+Examples:
+  goclean compare before.json after.json
+  goclean compare v1.4.0 HEAD --fail-on-new critical
+  goclean compare origin/main report.json --format json`,
+	Args: cobra.ExactArgs(2),
+	Run: func(cmd *cobra.Command, args []string) {
+		cfg, err := config.LoadWithProfile(cfgFile, profileName)
+		if err != nil {
+			fmt.Fprintf(os.Stderr, "Failed to load configuration: %v\n", err)
+			os.Exit(1)
+		}
+		
+		var failSeverity models.Severity
+		if compareFailOn != "" {
+			if failSeverity, err = models.ParseSeverity(compareFailOn); err != nil {
+				fmt.Fprintf(os.Stderr, "Invalid --fail-on-new: %v\n", err)
+				os.Exit(1)
+			}
+		}
+		
+		sides := make([]*reporters.JSONReport, len(args))
+		for i, arg := range args {
+			if sides[i], err = loadComparedReport(cfg, arg); err != nil {
+				fmt.Fprintf(os.Stderr, "%v\n", err)
+				os.Exit(1)
+			}
+		}
+		if err := reporters.CheckRuleSets(sides); err != nil {
+			if !compareForce {
+				fmt.Fprintf(os.Stderr, "Refusing to compare: %v\nRescan both sides with the same goclean version and configuration, or use --force\n", err)
+				os.Exit(1)
+			}
+			fmt.Fprintf(os.Stderr, "Warning: %v\n", err)
+		}
+		
+		comparison := reporters.CompareReports(sides[0], sides[1])
+		switch compareFormat {
+		case "json":
+			data, err := json.MarshalIndent(comparison, "", "  ")
+			if err != nil {
+				fmt.Fprintf(os.Stderr, "Failed to encode comparison: %v\n", err)
+				os.Exit(1)
+			}
+			fmt.Println(string(data))
+		case "text":
+			printComparison(os.Stdout, args[0], args[1], comparison)
+		default:
+			fmt.Fprintf(os.Stderr, "Unsupported format %q (use text or json)\n", compareFormat)
+			os.Exit(1)
+		}
+		
+		if compareFailOn != "" {
+			if net := comparison.Summary.NetNew(failSeverity); net > 0 {
+				fmt.Fprintf(os.Stderr, "%d net-new violations of %s severity or above\n", net, strings.ToLower(failSeverity.String()))
+				os.Exit(1)
+			}
+		}
+	},
+}
+
+// watchCmd rescans files as they change
+var watchCmd = &cobra.Command{
+	Use:   "watch [paths...]",
+	Short: "Rescan files as they change and print their violations",
+	Long: `Scan the given paths, then keep watching them and rescan only the files that
+are added or modified. Clone, coupling, unused pub API and documentation
+coverage detection then run again over every watched file, so a change can
+also update the violations of files that did not change. The violations of
+each updated file are printed as file:line:col: level: message lines,
+followed by the running total.
+
+Changes are detected through inotify on Linux. Other platforms, trees where
+notifications cannot be set up, and runs with --poll are polled every
+--interval instead; use --poll on network, container and WSL mounts, which
+do not deliver notifications, and raise --interval on very large trees.
+Press Ctrl+C to stop.
+
+Examples:
+  goclean watch ./src
+  goclean watch ./src --interval 2s
+  goclean watch ./src --poll`,
+	Args: cobra.MinimumNArgs(0),
+	Run: func(cmd *cobra.Command, args []string) {
+		cfg, err := config.LoadWithProfile(cfgFile, profileName)
+		if err != nil {
+			fmt.Fprintf(os.Stderr, "Failed to load configuration: %v\n", err)
+			os.Exit(1)
+		}
+		
+		watchPaths := args
+		if len(watchPaths) == 0 {
+			watchPaths = cfg.Scan.Paths
+		}
+		
+		walker := scanner.NewFileWalkerWithConfig(watchPaths, cfg.Scan.Exclude, cfg.Scan.FileTypes, false,
+			cfg.Scan.GetSkipTestFiles(), cfg.Scan.GetAggressiveMode(), cfg.Scan.CustomTestPatterns)
+		scan := func(paths []string) ([]*models.ScanResult, error) {
+			_, results, err := newConfiguredEngine(cfg, paths).Scan()
+			return results, err
+		}
+		analyzer := newConfiguredEngine(cfg, watchPaths)
+		analyze := func(results []*models.ScanResult) error {
+			analyzer.Reanalyze(results)
+			return nil
+		}
+		
+		ctx, stop := signal.NotifyContext(context.Background(), os.Interrupt)
+		defer stop()
+		
+		fmt.Printf("👀 Watching %s (Ctrl+C to stop)\n", strings.Join(watchPaths, ", "))
+		counts := make(map[string]int)
+		locations := reporters.NewLocationsReporter(reporters.FormatLocations)
+		watcher := watch.New(walker.Walk, scan, analyze, watchInterval)
+		if !watchPoll {
+			watcher.WithNotifications(watchPaths...)
+		}
+		watcher.Run(ctx, func(update *watch.Update) {
+			fmt.Printf("\n[%s] %d files updated\n", time.Now().Format("15:04:05"), len(update.Results)+len(update.Removed))
+			for _, path := range update.Removed {
+				delete(counts, path)
+			}
+			for _, result := range update.Results {
+				counts[result.File.Path] = len(result.Violations)
+				if len(result.Violations) == 0 {
+					fmt.Printf("✓ %s\n", result.File.Path)
+				}
+			}
+			if err := locations.Write(os.Stdout, update.Results); err != nil {
+				fmt.Fprintf(os.Stderr, "%v\n", err)
+			}
+			
+			total, files := 0, 0
+			for _, count := range counts {
+				total += count
+				if count > 0 {
+					files++
+				}
+			}
+			fmt.Printf("%d violations in %d files\n", total, files)
+		}, func(err error) {
+			fmt.Fprintf(os.Stderr, "Watch: %v\n", err)
+		})
+	},
+}
+
+// selftestCmd checks that the configured detectors still report the bundled corpus
+var selftestCmd = &cobra.Command{
+	Use:   "selftest",
+	Short: "Check that the configured rules fire on the bundled test corpus",
+	Long: `Run the enabled detectors, with the thresholds of the configuration, over the
+bundled test corpus (testdata/rust and testdata/samples) and check that every
+file still reports the violations it was written to trigger.
+
+A failure usually means a configuration change disabled a rule or raised a
+threshold beyond the corpus. The corpus is looked up in ./testdata, then next
+to the goclean executable, unless --corpus names its directory.
+
+Examples:
+  goclean selftest
+  goclean selftest --config goclean.yaml
+  goclean selftest --corpus /usr/share/goclean/testdata`,
+	Args: cobra.NoArgs,
+	Run: func(cmd *cobra.Command, args []string) {
+		cfg, err := config.LoadWithProfile(cfgFile, profileName)
+		if err != nil {
+			fmt.Fprintf(os.Stderr, "Failed to load configuration: %v\n", err)
+			os.Exit(1)
+		}
+		
+		candidates := []string{selftestCorpus}
+		if selftestCorpus == "" {
+			candidates = append(candidates, "testdata")
+			if executable, err := os.Executable(); err == nil {
+				candidates = append(candidates, filepath.Join(filepath.Dir(executable), "testdata"))
+			}
+		}
+		corpus, err := selftest.Locate(candidates...)
+		if err != nil {
+			fmt.Fprintf(os.Stderr, "%v\n", err)
+			os.Exit(1)
+		}
+		
+		report, err := selftest.Run(corpus, buildDetectorConfig(cfg))
+		if err != nil {
+			fmt.Fprintf(os.Stderr, "%v\n", err)
+			os.Exit(1)
+		}
+		
+		fmt.Printf("Self-test corpus: %s\n", corpus)
+		for _, file := range report.Files {
+			switch {
+			case !file.Found:
+				fmt.Printf("  FAIL %s: file not found or not scanned\n", file.File)
+			case !file.Passed():
+				fmt.Printf("  FAIL %s: %s not reported\n", file.File, strings.Join(file.Missing, ", "))
+			default:
+				fmt.Printf("  ok   %s (%s)\n", file.File, strings.Join(file.Expected, ", "))
+			}
+		}
+		if failed := report.Failed(); failed > 0 {
+			fmt.Printf("%d of %d corpus files failed; check for disabled rules or raised thresholds\n", failed, len(report.Files))
+			os.Exit(1)
+		}
+		fmt.Printf("All %d corpus files reported their expected violations\n", len(report.Files))
+	},
+}
+
+// benchCmd measures scan throughput on generated corpora
+var benchCmd = &cobra.Command{
+	Use:   "bench",
+	Short: "Measure scan throughput on generated Go and Rust corpora",
+	Long: `Generate corpora of Go and Rust code in a temporary directory and scan each
+one repeatedly with the detectors and thresholds of the configuration. The
+median scan of every corpus is reported in files and lines per second, with
+the memory it allocated.
+
+The built-in corpora are small (20 files), medium (200 files) and large
+(1000 files). Save a run with --output and pass it to --compare on a later
+build: the command exits with status 1 when a corpus is scanned more than
+--max-regression percent slower than in the saved run.
+
+Examples:
+  goclean bench
+  goclean bench --corpus small --corpus medium --iterations 10
+  goclean bench --output bench-v1.json
+  goclean bench --compare bench-v1.json --max-regression 15`,
+	Args: cobra.NoArgs,
+	Run: func(cmd *cobra.Command, args []string) {
+		cfg, err := config.LoadWithProfile(cfgFile, profileName)
+		if err != nil {
+			fmt.Fprintf(os.Stderr, "Failed to load configuration: %v\n", err)
+			os.Exit(1)
+		}
+		
+		corpora := bench.Corpora
+		if len(benchCorpora) > 0 {
+			corpora = nil
+			for _, name := range benchCorpora {
+				corpus, err := bench.FindCorpus(name)
+				if err != nil {
+					fmt.Fprintf(os.Stderr, "%v\n", err)
+					os.Exit(1)
+				}
+				corpora = append(corpora, corpus)
+			}
+		}
+		
+		var baselineReport *bench.Report
+		if benchCompare != "" {
+			// Fail on a bad baseline before spending time on the scans
+			if baselineReport, err = bench.Load(benchCompare); err != nil {
+				fmt.Fprintf(os.Stderr, "%v\n", err)
+				os.Exit(1)
+			}
+		}
+		
+		report, err := bench.Run(corpora, benchIterations, buildDetectorConfig(cfg), rootCmd.Version)
+		if err != nil {
+			fmt.Fprintf(os.Stderr, "%v\n", err)
+			os.Exit(1)
+		}
+		
+		switch benchFormat {
+		case "json":
+			data, err := json.MarshalIndent(report, "", "  ")
+			if err != nil {
+				fmt.Fprintf(os.Stderr, "Failed to encode benchmark report: %v\n", err)
+				os.Exit(1)
+			}
+			fmt.Println(string(data))
+		case "text":
+			printBenchResults(os.Stdout, report)
+		default:
+			fmt.Fprintf(os.Stderr, "Unsupported format %q (use text or json)\n", benchFormat)
+			os.Exit(1)
+		}
+		
+		if benchOutput != "" {
+			if err := report.Save(benchOutput); err != nil {
+				fmt.Fprintf(os.Stderr, "%v\n", err)
+				os.Exit(1)
+			}
+		}
+		
+		if baselineReport != nil {
+			regressions := bench.Compare(baselineReport, report, benchMaxRegression)
+			for _, regression := range regressions {
+				fmt.Fprintf(os.Stderr, "Regression: %s corpus scanned at %.0f lines/s, %.1f%% slower than %.0f lines/s in %s\n",
+					regression.Corpus, regression.Current, regression.Slowdown, regression.Baseline, benchCompare)
+			}
+			if len(regressions) > 0 {
+				os.Exit(1)
+			}
+		}
+	},
+}
+
+// lspCmd serves diagnostics and function health scores to editors
+var lspCmd = &cobra.Command{
+	Use:   "lsp",
+	Short: "Run a language server over stdio",
+	Long: `Run a Language Server Protocol server on stdin and stdout. Files are analyzed
+when they are opened or saved: violations are published as diagnostics and
+each function gets a "health: N/100" inlay hint combining its size,
+complexity and parameter count with the violations reported inside it.
+
+Code actions insert a "// goclean:ignore <rule>" comment above a violation or
+add it to the baseline file (scan.baseline, or goclean-baseline.json).
+Violations recorded in the baseline are not published.
+
+Configure your editor to start "goclean lsp" for Go and Rust files.`,
+	Args: cobra.NoArgs,
+	Run: func(cmd *cobra.Command, args []string) {
+		cfg, err := config.LoadWithProfile(cfgFile, profileName)
+		if err != nil {
+			fmt.Fprintf(os.Stderr, "Failed to load configuration: %v\n", err)
+			os.Exit(1)
+		}
+		
+		baselineFile := cfg.Scan.Baseline
+		if baselineFile == "" {
+			baselineFile = baseline.DefaultPath
+		}
+		workspace, _ := os.Getwd()
+		scan := func(path string) ([]*models.ScanResult, error) {
+			// Scan paths relative to the workspace so fingerprints match baselines written by the CLI
+			if rel, err := filepath.Rel(workspace, path); err == nil && !strings.HasPrefix(rel, "..") {
+				path = rel
+			}
+			_, results, err := newConfiguredEngine(cfg, []string{path}).Scan()
+			if err != nil {
+				return nil, err
+			}
+			// The baseline is reloaded on every scan to pick up violations added from the editor
+			if known, err := baseline.Load(baselineFile); err == nil {
+				known.Filter(nil, results)
+			}
+			return results, nil
+		}
+		server := lsp.NewServer(scan, health.NewScorer(buildDetectorConfig(cfg)), rootCmd.Version)
+		server.SetBaselinePath(baselineFile)
+		if err := server.Serve(os.Stdin, os.Stdout); err != nil {
+			fmt.Fprintf(os.Stderr, "Language server stopped: %v\n", err)
+			os.Exit(1)
+		}
+	},
+}
+
+// loadComparedReport loads one side of a comparison: a JSON report file, or
+// otherwise a git revision to scan
+func loadComparedReport(cfg *config.Config, arg string) (*reporters.JSONReport, error) {
+	if info, err := os.Stat(arg); err == nil && !info.IsDir() {
+		return reporters.LoadJSONReport(arg)
+	}
+	return scanRevision(cfg, arg)
+}
+
+// scanRevision scans a git revision checked out in a temporary worktree.
+// The scan runs in the checkout directory matching the working directory,
+// so its paths match those of a scan run here.
+func scanRevision(cfg *config.Config, revision string) (*reporters.JSONReport, error) {
+	worktree, err := gitdiff.CheckoutWorktree(".", revision)
+	if err != nil {
+		return nil, fmt.Errorf("%s is neither a report file nor a revision: %w", revision, err)
+	}
+	defer worktree.Remove()
+	
+	workingDir, err := os.Getwd()
+	if err != nil {
+		return nil, err
+	}
+	if err := os.Chdir(worktree.Dir); err != nil {
+		return nil, err
+	}
+	defer os.Chdir(workingDir)
+	
+	start := time.Now()
+	summary, results, err := newConfiguredEngine(cfg, cfg.Scan.Paths).Scan()
+	if err != nil {
+		return nil, fmt.Errorf("scan of %s failed: %w", revision, err)
+	}
+	applyThirdParty(cfg, cfg.Scan.Paths, summary, results)
+	
+	report := models.NewReport(summary, results, nil)
+	report.Manifest = scanManifest(cfg, cfg.Scan.Paths, time.Since(start))
+	return reporters.NewJSONReporter(nil).Build(report), nil
+}
+
+// printComparison writes the added and removed violations of a comparison
+func printComparison(out io.Writer, base, head string, comparison *reporters.ReportComparison) {
+	summary := comparison.Summary
+	fmt.Fprintf(out, "Comparing %s with %s: %d added, %d removed, %d unchanged\n",
+		base, head, summary.Added, summary.Removed, summary.Unchanged)
+	for _, section := range []struct {
+		title      string
+		violations []reporters.JSONViolation
+	}{
+		{"Added", comparison.Added},
+		{"Removed", comparison.Removed},
+	} {
+		if len(section.violations) == 0 {
+			continue
+		}
+		fmt.Fprintf(out, "\n%s:\n", section.title)
+		w := tabwriter.NewWriter(out, 0, 8, 2, ' ', 0)
+		for _, v := range section.violations {
+			fmt.Fprintf(w, "  %s\t%s\t%s:%d\t%s\n", v.Severity, v.Rule, v.File, v.Line, v.Message)
+		}
+		w.Flush()
+	}
+	
+	var net []string
+	for _, severity := range []models.Severity{models.SeverityCritical, models.SeverityHigh, models.SeverityMedium, models.SeverityLow, models.SeverityInfo} {
+		if count := summary.NetBySeverity[severity.String()]; count != 0 {
+			net = append(net, fmt.Sprintf("%+d %s", count, strings.ToLower(severity.String())))
+		}
+	}
+	if len(net) > 0 {
+		fmt.Fprintf(out, "\nNet change: %s\n", strings.Join(net, ", "))
+	}
+}
+
+// scanManifest captures the version, rule settings and environment of a scan
+func scanManifest(cfg *config.Config, scanPaths []string, duration time.Duration) *models.ScanManifest {
+	repoDir := "."
+	if len(scanPaths) > 0 {
+		repoDir = gitdiff.RepositoryDir(scanPaths[0])
+	}
+	return manifest.Capture(rootCmd.Version, buildDetectorConfig(cfg), cfg, repoDir, duration)
+}
+
+// newConfiguredEngine creates a quiet scanner engine for the configuration
+func newConfiguredEngine(cfg *config.Config, scanPaths []string) *scanner.Engine {
+	engine := scanner.NewEngineWithConfig(scanPaths, cfg.Scan.Exclude, cfg.Scan.FileTypes, false,
+		cfg.Scan.GetSkipTestFiles(), cfg.Scan.GetAggressiveMode(), cfg.Scan.CustomTestPatterns)
+	engine.SetViolationDetectorConfig(buildDetectorConfig(cfg))
+	engine.SetRustEdition(cfg.Rust.Edition)
+	engine.SetMaxWorkers(cfg.Scan.ConcurrentFiles)
+	return engine
+}
+
+// findViolationByFingerprint returns the violation with the given fingerprint or prefix
+func findViolationByFingerprint(results []*models.ScanResult, fingerprint string) *models.Violation {
+	for _, result := range results {
+		for _, violation := range result.Violations {
+			if violation.Fingerprint != "" && strings.HasPrefix(violation.Fingerprint, fingerprint) {
+				return violation
+			}
+		}
+	}
+	return nil
+}
+
+// replayFalsePositive re-runs a reproduction bundle and reports whether the violation still fires
+func replayFalsePositive(bundlePath string) {
+	bundle, err := fpreport.Load(bundlePath)
+	if err != nil {
+		fmt.Fprintf(os.Stderr, "%v\n", err)
+		os.Exit(1)
+	}
+	
+	result, err := fpreport.Replay(bundle)
+	if err != nil {
+		fmt.Fprintf(os.Stderr, "%v\n", err)
+		os.Exit(1)
+	}
+	
+	fmt.Printf("Replaying %s (rule %s, reported by v%s)\n", bundlePath, bundle.Rule, bundle.ToolVersion)
+	fmt.Printf("Original message: %s\n", bundle.Message)
+	for _, violation := range result.Matches {
+		fmt.Printf("  %s:%d %s\n", violation.File, violation.Line, violation.Message)
+	}
+	
+	if result.Reproduced {
+		fmt.Printf("\n❌ Reproduced: rule %s still fires on line %d\n", bundle.Rule, bundle.Line)
+	} else {
+		fmt.Printf("\n✅ Not reproduced: rule %s no longer fires on line %d\n", bundle.Rule, bundle.Line)
+	}
+}
+
+// getVersionFromFile reads the version from the VERSION file, falling back to a default if not found
+func getVersionFromFile() string {
+	const defaultVersion = "dev"
+	
+	// Try to find VERSION file in the current directory or parent directories
+	versionPaths := []string{
+		"VERSION",
+		"../VERSION",
+		"../../VERSION",
+	}
+	
+	for _, versionPath := range versionPaths {
+		if file, err := os.Open(versionPath); err == nil {
+			defer file.Close()
+			
+			content, err := io.ReadAll(file)
+			if err == nil {
+				version := strings.TrimSpace(string(content))
+				if version != "" {
+					return version
+				}
+			}
+		}
+	}
+	
+	// If VERSION file not found, try to find it relative to the executable
+	if execPath, err := os.Executable(); err == nil {
+		execDir := filepath.Dir(execPath)
+		versionFile := filepath.Join(execDir, "VERSION")
+		if file, err := os.Open(versionFile); err == nil {
+			defer file.Close()
+			
+			content, err := io.ReadAll(file)
+			if err == nil {
+				version := strings.TrimSpace(string(content))
+				if version != "" {
+					return version
+				}
+			}
+		}
+	}
+	
+	return defaultVersion
+}
+
+// applyThirdParty downgrades or separates the violations in vendored and
+// third-party files, as configured
+func applyThirdParty(cfg *config.Config, scanPaths []string, summary *models.ScanSummary, results []*models.ScanResult) thirdparty.Stats {
+	thirdPartyConfig := cfg.Scan.ThirdParty
+	repositoryDir := "."
+	if len(scanPaths) > 0 {
+		repositoryDir = gitdiff.RepositoryDir(scanPaths[0])
+	}
+	
+	classifier := thirdparty.New(".", thirdPartyConfig.Paths)
+	if thirdPartyConfig.GetSubmodules() {
+		if err := classifier.AddSubmodules(repositoryDir); err != nil {
+			fmt.Fprintf(os.Stderr, "Warning: failed to read git submodules: %v\n", err)
+		}
+	}
+	
+	if thirdPartyConfig.Mode == config.ThirdPartyModeSeparate {
+		return classifier.Separate(summary, results)
+	}
+	maxSeverity, err := models.ParseSeverity(thirdPartyConfig.MaxSeverity)
+	if err != nil {
+		maxSeverity = models.SeverityLow
+	}
+	return classifier.Downgrade(results, maxSeverity)
+}
+
+// writeLocations writes the location list to the output path, or to stdout
+func writeLocations(results []*models.ScanResult) {
+	out := io.Writer(os.Stdout)
+	if outputPath != "" {
+		file, err := os.Create(outputPath)
+		if err != nil {
+			fmt.Fprintf(os.Stderr, "Failed to create %s: %v\n", outputPath, err)
+			os.Exit(1)
+		}
+		defer file.Close()
+		out = file
+	}
+	if err := reporters.NewLocationsReporter(format).Write(out, results); err != nil {
+		fmt.Fprintf(os.Stderr, "Failed to write locations: %v\n", err)
+		os.Exit(1)
+	}
+}
+
+// generateConsoleViolationsOutput outputs violations in a structured format for AI agents
+func generateConsoleViolationsOutput(summary *models.ScanSummary, results []*models.ScanResult) {
+	// Print basic scan summary first
+	fmt.Printf("=== GoClean Scan Results ===\n")
+	fmt.Printf("Total Files: %d\n", summary.TotalFiles)
+	fmt.Printf("Scanned Files: %d\n", summary.ScannedFiles)
+	fmt.Printf("Total Violations: %d\n", summary.TotalViolations)
+	fmt.Printf("Scan Duration: %v\n\n", summary.Duration)
+	
+	if summary.TotalViolations == 0 {
+		fmt.Println("✅ No violations found!")
+		return
+	}
+	
+	fmt.Println("=== Violations (Structured Format) ===")
+	
+	// Create tab writer for structured output
+	w := tabwriter.NewWriter(os.Stdout, 0, 8, 1, '\t', 0)
+	
+	// Write header
+	fmt.Fprintf(w, "%s\t%s\t%s\t%s\t%s\n", "FILE", "LINE", "TYPE", "LEVEL", "MESSAGE")
+	
+	// Collect all violations from all files
+	var allViolations []*models.Violation
+	for _, result := range results {
+		for _, violation := range result.Violations {
+			allViolations = append(allViolations, violation)
+		}
+	}
+	
+	// Sort violations by file and then by line number
+	sort.Slice(allViolations, func(i, j int) bool {
+		if allViolations[i].File != allViolations[j].File {
+			return allViolations[i].File < allViolations[j].File
+		}
+		return allViolations[i].Line < allViolations[j].Line
+	})
+	
+	// Output each violation in tab-delimited format
+	for _, violation := range allViolations {
+		// Clean up message (remove newlines and tabs but preserve content)
+		message := strings.ReplaceAll(violation.Message, "\n", " ")
+		message = strings.ReplaceAll(message, "\t", " ")
+		
+		fmt.Fprintf(w, "%s\t%d\t%s\t%s\t%s\n",
+			violation.File,
+			violation.Line,
+			string(violation.Type),
+			violation.Severity.String(),
+			message)
+	}
+	
+	// Flush the tabwriter
+	w.Flush()
+	
+	fmt.Printf("\n=== Summary by Type ===\n")
+	// Count violations by type
+	violationCounts := make(map[models.ViolationType]int)
+	for _, violation := range allViolations {
+		violationCounts[violation.Type]++
+	}
+	
+	// Sort by count descending
+	type typeCount struct {
+		Type  models.ViolationType
+		Count int
+	}
+	var sortedTypes []typeCount
+	for vtype, count := range violationCounts {
+		sortedTypes = append(sortedTypes, typeCount{Type: vtype, Count: count})
+	}
+	sort.Slice(sortedTypes, func(i, j int) bool {
+		return sortedTypes[i].Count > sortedTypes[j].Count
+	})
+	
+	for _, tc := range sortedTypes {
+		fmt.Printf("%-30s: %d\n", tc.Type.GetDisplayName(), tc.Count)
+	}
+	
+	fmt.Printf("\n=== Summary by Severity ===\n")
+	// Count violations by severity
+	severityCounts := make(map[models.Severity]int)
+	for _, violation := range allViolations {
+		severityCounts[violation.Severity]++
+	}
+	
+	// Output in order of severity
+	severities := []models.Severity{models.SeverityCritical, models.SeverityHigh, models.SeverityMedium, models.SeverityLow, models.SeverityInfo}
+	for _, severity := range severities {
+		if count, exists := severityCounts[severity]; exists && count > 0 {
+			fmt.Printf("%-10s: %d\n", severity.String(), count)
+		}
+	}
+	
+	fmt.Printf("\n⚠️  Total: %d violations found\n", summary.TotalViolations)
+}
+
+// getLanguageExtensions maps language names to their file extensions
+func getLanguageExtensions(languages []string) []string {
+	languageMap := map[string][]string{
+		"go":         {".go"},
 		"rust":       {".rs"},
 		"javascript": {".js", ".jsx"},
 		"typescript": {".ts", ".tsx"},
@@ -577,17 +1781,437 @@ func estimateCacheSize(scanPaths []string) int {
 	return estimatedSize
 }
 
+// printThresholdSettings writes thresholds as an aligned table
+func printThresholdSettings(out io.Writer, settings []config.ThresholdSetting) {
+	w := tabwriter.NewWriter(out, 0, 8, 2, ' ', 0)
+	fmt.Fprintln(w, "THRESHOLD\tCONFIGURED\tGROUP\tSCALE\tEFFECTIVE")
+	for _, setting := range settings {
+		group, scale := "-", "-"
+		if setting.Group != "" {
+			group = setting.Group
+			scale = strconv.FormatFloat(setting.Scale, 'g', -1, 64)
+		}
+		fmt.Fprintf(w, "%s\t%d\t%s\t%s\t%d\n", setting.Key, setting.Configured, group, scale, setting.Effective)
+	}
+	w.Flush()
+}
+
+// printDetectorTimings prints the time each detector took to standard error,
+// slowest first, keeping standard output for reports
+func printDetectorTimings(timings []violations.DetectorTiming) {
+	w := tabwriter.NewWriter(os.Stderr, 0, 8, 2, ' ', 0)
+	fmt.Fprintln(w, "DETECTOR\tFILES\tTOTAL\tPER FILE")
+	for _, timing := range timings {
+		perFile := time.Duration(0)
+		if timing.Files > 0 {
+			perFile = timing.Duration / time.Duration(timing.Files)
+		}
+		fmt.Fprintf(w, "%s\t%d\t%v\t%v\n", timing.Name, timing.Files,
+			timing.Duration.Round(time.Microsecond), perFile.Round(time.Microsecond))
+	}
+	w.Flush()
+}
+
+// recordHistory adds the findings of a scan to the history file and warns
+// about findings that flipped on unchanged content
+func recordHistory(path, ruleSetHash string, results []*models.ScanResult, quiet bool) {
+	recorded, err := history.Load(path)
+	if err != nil {
+		fmt.Fprintf(os.Stderr, "Warning: %v; starting a new history\n", err)
+		recorded = history.New()
+	}
+	stats := recorded.Record(results, ruleSetHash)
+	if err := recorded.Save(path); err != nil {
+		fmt.Fprintf(os.Stderr, "Warning: %v\n", err)
+		return
+	}
+	if stats.Flaky > 0 {
+		fmt.Fprintf(os.Stderr, "Warning: %d findings appeared or disappeared on unchanged content; run goclean flaky --history %s\n",
+			stats.Flaky, path)
+	} else if !quiet {
+		fmt.Printf("History: %d files recorded, %d unchanged since an earlier scan\n", stats.Files, stats.Unchanged)
+	}
+}
+
+// printFlakyFindings writes the flaky findings as a table
+func printFlakyFindings(out io.Writer, flaky []history.FlakyFinding) {
+	w := tabwriter.NewWriter(out, 0, 8, 2, ' ', 0)
+	fmt.Fprintln(w, "RULE\tTYPE\tLOCATION\tSEEN\tFLIPS\tMESSAGE")
+	for _, finding := range flaky {
+		fmt.Fprintf(w, "%s\t%s\t%s:%d\t%d/%d\t%d\t%s\n", finding.Rule, finding.Type,
+			finding.File, finding.Line, finding.Seen, finding.Runs, finding.Flips, finding.Message)
+	}
+	w.Flush()
+}
+
+// printBenchResults writes the benchmark results as a table
+func printBenchResults(out io.Writer, report *bench.Report) {
+	fmt.Fprintf(out, "GoClean %s, %s on %s/%s with %d CPUs\n\n", report.GoCleanVersion, report.GoVersion, report.OS, report.Arch, report.CPUs)
+	w := tabwriter.NewWriter(out, 0, 8, 2, ' ', 0)
+	fmt.Fprintln(w, "CORPUS\tFILES\tLINES\tSECONDS\tFILES/S\tLINES/S\tALLOCATED\tVIOLATIONS")
+	for _, result := range report.Results {
+		fmt.Fprintf(w, "%s\t%d\t%d\t%.3f\t%.0f\t%.0f\t%.1f MiB\t%d\n", result.Corpus, result.Files, result.Lines,
+			result.Seconds, result.FilesPerSecond, result.LinesPerSecond, float64(result.AllocatedBytes)/(1<<20), result.Violations)
+	}
+	w.Flush()
+}
+
+// printReportPaths prints where the configured reports were written
+func printReportPaths(reporterManager *reporters.Manager, cfg *config.Config) {
+	if htmlPath := reporterManager.GetHTMLOutputPath(); htmlPath != "" {
+		fmt.Printf("📊 HTML report generated: %s\n", htmlPath)
+		if cfg.Output.HTML.AutoRefresh {
+			fmt.Printf("   Auto-refresh enabled (every %d seconds)\n", cfg.Output.HTML.RefreshInterval)
+		}
+	}
+
+	if markdownPath := reporterManager.GetMarkdownOutputPath(); markdownPath != "" {
+		fmt.Printf("📝 Markdown report generated: %s\n", markdownPath)
+	}
+
+	if jsonPath := reporterManager.GetJSONOutputPath(); jsonPath != "" {
+		fmt.Printf("📄 JSON report generated: %s\n", jsonPath)
+	}
+
+	if inventoryPath := reporterManager.GetUnsafeInventoryPath(); inventoryPath != "" {
+		fmt.Printf("🔒 Unsafe inventory generated: %s\n", inventoryPath)
+	}
+
+	if censusPath := reporterManager.GetPanicCensusPath(); censusPath != "" {
+		fmt.Printf("💥 Panic census generated: %s\n", censusPath)
+	}
+
+	if xmlPath := reporterManager.GetJUnitPath(); xmlPath != "" {
+		fmt.Printf("🧪 JUnit report generated: %s\n", xmlPath)
+	}
+
+	if qualityPath := reporterManager.GetCodeClimatePath(); qualityPath != "" {
+		fmt.Printf("🦊 Code Climate report generated: %s\n", qualityPath)
+	}
+
+	if summaryPath := reporterManager.GetGitHubSummaryPath(); summaryPath != "" {
+		fmt.Printf("🐙 GitHub job summary written: %s\n", summaryPath)
+	}
+}
+
+// generateSplitReports writes one set of reports per owner, top-level
+// directory or crate and prints where they were written
+func generateSplitReports(reporterManager *reporters.Manager, cfg *config.Config, scanPaths []string,
+	summary *models.ScanSummary, results []*models.ScanResult) {
+	parts, err := reporters.SplitResults(cfg.Output.SplitBy, scanPaths, summary, results)
+	if err != nil {
+		fmt.Fprintf(os.Stderr, "Failed to split reports: %v\n", err)
+		os.Exit(1)
+	}
+	if err := reporterManager.GenerateSplitReports(parts); err != nil {
+		fmt.Fprintf(os.Stderr, "Failed to generate reports: %v\n", err)
+		os.Exit(1)
+	}
+	
+	fmt.Printf("📂 Reports split by %s into %d parts:\n", cfg.Output.SplitBy, len(parts))
+	for _, part := range parts {
+		fmt.Printf("   %s: %d violations in %s/\n", part.Name, part.Summary.TotalViolations, part.Slug)
+	}
+	if htmlPath := reporterManager.GetHTMLOutputPath(); htmlPath != "" {
+		fmt.Printf("📊 HTML index of the split reports: %s\n", htmlPath)
+	}
+}
+
+// ruleListing is a rule as listed by the rules command
+type ruleListing struct {
+	ID                 string   `json:"id"`
+	Detector           string   `json:"detector"`
+	Category           string   `json:"category"`
+	Severity           string   `json:"severity"`                      // Default severity
+	ConfiguredSeverity string   `json:"configured_severity,omitempty"` // Set by severity.rules
+	Languages          []string `json:"languages"`
+	Enabled            bool     `json:"enabled"`
+}
+
+// ruleListings describes every rule of the registered detectors under a detector configuration
+func ruleListings(detectorConfig *violations.DetectorConfig) []ruleListing {
+	var overrides map[string]models.Severity
+	if detectorConfig.SeverityConfig != nil {
+		overrides = detectorConfig.SeverityConfig.RuleSeverities
+	}
+
+	catalog := violations.Rules()
+	listing := make([]ruleListing, 0, len(catalog))
+	for _, rule := range catalog {
+		entry := ruleListing{
+			ID:        rule.ID,
+			Detector:  rule.Detector,
+			Category:  rule.Category,
+			Severity:  strings.ToLower(rule.Severity.String()),
+			Languages: rule.Languages,
+			Enabled:   rule.Enabled(detectorConfig),
+		}
+		if severity, ok := overrides[rule.ID]; ok {
+			entry.ConfiguredSeverity = strings.ToLower(severity.String())
+		}
+		listing = append(listing, entry)
+	}
+	return listing
+}
+
+// printRuleListings writes the rules as a table; the severity column shows configured overrides
+func printRuleListings(out io.Writer, listing []ruleListing) {
+	w := tabwriter.NewWriter(out, 0, 8, 2, ' ', 0)
+	fmt.Fprintln(w, "RULE\tCATEGORY\tSEVERITY\tLANGUAGES\tENABLED")
+	for _, rule := range listing {
+		severity := rule.Severity
+		if rule.ConfiguredSeverity != "" {
+			severity = rule.ConfiguredSeverity + " (configured)"
+		}
+		enabled := "yes"
+		if !rule.Enabled {
+			enabled = "no"
+		}
+		fmt.Fprintf(w, "%s\t%s\t%s\t%s\t%s\n", rule.ID, rule.Category, severity, strings.Join(rule.Languages, ","), enabled)
+	}
+	w.Flush()
+}
+
+// ruleSeverities parses the configured rule severities. Deprecated rule IDs
+// apply to the rules that replace them.
+func ruleSeverities(overrides map[string]string) map[string]models.Severity {
+	severities := make(map[string]models.Severity, len(overrides))
+	for rule, name := range overrides {
+		severity, err := models.ParseSeverity(name)
+		if err != nil {
+			continue // Rejected when the configuration is validated
+		}
+		for _, id := range rules.Resolve(rule) {
+			severities[id] = severity
+		}
+	}
+	return severities
+}
+
+// checkQualityGates prints the quality gates the scan fails to stderr and
+// reports whether any failed. Every output mode checks them before exiting.
+func checkQualityGates(cfg *config.Config, summary *models.ScanSummary, results []*models.ScanResult) bool {
+	failures := qualityGateFailures(cfg, summary, results)
+	for _, failure := range failures {
+		fmt.Fprintf(os.Stderr, "\n❌ %s\n", failure)
+	}
+	return len(failures) > 0
+}
+
+// qualityGateFailures describes the panic surface, file health and
+// documentation coverage gates the scan fails
+func qualityGateFailures(cfg *config.Config, summary *models.ScanSummary, results []*models.ScanResult) []string {
+	var failures []string
+	if maxSurface := cfg.Export.PanicCensus.MaxPanicSurface; maxSurface > 0 {
+		census := reporters.BuildPanicCensus(results)
+		if census.Summary.PanicSurface > maxSurface {
+			failures = append(failures, fmt.Sprintf("Panic surface of %d exceeds the maximum of %d (%d in binary targets)",
+				census.Summary.PanicSurface, maxSurface, census.Summary.BinaryPanicSurface))
+		}
+	}
+	if minScore := cfg.Severity.MinFileHealth; minScore > 0 {
+		if below := models.FilesBelowHealth(summary.Health, minScore); len(below) > 0 {
+			failures = append(failures, fmt.Sprintf("%d files have a health score below %d, lowest: %s (%d)",
+				len(below), minScore, below[0].File, below[0].Score))
+		}
+	}
+	if minCoverage := cfg.Severity.DocCoverageMin; minCoverage > 0 {
+		if coverage := summary.DocCoverage; coverage != nil && coverage.Percent < float64(minCoverage) {
+			lowest := coverage.LowestModules()[0]
+			failures = append(failures, fmt.Sprintf("Documentation coverage of %.1f%% is below the minimum of %d%%, lowest: %s (%.1f%%)",
+				coverage.Percent, minCoverage, lowest.Module, lowest.Percent))
+		}
+	}
+	return failures
+}
+
+// resolveFailSeverity applies a --fail-on flag to the configuration, checks
+// the severity settings and returns the lowest severity that fails a run
+func resolveFailSeverity(cfg *config.Config, flag string) (models.Severity, error) {
+	if flag != "" {
+		cfg.Severity.FailOn = flag
+	}
+	if err := cfg.Severity.Validate(); err != nil {
+		return models.SeverityInfo, err
+	}
+	failSeverity, err := models.ParseSeverity(cfg.Severity.FailOn)
+	if err != nil {
+		return models.SeverityInfo, nil
+	}
+	return failSeverity, nil
+}
+
+// failingViolations counts the violations at or above the severity that fails a scan
+func failingViolations(results []*models.ScanResult, failSeverity models.Severity) int {
+	failing := 0
+	for _, result := range results {
+		for _, violation := range result.Violations {
+			if violation.Severity >= failSeverity {
+				failing++
+			}
+		}
+	}
+	return failing
+}
+
+// buildDetectorConfig applies the effective thresholds to the default detector configuration
+func buildDetectorConfig(cfg *config.Config) *violations.DetectorConfig {
+	thresholds := cfg.Thresholds.Effective()
+	detectorConfig := violations.DefaultDetectorConfig()
+	detectorConfig.MaxFunctionLines = thresholds.FunctionLines
+	detectorConfig.MaxCyclomaticComplexity = thresholds.CyclomaticComplexity
+	detectorConfig.MaxCognitiveComplexity = thresholds.CognitiveComplexity
+	detectorConfig.MaxParameters = thresholds.Parameters
+	detectorConfig.MaxNestingDepth = thresholds.NestingDepth
+	detectorConfig.MaxClassLines = thresholds.ClassLines
+	detectorConfig.MaxStringRepeats = thresholds.MagicStringRepeats
+	detectorConfig.MaxPrimitiveRepeats = thresholds.PrimitiveRepeats
+	detectorConfig.MaxGodStructFields = thresholds.GodStructFields
+	detectorConfig.MaxGodStructMethods = thresholds.GodStructMethods
+	detectorConfig.MagicNumbers = violations.MagicNumberAllowlist{
+		InIndex:     cfg.MagicNumbers.AllowInIndex,
+		InTests:     cfg.MagicNumbers.AllowInTests,
+		PowersOfTwo: cfg.MagicNumbers.AllowPowersOfTwo,
+		Epsilon:     cfg.MagicNumbers.AllowEpsilon,
+		Values:      cfg.MagicNumbers.Allowed,
+		Files:       cfg.MagicNumbers.Files,
+	}
+	detectorConfig.UnitLiterals = violations.UnitLiteralConfig{
+		Enabled:  cfg.UnitLiterals.Enabled,
+		MinValue: cfg.UnitLiterals.MinValue,
+		Kinds:    cfg.UnitLiterals.Kinds,
+	}
+	detectorConfig.TodoIssues = violations.TodoIssueConfig{
+		Enabled: cfg.Todos.RequireIssue,
+		Pattern: cfg.Todos.IssuePattern,
+		Markers: cfg.Todos.IssueMarkers,
+	}
+	detectorConfig.MinCloneTokens = thresholds.DuplicateTokens
+	detectorConfig.CloneMinimums = violations.CloneMinimums{
+		IntraFile:   thresholds.DuplicateTokensIntraFile,
+		IntraModule: thresholds.DuplicateTokensIntraModule,
+		CrossModule: thresholds.DuplicateTokensCrossModule,
+	}
+	detectorConfig.MaxFanOut = thresholds.FanOut
+	detectorConfig.MinMaintainabilityIndex = thresholds.MaintainabilityIndex
+	detectorConfig.MaxHalsteadVolume = thresholds.HalsteadVolume
+	detectorConfig.MaxHalsteadDifficulty = thresholds.HalsteadDifficulty
+	detectorConfig.MaxHalsteadEffort = thresholds.HalsteadEffort
+	detectorConfig.AggressiveMode = cfg.Scan.GetAggressiveMode()
+	detectorConfig.SkipTestFiles = cfg.Scan.GetSkipTestFiles()
+	detectorConfig.ReportUnusedSuppressions = cfg.Scan.ReportUnusedSuppressions
+	detectorConfig.MinCommentedCodeConfidence = cfg.CommentedCode.MinConfidence
+	detectorConfig.ShowReasons = showReason
+	detectorConfig.DetectPastedCode = cfg.Scan.DetectPastedCode
+	detectorConfig.Grouping = violations.GroupingConfig{
+		MinOccurrences: cfg.Scan.GroupRepeated.MinOccurrences,
+		Rules:          cfg.Scan.GroupRepeated.Rules,
+	}
+	detectorConfig.Remediation = violations.RemediationConfig{Rules: cfg.Debt.Rules}
+	detectorConfig.SeverityConfig.RuleSeverities = ruleSeverities(cfg.Severity.Rules)
+	detectorConfig.RustConfig.DetectInefficientString = cfg.Rust.GetDetectInefficientString()
+	detectorConfig.RustConfig.DetectBoxedPrimitives = cfg.Rust.GetDetectBoxedPrimitives()
+	detectorConfig.RustConfig.DetectBlockingInAsync = cfg.Rust.GetDetectBlockingInAsync()
+	detectorConfig.RustConfig.BlockingFunctions = cfg.Rust.BlockingFunctions
+	detectorConfig.RustConfig.DetectSequentialAwaits = cfg.Rust.GetDetectSequentialAwaits()
+	detectorConfig.RustConfig.DetectHeapAllocations = cfg.Rust.GetDetectHeapAllocations()
+	detectorConfig.RustConfig.DetectUnusedPublicAPI = cfg.Rust.GetDetectUnusedPublicAPI()
+	detectorConfig.RustConfig.AllowMutableStatics = cfg.Rust.GetAllowMutableStatics()
+	detectorConfig.RustConfig.DowngradeMutableStatics = cfg.Rust.GetDowngradeMutableStatics()
+	detectorConfig.RustConfig.RequireUnsafeComments = cfg.Rust.GetRequireUnsafeComments()
+	detectorConfig.RustConfig.SafetyCommentMarker = cfg.Rust.SafetyCommentMarker
+	detectorConfig.RustConfig.DetectPointerArithmetic = cfg.Rust.GetDetectPointerArithmetic()
+	detectorConfig.RustConfig.SkipInternalDocs = cfg.Rust.GetSkipInternalDocs()
+	detectorConfig.RustConfig.RequireSafetySection = cfg.Rust.GetRequireSafetySection()
+	detectorConfig.RustConfig.RequireErrorsSection = cfg.Rust.GetRequireErrorsSection()
+	detectorConfig.RustConfig.RequirePanicsSection = cfg.Rust.GetRequirePanicsSection()
+	detectorConfig.RustConfig.RequireDocExamples = cfg.Rust.GetRequireDocExamples()
+	detectorConfig.RustConfig.DocExamplePaths = cfg.Rust.DocExamplePaths
+	detectorConfig.RustConfig.CargoCheck = cfg.Rust.GetCargoCheck()
+	detectorConfig.RustConfig.EnableConcurrencyPack = cfg.Rust.HasRulePack(config.RulePackConcurrency)
+	detectorConfig.RustConfig.EnableImportsPack = cfg.Rust.HasRulePack(config.RulePackImports)
+	detectorConfig.RustConfig.MaxLocalImports = cfg.Rust.MaxLocalImports
+	detectorConfig.RustConfig.MaxReexportDepth = cfg.Rust.MaxReexportDepth
+	detectorConfig.RustConfig.UnboundedChannelAPIs = cfg.Rust.UnboundedChannelAPIs
+	detectorConfig.RustConfig.MaxImplMethods = cfg.Rust.MaxImplMethods
+	detectorConfig.RustConfig.MaxImplLines = cfg.Rust.MaxImplLines
+	detectorConfig.RustConfig.MaxEnumVariants = cfg.Rust.MaxEnumVariants
+	detectorConfig.RustConfig.MaxErrorVariants = cfg.Rust.MaxErrorVariants
+	detectorConfig.RustConfig.MaxUnwrapsPerFunction = cfg.Rust.MaxUnwrapsPerFunction
+	detectorConfig.RustConfig.MaxUnwrapsPerFile = cfg.Rust.MaxUnwrapsPerFile
+	detectorConfig.RustConfig.LibraryPanicMacros = cfg.Rust.LibraryPanicMacros
+	detectorConfig.RustConfig.AllowPanicsInBinaries = cfg.Rust.GetAllowPanicsInBinaries()
+	detectorConfig.RustConfig.AllowPanicsInTests = cfg.Rust.GetAllowPanicsInTests()
+	detectorConfig.RustConfig.AllowStringErrorsInBinaries = cfg.Rust.GetAllowStringErrorsInBinaries()
+	detectorConfig.RustConfig.EnforceResultPropagation = cfg.Rust.GetEnforceResultPropagation()
+	detectorConfig.RustConfig.EnablePatternMatchCheck = cfg.Rust.GetEnablePatternMatchCheck()
+	detectorConfig.RustConfig.RequireExhaustiveMatch = cfg.Rust.GetRequireExhaustiveMatch()
+	detectorConfig.RustConfig.MaxModuleDepth = cfg.Rust.MaxModuleDepth
+	detectorConfig.RustConfig.MaxTupleArity = cfg.Rust.MaxTupleArity
+	detectorConfig.RustConfig.SkipEnumAttributes = cfg.Rust.SkipEnumAttributes
+	detectorConfig.RustConfig.MaxMacroArms = cfg.Rust.MaxMacroArms
+	detectorConfig.RustConfig.MaxMacroNesting = cfg.Rust.MaxMacroNesting
+	detectorConfig.RustConfig.MaxMacroLines = cfg.Rust.MaxMacroLines
+	for _, plugin := range cfg.Plugins {
+		detectorConfig.Plugins = append(detectorConfig.Plugins, violations.PluginSpec{
+			Name:      plugin.Name,
+			Command:   plugin.Command,
+			Args:      plugin.Args,
+			Languages: plugin.Languages,
+			Timeout:   plugin.GetTimeout(),
+			Sandbox:   pluginLimits(plugin.Sandbox),
+		})
+	}
+	for _, rule := range cfg.ScriptRules {
+		severity, err := models.ParseSeverity(rule.Severity)
+		if err != nil {
+			severity = models.SeverityLow
+		}
+		detectorConfig.ScriptRules = append(detectorConfig.ScriptRules, violations.ScriptRuleSpec{
+			ID:         rule.ID,
+			Match:      rule.GetMatch(),
+			When:       rule.When,
+			Message:    rule.Message,
+			Severity:   severity,
+			Suggestion: rule.Suggestion,
+			Languages:  rule.Languages,
+		})
+	}
+	return detectorConfig
+}
+
+// pluginLimits returns the sandbox limits of a plugin, or nil when it runs
+// outside the sandbox
+func pluginLimits(cfg config.PluginSandboxConfig) *sandbox.Limits {
+	if !cfg.GetEnabled() {
+		return nil
+	}
+	return &sandbox.Limits{
+		CPUTime:    cfg.GetCPUTime(),
+		Memory:     cfg.GetMemory(),
+		Network:    cfg.AllowNetwork,
+		Filesystem: cfg.AllowFilesystem,
+		ReadPaths:  cfg.ReadPaths,
+
+		AllowUnsandboxed: cfg.AllowUnsandboxed,
+	}
+}
+
 func init() {
 	// Global flags
 	rootCmd.PersistentFlags().StringVar(&cfgFile, "config", "", "config file (default is goclean.yaml)")
+	rootCmd.PersistentFlags().StringVar(&profileName, "profile", "", "rule profile: default, strict, relaxed, security or embedded (default from config)")
 	rootCmd.PersistentFlags().BoolVarP(&verbose, "verbose", "v", false, "verbose output")
 
 	// Scan command flags
 	scanCmd.Flags().StringSliceVarP(&exclude, "exclude", "e", []string{}, "exclude patterns (comma-separated)")
 	scanCmd.Flags().StringSliceVarP(&fileTypes, "types", "t", []string{}, "file types to scan (comma-separated, e.g., .go,.js,.py)")
 	scanCmd.Flags().StringSliceVarP(&languages, "languages", "l", []string{}, "languages to scan (comma-separated, e.g., go,rust,javascript)")
-	scanCmd.Flags().StringVarP(&format, "format", "f", "html", "output format (html, markdown, json)")
+	scanCmd.Flags().StringVarP(&format, "format", "f", "html", "output format (html, markdown, json, codeclimate, github-summary, locations, quickfix)")
 	scanCmd.Flags().StringVarP(&outputPath, "output", "o", "", "output file path")
+	scanCmd.Flags().IntVarP(&jobs, "jobs", "j", 0, "number of files to scan in parallel (default from config, or the number of CPUs)")
+	scanCmd.Flags().StringVar(&failOn, "fail-on", "", "lowest severity that fails the scan: info, low, medium, high or critical (default from config)")
+	scanCmd.Flags().IntVar(&minHealth, "min-health", 0, "fail the scan when a file's health score is below this, from 1 to 100 (default from config)")
 	
 	// Test file handling flags
 	scanCmd.Flags().BoolVar(&aggressive, "aggressive", false, "Enable aggressive mode (scan test files and apply stricter rules)")
@@ -596,11 +2220,77 @@ func init() {
 	
 	// Console output flags
 	scanCmd.Flags().BoolVar(&consoleViolations, "console-violations", false, "Output violations directly to console in structured format for AI agents")
+	scanCmd.Flags().BoolVar(&showReason, "show-reason", false, "Print which lines of a comment parsed as code under each commented-code violation")
+	
+	// HTML report flags
+	scanCmd.Flags().BoolVar(&selfContained, "self-contained", false, "Write an interactive HTML report that needs no network access to view")
+	
+	// JUnit report flags
+	scanCmd.Flags().StringVar(&junitPath, "junit", "", "Write violations as JUnit XML test failures to this file")
+	scanCmd.Flags().StringVar(&junitGroupBy, "junit-group-by", "", "Group JUnit test cases into suites by rule or file (default from config)")
+	
+	// Monorepo report flags
+	scanCmd.Flags().StringVar(&splitReportBy, "split-report-by", "", "Write one set of reports per owner (CODEOWNERS), root (top-level directory) or crate")
+	
+	// Profiling flags
+	scanCmd.Flags().BoolVar(&timings, "timings", false, "Print the time each detector and plugin took after the scan")
 	
 	// Rust-specific flags
 	scanCmd.Flags().BoolVar(&rustOptimizations, "rust-opt", false, "Enable Rust performance optimizations (auto-enabled when scanning Rust)")
 	scanCmd.Flags().IntVar(&rustCacheSize, "rust-cache-size", 0, "Rust AST cache size (0 = auto-estimate)")
 	scanCmd.Flags().IntVar(&rustCacheTTL, "rust-cache-ttl", 0, "Rust cache TTL in minutes (0 = 30 minutes)")
+	scanCmd.Flags().StringVar(&rustEdition, "rust-edition", "", "Rust edition to parse with (auto, 2015, 2018, 2021, 2024; default from config)")
+	scanCmd.Flags().StringVar(&unsafeInventoryPath, "unsafe-inventory", "", "Write an inventory of unsafe Rust code and FFI boundaries to this JSON file")
+	scanCmd.Flags().StringVar(&panicCensusPath, "panic-census", "", "Write a per-target census of potential Rust panic sites to this JSON file")
+	scanCmd.Flags().IntVar(&maxPanicSurface, "max-panic-surface", 0, "Fail when production Rust code has more potential panic sites than this (0 = disabled)")
+
+	// False-positive report flags
+	reportFPCmd.Flags().StringVarP(&fpOutputPath, "output", "o", "", "Reproduction file path (default goclean-fp-<fingerprint>.json)")
+	reportFPCmd.Flags().IntVar(&fpContextLines, "context", 3, "Lines of context to include around the violation")
+	reportFPCmd.Flags().BoolVar(&fpFullFile, "full-file", false, "Include the whole file instead of the violation's lines")
+	reportFPCmd.Flags().BoolVar(&fpRedactStrings, "redact-strings", false, "Replace string literal contents with placeholders")
+	reportFPCmd.Flags().StringVar(&fpReplayPath, "replay", "", "Replay a reproduction file against the current detectors")
+	
+	// Baseline flags
+	scanCmd.Flags().StringVar(&baselinePath, "baseline", "", "Only report violations not recorded in this baseline file")
+	scanCmd.Flags().StringVar(&diffBase, "diff-base", "", "Only report violations on lines changed since this git revision")
+	baselineCmd.Flags().StringVarP(&baselineOutputPath, "output", "o", "", "Baseline file path (default goclean-baseline.json)")
+	
+	// History flags
+	scanCmd.Flags().StringVar(&historyPath, "history", "", "Record findings in this history file to detect flaky findings")
+	flakyCmd.Flags().StringVar(&historyPath, "history", "", "History file written by scan --history (default goclean-history.json)")
+	flakyCmd.Flags().StringVar(&flakyFormat, "format", "text", "output format (text, json)")
+	
+	// Fix flags
+	fixCmd.Flags().BoolVar(&fixDryRun, "dry-run", false, "Print the fixes as a unified diff without changing files")
+	
+	// Rules flags
+	rulesCmd.Flags().StringVar(&rulesFormat, "format", "text", "output format (text, json)")
+	
+	// Merge flags
+	mergeCmd.Flags().StringVarP(&mergeOutputPath, "output", "o", "./reports/violations.json", "Merged report path")
+	mergeCmd.Flags().BoolVar(&mergeForce, "force", false, "Merge reports produced by different rule sets")
+	mergeCmd.Flags().StringVar(&mergeFailOn, "fail-on", "", "lowest severity that fails the merge: info, low, medium, high or critical (default from config)")
+	
+	// Compare flags
+	compareCmd.Flags().StringVar(&compareFormat, "format", "text", "output format (text, json)")
+	compareCmd.Flags().StringVar(&compareFailOn, "fail-on-new", "", "Exit with code 1 when the head has more violations of this severity or above (info, low, medium, high, critical)")
+	compareCmd.Flags().BoolVar(&compareForce, "force", false, "Compare reports produced by different rule sets")
+	
+	// Watch flags
+	watchCmd.Flags().DurationVar(&watchInterval, "interval", watch.DefaultInterval, "How often to check the watched files for changes when file system notifications are unavailable")
+	watchCmd.Flags().BoolVar(&watchPoll, "poll", false, "Poll the watched files every --interval instead of using file system notifications")
+	
+	// Self-test flags
+	selftestCmd.Flags().StringVar(&selftestCorpus, "corpus", "", "Directory of the test corpus (default ./testdata, or testdata next to the executable)")
+	
+	// Bench flags
+	benchCmd.Flags().StringSliceVar(&benchCorpora, "corpus", nil, "Corpora to scan: small, medium, large (default all)")
+	benchCmd.Flags().IntVar(&benchIterations, "iterations", 5, "Measured scans of each corpus, after one warm-up scan")
+	benchCmd.Flags().StringVar(&benchFormat, "format", "text", "output format (text, json)")
+	benchCmd.Flags().StringVar(&benchOutput, "output", "", "Save the results to this file for later comparison")
+	benchCmd.Flags().StringVar(&benchCompare, "compare", "", "Fail when slower than the results saved in this file")
+	benchCmd.Flags().Float64Var(&benchMaxRegression, "max-regression", 10, "Slowdown in lines per second tolerated by --compare, in percent")
 
 	// Config subcommands
 	configCmd.AddCommand(configInitCmd)
@@ -609,9 +2299,28 @@ func init() {
 	rootCmd.AddCommand(scanCmd)
 	rootCmd.AddCommand(configCmd)
 	rootCmd.AddCommand(versionCmd)
+	rootCmd.AddCommand(reportFPCmd)
+	rootCmd.AddCommand(rulesCmd)
+	rootCmd.AddCommand(baselineCmd)
+	rootCmd.AddCommand(flakyCmd)
+	rootCmd.AddCommand(fixCmd)
+	rootCmd.AddCommand(lspCmd)
+	rootCmd.AddCommand(mergeCmd)
+	rootCmd.AddCommand(compareCmd)
+	rootCmd.AddCommand(watchCmd)
+	rootCmd.AddCommand(selftestCmd)
+	rootCmd.AddCommand(benchCmd)
 }
 
 func main() {
+	// Sandboxed plugins start as goclean processes that restrict themselves
+	// before executing the plugin
+	if len(os.Args) > 1 && os.Args[1] == sandbox.Arg {
+		err := sandbox.Run(os.Args[2:])
+		fmt.Fprintf(os.Stderr, "Error: %v\n", err)
+		os.Exit(sandbox.ExitCode)
+	}
+
 	if err := rootCmd.Execute(); err != nil {
 		fmt.Fprintf(os.Stderr, "Error: %v\n", err)
 		os.Exit(1)
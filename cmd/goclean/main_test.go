@@ -1,7 +1,12 @@
 package main
 
 import (
+	"strings"
 	"testing"
+
+	"github.com/ericfisherdev/goclean/internal/config"
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/violations"
 )
 
 func TestRootCommand(t *testing.T) {
@@ -175,4 +180,106 @@ func TestGlobalVariables(t *testing.T) {
 			t.Errorf("Global variable %s should not be nil", tc.name)
 		}
 	}
-}
\ No newline at end of file
+}
+func TestPrintThresholdSettings(t *testing.T) {
+	thresholds := config.GetDefaultConfig().Thresholds
+	thresholds.Complexity.Scale = 1.5
+
+	var out strings.Builder
+	printThresholdSettings(&out, thresholds.Settings())
+
+	lines := strings.Split(strings.TrimSpace(out.String()), "\n")
+	if len(lines) != 8 {
+		t.Fatalf("Expected a header and 7 thresholds, got %d lines:\n%s", len(lines), out.String())
+	}
+	if fields := strings.Fields(lines[1]); strings.Join(fields, " ") != "function_lines 25 complexity 1.5 38" {
+		t.Errorf("Unexpected function_lines row: %q", lines[1])
+	}
+	if fields := strings.Fields(lines[6]); strings.Join(fields, " ") != "class_lines 150 - - 150" {
+		t.Errorf("Unexpected class_lines row: %q", lines[6])
+	}
+}
+
+func TestRuleListings(t *testing.T) {
+	detectorConfig := violations.DefaultDetectorConfig()
+	detectorConfig.SeverityConfig.RuleSeverities = map[string]models.Severity{"todo-comment": models.SeverityInfo}
+
+	listing := make(map[string]ruleListing)
+	for _, rule := range ruleListings(detectorConfig) {
+		listing[rule.ID] = rule
+	}
+	todo := listing["todo-comment"]
+	if todo.Severity != "low" || todo.ConfiguredSeverity != "info" || todo.Category != models.CategoryMaintainability || !todo.Enabled {
+		t.Errorf("Unexpected todo-comment listing: %+v", todo)
+	}
+	if pasted := listing["pasted-code"]; pasted.Enabled || len(pasted.Languages) != 2 {
+		t.Errorf("Expected pasted-code to be listed for Go and Rust and disabled, got %+v", pasted)
+	}
+
+	var out strings.Builder
+	printRuleListings(&out, ruleListings(detectorConfig))
+	if !strings.Contains(out.String(), "info (configured)") {
+		t.Errorf("Expected the configured severity in the table, got:\n%s", out.String())
+	}
+}
+
+func TestRuleSeverities(t *testing.T) {
+	severities := ruleSeverities(map[string]string{
+		"todo-comment":               "info",
+		"function-length":            "Major",
+		"rust-missing-documentation": "low",
+		"magic-number":               "severe",
+	})
+	if severities["todo-comment"] != models.SeverityInfo || severities["function-length"] != models.SeverityMedium {
+		t.Errorf("Unexpected severities: %v", severities)
+	}
+	if severities["rust-missing-function-documentation"] != models.SeverityLow {
+		t.Errorf("Expected a deprecated rule to apply to its replacements, got %v", severities)
+	}
+	if _, ok := severities["magic-number"]; ok {
+		t.Error("Expected an unknown severity to be skipped")
+	}
+}
+
+func TestFailingViolations(t *testing.T) {
+	results := []*models.ScanResult{{Violations: []*models.Violation{
+		{Rule: "todo-comment", Severity: models.SeverityInfo},
+		{Rule: "function-length", Severity: models.SeverityMedium},
+		{Rule: "class-size", Severity: models.SeverityCritical},
+	}}}
+	tests := map[models.Severity]int{
+		models.SeverityInfo:     3,
+		models.SeverityMedium:   2,
+		models.SeverityHigh:     1,
+		models.SeverityCritical: 1,
+	}
+	for severity, want := range tests {
+		if got := failingViolations(results, severity); got != want {
+			t.Errorf("failingViolations(%s) = %d, want %d", severity, got, want)
+		}
+	}
+}
+
+func TestQualityGateFailures(t *testing.T) {
+	cfg := &config.Config{}
+	summary := &models.ScanSummary{
+		Health: []*models.FileHealth{{File: "a.go", Score: 90}, {File: "b.go", Score: 40}},
+		DocCoverage: &models.DocCoverage{Percent: 50, Modules: []models.ModuleDocCoverage{
+			{Module: "api", Percent: 25},
+			{Module: "core", Percent: 75},
+		}},
+	}
+	if failures := qualityGateFailures(cfg, summary, nil); len(failures) != 0 {
+		t.Errorf("Expected no failures without gates, got %v", failures)
+	}
+
+	cfg.Severity.MinFileHealth = 60
+	cfg.Severity.DocCoverageMin = 80
+	failures := qualityGateFailures(cfg, summary, nil)
+	if len(failures) != 2 {
+		t.Fatalf("Expected the health and coverage gates to fail, got %v", failures)
+	}
+	if !strings.Contains(failures[0], "lowest: b.go (40)") || !strings.Contains(failures[1], "lowest: api (25.0%)") {
+		t.Errorf("Expected the failures to name the lowest file and module, got %v", failures)
+	}
+}
@@ -0,0 +1,263 @@
+// Package baseline snapshots the violations of a codebase so later scans can
+// report only the violations introduced since the snapshot.
+package baseline
+
+import (
+	"encoding/json"
+	"fmt"
+	"os"
+	"path/filepath"
+	"sort"
+	"strings"
+	"time"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/rules"
+)
+
+// FormatVersion is the version of the baseline file format
+const FormatVersion = 1
+
+// DefaultPath is where baselines are written when no path is given
+const DefaultPath = "goclean-baseline.json"
+
+// Baseline is a snapshot of known violations
+type Baseline struct {
+	FormatVersion int       `json:"format_version"`
+	ToolVersion   string    `json:"tool_version"`
+	CreatedAt     time.Time `json:"created_at"`
+	Entries       []Entry   `json:"entries"`
+}
+
+// Entry is one known violation. Only the fingerprint is used for matching;
+// the other fields make the file reviewable.
+type Entry struct {
+	Fingerprint string `json:"fingerprint"`
+	Rule        string `json:"rule"`
+	Type        string `json:"type"`
+	File        string `json:"file"`
+	Line        int    `json:"line"`
+	Message     string `json:"message"`
+}
+
+// FilterStats summarizes how a scan compares to the baseline
+type FilterStats struct {
+	Suppressed int // Violations hidden because they are in the baseline
+	New        int // Violations not in the baseline
+	Resolved   int // Baseline entries no longer found
+}
+
+// New creates a baseline from the violations of a scan
+func New(results []*models.ScanResult, toolVersion string) *Baseline {
+	b := &Baseline{
+		FormatVersion: FormatVersion,
+		ToolVersion:   toolVersion,
+		CreatedAt:     time.Now().UTC(),
+		Entries:       make([]Entry, 0),
+	}
+	var violations []*models.Violation
+	for _, result := range results {
+		violations = append(violations, result.Violations...)
+	}
+	b.Add(violations)
+	return b
+}
+
+// Add records violations in the baseline, skipping those already recorded,
+// and returns how many were added
+func (b *Baseline) Add(violations []*models.Violation) int {
+	recorded := make(map[string]bool, len(b.Entries))
+	for _, entry := range b.Entries {
+		recorded[entry.Fingerprint] = true
+	}
+
+	added := 0
+	for _, violation := range violations {
+		fingerprint := violation.Fingerprint
+		if fingerprint == "" {
+			fingerprint = models.ComputeFingerprint(violation)
+		}
+		if recorded[fingerprint] {
+			continue
+		}
+		recorded[fingerprint] = true
+		b.Entries = append(b.Entries, Entry{
+			Fingerprint: fingerprint,
+			Rule:        violation.Rule,
+			Type:        string(violation.Type),
+			File:        filepath.ToSlash(violation.File),
+			Line:        violation.Line,
+			Message:     violation.Message,
+		})
+		added++
+	}
+
+	// Stable order keeps baseline diffs small when it is regenerated
+	sort.Slice(b.Entries, func(i, j int) bool {
+		a, c := b.Entries[i], b.Entries[j]
+		if a.File != c.File {
+			return a.File < c.File
+		}
+		if a.Line != c.Line {
+			return a.Line < c.Line
+		}
+		return a.Fingerprint < c.Fingerprint
+	})
+	return added
+}
+
+// Save writes the baseline as indented JSON
+func (b *Baseline) Save(path string) error {
+	data, err := json.MarshalIndent(b, "", "  ")
+	if err != nil {
+		return fmt.Errorf("failed to marshal baseline: %w", err)
+	}
+	if dir := filepath.Dir(path); dir != "." {
+		if err := os.MkdirAll(dir, 0755); err != nil {
+			return fmt.Errorf("failed to create baseline directory: %w", err)
+		}
+	}
+	if err := os.WriteFile(path, data, 0644); err != nil {
+		return fmt.Errorf("failed to write baseline: %w", err)
+	}
+	return nil
+}
+
+// Load reads a baseline written by Save
+func Load(path string) (*Baseline, error) {
+	data, err := os.ReadFile(path)
+	if err != nil {
+		return nil, fmt.Errorf("failed to read baseline: %w", err)
+	}
+	var b Baseline
+	if err := json.Unmarshal(data, &b); err != nil {
+		return nil, fmt.Errorf("failed to parse baseline: %w", err)
+	}
+	if b.FormatVersion > FormatVersion {
+		return nil, fmt.Errorf("baseline format version %d is newer than supported version %d", b.FormatVersion, FormatVersion)
+	}
+	return &b, nil
+}
+
+// Filter moves baselined violations from the results to their Baselined
+// lists and updates the summary counts. Violations are matched by
+// fingerprint without the occurrence suffix, so when a file has several
+// identical findings, only as many as the baseline recorded are hidden.
+// Entries that match no violation are added to the summary as fixed.
+func (b *Baseline) Filter(summary *models.ScanSummary, results []*models.ScanResult) FilterStats {
+	known := make(map[string]int, len(b.Entries))
+	for _, entry := range b.Entries {
+		known[baseFingerprint(entry.Fingerprint)]++
+	}
+	migrated := b.migratedFingerprints()
+
+	var stats FilterStats
+	for _, result := range results {
+		kept := result.Violations[:0]
+		for _, violation := range result.Violations {
+			fingerprint := violation.Fingerprint
+			if fingerprint == "" {
+				fingerprint = models.ComputeFingerprint(violation)
+			}
+			key := baseFingerprint(fingerprint)
+			if original, ok := migrated[key]; ok && known[key] == 0 {
+				key = original
+			}
+			if known[key] > 0 {
+				known[key]--
+				stats.Suppressed++
+				violation.Triage = models.TriageBaseline
+				result.Baselined = append(result.Baselined, violation)
+				if summary != nil {
+					summary.RemoveViolation(violation)
+				}
+				continue
+			}
+			kept = append(kept, violation)
+			stats.New++
+		}
+		result.Violations = kept
+	}
+
+	unmatched := make(map[string]int, len(known))
+	for key, remaining := range known {
+		stats.Resolved += remaining
+		unmatched[key] = remaining
+	}
+	if summary != nil {
+		summary.BaselineSuppressed = stats.Suppressed
+		summary.Fixed = b.fixedViolations(unmatched)
+	}
+	return stats
+}
+
+// fixedViolations returns the entries left unmatched by a scan as fixed
+// violations. Of several entries with the same fingerprint, the last ones
+// are taken as fixed.
+func (b *Baseline) fixedViolations(unmatched map[string]int) []*models.Violation {
+	seen := make(map[string]int)
+	for _, entry := range b.Entries {
+		seen[baseFingerprint(entry.Fingerprint)]++
+	}
+
+	var fixed []*models.Violation
+	for _, entry := range b.Entries {
+		key := baseFingerprint(entry.Fingerprint)
+		seen[key]--
+		if seen[key] >= unmatched[key] {
+			continue
+		}
+		fixed = append(fixed, &models.Violation{
+			Type:        models.ViolationType(entry.Type),
+			Message:     entry.Message,
+			File:        entry.File,
+			Line:        entry.Line,
+			Rule:        entry.Rule,
+			Fingerprint: entry.Fingerprint,
+			Triage:      models.TriageFixed,
+		})
+	}
+	return fixed
+}
+
+// DeprecatedRules returns the deprecated rule IDs recorded in the baseline
+func (b *Baseline) DeprecatedRules() []rules.Deprecation {
+	var found []rules.Deprecation
+	seen := make(map[string]bool)
+	for _, entry := range b.Entries {
+		if deprecation, ok := rules.Lookup(entry.Rule); ok && !seen[entry.Rule] {
+			seen[entry.Rule] = true
+			found = append(found, deprecation)
+		}
+	}
+	return found
+}
+
+// migratedFingerprints maps the fingerprints that entries of deprecated rules
+// would have under each replacement rule to the recorded fingerprints, so
+// renamed rules stay baselined until the baseline is regenerated
+func (b *Baseline) migratedFingerprints() map[string]string {
+	migrated := make(map[string]string)
+	for _, entry := range b.Entries {
+		deprecation, ok := rules.Lookup(entry.Rule)
+		if !ok {
+			continue
+		}
+		for _, replacement := range deprecation.Replacements {
+			fingerprint := models.ComputeFingerprint(&models.Violation{
+				Rule:    replacement,
+				Type:    models.ViolationType(entry.Type),
+				File:    entry.File,
+				Message: entry.Message,
+			})
+			migrated[fingerprint] = baseFingerprint(entry.Fingerprint)
+		}
+	}
+	return migrated
+}
+
+// baseFingerprint strips the occurrence suffix added to repeated findings
+func baseFingerprint(fingerprint string) string {
+	base, _, _ := strings.Cut(fingerprint, "-")
+	return base
+}
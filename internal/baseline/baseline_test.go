@@ -0,0 +1,183 @@
+package baseline
+
+import (
+	"encoding/json"
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+)
+
+func baselineTestViolation(file string, line int, rule, message string) *models.Violation {
+	v := &models.Violation{
+		Type:     models.ViolationTypeFunctionLength,
+		Severity: models.SeverityMedium,
+		Rule:     rule,
+		Message:  message,
+		File:     file,
+		Line:     line,
+	}
+	v.Fingerprint = models.ComputeFingerprint(v)
+	return v
+}
+
+func baselineTestResults() []*models.ScanResult {
+	return []*models.ScanResult{
+		{
+			File: &models.FileInfo{Path: "src/b.go"},
+			Violations: []*models.Violation{
+				baselineTestViolation("src/b.go", 12, "function-length", "Function 'Load' is too long"),
+			},
+		},
+		{
+			File: &models.FileInfo{Path: "src/a.go"},
+			Violations: []*models.Violation{
+				baselineTestViolation("src/a.go", 30, "function-length", "Function 'Parse' is too long"),
+				baselineTestViolation("src/a.go", 4, "function-length", "Function 'Init' is too long"),
+			},
+		},
+	}
+}
+
+func TestNewSortsEntries(t *testing.T) {
+	b := New(baselineTestResults(), "1.2.3")
+
+	if b.FormatVersion != FormatVersion || b.ToolVersion != "1.2.3" {
+		t.Errorf("Unexpected header: version %d, tool %q", b.FormatVersion, b.ToolVersion)
+	}
+	if len(b.Entries) != 3 {
+		t.Fatalf("Expected 3 entries, got %d", len(b.Entries))
+	}
+
+	expected := []struct {
+		file string
+		line int
+	}{{"src/a.go", 4}, {"src/a.go", 30}, {"src/b.go", 12}}
+	for i, entry := range b.Entries {
+		if entry.File != expected[i].file || entry.Line != expected[i].line {
+			t.Errorf("Entry %d: expected %s:%d, got %s:%d", i, expected[i].file, expected[i].line, entry.File, entry.Line)
+		}
+		if entry.Fingerprint == "" {
+			t.Errorf("Entry %d has no fingerprint", i)
+		}
+	}
+}
+
+func TestFilterHidesKnownViolations(t *testing.T) {
+	b := New(baselineTestResults(), "1.2.3")
+
+	// The same code later: Load was fixed and a new violation appeared
+	results := baselineTestResults()
+	results[0].Violations = nil
+	added := baselineTestViolation("src/a.go", 50, "function-length", "Function 'Render' is too long")
+	results[1].Violations = append(results[1].Violations, added)
+
+	summary := &models.ScanSummary{
+		TotalViolations:  3,
+		ViolationsByType: map[string]int{string(models.ViolationTypeFunctionLength): 3},
+	}
+	stats := b.Filter(summary, results)
+
+	if stats.Suppressed != 2 || stats.New != 1 || stats.Resolved != 1 {
+		t.Errorf("Unexpected stats: %+v", stats)
+	}
+	if len(results[1].Violations) != 1 || results[1].Violations[0] != added {
+		t.Fatalf("Expected only the new violation to remain, got %v", results[1].Violations)
+	}
+	if summary.TotalViolations != 1 || summary.BaselineSuppressed != 2 {
+		t.Errorf("Unexpected summary totals: %d violations, %d suppressed", summary.TotalViolations, summary.BaselineSuppressed)
+	}
+	if got := summary.ViolationsByType[string(models.ViolationTypeFunctionLength)]; got != 1 {
+		t.Errorf("Expected 1 function length violation in the summary, got %d", got)
+	}
+
+	if len(results[1].Baselined) != 2 || results[1].Baselined[0].Triage != models.TriageBaseline {
+		t.Errorf("Expected the known violations in Baselined, got %v", results[1].Baselined)
+	}
+	if len(summary.Fixed) != 1 || summary.Fixed[0].File != "src/b.go" || summary.Fixed[0].Line != 12 ||
+		summary.Fixed[0].Triage != models.TriageFixed {
+		t.Errorf("Expected Load to be reported as fixed, got %v", summary.Fixed)
+	}
+}
+
+func TestFilterMatchesRepeatedFindingsByCount(t *testing.T) {
+	repeated := func(fingerprint string) *models.Violation {
+		v := baselineTestViolation("src/a.go", 1, "magic-number", "Magic number 42")
+		v.Fingerprint = fingerprint
+		return v
+	}
+	known := []*models.ScanResult{{Violations: []*models.Violation{repeated("abc")}}}
+	b := New(known, "1.2.3")
+
+	// A second identical finding gets a suffixed fingerprint and is new
+	results := []*models.ScanResult{{Violations: []*models.Violation{repeated("abc"), repeated("abc-2")}}}
+	stats := b.Filter(nil, results)
+
+	if stats.Suppressed != 1 || stats.New != 1 {
+		t.Errorf("Expected 1 suppressed and 1 new violation, got %+v", stats)
+	}
+}
+
+func TestSaveAndLoad(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "nested", DefaultPath)
+	b := New(baselineTestResults(), "1.2.3")
+	if err := b.Save(path); err != nil {
+		t.Fatalf("Failed to save baseline: %v", err)
+	}
+
+	loaded, err := Load(path)
+	if err != nil {
+		t.Fatalf("Failed to load baseline: %v", err)
+	}
+	if len(loaded.Entries) != len(b.Entries) {
+		t.Fatalf("Expected %d entries, got %d", len(b.Entries), len(loaded.Entries))
+	}
+	for i := range b.Entries {
+		if loaded.Entries[i] != b.Entries[i] {
+			t.Errorf("Entry %d: expected %+v, got %+v", i, b.Entries[i], loaded.Entries[i])
+		}
+	}
+}
+
+func TestLoadRejectsNewerFormat(t *testing.T) {
+	path := filepath.Join(t.TempDir(), DefaultPath)
+	data, _ := json.Marshal(Baseline{FormatVersion: FormatVersion + 1})
+	if err := os.WriteFile(path, data, 0644); err != nil {
+		t.Fatalf("Failed to write baseline: %v", err)
+	}
+
+	if _, err := Load(path); err == nil {
+		t.Error("Expected an error for a newer baseline format")
+	}
+}
+
+func TestFilterMigratesDeprecatedRules(t *testing.T) {
+	old := baselineTestViolation("src/lib.rs", 3, "rust-missing-documentation", "Public Rust function 'run' is missing documentation")
+	b := New([]*models.ScanResult{{File: &models.FileInfo{Path: "src/lib.rs"}, Violations: []*models.Violation{old}}}, "1.0.0")
+
+	deprecated := b.DeprecatedRules()
+	if len(deprecated) != 1 || deprecated[0].ID != "rust-missing-documentation" {
+		t.Fatalf("Expected the deprecated rule to be reported, got %v", deprecated)
+	}
+
+	renamed := baselineTestViolation("src/lib.rs", 3, "rust-missing-function-documentation", "Public Rust function 'run' is missing documentation")
+	results := []*models.ScanResult{{File: &models.FileInfo{Path: "src/lib.rs"}, Violations: []*models.Violation{renamed}}}
+	stats := b.Filter(nil, results)
+	if stats.Suppressed != 1 || stats.New != 0 || stats.Resolved != 0 {
+		t.Errorf("Expected the renamed violation to stay baselined, got %+v", stats)
+	}
+}
+
+func TestAddSkipsRecordedViolations(t *testing.T) {
+	b := New(baselineTestResults(), "1.2.3")
+
+	known := baselineTestViolation("src/a.go", 4, "function-length", "Function 'Init' is too long")
+	added := baselineTestViolation("src/a.go", 20, "function-length", "Function 'Close' is too long")
+	if got := b.Add([]*models.Violation{known, added}); got != 1 {
+		t.Errorf("Expected 1 violation to be added, got %d", got)
+	}
+	if len(b.Entries) != 4 || b.Entries[1].Line != 20 {
+		t.Errorf("Expected the new entry sorted by line, got %+v", b.Entries)
+	}
+}
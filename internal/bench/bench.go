@@ -0,0 +1,221 @@
+// Package bench measures scan throughput on generated corpora of Go and
+// Rust code, and compares the results with a saved run so that packagers can
+// catch performance regressions between releases.
+package bench
+
+import (
+	"encoding/json"
+	"fmt"
+	"os"
+	"path/filepath"
+	"runtime"
+	"sort"
+	"strings"
+	"time"
+
+	"github.com/ericfisherdev/goclean/internal/scanner"
+	"github.com/ericfisherdev/goclean/internal/violations"
+)
+
+// FormatVersion is the version of the benchmark report format
+const FormatVersion = 1
+
+// Corpus is a generated code base of a given size
+type Corpus struct {
+	Name      string
+	Files     int // Half Go and half Rust files
+	Functions int // Functions per file
+}
+
+// Corpora are the built-in corpus sizes, smallest first
+var Corpora = []Corpus{
+	{Name: "small", Files: 20, Functions: 10},
+	{Name: "medium", Files: 200, Functions: 10},
+	{Name: "large", Files: 1000, Functions: 20},
+}
+
+// FindCorpus returns the built-in corpus with the given name
+func FindCorpus(name string) (Corpus, error) {
+	names := make([]string, 0, len(Corpora))
+	for _, corpus := range Corpora {
+		if corpus.Name == name {
+			return corpus, nil
+		}
+		names = append(names, corpus.Name)
+	}
+	return Corpus{}, fmt.Errorf("unknown corpus %q (available: %s)", name, strings.Join(names, ", "))
+}
+
+// Result is the measured throughput of scanning one corpus
+type Result struct {
+	Corpus         string  `json:"corpus"`
+	Files          int     `json:"files"`
+	Lines          int     `json:"lines"`
+	Iterations     int     `json:"iterations"`
+	Seconds        float64 `json:"seconds"` // Median time of a scan
+	FilesPerSecond float64 `json:"files_per_second"`
+	LinesPerSecond float64 `json:"lines_per_second"`
+	AllocatedBytes uint64  `json:"allocated_bytes"` // Memory allocated by the median scan
+	Violations     int     `json:"violations"`
+}
+
+// Report is the outcome of a benchmark run, with the environment it ran in
+type Report struct {
+	FormatVersion  int       `json:"format_version"`
+	GoCleanVersion string    `json:"goclean_version"`
+	GoVersion      string    `json:"go_version"`
+	OS             string    `json:"os"`
+	Arch           string    `json:"arch"`
+	CPUs           int       `json:"cpus"`
+	GeneratedAt    time.Time `json:"generated_at"`
+	Results        []Result  `json:"results"`
+}
+
+// Regression is a corpus scanned slower than in the baseline run
+type Regression struct {
+	Corpus   string
+	Baseline float64 // Lines per second of the baseline run
+	Current  float64 // Lines per second of this run
+	Slowdown float64 // Percentage of throughput lost
+}
+
+// Run generates each corpus in a temporary directory and scans it
+// iterations times after one warm-up scan, with the detector configuration
+func Run(corpora []Corpus, iterations int, config *violations.DetectorConfig, version string) (*Report, error) {
+	if iterations < 1 {
+		return nil, fmt.Errorf("iterations must be at least 1, got %d", iterations)
+	}
+
+	report := &Report{
+		FormatVersion:  FormatVersion,
+		GoCleanVersion: version,
+		GoVersion:      runtime.Version(),
+		OS:             runtime.GOOS,
+		Arch:           runtime.GOARCH,
+		CPUs:           runtime.NumCPU(),
+		GeneratedAt:    time.Now().UTC(),
+	}
+	for _, corpus := range corpora {
+		result, err := runCorpus(corpus, iterations, config)
+		if err != nil {
+			return nil, err
+		}
+		report.Results = append(report.Results, *result)
+	}
+	return report, nil
+}
+
+// runCorpus measures the scans of one corpus
+func runCorpus(corpus Corpus, iterations int, config *violations.DetectorConfig) (*Result, error) {
+	dir, err := os.MkdirTemp("", "goclean-bench-")
+	if err != nil {
+		return nil, fmt.Errorf("failed to create the corpus directory: %w", err)
+	}
+	defer os.RemoveAll(dir)
+
+	lines, err := Generate(dir, corpus)
+	if err != nil {
+		return nil, err
+	}
+
+	type sample struct {
+		duration  time.Duration
+		allocated uint64
+	}
+	samples := make([]sample, 0, iterations)
+	violationCount := 0
+	for i := 0; i <= iterations; i++ {
+		engine := scanner.NewEngine([]string{dir}, nil, []string{".go", ".rs"}, false)
+		engine.SetViolationDetectorConfig(config)
+
+		var before, after runtime.MemStats
+		runtime.GC()
+		runtime.ReadMemStats(&before)
+		start := time.Now()
+		summary, _, err := engine.Scan()
+		duration := time.Since(start)
+		runtime.ReadMemStats(&after)
+		if err != nil {
+			return nil, fmt.Errorf("scan of the %s corpus failed: %w", corpus.Name, err)
+		}
+
+		// The first scan warms up caches and is not measured
+		if i > 0 {
+			samples = append(samples, sample{duration: duration, allocated: after.TotalAlloc - before.TotalAlloc})
+		}
+		violationCount = summary.TotalViolations
+	}
+
+	sort.Slice(samples, func(i, j int) bool { return samples[i].duration < samples[j].duration })
+	median := samples[len(samples)/2]
+	seconds := median.duration.Seconds()
+	return &Result{
+		Corpus:         corpus.Name,
+		Files:          corpus.Files,
+		Lines:          lines,
+		Iterations:     iterations,
+		Seconds:        seconds,
+		FilesPerSecond: float64(corpus.Files) / seconds,
+		LinesPerSecond: float64(lines) / seconds,
+		AllocatedBytes: median.allocated,
+		Violations:     violationCount,
+	}, nil
+}
+
+// Compare returns the corpora this run scanned more than maxSlowdown percent
+// slower than the baseline run, measured in lines per second. Corpora
+// missing from either run are not compared.
+func Compare(baseline, current *Report, maxSlowdown float64) []Regression {
+	previous := make(map[string]Result, len(baseline.Results))
+	for _, result := range baseline.Results {
+		previous[result.Corpus] = result
+	}
+
+	var regressions []Regression
+	for _, result := range current.Results {
+		base, found := previous[result.Corpus]
+		if !found || base.LinesPerSecond <= 0 {
+			continue
+		}
+		slowdown := (base.LinesPerSecond - result.LinesPerSecond) * 100 / base.LinesPerSecond
+		if slowdown > maxSlowdown {
+			regressions = append(regressions, Regression{
+				Corpus:   result.Corpus,
+				Baseline: base.LinesPerSecond,
+				Current:  result.LinesPerSecond,
+				Slowdown: slowdown,
+			})
+		}
+	}
+	return regressions
+}
+
+// Load reads a report written by Save
+func Load(path string) (*Report, error) {
+	data, err := os.ReadFile(path)
+	if err != nil {
+		return nil, fmt.Errorf("failed to read benchmark report: %w", err)
+	}
+	var report Report
+	if err := json.Unmarshal(data, &report); err != nil {
+		return nil, fmt.Errorf("failed to parse benchmark report %s: %w", path, err)
+	}
+	if report.FormatVersion != FormatVersion {
+		return nil, fmt.Errorf("benchmark report %s has format version %d, expected %d", path, report.FormatVersion, FormatVersion)
+	}
+	return &report, nil
+}
+
+// Save writes the report as indented JSON
+func (r *Report) Save(path string) error {
+	data, err := json.MarshalIndent(r, "", "  ")
+	if err != nil {
+		return fmt.Errorf("failed to encode benchmark report: %w", err)
+	}
+	if dir := filepath.Dir(path); dir != "." {
+		if err := os.MkdirAll(dir, 0755); err != nil {
+			return fmt.Errorf("failed to create %s: %w", dir, err)
+		}
+	}
+	return os.WriteFile(path, append(data, '\n'), 0644)
+}
@@ -0,0 +1,124 @@
+package bench
+
+import (
+	"go/parser"
+	"go/token"
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/ericfisherdev/goclean/internal/scanner"
+	"github.com/ericfisherdev/goclean/internal/violations"
+)
+
+func TestGenerate(t *testing.T) {
+	dir := t.TempDir()
+	lines, err := Generate(dir, Corpus{Name: "tiny", Files: 4, Functions: 5})
+	if err != nil {
+		t.Fatalf("Generate failed: %v", err)
+	}
+	if lines == 0 {
+		t.Error("Expected generated lines")
+	}
+
+	goFiles, _ := filepath.Glob(filepath.Join(dir, "pkg0", "*.go"))
+	rustFiles, _ := filepath.Glob(filepath.Join(dir, "crate", "src", "module0", "*.rs"))
+	if len(goFiles) != 2 || len(rustFiles) != 2 {
+		t.Fatalf("Expected 2 Go and 2 Rust files, got %v and %v", goFiles, rustFiles)
+	}
+	if _, err := parser.ParseFile(token.NewFileSet(), goFiles[0], nil, 0); err != nil {
+		t.Errorf("Expected generated Go code to parse: %v", err)
+	}
+}
+
+func TestRun(t *testing.T) {
+	report, err := Run([]Corpus{{Name: "tiny", Files: 2, Functions: 4}}, 1, violations.DefaultDetectorConfig(), "test")
+	if err != nil {
+		t.Fatalf("Run failed: %v", err)
+	}
+	if report.FormatVersion != FormatVersion || report.GoCleanVersion != "test" || len(report.Results) != 1 {
+		t.Fatalf("Unexpected report %+v", report)
+	}
+	result := report.Results[0]
+	if result.Files != 2 || result.Lines == 0 || result.Seconds <= 0 || result.LinesPerSecond <= 0 {
+		t.Errorf("Unexpected result %+v", result)
+	}
+
+	if _, err := Run(Corpora, 0, violations.DefaultDetectorConfig(), "test"); err == nil {
+		t.Error("Expected an error for zero iterations")
+	}
+}
+
+func TestCompare(t *testing.T) {
+	baseline := &Report{Results: []Result{
+		{Corpus: "small", LinesPerSecond: 1000},
+		{Corpus: "medium", LinesPerSecond: 1000},
+		{Corpus: "large", LinesPerSecond: 1000},
+	}}
+	current := &Report{Results: []Result{
+		{Corpus: "small", LinesPerSecond: 950},
+		{Corpus: "medium", LinesPerSecond: 800},
+		{Corpus: "huge", LinesPerSecond: 10},
+	}}
+
+	regressions := Compare(baseline, current, 10)
+	if len(regressions) != 1 || regressions[0].Corpus != "medium" || regressions[0].Slowdown != 20 {
+		t.Errorf("Expected only medium to regress by 20%%, got %+v", regressions)
+	}
+}
+
+func TestSaveAndLoad(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "bench", "report.json")
+	report := &Report{FormatVersion: FormatVersion, Results: []Result{{Corpus: "small", LinesPerSecond: 1234.5}}}
+	if err := report.Save(path); err != nil {
+		t.Fatalf("Save failed: %v", err)
+	}
+
+	loaded, err := Load(path)
+	if err != nil {
+		t.Fatalf("Load failed: %v", err)
+	}
+	if len(loaded.Results) != 1 || loaded.Results[0] != report.Results[0] {
+		t.Errorf("Expected the results to survive a round trip, got %+v", loaded.Results)
+	}
+
+	if err := os.WriteFile(path, []byte(`{"format_version": 99}`), 0644); err != nil {
+		t.Fatal(err)
+	}
+	if _, err := Load(path); err == nil {
+		t.Error("Expected an error for an unknown format version")
+	}
+}
+
+func TestFindCorpus(t *testing.T) {
+	if corpus, err := FindCorpus("medium"); err != nil || corpus.Files != 200 {
+		t.Errorf("Expected the medium corpus, got %+v, %v", corpus, err)
+	}
+	if _, err := FindCorpus("huge"); err == nil || err.Error() != `unknown corpus "huge" (available: small, medium, large)` {
+		t.Errorf("Unexpected error %v", err)
+	}
+}
+
+// BenchmarkScan measures scans of the built-in corpora, as run by
+// go test -bench Scan ./internal/bench
+func BenchmarkScan(b *testing.B) {
+	for _, corpus := range Corpora {
+		b.Run(corpus.Name, func(b *testing.B) {
+			dir := b.TempDir()
+			lines, err := Generate(dir, corpus)
+			if err != nil {
+				b.Fatalf("Generate failed: %v", err)
+			}
+
+			b.ReportAllocs()
+			b.ResetTimer()
+			for i := 0; i < b.N; i++ {
+				engine := scanner.NewEngine([]string{dir}, nil, []string{".go", ".rs"}, false)
+				if _, _, err := engine.Scan(); err != nil {
+					b.Fatalf("Scan failed: %v", err)
+				}
+			}
+			b.ReportMetric(float64(lines)*float64(b.N)/b.Elapsed().Seconds(), "lines/s")
+		})
+	}
+}
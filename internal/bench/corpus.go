@@ -0,0 +1,104 @@
+package bench
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"strings"
+)
+
+// filesPerPackage is the number of files generated in each Go package or
+// Rust module directory
+const filesPerPackage = 10
+
+// Generate writes the files of a corpus to dir and returns their total line
+// count. The code is deterministic and mixes short and long functions,
+// nested branches, magic numbers and repeated blocks, so that every kind of
+// detector has work to do.
+func Generate(dir string, corpus Corpus) (int, error) {
+	lines := 0
+	for i := 0; i < corpus.Files; i++ {
+		var path, content string
+		pkg := i / filesPerPackage
+		if i%2 == 0 {
+			path = filepath.Join(dir, fmt.Sprintf("pkg%d", pkg), fmt.Sprintf("file%d.go", i))
+			content = goFile(pkg, i, corpus.Functions)
+		} else {
+			path = filepath.Join(dir, "crate", "src", fmt.Sprintf("module%d", pkg), fmt.Sprintf("file%d.rs", i))
+			content = rustFile(i, corpus.Functions)
+		}
+		if err := os.MkdirAll(filepath.Dir(path), 0755); err != nil {
+			return 0, fmt.Errorf("failed to create the corpus directory: %w", err)
+		}
+		if err := os.WriteFile(path, []byte(content), 0644); err != nil {
+			return 0, fmt.Errorf("failed to write corpus file: %w", err)
+		}
+		lines += strings.Count(content, "\n")
+	}
+	return lines, nil
+}
+
+// goFile returns the source of a generated Go file
+func goFile(pkg, file, functions int) string {
+	var b strings.Builder
+	fmt.Fprintf(&b, "package pkg%d\n\nimport (\n\t\"fmt\"\n\t\"strings\"\n)\n\n", pkg)
+	fmt.Fprintf(&b, "// Record%d holds the values processed by this file\n", file)
+	fmt.Fprintf(&b, "type Record%d struct {\n\tID     int\n\tName   string\n\tValues []int\n}\n", file)
+	for fn := 0; fn < functions; fn++ {
+		fmt.Fprintf(&b, "\n// process%d_%d sums the values above the limit\n", file, fn)
+		fmt.Fprintf(&b, "func process%d_%d(record *Record%d, limit int) (int, error) {\n", file, fn, file)
+		b.WriteString("\ttotal := 0\n")
+		b.WriteString("\tfor _, value := range record.Values {\n")
+		for depth := 0; depth < fn%4; depth++ {
+			fmt.Fprintf(&b, "%sif value > limit+%d {\n", tabs(depth+2), depth*7)
+		}
+		fmt.Fprintf(&b, "%stotal += value * %d\n", tabs(fn%4+2), fn+3)
+		for depth := fn%4 - 1; depth >= 0; depth-- {
+			fmt.Fprintf(&b, "%s}\n", tabs(depth+2))
+		}
+		b.WriteString("\t}\n")
+		b.WriteString("\tif total > 1000 {\n")
+		b.WriteString("\t\treturn 0, fmt.Errorf(\"total of %s too large: %d\", strings.ToUpper(record.Name), total)\n")
+		b.WriteString("\t}\n")
+		b.WriteString("\treturn total, nil\n}\n")
+	}
+	return b.String()
+}
+
+// rustFile returns the source of a generated Rust file
+func rustFile(file, functions int) string {
+	var b strings.Builder
+	b.WriteString("use std::collections::HashMap;\n\n")
+	fmt.Fprintf(&b, "/// Values processed by this module\npub struct Record%d {\n    pub id: u32,\n    pub name: String,\n    pub values: Vec<i64>,\n}\n", file)
+	for fn := 0; fn < functions; fn++ {
+		fmt.Fprintf(&b, "\n/// Sums the values of a record above the limit\n")
+		fmt.Fprintf(&b, "pub fn process_%d_%d(record: &Record%d, limit: i64) -> Result<i64, String> {\n", file, fn, file)
+		b.WriteString("    let mut counts: HashMap<i64, usize> = HashMap::new();\n")
+		b.WriteString("    let mut total = 0;\n")
+		b.WriteString("    for value in &record.values {\n")
+		for depth := 0; depth < fn%4; depth++ {
+			fmt.Fprintf(&b, "%sif *value > limit + %d {\n", spaces(depth+2), depth*7)
+		}
+		fmt.Fprintf(&b, "%stotal += value * %d;\n", spaces(fn%4+2), fn+3)
+		fmt.Fprintf(&b, "%s*counts.entry(*value).or_insert(0) += 1;\n", spaces(fn%4+2))
+		for depth := fn%4 - 1; depth >= 0; depth-- {
+			fmt.Fprintf(&b, "%s}\n", spaces(depth+2))
+		}
+		b.WriteString("    }\n")
+		b.WriteString("    if total > 1000 {\n")
+		b.WriteString("        return Err(format!(\"total of {} too large: {}\", record.name.to_uppercase(), total));\n")
+		b.WriteString("    }\n")
+		b.WriteString("    Ok(total)\n}\n")
+	}
+	return b.String()
+}
+
+// tabs returns an indentation of n tabs
+func tabs(n int) string {
+	return strings.Repeat("\t", n)
+}
+
+// spaces returns an indentation of n Rust levels of four spaces
+func spaces(n int) string {
+	return strings.Repeat("    ", n)
+}
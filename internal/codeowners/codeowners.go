@@ -0,0 +1,160 @@
+// Package codeowners reads GitHub and GitLab CODEOWNERS files to tell which
+// teams own the scanned files.
+package codeowners
+
+import (
+	"bufio"
+	"fmt"
+	"io"
+	"os"
+	"path/filepath"
+	"regexp"
+	"strings"
+)
+
+// Locations are the paths of the CODEOWNERS file relative to a repository
+// root, in the order GitHub looks for it
+var Locations = []string{".github/CODEOWNERS", "CODEOWNERS", "docs/CODEOWNERS"}
+
+// File is a parsed CODEOWNERS file
+type File struct {
+	Root  string // Directory the patterns are relative to
+	Rules []Rule
+}
+
+// Rule assigns owners to the files matching a pattern
+type Rule struct {
+	Pattern string
+	Owners  []string // Empty when the rule removes the owners of matching files
+	matcher *regexp.Regexp
+}
+
+// Find reads the CODEOWNERS file of the repository containing dir, looking
+// in dir and its parents
+func Find(dir string) (*File, error) {
+	dir, err := filepath.Abs(dir)
+	if err != nil {
+		return nil, err
+	}
+	if info, err := os.Stat(dir); err == nil && !info.IsDir() {
+		dir = filepath.Dir(dir)
+	}
+
+	for {
+		for _, location := range Locations {
+			file, err := os.Open(filepath.Join(dir, filepath.FromSlash(location)))
+			if err != nil {
+				continue
+			}
+			defer file.Close()
+			return Parse(dir, file)
+		}
+
+		parent := filepath.Dir(dir)
+		if parent == dir {
+			return nil, fmt.Errorf("no CODEOWNERS file found in %s or its parents", dir)
+		}
+		dir = parent
+	}
+}
+
+// Parse reads the rules of a CODEOWNERS file whose patterns are relative to
+// root. GitLab section headers are skipped.
+func Parse(root string, r io.Reader) (*File, error) {
+	if absolute, err := filepath.Abs(root); err == nil {
+		root = absolute
+	}
+	owners := &File{Root: root}
+
+	scanner := bufio.NewScanner(r)
+	for scanner.Scan() {
+		line := strings.TrimSpace(scanner.Text())
+		if line == "" || strings.HasPrefix(line, "#") || strings.HasPrefix(line, "[") || strings.HasPrefix(line, "^[") {
+			continue
+		}
+		if comment := strings.Index(line, " #"); comment >= 0 {
+			line = line[:comment]
+		}
+
+		fields := strings.Fields(line)
+		matcher, err := compilePattern(fields[0])
+		if err != nil {
+			return nil, fmt.Errorf("invalid CODEOWNERS pattern %q: %w", fields[0], err)
+		}
+		owners.Rules = append(owners.Rules, Rule{Pattern: fields[0], Owners: fields[1:], matcher: matcher})
+	}
+	if err := scanner.Err(); err != nil {
+		return nil, err
+	}
+	return owners, nil
+}
+
+// Owners returns the owners of a file. The last matching rule wins, as on
+// GitHub. Files outside the root or matched by no rule have no owners.
+func (f *File) Owners(path string) []string {
+	relative, err := f.relative(path)
+	if err != nil {
+		return nil
+	}
+	for i := len(f.Rules) - 1; i >= 0; i-- {
+		if f.Rules[i].matcher.MatchString(relative) {
+			return f.Rules[i].Owners
+		}
+	}
+	return nil
+}
+
+// relative returns a path relative to the root with forward slashes
+func (f *File) relative(path string) (string, error) {
+	absolute, err := filepath.Abs(path)
+	if err != nil {
+		return "", err
+	}
+	relative, err := filepath.Rel(f.Root, absolute)
+	if err != nil {
+		return "", err
+	}
+	if relative == ".." || strings.HasPrefix(relative, ".."+string(filepath.Separator)) {
+		return "", fmt.Errorf("%s is outside %s", path, f.Root)
+	}
+	return filepath.ToSlash(relative), nil
+}
+
+// compilePattern translates a gitignore-style CODEOWNERS pattern into a
+// regular expression over slash-separated paths. Patterns with a leading or
+// inner slash are anchored at the root; others match at any depth. A
+// pattern naming a directory matches every file below it.
+func compilePattern(pattern string) (*regexp.Regexp, error) {
+	directory := strings.HasSuffix(pattern, "/")
+	pattern = strings.TrimSuffix(pattern, "/")
+	anchored := strings.Contains(pattern, "/")
+	pattern = strings.TrimPrefix(pattern, "/")
+
+	var expr strings.Builder
+	expr.WriteString("^")
+	if !anchored {
+		expr.WriteString("(?:.*/)?")
+	}
+	for i := 0; i < len(pattern); i++ {
+		switch {
+		case strings.HasPrefix(pattern[i:], "**/"):
+			expr.WriteString("(?:.*/)?")
+			i += 2
+		case strings.HasPrefix(pattern[i:], "**"):
+			expr.WriteString(".*")
+			i++
+		case pattern[i] == '*':
+			expr.WriteString("[^/]*")
+		case pattern[i] == '?':
+			expr.WriteString("[^/]")
+		default:
+			expr.WriteString(regexp.QuoteMeta(pattern[i : i+1]))
+		}
+	}
+	if directory {
+		expr.WriteString("/.*$")
+	} else {
+		expr.WriteString("(?:/.*)?$")
+	}
+	return regexp.Compile(expr.String())
+}
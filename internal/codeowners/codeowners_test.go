@@ -0,0 +1,83 @@
+package codeowners
+
+import (
+	"os"
+	"path/filepath"
+	"reflect"
+	"strings"
+	"testing"
+)
+
+const sampleCodeowners = `# Default owners
+*                 @acme/platform
+
+[Frontend]
+/apps/web/        @acme/web
+*.rs              @acme/rust-guild @alice
+crates/**/ffi     @acme/ffi
+/apps/web/vendor/
+docs              @acme/docs # Documentation anywhere
+`
+
+func TestOwnersFollowLastMatchingRule(t *testing.T) {
+	root := t.TempDir()
+	owners, err := Parse(root, strings.NewReader(sampleCodeowners))
+	if err != nil {
+		t.Fatalf("Parse failed: %v", err)
+	}
+	if len(owners.Rules) != 6 {
+		t.Fatalf("Expected 6 rules, got %d", len(owners.Rules))
+	}
+
+	testCases := []struct {
+		path   string
+		owners []string
+	}{
+		{"main.go", []string{"@acme/platform"}},
+		{"apps/web/src/index.go", []string{"@acme/web"}},
+		{"apps/web/src/lib.rs", []string{"@acme/rust-guild", "@alice"}},
+		{"crates/net/ffi/bindings.go", []string{"@acme/ffi"}},
+		{"apps/web/vendor/lib.go", []string{}},
+		{"services/api/docs/guide.go", []string{"@acme/docs"}},
+		{"webapps/web/main.go", []string{"@acme/platform"}},
+	}
+	for _, tc := range testCases {
+		got := owners.Owners(filepath.Join(root, filepath.FromSlash(tc.path)))
+		if len(got) == 0 && len(tc.owners) == 0 {
+			continue
+		}
+		if !reflect.DeepEqual(got, tc.owners) {
+			t.Errorf("Owners(%s) = %v, expected %v", tc.path, got, tc.owners)
+		}
+	}
+
+	if got := owners.Owners(filepath.Join(filepath.Dir(root), "elsewhere.go")); len(got) != 0 {
+		t.Errorf("Expected no owners outside the root, got %v", got)
+	}
+}
+
+func TestFindLooksInParentsAndGitHubDirectory(t *testing.T) {
+	root := t.TempDir()
+	if err := os.MkdirAll(filepath.Join(root, ".github"), 0755); err != nil {
+		t.Fatal(err)
+	}
+	if err := os.WriteFile(filepath.Join(root, ".github", "CODEOWNERS"), []byte("/services/ @acme/backend\n"), 0644); err != nil {
+		t.Fatal(err)
+	}
+	nested := filepath.Join(root, "services", "api")
+	if err := os.MkdirAll(nested, 0755); err != nil {
+		t.Fatal(err)
+	}
+
+	owners, err := Find(nested)
+	if err != nil {
+		t.Fatalf("Find failed: %v", err)
+	}
+	if got := owners.Owners(filepath.Join(nested, "main.go")); !reflect.DeepEqual(got, []string{"@acme/backend"}) {
+		t.Errorf("Expected @acme/backend, got %v", got)
+	}
+
+	if _, err := Find(t.TempDir()); err == nil {
+		t.Error("Expected an error without a CODEOWNERS file")
+	}
+}
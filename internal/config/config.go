@@ -7,6 +7,11 @@ import (
 	"fmt"
 	"os"
 	"path/filepath"
+	"regexp"
+	"sort"
+	"strconv"
+	"strings"
+	"time"
 
 	"gopkg.in/yaml.v3"
 )
@@ -15,21 +20,34 @@ import (
 const (
 	DefaultFunctionLines        = 25
 	DefaultCyclomaticComplexity = 8
+	DefaultCognitiveComplexity  = 15
 	DefaultParameters           = 4
 	DefaultNestingDepth         = 3
 	DefaultClassLines           = 150
+	DefaultDuplicateTokens      = 50
+	DefaultFanOut               = 5
+	DefaultMaintainabilityIndex = 20
 	DefaultRefreshInterval      = 10
 )
 
 // Config represents the application configuration
 type Config struct {
-	Scan        ScanConfig    `yaml:"scan"`
-	Thresholds  Thresholds    `yaml:"thresholds"`
-	Output      OutputConfig  `yaml:"output"`
-	Export      ExportConfig  `yaml:"export"`
-	Logging     LoggingConfig `yaml:"logging"`
-	Rust        RustConfig    `yaml:"rust"`
-	Clippy      ClippyConfig  `yaml:"clippy"`
+	Profile       string              `yaml:"profile"`      // Built-in rule profile: default, strict, relaxed, security or embedded
+	Scan          ScanConfig          `yaml:"scan"`
+	Thresholds    Thresholds          `yaml:"thresholds"`
+	Output        OutputConfig        `yaml:"output"`
+	Export        ExportConfig        `yaml:"export"`
+	Logging       LoggingConfig       `yaml:"logging"`
+	Severity      SeverityConfig      `yaml:"severity"`
+	Debt          DebtConfig          `yaml:"debt"`
+	MagicNumbers  MagicNumbersConfig  `yaml:"magic_numbers"`
+	UnitLiterals  UnitLiteralsConfig  `yaml:"unit_literals"`
+	CommentedCode CommentedCodeConfig `yaml:"commented_code"`
+	Todos         TodoConfig          `yaml:"todos"`
+	Rust          RustConfig          `yaml:"rust"`
+	Clippy        ClippyConfig        `yaml:"clippy"`
+	Plugins       []PluginConfig      `yaml:"plugins"`      // External detectors speaking the goclean plugin protocol
+	ScriptRules   []ScriptRuleConfig  `yaml:"script_rules"` // Custom rules written as expressions
 }
 
 // ScanConfig contains scanning-related settings
@@ -46,21 +64,495 @@ type ScanConfig struct {
 	// Performance optimization settings
 	ConcurrentFiles  int    `yaml:"concurrent_files"`   // Maximum concurrent file processing
 	MaxFileSize      string `yaml:"max_file_size"`      // Maximum file size to process (e.g., "1MB", "500KB")
+	
+	// Baseline file of known violations to hide from reports
+	Baseline         string `yaml:"baseline"`
+	
+	// History file recording the findings of successive scans to detect flaky findings
+	History          string `yaml:"history"`
+	
+	// Report goclean:ignore directives that suppress no violation
+	ReportUnusedSuppressions bool `yaml:"report_unused_suppressions"`
+	
+	// Flag large blocks that look pasted from Stack Overflow, AI assistants or other projects
+	DetectPastedCode bool `yaml:"detect_pasted_code"`
+	
+	// Vendored and third-party code kept out of the project's own metrics
+	ThirdParty       ThirdPartyConfig `yaml:"third_party"`
+	
+	// Collapse repeated near-identical findings of a rule in one file into one violation
+	GroupRepeated    GroupRepeatedConfig `yaml:"group_repeated"`
+}
+
+// GroupRepeatedConfig collapses repeated findings, such as the magic numbers
+// of a lookup table, into one grouped violation listing every location
+type GroupRepeatedConfig struct {
+	MinOccurrences int            `yaml:"min_occurrences"` // Findings of any rule that form a group; 0 disables grouping
+	Rules          map[string]int `yaml:"rules"`           // Per-rule thresholds; 0 never groups the rule
+}
+
+// Validate checks that every threshold is 0 or at least 2
+func (g *GroupRepeatedConfig) Validate() error {
+	if g.MinOccurrences < 0 || g.MinOccurrences == 1 {
+		return fmt.Errorf("group_repeated min_occurrences must be 0 or at least 2, got %d", g.MinOccurrences)
+	}
+	rules := make([]string, 0, len(g.Rules))
+	for rule := range g.Rules {
+		rules = append(rules, rule)
+	}
+	sort.Strings(rules)
+	for _, rule := range rules {
+		if n := g.Rules[rule]; n < 0 || n == 1 {
+			return fmt.Errorf("group_repeated threshold for rule %s must be 0 or at least 2, got %d", rule, n)
+		}
+	}
+	return nil
+}
+
+// Third-party handling modes
+const (
+	ThirdPartyModeDowngrade = "downgrade" // Report third-party violations at reduced severity
+	ThirdPartyModeSeparate  = "separate"  // Count third-party violations separately from the totals
+)
+
+// ThirdPartyConfig marks vendored and third-party code
+type ThirdPartyConfig struct {
+	Paths       []string `yaml:"paths"`        // Directory patterns such as "vendor/"
+	Submodules  *bool    `yaml:"submodules"`   // Treat git submodules as third-party. Default: true
+	Mode        string   `yaml:"mode"`         // "downgrade" or "separate"
+	MaxSeverity string   `yaml:"max_severity"` // Highest severity reported in downgrade mode
+}
+
+// Validate checks the third-party mode and severity
+func (t *ThirdPartyConfig) Validate() error {
+	switch t.Mode {
+	case "", ThirdPartyModeDowngrade, ThirdPartyModeSeparate:
+	default:
+		return fmt.Errorf("unknown third_party mode %q (available: %s, %s)", t.Mode, ThirdPartyModeDowngrade, ThirdPartyModeSeparate)
+	}
+	if t.MaxSeverity != "" && !isSeverityName(t.MaxSeverity) {
+		return fmt.Errorf("unknown third_party max_severity %q", t.MaxSeverity)
+	}
+	return nil
+}
+
+// isSeverityName reports whether a name is a severity level, ignoring case.
+// "minor" and "major" are accepted for low and medium.
+func isSeverityName(name string) bool {
+	switch strings.ToLower(name) {
+	case "info", "low", "minor", "medium", "major", "high", "critical":
+		return true
+	}
+	return false
+}
+
+// PluginConfig registers an external detector plugin, an executable that
+// answers the JSON requests of the goclean plugin protocol
+type PluginConfig struct {
+	Name      string              `yaml:"name"`
+	Command   string              `yaml:"command"`
+	Args      []string            `yaml:"args"`
+	Languages []string            `yaml:"languages"` // Languages whose files are sent to the plugin; all when empty
+	Timeout   string              `yaml:"timeout"`   // Longest wait for the answer on one file, e.g. "30s". Default: 30s
+	Sandbox   PluginSandboxConfig `yaml:"sandbox"`   // Resource limits and access of the plugin process
+}
+
+// DefaultPluginMemory is the memory a sandboxed plugin may allocate unless configured
+const DefaultPluginMemory = "1GB"
+
+// PluginSandboxConfig limits what a plugin process may use and reach
+type PluginSandboxConfig struct {
+	Enabled          *bool    `yaml:"enabled"`           // Run the plugin in the sandbox. Default: true
+	CPUTime          string   `yaml:"cpu_time"`          // CPU time the plugin may use over a scan, e.g. "2m". Default: no limit
+	Memory           string   `yaml:"memory"`            // Memory the plugin may allocate, e.g. "512MB". Default: 1GB
+	AllowNetwork     bool     `yaml:"allow_network"`     // Let the plugin open network connections
+	AllowFilesystem  bool     `yaml:"allow_filesystem"`  // Let the plugin read and write any file the user can
+	ReadPaths        []string `yaml:"read_paths"`        // Files and directories the plugin may read without allow_filesystem
+	AllowUnsandboxed bool     `yaml:"allow_unsandboxed"` // Start the plugin when the system cannot enforce every restriction, with those it can
+}
+
+// Validate checks that the plugin names a command, languages goclean scans
+// and a valid timeout
+func (p *PluginConfig) Validate() error {
+	if p.Name == "" {
+		return fmt.Errorf("plugin with command %q has no name", p.Command)
+	}
+	if p.Command == "" {
+		return fmt.Errorf("plugin %s has no command", p.Name)
+	}
+	for _, language := range p.Languages {
+		switch strings.ToLower(language) {
+		case "go", "rust":
+		default:
+			return fmt.Errorf("plugin %s: unknown language %q (use go or rust)", p.Name, language)
+		}
+	}
+	if p.Timeout != "" {
+		if timeout, err := time.ParseDuration(p.Timeout); err != nil || timeout <= 0 {
+			return fmt.Errorf("plugin %s: invalid timeout %q", p.Name, p.Timeout)
+		}
+	}
+	if p.Sandbox.CPUTime != "" {
+		if cpuTime, err := time.ParseDuration(p.Sandbox.CPUTime); err != nil || cpuTime <= 0 {
+			return fmt.Errorf("plugin %s: invalid sandbox cpu_time %q", p.Name, p.Sandbox.CPUTime)
+		}
+	}
+	if p.Sandbox.Memory != "" {
+		if memory, err := parseByteSize(p.Sandbox.Memory); err != nil || memory <= 0 {
+			return fmt.Errorf("plugin %s: invalid sandbox memory %q", p.Name, p.Sandbox.Memory)
+		}
+	}
+	return nil
+}
+
+// GetTimeout returns the plugin timeout, or 0 for the default
+func (p *PluginConfig) GetTimeout() time.Duration {
+	timeout, err := time.ParseDuration(p.Timeout)
+	if err != nil {
+		return 0
+	}
+	return timeout
+}
+
+// GetEnabled returns whether the plugin runs in the sandbox (default: true)
+func (s *PluginSandboxConfig) GetEnabled() bool {
+	if s.Enabled == nil {
+		return true
+	}
+	return *s.Enabled
+}
+
+// GetCPUTime returns the CPU time limit of the plugin, or 0 for no limit
+func (s *PluginSandboxConfig) GetCPUTime() time.Duration {
+	cpuTime, err := time.ParseDuration(s.CPUTime)
+	if err != nil {
+		return 0
+	}
+	return cpuTime
+}
+
+// GetMemory returns the memory limit of the plugin in bytes
+func (s *PluginSandboxConfig) GetMemory() int64 {
+	memory := s.Memory
+	if memory == "" {
+		memory = DefaultPluginMemory
+	}
+	bytes, err := parseByteSize(memory)
+	if err != nil {
+		return 0
+	}
+	return bytes
+}
+
+// parseByteSize parses sizes like "512MB" or "1GB" into bytes
+func parseByteSize(size string) (int64, error) {
+	size = strings.ToUpper(strings.TrimSpace(size))
+	multiplier := int64(1)
+	for _, unit := range []struct {
+		suffix     string
+		multiplier int64
+	}{{"TB", 1 << 40}, {"GB", 1 << 30}, {"MB", 1 << 20}, {"KB", 1 << 10}, {"B", 1}} {
+		if number, found := strings.CutSuffix(size, unit.suffix); found {
+			size, multiplier = strings.TrimSpace(number), unit.multiplier
+			break
+		}
+	}
+	value, err := strconv.ParseFloat(size, 64)
+	if err != nil {
+		return 0, fmt.Errorf("invalid size: %s", size)
+	}
+	return int64(value * float64(multiplier)), nil
+}
+
+// Nodes a script rule can match
+const (
+	ScriptMatchFile     = "file"
+	ScriptMatchFunction = "function"
+	ScriptMatchType     = "type"
+	ScriptMatchImport   = "import"
+)
+
+// ScriptRuleConfig defines a custom rule as an expression evaluated for each
+// file, function, type or import of the scanned files
+type ScriptRuleConfig struct {
+	ID         string   `yaml:"id"`
+	Match      string   `yaml:"match"`      // file, function, type or import. Default: function
+	When       string   `yaml:"when"`       // Expression that is true for violating nodes, e.g. "lines > 40 && exported"
+	Message    string   `yaml:"message"`    // Message with {expression} placeholders. Default: the rule ID
+	Severity   string   `yaml:"severity"`   // Default: low
+	Suggestion string   `yaml:"suggestion"` // How to fix a violation
+	Languages  []string `yaml:"languages"`  // Languages the rule checks; all when empty
+}
+
+// Validate checks the fields of a script rule. The expressions are checked
+// when the rule is compiled.
+func (r *ScriptRuleConfig) Validate() error {
+	if r.ID == "" {
+		return fmt.Errorf("script rule with condition %q has no id", r.When)
+	}
+	if r.When == "" {
+		return fmt.Errorf("script rule %s has no when expression", r.ID)
+	}
+	switch r.GetMatch() {
+	case ScriptMatchFile, ScriptMatchFunction, ScriptMatchType, ScriptMatchImport:
+	default:
+		return fmt.Errorf("script rule %s: unknown match %q (use file, function, type or import)", r.ID, r.Match)
+	}
+	if r.Severity != "" && !isSeverityName(r.Severity) {
+		return fmt.Errorf("script rule %s: unknown severity %q", r.ID, r.Severity)
+	}
+	for _, language := range r.Languages {
+		switch strings.ToLower(language) {
+		case "go", "rust":
+		default:
+			return fmt.Errorf("script rule %s: unknown language %q (use go or rust)", r.ID, language)
+		}
+	}
+	return nil
+}
+
+// GetMatch returns the nodes the rule matches (default: function)
+func (r *ScriptRuleConfig) GetMatch() string {
+	if r.Match == "" {
+		return ScriptMatchFunction
+	}
+	return strings.ToLower(r.Match)
+}
+
+// SeverityConfig overrides the severity of individual rules and sets the
+// severity at which a scan fails
+type SeverityConfig struct {
+	Rules  map[string]string `yaml:"rules"`   // Rule ID to severity, e.g. todo-comment: info
+	FailOn string            `yaml:"fail_on"` // Lowest severity that fails a scan. Default: info (any violation)
+	// MinFileHealth fails a scan when a file's health score is below it (0 = disabled)
+	MinFileHealth int `yaml:"min_file_health"`
+	// DocCoverageMin fails a scan when the percentage of documented public items is below it (0 = disabled)
+	DocCoverageMin int `yaml:"doc_coverage_min"`
+}
+
+// Validate checks the severity names of the overrides and the failure level
+func (s *SeverityConfig) Validate() error {
+	for rule, severity := range s.Rules {
+		if !isSeverityName(severity) {
+			return fmt.Errorf("unknown severity %q for rule %s (use info, low, medium, high or critical)", severity, rule)
+		}
+	}
+	if s.FailOn != "" && !isSeverityName(s.FailOn) {
+		return fmt.Errorf("unknown fail_on severity %q (use info, low, medium, high or critical)", s.FailOn)
+	}
+	if s.MinFileHealth < 0 || s.MinFileHealth > 100 {
+		return fmt.Errorf("min_file_health must be between 0 and 100, got %d", s.MinFileHealth)
+	}
+	if s.DocCoverageMin < 0 || s.DocCoverageMin > 100 {
+		return fmt.Errorf("doc_coverage_min must be between 0 and 100, got %d", s.DocCoverageMin)
+	}
+	return nil
+}
+
+// DebtConfig sets the estimated time to fix the violations of rules
+type DebtConfig struct {
+	Rules map[string]int `yaml:"rules"` // Rule ID to minutes, e.g. magic-number: 5
+}
+
+// Validate checks that no estimate is negative
+func (d *DebtConfig) Validate() error {
+	rules := make([]string, 0, len(d.Rules))
+	for rule := range d.Rules {
+		rules = append(rules, rule)
+	}
+	sort.Strings(rules)
+	for _, rule := range rules {
+		if d.Rules[rule] < 0 {
+			return fmt.Errorf("debt estimate for rule %s must not be negative, got %d minutes", rule, d.Rules[rule])
+		}
+	}
+	return nil
+}
+
+// TodoConfig dates TODO, FIXME and other markers with git blame, and can
+// require them to reference the issue that tracks them
+type TodoConfig struct {
+	Blame          bool     `yaml:"blame"`             // Add the author and age of each marker to its violation
+	MaxTodoAgeDays int      `yaml:"max_todo_age_days"` // Raise markers older than this by one severity level (0 = never); implies blame
+	RequireIssue   bool     `yaml:"require_issue"`     // Report markers not followed by an issue reference
+	IssuePattern   string   `yaml:"issue_pattern"`     // Regular expression the text after a marker must start with; default TODO(#1234) or TODO(JIRA-123)
+	IssueMarkers   []string `yaml:"issue_markers"`     // Markers that need a reference; default TODO and FIXME
+}
+
+// Validate checks the maximum age, the issue pattern and the markers
+func (t *TodoConfig) Validate() error {
+	if t.MaxTodoAgeDays < 0 {
+		return fmt.Errorf("todos.max_todo_age_days must not be negative, got %d", t.MaxTodoAgeDays)
+	}
+	if _, err := regexp.Compile(t.IssuePattern); err != nil {
+		return fmt.Errorf("todos.issue_pattern: invalid regular expression %q: %w", t.IssuePattern, err)
+	}
+	for _, marker := range t.IssueMarkers {
+		if strings.TrimSpace(marker) == "" {
+			return fmt.Errorf("todos.issue_markers must not contain empty markers")
+		}
+	}
+	return nil
+}
+
+// Enabled reports whether markers are blamed
+func (t *TodoConfig) Enabled() bool {
+	return t.Blame || t.MaxTodoAgeDays > 0
+}
+
+// CommentedCodeConfig tunes the commented-code rules, which report comments
+// that parse as code
+type CommentedCodeConfig struct {
+	MinConfidence float64 `yaml:"min_confidence"` // Share of a comment's lines that must parse as code, from 0 to 1; 0 reports comments more than half code
+}
+
+// Validate checks that the minimum confidence is a share
+func (c *CommentedCodeConfig) Validate() error {
+	if c.MinConfidence < 0 || c.MinConfidence > 1 {
+		return fmt.Errorf("commented_code.min_confidence must be between 0 and 1, got %g", c.MinConfidence)
+	}
+	return nil
+}
+
+// MagicNumbersConfig sets the contexts in which the magic-number rules
+// allow numbers. Everything is off by default.
+type MagicNumbersConfig struct {
+	AllowInIndex     bool                `yaml:"allow_in_index"`      // Index and slice bounds, as in buf[12]
+	AllowInTests     bool                `yaml:"allow_in_tests"`      // Test files, and Rust files under tests/ or benches/
+	AllowPowersOfTwo bool                `yaml:"allow_powers_of_two"` // 16, 256, 65536 and so on
+	AllowEpsilon     bool                `yaml:"allow_epsilon"`       // Floats up to 0.001 in comparisons, as in diff < 1e-9
+	Allowed          []string            `yaml:"allowed"`             // Numbers allowed in every file
+	Files            map[string][]string `yaml:"files"`               // Glob to numbers allowed in matching files
+}
+
+// Validate checks that the allowed values are numbers and the globs are valid
+func (m *MagicNumbersConfig) Validate() error {
+	for _, value := range m.Allowed {
+		if !isNumberLiteral(value) {
+			return fmt.Errorf("magic_numbers.allowed: %q is not a number", value)
+		}
+	}
+	patterns := make([]string, 0, len(m.Files))
+	for pattern := range m.Files {
+		patterns = append(patterns, pattern)
+	}
+	sort.Strings(patterns)
+	for _, pattern := range patterns {
+		if _, err := filepath.Match(pattern, ""); err != nil {
+			return fmt.Errorf("magic_numbers.files: invalid glob %q: %w", pattern, err)
+		}
+		for _, value := range m.Files[pattern] {
+			if !isNumberLiteral(value) {
+				return fmt.Errorf("magic_numbers.files %s: %q is not a number", pattern, value)
+			}
+		}
+	}
+	return nil
+}
+
+// UnitLiteralsConfig enables the unit-literal rules, which report bare
+// numbers that look like durations, sizes or ports. Off by default.
+type UnitLiteralsConfig struct {
+	Enabled  bool     `yaml:"enabled"`
+	MinValue int      `yaml:"min_value"` // Smallest number reported; 0 uses 60
+	Kinds    []string `yaml:"kinds"`     // Any of duration, size and port; empty reports all three
+}
+
+// Validate checks the minimum and the kinds
+func (u *UnitLiteralsConfig) Validate() error {
+	if u.MinValue < 0 {
+		return fmt.Errorf("unit_literals.min_value must not be negative, got %d", u.MinValue)
+	}
+	for _, kind := range u.Kinds {
+		if kind != "duration" && kind != "size" && kind != "port" {
+			return fmt.Errorf("unit_literals.kinds: unknown kind %q (available: duration, size, port)", kind)
+		}
+	}
+	return nil
+}
+
+// isNumberLiteral reports whether value is an integer, in any base, or a float
+func isNumberLiteral(value string) bool {
+	text := strings.ReplaceAll(strings.TrimSpace(value), "_", "")
+	if _, err := strconv.ParseInt(text, 0, 64); err == nil {
+		return true
+	}
+	_, err := strconv.ParseFloat(text, 64)
+	return err == nil
+}
+
+// GetSubmodules safely returns the Submodules value with default fallback
+func (t *ThirdPartyConfig) GetSubmodules() bool {
+	if t.Submodules == nil {
+		return true // default value
+	}
+	return *t.Submodules
 }
 
 // Thresholds contains clean code thresholds
 type Thresholds struct {
 	FunctionLines        int `yaml:"function_lines"`
 	CyclomaticComplexity int `yaml:"cyclomatic_complexity"`
+	CognitiveComplexity  int `yaml:"cognitive_complexity"`
 	Parameters           int `yaml:"parameters"`
 	NestingDepth         int `yaml:"nesting_depth"`
 	ClassLines           int `yaml:"class_lines"`
+	DuplicateTokens      int `yaml:"duplicate_tokens"`
+	FanOut               int `yaml:"fan_out"`               // Distinct modules one function may call
+	MaintainabilityIndex int `yaml:"maintainability_index"` // Lowest maintainability index (0-100) a function may have
+	HalsteadVolume       int `yaml:"halstead_volume"`       // Highest Halstead volume of a function; 0 disables the check
+	HalsteadDifficulty   int `yaml:"halstead_difficulty"`   // Highest Halstead difficulty of a function; 0 disables the check
+	HalsteadEffort       int `yaml:"halstead_effort"`       // Highest Halstead effort of a function; 0 disables the check
+	MagicStringRepeats   int `yaml:"magic_string_repeats"`  // Uses of a string literal allowed in one file; 0 disables the check
+	PrimitiveRepeats     int `yaml:"primitive_repeats"`     // Functions and structs a primitive parameter or field may recur in under one name; 0 disables the check
+	GodStructFields      int `yaml:"god_struct_fields"`     // Fields of a struct with more than god_struct_methods methods; 0 disables the check
+	GodStructMethods     int `yaml:"god_struct_methods"`    // Methods of a struct with more than god_struct_fields fields; 0 disables the check
+
+	// Clone minimums per granularity, in tokens; 0 uses duplicate_tokens
+	DuplicateTokensIntraFile   int `yaml:"duplicate_tokens_intra_file"`   // Copies within one file
+	DuplicateTokensIntraModule int `yaml:"duplicate_tokens_intra_module"` // Copies in several files of one module
+	DuplicateTokensCrossModule int `yaml:"duplicate_tokens_cross_module"` // Copies in several modules
+
+	// Complexity scales function length, complexity, parameter and nesting thresholds together
+	Complexity ThresholdGroup `yaml:"complexity,omitempty"`
 }
 
 // OutputConfig contains output-related settings
 type OutputConfig struct {
 	HTML     HTMLConfig     `yaml:"html"`
 	Markdown MarkdownConfig `yaml:"markdown"`
+	Links    LinksConfig    `yaml:"links"`
+	SplitBy  string         `yaml:"split_by"` // Write one report per "owner", "root" or "crate"; one report when empty
+}
+
+// Report splits of monorepo scans
+const (
+	SplitByOwner = "owner" // One report per team listed in CODEOWNERS
+	SplitByRoot  = "root"  // One report per top-level directory of the scan path
+	SplitByCrate = "crate" // One report per Cargo package or Go module
+)
+
+// ValidateSplitBy checks how reports are split
+func ValidateSplitBy(splitBy string) error {
+	switch splitBy {
+	case "", SplitByOwner, SplitByRoot, SplitByCrate:
+		return nil
+	default:
+		return fmt.Errorf("unknown report split %q (available: %s, %s, %s)", splitBy, SplitByOwner, SplitByRoot, SplitByCrate)
+	}
+}
+
+// LinksConfig contains URL templates used to link violations to rule
+// documentation and to the offending line on a source forge (GitHub/GitLab).
+//
+// Templates support the placeholders {rule}, {type}, {repo}, {commit},
+// {path}, {line} and {end_line}.
+type LinksConfig struct {
+	RuleDocsTemplate string `yaml:"rule_docs_template"` // e.g. "https://docs.example.com/rules/{rule}"
+	SourceTemplate   string `yaml:"source_template"`    // Derived from repository_url when empty
+	RepositoryURL    string `yaml:"repository_url"`     // e.g. "https://github.com/org/project"
+	CommitSHA        string `yaml:"commit_sha"`         // Detected with git when empty
+	RepositoryRoot   string `yaml:"repository_root"`    // Detected with git when empty
 }
 
 // HTMLConfig contains HTML report settings
@@ -69,6 +561,7 @@ type HTMLConfig struct {
 	AutoRefresh     bool   `yaml:"auto_refresh"`
 	RefreshInterval int    `yaml:"refresh_interval"`
 	Theme           string `yaml:"theme"`
+	SelfContained   bool   `yaml:"self_contained"` // Interactive report with no external assets
 }
 
 // MarkdownConfig contains markdown report settings
@@ -93,8 +586,13 @@ type LoggingConfig struct {
 
 // ExportConfig contains export-related settings
 type ExportConfig struct {
-	JSON JSONConfig `yaml:"json"`
-	CSV  CSVConfig  `yaml:"csv"`
+	JSON            JSONConfig            `yaml:"json"`
+	CSV             CSVConfig             `yaml:"csv"`
+	UnsafeInventory UnsafeInventoryConfig `yaml:"unsafe_inventory"`
+	PanicCensus     PanicCensusConfig     `yaml:"panic_census"`
+	JUnit           JUnitConfig           `yaml:"junit"`
+	CodeClimate     CodeClimateConfig     `yaml:"codeclimate"`
+	GitHubSummary   GitHubSummaryConfig   `yaml:"github_summary"`
 }
 
 // JSONConfig contains JSON export settings
@@ -104,6 +602,59 @@ type JSONConfig struct {
 	PrettyPrint bool   `yaml:"pretty_print"`
 }
 
+// UnsafeInventoryConfig contains settings for the Rust unsafe-code inventory export
+type UnsafeInventoryConfig struct {
+	Enabled     bool   `yaml:"enabled"`
+	Path        string `yaml:"path"`
+	PrettyPrint bool   `yaml:"pretty_print"`
+}
+
+// PanicCensusConfig contains settings for the Rust panic-site census export
+type PanicCensusConfig struct {
+	Enabled         bool   `yaml:"enabled"`
+	Path            string `yaml:"path"`
+	PrettyPrint     bool   `yaml:"pretty_print"`
+	MaxPanicSurface int    `yaml:"max_panic_surface"` // Fail the scan above this many production panic sites; 0 disables the gate
+}
+
+// JUnit test suite groupings
+const (
+	JUnitGroupByRule = "rule" // One test suite per rule
+	JUnitGroupByFile = "file" // One test suite per scanned file
+)
+
+// JUnitConfig contains settings for the JUnit XML export read by CI servers
+type JUnitConfig struct {
+	Enabled bool   `yaml:"enabled"`
+	Path    string `yaml:"path"`
+	GroupBy string `yaml:"group_by"` // "rule" or "file"
+}
+
+// Validate checks the test suite grouping
+func (j *JUnitConfig) Validate() error {
+	switch j.GroupBy {
+	case "", JUnitGroupByRule, JUnitGroupByFile:
+		return nil
+	default:
+		return fmt.Errorf("unknown junit group_by %q (available: %s, %s)", j.GroupBy, JUnitGroupByRule, JUnitGroupByFile)
+	}
+}
+
+// CodeClimateConfig contains settings for the Code Climate issue export,
+// which GitLab reads as a code quality report
+type CodeClimateConfig struct {
+	Enabled bool   `yaml:"enabled"`
+	Path    string `yaml:"path"`
+}
+
+// GitHubSummaryConfig contains settings for the Markdown summary shown on
+// the job summary page of GitHub Actions
+type GitHubSummaryConfig struct {
+	Enabled       bool   `yaml:"enabled"`
+	Path          string `yaml:"path"`           // $GITHUB_STEP_SUMMARY when empty and set
+	TopViolations int    `yaml:"top_violations"` // New violations listed, most severe first
+}
+
 // CSVConfig contains CSV export settings
 type CSVConfig struct {
 	Enabled bool   `yaml:"enabled"`
@@ -121,6 +672,9 @@ type ClippyConfig struct {
 
 // RustConfig contains Rust-specific analysis settings
 type RustConfig struct {
+	// Edition used to parse Rust files: "auto" (from Cargo.toml), 2015, 2018, 2021 or 2024
+	Edition string `yaml:"edition"`
+	
 	// Ownership and borrowing analysis
 	EnableOwnershipAnalysis *bool `yaml:"enable_ownership_analysis"`
 	MaxLifetimeParams       int   `yaml:"max_lifetime_params"`
@@ -131,6 +685,12 @@ type RustConfig struct {
 	AllowUnwrap             *bool `yaml:"allow_unwrap"`
 	AllowExpect             *bool `yaml:"allow_expect"`
 	EnforceResultPropagation *bool `yaml:"enforce_result_propagation"`
+	MaxUnwrapsPerFunction   int   `yaml:"max_unwraps_per_function"` // unwrap and expect calls outside tests; 0 disables
+	MaxUnwrapsPerFile       int   `yaml:"max_unwraps_per_file"`     // unwrap and expect calls outside tests; 0 disables
+	LibraryPanicMacros      []string `yaml:"library_panic_macros"`  // Macros reported in pub functions of library code; empty disables
+	AllowPanicsInBinaries   *bool    `yaml:"allow_panics_in_binaries"`
+	AllowPanicsInTests      *bool    `yaml:"allow_panics_in_tests"`
+	AllowStringErrorsInBinaries *bool `yaml:"allow_string_errors_in_binaries"` // Result<_, String> in binaries and examples
 	
 	// Pattern matching analysis
 	EnablePatternMatchCheck  *bool `yaml:"enable_pattern_match_check"`
@@ -140,25 +700,59 @@ type RustConfig struct {
 	// Trait and impl analysis
 	MaxTraitBounds          int   `yaml:"max_trait_bounds"`
 	MaxImplMethods          int   `yaml:"max_impl_methods"`
+	MaxImplLines            int   `yaml:"max_impl_lines"` // 0 uses thresholds.class_lines
 	DetectOrphanInstances   *bool `yaml:"detect_orphan_instances"`
 	
 	// Unsafe code analysis
 	AllowUnsafe             *bool `yaml:"allow_unsafe"`
 	RequireUnsafeComments   *bool `yaml:"require_unsafe_comments"`
+	SafetyCommentMarker     string `yaml:"safety_comment_marker"` // Text a comment above unsafe code must contain
 	DetectTransmuteUsage    *bool `yaml:"detect_transmute_usage"`
+	DetectPointerArithmetic *bool `yaml:"detect_pointer_arithmetic"` // .add() and .offset() in unsafe blocks without a visible bounds check
 	
 	// Performance analysis
 	DetectInefficientString *bool `yaml:"detect_inefficient_string"`
 	DetectBoxedPrimitives   *bool `yaml:"detect_boxed_primitives"`
 	DetectBlockingInAsync   *bool `yaml:"detect_blocking_in_async"`
+	DetectSequentialAwaits  *bool `yaml:"detect_sequential_awaits"` // .await inside for and while loops
+	BlockingFunctions       []string `yaml:"blocking_functions"` // Known-blocking paths such as mycrate::sync_get, or methods such as .wait, besides the built-in ones
+	DetectHeapAllocations   *bool `yaml:"detect_heap_allocations"`
+	
+	// Embedded and no_std code
+	AllowMutableStatics     *bool `yaml:"allow_mutable_statics"`
+	DowngradeMutableStatics *bool `yaml:"downgrade_mutable_statics"` // Report static mut at low severity, for FFI-heavy crates
+	SkipInternalDocs        *bool `yaml:"skip_internal_docs"` // Only require docs on items visible outside the crate
+	
+	// Documentation sections of pub functions
+	RequireSafetySection    *bool `yaml:"require_safety_section"` // # Safety on unsafe fns
+	RequireErrorsSection    *bool `yaml:"require_errors_section"` // # Errors on fns returning a Result
+	RequirePanicsSection    *bool `yaml:"require_panics_section"` // # Panics on fns that can panic
+	
+	// Doc examples of pub functions and types
+	RequireDocExamples      *bool    `yaml:"require_doc_examples"` // A ``` block in the doc comments
+	DocExamplePaths         []string `yaml:"doc_example_paths"`    // Globs of the files it applies to; empty for all
+	
+	// Type-accurate analysis from rustc diagnostics; requires a compiling Cargo project
+	CargoCheck              *bool `yaml:"cargo_check"`
+	
+	// Optional rule packs, such as "concurrency" for async and server code
+	RulePacks               []string `yaml:"rule_packs"`
+	MaxLocalImports         int      `yaml:"max_local_imports"`      // Use declarations in a function body (imports pack)
+	MaxReexportDepth        int      `yaml:"max_reexport_depth"`     // Chained pub use declarations (imports pack)
+	UnboundedChannelAPIs    []string `yaml:"unbounded_channel_apis"` // Constructors of unbounded channels besides the built-in ones (concurrency pack)
 	
 	// Macro analysis
 	MaxMacroComplexity      int   `yaml:"max_macro_complexity"`
 	AllowRecursiveMacros    *bool `yaml:"allow_recursive_macros"`
+	MaxMacroArms            int   `yaml:"max_macro_arms"`    // Rule arms of a macro_rules! definition
+	MaxMacroNesting         int   `yaml:"max_macro_nesting"` // Token-tree nesting depth inside a macro arm
+	MaxMacroLines           int   `yaml:"max_macro_lines"`   // Lines of a macro_rules! definition
 	
 	// Module structure
 	MaxModuleDepth          int  `yaml:"max_module_depth"`
 	MaxFileLines            int  `yaml:"max_file_lines"`
+	MaxTupleArity           int  `yaml:"max_tuple_arity"` // Elements of a tuple in a return or field type
+	DetectUnusedPublicAPI   *bool `yaml:"detect_unused_public_api"` // pub items nothing in the workspace uses
 	
 	// Naming conventions (Rust-specific)
 	EnforceSnakeCase        *bool `yaml:"enforce_snake_case"`
@@ -171,6 +765,11 @@ type RustConfig struct {
 	MaxTraitMethods         int  `yaml:"max_trait_methods"`
 	MaxAssociatedTypes      int  `yaml:"max_associated_types"`
 	MaxComplexTraitParams   int  `yaml:"max_complex_trait_params"`
+	
+	// Enum analysis
+	MaxEnumVariants         int      `yaml:"max_enum_variants"`
+	MaxErrorVariants        int      `yaml:"max_error_variants"`
+	SkipEnumAttributes      []string `yaml:"skip_enum_attributes"` // Enums with one of these attributes or derives are not counted
 }
 
 // getBoolDefault returns the value pointed to by p, or d if p is nil
@@ -206,6 +805,18 @@ func (r *RustConfig) GetEnforceResultPropagation() bool {
 	return getBoolDefault(r.EnforceResultPropagation, true)
 }
 
+func (r *RustConfig) GetAllowPanicsInBinaries() bool {
+	return getBoolDefault(r.AllowPanicsInBinaries, true)
+}
+
+func (r *RustConfig) GetAllowPanicsInTests() bool {
+	return getBoolDefault(r.AllowPanicsInTests, true)
+}
+
+func (r *RustConfig) GetAllowStringErrorsInBinaries() bool {
+	return getBoolDefault(r.AllowStringErrorsInBinaries, true)
+}
+
 func (r *RustConfig) GetEnablePatternMatchCheck() bool {
 	return getBoolDefault(r.EnablePatternMatchCheck, true)
 }
@@ -230,6 +841,10 @@ func (r *RustConfig) GetDetectTransmuteUsage() bool {
 	return getBoolDefault(r.DetectTransmuteUsage, true)
 }
 
+func (r *RustConfig) GetDetectPointerArithmetic() bool {
+	return getBoolDefault(r.DetectPointerArithmetic, true)
+}
+
 func (r *RustConfig) GetDetectInefficientString() bool {
 	return getBoolDefault(r.DetectInefficientString, true)
 }
@@ -242,6 +857,60 @@ func (r *RustConfig) GetDetectBlockingInAsync() bool {
 	return getBoolDefault(r.DetectBlockingInAsync, true)
 }
 
+func (r *RustConfig) GetDetectSequentialAwaits() bool {
+	return getBoolDefault(r.DetectSequentialAwaits, true)
+}
+
+func (r *RustConfig) GetDetectHeapAllocations() bool {
+	return getBoolDefault(r.DetectHeapAllocations, false)
+}
+
+func (r *RustConfig) GetDetectUnusedPublicAPI() bool {
+	return getBoolDefault(r.DetectUnusedPublicAPI, false)
+}
+
+func (r *RustConfig) GetAllowMutableStatics() bool {
+	return getBoolDefault(r.AllowMutableStatics, false)
+}
+
+func (r *RustConfig) GetDowngradeMutableStatics() bool {
+	return getBoolDefault(r.DowngradeMutableStatics, false)
+}
+
+func (r *RustConfig) GetSkipInternalDocs() bool {
+	return getBoolDefault(r.SkipInternalDocs, false)
+}
+
+func (r *RustConfig) GetRequireSafetySection() bool {
+	return getBoolDefault(r.RequireSafetySection, true)
+}
+
+func (r *RustConfig) GetRequireErrorsSection() bool {
+	return getBoolDefault(r.RequireErrorsSection, false)
+}
+
+func (r *RustConfig) GetRequirePanicsSection() bool {
+	return getBoolDefault(r.RequirePanicsSection, false)
+}
+
+func (r *RustConfig) GetRequireDocExamples() bool {
+	return getBoolDefault(r.RequireDocExamples, false)
+}
+
+func (r *RustConfig) GetCargoCheck() bool {
+	return getBoolDefault(r.CargoCheck, false)
+}
+
+// HasRulePack reports whether the given rule pack is enabled
+func (r *RustConfig) HasRulePack(pack string) bool {
+	for _, enabled := range r.RulePacks {
+		if enabled == pack {
+			return true
+		}
+	}
+	return false
+}
+
 func (r *RustConfig) GetAllowRecursiveMacros() bool {
 	return getBoolDefault(r.AllowRecursiveMacros, false)
 }
@@ -260,13 +929,23 @@ func (r *RustConfig) GetEnforceScreamingSnake() bool {
 
 // Load loads configuration from a file
 func Load(configPath string) (*Config, error) {
+	return LoadWithProfile(configPath, "")
+}
+
+// LoadWithProfile loads configuration like Load. A non-empty profile
+// replaces the profile selected by the configuration file.
+func LoadWithProfile(configPath, profile string) (*Config, error) {
+	if err := ValidateProfile(profile); err != nil {
+		return nil, err
+	}
+
 	// If no config file specified, try to find one
 	if configPath == "" {
 		var err error
 		configPath, err = findConfigFile()
 		if err != nil {
-			// If no config file found, return default config
-			return GetDefaultConfig(), nil
+			// If no config file found, return the defaults of the profile
+			return profileDefaults(profile), nil
 		}
 	}
 
@@ -286,6 +965,12 @@ func Load(configPath string) (*Config, error) {
 	if err := yaml.Unmarshal(data, &config); err != nil {
 		return nil, fmt.Errorf("failed to parse config file: %w", err)
 	}
+	if profile != "" {
+		config.Profile = profile
+	}
+	if err := ValidateProfile(config.Profile); err != nil {
+		return nil, err
+	}
 
 	// Merge with defaults for any missing values
 	mergeWithDefaults(&config)
@@ -350,13 +1035,23 @@ func GetDefaultConfig() *Config {
 			CustomTestPatterns: []string{},
 			ConcurrentFiles: 0, // Use default (number of CPU cores)
 			MaxFileSize:     "", // No limit by default
+			ThirdParty: ThirdPartyConfig{
+				Paths:       []string{"vendor/", "third_party/"},
+				Submodules:  boolPtr(true),
+				Mode:        ThirdPartyModeDowngrade,
+				MaxSeverity: "low",
+			},
 		},
 		Thresholds: Thresholds{
 			FunctionLines:        DefaultFunctionLines,
 			CyclomaticComplexity: DefaultCyclomaticComplexity,
+			CognitiveComplexity:  DefaultCognitiveComplexity,
 			Parameters:           DefaultParameters,
 			NestingDepth:         DefaultNestingDepth,
 			ClassLines:           DefaultClassLines,
+			DuplicateTokens:      DefaultDuplicateTokens,
+			FanOut:               DefaultFanOut,
+			MaintainabilityIndex: DefaultMaintainabilityIndex,
 		},
 		Output: OutputConfig{
 			HTML: HTMLConfig{
@@ -381,12 +1076,38 @@ func GetDefaultConfig() *Config {
 				Enabled: false,
 				Path:    "./reports/violations.csv",
 			},
+			UnsafeInventory: UnsafeInventoryConfig{
+				Enabled:     false,
+				Path:        "./reports/unsafe-inventory.json",
+				PrettyPrint: true,
+			},
+			PanicCensus: PanicCensusConfig{
+				Enabled:     false,
+				Path:        "./reports/panic-census.json",
+				PrettyPrint: true,
+			},
+			JUnit: JUnitConfig{
+				Enabled: false,
+				Path:    "./reports/junit.xml",
+				GroupBy: JUnitGroupByRule,
+			},
+			CodeClimate: CodeClimateConfig{
+				Enabled: false,
+				Path:    "./reports/gl-code-quality-report.json",
+			},
+			GitHubSummary: GitHubSummaryConfig{
+				Enabled:       false,
+				TopViolations: 10,
+			},
 		},
 		Logging: LoggingConfig{
 			Level:  "info",
 			Format: "structured",
 		},
 		Rust: GetDefaultRustConfig(),
+		Severity: SeverityConfig{
+			FailOn: "info",
+		},
 		Clippy: GetDefaultClippyConfig(),
 	}
 }
@@ -394,6 +1115,8 @@ func GetDefaultConfig() *Config {
 // GetDefaultRustConfig returns the default Rust configuration
 func GetDefaultRustConfig() RustConfig {
 	return RustConfig{
+		Edition: "auto",
+		
 		// Ownership and borrowing
 		EnableOwnershipAnalysis: boolPtr(true),
 		MaxLifetimeParams:       3,
@@ -404,6 +1127,10 @@ func GetDefaultRustConfig() RustConfig {
 		AllowUnwrap:             boolPtr(false),
 		AllowExpect:             boolPtr(false),
 		EnforceResultPropagation: boolPtr(true),
+		LibraryPanicMacros:      []string{"panic", "todo", "unimplemented", "unreachable"},
+		AllowPanicsInBinaries:   boolPtr(true),
+		AllowPanicsInTests:      boolPtr(true),
+		AllowStringErrorsInBinaries: boolPtr(true),
 		
 		// Pattern matching
 		EnablePatternMatchCheck:  boolPtr(true),
@@ -418,20 +1145,47 @@ func GetDefaultRustConfig() RustConfig {
 		// Unsafe code
 		AllowUnsafe:             boolPtr(true),  // Allow but track
 		RequireUnsafeComments:   boolPtr(true),
+		SafetyCommentMarker:     "SAFETY:",
 		DetectTransmuteUsage:    boolPtr(true),
+		DetectPointerArithmetic: boolPtr(true),
 		
 		// Performance
 		DetectInefficientString: boolPtr(true),
 		DetectBoxedPrimitives:   boolPtr(true),
 		DetectBlockingInAsync:   boolPtr(true),
+		DetectSequentialAwaits:  boolPtr(true),
+		DetectHeapAllocations:   boolPtr(false),
+		
+		// Embedded and no_std code
+		AllowMutableStatics:     boolPtr(false),
+		DowngradeMutableStatics: boolPtr(false),
+		SkipInternalDocs:        boolPtr(false),
+		
+		// Documentation sections
+		RequireSafetySection:    boolPtr(true),
+		RequireErrorsSection:    boolPtr(false),
+		RequirePanicsSection:    boolPtr(false),
+		RequireDocExamples:      boolPtr(false),
+		
+		// Type-accurate analysis
+		CargoCheck:              boolPtr(false),
+		
+		// Rule packs
+		MaxLocalImports:         3,
+		MaxReexportDepth:        2,
 		
 		// Macro analysis
 		MaxMacroComplexity:      10,
 		AllowRecursiveMacros:    boolPtr(false),
+		MaxMacroArms:            8,
+		MaxMacroNesting:         4,
+		MaxMacroLines:           60,
 		
 		// Module structure
 		MaxModuleDepth:          5,
 		MaxFileLines:            500,
+		MaxTupleArity:           2,
+		DetectUnusedPublicAPI:   boolPtr(false),
 		
 		// Naming conventions
 		EnforceSnakeCase:        boolPtr(true),
@@ -444,6 +1198,11 @@ func GetDefaultRustConfig() RustConfig {
 		MaxTraitMethods:         8,
 		MaxAssociatedTypes:      4,
 		MaxComplexTraitParams:   2,
+		
+		// Enum analysis
+		MaxEnumVariants:         15,
+		MaxErrorVariants:        10,
+		SkipEnumAttributes:      []string{"automatically_derived", "EnumIter", "EnumString", "FromPrimitive", "Enumeration"},
 	}
 }
 
@@ -496,9 +1255,10 @@ func findConfigFile() (string, error) {
 	return "", fmt.Errorf("no config file found")
 }
 
-// mergeWithDefaults fills in missing configuration values with defaults
+// mergeWithDefaults fills in missing configuration values with the defaults
+// of the selected profile
 func mergeWithDefaults(config *Config) {
-	defaults := GetDefaultConfig()
+	defaults := profileDefaults(config.Profile)
 
 	// Merge scan config
 	if len(config.Scan.Paths) == 0 {
@@ -526,6 +1286,9 @@ func mergeWithDefaults(config *Config) {
 	if config.Thresholds.CyclomaticComplexity == 0 {
 		config.Thresholds.CyclomaticComplexity = defaults.Thresholds.CyclomaticComplexity
 	}
+	if config.Thresholds.CognitiveComplexity == 0 {
+		config.Thresholds.CognitiveComplexity = defaults.Thresholds.CognitiveComplexity
+	}
 	if config.Thresholds.Parameters == 0 {
 		config.Thresholds.Parameters = defaults.Thresholds.Parameters
 	}
@@ -535,6 +1298,15 @@ func mergeWithDefaults(config *Config) {
 	if config.Thresholds.ClassLines == 0 {
 		config.Thresholds.ClassLines = defaults.Thresholds.ClassLines
 	}
+	if config.Thresholds.DuplicateTokens == 0 {
+		config.Thresholds.DuplicateTokens = defaults.Thresholds.DuplicateTokens
+	}
+	if config.Thresholds.FanOut == 0 {
+		config.Thresholds.FanOut = defaults.Thresholds.FanOut
+	}
+	if config.Thresholds.MaintainabilityIndex == 0 {
+		config.Thresholds.MaintainabilityIndex = defaults.Thresholds.MaintainabilityIndex
+	}
 
 	// Merge output config
 	if config.Output.HTML.Path == "" {
@@ -550,6 +1322,11 @@ func mergeWithDefaults(config *Config) {
 		config.Output.Markdown.Path = defaults.Output.Markdown.Path
 	}
 
+	// Merge severity config
+	if config.Severity.FailOn == "" {
+		config.Severity.FailOn = defaults.Severity.FailOn
+	}
+
 	// Merge scan config additional fields
 	if config.Scan.ConcurrentFiles == 0 {
 		config.Scan.ConcurrentFiles = defaults.Scan.ConcurrentFiles
@@ -557,6 +1334,15 @@ func mergeWithDefaults(config *Config) {
 	if config.Scan.MaxFileSize == "" {
 		config.Scan.MaxFileSize = defaults.Scan.MaxFileSize
 	}
+	if config.Scan.ThirdParty.Paths == nil {
+		config.Scan.ThirdParty.Paths = defaults.Scan.ThirdParty.Paths
+	}
+	if config.Scan.ThirdParty.Mode == "" {
+		config.Scan.ThirdParty.Mode = defaults.Scan.ThirdParty.Mode
+	}
+	if config.Scan.ThirdParty.MaxSeverity == "" {
+		config.Scan.ThirdParty.MaxSeverity = defaults.Scan.ThirdParty.MaxSeverity
+	}
 
 	// Merge export config
 	if config.Export.JSON.Path == "" {
@@ -565,6 +1351,24 @@ func mergeWithDefaults(config *Config) {
 	if config.Export.CSV.Path == "" {
 		config.Export.CSV.Path = defaults.Export.CSV.Path
 	}
+	if config.Export.UnsafeInventory.Path == "" {
+		config.Export.UnsafeInventory.Path = defaults.Export.UnsafeInventory.Path
+	}
+	if config.Export.PanicCensus.Path == "" {
+		config.Export.PanicCensus.Path = defaults.Export.PanicCensus.Path
+	}
+	if config.Export.JUnit.Path == "" {
+		config.Export.JUnit.Path = defaults.Export.JUnit.Path
+	}
+	if config.Export.JUnit.GroupBy == "" {
+		config.Export.JUnit.GroupBy = defaults.Export.JUnit.GroupBy
+	}
+	if config.Export.CodeClimate.Path == "" {
+		config.Export.CodeClimate.Path = defaults.Export.CodeClimate.Path
+	}
+	if config.Export.GitHubSummary.TopViolations == 0 {
+		config.Export.GitHubSummary.TopViolations = defaults.Export.GitHubSummary.TopViolations
+	}
 
 	// Merge clippy config
 	mergeClippyConfig(&config.Clippy, &defaults.Clippy)
@@ -585,6 +1389,10 @@ func mergeWithDefaults(config *Config) {
 func mergeRustConfig(config *RustConfig, defaults *RustConfig) {
 	// Set default values only when fields are nil (not provided in YAML)
 	
+	if config.Edition == "" {
+		config.Edition = defaults.Edition
+	}
+	
 	// Bool fields - only set defaults when nil
 	if config.EnableOwnershipAnalysis == nil {
 		config.EnableOwnershipAnalysis = defaults.EnableOwnershipAnalysis
@@ -604,6 +1412,18 @@ func mergeRustConfig(config *RustConfig, defaults *RustConfig) {
 	if config.EnforceResultPropagation == nil {
 		config.EnforceResultPropagation = defaults.EnforceResultPropagation
 	}
+	if config.LibraryPanicMacros == nil {
+		config.LibraryPanicMacros = defaults.LibraryPanicMacros
+	}
+	if config.AllowPanicsInBinaries == nil {
+		config.AllowPanicsInBinaries = defaults.AllowPanicsInBinaries
+	}
+	if config.AllowPanicsInTests == nil {
+		config.AllowPanicsInTests = defaults.AllowPanicsInTests
+	}
+	if config.AllowStringErrorsInBinaries == nil {
+		config.AllowStringErrorsInBinaries = defaults.AllowStringErrorsInBinaries
+	}
 	if config.EnablePatternMatchCheck == nil {
 		config.EnablePatternMatchCheck = defaults.EnablePatternMatchCheck
 	}
@@ -619,9 +1439,15 @@ func mergeRustConfig(config *RustConfig, defaults *RustConfig) {
 	if config.RequireUnsafeComments == nil {
 		config.RequireUnsafeComments = defaults.RequireUnsafeComments
 	}
+	if config.SafetyCommentMarker == "" {
+		config.SafetyCommentMarker = defaults.SafetyCommentMarker
+	}
 	if config.DetectTransmuteUsage == nil {
 		config.DetectTransmuteUsage = defaults.DetectTransmuteUsage
 	}
+	if config.DetectPointerArithmetic == nil {
+		config.DetectPointerArithmetic = defaults.DetectPointerArithmetic
+	}
 	if config.DetectInefficientString == nil {
 		config.DetectInefficientString = defaults.DetectInefficientString
 	}
@@ -631,6 +1457,39 @@ func mergeRustConfig(config *RustConfig, defaults *RustConfig) {
 	if config.DetectBlockingInAsync == nil {
 		config.DetectBlockingInAsync = defaults.DetectBlockingInAsync
 	}
+	if config.DetectSequentialAwaits == nil {
+		config.DetectSequentialAwaits = defaults.DetectSequentialAwaits
+	}
+	if config.DetectHeapAllocations == nil {
+		config.DetectHeapAllocations = defaults.DetectHeapAllocations
+	}
+	if config.DetectUnusedPublicAPI == nil {
+		config.DetectUnusedPublicAPI = defaults.DetectUnusedPublicAPI
+	}
+	if config.AllowMutableStatics == nil {
+		config.AllowMutableStatics = defaults.AllowMutableStatics
+	}
+	if config.DowngradeMutableStatics == nil {
+		config.DowngradeMutableStatics = defaults.DowngradeMutableStatics
+	}
+	if config.SkipInternalDocs == nil {
+		config.SkipInternalDocs = defaults.SkipInternalDocs
+	}
+	if config.RequireSafetySection == nil {
+		config.RequireSafetySection = defaults.RequireSafetySection
+	}
+	if config.RequireErrorsSection == nil {
+		config.RequireErrorsSection = defaults.RequireErrorsSection
+	}
+	if config.RequirePanicsSection == nil {
+		config.RequirePanicsSection = defaults.RequirePanicsSection
+	}
+	if config.RequireDocExamples == nil {
+		config.RequireDocExamples = defaults.RequireDocExamples
+	}
+	if config.CargoCheck == nil {
+		config.CargoCheck = defaults.CargoCheck
+	}
 	if config.AllowRecursiveMacros == nil {
 		config.AllowRecursiveMacros = defaults.AllowRecursiveMacros
 	}
@@ -660,12 +1519,30 @@ func mergeRustConfig(config *RustConfig, defaults *RustConfig) {
 	if config.MaxMacroComplexity == 0 {
 		config.MaxMacroComplexity = defaults.MaxMacroComplexity
 	}
+	if config.MaxLocalImports == 0 {
+		config.MaxLocalImports = defaults.MaxLocalImports
+	}
+	if config.MaxReexportDepth == 0 {
+		config.MaxReexportDepth = defaults.MaxReexportDepth
+	}
+	if config.MaxMacroArms == 0 {
+		config.MaxMacroArms = defaults.MaxMacroArms
+	}
+	if config.MaxMacroNesting == 0 {
+		config.MaxMacroNesting = defaults.MaxMacroNesting
+	}
+	if config.MaxMacroLines == 0 {
+		config.MaxMacroLines = defaults.MaxMacroLines
+	}
 	if config.MaxModuleDepth == 0 {
 		config.MaxModuleDepth = defaults.MaxModuleDepth
 	}
 	if config.MaxFileLines == 0 {
 		config.MaxFileLines = defaults.MaxFileLines
 	}
+	if config.MaxTupleArity == 0 {
+		config.MaxTupleArity = defaults.MaxTupleArity
+	}
 	if config.MaxTraitComplexity == 0 {
 		config.MaxTraitComplexity = defaults.MaxTraitComplexity
 	}
@@ -681,6 +1558,15 @@ func mergeRustConfig(config *RustConfig, defaults *RustConfig) {
 	if config.MaxComplexTraitParams == 0 {
 		config.MaxComplexTraitParams = defaults.MaxComplexTraitParams
 	}
+	if config.MaxEnumVariants == 0 {
+		config.MaxEnumVariants = defaults.MaxEnumVariants
+	}
+	if config.MaxErrorVariants == 0 {
+		config.MaxErrorVariants = defaults.MaxErrorVariants
+	}
+	if config.SkipEnumAttributes == nil {
+		config.SkipEnumAttributes = defaults.SkipEnumAttributes
+	}
 }
 
 // mergeClippyConfig merges Clippy configuration with defaults
@@ -706,6 +1592,15 @@ func mergeClippyConfig(config *ClippyConfig, defaults *ClippyConfig) {
 	// This allows users to explicitly set an empty list if desired
 }
 
+// ValidateRustEdition checks that edition is "auto" or a supported Rust edition
+func ValidateRustEdition(edition string) error {
+	switch edition {
+	case "", "auto", "2015", "2018", "2021", "2024":
+		return nil
+	}
+	return fmt.Errorf("unsupported rust edition %q (use auto, 2015, 2018, 2021 or 2024)", edition)
+}
+
 // Validate checks if the configuration is valid
 func (c *Config) Validate() error {
 	// Check that at least one scan path is specified
@@ -727,6 +1622,9 @@ func (c *Config) Validate() error {
 	if c.Thresholds.CyclomaticComplexity <= 0 {
 		return fmt.Errorf("cyclomatic_complexity threshold must be positive")
 	}
+	if c.Thresholds.CognitiveComplexity <= 0 {
+		return fmt.Errorf("cognitive_complexity threshold must be positive")
+	}
 	if c.Thresholds.Parameters <= 0 {
 		return fmt.Errorf("parameters threshold must be positive")
 	}
@@ -736,6 +1634,110 @@ func (c *Config) Validate() error {
 	if c.Thresholds.ClassLines <= 0 {
 		return fmt.Errorf("class_lines threshold must be positive")
 	}
+	if c.Thresholds.DuplicateTokens <= 0 {
+		return fmt.Errorf("duplicate_tokens threshold must be positive")
+	}
+	if c.Thresholds.MagicStringRepeats < 0 {
+		return fmt.Errorf("magic_string_repeats threshold must not be negative")
+	}
+	if c.Thresholds.PrimitiveRepeats < 0 {
+		return fmt.Errorf("primitive_repeats threshold must not be negative")
+	}
+	if c.Thresholds.GodStructFields < 0 || c.Thresholds.GodStructMethods < 0 {
+		return fmt.Errorf("god_struct thresholds must not be negative")
+	}
+	if c.Thresholds.DuplicateTokensIntraFile < 0 || c.Thresholds.DuplicateTokensIntraModule < 0 || c.Thresholds.DuplicateTokensCrossModule < 0 {
+		return fmt.Errorf("duplicate_tokens thresholds per granularity must not be negative")
+	}
+	if c.Thresholds.FanOut <= 0 {
+		return fmt.Errorf("fan_out threshold must be positive")
+	}
+	if c.Thresholds.MaintainabilityIndex <= 0 || c.Thresholds.MaintainabilityIndex > 100 {
+		return fmt.Errorf("maintainability_index threshold must be between 1 and 100")
+	}
+	if c.Thresholds.HalsteadVolume < 0 || c.Thresholds.HalsteadDifficulty < 0 || c.Thresholds.HalsteadEffort < 0 {
+		return fmt.Errorf("halstead thresholds must not be negative")
+	}
+	if c.Thresholds.Complexity.Scale < 0 {
+		return fmt.Errorf("complexity scale must not be negative")
+	}
+	if c.Export.PanicCensus.MaxPanicSurface < 0 {
+		return fmt.Errorf("panic_census max_panic_surface must not be negative")
+	}
+	if err := ValidateRustEdition(c.Rust.Edition); err != nil {
+		return err
+	}
+	if err := ValidateProfile(c.Profile); err != nil {
+		return err
+	}
+	if err := ValidateRulePacks(c.Rust.RulePacks); err != nil {
+		return err
+	}
+	for _, function := range c.Rust.BlockingFunctions {
+		if strings.TrimSpace(function) == "" || strings.ContainsAny(function, " \t(") {
+			return fmt.Errorf("rust.blocking_functions: invalid entry %q, expected a path such as std::thread::sleep or a method such as .blocking_lock", function)
+		}
+	}
+	for _, function := range c.Rust.UnboundedChannelAPIs {
+		if strings.TrimSpace(function) == "" || strings.ContainsAny(function, " \t(") {
+			return fmt.Errorf("rust.unbounded_channel_apis: invalid entry %q, expected a path such as flume::unbounded", function)
+		}
+	}
+	for _, pattern := range c.Rust.DocExamplePaths {
+		if _, err := filepath.Match(pattern, ""); err != nil {
+			return fmt.Errorf("rust.doc_example_paths: invalid glob %q: %w", pattern, err)
+		}
+	}
+	if err := c.Scan.ThirdParty.Validate(); err != nil {
+		return err
+	}
+	if err := c.Scan.GroupRepeated.Validate(); err != nil {
+		return err
+	}
+	if err := c.Export.JUnit.Validate(); err != nil {
+		return err
+	}
+	if err := ValidateSplitBy(c.Output.SplitBy); err != nil {
+		return err
+	}
+	if err := c.Severity.Validate(); err != nil {
+		return err
+	}
+	if err := c.Debt.Validate(); err != nil {
+		return err
+	}
+	if err := c.Todos.Validate(); err != nil {
+		return err
+	}
+	if err := c.MagicNumbers.Validate(); err != nil {
+		return err
+	}
+	if err := c.UnitLiterals.Validate(); err != nil {
+		return err
+	}
+	if err := c.CommentedCode.Validate(); err != nil {
+		return err
+	}
+	names := make(map[string]bool)
+	for i := range c.Plugins {
+		if err := c.Plugins[i].Validate(); err != nil {
+			return err
+		}
+		if names[c.Plugins[i].Name] {
+			return fmt.Errorf("plugin %s is configured twice", c.Plugins[i].Name)
+		}
+		names[c.Plugins[i].Name] = true
+	}
+	ids := make(map[string]bool)
+	for i := range c.ScriptRules {
+		if err := c.ScriptRules[i].Validate(); err != nil {
+			return err
+		}
+		if ids[c.ScriptRules[i].ID] {
+			return fmt.Errorf("script rule %s is configured twice", c.ScriptRules[i].ID)
+		}
+		ids[c.ScriptRules[i].ID] = true
+	}
 
 	// Validate output paths
 	if c.Output.HTML.Path != "" {
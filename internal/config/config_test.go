@@ -4,6 +4,7 @@ import (
 	"os"
 	"path/filepath"
 	"testing"
+	"time"
 )
 
 func TestGetDefaultConfig(t *testing.T) {
@@ -27,6 +28,9 @@ func TestGetDefaultConfig(t *testing.T) {
 	if cfg.Thresholds.CyclomaticComplexity != 8 {
 		t.Errorf("Expected cyclomatic complexity threshold 8, got %d", cfg.Thresholds.CyclomaticComplexity)
 	}
+	if cfg.Thresholds.CognitiveComplexity != 15 {
+		t.Errorf("Expected cognitive complexity threshold 15, got %d", cfg.Thresholds.CognitiveComplexity)
+	}
 	if cfg.Thresholds.Parameters != 4 {
 		t.Errorf("Expected parameters threshold 4, got %d", cfg.Thresholds.Parameters)
 	}
@@ -178,6 +182,102 @@ func TestConfigValidation(t *testing.T) {
 			expectError: true,
 			errorMsg:    "at least one scan path must be specified",
 		},
+		{
+			name: "invalid junit grouping",
+			modifyFunc: func(cfg *Config) {
+				cfg.Export.JUnit.GroupBy = "package"
+			},
+			expectError: true,
+			errorMsg:    "unknown junit group_by \"package\" (available: rule, file)",
+		},
+		{
+			name: "invalid report split",
+			modifyFunc: func(cfg *Config) {
+				cfg.Output.SplitBy = "team"
+			},
+			expectError: true,
+			errorMsg:    "unknown report split \"team\" (available: owner, root, crate)",
+		},
+		{
+			name: "invalid third-party mode",
+			modifyFunc: func(cfg *Config) {
+				cfg.Scan.ThirdParty.Mode = "ignore"
+			},
+			expectError: true,
+			errorMsg:    "unknown third_party mode \"ignore\" (available: downgrade, separate)",
+		},
+		{
+			name: "group of one finding",
+			modifyFunc: func(cfg *Config) {
+				cfg.Scan.GroupRepeated.Rules = map[string]int{"todo-comment": 0, "magic-number": 1}
+			},
+			expectError: true,
+			errorMsg:    "group_repeated threshold for rule magic-number must be 0 or at least 2, got 1",
+		},
+		{
+			name: "invalid rule severity",
+			modifyFunc: func(cfg *Config) {
+				cfg.Severity.Rules = map[string]string{"todo-comment": "trivial", "magic-number": "minor"}
+			},
+			expectError: true,
+			errorMsg:    "unknown severity \"trivial\" for rule todo-comment (use info, low, medium, high or critical)",
+		},
+		{
+			name: "negative debt estimate",
+			modifyFunc: func(cfg *Config) {
+				cfg.Debt.Rules = map[string]int{"magic-number": 5, "function-length": -10}
+			},
+			expectError: true,
+			errorMsg:    "debt estimate for rule function-length must not be negative, got -10 minutes",
+		},
+		{
+			name: "invalid fail-on severity",
+			modifyFunc: func(cfg *Config) {
+				cfg.Severity.FailOn = "blocker"
+			},
+			expectError: true,
+			errorMsg:    "unknown fail_on severity \"blocker\" (use info, low, medium, high or critical)",
+		},
+		{
+			name: "invalid minimum file health",
+			modifyFunc: func(cfg *Config) {
+				cfg.Severity.MinFileHealth = 120
+			},
+			expectError: true,
+			errorMsg:    "min_file_health must be between 0 and 100, got 120",
+		},
+		{
+			name: "invalid minimum documentation coverage",
+			modifyFunc: func(cfg *Config) {
+				cfg.Severity.DocCoverageMin = -5
+			},
+			expectError: true,
+			errorMsg:    "doc_coverage_min must be between 0 and 100, got -5",
+		},
+		{
+			name: "negative maximum TODO age",
+			modifyFunc: func(cfg *Config) {
+				cfg.Todos.MaxTodoAgeDays = -1
+			},
+			expectError: true,
+			errorMsg:    "todos.max_todo_age_days must not be negative, got -1",
+		},
+		{
+			name: "commented code confidence above 1",
+			modifyFunc: func(cfg *Config) {
+				cfg.CommentedCode.MinConfidence = 1.5
+			},
+			expectError: true,
+			errorMsg:    "commented_code.min_confidence must be between 0 and 1, got 1.5",
+		},
+		{
+			name: "empty TODO issue marker",
+			modifyFunc: func(cfg *Config) {
+				cfg.Todos.IssueMarkers = []string{"TODO", " "}
+			},
+			expectError: true,
+			errorMsg:    "todos.issue_markers must not contain empty markers",
+		},
 		{
 			name: "invalid function lines threshold",
 			modifyFunc: func(cfg *Config) {
@@ -194,6 +294,198 @@ func TestConfigValidation(t *testing.T) {
 			expectError: true,
 			errorMsg:    "cyclomatic_complexity threshold must be positive",
 		},
+		{
+			name: "invalid cognitive complexity threshold",
+			modifyFunc: func(cfg *Config) {
+				cfg.Thresholds.CognitiveComplexity = -1
+			},
+			expectError: true,
+			errorMsg:    "cognitive_complexity threshold must be positive",
+		},
+		{
+			name: "invalid duplicate tokens threshold",
+			modifyFunc: func(cfg *Config) {
+				cfg.Thresholds.DuplicateTokens = -1
+			},
+			expectError: true,
+			errorMsg:    "duplicate_tokens threshold must be positive",
+		},
+		{
+			name: "invalid fan-out threshold",
+			modifyFunc: func(cfg *Config) {
+				cfg.Thresholds.FanOut = -1
+			},
+			expectError: true,
+			errorMsg:    "fan_out threshold must be positive",
+		},
+		{
+			name: "maintainability index threshold over 100",
+			modifyFunc: func(cfg *Config) {
+				cfg.Thresholds.MaintainabilityIndex = 120
+			},
+			expectError: true,
+			errorMsg:    "maintainability_index threshold must be between 1 and 100",
+		},
+		{
+			name: "magic number allowlist with a non-number",
+			modifyFunc: func(cfg *Config) {
+				cfg.MagicNumbers.Allowed = []string{"42", "0x7f", "1_000", "forty-two"}
+			},
+			expectError: true,
+			errorMsg:    `magic_numbers.allowed: "forty-two" is not a number`,
+		},
+		{
+			name: "magic number allowlist with an invalid glob",
+			modifyFunc: func(cfg *Config) {
+				cfg.MagicNumbers.Files = map[string][]string{"internal/geo/[*.go": {"6371"}}
+			},
+			expectError: true,
+			errorMsg:    `magic_numbers.files: invalid glob "internal/geo/[*.go": syntax error in pattern`,
+		},
+		{
+			name: "unknown unit literal kind",
+			modifyFunc: func(cfg *Config) {
+				cfg.UnitLiterals.Kinds = []string{"duration", "weight"}
+			},
+			expectError: true,
+			errorMsg:    `unit_literals.kinds: unknown kind "weight" (available: duration, size, port)`,
+		},
+		{
+			name: "negative magic string threshold",
+			modifyFunc: func(cfg *Config) {
+				cfg.Thresholds.MagicStringRepeats = -1
+			},
+			expectError: true,
+			errorMsg:    "magic_string_repeats threshold must not be negative",
+		},
+		{
+			name: "negative primitive threshold",
+			modifyFunc: func(cfg *Config) {
+				cfg.Thresholds.PrimitiveRepeats = -1
+			},
+			expectError: true,
+			errorMsg:    "primitive_repeats threshold must not be negative",
+		},
+		{
+			name: "negative god struct threshold",
+			modifyFunc: func(cfg *Config) {
+				cfg.Thresholds.GodStructMethods = -1
+			},
+			expectError: true,
+			errorMsg:    "god_struct thresholds must not be negative",
+		},
+		{
+			name: "negative clone granularity threshold",
+			modifyFunc: func(cfg *Config) {
+				cfg.Thresholds.DuplicateTokensCrossModule = -5
+			},
+			expectError: true,
+			errorMsg:    "duplicate_tokens thresholds per granularity must not be negative",
+		},
+		{
+			name: "negative halstead threshold",
+			modifyFunc: func(cfg *Config) {
+				cfg.Thresholds.HalsteadEffort = -1
+			},
+			expectError: true,
+			errorMsg:    "halstead thresholds must not be negative",
+		},
+		{
+			name: "negative complexity scale",
+			modifyFunc: func(cfg *Config) {
+				cfg.Thresholds.Complexity.Scale = -1.5
+			},
+			expectError: true,
+			errorMsg:    "complexity scale must not be negative",
+		},
+		{
+			name: "unsupported rust edition",
+			modifyFunc: func(cfg *Config) {
+				cfg.Rust.Edition = "2020"
+			},
+			expectError: true,
+			errorMsg:    `unsupported rust edition "2020" (use auto, 2015, 2018, 2021 or 2024)`,
+		},
+		{
+			name: "unknown profile",
+			modifyFunc: func(cfg *Config) {
+				cfg.Profile = "kernel"
+			},
+			expectError: true,
+			errorMsg:    `unknown profile "kernel" (available: default, strict, relaxed, security, embedded)`,
+		},
+		{
+			name: "unknown rule pack",
+			modifyFunc: func(cfg *Config) {
+				cfg.Rust.RulePacks = []string{"concurrency", "locking"}
+			},
+			expectError: true,
+			errorMsg:    `unknown rule pack "locking" (available: concurrency, imports)`,
+		},
+		{
+			name: "plugin without command",
+			modifyFunc: func(cfg *Config) {
+				cfg.Plugins = []PluginConfig{{Name: "house-rules"}}
+			},
+			expectError: true,
+			errorMsg:    "plugin house-rules has no command",
+		},
+		{
+			name: "plugin with unknown language",
+			modifyFunc: func(cfg *Config) {
+				cfg.Plugins = []PluginConfig{{Name: "house-rules", Command: "house-rules", Languages: []string{"go", "python"}}}
+			},
+			expectError: true,
+			errorMsg:    `plugin house-rules: unknown language "python" (use go or rust)`,
+		},
+		{
+			name: "plugin with invalid timeout",
+			modifyFunc: func(cfg *Config) {
+				cfg.Plugins = []PluginConfig{{Name: "house-rules", Command: "house-rules", Timeout: "soon"}}
+			},
+			expectError: true,
+			errorMsg:    `plugin house-rules: invalid timeout "soon"`,
+		},
+		{
+			name: "plugin with invalid sandbox memory",
+			modifyFunc: func(cfg *Config) {
+				cfg.Plugins = []PluginConfig{{Name: "house-rules", Command: "house-rules", Sandbox: PluginSandboxConfig{Memory: "lots"}}}
+			},
+			expectError: true,
+			errorMsg:    `plugin house-rules: invalid sandbox memory "lots"`,
+		},
+		{
+			name: "script rule with unknown match",
+			modifyFunc: func(cfg *Config) {
+				cfg.ScriptRules = []ScriptRuleConfig{{ID: "long-handler", Match: "statement", When: "lines > 40"}}
+			},
+			expectError: true,
+			errorMsg:    `script rule long-handler: unknown match "statement" (use file, function, type or import)`,
+		},
+		{
+			name: "script rule without condition",
+			modifyFunc: func(cfg *Config) {
+				cfg.ScriptRules = []ScriptRuleConfig{{ID: "long-handler"}}
+			},
+			expectError: true,
+			errorMsg:    "script rule long-handler has no when expression",
+		},
+		{
+			name: "duplicate script rule",
+			modifyFunc: func(cfg *Config) {
+				cfg.ScriptRules = []ScriptRuleConfig{{ID: "long-handler", When: "true"}, {ID: "long-handler", When: "false"}}
+			},
+			expectError: true,
+			errorMsg:    "script rule long-handler is configured twice",
+		},
+		{
+			name: "duplicate plugin",
+			modifyFunc: func(cfg *Config) {
+				cfg.Plugins = []PluginConfig{{Name: "house-rules", Command: "a"}, {Name: "house-rules", Command: "b"}}
+			},
+			expectError: true,
+			errorMsg:    "plugin house-rules is configured twice",
+		},
 		{
 			name: "invalid logging level",
 			modifyFunc: func(cfg *Config) {
@@ -228,6 +520,19 @@ func TestConfigValidation(t *testing.T) {
 	}
 }
 
+func TestPluginSandboxDefaults(t *testing.T) {
+	var sandbox PluginSandboxConfig
+	if !sandbox.GetEnabled() || sandbox.GetCPUTime() != 0 || sandbox.GetMemory() != 1<<30 {
+		t.Errorf("Expected an enabled sandbox with 1GB and no CPU limit, got %v, %v, %d",
+			sandbox.GetEnabled(), sandbox.GetCPUTime(), sandbox.GetMemory())
+	}
+
+	sandbox = PluginSandboxConfig{CPUTime: "2m", Memory: "512MB"}
+	if sandbox.GetCPUTime() != 2*time.Minute || sandbox.GetMemory() != 512<<20 {
+		t.Errorf("Expected 2m and 512MB, got %v and %d", sandbox.GetCPUTime(), sandbox.GetMemory())
+	}
+}
+
 func TestMergeWithDefaults(t *testing.T) {
 	cfg := &Config{}
 	mergeWithDefaults(cfg)
@@ -0,0 +1,114 @@
+package config
+
+import (
+	"fmt"
+	"strings"
+)
+
+// Built-in rule profiles
+const (
+	ProfileDefault  = "default"
+	ProfileStrict   = "strict"   // Tight thresholds for new or well-kept code
+	ProfileRelaxed  = "relaxed"  // Loose thresholds for adopting GoClean in legacy code
+	ProfileSecurity = "security" // Unsafe code and error handling; size and style rules relaxed
+	ProfileEmbedded = "embedded" // no_std and embedded crates
+)
+
+// knownProfiles lists the profiles that can be selected
+var knownProfiles = []string{ProfileDefault, ProfileStrict, ProfileRelaxed, ProfileSecurity, ProfileEmbedded}
+
+// ValidateProfile checks that profile names a built-in profile; empty selects the default
+func ValidateProfile(profile string) error {
+	if profile == "" {
+		return nil
+	}
+	for _, name := range knownProfiles {
+		if profile == name {
+			return nil
+		}
+	}
+	return fmt.Errorf("unknown profile %q (available: %s)", profile, strings.Join(knownProfiles, ", "))
+}
+
+// profileDefaults returns the default configuration of a profile. Settings
+// given explicitly in the configuration file are merged over it.
+func profileDefaults(profile string) *Config {
+	config := GetDefaultConfig()
+	config.Profile = profile
+	applyProfile(config)
+	return config
+}
+
+// applyProfile replaces the thresholds and rule settings of config with
+// those of its profile
+func applyProfile(config *Config) {
+	rust := &config.Rust
+	switch config.Profile {
+	case ProfileStrict:
+		config.Thresholds = Thresholds{
+			FunctionLines:        15,
+			CyclomaticComplexity: 5,
+			CognitiveComplexity:  10,
+			Parameters:           3,
+			NestingDepth:         2,
+			ClassLines:           100,
+			DuplicateTokens:      30,
+			FanOut:               4,
+			MaintainabilityIndex: 30,
+			GodStructFields:      15,
+			GodStructMethods:     15,
+		}
+		rust.MaxFileLines = 300
+		rust.MaxImplMethods = 12
+		rust.AllowUnsafe = boolPtr(false)
+
+	case ProfileRelaxed:
+		config.Thresholds = relaxedThresholds()
+		rust.MaxFileLines = 1000
+		rust.MaxImplMethods = 40
+		rust.AllowUnwrap = boolPtr(true)
+		rust.AllowExpect = boolPtr(true)
+		rust.DetectUnnecessaryClones = boolPtr(false)
+		rust.DetectInefficientString = boolPtr(false)
+		rust.DetectBoxedPrimitives = boolPtr(false)
+		config.Severity.FailOn = "high"
+
+	case ProfileSecurity:
+		// Size and complexity findings would bury the ones that matter here
+		config.Thresholds = relaxedThresholds()
+		rust.AllowUnsafe = boolPtr(false)
+		rust.RequireUnsafeComments = boolPtr(true)
+		rust.DetectTransmuteUsage = boolPtr(true)
+		rust.AllowMutableStatics = boolPtr(false)
+		rust.AllowUnwrap = boolPtr(false)
+		rust.AllowExpect = boolPtr(false)
+		rust.EnableErrorHandlingCheck = boolPtr(true)
+		rust.EnforceResultPropagation = boolPtr(true)
+		rust.DetectUnnecessaryClones = boolPtr(false)
+		rust.DetectInefficientString = boolPtr(false)
+		rust.DetectBoxedPrimitives = boolPtr(false)
+		config.Severity.FailOn = "medium"
+
+	case ProfileEmbedded:
+		// Firmware keeps state in statics, avoids the heap and documents only
+		// the items it exports
+		rust.AllowMutableStatics = boolPtr(true)
+		rust.DetectHeapAllocations = boolPtr(true)
+		rust.SkipInternalDocs = boolPtr(true)
+	}
+}
+
+// relaxedThresholds returns thresholds about twice the defaults
+func relaxedThresholds() Thresholds {
+	return Thresholds{
+		FunctionLines:        50,
+		CyclomaticComplexity: 15,
+		CognitiveComplexity:  25,
+		Parameters:           6,
+		NestingDepth:         5,
+		ClassLines:           300,
+		DuplicateTokens:      100,
+		FanOut:               10,
+		MaintainabilityIndex: 10,
+	}
+}
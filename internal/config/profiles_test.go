@@ -0,0 +1,93 @@
+package config
+
+import (
+	"os"
+	"path/filepath"
+	"testing"
+)
+
+func TestEmbeddedProfile(t *testing.T) {
+	configPath := filepath.Join(t.TempDir(), "goclean.yaml")
+	configContent := `profile: embedded
+
+rust:
+  detect_heap_allocations: false
+`
+	if err := os.WriteFile(configPath, []byte(configContent), 0644); err != nil {
+		t.Fatalf("Failed to create test config file: %v", err)
+	}
+
+	cfg, err := Load(configPath)
+	if err != nil {
+		t.Fatalf("Failed to load config: %v", err)
+	}
+
+	if !cfg.Rust.GetAllowMutableStatics() {
+		t.Error("Expected the embedded profile to allow mutable statics")
+	}
+	if !cfg.Rust.GetSkipInternalDocs() {
+		t.Error("Expected the embedded profile to skip docs on internal items")
+	}
+	if cfg.Rust.GetDetectHeapAllocations() {
+		t.Error("Expected the explicit detect_heap_allocations setting to override the profile")
+	}
+}
+
+func TestDefaultProfile(t *testing.T) {
+	cfg := GetDefaultConfig()
+	applyProfile(cfg)
+
+	if cfg.Rust.GetAllowMutableStatics() || cfg.Rust.GetDetectHeapAllocations() || cfg.Rust.GetSkipInternalDocs() {
+		t.Error("Expected the default profile to keep the embedded rules off")
+	}
+}
+
+func TestStrictProfileThresholds(t *testing.T) {
+	configPath := filepath.Join(t.TempDir(), "goclean.yaml")
+	configContent := `thresholds:
+  function_lines: 40
+`
+	if err := os.WriteFile(configPath, []byte(configContent), 0644); err != nil {
+		t.Fatalf("Failed to create test config file: %v", err)
+	}
+
+	cfg, err := LoadWithProfile(configPath, ProfileStrict)
+	if err != nil {
+		t.Fatalf("Failed to load config: %v", err)
+	}
+
+	if cfg.Profile != ProfileStrict {
+		t.Errorf("Expected the strict profile to be selected, got %q", cfg.Profile)
+	}
+	if cfg.Thresholds.CyclomaticComplexity != 5 || cfg.Thresholds.Parameters != 3 || cfg.Thresholds.GodStructFields != 15 {
+		t.Errorf("Expected strict thresholds, got %+v", cfg.Thresholds)
+	}
+	if cfg.Thresholds.FunctionLines != 40 {
+		t.Errorf("Expected the explicit function_lines threshold to override the profile, got %d", cfg.Thresholds.FunctionLines)
+	}
+	if cfg.Rust.GetAllowUnsafe() {
+		t.Error("Expected the strict profile to report unsafe code")
+	}
+}
+
+func TestRelaxedAndSecurityProfiles(t *testing.T) {
+	relaxed := profileDefaults(ProfileRelaxed)
+	if !relaxed.Rust.GetAllowUnwrap() || relaxed.Severity.FailOn != "high" || relaxed.Thresholds.FunctionLines != 50 {
+		t.Errorf("Unexpected relaxed profile: unwrap %v, fail_on %s, %+v",
+			relaxed.Rust.GetAllowUnwrap(), relaxed.Severity.FailOn, relaxed.Thresholds)
+	}
+
+	security := profileDefaults(ProfileSecurity)
+	if security.Rust.GetAllowUnsafe() || security.Rust.GetAllowUnwrap() || security.Rust.GetDetectUnnecessaryClones() {
+		t.Error("Expected the security profile to report unsafe code and unwraps but not clones")
+	}
+	if security.Severity.FailOn != "medium" {
+		t.Errorf("Expected the security profile to fail on medium, got %s", security.Severity.FailOn)
+	}
+}
+
+func TestLoadWithUnknownProfile(t *testing.T) {
+	if _, err := LoadWithProfile("", "pedantic"); err == nil {
+		t.Error("Expected an error for an unknown profile")
+	}
+}
@@ -0,0 +1,32 @@
+package config
+
+import (
+	"fmt"
+	"strings"
+)
+
+// Optional rule packs, enabled with rust.rule_packs
+const (
+	RulePackConcurrency = "concurrency" // Lock, task and shared state smells in async and server code
+	RulePackImports     = "imports"     // Sorting, duplicates and placement of use declarations
+)
+
+// knownRulePacks lists the rule packs that can be enabled
+var knownRulePacks = []string{RulePackConcurrency, RulePackImports}
+
+// ValidateRulePacks checks that every enabled rule pack exists
+func ValidateRulePacks(packs []string) error {
+	for _, pack := range packs {
+		known := false
+		for _, name := range knownRulePacks {
+			if pack == name {
+				known = true
+				break
+			}
+		}
+		if !known {
+			return fmt.Errorf("unknown rule pack %q (available: %s)", pack, strings.Join(knownRulePacks, ", "))
+		}
+	}
+	return nil
+}
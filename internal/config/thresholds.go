@@ -0,0 +1,104 @@
+package config
+
+import "math"
+
+// Threshold groups that can be scaled as a whole
+const (
+	ThresholdGroupComplexity = "complexity"
+)
+
+// ThresholdGroup adjusts a family of related thresholds together
+type ThresholdGroup struct {
+	// Scale multiplies every threshold of the group; 0 leaves them unchanged
+	Scale float64 `yaml:"scale,omitempty"`
+}
+
+// ThresholdSetting describes one threshold and how its effective value is derived
+type ThresholdSetting struct {
+	Key        string  `json:"key"`             // YAML key under thresholds
+	Group      string  `json:"group,omitempty"` // Group scaling the threshold, if any
+	Configured int     `json:"configured"`
+	Scale      float64 `json:"scale"`
+	Effective  int     `json:"effective"`
+}
+
+// thresholdField binds a threshold key to its value
+type thresholdField struct {
+	key   string
+	group string
+	value *int
+}
+
+// fields lists the thresholds in display order
+func (t *Thresholds) fields() []thresholdField {
+	return []thresholdField{
+		{key: "function_lines", group: ThresholdGroupComplexity, value: &t.FunctionLines},
+		{key: "cyclomatic_complexity", group: ThresholdGroupComplexity, value: &t.CyclomaticComplexity},
+		{key: "cognitive_complexity", group: ThresholdGroupComplexity, value: &t.CognitiveComplexity},
+		{key: "parameters", group: ThresholdGroupComplexity, value: &t.Parameters},
+		{key: "nesting_depth", group: ThresholdGroupComplexity, value: &t.NestingDepth},
+		{key: "class_lines", value: &t.ClassLines},
+		{key: "fan_out", value: &t.FanOut},
+		{key: "maintainability_index", value: &t.MaintainabilityIndex},
+		{key: "halstead_volume", value: &t.HalsteadVolume},
+		{key: "halstead_difficulty", value: &t.HalsteadDifficulty},
+		{key: "halstead_effort", value: &t.HalsteadEffort},
+		{key: "magic_string_repeats", value: &t.MagicStringRepeats},
+		{key: "primitive_repeats", value: &t.PrimitiveRepeats},
+		{key: "god_struct_fields", value: &t.GodStructFields},
+		{key: "god_struct_methods", value: &t.GodStructMethods},
+		{key: "duplicate_tokens", value: &t.DuplicateTokens},
+		{key: "duplicate_tokens_intra_file", value: &t.DuplicateTokensIntraFile},
+		{key: "duplicate_tokens_intra_module", value: &t.DuplicateTokensIntraModule},
+		{key: "duplicate_tokens_cross_module", value: &t.DuplicateTokensCrossModule},
+	}
+}
+
+// groupScale returns the multiplier of a threshold group
+func (t *Thresholds) groupScale(group string) float64 {
+	if group == ThresholdGroupComplexity && t.Complexity.Scale > 0 {
+		return t.Complexity.Scale
+	}
+	return 1
+}
+
+// Effective returns the thresholds with group scales applied. The result has
+// no scales left, so applying Effective again does not change it.
+func (t Thresholds) Effective() Thresholds {
+	effective := t
+	for _, field := range effective.fields() {
+		*field.value = scaleThreshold(*field.value, t.groupScale(field.group))
+	}
+	effective.Complexity = ThresholdGroup{}
+	return effective
+}
+
+// Settings lists every threshold with its configured and effective value
+func (t Thresholds) Settings() []ThresholdSetting {
+	effective := t.Effective()
+	effectiveFields := effective.fields()
+
+	settings := make([]ThresholdSetting, 0, len(effectiveFields))
+	for i, field := range t.fields() {
+		settings = append(settings, ThresholdSetting{
+			Key:        field.key,
+			Group:      field.group,
+			Configured: *field.value,
+			Scale:      t.groupScale(field.group),
+			Effective:  *effectiveFields[i].value,
+		})
+	}
+	return settings
+}
+
+// scaleThreshold multiplies a threshold, rounding to the nearest positive integer
+func scaleThreshold(value int, scale float64) int {
+	if scale == 1 || value <= 0 {
+		return value
+	}
+	scaled := int(math.Round(float64(value) * scale))
+	if scaled < 1 {
+		return 1
+	}
+	return scaled
+}
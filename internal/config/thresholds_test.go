@@ -0,0 +1,79 @@
+package config
+
+import "testing"
+
+func TestThresholdsEffective(t *testing.T) {
+	thresholds := GetDefaultConfig().Thresholds
+	thresholds.Complexity.Scale = 1.5
+
+	effective := thresholds.Effective()
+	expected := map[string][2]int{
+		"function_lines":        {thresholds.FunctionLines, 38},
+		"cyclomatic_complexity": {thresholds.CyclomaticComplexity, 12},
+		"cognitive_complexity":  {thresholds.CognitiveComplexity, 23},
+		"parameters":            {thresholds.Parameters, 6},
+		"nesting_depth":         {thresholds.NestingDepth, 5},
+		"class_lines":           {thresholds.ClassLines, 150},
+		"fan_out":               {thresholds.FanOut, 5},
+		"maintainability_index": {thresholds.MaintainabilityIndex, 20},
+		"duplicate_tokens":      {thresholds.DuplicateTokens, 50},
+	}
+	actual := map[string]int{
+		"function_lines":        effective.FunctionLines,
+		"cyclomatic_complexity": effective.CyclomaticComplexity,
+		"cognitive_complexity":  effective.CognitiveComplexity,
+		"parameters":            effective.Parameters,
+		"nesting_depth":         effective.NestingDepth,
+		"class_lines":           effective.ClassLines,
+		"fan_out":               effective.FanOut,
+		"maintainability_index": effective.MaintainabilityIndex,
+		"duplicate_tokens":      effective.DuplicateTokens,
+	}
+	for key, values := range expected {
+		if actual[key] != values[1] {
+			t.Errorf("Expected effective %s %d (configured %d), got %d", key, values[1], values[0], actual[key])
+		}
+	}
+
+	if again := effective.Effective(); again != effective {
+		t.Errorf("Expected Effective to be idempotent, got %+v", again)
+	}
+}
+
+func TestThresholdsEffectiveWithoutScale(t *testing.T) {
+	thresholds := GetDefaultConfig().Thresholds
+	if effective := thresholds.Effective(); effective != thresholds {
+		t.Errorf("Expected unscaled thresholds to be unchanged, got %+v", effective)
+	}
+
+	thresholds.Complexity.Scale = 0.1
+	if effective := thresholds.Effective(); effective.NestingDepth != 1 {
+		t.Errorf("Expected scaled thresholds to stay positive, got nesting depth %d", effective.NestingDepth)
+	}
+}
+
+func TestThresholdsSettings(t *testing.T) {
+	thresholds := GetDefaultConfig().Thresholds
+	thresholds.Complexity.Scale = 2
+
+	settings := thresholds.Settings()
+	if len(settings) != 18 {
+		t.Fatalf("Expected 18 threshold settings, got %d", len(settings))
+	}
+
+	first := settings[0]
+	if first.Key != "function_lines" || first.Group != ThresholdGroupComplexity ||
+		first.Configured != 25 || first.Scale != 2 || first.Effective != 50 {
+		t.Errorf("Unexpected function_lines setting: %+v", first)
+	}
+
+	duplicates := settings[14]
+	if duplicates.Key != "duplicate_tokens" || duplicates.Group != "" || duplicates.Scale != 1 || duplicates.Effective != duplicates.Configured {
+		t.Errorf("Unexpected duplicate_tokens setting: %+v", duplicates)
+	}
+
+	last := settings[len(settings)-1]
+	if last.Key != "duplicate_tokens_cross_module" || last.Configured != 0 || last.Effective != 0 {
+		t.Errorf("Unexpected duplicate_tokens_cross_module setting: %+v", last)
+	}
+}
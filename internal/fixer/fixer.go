@@ -0,0 +1,331 @@
+// Package fixer applies the fixes that detectors attach to violations and
+// renders them as unified diffs.
+package fixer
+
+import (
+	"fmt"
+	"os"
+	"sort"
+	"strings"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+)
+
+// contextLines is the number of unchanged lines shown around a diff hunk
+const contextLines = 3
+
+// FileResult describes the fixes of one file
+type FileResult struct {
+	Path    string
+	Applied []*models.Violation // Violations whose fix was applied
+	Skipped []*models.Violation // Fixes that overlap an applied fix or do not fit the file
+	Diff    string              // Unified diff of the changes
+}
+
+// Report summarizes a fix run
+type Report struct {
+	Files        []*FileResult
+	FilesChanged int
+	Applied      int
+	Skipped      int
+}
+
+// Fixer applies fixes to source files
+type Fixer struct {
+	dryRun bool
+}
+
+// New creates a fixer. A dry-run fixer computes diffs without writing files.
+func New(dryRun bool) *Fixer {
+	return &Fixer{dryRun: dryRun}
+}
+
+// Apply applies the fixes of all violations in the results. The edits of
+// one violation are applied together or not at all; a fix that overlaps a
+// fix applied earlier in the file is skipped and can be applied by running
+// again after a rescan.
+func (f *Fixer) Apply(results []*models.ScanResult) (*Report, error) {
+	report := &Report{}
+	for _, result := range results {
+		if result == nil || result.File == nil {
+			continue
+		}
+		var fixable []*models.Violation
+		for _, violation := range result.Violations {
+			if violation.Fix != nil && len(violation.Fix.Edits) > 0 {
+				fixable = append(fixable, violation)
+			}
+		}
+		if len(fixable) == 0 {
+			continue
+		}
+
+		fileResult, err := f.fixFile(result.File.Path, fixable)
+		if err != nil {
+			return report, err
+		}
+		report.Files = append(report.Files, fileResult)
+		report.Applied += len(fileResult.Applied)
+		report.Skipped += len(fileResult.Skipped)
+		if len(fileResult.Applied) > 0 {
+			report.FilesChanged++
+		}
+	}
+	return report, nil
+}
+
+// fixFile applies the non-overlapping fixes of one file
+func (f *Fixer) fixFile(path string, violations []*models.Violation) (*FileResult, error) {
+	info, err := os.Stat(path)
+	if err != nil {
+		return nil, fmt.Errorf("failed to read %s: %w", path, err)
+	}
+	content, err := os.ReadFile(path)
+	if err != nil {
+		return nil, fmt.Errorf("failed to read %s: %w", path, err)
+	}
+
+	fileResult := &FileResult{Path: path}
+	if len(content) == 0 {
+		fileResult.Skipped = violations
+		return fileResult, nil
+	}
+	source := newSourceFile(string(content))
+
+	// Apply fixes in source order so the outcome does not depend on detector order
+	sort.SliceStable(violations, func(i, j int) bool {
+		a, b := violations[i].Fix.Edits[0], violations[j].Fix.Edits[0]
+		if a.Line != b.Line {
+			return a.Line < b.Line
+		}
+		return a.Column < b.Column
+	})
+
+	var accepted []resolvedEdit
+	for _, violation := range violations {
+		edits, ok := source.resolve(violation.Fix.Edits)
+		if !ok || overlapsAny(edits, accepted) {
+			fileResult.Skipped = append(fileResult.Skipped, violation)
+			continue
+		}
+		accepted = append(accepted, edits...)
+		fileResult.Applied = append(fileResult.Applied, violation)
+	}
+	if len(accepted) == 0 {
+		return fileResult, nil
+	}
+
+	newContent, diff := source.apply(accepted, path)
+	fileResult.Diff = diff
+	if !f.dryRun {
+		if err := os.WriteFile(path, []byte(newContent), info.Mode().Perm()); err != nil {
+			return nil, fmt.Errorf("failed to write %s: %w", path, err)
+		}
+	}
+	return fileResult, nil
+}
+
+// resolvedEdit is a text edit as byte offsets into the file
+type resolvedEdit struct {
+	start, end int
+	newText    string
+}
+
+// overlapsAny reports whether any edit overlaps an accepted edit. Two
+// insertions at the same position also conflict, as their order is unknown.
+func overlapsAny(edits, accepted []resolvedEdit) bool {
+	for _, edit := range edits {
+		for _, other := range accepted {
+			if edit.start < other.end && other.start < edit.end {
+				return true
+			}
+			if edit.start == other.start && (edit.start == edit.end || other.start == other.end) {
+				return true
+			}
+		}
+	}
+	return false
+}
+
+// sourceFile is the content of a file split into lines
+type sourceFile struct {
+	content    string
+	lineStarts []int // Byte offset of each line; a final entry marks the end of the file
+}
+
+func newSourceFile(content string) *sourceFile {
+	lineStarts := []int{0}
+	for i := 0; i < len(content); i++ {
+		if content[i] == '\n' && i+1 < len(content) {
+			lineStarts = append(lineStarts, i+1)
+		}
+	}
+	return &sourceFile{content: content, lineStarts: append(lineStarts, len(content))}
+}
+
+// lineCount returns the number of lines of the file
+func (s *sourceFile) lineCount() int {
+	return len(s.lineStarts) - 1
+}
+
+// offset converts a 1-based line and byte column to an offset. The position
+// just after the last line, (lineCount+1, 1), is the end of the file.
+func (s *sourceFile) offset(line, column int) (int, bool) {
+	if line == s.lineCount()+1 && column == 1 {
+		return len(s.content), true
+	}
+	if line < 1 || line > s.lineCount() || column < 1 {
+		return 0, false
+	}
+	offset := s.lineStarts[line-1] + column - 1
+	if offset > s.lineStarts[line] {
+		return 0, false
+	}
+	return offset, true
+}
+
+// lineOf returns the 1-based line containing offset
+func (s *sourceFile) lineOf(offset int) int {
+	return sort.Search(s.lineCount(), func(i int) bool { return s.lineStarts[i+1] > offset })
+}
+
+// resolve converts edits to offsets, failing when an edit is outside the file
+func (s *sourceFile) resolve(edits []models.TextEdit) ([]resolvedEdit, bool) {
+	resolved := make([]resolvedEdit, 0, len(edits))
+	for _, edit := range edits {
+		start, ok := s.offset(edit.Line, edit.Column)
+		if !ok {
+			return nil, false
+		}
+		end, ok := s.offset(edit.EndLine, edit.EndColumn)
+		if !ok || end < start {
+			return nil, false
+		}
+		resolved = append(resolved, resolvedEdit{start: start, end: end, newText: edit.NewText})
+	}
+	for i := range resolved {
+		if overlapsAny(resolved[i+1:], resolved[i:i+1]) {
+			return nil, false
+		}
+	}
+	return resolved, true
+}
+
+// change replaces the old lines first through last with new lines
+type change struct {
+	first, last int
+	newLines    []string
+}
+
+// apply applies non-overlapping edits and returns the new content with a
+// unified diff of the change
+func (s *sourceFile) apply(edits []resolvedEdit, path string) (string, string) {
+	sort.Slice(edits, func(i, j int) bool { return edits[i].start < edits[j].start })
+
+	// Group edits by the whole lines they touch
+	var changes []*change
+	var changeEdits [][]resolvedEdit
+	for _, edit := range edits {
+		first, last := s.lineOf(edit.start)+1, s.lineOf(edit.end)+1
+		if edit.end > edit.start && edit.end == s.lineStarts[last-1] {
+			last-- // The edit ends at the start of a line and leaves it untouched
+		}
+		if first > s.lineCount() {
+			first, last = s.lineCount(), s.lineCount()
+		}
+		if n := len(changes); n > 0 && first <= changes[n-1].last {
+			changes[n-1].last = max(changes[n-1].last, last)
+			changeEdits[n-1] = append(changeEdits[n-1], edit)
+			continue
+		}
+		changes = append(changes, &change{first: first, last: last})
+		changeEdits = append(changeEdits, []resolvedEdit{edit})
+	}
+
+	var output strings.Builder
+	position := 0
+	for i, c := range changes {
+		start, end := s.lineStarts[c.first-1], s.lineStarts[c.last]
+		var segment strings.Builder
+		segmentPosition := start
+		for _, edit := range changeEdits[i] {
+			segment.WriteString(s.content[segmentPosition:edit.start])
+			segment.WriteString(edit.newText)
+			segmentPosition = edit.end
+		}
+		segment.WriteString(s.content[segmentPosition:end])
+
+		output.WriteString(s.content[position:start])
+		output.WriteString(segment.String())
+		position = end
+		c.newLines = splitLines(segment.String())
+	}
+	output.WriteString(s.content[position:])
+
+	return output.String(), s.diff(changes, path)
+}
+
+// diff renders changes as a unified diff with context lines
+func (s *sourceFile) diff(changes []*change, path string) string {
+	var out strings.Builder
+	slashPath := strings.TrimPrefix(strings.ReplaceAll(path, "\\", "/"), "./")
+	fmt.Fprintf(&out, "--- a/%s\n+++ b/%s\n", slashPath, slashPath)
+
+	oldLines := splitLines(s.content)
+	delta := 0 // Lines added minus lines removed before the current hunk
+	for i := 0; i < len(changes); {
+		// Changes whose context would touch are shown in one hunk
+		j := i + 1
+		for j < len(changes) && changes[j].first-changes[j-1].last-1 <= 2*contextLines {
+			j++
+		}
+
+		hunkStart := max(changes[i].first-contextLines, 1)
+		hunkEnd := min(changes[j-1].last+contextLines, len(oldLines))
+		var body strings.Builder
+		hunkDelta := 0
+		line := hunkStart
+		for _, c := range changes[i:j] {
+			for ; line < c.first; line++ {
+				writeDiffLine(&body, ' ', oldLines[line-1])
+			}
+			for ; line <= c.last; line++ {
+				writeDiffLine(&body, '-', oldLines[line-1])
+			}
+			for _, newLine := range c.newLines {
+				writeDiffLine(&body, '+', newLine)
+			}
+			hunkDelta += len(c.newLines) - (c.last - c.first + 1)
+		}
+		for ; line <= hunkEnd; line++ {
+			writeDiffLine(&body, ' ', oldLines[line-1])
+		}
+
+		oldCount := hunkEnd - hunkStart + 1
+		newCount := oldCount + hunkDelta
+		newStart := hunkStart + delta
+		if newCount == 0 {
+			newStart--
+		}
+		fmt.Fprintf(&out, "@@ -%d,%d +%d,%d @@\n", hunkStart, oldCount, newStart, newCount)
+		out.WriteString(body.String())
+
+		delta += hunkDelta
+		i = j
+	}
+	return out.String()
+}
+
+// splitLines splits text into lines without their line endings
+func splitLines(text string) []string {
+	if text == "" {
+		return nil
+	}
+	return strings.Split(strings.TrimSuffix(text, "\n"), "\n")
+}
+
+func writeDiffLine(out *strings.Builder, prefix byte, line string) {
+	out.WriteByte(prefix)
+	out.WriteString(line)
+	out.WriteByte('\n')
+}
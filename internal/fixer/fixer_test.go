@@ -0,0 +1,126 @@
+package fixer
+
+import (
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+)
+
+const fixerTestSource = `fn main() {
+    let userCount = 1;
+    println!("{}", userCount);
+    // let old = 2;
+    // println!("{}", old);
+}
+`
+
+func fixerTestResults(path string) []*models.ScanResult {
+	rename := &models.Violation{File: path, Line: 2, Fix: &models.Fix{
+		Description: "Rename 'userCount' to 'user_count'",
+		Edits: []models.TextEdit{
+			{Line: 2, Column: 9, EndLine: 2, EndColumn: 18, NewText: "user_count"},
+			{Line: 3, Column: 20, EndLine: 3, EndColumn: 29, NewText: "user_count"},
+		},
+	}}
+	overlapping := &models.Violation{File: path, Line: 3, Fix: &models.Fix{
+		Description: "Rename 'userCount' to 'count'",
+		Edits:       []models.TextEdit{{Line: 3, Column: 20, EndLine: 3, EndColumn: 29, NewText: "count"}},
+	}}
+	removal := &models.Violation{File: path, Line: 4, Fix: &models.Fix{
+		Description: "Delete the commented-out code",
+		Edits:       []models.TextEdit{models.DeleteLinesEdit(4, 5)},
+	}}
+	unfixable := &models.Violation{File: path, Line: 1}
+
+	return []*models.ScanResult{{
+		File:       &models.FileInfo{Path: path},
+		Violations: []*models.Violation{removal, overlapping, unfixable, rename},
+	}}
+}
+
+func writeFixerTestFile(t *testing.T) string {
+	t.Helper()
+	path := filepath.Join(t.TempDir(), "main.rs")
+	if err := os.WriteFile(path, []byte(fixerTestSource), 0644); err != nil {
+		t.Fatalf("Failed to write test file: %v", err)
+	}
+	return path
+}
+
+func TestApplyWritesNonOverlappingFixes(t *testing.T) {
+	path := writeFixerTestFile(t)
+
+	report, err := New(false).Apply(fixerTestResults(path))
+	if err != nil {
+		t.Fatalf("Apply failed: %v", err)
+	}
+	if report.Applied != 2 || report.Skipped != 1 || report.FilesChanged != 1 {
+		t.Errorf("Expected 2 applied and 1 skipped fix in 1 file, got %+v", report)
+	}
+	if skipped := report.Files[0].Skipped; len(skipped) != 1 || skipped[0].Line != 3 {
+		t.Errorf("Expected the overlapping rename to be skipped, got %v", skipped)
+	}
+
+	content, err := os.ReadFile(path)
+	if err != nil {
+		t.Fatalf("Failed to read fixed file: %v", err)
+	}
+	expected := `fn main() {
+    let user_count = 1;
+    println!("{}", user_count);
+}
+`
+	if string(content) != expected {
+		t.Errorf("Unexpected fixed content:\n%s", content)
+	}
+}
+
+func TestApplyDryRunReturnsDiff(t *testing.T) {
+	path := writeFixerTestFile(t)
+
+	report, err := New(true).Apply(fixerTestResults(path))
+	if err != nil {
+		t.Fatalf("Apply failed: %v", err)
+	}
+
+	content, _ := os.ReadFile(path)
+	if string(content) != fixerTestSource {
+		t.Error("Expected a dry run to leave the file unchanged")
+	}
+
+	expectedHunk := `@@ -1,6 +1,4 @@
+ fn main() {
+-    let userCount = 1;
++    let user_count = 1;
+-    println!("{}", userCount);
++    println!("{}", user_count);
+-    // let old = 2;
+-    // println!("{}", old);
+ }
+`
+	diff := report.Files[0].Diff
+	if !strings.HasPrefix(diff, "--- a/") || !strings.HasSuffix(diff, expectedHunk) {
+		t.Errorf("Unexpected diff:\n%s", diff)
+	}
+}
+
+func TestApplySkipsEditsOutsideTheFile(t *testing.T) {
+	path := writeFixerTestFile(t)
+	results := []*models.ScanResult{{
+		File: &models.FileInfo{Path: path},
+		Violations: []*models.Violation{{File: path, Line: 40, Fix: &models.Fix{
+			Edits: []models.TextEdit{{Line: 40, Column: 1, EndLine: 40, EndColumn: 2}},
+		}}},
+	}}
+
+	report, err := New(false).Apply(results)
+	if err != nil {
+		t.Fatalf("Apply failed: %v", err)
+	}
+	if report.Applied != 0 || report.Skipped != 1 {
+		t.Errorf("Expected the stale fix to be skipped, got %+v", report)
+	}
+}
@@ -0,0 +1,282 @@
+// Package fpreport bundles suspected false-positive violations into anonymized
+// reproduction files and replays them against the current detectors.
+package fpreport
+
+import (
+	"encoding/json"
+	"fmt"
+	"go/parser"
+	"go/token"
+	"os"
+	"path/filepath"
+	"regexp"
+	"strings"
+	"time"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/scanner"
+	"github.com/ericfisherdev/goclean/internal/violations"
+)
+
+// FormatVersion is the version of the bundle file format
+const FormatVersion = 1
+
+// reproBaseName replaces the original file name in bundles
+const reproBaseName = "repro"
+
+// goSnippetHeader makes partial Go snippets parseable
+const goSnippetHeader = "package repro\n\n"
+
+// goStatementsHeader wraps Go snippets cut from a function body in a function
+const goStatementsHeader = "func repro() {\n"
+
+// stringLiteralPattern matches double-quoted string literals with escapes
+var stringLiteralPattern = regexp.MustCompile(`"(?:[^"\\\n]|\\.)*"`)
+
+// Bundle is a self-contained reproduction of a reported violation
+type Bundle struct {
+	FormatVersion int                        `json:"format_version"`
+	ToolVersion   string                     `json:"tool_version"`
+	CreatedAt     time.Time                  `json:"created_at"`
+	Fingerprint   string                     `json:"fingerprint"`
+	Rule          string                     `json:"rule"`
+	Type          string                     `json:"type"`
+	Severity      string                     `json:"severity"`
+	Message       string                     `json:"message"`
+	Language      string                     `json:"language"`
+	FileName      string                     `json:"file_name"`
+	Source        string                     `json:"source"`
+	Line          int                        `json:"line"`               // Violation line within Source
+	EndLine       int                        `json:"end_line"`           // Violation end line within Source
+	Config        *violations.DetectorConfig `json:"config"`             // Effective rule configuration of the scan
+	Redacted      []string                   `json:"redacted,omitempty"` // What was anonymized
+}
+
+// Options controls how much source is bundled and what is redacted
+type Options struct {
+	ContextLines  int  // Lines of context around the violation
+	FullFile      bool // Bundle the whole file instead of the violation's lines
+	RedactStrings bool // Replace string literal contents with placeholders
+}
+
+// ReplayResult is the outcome of replaying a bundle
+type ReplayResult struct {
+	Reproduced bool                // The bundled rule fired on the bundled line
+	Matches    []*models.Violation // Violations of the bundled rule
+	Violations []*models.Violation // All violations found in the bundled source
+}
+
+// NewBundle creates an anonymized bundle for a violation found with the
+// given detector configuration
+func NewBundle(v *models.Violation, detectorConfig *violations.DetectorConfig, toolVersion string, opts Options) (*Bundle, error) {
+	content, err := os.ReadFile(v.File)
+	if err != nil {
+		return nil, fmt.Errorf("failed to read %s: %w", v.File, err)
+	}
+	lines := strings.Split(strings.TrimRight(string(content), "\n"), "\n")
+
+	endLine := v.EndLine
+	if endLine < v.Line {
+		endLine = v.Line
+	}
+	first, last := 1, len(lines)
+	if !opts.FullFile {
+		first = max(1, v.Line-opts.ContextLines)
+		last = min(len(lines), endLine+opts.ContextLines)
+	}
+	if first > last {
+		return nil, fmt.Errorf("violation lines %d-%d are outside %s", v.Line, endLine, v.File)
+	}
+
+	ext := filepath.Ext(v.File)
+	source := strings.Join(lines[first-1:last], "\n") + "\n"
+	offset := first - 1
+	if ext == ".go" && first > 1 {
+		var header string
+		source, header = goSnippet(source)
+		offset -= strings.Count(header, "\n")
+	}
+
+	bundle := &Bundle{
+		FormatVersion: FormatVersion,
+		ToolVersion:   toolVersion,
+		CreatedAt:     time.Now().UTC(),
+		Fingerprint:   v.Fingerprint,
+		Rule:          v.Rule,
+		Type:          string(v.Type),
+		Severity:      v.Severity.String(),
+		Message:       v.Message,
+		Language:      languageForExtension(ext),
+		FileName:      reproBaseName + ext,
+		Source:        source,
+		Line:          v.Line - offset,
+		EndLine:       endLine - offset,
+		Config:        withoutExternalTools(detectorConfig),
+	}
+	bundle.anonymize(v.File, opts)
+	return bundle, nil
+}
+
+// goSnippet makes a Go snippet a parseable file. Snippets of declarations
+// get a package clause; snippets cut from a function body are also wrapped
+// in a function. It returns the file and the header added before the snippet.
+func goSnippet(snippet string) (string, string) {
+	header := goSnippetHeader
+	if !parsesAsGoFile(header + snippet) {
+		if wrapped := header + goStatementsHeader; parsesAsGoFile(wrapped + snippet + "}\n") {
+			return wrapped + snippet + "}\n", wrapped
+		}
+	}
+	return header + snippet, header
+}
+
+// parsesAsGoFile reports whether src is a syntactically valid Go file
+func parsesAsGoFile(src string) bool {
+	_, err := parser.ParseFile(token.NewFileSet(), "repro.go", src, parser.SkipObjectResolution)
+	return err == nil
+}
+
+// withoutExternalTools copies a detector configuration without the plugins,
+// Clippy and cargo check, whose commands and paths stay on the reporter's
+// machine and must not run when someone else replays the bundle
+func withoutExternalTools(detectorConfig *violations.DetectorConfig) *violations.DetectorConfig {
+	if detectorConfig == nil {
+		return violations.DefaultDetectorConfig()
+	}
+	bundled := *detectorConfig
+	bundled.Plugins = nil
+	bundled.ClippyConfig = nil
+	bundled.ShowReasons = false
+	bundled.Verbose = false
+	if detectorConfig.RustConfig != nil {
+		rust := *detectorConfig.RustConfig
+		rust.CargoCheck = false
+		bundled.RustConfig = &rust
+	}
+	return &bundled
+}
+
+// anonymize removes paths, user names and optionally string contents
+func (b *Bundle) anonymize(originalPath string, opts Options) {
+	replacements := []string{}
+	if abs, err := filepath.Abs(originalPath); err == nil {
+		replacements = append(replacements, abs, filepath.ToSlash(abs))
+	}
+	replacements = append(replacements, originalPath, filepath.ToSlash(originalPath))
+
+	for _, path := range replacements {
+		if path == "" {
+			continue
+		}
+		b.Message = strings.ReplaceAll(b.Message, path, b.FileName)
+		b.Source = strings.ReplaceAll(b.Source, path, b.FileName)
+	}
+	b.Redacted = append(b.Redacted, "file paths")
+
+	if home, err := os.UserHomeDir(); err == nil && home != "" && home != "/" {
+		b.Message = strings.ReplaceAll(b.Message, home, "~")
+		b.Source = strings.ReplaceAll(b.Source, home, "~")
+		b.Redacted = append(b.Redacted, "home directory")
+	}
+
+	if opts.RedactStrings {
+		b.Source = stringLiteralPattern.ReplaceAllStringFunc(b.Source, redactLiteral)
+		b.Redacted = append(b.Redacted, "string literals")
+	}
+}
+
+// redactLiteral keeps a string literal's length but hides its contents
+func redactLiteral(literal string) string {
+	if len(literal) <= 2 {
+		return literal
+	}
+	return `"` + strings.Repeat("x", len(literal)-2) + `"`
+}
+
+// Save writes the bundle as indented JSON
+func (b *Bundle) Save(path string) error {
+	data, err := json.MarshalIndent(b, "", "  ")
+	if err != nil {
+		return fmt.Errorf("failed to marshal bundle: %w", err)
+	}
+	if dir := filepath.Dir(path); dir != "." {
+		if err := os.MkdirAll(dir, 0755); err != nil {
+			return fmt.Errorf("failed to create bundle directory: %w", err)
+		}
+	}
+	if err := os.WriteFile(path, data, 0644); err != nil {
+		return fmt.Errorf("failed to write bundle: %w", err)
+	}
+	return nil
+}
+
+// Load reads a bundle written by Save
+func Load(path string) (*Bundle, error) {
+	data, err := os.ReadFile(path)
+	if err != nil {
+		return nil, fmt.Errorf("failed to read bundle: %w", err)
+	}
+	var bundle Bundle
+	if err := json.Unmarshal(data, &bundle); err != nil {
+		return nil, fmt.Errorf("failed to parse bundle: %w", err)
+	}
+	if bundle.FormatVersion > FormatVersion {
+		return nil, fmt.Errorf("bundle format version %d is newer than supported version %d", bundle.FormatVersion, FormatVersion)
+	}
+	if bundle.FileName == "" || filepath.Base(bundle.FileName) != bundle.FileName {
+		return nil, fmt.Errorf("bundle has invalid file name %q", bundle.FileName)
+	}
+	bundle.Config = withoutExternalTools(bundle.Config)
+	return &bundle, nil
+}
+
+// Replay scans the bundled source with the bundled detector configuration
+func Replay(bundle *Bundle) (*ReplayResult, error) {
+	dir, err := os.MkdirTemp("", "goclean-replay-")
+	if err != nil {
+		return nil, fmt.Errorf("failed to create replay directory: %w", err)
+	}
+	defer os.RemoveAll(dir)
+
+	if err := os.WriteFile(filepath.Join(dir, bundle.FileName), []byte(bundle.Source), 0644); err != nil {
+		return nil, fmt.Errorf("failed to write replay source: %w", err)
+	}
+
+	engine := scanner.NewEngineWithConfig([]string{dir}, nil, []string{filepath.Ext(bundle.FileName)},
+		false, false, bundle.Config.AggressiveMode, nil)
+	engine.SetViolationDetectorConfig(bundle.Config)
+
+	_, results, err := engine.Scan()
+	if err != nil {
+		return nil, fmt.Errorf("replay scan failed: %w", err)
+	}
+
+	result := &ReplayResult{}
+	for _, scanResult := range results {
+		for _, violation := range scanResult.Violations {
+			violation.File = bundle.FileName
+			violation.Fingerprint = models.ComputeFingerprint(violation)
+			result.Violations = append(result.Violations, violation)
+			if violation.Rule != bundle.Rule {
+				continue
+			}
+			result.Matches = append(result.Matches, violation)
+			if violation.Line == bundle.Line {
+				result.Reproduced = true
+			}
+		}
+	}
+	return result, nil
+}
+
+// languageForExtension names the language of a source file extension
+func languageForExtension(ext string) string {
+	switch ext {
+	case ".go":
+		return "Go"
+	case ".rs":
+		return "Rust"
+	default:
+		return strings.TrimPrefix(ext, ".")
+	}
+}
@@ -0,0 +1,150 @@
+package fpreport
+
+import (
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/violations"
+)
+
+const fpTestSource = `package sample
+
+import "fmt"
+
+// Greeting builds a greeting
+func Greeting(name string) string {
+	return fmt.Sprint("hello ", name)
+}
+`
+
+func writeFPTestFile(t *testing.T) string {
+	t.Helper()
+	path := filepath.Join(t.TempDir(), "greeting.go")
+	if err := os.WriteFile(path, []byte(fpTestSource), 0644); err != nil {
+		t.Fatalf("Failed to write test source: %v", err)
+	}
+	return path
+}
+
+func fpTestViolation(path string) *models.Violation {
+	v := &models.Violation{
+		Type:     models.ViolationTypeFunctionLength,
+		Severity: models.SeverityLow,
+		Rule:     "function-length",
+		Message:  "Function 'Greeting' in " + path + " is too long (3 lines, max: 1)",
+		File:     path,
+		Line:     6,
+		EndLine:  8,
+	}
+	v.Fingerprint = models.ComputeFingerprint(v)
+	return v
+}
+
+func TestNewBundle(t *testing.T) {
+	path := writeFPTestFile(t)
+	detectorConfig := violations.DefaultDetectorConfig()
+	detectorConfig.MaxFunctionLines = 1
+	detectorConfig.RustConfig.CargoCheck = true
+	detectorConfig.Plugins = []violations.PluginSpec{{Name: "local", Command: "/home/me/bin/plugin"}}
+
+	bundle, err := NewBundle(fpTestViolation(path), detectorConfig, "1.2.3", Options{RedactStrings: true})
+	if err != nil {
+		t.Fatalf("Failed to create bundle: %v", err)
+	}
+
+	if bundle.FileName != "repro.go" || bundle.Language != "Go" {
+		t.Errorf("Expected anonymized Go file name, got %q (%s)", bundle.FileName, bundle.Language)
+	}
+	if !strings.HasPrefix(bundle.Source, "package repro\n\nfunc Greeting") {
+		t.Errorf("Expected snippet with package header, got %q", bundle.Source)
+	}
+	if bundle.Line != 3 || bundle.EndLine != 5 {
+		t.Errorf("Expected snippet lines 3-5, got %d-%d", bundle.Line, bundle.EndLine)
+	}
+	if strings.Contains(bundle.Message, path) || !strings.Contains(bundle.Message, "repro.go") {
+		t.Errorf("Expected file path to be anonymized in message, got %q", bundle.Message)
+	}
+	if strings.Contains(bundle.Source, "hello") || !strings.Contains(bundle.Source, `"xxxxxx"`) {
+		t.Errorf("Expected string literals to be redacted, got %q", bundle.Source)
+	}
+	if bundle.Config.MaxFunctionLines != 1 || bundle.ToolVersion != "1.2.3" {
+		t.Errorf("Unexpected bundle metadata %+v", bundle.Config)
+	}
+	if len(bundle.Config.Plugins) != 0 || bundle.Config.RustConfig.CargoCheck || !detectorConfig.RustConfig.CargoCheck {
+		t.Errorf("Expected external tools to be left out of a copy of the configuration, got %+v", bundle.Config)
+	}
+}
+
+func TestNewBundleWrapsStatements(t *testing.T) {
+	path := writeFPTestFile(t)
+	v := fpTestViolation(path)
+	v.Line, v.EndLine = 7, 7
+
+	bundle, err := NewBundle(v, violations.DefaultDetectorConfig(), "1.2.3", Options{})
+	if err != nil {
+		t.Fatalf("Failed to create bundle: %v", err)
+	}
+
+	want := "package repro\n\nfunc repro() {\n\treturn fmt.Sprint(\"hello \", name)\n}\n"
+	if bundle.Source != want {
+		t.Errorf("Expected the statement to be wrapped in a function, got %q", bundle.Source)
+	}
+	if bundle.Line != 4 || bundle.EndLine != 4 {
+		t.Errorf("Expected snippet line 4, got %d-%d", bundle.Line, bundle.EndLine)
+	}
+}
+
+func TestReplay(t *testing.T) {
+	path := writeFPTestFile(t)
+	detectorConfig := violations.DefaultDetectorConfig()
+	detectorConfig.MaxFunctionLines = 1
+
+	bundle, err := NewBundle(fpTestViolation(path), detectorConfig, "1.2.3", Options{})
+	if err != nil {
+		t.Fatalf("Failed to create bundle: %v", err)
+	}
+
+	bundlePath := filepath.Join(t.TempDir(), "fp.json")
+	if err := bundle.Save(bundlePath); err != nil {
+		t.Fatalf("Failed to save bundle: %v", err)
+	}
+	loaded, err := Load(bundlePath)
+	if err != nil {
+		t.Fatalf("Failed to load bundle: %v", err)
+	}
+
+	result, err := Replay(loaded)
+	if err != nil {
+		t.Fatalf("Replay failed: %v", err)
+	}
+	if !result.Reproduced || len(result.Matches) != 1 {
+		t.Fatalf("Expected the violation to reproduce, got %+v", result)
+	}
+	if result.Matches[0].File != "repro.go" {
+		t.Errorf("Expected replayed violation in repro.go, got %s", result.Matches[0].File)
+	}
+
+	// With a relaxed threshold the rule no longer fires
+	loaded.Config.MaxFunctionLines = 50
+	result, err = Replay(loaded)
+	if err != nil {
+		t.Fatalf("Replay failed: %v", err)
+	}
+	if result.Reproduced {
+		t.Error("Expected the violation not to reproduce with a relaxed threshold")
+	}
+}
+
+func TestLoadRejectsUnsafeFileName(t *testing.T) {
+	bundlePath := filepath.Join(t.TempDir(), "fp.json")
+	content := `{"format_version": 1, "file_name": "../escape.go", "source": "package x\n"}`
+	if err := os.WriteFile(bundlePath, []byte(content), 0644); err != nil {
+		t.Fatalf("Failed to write bundle: %v", err)
+	}
+	if _, err := Load(bundlePath); err == nil {
+		t.Error("Expected bundle with a path in its file name to be rejected")
+	}
+}
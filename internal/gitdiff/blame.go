@@ -0,0 +1,116 @@
+package gitdiff
+
+import (
+	"fmt"
+	"path/filepath"
+	"sort"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+)
+
+// TodoStats counts the TODO markers AgeTodos dated and escalated
+type TodoStats struct {
+	Blamed    int // Markers on committed lines
+	Escalated int // Markers older than the maximum age
+}
+
+// Blame returns the author and time of the last commit to change each of
+// the given lines of a file. Lines not committed yet are left out.
+func Blame(path string, lines []int) (map[int]models.Blame, error) {
+	absPath, err := filepath.Abs(path)
+	if err != nil {
+		return nil, err
+	}
+
+	sorted := append([]int(nil), lines...)
+	sort.Ints(sorted)
+	args := []string{"blame", "--line-porcelain"}
+	for i, line := range sorted {
+		if line > 0 && (i == 0 || line != sorted[i-1]) {
+			args = append(args, "-L", fmt.Sprintf("%d,%d", line, line))
+		}
+	}
+	args = append(args, "--", filepath.Base(absPath))
+
+	output, err := git(filepath.Dir(absPath), args...)
+	if err != nil {
+		return nil, fmt.Errorf("git blame failed: %w", err)
+	}
+	return ParseBlame(output), nil
+}
+
+// ParseBlame reads the lines of the output of git blame --line-porcelain,
+// where every line has a header with its commit and final line number, the
+// commit details, and the content of the line after a tab
+func ParseBlame(output string) map[int]models.Blame {
+	blamed := make(map[int]models.Blame)
+	line, uncommitted := 0, false
+	var current models.Blame
+	for _, text := range strings.Split(output, "\n") {
+		switch {
+		case strings.HasPrefix(text, "\t"):
+			if line > 0 && !uncommitted {
+				blamed[line] = current
+			}
+			line, current = 0, models.Blame{}
+		case line == 0:
+			fields := strings.Fields(text)
+			if len(fields) >= 3 && len(fields[0]) >= 40 {
+				line, _ = strconv.Atoi(fields[2])
+				uncommitted = strings.Trim(fields[0], "0") == ""
+			}
+		case strings.HasPrefix(text, "author "):
+			current.Author = strings.TrimPrefix(text, "author ")
+		case strings.HasPrefix(text, "author-time "):
+			if seconds, err := strconv.ParseInt(strings.TrimPrefix(text, "author-time "), 10, 64); err == nil {
+				current.Time = time.Unix(seconds, 0).UTC()
+			}
+		}
+	}
+	return blamed
+}
+
+// AgeTodos adds the author and age of each TODO marker from git blame, and
+// raises the severity of markers older than maxAgeDays by one level. A
+// maxAgeDays of 0 never escalates. Files outside a git repository and lines
+// not committed yet are left as they are.
+func AgeTodos(results []*models.ScanResult, maxAgeDays int, now time.Time) TodoStats {
+	var stats TodoStats
+	for _, result := range results {
+		var todos []*models.Violation
+		var lines []int
+		for _, violation := range result.Violations {
+			if violation.Type == models.ViolationTypeTodo {
+				todos = append(todos, violation)
+				lines = append(lines, violation.Line)
+			}
+		}
+		if len(todos) == 0 || result.File == nil {
+			continue
+		}
+		blamed, err := Blame(result.File.Path, lines)
+		if err != nil {
+			continue
+		}
+
+		for _, violation := range todos {
+			blame, found := blamed[violation.Line]
+			if !found {
+				continue
+			}
+			blame.AgeDays = max(int(now.Sub(blame.Time).Hours()/24), 0)
+			violation.Blame = &blame
+			violation.Description = fmt.Sprintf("Added by %s %d days ago", blame.Author, blame.AgeDays)
+			stats.Blamed++
+
+			if maxAgeDays > 0 && blame.AgeDays > maxAgeDays && violation.Severity < models.SeverityCritical {
+				violation.Severity++
+				stats.Escalated++
+			}
+		}
+	}
+	return stats
+}
@@ -0,0 +1,89 @@
+package gitdiff
+
+import (
+	"os"
+	"os/exec"
+	"path/filepath"
+	"testing"
+	"time"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+)
+
+func TestParseBlame(t *testing.T) {
+	output := "8f2b6c1d9e0a4b3c2d1e0f9a8b7c6d5e4f3a2b1c 3 3 1\n" +
+		"author Jane Doe\n" +
+		"author-mail <jane@example.com>\n" +
+		"author-time 1577836800\n" +
+		"author-tz +0000\n" +
+		"summary initial\n" +
+		"filename main.go\n" +
+		"\t// TODO: handle errors\n" +
+		"0000000000000000000000000000000000000000 7 7 1\n" +
+		"author Not Committed Yet\n" +
+		"author-time 1760000000\n" +
+		"filename main.go\n" +
+		"\t// FIXME: flaky\n"
+
+	blamed := ParseBlame(output)
+	if len(blamed) != 1 {
+		t.Fatalf("Expected only the committed line, got %+v", blamed)
+	}
+	if blame := blamed[3]; blame.Author != "Jane Doe" || !blame.Time.Equal(time.Date(2020, 1, 1, 0, 0, 0, 0, time.UTC)) {
+		t.Errorf("Unexpected blame of line 3: %+v", blame)
+	}
+}
+
+func TestAgeTodos(t *testing.T) {
+	if _, err := exec.LookPath("git"); err != nil {
+		t.Skip("git is not installed")
+	}
+
+	root := t.TempDir()
+	runGit := func(date string, args ...string) {
+		t.Helper()
+		cmd := exec.Command("git", append([]string{"-c", "user.name=GoClean", "-c", "user.email=goclean@example.com"}, args...)...)
+		cmd.Dir = root
+		cmd.Env = append(os.Environ(), "GIT_AUTHOR_DATE="+date, "GIT_COMMITTER_DATE="+date)
+		if output, err := cmd.CombinedOutput(); err != nil {
+			t.Fatalf("git %v failed: %v\n%s", args, err, output)
+		}
+	}
+	path := filepath.Join(root, "main.go")
+	writeFile := func(content string) {
+		t.Helper()
+		if err := os.WriteFile(path, []byte(content), 0644); err != nil {
+			t.Fatalf("Failed to write main.go: %v", err)
+		}
+	}
+
+	runGit("2020-01-01T00:00:00Z", "init", "--quiet")
+	writeFile("package main\n\n// TODO: handle errors\nfunc main() {}\n")
+	runGit("2020-01-01T00:00:00Z", "add", "main.go")
+	runGit("2020-01-01T00:00:00Z", "commit", "--quiet", "-m", "initial")
+	writeFile("package main\n\n// TODO: handle errors\nfunc main() {}\n\n// TODO: add flags\n")
+	runGit("2026-10-01T00:00:00Z", "commit", "--quiet", "-am", "flags")
+	writeFile("package main\n\n// TODO: handle errors\nfunc main() {}\n\n// TODO: add flags\n// HACK: uncommitted\n")
+
+	old := &models.Violation{Type: models.ViolationTypeTodo, Severity: models.SeverityLow, File: path, Line: 3}
+	fresh := &models.Violation{Type: models.ViolationTypeTodo, Severity: models.SeverityLow, File: path, Line: 6}
+	uncommitted := &models.Violation{Type: models.ViolationTypeTodo, Severity: models.SeverityMedium, File: path, Line: 7}
+	other := &models.Violation{Type: models.ViolationTypeMagicNumber, Severity: models.SeverityLow, File: path, Line: 4}
+	results := []*models.ScanResult{{File: &models.FileInfo{Path: path}, Violations: []*models.Violation{old, other, fresh, uncommitted}}}
+
+	now := time.Date(2026, 10, 16, 0, 0, 0, 0, time.UTC)
+	stats := AgeTodos(results, 365, now)
+	if stats.Blamed != 2 || stats.Escalated != 1 {
+		t.Errorf("Expected 2 markers dated and 1 escalated, got %+v", stats)
+	}
+	if old.Blame == nil || old.Blame.Author != "GoClean" || old.Blame.AgeDays != 2480 || old.Severity != models.SeverityMedium ||
+		old.Description != "Added by GoClean 2480 days ago" {
+		t.Errorf("Expected the old TODO to be dated and escalated, got %+v with blame %+v", old, old.Blame)
+	}
+	if fresh.Blame == nil || fresh.Blame.AgeDays != 15 || fresh.Severity != models.SeverityLow {
+		t.Errorf("Expected the fresh TODO to keep its severity, got %+v", fresh)
+	}
+	if uncommitted.Blame != nil || uncommitted.Severity != models.SeverityMedium || other.Blame != nil {
+		t.Error("Expected uncommitted markers and other violations to be left alone")
+	}
+}
@@ -0,0 +1,262 @@
+// Package gitdiff computes the lines changed since a git revision so that
+// scans can report only the violations a change introduces, checks out
+// revisions so that they can be scanned and compared, and blames lines to
+// date TODO markers.
+package gitdiff
+
+import (
+	"bufio"
+	"fmt"
+	"math"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"regexp"
+	"strconv"
+	"strings"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+)
+
+// hunkHeaderRegex matches the header of a hunk and captures the range of new lines
+var hunkHeaderRegex = regexp.MustCompile(`^@@ -\d+(?:,\d+)? \+(\d+)(?:,(\d+))? @@`)
+
+// LineRange is an inclusive range of 1-based lines
+type LineRange struct {
+	Start int
+	End   int
+}
+
+// Changes holds the lines added or modified since a base revision
+type Changes struct {
+	root  string                 // Repository root
+	files map[string][]LineRange // Changed lines by slash path relative to the root
+}
+
+// FilterStats summarizes how many violations a diff filter kept
+type FilterStats struct {
+	Kept   int // Violations on changed lines
+	Hidden int // Violations in unchanged code
+}
+
+// Load computes the changes between the merge base of base and HEAD and the
+// working tree of the repository containing dir. Comparing with the merge
+// base ignores commits made on base after the current branch was created.
+// Untracked files count as entirely new.
+func Load(dir, base string) (*Changes, error) {
+	root, err := git(dir, "rev-parse", "--show-toplevel")
+	if err != nil {
+		return nil, fmt.Errorf("not a git repository: %w", err)
+	}
+	root = strings.TrimSpace(root)
+
+	if _, err := git(root, "rev-parse", "--verify", "--quiet", base+"^{commit}"); err != nil {
+		return nil, fmt.Errorf("unknown revision %q", base)
+	}
+	mergeBase, err := git(root, "merge-base", base, "HEAD")
+	if err != nil {
+		return nil, fmt.Errorf("no common ancestor of %s and HEAD: %w", base, err)
+	}
+
+	diff, err := git(root, "-c", "core.quotePath=false", "diff", "--unified=0", "--no-color", "--no-ext-diff",
+		"--find-renames", "--src-prefix=a/", "--dst-prefix=b/", strings.TrimSpace(mergeBase), "--")
+	if err != nil {
+		return nil, fmt.Errorf("git diff failed: %w", err)
+	}
+	changes := Parse(root, diff)
+
+	untracked, err := git(root, "ls-files", "--others", "--exclude-standard", "-z")
+	if err != nil {
+		return nil, fmt.Errorf("failed to list untracked files: %w", err)
+	}
+	for _, path := range strings.Split(untracked, "\x00") {
+		if path != "" {
+			changes.files[path] = []LineRange{{Start: 1, End: math.MaxInt}}
+		}
+	}
+	return changes, nil
+}
+
+// Parse reads the changed lines from the output of git diff --unified=0.
+// Deleted lines leave no trace, as there is no code left to report on.
+func Parse(root, diff string) *Changes {
+	changes := &Changes{root: root, files: make(map[string][]LineRange)}
+
+	var current string
+	scanner := bufio.NewScanner(strings.NewReader(diff))
+	scanner.Buffer(make([]byte, 0, 64*1024), 1024*1024)
+	for scanner.Scan() {
+		line := scanner.Text()
+		switch {
+		case strings.HasPrefix(line, "+++ "):
+			current = ""
+			if path, ok := strings.CutPrefix(line, "+++ b/"); ok {
+				current = path
+			}
+		case current != "" && strings.HasPrefix(line, "@@ "):
+			match := hunkHeaderRegex.FindStringSubmatch(line)
+			if match == nil {
+				continue
+			}
+			start, _ := strconv.Atoi(match[1])
+			count := 1
+			if match[2] != "" {
+				count, _ = strconv.Atoi(match[2])
+			}
+			if count > 0 {
+				changes.files[current] = append(changes.files[current], LineRange{Start: start, End: start + count - 1})
+			}
+		}
+	}
+	return changes
+}
+
+// Files returns the number of files with changed lines
+func (c *Changes) Files() int {
+	return len(c.files)
+}
+
+// Touches reports whether the diff changes any line from start to end of
+// the file. A line of zero or less stands for the whole file.
+func (c *Changes) Touches(path string, start, end int) bool {
+	ranges := c.files[c.relativePath(path)]
+	if len(ranges) == 0 {
+		return false
+	}
+	if start <= 0 {
+		return true
+	}
+	end = max(end, start)
+	for _, r := range ranges {
+		if start <= r.End && r.Start <= end {
+			return true
+		}
+	}
+	return false
+}
+
+// Filter removes violations the diff does not touch from the results and
+// updates the summary counts
+func (c *Changes) Filter(summary *models.ScanSummary, results []*models.ScanResult) FilterStats {
+	var stats FilterStats
+	for _, result := range results {
+		kept := result.Violations[:0]
+		for _, violation := range result.Violations {
+			if c.Touches(violation.File, violation.Line, violation.EndLine) {
+				kept = append(kept, violation)
+				stats.Kept++
+				continue
+			}
+			stats.Hidden++
+			if summary != nil {
+				summary.RemoveViolation(violation)
+			}
+		}
+		result.Violations = kept
+	}
+	if summary != nil {
+		summary.OutsideDiff = stats.Hidden
+	}
+	return stats
+}
+
+// relativePath converts a scanned file path into a slash path relative to the repository root
+func (c *Changes) relativePath(path string) string {
+	if absPath, err := filepath.Abs(path); err == nil {
+		path = absPath
+		if resolved, err := filepath.EvalSymlinks(absPath); err == nil {
+			path = resolved
+		}
+	}
+	if rel, err := filepath.Rel(c.root, path); err == nil {
+		path = rel
+	}
+	return filepath.ToSlash(path)
+}
+
+// Revision returns the commit checked out in the repository containing dir
+// and whether its working tree has uncommitted or untracked changes
+func Revision(dir string) (string, bool, error) {
+	sha, err := git(dir, "rev-parse", "HEAD")
+	if err != nil {
+		return "", false, err
+	}
+	status, err := git(dir, "status", "--porcelain")
+	if err != nil {
+		return "", false, err
+	}
+	return strings.TrimSpace(sha), strings.TrimSpace(status) != "", nil
+}
+
+// Worktree is a revision checked out in a temporary directory, so that it
+// can be scanned without touching the working tree
+type Worktree struct {
+	Root string // Root of the checkout
+	Dir  string // Directory of the checkout matching the directory it was created from
+	repo string
+}
+
+// CheckoutWorktree checks out revision in a detached temporary worktree of
+// the repository containing dir. Call Remove once done with it.
+func CheckoutWorktree(dir, revision string) (*Worktree, error) {
+	root, err := git(dir, "rev-parse", "--show-toplevel")
+	if err != nil {
+		return nil, fmt.Errorf("not a git repository: %w", err)
+	}
+	root = strings.TrimSpace(root)
+	prefix, err := git(dir, "rev-parse", "--show-prefix")
+	if err != nil {
+		return nil, err
+	}
+	if _, err := git(root, "rev-parse", "--verify", "--quiet", revision+"^{commit}"); err != nil {
+		return nil, fmt.Errorf("unknown revision %q", revision)
+	}
+
+	checkout, err := os.MkdirTemp("", "goclean-worktree-")
+	if err != nil {
+		return nil, fmt.Errorf("failed to create worktree directory: %w", err)
+	}
+	if _, err := git(root, "worktree", "add", "--detach", "--quiet", checkout, revision); err != nil {
+		os.RemoveAll(checkout)
+		return nil, fmt.Errorf("failed to check out %s: %w", revision, err)
+	}
+	return &Worktree{
+		Root: checkout,
+		Dir:  filepath.Join(checkout, filepath.FromSlash(strings.TrimSpace(prefix))),
+		repo: root,
+	}, nil
+}
+
+// Remove deletes the checkout and unregisters it from the repository
+func (w *Worktree) Remove() error {
+	_, err := git(w.repo, "worktree", "remove", "--force", w.Root)
+	if removeErr := os.RemoveAll(w.Root); err == nil {
+		err = removeErr
+	}
+	return err
+}
+
+// git runs a git command in dir and returns its output
+func git(dir string, args ...string) (string, error) {
+	cmd := exec.Command("git", args...)
+	cmd.Dir = dir
+	output, err := cmd.Output()
+	if err != nil {
+		if exitErr, ok := err.(*exec.ExitError); ok && len(exitErr.Stderr) > 0 {
+			return "", fmt.Errorf("%s", strings.TrimSpace(string(exitErr.Stderr)))
+		}
+		return "", err
+	}
+	return string(output), nil
+}
+
+// RepositoryDir returns the directory to look up the repository from for a scan path
+func RepositoryDir(scanPath string) string {
+	if info, err := os.Stat(scanPath); err == nil && !info.IsDir() {
+		return filepath.Dir(scanPath)
+	}
+	if scanPath == "" {
+		return "."
+	}
+	return scanPath
+}
@@ -0,0 +1,197 @@
+package gitdiff
+
+import (
+	"os"
+	"os/exec"
+	"path/filepath"
+	"testing"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+)
+
+const testDiff = `diff --git a/src/a.go b/src/a.go
+index 83db48f..bf269f4 100644
+--- a/src/a.go
++++ b/src/a.go
+@@ -3,0 +4,2 @@ func A() {
++	x := 1
++	y := 2
+@@ -20 +22 @@ func B() {
+-	return nil
++	return err
+@@ -30,2 +31,0 @@ func C() {
+-	a()
+-	b()
+diff --git a/src/old.go b/src/old.go
+deleted file mode 100644
+--- a/src/old.go
++++ /dev/null
+@@ -1,3 +0,0 @@
+-package src
+`
+
+func TestParse(t *testing.T) {
+	root := t.TempDir()
+	changes := Parse(root, testDiff)
+
+	if changes.Files() != 1 {
+		t.Fatalf("Expected changes in 1 file, got %d", changes.Files())
+	}
+
+	path := filepath.Join(root, "src", "a.go")
+	tests := []struct {
+		start, end int
+		want       bool
+	}{
+		{4, 0, true},
+		{5, 0, true},
+		{6, 0, false},
+		{22, 0, true},
+		{31, 0, false}, // Only deleted lines
+		{1, 4, true},   // A span overlapping added lines
+		{10, 21, false},
+		{0, 0, true}, // File-level violations in a changed file
+	}
+	for _, tt := range tests {
+		if got := changes.Touches(path, tt.start, tt.end); got != tt.want {
+			t.Errorf("Touches(%d, %d) = %v, want %v", tt.start, tt.end, got, tt.want)
+		}
+	}
+	if changes.Touches(filepath.Join(root, "src", "b.go"), 0, 0) {
+		t.Error("Expected an unchanged file not to be touched")
+	}
+}
+
+func TestFilter(t *testing.T) {
+	root := t.TempDir()
+	changes := Parse(root, testDiff)
+
+	path := filepath.Join(root, "src", "a.go")
+	results := []*models.ScanResult{{
+		File: &models.FileInfo{Path: path},
+		Violations: []*models.Violation{
+			{Type: models.ViolationTypeFunctionLength, File: path, Line: 1, EndLine: 10},
+			{Type: models.ViolationTypeNaming, File: path, Line: 12},
+			{Type: models.ViolationTypeNaming, File: path, Line: 22},
+		},
+	}}
+	summary := &models.ScanSummary{
+		TotalViolations: 3,
+		ViolationsByType: map[string]int{
+			string(models.ViolationTypeFunctionLength): 1,
+			string(models.ViolationTypeNaming):         2,
+		},
+	}
+
+	stats := changes.Filter(summary, results)
+	if stats.Kept != 2 || stats.Hidden != 1 {
+		t.Errorf("Expected 2 kept and 1 hidden, got %+v", stats)
+	}
+	if len(results[0].Violations) != 2 || results[0].Violations[1].Line != 22 {
+		t.Errorf("Expected the violations on lines 1 and 22 to be kept, got %d", len(results[0].Violations))
+	}
+	if summary.TotalViolations != 2 || summary.ViolationsByType[string(models.ViolationTypeNaming)] != 1 || summary.OutsideDiff != 1 {
+		t.Errorf("Unexpected summary after filtering: %+v", summary)
+	}
+}
+
+func TestLoad(t *testing.T) {
+	if _, err := exec.LookPath("git"); err != nil {
+		t.Skip("git is not installed")
+	}
+
+	root := t.TempDir()
+	runGit := func(args ...string) {
+		t.Helper()
+		cmd := exec.Command("git", append([]string{"-c", "user.name=GoClean", "-c", "user.email=goclean@example.com"}, args...)...)
+		cmd.Dir = root
+		if output, err := cmd.CombinedOutput(); err != nil {
+			t.Fatalf("git %v failed: %v\n%s", args, err, output)
+		}
+	}
+	writeFile := func(name, content string) string {
+		t.Helper()
+		path := filepath.Join(root, name)
+		if err := os.WriteFile(path, []byte(content), 0644); err != nil {
+			t.Fatalf("Failed to write %s: %v", name, err)
+		}
+		return path
+	}
+
+	runGit("init", "--quiet")
+	mainFile := writeFile("main.go", "package main\n\nfunc main() {\n}\n")
+	runGit("add", "main.go")
+	runGit("commit", "--quiet", "-m", "initial")
+
+	writeFile("main.go", "package main\n\nfunc main() {\n\tprintln(1)\n}\n")
+	added := writeFile("added.go", "package main\n")
+
+	changes, err := Load(root, "HEAD")
+	if err != nil {
+		t.Fatalf("Load failed: %v", err)
+	}
+	if !changes.Touches(mainFile, 4, 0) || changes.Touches(mainFile, 1, 3) {
+		t.Error("Expected only line 4 of main.go to be changed")
+	}
+	if !changes.Touches(added, 1, 0) {
+		t.Error("Expected the untracked file to be changed")
+	}
+
+	if _, err := Load(root, "no-such-branch"); err == nil {
+		t.Error("Expected an error for an unknown revision")
+	}
+
+	sha, dirty, err := Revision(root)
+	if err != nil || len(sha) != 40 || !dirty {
+		t.Errorf("Expected a dirty checkout of a commit, got %q, %v, %v", sha, dirty, err)
+	}
+}
+
+func TestCheckoutWorktree(t *testing.T) {
+	if _, err := exec.LookPath("git"); err != nil {
+		t.Skip("git is not installed")
+	}
+
+	root := t.TempDir()
+	runGit := func(args ...string) {
+		t.Helper()
+		cmd := exec.Command("git", append([]string{"-c", "user.name=GoClean", "-c", "user.email=goclean@example.com"}, args...)...)
+		cmd.Dir = root
+		if output, err := cmd.CombinedOutput(); err != nil {
+			t.Fatalf("git %v failed: %v\n%s", args, err, output)
+		}
+	}
+	sub := filepath.Join(root, "cmd")
+	if err := os.MkdirAll(sub, 0755); err != nil {
+		t.Fatal(err)
+	}
+	if err := os.WriteFile(filepath.Join(sub, "main.go"), []byte("package main\n"), 0644); err != nil {
+		t.Fatal(err)
+	}
+	runGit("init", "--quiet")
+	runGit("add", ".")
+	runGit("commit", "--quiet", "-m", "initial")
+	if err := os.WriteFile(filepath.Join(sub, "main.go"), []byte("package changed\n"), 0644); err != nil {
+		t.Fatal(err)
+	}
+
+	worktree, err := CheckoutWorktree(sub, "HEAD")
+	if err != nil {
+		t.Fatalf("CheckoutWorktree failed: %v", err)
+	}
+	content, err := os.ReadFile(filepath.Join(worktree.Dir, "main.go"))
+	if err != nil || string(content) != "package main\n" {
+		t.Errorf("Expected the committed file in the checkout subdirectory, got %q, %v", content, err)
+	}
+
+	if err := worktree.Remove(); err != nil {
+		t.Errorf("Remove failed: %v", err)
+	}
+	if _, err := os.Stat(worktree.Root); !os.IsNotExist(err) {
+		t.Errorf("Expected the checkout to be deleted, got %v", err)
+	}
+
+	if _, err := CheckoutWorktree(root, "no-such-branch"); err == nil {
+		t.Error("Expected an error for an unknown revision")
+	}
+}
@@ -0,0 +1,234 @@
+// Package health scores functions by combining their metrics with the
+// violations reported inside them, for editor overlays such as
+// "health: 42/100", and files by combining the scores of their functions.
+package health
+
+import (
+	"math"
+	"sort"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/types"
+	"github.com/ericfisherdev/goclean/internal/violations"
+)
+
+// Scoring weights. Each metric costs up to metricWeight points and the
+// violations up to maxViolationPenalty, out of 100.
+const (
+	metricWeight        = 15.0
+	maxViolationPenalty = 40.0
+)
+
+// violationPenalties is the cost of one violation by severity
+var violationPenalties = map[models.Severity]float64{
+	models.SeverityInfo:     1,
+	models.SeverityLow:      2,
+	models.SeverityMedium:   5,
+	models.SeverityHigh:     10,
+	models.SeverityCritical: 20,
+}
+
+// Span locates a function in its file. Lines and columns are 1-based.
+type Span struct {
+	StartLine   int `json:"start_line"`
+	StartColumn int `json:"start_column"`
+	EndLine     int `json:"end_line"`
+	EndColumn   int `json:"end_column"`
+}
+
+// FunctionHealth is the health of one function
+type FunctionHealth struct {
+	File                 string `json:"file"`
+	Name                 string `json:"name"`
+	Span                 Span   `json:"span"`
+	Score                int    `json:"score"` // 0 (worst) to 100 (best)
+	Lines                int    `json:"lines"`
+	CyclomaticComplexity int    `json:"cyclomatic_complexity"`
+	CognitiveComplexity  int    `json:"cognitive_complexity"`
+	Parameters           int    `json:"parameters"`
+	Violations           int    `json:"violations"` // Violations reported inside the function
+}
+
+// Scorer computes function health relative to the configured thresholds
+type Scorer struct {
+	config *violations.DetectorConfig
+}
+
+// NewScorer creates a scorer using the thresholds of the detector configuration
+func NewScorer(config *violations.DetectorConfig) *Scorer {
+	if config == nil {
+		config = violations.DefaultDetectorConfig()
+	}
+	return &Scorer{config: config}
+}
+
+// Functions returns the health of every function of a scanned file, in
+// source order. Each violation counts against the innermost function
+// containing its line.
+func (s *Scorer) Functions(result *models.ScanResult) []*FunctionHealth {
+	if result == nil || result.File == nil {
+		return nil
+	}
+
+	var functions []*FunctionHealth
+	if info, ok := result.ASTInfo.(*types.GoASTInfo); ok && info != nil {
+		functions = s.goFunctions(result.File.Path, info)
+	}
+	if info, ok := result.RustASTInfo.(*types.RustASTInfo); ok && info != nil {
+		functions = s.rustFunctions(result.File.Path, info)
+	}
+
+	penalties := make([]float64, len(functions))
+	for _, violation := range result.Violations {
+		if i := innermost(functions, violation.Line); i >= 0 {
+			functions[i].Violations++
+			penalties[i] += violationPenalties[violation.Severity]
+		}
+	}
+	for i, fn := range functions {
+		fn.Score = s.score(fn, penalties[i])
+	}
+
+	sort.SliceStable(functions, func(i, j int) bool {
+		return functions[i].Span.StartLine < functions[j].Span.StartLine
+	})
+	return functions
+}
+
+// Rank returns the health of the functions of all results, least healthy first
+func (s *Scorer) Rank(results []*models.ScanResult) []*FunctionHealth {
+	var ranked []*FunctionHealth
+	for _, result := range results {
+		ranked = append(ranked, s.Functions(result)...)
+	}
+	sort.SliceStable(ranked, func(i, j int) bool {
+		return ranked[i].Score < ranked[j].Score
+	})
+	return ranked
+}
+
+// File returns the health of a scanned file: the mean score of its
+// functions weighted by their length, less the violations reported outside
+// any function. A file without functions starts from 100.
+func (s *Scorer) File(result *models.ScanResult) *models.FileHealth {
+	if result == nil || result.File == nil {
+		return nil
+	}
+
+	functions := s.Functions(result)
+	score, weight := 0.0, 0.0
+	for _, fn := range functions {
+		lines := float64(max(fn.Lines, 1))
+		score += float64(fn.Score) * lines
+		weight += lines
+	}
+	if weight == 0 {
+		score, weight = 100, 1
+	}
+
+	outside := 0.0
+	for _, violation := range result.Violations {
+		if innermost(functions, violation.Line) < 0 {
+			outside += violationPenalties[violation.Severity]
+		}
+	}
+	return &models.FileHealth{
+		File:       result.File.Path,
+		Score:      int(math.Max(0, math.Round(score/weight-math.Min(outside, maxViolationPenalty)))),
+		Violations: len(result.Violations),
+	}
+}
+
+// Files returns the health of every scanned file, ordered by file
+func (s *Scorer) Files(results []*models.ScanResult) []*models.FileHealth {
+	var files []*models.FileHealth
+	for _, result := range results {
+		if file := s.File(result); file != nil {
+			files = append(files, file)
+		}
+	}
+	sort.SliceStable(files, func(i, j int) bool {
+		return files[i].File < files[j].File
+	})
+	return files
+}
+
+func (s *Scorer) goFunctions(path string, info *types.GoASTInfo) []*FunctionHealth {
+	functions := make([]*FunctionHealth, 0, len(info.Functions))
+	for _, fn := range info.Functions {
+		if fn == nil {
+			continue
+		}
+		cognitive := 0
+		if fn.ASTNode != nil {
+			cognitive = violations.GoCognitiveComplexity(fn.ASTNode)
+		}
+		functions = append(functions, &FunctionHealth{
+			File:                 path,
+			Name:                 fn.Name,
+			Span:                 Span{StartLine: fn.StartLine, StartColumn: fn.StartColumn, EndLine: fn.EndLine, EndColumn: fn.EndColumn},
+			Lines:                fn.LineCount,
+			CyclomaticComplexity: fn.Complexity,
+			CognitiveComplexity:  cognitive,
+			Parameters:           len(fn.Parameters),
+		})
+	}
+	return functions
+}
+
+func (s *Scorer) rustFunctions(path string, info *types.RustASTInfo) []*FunctionHealth {
+	functions := make([]*FunctionHealth, 0, len(info.Functions))
+	for _, fn := range info.Functions {
+		if fn == nil {
+			continue
+		}
+		cognitive := 0
+		if fn.Node != nil {
+			cognitive = violations.RustCognitiveComplexity(fn.Node)
+		}
+		functions = append(functions, &FunctionHealth{
+			File:                 path,
+			Name:                 fn.Name,
+			Span:                 Span{StartLine: fn.StartLine, StartColumn: fn.StartColumn, EndLine: fn.EndLine, EndColumn: fn.EndColumn},
+			Lines:                fn.LineCount,
+			CyclomaticComplexity: fn.Complexity,
+			CognitiveComplexity:  cognitive,
+			Parameters:           len(fn.Parameters),
+		})
+	}
+	return functions
+}
+
+// score combines the metric and violation penalties into a 0-100 score
+func (s *Scorer) score(fn *FunctionHealth, violationPenalty float64) int {
+	penalty := metricPenalty(fn.Lines, s.config.MaxFunctionLines) +
+		metricPenalty(fn.CyclomaticComplexity, s.config.MaxCyclomaticComplexity) +
+		metricPenalty(fn.CognitiveComplexity, s.config.MaxCognitiveComplexity) +
+		metricPenalty(fn.Parameters, s.config.MaxParameters) +
+		math.Min(violationPenalty, maxViolationPenalty)
+	return int(math.Max(0, math.Round(100-penalty)))
+}
+
+// metricPenalty grows linearly from nothing at half the threshold to the
+// full metric weight at twice the threshold
+func metricPenalty(value, threshold int) float64 {
+	if threshold <= 0 {
+		return 0
+	}
+	ratio := (float64(value)/float64(threshold) - 0.5) / 1.5
+	return metricWeight * math.Max(0, math.Min(1, ratio))
+}
+
+// innermost returns the index of the smallest function containing line, or -1
+func innermost(functions []*FunctionHealth, line int) int {
+	found := -1
+	for i, fn := range functions {
+		if line < fn.Span.StartLine || line > fn.Span.EndLine {
+			continue
+		}
+		if found < 0 || fn.Span.EndLine-fn.Span.StartLine < functions[found].Span.EndLine-functions[found].Span.StartLine {
+			found = i
+		}
+	}
+	return found
+}
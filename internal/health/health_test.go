@@ -0,0 +1,112 @@
+package health
+
+import (
+	"testing"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+func testResult(path string) *models.ScanResult {
+	return &models.ScanResult{
+		File: &models.FileInfo{Path: path, Language: "Go"},
+		ASTInfo: &types.GoASTInfo{Functions: []*types.FunctionInfo{
+			{Name: "process", StartLine: 10, EndLine: 60, LineCount: 50, Complexity: 16},
+			{Name: "helper", StartLine: 1, EndLine: 5, LineCount: 5, Complexity: 1, Parameters: []types.ParameterInfo{{Name: "x"}}},
+		}},
+		Violations: []*models.Violation{
+			{Type: models.ViolationTypeFunctionLength, Severity: models.SeverityHigh, File: path, Line: 20},
+			{Type: models.ViolationTypeNaming, Severity: models.SeverityMedium, File: path, Line: 30},
+			{Type: models.ViolationTypeMagicNumber, Severity: models.SeverityLow, File: path, Line: 8},
+		},
+	}
+}
+
+func TestFunctions(t *testing.T) {
+	functions := NewScorer(nil).Functions(testResult("main.go"))
+	if len(functions) != 2 {
+		t.Fatalf("Expected 2 functions, got %d", len(functions))
+	}
+
+	helper, process := functions[0], functions[1]
+	if helper.Name != "helper" || process.Name != "process" {
+		t.Fatalf("Expected functions in source order, got %s and %s", helper.Name, process.Name)
+	}
+	if helper.Score != 100 || helper.Violations != 0 || helper.Parameters != 1 {
+		t.Errorf("Expected a healthy helper, got %+v", helper)
+	}
+	// Twice the line and complexity thresholds cost 15 points each, and the
+	// high and medium violations 10 and 5 more
+	if process.Score != 55 || process.Violations != 2 {
+		t.Errorf("Expected process to score 55 with 2 violations, got %+v", process)
+	}
+	if process.Span.StartLine != 10 || process.Span.EndLine != 60 || process.File != "main.go" {
+		t.Errorf("Unexpected span for process: %+v", process)
+	}
+}
+
+func TestFunctionsCapsViolationPenalty(t *testing.T) {
+	result := &models.ScanResult{
+		File: &models.FileInfo{Path: "lib.rs", Language: "Rust"},
+		RustASTInfo: &types.RustASTInfo{Functions: []*types.RustFunctionInfo{
+			{Name: "outer", StartLine: 1, EndLine: 20, LineCount: 20, Complexity: 1},
+			{Name: "inner", StartLine: 5, EndLine: 8, LineCount: 4, Complexity: 1},
+		}},
+	}
+	for i := 0; i < 5; i++ {
+		result.Violations = append(result.Violations, &models.Violation{Severity: models.SeverityCritical, Line: 6})
+	}
+	result.Violations = append(result.Violations, &models.Violation{Severity: models.SeverityInfo, Line: 12})
+
+	functions := NewScorer(nil).Functions(result)
+	if len(functions) != 2 {
+		t.Fatalf("Expected 2 functions, got %d", len(functions))
+	}
+	outer, inner := functions[0], functions[1]
+	if inner.Violations != 5 || outer.Violations != 1 {
+		t.Errorf("Expected violations to count against the innermost function, got outer=%d inner=%d", outer.Violations, inner.Violations)
+	}
+	if inner.Score != 60 {
+		t.Errorf("Expected the violation penalty to be capped, got score %d", inner.Score)
+	}
+}
+
+func TestRank(t *testing.T) {
+	scorer := NewScorer(nil)
+	ranked := scorer.Rank([]*models.ScanResult{testResult("a.go"), testResult("b.go"), {}})
+	if len(ranked) != 4 {
+		t.Fatalf("Expected 4 ranked functions, got %d", len(ranked))
+	}
+	if ranked[0].Name != "process" || ranked[0].File != "a.go" || ranked[1].Name != "process" || ranked[3].Name != "helper" {
+		t.Errorf("Expected the least healthy functions first, got %s, %s, %s, %s",
+			ranked[0].Name, ranked[1].Name, ranked[2].Name, ranked[3].Name)
+	}
+}
+
+func TestFile(t *testing.T) {
+	scorer := NewScorer(nil)
+	// helper (5 lines, 100) and process (50 lines, 55) average 59, and the
+	// low violation outside both functions costs 2 more
+	file := scorer.File(testResult("main.go"))
+	if file.File != "main.go" || file.Score != 57 || file.Violations != 3 {
+		t.Errorf("Expected main.go to score 57 with 3 violations, got %+v", file)
+	}
+
+	empty := &models.ScanResult{
+		File:       &models.FileInfo{Path: "doc.go"},
+		Violations: []*models.Violation{{Severity: models.SeverityHigh, Line: 1}},
+	}
+	if file := scorer.File(empty); file.Score != 90 {
+		t.Errorf("Expected a file without functions to start from 100, got %+v", file)
+	}
+	if file := scorer.File(&models.ScanResult{}); file != nil {
+		t.Errorf("Expected no health without a file, got %+v", file)
+	}
+}
+
+func TestFiles(t *testing.T) {
+	files := NewScorer(nil).Files([]*models.ScanResult{testResult("b.go"), {}, testResult("a.go")})
+	if len(files) != 2 || files[0].File != "a.go" || files[1].File != "b.go" {
+		t.Errorf("Expected the files ordered by path, got %+v", files)
+	}
+}
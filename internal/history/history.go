@@ -0,0 +1,271 @@
+// Package history records the violations of successive scans to find flaky
+// findings: violations that appear and disappear across scans of identical
+// file content, which points at a nondeterministic detector.
+//
+// GoClean has no database, so the history is kept in a JSON file, like
+// baselines, rather than in a SQLite store.
+package history
+
+import (
+	"crypto/sha256"
+	"encoding/hex"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"io/fs"
+	"os"
+	"path/filepath"
+	"sort"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+)
+
+// FormatVersion is the version of the history file format
+const FormatVersion = 1
+
+// DefaultPath is where the history is kept when no path is given
+const DefaultPath = "goclean-history.json"
+
+// History holds the findings of the scans of each file since its content
+// or the rule set last changed
+type History struct {
+	FormatVersion int                     `json:"format_version"`
+	RuleSetHash   string                  `json:"rule_set_hash"` // Rules and settings of the recorded scans
+	TreeHash      string                  `json:"tree_hash"`     // Content of every file of the last scan
+	Files         map[string]*FileHistory `json:"files"`
+}
+
+// FileHistory holds the findings of the scans of one version of a file
+type FileHistory struct {
+	ContentHash string              `json:"content_hash"`
+	Runs        int                 `json:"runs"`     // Scans of this content
+	Findings    map[string]*Finding `json:"findings"` // By fingerprint
+}
+
+// Finding is a violation reported by at least one scan of a file version
+type Finding struct {
+	Rule    string `json:"rule"`
+	Type    string `json:"type"`
+	Line    int    `json:"line"`
+	Message string `json:"message"`
+	Seen    int    `json:"seen"`    // Scans that reported the finding
+	Flips   int    `json:"flips"`   // Times it appeared or disappeared between scans
+	Present bool   `json:"present"` // Reported by the last scan
+}
+
+// FlakyFinding is a finding that came and went across scans of the same content
+type FlakyFinding struct {
+	File        string `json:"file"`
+	Fingerprint string `json:"fingerprint"`
+	Runs        int    `json:"runs"`
+	Finding
+}
+
+// RecordStats summarizes what a scan added to the history
+type RecordStats struct {
+	Files     int // Files recorded
+	Unchanged int // Files whose content was scanned before
+	Flaky     int // Findings that flipped in this scan
+}
+
+// New creates an empty history
+func New() *History {
+	return &History{FormatVersion: FormatVersion, Files: make(map[string]*FileHistory)}
+}
+
+// Load reads a history written by Save. A missing file is an empty history.
+func Load(path string) (*History, error) {
+	data, err := os.ReadFile(path)
+	if errors.Is(err, fs.ErrNotExist) {
+		return New(), nil
+	}
+	if err != nil {
+		return nil, fmt.Errorf("failed to read history: %w", err)
+	}
+	var h History
+	if err := json.Unmarshal(data, &h); err != nil {
+		return nil, fmt.Errorf("failed to parse history: %w", err)
+	}
+	if h.FormatVersion > FormatVersion {
+		return nil, fmt.Errorf("history format version %d is newer than supported version %d", h.FormatVersion, FormatVersion)
+	}
+	if h.Files == nil {
+		h.Files = make(map[string]*FileHistory)
+	}
+	return &h, nil
+}
+
+// Save writes the history as indented JSON
+func (h *History) Save(path string) error {
+	data, err := json.MarshalIndent(h, "", "  ")
+	if err != nil {
+		return fmt.Errorf("failed to marshal history: %w", err)
+	}
+	if dir := filepath.Dir(path); dir != "." {
+		if err := os.MkdirAll(dir, 0755); err != nil {
+			return fmt.Errorf("failed to create history directory: %w", err)
+		}
+	}
+	if err := os.WriteFile(path, data, 0644); err != nil {
+		return fmt.Errorf("failed to write history: %w", err)
+	}
+	return nil
+}
+
+// Record adds the violations of a scan to the history. Files are compared
+// by content hash: the findings of a file are only compared with earlier
+// scans of the same content under the same rule set, and are started over
+// when either changes. Duplication findings depend on other files, so they
+// only count as flips when no scanned file changed. Inline-suppressed and
+// baselined violations are recorded too, so that filtering is not mistaken
+// for a flip.
+func (h *History) Record(results []*models.ScanResult, ruleSetHash string) RecordStats {
+	if h.RuleSetHash != ruleSetHash {
+		h.Files = make(map[string]*FileHistory)
+		h.RuleSetHash = ruleSetHash
+	}
+
+	contentHashes := make(map[string]string, len(results))
+	for _, result := range results {
+		if result.File == nil {
+			continue
+		}
+		if hash, err := hashFile(result.File.Path); err == nil {
+			contentHashes[result.File.Path] = hash
+		}
+	}
+	treeHash := hashTree(contentHashes)
+	treeUnchanged := treeHash == h.TreeHash
+	h.TreeHash = treeHash
+
+	var stats RecordStats
+	for _, result := range results {
+		if result.File == nil || contentHashes[result.File.Path] == "" {
+			continue
+		}
+		path := result.File.Path
+		file := h.Files[path]
+		if file == nil || file.ContentHash != contentHashes[path] {
+			file = &FileHistory{ContentHash: contentHashes[path], Findings: make(map[string]*Finding)}
+			h.Files[path] = file
+		}
+		stats.Files++
+		if file.Runs > 0 {
+			stats.Unchanged++
+		}
+
+		violations := append(append([]*models.Violation{}, result.Violations...), result.Suppressed...)
+		violations = append(violations, result.Baselined...)
+		stats.Flaky += file.record(violations, treeUnchanged)
+	}
+
+	h.prune()
+	return stats
+}
+
+// record compares the violations of a scan with the findings of the earlier
+// scans of the file and returns how many findings flipped
+func (f *FileHistory) record(violations []*models.Violation, treeUnchanged bool) int {
+	flipped := 0
+	flip := func(finding *Finding) {
+		if f.Runs > 0 && (treeUnchanged || finding.Type != string(models.ViolationTypeDuplication)) {
+			finding.Flips++
+			flipped++
+		}
+	}
+
+	reported := make(map[string]bool, len(violations))
+	for _, violation := range violations {
+		fingerprint := violation.Fingerprint
+		if fingerprint == "" {
+			fingerprint = models.ComputeFingerprint(violation)
+		}
+		if reported[fingerprint] {
+			continue
+		}
+		reported[fingerprint] = true
+
+		finding := f.Findings[fingerprint]
+		if finding == nil {
+			finding = &Finding{Rule: violation.Rule, Type: string(violation.Type)}
+			f.Findings[fingerprint] = finding
+		}
+		if !finding.Present {
+			flip(finding)
+		}
+		finding.Line = violation.Line
+		finding.Message = violation.Message
+		finding.Seen++
+		finding.Present = true
+	}
+
+	for fingerprint, finding := range f.Findings {
+		if finding.Present && !reported[fingerprint] {
+			finding.Present = false
+			flip(finding)
+		}
+	}
+	f.Runs++
+	return flipped
+}
+
+// prune forgets files that no longer exist
+func (h *History) prune() {
+	for path := range h.Files {
+		if _, err := os.Stat(path); errors.Is(err, fs.ErrNotExist) {
+			delete(h.Files, path)
+		}
+	}
+}
+
+// Flaky returns the findings that flipped at least once, those that flipped
+// most first
+func (h *History) Flaky() []FlakyFinding {
+	var flaky []FlakyFinding
+	for path, file := range h.Files {
+		for fingerprint, finding := range file.Findings {
+			if finding.Flips > 0 {
+				flaky = append(flaky, FlakyFinding{File: path, Fingerprint: fingerprint, Runs: file.Runs, Finding: *finding})
+			}
+		}
+	}
+	sort.Slice(flaky, func(i, j int) bool {
+		a, b := flaky[i], flaky[j]
+		if a.Flips != b.Flips {
+			return a.Flips > b.Flips
+		}
+		if a.File != b.File {
+			return a.File < b.File
+		}
+		if a.Line != b.Line {
+			return a.Line < b.Line
+		}
+		return a.Fingerprint < b.Fingerprint
+	})
+	return flaky
+}
+
+// hashFile returns the hash of the content of a file
+func hashFile(path string) (string, error) {
+	data, err := os.ReadFile(path)
+	if err != nil {
+		return "", err
+	}
+	sum := sha256.Sum256(data)
+	return hex.EncodeToString(sum[:]), nil
+}
+
+// hashTree returns a hash of the content hashes of a set of files
+func hashTree(contentHashes map[string]string) string {
+	paths := make([]string, 0, len(contentHashes))
+	for path := range contentHashes {
+		paths = append(paths, path)
+	}
+	sort.Strings(paths)
+
+	hash := sha256.New()
+	for _, path := range paths {
+		fmt.Fprintf(hash, "%s\x00%s\n", path, contentHashes[path])
+	}
+	return hex.EncodeToString(hash.Sum(nil))
+}
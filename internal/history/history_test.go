@@ -0,0 +1,122 @@
+package history
+
+import (
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+)
+
+// scanResult returns the result of a scan of path reporting the given rules
+func scanResult(path string, rules ...string) *models.ScanResult {
+	result := &models.ScanResult{File: &models.FileInfo{Path: path}}
+	for i, rule := range rules {
+		result.Violations = append(result.Violations, &models.Violation{
+			Rule:    rule,
+			Type:    models.ViolationTypeFunctionLength,
+			File:    path,
+			Line:    i + 1,
+			Message: rule + " violated",
+		})
+	}
+	return result
+}
+
+func writeFile(t *testing.T, path, content string) {
+	t.Helper()
+	if err := os.WriteFile(path, []byte(content), 0644); err != nil {
+		t.Fatalf("Failed to write %s: %v", path, err)
+	}
+}
+
+func TestRecordCountsFlipsOfIdenticalContent(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "main.go")
+	writeFile(t, path, "package main\n")
+
+	h := New()
+	h.Record([]*models.ScanResult{scanResult(path, "stable", "flaky")}, "rules")
+	if stats := h.Record([]*models.ScanResult{scanResult(path, "stable")}, "rules"); stats.Flaky != 1 || stats.Unchanged != 1 {
+		t.Errorf("Expected one flip on unchanged content, got %+v", stats)
+	}
+	h.Record([]*models.ScanResult{scanResult(path, "stable", "flaky")}, "rules")
+
+	flaky := h.Flaky()
+	if len(flaky) != 1 {
+		t.Fatalf("Expected one flaky finding, got %+v", flaky)
+	}
+	if flaky[0].Rule != "flaky" || flaky[0].Flips != 2 || flaky[0].Seen != 2 || flaky[0].Runs != 3 {
+		t.Errorf("Unexpected flaky finding %+v", flaky[0])
+	}
+}
+
+func TestRecordStartsOverWhenContentOrRulesChange(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "main.go")
+	writeFile(t, path, "package main\n")
+
+	h := New()
+	h.Record([]*models.ScanResult{scanResult(path, "a")}, "rules")
+	writeFile(t, path, "package main\n\nfunc main() {}\n")
+	if stats := h.Record([]*models.ScanResult{scanResult(path, "b")}, "rules"); stats.Flaky != 0 || stats.Unchanged != 0 {
+		t.Errorf("Expected edited content to start over, got %+v", stats)
+	}
+	if stats := h.Record([]*models.ScanResult{scanResult(path)}, "other rules"); stats.Flaky != 0 {
+		t.Errorf("Expected a new rule set to start over, got %+v", stats)
+	}
+	if len(h.Flaky()) != 0 {
+		t.Errorf("Expected no flaky findings, got %+v", h.Flaky())
+	}
+}
+
+func TestRecordIgnoresDuplicationFlipsWhenOtherFilesChange(t *testing.T) {
+	dir := t.TempDir()
+	path, other := filepath.Join(dir, "a.go"), filepath.Join(dir, "b.go")
+	writeFile(t, path, "package a\n")
+	writeFile(t, other, "package b\n")
+
+	duplicated := scanResult(path)
+	duplicated.Violations = []*models.Violation{{Rule: "duplication", Type: models.ViolationTypeDuplication, File: path, Message: "copied"}}
+
+	h := New()
+	h.Record([]*models.ScanResult{duplicated, scanResult(other)}, "rules")
+	writeFile(t, other, "package b\n\n// edited\n")
+	if stats := h.Record([]*models.ScanResult{scanResult(path), scanResult(other)}, "rules"); stats.Flaky != 0 {
+		t.Errorf("Expected the duplication to follow the edit of b.go, got %+v", stats)
+	}
+	if stats := h.Record([]*models.ScanResult{duplicated, scanResult(other)}, "rules"); stats.Flaky != 1 {
+		t.Errorf("Expected a flip with every file unchanged, got %+v", stats)
+	}
+}
+
+func TestSaveAndLoad(t *testing.T) {
+	dir := t.TempDir()
+	path := filepath.Join(dir, "main.go")
+	writeFile(t, path, "package main\n")
+	historyPath := filepath.Join(dir, ".goclean", "history.json")
+
+	empty, err := Load(historyPath)
+	if err != nil || len(empty.Files) != 0 {
+		t.Fatalf("Expected a missing history to load empty, got %+v, %v", empty, err)
+	}
+
+	h := New()
+	h.Record([]*models.ScanResult{scanResult(path, "a")}, "rules")
+	h.Record([]*models.ScanResult{scanResult(path)}, "rules")
+	if err := h.Save(historyPath); err != nil {
+		t.Fatalf("Save failed: %v", err)
+	}
+
+	loaded, err := Load(historyPath)
+	if err != nil {
+		t.Fatalf("Load failed: %v", err)
+	}
+	if flaky := loaded.Flaky(); len(flaky) != 1 || flaky[0].File != path {
+		t.Errorf("Expected the flaky finding to survive a round trip, got %+v", flaky)
+	}
+
+	os.Remove(path)
+	loaded.Record(nil, "rules")
+	if len(loaded.Files) != 0 {
+		t.Errorf("Expected deleted files to be forgotten, got %v", loaded.Files)
+	}
+}
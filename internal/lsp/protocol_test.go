@@ -0,0 +1,53 @@
+package lsp
+
+import (
+	"bufio"
+	"bytes"
+	"path/filepath"
+	"runtime"
+	"strings"
+	"testing"
+)
+
+func TestReadWriteMessage(t *testing.T) {
+	var buf bytes.Buffer
+	if err := writeMessage(&buf, map[string]string{"method": "initialized"}); err != nil {
+		t.Fatalf("writeMessage failed: %v", err)
+	}
+	if !strings.HasPrefix(buf.String(), "Content-Length: 24\r\n\r\n") {
+		t.Errorf("Unexpected framing: %q", buf.String())
+	}
+
+	body, err := readMessage(bufio.NewReader(&buf))
+	if err != nil {
+		t.Fatalf("readMessage failed: %v", err)
+	}
+	if string(body) != `{"method":"initialized"}` {
+		t.Errorf("Unexpected body: %s", body)
+	}
+}
+
+func TestReadMessageWithoutLength(t *testing.T) {
+	reader := bufio.NewReader(strings.NewReader("Content-Type: application/json\r\n\r\n{}"))
+	if _, err := readMessage(reader); err == nil {
+		t.Error("Expected an error for a message without Content-Length")
+	}
+}
+
+func TestURIConversion(t *testing.T) {
+	if runtime.GOOS == "windows" {
+		t.Skip("POSIX paths")
+	}
+	path := filepath.Join(t.TempDir(), "my dir", "main.go")
+	uri := PathToURI(path)
+	if !strings.HasPrefix(uri, "file:///") || !strings.Contains(uri, "my%20dir") {
+		t.Errorf("Unexpected URI %q", uri)
+	}
+	back, err := URIToPath(uri)
+	if err != nil || back != path {
+		t.Errorf("URIToPath(%q) = %q, %v; want %q", uri, back, err, path)
+	}
+	if _, err := URIToPath("untitled:Untitled-1"); err == nil {
+		t.Error("Expected an error for a non-file URI")
+	}
+}
@@ -0,0 +1,414 @@
+// Package lsp implements a minimal Language Server Protocol server over
+// stdio. It publishes violations as diagnostics and function health scores
+// as inlay hints, re-analyzing files when they are opened or saved. Code
+// actions suppress a violation inline or record it in the baseline.
+package lsp
+
+import (
+	"bufio"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"io"
+	"os"
+	"strings"
+	"unicode/utf16"
+
+	"github.com/ericfisherdev/goclean/internal/baseline"
+	"github.com/ericfisherdev/goclean/internal/health"
+	"github.com/ericfisherdev/goclean/internal/models"
+)
+
+// ScanFunc analyzes a single file
+type ScanFunc func(path string) ([]*models.ScanResult, error)
+
+// commandAddToBaseline records the violation named by its arguments, a
+// document URI and a fingerprint, in the baseline file
+const commandAddToBaseline = "goclean.addToBaseline"
+
+// ErrExitWithoutShutdown is returned by Serve when the client sends exit
+// without a prior shutdown request
+var ErrExitWithoutShutdown = errors.New("exit received before shutdown")
+
+// Server is a language server for one client connection
+type Server struct {
+	scan         ScanFunc
+	scorer       *health.Scorer
+	out          io.Writer
+	version      string
+	shutdown     bool
+	baselinePath string
+	hints        map[string][]InlayHint         // Inlay hints by document URI
+	violations   map[string][]*models.Violation // Published violations by document URI
+}
+
+// NewServer creates a server that analyzes files with scan and scores their
+// functions with scorer
+func NewServer(scan ScanFunc, scorer *health.Scorer, version string) *Server {
+	if scorer == nil {
+		scorer = health.NewScorer(nil)
+	}
+	return &Server{
+		scan:         scan,
+		scorer:       scorer,
+		version:      version,
+		baselinePath: baseline.DefaultPath,
+		hints:        make(map[string][]InlayHint),
+		violations:   make(map[string][]*models.Violation),
+	}
+}
+
+// SetBaselinePath sets the baseline file that code actions add violations to
+func (s *Server) SetBaselinePath(path string) {
+	s.baselinePath = path
+}
+
+// Serve handles messages from in until the client exits or in is closed
+func (s *Server) Serve(in io.Reader, out io.Writer) error {
+	s.out = out
+	reader := bufio.NewReader(in)
+	for {
+		body, err := readMessage(reader)
+		if err == io.EOF {
+			return nil
+		}
+		if err != nil {
+			return err
+		}
+
+		var msg message
+		if err := json.Unmarshal(body, &msg); err != nil {
+			if err := s.replyError(nil, codeInvalidRequest, err.Error()); err != nil {
+				return err
+			}
+			continue
+		}
+		if msg.Method == "exit" {
+			if !s.shutdown {
+				return ErrExitWithoutShutdown
+			}
+			return nil
+		}
+		if err := s.handle(&msg); err != nil {
+			return err
+		}
+	}
+}
+
+// handle dispatches a message. Only write errors are returned; failures to
+// serve a request are reported to the client.
+func (s *Server) handle(msg *message) error {
+	isRequest := len(msg.ID) > 0
+	switch msg.Method {
+	case "initialize":
+		return s.reply(msg.ID, s.capabilities())
+	case "shutdown":
+		s.shutdown = true
+		return s.reply(msg.ID, nil)
+	case "textDocument/didOpen", "textDocument/didSave":
+		var params textDocumentParams
+		if err := json.Unmarshal(msg.Params, &params); err != nil {
+			return nil
+		}
+		return s.analyze(params.TextDocument.URI)
+	case "textDocument/didClose":
+		var params textDocumentParams
+		if err := json.Unmarshal(msg.Params, &params); err != nil {
+			return nil
+		}
+		delete(s.hints, params.TextDocument.URI)
+		delete(s.violations, params.TextDocument.URI)
+		return s.publish(params.TextDocument.URI, []Diagnostic{})
+	case "textDocument/inlayHint":
+		var params inlayHintParams
+		if err := json.Unmarshal(msg.Params, &params); err != nil {
+			return s.replyError(msg.ID, codeInvalidParams, err.Error())
+		}
+		return s.reply(msg.ID, s.inlayHints(params))
+	case "textDocument/codeAction":
+		var params codeActionParams
+		if err := json.Unmarshal(msg.Params, &params); err != nil {
+			return s.replyError(msg.ID, codeInvalidParams, err.Error())
+		}
+		return s.reply(msg.ID, s.codeActions(params))
+	case "workspace/executeCommand":
+		var params executeCommandParams
+		if err := json.Unmarshal(msg.Params, &params); err != nil {
+			return s.replyError(msg.ID, codeInvalidParams, err.Error())
+		}
+		uri, err := s.addToBaseline(params)
+		if err != nil {
+			return s.replyError(msg.ID, codeRequestFailed, err.Error())
+		}
+		if err := s.reply(msg.ID, nil); err != nil {
+			return err
+		}
+		// The scan hides baselined violations, so re-analyzing clears the diagnostic
+		return s.analyze(uri)
+	}
+
+	if isRequest {
+		return s.replyError(msg.ID, codeMethodNotFound, fmt.Sprintf("method %q is not supported", msg.Method))
+	}
+	return nil // Unknown notifications, including initialized, are ignored
+}
+
+func (s *Server) capabilities() interface{} {
+	return map[string]interface{}{
+		"capabilities": map[string]interface{}{
+			"textDocumentSync": map[string]interface{}{
+				"openClose": true,
+				"save":      true,
+			},
+			"inlayHintProvider": true,
+			"codeActionProvider": map[string]interface{}{
+				"codeActionKinds": []string{"quickfix"},
+			},
+			"executeCommandProvider": map[string]interface{}{
+				"commands": []string{commandAddToBaseline},
+			},
+		},
+		"serverInfo": map[string]string{
+			"name":    "goclean",
+			"version": s.version,
+		},
+	}
+}
+
+// analyze scans the saved contents of a document, publishes its violations
+// and caches the health hints of its functions
+func (s *Server) analyze(uri string) error {
+	path, err := URIToPath(uri)
+	if err != nil {
+		return nil
+	}
+	results, err := s.scan(path)
+	if err != nil {
+		return s.logMessage(fmt.Sprintf("goclean: failed to analyze %s: %v", path, err))
+	}
+
+	content, _ := os.ReadFile(path)
+	lines := strings.Split(string(content), "\n")
+
+	diagnostics := []Diagnostic{}
+	var hints []InlayHint
+	var violations []*models.Violation
+	for _, result := range results {
+		for _, violation := range result.Violations {
+			diagnostics = append(diagnostics, toDiagnostic(violation))
+		}
+		violations = append(violations, result.Violations...)
+		for _, fn := range s.scorer.Functions(result) {
+			hints = append(hints, healthHint(fn, lines))
+		}
+	}
+	s.hints[uri] = hints
+	s.violations[uri] = violations
+	return s.publish(uri, diagnostics)
+}
+
+// codeActions offers to suppress or baseline each violation published for
+// the document within the requested range
+func (s *Server) codeActions(params codeActionParams) []CodeAction {
+	uri := params.TextDocument.URI
+	var lines []string
+	if path, err := URIToPath(uri); err == nil {
+		content, _ := os.ReadFile(path)
+		lines = strings.Split(string(content), "\n")
+	}
+
+	actions := []CodeAction{}
+	for _, violation := range s.violations[uri] {
+		diagnostic := toDiagnostic(violation)
+		if diagnostic.Range.Start.Line > params.Range.End.Line || diagnostic.Range.End.Line < params.Range.Start.Line {
+			continue
+		}
+		if violation.Rule != "" {
+			actions = append(actions, ignoreAction(uri, violation, diagnostic, lines))
+		}
+		title := fmt.Sprintf("Add this %s violation to the baseline", ruleName(violation))
+		actions = append(actions, CodeAction{
+			Title:       title,
+			Kind:        "quickfix",
+			Diagnostics: []Diagnostic{diagnostic},
+			Command: &Command{
+				Title:     title,
+				Command:   commandAddToBaseline,
+				Arguments: []interface{}{uri, fingerprint(violation)},
+			},
+		})
+	}
+	return actions
+}
+
+// ignoreAction inserts a goclean:ignore directive above the first line of a
+// violation, indented like that line
+func ignoreAction(uri string, v *models.Violation, diagnostic Diagnostic, lines []string) CodeAction {
+	line := diagnostic.Range.Start.Line
+	indent := ""
+	if line < len(lines) {
+		text := lines[line]
+		indent = text[:len(text)-len(strings.TrimLeft(text, " \t"))]
+	}
+	edit := TextEdit{
+		Range:   Range{Start: Position{Line: line}, End: Position{Line: line}},
+		NewText: indent + "// goclean:ignore " + v.Rule + "\n",
+	}
+	return CodeAction{
+		Title:       fmt.Sprintf("Ignore %s on this line", v.Rule),
+		Kind:        "quickfix",
+		Diagnostics: []Diagnostic{diagnostic},
+		Edit:        &WorkspaceEdit{Changes: map[string][]TextEdit{uri: {edit}}},
+	}
+}
+
+// addToBaseline runs the add-to-baseline command and returns the URI of the
+// document whose violation was recorded
+func (s *Server) addToBaseline(params executeCommandParams) (string, error) {
+	if params.Command != commandAddToBaseline {
+		return "", fmt.Errorf("unknown command %q", params.Command)
+	}
+	var uri, id string
+	if len(params.Arguments) != 2 ||
+		json.Unmarshal(params.Arguments[0], &uri) != nil || json.Unmarshal(params.Arguments[1], &id) != nil {
+		return "", fmt.Errorf("%s expects a document URI and a violation fingerprint", commandAddToBaseline)
+	}
+
+	var violation *models.Violation
+	for _, v := range s.violations[uri] {
+		if fingerprint(v) == id {
+			violation = v
+			break
+		}
+	}
+	if violation == nil {
+		return "", fmt.Errorf("violation %s is no longer reported for %s", id, uri)
+	}
+
+	known, err := baseline.Load(s.baselinePath)
+	if errors.Is(err, os.ErrNotExist) {
+		known, err = baseline.New(nil, s.version), nil
+	}
+	if err != nil {
+		return "", err
+	}
+	known.Add([]*models.Violation{violation})
+	return uri, known.Save(s.baselinePath)
+}
+
+// fingerprint returns the fingerprint a baseline records for a violation
+func fingerprint(v *models.Violation) string {
+	if v.Fingerprint != "" {
+		return v.Fingerprint
+	}
+	return models.ComputeFingerprint(v)
+}
+
+// ruleName names the rule of a violation, falling back to its type
+func ruleName(v *models.Violation) string {
+	if v.Rule != "" {
+		return v.Rule
+	}
+	return string(v.Type)
+}
+
+// inlayHints returns the cached hints within the requested range
+func (s *Server) inlayHints(params inlayHintParams) []InlayHint {
+	hints := []InlayHint{}
+	for _, hint := range s.hints[params.TextDocument.URI] {
+		if hint.Position.Line >= params.Range.Start.Line && hint.Position.Line <= params.Range.End.Line {
+			hints = append(hints, hint)
+		}
+	}
+	return hints
+}
+
+// toDiagnostic converts a violation into a diagnostic. Violations without an
+// end column extend to the end of their last line.
+func toDiagnostic(v *models.Violation) Diagnostic {
+	start := Position{Line: max(v.Line-1, 0), Character: max(v.Column-1, 0)}
+	end := Position{Line: start.Line, Character: lineEnd}
+	if v.EndLine >= v.Line {
+		end.Line = max(v.EndLine-1, 0)
+	}
+	if v.EndColumn > 0 {
+		end.Character = v.EndColumn - 1
+	}
+
+	message := v.Message
+	if v.Suggestion != "" {
+		message += "\n" + v.Suggestion
+	}
+	return Diagnostic{
+		Range:    Range{Start: start, End: end},
+		Severity: diagnosticSeverity(v.Severity),
+		Code:     v.Rule,
+		Source:   "goclean",
+		Message:  message,
+	}
+}
+
+func diagnosticSeverity(severity models.Severity) int {
+	switch severity {
+	case models.SeverityCritical:
+		return severityError
+	case models.SeverityHigh, models.SeverityMedium:
+		return severityWarning
+	case models.SeverityLow:
+		return severityInformation
+	default:
+		return severityHint
+	}
+}
+
+// healthHint places the score of a function at the end of its first line
+func healthHint(fn *health.FunctionHealth, lines []string) InlayHint {
+	line := max(fn.Span.StartLine-1, 0)
+	character := lineEnd
+	if line < len(lines) {
+		character = utf16Length(strings.TrimRight(lines[line], "\r"))
+	}
+	return InlayHint{
+		Position: Position{Line: line, Character: character},
+		Label:    fmt.Sprintf("health: %d/100", fn.Score),
+		Tooltip: fmt.Sprintf("%d lines, cyclomatic complexity %d, cognitive complexity %d, %d parameters, %d violations",
+			fn.Lines, fn.CyclomaticComplexity, fn.CognitiveComplexity, fn.Parameters, fn.Violations),
+		PaddingLeft: true,
+	}
+}
+
+// utf16Length returns the length of s in UTF-16 code units, the unit of LSP character offsets
+func utf16Length(s string) int {
+	length := 0
+	for _, r := range s {
+		length += utf16.RuneLen(r)
+	}
+	return length
+}
+
+func (s *Server) publish(uri string, diagnostics []Diagnostic) error {
+	return writeMessage(s.out, notification{
+		JSONRPC: "2.0",
+		Method:  "textDocument/publishDiagnostics",
+		Params:  publishDiagnosticsParams{URI: uri, Diagnostics: diagnostics},
+	})
+}
+
+func (s *Server) logMessage(text string) error {
+	return writeMessage(s.out, notification{
+		JSONRPC: "2.0",
+		Method:  "window/logMessage",
+		Params:  map[string]interface{}{"type": messageTypeError, "message": text},
+	})
+}
+
+func (s *Server) reply(id json.RawMessage, result interface{}) error {
+	return writeMessage(s.out, resultResponse{JSONRPC: "2.0", ID: id, Result: result})
+}
+
+func (s *Server) replyError(id json.RawMessage, code int, text string) error {
+	if id == nil {
+		id = json.RawMessage("null")
+	}
+	return writeMessage(s.out, errorResponse{JSONRPC: "2.0", ID: id, Error: responseError{Code: code, Message: text}})
+}
@@ -0,0 +1,198 @@
+package lsp
+
+import (
+	"bufio"
+	"bytes"
+	"encoding/json"
+	"fmt"
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+
+	"github.com/ericfisherdev/goclean/internal/baseline"
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+// frame encodes messages as a client would send them
+func frame(t *testing.T, messages ...string) *bytes.Buffer {
+	t.Helper()
+	var buf bytes.Buffer
+	for _, msg := range messages {
+		fmt.Fprintf(&buf, "Content-Length: %d\r\n\r\n%s", len(msg), msg)
+	}
+	return &buf
+}
+
+// readAll decodes every message the server wrote
+func readAll(t *testing.T, out *bytes.Buffer) []map[string]json.RawMessage {
+	t.Helper()
+	var messages []map[string]json.RawMessage
+	reader := bufio.NewReader(out)
+	for {
+		body, err := readMessage(reader)
+		if err != nil {
+			return messages
+		}
+		var msg map[string]json.RawMessage
+		if err := json.Unmarshal(body, &msg); err != nil {
+			t.Fatalf("Invalid message %s: %v", body, err)
+		}
+		messages = append(messages, msg)
+	}
+}
+
+func TestServer(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "main.go")
+	code := "package main\n\nfunc run() {\n\tprintln(404)\n}\n"
+	if err := os.WriteFile(path, []byte(code), 0644); err != nil {
+		t.Fatalf("Failed to write file: %v", err)
+	}
+	uri := PathToURI(path)
+
+	scan := func(scanned string) ([]*models.ScanResult, error) {
+		if scanned != path {
+			t.Errorf("Expected %s to be scanned, got %s", path, scanned)
+		}
+		return []*models.ScanResult{{
+			File: &models.FileInfo{Path: path},
+			ASTInfo: &types.GoASTInfo{Functions: []*types.FunctionInfo{
+				{Name: "run", StartLine: 3, EndLine: 5, LineCount: 3, Complexity: 1},
+			}},
+			Violations: []*models.Violation{
+				{Severity: models.SeverityHigh, Rule: "magic-number", Message: "Magic number 404", File: path, Line: 4, Column: 10},
+			},
+		}}, nil
+	}
+
+	in := frame(t,
+		`{"jsonrpc":"2.0","id":1,"method":"initialize","params":{}}`,
+		`{"jsonrpc":"2.0","method":"initialized","params":{}}`,
+		`{"jsonrpc":"2.0","method":"textDocument/didOpen","params":{"textDocument":{"uri":"`+uri+`","languageId":"go","version":1,"text":""}}}`,
+		`{"jsonrpc":"2.0","id":2,"method":"textDocument/inlayHint","params":{"textDocument":{"uri":"`+uri+`"},"range":{"start":{"line":0,"character":0},"end":{"line":10,"character":0}}}}`,
+		`{"jsonrpc":"2.0","id":3,"method":"textDocument/hover","params":{}}`,
+		`{"jsonrpc":"2.0","id":4,"method":"shutdown"}`,
+		`{"jsonrpc":"2.0","method":"exit"}`,
+	)
+	var out bytes.Buffer
+	if err := NewServer(scan, nil, "test").Serve(in, &out); err != nil {
+		t.Fatalf("Serve failed: %v", err)
+	}
+
+	messages := readAll(t, &out)
+	if len(messages) != 5 {
+		t.Fatalf("Expected 5 messages, got %d", len(messages))
+	}
+	if !strings.Contains(string(messages[0]["result"]), `"inlayHintProvider":true`) {
+		t.Errorf("Expected inlay hints to be advertised, got %s", messages[0]["result"])
+	}
+
+	var diagnostics publishDiagnosticsParams
+	if err := json.Unmarshal(messages[1]["params"], &diagnostics); err != nil {
+		t.Fatalf("Invalid diagnostics: %v", err)
+	}
+	if len(diagnostics.Diagnostics) != 1 {
+		t.Fatalf("Expected 1 diagnostic, got %d", len(diagnostics.Diagnostics))
+	}
+	diagnostic := diagnostics.Diagnostics[0]
+	if diagnostic.Range.Start != (Position{Line: 3, Character: 9}) || diagnostic.Severity != severityWarning || diagnostic.Code != "magic-number" {
+		t.Errorf("Unexpected diagnostic: %+v", diagnostic)
+	}
+
+	var hints []InlayHint
+	if err := json.Unmarshal(messages[2]["result"], &hints); err != nil {
+		t.Fatalf("Invalid inlay hints: %v", err)
+	}
+	if len(hints) != 1 || hints[0].Label != "health: 90/100" || hints[0].Position != (Position{Line: 2, Character: 12}) {
+		t.Errorf("Unexpected inlay hints: %+v", hints)
+	}
+
+	if !strings.Contains(string(messages[3]["error"]), "-32601") {
+		t.Errorf("Expected an unsupported method error, got %s", messages[3]["error"])
+	}
+	if string(messages[4]["result"]) != "null" {
+		t.Errorf("Expected a null shutdown result, got %s", messages[4]["result"])
+	}
+}
+
+func TestServerCodeActions(t *testing.T) {
+	dir := t.TempDir()
+	path := filepath.Join(dir, "main.go")
+	code := "package main\n\nfunc run() {\n\tprintln(404)\n}\n"
+	if err := os.WriteFile(path, []byte(code), 0644); err != nil {
+		t.Fatalf("Failed to write file: %v", err)
+	}
+	uri := PathToURI(path)
+
+	scan := func(string) ([]*models.ScanResult, error) {
+		return []*models.ScanResult{{
+			File: &models.FileInfo{Path: path},
+			Violations: []*models.Violation{
+				{Severity: models.SeverityHigh, Rule: "magic-number", Message: "Magic number 404", File: "main.go", Line: 4, Column: 10, Fingerprint: "abc"},
+			},
+		}}, nil
+	}
+
+	in := frame(t,
+		`{"jsonrpc":"2.0","id":1,"method":"initialize","params":{}}`,
+		`{"jsonrpc":"2.0","method":"textDocument/didOpen","params":{"textDocument":{"uri":"`+uri+`"}}}`,
+		`{"jsonrpc":"2.0","id":2,"method":"textDocument/codeAction","params":{"textDocument":{"uri":"`+uri+`"},"range":{"start":{"line":3,"character":9},"end":{"line":3,"character":9}},"context":{"diagnostics":[]}}}`,
+		`{"jsonrpc":"2.0","id":3,"method":"workspace/executeCommand","params":{"command":"goclean.addToBaseline","arguments":["`+uri+`","abc"]}}`,
+		`{"jsonrpc":"2.0","id":4,"method":"workspace/executeCommand","params":{"command":"goclean.addToBaseline","arguments":["`+uri+`","missing"]}}`,
+		`{"jsonrpc":"2.0","id":5,"method":"shutdown"}`,
+		`{"jsonrpc":"2.0","method":"exit"}`,
+	)
+	baselinePath := filepath.Join(dir, "baseline.json")
+	server := NewServer(scan, nil, "test")
+	server.SetBaselinePath(baselinePath)
+	var out bytes.Buffer
+	if err := server.Serve(in, &out); err != nil {
+		t.Fatalf("Serve failed: %v", err)
+	}
+
+	messages := readAll(t, &out)
+	if len(messages) != 7 {
+		t.Fatalf("Expected 7 messages, got %d", len(messages))
+	}
+	if !strings.Contains(string(messages[0]["result"]), `"goclean.addToBaseline"`) {
+		t.Errorf("Expected the baseline command to be advertised, got %s", messages[0]["result"])
+	}
+
+	var actions []CodeAction
+	if err := json.Unmarshal(messages[2]["result"], &actions); err != nil {
+		t.Fatalf("Invalid code actions: %v", err)
+	}
+	if len(actions) != 2 {
+		t.Fatalf("Expected 2 code actions, got %+v", actions)
+	}
+	edits := actions[0].Edit.Changes[uri]
+	if len(edits) != 1 || edits[0].NewText != "\t// goclean:ignore magic-number\n" || edits[0].Range.Start != (Position{Line: 3}) {
+		t.Errorf("Unexpected ignore edit: %+v", edits)
+	}
+	if actions[1].Command == nil || actions[1].Command.Command != commandAddToBaseline {
+		t.Errorf("Expected a baseline command, got %+v", actions[1])
+	}
+
+	if string(messages[3]["result"]) != "null" || messages[4]["method"] == nil {
+		t.Errorf("Expected the command to succeed and the file to be re-analyzed, got %v", messages[3:5])
+	}
+	known, err := baseline.Load(baselinePath)
+	if err != nil {
+		t.Fatalf("Failed to load the baseline: %v", err)
+	}
+	if len(known.Entries) != 1 || known.Entries[0].Fingerprint != "abc" || known.Entries[0].File != "main.go" {
+		t.Errorf("Unexpected baseline entries: %+v", known.Entries)
+	}
+	if !strings.Contains(string(messages[5]["error"]), "no longer reported") {
+		t.Errorf("Expected an error for an unknown violation, got %s", messages[5]["error"])
+	}
+}
+
+func TestServerExitWithoutShutdown(t *testing.T) {
+	in := frame(t, `{"jsonrpc":"2.0","method":"exit"}`)
+	var out bytes.Buffer
+	if err := NewServer(nil, nil, "test").Serve(in, &out); err != ErrExitWithoutShutdown {
+		t.Errorf("Expected ErrExitWithoutShutdown, got %v", err)
+	}
+}
@@ -0,0 +1,47 @@
+// Package manifest captures the environment of a scan for the manifest
+// embedded in its result files.
+package manifest
+
+import (
+	"crypto/sha256"
+	"encoding/hex"
+	"encoding/json"
+	"fmt"
+	"runtime"
+	"time"
+
+	"github.com/ericfisherdev/goclean/internal/gitdiff"
+	"github.com/ericfisherdev/goclean/internal/models"
+)
+
+// Capture returns the manifest of a scan. ruleSettings are the settings the
+// detectors ran with and config is the whole configuration; both are only
+// hashed. The git revision is read from the repository containing dir, and
+// left empty outside a repository.
+func Capture(toolVersion string, ruleSettings, config interface{}, dir string, duration time.Duration) *models.ScanManifest {
+	manifest := &models.ScanManifest{
+		ToolVersion: toolVersion,
+		RuleSetHash: Hash(toolVersion, ruleSettings),
+		ConfigHash:  Hash(config),
+		Platform:    runtime.GOOS + "/" + runtime.GOARCH,
+		Duration:    duration,
+	}
+	if sha, dirty, err := gitdiff.Revision(dir); err == nil {
+		manifest.GitSHA = sha
+		manifest.GitDirty = dirty
+	}
+	return manifest
+}
+
+// Hash returns a short hash of the JSON encoding of values. Map keys are
+// encoded in sorted order, so equal values always hash the same.
+func Hash(values ...interface{}) string {
+	hash := sha256.New()
+	encoder := json.NewEncoder(hash)
+	for _, value := range values {
+		if err := encoder.Encode(value); err != nil {
+			fmt.Fprintf(hash, "%#v\n", value)
+		}
+	}
+	return hex.EncodeToString(hash.Sum(nil))[:16]
+}
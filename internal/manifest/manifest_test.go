@@ -0,0 +1,36 @@
+package manifest
+
+import (
+	"runtime"
+	"testing"
+	"time"
+)
+
+func TestHash(t *testing.T) {
+	settings := map[string]int{"function_lines": 25, "parameters": 4}
+	same := map[string]int{"parameters": 4, "function_lines": 25}
+	if Hash("1.0.0", settings) != Hash("1.0.0", same) {
+		t.Error("Expected equal settings to hash the same")
+	}
+	if Hash("1.0.0", settings) == Hash("1.1.0", settings) {
+		t.Error("Expected the tool version to change the hash")
+	}
+	if len(Hash(settings)) != 16 {
+		t.Errorf("Expected a 16 character hash, got %q", Hash(settings))
+	}
+}
+
+func TestCapture(t *testing.T) {
+	settings := map[string]int{"function_lines": 25}
+	manifest := Capture("1.0.0", settings, settings, t.TempDir(), time.Second)
+
+	if manifest.RuleSetHash != Hash("1.0.0", settings) || manifest.ConfigHash != Hash(settings) {
+		t.Errorf("Unexpected hashes: %+v", manifest)
+	}
+	if manifest.Platform != runtime.GOOS+"/"+runtime.GOARCH || manifest.Duration != time.Second {
+		t.Errorf("Unexpected environment: %+v", manifest)
+	}
+	if manifest.GitSHA != "" || manifest.GitDirty {
+		t.Errorf("Expected no git revision outside a repository, got %q", manifest.GitSHA)
+	}
+}
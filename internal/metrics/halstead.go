@@ -0,0 +1,130 @@
+// Package metrics computes classic source code metrics, such as the
+// Halstead volume, difficulty and effort and the maintainability index,
+// from lexical tokens.
+package metrics
+
+import (
+	"go/scanner"
+	"go/token"
+	"math"
+	"sort"
+
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+// Token is a lexical token classified for the Halstead measures
+type Token struct {
+	Text    string
+	Line    int
+	Operand bool // Identifiers and literals; keywords, operators and delimiters are operators
+}
+
+// Halstead holds the operator and operand counts of a piece of code
+type Halstead struct {
+	DistinctOperators int
+	DistinctOperands  int
+	Operators         int
+	Operands          int
+}
+
+// Vocabulary is the number of distinct operators and operands
+func (h Halstead) Vocabulary() int {
+	return h.DistinctOperators + h.DistinctOperands
+}
+
+// Length is the total number of operators and operands
+func (h Halstead) Length() int {
+	return h.Operators + h.Operands
+}
+
+// Volume is the size of the code in bits: length * log2(vocabulary)
+func (h Halstead) Volume() float64 {
+	if h.Vocabulary() < 2 {
+		return 0
+	}
+	return float64(h.Length()) * math.Log2(float64(h.Vocabulary()))
+}
+
+// Difficulty is how hard the code is to write or understand:
+// (distinct operators / 2) * (operands / distinct operands)
+func (h Halstead) Difficulty() float64 {
+	if h.DistinctOperands == 0 {
+		return 0
+	}
+	return float64(h.DistinctOperators) / 2 * float64(h.Operands) / float64(h.DistinctOperands)
+}
+
+// Effort is the mental effort to write the code: difficulty * volume
+func (h Halstead) Effort() float64 {
+	return h.Difficulty() * h.Volume()
+}
+
+// CountHalstead counts the operators and operands among the tokens on lines
+// startLine to endLine. Tokens must be in source order.
+func CountHalstead(tokens []Token, startLine, endLine int) Halstead {
+	first := sort.Search(len(tokens), func(i int) bool { return tokens[i].Line >= startLine })
+
+	var h Halstead
+	operators := make(map[string]bool)
+	operands := make(map[string]bool)
+	for _, tok := range tokens[first:] {
+		if tok.Line > endLine {
+			break
+		}
+		if tok.Operand {
+			h.Operands++
+			operands[tok.Text] = true
+		} else {
+			h.Operators++
+			operators[tok.Text] = true
+		}
+	}
+	h.DistinctOperators = len(operators)
+	h.DistinctOperands = len(operands)
+	return h
+}
+
+// closingDelimiters end a pair whose opening delimiter is the operator
+var closingDelimiters = map[string]bool{")": true, "]": true, "}": true}
+
+// GoTokens classifies the tokens of Go source. Semicolons, which are mostly
+// inserted at line ends, and closing delimiters are left out.
+func GoTokens(src []byte) []Token {
+	fset := token.NewFileSet()
+	file := fset.AddFile("", fset.Base(), len(src))
+
+	var s scanner.Scanner
+	s.Init(file, src, nil, 0)
+
+	var tokens []Token
+	for {
+		pos, tok, literal := s.Scan()
+		if tok == token.EOF {
+			break
+		}
+		if tok == token.SEMICOLON || tok == token.RPAREN || tok == token.RBRACK || tok == token.RBRACE {
+			continue
+		}
+
+		text := tok.String()
+		if tok == token.IDENT || tok.IsLiteral() {
+			text = literal
+		}
+		tokens = append(tokens, Token{Text: text, Line: file.Line(pos), Operand: tok == token.IDENT || tok.IsLiteral()})
+	}
+	return tokens
+}
+
+// RustTokens classifies the tokens of a parsed Rust file. Closing delimiters
+// are left out.
+func RustTokens(rustTokens []types.RustTokenInfo) []Token {
+	tokens := make([]Token, 0, len(rustTokens))
+	for _, tok := range rustTokens {
+		if tok.Kind == types.RustTokenPunct && closingDelimiters[tok.Text] {
+			continue
+		}
+		operand := tok.Kind == types.RustTokenIdent || tok.Kind == types.RustTokenLiteral || tok.Kind == types.RustTokenLifetime
+		tokens = append(tokens, Token{Text: tok.Text, Line: tok.Line, Operand: operand})
+	}
+	return tokens
+}
@@ -0,0 +1,74 @@
+package metrics
+
+import (
+	"math"
+	"testing"
+
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+const goSource = `package calc
+
+func add(a, b int) int {
+	return a + b
+}
+`
+
+func TestCountHalsteadGo(t *testing.T) {
+	tokens := GoTokens([]byte(goSource))
+
+	// func ( , { return + as operators; add a b int a b int as operands
+	h := CountHalstead(tokens, 3, 5)
+	expected := Halstead{DistinctOperators: 6, DistinctOperands: 4, Operators: 6, Operands: 7}
+	if h != expected {
+		t.Fatalf("Expected %+v, got %+v", expected, h)
+	}
+	if h.Vocabulary() != 10 || h.Length() != 13 {
+		t.Errorf("Expected vocabulary 10 and length 13, got %d and %d", h.Vocabulary(), h.Length())
+	}
+	if math.Abs(h.Volume()-13*math.Log2(10)) > 1e-9 {
+		t.Errorf("Expected volume 13 log2(10), got %f", h.Volume())
+	}
+	if h.Difficulty() != 5.25 {
+		t.Errorf("Expected difficulty 6/2 * 7/4 = 5.25, got %f", h.Difficulty())
+	}
+	if math.Abs(h.Effort()-5.25*h.Volume()) > 1e-9 {
+		t.Errorf("Expected effort difficulty * volume, got %f", h.Effort())
+	}
+
+	body := CountHalstead(tokens, 4, 4)
+	if body != (Halstead{DistinctOperators: 2, DistinctOperands: 2, Operators: 2, Operands: 2}) {
+		t.Errorf("Expected only the tokens of line 4, got %+v", body)
+	}
+}
+
+func TestCountHalsteadRust(t *testing.T) {
+	tokens := RustTokens([]types.RustTokenInfo{
+		{Kind: types.RustTokenKeyword, Text: "fn", Line: 1},
+		{Kind: types.RustTokenIdent, Text: "id", Line: 1},
+		{Kind: types.RustTokenPunct, Text: "(", Line: 1},
+		{Kind: types.RustTokenIdent, Text: "x", Line: 1},
+		{Kind: types.RustTokenPunct, Text: ":", Line: 1},
+		{Kind: types.RustTokenIdent, Text: "u32", Line: 1},
+		{Kind: types.RustTokenPunct, Text: ")", Line: 1},
+		{Kind: types.RustTokenPunct, Text: "{", Line: 1},
+		{Kind: types.RustTokenIdent, Text: "x", Line: 2},
+		{Kind: types.RustTokenPunct, Text: "}", Line: 3},
+	})
+
+	h := CountHalstead(tokens, 1, 3)
+	expected := Halstead{DistinctOperators: 4, DistinctOperands: 3, Operators: 4, Operands: 4}
+	if h != expected {
+		t.Errorf("Expected %+v, got %+v", expected, h)
+	}
+}
+
+func TestVolumeOfTrivialCode(t *testing.T) {
+	trivial := Halstead{DistinctOperators: 1, Operators: 3}
+	if volume := trivial.Volume(); volume != 0 {
+		t.Errorf("Expected no volume for a vocabulary of one, got %f", volume)
+	}
+	if trivial.Difficulty() != 0 || trivial.Effort() != 0 {
+		t.Errorf("Expected no difficulty or effort without operands, got %f and %f", trivial.Difficulty(), trivial.Effort())
+	}
+}
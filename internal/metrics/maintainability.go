@@ -0,0 +1,17 @@
+package metrics
+
+import "math"
+
+// MaintainabilityIndex returns the maintainability index of code with the
+// given Halstead volume, cyclomatic complexity and lines of code, on the
+// 0-100 scale used by Visual Studio:
+//
+//	100 * (171 - 5.2 ln(volume) - 0.23 complexity - 16.2 ln(lines)) / 171
+//
+// Higher is easier to maintain; below 20 is usually considered hard to
+// maintain. The result is rounded to one decimal.
+func MaintainabilityIndex(volume float64, complexity, lines int) float64 {
+	raw := 171 - 5.2*math.Log(math.Max(volume, 1)) - 0.23*float64(complexity) - 16.2*math.Log(float64(max(lines, 1)))
+	index := math.Max(0, math.Min(100, raw*100/171))
+	return math.Round(index*10) / 10
+}
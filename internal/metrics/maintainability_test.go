@@ -0,0 +1,22 @@
+package metrics
+
+import "testing"
+
+func TestMaintainabilityIndex(t *testing.T) {
+	testCases := []struct {
+		volume     float64
+		complexity int
+		lines      int
+		expected   float64
+	}{
+		{0, 1, 1, 99.9},
+		{43.18, 1, 3, 78.0},
+		{10000, 30, 200, 17.8},
+		{1e9, 100, 5000, 0},
+	}
+	for _, tc := range testCases {
+		if index := MaintainabilityIndex(tc.volume, tc.complexity, tc.lines); index != tc.expected {
+			t.Errorf("MaintainabilityIndex(%v, %d, %d) = %v, expected %v", tc.volume, tc.complexity, tc.lines, index, tc.expected)
+		}
+	}
+}
@@ -0,0 +1,106 @@
+package models
+
+import (
+	"fmt"
+	"path/filepath"
+	"sort"
+	"strings"
+)
+
+// MinutesPerDebtDay is the length of a working day in debt totals
+const MinutesPerDebtDay = 8 * 60
+
+// TechnicalDebt is the estimated time to fix the reported violations, in
+// total and by file and module. A module is the directory of a file: a Go
+// package, or a Rust module directory.
+type TechnicalDebt struct {
+	Minutes int         `json:"minutes"`
+	Files   []DebtEntry `json:"files"`   // Most debt first
+	Modules []DebtEntry `json:"modules"` // Most debt first
+}
+
+// DebtEntry is the technical debt of one file or module
+type DebtEntry struct {
+	Name       string `json:"name"`
+	Minutes    int    `json:"minutes"`
+	Violations int    `json:"violations"`
+}
+
+// Total returns the total debt formatted like "2d 3h 15m"
+func (d *TechnicalDebt) Total() string {
+	return FormatDebt(d.Minutes)
+}
+
+// Formatted returns the debt of the entry formatted like "2d 3h 15m"
+func (e DebtEntry) Formatted() string {
+	return FormatDebt(e.Minutes)
+}
+
+// ComputeTechnicalDebt adds up the remediation estimates of violations
+func ComputeTechnicalDebt(violations []*Violation) *TechnicalDebt {
+	debt := &TechnicalDebt{}
+	files := make(map[string]*DebtEntry)
+	modules := make(map[string]*DebtEntry)
+	for _, violation := range violations {
+		debt.Minutes += violation.Remediation
+		addDebt(files, filepath.ToSlash(violation.File), violation.Remediation)
+		addDebt(modules, filepath.ToSlash(filepath.Dir(violation.File)), violation.Remediation)
+	}
+	debt.Files = sortedDebt(files)
+	debt.Modules = sortedDebt(modules)
+	return debt
+}
+
+// CountDebt sets the technical debt of the reported violations on the summary
+func (s *ScanSummary) CountDebt(results []*ScanResult) {
+	var violations []*Violation
+	for _, result := range results {
+		violations = append(violations, result.Violations...)
+	}
+	s.Debt = ComputeTechnicalDebt(violations)
+}
+
+// addDebt adds one violation to the entry of name
+func addDebt(entries map[string]*DebtEntry, name string, minutes int) {
+	entry, found := entries[name]
+	if !found {
+		entry = &DebtEntry{Name: name}
+		entries[name] = entry
+	}
+	entry.Minutes += minutes
+	entry.Violations++
+}
+
+// sortedDebt lists the entries by debt, most first, then by name
+func sortedDebt(entries map[string]*DebtEntry) []DebtEntry {
+	sorted := make([]DebtEntry, 0, len(entries))
+	for _, entry := range entries {
+		sorted = append(sorted, *entry)
+	}
+	sort.Slice(sorted, func(i, j int) bool {
+		if sorted[i].Minutes != sorted[j].Minutes {
+			return sorted[i].Minutes > sorted[j].Minutes
+		}
+		return sorted[i].Name < sorted[j].Name
+	})
+	return sorted
+}
+
+// FormatDebt formats minutes of debt in working days of 8 hours, hours and
+// minutes, such as "2d 3h 15m"; zero units are left out
+func FormatDebt(minutes int) string {
+	if minutes <= 0 {
+		return "0m"
+	}
+	var parts []string
+	if days := minutes / MinutesPerDebtDay; days > 0 {
+		parts = append(parts, fmt.Sprintf("%dd", days))
+	}
+	if hours := minutes % MinutesPerDebtDay / 60; hours > 0 {
+		parts = append(parts, fmt.Sprintf("%dh", hours))
+	}
+	if rest := minutes % 60; rest > 0 {
+		parts = append(parts, fmt.Sprintf("%dm", rest))
+	}
+	return strings.Join(parts, " ")
+}
@@ -0,0 +1,49 @@
+package models
+
+import "testing"
+
+func TestFormatDebt(t *testing.T) {
+	tests := []struct {
+		minutes  int
+		expected string
+	}{
+		{0, "0m"},
+		{45, "45m"},
+		{60, "1h"},
+		{135, "2h 15m"},
+		{480, "1d"},
+		{1000, "2d 40m"},
+	}
+
+	for _, tt := range tests {
+		if got := FormatDebt(tt.minutes); got != tt.expected {
+			t.Errorf("FormatDebt(%d) = %q, expected %q", tt.minutes, got, tt.expected)
+		}
+	}
+}
+
+func TestCountDebt(t *testing.T) {
+	results := []*ScanResult{
+		{Violations: []*Violation{
+			{File: "pkg/a.go", Remediation: 10},
+			{File: "pkg/a.go", Remediation: 20},
+		}},
+		{Violations: []*Violation{{File: "pkg/b.go", Remediation: 30}}},
+		{Violations: []*Violation{{File: "cmd/main.go", Remediation: 5}}},
+	}
+
+	summary := &ScanSummary{}
+	summary.CountDebt(results)
+
+	debt := summary.Debt
+	if debt.Minutes != 65 {
+		t.Errorf("Expected 65 minutes of debt, got %d", debt.Minutes)
+	}
+	if len(debt.Files) != 3 || debt.Files[0] != (DebtEntry{Name: "pkg/a.go", Minutes: 30, Violations: 2}) ||
+		debt.Files[1].Name != "pkg/b.go" {
+		t.Errorf("Expected files by debt, ties by name, got %+v", debt.Files)
+	}
+	if len(debt.Modules) != 2 || debt.Modules[0] != (DebtEntry{Name: "pkg", Minutes: 60, Violations: 3}) {
+		t.Errorf("Expected modules by debt, got %+v", debt.Modules)
+	}
+}
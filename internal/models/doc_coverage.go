@@ -0,0 +1,86 @@
+package models
+
+import (
+	"math"
+	"path/filepath"
+	"sort"
+)
+
+// DocCoverage is the share of public items with documentation, in total and
+// by module. A module is the directory of a file, as in TechnicalDebt.
+type DocCoverage struct {
+	Documented int                 `json:"documented"`
+	Public     int                 `json:"public"`
+	Percent    float64             `json:"percent"` // 0 to 100, rounded to one decimal
+	Modules    []ModuleDocCoverage `json:"modules"` // Ordered by module
+	Files      []FileDocCoverage   `json:"files"`   // Ordered by file
+}
+
+// ModuleDocCoverage is the documentation coverage of one module
+type ModuleDocCoverage struct {
+	Module     string  `json:"module"`
+	Documented int     `json:"documented"`
+	Public     int     `json:"public"`
+	Percent    float64 `json:"percent"`
+}
+
+// FileDocCoverage counts the public items of one file and those documented
+type FileDocCoverage struct {
+	File       string `json:"file"`
+	Documented int    `json:"documented"`
+	Public     int    `json:"public"`
+}
+
+// ComputeDocCoverage adds up the public items of files by module and in
+// total. It returns nil when no file has public items.
+func ComputeDocCoverage(files []FileDocCoverage) *DocCoverage {
+	coverage := &DocCoverage{}
+	modules := make(map[string]*ModuleDocCoverage)
+	for _, file := range files {
+		if file.Public == 0 {
+			continue
+		}
+		coverage.Files = append(coverage.Files, file)
+		coverage.Documented += file.Documented
+		coverage.Public += file.Public
+
+		name := filepath.ToSlash(filepath.Dir(file.File))
+		module, found := modules[name]
+		if !found {
+			module = &ModuleDocCoverage{Module: name}
+			modules[name] = module
+		}
+		module.Documented += file.Documented
+		module.Public += file.Public
+	}
+	if coverage.Public == 0 {
+		return nil
+	}
+	coverage.Percent = docPercent(coverage.Documented, coverage.Public)
+
+	for _, module := range modules {
+		module.Percent = docPercent(module.Documented, module.Public)
+		coverage.Modules = append(coverage.Modules, *module)
+	}
+	sort.Slice(coverage.Modules, func(i, j int) bool {
+		return coverage.Modules[i].Module < coverage.Modules[j].Module
+	})
+	sort.SliceStable(coverage.Files, func(i, j int) bool {
+		return coverage.Files[i].File < coverage.Files[j].File
+	})
+	return coverage
+}
+
+// LowestModules lists the modules by coverage, lowest first, then by module
+func (c *DocCoverage) LowestModules() []ModuleDocCoverage {
+	sorted := append([]ModuleDocCoverage(nil), c.Modules...)
+	sort.SliceStable(sorted, func(i, j int) bool {
+		return sorted[i].Percent < sorted[j].Percent
+	})
+	return sorted
+}
+
+// docPercent returns documented as a percentage of public, to one decimal
+func docPercent(documented, public int) float64 {
+	return math.Round(float64(documented)*1000/float64(public)) / 10
+}
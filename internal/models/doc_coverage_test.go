@@ -0,0 +1,32 @@
+package models
+
+import "testing"
+
+func TestComputeDocCoverage(t *testing.T) {
+	coverage := ComputeDocCoverage([]FileDocCoverage{
+		{File: "src/net/http.rs", Documented: 1, Public: 4},
+		{File: "src/lib.rs", Documented: 3, Public: 3},
+		{File: "src/net/tcp.rs", Documented: 2, Public: 2},
+		{File: "src/private.rs"},
+	})
+
+	if coverage.Documented != 6 || coverage.Public != 9 || coverage.Percent != 66.7 {
+		t.Errorf("Expected 6 of 9 items documented (66.7%%), got %+v", coverage)
+	}
+	if len(coverage.Files) != 3 || coverage.Files[0].File != "src/lib.rs" {
+		t.Errorf("Expected the files with public items ordered by file, got %+v", coverage.Files)
+	}
+	if len(coverage.Modules) != 2 {
+		t.Fatalf("Expected 2 modules, got %+v", coverage.Modules)
+	}
+	if net := coverage.Modules[1]; net.Module != "src/net" || net.Documented != 3 || net.Public != 6 || net.Percent != 50 {
+		t.Errorf("Unexpected src/net coverage %+v", net)
+	}
+	if lowest := coverage.LowestModules(); lowest[0].Module != "src/net" {
+		t.Errorf("Expected src/net to have the lowest coverage, got %+v", lowest)
+	}
+
+	if coverage := ComputeDocCoverage([]FileDocCoverage{{File: "src/private.rs"}}); coverage != nil {
+		t.Errorf("Expected no coverage without public items, got %+v", coverage)
+	}
+}
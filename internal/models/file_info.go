@@ -1,6 +1,9 @@
 package models
 
 import (
+	"math"
+	"path/filepath"
+	"sort"
 	"time"
 )
 
@@ -19,11 +22,37 @@ type FileInfo struct {
 
 // ScanResult represents the result of scanning a single file
 type ScanResult struct {
-	File        *FileInfo    `json:"file"`
-	Violations  []*Violation `json:"violations"`
-	Metrics     *FileMetrics `json:"metrics"`
-	ASTInfo     interface{}  `json:"ast_info,omitempty"`      // Go AST info when available
-	RustASTInfo interface{}  `json:"rust_ast_info,omitempty"` // Rust AST info when available
+	File         *FileInfo      `json:"file"`
+	Violations   []*Violation   `json:"violations"`
+	Metrics      *FileMetrics   `json:"metrics"`
+	ASTInfo      interface{}    `json:"ast_info,omitempty"`      // Go AST info when available
+	RustASTInfo  interface{}    `json:"rust_ast_info,omitempty"` // Rust AST info when available
+	Suppressions []*Suppression `json:"-"`                       // Inline goclean:ignore directives
+	Suppressed   []*Violation   `json:"suppressed,omitempty"`    // Violations hidden by inline directives
+	Baselined    []*Violation   `json:"baselined,omitempty"`     // Violations hidden by the baseline
+}
+
+// Suppression is an inline `goclean:ignore` directive in a source file
+type Suppression struct {
+	Rules      []string // Suppressed rule IDs; empty when the directive names no rule
+	Deprecated []string // Deprecated rule IDs named by the directive, resolved into Rules
+	Reason     string   // Optional justification
+	Line       int      // Line of the directive
+	TargetLine int      // Line whose violations are suppressed
+	Used       bool     // Set when the directive suppressed a violation
+}
+
+// Matches reports whether the directive suppresses the violation
+func (s *Suppression) Matches(v *Violation) bool {
+	if v.Line != s.TargetLine {
+		return false
+	}
+	for _, rule := range s.Rules {
+		if rule == v.Rule {
+			return true
+		}
+	}
+	return false
 }
 
 // FileMetrics contains basic metrics about a file
@@ -39,12 +68,248 @@ type FileMetrics struct {
 
 // ScanSummary provides an overview of the entire scan operation
 type ScanSummary struct {
-	TotalFiles       int           `json:"total_files"`
-	ScannedFiles     int           `json:"scanned_files"`
-	SkippedFiles     int           `json:"skipped_files"`
-	TotalViolations  int           `json:"total_violations"`
-	ViolationsByType map[string]int `json:"violations_by_type"`
-	StartTime        time.Time     `json:"start_time"`
-	EndTime          time.Time     `json:"end_time"`
-	Duration         time.Duration `json:"duration"`
-}
\ No newline at end of file
+	TotalFiles       int               `json:"total_files"`
+	ScannedFiles     int               `json:"scanned_files"`
+	SkippedFiles     int               `json:"skipped_files"`
+	TotalViolations  int               `json:"total_violations"`
+	ViolationsByType map[string]int    `json:"violations_by_type"`
+	StartTime        time.Time         `json:"start_time"`
+	EndTime          time.Time         `json:"end_time"`
+	Duration         time.Duration     `json:"duration"`
+	CloneGroups      []*CloneGroup     `json:"clone_groups,omitempty"` // Ranked by weight, heaviest first
+	Coupling         []*ModuleCoupling `json:"coupling,omitempty"`     // Ordered by module
+
+	// Maintainability holds the maintainability index of each file with functions, ordered by file
+	Maintainability []*FileMaintainability `json:"maintainability,omitempty"`
+
+	// BaselineSuppressed counts known violations hidden by a baseline file
+	BaselineSuppressed int `json:"baseline_suppressed,omitempty"`
+	// InlineSuppressed counts violations hidden by goclean:ignore comments
+	InlineSuppressed int `json:"inline_suppressed,omitempty"`
+	// OutsideDiff counts violations hidden because the diff against --diff-base does not touch them
+	OutsideDiff int `json:"outside_diff,omitempty"`
+	// ThirdParty counts violations in vendored and third-party code kept out of the totals
+	ThirdParty int `json:"third_party,omitempty"`
+
+	// Triage counts violations by triage state, see CountTriage
+	Triage *TriageCounts `json:"triage,omitempty"`
+	// Debt is the estimated time to fix the reported violations, see CountDebt
+	Debt *TechnicalDebt `json:"technical_debt,omitempty"`
+	// Health holds the health score of each scanned file, ordered by file
+	Health []*FileHealth `json:"file_health,omitempty"`
+	// DocCoverage is the share of public items with documentation, or nil without public items
+	DocCoverage *DocCoverage `json:"doc_coverage,omitempty"`
+	// PrimitiveObsession holds the primitive parameters and fields recurring across signatures, most recurring first
+	PrimitiveObsession []*PrimitiveCluster `json:"primitive_obsession,omitempty"`
+	// Fixed holds the baseline entries no longer found, as violations in the fixed state
+	Fixed []*Violation `json:"fixed,omitempty"`
+}
+
+// TriageCounts counts the violations of a scan in each triage state
+type TriageCounts struct {
+	New        int `json:"new"`
+	Baseline   int `json:"baseline"`
+	Suppressed int `json:"suppressed"`
+	Fixed      int `json:"fixed"`
+}
+
+// CountTriage marks the reported violations that have no triage state as
+// new and counts the violations of each state in the summary
+func (s *ScanSummary) CountTriage(results []*ScanResult) {
+	counts := &TriageCounts{Fixed: len(s.Fixed)}
+	for _, result := range results {
+		for _, violation := range result.Violations {
+			if violation.Triage == "" {
+				violation.Triage = TriageNew
+			}
+			counts.New++
+		}
+		counts.Baseline += len(result.Baselined)
+		counts.Suppressed += len(result.Suppressed)
+	}
+	s.Triage = counts
+}
+
+// RemoveViolation updates the counts for a violation filtered out of the results
+func (s *ScanSummary) RemoveViolation(v *Violation) {
+	s.TotalViolations--
+	if s.ViolationsByType == nil {
+		return
+	}
+	violationType := string(v.Type)
+	s.ViolationsByType[violationType]--
+	if s.ViolationsByType[violationType] <= 0 {
+		delete(s.ViolationsByType, violationType)
+	}
+}
+
+// CloneGroup is a token sequence duplicated at two or more locations
+type CloneGroup struct {
+	ID               int              `json:"id"`
+	Tokens           int              `json:"tokens"`                // Tokens shared by every member
+	Lines            int              `json:"lines"`                 // Lines of the longest member
+	DuplicatedTokens int              `json:"duplicated_tokens"`     // Tokens removable by extracting the clone
+	Complexity       int              `json:"complexity,omitempty"`  // Decision points in the shared code, plus one
+	Weight           int              `json:"weight,omitempty"`      // Duplicated tokens scaled by complexity
+	Granularity      CloneGranularity `json:"granularity,omitempty"` // How far apart the members are
+	Members          []CloneLocation  `json:"members"`
+}
+
+// CloneGranularity is how far apart the copies of a clone are
+type CloneGranularity string
+
+// Clone granularities, from the closest copies to the farthest apart. A
+// module is the directory of a file: a Go package, or a Rust module directory.
+const (
+	CloneIntraFile   CloneGranularity = "intra-file"   // Every copy is in one file
+	CloneIntraModule CloneGranularity = "intra-module" // Copies are in several files of one module
+	CloneCrossModule CloneGranularity = "cross-module" // Copies are in several modules
+)
+
+// CloneGranularityOf returns the granularity of copies in the given files
+func CloneGranularityOf(files ...string) CloneGranularity {
+	granularity := CloneIntraFile
+	if len(files) == 0 {
+		return granularity
+	}
+	for _, file := range files[1:] {
+		switch {
+		case filepath.Dir(file) != filepath.Dir(files[0]):
+			return CloneCrossModule
+		case file != files[0]:
+			granularity = CloneIntraModule
+		}
+	}
+	return granularity
+}
+
+// RankCloneGroups orders clone groups by weight, heaviest first, so that
+// duplicated complex code is refactored before duplicated simple code, and
+// numbers them in that order
+func RankCloneGroups(groups []*CloneGroup) {
+	sort.SliceStable(groups, func(i, j int) bool {
+		a, b := groups[i], groups[j]
+		if a.Weight != b.Weight {
+			return a.Weight > b.Weight
+		}
+		if a.DuplicatedTokens != b.DuplicatedTokens {
+			return a.DuplicatedTokens > b.DuplicatedTokens
+		}
+		if len(a.Members) == 0 || len(b.Members) == 0 {
+			return len(a.Members) > len(b.Members)
+		}
+		if a.Members[0].File != b.Members[0].File {
+			return a.Members[0].File < b.Members[0].File
+		}
+		return a.Members[0].StartLine < b.Members[0].StartLine
+	})
+	for i, group := range groups {
+		group.ID = i + 1
+	}
+}
+
+// ModuleCoupling describes how a module depends on other modules and how
+// other scanned modules depend on it. A Go module is a package, named by its
+// import path; a Rust module is named by its path in the crate.
+type ModuleCoupling struct {
+	Module       string   `json:"module"`
+	Language     string   `json:"language"`
+	Files        int      `json:"files"`
+	Efferent     int      `json:"efferent"`               // Modules this module depends on
+	Afferent     int      `json:"afferent"`               // Scanned modules depending on this module
+	Instability  float64  `json:"instability"`            // Efferent / (Afferent + Efferent)
+	MaxFanOut    int      `json:"max_fan_out"`            // Most modules called by one function of the module
+	Dependencies []string `json:"dependencies,omitempty"` // Modules this module depends on, sorted
+}
+
+// LinkCoupling sets the afferent coupling and instability of modules from
+// the dependencies of the others and orders them by module
+func LinkCoupling(modules []*ModuleCoupling) {
+	afferent := make(map[string]int)
+	for _, module := range modules {
+		for _, dep := range module.Dependencies {
+			afferent[dep]++
+		}
+	}
+	for _, module := range modules {
+		module.Efferent = len(module.Dependencies)
+		module.Afferent = afferent[module.Module]
+		module.Instability = 0
+		if total := module.Afferent + module.Efferent; total > 0 {
+			module.Instability = float64(module.Efferent) / float64(total)
+		}
+	}
+	sort.SliceStable(modules, func(i, j int) bool {
+		return modules[i].Module < modules[j].Module
+	})
+}
+
+// CloneLocation is one copy of a cloned token sequence
+type CloneLocation struct {
+	File      string `json:"file"`
+	StartLine int    `json:"start_line"`
+	EndLine   int    `json:"end_line"`
+}
+
+// FileMaintainability is the maintainability index of a file and its
+// functions, on a 0-100 scale where higher is easier to maintain
+type FileMaintainability struct {
+	File      string                    `json:"file"`
+	Language  string                    `json:"language"`
+	Index     float64                   `json:"index"` // Mean index of the functions
+	Functions []FunctionMaintainability `json:"functions"`
+}
+
+// FunctionMaintainability is the maintainability index of a function and
+// the metrics it is computed from
+type FunctionMaintainability struct {
+	Name       string          `json:"name"`
+	Line       int             `json:"line"`
+	EndLine    int             `json:"end_line"`
+	Lines      int             `json:"lines"`
+	Complexity int             `json:"complexity"`
+	Volume     float64         `json:"volume"` // Halstead volume
+	Index      float64         `json:"index"`
+	Halstead   HalsteadMetrics `json:"halstead"`
+}
+
+// HalsteadMetrics are the Halstead measures of a function
+type HalsteadMetrics struct {
+	DistinctOperators int     `json:"distinct_operators"`
+	DistinctOperands  int     `json:"distinct_operands"`
+	Operators         int     `json:"operators"`
+	Operands          int     `json:"operands"`
+	Volume            float64 `json:"volume"`
+	Difficulty        float64 `json:"difficulty"`
+	Effort            float64 `json:"effort"`
+}
+
+// LowestMaintainability returns the files ordered by maintainability index,
+// least maintainable first
+func LowestMaintainability(files []*FileMaintainability) []*FileMaintainability {
+	sorted := append([]*FileMaintainability(nil), files...)
+	sort.SliceStable(sorted, func(i, j int) bool {
+		if sorted[i].Index != sorted[j].Index {
+			return sorted[i].Index < sorted[j].Index
+		}
+		return sorted[i].File < sorted[j].File
+	})
+	return sorted
+}
+
+// MeanMaintainability returns the mean maintainability index of all
+// functions of the files, the single number to track across scans, and the
+// number of functions
+func MeanMaintainability(files []*FileMaintainability) (float64, int) {
+	total, count := 0.0, 0
+	for _, file := range files {
+		for _, function := range file.Functions {
+			total += function.Index
+			count++
+		}
+	}
+	if count == 0 {
+		return 0, 0
+	}
+	return math.Round(total/float64(count)*10) / 10, count
+}
@@ -363,4 +363,63 @@ func TestFileMetricsEdgeCases(t *testing.T) {
 	if commentOnlyMetrics.CommentLines != 5 {
 		t.Errorf("Expected 5 comment lines, got %d", commentOnlyMetrics.CommentLines)
 	}
-}
\ No newline at end of file
+}
+func TestRankCloneGroups(t *testing.T) {
+	getters := &CloneGroup{DuplicatedTokens: 90, Complexity: 1, Weight: 90,
+		Members: []CloneLocation{{File: "a.go", StartLine: 1}}}
+	validation := &CloneGroup{DuplicatedTokens: 60, Complexity: 5, Weight: 300,
+		Members: []CloneLocation{{File: "b.go", StartLine: 10}}}
+	earlier := &CloneGroup{DuplicatedTokens: 90, Complexity: 1, Weight: 90,
+		Members: []CloneLocation{{File: "a.go", StartLine: 0}}}
+
+	groups := []*CloneGroup{getters, validation, earlier}
+	RankCloneGroups(groups)
+
+	if groups[0] != validation || groups[1] != earlier || groups[2] != getters {
+		t.Errorf("Expected the complex clone first and ties ordered by location, got %+v", groups)
+	}
+	for i, group := range groups {
+		if group.ID != i+1 {
+			t.Errorf("Expected group %d to be numbered %d, got %d", i, i+1, group.ID)
+		}
+	}
+}
+
+func TestCloneGranularityOf(t *testing.T) {
+	tests := []struct {
+		files []string
+		want  CloneGranularity
+	}{
+		{[]string{"pkg/a.go", "pkg/a.go"}, CloneIntraFile},
+		{[]string{"pkg/a.go", "pkg/b.go", "pkg/a.go"}, CloneIntraModule},
+		{[]string{"pkg/a.go", "pkg/b.go", "other/a.go"}, CloneCrossModule},
+		{[]string{"src/lib.rs", "src/parser/mod.rs"}, CloneCrossModule},
+	}
+	for _, tt := range tests {
+		if got := CloneGranularityOf(tt.files...); got != tt.want {
+			t.Errorf("CloneGranularityOf(%v) = %s, expected %s", tt.files, got, tt.want)
+		}
+	}
+}
+
+func TestCountTriage(t *testing.T) {
+	reported := &Violation{Rule: "function-length"}
+	results := []*ScanResult{
+		{
+			Violations: []*Violation{reported},
+			Suppressed: []*Violation{{Rule: "magic-number", Triage: TriageSuppressed}},
+			Baselined:  []*Violation{{Rule: "class-size", Triage: TriageBaseline}, {Rule: "todo-comment", Triage: TriageBaseline}},
+		},
+		{Violations: []*Violation{}},
+	}
+	summary := &ScanSummary{Fixed: []*Violation{{Rule: "nesting-depth", Triage: TriageFixed}}}
+	summary.CountTriage(results)
+
+	if reported.Triage != TriageNew {
+		t.Errorf("Expected the reported violation to be new, got %q", reported.Triage)
+	}
+	want := TriageCounts{New: 1, Baseline: 2, Suppressed: 1, Fixed: 1}
+	if summary.Triage == nil || *summary.Triage != want {
+		t.Errorf("Expected %+v, got %+v", want, summary.Triage)
+	}
+}
@@ -0,0 +1,24 @@
+package models
+
+// Fix is a remediation that can be applied to the source without review.
+// Detectors attach a Fix to a violation when the correct change is known.
+type Fix struct {
+	Description string     `json:"description"`
+	Edits       []TextEdit `json:"edits"`
+}
+
+// TextEdit replaces the text between two positions of the violation's file.
+// Lines and columns are 1-based, columns count bytes and the end position is
+// exclusive. An empty NewText deletes the range.
+type TextEdit struct {
+	Line      int    `json:"line"`
+	Column    int    `json:"column"`
+	EndLine   int    `json:"end_line"`
+	EndColumn int    `json:"end_column"`
+	NewText   string `json:"new_text"`
+}
+
+// DeleteLinesEdit returns an edit removing the whole lines startLine through endLine
+func DeleteLinesEdit(startLine, endLine int) TextEdit {
+	return TextEdit{Line: startLine, Column: 1, EndLine: endLine + 1, EndColumn: 1}
+}
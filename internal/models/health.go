@@ -0,0 +1,60 @@
+package models
+
+import "sort"
+
+// FileHealth is the health score of one file, from 0 (worst) to 100 (best),
+// combining the metrics of its functions with the violations reported in it
+type FileHealth struct {
+	File       string `json:"file"`
+	Score      int    `json:"score"`
+	Violations int    `json:"violations"`
+}
+
+// HealthByFile returns the health of the scored files by path, or nil when
+// the files were not scored
+func (r *Report) HealthByFile() map[string]*FileHealth {
+	if r.Summary == nil || len(r.Summary.Health) == 0 {
+		return nil
+	}
+	byFile := make(map[string]*FileHealth, len(r.Summary.Health))
+	for _, file := range r.Summary.Health {
+		byFile[file.File] = file
+	}
+	return byFile
+}
+
+// LowestHealth returns the files sorted by score, least healthy first, then by path
+func LowestHealth(files []*FileHealth) []*FileHealth {
+	sorted := append([]*FileHealth(nil), files...)
+	sort.SliceStable(sorted, func(i, j int) bool {
+		if sorted[i].Score != sorted[j].Score {
+			return sorted[i].Score < sorted[j].Score
+		}
+		return sorted[i].File < sorted[j].File
+	})
+	return sorted
+}
+
+// FilesBelowHealth returns the files scoring below minScore, least healthy first
+func FilesBelowHealth(files []*FileHealth, minScore int) []*FileHealth {
+	var below []*FileHealth
+	for _, file := range LowestHealth(files) {
+		if file.Score >= minScore {
+			break
+		}
+		below = append(below, file)
+	}
+	return below
+}
+
+// HealthLabel rates a score as healthy from 80, fair from 50, and poor below
+func HealthLabel(score int) string {
+	switch {
+	case score >= 80:
+		return "healthy"
+	case score >= 50:
+		return "fair"
+	default:
+		return "poor"
+	}
+}
@@ -0,0 +1,41 @@
+package models
+
+import "testing"
+
+func TestFilesBelowHealth(t *testing.T) {
+	files := []*FileHealth{
+		{File: "a.go", Score: 90},
+		{File: "c.go", Score: 40},
+		{File: "b.go", Score: 40},
+		{File: "d.go", Score: 65},
+	}
+
+	below := FilesBelowHealth(files, 70)
+	if len(below) != 3 || below[0].File != "b.go" || below[1].File != "c.go" || below[2].File != "d.go" {
+		t.Errorf("Expected the files below 70, least healthy first, got %+v", below)
+	}
+	if files[0].File != "a.go" || files[1].File != "c.go" {
+		t.Error("Expected the original order to be kept")
+	}
+	if below := FilesBelowHealth(files, 40); len(below) != 0 {
+		t.Errorf("Expected no file below 40, got %+v", below)
+	}
+}
+
+func TestHealthByFile(t *testing.T) {
+	report := &Report{Summary: &ScanSummary{Health: []*FileHealth{{File: "a.go", Score: 80}}}}
+	if byFile := report.HealthByFile(); byFile["a.go"].Score != 80 || byFile["b.go"] != nil {
+		t.Errorf("Unexpected health by file %+v", byFile)
+	}
+	if byFile := (&Report{}).HealthByFile(); byFile != nil {
+		t.Errorf("Expected no health without a summary, got %+v", byFile)
+	}
+}
+
+func TestHealthLabel(t *testing.T) {
+	for score, expected := range map[int]string{100: "healthy", 80: "healthy", 79: "fair", 50: "fair", 49: "poor", 0: "poor"} {
+		if label := HealthLabel(score); label != expected {
+			t.Errorf("HealthLabel(%d) = %q, expected %q", score, label, expected)
+		}
+	}
+}
@@ -0,0 +1,38 @@
+package models
+
+import (
+	"fmt"
+	"time"
+)
+
+// ScanManifest records what produced a scan result. Results are only
+// comparable when the same rule set produced them.
+type ScanManifest struct {
+	ToolVersion string        `json:"tool_version"`
+	RuleSetHash string        `json:"rule_set_hash"` // Hash of the tool version and the rule settings
+	ConfigHash  string        `json:"config_hash"`   // Hash of the whole configuration
+	GitSHA      string        `json:"git_sha,omitempty"`
+	GitDirty    bool          `json:"git_dirty,omitempty"` // The working tree had uncommitted changes
+	Platform    string        `json:"platform"`            // GOOS/GOARCH
+	Duration    time.Duration `json:"duration"`
+}
+
+// CheckRuleSets returns an error when the manifests name different rule
+// sets. Results without a manifest, from older versions, are not checked.
+func CheckRuleSets(manifests []*ScanManifest) error {
+	var first *ScanManifest
+	for _, manifest := range manifests {
+		if manifest == nil {
+			continue
+		}
+		if first == nil {
+			first = manifest
+			continue
+		}
+		if manifest.RuleSetHash != first.RuleSetHash {
+			return fmt.Errorf("results were produced by different rule sets (%s with goclean %s, %s with goclean %s)",
+				first.RuleSetHash, first.ToolVersion, manifest.RuleSetHash, manifest.ToolVersion)
+		}
+	}
+	return nil
+}
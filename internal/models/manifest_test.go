@@ -0,0 +1,15 @@
+package models
+
+import "testing"
+
+func TestCheckRuleSets(t *testing.T) {
+	a := &ScanManifest{ToolVersion: "1.2.0", RuleSetHash: "aaa"}
+	b := &ScanManifest{ToolVersion: "1.3.0", RuleSetHash: "bbb"}
+
+	if err := CheckRuleSets([]*ScanManifest{a, nil, a}); err != nil {
+		t.Errorf("Expected matching rule sets to pass, got %v", err)
+	}
+	if err := CheckRuleSets([]*ScanManifest{nil, a, b}); err == nil {
+		t.Error("Expected an error for different rule sets")
+	}
+}
@@ -0,0 +1,104 @@
+package models
+
+import (
+	"sort"
+	"strings"
+)
+
+// PrimitiveCluster is a parameter or field name that recurs with the same
+// primitive type across the functions and structs of a scan, such as
+// user_id: u64, a candidate for a type of its own. Names match whatever
+// their case, so user_id and userId are one name.
+type PrimitiveCluster struct {
+	Name     string         `json:"name"`     // As written at its first use
+	Type     string         `json:"type"`     // Primitive type, references removed
+	Language string         `json:"language"` // go or rust
+	Newtype  string         `json:"newtype"`  // Suggested name of the type, e.g. UserId
+	Uses     []PrimitiveUse `json:"uses"`     // Ordered by file and line
+}
+
+// PrimitiveUse is one parameter or field of a primitive cluster
+type PrimitiveUse struct {
+	File   string `json:"file"`
+	Line   int    `json:"line"`
+	Column int    `json:"column"`
+	Owner  string `json:"owner"` // Function or struct declaring it
+	Kind   string `json:"kind"`  // parameter or field
+}
+
+// Signatures returns the number of functions and structs the name recurs in
+func (c *PrimitiveCluster) Signatures() int {
+	owners := make(map[string]bool)
+	for _, use := range c.Uses {
+		owners[use.File+"\x00"+use.Owner] = true
+	}
+	return len(owners)
+}
+
+// Files returns the number of files the name recurs in
+func (c *PrimitiveCluster) Files() int {
+	files := make(map[string]bool)
+	for _, use := range c.Uses {
+		files[use.File] = true
+	}
+	return len(files)
+}
+
+// MergePrimitiveClusters combines clusters of the same name, type and
+// language, keeping each use once. Clusters are ordered by the signatures
+// they recur in, most first, then by name.
+func MergePrimitiveClusters(lists ...[]*PrimitiveCluster) []*PrimitiveCluster {
+	var merged []*PrimitiveCluster
+	byKey := make(map[string]*PrimitiveCluster)
+	seen := make(map[*PrimitiveCluster]map[PrimitiveUse]bool)
+	for _, clusters := range lists {
+		for _, cluster := range clusters {
+			key := cluster.Language + "\x00" + cluster.Type + "\x00" + strings.ToLower(cluster.Newtype)
+			target, found := byKey[key]
+			if !found {
+				target = &PrimitiveCluster{Name: cluster.Name, Type: cluster.Type, Language: cluster.Language, Newtype: cluster.Newtype}
+				byKey[key] = target
+				seen[target] = make(map[PrimitiveUse]bool)
+				merged = append(merged, target)
+			}
+			for _, use := range cluster.Uses {
+				if !seen[target][use] {
+					seen[target][use] = true
+					target.Uses = append(target.Uses, use)
+				}
+			}
+		}
+	}
+	for _, cluster := range merged {
+		SortPrimitiveUses(cluster.Uses)
+	}
+	SortPrimitiveClusters(merged)
+	return merged
+}
+
+// SortPrimitiveUses orders uses by file, line and column
+func SortPrimitiveUses(uses []PrimitiveUse) {
+	sort.SliceStable(uses, func(i, j int) bool {
+		if uses[i].File != uses[j].File {
+			return uses[i].File < uses[j].File
+		}
+		if uses[i].Line != uses[j].Line {
+			return uses[i].Line < uses[j].Line
+		}
+		return uses[i].Column < uses[j].Column
+	})
+}
+
+// SortPrimitiveClusters orders clusters by the signatures they recur in,
+// most first, then by name and type
+func SortPrimitiveClusters(clusters []*PrimitiveCluster) {
+	sort.SliceStable(clusters, func(i, j int) bool {
+		if si, sj := clusters[i].Signatures(), clusters[j].Signatures(); si != sj {
+			return si > sj
+		}
+		if clusters[i].Name != clusters[j].Name {
+			return clusters[i].Name < clusters[j].Name
+		}
+		return clusters[i].Type < clusters[j].Type
+	})
+}
@@ -0,0 +1,42 @@
+package models
+
+import "testing"
+
+func TestMergePrimitiveClusters(t *testing.T) {
+	first := []*PrimitiveCluster{{
+		Name: "user_id", Type: "u64", Language: "rust", Newtype: "UserId",
+		Uses: []PrimitiveUse{
+			{File: "src/b.rs", Line: 3, Owner: "ship", Kind: "parameter"},
+			{File: "src/a.rs", Line: 1, Owner: "Order", Kind: "field"},
+		},
+	}}
+	second := []*PrimitiveCluster{
+		{
+			Name: "userId", Type: "u64", Language: "rust", Newtype: "UserId",
+			Uses: []PrimitiveUse{
+				{File: "src/a.rs", Line: 1, Owner: "Order", Kind: "field"},
+				{File: "src/c.rs", Line: 7, Owner: "cancel", Kind: "parameter"},
+				{File: "src/c.rs", Line: 9, Owner: "refund", Kind: "parameter"},
+			},
+		},
+		{
+			Name: "email", Type: "String", Language: "rust", Newtype: "Email",
+			Uses: []PrimitiveUse{{File: "src/c.rs", Line: 2, Owner: "Customer", Kind: "field"}},
+		},
+	}
+
+	merged := MergePrimitiveClusters(first, second)
+	if len(merged) != 2 {
+		t.Fatalf("Expected 2 clusters, got %d", len(merged))
+	}
+	userID := merged[0]
+	if userID.Name != "user_id" || len(userID.Uses) != 4 || userID.Signatures() != 4 || userID.Files() != 3 {
+		t.Errorf("Expected the user_id clusters merged with each use once, got %+v", userID)
+	}
+	if userID.Uses[0].File != "src/a.rs" || userID.Uses[3].Line != 9 {
+		t.Errorf("Expected the uses ordered by file and line, got %+v", userID.Uses)
+	}
+	if merged[1].Name != "email" {
+		t.Errorf("Expected the less recurring email cluster last, got %s", merged[1].Name)
+	}
+}
@@ -13,6 +13,7 @@ type Report struct {
 	Summary     *ScanSummary   `json:"summary"`
 	Files       []*ScanResult  `json:"files"`
 	Statistics  *Statistics    `json:"statistics"`
+	Manifest    *ScanManifest  `json:"manifest,omitempty"`
 }
 
 // ReportConfig contains configuration settings used for this report
@@ -27,6 +28,7 @@ type ReportConfig struct {
 type Thresholds struct {
 	FunctionLines        int `json:"function_lines"`
 	CyclomaticComplexity int `json:"cyclomatic_complexity"`
+	CognitiveComplexity  int `json:"cognitive_complexity"`
 	Parameters           int `json:"parameters"`
 	NestingDepth         int `json:"nesting_depth"`
 	ClassLines           int `json:"class_lines"`
@@ -239,6 +241,47 @@ func (s Severity) GetColor() string {
 	}
 }
 
+// Categories of the general violation types. Rust-specific types use the
+// categories of GetRustViolationCategory.
+const (
+	CategoryComplexity      = "complexity"
+	CategoryNaming          = "naming"
+	CategoryDocumentation   = "documentation"
+	CategoryMaintainability = "maintainability"
+	CategoryDuplication     = "duplication"
+	CategoryStructure       = "structure"
+	CategorySuppression     = "suppression"
+	CategoryGeneral         = "general"
+)
+
+// Category returns the category of a violation type, for grouping in
+// machine-readable reports
+func (vt ViolationType) Category() string {
+	if category := GetRustViolationCategory(vt); category != "" {
+		return string(category)
+	}
+	switch vt {
+	case ViolationTypeFunctionLength, ViolationTypeCyclomaticComplexity, ViolationTypeCognitiveComplexity,
+		ViolationTypeParameterCount, ViolationTypeNestingDepth, ViolationTypeHalstead:
+		return CategoryComplexity
+	case ViolationTypeNaming:
+		return CategoryNaming
+	case ViolationTypeMissingDocumentation, ViolationTypeDocumentation:
+		return CategoryDocumentation
+	case ViolationTypeMagicNumber, ViolationTypeMagicString, ViolationTypeCommentedCode, ViolationTypeTodo,
+		ViolationTypePastedCode, ViolationTypeMaintainability:
+		return CategoryMaintainability
+	case ViolationTypeDuplication:
+		return CategoryDuplication
+	case ViolationTypeClassSize, ViolationTypeStructure, ViolationTypeFanOut, ViolationTypePrimitiveObsession:
+		return CategoryStructure
+	case ViolationTypeSuppression:
+		return CategorySuppression
+	default:
+		return CategoryGeneral
+	}
+}
+
 // GetTypeDisplayName returns a human-readable name for violation types
 func (vt ViolationType) GetDisplayName() string {
 	switch vt {
@@ -246,6 +289,8 @@ func (vt ViolationType) GetDisplayName() string {
 		return "Long Functions"
 	case ViolationTypeCyclomaticComplexity:
 		return "Complex Functions"
+	case ViolationTypeCognitiveComplexity:
+		return "Hard-to-Understand Functions"
 	case ViolationTypeParameterCount:
 		return "Too Many Parameters"
 	case ViolationTypeNestingDepth:
@@ -258,8 +303,24 @@ func (vt ViolationType) GetDisplayName() string {
 		return "Missing Documentation"
 	case ViolationTypeMagicNumber:
 		return "Magic Numbers"
+	case ViolationTypeMagicString:
+		return "Magic Strings"
 	case ViolationTypeDuplication:
 		return "Code Duplication"
+	case ViolationTypeFanOut:
+		return "High Fan-Out"
+	case ViolationTypeMaintainability:
+		return "Low Maintainability Index"
+	case ViolationTypeHalstead:
+		return "High Halstead Metric"
+	case ViolationTypePastedCode:
+		return "Possibly Pasted Code"
+	case ViolationTypePrimitiveObsession:
+		return "Primitive Obsession"
+	case ViolationTypePlugin:
+		return "Plugin Rule"
+	case ViolationTypeScriptRule:
+		return "Script Rule"
 	default:
 		return string(vt)
 	}
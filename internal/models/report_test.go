@@ -233,6 +233,23 @@ func TestViolationTypeGetDisplayName(t *testing.T) {
 	}
 }
 
+func TestViolationTypeCategory(t *testing.T) {
+	categoryTests := map[ViolationType]string{
+		ViolationTypeCognitiveComplexity: CategoryComplexity,
+		ViolationTypeNaming:              CategoryNaming,
+		ViolationTypeMagicNumber:         CategoryMaintainability,
+		ViolationTypeDuplication:         CategoryDuplication,
+		ViolationTypeRustOveruseUnwrap:   string(RustCategoryErrorHandling),
+		ViolationType("custom_rule"):     CategoryGeneral,
+	}
+
+	for violationType, expected := range categoryTests {
+		if category := violationType.Category(); category != expected {
+			t.Errorf("Expected category '%s' for type %s, got '%s'", expected, violationType, category)
+		}
+	}
+}
+
 func TestGenerateReportID(t *testing.T) {
 	id1 := generateReportID()
 	time.Sleep(time.Second) // Ensure different timestamps (seconds precision)
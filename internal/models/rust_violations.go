@@ -66,7 +66,8 @@ func GetRustViolationCategory(violationType ViolationType) RustViolationCategory
 		 ViolationTypeRustNestedPatternMatching,
 		 ViolationTypeRustInefficientDestructuring,
 		 ViolationTypeRustUnreachablePattern,
-		 ViolationTypeRustMissingMatchArm:
+		 ViolationTypeRustMissingMatchArm,
+		 ViolationTypeRustMatchOnBool:
 		return RustCategoryPatternMatching
 		
 	// Trait violations
@@ -86,14 +87,16 @@ func GetRustViolationCategory(violationType ViolationType) RustViolationCategory
 	case ViolationTypeRustAsyncFnInTrait,
 		 ViolationTypeRustSendSyncViolation,
 		 ViolationTypeRustDeadlockProne,
-		 ViolationTypeRustRaceCondition:
+		 ViolationTypeRustRaceCondition,
+		 ViolationTypeRustUnboundedChannel:
 		return RustCategoryAsync
 		
 	// Module violations
 	case ViolationTypeRustImproperVisibility,
 		 ViolationTypeRustCircularDependency,
 		 ViolationTypeRustModuleOrganization,
-		 ViolationTypeRustUnusedImport:
+		 ViolationTypeRustUnusedImport,
+		 ViolationTypeRustUnusedPublicItem:
 		return RustCategoryModules
 		
 	default:
@@ -184,6 +187,8 @@ func GetRustViolationDescription(violationType ViolationType) string {
 		return "Unreachable pattern in match expression"
 	case ViolationTypeRustMissingMatchArm:
 		return "Missing match arm for important cases"
+	case ViolationTypeRustMatchOnBool:
+		return "Match on a bool instead of if/else"
 		
 	// Trait violations
 	case ViolationTypeRustOverlyComplexTrait:
@@ -212,6 +217,8 @@ func GetRustViolationDescription(violationType ViolationType) string {
 		return "Code pattern prone to deadlocks"
 	case ViolationTypeRustRaceCondition:
 		return "Potential race condition detected"
+	case ViolationTypeRustUnboundedChannel:
+		return "Unbounded channel without backpressure"
 		
 	// Module violations
 	case ViolationTypeRustImproperVisibility:
@@ -222,6 +229,8 @@ func GetRustViolationDescription(violationType ViolationType) string {
 		return "Poor module organization structure"
 	case ViolationTypeRustUnusedImport:
 		return "Unused import statement"
+	case ViolationTypeRustUnusedPublicItem:
+		return "Public item never used in the workspace"
 		
 	default:
 		return "Unknown Rust violation"
@@ -306,6 +315,8 @@ func GetRustViolationSuggestion(violationType ViolationType) string {
 		return "Remove unreachable pattern or reorder match arms"
 	case ViolationTypeRustMissingMatchArm:
 		return "Add match arms for important cases instead of using catch-all"
+	case ViolationTypeRustMatchOnBool:
+		return "Use if/else, which states a two-way choice more plainly"
 		
 	// Trait violations
 	case ViolationTypeRustOverlyComplexTrait:
@@ -334,6 +345,8 @@ func GetRustViolationSuggestion(violationType ViolationType) string {
 		return "Acquire locks in consistent order or use timeout-based locking"
 	case ViolationTypeRustRaceCondition:
 		return "Use atomic operations or proper synchronization mechanisms"
+	case ViolationTypeRustUnboundedChannel:
+		return "Use a bounded channel so that slow consumers push back on producers"
 		
 	// Module violations
 	case ViolationTypeRustImproperVisibility:
@@ -344,6 +357,8 @@ func GetRustViolationSuggestion(violationType ViolationType) string {
 		return "Organize related functionality into logical module hierarchies"
 	case ViolationTypeRustUnusedImport:
 		return "Remove unused import or use #[allow(unused_imports)] if needed for conditional compilation"
+	case ViolationTypeRustUnusedPublicItem:
+		return "Remove the unused item, or narrow its visibility to pub(crate) until something uses it"
 		
 	default:
 		return "Refer to Rust documentation and best practices"
@@ -379,6 +394,7 @@ func GetDefaultRustViolationSeverity(violationType ViolationType) Severity {
 		 ViolationTypeRustMacroComplexity,
 		 ViolationTypeRustAsyncFnInTrait,
 		 ViolationTypeRustSendSyncViolation,
+		 ViolationTypeRustUnboundedChannel,
 		 ViolationTypeRustCircularDependency:
 		return SeverityMedium
 		
@@ -400,13 +416,15 @@ func GetDefaultRustViolationSeverity(violationType ViolationType) Severity {
 		 ViolationTypeRustInefficientDestructuring,
 		 ViolationTypeRustUnreachablePattern,
 		 ViolationTypeRustMissingMatchArm,
+		 ViolationTypeRustMatchOnBool,
 		 ViolationTypeRustMissingTraitImpl,
 		 ViolationTypeRustOrphanRule,
 		 ViolationTypeRustMacroHygiene,
 		 ViolationTypeRustProceduralMacroMisuse,
 		 ViolationTypeRustImproperVisibility,
 		 ViolationTypeRustModuleOrganization,
-		 ViolationTypeRustUnusedImport:
+		 ViolationTypeRustUnusedImport,
+		 ViolationTypeRustUnusedPublicItem:
 		return SeverityLow
 		
 	default:
@@ -421,6 +421,7 @@ func TestAllRustViolationTypesHaveCategory(t *testing.T) {
 		ViolationTypeRustInefficientDestructuring,
 		ViolationTypeRustUnreachablePattern,
 		ViolationTypeRustMissingMatchArm,
+		ViolationTypeRustMatchOnBool,
 		
 		// Trait violations
 		ViolationTypeRustOverlyComplexTrait,
@@ -438,12 +439,14 @@ func TestAllRustViolationTypesHaveCategory(t *testing.T) {
 		ViolationTypeRustSendSyncViolation,
 		ViolationTypeRustDeadlockProne,
 		ViolationTypeRustRaceCondition,
+		ViolationTypeRustUnboundedChannel,
 		
 		// Module violations
 		ViolationTypeRustImproperVisibility,
 		ViolationTypeRustCircularDependency,
 		ViolationTypeRustModuleOrganization,
 		ViolationTypeRustUnusedImport,
+		ViolationTypeRustUnusedPublicItem,
 	}
 	
 	for _, violationType := range rustViolationTypes {
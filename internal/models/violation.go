@@ -1,5 +1,14 @@
 package models
 
+import (
+	"crypto/sha256"
+	"encoding/hex"
+	"fmt"
+	"path/filepath"
+	"strings"
+	"time"
+)
+
 // Severity represents the severity level of a violation
 type Severity int
 
@@ -29,6 +38,37 @@ func (s Severity) String() string {
 	}
 }
 
+// severityAliases maps the Code Climate names for the middle levels to severities
+var severityAliases = map[string]Severity{
+	"minor": SeverityLow,
+	"major": SeverityMedium,
+}
+
+// ParseSeverity returns the severity with the given name, ignoring case.
+// "minor" and "major" are accepted for Low and Medium.
+func ParseSeverity(name string) (Severity, error) {
+	for s := SeverityInfo; s <= SeverityCritical; s++ {
+		if strings.EqualFold(name, s.String()) {
+			return s, nil
+		}
+	}
+	if s, ok := severityAliases[strings.ToLower(name)]; ok {
+		return s, nil
+	}
+	return SeverityInfo, fmt.Errorf("unknown severity %q", name)
+}
+
+// TriageState tells how a violation relates to the baseline and to inline
+// suppressions, so a report shows both what is broken and what changed
+type TriageState string
+
+const (
+	TriageNew        TriageState = "new"        // Reported: not in the baseline nor suppressed
+	TriageBaseline   TriageState = "baseline"   // Hidden because the baseline records it
+	TriageSuppressed TriageState = "suppressed" // Hidden by a goclean:ignore directive
+	TriageFixed      TriageState = "fixed"      // Recorded in the baseline but no longer found
+)
+
 // ViolationType represents the category of violation
 type ViolationType string
 
@@ -36,12 +76,14 @@ const (
 	// General violation types (language-agnostic)
 	ViolationTypeFunctionLength         ViolationType = "function_length"
 	ViolationTypeCyclomaticComplexity  ViolationType = "cyclomatic_complexity"
+	ViolationTypeCognitiveComplexity   ViolationType = "cognitive_complexity"
 	ViolationTypeParameterCount        ViolationType = "parameter_count"
 	ViolationTypeNestingDepth         ViolationType = "nesting_depth"
 	ViolationTypeNaming               ViolationType = "naming_convention"
 	ViolationTypeClassSize            ViolationType = "class_size"
 	ViolationTypeMissingDocumentation ViolationType = "missing_documentation"
 	ViolationTypeMagicNumber          ViolationType = "magic_number"
+	ViolationTypeMagicString          ViolationType = "magic_string"
 	ViolationTypeDuplication          ViolationType = "code_duplication"
 	// Deprecated: Use ViolationTypeMagicNumber instead. Will be removed in future version.
 	ViolationTypeMagicNumbers         ViolationType = ViolationTypeMagicNumber
@@ -49,6 +91,14 @@ const (
 	ViolationTypeTodo                 ViolationType = "todo_marker"
 	ViolationTypeDocumentation        ViolationType = "documentation_quality"
 	ViolationTypeStructure            ViolationType = "code_structure"
+	ViolationTypeSuppression          ViolationType = "suppression"
+	ViolationTypeFanOut               ViolationType = "function_fan_out"
+	ViolationTypeMaintainability      ViolationType = "maintainability_index"
+	ViolationTypeHalstead             ViolationType = "halstead_metric"
+	ViolationTypePastedCode           ViolationType = "pasted_code"
+	ViolationTypePrimitiveObsession   ViolationType = "primitive_obsession"
+	ViolationTypePlugin               ViolationType = "plugin"      // Reported by an external detector plugin
+	ViolationTypeScriptRule           ViolationType = "script_rule" // Reported by a custom script rule
 	
 	// Rust-specific naming violations
 	ViolationTypeRustInvalidFunctionNaming ViolationType = "rust_invalid_function_naming"
@@ -93,6 +143,7 @@ const (
 	ViolationTypeRustInefficientDestructuring ViolationType = "rust_inefficient_destructuring"
 	ViolationTypeRustUnreachablePattern      ViolationType = "rust_unreachable_pattern"
 	ViolationTypeRustMissingMatchArm         ViolationType = "rust_missing_match_arm"
+	ViolationTypeRustMatchOnBool             ViolationType = "rust_match_on_bool"
 	
 	// Rust-specific trait and implementation violations
 	ViolationTypeRustOverlyComplexTrait      ViolationType = "rust_overly_complex_trait"
@@ -110,12 +161,14 @@ const (
 	ViolationTypeRustSendSyncViolation       ViolationType = "rust_send_sync_violation"
 	ViolationTypeRustDeadlockProne           ViolationType = "rust_deadlock_prone"
 	ViolationTypeRustRaceCondition           ViolationType = "rust_race_condition"
+	ViolationTypeRustUnboundedChannel        ViolationType = "rust_unbounded_channel"
 	
 	// Rust-specific module and visibility violations
 	ViolationTypeRustImproperVisibility      ViolationType = "rust_improper_visibility"
 	ViolationTypeRustCircularDependency      ViolationType = "rust_circular_dependency"
 	ViolationTypeRustModuleOrganization      ViolationType = "rust_module_organization"
 	ViolationTypeRustUnusedImport            ViolationType = "rust_unused_import"
+	ViolationTypeRustUnusedPublicItem        ViolationType = "rust_unused_public_item"
 )
 
 // Violation represents a clean code violation found during scanning
@@ -134,6 +187,42 @@ type Violation struct {
 	Rule        string        `json:"rule"`
 	Suggestion  string        `json:"suggestion,omitempty"`
 	CodeSnippet string        `json:"code_snippet,omitempty"`
+	DocURL      string        `json:"doc_url,omitempty"`    // Link to the rule's documentation page
+	SourceURL   string        `json:"source_url,omitempty"` // Link to the file/line on the source forge
+	Fingerprint string        `json:"fingerprint,omitempty"` // Stable identifier, see ComputeFingerprint
+	Fix         *Fix          `json:"fix,omitempty"`         // Automatic remediation, applied by goclean fix
+	Triage      TriageState   `json:"triage,omitempty"`      // new, baseline, suppressed or fixed
+	Occurrences []Occurrence  `json:"occurrences,omitempty"` // Findings collapsed into this grouped violation
+	Remediation int           `json:"remediation_minutes,omitempty"` // Estimated minutes to fix
+	Blame       *Blame        `json:"blame,omitempty"`               // Last commit to change the line, for TODO markers
+	Confidence  float64       `json:"confidence,omitempty"`          // How sure a heuristic detector is of the finding, from 0 to 1
+	Reasons     []string      `json:"reasons,omitempty"`             // What made a heuristic detector report the finding, with --show-reason
+}
+
+// Occurrence locates one of the findings collapsed into a grouped violation
+type Occurrence struct {
+	Line    int `json:"line"`
+	Column  int `json:"column"`
+	EndLine int `json:"end_line,omitempty"`
+}
+
+// Blame is the author and date of the last commit to change a line
+type Blame struct {
+	Author  string    `json:"author"`
+	Time    time.Time `json:"time"`
+	AgeDays int       `json:"age_days"` // Days from the commit to the scan
+}
+
+// ComputeFingerprint returns a short stable identifier for a violation. It is
+// derived from the rule, type, file and message but not the line, so it
+// survives unrelated edits that shift code up or down.
+func ComputeFingerprint(v *Violation) string {
+	hash := sha256.New()
+	for _, part := range []string{v.Rule, string(v.Type), filepath.ToSlash(v.File), v.Message} {
+		hash.Write([]byte(part))
+		hash.Write([]byte{0})
+	}
+	return hex.EncodeToString(hash.Sum(nil))[:16]
 }
 
 // Location represents a position in source code
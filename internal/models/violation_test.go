@@ -1,6 +1,7 @@
 package models
 
 import (
+	"strings"
 	"testing"
 )
 
@@ -24,6 +25,28 @@ func TestSeverityString(t *testing.T) {
 	}
 }
 
+func TestParseSeverity(t *testing.T) {
+	for _, name := range []string{"info", "Low", "MEDIUM", "high", "Critical"} {
+		severity, err := ParseSeverity(name)
+		if err != nil {
+			t.Errorf("ParseSeverity(%q) failed: %v", name, err)
+			continue
+		}
+		if !strings.EqualFold(severity.String(), name) {
+			t.Errorf("ParseSeverity(%q) = %s", name, severity)
+		}
+	}
+	if severity, err := ParseSeverity("Minor"); err != nil || severity != SeverityLow {
+		t.Errorf("Expected minor to alias Low, got %s (%v)", severity, err)
+	}
+	if severity, err := ParseSeverity("major"); err != nil || severity != SeverityMedium {
+		t.Errorf("Expected major to alias Medium, got %s (%v)", severity, err)
+	}
+	if _, err := ParseSeverity("severe"); err == nil {
+		t.Error("Expected an error for an unknown severity")
+	}
+}
+
 func TestViolationTypes(t *testing.T) {
 	// Test that all violation types are defined as expected
 	expectedTypes := map[ViolationType]string{
@@ -0,0 +1,194 @@
+package reporters
+
+import (
+	"crypto/md5"
+	"encoding/hex"
+	"encoding/json"
+	"fmt"
+	"os"
+	"path/filepath"
+	"strings"
+
+	"github.com/ericfisherdev/goclean/internal/config"
+	"github.com/ericfisherdev/goclean/internal/models"
+)
+
+// FormatCodeClimate writes the Code Climate issue JSON that GitLab shows as
+// code quality findings in merge requests
+const FormatCodeClimate = "codeclimate"
+
+// codeClimatePointsPerMinute converts remediation minutes to Code Climate
+// remediation points; the format rates a trivial fix of about five minutes
+// at 50,000 points
+const codeClimatePointsPerMinute = 10000
+
+// codeClimateIssue is one issue of the Code Climate report format
+type codeClimateIssue struct {
+	Type              string              `json:"type"`
+	CheckName         string              `json:"check_name"`
+	Description       string              `json:"description"`
+	Content           *codeClimateContent `json:"content,omitempty"`
+	Categories        []string            `json:"categories"`
+	Location          codeClimateLocation `json:"location"`
+	Severity          string              `json:"severity"`
+	RemediationPoints int                 `json:"remediation_points,omitempty"`
+	Fingerprint       string              `json:"fingerprint"`
+}
+
+// codeClimateContent holds the longer explanation of an issue
+type codeClimateContent struct {
+	Body string `json:"body"`
+}
+
+// codeClimateLocation is the file and line range of an issue
+type codeClimateLocation struct {
+	Path  string           `json:"path"`
+	Lines codeClimateLines `json:"lines"`
+}
+
+// codeClimateLines is a 1-based, inclusive line range
+type codeClimateLines struct {
+	Begin int `json:"begin"`
+	End   int `json:"end"`
+}
+
+// CodeClimateReporter writes violations as a Code Climate issue array, the
+// format of GitLab's code quality report artifact
+type CodeClimateReporter struct {
+	config *config.CodeClimateConfig
+}
+
+// NewCodeClimateReporter creates a new Code Climate reporter with the given configuration
+func NewCodeClimateReporter(cfg *config.CodeClimateConfig) *CodeClimateReporter {
+	if cfg == nil {
+		cfg = &config.CodeClimateConfig{
+			Enabled: true,
+			Path:    "./reports/gl-code-quality-report.json",
+		}
+	}
+	return &CodeClimateReporter{config: cfg}
+}
+
+// Generate writes the Code Climate report for the report's files
+func (r *CodeClimateReporter) Generate(report *models.Report) error {
+	if !r.config.Enabled {
+		return nil
+	}
+
+	dir := filepath.Dir(r.config.Path)
+	if err := os.MkdirAll(dir, 0755); err != nil {
+		return fmt.Errorf("failed to create report directory: %w", err)
+	}
+
+	data, err := json.MarshalIndent(buildCodeClimateIssues(report), "", "  ")
+	if err != nil {
+		return fmt.Errorf("failed to marshal Code Climate report: %w", err)
+	}
+
+	if err := os.WriteFile(r.config.Path, append(data, '\n'), 0644); err != nil {
+		return fmt.Errorf("failed to write Code Climate report: %w", err)
+	}
+	return nil
+}
+
+// buildCodeClimateIssues converts the violations of a report to issues. A
+// clean scan gives an empty array, which GitLab reads as no findings.
+func buildCodeClimateIssues(report *models.Report) []*codeClimateIssue {
+	issues := []*codeClimateIssue{}
+	seen := make(map[string]int)
+	for _, result := range report.Files {
+		for _, violation := range sortedViolations(result.Violations) {
+			issue := codeClimateIssueFor(violation)
+
+			// Identical violations in one file get distinct fingerprints by occurrence
+			key := codeClimateKey(issue.CheckName, issue.Location.Path, violation.Message)
+			seen[key]++
+			issue.Fingerprint = codeClimateFingerprint(fmt.Sprintf("%s\x00%d", key, seen[key]))
+			issues = append(issues, issue)
+		}
+	}
+	return issues
+}
+
+// codeClimateIssueFor converts a single violation, leaving out its fingerprint
+func codeClimateIssueFor(v *models.Violation) *codeClimateIssue {
+	begin := max(v.Line, 1)
+	issue := &codeClimateIssue{
+		Type:        "issue",
+		CheckName:   violationRule(v),
+		Description: strings.Join(strings.Fields(v.Message), " "),
+		Categories:  []string{codeClimateCategory(v.Type)},
+		Location: codeClimateLocation{
+			Path:  codeClimatePath(v.File),
+			Lines: codeClimateLines{Begin: begin, End: max(v.EndLine, begin)},
+		},
+		Severity:          codeClimateSeverity(v.Severity),
+		RemediationPoints: v.Remediation * codeClimatePointsPerMinute,
+	}
+	if v.Suggestion != "" {
+		issue.Content = &codeClimateContent{Body: v.Suggestion}
+	}
+	return issue
+}
+
+// codeClimateKey identifies a violation without its line, so fingerprints
+// survive code being moved up or down and GitLab can tell new issues from
+// existing ones
+func codeClimateKey(rule, path, message string) string {
+	return rule + "\x00" + path + "\x00" + message
+}
+
+// codeClimateFingerprint hashes a violation key into the hex digest GitLab expects
+func codeClimateFingerprint(key string) string {
+	sum := md5.Sum([]byte(key))
+	return hex.EncodeToString(sum[:])
+}
+
+// codeClimatePath returns a file path relative to the working directory with
+// forward slashes, as GitLab resolves paths against the repository root
+func codeClimatePath(path string) string {
+	if filepath.IsAbs(path) {
+		if wd, err := os.Getwd(); err == nil {
+			if rel, err := filepath.Rel(wd, path); err == nil && !strings.HasPrefix(rel, "..") {
+				path = rel
+			}
+		}
+	}
+	return strings.TrimPrefix(filepath.ToSlash(filepath.Clean(path)), "./")
+}
+
+// codeClimateSeverity maps severities to the Code Climate severity levels
+func codeClimateSeverity(severity models.Severity) string {
+	switch severity {
+	case models.SeverityCritical:
+		return "blocker"
+	case models.SeverityHigh:
+		return "critical"
+	case models.SeverityMedium:
+		return "major"
+	case models.SeverityLow:
+		return "minor"
+	default:
+		return "info"
+	}
+}
+
+// codeClimateCategory maps a violation type to one of the fixed Code Climate categories
+func codeClimateCategory(vt models.ViolationType) string {
+	switch vt.Category() {
+	case models.CategoryComplexity, models.CategoryStructure:
+		return "Complexity"
+	case models.CategoryDuplication:
+		return "Duplication"
+	case string(models.RustCategorySafety):
+		return "Security"
+	case string(models.RustCategoryPerformance):
+		return "Performance"
+	case string(models.RustCategoryErrorHandling), string(models.RustCategoryOwnership), string(models.RustCategoryAsync):
+		return "Bug Risk"
+	case models.CategoryNaming, models.CategoryDocumentation:
+		return "Style"
+	default:
+		return "Clarity"
+	}
+}
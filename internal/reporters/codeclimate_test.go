@@ -0,0 +1,86 @@
+package reporters
+
+import (
+	"encoding/json"
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/ericfisherdev/goclean/internal/config"
+	"github.com/ericfisherdev/goclean/internal/models"
+)
+
+func TestCodeClimateReporter_Generate(t *testing.T) {
+	outputPath := filepath.Join(t.TempDir(), "reports", "gl-code-quality-report.json")
+	reporter := NewCodeClimateReporter(&config.CodeClimateConfig{Enabled: true, Path: outputPath})
+
+	if err := reporter.Generate(createTestReport()); err != nil {
+		t.Fatalf("Failed to generate Code Climate report: %v", err)
+	}
+
+	data, err := os.ReadFile(outputPath)
+	if err != nil {
+		t.Fatalf("Failed to read Code Climate report: %v", err)
+	}
+	var issues []map[string]interface{}
+	if err := json.Unmarshal(data, &issues); err != nil {
+		t.Fatalf("Failed to parse Code Climate report: %v", err)
+	}
+	if len(issues) != 2 {
+		t.Fatalf("Expected 2 issues, got %d", len(issues))
+	}
+
+	issue := issues[1]
+	if issue["type"] != "issue" || issue["check_name"] != "function-length" || issue["severity"] != "blocker" {
+		t.Errorf("Unexpected issue: %v", issue)
+	}
+	location := issue["location"].(map[string]interface{})
+	lines := location["lines"].(map[string]interface{})
+	if location["path"] != "test.go" || lines["begin"] != float64(10) || lines["end"] != float64(10) {
+		t.Errorf("Unexpected location: %v", location)
+	}
+	if categories := issue["categories"].([]interface{}); len(categories) != 1 || categories[0] != "Complexity" {
+		t.Errorf("Expected the Complexity category, got %v", categories)
+	}
+	if fingerprint, _ := issue["fingerprint"].(string); len(fingerprint) != 32 {
+		t.Errorf("Expected an MD5 fingerprint, got %q", fingerprint)
+	}
+}
+
+func TestBuildCodeClimateIssues_Fingerprints(t *testing.T) {
+	violation := func(line int) *models.Violation {
+		return &models.Violation{
+			Type:     models.ViolationTypeMagicNumber,
+			Severity: models.SeverityLow,
+			Message:  "Magic number 404",
+			File:     "./api/handler.go",
+			Line:     line,
+			Rule:     "magic-number",
+		}
+	}
+	report := &models.Report{Files: []*models.ScanResult{{Violations: []*models.Violation{violation(12), violation(20)}}}}
+	moved := &models.Report{Files: []*models.ScanResult{{Violations: []*models.Violation{violation(15), violation(23)}}}}
+
+	issues := buildCodeClimateIssues(report)
+	if issues[0].Location.Path != "api/handler.go" || issues[0].Severity != "minor" {
+		t.Errorf("Unexpected issue: %+v", issues[0])
+	}
+	if issues[0].Fingerprint == issues[1].Fingerprint {
+		t.Error("Expected repeated violations to get distinct fingerprints")
+	}
+	for i, issue := range buildCodeClimateIssues(moved) {
+		if issue.Fingerprint != issues[i].Fingerprint {
+			t.Errorf("Expected fingerprint %d to survive moved lines", i)
+		}
+	}
+}
+
+func TestBuildCodeClimateIssues_NoViolations(t *testing.T) {
+	data, err := json.Marshal(buildCodeClimateIssues(createTestReportNoViolations()))
+	if err != nil {
+		t.Fatalf("Failed to marshal issues: %v", err)
+	}
+	if string(data) != "[]" {
+		t.Errorf("Expected an empty array, got %s", data)
+	}
+}
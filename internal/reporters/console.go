@@ -124,6 +124,21 @@ func (c *ConsoleReporter) printSummary(summary *models.ScanSummary) {
 	fmt.Fprintf(w, "Skipped Files:\t%d\n", summary.SkippedFiles)
 	fmt.Fprintf(w, "Total Violations:\t%s\n", c.colorizeViolationCount(summary.TotalViolations))
 	fmt.Fprintf(w, "Scan Duration:\t%v\n", summary.Duration.Round(time.Millisecond))
+	if triage := summary.Triage; triage != nil {
+		fmt.Fprintf(w, "Triage:\t%d new, %d in baseline, %d suppressed, %d fixed\n",
+			triage.New, triage.Baseline, triage.Suppressed, triage.Fixed)
+	}
+	if debt := summary.Debt; debt != nil && debt.Minutes > 0 {
+		fmt.Fprintf(w, "Technical Debt:\t%s\n", debt.Total())
+	}
+	if coverage := summary.DocCoverage; coverage != nil {
+		fmt.Fprintf(w, "Documentation Coverage:\t%.1f%% (%d of %d public items)\n",
+			coverage.Percent, coverage.Documented, coverage.Public)
+	}
+	if clusters := summary.PrimitiveObsession; len(clusters) > 0 {
+		fmt.Fprintf(w, "Primitive Obsession:\t%d recurring names; most: %s %s (%d functions and structs)\n",
+			len(clusters), clusters[0].Name, clusters[0].Type, clusters[0].Signatures())
+	}
 	w.Flush()
 	fmt.Println()
 }
@@ -268,13 +283,15 @@ func (c *ConsoleReporter) printDetailedViolations(report *models.Report) {
 		return len(sortedFiles[i].Violations) > len(sortedFiles[j].Violations)
 	})
 	
+	healthByFile := report.HealthByFile()
 	for i, fv := range sortedFiles {
 		if i > 0 {
 			fmt.Println()
 		}
 		
-		fmt.Printf("%s %s (%d violations)\n", 
+		fmt.Printf("%s %s%s (%d violations)\n", 
 			c.colorize("📁", "file"),
+			c.healthPrefix(healthByFile[fv.File]),
 			c.colorize(fv.File, "filename"),
 			len(fv.Violations))
 		fmt.Println(strings.Repeat("-", DetailSeparatorLength))
@@ -315,6 +332,10 @@ func (c *ConsoleReporter) printViolation(v *models.Violation) {
 		fmt.Fprintf(c.output, "    %s\n", c.colorize(v.Description, "description"))
 	}
 	
+	for _, reason := range v.Reasons {
+		fmt.Fprintf(c.output, "    • %s\n", c.colorize(reason, "description"))
+	}
+	
 	if v.Suggestion != "" {
 		fmt.Fprintf(c.output, "    💡 %s\n", c.colorize(v.Suggestion, "suggestion"))
 	}
@@ -437,6 +458,22 @@ func (c *ConsoleReporter) colorizeViolationCount(count int) string {
 	}
 }
 
+// healthPrefix returns the health score shown before a file name, colored by
+// its rating, or nothing when the file was not scored
+func (c *ConsoleReporter) healthPrefix(health *models.FileHealth) string {
+	if health == nil {
+		return ""
+	}
+	colorType := "error"
+	switch models.HealthLabel(health.Score) {
+	case "healthy":
+		colorType = "success"
+	case "fair":
+		colorType = "warning"
+	}
+	return c.colorize(fmt.Sprintf("[health %d]", health.Score), colorType) + " "
+}
+
 // getSeverityIcon returns an appropriate icon for each severity level
 func (c *ConsoleReporter) getSeverityIcon(severity models.Severity) string {
 	switch severity {
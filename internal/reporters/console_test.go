@@ -285,4 +285,28 @@ func createTestReportNoViolations() *models.Report {
 	}
 
 	return models.NewReport(summary, []*models.ScanResult{scanResult}, reportConfig)
-}
\ No newline at end of file
+}
+func TestConsoleReporter_HealthPrefix(t *testing.T) {
+	oldStdout := os.Stdout
+	r, w, _ := os.Pipe()
+	os.Stdout = w
+
+	reporter := NewConsoleReporterLegacy(true, false)
+	report := createTestReport()
+	report.Summary.Health = []*models.FileHealth{{File: "test.go", Score: 64, Violations: 2}}
+
+	err := reporter.Generate(report)
+	w.Close()
+	os.Stdout = oldStdout
+	if err != nil {
+		t.Fatalf("Failed to generate console report: %v", err)
+	}
+
+	output, _ := io.ReadAll(r)
+	if !strings.Contains(string(output), "[health 64] test.go (2 violations)") {
+		t.Errorf("Expected the file's health before its violations, got:\n%s", output)
+	}
+	if prefix := reporter.healthPrefix(nil); prefix != "" {
+		t.Errorf("Expected no prefix for an unscored file, got %q", prefix)
+	}
+}
@@ -0,0 +1,270 @@
+package reporters
+
+import (
+	"fmt"
+	"net/url"
+	"os"
+	"path/filepath"
+	"sort"
+	"strings"
+
+	"github.com/ericfisherdev/goclean/internal/config"
+	"github.com/ericfisherdev/goclean/internal/models"
+)
+
+// FormatGitHubSummary writes a Markdown summary for the job summary page of
+// GitHub Actions
+const FormatGitHubSummary = "github-summary"
+
+// GitHubStepSummaryEnv names the file GitHub Actions shows as the summary of a step
+const GitHubStepSummaryEnv = "GITHUB_STEP_SUMMARY"
+
+// defaultGitHubSummaryPath is used outside GitHub Actions when no path is configured
+const defaultGitHubSummaryPath = "./reports/github-summary.md"
+
+// defaultTopViolations is the number of new violations listed when not configured
+const defaultTopViolations = 10
+
+// GitHubSummaryReporter writes the gate verdict, a badge, the violations by
+// severity and the most severe new violations as Markdown
+type GitHubSummaryReporter struct {
+	config *config.GitHubSummaryConfig
+	failOn models.Severity
+}
+
+// NewGitHubSummaryReporter creates a new GitHub summary reporter. Violations
+// at or above failOn fail the gate, as they fail the scan.
+func NewGitHubSummaryReporter(cfg *config.GitHubSummaryConfig, failOn models.Severity) *GitHubSummaryReporter {
+	if cfg == nil {
+		cfg = &config.GitHubSummaryConfig{Enabled: true}
+	}
+	return &GitHubSummaryReporter{config: cfg, failOn: failOn}
+}
+
+// GitHubSummaryPath returns the file a summary is written to: the configured
+// path, the step summary of GitHub Actions, or a file under ./reports
+func GitHubSummaryPath(cfg *config.GitHubSummaryConfig) string {
+	if cfg.Path != "" {
+		return cfg.Path
+	}
+	if stepSummary := os.Getenv(GitHubStepSummaryEnv); stepSummary != "" {
+		return stepSummary
+	}
+	return defaultGitHubSummaryPath
+}
+
+// Generate writes the summary. The step summary file of GitHub Actions is
+// appended to, so the summaries of other tools in the step are kept.
+func (r *GitHubSummaryReporter) Generate(report *models.Report) error {
+	if !r.config.Enabled {
+		return nil
+	}
+
+	path := GitHubSummaryPath(r.config)
+	if err := os.MkdirAll(filepath.Dir(path), 0755); err != nil {
+		return fmt.Errorf("failed to create report directory: %w", err)
+	}
+
+	flags := os.O_CREATE | os.O_WRONLY | os.O_TRUNC
+	if path == os.Getenv(GitHubStepSummaryEnv) {
+		flags = os.O_CREATE | os.O_WRONLY | os.O_APPEND
+	}
+	file, err := os.OpenFile(path, flags, 0644)
+	if err != nil {
+		return fmt.Errorf("failed to open GitHub summary: %w", err)
+	}
+	defer file.Close()
+
+	if _, err := file.WriteString(r.buildSummary(report)); err != nil {
+		return fmt.Errorf("failed to write GitHub summary: %w", err)
+	}
+	return nil
+}
+
+// buildSummary renders the Markdown summary of a report
+func (r *GitHubSummaryReporter) buildSummary(report *models.Report) string {
+	var reported []*models.Violation
+	bySeverity := make(map[models.Severity]int)
+	failing := 0
+	for _, result := range report.Files {
+		for _, violation := range result.Violations {
+			reported = append(reported, violation)
+			bySeverity[violation.Severity]++
+			if violation.Severity >= r.failOn {
+				failing++
+			}
+		}
+	}
+
+	var md strings.Builder
+	md.WriteString("## GoClean\n\n")
+	md.WriteString(githubSummaryBadge(len(reported), failing) + "\n\n")
+	switch {
+	case failing > 0:
+		fmt.Fprintf(&md, "❌ **Failed**: %d of %d violations are at %s severity or above.\n\n", failing, len(reported), r.failOn)
+	case len(reported) > 0:
+		fmt.Fprintf(&md, "✅ **Passed**: %d violations, none at %s severity or above.\n\n", len(reported), r.failOn)
+	default:
+		md.WriteString("✅ **Passed**: no violations found.\n\n")
+	}
+
+	if summary := report.Summary; summary != nil {
+		fmt.Fprintf(&md, "Scanned %d files", summary.ScannedFiles)
+		if triage := summary.Triage; triage != nil && (triage.Baseline > 0 || triage.Suppressed > 0 || triage.Fixed > 0) {
+			fmt.Fprintf(&md, "; %d new, %d baselined, %d suppressed, %d fixed since the baseline",
+				triage.New, triage.Baseline, triage.Suppressed, triage.Fixed)
+		}
+		md.WriteString(".\n\n")
+		if coverage := summary.DocCoverage; coverage != nil {
+			fmt.Fprintf(&md, "Documentation coverage: %.1f%% of public items (%d of %d).\n\n",
+				coverage.Percent, coverage.Documented, coverage.Public)
+		}
+	}
+
+	if len(reported) == 0 {
+		return md.String()
+	}
+
+	md.WriteString("| Severity | Violations |\n|----------|-----------:|\n")
+	for severity := models.SeverityCritical; severity >= models.SeverityInfo; severity-- {
+		if count := bySeverity[severity]; count > 0 {
+			fmt.Fprintf(&md, "| %s | %d |\n", severity, count)
+		}
+	}
+	md.WriteString("\n")
+
+	r.writeTopViolations(&md, reported)
+	if report.Summary != nil {
+		r.writeLeastHealthyFiles(&md, report.Summary.Health)
+	}
+	return md.String()
+}
+
+// writeTopViolations lists the most severe new violations with links to the
+// source and the rule documentation
+func (r *GitHubSummaryReporter) writeTopViolations(md *strings.Builder, reported []*models.Violation) {
+	var fresh []*models.Violation
+	for _, violation := range reported {
+		if violation.Triage == "" || violation.Triage == models.TriageNew {
+			fresh = append(fresh, violation)
+		}
+	}
+	if len(fresh) == 0 {
+		return
+	}
+	sort.SliceStable(fresh, func(i, j int) bool {
+		if fresh[i].Severity != fresh[j].Severity {
+			return fresh[i].Severity > fresh[j].Severity
+		}
+		if fresh[i].File != fresh[j].File {
+			return fresh[i].File < fresh[j].File
+		}
+		return fresh[i].Line < fresh[j].Line
+	})
+
+	limit := r.config.TopViolations
+	if limit <= 0 {
+		limit = defaultTopViolations
+	}
+	shown := fresh[:min(limit, len(fresh))]
+
+	links := actionsLinkResolver()
+	md.WriteString("### Top new violations\n\n")
+	md.WriteString("| Severity | Rule | Location | Message |\n|----------|------|----------|---------|\n")
+	for _, violation := range shown {
+		rule := "`" + violationRule(violation) + "`"
+		if violation.DocURL != "" {
+			rule = fmt.Sprintf("[%s](%s)", rule, violation.DocURL)
+		}
+		location := fmt.Sprintf("%s:%d", codeClimatePath(violation.File), violation.Line)
+		if sourceURL := githubSourceURL(violation, links); sourceURL != "" {
+			location = fmt.Sprintf("[%s](%s)", location, sourceURL)
+		}
+		fmt.Fprintf(md, "| %s | %s | %s | %s |\n", violation.Severity, rule, location, markdownTableCell(violation.Message))
+	}
+	if hidden := len(fresh) - len(shown); hidden > 0 {
+		fmt.Fprintf(md, "\n…and %d more new violations.\n", hidden)
+	}
+	md.WriteString("\n")
+}
+
+// writeLeastHealthyFiles lists the files rated below healthy, least healthy first
+func (r *GitHubSummaryReporter) writeLeastHealthyFiles(md *strings.Builder, files []*models.FileHealth) {
+	var unhealthy []*models.FileHealth
+	for _, file := range models.LowestHealth(files) {
+		if models.HealthLabel(file.Score) != "healthy" {
+			unhealthy = append(unhealthy, file)
+		}
+	}
+	if len(unhealthy) == 0 {
+		return
+	}
+
+	limit := r.config.TopViolations
+	if limit <= 0 {
+		limit = defaultTopViolations
+	}
+	shown := unhealthy[:min(limit, len(unhealthy))]
+
+	md.WriteString("### Least healthy files\n\n")
+	md.WriteString("| File | Health | Violations |\n|------|-------:|-----------:|\n")
+	for _, file := range shown {
+		fmt.Fprintf(md, "| %s | %d (%s) | %d |\n", codeClimatePath(file.File), file.Score, models.HealthLabel(file.Score), file.Violations)
+	}
+	if hidden := len(unhealthy) - len(shown); hidden > 0 {
+		fmt.Fprintf(md, "\n…and %d more files below a health of 80.\n", hidden)
+	}
+	md.WriteString("\n")
+}
+
+// githubSummaryBadge returns a shields.io badge with the violation count,
+// green for a clean scan, yellow when the gate passes and red when it fails
+func githubSummaryBadge(violations, failing int) string {
+	color := "brightgreen"
+	switch {
+	case failing > 0:
+		color = "red"
+	case violations > 0:
+		color = "yellow"
+	}
+	message := fmt.Sprintf("%d violations", violations)
+	if violations == 1 {
+		message = "1 violation"
+	}
+	return fmt.Sprintf("![GoClean: %s](https://img.shields.io/badge/goclean-%s-%s)", message, url.PathEscape(message), color)
+}
+
+// githubSourceURL returns the source link of a violation. Without configured
+// links, the repository and commit of the GitHub Actions run are used.
+func githubSourceURL(violation *models.Violation, links *LinkResolver) string {
+	if violation.SourceURL != "" {
+		return violation.SourceURL
+	}
+	if links == nil || violation.File == "" {
+		return ""
+	}
+	return links.expand(links.sourceTemplate, violation)
+}
+
+// actionsLinkResolver builds source links from the environment of a GitHub
+// Actions run, or returns nil outside of one
+func actionsLinkResolver() *LinkResolver {
+	server, repository, commit := os.Getenv("GITHUB_SERVER_URL"), os.Getenv("GITHUB_REPOSITORY"), os.Getenv("GITHUB_SHA")
+	if server == "" || repository == "" || commit == "" {
+		return nil
+	}
+	links := NewLinkResolver(config.LinksConfig{
+		RepositoryURL:  strings.TrimSuffix(server, "/") + "/" + repository,
+		CommitSHA:      commit,
+		RepositoryRoot: os.Getenv("GITHUB_WORKSPACE"),
+	})
+	if !links.SourceLinksEnabled() {
+		return nil
+	}
+	return links
+}
+
+// markdownTableCell keeps text on one line of a Markdown table
+func markdownTableCell(text string) string {
+	return strings.ReplaceAll(strings.Join(strings.Fields(text), " "), "|", "\\|")
+}
@@ -0,0 +1,147 @@
+package reporters
+
+import (
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+
+	"github.com/ericfisherdev/goclean/internal/config"
+	"github.com/ericfisherdev/goclean/internal/models"
+)
+
+// githubSummaryTestReport returns a report of three new violations and one baselined
+func githubSummaryTestReport() *models.Report {
+	violations := []*models.Violation{
+		{Rule: "magic-number", Severity: models.SeverityLow, File: "api/handler.go", Line: 12, Message: "Magic number 404", Triage: models.TriageNew},
+		{Rule: "function-length", Severity: models.SeverityHigh, File: "api/handler.go", Line: 30, Message: "Function 'serve' is 80 lines | max 40", Triage: models.TriageNew,
+			DocURL: "https://docs.example.com/rules/function-length", SourceURL: "https://github.com/acme/shop/blob/abc/api/handler.go#L30"},
+		{Rule: "todo-comment", Severity: models.SeverityInfo, File: "api/routes.go", Line: 3, Message: "TODO comment", Triage: models.TriageNew},
+	}
+	summary := &models.ScanSummary{
+		ScannedFiles:    2,
+		TotalViolations: len(violations),
+		Triage:          &models.TriageCounts{New: 3, Baseline: 1},
+	}
+	results := []*models.ScanResult{{File: &models.FileInfo{Path: "api/handler.go"}, Violations: violations}}
+	return models.NewReport(summary, results, &models.ReportConfig{})
+}
+
+// clearActionsEnv unsets the GitHub Actions variables the reporter reads
+func clearActionsEnv(t *testing.T) {
+	t.Helper()
+	for _, name := range []string{GitHubStepSummaryEnv, "GITHUB_SERVER_URL", "GITHUB_REPOSITORY", "GITHUB_SHA"} {
+		t.Setenv(name, "")
+	}
+}
+
+func TestGitHubSummaryReporter_Failed(t *testing.T) {
+	clearActionsEnv(t)
+	reporter := NewGitHubSummaryReporter(&config.GitHubSummaryConfig{Enabled: true, TopViolations: 2}, models.SeverityHigh)
+	summary := reporter.buildSummary(githubSummaryTestReport())
+
+	for _, expected := range []string{
+		"![GoClean: 3 violations](https://img.shields.io/badge/goclean-3%20violations-red)",
+		"❌ **Failed**: 1 of 3 violations are at High severity or above.",
+		"Scanned 2 files; 3 new, 1 baselined, 0 suppressed, 0 fixed since the baseline.",
+		"| High | 1 |\n| Low | 1 |\n| Info | 1 |",
+		"| High | [`function-length`](https://docs.example.com/rules/function-length) | [api/handler.go:30](https://github.com/acme/shop/blob/abc/api/handler.go#L30) | Function 'serve' is 80 lines \\| max 40 |",
+		"| Low | `magic-number` | api/handler.go:12 | Magic number 404 |",
+		"…and 1 more new violations.",
+	} {
+		if !strings.Contains(summary, expected) {
+			t.Errorf("Expected the summary to contain %q, got:\n%s", expected, summary)
+		}
+	}
+	if strings.Contains(summary, "todo-comment") {
+		t.Errorf("Expected only the 2 most severe violations to be listed, got:\n%s", summary)
+	}
+}
+
+func TestGitHubSummaryReporter_PassedWithActionsLinks(t *testing.T) {
+	clearActionsEnv(t)
+	t.Setenv("GITHUB_SERVER_URL", "https://github.com")
+	t.Setenv("GITHUB_REPOSITORY", "acme/shop")
+	t.Setenv("GITHUB_SHA", "def456")
+	workspace, err := os.Getwd()
+	if err != nil {
+		t.Fatalf("Failed to get working directory: %v", err)
+	}
+	t.Setenv("GITHUB_WORKSPACE", workspace)
+
+	reporter := NewGitHubSummaryReporter(&config.GitHubSummaryConfig{Enabled: true}, models.SeverityCritical)
+	summary := reporter.buildSummary(githubSummaryTestReport())
+
+	if !strings.Contains(summary, "goclean-3%20violations-yellow") || !strings.Contains(summary, "✅ **Passed**: 3 violations, none at Critical severity or above.") {
+		t.Errorf("Expected a passing verdict, got:\n%s", summary)
+	}
+	if !strings.Contains(summary, "[api/handler.go:12](https://github.com/acme/shop/blob/def456/api/handler.go#L12)") {
+		t.Errorf("Expected source links to the workflow run commit, got:\n%s", summary)
+	}
+}
+
+func TestGitHubSummaryReporter_LeastHealthyFiles(t *testing.T) {
+	clearActionsEnv(t)
+	report := githubSummaryTestReport()
+	report.Summary.Health = []*models.FileHealth{
+		{File: "api/handler.go", Score: 58, Violations: 3},
+		{File: "api/routes.go", Score: 93, Violations: 1},
+		{File: "api/legacy.go", Score: 21, Violations: 14},
+	}
+
+	reporter := NewGitHubSummaryReporter(&config.GitHubSummaryConfig{Enabled: true}, models.SeverityHigh)
+	summary := reporter.buildSummary(report)
+
+	expected := "### Least healthy files\n\n| File | Health | Violations |\n|------|-------:|-----------:|\n" +
+		"| api/legacy.go | 21 (poor) | 14 |\n| api/handler.go | 58 (fair) | 3 |\n"
+	if !strings.Contains(summary, expected) {
+		t.Errorf("Expected the files below healthy, least healthy first, got:\n%s", summary)
+	}
+	if strings.Contains(summary, "api/routes.go |") {
+		t.Errorf("Expected healthy files to be left out, got:\n%s", summary)
+	}
+}
+
+func TestGitHubSummaryReporter_DocCoverage(t *testing.T) {
+	clearActionsEnv(t)
+	report := githubSummaryTestReport()
+	report.Summary.DocCoverage = models.ComputeDocCoverage([]models.FileDocCoverage{{File: "api/handler.go", Documented: 3, Public: 4}})
+
+	summary := NewGitHubSummaryReporter(&config.GitHubSummaryConfig{Enabled: true}, models.SeverityHigh).buildSummary(report)
+	if !strings.Contains(summary, "Documentation coverage: 75.0% of public items (3 of 4).") {
+		t.Errorf("Expected the documentation coverage, got:\n%s", summary)
+	}
+}
+
+func TestGitHubSummaryReporter_AppendsToStepSummary(t *testing.T) {
+	clearActionsEnv(t)
+	stepSummary := filepath.Join(t.TempDir(), "step_summary.md")
+	if err := os.WriteFile(stepSummary, []byte("## Tests\n\nAll passed.\n\n"), 0644); err != nil {
+		t.Fatalf("Failed to write step summary: %v", err)
+	}
+	t.Setenv(GitHubStepSummaryEnv, stepSummary)
+
+	cfg := &config.GitHubSummaryConfig{Enabled: true}
+	if path := GitHubSummaryPath(cfg); path != stepSummary {
+		t.Errorf("Expected the step summary path, got %q", path)
+	}
+	clean := models.NewReport(&models.ScanSummary{ScannedFiles: 4}, nil, &models.ReportConfig{})
+	if err := NewGitHubSummaryReporter(cfg, models.SeverityInfo).Generate(clean); err != nil {
+		t.Fatalf("Failed to generate GitHub summary: %v", err)
+	}
+
+	data, err := os.ReadFile(stepSummary)
+	if err != nil {
+		t.Fatalf("Failed to read step summary: %v", err)
+	}
+	content := string(data)
+	if !strings.HasPrefix(content, "## Tests\n") {
+		t.Errorf("Expected the existing summary to be kept, got:\n%s", content)
+	}
+	if !strings.Contains(content, "goclean-0%20violations-brightgreen") || !strings.Contains(content, "✅ **Passed**: no violations found.") {
+		t.Errorf("Expected a clean summary to be appended, got:\n%s", content)
+	}
+	if strings.Contains(content, "| Severity |") {
+		t.Errorf("Expected no tables for a clean scan, got:\n%s", content)
+	}
+}
@@ -37,6 +37,7 @@ type HTMLConfig struct {
 	AutoRefresh     bool
 	RefreshInterval int
 	Theme           string
+	SelfContained   bool // Write the interactive report, which needs no network access to view
 }
 
 // NewHTMLReporter creates a new HTML reporter
@@ -68,6 +69,13 @@ func (h *HTMLReporter) Generate(report *models.Report) error {
 	}
 	defer file.Close()
 
+	if h.config.SelfContained {
+		if err := h.generateInteractive(file, report); err != nil {
+			return fmt.Errorf("failed to execute template: %w", err)
+		}
+		return nil
+	}
+
 	// Prepare template data
 	templateData := struct {
 		*models.Report
@@ -261,6 +269,17 @@ func getTemplateFunctions() template.FuncMap {
 		"lower": func(s string) string {
 			return strings.ToLower(s)
 		},
+		"healthLabel": models.HealthLabel,
+		"healthBadge": func(score int) string {
+			switch models.HealthLabel(score) {
+			case "healthy":
+				return "badge bg-success"
+			case "fair":
+				return "badge bg-warning"
+			default:
+				return "badge bg-danger"
+			}
+		},
 	}
 }
 
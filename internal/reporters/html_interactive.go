@@ -0,0 +1,316 @@
+package reporters
+
+import (
+	"html/template"
+	"os"
+	"path/filepath"
+	"regexp"
+	"sort"
+	"strings"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+)
+
+// Snippet settings of the interactive report
+const (
+	snippetContextLines = 2  // Lines shown before and after a violation
+	snippetMaxLines     = 15 // Lines shown at most for a violation spanning many lines
+	chartMaxRules       = 10 // Rules shown in the rule chart
+)
+
+// tokenRegex splits a line of source code into comments, strings, numbers and words
+var tokenRegex = regexp.MustCompile("(//.*$)|(\"(?:[^\"\\\\]|\\\\.)*\"|`[^`]*`|'(?:[^'\\\\]|\\\\.)')|\\b(\\d[\\w.]*)|\\b([A-Za-z_]\\w*)\\b")
+
+// sourceKeywords are the keywords highlighted in snippets, by language
+var sourceKeywords = map[string]map[string]bool{
+	"go": keywordSet("break case chan const continue default defer else fallthrough for func go goto if import " +
+		"interface map package range return select struct switch type var nil true false"),
+	"rust": keywordSet("as async await break const continue crate dyn else enum extern false fn for if impl in let " +
+		"loop match mod move mut pub ref return self Self static struct super trait true type unsafe use where while"),
+}
+
+// interactiveReport is the data of the self-contained interactive report
+type interactiveReport struct {
+	GeneratedAt string
+	Theme       string
+	RefreshMeta template.HTML
+	Summary     *models.ScanSummary
+	Severities  []chartBar
+	Rules       []chartBar
+	RuleNames   []string
+	Rows        []*interactiveRow
+	Files       []*interactiveFile
+	Scored      bool // The files have health scores
+}
+
+// chartBar is one bar of a summary chart
+type chartBar struct {
+	Label   string
+	Count   int
+	Percent float64 // Width relative to the largest bar
+	Class   string
+}
+
+// interactiveRow is a violation in the sortable table
+type interactiveRow struct {
+	ID           int
+	FileID       int
+	File         string
+	Line         int
+	Column       int
+	Severity     string
+	SeverityRank int
+	Rule         string
+	Message      string
+	Suggestion   string
+	Snippet      []snippetLine
+	Occurrences  []models.Occurrence
+	Health       int // Health score of the file, or 100 when not scored
+}
+
+// interactiveFile groups the violations of one file for the drill-down view
+type interactiveFile struct {
+	ID          int
+	Path        string
+	Language    string
+	MaxSeverity string
+	Rows        []*interactiveRow
+	Health      *models.FileHealth
+}
+
+// snippetLine is a numbered, highlighted line of source code
+type snippetLine struct {
+	Number    int
+	Code      template.HTML
+	Violation bool // The line is part of the violation
+}
+
+// generateInteractive writes the self-contained interactive report
+func (h *HTMLReporter) generateInteractive(file *os.File, report *models.Report) error {
+	data := buildInteractiveReport(report)
+	data.Theme = h.config.Theme
+	data.RefreshMeta = h.getRefreshMeta()
+	return h.template.ExecuteTemplate(file, "interactive.html", data)
+}
+
+// buildInteractiveReport collects the table rows, files and charts of a report
+func buildInteractiveReport(report *models.Report) *interactiveReport {
+	data := &interactiveReport{
+		GeneratedAt: report.GeneratedAt.Format("2006-01-02 15:04:05"),
+		Summary:     report.Summary,
+	}
+	healthByFile := report.HealthByFile()
+	data.Scored = healthByFile != nil
+
+	severityCounts := make(map[models.Severity]int)
+	ruleCounts := make(map[string]int)
+	for _, result := range report.Files {
+		if result.File == nil || len(result.Violations) == 0 {
+			continue
+		}
+
+		file := &interactiveFile{
+			ID:       len(data.Files) + 1,
+			Path:     result.File.Path,
+			Language: snippetLanguage(result.File.Path),
+			Health:   healthByFile[result.File.Path],
+		}
+		fileHealth := 100
+		if file.Health != nil {
+			fileHealth = file.Health.Score
+		}
+		lines := readSourceLines(result.File.Path)
+		maxSeverity := models.SeverityInfo
+		for _, violation := range sortedViolations(result.Violations) {
+			row := &interactiveRow{
+				ID:           len(data.Rows) + 1,
+				FileID:       file.ID,
+				File:         result.File.Path,
+				Line:         violation.Line,
+				Column:       violation.Column,
+				Severity:     violation.Severity.String(),
+				SeverityRank: int(violation.Severity),
+				Rule:         violationRule(violation),
+				Message:      violation.Message,
+				Suggestion:   violation.Suggestion,
+				Snippet:      buildSnippet(lines, violation, file.Language),
+				Occurrences:  violation.Occurrences,
+				Health:       fileHealth,
+			}
+			data.Rows = append(data.Rows, row)
+			file.Rows = append(file.Rows, row)
+			severityCounts[violation.Severity]++
+			ruleCounts[row.Rule]++
+			maxSeverity = max(maxSeverity, violation.Severity)
+		}
+		file.MaxSeverity = maxSeverity.String()
+		data.Files = append(data.Files, file)
+	}
+
+	data.Severities = severityChart(severityCounts)
+	data.Rules, data.RuleNames = ruleChart(ruleCounts)
+	return data
+}
+
+// violationRule returns the rule of a violation, or its type when it has none
+func violationRule(v *models.Violation) string {
+	if v.Rule != "" {
+		return v.Rule
+	}
+	return string(v.Type)
+}
+
+// sortedViolations returns the violations of a file by line and column
+func sortedViolations(violations []*models.Violation) []*models.Violation {
+	sorted := append([]*models.Violation(nil), violations...)
+	sort.SliceStable(sorted, func(i, j int) bool {
+		if sorted[i].Line != sorted[j].Line {
+			return sorted[i].Line < sorted[j].Line
+		}
+		return sorted[i].Column < sorted[j].Column
+	})
+	return sorted
+}
+
+// severityChart returns a bar per severity, most severe first
+func severityChart(counts map[models.Severity]int) []chartBar {
+	largest := 0
+	for _, count := range counts {
+		largest = max(largest, count)
+	}
+
+	var bars []chartBar
+	for s := models.SeverityCritical; s >= models.SeverityInfo; s-- {
+		if counts[s] == 0 {
+			continue
+		}
+		bars = append(bars, chartBar{
+			Label:   s.String(),
+			Count:   counts[s],
+			Percent: float64(counts[s]) / float64(largest) * PercentageBase,
+			Class:   "sev-" + strings.ToLower(s.String()),
+		})
+	}
+	return bars
+}
+
+// ruleChart returns a bar for each of the most reported rules, and the
+// names of all rules for the filter
+func ruleChart(counts map[string]int) ([]chartBar, []string) {
+	names := make([]string, 0, len(counts))
+	for name := range counts {
+		names = append(names, name)
+	}
+	sort.Strings(names)
+
+	ranked := append([]string(nil), names...)
+	sort.SliceStable(ranked, func(i, j int) bool {
+		return counts[ranked[i]] > counts[ranked[j]]
+	})
+	if len(ranked) > chartMaxRules {
+		ranked = ranked[:chartMaxRules]
+	}
+
+	bars := make([]chartBar, 0, len(ranked))
+	for _, name := range ranked {
+		bars = append(bars, chartBar{
+			Label:   name,
+			Count:   counts[name],
+			Percent: float64(counts[name]) / float64(counts[ranked[0]]) * PercentageBase,
+			Class:   "rule",
+		})
+	}
+	return bars, names
+}
+
+// readSourceLines returns the lines of a source file, or nil when it cannot be read
+func readSourceLines(path string) []string {
+	content, err := os.ReadFile(path)
+	if err != nil {
+		return nil
+	}
+	return strings.Split(strings.ReplaceAll(string(content), "\r\n", "\n"), "\n")
+}
+
+// buildSnippet returns the lines around a violation, with the lines of the
+// violation marked. Violations spanning many lines show their first lines.
+func buildSnippet(lines []string, v *models.Violation, language string) []snippetLine {
+	if lines == nil && v.CodeSnippet != "" {
+		// Fall back to the snippet captured during the scan, which marks violation lines with an arrow
+		var snippet []snippetLine
+		for _, line := range strings.Split(v.CodeSnippet, "\n") {
+			snippet = append(snippet, snippetLine{
+				Code:      highlightLine(line, language),
+				Violation: strings.HasPrefix(line, "→"),
+			})
+		}
+		return snippet
+	}
+	if v.Line <= 0 || v.Line > len(lines) {
+		return nil
+	}
+	endLine := min(max(v.EndLine, v.Line), len(lines))
+	start := max(1, v.Line-snippetContextLines)
+	end := min(len(lines), endLine+snippetContextLines, start+snippetMaxLines-1)
+
+	snippet := make([]snippetLine, 0, end-start+1)
+	for number := start; number <= end; number++ {
+		snippet = append(snippet, snippetLine{
+			Number:    number,
+			Code:      highlightLine(lines[number-1], language),
+			Violation: number >= v.Line && number <= endLine,
+		})
+	}
+	return snippet
+}
+
+// highlightLine escapes a line of source code and marks its comments,
+// strings, numbers and keywords for styling
+func highlightLine(line, language string) template.HTML {
+	keywords := sourceKeywords[language]
+	var result strings.Builder
+	last := 0
+	for _, match := range tokenRegex.FindAllStringSubmatchIndex(line, -1) {
+		class := ""
+		switch {
+		case match[2] >= 0:
+			class = "tok-comment"
+		case match[4] >= 0:
+			class = "tok-string"
+		case match[6] >= 0:
+			class = "tok-number"
+		case match[8] >= 0 && keywords[line[match[8]:match[9]]]:
+			class = "tok-keyword"
+		default:
+			continue
+		}
+		result.WriteString(template.HTMLEscapeString(line[last:match[0]]))
+		result.WriteString(`<span class="` + class + `">`)
+		result.WriteString(template.HTMLEscapeString(line[match[0]:match[1]]))
+		result.WriteString("</span>")
+		last = match[1]
+	}
+	result.WriteString(template.HTMLEscapeString(line[last:]))
+	return template.HTML(result.String())
+}
+
+// snippetLanguage returns the language used to highlight a file
+func snippetLanguage(path string) string {
+	switch strings.ToLower(filepath.Ext(path)) {
+	case ".go":
+		return "go"
+	case ".rs":
+		return "rust"
+	default:
+		return "plaintext"
+	}
+}
+
+// keywordSet returns the set of space-separated keywords
+func keywordSet(keywords string) map[string]bool {
+	set := make(map[string]bool)
+	for _, keyword := range strings.Fields(keywords) {
+		set[keyword] = true
+	}
+	return set
+}
@@ -0,0 +1,130 @@
+package reporters
+
+import (
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+)
+
+func TestHTMLReporter_SelfContained(t *testing.T) {
+	tempDir := t.TempDir()
+	sourcePath := filepath.Join(tempDir, "main.go")
+	source := "package main\n\nfunc main() {\n\tx := \"<b>\" // greeting\n\tprintln(x, 42)\n}\n"
+	if err := os.WriteFile(sourcePath, []byte(source), 0644); err != nil {
+		t.Fatalf("Failed to write source file: %v", err)
+	}
+
+	report := createTestReport()
+	report.Files[0].File.Path = sourcePath
+	report.Files[0].Violations = append(report.Files[0].Violations, &models.Violation{
+		Type:     models.ViolationTypeMagicNumber,
+		Severity: models.SeverityLow,
+		Message:  "Magic number <42>",
+		File:     sourcePath,
+		Line:     5,
+	})
+
+	outputPath := filepath.Join(tempDir, "report.html")
+	reporter, err := NewHTMLReporter(&HTMLConfig{OutputPath: outputPath, Theme: "light", SelfContained: true})
+	if err != nil {
+		t.Fatalf("Failed to create HTML reporter: %v", err)
+	}
+	if err := reporter.Generate(report); err != nil {
+		t.Fatalf("Failed to generate the interactive report: %v", err)
+	}
+
+	content, err := os.ReadFile(outputPath)
+	if err != nil {
+		t.Fatalf("Failed to read the report: %v", err)
+	}
+	html := string(content)
+
+	if strings.Contains(html, "https://") || strings.Contains(html, "<link") || strings.Contains(html, "<script src") {
+		t.Error("Expected the report to load no external assets")
+	}
+	expected := []string{
+		`id="violationTable"`,
+		`<option value="function-length">function-length</option>`,
+		`<option value="magic_number">magic_number</option>`,
+		`data-filter-severity="critical"`,
+		"Magic number &lt;42&gt;",
+		`<span class="line violation-line"><span class="number">5</span>`,
+		`<span class="tok-string">&#34;&lt;b&gt;&#34;</span>`,
+		`<span class="tok-comment">// greeting</span>`,
+	}
+	for _, want := range expected {
+		if !strings.Contains(html, want) {
+			t.Errorf("Expected the report to contain %q", want)
+		}
+	}
+}
+
+func TestBuildInteractiveReport(t *testing.T) {
+	data := buildInteractiveReport(createTestReport())
+
+	if len(data.Rows) != 2 || len(data.Files) != 1 {
+		t.Fatalf("Expected 2 rows in 1 file, got %d rows in %d files", len(data.Rows), len(data.Files))
+	}
+	if data.Rows[0].Line != 5 || data.Rows[1].Line != 10 {
+		t.Errorf("Expected rows sorted by line, got lines %d and %d", data.Rows[0].Line, data.Rows[1].Line)
+	}
+	if data.Files[0].MaxSeverity != models.SeverityCritical.String() {
+		t.Errorf("Expected the file to take its most severe violation, got %s", data.Files[0].MaxSeverity)
+	}
+	if len(data.Severities) != 2 || data.Severities[0].Label != models.SeverityCritical.String() || data.Severities[0].Percent != 100 {
+		t.Errorf("Unexpected severity chart: %+v", data.Severities)
+	}
+
+	// Unreadable files fall back to the snippet captured during the scan
+	if snippet := data.Rows[0].Snippet; len(snippet) != 1 || !strings.Contains(string(snippet[0].Code), "tok-keyword") {
+		t.Errorf("Expected the captured snippet to be highlighted, got %+v", snippet)
+	}
+}
+
+func TestBuildInteractiveReport_Health(t *testing.T) {
+	if data := buildInteractiveReport(createTestReport()); data.Scored || data.Rows[0].Health != 100 {
+		t.Errorf("Expected unscored files to count as healthy, got scored=%v health=%d", data.Scored, data.Rows[0].Health)
+	}
+
+	report := createTestReport()
+	report.Summary.Health = []*models.FileHealth{{File: "test.go", Score: 58, Violations: 2}}
+	data := buildInteractiveReport(report)
+	if !data.Scored || data.Files[0].Health.Score != 58 || data.Rows[0].Health != 58 || data.Rows[1].Health != 58 {
+		t.Errorf("Expected the rows and file to carry the file's health, got %+v", data.Files[0].Health)
+	}
+}
+
+func TestBuildSnippet(t *testing.T) {
+	lines := make([]string, 40)
+	for i := range lines {
+		lines[i] = "line"
+	}
+
+	snippet := buildSnippet(lines, &models.Violation{Line: 2, EndLine: 3}, "go")
+	if len(snippet) != 5 || snippet[0].Number != 1 || !snippet[1].Violation || !snippet[2].Violation || snippet[3].Violation {
+		t.Errorf("Expected lines 1-5 with lines 2 and 3 marked, got %+v", snippet)
+	}
+
+	long := buildSnippet(lines, &models.Violation{Line: 10, EndLine: 35}, "go")
+	if len(long) != snippetMaxLines || long[0].Number != 8 {
+		t.Errorf("Expected %d lines from line 8, got %d from %d", snippetMaxLines, len(long), long[0].Number)
+	}
+}
+
+func TestHighlightLine(t *testing.T) {
+	tests := []struct {
+		line, language, want string
+	}{
+		{"if x < 10 {", "go", `<span class="tok-keyword">if</span> x &lt; <span class="tok-number">10</span> {`},
+		{`let s = "fn"; // fn`, "rust", `<span class="tok-keyword">let</span> s = <span class="tok-string">&#34;fn&#34;</span>; <span class="tok-comment">// fn</span>`},
+		{"fn <T>", "plaintext", "fn &lt;T&gt;"},
+	}
+	for _, tt := range tests {
+		if got := string(highlightLine(tt.line, tt.language)); got != tt.want {
+			t.Errorf("highlightLine(%q) = %q, want %q", tt.line, got, tt.want)
+		}
+	}
+}
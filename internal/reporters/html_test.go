@@ -262,6 +262,63 @@ func createTestReport() *models.Report {
 	return models.NewReport(summary, []*models.ScanResult{scanResult}, reportConfig)
 }
 
+func TestHTMLReporter_FileHealth(t *testing.T) {
+	outputPath := filepath.Join(t.TempDir(), "report.html")
+	reporter, err := NewHTMLReporter(&HTMLConfig{OutputPath: outputPath, Theme: "light"})
+	if err != nil {
+		t.Fatalf("Failed to create HTML reporter: %v", err)
+	}
+
+	report := createTestReport()
+	report.Summary.Health = []*models.FileHealth{{File: "test.go", Score: 42, Violations: 2}}
+	if err := reporter.Generate(report); err != nil {
+		t.Fatalf("Failed to generate HTML report: %v", err)
+	}
+
+	content, err := os.ReadFile(outputPath)
+	if err != nil {
+		t.Fatalf("Failed to read generated HTML file: %v", err)
+	}
+	expected := []string{
+		`<th class="sortable" title="0 (worst) to 100 (best)">Health</th>`,
+		`<td data-sort-value="42"><span class="badge bg-danger">42</span></td>`,
+		`data-health="42"`,
+		`<option value="health">Health (least healthy first)</option>`,
+		`health 42</span>`,
+	}
+	for _, want := range expected {
+		if !strings.Contains(string(content), want) {
+			t.Errorf("Expected the report to contain %q", want)
+		}
+	}
+}
+
+func TestHTMLReporter_DocCoverage(t *testing.T) {
+	outputPath := filepath.Join(t.TempDir(), "report.html")
+	reporter, err := NewHTMLReporter(&HTMLConfig{OutputPath: outputPath, Theme: "light"})
+	if err != nil {
+		t.Fatalf("Failed to create HTML reporter: %v", err)
+	}
+
+	report := createTestReport()
+	report.Summary.DocCoverage = models.ComputeDocCoverage([]models.FileDocCoverage{
+		{File: "pkg/a.go", Documented: 3, Public: 4},
+		{File: "cmd/main.go", Documented: 1, Public: 2},
+	})
+	if err := reporter.Generate(report); err != nil {
+		t.Fatalf("Failed to generate HTML report: %v", err)
+	}
+
+	content, err := os.ReadFile(outputPath)
+	if err != nil {
+		t.Fatalf("Failed to read generated HTML file: %v", err)
+	}
+	want := "Documentation coverage: 66.7% of public items (4 of 6); lowest in cmd (50.0%)"
+	if !strings.Contains(string(content), want) {
+		t.Errorf("Expected the report to contain %q", want)
+	}
+}
+
 func TestHTMLReporter_InteractiveFeatures(t *testing.T) {
 	tempDir := t.TempDir()
 	outputPath := filepath.Join(tempDir, "interactive-report.html")
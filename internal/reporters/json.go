@@ -11,6 +11,11 @@ import (
 	"github.com/ericfisherdev/goclean/internal/models"
 )
 
+// JSONSchemaVersion is the version of the JSON report schema, documented in
+// docs/schemas/report.v1.json. Adding optional fields keeps the version;
+// removing, renaming or retyping a field increments it.
+const JSONSchemaVersion = 1
+
 // JSONReporter generates JSON reports for analysis results
 type JSONReporter struct {
 	config *config.JSONConfig
@@ -35,13 +40,11 @@ func (r *JSONReporter) Generate(report *models.Report) error {
 	if !r.config.Enabled {
 		return nil // Skip generation if disabled
 	}
+	return r.Write(r.Build(report))
+}
 
-	// Create the report directory if it doesn't exist
-	dir := filepath.Dir(r.config.Path)
-	if err := os.MkdirAll(dir, 0755); err != nil {
-		return fmt.Errorf("failed to create report directory: %w", err)
-	}
-
+// Build converts the report data into the JSON report structure
+func (r *JSONReporter) Build(report *models.Report) *JSONReport {
 	// Extract all violations from scan results
 	allViolations := r.extractViolations(report.Files)
 	
@@ -50,6 +53,7 @@ func (r *JSONReporter) Generate(report *models.Report) error {
 
 	// Create the JSON report data structure
 	jsonData := &JSONReport{
+		SchemaVersion: JSONSchemaVersion,
 		Metadata: JSONMetadata{
 			GeneratedAt:     report.GeneratedAt,
 			GoCleanVersion:  "dev", // TODO: Get actual version
@@ -60,6 +64,35 @@ func (r *JSONReporter) Generate(report *models.Report) error {
 		Summary: r.generateSummary(allViolations, fileLanguages),
 		Violations: r.convertViolations(allViolations, fileLanguages),
 		Statistics: r.generateStatistics(allViolations, fileLanguages),
+		CloneGroups:     report.Summary.CloneGroups,
+		Coupling:        report.Summary.Coupling,
+		Maintainability: report.Summary.Maintainability,
+		TechnicalDebt:   models.ComputeTechnicalDebt(allViolations),
+		Health:          report.Summary.Health,
+		DocCoverage:     report.Summary.DocCoverage,
+		Primitives:      report.Summary.PrimitiveObsession,
+		Manifest:        report.Manifest,
+	}
+
+	// Hidden and fixed violations are listed separately so the report also shows what changed
+	var baselined, suppressed []*models.Violation
+	for _, file := range report.Files {
+		baselined = append(baselined, file.Baselined...)
+		suppressed = append(suppressed, file.Suppressed...)
+	}
+	jsonData.Baselined = r.convertViolations(baselined, fileLanguages)
+	jsonData.Suppressed = r.convertViolations(suppressed, fileLanguages)
+	jsonData.Fixed = r.convertViolations(report.Summary.Fixed, fileLanguages)
+	jsonData.Summary.ByTriage = jsonTriageCounts(jsonData)
+	return jsonData
+}
+
+// Write writes a JSON report to the configured path
+func (r *JSONReporter) Write(jsonData *JSONReport) error {
+	// Create the report directory if it doesn't exist
+	dir := filepath.Dir(r.config.Path)
+	if err := os.MkdirAll(dir, 0755); err != nil {
+		return fmt.Errorf("failed to create report directory: %w", err)
 	}
 
 	// Marshal to JSON
@@ -89,10 +122,22 @@ func (r *JSONReporter) Format() string {
 
 // JSONReport represents the structure of the JSON report
 type JSONReport struct {
-	Metadata   JSONMetadata        `json:"metadata"`
-	Summary    JSONSummary         `json:"summary"`
-	Violations []JSONViolation     `json:"violations"`
-	Statistics JSONStatistics      `json:"statistics"`
+	SchemaVersion   int                           `json:"schema_version"`
+	Metadata        JSONMetadata                  `json:"metadata"`
+	Summary         JSONSummary                   `json:"summary"`
+	Violations      []JSONViolation               `json:"violations"`
+	Statistics      JSONStatistics                `json:"statistics"`
+	CloneGroups     []*models.CloneGroup          `json:"clone_groups,omitempty"`
+	Coupling        []*models.ModuleCoupling      `json:"coupling,omitempty"`
+	Maintainability []*models.FileMaintainability `json:"maintainability,omitempty"` // Ordered by file
+	TechnicalDebt   *models.TechnicalDebt         `json:"technical_debt,omitempty"`  // Estimated time to fix the violations
+	Health          []*models.FileHealth          `json:"file_health,omitempty"`     // Ordered by file
+	DocCoverage     *models.DocCoverage           `json:"doc_coverage,omitempty"`    // Share of public items with documentation
+	Baselined       []JSONViolation               `json:"baselined,omitempty"`       // Hidden because the baseline records them
+	Suppressed      []JSONViolation               `json:"suppressed,omitempty"`      // Hidden by goclean:ignore directives
+	Fixed           []JSONViolation               `json:"fixed,omitempty"`           // In the baseline but no longer found
+	Primitives      []*models.PrimitiveCluster    `json:"primitive_obsession,omitempty"`
+	Manifest        *models.ScanManifest          `json:"manifest,omitempty"`
 }
 
 // JSONMetadata contains metadata about the report
@@ -109,20 +154,43 @@ type JSONSummary struct {
 	BySeverity map[string]int `json:"by_severity"`
 	ByType     map[string]int `json:"by_type"`
 	ByLanguage map[string]int `json:"by_language"`
+	ByTriage   map[string]int `json:"by_triage"`
 }
 
 // JSONViolation represents a violation in the JSON report
 type JSONViolation struct {
-	ID          string `json:"id"`
-	Type        string `json:"type"`
-	Severity    string `json:"severity"`
-	Message     string `json:"message"`
-	File        string `json:"file"`
-	Line        int    `json:"line"`
-	Column      int    `json:"column"`
-	Language    string `json:"language"`
-	Suggestion  string `json:"suggestion,omitempty"`
-	CodeSnippet string `json:"code_snippet,omitempty"`
+	ID          string              `json:"id"`
+	Rule        string              `json:"rule"`
+	Type        string              `json:"type"`
+	Category    string              `json:"category"`
+	Severity    string              `json:"severity"`
+	Message     string              `json:"message"`
+	File        string              `json:"file"`
+	Line        int                 `json:"line"`
+	Column      int                 `json:"column"`
+	Span        JSONSpan            `json:"span"`
+	Language    string              `json:"language"`
+	Suggestion  string              `json:"suggestion,omitempty"`
+	CodeSnippet string              `json:"code_snippet,omitempty"`
+	DocURL      string              `json:"doc_url,omitempty"`
+	SourceURL   string              `json:"source_url,omitempty"`
+	Fingerprint string              `json:"fingerprint,omitempty"`
+	Fix         *models.Fix         `json:"fix,omitempty"`
+	Triage      string              `json:"triage,omitempty"`
+	Occurrences []models.Occurrence `json:"occurrences,omitempty"` // Findings collapsed into a grouped violation
+	Remediation int                 `json:"remediation_minutes,omitempty"` // Estimated minutes to fix
+	Blame       *models.Blame       `json:"blame,omitempty"`               // Author and age of TODO markers
+	Confidence  float64             `json:"confidence,omitempty"`          // How sure a heuristic detector is, from 0 to 1
+	Reasons     []string            `json:"reasons,omitempty"`             // What made a heuristic detector report it
+}
+
+// JSONSpan is the source range of a violation. Lines and columns are 1-based
+// and inclusive; the end equals the start for single-position violations.
+type JSONSpan struct {
+	StartLine   int `json:"start_line"`
+	StartColumn int `json:"start_column"`
+	EndLine     int `json:"end_line"`
+	EndColumn   int `json:"end_column"`
 }
 
 // JSONStatistics contains statistical information about the violations
@@ -198,15 +266,28 @@ func (r *JSONReporter) convertViolations(violations []*models.Violation, fileLan
 		
 		jsonViolations[i] = JSONViolation{
 			ID:          v.ID,
+			Rule:        violationRule(v),
 			Type:        string(v.Type),
+			Category:    v.Type.Category(),
 			Severity:    v.Severity.String(),
 			Message:     v.Message,
 			File:        v.File,
 			Line:        v.Line,
 			Column:      v.Column,
+			Span:        violationSpan(v),
 			Language:    language,
 			Suggestion:  v.Suggestion,
 			CodeSnippet: v.CodeSnippet,
+			DocURL:      v.DocURL,
+			SourceURL:   v.SourceURL,
+			Fingerprint: v.Fingerprint,
+			Fix:         v.Fix,
+			Triage:      string(v.Triage),
+			Occurrences: v.Occurrences,
+			Remediation: v.Remediation,
+			Blame:       v.Blame,
+			Confidence:  v.Confidence,
+			Reasons:     v.Reasons,
 			// Note: Metadata removed as it doesn't exist in models.Violation
 		}
 	}
@@ -214,6 +295,29 @@ func (r *JSONReporter) convertViolations(violations []*models.Violation, fileLan
 	return jsonViolations
 }
 
+// jsonTriageCounts counts the violations of a report in each triage state
+func jsonTriageCounts(report *JSONReport) map[string]int {
+	return map[string]int{
+		string(models.TriageNew):        len(report.Violations),
+		string(models.TriageBaseline):   len(report.Baselined),
+		string(models.TriageSuppressed): len(report.Suppressed),
+		string(models.TriageFixed):      len(report.Fixed),
+	}
+}
+
+// violationSpan returns the source range of a violation, ending at its
+// start when the detector reported no end
+func violationSpan(v *models.Violation) JSONSpan {
+	span := JSONSpan{StartLine: v.Line, StartColumn: v.Column, EndLine: v.EndLine, EndColumn: v.EndColumn}
+	if span.EndLine < span.StartLine {
+		span.EndLine = span.StartLine
+	}
+	if span.EndLine == span.StartLine && span.EndColumn < span.StartColumn {
+		span.EndColumn = span.StartColumn
+	}
+	return span
+}
+
 // generateStatistics generates statistical information about the violations
 func (r *JSONReporter) generateStatistics(violations []*models.Violation, fileLanguages map[string]string) JSONStatistics {
 	stats := JSONStatistics{
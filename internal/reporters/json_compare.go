@@ -0,0 +1,87 @@
+package reporters
+
+import (
+	"github.com/ericfisherdev/goclean/internal/models"
+)
+
+// ReportComparison lists how the violations of a report changed since a
+// base report
+type ReportComparison struct {
+	Added     []JSONViolation   `json:"added"`     // Only in the head report
+	Removed   []JSONViolation   `json:"removed"`   // Only in the base report
+	Unchanged []JSONViolation   `json:"unchanged"` // In both reports, as the head reports them
+	Summary   ComparisonSummary `json:"summary"`
+}
+
+// ComparisonSummary counts the violations of a comparison, in total and by severity
+type ComparisonSummary struct {
+	Added             int            `json:"added"`
+	Removed           int            `json:"removed"`
+	Unchanged         int            `json:"unchanged"`
+	AddedBySeverity   map[string]int `json:"added_by_severity"`
+	RemovedBySeverity map[string]int `json:"removed_by_severity"`
+	NetBySeverity     map[string]int `json:"net_by_severity"` // Added minus removed
+}
+
+// CompareReports matches the violations of two reports by fingerprint and
+// sorts them into added, removed and unchanged. Reports from older versions
+// may lack fingerprints, in which case the location and message are used.
+// Use CheckRuleSets first to refuse reports scanned with different rules.
+func CompareReports(base, head *JSONReport) *ReportComparison {
+	comparison := &ReportComparison{
+		Added:     []JSONViolation{},
+		Removed:   []JSONViolation{},
+		Unchanged: []JSONViolation{},
+		Summary: ComparisonSummary{
+			AddedBySeverity:   make(map[string]int),
+			RemovedBySeverity: make(map[string]int),
+			NetBySeverity:     make(map[string]int),
+		},
+	}
+
+	// Identical violations carry numbered fingerprints, so counting keys
+	// matches each copy once
+	remaining := make(map[string]int, len(base.Violations))
+	for _, v := range base.Violations {
+		remaining[mergeKey(v)]++
+	}
+	for _, v := range head.Violations {
+		key := mergeKey(v)
+		if remaining[key] > 0 {
+			remaining[key]--
+			comparison.Unchanged = append(comparison.Unchanged, v)
+			continue
+		}
+		comparison.Added = append(comparison.Added, v)
+		comparison.Summary.AddedBySeverity[v.Severity]++
+		comparison.Summary.NetBySeverity[v.Severity]++
+	}
+	for _, v := range base.Violations {
+		key := mergeKey(v)
+		if remaining[key] == 0 {
+			continue
+		}
+		remaining[key]--
+		comparison.Removed = append(comparison.Removed, v)
+		comparison.Summary.RemovedBySeverity[v.Severity]++
+		comparison.Summary.NetBySeverity[v.Severity]--
+	}
+
+	comparison.Summary.Added = len(comparison.Added)
+	comparison.Summary.Removed = len(comparison.Removed)
+	comparison.Summary.Unchanged = len(comparison.Unchanged)
+	return comparison
+}
+
+// NetNew returns how many more violations of at least the minimum severity
+// the head report has than the base report. A violation whose message
+// changed counts as removed and added, so it does not add to the total.
+func (s ComparisonSummary) NetNew(minimum models.Severity) int {
+	net := 0
+	for name, count := range s.NetBySeverity {
+		if severity, err := models.ParseSeverity(name); err == nil && severity >= minimum {
+			net += count
+		}
+	}
+	return net
+}
@@ -0,0 +1,53 @@
+package reporters
+
+import (
+	"testing"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+)
+
+func TestCompareReports(t *testing.T) {
+	kept := JSONViolation{Severity: "High", File: "pkg/a.go", Line: 3, Fingerprint: "aaa"}
+	copied := JSONViolation{Severity: "Low", File: "pkg/a.go", Line: 9, Fingerprint: "bbb"}
+	fixed := JSONViolation{Severity: "Critical", File: "pkg/b.go", Line: 1, Fingerprint: "ccc"}
+	base := &JSONReport{Violations: []JSONViolation{kept, copied, fixed}}
+
+	// The unchanged violation moved down, and a second copy of bbb appeared
+	moved := kept
+	moved.Line = 5
+	second := copied
+	second.Fingerprint = "bbb-2"
+	introduced := JSONViolation{Severity: "Critical", File: "pkg/c.go", Line: 7, Fingerprint: "ddd"}
+	head := &JSONReport{Violations: []JSONViolation{moved, copied, second, introduced}}
+
+	comparison := CompareReports(base, head)
+	summary := comparison.Summary
+	if summary.Added != 2 || summary.Removed != 1 || summary.Unchanged != 2 {
+		t.Fatalf("Expected 2 added, 1 removed and 2 unchanged, got %+v", summary)
+	}
+	if comparison.Unchanged[0].Line != 5 {
+		t.Errorf("Expected unchanged violations as the head reports them, got %+v", comparison.Unchanged[0])
+	}
+	if comparison.Removed[0].Fingerprint != "ccc" {
+		t.Errorf("Expected ccc to be removed, got %+v", comparison.Removed)
+	}
+	if summary.AddedBySeverity["Critical"] != 1 || summary.AddedBySeverity["Low"] != 1 || summary.NetBySeverity["Critical"] != 0 {
+		t.Errorf("Unexpected counts by severity: %+v", summary)
+	}
+
+	// One critical fixed and one introduced is no net-new critical
+	if net := summary.NetNew(models.SeverityCritical); net != 0 {
+		t.Errorf("Expected no net-new criticals, got %d", net)
+	}
+	if net := summary.NetNew(models.SeverityLow); net != 1 {
+		t.Errorf("Expected 1 net-new violation of low severity or above, got %d", net)
+	}
+}
+
+func TestCompareReportsWithoutFingerprints(t *testing.T) {
+	old := JSONViolation{Type: "magic_number", Severity: "Medium", File: "src/lib.rs", Line: 4, Column: 2, Message: "Magic number 42"}
+	comparison := CompareReports(&JSONReport{Violations: []JSONViolation{old}}, &JSONReport{Violations: []JSONViolation{old}})
+	if comparison.Summary.Unchanged != 1 || len(comparison.Added) != 0 || len(comparison.Removed) != 0 {
+		t.Errorf("Expected violations without fingerprints to match by location, got %+v", comparison.Summary)
+	}
+}
@@ -0,0 +1,297 @@
+package reporters
+
+import (
+	"encoding/json"
+	"fmt"
+	"os"
+	"sort"
+	"time"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+)
+
+// LoadJSONReport reads a report written by the JSON reporter
+func LoadJSONReport(path string) (*JSONReport, error) {
+	data, err := os.ReadFile(path)
+	if err != nil {
+		return nil, fmt.Errorf("failed to read JSON report: %w", err)
+	}
+
+	var report JSONReport
+	if err := json.Unmarshal(data, &report); err != nil {
+		return nil, fmt.Errorf("failed to parse JSON report %s: %w", path, err)
+	}
+	if report.SchemaVersion > JSONSchemaVersion {
+		return nil, fmt.Errorf("JSON report %s uses schema version %d, but this version of goclean reads up to version %d",
+			path, report.SchemaVersion, JSONSchemaVersion)
+	}
+	return &report, nil
+}
+
+// Merge combines the JSON reports of scan shards into a single report.
+// Violations reported by more than one shard are kept once, matched by
+// fingerprint, and the summary and statistics are recomputed from the
+// merged violations. Clones spanning two shards cannot be detected. Modules
+// split across shards are combined, and their afferent coupling recomputed.
+// A baseline entry is fixed only if no shard still found it. Use
+// CheckRuleSets first to refuse shards scanned with different rules.
+func (r *JSONReporter) Merge(shards []*JSONReport) (*JSONReport, error) {
+	merged := &JSONReport{
+		SchemaVersion: JSONSchemaVersion,
+		Metadata:      JSONMetadata{GeneratedAt: time.Now()},
+	}
+
+	var violations []*models.Violation
+	fileLanguages := make(map[string]string)
+	seen := make(map[string]bool)
+	modules := make(map[string]*models.ModuleCoupling)
+	var fixed []JSONViolation
+	seenBaselined, seenSuppressed, seenFixed := make(map[string]bool), make(map[string]bool), make(map[string]bool)
+	seenMaintainability, seenHealth := make(map[string]bool), make(map[string]bool)
+	var documented []models.FileDocCoverage
+	seenDocumented := make(map[string]bool)
+	var primitives [][]*models.PrimitiveCluster
+	for _, shard := range shards {
+		if merged.Metadata.GoCleanVersion == "" {
+			merged.Metadata.GoCleanVersion = shard.Metadata.GoCleanVersion
+		}
+		merged.Metadata.FilesScanned += shard.Metadata.FilesScanned
+		// Shards run in parallel, so the slowest one bounds the scan
+		merged.Metadata.ScanDuration = max(merged.Metadata.ScanDuration, shard.Metadata.ScanDuration)
+		merged.Manifest = mergeManifest(merged.Manifest, shard.Manifest)
+
+		for _, v := range shard.Violations {
+			key := mergeKey(v)
+			if seen[key] {
+				continue
+			}
+			seen[key] = true
+
+			violation, err := v.toViolation()
+			if err != nil {
+				return nil, err
+			}
+			violations = append(violations, violation)
+			if v.Language != "" && v.Language != "Unknown" {
+				fileLanguages[v.File] = v.Language
+			}
+		}
+
+		merged.Baselined = appendUnseen(merged.Baselined, shard.Baselined, seenBaselined)
+		merged.Suppressed = appendUnseen(merged.Suppressed, shard.Suppressed, seenSuppressed)
+		fixed = appendUnseen(fixed, shard.Fixed, seenFixed)
+
+		merged.CloneGroups = append(merged.CloneGroups, shard.CloneGroups...)
+		merged.Maintainability = appendUnseenFiles(merged.Maintainability, shard.Maintainability, seenMaintainability)
+		merged.Health = appendUnseenHealth(merged.Health, shard.Health, seenHealth)
+		if shard.DocCoverage != nil {
+			documented = appendUnseenDocCoverage(documented, shard.DocCoverage.Files, seenDocumented)
+		}
+		primitives = append(primitives, shard.Primitives)
+		for _, module := range shard.Coupling {
+			merged.Coupling = mergeCoupling(merged.Coupling, modules, module)
+		}
+	}
+	models.RankCloneGroups(merged.CloneGroups)
+	models.LinkCoupling(merged.Coupling)
+	sort.SliceStable(merged.Maintainability, func(i, j int) bool {
+		return merged.Maintainability[i].File < merged.Maintainability[j].File
+	})
+	sort.SliceStable(merged.Health, func(i, j int) bool {
+		return merged.Health[i].File < merged.Health[j].File
+	})
+
+	sort.SliceStable(violations, func(i, j int) bool {
+		if violations[i].File != violations[j].File {
+			return violations[i].File < violations[j].File
+		}
+		if violations[i].Line != violations[j].Line {
+			return violations[i].Line < violations[j].Line
+		}
+		return violations[i].Column < violations[j].Column
+	})
+
+	merged.Metadata.TotalViolations = len(violations)
+	merged.Summary = r.generateSummary(violations, fileLanguages)
+	merged.Violations = r.convertViolations(violations, fileLanguages)
+	merged.Statistics = r.generateStatistics(violations, fileLanguages)
+	merged.TechnicalDebt = models.ComputeTechnicalDebt(violations)
+	merged.DocCoverage = models.ComputeDocCoverage(documented)
+	merged.Primitives = models.MergePrimitiveClusters(primitives...)
+
+	// Each shard reports the baseline entries outside its files as fixed,
+	// so only entries no shard still found are fixed
+	for _, v := range fixed {
+		if !seenBaselined[mergeKey(v)] {
+			merged.Fixed = append(merged.Fixed, v)
+		}
+	}
+	merged.Summary.ByTriage = jsonTriageCounts(merged)
+	return merged, nil
+}
+
+// ScanResults converts the violations of a report back into one scan result
+// per file, so checks written for a scan can run on a loaded report
+func (r *JSONReport) ScanResults() ([]*models.ScanResult, error) {
+	var results []*models.ScanResult
+	byFile := make(map[string]*models.ScanResult)
+	for _, v := range r.Violations {
+		violation, err := v.toViolation()
+		if err != nil {
+			return nil, err
+		}
+		result := byFile[v.File]
+		if result == nil {
+			result = &models.ScanResult{File: &models.FileInfo{Path: v.File, Language: v.Language}}
+			byFile[v.File] = result
+			results = append(results, result)
+		}
+		result.Violations = append(result.Violations, violation)
+	}
+	return results, nil
+}
+
+// appendUnseen appends the violations whose merge key has not been seen yet
+func appendUnseen(merged, violations []JSONViolation, seen map[string]bool) []JSONViolation {
+	for _, v := range violations {
+		key := mergeKey(v)
+		if seen[key] {
+			continue
+		}
+		seen[key] = true
+		merged = append(merged, v)
+	}
+	return merged
+}
+
+// appendUnseenFiles adds the maintainability of files not yet seen; files
+// scanned by several shards are kept once
+func appendUnseenFiles(merged, files []*models.FileMaintainability, seen map[string]bool) []*models.FileMaintainability {
+	for _, file := range files {
+		if seen[file.File] {
+			continue
+		}
+		seen[file.File] = true
+		merged = append(merged, file)
+	}
+	return merged
+}
+
+// appendUnseenHealth adds the health of files not yet seen; files scanned by
+// several shards are kept once
+func appendUnseenHealth(merged, files []*models.FileHealth, seen map[string]bool) []*models.FileHealth {
+	for _, file := range files {
+		if seen[file.File] {
+			continue
+		}
+		seen[file.File] = true
+		merged = append(merged, file)
+	}
+	return merged
+}
+
+// appendUnseenDocCoverage adds the documentation counts of files not yet
+// seen; files scanned by several shards are kept once
+func appendUnseenDocCoverage(merged, files []models.FileDocCoverage, seen map[string]bool) []models.FileDocCoverage {
+	for _, file := range files {
+		if seen[file.File] {
+			continue
+		}
+		seen[file.File] = true
+		merged = append(merged, file)
+	}
+	return merged
+}
+
+// CheckRuleSets returns an error when the reports were produced by different
+// rule sets, so their violations cannot be combined or compared
+func CheckRuleSets(reports []*JSONReport) error {
+	manifests := make([]*models.ScanManifest, len(reports))
+	for i, report := range reports {
+		manifests[i] = report.Manifest
+	}
+	return models.CheckRuleSets(manifests)
+}
+
+// mergeManifest combines the manifest of a shard into the merged manifest.
+// The slowest shard bounds the duration and any dirty checkout makes the
+// merge dirty; shards of different commits leave the commit empty.
+func mergeManifest(merged, shard *models.ScanManifest) *models.ScanManifest {
+	if shard == nil {
+		return merged
+	}
+	if merged == nil {
+		combined := *shard
+		return &combined
+	}
+	merged.Duration = max(merged.Duration, shard.Duration)
+	merged.GitDirty = merged.GitDirty || shard.GitDirty
+	if merged.GitSHA != shard.GitSHA {
+		merged.GitSHA = ""
+	}
+	return merged
+}
+
+// mergeKey identifies a violation across shards. Reports from older versions
+// may lack fingerprints, in which case the location and message are used.
+func mergeKey(v JSONViolation) string {
+	if v.Fingerprint != "" {
+		return v.Fingerprint
+	}
+	return fmt.Sprintf("%s:%d:%d:%s:%s", v.File, v.Line, v.Column, v.Type, v.Message)
+}
+
+// toViolation converts a JSON violation back into the model
+func (v JSONViolation) toViolation() (*models.Violation, error) {
+	severity, err := models.ParseSeverity(v.Severity)
+	if err != nil {
+		return nil, fmt.Errorf("violation %s in %s:%d: %w", v.ID, v.File, v.Line, err)
+	}
+	return &models.Violation{
+		ID:          v.ID,
+		Type:        models.ViolationType(v.Type),
+		Severity:    severity,
+		Message:     v.Message,
+		File:        v.File,
+		Line:        v.Line,
+		Column:      v.Column,
+		EndLine:     v.Span.EndLine,
+		EndColumn:   v.Span.EndColumn,
+		Rule:        v.Rule,
+		Suggestion:  v.Suggestion,
+		CodeSnippet: v.CodeSnippet,
+		DocURL:      v.DocURL,
+		SourceURL:   v.SourceURL,
+		Fingerprint: v.Fingerprint,
+		Fix:         v.Fix,
+		Triage:      models.TriageState(v.Triage),
+		Occurrences: v.Occurrences,
+		Remediation: v.Remediation,
+		Blame:       v.Blame,
+		Confidence:  v.Confidence,
+		Reasons:     v.Reasons,
+	}, nil
+}
+
+// mergeCoupling adds the coupling of a module reported by a shard, combining
+// it with the same module reported by an earlier shard
+func mergeCoupling(merged []*models.ModuleCoupling, modules map[string]*models.ModuleCoupling, module *models.ModuleCoupling) []*models.ModuleCoupling {
+	existing, ok := modules[module.Module]
+	if !ok {
+		modules[module.Module] = module
+		return append(merged, module)
+	}
+
+	existing.Files += module.Files
+	existing.MaxFanOut = max(existing.MaxFanOut, module.MaxFanOut)
+	deps := make(map[string]bool)
+	for _, dep := range append(existing.Dependencies, module.Dependencies...) {
+		deps[dep] = true
+	}
+	existing.Dependencies = existing.Dependencies[:0]
+	for dep := range deps {
+		existing.Dependencies = append(existing.Dependencies, dep)
+	}
+	sort.Strings(existing.Dependencies)
+	return merged
+}
@@ -0,0 +1,206 @@
+package reporters
+
+import (
+	"path/filepath"
+	"testing"
+	"time"
+
+	"github.com/ericfisherdev/goclean/internal/config"
+	"github.com/ericfisherdev/goclean/internal/models"
+)
+
+func writeShard(t *testing.T, path string, report *JSONReport) {
+	t.Helper()
+	reporter := NewJSONReporter(&config.JSONConfig{Enabled: true, Path: path})
+	if err := reporter.Write(report); err != nil {
+		t.Fatalf("Failed to write shard: %v", err)
+	}
+}
+
+func TestJSONReporter_Merge(t *testing.T) {
+	tempDir := t.TempDir()
+	shared := JSONViolation{Type: "naming_convention", Severity: "Low", File: "pkg/b.go", Line: 3, Language: "Go", Fingerprint: "bbb"}
+
+	first := filepath.Join(tempDir, "shard1.json")
+	writeShard(t, first, &JSONReport{
+		Metadata: JSONMetadata{GoCleanVersion: "1.2.0", FilesScanned: 10, ScanDuration: 2 * time.Second},
+		Violations: []JSONViolation{
+			{Type: "function_length", Severity: "High", File: "pkg/c.go", Line: 8, Language: "Go", Fingerprint: "ccc"},
+			shared,
+		},
+		CloneGroups: []*models.CloneGroup{{ID: 1, Tokens: 60}},
+	})
+	second := filepath.Join(tempDir, "shard2.json")
+	writeShard(t, second, &JSONReport{
+		Metadata: JSONMetadata{GoCleanVersion: "1.2.0", FilesScanned: 5, ScanDuration: 3 * time.Second},
+		Violations: []JSONViolation{
+			shared,
+			{Type: "magic_number", Severity: "Medium", File: "src/lib.rs", Line: 1, Language: "Rust"},
+		},
+		CloneGroups: []*models.CloneGroup{{ID: 1, Tokens: 80}},
+	})
+
+	var shards []*JSONReport
+	for _, path := range []string{first, second} {
+		shard, err := LoadJSONReport(path)
+		if err != nil {
+			t.Fatalf("LoadJSONReport failed: %v", err)
+		}
+		shards = append(shards, shard)
+	}
+
+	merged, err := NewJSONReporter(nil).Merge(shards)
+	if err != nil {
+		t.Fatalf("Merge failed: %v", err)
+	}
+
+	if merged.Metadata.FilesScanned != 15 || merged.Metadata.TotalViolations != 3 || merged.Metadata.ScanDuration != 3*time.Second {
+		t.Errorf("Unexpected metadata: %+v", merged.Metadata)
+	}
+	if len(merged.Violations) != 3 {
+		t.Fatalf("Expected 3 violations after deduplication, got %d", len(merged.Violations))
+	}
+	if merged.Violations[0].File != "pkg/b.go" || merged.Violations[2].File != "src/lib.rs" {
+		t.Errorf("Expected violations sorted by file, got %s first and %s last", merged.Violations[0].File, merged.Violations[2].File)
+	}
+	if merged.Summary.BySeverity["Low"] != 1 || merged.Summary.ByLanguage["Go"] != 2 || merged.Summary.ByLanguage["Rust"] != 1 {
+		t.Errorf("Unexpected summary: %+v", merged.Summary)
+	}
+	if merged.Statistics.TotalFiles != 3 {
+		t.Errorf("Expected statistics over 3 files, got %d", merged.Statistics.TotalFiles)
+	}
+	if len(merged.CloneGroups) != 2 || merged.CloneGroups[1].ID != 2 {
+		t.Errorf("Expected clone groups to be renumbered, got %d groups", len(merged.CloneGroups))
+	}
+}
+
+func TestJSONReporter_MergeTriage(t *testing.T) {
+	known := JSONViolation{Type: "magic_number", Severity: "Low", File: "pkg/a.go", Line: 3, Fingerprint: "aaa", Triage: "baseline"}
+	gone := JSONViolation{Type: "naming_convention", Severity: "Low", File: "pkg/b.go", Line: 1, Fingerprint: "bbb", Triage: "fixed"}
+
+	// The second shard did not scan pkg/a.go, so it reports its baseline entry as fixed
+	knownAsFixed := known
+	knownAsFixed.Triage = "fixed"
+	shards := []*JSONReport{
+		{Baselined: []JSONViolation{known}, Fixed: []JSONViolation{gone}},
+		{Fixed: []JSONViolation{knownAsFixed, gone}},
+	}
+
+	merged, err := NewJSONReporter(nil).Merge(shards)
+	if err != nil {
+		t.Fatalf("Merge failed: %v", err)
+	}
+	if len(merged.Baselined) != 1 || len(merged.Fixed) != 1 || merged.Fixed[0].Fingerprint != "bbb" {
+		t.Errorf("Expected aaa baselined and bbb fixed, got %+v and %+v", merged.Baselined, merged.Fixed)
+	}
+	if merged.Summary.ByTriage["baseline"] != 1 || merged.Summary.ByTriage["fixed"] != 1 || merged.Summary.ByTriage["new"] != 0 {
+		t.Errorf("Unexpected triage counts: %v", merged.Summary.ByTriage)
+	}
+}
+
+func TestJSONReporter_MergeHealth(t *testing.T) {
+	shards := []*JSONReport{
+		{Health: []*models.FileHealth{{File: "pkg/b.go", Score: 70}, {File: "pkg/c.go", Score: 40}}},
+		{Health: []*models.FileHealth{{File: "pkg/a.go", Score: 90}, {File: "pkg/b.go", Score: 70}}},
+	}
+
+	merged, err := NewJSONReporter(nil).Merge(shards)
+	if err != nil {
+		t.Fatalf("Merge failed: %v", err)
+	}
+	if len(merged.Health) != 3 || merged.Health[0].File != "pkg/a.go" || merged.Health[2].File != "pkg/c.go" {
+		t.Errorf("Expected each file once, ordered by file, got %+v", merged.Health)
+	}
+}
+
+func TestJSONReporter_MergeDocCoverage(t *testing.T) {
+	shards := []*JSONReport{
+		{DocCoverage: &models.DocCoverage{Files: []models.FileDocCoverage{
+			{File: "src/lib.rs", Documented: 2, Public: 2}, {File: "src/net/http.rs", Documented: 0, Public: 3},
+		}}},
+		{DocCoverage: &models.DocCoverage{Files: []models.FileDocCoverage{
+			{File: "src/net/http.rs", Documented: 0, Public: 3}, {File: "src/net/tcp.rs", Documented: 3, Public: 3},
+		}}},
+		{},
+	}
+
+	merged, err := NewJSONReporter(nil).Merge(shards)
+	if err != nil {
+		t.Fatalf("Merge failed: %v", err)
+	}
+	coverage := merged.DocCoverage
+	if coverage == nil || coverage.Documented != 5 || coverage.Public != 8 || coverage.Percent != 62.5 || len(coverage.Modules) != 2 {
+		t.Errorf("Expected each file counted once, got %+v", coverage)
+	}
+}
+
+func TestCheckRuleSetsAndMergeManifest(t *testing.T) {
+	first := &JSONReport{Manifest: &models.ScanManifest{ToolVersion: "1.2.0", RuleSetHash: "aaa", GitSHA: "abc", Duration: time.Second}}
+	second := &JSONReport{Manifest: &models.ScanManifest{ToolVersion: "1.2.0", RuleSetHash: "aaa", GitSHA: "abc", GitDirty: true, Duration: 2 * time.Second}}
+	legacy := &JSONReport{}
+	other := &JSONReport{Manifest: &models.ScanManifest{ToolVersion: "1.3.0", RuleSetHash: "bbb"}}
+
+	if err := CheckRuleSets([]*JSONReport{first, legacy, second}); err != nil {
+		t.Errorf("Expected shards of one rule set to be compatible, got %v", err)
+	}
+	if err := CheckRuleSets([]*JSONReport{first, other}); err == nil {
+		t.Error("Expected an error for shards of different rule sets")
+	}
+
+	merged, err := NewJSONReporter(nil).Merge([]*JSONReport{first, legacy, second})
+	if err != nil {
+		t.Fatalf("Merge failed: %v", err)
+	}
+	manifest := merged.Manifest
+	if manifest == nil || manifest.RuleSetHash != "aaa" || manifest.GitSHA != "abc" || !manifest.GitDirty || manifest.Duration != 2*time.Second {
+		t.Errorf("Unexpected merged manifest: %+v", manifest)
+	}
+	if first.Manifest.GitDirty {
+		t.Error("Expected the shard manifest to be left unchanged")
+	}
+}
+
+func TestJSONReporter_MergeRejectsUnknownSeverity(t *testing.T) {
+	shard := &JSONReport{Violations: []JSONViolation{{Type: "naming_convention", Severity: "Severe", File: "a.go"}}}
+	if _, err := NewJSONReporter(nil).Merge([]*JSONReport{shard}); err == nil {
+		t.Error("Expected an error for an unknown severity")
+	}
+}
+
+func TestLoadJSONReport_Invalid(t *testing.T) {
+	if _, err := LoadJSONReport(filepath.Join(t.TempDir(), "missing.json")); err == nil {
+		t.Error("Expected an error for a missing report")
+	}
+}
+
+func TestLoadJSONReport_RejectsNewerSchema(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "future.json")
+	writeShard(t, path, &JSONReport{SchemaVersion: JSONSchemaVersion + 1})
+	if _, err := LoadJSONReport(path); err == nil {
+		t.Error("Expected an error for a report with a newer schema version")
+	}
+}
+
+func TestJSONReport_ScanResults(t *testing.T) {
+	report := &JSONReport{Violations: []JSONViolation{
+		{Type: "function_length", Severity: "High", File: "pkg/a.go", Line: 8, Language: "Go"},
+		{Type: "magic_number", Severity: "Low", File: "src/lib.rs", Line: 1, Language: "Rust"},
+		{Type: "naming_convention", Severity: "Medium", File: "pkg/a.go", Line: 12, Language: "Go"},
+	}}
+
+	results, err := report.ScanResults()
+	if err != nil {
+		t.Fatalf("ScanResults failed: %v", err)
+	}
+	if len(results) != 2 || results[0].File.Path != "pkg/a.go" || results[1].File.Language != "Rust" {
+		t.Fatalf("Expected one result per file in report order, got %+v", results)
+	}
+	if len(results[0].Violations) != 2 || results[0].Violations[0].Severity != models.SeverityHigh {
+		t.Errorf("Expected both pkg/a.go violations with their severities, got %+v", results[0].Violations)
+	}
+
+	report.Violations = append(report.Violations, JSONViolation{Type: "magic_number", Severity: "Severe", File: "pkg/b.go"})
+	if _, err := report.ScanResults(); err == nil {
+		t.Error("Expected an unknown severity to be rejected")
+	}
+}
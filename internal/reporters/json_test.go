@@ -2,8 +2,12 @@ package reporters
 
 import (
 	"encoding/json"
+	"fmt"
 	"os"
 	"path/filepath"
+	"reflect"
+	"sort"
+	"strings"
 	"testing"
 	"time"
 
@@ -325,4 +329,140 @@ func TestFindMostCommon(t *testing.T) {
 			}
 		})
 	}
-}
\ No newline at end of file
+}
+
+func TestConvertViolations_RuleCategoryAndSpan(t *testing.T) {
+	violations := []*models.Violation{
+		{Type: models.ViolationTypeFunctionLength, Rule: "function-length", Severity: models.SeverityHigh, File: "a.go", Line: 10, Column: 1, EndLine: 40, EndColumn: 2},
+		{Type: models.ViolationTypeMagicNumber, Severity: models.SeverityLow, File: "a.go", Line: 12, Column: 9},
+	}
+
+	converted := NewJSONReporter(nil).convertViolations(violations, map[string]string{"a.go": "Go"})
+	if converted[0].Rule != "function-length" || converted[0].Category != models.CategoryComplexity {
+		t.Errorf("Unexpected rule and category: %s, %s", converted[0].Rule, converted[0].Category)
+	}
+	if want := (JSONSpan{StartLine: 10, StartColumn: 1, EndLine: 40, EndColumn: 2}); converted[0].Span != want {
+		t.Errorf("Expected span %+v, got %+v", want, converted[0].Span)
+	}
+
+	// Violations without a rule or end position fall back to their type and start
+	if converted[1].Rule != "magic_number" {
+		t.Errorf("Expected the type as rule, got %q", converted[1].Rule)
+	}
+	if want := (JSONSpan{StartLine: 12, StartColumn: 9, EndLine: 12, EndColumn: 9}); converted[1].Span != want {
+		t.Errorf("Expected span %+v, got %+v", want, converted[1].Span)
+	}
+}
+
+func TestJSONReporter_GenerateTriage(t *testing.T) {
+	outputPath := filepath.Join(t.TempDir(), "triage.json")
+	reporter := NewJSONReporter(&config.JSONConfig{Enabled: true, Path: outputPath})
+
+	reported := &models.Violation{Type: models.ViolationTypeFunctionLength, Severity: models.SeverityHigh, File: "a.go", Line: 10, Triage: models.TriageNew}
+	known := &models.Violation{Type: models.ViolationTypeMagicNumber, Severity: models.SeverityLow, File: "a.go", Line: 3, Triage: models.TriageBaseline}
+	ignored := &models.Violation{Type: models.ViolationTypeMagicNumber, Severity: models.SeverityLow, File: "a.go", Line: 5, Triage: models.TriageSuppressed}
+	fixed := &models.Violation{Type: models.ViolationTypeNaming, Severity: models.SeverityLow, File: "b.go", Line: 1, Triage: models.TriageFixed}
+	report := &models.Report{
+		Summary: &models.ScanSummary{Fixed: []*models.Violation{fixed}},
+		Files: []*models.ScanResult{{
+			File:       &models.FileInfo{Path: "a.go", Language: "Go"},
+			Violations: []*models.Violation{reported},
+			Baselined:  []*models.Violation{known},
+			Suppressed: []*models.Violation{ignored},
+		}},
+	}
+	if err := reporter.Generate(report); err != nil {
+		t.Fatalf("Generate failed: %v", err)
+	}
+
+	loaded, err := LoadJSONReport(outputPath)
+	if err != nil {
+		t.Fatalf("LoadJSONReport failed: %v", err)
+	}
+	if len(loaded.Violations) != 1 || loaded.Violations[0].Triage != "new" {
+		t.Errorf("Expected one new violation, got %+v", loaded.Violations)
+	}
+	if len(loaded.Baselined) != 1 || loaded.Baselined[0].Triage != "baseline" ||
+		len(loaded.Suppressed) != 1 || loaded.Suppressed[0].Triage != "suppressed" ||
+		len(loaded.Fixed) != 1 || loaded.Fixed[0].File != "b.go" {
+		t.Errorf("Unexpected hidden violations: %+v, %+v, %+v", loaded.Baselined, loaded.Suppressed, loaded.Fixed)
+	}
+	want := map[string]int{"new": 1, "baseline": 1, "suppressed": 1, "fixed": 1}
+	if !reflect.DeepEqual(loaded.Summary.ByTriage, want) {
+		t.Errorf("Expected triage counts %v, got %v", want, loaded.Summary.ByTriage)
+	}
+}
+
+// TestJSONSchemaMatchesReport keeps the documented schema in step with the
+// report types, so that the JSON output cannot change shape unnoticed
+func TestJSONSchemaMatchesReport(t *testing.T) {
+	data, err := os.ReadFile(filepath.Join("..", "..", "docs", "schemas", "report.v1.json"))
+	if err != nil {
+		t.Fatalf("Failed to read the schema: %v", err)
+	}
+	type object struct {
+		Required   []string                   `json:"required"`
+		Properties map[string]json.RawMessage `json:"properties"`
+	}
+	var schema struct {
+		object
+		Defs map[string]object `json:"$defs"`
+	}
+	if err := json.Unmarshal(data, &schema); err != nil {
+		t.Fatalf("Failed to parse the schema: %v", err)
+	}
+
+	definitions := map[string]reflect.Type{
+		"metadata":        reflect.TypeOf(JSONMetadata{}),
+		"summary":         reflect.TypeOf(JSONSummary{}),
+		"violation":       reflect.TypeOf(JSONViolation{}),
+		"span":            reflect.TypeOf(JSONSpan{}),
+		"fix":             reflect.TypeOf(models.Fix{}),
+		"text_edit":       reflect.TypeOf(models.TextEdit{}),
+		"statistics":      reflect.TypeOf(JSONStatistics{}),
+		"language_stats":  reflect.TypeOf(JSONLanguageStats{}),
+		"clone_group":     reflect.TypeOf(models.CloneGroup{}),
+		"clone_location":  reflect.TypeOf(models.CloneLocation{}),
+		"module_coupling": reflect.TypeOf(models.ModuleCoupling{}),
+		"scan_manifest":   reflect.TypeOf(models.ScanManifest{}),
+	}
+	check := func(name string, definition object, goType reflect.Type) {
+		var fields, required []string
+		for i := 0; i < goType.NumField(); i++ {
+			tag := strings.Split(goType.Field(i).Tag.Get("json"), ",")
+			fields = append(fields, tag[0])
+			if len(tag) == 1 || tag[1] != "omitempty" {
+				required = append(required, tag[0])
+			}
+		}
+		var properties []string
+		for property := range definition.Properties {
+			properties = append(properties, property)
+		}
+		sort.Strings(fields)
+		sort.Strings(required)
+		sort.Strings(properties)
+		documented := append([]string(nil), definition.Required...)
+		sort.Strings(documented)
+
+		if !reflect.DeepEqual(fields, properties) {
+			t.Errorf("%s: schema properties %v do not match the fields %v", name, properties, fields)
+		}
+		if !reflect.DeepEqual(required, documented) {
+			t.Errorf("%s: schema requires %v, but the fields without omitempty are %v", name, documented, required)
+		}
+	}
+
+	check("report", schema.object, reflect.TypeOf(JSONReport{}))
+	for name, goType := range definitions {
+		definition, ok := schema.Defs[name]
+		if !ok {
+			t.Errorf("The schema does not define %s", name)
+			continue
+		}
+		check(name, definition, goType)
+	}
+	if !strings.Contains(string(schema.Properties["schema_version"]), fmt.Sprintf(`"const": %d`, JSONSchemaVersion)) {
+		t.Errorf("Expected the schema to document version %d", JSONSchemaVersion)
+	}
+}
@@ -0,0 +1,181 @@
+package reporters
+
+import (
+	"encoding/xml"
+	"fmt"
+	"os"
+	"path/filepath"
+	"sort"
+	"strings"
+
+	"github.com/ericfisherdev/goclean/internal/config"
+	"github.com/ericfisherdev/goclean/internal/models"
+)
+
+// junitTestSuites is the root element of a JUnit XML report
+type junitTestSuites struct {
+	XMLName  xml.Name          `xml:"testsuites"`
+	Name     string            `xml:"name,attr"`
+	Tests    int               `xml:"tests,attr"`
+	Failures int               `xml:"failures,attr"`
+	Time     string            `xml:"time,attr"`
+	Suites   []*junitTestSuite `xml:"testsuite"`
+}
+
+// junitTestSuite groups the test cases of one rule or file
+type junitTestSuite struct {
+	Name      string           `xml:"name,attr"`
+	Tests     int              `xml:"tests,attr"`
+	Failures  int              `xml:"failures,attr"`
+	Time      string           `xml:"time,attr"`
+	Timestamp string           `xml:"timestamp,attr"`
+	Cases     []*junitTestCase `xml:"testcase"`
+}
+
+// junitTestCase is a violation, or a passing check when it has no failure
+type junitTestCase struct {
+	Name      string        `xml:"name,attr"`
+	Classname string        `xml:"classname,attr"`
+	Time      string        `xml:"time,attr"`
+	Failure   *junitFailure `xml:"failure,omitempty"`
+}
+
+// junitFailure describes the violation of a failed test case
+type junitFailure struct {
+	Message string `xml:"message,attr"`
+	Type    string `xml:"type,attr"`
+	Text    string `xml:",chardata"`
+}
+
+// JUnitReporter writes violations as JUnit XML test failures, which CI
+// servers such as Jenkins render with counts and trends
+type JUnitReporter struct {
+	config *config.JUnitConfig
+}
+
+// NewJUnitReporter creates a new JUnit reporter with the given configuration
+func NewJUnitReporter(cfg *config.JUnitConfig) *JUnitReporter {
+	if cfg == nil {
+		cfg = &config.JUnitConfig{
+			Enabled: true,
+			Path:    "./reports/junit.xml",
+			GroupBy: config.JUnitGroupByRule,
+		}
+	}
+	return &JUnitReporter{config: cfg}
+}
+
+// Generate writes the JUnit report for the report's files
+func (r *JUnitReporter) Generate(report *models.Report) error {
+	if !r.config.Enabled {
+		return nil
+	}
+
+	dir := filepath.Dir(r.config.Path)
+	if err := os.MkdirAll(dir, 0755); err != nil {
+		return fmt.Errorf("failed to create report directory: %w", err)
+	}
+
+	data, err := xml.MarshalIndent(buildJUnitReport(report, r.config.GroupBy), "", "  ")
+	if err != nil {
+		return fmt.Errorf("failed to marshal JUnit report: %w", err)
+	}
+	data = append([]byte(xml.Header), data...)
+
+	if err := os.WriteFile(r.config.Path, append(data, '\n'), 0644); err != nil {
+		return fmt.Errorf("failed to write JUnit report: %w", err)
+	}
+	return nil
+}
+
+// buildJUnitReport groups the violations of a report into test suites by
+// rule or by file. Grouped by file, every scanned file gets a suite, and
+// clean files pass, so CI trends track the files that stay clean too.
+func buildJUnitReport(report *models.Report, groupBy string) *junitTestSuites {
+	suites := make(map[string]*junitTestSuite)
+	suite := func(name string) *junitTestSuite {
+		if s, ok := suites[name]; ok {
+			return s
+		}
+		s := &junitTestSuite{
+			Name:      name,
+			Time:      "0",
+			Timestamp: report.GeneratedAt.Format("2006-01-02T15:04:05"),
+		}
+		suites[name] = s
+		return s
+	}
+
+	for _, result := range report.Files {
+		if result.File == nil {
+			continue
+		}
+		if groupBy == config.JUnitGroupByFile && len(result.Violations) == 0 {
+			s := suite(result.File.Path)
+			s.Cases = append(s.Cases, &junitTestCase{
+				Name:      result.File.Path,
+				Classname: result.File.Path,
+				Time:      "0",
+			})
+			continue
+		}
+		for _, violation := range sortedViolations(result.Violations) {
+			name := violationRule(violation)
+			if groupBy == config.JUnitGroupByFile {
+				name = result.File.Path
+			}
+			s := suite(name)
+			s.Cases = append(s.Cases, junitCase(name, violation))
+		}
+	}
+
+	root := &junitTestSuites{
+		Name: "goclean",
+		Time: fmt.Sprintf("%.3f", report.Summary.Duration.Seconds()),
+	}
+	if len(suites) == 0 {
+		// CI servers reject reports without test cases, so a clean scan passes one
+		s := suite("goclean")
+		s.Cases = append(s.Cases, &junitTestCase{Name: "no violations", Classname: "goclean", Time: "0"})
+	}
+	for _, s := range suites {
+		for _, c := range s.Cases {
+			s.Tests++
+			if c.Failure != nil {
+				s.Failures++
+			}
+		}
+		root.Tests += s.Tests
+		root.Failures += s.Failures
+		root.Suites = append(root.Suites, s)
+	}
+	sort.Slice(root.Suites, func(i, j int) bool {
+		return root.Suites[i].Name < root.Suites[j].Name
+	})
+	return root
+}
+
+// junitCase converts a violation to a failed test case of the named suite
+func junitCase(suite string, v *models.Violation) *junitTestCase {
+	location := fmt.Sprintf("%s:%d:%d", v.File, v.Line, v.Column)
+
+	var text strings.Builder
+	fmt.Fprintf(&text, "%s\n", v.Message)
+	fmt.Fprintf(&text, "Rule: %s\n", violationRule(v))
+	fmt.Fprintf(&text, "Severity: %s\n", v.Severity)
+	fmt.Fprintf(&text, "Location: %s\n", location)
+	if v.Suggestion != "" {
+		fmt.Fprintf(&text, "Suggestion: %s\n", v.Suggestion)
+	}
+
+	return &junitTestCase{
+		Name:      location,
+		Classname: suite,
+		Time:      "0",
+		Failure: &junitFailure{
+			Message: v.Message,
+			Type:    v.Severity.String(),
+			Text:    text.String(),
+		},
+	}
+}
@@ -0,0 +1,80 @@
+package reporters
+
+import (
+	"encoding/xml"
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+
+	"github.com/ericfisherdev/goclean/internal/config"
+	"github.com/ericfisherdev/goclean/internal/models"
+)
+
+func TestJUnitReporter_Generate(t *testing.T) {
+	outputPath := filepath.Join(t.TempDir(), "reports", "junit.xml")
+	reporter := NewJUnitReporter(&config.JUnitConfig{Enabled: true, Path: outputPath, GroupBy: config.JUnitGroupByRule})
+
+	if err := reporter.Generate(createTestReport()); err != nil {
+		t.Fatalf("Failed to generate JUnit report: %v", err)
+	}
+
+	data, err := os.ReadFile(outputPath)
+	if err != nil {
+		t.Fatalf("Failed to read JUnit report: %v", err)
+	}
+	if !strings.HasPrefix(string(data), xml.Header) {
+		t.Error("Expected the report to start with an XML declaration")
+	}
+
+	var parsed junitTestSuites
+	if err := xml.Unmarshal(data, &parsed); err != nil {
+		t.Fatalf("Failed to parse JUnit report: %v", err)
+	}
+	if parsed.Tests != 2 || parsed.Failures != 2 || len(parsed.Suites) != 2 {
+		t.Fatalf("Expected 2 failures in 2 suites, got %d of %d in %d", parsed.Failures, parsed.Tests, len(parsed.Suites))
+	}
+
+	suite := parsed.Suites[0]
+	if suite.Name != "function-length" || suite.Tests != 1 || suite.Failures != 1 {
+		t.Errorf("Expected the function-length suite first, got %+v", suite)
+	}
+	testCase := suite.Cases[0]
+	if testCase.Name != "test.go:10:1" || testCase.Classname != "function-length" {
+		t.Errorf("Unexpected test case: %+v", testCase)
+	}
+	if testCase.Failure == nil || testCase.Failure.Type != "Critical" || testCase.Failure.Message != "Test Violation" {
+		t.Fatalf("Expected a critical failure, got %+v", testCase.Failure)
+	}
+	if !strings.Contains(testCase.Failure.Text, "Suggestion: Break down this function") {
+		t.Errorf("Expected the failure to include the suggestion, got %q", testCase.Failure.Text)
+	}
+}
+
+func TestBuildJUnitReport_GroupByFile(t *testing.T) {
+	report := createTestReport()
+	report.Files = append(report.Files, &models.ScanResult{File: &models.FileInfo{Path: "clean.go"}})
+
+	suites := buildJUnitReport(report, config.JUnitGroupByFile)
+	if suites.Tests != 3 || suites.Failures != 2 || len(suites.Suites) != 2 {
+		t.Fatalf("Expected 2 failures of 3 tests in 2 suites, got %d of %d in %d", suites.Failures, suites.Tests, len(suites.Suites))
+	}
+
+	clean, dirty := suites.Suites[0], suites.Suites[1]
+	if clean.Name != "clean.go" || clean.Failures != 0 || clean.Tests != 1 {
+		t.Errorf("Expected clean.go to pass, got %+v", clean)
+	}
+	if dirty.Name != "test.go" || dirty.Failures != 2 {
+		t.Fatalf("Expected test.go to fail twice, got %+v", dirty)
+	}
+	if dirty.Cases[0].Name != "test.go:5:5" || dirty.Cases[1].Name != "test.go:10:1" {
+		t.Errorf("Expected test cases ordered by line, got %s and %s", dirty.Cases[0].Name, dirty.Cases[1].Name)
+	}
+}
+
+func TestBuildJUnitReport_NoViolations(t *testing.T) {
+	suites := buildJUnitReport(createTestReportNoViolations(), config.JUnitGroupByRule)
+	if suites.Tests != 1 || suites.Failures != 0 || len(suites.Suites) != 1 {
+		t.Errorf("Expected one passing test case, got %d tests and %d failures", suites.Tests, suites.Failures)
+	}
+}
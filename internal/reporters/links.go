@@ -0,0 +1,146 @@
+package reporters
+
+import (
+	"os"
+	"os/exec"
+	"path/filepath"
+	"strconv"
+	"strings"
+
+	"github.com/ericfisherdev/goclean/internal/config"
+	"github.com/ericfisherdev/goclean/internal/models"
+)
+
+// Default source link templates for the supported forges
+const (
+	GitHubSourceTemplate = "{repo}/blob/{commit}/{path}#L{line}"
+	GitLabSourceTemplate = "{repo}/-/blob/{commit}/{path}#L{line}"
+)
+
+// LinkResolver decorates violations with links to rule documentation and
+// to the exact file/line on the configured source forge
+type LinkResolver struct {
+	ruleDocsTemplate string
+	sourceTemplate   string
+	repositoryURL    string
+	commitSHA        string
+	repositoryRoot   string
+}
+
+// NewLinkResolver creates a link resolver from the links configuration.
+// Missing commit SHA and repository root are detected with git when possible.
+func NewLinkResolver(cfg config.LinksConfig) *LinkResolver {
+	resolver := &LinkResolver{
+		ruleDocsTemplate: cfg.RuleDocsTemplate,
+		sourceTemplate:   cfg.SourceTemplate,
+		repositoryURL:    strings.TrimSuffix(strings.TrimSuffix(cfg.RepositoryURL, "/"), ".git"),
+		commitSHA:        cfg.CommitSHA,
+		repositoryRoot:   cfg.RepositoryRoot,
+	}
+
+	if resolver.repositoryURL == "" {
+		return resolver
+	}
+
+	if resolver.sourceTemplate == "" {
+		resolver.sourceTemplate = defaultSourceTemplate(resolver.repositoryURL)
+	}
+	if resolver.commitSHA == "" {
+		resolver.commitSHA = runGit("rev-parse", "HEAD")
+	}
+	if resolver.repositoryRoot == "" {
+		resolver.repositoryRoot = runGit("rev-parse", "--show-toplevel")
+	}
+
+	return resolver
+}
+
+// Enabled reports whether any link template is configured
+func (l *LinkResolver) Enabled() bool {
+	return l.ruleDocsTemplate != "" || l.SourceLinksEnabled()
+}
+
+// SourceLinksEnabled reports whether source forge links can be built
+func (l *LinkResolver) SourceLinksEnabled() bool {
+	return l.sourceTemplate != "" && l.repositoryURL != "" && l.commitSHA != ""
+}
+
+// Apply sets DocURL and SourceURL on every violation in the scan results
+func (l *LinkResolver) Apply(files []*models.ScanResult) {
+	if !l.Enabled() {
+		return
+	}
+
+	for _, file := range files {
+		for _, violation := range file.Violations {
+			l.ApplyToViolation(violation)
+		}
+	}
+}
+
+// ApplyToViolation sets DocURL and SourceURL on a single violation
+func (l *LinkResolver) ApplyToViolation(violation *models.Violation) {
+	if violation == nil {
+		return
+	}
+	if l.ruleDocsTemplate != "" && violation.Rule != "" {
+		violation.DocURL = l.expand(l.ruleDocsTemplate, violation)
+	}
+	if l.SourceLinksEnabled() && violation.File != "" {
+		violation.SourceURL = l.expand(l.sourceTemplate, violation)
+	}
+}
+
+// expand substitutes the template placeholders for a violation
+func (l *LinkResolver) expand(template string, violation *models.Violation) string {
+	endLine := violation.EndLine
+	if endLine == 0 {
+		endLine = violation.Line
+	}
+
+	replacer := strings.NewReplacer(
+		"{rule}", violation.Rule,
+		"{type}", string(violation.Type),
+		"{repo}", l.repositoryURL,
+		"{commit}", l.commitSHA,
+		"{path}", l.relativePath(violation.File),
+		"{line}", strconv.Itoa(violation.Line),
+		"{end_line}", strconv.Itoa(endLine),
+	)
+	return replacer.Replace(template)
+}
+
+// relativePath converts a file path into a forward-slash path relative to the repository root
+func (l *LinkResolver) relativePath(path string) string {
+	root := l.repositoryRoot
+	if root == "" {
+		if wd, err := os.Getwd(); err == nil {
+			root = wd
+		}
+	}
+
+	if absPath, err := filepath.Abs(path); err == nil && root != "" {
+		if rel, err := filepath.Rel(root, absPath); err == nil && !strings.HasPrefix(rel, "..") {
+			path = rel
+		}
+	}
+
+	return strings.TrimPrefix(filepath.ToSlash(path), "./")
+}
+
+// defaultSourceTemplate picks the blob URL layout based on the repository host
+func defaultSourceTemplate(repositoryURL string) string {
+	if strings.Contains(strings.ToLower(repositoryURL), "gitlab") {
+		return GitLabSourceTemplate
+	}
+	return GitHubSourceTemplate
+}
+
+// runGit runs a git command and returns its trimmed output, or "" on failure
+func runGit(args ...string) string {
+	output, err := exec.Command("git", args...).Output()
+	if err != nil {
+		return ""
+	}
+	return strings.TrimSpace(string(output))
+}
@@ -0,0 +1,80 @@
+package reporters
+
+import (
+	"testing"
+
+	"github.com/ericfisherdev/goclean/internal/config"
+	"github.com/ericfisherdev/goclean/internal/models"
+)
+
+func TestLinkResolver_DisabledByDefault(t *testing.T) {
+	resolver := NewLinkResolver(config.LinksConfig{})
+
+	if resolver.Enabled() {
+		t.Error("Expected link resolver to be disabled without templates")
+	}
+
+	violation := &models.Violation{Rule: "rust-function-length", File: "src/lib.rs", Line: 10}
+	resolver.Apply([]*models.ScanResult{{Violations: []*models.Violation{violation}}})
+
+	if violation.DocURL != "" || violation.SourceURL != "" {
+		t.Errorf("Expected no links, got doc=%q source=%q", violation.DocURL, violation.SourceURL)
+	}
+}
+
+func TestLinkResolver_GitHubLinks(t *testing.T) {
+	resolver := NewLinkResolver(config.LinksConfig{
+		RuleDocsTemplate: "https://docs.example.com/rules/{rule}",
+		RepositoryURL:    "https://github.com/org/project.git",
+		CommitSHA:        "abc123",
+		RepositoryRoot:   "/repo",
+	})
+
+	violation := &models.Violation{
+		Rule: "rust-function-length",
+		File: "/repo/src/lib.rs",
+		Line: 42,
+	}
+	resolver.ApplyToViolation(violation)
+
+	expectedDoc := "https://docs.example.com/rules/rust-function-length"
+	if violation.DocURL != expectedDoc {
+		t.Errorf("Expected doc URL %q, got %q", expectedDoc, violation.DocURL)
+	}
+
+	expectedSource := "https://github.com/org/project/blob/abc123/src/lib.rs#L42"
+	if violation.SourceURL != expectedSource {
+		t.Errorf("Expected source URL %q, got %q", expectedSource, violation.SourceURL)
+	}
+}
+
+func TestLinkResolver_GitLabAndCustomTemplate(t *testing.T) {
+	gitlab := NewLinkResolver(config.LinksConfig{
+		RepositoryURL:  "https://gitlab.com/group/project/",
+		CommitSHA:      "def456",
+		RepositoryRoot: "/repo",
+	})
+
+	violation := &models.Violation{File: "/repo/main.go", Line: 7}
+	gitlab.ApplyToViolation(violation)
+
+	expected := "https://gitlab.com/group/project/-/blob/def456/main.go#L7"
+	if violation.SourceURL != expected {
+		t.Errorf("Expected %q, got %q", expected, violation.SourceURL)
+	}
+
+	custom := NewLinkResolver(config.LinksConfig{
+		SourceTemplate: "{repo}/src/{commit}/{path}#lines-{line}:{end_line}",
+		RepositoryURL:  "https://bitbucket.org/team/project",
+		CommitSHA:      "0ff1ce",
+		RepositoryRoot: "/repo",
+	})
+
+	violation = &models.Violation{File: "/repo/pkg/a.go", Line: 3, EndLine: 9}
+	custom.ApplyToViolation(violation)
+
+	expected = "https://bitbucket.org/team/project/src/0ff1ce/pkg/a.go#lines-3:9"
+	if violation.SourceURL != expected {
+		t.Errorf("Expected %q, got %q", expected, violation.SourceURL)
+	}
+}
@@ -0,0 +1,89 @@
+package reporters
+
+import (
+	"bufio"
+	"fmt"
+	"io"
+	"sort"
+	"strings"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+)
+
+// Location list formats
+const (
+	// FormatLocations writes GNU-style "file:line:col: level: message" lines,
+	// understood by grep-based tools, Emacs compilation mode and fzf pickers
+	FormatLocations = "locations"
+	// FormatQuickfix writes "file:line:col:T: message" lines with a one-letter
+	// type, matching the Vim errorformat %f:%l:%c:%t:\ %m
+	FormatQuickfix = "quickfix"
+)
+
+// IsLocationsFormat reports whether format is one of the location list formats
+func IsLocationsFormat(format string) bool {
+	return format == FormatLocations || format == FormatQuickfix
+}
+
+// LocationsReporter writes one line per violation for editors and pickers
+// that jump to file locations
+type LocationsReporter struct {
+	format string
+}
+
+// NewLocationsReporter creates a reporter for FormatLocations or FormatQuickfix
+func NewLocationsReporter(format string) *LocationsReporter {
+	return &LocationsReporter{format: format}
+}
+
+// Write writes the violations of the results sorted by file, line and column
+func (r *LocationsReporter) Write(w io.Writer, results []*models.ScanResult) error {
+	var all []*models.Violation
+	for _, result := range results {
+		all = append(all, result.Violations...)
+	}
+	sort.SliceStable(all, func(i, j int) bool {
+		if all[i].File != all[j].File {
+			return all[i].File < all[j].File
+		}
+		if all[i].Line != all[j].Line {
+			return all[i].Line < all[j].Line
+		}
+		return all[i].Column < all[j].Column
+	})
+
+	buffered := bufio.NewWriter(w)
+	for _, v := range all {
+		fmt.Fprintln(buffered, r.formatLine(v))
+	}
+	return buffered.Flush()
+}
+
+// formatLine formats a single violation. Columns start at 1, and messages
+// are kept on one line so every line is one location.
+func (r *LocationsReporter) formatLine(v *models.Violation) string {
+	message := strings.Join(strings.Fields(v.Message), " ")
+	if v.Rule != "" {
+		message += " [" + v.Rule + "]"
+	}
+	line := max(v.Line, 1)
+	column := max(v.Column, 1)
+
+	level := locationLevel(v.Severity)
+	if r.format == FormatQuickfix {
+		return fmt.Sprintf("%s:%d:%d:%s: %s", v.File, line, column, strings.ToUpper(level[:1]), message)
+	}
+	return fmt.Sprintf("%s:%d:%d: %s: %s", v.File, line, column, level, message)
+}
+
+// locationLevel maps severities to the levels of compiler diagnostics
+func locationLevel(severity models.Severity) string {
+	switch severity {
+	case models.SeverityCritical:
+		return "error"
+	case models.SeverityHigh, models.SeverityMedium:
+		return "warning"
+	default:
+		return "note"
+	}
+}
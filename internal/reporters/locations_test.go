@@ -0,0 +1,54 @@
+package reporters
+
+import (
+	"bytes"
+	"testing"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+)
+
+func locationsTestResults() []*models.ScanResult {
+	return []*models.ScanResult{
+		{Violations: []*models.Violation{
+			{File: "src/b.go", Line: 7, Column: 2, Severity: models.SeverityMedium, Rule: "magic-number", Message: "Magic number 42"},
+		}},
+		{Violations: []*models.Violation{
+			{File: "src/a.go", Line: 30, Column: 1, Severity: models.SeverityCritical, Rule: "function-length", Message: "Function is\ntoo long"},
+			{File: "src/a.go", Line: 4, Severity: models.SeverityLow, Message: "Unclear name"},
+		}},
+	}
+}
+
+func TestLocationsReporter(t *testing.T) {
+	var buf bytes.Buffer
+	if err := NewLocationsReporter(FormatLocations).Write(&buf, locationsTestResults()); err != nil {
+		t.Fatalf("Write failed: %v", err)
+	}
+
+	expected := "src/a.go:4:1: note: Unclear name\n" +
+		"src/a.go:30:1: error: Function is too long [function-length]\n" +
+		"src/b.go:7:2: warning: Magic number 42 [magic-number]\n"
+	if buf.String() != expected {
+		t.Errorf("Unexpected output:\n%s\nwant:\n%s", buf.String(), expected)
+	}
+}
+
+func TestLocationsReporter_Quickfix(t *testing.T) {
+	var buf bytes.Buffer
+	if err := NewLocationsReporter(FormatQuickfix).Write(&buf, locationsTestResults()); err != nil {
+		t.Fatalf("Write failed: %v", err)
+	}
+
+	expected := "src/a.go:4:1:N: Unclear name\n" +
+		"src/a.go:30:1:E: Function is too long [function-length]\n" +
+		"src/b.go:7:2:W: Magic number 42 [magic-number]\n"
+	if buf.String() != expected {
+		t.Errorf("Unexpected output:\n%s\nwant:\n%s", buf.String(), expected)
+	}
+}
+
+func TestIsLocationsFormat(t *testing.T) {
+	if !IsLocationsFormat("locations") || !IsLocationsFormat("quickfix") || IsLocationsFormat("json") {
+		t.Error("Unexpected location format detection")
+	}
+}
@@ -17,6 +17,8 @@ type Reporter interface {
 type Manager struct {
 	reporters []Reporter
 	config    *config.Config
+	links     *LinkResolver
+	manifest  *models.ScanManifest
 }
 
 // NewManager creates a new reporter manager
@@ -24,6 +26,7 @@ func NewManager(cfg *config.Config) (*Manager, error) {
 	manager := &Manager{
 		reporters: make([]Reporter, 0),
 		config:    cfg,
+		links:     NewLinkResolver(cfg.Output.Links),
 	}
 
 	// Initialize HTML reporter if configured
@@ -33,6 +36,7 @@ func NewManager(cfg *config.Config) (*Manager, error) {
 			AutoRefresh:     cfg.Output.HTML.AutoRefresh,
 			RefreshInterval: cfg.Output.HTML.RefreshInterval,
 			Theme:           cfg.Output.HTML.Theme,
+			SelfContained:   cfg.Output.HTML.SelfContained,
 		}
 
 		htmlReporter, err := NewHTMLReporter(htmlConfig)
@@ -60,22 +64,55 @@ func NewManager(cfg *config.Config) (*Manager, error) {
 		manager.reporters = append(manager.reporters, jsonReporter)
 	}
 
+	// Initialize unsafe inventory export if configured
+	if cfg.Export.UnsafeInventory.Enabled && cfg.Export.UnsafeInventory.Path != "" {
+		inventoryReporter := NewUnsafeInventoryReporter(&cfg.Export.UnsafeInventory, cfg.Rust.SafetyCommentMarker)
+		manager.reporters = append(manager.reporters, inventoryReporter)
+	}
+
+	// Initialize panic census export if configured
+	if cfg.Export.PanicCensus.Enabled && cfg.Export.PanicCensus.Path != "" {
+		censusReporter := NewPanicCensusReporter(&cfg.Export.PanicCensus)
+		manager.reporters = append(manager.reporters, censusReporter)
+	}
+
+	// Initialize JUnit export if configured
+	if cfg.Export.JUnit.Enabled && cfg.Export.JUnit.Path != "" {
+		junitReporter := NewJUnitReporter(&cfg.Export.JUnit)
+		manager.reporters = append(manager.reporters, junitReporter)
+	}
+
+	// Initialize Code Climate export if configured
+	if cfg.Export.CodeClimate.Enabled && cfg.Export.CodeClimate.Path != "" {
+		codeClimateReporter := NewCodeClimateReporter(&cfg.Export.CodeClimate)
+		manager.reporters = append(manager.reporters, codeClimateReporter)
+	}
+
+	// Initialize GitHub job summary if configured
+	if cfg.Export.GitHubSummary.Enabled {
+		failOn, err := models.ParseSeverity(cfg.Severity.FailOn)
+		if err != nil {
+			failOn = models.SeverityInfo
+		}
+		summaryReporter := NewGitHubSummaryReporter(&cfg.Export.GitHubSummary, failOn)
+		manager.reporters = append(manager.reporters, summaryReporter)
+	}
+
 	return manager, nil
 }
 
+// SetManifest sets the scan manifest embedded in the reports
+func (m *Manager) SetManifest(manifest *models.ScanManifest) {
+	m.manifest = manifest
+}
+
 // GenerateReports creates all configured reports
 func (m *Manager) GenerateReports(summary *models.ScanSummary, files []*models.ScanResult) error {
 	// Create report config from current config
 	reportConfig := &models.ReportConfig{
-		Paths:     m.config.Scan.Paths,
-		FileTypes: m.config.Scan.FileTypes,
-		Thresholds: &models.Thresholds{
-			FunctionLines:        m.config.Thresholds.FunctionLines,
-			CyclomaticComplexity: m.config.Thresholds.CyclomaticComplexity,
-			Parameters:           m.config.Thresholds.Parameters,
-			NestingDepth:         m.config.Thresholds.NestingDepth,
-			ClassLines:           m.config.Thresholds.ClassLines,
-		},
+		Paths:      m.config.Scan.Paths,
+		FileTypes:  m.config.Scan.FileTypes,
+		Thresholds: m.reportThresholds(),
 		HTMLSettings: &models.HTMLOptions{
 			AutoRefresh:     m.config.Output.HTML.AutoRefresh,
 			RefreshInterval: m.config.Output.HTML.RefreshInterval,
@@ -83,8 +120,12 @@ func (m *Manager) GenerateReports(summary *models.ScanSummary, files []*models.S
 		},
 	}
 
+	// Decorate violations with rule documentation and source forge links
+	m.links.Apply(files)
+
 	// Create the report
 	report := models.NewReport(summary, files, reportConfig)
+	report.Manifest = m.manifest
 
 	// Generate all reports concurrently
 	var wg sync.WaitGroup
@@ -115,15 +156,9 @@ func (m *Manager) GenerateReports(summary *models.ScanSummary, files []*models.S
 func (m *Manager) GenerateConsoleReport(summary *models.ScanSummary, files []*models.ScanResult, verbose, colors bool) error {
 	// Create report config
 	reportConfig := &models.ReportConfig{
-		Paths:     m.config.Scan.Paths,
-		FileTypes: m.config.Scan.FileTypes,
-		Thresholds: &models.Thresholds{
-			FunctionLines:        m.config.Thresholds.FunctionLines,
-			CyclomaticComplexity: m.config.Thresholds.CyclomaticComplexity,
-			Parameters:           m.config.Thresholds.Parameters,
-			NestingDepth:         m.config.Thresholds.NestingDepth,
-			ClassLines:           m.config.Thresholds.ClassLines,
-		},
+		Paths:      m.config.Scan.Paths,
+		FileTypes:  m.config.Scan.FileTypes,
+		Thresholds: m.reportThresholds(),
 	}
 
 	// Create the report
@@ -142,15 +177,9 @@ func (m *Manager) GenerateHTMLReportWithProgress(summary *models.ScanSummary, fi
 
 	// Create report config
 	reportConfig := &models.ReportConfig{
-		Paths:     m.config.Scan.Paths,
-		FileTypes: m.config.Scan.FileTypes,
-		Thresholds: &models.Thresholds{
-			FunctionLines:        m.config.Thresholds.FunctionLines,
-			CyclomaticComplexity: m.config.Thresholds.CyclomaticComplexity,
-			Parameters:           m.config.Thresholds.Parameters,
-			NestingDepth:         m.config.Thresholds.NestingDepth,
-			ClassLines:           m.config.Thresholds.ClassLines,
-		},
+		Paths:      m.config.Scan.Paths,
+		FileTypes:  m.config.Scan.FileTypes,
+		Thresholds: m.reportThresholds(),
 		HTMLSettings: &models.HTMLOptions{
 			AutoRefresh:     m.config.Output.HTML.AutoRefresh,
 			RefreshInterval: m.config.Output.HTML.RefreshInterval,
@@ -158,8 +187,12 @@ func (m *Manager) GenerateHTMLReportWithProgress(summary *models.ScanSummary, fi
 		},
 	}
 
+	// Decorate violations with rule documentation and source forge links
+	m.links.Apply(files)
+
 	// Create the report
 	report := models.NewReport(summary, files, reportConfig)
+	report.Manifest = m.manifest
 
 	// Create HTML reporter
 	htmlConfig := &HTMLConfig{
@@ -167,6 +200,7 @@ func (m *Manager) GenerateHTMLReportWithProgress(summary *models.ScanSummary, fi
 		AutoRefresh:     m.config.Output.HTML.AutoRefresh,
 		RefreshInterval: m.config.Output.HTML.RefreshInterval,
 		Theme:           m.config.Output.HTML.Theme,
+		SelfContained:   m.config.Output.HTML.SelfContained,
 	}
 
 	htmlReporter, err := NewHTMLReporter(htmlConfig)
@@ -178,6 +212,19 @@ func (m *Manager) GenerateHTMLReportWithProgress(summary *models.ScanSummary, fi
 	return htmlReporter.GenerateWithProgress(report, progressFn)
 }
 
+// reportThresholds returns the effective thresholds recorded in reports
+func (m *Manager) reportThresholds() *models.Thresholds {
+	thresholds := m.config.Thresholds.Effective()
+	return &models.Thresholds{
+		FunctionLines:        thresholds.FunctionLines,
+		CyclomaticComplexity: thresholds.CyclomaticComplexity,
+		CognitiveComplexity:  thresholds.CognitiveComplexity,
+		Parameters:           thresholds.Parameters,
+		NestingDepth:         thresholds.NestingDepth,
+		ClassLines:           thresholds.ClassLines,
+	}
+}
+
 // GetConfiguredReporters returns the list of configured reporter types
 func (m *Manager) GetConfiguredReporters() []string {
 	var types []string
@@ -191,6 +238,21 @@ func (m *Manager) GetConfiguredReporters() []string {
 	if m.config.Export.JSON.Enabled && m.config.Export.JSON.Path != "" {
 		types = append(types, "JSON")
 	}
+	if m.config.Export.UnsafeInventory.Enabled && m.config.Export.UnsafeInventory.Path != "" {
+		types = append(types, "Unsafe inventory")
+	}
+	if m.config.Export.PanicCensus.Enabled && m.config.Export.PanicCensus.Path != "" {
+		types = append(types, "Panic census")
+	}
+	if m.config.Export.JUnit.Enabled && m.config.Export.JUnit.Path != "" {
+		types = append(types, "JUnit")
+	}
+	if m.config.Export.CodeClimate.Enabled && m.config.Export.CodeClimate.Path != "" {
+		types = append(types, "Code Climate")
+	}
+	if m.config.Export.GitHubSummary.Enabled {
+		types = append(types, "GitHub summary")
+	}
 
 	return types
 }
@@ -214,4 +276,44 @@ func (m *Manager) GetJSONOutputPath() string {
 		return m.config.Export.JSON.Path
 	}
 	return ""
-}
\ No newline at end of file
+}
+
+// GetUnsafeInventoryPath returns the configured unsafe inventory output path
+func (m *Manager) GetUnsafeInventoryPath() string {
+	if m.config.Export.UnsafeInventory.Enabled {
+		return m.config.Export.UnsafeInventory.Path
+	}
+	return ""
+}
+
+// GetPanicCensusPath returns the configured panic census output path
+func (m *Manager) GetPanicCensusPath() string {
+	if m.config.Export.PanicCensus.Enabled {
+		return m.config.Export.PanicCensus.Path
+	}
+	return ""
+}
+
+// GetJUnitPath returns the configured JUnit output path
+func (m *Manager) GetJUnitPath() string {
+	if m.config.Export.JUnit.Enabled {
+		return m.config.Export.JUnit.Path
+	}
+	return ""
+}
+
+// GetCodeClimatePath returns the configured Code Climate output path
+func (m *Manager) GetCodeClimatePath() string {
+	if m.config.Export.CodeClimate.Enabled {
+		return m.config.Export.CodeClimate.Path
+	}
+	return ""
+}
+
+// GetGitHubSummaryPath returns the file the GitHub job summary is written to
+func (m *Manager) GetGitHubSummaryPath() string {
+	if m.config.Export.GitHubSummary.Enabled {
+		return GitHubSummaryPath(&m.config.Export.GitHubSummary)
+	}
+	return ""
+}
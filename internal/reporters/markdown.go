@@ -74,6 +74,36 @@ func (m *MarkdownReporter) generateMarkdown(report *models.Report) string {
 		m.writeTopViolatedFiles(&md, report.Statistics.TopViolatedFiles)
 	}
 	
+	// Clone Groups
+	if len(report.Summary.CloneGroups) > 0 {
+		m.writeCloneGroups(&md, report.Summary.CloneGroups)
+	}
+	
+	// Maintainability Index
+	if len(report.Summary.Maintainability) > 0 {
+		m.writeMaintainability(&md, report.Summary.Maintainability)
+	}
+	
+	// Technical Debt
+	if debt := report.Summary.Debt; debt != nil && debt.Minutes > 0 {
+		m.writeTechnicalDebt(&md, debt)
+	}
+	
+	// File Health
+	if len(report.Summary.Health) > 0 {
+		m.writeFileHealth(&md, report.Summary.Health)
+	}
+	
+	// Documentation Coverage
+	if coverage := report.Summary.DocCoverage; coverage != nil {
+		m.writeDocCoverage(&md, coverage)
+	}
+	
+	// Primitive Obsession
+	if len(report.Summary.PrimitiveObsession) > 0 {
+		m.writePrimitiveObsession(&md, report.Summary.PrimitiveObsession)
+	}
+	
 	// Detailed Violations
 	m.writeDetailedViolations(&md, report)
 	
@@ -123,6 +153,22 @@ func (m *MarkdownReporter) writeSummary(md *strings.Builder, summary *models.Sca
 		md.WriteString(fmt.Sprintf("| Scan Duration | %v |\n", summary.Duration.Round(time.Millisecond)))
 	}
 	
+	if triage := summary.Triage; triage != nil {
+		md.WriteString("\n### Triage\n\n")
+		md.WriteString("| State | Violations |\n")
+		md.WriteString("|-------|------------|\n")
+		md.WriteString(fmt.Sprintf("| New | %d |\n", triage.New))
+		md.WriteString(fmt.Sprintf("| In baseline | %d |\n", triage.Baseline))
+		md.WriteString(fmt.Sprintf("| Suppressed | %d |\n", triage.Suppressed))
+		md.WriteString(fmt.Sprintf("| Fixed since baseline | %d |\n", triage.Fixed))
+		if len(summary.Fixed) > 0 {
+			md.WriteString("\n**Fixed since the baseline:**\n\n")
+			for _, fixed := range summary.Fixed {
+				md.WriteString(fmt.Sprintf("- ✅ `%s:%d` %s (`%s`)\n", fixed.File, fixed.Line, fixed.Message, fixed.Rule))
+			}
+		}
+	}
+	
 	md.WriteString("\n")
 }
 
@@ -219,6 +265,154 @@ func (m *MarkdownReporter) writeTopViolatedFiles(md *strings.Builder, topFiles [
 	md.WriteString("\n")
 }
 
+// writeCloneGroups writes the duplicated code section
+func (m *MarkdownReporter) writeCloneGroups(md *strings.Builder, groups []*models.CloneGroup) {
+	md.WriteString("## Duplicated Code\n\n")
+	md.WriteString("Each group is a code block repeated at every listed location. Groups are ranked by the tokens extracting them would remove, weighted by the complexity of the duplicated code, so the riskiest copies come first:\n\n")
+	
+	md.WriteString("| Group | Tokens | Lines | Complexity | Copies | Granularity | Locations |\n")
+	md.WriteString("|-------|--------|-------|------------|--------|-------------|-----------|\n")
+	
+	displayCount := len(groups)
+	if displayCount > 10 {
+		displayCount = 10
+	}
+	
+	for _, group := range groups[:displayCount] {
+		locations := make([]string, 0, len(group.Members))
+		for _, member := range group.Members {
+			locations = append(locations, fmt.Sprintf("`%s:%d-%d`", member.File, member.StartLine, member.EndLine))
+		}
+		md.WriteString(fmt.Sprintf("| %d | %d | %d | %d | %d | %s | %s |\n",
+			group.ID, group.Tokens, group.Lines, group.Complexity, len(group.Members), group.Granularity, strings.Join(locations, "<br>")))
+	}
+	
+	if len(groups) > displayCount {
+		md.WriteString(fmt.Sprintf("\n*%d more clone groups not shown.*\n", len(groups)-displayCount))
+	}
+	
+	md.WriteString("\n")
+}
+
+// writeMaintainability writes the maintainability index section
+func (m *MarkdownReporter) writeMaintainability(md *strings.Builder, files []*models.FileMaintainability) {
+	mean, functions := models.MeanMaintainability(files)
+	md.WriteString("## Maintainability Index\n\n")
+	md.WriteString(fmt.Sprintf("**Overall index:** %.1f (mean of %d functions, 0-100, higher is easier to maintain)\n\n", mean, functions))
+	md.WriteString("Least maintainable files, with their least maintainable function:\n\n")
+	
+	md.WriteString("| File | Index | Functions | Lowest Function | Lowest Index |\n")
+	md.WriteString("|------|-------|-----------|-----------------|--------------|\n")
+	
+	sorted := models.LowestMaintainability(files)
+	displayCount := len(sorted)
+	if displayCount > 10 {
+		displayCount = 10
+	}
+	
+	for _, file := range sorted[:displayCount] {
+		if len(file.Functions) == 0 {
+			continue
+		}
+		lowest := file.Functions[0]
+		for _, function := range file.Functions[1:] {
+			if function.Index < lowest.Index {
+				lowest = function
+			}
+		}
+		md.WriteString(fmt.Sprintf("| `%s` | %.1f | %d | `%s` (line %d) | %.1f |\n",
+			file.File, file.Index, len(file.Functions), lowest.Name, lowest.Line, lowest.Index))
+	}
+	
+	if len(sorted) > displayCount {
+		md.WriteString(fmt.Sprintf("\n*%d more files not shown.*\n", len(sorted)-displayCount))
+	}
+	
+	md.WriteString("\n")
+}
+
+// writeTechnicalDebt writes the estimated time to fix the violations, with
+// the modules and files carrying the most debt
+func (m *MarkdownReporter) writeTechnicalDebt(md *strings.Builder, debt *models.TechnicalDebt) {
+	md.WriteString("## Technical Debt\n\n")
+	md.WriteString(fmt.Sprintf("**Estimated remediation time:** %s (%d minutes, 8-hour days)\n\n", debt.Total(), debt.Minutes))
+	m.writeDebtTable(md, "Module", debt.Modules)
+	m.writeDebtTable(md, "File", debt.Files)
+}
+
+// writeDebtTable writes the ten entries with the most debt
+func (m *MarkdownReporter) writeDebtTable(md *strings.Builder, label string, entries []models.DebtEntry) {
+	md.WriteString(fmt.Sprintf("| %s | Debt | Violations |\n", label))
+	md.WriteString(fmt.Sprintf("|%s|------|------------|\n", strings.Repeat("-", len(label)+2)))
+	
+	displayCount := min(len(entries), 10)
+	for _, entry := range entries[:displayCount] {
+		md.WriteString(fmt.Sprintf("| `%s` | %s | %d |\n", entry.Name, entry.Formatted(), entry.Violations))
+	}
+	if len(entries) > displayCount {
+		md.WriteString(fmt.Sprintf("\n*%d more not shown.*\n", len(entries)-displayCount))
+	}
+	md.WriteString("\n")
+}
+
+// writeFileHealth writes the ten least healthy files
+func (m *MarkdownReporter) writeFileHealth(md *strings.Builder, files []*models.FileHealth) {
+	md.WriteString("## File Health\n\n")
+	md.WriteString("Least healthy files (0-100, higher is healthier):\n\n")
+	md.WriteString("| File | Health | Rating | Violations |\n")
+	md.WriteString("|------|--------|--------|------------|\n")
+	
+	sorted := models.LowestHealth(files)
+	displayCount := min(len(sorted), 10)
+	for _, file := range sorted[:displayCount] {
+		md.WriteString(fmt.Sprintf("| `%s` | %d | %s | %d |\n", file.File, file.Score, models.HealthLabel(file.Score), file.Violations))
+	}
+	if len(sorted) > displayCount {
+		md.WriteString(fmt.Sprintf("\n*%d more files not shown.*\n", len(sorted)-displayCount))
+	}
+	md.WriteString("\n")
+}
+
+// writeDocCoverage writes the documentation coverage of public items, with
+// the ten least documented modules
+func (m *MarkdownReporter) writeDocCoverage(md *strings.Builder, coverage *models.DocCoverage) {
+	md.WriteString("## Documentation Coverage\n\n")
+	md.WriteString(fmt.Sprintf("**%.1f%%** of public items are documented (%d of %d)\n\n",
+		coverage.Percent, coverage.Documented, coverage.Public))
+	md.WriteString("| Module | Coverage | Documented | Public |\n")
+	md.WriteString("|--------|----------|------------|--------|\n")
+	
+	sorted := coverage.LowestModules()
+	displayCount := min(len(sorted), 10)
+	for _, module := range sorted[:displayCount] {
+		md.WriteString(fmt.Sprintf("| `%s` | %.1f%% | %d | %d |\n", module.Module, module.Percent, module.Documented, module.Public))
+	}
+	if len(sorted) > displayCount {
+		md.WriteString(fmt.Sprintf("\n*%d more modules not shown.*\n", len(sorted)-displayCount))
+	}
+	md.WriteString("\n")
+}
+
+// writePrimitiveObsession writes the ten primitive parameter and field names
+// recurring across the most functions and structs, with the type to introduce
+func (m *MarkdownReporter) writePrimitiveObsession(md *strings.Builder, clusters []*models.PrimitiveCluster) {
+	md.WriteString("## Primitive Obsession\n\n")
+	md.WriteString(fmt.Sprintf("%d parameter and field names recur with the same primitive type across functions and structs; each is a candidate for a type of its own\n\n",
+		len(clusters)))
+	md.WriteString("| Name | Type | Functions and Structs | Files | Suggested Type |\n")
+	md.WriteString("|------|------|-----------------------|-------|----------------|\n")
+	
+	displayCount := min(len(clusters), 10)
+	for _, cluster := range clusters[:displayCount] {
+		md.WriteString(fmt.Sprintf("| `%s` | `%s` | %d | %d | `%s` |\n",
+			cluster.Name, cluster.Type, cluster.Signatures(), cluster.Files(), cluster.Newtype))
+	}
+	if len(clusters) > displayCount {
+		md.WriteString(fmt.Sprintf("\n*%d more names not shown.*\n", len(clusters)-displayCount))
+	}
+	md.WriteString("\n")
+}
+
 // writeDetailedViolations writes detailed violations section
 func (m *MarkdownReporter) writeDetailedViolations(md *strings.Builder, report *models.Report) {
 	violationsByFile := report.GetViolationsByFile()
@@ -247,9 +441,14 @@ func (m *MarkdownReporter) writeDetailedViolations(md *strings.Builder, report *
 		return len(sortedFiles[i].Violations) > len(sortedFiles[j].Violations)
 	})
 	
+	healthByFile := report.HealthByFile()
 	for _, fv := range sortedFiles {
 		md.WriteString(fmt.Sprintf("#### 📁 %s\n\n", fv.File))
-		md.WriteString(fmt.Sprintf("**%d violations found**\n\n", len(fv.Violations)))
+		if health := healthByFile[fv.File]; health != nil {
+			md.WriteString(fmt.Sprintf("**%d violations found** · health %d/100\n\n", len(fv.Violations), health.Score))
+		} else {
+			md.WriteString(fmt.Sprintf("**%d violations found**\n\n", len(fv.Violations)))
+		}
 		
 		// Sort violations by line number
 		sort.Slice(fv.Violations, func(i, j int) bool {
@@ -272,8 +471,16 @@ func (m *MarkdownReporter) writeDetailedViolations(md *strings.Builder, report *
 			}
 			
 			for _, violation := range violations {
-				md.WriteString(fmt.Sprintf("- **Line %d** (%s): %s\n", 
-					violation.Line, violation.Severity.String(), violation.Message))
+				lineLabel := fmt.Sprintf("Line %d", violation.Line)
+				if violation.SourceURL != "" {
+					lineLabel = fmt.Sprintf("[%s](%s)", lineLabel, violation.SourceURL)
+				}
+				md.WriteString(fmt.Sprintf("- **%s** (%s): %s\n", 
+					lineLabel, violation.Severity.String(), violation.Message))
+				
+				if violation.DocURL != "" {
+					md.WriteString(fmt.Sprintf("  - 📖 Rule: [`%s`](%s)\n", violation.Rule, violation.DocURL))
+				}
 				
 				if violation.Description != "" {
 					md.WriteString(fmt.Sprintf("  - *%s*\n", violation.Description))
@@ -338,6 +545,11 @@ func (m *MarkdownReporter) writeRecommendations(md *strings.Builder, report *mod
 			"🌀 **Reduce cyclomatic complexity** - Simplify conditional logic and nested statements",
 			"🏗️ **Use early returns** - Eliminate deep nesting with guard clauses")
 			
+	case models.ViolationTypeCognitiveComplexity:
+		recommendations = append(recommendations,
+			"🧠 **Flatten nested control flow** - Deeply nested branches are the main driver of cognitive complexity",
+			"✂️ **Extract nested blocks** - Move inner loops and conditions into well-named helper functions")
+			
 	case models.ViolationTypeParameterCount:
 		recommendations = append(recommendations,
 			"📦 **Group related parameters** - Use structs or configuration objects",
@@ -386,6 +598,17 @@ func (m *MarkdownReporter) writeFooter(md *strings.Builder, report *models.Repor
 	
 	md.WriteString(fmt.Sprintf("**Report generated in:** %v  \n", report.Summary.Duration.Round(time.Millisecond)))
 	md.WriteString(fmt.Sprintf("**Timestamp:** %s  \n", report.GeneratedAt.Format(time.RFC3339)))
+	if manifest := report.Manifest; manifest != nil {
+		revision := "not a git checkout"
+		if manifest.GitSHA != "" {
+			revision = manifest.GitSHA
+			if manifest.GitDirty {
+				revision += " (uncommitted changes)"
+			}
+		}
+		md.WriteString(fmt.Sprintf("**Scan manifest:** goclean %s on %s, rule set `%s`, config `%s`, commit %s  \n",
+			manifest.ToolVersion, manifest.Platform, manifest.RuleSetHash, manifest.ConfigHash, revision))
+	}
 	
 	// Add some final motivational message based on results
 	if report.Summary.TotalViolations == 0 {
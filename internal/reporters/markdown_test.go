@@ -228,6 +228,34 @@ func TestMarkdownReporter_WriteSummary(t *testing.T) {
 			t.Error("Expected content to contain clean code message")
 		}
 	})
+
+	t.Run("with triage", func(t *testing.T) {
+		summary := &models.ScanSummary{
+			TotalViolations: 0,
+			Triage:          &models.TriageCounts{Baseline: 4, Suppressed: 2, Fixed: 1},
+			Fixed: []*models.Violation{{
+				File: "src/a.go", Line: 12, Rule: "function-length",
+				Message: "Function 'Load' is too long", Triage: models.TriageFixed,
+			}},
+		}
+
+		var md strings.Builder
+		reporter.writeSummary(&md, summary)
+
+		content := md.String()
+		for _, expected := range []string{
+			"### Triage",
+			"| New | 0 |",
+			"| In baseline | 4 |",
+			"| Suppressed | 2 |",
+			"| Fixed since baseline | 1 |",
+			"- ✅ `src/a.go:12` Function 'Load' is too long (`function-length`)",
+		} {
+			if !strings.Contains(content, expected) {
+				t.Errorf("Expected content to contain %q", expected)
+			}
+		}
+	})
 }
 
 func TestMarkdownReporter_WriteStatistics(t *testing.T) {
@@ -321,6 +349,165 @@ func TestMarkdownReporter_WriteTopViolatedFiles(t *testing.T) {
 	}
 }
 
+func TestMarkdownReporter_WriteCloneGroups(t *testing.T) {
+	config := &MarkdownConfig{
+		OutputPath:      "/tmp/test.md",
+		IncludeExamples: false,
+	}
+
+	reporter := NewMarkdownReporter(config)
+
+	groups := []*models.CloneGroup{
+		{
+			ID:               1,
+			Tokens:           120,
+			Lines:            14,
+			DuplicatedTokens: 240,
+			Complexity:       4,
+			Weight:           960,
+			Granularity:      models.CloneCrossModule,
+			Members: []models.CloneLocation{
+				{File: "a/users.go", StartLine: 8, EndLine: 21},
+				{File: "b/staff.go", StartLine: 7, EndLine: 19},
+				{File: "c/admins.go", StartLine: 8, EndLine: 21},
+			},
+		},
+	}
+
+	var md strings.Builder
+	reporter.writeCloneGroups(&md, groups)
+
+	content := md.String()
+	expectedStrings := []string{
+		"## Duplicated Code",
+		"| Group | Tokens | Lines | Complexity | Copies | Granularity | Locations |",
+		"| 1 | 120 | 14 | 4 | 3 | cross-module |",
+		"`a/users.go:8-21`<br>`b/staff.go:7-19`<br>`c/admins.go:8-21`",
+	}
+
+	for _, expected := range expectedStrings {
+		if !strings.Contains(content, expected) {
+			t.Errorf("Expected content to contain %q", expected)
+		}
+	}
+}
+
+func TestMarkdownReporter_WriteMaintainability(t *testing.T) {
+	reporter := NewMarkdownReporter(&MarkdownConfig{OutputPath: "/tmp/test.md"})
+
+	files := []*models.FileMaintainability{
+		{File: "good.go", Index: 80, Functions: []models.FunctionMaintainability{{Name: "small", Line: 3, Index: 80}}},
+		{File: "bad.go", Index: 30, Functions: []models.FunctionMaintainability{
+			{Name: "fine", Line: 3, Index: 50},
+			{Name: "tangled", Line: 20, Index: 10},
+		}},
+	}
+
+	var md strings.Builder
+	reporter.writeMaintainability(&md, files)
+
+	content := md.String()
+	expectedStrings := []string{
+		"## Maintainability Index",
+		"**Overall index:** 46.7 (mean of 3 functions",
+		"| `bad.go` | 30.0 | 2 | `tangled` (line 20) | 10.0 |",
+	}
+	for _, expected := range expectedStrings {
+		if !strings.Contains(content, expected) {
+			t.Errorf("Expected content to contain %q", expected)
+		}
+	}
+	if strings.Index(content, "bad.go") > strings.Index(content, "good.go") {
+		t.Error("Expected the least maintainable file first")
+	}
+}
+
+func TestMarkdownReporter_WriteTechnicalDebt(t *testing.T) {
+	reporter := NewMarkdownReporter(&MarkdownConfig{OutputPath: "/tmp/test.md"})
+
+	debt := models.ComputeTechnicalDebt([]*models.Violation{
+		{File: "billing/charge.go", Remediation: 480},
+		{File: "billing/refund.go", Remediation: 45},
+		{File: "api/handler.go", Remediation: 30},
+	})
+
+	var md strings.Builder
+	reporter.writeTechnicalDebt(&md, debt)
+
+	content := md.String()
+	expectedStrings := []string{
+		"## Technical Debt",
+		"**Estimated remediation time:** 1d 1h 15m (555 minutes, 8-hour days)",
+		"| `billing` | 1d 45m | 2 |",
+		"| `billing/charge.go` | 1d | 1 |",
+		"| `api/handler.go` | 30m | 1 |",
+	}
+	for _, expected := range expectedStrings {
+		if !strings.Contains(content, expected) {
+			t.Errorf("Expected content to contain %q", expected)
+		}
+	}
+}
+
+func TestMarkdownReporter_WriteFileHealth(t *testing.T) {
+	reporter := NewMarkdownReporter(&MarkdownConfig{OutputPath: "/tmp/test.md"})
+
+	var md strings.Builder
+	reporter.writeFileHealth(&md, []*models.FileHealth{
+		{File: "good.go", Score: 95, Violations: 1},
+		{File: "bad.go", Score: 35, Violations: 12},
+	})
+
+	content := md.String()
+	expectedStrings := []string{
+		"## File Health",
+		"| `bad.go` | 35 | poor | 12 |",
+		"| `good.go` | 95 | healthy | 1 |",
+	}
+	for _, expected := range expectedStrings {
+		if !strings.Contains(content, expected) {
+			t.Errorf("Expected content to contain %q", expected)
+		}
+	}
+	if strings.Index(content, "bad.go") > strings.Index(content, "good.go") {
+		t.Error("Expected the least healthy file first")
+	}
+
+	report := createMarkdownTestReport()
+	report.Summary.Health = []*models.FileHealth{{File: "/test/file.go", Score: 61, Violations: 2}}
+	md.Reset()
+	reporter.writeDetailedViolations(&md, report)
+	if !strings.Contains(md.String(), "**2 violations found** · health 61/100") {
+		t.Errorf("Expected the file's health in its breakdown, got:\n%s", md.String())
+	}
+}
+
+func TestMarkdownReporter_WriteDocCoverage(t *testing.T) {
+	reporter := NewMarkdownReporter(&MarkdownConfig{OutputPath: "/tmp/test.md"})
+
+	var md strings.Builder
+	reporter.writeDocCoverage(&md, models.ComputeDocCoverage([]models.FileDocCoverage{
+		{File: "src/lib.rs", Documented: 4, Public: 4},
+		{File: "src/net/http.rs", Documented: 1, Public: 4},
+	}))
+
+	content := md.String()
+	expectedStrings := []string{
+		"## Documentation Coverage",
+		"**62.5%** of public items are documented (5 of 8)",
+		"| `src/net` | 25.0% | 1 | 4 |",
+		"| `src` | 100.0% | 4 | 4 |",
+	}
+	for _, expected := range expectedStrings {
+		if !strings.Contains(content, expected) {
+			t.Errorf("Expected content to contain %q", expected)
+		}
+	}
+	if strings.Index(content, "`src/net`") > strings.Index(content, "`src`") {
+		t.Error("Expected the least documented module first")
+	}
+}
+
 func TestMarkdownReporter_WriteDetailedViolations(t *testing.T) {
 	config := &MarkdownConfig{
 		OutputPath:      "/tmp/test.md",
@@ -516,6 +703,30 @@ func TestMarkdownReporter_WriteFooter(t *testing.T) {
 			t.Error("Expected content to contain improvement message")
 		}
 	})
+
+	t.Run("with manifest", func(t *testing.T) {
+		report := &models.Report{
+			GeneratedAt: time.Now(),
+			Summary:     &models.ScanSummary{Duration: time.Second},
+			Manifest: &models.ScanManifest{
+				ToolVersion: "1.2.0",
+				RuleSetHash: "0123456789abcdef",
+				ConfigHash:  "fedcba9876543210",
+				GitSHA:      "abc123",
+				GitDirty:    true,
+				Platform:    "linux/amd64",
+			},
+		}
+
+		var md strings.Builder
+		reporter.writeFooter(&md, report)
+
+		content := md.String()
+		expected := "**Scan manifest:** goclean 1.2.0 on linux/amd64, rule set `0123456789abcdef`, config `fedcba9876543210`, commit abc123 (uncommitted changes)"
+		if !strings.Contains(content, expected) {
+			t.Errorf("Expected the manifest line, got:\n%s", content)
+		}
+	})
 }
 
 func TestMarkdownReporter_GetSeverityStatus(t *testing.T) {
@@ -0,0 +1,229 @@
+package reporters
+
+import (
+	"encoding/json"
+	"fmt"
+	"os"
+	"path/filepath"
+	"sort"
+	"time"
+
+	"github.com/ericfisherdev/goclean/internal/config"
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+// Cargo target kinds used to group panic sites
+const (
+	PanicTargetBinary  = "bin"
+	PanicTargetLibrary = "lib"
+)
+
+// PanicCensus inventories potential panic sites per Cargo target. Test code,
+// examples, benches and build scripts are excluded, so the panic surface
+// reflects what can fail in production.
+type PanicCensus struct {
+	GeneratedAt time.Time          `json:"generated_at"`
+	Summary     PanicCensusSummary `json:"summary"`
+	Targets     []*PanicTarget     `json:"targets"`
+}
+
+// PanicCensusSummary aggregates the census over all targets
+type PanicCensusSummary struct {
+	PanicSurface       int            `json:"panic_surface"`        // Sites in all production targets
+	BinaryPanicSurface int            `json:"binary_panic_surface"` // Sites in binary targets only
+	ByKind             map[string]int `json:"by_kind"`
+	Targets            int            `json:"targets"`
+	BinaryTargets      int            `json:"binary_targets"`
+	ExcludedTestSites  int            `json:"excluded_test_sites"`
+}
+
+// PanicTarget lists the panic sites of one binary or library target
+type PanicTarget struct {
+	Name           string           `json:"name"`
+	Kind           string           `json:"kind"`
+	Crate          string           `json:"crate"`
+	Manifest       string           `json:"manifest,omitempty"`
+	PanicSurface   int              `json:"panic_surface"`
+	ByKind         map[string]int   `json:"by_kind"`
+	FilesWithSites int              `json:"files_with_sites"`
+	Sites          []PanicSiteEntry `json:"sites"`
+}
+
+// PanicSiteEntry is one potential panic site
+type PanicSiteEntry struct {
+	Kind     string `json:"kind"`
+	Name     string `json:"name"`
+	File     string `json:"file"`
+	Line     int    `json:"line"`
+	Column   int    `json:"column"`
+	Function string `json:"function"`
+}
+
+// BuildPanicCensus groups the panic sites of the scanned Rust files by target
+func BuildPanicCensus(files []*models.ScanResult) *PanicCensus {
+	census := &PanicCensus{
+		GeneratedAt: time.Now(),
+		Summary:     PanicCensusSummary{ByKind: make(map[string]int)},
+		Targets:     make([]*PanicTarget, 0),
+	}
+
+	targets := make(map[string]*PanicTarget)
+
+	for _, file := range files {
+		if file == nil || file.File == nil {
+			continue
+		}
+		astInfo, ok := file.RustASTInfo.(*types.RustASTInfo)
+		if !ok || astInfo == nil || len(astInfo.PanicSites) == 0 {
+			continue
+		}
+
+		found, key, ok := panicCensusTarget(file.File.Path, astInfo)
+		if !ok {
+			continue
+		}
+
+		target := targets[key]
+		if target == nil {
+			target = found
+			target.ByKind = make(map[string]int)
+			target.Sites = make([]PanicSiteEntry, 0)
+			targets[key] = target
+		}
+
+		counted := false
+		for _, site := range astInfo.PanicSites {
+			if site.InTest {
+				census.Summary.ExcludedTestSites++
+				continue
+			}
+			target.Sites = append(target.Sites, PanicSiteEntry{
+				Kind:     site.Kind,
+				Name:     site.Name,
+				File:     file.File.Path,
+				Line:     site.Line,
+				Column:   site.Column,
+				Function: site.Function,
+			})
+			target.ByKind[site.Kind]++
+			target.PanicSurface++
+			if !counted {
+				target.FilesWithSites++
+				counted = true
+			}
+		}
+	}
+
+	for _, target := range targets {
+		sort.SliceStable(target.Sites, func(i, j int) bool {
+			a, b := target.Sites[i], target.Sites[j]
+			if a.File != b.File {
+				return a.File < b.File
+			}
+			if a.Line != b.Line {
+				return a.Line < b.Line
+			}
+			return a.Column < b.Column
+		})
+		census.Targets = append(census.Targets, target)
+
+		census.Summary.Targets++
+		census.Summary.PanicSurface += target.PanicSurface
+		if target.Kind == PanicTargetBinary {
+			census.Summary.BinaryTargets++
+			census.Summary.BinaryPanicSurface += target.PanicSurface
+		}
+		for kind, count := range target.ByKind {
+			census.Summary.ByKind[kind] += count
+		}
+	}
+
+	// Binaries first, then by crate and name
+	sort.Slice(census.Targets, func(i, j int) bool {
+		a, b := census.Targets[i], census.Targets[j]
+		if a.Kind != b.Kind {
+			return a.Kind == PanicTargetBinary
+		}
+		if a.Crate != b.Crate {
+			return a.Crate < b.Crate
+		}
+		if a.Name != b.Name {
+			return a.Name < b.Name
+		}
+		return a.Manifest < b.Manifest
+	})
+
+	return census
+}
+
+// panicCensusTarget returns the production target of a scanned file as the
+// scanner resolved it from the package manifest, and the key grouping its
+// files. Tests, benches, examples and build scripts report ok = false. Files
+// outside any Cargo package are grouped by directory into a library.
+func panicCensusTarget(path string, info *types.RustASTInfo) (target *PanicTarget, key string, ok bool) {
+	if info.Package == nil {
+		dir := filepath.Dir(path)
+		target = &PanicTarget{Name: filepath.Base(dir), Kind: PanicTargetLibrary, Crate: filepath.Base(dir)}
+		return target, PanicTargetLibrary + ":" + dir, true
+	}
+
+	target = &PanicTarget{Name: info.TargetName, Crate: info.Package.Name, Manifest: info.Package.Manifest}
+	switch info.Target {
+	case types.RustTargetBinary:
+		target.Kind = PanicTargetBinary
+	case types.RustTargetLibrary:
+		target.Kind = PanicTargetLibrary
+	default:
+		return nil, "", false
+	}
+	return target, target.Kind + ":" + target.Manifest + ":" + target.Name, true
+}
+
+// PanicCensusReporter writes the panic census as JSON
+type PanicCensusReporter struct {
+	config *config.PanicCensusConfig
+}
+
+// NewPanicCensusReporter creates a new panic census reporter
+func NewPanicCensusReporter(cfg *config.PanicCensusConfig) *PanicCensusReporter {
+	if cfg == nil {
+		cfg = &config.PanicCensusConfig{
+			Enabled:     true,
+			Path:        "./reports/panic-census.json",
+			PrettyPrint: true,
+		}
+	}
+	return &PanicCensusReporter{config: cfg}
+}
+
+// Generate writes the panic census for the report's files
+func (r *PanicCensusReporter) Generate(report *models.Report) error {
+	if !r.config.Enabled {
+		return nil
+	}
+
+	dir := filepath.Dir(r.config.Path)
+	if err := os.MkdirAll(dir, 0755); err != nil {
+		return fmt.Errorf("failed to create report directory: %w", err)
+	}
+
+	census := BuildPanicCensus(report.Files)
+
+	var data []byte
+	var err error
+	if r.config.PrettyPrint {
+		data, err = json.MarshalIndent(census, "", "  ")
+	} else {
+		data, err = json.Marshal(census)
+	}
+	if err != nil {
+		return fmt.Errorf("failed to marshal panic census: %w", err)
+	}
+
+	if err := os.WriteFile(r.config.Path, data, 0644); err != nil {
+		return fmt.Errorf("failed to write panic census: %w", err)
+	}
+
+	return nil
+}
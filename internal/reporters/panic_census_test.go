@@ -0,0 +1,117 @@
+package reporters
+
+import (
+	"encoding/json"
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/ericfisherdev/goclean/internal/config"
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/scanner"
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+// panicCensusCrate writes a workspace member with a library, three binaries,
+// a build script and an integration test
+func panicCensusCrate(t *testing.T) []*models.ScanResult {
+	t.Helper()
+	root := t.TempDir()
+
+	files := map[string]string{
+		"Cargo.toml": "[workspace]\nmembers = [\"svc\"]\n",
+		"svc/Cargo.toml": `[package]
+name = "svc"
+
+[[bin]]
+name = "admin"
+path = "tools/admin.rs"
+`,
+		"svc/src/lib.rs":        "pub fn parse(s: &str) -> u32 { s.parse().unwrap() }\n",
+		"svc/src/main.rs":       "fn main() { let v = vec![1]; println!(\"{}\", v[0]); }\n",
+		"svc/src/bin/worker.rs": "fn main() { let n = std::env::args().count(); std::process::exit((n - 1) as i32); }\n",
+		"svc/tools/admin.rs":    "fn main() { todo!() }\n",
+		"svc/tests/it.rs":       "fn helper() { None::<u8>.unwrap(); }\n",
+		"svc/build.rs":          "fn main() { std::env::var(\"OUT_DIR\").unwrap(); }\n",
+	}
+
+	resolver := scanner.NewRustTargetResolver()
+	var results []*models.ScanResult
+	for rel, content := range files {
+		path := filepath.Join(root, filepath.FromSlash(rel))
+		if err := os.MkdirAll(filepath.Dir(path), 0755); err != nil {
+			t.Fatalf("Failed to create directory: %v", err)
+		}
+		if err := os.WriteFile(path, []byte(content), 0644); err != nil {
+			t.Fatalf("Failed to write %s: %v", rel, err)
+		}
+		if filepath.Ext(rel) != ".rs" {
+			continue
+		}
+		astInfo := &types.RustASTInfo{}
+		scanner.NewRustSyntaxParser([]byte(content)).Parse(astInfo)
+		astInfo.Target, astInfo.TargetName, astInfo.Package = resolver.Resolve(path)
+		results = append(results, &models.ScanResult{
+			File:        &models.FileInfo{Path: path, Language: "Rust"},
+			RustASTInfo: astInfo,
+		})
+	}
+	return results
+}
+
+func TestBuildPanicCensus(t *testing.T) {
+	census := BuildPanicCensus(panicCensusCrate(t))
+
+	expected := []struct {
+		name    string
+		kind    string
+		surface int
+	}{
+		{"admin", PanicTargetBinary, 1},
+		{"svc", PanicTargetBinary, 1},
+		{"worker", PanicTargetBinary, 1},
+		{"svc", PanicTargetLibrary, 1},
+	}
+	if len(census.Targets) != len(expected) {
+		t.Fatalf("Expected %d targets, got %d: %+v", len(expected), len(census.Targets), census.Targets)
+	}
+	for i, want := range expected {
+		target := census.Targets[i]
+		if target.Name != want.name || target.Kind != want.kind || target.PanicSurface != want.surface {
+			t.Errorf("Target %d: expected %s %s with %d sites, got %s %s with %d",
+				i, want.kind, want.name, want.surface, target.Kind, target.Name, target.PanicSurface)
+		}
+		if target.Crate != "svc" || filepath.Base(filepath.Dir(target.Manifest)) != "svc" {
+			t.Errorf("Target %d: expected crate 'svc' from svc/Cargo.toml, got %q from %q", i, target.Crate, target.Manifest)
+		}
+	}
+
+	summary := census.Summary
+	if summary.PanicSurface != 4 || summary.BinaryPanicSurface != 3 {
+		t.Errorf("Expected panic surface 4 (3 in binaries), got %d (%d)", summary.PanicSurface, summary.BinaryPanicSurface)
+	}
+	if summary.ByKind[types.RustPanicIndex] != 1 || summary.ByKind[types.RustPanicArithmetic] != 1 {
+		t.Errorf("Unexpected kind breakdown %v", summary.ByKind)
+	}
+}
+
+func TestPanicCensusReporter_Generate(t *testing.T) {
+	outputPath := filepath.Join(t.TempDir(), "census", "panic-census.json")
+	reporter := NewPanicCensusReporter(&config.PanicCensusConfig{Enabled: true, Path: outputPath})
+
+	if err := reporter.Generate(&models.Report{Files: panicCensusCrate(t)}); err != nil {
+		t.Fatalf("Failed to generate panic census: %v", err)
+	}
+
+	data, err := os.ReadFile(outputPath)
+	if err != nil {
+		t.Fatalf("Failed to read panic census: %v", err)
+	}
+	var census PanicCensus
+	if err := json.Unmarshal(data, &census); err != nil {
+		t.Fatalf("Invalid panic census JSON: %v", err)
+	}
+	if census.Summary.PanicSurface != 4 {
+		t.Errorf("Expected panic surface 4, got %d", census.Summary.PanicSurface)
+	}
+}
@@ -0,0 +1,421 @@
+package reporters
+
+import (
+	"bufio"
+	"fmt"
+	"os"
+	"path/filepath"
+	"regexp"
+	"sort"
+	"strings"
+	"time"
+
+	"github.com/ericfisherdev/goclean/internal/codeowners"
+	"github.com/ericfisherdev/goclean/internal/config"
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/scanner"
+)
+
+// Parts of files no owner, top-level directory or crate claims
+const (
+	unownedPart  = "unowned"
+	topLevelPart = "top-level"
+	noCratePart  = "no-crate"
+)
+
+// slugPattern matches the characters replaced in the directory names of parts
+var slugPattern = regexp.MustCompile(`[^a-z0-9._-]+`)
+
+// ReportPart is the share of a monorepo scan that belongs to one team,
+// top-level directory or crate
+type ReportPart struct {
+	Name    string // Owner, directory or crate name
+	Slug    string // Directory name of the part's reports
+	Summary *models.ScanSummary
+	Results []*models.ScanResult
+}
+
+// SplitResults divides the results of a scan into one part per owner,
+// top-level directory of the scan paths or crate, ordered by name. Files
+// with several owners are in the part of each.
+func SplitResults(splitBy string, scanPaths []string, summary *models.ScanSummary, results []*models.ScanResult) ([]*ReportPart, error) {
+	partsOf, err := newPartResolver(splitBy, scanPaths)
+	if err != nil {
+		return nil, err
+	}
+
+	byName := make(map[string]*ReportPart)
+	var names []string
+	for _, result := range results {
+		if result == nil || result.File == nil {
+			continue
+		}
+		for _, name := range partsOf(result.File.Path) {
+			part := byName[name]
+			if part == nil {
+				part = &ReportPart{Name: name}
+				byName[name] = part
+				names = append(names, name)
+			}
+			part.Results = append(part.Results, result)
+		}
+	}
+	sort.Strings(names)
+
+	parts := make([]*ReportPart, 0, len(names))
+	slugs := make(map[string]int)
+	for _, name := range names {
+		part := byName[name]
+		part.Slug = partSlug(name, slugs)
+		part.Summary = partSummary(summary, part.Results, partsOf, name)
+		parts = append(parts, part)
+	}
+	return parts, nil
+}
+
+// newPartResolver returns the function naming the parts of a file
+func newPartResolver(splitBy string, scanPaths []string) (func(path string) []string, error) {
+	switch splitBy {
+	case config.SplitByOwner:
+		dir := "."
+		if len(scanPaths) > 0 {
+			dir = scanPaths[0]
+		}
+		owners, err := codeowners.Find(dir)
+		if err != nil {
+			return nil, fmt.Errorf("splitting reports by owner: %w", err)
+		}
+		return func(path string) []string {
+			if found := owners.Owners(path); len(found) > 0 {
+				return found
+			}
+			return []string{unownedPart}
+		}, nil
+	case config.SplitByRoot:
+		return func(path string) []string {
+			return []string{topLevelDir(scanPaths, path)}
+		}, nil
+	case config.SplitByCrate:
+		crates := make(map[string]string)
+		packages := scanner.NewRustTargetResolver()
+		return func(path string) []string {
+			return []string{crateName(path, crates, packages)}
+		}, nil
+	default:
+		return nil, config.ValidateSplitBy(splitBy)
+	}
+}
+
+// topLevelDir returns the first directory of a path below the scan path
+// containing it, or topLevelPart for files directly in a scan path
+func topLevelDir(scanPaths []string, path string) string {
+	absolute, err := filepath.Abs(path)
+	if err != nil {
+		return topLevelPart
+	}
+
+	best := ""
+	for _, scanPath := range scanPaths {
+		root, err := filepath.Abs(scanPath)
+		if err != nil {
+			continue
+		}
+		relative, err := filepath.Rel(root, absolute)
+		if err != nil || relative == ".." || strings.HasPrefix(relative, ".."+string(filepath.Separator)) {
+			continue
+		}
+		// The deepest scan path containing the file decides
+		if len(root) > len(best) {
+			best = root
+		}
+	}
+	if best == "" {
+		best = "."
+	}
+
+	relative, err := filepath.Rel(best, absolute)
+	if err != nil {
+		return topLevelPart
+	}
+	first, _, found := strings.Cut(filepath.ToSlash(relative), "/")
+	if !found {
+		return topLevelPart
+	}
+	return first
+}
+
+// crateName returns the package name of the nearest Cargo package or the
+// module path of the nearest go.mod above a file, caching the answer per
+// directory. Workspace manifests without a [package] section are skipped.
+func crateName(path string, cache map[string]string, packages *scanner.RustTargetResolver) string {
+	start, err := filepath.Abs(filepath.Dir(path))
+	if err != nil {
+		return noCratePart
+	}
+
+	var visited []string
+	name := noCratePart
+	for dir := start; ; {
+		if cached, found := cache[dir]; found {
+			name = cached
+			break
+		}
+		visited = append(visited, dir)
+
+		if manifestPath := filepath.Join(dir, "Cargo.toml"); fileExists(manifestPath) {
+			if _, _, pkg := packages.Resolve(manifestPath); pkg != nil && pkg.Manifest == manifestPath {
+				name = pkg.Name
+				break
+			}
+		}
+		if modulePath := readModulePath(filepath.Join(dir, "go.mod")); modulePath != "" {
+			name = modulePath
+			break
+		}
+
+		parent := filepath.Dir(dir)
+		if parent == dir {
+			break
+		}
+		dir = parent
+	}
+
+	for _, dir := range visited {
+		cache[dir] = name
+	}
+	return name
+}
+
+// fileExists reports whether a regular file exists at path
+func fileExists(path string) bool {
+	info, err := os.Stat(path)
+	return err == nil && !info.IsDir()
+}
+
+// readModulePath returns the module directive of a go.mod file, or "" when it cannot be read
+func readModulePath(path string) string {
+	file, err := os.Open(path)
+	if err != nil {
+		return ""
+	}
+	defer file.Close()
+
+	scanner := bufio.NewScanner(file)
+	for scanner.Scan() {
+		if modulePath, ok := strings.CutPrefix(strings.TrimSpace(scanner.Text()), "module "); ok {
+			return strings.Trim(strings.TrimSpace(modulePath), `"`)
+		}
+	}
+	return ""
+}
+
+// partSlug turns a part name into a unique directory name, so "@acme/web"
+// becomes "acme-web"
+func partSlug(name string, used map[string]int) string {
+	slug := strings.Trim(slugPattern.ReplaceAllString(strings.ToLower(name), "-"), "-.")
+	if slug == "" {
+		slug = "part"
+	}
+	used[slug]++
+	if n := used[slug]; n > 1 {
+		slug = fmt.Sprintf("%s-%d", slug, n)
+	}
+	return slug
+}
+
+// partSummary recounts the summary of a scan for the files of one part.
+// Clone groups with a member in the part, and the maintainability and fixed
+// violations of its files are kept; counts that cannot be attributed to files are left out.
+func partSummary(summary *models.ScanSummary, results []*models.ScanResult, partsOf func(string) []string, name string) *models.ScanSummary {
+	part := &models.ScanSummary{
+		TotalFiles:       len(results),
+		ViolationsByType: make(map[string]int),
+		StartTime:        summary.StartTime,
+		EndTime:          summary.EndTime,
+		Duration:         summary.Duration,
+	}
+
+	files := make(map[string]bool, len(results))
+	for _, result := range results {
+		files[result.File.Path] = true
+		if result.File.Scanned {
+			part.ScannedFiles++
+		}
+		for _, violation := range result.Violations {
+			part.TotalViolations++
+			part.ViolationsByType[string(violation.Type)]++
+		}
+		part.BaselineSuppressed += len(result.Baselined)
+		part.InlineSuppressed += len(result.Suppressed)
+	}
+	part.SkippedFiles = part.TotalFiles - part.ScannedFiles
+
+	for _, group := range summary.CloneGroups {
+		for _, member := range group.Members {
+			if files[member.File] {
+				part.CloneGroups = append(part.CloneGroups, group)
+				break
+			}
+		}
+	}
+	for _, file := range summary.Maintainability {
+		if files[file.File] {
+			part.Maintainability = append(part.Maintainability, file)
+		}
+	}
+	for _, file := range summary.Health {
+		if files[file.File] {
+			part.Health = append(part.Health, file)
+		}
+	}
+	if summary.DocCoverage != nil {
+		var documented []models.FileDocCoverage
+		for _, file := range summary.DocCoverage.Files {
+			if files[file.File] {
+				documented = append(documented, file)
+			}
+		}
+		part.DocCoverage = models.ComputeDocCoverage(documented)
+	}
+	for _, cluster := range summary.PrimitiveObsession {
+		for _, use := range cluster.Uses {
+			if files[use.File] {
+				part.PrimitiveObsession = append(part.PrimitiveObsession, cluster)
+				break
+			}
+		}
+	}
+	for _, fixed := range summary.Fixed {
+		for _, owner := range partsOf(fixed.File) {
+			if owner == name {
+				part.Fixed = append(part.Fixed, fixed)
+				break
+			}
+		}
+	}
+
+	part.CountTriage(results)
+	part.CountDebt(results)
+	return part
+}
+
+// GenerateSplitReports writes the configured reports once per part, each
+// in a directory named after the part next to the configured path, and an
+// HTML index of the parts at the configured HTML path
+func (m *Manager) GenerateSplitReports(parts []*ReportPart) error {
+	for _, part := range parts {
+		manager, err := NewManager(splitConfig(m.config, part.Slug))
+		if err != nil {
+			return err
+		}
+		manager.SetManifest(m.manifest)
+		if err := manager.GenerateReports(part.Summary, part.Results); err != nil {
+			return fmt.Errorf("failed to generate the reports of %s: %w", part.Name, err)
+		}
+	}
+
+	if m.config.Output.HTML.Path == "" {
+		return nil
+	}
+	return m.writeSplitIndex(parts)
+}
+
+// splitConfig returns a copy of a configuration writing its reports to a
+// directory named slug next to each configured path
+func splitConfig(cfg *config.Config, slug string) *config.Config {
+	partConfig := *cfg
+	for _, path := range []*string{
+		&partConfig.Output.HTML.Path,
+		&partConfig.Output.Markdown.Path,
+		&partConfig.Export.JSON.Path,
+		&partConfig.Export.UnsafeInventory.Path,
+		&partConfig.Export.PanicCensus.Path,
+		&partConfig.Export.JUnit.Path,
+		&partConfig.Export.CodeClimate.Path,
+		&partConfig.Export.GitHubSummary.Path,
+	} {
+		if *path != "" {
+			*path = splitPath(*path, slug)
+		}
+	}
+	return &partConfig
+}
+
+// splitPath returns the path of a report in the directory of a part
+func splitPath(path, slug string) string {
+	return filepath.Join(filepath.Dir(path), slug, filepath.Base(path))
+}
+
+// splitIndexEntry is a row of the HTML index of split reports
+type splitIndexEntry struct {
+	Name       string
+	Link       string // Report of the part, relative to the index
+	Files      int
+	Violations int
+	Critical   int
+	High       int
+	Medium     int
+	Low        int
+	Info       int
+}
+
+// writeSplitIndex writes the HTML page linking to the report of each part
+func (m *Manager) writeSplitIndex(parts []*ReportPart) error {
+	htmlReporter, err := NewHTMLReporter(&HTMLConfig{OutputPath: m.config.Output.HTML.Path, Theme: m.config.Output.HTML.Theme})
+	if err != nil {
+		return fmt.Errorf("failed to create HTML reporter: %w", err)
+	}
+
+	entries := make([]splitIndexEntry, 0, len(parts))
+	for _, part := range parts {
+		entry := splitIndexEntry{
+			Name:       part.Name,
+			Link:       filepath.ToSlash(filepath.Join(part.Slug, filepath.Base(m.config.Output.HTML.Path))),
+			Files:      part.Summary.TotalFiles,
+			Violations: part.Summary.TotalViolations,
+		}
+		for _, result := range part.Results {
+			for _, violation := range result.Violations {
+				switch violation.Severity {
+				case models.SeverityCritical:
+					entry.Critical++
+				case models.SeverityHigh:
+					entry.High++
+				case models.SeverityMedium:
+					entry.Medium++
+				case models.SeverityLow:
+					entry.Low++
+				default:
+					entry.Info++
+				}
+			}
+		}
+		entries = append(entries, entry)
+	}
+
+	outputPath := m.config.Output.HTML.Path
+	if err := os.MkdirAll(filepath.Dir(outputPath), DirPermissions); err != nil {
+		return fmt.Errorf("failed to create output directory: %w", err)
+	}
+	file, err := os.Create(outputPath)
+	if err != nil {
+		return fmt.Errorf("failed to create output file: %w", err)
+	}
+	defer file.Close()
+
+	data := struct {
+		SplitBy     string
+		Theme       string
+		GeneratedAt string
+		Parts       []splitIndexEntry
+	}{
+		SplitBy:     m.config.Output.SplitBy,
+		Theme:       m.config.Output.HTML.Theme,
+		GeneratedAt: time.Now().Format("2006-01-02 15:04:05"),
+		Parts:       entries,
+	}
+	if err := htmlReporter.template.ExecuteTemplate(file, "split-index.html", data); err != nil {
+		return fmt.Errorf("failed to execute template: %w", err)
+	}
+	return nil
+}
@@ -0,0 +1,208 @@
+package reporters
+
+import (
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+
+	"github.com/ericfisherdev/goclean/internal/config"
+	"github.com/ericfisherdev/goclean/internal/models"
+)
+
+// splitFixture writes files to a temporary monorepo and returns their scan results
+func splitFixture(t *testing.T, files map[string]string, violations map[string]int) (string, []*models.ScanResult) {
+	t.Helper()
+	root := t.TempDir()
+	var results []*models.ScanResult
+	for name, content := range files {
+		path := filepath.Join(root, filepath.FromSlash(name))
+		if err := os.MkdirAll(filepath.Dir(path), 0755); err != nil {
+			t.Fatal(err)
+		}
+		if err := os.WriteFile(path, []byte(content), 0644); err != nil {
+			t.Fatal(err)
+		}
+		if !strings.HasSuffix(name, ".go") && !strings.HasSuffix(name, ".rs") {
+			continue
+		}
+
+		result := &models.ScanResult{File: &models.FileInfo{Path: path, Scanned: true}}
+		for i := 0; i < violations[name]; i++ {
+			result.Violations = append(result.Violations, &models.Violation{
+				Type:     models.ViolationTypeFunctionLength,
+				Severity: models.SeverityHigh,
+				File:     path,
+				Line:     i + 1,
+			})
+		}
+		results = append(results, result)
+	}
+	return root, results
+}
+
+// partNames returns the names of parts with their violation counts
+func partNames(parts []*ReportPart) map[string]int {
+	names := make(map[string]int)
+	for _, part := range parts {
+		names[part.Name] = part.Summary.TotalViolations
+	}
+	return names
+}
+
+func TestSplitResultsByRoot(t *testing.T) {
+	root, results := splitFixture(t, map[string]string{
+		"services/api/main.go": "package main",
+		"services/web/web.go":  "package web",
+		"tools/gen.go":         "package tools",
+		"main.go":              "package main",
+	}, map[string]int{"services/api/main.go": 2, "services/web/web.go": 1, "main.go": 1})
+
+	parts, err := SplitResults(config.SplitByRoot, []string{root}, &models.ScanSummary{}, results)
+	if err != nil {
+		t.Fatalf("SplitResults failed: %v", err)
+	}
+	if len(parts) != 3 || parts[0].Name != "services" || parts[1].Name != "tools" || parts[2].Name != topLevelPart {
+		t.Fatalf("Expected services, tools and top-level in order, got %v", partNames(parts))
+	}
+	if parts[0].Summary.TotalFiles != 2 || parts[0].Summary.TotalViolations != 3 || parts[0].Summary.Triage.New != 3 {
+		t.Errorf("Unexpected services summary %+v", parts[0].Summary)
+	}
+	if parts[1].Summary.TotalViolations != 0 || parts[1].Summary.ScannedFiles != 1 {
+		t.Errorf("Expected tools to have one clean file, got %+v", parts[1].Summary)
+	}
+}
+
+func TestSplitResultsKeepsFileHealth(t *testing.T) {
+	root, results := splitFixture(t, map[string]string{
+		"api/main.go": "package main",
+		"web/web.go":  "package web",
+	}, map[string]int{"api/main.go": 1})
+	summary := &models.ScanSummary{Health: []*models.FileHealth{
+		{File: filepath.Join(root, "api", "main.go"), Score: 64},
+		{File: filepath.Join(root, "web", "web.go"), Score: 100},
+	}}
+
+	parts, err := SplitResults(config.SplitByRoot, []string{root}, summary, results)
+	if err != nil {
+		t.Fatalf("SplitResults failed: %v", err)
+	}
+	if len(parts) != 2 || len(parts[0].Summary.Health) != 1 || parts[0].Summary.Health[0].Score != 64 ||
+		len(parts[1].Summary.Health) != 1 || parts[1].Summary.Health[0].Score != 100 {
+		t.Errorf("Expected each part to keep the health of its own files, got %v", partNames(parts))
+	}
+}
+
+func TestSplitResultsKeepsDocCoverage(t *testing.T) {
+	root, results := splitFixture(t, map[string]string{
+		"api/main.go": "package main",
+		"web/web.go":  "package web",
+	}, map[string]int{"api/main.go": 1})
+	summary := &models.ScanSummary{DocCoverage: models.ComputeDocCoverage([]models.FileDocCoverage{
+		{File: filepath.Join(root, "api", "main.go"), Documented: 1, Public: 4},
+		{File: filepath.Join(root, "web", "web.go"), Documented: 2, Public: 2},
+	})}
+
+	parts, err := SplitResults(config.SplitByRoot, []string{root}, summary, results)
+	if err != nil {
+		t.Fatalf("SplitResults failed: %v", err)
+	}
+	if len(parts) != 2 {
+		t.Fatalf("Expected a part per root, got %v", partNames(parts))
+	}
+	if api, web := parts[0].Summary.DocCoverage, parts[1].Summary.DocCoverage; api.Percent != 25 || web.Percent != 100 {
+		t.Errorf("Expected each part to cover its own files, got %+v and %+v", api, web)
+	}
+}
+
+func TestSplitResultsByOwner(t *testing.T) {
+	root, results := splitFixture(t, map[string]string{
+		"CODEOWNERS":           "/services/ @acme/backend\n/services/shared/ @acme/backend @acme/web\n",
+		"services/api/main.go": "package main",
+		"services/shared/x.go": "package shared",
+		"scripts/run.go":       "package scripts",
+	}, map[string]int{"services/api/main.go": 1, "services/shared/x.go": 2, "scripts/run.go": 1})
+
+	parts, err := SplitResults(config.SplitByOwner, []string{root}, &models.ScanSummary{}, results)
+	if err != nil {
+		t.Fatalf("SplitResults failed: %v", err)
+	}
+	names := partNames(parts)
+	if len(names) != 3 || names["@acme/backend"] != 3 || names["@acme/web"] != 2 || names[unownedPart] != 1 {
+		t.Errorf("Expected shared files in both teams' reports and unowned files apart, got %v", names)
+	}
+	if parts[0].Slug != "acme-backend" {
+		t.Errorf("Expected the slug acme-backend, got %q", parts[0].Slug)
+	}
+
+	if _, err := SplitResults(config.SplitByOwner, []string{t.TempDir()}, &models.ScanSummary{}, results); err == nil {
+		t.Error("Expected an error without a CODEOWNERS file")
+	}
+}
+
+func TestSplitResultsByCrate(t *testing.T) {
+	root, results := splitFixture(t, map[string]string{
+		"crates/engine/Cargo.toml":    "[package]\nname = \"engine-core\"\nversion = \"0.1.0\"\n",
+		"crates/engine/src/lib.rs":    "pub fn run() {}",
+		"crates/engine/src/sub/io.rs": "pub fn read() {}",
+		"tools/go.mod":                "module example.com/tools\n\ngo 1.23\n",
+		"tools/cmd/gen.go":            "package main",
+	}, map[string]int{"crates/engine/src/lib.rs": 1, "tools/cmd/gen.go": 2})
+
+	parts, err := SplitResults(config.SplitByCrate, []string{root}, &models.ScanSummary{}, results)
+	if err != nil {
+		t.Fatalf("SplitResults failed: %v", err)
+	}
+	names := partNames(parts)
+	if len(names) != 2 || names["engine-core"] != 1 || names["example.com/tools"] != 2 {
+		t.Errorf("Expected one part per Cargo package and Go module, got %v", names)
+	}
+	for _, part := range parts {
+		if part.Name == "engine-core" && part.Summary.TotalFiles != 2 {
+			t.Errorf("Expected both engine files in its part, got %d", part.Summary.TotalFiles)
+		}
+	}
+}
+
+func TestGenerateSplitReports(t *testing.T) {
+	root, results := splitFixture(t, map[string]string{
+		"api/main.go": "package main",
+		"web/web.go":  "package web",
+	}, map[string]int{"api/main.go": 1})
+	parts, err := SplitResults(config.SplitByRoot, []string{root}, &models.ScanSummary{}, results)
+	if err != nil {
+		t.Fatalf("SplitResults failed: %v", err)
+	}
+
+	out := t.TempDir()
+	cfg := &config.Config{
+		Output: config.OutputConfig{
+			HTML:    config.HTMLConfig{Path: filepath.Join(out, "report.html")},
+			SplitBy: config.SplitByRoot,
+		},
+		Export: config.ExportConfig{JSON: config.JSONConfig{Enabled: true, Path: filepath.Join(out, "json", "violations.json")}},
+	}
+	manager, err := NewManager(cfg)
+	if err != nil {
+		t.Fatalf("Failed to create manager: %v", err)
+	}
+	if err := manager.GenerateSplitReports(parts); err != nil {
+		t.Fatalf("GenerateSplitReports failed: %v", err)
+	}
+
+	for _, path := range []string{"api/report.html", "web/report.html", "json/api/violations.json", "json/web/violations.json"} {
+		if _, err := os.Stat(filepath.Join(out, filepath.FromSlash(path))); err != nil {
+			t.Errorf("Expected the split report %s: %v", path, err)
+		}
+	}
+
+	index, err := os.ReadFile(filepath.Join(out, "report.html"))
+	if err != nil {
+		t.Fatalf("Expected an HTML index at the configured path: %v", err)
+	}
+	for _, link := range []string{`href="api/report.html"`, `href="web/report.html"`} {
+		if !strings.Contains(string(index), link) {
+			t.Errorf("Expected the index to link %s", link)
+		}
+	}
+}
@@ -0,0 +1,234 @@
+package reporters
+
+import (
+	"encoding/json"
+	"fmt"
+	"os"
+	"path/filepath"
+	"sort"
+	"time"
+
+	"github.com/ericfisherdev/goclean/internal/config"
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/types"
+	"github.com/ericfisherdev/goclean/internal/violations"
+)
+
+// Unsafe inventory entry kinds
+const (
+	UnsafeKindBlock       = "unsafe_block"
+	UnsafeKindFunction    = "unsafe_fn"
+	UnsafeKindImpl        = "unsafe_impl"
+	UnsafeKindTrait       = "unsafe_trait"
+	UnsafeKindExternBlock = "extern_block"
+	UnsafeKindForeignFn   = "foreign_fn"
+	UnsafeKindExportedFn  = "exported_fn"
+)
+
+// UnsafeInventory is a census of every unsafe region and FFI boundary in the scanned Rust code.
+// It is produced regardless of violations so reviewers can audit all unsafety.
+type UnsafeInventory struct {
+	GeneratedAt time.Time              `json:"generated_at"`
+	Summary     UnsafeInventorySummary `json:"summary"`
+	Entries     []UnsafeEntry          `json:"entries"`
+}
+
+// UnsafeInventorySummary aggregates the inventory entries
+type UnsafeInventorySummary struct {
+	TotalEntries      int            `json:"total_entries"`
+	ByKind            map[string]int `json:"by_kind"`
+	WithSafetyComment int            `json:"with_safety_comment"`
+	MissingSafety     int            `json:"missing_safety_comment"`
+	FFIBoundaries     int            `json:"ffi_boundaries"`
+	UnsafeLines       int            `json:"unsafe_lines"`
+	FilesWithUnsafe   int            `json:"files_with_unsafe"`
+}
+
+// UnsafeEntry describes one unsafe block, function, impl, trait or FFI boundary
+type UnsafeEntry struct {
+	Kind             string `json:"kind"`
+	File             string `json:"file"`
+	Line             int    `json:"line"`
+	EndLine          int    `json:"end_line"`
+	Lines            int    `json:"lines"`
+	Name             string `json:"name,omitempty"`     // Item name; empty for blocks
+	Function         string `json:"function,omitempty"` // Enclosing function of an unsafe block
+	Signature        string `json:"signature,omitempty"`
+	HasSafetyComment bool   `json:"has_safety_comment"`
+	FFI              bool   `json:"ffi"`
+	ABI              string `json:"abi,omitempty"`
+}
+
+// BuildUnsafeInventory collects unsafe regions from the Rust syntax trees of
+// the scan results. An entry is justified by the same comments the safety
+// comment rule accepts, containing marker, or the default SAFETY: marker when
+// it is empty.
+func BuildUnsafeInventory(files []*models.ScanResult, marker string) *UnsafeInventory {
+	inventory := &UnsafeInventory{
+		GeneratedAt: time.Now(),
+		Summary:     UnsafeInventorySummary{ByKind: make(map[string]int)},
+		Entries:     make([]UnsafeEntry, 0),
+	}
+
+	for _, file := range files {
+		if file == nil || file.File == nil {
+			continue
+		}
+		astInfo, ok := file.RustASTInfo.(*types.RustASTInfo)
+		if !ok || astInfo == nil || astInfo.Syntax == nil {
+			continue
+		}
+
+		entries := collectUnsafeEntries(file.File.Path, astInfo, marker)
+		if len(entries) > 0 {
+			inventory.Summary.FilesWithUnsafe++
+		}
+		inventory.Entries = append(inventory.Entries, entries...)
+	}
+
+	sort.SliceStable(inventory.Entries, func(i, j int) bool {
+		a, b := inventory.Entries[i], inventory.Entries[j]
+		if a.File != b.File {
+			return a.File < b.File
+		}
+		return a.Line < b.Line
+	})
+
+	for _, entry := range inventory.Entries {
+		inventory.Summary.TotalEntries++
+		inventory.Summary.ByKind[entry.Kind]++
+		if entry.HasSafetyComment {
+			inventory.Summary.WithSafetyComment++
+		} else {
+			inventory.Summary.MissingSafety++
+		}
+		if entry.FFI {
+			inventory.Summary.FFIBoundaries++
+		}
+		if entry.Kind == UnsafeKindBlock || entry.Kind == UnsafeKindFunction {
+			inventory.Summary.UnsafeLines += entry.Lines
+		}
+	}
+
+	return inventory
+}
+
+// collectUnsafeEntries walks one file's syntax tree
+func collectUnsafeEntries(path string, astInfo *types.RustASTInfo, marker string) []UnsafeEntry {
+	var entries []UnsafeEntry
+
+	types.InspectRust(astInfo.Syntax, func(node *types.RustNode) bool {
+		kind, ffi := classifyUnsafeNode(node)
+		if kind == "" {
+			return true
+		}
+
+		entry := UnsafeEntry{
+			Kind:      kind,
+			File:      path,
+			Line:      node.StartLine,
+			EndLine:   node.EndLine,
+			Lines:     node.LineCount(),
+			Name:      node.Name,
+			Signature: node.Text,
+			FFI:       ffi,
+			ABI:       node.ABI,
+		}
+		if kind == UnsafeKindBlock {
+			entry.Name = ""
+			if fn := node.EnclosingItem(types.RustNodeFunction); fn != nil {
+				entry.Function = fn.Name
+			}
+		}
+		if kind == UnsafeKindForeignFn && entry.ABI == "" && node.Parent != nil {
+			entry.ABI = node.Parent.ABI
+		}
+		entry.HasSafetyComment = violations.HasRustSafetyComment(node, astInfo.Comments, marker)
+		entries = append(entries, entry)
+		return true
+	})
+
+	return entries
+}
+
+// classifyUnsafeNode returns the inventory kind of a node and whether it crosses an FFI boundary
+func classifyUnsafeNode(node *types.RustNode) (string, bool) {
+	switch node.Kind {
+	case types.RustNodeUnsafe:
+		return UnsafeKindBlock, false
+	case types.RustNodeExternBlock:
+		return UnsafeKindExternBlock, true
+	case types.RustNodeImpl:
+		if node.HasModifier("unsafe") {
+			return UnsafeKindImpl, false
+		}
+	case types.RustNodeTrait:
+		if node.HasModifier("unsafe") {
+			return UnsafeKindTrait, false
+		}
+	case types.RustNodeFunction:
+		if node.Parent != nil && node.Parent.Kind == types.RustNodeExternBlock {
+			return UnsafeKindForeignFn, true
+		}
+		exported := node.HasModifier("extern") || node.HasAttribute("no_mangle") ||
+			node.HasAttribute("export_name") || node.HasAttribute("unsafe(no_mangle)") ||
+			node.HasAttribute("unsafe(export_name")
+		if node.HasModifier("unsafe") {
+			return UnsafeKindFunction, exported
+		}
+		if exported {
+			return UnsafeKindExportedFn, true
+		}
+	}
+	return "", false
+}
+
+// UnsafeInventoryReporter writes the unsafe inventory as JSON
+type UnsafeInventoryReporter struct {
+	config *config.UnsafeInventoryConfig
+	marker string // Safety comment marker from rust.safety_comment_marker
+}
+
+// NewUnsafeInventoryReporter creates a new unsafe inventory reporter that
+// accepts comments containing marker as safety comments
+func NewUnsafeInventoryReporter(cfg *config.UnsafeInventoryConfig, marker string) *UnsafeInventoryReporter {
+	if cfg == nil {
+		cfg = &config.UnsafeInventoryConfig{
+			Enabled:     true,
+			Path:        "./reports/unsafe-inventory.json",
+			PrettyPrint: true,
+		}
+	}
+	return &UnsafeInventoryReporter{config: cfg, marker: marker}
+}
+
+// Generate writes the unsafe inventory for the report's files
+func (r *UnsafeInventoryReporter) Generate(report *models.Report) error {
+	if !r.config.Enabled {
+		return nil
+	}
+
+	dir := filepath.Dir(r.config.Path)
+	if err := os.MkdirAll(dir, 0755); err != nil {
+		return fmt.Errorf("failed to create report directory: %w", err)
+	}
+
+	inventory := BuildUnsafeInventory(report.Files, r.marker)
+
+	var data []byte
+	var err error
+	if r.config.PrettyPrint {
+		data, err = json.MarshalIndent(inventory, "", "  ")
+	} else {
+		data, err = json.Marshal(inventory)
+	}
+	if err != nil {
+		return fmt.Errorf("failed to marshal unsafe inventory: %w", err)
+	}
+
+	if err := os.WriteFile(r.config.Path, data, 0644); err != nil {
+		return fmt.Errorf("failed to write unsafe inventory: %w", err)
+	}
+
+	return nil
+}
@@ -0,0 +1,169 @@
+package reporters
+
+import (
+	"encoding/json"
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/ericfisherdev/goclean/internal/config"
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/scanner"
+	"github.com/ericfisherdev/goclean/internal/types"
+	"github.com/ericfisherdev/goclean/internal/violations"
+)
+
+const unsafeInventorySource = `extern "C" {
+    fn abs(x: i32) -> i32;
+}
+
+/// Reads a value.
+///
+/// # Safety
+/// The pointer must be valid.
+pub unsafe fn read(ptr: *const u8) -> u8 {
+    *ptr
+}
+
+#[no_mangle]
+pub extern "C" fn exported(x: i32) -> i32 {
+    // SAFETY: abs has no preconditions
+    unsafe { abs(x) }
+}
+
+unsafe impl Send for Wrapper {}
+
+fn careless(p: *const u8) -> u8 {
+    unsafe { *p }
+}
+`
+
+func unsafeInventoryResults() []*models.ScanResult {
+	astInfo := &types.RustASTInfo{}
+	scanner.NewRustSyntaxParser([]byte(unsafeInventorySource)).Parse(astInfo)
+
+	return []*models.ScanResult{
+		{File: &models.FileInfo{Path: "src/ffi.rs", Language: "Rust"}, RustASTInfo: astInfo},
+		{File: &models.FileInfo{Path: "main.go", Language: "Go"}},
+	}
+}
+
+func TestBuildUnsafeInventory(t *testing.T) {
+	inventory := BuildUnsafeInventory(unsafeInventoryResults(), "")
+
+	expectedKinds := []string{
+		UnsafeKindExternBlock, UnsafeKindForeignFn, UnsafeKindFunction,
+		UnsafeKindExportedFn, UnsafeKindBlock, UnsafeKindImpl, UnsafeKindBlock,
+	}
+	if len(inventory.Entries) != len(expectedKinds) {
+		t.Fatalf("Expected %d entries, got %d: %+v", len(expectedKinds), len(inventory.Entries), inventory.Entries)
+	}
+	for i, kind := range expectedKinds {
+		if inventory.Entries[i].Kind != kind {
+			t.Errorf("Entry %d: expected kind %s, got %s", i, kind, inventory.Entries[i].Kind)
+		}
+	}
+
+	foreign := inventory.Entries[1]
+	if foreign.Name != "abs" || !foreign.FFI || foreign.ABI != "C" {
+		t.Errorf("Unexpected foreign function entry %+v", foreign)
+	}
+
+	if !inventory.Entries[2].HasSafetyComment {
+		t.Error("Expected unsafe fn with a '# Safety' doc section to be documented")
+	}
+	if inventory.Entries[3].HasSafetyComment {
+		t.Error("Expected exported fn without comments to be undocumented")
+	}
+
+	block := inventory.Entries[4]
+	if !block.HasSafetyComment || block.Function != "exported" {
+		t.Errorf("Expected documented unsafe block in 'exported', got %+v", block)
+	}
+	if inventory.Entries[6].HasSafetyComment {
+		t.Error("Expected unsafe block in 'careless' to be missing a SAFETY comment")
+	}
+
+	summary := inventory.Summary
+	if summary.FFIBoundaries != 3 {
+		t.Errorf("Expected 3 FFI boundaries, got %d", summary.FFIBoundaries)
+	}
+	if summary.WithSafetyComment != 2 || summary.MissingSafety != 5 {
+		t.Errorf("Expected 2 documented and 5 undocumented entries, got %d/%d",
+			summary.WithSafetyComment, summary.MissingSafety)
+	}
+	if summary.UnsafeLines != 5 {
+		t.Errorf("Expected 5 unsafe lines, got %d", summary.UnsafeLines)
+	}
+	if summary.FilesWithUnsafe != 1 {
+		t.Errorf("Expected 1 file with unsafe code, got %d", summary.FilesWithUnsafe)
+	}
+}
+
+// TestBuildUnsafeInventoryMatchesSafetyCommentRule tests that the inventory
+// honors the configured marker and, like the safety comment rule, ignores
+// comments inside a block or separated from it by code
+func TestBuildUnsafeInventoryMatchesSafetyCommentRule(t *testing.T) {
+	source := `fn first(v: &[u8]) -> u8 {
+    // Invariant: v is not empty
+    unsafe { *v.get_unchecked(0) }
+}
+
+fn second(p: *const u8) -> u8 {
+    // SAFETY: p is valid
+    let q = p;
+    unsafe {
+        // SAFETY: q is valid
+        *q
+    }
+}
+`
+	astInfo := &types.RustASTInfo{}
+	scanner.NewRustSyntaxParser([]byte(source)).Parse(astInfo)
+	results := []*models.ScanResult{{File: &models.FileInfo{Path: "src/lib.rs", Language: "Rust"}, RustASTInfo: astInfo}}
+
+	inventory := BuildUnsafeInventory(results, "invariant:")
+	if len(inventory.Entries) != 2 {
+		t.Fatalf("Expected 2 entries, got %d: %+v", len(inventory.Entries), inventory.Entries)
+	}
+	if !inventory.Entries[0].HasSafetyComment {
+		t.Error("Expected the configured marker to justify the block in 'first'")
+	}
+	if inventory.Entries[1].HasSafetyComment {
+		t.Error("Expected the block in 'second' to be missing a comment right above it")
+	}
+
+	detectorConfig := violations.DefaultDetectorConfig()
+	detectorConfig.RustConfig.RequireUnsafeComments = true
+	detectorConfig.RustConfig.SafetyCommentMarker = "invariant:"
+	found := violations.NewRustSafetyCommentDetector(detectorConfig).Detect(results[0].File, astInfo)
+	if len(found) != inventory.Summary.MissingSafety {
+		t.Errorf("Expected the rule to report the %d undocumented entries, got %d", inventory.Summary.MissingSafety, len(found))
+	}
+}
+
+func TestUnsafeInventoryReporter_Generate(t *testing.T) {
+	outputPath := filepath.Join(t.TempDir(), "inventory", "unsafe.json")
+	reporter := NewUnsafeInventoryReporter(&config.UnsafeInventoryConfig{
+		Enabled: true,
+		Path:    outputPath,
+	}, "SAFETY:")
+
+	report := models.NewReport(&models.ScanSummary{}, unsafeInventoryResults(), &models.ReportConfig{})
+	if err := reporter.Generate(report); err != nil {
+		t.Fatalf("Failed to generate unsafe inventory: %v", err)
+	}
+
+	data, err := os.ReadFile(outputPath)
+	if err != nil {
+		t.Fatalf("Failed to read unsafe inventory: %v", err)
+	}
+
+	var inventory UnsafeInventory
+	if err := json.Unmarshal(data, &inventory); err != nil {
+		t.Fatalf("Failed to parse unsafe inventory: %v", err)
+	}
+	if inventory.Summary.TotalEntries != 7 || inventory.Summary.ByKind[UnsafeKindBlock] != 2 {
+		t.Errorf("Unexpected summary %+v", inventory.Summary)
+	}
+}
@@ -0,0 +1,70 @@
+// Package rules tracks how rule IDs change between releases. Renamed and
+// split rules keep their old IDs mapped to the replacements for at least one
+// release, so suppressions and baselines written for older releases keep
+// working while users are warned to migrate them.
+package rules
+
+import (
+	"fmt"
+	"sort"
+	"strings"
+)
+
+// Deprecation describes a rule ID that was renamed or split
+type Deprecation struct {
+	ID           string   // Deprecated rule ID
+	Replacements []string // Rule IDs that replace it; several when the rule was split
+	Since        string   // Release that deprecated the ID
+}
+
+// deprecations maps deprecated rule IDs to their replacements. Remove an
+// entry once it has been deprecated for a full release cycle.
+var deprecations = map[string]Deprecation{
+	"rust-missing-documentation": {
+		ID: "rust-missing-documentation",
+		Replacements: []string{
+			"rust-missing-function-documentation",
+			"rust-missing-struct-documentation",
+			"rust-missing-enum-documentation",
+			"rust-missing-trait-documentation",
+			"rust-missing-module-documentation",
+			"rust-missing-constant-documentation",
+			"rust-missing-macro-documentation",
+		},
+		Since: "2025.08.18",
+	},
+}
+
+// Lookup returns the deprecation of a rule ID, if it is deprecated
+func Lookup(id string) (Deprecation, bool) {
+	deprecation, ok := deprecations[id]
+	return deprecation, ok
+}
+
+// Resolve returns the rule IDs a possibly deprecated ID stands for
+func Resolve(id string) []string {
+	if deprecation, ok := deprecations[id]; ok {
+		return deprecation.Replacements
+	}
+	return []string{id}
+}
+
+// Deprecations returns every deprecated rule ID, sorted by ID
+func Deprecations() []Deprecation {
+	list := make([]Deprecation, 0, len(deprecations))
+	for _, deprecation := range deprecations {
+		list = append(list, deprecation)
+	}
+	sort.Slice(list, func(i, j int) bool { return list[i].ID < list[j].ID })
+	return list
+}
+
+// Warning describes the deprecation and how to migrate
+func (d Deprecation) Warning() string {
+	verb := "renamed to"
+	if len(d.Replacements) > 1 {
+		verb = "split into"
+	}
+	return fmt.Sprintf("rule %s was %s %s in %s and will stop being recognized in a future release",
+		d.ID, verb, strings.Join(d.Replacements, ", "), d.Since)
+}
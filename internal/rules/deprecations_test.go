@@ -0,0 +1,41 @@
+package rules
+
+import (
+	"reflect"
+	"strings"
+	"testing"
+)
+
+func TestResolve(t *testing.T) {
+	if got := Resolve("rust-function-length"); !reflect.DeepEqual(got, []string{"rust-function-length"}) {
+		t.Errorf("Expected a current rule to resolve to itself, got %v", got)
+	}
+	got := Resolve("rust-missing-documentation")
+	if len(got) != 7 || got[0] != "rust-missing-function-documentation" {
+		t.Errorf("Expected the split documentation rules, got %v", got)
+	}
+}
+
+func TestDeprecations(t *testing.T) {
+	for _, deprecation := range Deprecations() {
+		if deprecation.Since == "" || len(deprecation.Replacements) == 0 {
+			t.Errorf("Deprecation of %s needs a release and replacements", deprecation.ID)
+		}
+		for _, replacement := range deprecation.Replacements {
+			if _, ok := Lookup(replacement); ok {
+				t.Errorf("%s is replaced by deprecated rule %s", deprecation.ID, replacement)
+			}
+		}
+	}
+}
+
+func TestWarning(t *testing.T) {
+	renamed := Deprecation{ID: "old", Replacements: []string{"new"}, Since: "2025.08.18"}
+	if warning := renamed.Warning(); !strings.Contains(warning, "old was renamed to new in 2025.08.18") {
+		t.Errorf("Unexpected warning: %s", warning)
+	}
+	split := Deprecation{ID: "old", Replacements: []string{"a", "b"}, Since: "2025.08.18"}
+	if warning := split.Warning(); !strings.Contains(warning, "split into a, b") {
+		t.Errorf("Unexpected warning: %s", warning)
+	}
+}
@@ -0,0 +1,423 @@
+// Package rustsyntax splits Rust source into tokens and comments. It is
+// shared by the scanner, which parses the tokens into a syntax tree, and the
+// detectors that read Rust found outside the tree, such as commented-out code.
+package rustsyntax
+
+import (
+	"strings"
+
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+// TokenKind classifies lexical tokens in Rust source
+type TokenKind int
+
+const (
+	Ident TokenKind = iota
+	Lifetime
+	Literal
+	Punct
+	Open
+	Close
+)
+
+// Token is a single lexical token with its 1-based source position
+type Token struct {
+	Kind         TokenKind
+	Text         string
+	Line         int
+	Column       int
+	EndLine      int
+	EndColumn    int
+	Unterminated bool // A string literal running to the end of the source without its closing quote
+}
+
+// multiCharPuncts lists punctuation recognized as single tokens, longest first.
+// '<' and '>' are deliberately kept single so generic argument lists can be balanced.
+var multiCharPuncts = []string{
+	"..=", "...", "::", "->", "=>", "==", "!=", "<=", ">=", "&&", "||",
+	"+=", "-=", "*=", "/=", "%=", "^=", "&=", "|=", "..",
+}
+
+// keywords lists the strict and reserved keywords of every edition
+var keywords = map[string]bool{
+	"as": true, "break": true, "const": true, "continue": true,
+	"crate": true, "else": true, "enum": true, "extern": true, "false": true,
+	"fn": true, "for": true, "if": true, "impl": true, "in": true, "let": true,
+	"loop": true, "match": true, "mod": true, "move": true, "mut": true, "pub": true,
+	"ref": true, "return": true, "self": true, "Self": true, "static": true, "struct": true,
+	"super": true, "trait": true, "true": true, "type": true, "unsafe": true, "use": true,
+	"where": true, "while": true, "abstract": true, "become": true, "box": true, "do": true,
+	"final": true, "macro": true, "override": true, "priv": true, "typeof": true,
+	"unsized": true, "virtual": true, "yield": true,
+}
+
+// editionKeywords maps keywords added by later editions to the edition
+// that introduced them; before that edition they are plain identifiers
+var editionKeywords = map[string]types.RustEdition{
+	"async": types.RustEdition2018,
+	"await": types.RustEdition2018,
+	"dyn":   types.RustEdition2018,
+	"try":   types.RustEdition2018,
+	"gen":   types.RustEdition2024,
+}
+
+// IsKeyword reports whether text is a keyword in the given edition
+func IsKeyword(text string, edition types.RustEdition) bool {
+	if keywords[text] {
+		return true
+	}
+	since, found := editionKeywords[text]
+	return found && edition.AtLeast(since)
+}
+
+// tokenizer converts Rust source into tokens and comments, correctly
+// skipping string, raw string, char and nested block comment contents
+type tokenizer struct {
+	src      string
+	pos      int
+	line     int
+	column   int
+	edition  types.RustEdition
+	tokens   []Token
+	comments []*Comment
+}
+
+// Comment is a comment captured during tokenization
+type Comment struct {
+	Text    string
+	Line    int
+	EndLine int
+	Column  int
+	IsDoc   bool
+	IsInner bool
+	IsBlock bool
+	// TokenIndex is the index of the first token following the comment
+	TokenIndex int
+}
+
+// Tokenize tokenizes Rust source code written for the given edition
+func Tokenize(src string, edition types.RustEdition) ([]Token, []*Comment) {
+	t := &tokenizer{src: src, line: 1, column: 1, edition: edition}
+	t.run()
+	return t.tokens, t.comments
+}
+
+func (t *tokenizer) run() {
+	for t.pos < len(t.src) {
+		c := t.src[t.pos]
+		switch {
+		case c == '\n':
+			t.advance(1)
+		case c == ' ' || c == '\t' || c == '\r':
+			t.advance(1)
+		case strings.HasPrefix(t.src[t.pos:], "//"):
+			t.lineComment()
+		case strings.HasPrefix(t.src[t.pos:], "/*"):
+			t.blockComment()
+		case c == '"':
+			t.stringLiteral(0)
+		case (c == 'r' || c == 'b' || c == 'c') && t.rawStringPrefixLen() > 0:
+			t.rawString(t.rawStringPrefixLen())
+		case c == 'b' && t.peek(1) == '"':
+			t.stringLiteral(1)
+		case c == 'c' && t.peek(1) == '"' && t.edition.AtLeast(types.RustEdition2021):
+			// C string literals; older editions lex c"..." as an identifier and a string
+			t.stringLiteral(1)
+		case c == 'b' && t.peek(1) == '\'':
+			t.charLiteral(1)
+		case c == '\'':
+			t.quote()
+		case isDigit(c):
+			t.number()
+		case isIdentStart(c):
+			t.ident()
+		case c == '(' || c == '[' || c == '{':
+			t.emit(Open, 1)
+		case c == ')' || c == ']' || c == '}':
+			t.emit(Close, 1)
+		default:
+			t.punct()
+		}
+	}
+}
+
+// advance moves forward n bytes, tracking line and column
+func (t *tokenizer) advance(n int) {
+	for i := 0; i < n && t.pos < len(t.src); i++ {
+		if t.src[t.pos] == '\n' {
+			t.line++
+			t.column = 1
+		} else {
+			t.column++
+		}
+		t.pos++
+	}
+}
+
+func (t *tokenizer) peek(offset int) byte {
+	if t.pos+offset < len(t.src) {
+		return t.src[t.pos+offset]
+	}
+	return 0
+}
+
+// emit records a token spanning the next n bytes
+func (t *tokenizer) emit(kind TokenKind, n int) {
+	start := t.pos
+	line, column := t.line, t.column
+	t.advance(n)
+	t.tokens = append(t.tokens, Token{
+		Kind:      kind,
+		Text:      t.src[start:t.pos],
+		Line:      line,
+		Column:    column,
+		EndLine:   t.line,
+		EndColumn: t.column,
+	})
+}
+
+func (t *tokenizer) lineComment() {
+	start := t.pos
+	line, column := t.line, t.column
+	end := strings.IndexByte(t.src[t.pos:], '\n')
+	if end < 0 {
+		end = len(t.src) - t.pos
+	}
+	t.advance(end)
+	text := t.src[start:t.pos]
+	isDoc := (strings.HasPrefix(text, "///") && !strings.HasPrefix(text, "////")) || strings.HasPrefix(text, "//!")
+	t.comments = append(t.comments, &Comment{
+		Text:       text,
+		Line:       line,
+		EndLine:    line,
+		Column:     column,
+		IsDoc:      isDoc,
+		IsInner:    strings.HasPrefix(text, "//!"),
+		TokenIndex: len(t.tokens),
+	})
+}
+
+func (t *tokenizer) blockComment() {
+	start := t.pos
+	line, column := t.line, t.column
+	depth := 0
+	for t.pos < len(t.src) {
+		if strings.HasPrefix(t.src[t.pos:], "/*") {
+			depth++
+			t.advance(2)
+		} else if strings.HasPrefix(t.src[t.pos:], "*/") {
+			depth--
+			t.advance(2)
+			if depth == 0 {
+				break
+			}
+		} else {
+			t.advance(1)
+		}
+	}
+	text := t.src[start:t.pos]
+	isDoc := (strings.HasPrefix(text, "/**") && !strings.HasPrefix(text, "/***") && text != "/**/") || strings.HasPrefix(text, "/*!")
+	t.comments = append(t.comments, &Comment{
+		Text:       text,
+		Line:       line,
+		EndLine:    t.line,
+		Column:     column,
+		IsDoc:      isDoc,
+		IsInner:    strings.HasPrefix(text, "/*!"),
+		IsBlock:    true,
+		TokenIndex: len(t.tokens),
+	})
+}
+
+// stringLiteral consumes a (byte) string literal with escapes
+func (t *tokenizer) stringLiteral(prefixLen int) {
+	start := t.pos
+	line, column := t.line, t.column
+	t.advance(prefixLen + 1)
+	closed := false
+	for t.pos < len(t.src) && !closed {
+		c := t.src[t.pos]
+		if c == '\\' {
+			t.advance(2)
+			continue
+		}
+		t.advance(1)
+		closed = c == '"'
+	}
+	t.consumeSuffix()
+	t.pushLiteral(start, line, column)
+	t.tokens[len(t.tokens)-1].Unterminated = !closed
+}
+
+// rawStringPrefixLen returns the length of a raw string prefix (r", r#", br#", cr") or 0
+func (t *tokenizer) rawStringPrefixLen() int {
+	i := t.pos
+	if t.src[i] == 'b' || t.src[i] == 'c' {
+		i++
+	}
+	if i >= len(t.src) || t.src[i] != 'r' {
+		return 0
+	}
+	i++
+	for i < len(t.src) && t.src[i] == '#' {
+		i++
+	}
+	if i < len(t.src) && t.src[i] == '"' {
+		return i - t.pos + 1
+	}
+	return 0
+}
+
+func (t *tokenizer) rawString(prefixLen int) {
+	start := t.pos
+	line, column := t.line, t.column
+	hashes := strings.Count(t.src[t.pos:t.pos+prefixLen], "#")
+	t.advance(prefixLen)
+	terminator := "\"" + strings.Repeat("#", hashes)
+	end := strings.Index(t.src[t.pos:], terminator)
+	if end < 0 {
+		t.advance(len(t.src) - t.pos)
+	} else {
+		t.advance(end + len(terminator))
+	}
+	t.pushLiteral(start, line, column)
+	t.tokens[len(t.tokens)-1].Unterminated = end < 0
+}
+
+func (t *tokenizer) charLiteral(prefixLen int) {
+	start := t.pos
+	line, column := t.line, t.column
+	t.advance(prefixLen + 1)
+	for t.pos < len(t.src) && t.src[t.pos] != '\n' {
+		c := t.src[t.pos]
+		if c == '\\' {
+			t.advance(2)
+			continue
+		}
+		t.advance(1)
+		if c == '\'' {
+			break
+		}
+	}
+	t.pushLiteral(start, line, column)
+}
+
+// quote distinguishes char literals from lifetimes and labels
+func (t *tokenizer) quote() {
+	if t.peek(1) == '\\' {
+		t.charLiteral(0)
+		return
+	}
+	// 'x' (possibly multi-byte UTF-8): look for the closing quote right after one character
+	i := t.pos + 1
+	if i < len(t.src) {
+		i += utf8SeqLen(t.src[i])
+	}
+	if i < len(t.src) && t.src[i] == '\'' {
+		t.charLiteral(0)
+		return
+	}
+	// Lifetime or label
+	n := 1
+	for t.pos+n < len(t.src) && isIdentChar(t.src[t.pos+n]) {
+		n++
+	}
+	t.emit(Lifetime, n)
+}
+
+func (t *tokenizer) number() {
+	start := t.pos
+	line, column := t.line, t.column
+	for t.pos < len(t.src) {
+		c := t.src[t.pos]
+		if isIdentChar(c) {
+			// Exponent sign, e.g. 1e-5
+			if (c == 'e' || c == 'E') && (t.peek(1) == '-' || t.peek(1) == '+') && !strings.HasPrefix(t.src[start:t.pos], "0x") {
+				t.advance(2)
+				continue
+			}
+			t.advance(1)
+			continue
+		}
+		// Decimal point only when followed by a digit (not a range or method call)
+		if c == '.' && isDigit(t.peek(1)) {
+			t.advance(1)
+			continue
+		}
+		break
+	}
+	t.pushLiteral(start, line, column)
+}
+
+func (t *tokenizer) ident() {
+	n := 0
+	for t.pos+n < len(t.src) && isIdentChar(t.src[t.pos+n]) {
+		n++
+	}
+	// Raw identifiers: r#type
+	if n == 1 && t.src[t.pos] == 'r' && t.peek(1) == '#' && t.pos+2 < len(t.src) && isIdentStart(t.src[t.pos+2]) {
+		n = 2
+		for t.pos+n < len(t.src) && isIdentChar(t.src[t.pos+n]) {
+			n++
+		}
+	}
+	t.emit(Ident, n)
+}
+
+func (t *tokenizer) punct() {
+	rest := t.src[t.pos:]
+	for _, p := range multiCharPuncts {
+		if strings.HasPrefix(rest, p) {
+			t.emit(Punct, len(p))
+			return
+		}
+	}
+	t.emit(Punct, utf8SeqLen(t.src[t.pos]))
+}
+
+// consumeSuffix consumes a literal suffix such as the `u8` in `b"x"u8`
+func (t *tokenizer) consumeSuffix() {
+	for t.pos < len(t.src) && isIdentChar(t.src[t.pos]) {
+		t.advance(1)
+	}
+}
+
+func (t *tokenizer) pushLiteral(start, line, column int) {
+	t.tokens = append(t.tokens, Token{
+		Kind:      Literal,
+		Text:      t.src[start:t.pos],
+		Line:      line,
+		Column:    column,
+		EndLine:   t.line,
+		EndColumn: t.column,
+	})
+}
+
+func isDigit(c byte) bool {
+	return c >= '0' && c <= '9'
+}
+
+func isIdentStart(c byte) bool {
+	return c == '_' || (c >= 'a' && c <= 'z') || (c >= 'A' && c <= 'Z') || c >= 0x80
+}
+
+func isIdentChar(c byte) bool {
+	return isIdentStart(c) || isDigit(c)
+}
+
+// utf8SeqLen returns the byte length of the UTF-8 sequence starting with c
+func utf8SeqLen(c byte) int {
+	switch {
+	case c < 0x80:
+		return 1
+	case c>>5 == 0x6:
+		return 2
+	case c>>4 == 0xE:
+		return 3
+	case c>>3 == 0x1E:
+		return 4
+	default:
+		return 1
+	}
+}
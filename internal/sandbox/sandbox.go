@@ -0,0 +1,265 @@
+// Package sandbox runs untrusted plugin commands with resource limits and
+// without access to the network or to files outside the system directories.
+//
+// A sandboxed command is started through the goclean executable itself: the
+// new process limits and restricts itself while it is still goclean, then
+// replaces itself with the plugin, which inherits the restrictions.
+package sandbox
+
+import (
+	"errors"
+	"fmt"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"runtime"
+	"strconv"
+	"strings"
+	"time"
+)
+
+// Arg is the first argument of the goclean processes that restrict
+// themselves and execute a plugin
+const Arg = "__goclean-sandbox"
+
+// ExitCode is the exit status of a sandbox process that could not execute
+// its plugin
+const ExitCode = 126
+
+// systemPaths are readable by sandboxed commands so that interpreters and
+// shared libraries load
+var systemPaths = []string{"/bin", "/sbin", "/usr", "/lib", "/lib32", "/lib64", "/etc", "/opt", "/nix/store"}
+
+// devicePaths are readable and writable by sandboxed commands
+var devicePaths = []string{"/dev/null", "/dev/zero", "/dev/random", "/dev/urandom"}
+
+// environment lists the variables passed to sandboxed commands; the others,
+// which may hold credentials, are dropped
+var environment = []string{"PATH", "LANG", "LC_ALL", "LC_CTYPE", "TZ", "SYSTEMROOT"}
+
+// Limits restrict a sandboxed command
+type Limits struct {
+	CPUTime    time.Duration // CPU time the process may use over its life; unlimited when 0
+	Memory     int64         // Bytes of data the process may allocate; unlimited when 0
+	Network    bool          // Allow network access
+	Filesystem bool          // Allow access to every file the user can reach
+	ReadPaths  []string      // Files and directories readable without filesystem access
+
+	// AllowUnsandboxed runs the command when the platform cannot enforce
+	// every restriction, with those it can. Otherwise such commands fail
+	// with ErrUnenforced.
+	AllowUnsandboxed bool
+}
+
+// ErrUnenforced is returned for commands whose restrictions the platform
+// cannot enforce
+var ErrUnenforced = errors.New("restrictions cannot be enforced")
+
+// Enforcement describes the restrictions of limits the platform enforces
+type Enforcement struct {
+	Resources  bool     // CPU time and memory limits apply
+	Filesystem bool     // Files outside the readable paths are denied
+	Network    bool     // TCP connections are denied
+	Missing    []string // Restrictions of the limits the platform cannot enforce
+}
+
+// String lists the enforced restrictions and the missing ones, such as
+// "resource limits, file isolation (network isolation not enforced)"
+func (e Enforcement) String() string {
+	var enforced []string
+	if e.Resources {
+		enforced = append(enforced, "resource limits")
+	}
+	if e.Filesystem {
+		enforced = append(enforced, "file isolation")
+	}
+	if e.Network {
+		enforced = append(enforced, "network isolation")
+	}
+	description := "no restrictions"
+	if len(enforced) > 0 {
+		description = strings.Join(enforced, ", ")
+	}
+	if len(e.Missing) > 0 {
+		description += " (" + strings.Join(e.Missing, ", ") + " not enforced)"
+	}
+	return description
+}
+
+// Available reports whether commands can be sandboxed on this platform
+func Available() bool {
+	return supported
+}
+
+// Check returns the restrictions of limits the platform enforces
+func Check(limits Limits) Enforcement {
+	filesystem, network := isolation()
+	var enforcement Enforcement
+	if limits.CPUTime > 0 || limits.Memory > 0 {
+		enforcement.Resources = supported
+		if !supported {
+			enforcement.Missing = append(enforcement.Missing, "resource limits")
+		}
+	}
+	if !limits.Filesystem {
+		enforcement.Filesystem = filesystem
+		if !filesystem {
+			enforcement.Missing = append(enforcement.Missing, "file isolation")
+		}
+	}
+	if !limits.Network {
+		enforcement.Network = network
+		if !network {
+			enforcement.Missing = append(enforcement.Missing, "network isolation")
+		}
+	}
+	return enforcement
+}
+
+// Command returns a command that runs program with args under limits. The
+// program, its directory and the directories of arguments naming files are
+// readable besides the system directories and the read paths of the limits.
+// Unless the limits allow it, restrictions the platform cannot enforce fail
+// the command with ErrUnenforced.
+func Command(limits Limits, program string, args ...string) (*exec.Cmd, error) {
+	if !supported {
+		return nil, fmt.Errorf("sandboxing is not supported on %s", runtime.GOOS)
+	}
+	if missing := Check(limits).Missing; len(missing) > 0 && !limits.AllowUnsandboxed {
+		return nil, fmt.Errorf("%w: %s", ErrUnenforced, strings.Join(missing, " and "))
+	}
+	self, err := os.Executable()
+	if err != nil {
+		return nil, fmt.Errorf("locating the goclean executable: %w", err)
+	}
+	path, err := exec.LookPath(program)
+	if err != nil {
+		return nil, err
+	}
+	if path, err = filepath.Abs(path); err != nil {
+		return nil, err
+	}
+
+	readPaths := []string{filepath.Dir(path)}
+	for _, arg := range args {
+		if info, err := os.Stat(arg); err == nil && info.Mode().IsRegular() {
+			if absolute, err := filepath.Abs(arg); err == nil {
+				readPaths = append(readPaths, filepath.Dir(absolute))
+			}
+		}
+	}
+	for _, readPath := range limits.ReadPaths {
+		if absolute, err := filepath.Abs(readPath); err == nil {
+			readPaths = append(readPaths, absolute)
+		}
+	}
+	limits.ReadPaths = readPaths
+
+	cmdArgs := append(formatArgs(limits), "--", path)
+	cmd := exec.Command(self, append(cmdArgs, args...)...)
+	cmd.Env = filterEnvironment(os.Environ())
+	return cmd, nil
+}
+
+// Run restricts the current process to the limits in args and replaces it
+// with the command following "--". It only returns on failure.
+func Run(args []string) error {
+	limits, argv, err := parseArgs(args)
+	if err != nil {
+		return err
+	}
+
+	// Landlock restrictions apply to the calling thread, which must be the
+	// one executing the plugin
+	runtime.LockOSThread()
+	if err := setResourceLimits(limits); err != nil {
+		return fmt.Errorf("limiting resources: %w", err)
+	}
+	if err := restrictAccess(limits); err != nil {
+		return fmt.Errorf("restricting access: %w", err)
+	}
+	if err := execute(argv, os.Environ()); err != nil {
+		return fmt.Errorf("executing %s: %w", argv[0], err)
+	}
+	return nil
+}
+
+// formatArgs encodes limits as sandbox process arguments
+func formatArgs(limits Limits) []string {
+	args := []string{Arg}
+	if limits.CPUTime > 0 {
+		args = append(args, "--cpu="+limits.CPUTime.String())
+	}
+	if limits.Memory > 0 {
+		args = append(args, "--memory="+strconv.FormatInt(limits.Memory, 10))
+	}
+	if limits.Network {
+		args = append(args, "--network")
+	}
+	if limits.Filesystem {
+		args = append(args, "--filesystem")
+	}
+	if limits.AllowUnsandboxed {
+		args = append(args, "--allow-unsandboxed")
+	}
+	for _, path := range limits.ReadPaths {
+		args = append(args, "--read="+path)
+	}
+	return args
+}
+
+// parseArgs decodes the arguments following Arg into limits and the command
+// to execute
+func parseArgs(args []string) (Limits, []string, error) {
+	var limits Limits
+	for i, arg := range args {
+		if arg == "--" {
+			if i == len(args)-1 {
+				return limits, nil, fmt.Errorf("no command to sandbox")
+			}
+			return limits, args[i+1:], nil
+		}
+
+		name, value, _ := strings.Cut(arg, "=")
+		switch name {
+		case "--cpu":
+			cpuTime, err := time.ParseDuration(value)
+			if err != nil {
+				return limits, nil, fmt.Errorf("invalid CPU time %q", value)
+			}
+			limits.CPUTime = cpuTime
+		case "--memory":
+			memory, err := strconv.ParseInt(value, 10, 64)
+			if err != nil {
+				return limits, nil, fmt.Errorf("invalid memory limit %q", value)
+			}
+			limits.Memory = memory
+		case "--network":
+			limits.Network = true
+		case "--filesystem":
+			limits.Filesystem = true
+		case "--allow-unsandboxed":
+			limits.AllowUnsandboxed = true
+		case "--read":
+			limits.ReadPaths = append(limits.ReadPaths, value)
+		default:
+			return limits, nil, fmt.Errorf("unknown sandbox argument %q", arg)
+		}
+	}
+	return limits, nil, fmt.Errorf("no command to sandbox")
+}
+
+// filterEnvironment keeps the variables sandboxed commands need
+func filterEnvironment(env []string) []string {
+	var kept []string
+	for _, variable := range env {
+		name, _, _ := strings.Cut(variable, "=")
+		for _, allowed := range environment {
+			if strings.EqualFold(name, allowed) {
+				kept = append(kept, variable)
+				break
+			}
+		}
+	}
+	return kept
+}
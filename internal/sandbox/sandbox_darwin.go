@@ -0,0 +1,18 @@
+package sandbox
+
+import "fmt"
+
+// isolation reports that macOS cannot deny file and network access
+func isolation() (filesystem, network bool) {
+	return false, false
+}
+
+// restrictAccess cannot deny file and network access on macOS; the plugin
+// only runs with resource limits and a reduced environment when the limits
+// allow it
+func restrictAccess(limits Limits) error {
+	if (!limits.Network || !limits.Filesystem) && !limits.AllowUnsandboxed {
+		return fmt.Errorf("%w: file and network isolation is not supported on macOS", ErrUnenforced)
+	}
+	return nil
+}
@@ -0,0 +1,164 @@
+package sandbox
+
+import (
+	"encoding/binary"
+	"fmt"
+	"os"
+	"syscall"
+	"unsafe"
+)
+
+// Landlock system calls, identical on every architecture
+const (
+	sysLandlockCreateRuleset = 444
+	sysLandlockAddRule       = 445
+	sysLandlockRestrictSelf  = 446
+
+	landlockCreateRulesetVersion = 1
+	landlockRulePathBeneath      = 1
+
+	prSetNoNewPrivs = 38
+	oPath           = 0x200000
+)
+
+// Landlock access rights
+const (
+	accessFSExecute   = 1 << 0
+	accessFSWriteFile = 1 << 1
+	accessFSReadFile  = 1 << 2
+	accessFSReadDir   = 1 << 3
+	accessFSTruncate  = 1 << 14
+	accessFSIoctlDev  = 1 << 15
+
+	accessNetBindTCP    = 1 << 0
+	accessNetConnectTCP = 1 << 1
+)
+
+// landlockRulesetAttr mirrors struct landlock_ruleset_attr
+type landlockRulesetAttr struct {
+	handledAccessFS  uint64
+	handledAccessNet uint64
+}
+
+// isolation reports whether the kernel's Landlock version can deny file
+// access and, from Linux 6.7, TCP connections
+func isolation() (filesystem, network bool) {
+	abi := landlockABI()
+	return abi > 0, abi >= 4
+}
+
+// restrictAccess denies the process the files outside the readable paths
+// and TCP connections with Landlock. Kernels without Landlock, or without
+// its network rules before Linux 6.7, fail with ErrUnenforced unless the
+// limits allow running with the restrictions they can enforce.
+func restrictAccess(limits Limits) error {
+	if limits.Network && limits.Filesystem {
+		return nil
+	}
+	abi := landlockABI()
+	if abi == 0 {
+		if !limits.AllowUnsandboxed {
+			return fmt.Errorf("%w: the kernel does not support Landlock", ErrUnenforced)
+		}
+		return nil
+	}
+
+	var attr landlockRulesetAttr
+	if !limits.Filesystem {
+		attr.handledAccessFS = handledFSAccess(abi)
+	}
+	if !limits.Network {
+		if abi >= 4 {
+			attr.handledAccessNet = accessNetBindTCP | accessNetConnectTCP
+		} else if !limits.AllowUnsandboxed {
+			return fmt.Errorf("%w: network isolation needs Linux 6.7 or later", ErrUnenforced)
+		}
+	}
+	if attr.handledAccessFS == 0 && attr.handledAccessNet == 0 {
+		return nil
+	}
+
+	fd, _, errno := syscall.Syscall(sysLandlockCreateRuleset, uintptr(unsafe.Pointer(&attr)), unsafe.Sizeof(attr), 0)
+	if errno != 0 {
+		return fmt.Errorf("creating the Landlock ruleset: %w", errno)
+	}
+	ruleset := int(fd)
+	defer syscall.Close(ruleset)
+
+	if !limits.Filesystem {
+		readable := accessFSExecute | accessFSReadFile | accessFSReadDir
+		for _, path := range append(systemPaths, limits.ReadPaths...) {
+			if err := allowPath(ruleset, path, readable&attr.handledAccessFS); err != nil {
+				return err
+			}
+		}
+		writable := accessFSReadFile | accessFSWriteFile | accessFSTruncate | accessFSIoctlDev
+		for _, path := range devicePaths {
+			if err := allowPath(ruleset, path, writable&attr.handledAccessFS); err != nil {
+				return err
+			}
+		}
+	}
+
+	if _, _, errno := syscall.RawSyscall6(syscall.SYS_PRCTL, prSetNoNewPrivs, 1, 0, 0, 0, 0); errno != 0 {
+		return fmt.Errorf("setting no_new_privs: %w", errno)
+	}
+	if _, _, errno := syscall.Syscall(sysLandlockRestrictSelf, uintptr(ruleset), 0, 0); errno != 0 {
+		return fmt.Errorf("enforcing the Landlock ruleset: %w", errno)
+	}
+	return nil
+}
+
+// landlockABI returns the Landlock version of the kernel, or 0 when
+// Landlock is missing or disabled
+func landlockABI() uint64 {
+	abi, _, errno := syscall.Syscall(sysLandlockCreateRuleset, 0, 0, landlockCreateRulesetVersion)
+	if errno != 0 {
+		return 0
+	}
+	return uint64(abi)
+}
+
+// handledFSAccess returns every file access right of a Landlock version,
+// so that all of them are denied unless a rule allows them
+func handledFSAccess(abi uint64) uint64 {
+	switch {
+	case abi >= 5:
+		return 1<<16 - 1
+	case abi >= 3:
+		return 1<<15 - 1
+	case abi == 2:
+		return 1<<14 - 1
+	default:
+		return 1<<13 - 1
+	}
+}
+
+// allowPath adds a rule granting access to a file or directory tree.
+// Missing paths are skipped; files only get the rights that apply to files.
+func allowPath(ruleset int, path string, access uint64) error {
+	info, err := os.Stat(path)
+	if err != nil {
+		return nil
+	}
+	if !info.IsDir() {
+		access &^= accessFSReadDir
+	}
+
+	fd, err := syscall.Open(path, oPath|syscall.O_CLOEXEC, 0)
+	if err != nil {
+		return nil
+	}
+	defer syscall.Close(fd)
+
+	// struct landlock_path_beneath_attr is packed: a 64-bit access mask
+	// followed by a 32-bit descriptor
+	var rule [12]byte
+	binary.NativeEndian.PutUint64(rule[0:8], access)
+	binary.NativeEndian.PutUint32(rule[8:12], uint32(fd))
+	if _, _, errno := syscall.Syscall6(sysLandlockAddRule, uintptr(ruleset), landlockRulePathBeneath,
+		uintptr(unsafe.Pointer(&rule[0])), 0, 0, 0); errno != 0 {
+		return fmt.Errorf("allowing %s: %w", path, errno)
+	}
+	return nil
+}
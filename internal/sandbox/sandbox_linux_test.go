@@ -0,0 +1,83 @@
+package sandbox
+
+import (
+	"errors"
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+	"time"
+)
+
+func TestCommandDeniesFilesOutsideReadPaths(t *testing.T) {
+	if landlockABI() == 0 {
+		t.Skip("Landlock is not available")
+	}
+	secret := filepath.Join(t.TempDir(), "secret.txt")
+	if err := os.WriteFile(secret, []byte("token"), 0600); err != nil {
+		t.Fatal(err)
+	}
+
+	// Kernels before Linux 6.7 cannot isolate the network
+	limits := Limits{Network: true}
+	cmd, err := Command(limits, "cat", secret)
+	if err != nil {
+		t.Fatalf("Command failed: %v", err)
+	}
+	// The argument names a file, so its directory is readable
+	if output, err := cmd.Output(); err != nil || string(output) != "token" {
+		t.Fatalf("Expected the argument file to be readable, got %q: %v", output, err)
+	}
+
+	cmd, err = Command(limits, "sh", "-c", "cat "+secret)
+	if err != nil {
+		t.Fatalf("Command failed: %v", err)
+	}
+	if output, err := cmd.Output(); err == nil {
+		t.Errorf("Expected reading %s to be denied, got %q", secret, output)
+	}
+
+	cmd, err = Command(Limits{Filesystem: true}, "sh", "-c", "cat "+secret)
+	if err != nil {
+		t.Fatalf("Command failed: %v", err)
+	}
+	if output, err := cmd.Output(); err != nil || string(output) != "token" {
+		t.Errorf("Expected filesystem access to allow the read, got %q: %v", output, err)
+	}
+}
+
+func TestCommandRefusesUnenforcedIsolation(t *testing.T) {
+	if _, network := isolation(); network {
+		t.Skip("The kernel isolates the network")
+	}
+
+	if _, err := Command(Limits{}, "true"); !errors.Is(err, ErrUnenforced) {
+		t.Fatalf("Expected ErrUnenforced without network isolation, got %v", err)
+	}
+	cmd, err := Command(Limits{AllowUnsandboxed: true}, "true")
+	if err != nil {
+		t.Fatalf("Command failed: %v", err)
+	}
+	if err := cmd.Run(); err != nil {
+		t.Errorf("Expected the command to run with the restrictions available, got %v", err)
+	}
+}
+
+func TestCommandStopsAtCPUTimeLimit(t *testing.T) {
+	cmd, err := Command(Limits{CPUTime: time.Second, Filesystem: true, Network: true}, "sh", "-c", "while :; do :; done")
+	if err != nil {
+		t.Fatalf("Command failed: %v", err)
+	}
+
+	done := make(chan error, 1)
+	go func() { done <- cmd.Run() }()
+	select {
+	case err := <-done:
+		if err == nil || !strings.Contains(err.Error(), "signal") {
+			t.Errorf("Expected the busy loop to be killed by a signal, got %v", err)
+		}
+	case <-time.After(30 * time.Second):
+		cmd.Process.Kill()
+		t.Fatal("The CPU time limit did not stop the busy loop")
+	}
+}
@@ -0,0 +1,26 @@
+//go:build !linux && !darwin
+
+package sandbox
+
+import (
+	"fmt"
+	"runtime"
+)
+
+const supported = false
+
+func isolation() (filesystem, network bool) {
+	return false, false
+}
+
+func setResourceLimits(limits Limits) error {
+	return fmt.Errorf("resource limits are not supported on %s", runtime.GOOS)
+}
+
+func restrictAccess(limits Limits) error {
+	return fmt.Errorf("access restrictions are not supported on %s", runtime.GOOS)
+}
+
+func execute(argv []string, env []string) error {
+	return fmt.Errorf("sandboxed execution is not supported on %s", runtime.GOOS)
+}
@@ -0,0 +1,82 @@
+package sandbox
+
+import (
+	"fmt"
+	"os"
+	"reflect"
+	"testing"
+	"time"
+)
+
+// TestMain lets the test binary stand in for goclean when a test starts a
+// sandboxed command
+func TestMain(m *testing.M) {
+	if len(os.Args) > 1 && os.Args[1] == Arg {
+		err := Run(os.Args[2:])
+		fmt.Fprintf(os.Stderr, "Error: %v\n", err)
+		os.Exit(ExitCode)
+	}
+	os.Exit(m.Run())
+}
+
+func TestArgsRoundTrip(t *testing.T) {
+	limits := Limits{
+		CPUTime:   90 * time.Second,
+		Memory:    512 << 20,
+		Network:   true,
+		ReadPaths: []string{"/srv/rules", "/srv/data"},
+
+		AllowUnsandboxed: true,
+	}
+	args := append(formatArgs(limits), "--", "/usr/bin/rules", "--strict")
+	if args[0] != Arg {
+		t.Fatalf("Expected %s first, got %v", Arg, args)
+	}
+
+	parsed, argv, err := parseArgs(args[1:])
+	if err != nil {
+		t.Fatalf("parseArgs failed: %v", err)
+	}
+	if !reflect.DeepEqual(parsed, limits) {
+		t.Errorf("Expected %+v, got %+v", limits, parsed)
+	}
+	if !reflect.DeepEqual(argv, []string{"/usr/bin/rules", "--strict"}) {
+		t.Errorf("Unexpected command %v", argv)
+	}
+
+	for _, invalid := range [][]string{{"--cpu=fast", "--", "rules"}, {"--network"}, {"--unknown", "--", "rules"}, {"--"}} {
+		if _, _, err := parseArgs(invalid); err == nil {
+			t.Errorf("Expected an error for %v", invalid)
+		}
+	}
+}
+
+func TestFilterEnvironmentDropsCredentials(t *testing.T) {
+	env := filterEnvironment([]string{"PATH=/usr/bin", "GITHUB_TOKEN=secret", "LANG=C.UTF-8", "HOME=/home/dev"})
+	if !reflect.DeepEqual(env, []string{"PATH=/usr/bin", "LANG=C.UTF-8"}) {
+		t.Errorf("Expected only PATH and LANG, got %v", env)
+	}
+}
+
+func TestEnforcementString(t *testing.T) {
+	cases := []struct {
+		enforcement Enforcement
+		want        string
+	}{
+		{Enforcement{Resources: true, Filesystem: true, Network: true}, "resource limits, file isolation, network isolation"},
+		{Enforcement{Resources: true, Filesystem: true, Missing: []string{"network isolation"}}, "resource limits, file isolation (network isolation not enforced)"},
+		{Enforcement{Missing: []string{"file isolation", "network isolation"}}, "no restrictions (file isolation, network isolation not enforced)"},
+	}
+	for _, c := range cases {
+		if got := c.enforcement.String(); got != c.want {
+			t.Errorf("Expected %q, got %q", c.want, got)
+		}
+	}
+}
+
+func TestCheckSkipsAllowedAccess(t *testing.T) {
+	enforcement := Check(Limits{Network: true, Filesystem: true})
+	if enforcement.Filesystem || enforcement.Network || len(enforcement.Missing) > 0 {
+		t.Errorf("Expected nothing to enforce with network and file access allowed, got %+v", enforcement)
+	}
+}
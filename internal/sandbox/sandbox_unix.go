@@ -0,0 +1,43 @@
+//go:build linux || darwin
+
+package sandbox
+
+import (
+	"syscall"
+	"time"
+)
+
+const supported = true
+
+// setResourceLimits caps the CPU time and data size of the process. The
+// kernel sends SIGXCPU when the CPU time runs out and SIGKILL a second later.
+func setResourceLimits(limits Limits) error {
+	if limits.CPUTime > 0 {
+		seconds := uint64((limits.CPUTime + time.Second - 1) / time.Second)
+		if err := lowerLimit(syscall.RLIMIT_CPU, seconds, seconds+1); err != nil {
+			return err
+		}
+	}
+	if limits.Memory > 0 {
+		if err := lowerLimit(syscall.RLIMIT_DATA, uint64(limits.Memory), uint64(limits.Memory)); err != nil {
+			return err
+		}
+	}
+	return nil
+}
+
+// lowerLimit sets a resource limit, keeping any lower limit already in place
+func lowerLimit(resource int, soft, hard uint64) error {
+	var current syscall.Rlimit
+	if err := syscall.Getrlimit(resource, &current); err != nil {
+		return err
+	}
+	limit := syscall.Rlimit{Cur: min(soft, current.Cur), Max: min(hard, current.Max)}
+	limit.Cur = min(limit.Cur, limit.Max)
+	return syscall.Setrlimit(resource, &limit)
+}
+
+// execute replaces the process with a command
+func execute(argv []string, env []string) error {
+	return syscall.Exec(argv[0], argv, env)
+}
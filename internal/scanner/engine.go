@@ -6,11 +6,13 @@ package scanner
 import (
 	"fmt"
 	"runtime"
+	"sort"
 	"sync"
 	"time"
 
 	"github.com/ericfisherdev/goclean/internal/models"
 	"github.com/ericfisherdev/goclean/internal/violations"
+	"github.com/ericfisherdev/goclean/pkg/severity"
 )
 
 // Scanning progress constants
@@ -31,6 +33,9 @@ type Engine struct {
 	workerBufferSize     int
 	rustOptimizer        *RustPerformanceOptimizer
 	enableRustOptimization bool
+	timings              bool // Measure the time each detector takes
+	severityPolicy       severity.Policy
+	policyInputs         severity.Inputs
 }
 
 // NewEngine creates a new scanning engine
@@ -143,6 +148,7 @@ func (e *Engine) Scan() (*models.ScanSummary, []*models.ScanResult, error) {
 
 	// Reset violation detector caches for new scan
 	e.violationDetector.ResetDuplicationCache()
+	defer e.violationDetector.ClosePlugins()
 	
 	// Cleanup expired Rust cache entries if optimization is enabled
 	if e.enableRustOptimization && e.rustOptimizer != nil {
@@ -189,11 +195,20 @@ func (e *Engine) Scan() (*models.ScanSummary, []*models.ScanResult, error) {
 	if err != nil {
 		return nil, nil, fmt.Errorf("file scanning failed: %w", err)
 	}
+	e.violationDetector.DetectClones(results)
+	e.violationDetector.DetectCircularDependencies(results)
+	e.violationDetector.DetectUnusedPublicAPI(results)
+	e.violationDetector.DetectPrimitiveObsession(results)
 
 	endTime := time.Now()
 
 	// Generate summary
 	summary := e.generateSummary(files, results, startTime, endTime)
+	summary.CloneGroups = e.violationDetector.CloneGroups()
+	summary.Coupling = e.violationDetector.Coupling()
+	summary.Maintainability = e.violationDetector.Maintainability()
+	summary.DocCoverage = e.violationDetector.DocCoverage()
+	summary.PrimitiveObsession = e.violationDetector.PrimitiveObsession()
 
 	if e.progressFn != nil {
 		e.progressFn(fmt.Sprintf("Scan completed: %d violations found in %v",
@@ -207,39 +222,93 @@ func (e *Engine) Scan() (*models.ScanSummary, []*models.ScanResult, error) {
 	return summary, results, nil
 }
 
+// Reanalyze detects the violations of results from earlier scans again,
+// reusing their syntax trees instead of parsing the files. The detectors
+// that compare files, such as clone, coupling, unused pub API and
+// documentation coverage detection, see every result together, so
+// rescanning a few changed files and reanalyzing them with the unchanged
+// ones gives the same violations as a full scan. Files that failed to parse
+// are kept without violations.
+func (e *Engine) Reanalyze(results []*models.ScanResult) *models.ScanSummary {
+	startTime := time.Now()
+
+	e.violationDetector.ResetDuplicationCache()
+	defer e.violationDetector.ClosePlugins()
+
+	files := make([]*models.FileInfo, 0, len(results))
+	for _, result := range results {
+		files = append(files, result.File)
+		if result.File.Error != "" {
+			continue
+		}
+		// Suppressions must only count as used by this analysis' violations
+		for _, suppression := range result.Suppressions {
+			suppression.Used = false
+		}
+		e.violationDetector.DetectViolations(result)
+	}
+	e.violationDetector.DetectClones(results)
+	e.violationDetector.DetectCircularDependencies(results)
+	e.violationDetector.DetectUnusedPublicAPI(results)
+	e.violationDetector.DetectPrimitiveObsession(results)
+
+	summary := e.generateSummary(files, results, startTime, time.Now())
+	summary.CloneGroups = e.violationDetector.CloneGroups()
+	summary.Coupling = e.violationDetector.Coupling()
+	summary.Maintainability = e.violationDetector.Maintainability()
+	summary.DocCoverage = e.violationDetector.DocCoverage()
+	summary.PrimitiveObsession = e.violationDetector.PrimitiveObsession()
+	return summary
+}
+
+// scanJob is a file to scan and its position in the discovery order
+type scanJob struct {
+	index int
+	file  *models.FileInfo
+}
+
+// scanOutput is the result of a scan job
+type scanOutput struct {
+	index  int
+	result *models.ScanResult
+}
+
 // scanFiles scans multiple files concurrently and reports progress.
+// Workers pull files from a shared queue, largest first, so a few big files
+// do not leave the other workers idle at the end of the scan. Results are
+// returned in discovery order regardless of which worker finished first.
 func (e *Engine) scanFiles(files []*models.FileInfo) ([]*models.ScanResult, error) {
-	filesChan := make(chan *models.FileInfo, e.workerBufferSize)
-	resultsChan := make(chan *models.ScanResult, e.workerBufferSize)
+	jobsChan := make(chan scanJob, e.workerBufferSize)
+	outputsChan := make(chan scanOutput, e.workerBufferSize)
 	errorsChan := make(chan error, e.workerBufferSize)
 
 	var wg sync.WaitGroup
 	for i := 0; i < e.maxWorkers; i++ {
 		wg.Add(1)
-		go e.worker(&wg, filesChan, resultsChan, errorsChan)
+		go e.worker(&wg, jobsChan, outputsChan, errorsChan)
 	}
 
 	go func() {
-		for _, file := range files {
-			filesChan <- file
+		for _, job := range largestFirst(files) {
+			jobsChan <- job
 		}
-		close(filesChan)
+		close(jobsChan)
 	}()
 
 	go func() {
 		wg.Wait()
-		close(resultsChan)
+		close(outputsChan)
 		close(errorsChan)
 	}()
 
-	var results []*models.ScanResult
+	results := make([]*models.ScanResult, len(files))
 	var errors []error
 	processed := 0
 	total := len(files)
 
 	// Collect results
-	for result := range resultsChan {
-		results = append(results, result)
+	for output := range outputsChan {
+		results[output.index] = output.result
 		processed++
 		if e.progressFn != nil && processed%ProgressUpdateInterval == 0 { // Update every N files
 			percentage := float64(processed) / float64(total) * PercentageMultiplier
@@ -271,11 +340,24 @@ func (e *Engine) scanFiles(files []*models.FileInfo) ([]*models.ScanResult, erro
 	return results, nil
 }
 
+// largestFirst returns the scan jobs for files ordered by decreasing size
+func largestFirst(files []*models.FileInfo) []scanJob {
+	jobs := make([]scanJob, len(files))
+	for i, file := range files {
+		jobs[i] = scanJob{index: i, file: file}
+	}
+	sort.SliceStable(jobs, func(i, j int) bool {
+		return jobs[i].file.Size > jobs[j].file.Size
+	})
+	return jobs
+}
+
 // worker processes files from the input channel.
-func (e *Engine) worker(wg *sync.WaitGroup, filesChan <-chan *models.FileInfo, resultsChan chan<- *models.ScanResult, errorsChan chan<- error) {
+func (e *Engine) worker(wg *sync.WaitGroup, jobsChan <-chan scanJob, outputsChan chan<- scanOutput, errorsChan chan<- error) {
 	defer wg.Done()
 
-	for file := range filesChan {
+	for job := range jobsChan {
+		file := job.file
 		if e.progressFn != nil && e.verbose {
 			e.progressFn(fmt.Sprintf("Scanning %s...", file.Name))
 		}
@@ -294,7 +376,7 @@ func (e *Engine) worker(wg *sync.WaitGroup, filesChan <-chan *models.FileInfo, r
 		}
 		
 		// Always send result first to avoid blocking on the errors channel
-		resultsChan <- result
+		outputsChan <- scanOutput{index: job.index, result: result}
 		
 		// Send error non-blocking (best-effort error reporting)
 		if err != nil {
@@ -335,11 +417,18 @@ func (e *Engine) generateSummary(files []*models.FileInfo, results []*models.Sca
 			summary.TotalViolations++
 			summary.ViolationsByType[string(violation.Type)]++
 		}
+		summary.InlineSuppressed += len(result.Suppressed)
 	}
 	
 	return summary
 }
 
+// SetRustEdition sets the edition Rust files are parsed with; "auto" detects
+// it from each file's Cargo.toml
+func (e *Engine) SetRustEdition(edition string) {
+	e.parser.rustASTAnalyzer.SetEdition(edition)
+}
+
 // SetProgressCallback sets a function to be called for progress updates
 func (e *Engine) SetProgressCallback(fn func(string)) {
 	e.progressFn = fn
@@ -353,5 +442,31 @@ func (e *Engine) EnableRealTimeMode(enabled bool) {
 // SetViolationDetectorConfig sets a custom violation detector configuration
 func (e *Engine) SetViolationDetectorConfig(config *violations.DetectorConfig) {
 	e.violationDetector = NewViolationDetector(config)
+	if e.timings {
+		e.violationDetector.EnableTimings()
+	}
+	e.violationDetector.SetSeverityPolicy(e.severityPolicy, e.policyInputs)
+}
+
+// SetSeverityPolicy makes scans ask policy for the final severity of every
+// violation, after the configured rule severities. inputs tells the policy
+// which files are tests or third-party code and which lines changed. The
+// policy is kept when the detector configuration is replaced.
+func (e *Engine) SetSeverityPolicy(policy severity.Policy, inputs severity.Inputs) {
+	e.severityPolicy = policy
+	e.policyInputs = inputs
+	e.violationDetector.SetSeverityPolicy(policy, inputs)
+}
+
+// EnableTimings makes scans measure the time each detector takes
+func (e *Engine) EnableTimings() {
+	e.timings = true
+	e.violationDetector.EnableTimings()
+}
+
+// DetectorTimings returns the time each detector took, slowest first, when
+// timings are enabled
+func (e *Engine) DetectorTimings() []violations.DetectorTiming {
+	return e.violationDetector.Timings()
 }
 
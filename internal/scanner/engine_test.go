@@ -9,6 +9,8 @@ import (
 	"time"
 
 	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/violations"
+	"github.com/ericfisherdev/goclean/pkg/severity"
 )
 
 func TestNewEngine(t *testing.T) {
@@ -473,4 +475,185 @@ func broken%d( {
 
 	t.Logf("Successfully processed %d files with %d parse errors without deadlock", 
 		len(results), errorCount)
-}
\ No newline at end of file
+}
+func TestScanResultsInDiscoveryOrder(t *testing.T) {
+	tmpDir := t.TempDir()
+
+	// Vary file sizes so that workers finish out of order
+	for i := 0; i < 30; i++ {
+		fileName := filepath.Join(tmpDir, fmt.Sprintf("file%02d.go", i))
+		content := fmt.Sprintf("package main\n\nfunc f%d() {\n", i)
+		for j := 0; j < (i%7)*20; j++ {
+			content += fmt.Sprintf("\tprintln(%d)\n", j)
+		}
+		content += "}\n"
+		if err := os.WriteFile(fileName, []byte(content), 0644); err != nil {
+			t.Fatalf("Failed to create test file %d: %v", i, err)
+		}
+	}
+
+	scan := func(workers int) []*models.ScanResult {
+		engine := NewEngine([]string{tmpDir}, []string{}, []string{".go"}, false)
+		engine.SetMaxWorkers(workers)
+		_, results, err := engine.Scan()
+		if err != nil {
+			t.Fatalf("Scan with %d workers failed: %v", workers, err)
+		}
+		return results
+	}
+
+	sequential := scan(1)
+	parallel := scan(8)
+	if len(sequential) != 30 || len(parallel) != 30 {
+		t.Fatalf("Expected 30 results, got %d and %d", len(sequential), len(parallel))
+	}
+	for i := range sequential {
+		expected := filepath.Join(tmpDir, fmt.Sprintf("file%02d.go", i))
+		if sequential[i].File.Path != expected || parallel[i].File.Path != expected {
+			t.Errorf("Expected result %d to be %s, got %s and %s", i, expected, sequential[i].File.Path, parallel[i].File.Path)
+		}
+	}
+}
+
+// TestScanAppliesSeverityPolicy tests that the engine's severity policy sets
+// the severity of violations of single files and of clones found across
+// files, and outlives a new detector configuration
+func TestScanAppliesSeverityPolicy(t *testing.T) {
+	body := `(name string, email string) error {
+	if len(name) < 2 || len(name) > 50 {
+		return errors.New("invalid name length")
+	}
+	if !strings.Contains(email, "@") {
+		return errors.New("invalid email")
+	}
+	return nil
+}
+`
+	tmpDir := t.TempDir()
+	source := "package payments\n\nfunc checkUser" + body + "\nfunc checkAdmin" + body +
+		"\nfunc mix(a, b, c, d, e, f int) int { return a + b + c + d + e + f }\n"
+	if err := os.WriteFile(filepath.Join(tmpDir, "payments.go"), []byte(source), 0644); err != nil {
+		t.Fatalf("Failed to create test file: %v", err)
+	}
+
+	engine := NewEngine([]string{tmpDir}, []string{}, []string{".go"}, false)
+	engine.SetMaxWorkers(1) // The policy below records contexts without locking
+	contexts := make(map[string]severity.Context)
+	engine.SetSeverityPolicy(severity.PolicyFunc(func(v *severity.Violation, ctx severity.Context) severity.Level {
+		contexts[v.Rule] = ctx
+		if ctx.Changed {
+			return severity.Critical
+		}
+		return v.Severity
+	}), severity.Inputs{
+		Touches: func(path string, start, end int) bool { return true },
+	})
+	config := violations.DefaultDetectorConfig()
+	config.MaxParameters = 4
+	config.MinCloneTokens = 30
+	engine.SetViolationDetectorConfig(config)
+
+	_, results, err := engine.Scan()
+	if err != nil {
+		t.Fatalf("Scan failed: %v", err)
+	}
+	if len(results) != 1 {
+		t.Fatalf("Expected 1 result, got %d", len(results))
+	}
+	found := make(map[string]models.Severity)
+	for _, v := range results[0].Violations {
+		found[v.Rule] = v.Severity
+	}
+	for _, rule := range []string{"parameter-count", "code-clone"} {
+		if level, ok := found[rule]; !ok || level != models.SeverityCritical {
+			t.Errorf("Expected the policy to raise %s to Critical, got %v", rule, found)
+		}
+	}
+	if ctx := contexts["parameter-count"]; ctx.Language != "Go" || !ctx.InDiff || filepath.Base(ctx.File) != "payments.go" {
+		t.Errorf("Expected the policy to see the file and diff context, got %+v", ctx)
+	}
+}
+
+func TestReanalyzeFindsClonesAcrossSeparateScans(t *testing.T) {
+	body := `(name string, email string) error {
+	if len(name) < 2 || len(name) > 50 {
+		return errors.New("invalid name length")
+	}
+	if !strings.Contains(email, "@") {
+		return errors.New("invalid email")
+	}
+	return nil
+}
+`
+	tmpDir := t.TempDir()
+	users := filepath.Join(tmpDir, "users.go")
+	admins := filepath.Join(tmpDir, "admins.go")
+	if err := os.WriteFile(users, []byte("package accounts\n\nfunc checkUser"+body), 0644); err != nil {
+		t.Fatalf("Failed to create test file: %v", err)
+	}
+	if err := os.WriteFile(admins, []byte("package accounts\n\nfunc checkAdmin"+body), 0644); err != nil {
+		t.Fatalf("Failed to create test file: %v", err)
+	}
+	config := violations.DefaultDetectorConfig()
+	config.MinCloneTokens = 30
+
+	// Scan each file on its own, as a watcher rescanning only changed files does
+	var results []*models.ScanResult
+	for _, path := range []string{users, admins} {
+		engine := NewEngine([]string{path}, []string{}, []string{".go"}, false)
+		engine.SetViolationDetectorConfig(config)
+		_, scanned, err := engine.Scan()
+		if err != nil {
+			t.Fatalf("Scan failed: %v", err)
+		}
+		results = append(results, scanned...)
+	}
+	if len(results) != 2 {
+		t.Fatalf("Expected 2 results, got %d", len(results))
+	}
+
+	countClones := func() int {
+		clones := 0
+		for _, result := range results {
+			for _, v := range result.Violations {
+				if v.Rule == "code-clone" {
+					clones++
+				}
+			}
+		}
+		return clones
+	}
+	if clones := countClones(); clones != 0 {
+		t.Fatalf("Expected no clones within either file, got %d", clones)
+	}
+
+	// Reanalyzing twice must not add the clone twice
+	engine := NewEngine([]string{tmpDir}, []string{}, []string{".go"}, false)
+	engine.SetViolationDetectorConfig(config)
+	engine.Reanalyze(results)
+	summary := engine.Reanalyze(results)
+
+	if clones := countClones(); clones != 1 {
+		t.Errorf("Expected 1 clone violation across the files after reanalysis, got %d", clones)
+	}
+	if len(summary.CloneGroups) != 1 || summary.TotalFiles != 2 {
+		t.Errorf("Expected the summary to cover both files and their clone group, got %d files, %d groups",
+			summary.TotalFiles, len(summary.CloneGroups))
+	}
+}
+
+func TestLargestFirst(t *testing.T) {
+	files := []*models.FileInfo{{Path: "a", Size: 10}, {Path: "b", Size: 300}, {Path: "c", Size: 10}, {Path: "d", Size: 50}}
+
+	jobs := largestFirst(files)
+	var order []string
+	for _, job := range jobs {
+		order = append(order, job.file.Path)
+		if files[job.index] != job.file {
+			t.Errorf("Expected job index %d to point at %s", job.index, job.file.Path)
+		}
+	}
+	if fmt.Sprint(order) != "[b d a c]" {
+		t.Errorf("Expected the largest files first, got %v", order)
+	}
+}
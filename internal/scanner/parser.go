@@ -89,6 +89,7 @@ func (p *Parser) parseGoFileWithAST(fileInfo *models.FileInfo) (*models.ScanResu
 		Metrics:    metrics,
 		ASTInfo:    astInfo, // Store AST info for violation detection
 	}
+	result.Suppressions = ParseSuppressions(content, fileInfo.Language)
 
 	if p.verbose && os.Getenv("GOCLEAN_TEST_MODE") == "" {
 		fmt.Fprintf(os.Stderr, "AST parsed %s: %d lines, %d functions, %d types\n",
@@ -131,6 +132,7 @@ func (p *Parser) parseRustFileWithAST(fileInfo *models.FileInfo) (*models.ScanRe
 		Metrics:     metrics,
 		RustASTInfo: rustASTInfo, // Store Rust AST info for violation detection
 	}
+	result.Suppressions = ParseSuppressions(content, fileInfo.Language)
 
 	if p.verbose && os.Getenv("GOCLEAN_TEST_MODE") == "" {
 		fmt.Fprintf(os.Stderr, "Rust AST parsed %s: %d lines, %d functions, %d structs, %d enums\n",
@@ -163,6 +165,7 @@ func (p *Parser) parseFileLineByLine(fileInfo *models.FileInfo, content []byte)
 		Violations: []*models.Violation{}, // Will be populated by violation detectors
 		Metrics:    metrics,
 	}
+	result.Suppressions = ParseSuppressions(content, fileInfo.Language)
 
 	if p.verbose {
 		fmt.Printf("Line parsed %s: %d lines, %d code lines, %d comment lines\n",
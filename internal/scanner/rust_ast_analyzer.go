@@ -2,30 +2,17 @@ package scanner
 
 import (
 	"fmt"
-	"regexp"
 	"strings"
 
 	"github.com/ericfisherdev/goclean/internal/types"
 )
 
-// Pre-compiled regex patterns for Rust AST parsing
-var (
-	fnRegex      = regexp.MustCompile(`^\s*(pub(?:\([^)]*\))?\s+)?(async\s+)?(unsafe\s+)?(const\s+)?fn\s+(\w+)\s*\(`)
-	structRegex  = regexp.MustCompile(`^\s*(pub(?:\([^)]*\))?\s+)?struct\s+(\w+)`)
-	enumRegex    = regexp.MustCompile(`^\s*(pub(?:\([^)]*\))?\s+)?enum\s+(\w+)`)
-	traitRegex   = regexp.MustCompile(`^\s*(pub(?:\([^)]*\))?\s+)?trait\s+(\w+)`)
-	implRegex    = regexp.MustCompile(`^\s*impl\s+(?:(\w+)\s+for\s+)?(\w+)`)
-	moduleRegex  = regexp.MustCompile(`^\s*(pub(?:\([^)]*\))?\s+)?mod\s+(\w+)`)
-	constRegex   = regexp.MustCompile(`^\s*(pub(?:\([^)]*\))?\s+)?const\s+(\w+)\s*:\s*([^=]+)`)
-	useRegex     = regexp.MustCompile(`^\s*(pub(?:\([^)]*\))?\s+)?use\s+([^;]+)`)
-	macroRegex   = regexp.MustCompile(`^\s*macro_rules!\s+(\w+)`)
-)
-
 // RustASTAnalyzer handles Rust AST parsing and analysis
-// Note: This is a simplified implementation that will be enhanced with syn crate integration
 type RustASTAnalyzer struct {
 	verbose   bool
 	optimizer *RustPerformanceOptimizer
+	editions  *RustEditionResolver
+	targets   *RustTargetResolver
 }
 
 // NewRustASTAnalyzer creates a new Rust AST analyzer instance
@@ -33,6 +20,8 @@ func NewRustASTAnalyzer(verbose bool) *RustASTAnalyzer {
 	return &RustASTAnalyzer{
 		verbose:   verbose,
 		optimizer: NewRustPerformanceOptimizer(verbose),
+		editions:  NewRustEditionResolver(RustEditionAuto),
+		targets:   NewRustTargetResolver(),
 	}
 }
 
@@ -41,9 +30,16 @@ func NewRustASTAnalyzerWithOptimizer(verbose bool, optimizer *RustPerformanceOpt
 	return &RustASTAnalyzer{
 		verbose:   verbose,
 		optimizer: optimizer,
+		editions:  NewRustEditionResolver(RustEditionAuto),
+		targets:   NewRustTargetResolver(),
 	}
 }
 
+// SetEdition sets the edition files are parsed with; "auto" detects it from Cargo.toml
+func (a *RustASTAnalyzer) SetEdition(edition string) {
+	a.editions = NewRustEditionResolver(edition)
+}
+
 // AnalyzeRustFile performs AST-based analysis of a Rust source file with automatic fallback
 func (a *RustASTAnalyzer) AnalyzeRustFile(filePath string, content []byte) (*types.RustASTInfo, error) {
 	if a.verbose {
@@ -62,12 +58,30 @@ func (a *RustASTAnalyzer) AnalyzeRustFile(filePath string, content []byte) (*typ
 	}
 
 	// Use the parser manager for automatic fallback handling
+	edition := a.editions.EditionFor(filePath)
 	parserManager := GetGlobalParserManager(a.verbose)
-	astInfo, err := parserManager.ParseRustFile(content, filePath)
+	astInfo, err := parserManager.ParseRustFileForEdition(content, filePath, edition)
 	if err != nil {
 		return nil, fmt.Errorf("Rust parsing failed: %w", err)
 	}
 
+	// The syn bridge reports items only; attach the shared syntax tree, comments and tokens
+	if astInfo.Syntax == nil {
+		syntaxInfo := &types.RustASTInfo{}
+		NewRustSyntaxParserForEdition(content, edition).Parse(syntaxInfo)
+		astInfo.Syntax = syntaxInfo.Syntax
+		astInfo.Comments = syntaxInfo.Comments
+		astInfo.PanicSites = syntaxInfo.PanicSites
+		astInfo.Tokens = syntaxInfo.Tokens
+		astInfo.Edition = syntaxInfo.Edition
+		linkFunctionNodes(astInfo, syntaxInfo)
+	}
+	astInfo.Target, astInfo.TargetName, astInfo.Package = a.targets.Resolve(filePath)
+	if astInfo.CrateName == "" {
+		// Cargo names the crate of a target after it, with hyphens replaced
+		astInfo.CrateName = strings.ReplaceAll(astInfo.TargetName, "-", "_")
+	}
+
 	// Cache the result if optimizer is available
 	if a.optimizer != nil {
 		contentHash := a.optimizer.CalculateContentHash(content)
@@ -100,11 +114,15 @@ func (a *RustASTAnalyzer) parseWithSynCrate(filePath string, content []byte) (*t
 	return astInfo, nil
 }
 
-// parseWithRegexFallback uses regex-based parsing as a fallback when syn crate fails
-func (a *RustASTAnalyzer) parseWithRegexFallback(filePath string, content []byte) (*types.RustASTInfo, error) {
-	source := string(content)
-	lines := strings.Split(source, "\n")
+// parseWithTokenParser parses Rust code without the syn crate when it is unavailable.
+// It uses the token-based syntax parser, which provides accurate spans for
+// multiline signatures, nested items and macro invocations.
+func (a *RustASTAnalyzer) parseWithTokenParser(filePath string, content []byte) (*types.RustASTInfo, error) {
+	return a.parseWithEdition(filePath, content, a.editions.EditionFor(filePath))
+}
 
+// parseWithEdition parses Rust code with the token-based syntax parser for the given edition
+func (a *RustASTAnalyzer) parseWithEdition(filePath string, content []byte, edition types.RustEdition) (*types.RustASTInfo, error) {
 	// Get AST info from pool if optimizer is available
 	var astInfo *types.RustASTInfo
 	if a.optimizer != nil {
@@ -125,318 +143,28 @@ func (a *RustASTAnalyzer) parseWithRegexFallback(filePath string, content []byte
 
 	// Set basic info
 	astInfo.FilePath = filePath
-	astInfo.CrateName = a.extractCrateName(source)
 
-	// Parse file line by line using regex patterns
-	a.parseRustContent(lines, astInfo)
+	// Parse the token stream into a syntax tree; this also fills the item collections
+	NewRustSyntaxParserForEdition(content, edition).Parse(astInfo)
 
 	return astInfo, nil
 }
 
-// parseRustContent parses Rust content using regex patterns
-// This serves as a fallback when syn crate parsing fails
-func (a *RustASTAnalyzer) parseRustContent(lines []string, astInfo *types.RustASTInfo) {
-	for i, line := range lines {
-		lineNum := i + 1
-		trimmed := strings.TrimSpace(line)
-
-		// Skip empty lines and comments
-		if trimmed == "" || strings.HasPrefix(trimmed, "//") || strings.HasPrefix(trimmed, "/*") {
+// linkFunctionNodes attaches syntax nodes to functions reported by another parser, matched by name and line
+func linkFunctionNodes(target, syntaxInfo *types.RustASTInfo) {
+	for _, fn := range target.Functions {
+		if fn.Node != nil {
 			continue
 		}
-
-		// Parse function declarations
-		if funcInfo := a.parseFunctionDeclaration(line, lineNum); funcInfo != nil {
-			astInfo.Functions = append(astInfo.Functions, funcInfo)
-		}
-
-		// Parse struct declarations
-		if structInfo := a.parseStructDeclaration(line, lineNum); structInfo != nil {
-			astInfo.Structs = append(astInfo.Structs, structInfo)
-		}
-
-		// Parse enum declarations
-		if enumInfo := a.parseEnumDeclaration(line, lineNum); enumInfo != nil {
-			astInfo.Enums = append(astInfo.Enums, enumInfo)
-		}
-
-		// Parse trait declarations
-		if traitInfo := a.parseTraitDeclaration(line, lineNum); traitInfo != nil {
-			astInfo.Traits = append(astInfo.Traits, traitInfo)
-		}
-
-		// Parse impl blocks
-		if implInfo := a.parseImplDeclaration(line, lineNum); implInfo != nil {
-			astInfo.Impls = append(astInfo.Impls, implInfo)
-		}
-
-		// Parse module declarations
-		if moduleInfo := a.parseModuleDeclaration(line, lineNum); moduleInfo != nil {
-			astInfo.Modules = append(astInfo.Modules, moduleInfo)
-		}
-
-		// Parse constant declarations
-		if constInfo := a.parseConstantDeclaration(line, lineNum); constInfo != nil {
-			astInfo.Constants = append(astInfo.Constants, constInfo)
-		}
-
-		// Parse use declarations
-		if useInfo := a.parseUseDeclaration(line, lineNum); useInfo != nil {
-			astInfo.Uses = append(astInfo.Uses, useInfo)
-		}
-
-		// Parse macro declarations
-		if macroInfo := a.parseMacroDeclaration(line, lineNum); macroInfo != nil {
-			astInfo.Macros = append(astInfo.Macros, macroInfo)
+		for _, candidate := range syntaxInfo.Functions {
+			if candidate.Name == fn.Name && candidate.StartLine <= fn.StartLine && fn.StartLine <= candidate.EndLine {
+				fn.Node = candidate.Node
+				break
+			}
 		}
 	}
 }
 
-// extractCrateName attempts to extract crate name from the file content
-func (a *RustASTAnalyzer) extractCrateName(content string) string {
-	// For now, return empty string - will be enhanced with proper parsing
-	return ""
-}
-
-// parseFunctionDeclaration parses Rust function declarations
-func (a *RustASTAnalyzer) parseFunctionDeclaration(line string, lineNum int) *types.RustFunctionInfo {
-	// Use pre-compiled regex pattern for Rust function declarations
-	// Matches: [visibility] [async] [unsafe] [const] fn function_name
-	matches := fnRegex.FindStringSubmatch(line)
-	if matches == nil {
-		return nil
-	}
-
-	visibility := strings.TrimSpace(matches[1])
-	if visibility == "" {
-		visibility = "private"
-	}
-
-	return &types.RustFunctionInfo{
-		Name:           matches[5],
-		StartLine:      lineNum,
-		EndLine:        lineNum, // Will be updated when we find the closing brace
-		StartColumn:    strings.Index(line, "fn") + 1,
-		EndColumn:      len(line),
-		Parameters:     []types.RustParameterInfo{}, // TODO: Parse parameters
-		ReturnType:     "",                          // TODO: Parse return type
-		IsPublic:       strings.Contains(visibility, "pub"),
-		IsAsync:        matches[2] != "",
-		IsUnsafe:       matches[3] != "",
-		IsConst:        matches[4] != "",
-		Complexity:     1, // TODO: Calculate actual complexity
-		LineCount:      1, // TODO: Calculate actual line count
-		HasDocComments: false, // TODO: Check for doc comments above
-		Visibility:     visibility,
-	}
-}
-
-// parseStructDeclaration parses Rust struct declarations
-func (a *RustASTAnalyzer) parseStructDeclaration(line string, lineNum int) *types.RustStructInfo {
-	matches := structRegex.FindStringSubmatch(line)
-	if matches == nil {
-		return nil
-	}
-
-	visibility := strings.TrimSpace(matches[1])
-	if visibility == "" {
-		visibility = "private"
-	}
-
-	return &types.RustStructInfo{
-		Name:           matches[2],
-		StartLine:      lineNum,
-		EndLine:        lineNum,
-		StartColumn:    strings.Index(line, "struct") + 1,
-		EndColumn:      len(line),
-		IsPublic:       strings.Contains(visibility, "pub"),
-		FieldCount:     0, // TODO: Count fields
-		Visibility:     visibility,
-		HasDocComments: false, // TODO: Check for doc comments
-	}
-}
-
-// parseEnumDeclaration parses Rust enum declarations
-func (a *RustASTAnalyzer) parseEnumDeclaration(line string, lineNum int) *types.RustEnumInfo {
-	matches := enumRegex.FindStringSubmatch(line)
-	if matches == nil {
-		return nil
-	}
-
-	visibility := strings.TrimSpace(matches[1])
-	if visibility == "" {
-		visibility = "private"
-	}
-
-	return &types.RustEnumInfo{
-		Name:           matches[2],
-		StartLine:      lineNum,
-		EndLine:        lineNum,
-		StartColumn:    strings.Index(line, "enum") + 1,
-		EndColumn:      len(line),
-		IsPublic:       strings.Contains(visibility, "pub"),
-		VariantCount:   0, // TODO: Count variants
-		Visibility:     visibility,
-		HasDocComments: false, // TODO: Check for doc comments
-	}
-}
-
-// parseTraitDeclaration parses Rust trait declarations
-func (a *RustASTAnalyzer) parseTraitDeclaration(line string, lineNum int) *types.RustTraitInfo {
-	matches := traitRegex.FindStringSubmatch(line)
-	if matches == nil {
-		return nil
-	}
-
-	visibility := strings.TrimSpace(matches[1])
-	if visibility == "" {
-		visibility = "private"
-	}
-
-	return &types.RustTraitInfo{
-		Name:           matches[2],
-		StartLine:      lineNum,
-		EndLine:        lineNum,
-		StartColumn:    strings.Index(line, "trait") + 1,
-		EndColumn:      len(line),
-		IsPublic:       strings.Contains(visibility, "pub"),
-		MethodCount:    0, // TODO: Count methods
-		Visibility:     visibility,
-		HasDocComments: false, // TODO: Check for doc comments
-	}
-}
-
-// parseImplDeclaration parses Rust impl blocks
-func (a *RustASTAnalyzer) parseImplDeclaration(line string, lineNum int) *types.RustImplInfo {
-	matches := implRegex.FindStringSubmatch(line)
-	if matches == nil {
-		return nil
-	}
-
-	return &types.RustImplInfo{
-		StartLine:   lineNum,
-		EndLine:     lineNum,
-		StartColumn: strings.Index(line, "impl") + 1,
-		EndColumn:   len(line),
-		TargetType:  matches[2],
-		TraitName:   matches[1], // Empty for inherent impls
-		MethodCount: 0,          // TODO: Count methods
-	}
-}
-
-// parseModuleDeclaration parses Rust module declarations
-func (a *RustASTAnalyzer) parseModuleDeclaration(line string, lineNum int) *types.RustModuleInfo {
-	matches := moduleRegex.FindStringSubmatch(line)
-	if matches == nil {
-		return nil
-	}
-
-	visibility := strings.TrimSpace(matches[1])
-	if visibility == "" {
-		visibility = "private"
-	}
-
-	return &types.RustModuleInfo{
-		Name:           matches[2],
-		StartLine:      lineNum,
-		EndLine:        lineNum,
-		StartColumn:    strings.Index(line, "mod") + 1,
-		EndColumn:      len(line),
-		IsPublic:       strings.Contains(visibility, "pub"),
-		Visibility:     visibility,
-		HasDocComments: false, // TODO: Check for doc comments
-	}
-}
-
-// parseConstantDeclaration parses Rust constant declarations
-func (a *RustASTAnalyzer) parseConstantDeclaration(line string, lineNum int) *types.RustConstantInfo {
-	matches := constRegex.FindStringSubmatch(line)
-	if matches == nil {
-		return nil
-	}
-
-	visibility := strings.TrimSpace(matches[1])
-	if visibility == "" {
-		visibility = "private"
-	}
-
-	return &types.RustConstantInfo{
-		Name:           matches[2],
-		Type:           strings.TrimSpace(matches[3]),
-		Line:           lineNum,
-		Column:         strings.Index(line, "const") + 1,
-		IsPublic:       strings.Contains(visibility, "pub"),
-		Visibility:     visibility,
-		HasDocComments: false, // TODO: Check for doc comments
-	}
-}
-
-// parseUseDeclaration parses Rust use declarations
-func (a *RustASTAnalyzer) parseUseDeclaration(line string, lineNum int) *types.RustUseInfo {
-	matches := useRegex.FindStringSubmatch(line)
-	if matches == nil {
-		return nil
-	}
-
-	visibility := strings.TrimSpace(matches[1])
-	path := strings.TrimSpace(matches[2])
-
-	// Handle aliasing (use path as alias)
-	alias := ""
-	if strings.Contains(path, " as ") {
-		parts := strings.SplitN(path, " as ", 2)
-		path = strings.TrimSpace(parts[0])
-		alias = strings.TrimSpace(parts[1])
-	}
-
-	return &types.RustUseInfo{
-		Path:       path,
-		Alias:      alias,
-		Line:       lineNum,
-		Column:     strings.Index(line, "use") + 1,
-		Visibility: visibility,
-	}
-}
-
-// parseMacroDeclaration parses Rust macro declarations
-func (a *RustASTAnalyzer) parseMacroDeclaration(line string, lineNum int) *types.RustMacroInfo {
-	matches := macroRegex.FindStringSubmatch(line)
-	if matches == nil {
-		return nil
-	}
-
-	return &types.RustMacroInfo{
-		Name:           matches[1],
-		StartLine:      lineNum,
-		EndLine:        lineNum,
-		StartColumn:    strings.Index(line, "macro_rules!") + 1,
-		EndColumn:      len(line),
-		IsPublic:       false, // TODO: Determine visibility
-		MacroType:      "macro_rules!",
-		HasDocComments: false, // TODO: Check for doc comments
-	}
-}
-
-// calculateCyclomaticComplexity calculates cyclomatic complexity for Rust functions
-// This is a simplified version that will be enhanced with proper AST analysis
-func (a *RustASTAnalyzer) calculateCyclomaticComplexity(content string) int {
-	complexity := 1 // Base complexity
-
-	// Count control flow statements
-	patterns := []string{
-		`\bif\b`, `\belse\s+if\b`, `\bwhile\b`, `\bfor\b`, `\bloop\b`,
-		`\bmatch\b`, `\bcontinue\b`, `\bbreak\b`, `\breturn\b`,
-	}
-
-	for _, pattern := range patterns {
-		regex := regexp.MustCompile(pattern)
-		matches := regex.FindAllString(content, -1)
-		complexity += len(matches)
-	}
-
-	return complexity
-}
-
 // GetOptimizer returns the performance optimizer instance
 func (a *RustASTAnalyzer) GetOptimizer() *RustPerformanceOptimizer {
 	return a.optimizer
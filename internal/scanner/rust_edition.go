@@ -0,0 +1,156 @@
+package scanner
+
+import (
+	"bufio"
+	"os"
+	"path/filepath"
+	"strings"
+	"sync"
+
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+// RustEditionAuto detects the edition of each file from its Cargo.toml
+const RustEditionAuto = "auto"
+
+// RustEditionResolver determines the edition Rust files are parsed with:
+// either a configured edition, or the edition of the Cargo package that
+// contains the file
+type RustEditionResolver struct {
+	configured types.RustEdition
+	cache      map[string]types.RustEdition // directory -> edition
+	mutex      sync.Mutex
+}
+
+// NewRustEditionResolver creates a resolver. An empty edition or "auto"
+// enables detection from Cargo.toml.
+func NewRustEditionResolver(edition string) *RustEditionResolver {
+	r := &RustEditionResolver{cache: make(map[string]types.RustEdition)}
+	if edition != "" && edition != RustEditionAuto {
+		r.configured = types.RustEdition(edition)
+	}
+	return r
+}
+
+// EditionFor returns the edition of the Rust file at filePath
+func (r *RustEditionResolver) EditionFor(filePath string) types.RustEdition {
+	if r.configured != "" {
+		return r.configured
+	}
+
+	dir := filepath.Dir(filePath)
+	if abs, err := filepath.Abs(dir); err == nil {
+		dir = abs
+	}
+
+	r.mutex.Lock()
+	defer r.mutex.Unlock()
+	if edition, found := r.cache[dir]; found {
+		return edition
+	}
+	edition := detectRustEdition(dir)
+	r.cache[dir] = edition
+	return edition
+}
+
+// detectRustEdition reads the edition of the package whose Cargo.toml is
+// closest to dir. Packages without an edition key use 2015, as Cargo does;
+// an edition inherited from the workspace is read from the workspace root.
+func detectRustEdition(dir string) types.RustEdition {
+	inheriting := false
+	for {
+		manifest, err := readCargoEditionManifest(filepath.Join(dir, "Cargo.toml"))
+		if err == nil {
+			if !inheriting && manifest.isPackage && !manifest.inheritsEdition {
+				if manifest.edition == "" {
+					return types.RustEdition2015
+				}
+				return manifest.edition
+			}
+			inheriting = inheriting || manifest.inheritsEdition
+			if inheriting && manifest.workspaceEdition != "" {
+				return manifest.workspaceEdition
+			}
+			if inheriting && manifest.isWorkspace {
+				return types.RustEdition2015
+			}
+		}
+
+		parent := filepath.Dir(dir)
+		if parent == dir {
+			break
+		}
+		dir = parent
+	}
+	return types.DefaultRustEdition
+}
+
+// cargoEditionManifest holds the edition-related fields of a Cargo.toml
+type cargoEditionManifest struct {
+	isPackage        bool
+	isWorkspace      bool
+	edition          types.RustEdition // [package] edition
+	inheritsEdition  bool              // edition.workspace = true
+	workspaceEdition types.RustEdition // [workspace.package] edition
+}
+
+// readCargoEditionManifest reads the package and workspace editions of a
+// Cargo.toml with a minimal line-based reader
+func readCargoEditionManifest(path string) (*cargoEditionManifest, error) {
+	file, err := os.Open(path)
+	if err != nil {
+		return nil, err
+	}
+	defer file.Close()
+
+	manifest := &cargoEditionManifest{}
+	section := ""
+	scanner := bufio.NewScanner(file)
+	for scanner.Scan() {
+		line := strings.TrimSpace(scanner.Text())
+		if line == "" || strings.HasPrefix(line, "#") {
+			continue
+		}
+		if strings.HasPrefix(line, "[") {
+			section = line
+			switch {
+			case section == "[package]":
+				manifest.isPackage = true
+			case strings.HasPrefix(section, "[workspace"):
+				manifest.isWorkspace = true
+			}
+			continue
+		}
+
+		key, value, found := strings.Cut(line, "=")
+		if !found {
+			continue
+		}
+		key = strings.TrimSpace(key)
+		value = strings.TrimSpace(value)
+		switch {
+		case section == "[package]" && key == "edition.workspace":
+			manifest.inheritsEdition = strings.HasPrefix(value, "true")
+		case section == "[package]" && key == "edition" && strings.HasPrefix(value, "{"):
+			// edition = { workspace = true }
+			manifest.inheritsEdition = strings.Contains(value, "workspace") && strings.Contains(value, "true")
+		case section == "[package]" && key == "edition":
+			manifest.edition = types.RustEdition(tomlStringValue(value))
+		case section == "[workspace.package]" && key == "edition":
+			manifest.workspaceEdition = types.RustEdition(tomlStringValue(value))
+		}
+	}
+	return manifest, scanner.Err()
+}
+
+// tomlStringValue returns the contents of a quoted TOML string, ignoring a
+// trailing comment
+func tomlStringValue(value string) string {
+	if value == "" || (value[0] != '"' && value[0] != '\'') {
+		return value
+	}
+	if end := strings.IndexByte(value[1:], value[0]); end >= 0 {
+		return value[1 : end+1]
+	}
+	return strings.Trim(value, `"'`)
+}
@@ -0,0 +1,62 @@
+package scanner
+
+import (
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+func writeEditionTestFile(t *testing.T, path, content string) {
+	t.Helper()
+	if err := os.MkdirAll(filepath.Dir(path), 0755); err != nil {
+		t.Fatalf("Failed to create directory: %v", err)
+	}
+	if err := os.WriteFile(path, []byte(content), 0644); err != nil {
+		t.Fatalf("Failed to write %s: %v", path, err)
+	}
+}
+
+// TestRustEditionResolver_DetectsFromCargoToml tests package, default and
+// workspace-inherited editions
+func TestRustEditionResolver_DetectsFromCargoToml(t *testing.T) {
+	root := t.TempDir()
+	writeEditionTestFile(t, filepath.Join(root, "Cargo.toml"), `[workspace]
+members = ["modern", "legacy", "inherited"]
+
+[workspace.package]
+edition = "2024" # shared by members
+`)
+	writeEditionTestFile(t, filepath.Join(root, "modern", "Cargo.toml"), "[package]\nname = \"modern\"\nedition = \"2021\"\n")
+	writeEditionTestFile(t, filepath.Join(root, "legacy", "Cargo.toml"), "[package]\nname = \"legacy\"\n")
+	writeEditionTestFile(t, filepath.Join(root, "inherited", "Cargo.toml"), "[package]\nname = \"inherited\"\nedition.workspace = true\n")
+
+	resolver := NewRustEditionResolver(RustEditionAuto)
+	tests := []struct {
+		file string
+		want types.RustEdition
+	}{
+		{filepath.Join(root, "modern", "src", "lib.rs"), types.RustEdition2021},
+		{filepath.Join(root, "legacy", "src", "main.rs"), types.RustEdition2015},
+		{filepath.Join(root, "inherited", "src", "nested", "mod.rs"), types.RustEdition2024},
+	}
+	for _, tt := range tests {
+		if got := resolver.EditionFor(tt.file); got != tt.want {
+			t.Errorf("EditionFor(%s) = %q, want %q", tt.file, got, tt.want)
+		}
+	}
+}
+
+// TestRustEditionResolver_Configured tests that a configured edition overrides Cargo.toml
+func TestRustEditionResolver_Configured(t *testing.T) {
+	dir := t.TempDir()
+	writeEditionTestFile(t, filepath.Join(dir, "Cargo.toml"), "[package]\nname = \"app\"\nedition = \"2018\"\n")
+
+	if got := NewRustEditionResolver("2024").EditionFor(filepath.Join(dir, "src", "main.rs")); got != types.RustEdition2024 {
+		t.Errorf("Expected the configured edition 2024, got %q", got)
+	}
+	if got := NewRustEditionResolver("").EditionFor(filepath.Join(dir, "src", "main.rs")); got != types.RustEdition2018 {
+		t.Errorf("Expected the detected edition 2018, got %q", got)
+	}
+}
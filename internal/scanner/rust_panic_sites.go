@@ -0,0 +1,223 @@
+package scanner
+
+import (
+	"strings"
+
+	"github.com/ericfisherdev/goclean/internal/rustsyntax"
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+// rustFnBody records the token range of a function body
+type rustFnBody struct {
+	node  *types.RustNode
+	open  int
+	close int
+}
+
+// rustPanicMacros lists macros that panic in release builds; debug_assert! is compiled out
+var rustPanicMacros = map[string]bool{
+	"panic":         true,
+	"unreachable":   true,
+	"todo":          true,
+	"unimplemented": true,
+	"assert":        true,
+	"assert_eq":     true,
+	"assert_ne":     true,
+}
+
+// rustArithmeticOps lists operators that panic on overflow (debug builds or
+// overflow-checks = true) or on division by zero
+var rustArithmeticOps = map[string]bool{
+	"+": true, "-": true, "*": true, "/": true, "%": true,
+	"+=": true, "-=": true, "*=": true, "/=": true, "%=": true,
+}
+
+// rustExpressionKeywords are keywords that cannot end an indexable or arithmetic operand
+var rustExpressionKeywords = map[string]bool{
+	"as": true, "box": true, "break": true, "const": true, "continue": true, "dyn": true,
+	"else": true, "fn": true, "for": true, "if": true, "impl": true, "in": true,
+	"let": true, "loop": true, "match": true, "move": true, "mut": true, "ref": true,
+	"return": true, "static": true, "type": true, "unsafe": true, "where": true,
+	"while": true, "yield": true,
+}
+
+// panicSites finds potential runtime panics inside function bodies. Each site
+// is attributed to its innermost enclosing function.
+func (p *RustSyntaxParser) panicSites() []*types.RustPanicSite {
+	owners := make([]*types.RustNode, len(p.tokens))
+	for _, body := range p.fnBodies {
+		for i := body.open + 1; i < body.close && i < len(owners); i++ {
+			owners[i] = body.node
+		}
+	}
+
+	var sites []*types.RustPanicSite
+	testCode := make(map[*types.RustNode]bool)
+	for i, owner := range owners {
+		if owner == nil {
+			continue
+		}
+		kind, at := p.panicSiteAt(i)
+		if kind == "" {
+			continue
+		}
+		inTest, seen := testCode[owner]
+		if !seen {
+			inTest = isRustTestCode(owner)
+			testCode[owner] = inTest
+		}
+		tok := p.tokens[at]
+		sites = append(sites, &types.RustPanicSite{
+			Kind:     kind,
+			Name:     tok.Text,
+			Function: owner.Name,
+			Line:     tok.Line,
+			Column:   tok.Column,
+			InTest:   inTest,
+		})
+	}
+	return sites
+}
+
+// panicSiteAt classifies the token at i, returning the site kind and the index
+// of the token that names it
+func (p *RustSyntaxParser) panicSiteAt(i int) (string, int) {
+	tok := p.tokens[i]
+	switch {
+	case p.is(i, ".") && p.is(i+1, "unwrap") && p.is(i+2, "("):
+		return types.RustPanicUnwrap, i + 1
+	case p.is(i, ".") && p.is(i+1, "expect") && p.is(i+2, "("):
+		return types.RustPanicExpect, i + 1
+	case tok.Kind == rustsyntax.Ident && rustPanicMacros[tok.Text] && p.is(i+1, "!") &&
+		i+2 < len(p.tokens) && p.tokens[i+2].Kind == rustsyntax.Open && !p.is(i-1, "."):
+		return types.RustPanicMacro, i
+	case p.is(i, "[") && p.isOperandEnd(i-1):
+		closeIdx := p.closeOf(i, len(p.tokens))
+		if !p.hasTopLevel(i+1, closeIdx, "..") && !p.hasTopLevel(i+1, closeIdx, "..=") {
+			return types.RustPanicIndex, i
+		}
+		// The full range `[..]` cannot fail
+		if closeIdx == i+2 {
+			return "", i
+		}
+		return types.RustPanicSlice, i
+	case tok.Kind == rustsyntax.Punct && rustArithmeticOps[tok.Text] && p.isArithmetic(i):
+		return types.RustPanicArithmetic, i
+	}
+	return "", i
+}
+
+// isOperandEnd reports whether the token at i can end a value expression
+func (p *RustSyntaxParser) isOperandEnd(i int) bool {
+	if i < 0 || i >= len(p.tokens) {
+		return false
+	}
+	tok := p.tokens[i]
+	switch tok.Kind {
+	case rustsyntax.Ident:
+		return !rustExpressionKeywords[tok.Text]
+	case rustsyntax.Literal:
+		return true
+	case rustsyntax.Close:
+		return tok.Text == ")" || tok.Text == "]"
+	case rustsyntax.Punct:
+		return tok.Text == "?"
+	}
+	return false
+}
+
+// isOperandStart reports whether the token at i can start a value expression
+func (p *RustSyntaxParser) isOperandStart(i int) bool {
+	if i >= len(p.tokens) {
+		return false
+	}
+	tok := p.tokens[i]
+	switch tok.Kind {
+	case rustsyntax.Ident:
+		return !rustExpressionKeywords[tok.Text]
+	case rustsyntax.Literal:
+		return true
+	case rustsyntax.Open:
+		return tok.Text == "("
+	case rustsyntax.Punct:
+		return tok.Text == "&" || tok.Text == "*" || tok.Text == "-"
+	}
+	return false
+}
+
+// isArithmetic reports whether the operator at i is binary integer arithmetic
+// that can overflow or divide by zero
+func (p *RustSyntaxParser) isArithmetic(i int) bool {
+	if !p.isOperandEnd(i-1) || !p.isOperandStart(i+1) {
+		return false
+	}
+	left, right := p.tokens[i-1], p.tokens[i+1]
+
+	// Constant expressions are checked at compile time; floats and strings do not panic
+	if left.Kind == rustsyntax.Literal && right.Kind == rustsyntax.Literal {
+		return false
+	}
+	if isNonIntegerLiteral(left) || isNonIntegerLiteral(right) {
+		return false
+	}
+
+	// Trait bounds such as `dyn Read + Send` or `T: Clone + 'a`
+	if p.tokens[i].Text == "+" && (p.isTypeName(i-1) || p.isTypeName(i+1)) {
+		return false
+	}
+	return true
+}
+
+// isTypeName reports whether the identifier at i is a CamelCase type used
+// on its own rather than as a path or constructor in an expression
+func (p *RustSyntaxParser) isTypeName(i int) bool {
+	tok := p.tokens[i]
+	if tok.Kind == rustsyntax.Lifetime {
+		return true
+	}
+	if tok.Kind != rustsyntax.Ident || tok.Text[0] < 'A' || tok.Text[0] > 'Z' || strings.ToUpper(tok.Text) == tok.Text {
+		return false
+	}
+	return !(p.is(i+1, "::") || p.is(i+1, "(") || p.is(i+1, "{") || p.is(i+1, ".") || p.is(i-1, "::"))
+}
+
+// hasTopLevel reports whether text occurs in [start, end) outside nested groups
+func (p *RustSyntaxParser) hasTopLevel(start, end int, text string) bool {
+	for j := start; j < end; j++ {
+		if p.tokens[j].Kind == rustsyntax.Open {
+			j = p.closeOf(j, end)
+			continue
+		}
+		if p.is(j, text) {
+			return true
+		}
+	}
+	return false
+}
+
+// isNonIntegerLiteral reports whether tok is a float, string, char or byte literal
+func isNonIntegerLiteral(tok rustsyntax.Token) bool {
+	if tok.Kind != rustsyntax.Literal {
+		return false
+	}
+	text := tok.Text
+	if strings.ContainsAny(text, `"'`) {
+		return true
+	}
+	if strings.HasPrefix(text, "0x") || strings.HasPrefix(text, "0b") || strings.HasPrefix(text, "0o") {
+		return false
+	}
+	return strings.ContainsAny(text, ".eE") || strings.HasSuffix(text, "f32") || strings.HasSuffix(text, "f64")
+}
+
+// isRustTestCode reports whether a function is a test or lives in a #[cfg(test)] item
+func isRustTestCode(fn *types.RustNode) bool {
+	for node := fn; node != nil; node = node.Parent {
+		for _, attr := range node.Attributes {
+			if attr == "test" || strings.HasSuffix(attr, "::test") || strings.HasPrefix(attr, "cfg(test") {
+				return true
+			}
+		}
+	}
+	return false
+}
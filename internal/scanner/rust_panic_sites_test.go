@@ -0,0 +1,71 @@
+package scanner
+
+import (
+	"testing"
+
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+// TestRustPanicSites tests detection of unwraps, indexing, arithmetic and panicking macros
+func TestRustPanicSites(t *testing.T) {
+	source := `fn run(args: Vec<String>, data: &[u8]) -> u32 {
+    let first = args.get(0).unwrap();
+    let n: usize = first.parse().expect("number");
+    let head = &data[..n];
+    let all = &data[..];
+    let x = data[n - 1] as u32;
+    let ratio = 0.5 * 2.0;
+    let bound: Box<dyn Fn() + Send> = Box::new(|| {});
+    if head.is_empty() {
+        unreachable!("checked above");
+    }
+    debug_assert!(all.len() > 0);
+    x + 1
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn checks() {
+        assert_eq!(run(vec![], &[1]), 1);
+    }
+}
+`
+	astInfo := &types.RustASTInfo{}
+	NewRustSyntaxParser([]byte(source)).Parse(astInfo)
+
+	expected := []struct {
+		kind   string
+		line   int
+		inTest bool
+	}{
+		{types.RustPanicUnwrap, 2, false},
+		{types.RustPanicExpect, 3, false},
+		{types.RustPanicSlice, 4, false},
+		{types.RustPanicIndex, 6, false},
+		{types.RustPanicArithmetic, 6, false},
+		{types.RustPanicMacro, 10, false},
+		{types.RustPanicArithmetic, 13, false},
+		{types.RustPanicMacro, 20, true},
+	}
+
+	if len(astInfo.PanicSites) != len(expected) {
+		for _, site := range astInfo.PanicSites {
+			t.Logf("site: %+v", site)
+		}
+		t.Fatalf("Expected %d panic sites, got %d", len(expected), len(astInfo.PanicSites))
+	}
+	for i, want := range expected {
+		site := astInfo.PanicSites[i]
+		if site.Kind != want.kind || site.Line != want.line || site.InTest != want.inTest {
+			t.Errorf("Site %d: expected %s at line %d (test=%v), got %+v", i, want.kind, want.line, want.inTest, site)
+		}
+	}
+
+	if site := astInfo.PanicSites[0]; site.Function != "run" || site.Name != "unwrap" {
+		t.Errorf("Expected unwrap attributed to 'run', got %+v", site)
+	}
+	if site := astInfo.PanicSites[7]; site.Function != "checks" || site.Name != "assert_eq" {
+		t.Errorf("Expected assert_eq attributed to 'checks', got %+v", site)
+	}
+}
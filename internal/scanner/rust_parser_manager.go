@@ -118,7 +118,7 @@ func (m *RustParserManager) tryInitializeRegexParser() bool {
 	
 	// Test regex parser with simple Rust code
 	testCode := `fn test() { println!("hello"); }`
-	_, err := regexAnalyzer.parseWithRegexFallback("test.rs", []byte(testCode))
+	_, err := regexAnalyzer.parseWithTokenParser("test.rs", []byte(testCode))
 	if err != nil {
 		m.recordError(fmt.Errorf("regex parser test failed: %w", err))
 		return false
@@ -180,8 +180,16 @@ struct TestStruct {
 	return true
 }
 
-// ParseRustFile parses a Rust file using the best available parser
+// ParseRustFile parses a Rust file using the best available parser, with the
+// edition detected from the file's Cargo.toml
 func (m *RustParserManager) ParseRustFile(content []byte, filePath string) (*types.RustASTInfo, error) {
+	return m.ParseRustFileForEdition(content, filePath, "")
+}
+
+// ParseRustFileForEdition parses a Rust file using the best available parser.
+// The edition controls how the syntax tree is built when the syn crate is
+// unavailable; an empty edition is detected from the file's Cargo.toml.
+func (m *RustParserManager) ParseRustFileForEdition(content []byte, filePath string, edition types.RustEdition) (*types.RustASTInfo, error) {
 	atomic.AddInt64(&m.attemptCount, 1)
 
     m.mutex.RLock()
@@ -209,11 +217,11 @@ func (m *RustParserManager) ParseRustFile(content []byte, filePath string) (*typ
                 }
                 m.mutex.Unlock()
             }
-            result, err = m.parseWithRegexFallback(content, filePath)
+            result, err = m.parseWithTokenParser(content, filePath, edition)
         }
 
     case ParserTypeRegex:
-        result, err = m.parseWithRegexFallback(content, filePath)
+        result, err = m.parseWithTokenParser(content, filePath, edition)
 
     case ParserTypeFallback:
         return nil, fmt.Errorf("Rust parsing is not available: %s", fallbackReason)
@@ -243,8 +251,8 @@ func (m *RustParserManager) parseWithSyn(content []byte, filePath string) (*type
 	return m.synParser.ParseRustFile(content, filePath)
 }
 
-// parseWithRegexFallback uses the regex-based parser
-func (m *RustParserManager) parseWithRegexFallback(content []byte, filePath string) (*types.RustASTInfo, error) {
+// parseWithTokenParser uses the token-based syntax parser
+func (m *RustParserManager) parseWithTokenParser(content []byte, filePath string, edition types.RustEdition) (*types.RustASTInfo, error) {
 	m.mutex.RLock()
 	defer m.mutex.RUnlock()
 	
@@ -252,7 +260,10 @@ func (m *RustParserManager) parseWithRegexFallback(content []byte, filePath stri
 		return nil, fmt.Errorf("regex analyzer not initialized")
 	}
 
-	return m.regexAnalyzer.parseWithRegexFallback(filePath, content)
+	if edition == "" {
+		return m.regexAnalyzer.parseWithTokenParser(filePath, content)
+	}
+	return m.regexAnalyzer.parseWithEdition(filePath, content, edition)
 }
 
 // ValidateSyntax validates Rust syntax using the best available method
@@ -271,7 +282,7 @@ func (m *RustParserManager) ValidateSyntax(content []byte) (bool, error) {
 
 	case ParserTypeRegex:
 		// Regex parser doesn't have syntax validation, so try basic parsing
-		_, err := m.parseWithRegexFallback(content, "syntax_validation.rs")
+		_, err := m.parseWithTokenParser(content, "syntax_validation.rs", "")
 		return err == nil, nil
 
 	case ParserTypeFallback:
@@ -309,8 +320,8 @@ func (m *RustParserManager) GetCapabilities() *RustParserCapabilities {
 
 	case ParserTypeRegex:
 		capabilities.HasSyntaxValidation = false
-		capabilities.HasExpressionParsing = false
-		capabilities.AccuracyLevel = "basic"
+		capabilities.HasExpressionParsing = true // token-based syntax tree
+		capabilities.AccuracyLevel = "good"
 		capabilities.PerformanceLevel = "good"
 
 	case ParserTypeFallback:
@@ -201,6 +201,11 @@ func (opt *RustPerformanceOptimizer) GetASTInfo() *types.RustASTInfo {
 	astInfo.Constants = astInfo.Constants[:0]
 	astInfo.Uses = astInfo.Uses[:0]
 	astInfo.Macros = astInfo.Macros[:0]
+	astInfo.Syntax = nil
+	astInfo.Comments = nil
+	astInfo.PanicSites = nil
+	astInfo.Tokens = nil
+	astInfo.Edition = ""
 	
 	return astInfo
 }
@@ -0,0 +1,1395 @@
+package scanner
+
+import (
+	"strings"
+
+	"github.com/ericfisherdev/goclean/internal/rustsyntax"
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+// RustSyntaxParser builds a syntax tree for Rust source from a token stream.
+// Unlike the line-oriented regex fallback it understands multiline signatures,
+// macro invocations, nested items and string/comment contents, so every node
+// carries an accurate span.
+type RustSyntaxParser struct {
+	tokens   []rustsyntax.Token
+	comments []*rustsyntax.Comment
+	matching []int        // index of the matching delimiter for every open/close token
+	docAt    map[int]bool // token indexes directly preceded by an outer doc comment
+	fnBodies []rustFnBody // function bodies in source order, outer before nested
+	edition  types.RustEdition
+	astInfo  *types.RustASTInfo
+}
+
+// NewRustSyntaxParser tokenizes the given source and prepares a parser for
+// the default edition
+func NewRustSyntaxParser(content []byte) *RustSyntaxParser {
+	return NewRustSyntaxParserForEdition(content, types.DefaultRustEdition)
+}
+
+// NewRustSyntaxParserForEdition tokenizes the given source and prepares a
+// parser that recognizes the keywords and syntax of the given edition
+func NewRustSyntaxParserForEdition(content []byte, edition types.RustEdition) *RustSyntaxParser {
+	if edition == "" {
+		edition = types.DefaultRustEdition
+	}
+	tokens, comments := rustsyntax.Tokenize(string(content), edition)
+	docAt := make(map[int]bool)
+	for _, c := range comments {
+		if c.IsDoc && !c.IsInner {
+			docAt[c.TokenIndex] = true
+		}
+	}
+	return &RustSyntaxParser{
+		tokens:   tokens,
+		comments: comments,
+		matching: matchRustDelimiters(tokens),
+		docAt:    docAt,
+		edition:  edition,
+	}
+}
+
+// ParseRustSyntax parses Rust source into a syntax tree
+func ParseRustSyntax(content []byte) *types.RustNode {
+	parser := NewRustSyntaxParser(content)
+	return parser.Parse(&types.RustASTInfo{})
+}
+
+// Parse builds the syntax tree and fills the item collections of astInfo
+func (p *RustSyntaxParser) Parse(astInfo *types.RustASTInfo) *types.RustNode {
+	p.astInfo = astInfo
+
+	root := &types.RustNode{Kind: types.RustNodeFile, StartLine: 1, StartColumn: 1}
+	if len(p.tokens) > 0 {
+		last := p.tokens[len(p.tokens)-1]
+		root.EndLine = last.EndLine
+		root.EndColumn = last.EndColumn
+	}
+
+	p.parseItems(0, len(p.tokens), root)
+
+	astInfo.Syntax = root
+	astInfo.Comments = p.commentInfos()
+	astInfo.PanicSites = p.panicSites()
+	astInfo.Tokens = p.tokenInfos()
+	astInfo.Edition = p.edition
+	return root
+}
+
+// matchRustDelimiters pairs every open delimiter with its closing delimiter
+func matchRustDelimiters(tokens []rustsyntax.Token) []int {
+	matching := make([]int, len(tokens))
+	var stack []int
+	for i, tok := range tokens {
+		matching[i] = -1
+		switch tok.Kind {
+		case rustsyntax.Open:
+			stack = append(stack, i)
+		case rustsyntax.Close:
+			if len(stack) > 0 {
+				open := stack[len(stack)-1]
+				stack = stack[:len(stack)-1]
+				matching[open] = i
+				matching[i] = open
+			}
+		}
+	}
+	return matching
+}
+
+// closeOf returns the index of the delimiter closing the group opened at i,
+// clamped to end for unbalanced input
+func (p *RustSyntaxParser) closeOf(i, end int) int {
+	if i < len(p.matching) && p.matching[i] > i && p.matching[i] < end {
+		return p.matching[i]
+	}
+	return end - 1
+}
+
+func (p *RustSyntaxParser) is(i int, text string) bool {
+	return i >= 0 && i < len(p.tokens) && p.tokens[i].Text == text && p.tokens[i].Kind != rustsyntax.Literal
+}
+
+func (p *RustSyntaxParser) isIdent(i int) bool {
+	return i >= 0 && i < len(p.tokens) && p.tokens[i].Kind == rustsyntax.Ident
+}
+
+// pendingItem accumulates the prefix (docs, attributes, visibility, qualifiers) of an item
+type pendingItem struct {
+	start      int
+	attributes []string
+	modifiers  []string
+	visibility string
+	abi        string
+}
+
+// parseItems parses item declarations in the token range [start, end)
+func (p *RustSyntaxParser) parseItems(start, end int, parent *types.RustNode) {
+	var item *pendingItem
+	i := start
+	for i < end {
+		if item == nil {
+			item = &pendingItem{start: i}
+		}
+		tok := p.tokens[i]
+
+		switch {
+		case p.is(i, "#") && p.is(i+1, "!") && p.is(i+2, "["):
+			// Inner attributes apply to the enclosing module, not the next item
+			i = p.closeOf(i+2, end) + 1
+			item = nil
+			continue
+
+		case p.is(i, "#") && p.is(i+1, "["):
+			closeIdx := p.closeOf(i+1, end)
+			item.attributes = append(item.attributes, p.joinTokens(i+2, closeIdx))
+			i = closeIdx + 1
+			continue
+
+		case p.is(i, "pub"):
+			vis := "pub"
+			next := i + 1
+			if p.is(next, "(") {
+				closeIdx := p.closeOf(next, end)
+				vis = "pub(" + p.joinTokens(next+1, closeIdx) + ")"
+				next = closeIdx + 1
+			}
+			item.visibility = vis
+			i = next
+			continue
+
+		case p.isQualifier(i):
+			item.modifiers = append(item.modifiers, tok.Text)
+			i++
+			// extern "C"; a bare extern uses the C ABI
+			if tok.Text == "extern" {
+				item.abi = "C"
+				if i < end && p.tokens[i].Kind == rustsyntax.Literal {
+					item.abi = strings.Trim(p.tokens[i].Text, `"`)
+					i++
+				}
+			}
+			continue
+
+		case p.is(i, "fn"):
+			i = p.parseFunction(i, end, parent, item)
+		case p.is(i, "struct"), p.is(i, "union") && p.isIdent(i+1):
+			i = p.parseStruct(i, end, parent, item)
+		case p.is(i, "enum"):
+			i = p.parseEnum(i, end, parent, item)
+		case p.is(i, "trait"):
+			i = p.parseTrait(i, end, parent, item)
+		case p.is(i, "impl"):
+			i = p.parseImpl(i, end, parent, item)
+		case p.is(i, "mod"):
+			i = p.parseModule(i, end, parent, item)
+		case p.is(i, "use"):
+			i = p.parseUse(i, end, parent, item)
+		case p.is(i, "static"), p.is(i, "const"):
+			i = p.parseConstOrStatic(i, end, parent, item)
+		case p.is(i, "type"):
+			i = p.parseSimpleItem(i, end, parent, item, types.RustNodeTypeAlias)
+		case p.is(i, "{") && item.hasModifier("extern"):
+			i = p.parseExternBlock(i, end, parent, item)
+		case p.is(i, "macro_rules") && p.is(i+1, "!"):
+			i = p.parseMacroRules(i, end, parent, item)
+		case p.isIdent(i) && p.is(i+1, "!") && i+2 < end && p.tokens[i+2].Kind == rustsyntax.Open:
+			i = p.parseMacroCall(i, end, parent)
+			if p.is(i, ";") {
+				i++
+			}
+		case tok.Kind == rustsyntax.Open:
+			// Stray group (e.g. unsupported syntax): skip it as a unit
+			i = p.closeOf(i, end) + 1
+		default:
+			i++
+		}
+		item = nil
+	}
+}
+
+// isQualifier reports whether the token is an item qualifier that precedes the item keyword
+func (p *RustSyntaxParser) isQualifier(i int) bool {
+	switch p.tokens[i].Text {
+	case "async", "unsafe", "extern", "default":
+		return p.tokens[i].Kind == rustsyntax.Ident && !p.is(i+1, "{") || p.tokens[i].Text == "extern"
+	case "const":
+		return p.is(i+1, "fn") || p.is(i+1, "unsafe") || p.is(i+1, "async") || p.is(i+1, "extern")
+	}
+	return false
+}
+
+func (item *pendingItem) hasModifier(modifier string) bool {
+	for _, m := range item.modifiers {
+		if m == modifier {
+			return true
+		}
+	}
+	return false
+}
+
+// newItemNode creates a node for an item whose keyword is at index kw
+func (p *RustSyntaxParser) newItemNode(kind types.RustNodeKind, item *pendingItem, kw int) *types.RustNode {
+	first := p.tokens[kw]
+	if item != nil && item.start < kw {
+		// Start at visibility/qualifiers, but not at attributes
+		for j := item.start; j < kw; j++ {
+			if p.is(j, "pub") || p.isQualifierText(j) {
+				first = p.tokens[j]
+				break
+			}
+		}
+	}
+
+	node := &types.RustNode{
+		Kind:        kind,
+		StartLine:   first.Line,
+		StartColumn: first.Column,
+		EndLine:     p.tokens[kw].EndLine,
+		EndColumn:   p.tokens[kw].EndColumn,
+	}
+	if item != nil {
+		node.HasDoc = p.hasDocComment(item.start, kw)
+		node.Attributes = item.attributes
+		if item.visibility != "" {
+			node.Modifiers = append(node.Modifiers, item.visibility)
+		}
+		node.Modifiers = append(node.Modifiers, item.modifiers...)
+		node.ABI = item.abi
+	}
+	return node
+}
+
+func (p *RustSyntaxParser) isQualifierText(i int) bool {
+	if i < 0 || i >= len(p.tokens) {
+		return false
+	}
+	switch p.tokens[i].Text {
+	case "async", "unsafe", "extern", "default", "const":
+		return p.tokens[i].Kind == rustsyntax.Ident
+	}
+	return false
+}
+
+// finish sets the end of a node to the token at index last
+func (p *RustSyntaxParser) finish(node *types.RustNode, last int) {
+	if last >= 0 && last < len(p.tokens) {
+		node.EndLine = p.tokens[last].EndLine
+		node.EndColumn = p.tokens[last].EndColumn
+	}
+}
+
+// skipGenerics skips a generic parameter list starting at i (if present)
+func (p *RustSyntaxParser) skipGenerics(i, end int) int {
+	if !p.is(i, "<") {
+		return i
+	}
+	depth := 0
+	for i < end {
+		switch {
+		case p.is(i, "<"):
+			depth++
+		case p.is(i, ">"):
+			depth--
+			if depth == 0 {
+				return i + 1
+			}
+		case p.tokens[i].Kind == rustsyntax.Open:
+			i = p.closeOf(i, end)
+		case p.is(i, "{") || p.is(i, ";"):
+			return i
+		}
+		i++
+	}
+	return i
+}
+
+// findBodyOrSemicolon finds the first '{' or ';' at the current nesting level
+func (p *RustSyntaxParser) findBodyOrSemicolon(i, end int) int {
+	for i < end {
+		if p.is(i, "{") || p.is(i, ";") {
+			return i
+		}
+		if p.tokens[i].Kind == rustsyntax.Open {
+			i = p.closeOf(i, end)
+		}
+		i++
+	}
+	return end
+}
+
+// parseFunction parses `fn name<generics>(params) -> ret where ... { body }`
+func (p *RustSyntaxParser) parseFunction(kw, end int, parent *types.RustNode, item *pendingItem) int {
+	node := p.newItemNode(types.RustNodeFunction, item, kw)
+	i := kw + 1
+	if p.isIdent(i) {
+		node.Name = p.tokens[i].Text
+		i++
+	}
+	i = p.skipGenerics(i, end)
+
+	var params []types.RustParameterInfo
+	paramsClose := i
+	if p.is(i, "(") {
+		paramsClose = p.closeOf(i, end)
+		params = p.parseParameters(i+1, paramsClose)
+		i = paramsClose + 1
+	}
+
+	bodyIdx := p.findBodyOrSemicolon(i, end)
+	returnType := ""
+	if p.is(i, "->") {
+		retEnd := bodyIdx
+		for j := i + 1; j < bodyIdx; j++ {
+			if p.is(j, "where") {
+				retEnd = j
+				break
+			}
+		}
+		returnType = p.joinTokens(i+1, retEnd)
+	}
+	node.Text = p.joinTokens(kw, bodyIdx)
+
+	next := bodyIdx + 1
+	if p.is(bodyIdx, "{") {
+		closeIdx := p.closeOf(bodyIdx, end)
+		p.fnBodies = append(p.fnBodies, rustFnBody{node: node, open: bodyIdx, close: closeIdx})
+		p.parseBlock(bodyIdx+1, closeIdx, node)
+		p.finish(node, closeIdx)
+		next = closeIdx + 1
+	} else {
+		p.finish(node, bodyIdx)
+	}
+	parent.AddChild(node)
+
+	visibility := "private"
+	if item != nil && item.visibility != "" {
+		visibility = item.visibility
+	}
+	if p.astInfo != nil {
+		p.astInfo.Functions = append(p.astInfo.Functions, &types.RustFunctionInfo{
+			Name:           node.Name,
+			StartLine:      node.StartLine,
+			EndLine:        node.EndLine,
+			StartColumn:    node.StartColumn,
+			EndColumn:      node.EndColumn,
+			Parameters:     params,
+			ReturnType:     returnType,
+			IsPublic:       strings.HasPrefix(visibility, "pub"),
+			IsAsync:        node.HasModifier("async"),
+			IsUnsafe:       node.HasModifier("unsafe"),
+			IsConst:        node.HasModifier("const"),
+			Complexity:     cyclomaticComplexity(node),
+			LineCount:      node.LineCount(),
+			HasDocComments: node.HasDoc,
+			Visibility:     visibility,
+			Node:           node,
+		})
+	}
+	return next
+}
+
+// parseParameters splits a parameter list into named, typed parameters (excluding self)
+func (p *RustSyntaxParser) parseParameters(start, end int) []types.RustParameterInfo {
+	params := make([]types.RustParameterInfo, 0)
+	for _, part := range p.splitTopLevel(start, end, ",") {
+		if part[0] >= part[1] {
+			continue
+		}
+		colon := -1
+		for j := part[0]; j < part[1]; j++ {
+			if p.is(j, ":") {
+				colon = j
+				break
+			}
+			if p.tokens[j].Kind == rustsyntax.Open {
+				j = p.closeOf(j, part[1])
+			}
+		}
+
+		patternStart := part[0]
+		// Skip parameter attributes
+		for p.is(patternStart, "#") && p.is(patternStart+1, "[") {
+			patternStart = p.closeOf(patternStart+1, part[1]) + 1
+		}
+
+		if colon < 0 {
+			// Receiver without explicit type: self, &self, &mut self, mut self, &'a self
+			continue
+		}
+
+		pattern := p.joinTokens(patternStart, colon)
+		typeText := p.joinTokens(colon+1, part[1])
+		isMutable := p.is(patternStart, "mut")
+		name := strings.TrimSpace(strings.TrimPrefix(pattern, "mut "))
+		if name == "self" {
+			continue
+		}
+		params = append(params, types.RustParameterInfo{
+			Name:      name,
+			Type:      typeText,
+			IsMutable: isMutable,
+			IsRef:     strings.HasPrefix(typeText, "&"),
+		})
+	}
+	return params
+}
+
+// splitTopLevel splits [start, end) on a separator at nesting depth zero
+// (ignoring delimiters and generic angle brackets); returns half-open ranges
+func (p *RustSyntaxParser) splitTopLevel(start, end int, separator string) [][2]int {
+	var parts [][2]int
+	partStart := start
+	angle := 0
+	for i := start; i < end; i++ {
+		switch {
+		case p.tokens[i].Kind == rustsyntax.Open:
+			i = p.closeOf(i, end)
+		case p.is(i, "<"):
+			angle++
+		case p.is(i, ">") && angle > 0:
+			angle--
+		case p.is(i, separator) && angle == 0:
+			parts = append(parts, [2]int{partStart, i})
+			partStart = i + 1
+		}
+	}
+	if partStart < end {
+		parts = append(parts, [2]int{partStart, end})
+	}
+	return parts
+}
+
+// parseStruct parses struct and union declarations
+func (p *RustSyntaxParser) parseStruct(kw, end int, parent *types.RustNode, item *pendingItem) int {
+	kind := types.RustNodeStruct
+	if p.is(kw, "union") {
+		kind = types.RustNodeUnion
+	}
+	node := p.newItemNode(kind, item, kw)
+	i := kw + 1
+	if p.isIdent(i) {
+		node.Name = p.tokens[i].Text
+		i++
+	}
+	i = p.skipGenerics(i, end)
+
+	fieldCount := 0
+	var last int
+	if p.is(i, "(") {
+		closeIdx := p.closeOf(i, end)
+		fieldCount = len(p.splitTopLevel(i+1, closeIdx, ","))
+		last = p.findBodyOrSemicolon(closeIdx+1, end)
+	} else {
+		bodyIdx := p.findBodyOrSemicolon(i, end)
+		last = bodyIdx
+		if p.is(bodyIdx, "{") {
+			closeIdx := p.closeOf(bodyIdx, end)
+			fieldCount = len(p.splitTopLevel(bodyIdx+1, closeIdx, ","))
+			last = closeIdx
+		}
+	}
+	node.Text = p.joinTokens(kw, minInt(i, end))
+	p.finish(node, last)
+	parent.AddChild(node)
+
+	if p.astInfo != nil && kind == types.RustNodeStruct {
+		visibility := item.visibilityOrPrivate()
+		p.astInfo.Structs = append(p.astInfo.Structs, &types.RustStructInfo{
+			Name:           node.Name,
+			StartLine:      node.StartLine,
+			EndLine:        node.EndLine,
+			StartColumn:    node.StartColumn,
+			EndColumn:      node.EndColumn,
+			IsPublic:       strings.HasPrefix(visibility, "pub"),
+			FieldCount:     fieldCount,
+			Visibility:     visibility,
+			HasDocComments: node.HasDoc,
+		})
+	}
+	return last + 1
+}
+
+// parseEnum parses enum declarations and counts variants
+func (p *RustSyntaxParser) parseEnum(kw, end int, parent *types.RustNode, item *pendingItem) int {
+	node := p.newItemNode(types.RustNodeEnum, item, kw)
+	i := kw + 1
+	if p.isIdent(i) {
+		node.Name = p.tokens[i].Text
+		i++
+	}
+	i = p.skipGenerics(i, end)
+	bodyIdx := p.findBodyOrSemicolon(i, end)
+	last := bodyIdx
+	variants := 0
+	if p.is(bodyIdx, "{") {
+		closeIdx := p.closeOf(bodyIdx, end)
+		variants = len(p.splitTopLevel(bodyIdx+1, closeIdx, ","))
+		last = closeIdx
+	}
+	node.Text = p.joinTokens(kw, minInt(i, end))
+	p.finish(node, last)
+	parent.AddChild(node)
+
+	if p.astInfo != nil {
+		visibility := item.visibilityOrPrivate()
+		p.astInfo.Enums = append(p.astInfo.Enums, &types.RustEnumInfo{
+			Name:           node.Name,
+			StartLine:      node.StartLine,
+			EndLine:        node.EndLine,
+			StartColumn:    node.StartColumn,
+			EndColumn:      node.EndColumn,
+			IsPublic:       strings.HasPrefix(visibility, "pub"),
+			VariantCount:   variants,
+			Visibility:     visibility,
+			HasDocComments: node.HasDoc,
+		})
+	}
+	return last + 1
+}
+
+// parseTrait parses trait declarations and their associated items
+func (p *RustSyntaxParser) parseTrait(kw, end int, parent *types.RustNode, item *pendingItem) int {
+	node := p.newItemNode(types.RustNodeTrait, item, kw)
+	i := kw + 1
+	if p.isIdent(i) {
+		node.Name = p.tokens[i].Text
+		i++
+	}
+	bodyIdx := p.findBodyOrSemicolon(i, end)
+	node.Text = p.joinTokens(kw, bodyIdx)
+	last := bodyIdx
+	if p.is(bodyIdx, "{") {
+		closeIdx := p.closeOf(bodyIdx, end)
+		p.parseItems(bodyIdx+1, closeIdx, node)
+		last = closeIdx
+	}
+	p.finish(node, last)
+	parent.AddChild(node)
+
+	if p.astInfo != nil {
+		visibility := item.visibilityOrPrivate()
+		p.astInfo.Traits = append(p.astInfo.Traits, &types.RustTraitInfo{
+			Name:           node.Name,
+			StartLine:      node.StartLine,
+			EndLine:        node.EndLine,
+			StartColumn:    node.StartColumn,
+			EndColumn:      node.EndColumn,
+			IsPublic:       strings.HasPrefix(visibility, "pub"),
+			MethodCount:    countChildren(node, types.RustNodeFunction),
+			Visibility:     visibility,
+			HasDocComments: node.HasDoc,
+		})
+	}
+	return last + 1
+}
+
+// parseImpl parses `impl<T> Trait for Type where ... { items }`
+func (p *RustSyntaxParser) parseImpl(kw, end int, parent *types.RustNode, item *pendingItem) int {
+	node := p.newItemNode(types.RustNodeImpl, item, kw)
+	i := p.skipGenerics(kw+1, end)
+	bodyIdx := p.findBodyOrSemicolon(i, end)
+
+	headerEnd := bodyIdx
+	for j := i; j < bodyIdx; j++ {
+		if p.is(j, "where") {
+			headerEnd = j
+			break
+		}
+	}
+
+	var traitName, targetType string
+	forIdx := -1
+	angle := 0
+	for j := i; j < headerEnd; j++ {
+		switch {
+		case p.is(j, "<"):
+			angle++
+		case p.is(j, ">"):
+			angle--
+		case p.is(j, "for") && angle == 0:
+			forIdx = j
+		}
+		if forIdx >= 0 {
+			break
+		}
+	}
+	if forIdx >= 0 {
+		traitName = p.typePathName(i, forIdx)
+		targetType = p.typePathName(forIdx+1, headerEnd)
+	} else {
+		targetType = p.typePathName(i, headerEnd)
+	}
+	node.Name = targetType
+	node.Text = p.joinTokens(kw, bodyIdx)
+
+	last := bodyIdx
+	if p.is(bodyIdx, "{") {
+		closeIdx := p.closeOf(bodyIdx, end)
+		p.parseItems(bodyIdx+1, closeIdx, node)
+		last = closeIdx
+	}
+	p.finish(node, last)
+	parent.AddChild(node)
+
+	if p.astInfo != nil {
+		p.astInfo.Impls = append(p.astInfo.Impls, &types.RustImplInfo{
+			StartLine:   node.StartLine,
+			EndLine:     node.EndLine,
+			StartColumn: node.StartColumn,
+			EndColumn:   node.EndColumn,
+			TargetType:  targetType,
+			TraitName:   traitName,
+			MethodCount: countChildren(node, types.RustNodeFunction),
+		})
+	}
+	return last + 1
+}
+
+// typePathName returns the path of a type without generic arguments, e.g. "fmt::Display"
+func (p *RustSyntaxParser) typePathName(start, end int) string {
+	var b strings.Builder
+	for j := start; j < end; j++ {
+		tok := p.tokens[j]
+		if p.is(j, "<") {
+			break
+		}
+		if tok.Kind == rustsyntax.Ident && tok.Text != "dyn" && tok.Text != "mut" || p.is(j, "::") {
+			b.WriteString(tok.Text)
+		}
+	}
+	return b.String()
+}
+
+// parseModule parses inline (`mod x { ... }`) and file (`mod x;`) modules
+func (p *RustSyntaxParser) parseModule(kw, end int, parent *types.RustNode, item *pendingItem) int {
+	node := p.newItemNode(types.RustNodeModule, item, kw)
+	if p.isIdent(kw + 1) {
+		node.Name = p.tokens[kw+1].Text
+	}
+	node.Text = "mod " + node.Name
+	bodyIdx := p.findBodyOrSemicolon(kw+1, end)
+	last := bodyIdx
+	if p.is(bodyIdx, "{") {
+		closeIdx := p.closeOf(bodyIdx, end)
+		p.parseItems(bodyIdx+1, closeIdx, node)
+		last = closeIdx
+	}
+	p.finish(node, last)
+	parent.AddChild(node)
+
+	if p.astInfo != nil {
+		visibility := item.visibilityOrPrivate()
+		p.astInfo.Modules = append(p.astInfo.Modules, &types.RustModuleInfo{
+			Name:           node.Name,
+			StartLine:      node.StartLine,
+			EndLine:        node.EndLine,
+			StartColumn:    node.StartColumn,
+			EndColumn:      node.EndColumn,
+			IsPublic:       strings.HasPrefix(visibility, "pub"),
+			Visibility:     visibility,
+			HasDocComments: node.HasDoc,
+		})
+	}
+	return last + 1
+}
+
+// parseUse parses use declarations, including grouped and aliased imports
+func (p *RustSyntaxParser) parseUse(kw, end int, parent *types.RustNode, item *pendingItem) int {
+	node := p.newItemNode(types.RustNodeUse, item, kw)
+	semi := p.findBodyOrSemicolon(kw+1, end)
+	for p.is(semi, "{") {
+		semi = p.findBodyOrSemicolon(p.closeOf(semi, end)+1, end)
+	}
+	path := p.joinTokens(kw+1, semi)
+	alias := ""
+	if idx := strings.LastIndex(path, " as "); idx >= 0 && !strings.Contains(path[idx:], "}") {
+		alias = strings.TrimSpace(path[idx+4:])
+		path = strings.TrimSpace(path[:idx])
+	}
+	node.Name = path
+	node.Text = p.joinTokens(kw, semi)
+	p.finish(node, semi)
+	parent.AddChild(node)
+
+	if p.astInfo != nil {
+		visibility := ""
+		if item != nil {
+			visibility = item.visibility
+		}
+		p.astInfo.Uses = append(p.astInfo.Uses, &types.RustUseInfo{
+			Path:       path,
+			Alias:      alias,
+			Line:       node.StartLine,
+			Column:     node.StartColumn,
+			Visibility: visibility,
+		})
+	}
+	return semi + 1
+}
+
+// parseConstOrStatic parses `const NAME: T = ...;` and `static [mut] NAME: T = ...;`
+func (p *RustSyntaxParser) parseConstOrStatic(kw, end int, parent *types.RustNode, item *pendingItem) int {
+	kind := types.RustNodeConst
+	if p.is(kw, "static") {
+		kind = types.RustNodeStatic
+	}
+	node := p.newItemNode(kind, item, kw)
+	i := kw + 1
+	if p.is(i, "mut") {
+		node.Modifiers = append(node.Modifiers, "mut")
+		i++
+	}
+	if p.isIdent(i) {
+		node.Name = p.tokens[i].Text
+		i++
+	}
+	semi := p.findStatementEnd(i, end)
+	typeText := ""
+	if p.is(i, ":") {
+		typeEnd := semi
+		for j := i + 1; j < semi; j++ {
+			if p.is(j, "=") {
+				typeEnd = j
+				break
+			}
+			if p.tokens[j].Kind == rustsyntax.Open {
+				j = p.closeOf(j, semi)
+			}
+		}
+		typeText = p.joinTokens(i+1, typeEnd)
+	}
+	node.Text = p.joinTokens(kw, semi)
+	// Initializers may contain blocks and closures
+	p.parseBlock(i, semi, node)
+	p.finish(node, semi)
+	parent.AddChild(node)
+
+	if p.astInfo != nil && kind == types.RustNodeConst {
+		visibility := item.visibilityOrPrivate()
+		p.astInfo.Constants = append(p.astInfo.Constants, &types.RustConstantInfo{
+			Name:           node.Name,
+			Type:           typeText,
+			Line:           node.StartLine,
+			Column:         node.StartColumn,
+			IsPublic:       strings.HasPrefix(visibility, "pub"),
+			Visibility:     visibility,
+			HasDocComments: node.HasDoc,
+		})
+	}
+	return semi + 1
+}
+
+// findStatementEnd finds the ';' terminating a statement, skipping nested groups
+func (p *RustSyntaxParser) findStatementEnd(i, end int) int {
+	for i < end {
+		if p.is(i, ";") {
+			return i
+		}
+		if p.tokens[i].Kind == rustsyntax.Open {
+			i = p.closeOf(i, end)
+		}
+		i++
+	}
+	return end - 1
+}
+
+// parseSimpleItem parses items terminated by ';' that need no further structure
+func (p *RustSyntaxParser) parseSimpleItem(kw, end int, parent *types.RustNode, item *pendingItem, kind types.RustNodeKind) int {
+	node := p.newItemNode(kind, item, kw)
+	if p.isIdent(kw + 1) {
+		node.Name = p.tokens[kw+1].Text
+	}
+	semi := p.findStatementEnd(kw+1, end)
+	node.Text = p.joinTokens(kw, semi)
+	p.finish(node, semi)
+	parent.AddChild(node)
+	return semi + 1
+}
+
+// parseExternBlock parses `extern "C" { ... }` foreign item blocks
+func (p *RustSyntaxParser) parseExternBlock(open, end int, parent *types.RustNode, item *pendingItem) int {
+	node := p.newItemNode(types.RustNodeExternBlock, item, open)
+	closeIdx := p.closeOf(open, end)
+	p.parseItems(open+1, closeIdx, node)
+	p.finish(node, closeIdx)
+	parent.AddChild(node)
+	return closeIdx + 1
+}
+
+// parseMacroRules parses `macro_rules! name { ... }`
+func (p *RustSyntaxParser) parseMacroRules(kw, end int, parent *types.RustNode, item *pendingItem) int {
+	node := p.newItemNode(types.RustNodeMacroRules, item, kw)
+	i := kw + 2
+	if p.isIdent(i) {
+		node.Name = p.tokens[i].Text
+		i++
+	}
+	last := i
+	if i < end && p.tokens[i].Kind == rustsyntax.Open {
+		last = p.closeOf(i, end)
+	}
+	if p.is(last+1, ";") {
+		last++
+	}
+	p.finish(node, last)
+	parent.AddChild(node)
+
+	if p.astInfo != nil {
+		p.astInfo.Macros = append(p.astInfo.Macros, &types.RustMacroInfo{
+			Name:           node.Name,
+			StartLine:      node.StartLine,
+			EndLine:        node.EndLine,
+			StartColumn:    node.StartColumn,
+			EndColumn:      node.EndColumn,
+			IsPublic:       item != nil && item.hasMacroExport(),
+			MacroType:      "macro_rules!",
+			HasDocComments: node.HasDoc,
+		})
+	}
+	return last + 1
+}
+
+// parseMacroCall records a macro invocation `path!(...)` as a single node without descending
+func (p *RustSyntaxParser) parseMacroCall(nameIdx, end int, parent *types.RustNode) int {
+	open := nameIdx + 2
+	closeIdx := p.closeOf(open, end)
+	node := &types.RustNode{
+		Kind:        types.RustNodeMacroCall,
+		Name:        p.tokens[nameIdx].Text,
+		Text:        p.joinTokens(nameIdx, minInt(closeIdx+1, end)),
+		StartLine:   p.tokens[nameIdx].Line,
+		StartColumn: p.tokens[nameIdx].Column,
+	}
+	p.finish(node, closeIdx)
+	parent.AddChild(node)
+	return closeIdx + 1
+}
+
+// parseBlock parses statements and expressions in [start, end), recording
+// control flow, nested blocks, closures, macro calls and nested items
+func (p *RustSyntaxParser) parseBlock(start, end int, parent *types.RustNode) {
+	var logical *types.RustNode // current run of logical operators within one expression
+	i := start
+	for i < end {
+		tok := p.tokens[i]
+		switch {
+		case p.isItemStart(i):
+			next := p.itemEnd(i, end)
+			p.parseItems(i, next, parent)
+			i = next
+
+		case p.is(i, "if") && parent.Kind == types.RustNodeMatch && p.isMatchGuard(i, end):
+			// Match guards are part of the arm pattern
+			i++
+
+		case p.is(i, "if") || p.is(i, "while") || p.is(i, "match") || p.is(i, "for"):
+			i = p.parseControlFlow(i, end, parent)
+
+		case p.is(i, "loop") && p.is(i+1, "{"):
+			i = p.parseKeywordBlock(i, i+1, end, parent, types.RustNodeLoop)
+
+		case p.is(i, "unsafe") && p.is(i+1, "{"):
+			i = p.parseKeywordBlock(i, i+1, end, parent, types.RustNodeUnsafe)
+
+		case p.isAsyncClosureStart(i):
+			i = p.parseClosure(i, end, parent)
+
+		case p.isBlockKeyword(i) && p.is(i+1, "{"):
+			i = p.parseKeywordBlock(i, i+1, end, parent, types.RustNodeBlock)
+
+		case p.isBlockKeyword(i) && p.is(i+1, "move") && p.is(i+2, "{"):
+			i = p.parseKeywordBlock(i, i+2, end, parent, types.RustNodeBlock)
+
+		case p.is(i, "let"):
+			i = p.parseLet(i, end, parent)
+
+		case p.is(i, "else") && p.is(i+1, "{"):
+			i = p.parseKeywordBlock(i, i+1, end, parent, types.RustNodeElse)
+
+		case (p.is(i, "|") || p.is(i, "||")) && p.isClosureStart(i), p.is(i, "move") && (p.is(i+1, "|") || p.is(i+1, "||")):
+			i = p.parseClosure(i, end, parent)
+
+		case p.isIdent(i) && p.is(i+1, "!") && i+2 < end && p.tokens[i+2].Kind == rustsyntax.Open:
+			i = p.parseMacroCall(i, end, parent)
+
+		case p.is(i, "=>") && parent.Kind == types.RustNodeMatch:
+			logical = nil
+			parent.AddChild(&types.RustNode{
+				Kind:        types.RustNodeMatchArm,
+				Name:        "=>",
+				StartLine:   tok.Line,
+				StartColumn: tok.Column,
+				EndLine:     tok.EndLine,
+				EndColumn:   tok.EndColumn,
+			})
+			i++
+
+		case p.is(i, "&&") || p.is(i, "||"):
+			if logical != nil {
+				logical.Name += " " + p.tokens[i].Text
+				p.finish(logical, i)
+			} else {
+				logical = p.leafNode(types.RustNodeLogicalOp, i)
+				parent.AddChild(logical)
+			}
+			i++
+
+		case p.is(i, ";") || p.is(i, ",") || p.is(i, "=") || p.is(i, "=>"):
+			// Expression boundary: a following operator starts a new sequence
+			logical = nil
+			i++
+
+		case p.is(i, "?"):
+			parent.AddChild(p.leafNode(types.RustNodeTry, i))
+			i++
+
+		case p.is(i, ".") && p.is(i+1, "await"):
+			parent.AddChild(p.leafNode(types.RustNodeAwait, i+1))
+			i += 2
+
+		case p.is(i, "{"):
+			closeIdx := p.closeOf(i, end)
+			block := &types.RustNode{Kind: types.RustNodeBlock, StartLine: tok.Line, StartColumn: tok.Column}
+			p.parseBlock(i+1, closeIdx, block)
+			p.finish(block, closeIdx)
+			parent.AddChild(block)
+			i = closeIdx + 1
+
+		case tok.Kind == rustsyntax.Open:
+			// Parentheses and brackets: descend without creating a node
+			closeIdx := p.closeOf(i, end)
+			p.parseBlock(i+1, closeIdx, parent)
+			i = closeIdx + 1
+
+		default:
+			i++
+		}
+	}
+}
+
+func (p *RustSyntaxParser) leafNode(kind types.RustNodeKind, i int) *types.RustNode {
+	tok := p.tokens[i]
+	return &types.RustNode{
+		Kind:        kind,
+		Name:        tok.Text,
+		StartLine:   tok.Line,
+		StartColumn: tok.Column,
+		EndLine:     tok.EndLine,
+		EndColumn:   tok.EndColumn,
+	}
+}
+
+// isItemStart reports whether an item declaration starts at i inside a block
+func (p *RustSyntaxParser) isItemStart(i int) bool {
+	switch {
+	case p.is(i, "fn") && p.isIdent(i+1):
+		return true
+	case p.isQualifierText(i) && (p.is(i+1, "fn") || p.is(i+1, "impl") || p.is(i+1, "trait")):
+		return true
+	case p.isQualifierText(i) && p.isQualifierText(i+1) && p.is(i+2, "fn"):
+		return true
+	case p.is(i, "struct") || p.is(i, "enum") || p.is(i, "trait") || p.is(i, "impl") || p.is(i, "mod"):
+		return p.isIdent(i+1) || p.is(i+1, "<")
+	case p.is(i, "use") || p.is(i, "static"):
+		return true
+	case p.is(i, "const") && p.isIdent(i+1) && p.is(i+2, ":"):
+		return true
+	case p.is(i, "macro_rules") && p.is(i+1, "!"):
+		return true
+	case p.is(i, "#") && p.is(i+1, "["):
+		return true
+	case p.is(i, "pub"):
+		return true
+	}
+	return false
+}
+
+// itemEnd returns the index after the item starting at i
+func (p *RustSyntaxParser) itemEnd(i, end int) int {
+	for j := i; j < end; j++ {
+		if p.is(j, ";") {
+			return j + 1
+		}
+		if p.is(j, "{") {
+			closeIdx := p.closeOf(j, end)
+			// Structs, enums and unit-like declarations followed by ';' are handled by the ';' case
+			if p.is(closeIdx+1, ";") && closeIdx+1 < end {
+				return closeIdx + 2
+			}
+			return closeIdx + 1
+		}
+		if p.tokens[j].Kind == rustsyntax.Open {
+			j = p.closeOf(j, end)
+		}
+	}
+	return end
+}
+
+// rustControlFlowKinds maps control flow keywords to their node kinds
+var rustControlFlowKinds = map[string]types.RustNodeKind{
+	"if":    types.RustNodeIf,
+	"while": types.RustNodeWhile,
+	"match": types.RustNodeMatch,
+	"for":   types.RustNodeFor,
+}
+
+// parseControlFlow parses if/while/match/for with their condition and body
+func (p *RustSyntaxParser) parseControlFlow(kw, end int, parent *types.RustNode) int {
+	tok := p.tokens[kw]
+	node := &types.RustNode{
+		Kind:        rustControlFlowKinds[tok.Text],
+		Name:        tok.Text,
+		StartLine:   tok.Line,
+		StartColumn: tok.Column,
+	}
+
+	// The body is the first '{' not nested in a group (struct literals are not allowed in conditions)
+	bodyIdx := kw + 1
+	for bodyIdx < end && !p.is(bodyIdx, "{") {
+		if p.tokens[bodyIdx].Kind == rustsyntax.Open {
+			bodyIdx = p.closeOf(bodyIdx, end)
+		}
+		bodyIdx++
+	}
+	node.Text = p.joinTokens(kw, bodyIdx)
+	// Conditions can contain closures, macro calls and logical operators
+	p.parseBlock(kw+1, bodyIdx, node)
+
+	if bodyIdx >= end {
+		p.finish(node, end-1)
+		parent.AddChild(node)
+		return end
+	}
+
+	closeIdx := p.closeOf(bodyIdx, end)
+	if node.Kind == types.RustNodeMatch {
+		p.parseBlock(bodyIdx+1, closeIdx, node)
+	} else {
+		body := &types.RustNode{Kind: types.RustNodeBlock, StartLine: p.tokens[bodyIdx].Line, StartColumn: p.tokens[bodyIdx].Column}
+		p.parseBlock(bodyIdx+1, closeIdx, body)
+		p.finish(body, closeIdx)
+		node.AddChild(body)
+	}
+	p.finish(node, closeIdx)
+	parent.AddChild(node)
+	next := closeIdx + 1
+
+	// else / else if chains belong to the if node
+	if node.Kind == types.RustNodeIf && p.is(next, "else") {
+		if p.is(next+1, "if") {
+			next = p.parseControlFlow(next+1, end, node)
+		} else if p.is(next+1, "{") {
+			next = p.parseKeywordBlock(next, next+1, end, node, types.RustNodeElse)
+		}
+		last := node.Children[len(node.Children)-1]
+		node.EndLine, node.EndColumn = last.EndLine, last.EndColumn
+	}
+	return next
+}
+
+// isMatchGuard reports whether the `if` at i guards a match arm, i.e. reaches
+// `=>` before any block
+func (p *RustSyntaxParser) isMatchGuard(i, end int) bool {
+	for j := i + 1; j < end; j++ {
+		switch {
+		case p.is(j, "=>"):
+			return true
+		case p.is(j, "{") || p.is(j, ","):
+			return false
+		case p.tokens[j].Kind == rustsyntax.Open:
+			j = p.closeOf(j, end)
+		}
+	}
+	return false
+}
+
+// parseKeywordBlock parses `keyword { ... }` forms such as loop, unsafe and else
+func (p *RustSyntaxParser) parseKeywordBlock(kw, open, end int, parent *types.RustNode, kind types.RustNodeKind) int {
+	tok := p.tokens[kw]
+	node := &types.RustNode{
+		Kind:        kind,
+		Name:        tok.Text,
+		StartLine:   tok.Line,
+		StartColumn: tok.Column,
+	}
+	closeIdx := p.closeOf(open, end)
+	p.parseBlock(open+1, closeIdx, node)
+	p.finish(node, closeIdx)
+	parent.AddChild(node)
+	return closeIdx + 1
+}
+
+// isBlockKeyword reports whether the token introduces a block expression such
+// as `const { }`, `async { }` (2018+) or a `gen { }` generator block (2024+)
+func (p *RustSyntaxParser) isBlockKeyword(i int) bool {
+	switch {
+	case p.is(i, "const"):
+		return true
+	case p.is(i, "async"):
+		return p.edition.AtLeast(types.RustEdition2018)
+	case p.is(i, "gen"):
+		return p.edition.AtLeast(types.RustEdition2024)
+	}
+	return false
+}
+
+// isAsyncClosureStart reports whether an `async [move] |params|` closure starts at i
+func (p *RustSyntaxParser) isAsyncClosureStart(i int) bool {
+	if !p.is(i, "async") || !p.edition.AtLeast(types.RustEdition2018) {
+		return false
+	}
+	next := i + 1
+	if p.is(next, "move") {
+		next++
+	}
+	return p.is(next, "|") || p.is(next, "||")
+}
+
+// parseLet parses a let statement. A let-else statement gets a LetElse node
+// for its diverging block; the initializer is parsed like any expression.
+func (p *RustSyntaxParser) parseLet(kw, end int, parent *types.RustNode) int {
+	elseIdx := -1
+	for j := kw + 1; j < end && !p.is(j, ";"); j++ {
+		// An initializer ending in `}` cannot be followed by let-else, so that
+		// `else` belongs to an if expression
+		if p.is(j, "else") && p.is(j+1, "{") && !p.is(j-1, "}") {
+			elseIdx = j
+			break
+		}
+		if p.tokens[j].Kind == rustsyntax.Open {
+			j = p.closeOf(j, end)
+		}
+	}
+	if elseIdx < 0 {
+		return kw + 1
+	}
+
+	p.parseBlock(kw+1, elseIdx, parent)
+	return p.parseKeywordBlock(elseIdx, elseIdx+1, end, parent, types.RustNodeLetElse)
+}
+
+// isClosureStart distinguishes `||` closures from the logical-or operator
+func (p *RustSyntaxParser) isClosureStart(i int) bool {
+	if i == 0 {
+		return true
+	}
+	prev := p.tokens[i-1]
+	if prev.Kind == rustsyntax.Ident {
+		switch prev.Text {
+		case "return", "move", "in", "else":
+			return true
+		}
+		return false
+	}
+	if prev.Kind == rustsyntax.Literal || prev.Kind == rustsyntax.Close || prev.Kind == rustsyntax.Lifetime || prev.Text == "?" {
+		return false
+	}
+	return true
+}
+
+// parseClosure parses `[async] [move] |params| body`
+func (p *RustSyntaxParser) parseClosure(i, end int, parent *types.RustNode) int {
+	tok := p.tokens[i]
+	node := &types.RustNode{Kind: types.RustNodeClosure, StartLine: tok.Line, StartColumn: tok.Column}
+	if p.is(i, "async") {
+		node.Modifiers = append(node.Modifiers, "async")
+		i++
+	}
+	if p.is(i, "move") {
+		node.Modifiers = append(node.Modifiers, "move")
+		i++
+	}
+
+	// Parameter list
+	if p.is(i, "||") {
+		i++
+	} else {
+		i++
+		for i < end && !p.is(i, "|") {
+			if p.tokens[i].Kind == rustsyntax.Open {
+				i = p.closeOf(i, end)
+			}
+			i++
+		}
+		i++
+	}
+	if p.is(i, "->") {
+		for i < end && !p.is(i, "{") {
+			i++
+		}
+	}
+
+	// Body: a block or an expression up to ',' ';' or the end of the enclosing group
+	bodyEnd := i
+	if p.is(i, "{") {
+		bodyEnd = p.closeOf(i, end) + 1
+	} else {
+		for bodyEnd < end && !p.is(bodyEnd, ",") && !p.is(bodyEnd, ";") {
+			if p.tokens[bodyEnd].Kind == rustsyntax.Open {
+				bodyEnd = p.closeOf(bodyEnd, end)
+			}
+			bodyEnd++
+		}
+	}
+	if p.is(i, "{") {
+		p.parseBlock(i+1, bodyEnd-1, node)
+	} else {
+		p.parseBlock(i, bodyEnd, node)
+	}
+	p.finish(node, maxInt(bodyEnd-1, i))
+	parent.AddChild(node)
+	return maxInt(bodyEnd, i+1)
+}
+
+// cyclomaticComplexity computes McCabe complexity from the function's syntax nodes
+func cyclomaticComplexity(fn *types.RustNode) int {
+	complexity := 1
+	types.InspectRust(fn, func(n *types.RustNode) bool {
+		if n != fn && (n.Kind == types.RustNodeFunction || n.Kind == types.RustNodeClosure) {
+			return false // nested functions and closures are measured separately
+		}
+		switch n.Kind {
+		case types.RustNodeIf, types.RustNodeWhile, types.RustNodeFor, types.RustNodeLoop, types.RustNodeLetElse:
+			complexity++
+		case types.RustNodeLogicalOp:
+			complexity += len(strings.Fields(n.Name))
+		case types.RustNodeMatch:
+			arms := countChildren(n, types.RustNodeMatchArm)
+			if arms > 1 {
+				complexity += arms - 1
+			}
+		}
+		return true
+	})
+	return complexity
+}
+
+// hasDocComment reports whether an outer doc comment precedes any token in [start, kw],
+// so docs placed before or after attributes are both recognized
+func (p *RustSyntaxParser) hasDocComment(start, kw int) bool {
+	for i := start; i <= kw; i++ {
+		if p.docAt[i] {
+			return true
+		}
+	}
+	return false
+}
+
+// commentInfos converts captured comments to the shared comment representation
+func (p *RustSyntaxParser) commentInfos() []*types.RustCommentInfo {
+	infos := make([]*types.RustCommentInfo, 0, len(p.comments))
+	for _, c := range p.comments {
+		infos = append(infos, &types.RustCommentInfo{
+			Text:    c.Text,
+			Line:    c.Line,
+			EndLine: c.EndLine,
+			Column:  c.Column,
+			IsDoc:   c.IsDoc,
+			IsBlock: c.IsBlock,
+		})
+	}
+	return infos
+}
+
+// tokenInfos exports the token stream with keywords told apart from identifiers
+func (p *RustSyntaxParser) tokenInfos() []types.RustTokenInfo {
+	infos := make([]types.RustTokenInfo, 0, len(p.tokens))
+	for _, tok := range p.tokens {
+		kind := types.RustTokenPunct
+		switch tok.Kind {
+		case rustsyntax.Ident:
+			kind = types.RustTokenIdent
+			if rustsyntax.IsKeyword(tok.Text, p.edition) {
+				kind = types.RustTokenKeyword
+			}
+		case rustsyntax.Lifetime:
+			kind = types.RustTokenLifetime
+		case rustsyntax.Literal:
+			kind = types.RustTokenLiteral
+		}
+		infos = append(infos, types.RustTokenInfo{
+			Kind:   kind,
+			Text:   tok.Text,
+			Line:   tok.Line,
+			Column: tok.Column,
+		})
+	}
+	return infos
+}
+
+// joinTokens renders tokens [start, end) as compact source text
+func (p *RustSyntaxParser) joinTokens(start, end int) string {
+	var b strings.Builder
+	for j := start; j < end && j < len(p.tokens); j++ {
+		tok := p.tokens[j]
+		if j > start && needsSpace(p.tokens[j-1], tok) {
+			b.WriteByte(' ')
+		}
+		b.WriteString(tok.Text)
+	}
+	return b.String()
+}
+
+// needsSpace decides whether two adjacent tokens are separated by a space
+func needsSpace(prev, next rustsyntax.Token) bool {
+	wordLike := func(t rustsyntax.Token) bool {
+		return t.Kind == rustsyntax.Ident || t.Kind == rustsyntax.Literal || t.Kind == rustsyntax.Lifetime
+	}
+	if wordLike(prev) && wordLike(next) {
+		return true
+	}
+	switch next.Text {
+	case "->", "=>", "=", "+", "as", "in":
+		return true
+	}
+	switch prev.Text {
+	case "->", "=>", "=", "+", ",", ":":
+		return prev.Text != ":" || next.Text != ":"
+	}
+	return false
+}
+
+func (item *pendingItem) visibilityOrPrivate() string {
+	if item == nil || item.visibility == "" {
+		return "private"
+	}
+	return item.visibility
+}
+
+func (item *pendingItem) hasMacroExport() bool {
+	if item == nil {
+		return false
+	}
+	for _, attr := range item.attributes {
+		if attr == "macro_export" || strings.HasPrefix(attr, "macro_export(") {
+			return true
+		}
+	}
+	return false
+}
+
+// countChildren counts direct children of the given kind
+func countChildren(node *types.RustNode, kind types.RustNodeKind) int {
+	count := 0
+	for _, child := range node.Children {
+		if child.Kind == kind {
+			count++
+		}
+	}
+	return count
+}
+
+func minInt(a, b int) int {
+	if a < b {
+		return a
+	}
+	return b
+}
+
+func maxInt(a, b int) int {
+	if a > b {
+		return a
+	}
+	return b
+}
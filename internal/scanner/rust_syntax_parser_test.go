@@ -0,0 +1,252 @@
+package scanner
+
+import (
+	"testing"
+
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+// TestRustSyntaxParser_MultilineFunction tests spans, parameters and complexity of a multiline signature
+func TestRustSyntaxParser_MultilineFunction(t *testing.T) {
+	source := `/// Processes items
+pub async fn process<T: Clone>(
+    &self,
+    items: Vec<T>,
+    mut limit: usize,
+) -> Result<Vec<T>, String> {
+    if items.is_empty() && limit > 0 {
+        return Err("empty".to_string());
+    }
+    for item in items.iter() {
+        match item {
+            Some(x) if x > 0 => println!("{}", x),
+            _ => {}
+        }
+    }
+    Ok(items)
+}
+`
+	astInfo := &types.RustASTInfo{}
+	NewRustSyntaxParser([]byte(source)).Parse(astInfo)
+
+	if len(astInfo.Functions) != 1 {
+		t.Fatalf("Expected 1 function, got %d", len(astInfo.Functions))
+	}
+
+	fn := astInfo.Functions[0]
+	if fn.Name != "process" {
+		t.Errorf("Expected function name 'process', got %q", fn.Name)
+	}
+	if fn.StartLine != 2 || fn.EndLine != 17 {
+		t.Errorf("Expected span 2-17, got %d-%d", fn.StartLine, fn.EndLine)
+	}
+	if fn.LineCount != 16 {
+		t.Errorf("Expected 16 lines, got %d", fn.LineCount)
+	}
+	if !fn.IsPublic || !fn.IsAsync || !fn.HasDocComments {
+		t.Errorf("Expected public async documented function, got public=%v async=%v doc=%v",
+			fn.IsPublic, fn.IsAsync, fn.HasDocComments)
+	}
+	if fn.ReturnType != "Result<Vec<T>, String>" {
+		t.Errorf("Unexpected return type %q", fn.ReturnType)
+	}
+
+	if len(fn.Parameters) != 2 {
+		t.Fatalf("Expected 2 parameters (self excluded), got %d", len(fn.Parameters))
+	}
+	if fn.Parameters[0].Name != "items" || fn.Parameters[0].Type != "Vec<T>" {
+		t.Errorf("Unexpected first parameter %+v", fn.Parameters[0])
+	}
+	if fn.Parameters[1].Name != "limit" || !fn.Parameters[1].IsMutable {
+		t.Errorf("Unexpected second parameter %+v", fn.Parameters[1])
+	}
+
+	// 1 + if + && + for + (2 match arms - 1)
+	if fn.Complexity != 5 {
+		t.Errorf("Expected complexity 5, got %d", fn.Complexity)
+	}
+	if fn.Node == nil || fn.Node.Kind != types.RustNodeFunction {
+		t.Error("Expected function to reference its syntax node")
+	}
+}
+
+// TestRustSyntaxParser_Items tests structs, enums, impls, modules and uses
+func TestRustSyntaxParser_Items(t *testing.T) {
+	source := `use std::collections::{HashMap, HashSet};
+
+#[derive(Debug)]
+pub struct Config {
+    name: String,
+    values: HashMap<String, Vec<u8>>,
+}
+
+pub enum State { Idle, Running(u32), Done { code: i32 } }
+
+impl fmt::Display for Config {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{{ name: {} }}", self.name)
+    }
+}
+
+mod tests {
+    fn helper() -> &'static str { "fn fake() {" }
+}
+`
+	astInfo := &types.RustASTInfo{}
+	root := NewRustSyntaxParser([]byte(source)).Parse(astInfo)
+
+	if len(astInfo.Uses) != 1 || astInfo.Uses[0].Path != "std::collections::{HashMap, HashSet}" {
+		t.Errorf("Unexpected uses: %+v", astInfo.Uses)
+	}
+
+	if len(astInfo.Structs) != 1 || astInfo.Structs[0].FieldCount != 2 {
+		t.Errorf("Expected 1 struct with 2 fields, got %+v", astInfo.Structs)
+	}
+	if len(astInfo.Structs) == 1 && (astInfo.Structs[0].StartLine != 4 || astInfo.Structs[0].EndLine != 7) {
+		t.Errorf("Expected struct span 4-7, got %d-%d", astInfo.Structs[0].StartLine, astInfo.Structs[0].EndLine)
+	}
+
+	if len(astInfo.Enums) != 1 || astInfo.Enums[0].VariantCount != 3 {
+		t.Errorf("Expected 1 enum with 3 variants, got %+v", astInfo.Enums)
+	}
+
+	if len(astInfo.Impls) != 1 {
+		t.Fatalf("Expected 1 impl, got %d", len(astInfo.Impls))
+	}
+	impl := astInfo.Impls[0]
+	if impl.TraitName != "fmt::Display" || impl.TargetType != "Config" || impl.MethodCount != 1 {
+		t.Errorf("Unexpected impl %+v", impl)
+	}
+
+	if len(astInfo.Functions) != 2 {
+		t.Errorf("Expected 2 functions (string contents ignored), got %d", len(astInfo.Functions))
+	}
+	if len(astInfo.Modules) != 1 || astInfo.Modules[0].Name != "tests" {
+		t.Errorf("Unexpected modules %+v", astInfo.Modules)
+	}
+
+	structs := types.FindRustNodes(root, types.RustNodeStruct)
+	if len(structs) != 1 || !structs[0].HasAttribute("derive") {
+		t.Error("Expected struct node to carry its derive attribute")
+	}
+
+	macros := types.FindRustNodes(root, types.RustNodeMacroCall)
+	if len(macros) != 1 || macros[0].Name != "write" {
+		t.Errorf("Expected one write! macro call, got %d", len(macros))
+	}
+	if macros[0].EnclosingItem(types.RustNodeImpl) == nil {
+		t.Error("Expected macro call to be nested inside the impl")
+	}
+}
+
+// TestRustSyntaxParser_UnsafeClosuresAndComments tests expression nodes and comment capture
+func TestRustSyntaxParser_UnsafeClosuresAndComments(t *testing.T) {
+	source := `// SAFETY: caller guarantees ptr is valid
+fn read(ptr: *const u8) -> u8 {
+    let f = |x: u8| x + 1;
+    unsafe { f(*ptr) }
+}
+`
+	astInfo := &types.RustASTInfo{}
+	root := NewRustSyntaxParser([]byte(source)).Parse(astInfo)
+
+	if got := len(types.FindRustNodes(root, types.RustNodeUnsafe)); got != 1 {
+		t.Errorf("Expected 1 unsafe block, got %d", got)
+	}
+	if got := len(types.FindRustNodes(root, types.RustNodeClosure)); got != 1 {
+		t.Errorf("Expected 1 closure, got %d", got)
+	}
+
+	if len(astInfo.Comments) != 1 {
+		t.Fatalf("Expected 1 comment, got %d", len(astInfo.Comments))
+	}
+	if astInfo.Comments[0].Line != 1 || astInfo.Comments[0].IsDoc {
+		t.Errorf("Unexpected comment %+v", astInfo.Comments[0])
+	}
+
+	if len(astInfo.Functions) != 1 {
+		t.Fatalf("Expected 1 function, got %d", len(astInfo.Functions))
+	}
+	fn := astInfo.Functions[0]
+	if fn.HasDocComments {
+		t.Error("Regular comments should not count as documentation")
+	}
+	if fn.Parameters[0].Type != "*const u8" {
+		t.Errorf("Expected parameter type '*const u8', got %q", fn.Parameters[0].Type)
+	}
+	if fn.Complexity != 1 {
+		t.Errorf("Expected complexity 1, got %d", fn.Complexity)
+	}
+}
+
+// TestRustSyntaxParser_EditionSyntax tests let-else, async closures and gen
+// blocks, and that async closures are only recognized from the 2018 edition
+func TestRustSyntaxParser_EditionSyntax(t *testing.T) {
+	source := `fn load(path: Option<&str>) -> usize {
+    let Some(path) = path else {
+        return 0;
+    };
+    let fetch = async |id: u32| {
+        if id > 0 { id } else { 0 }
+    };
+    let values = gen { yield 1; };
+    path.len()
+}
+`
+	astInfo := &types.RustASTInfo{}
+	root := NewRustSyntaxParserForEdition([]byte(source), types.RustEdition2024).Parse(astInfo)
+
+	if astInfo.Edition != types.RustEdition2024 {
+		t.Errorf("Expected edition 2024, got %q", astInfo.Edition)
+	}
+	letElse := types.FindRustNodes(root, types.RustNodeLetElse)
+	if len(letElse) != 1 || letElse[0].StartLine != 2 || letElse[0].EndLine != 4 {
+		t.Errorf("Expected one let-else block on lines 2-4, got %+v", letElse)
+	}
+	closures := types.FindRustNodes(root, types.RustNodeClosure)
+	if len(closures) != 1 || !closures[0].HasModifier("async") {
+		t.Fatalf("Expected one async closure, got %+v", closures)
+	}
+	if len(types.FindRustNodes(closures[0], types.RustNodeIf)) != 1 {
+		t.Error("Expected the if expression inside the async closure")
+	}
+
+	// 1 + let-else; the closure's if is measured separately
+	if fn := astInfo.Functions[0]; fn.Complexity != 2 {
+		t.Errorf("Expected complexity 2, got %d", fn.Complexity)
+	}
+	for _, tok := range astInfo.Tokens {
+		if tok.Text == "gen" && tok.Kind != types.RustTokenKeyword {
+			t.Errorf("Expected gen to be a keyword in 2024, got %q", tok.Kind)
+		}
+	}
+
+	// In 2015, async is an identifier, so there is no closure
+	legacy := &types.RustASTInfo{}
+	NewRustSyntaxParserForEdition([]byte(source), types.RustEdition2015).Parse(legacy)
+	if fn := legacy.Functions[0]; fn.Complexity != 3 {
+		t.Errorf("Expected complexity 3 in 2015, got %d", fn.Complexity)
+	}
+	for _, tok := range legacy.Tokens {
+		if (tok.Text == "async" || tok.Text == "gen") && tok.Kind != types.RustTokenIdent {
+			t.Errorf("Expected %s to be an identifier in 2015, got %q", tok.Text, tok.Kind)
+		}
+	}
+}
+
+// TestRustSyntaxParser_LetWithIfElse tests that the else of an if expression in
+// a let initializer is not mistaken for let-else
+func TestRustSyntaxParser_LetWithIfElse(t *testing.T) {
+	source := `fn pick(flag: bool) -> u8 {
+    let value = if flag { 1 } else { 2 };
+    value
+}
+`
+	root := NewRustSyntaxParser([]byte(source)).Parse(&types.RustASTInfo{})
+	if got := len(types.FindRustNodes(root, types.RustNodeLetElse)); got != 0 {
+		t.Errorf("Expected no let-else, got %d", got)
+	}
+	if got := len(types.FindRustNodes(root, types.RustNodeElse)); got != 1 {
+		t.Errorf("Expected the else branch of the if, got %d", got)
+	}
+}
@@ -0,0 +1,244 @@
+package scanner
+
+import (
+	"bufio"
+	"os"
+	"path/filepath"
+	"strings"
+	"sync"
+
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+// RustTargetResolver determines the Cargo target each Rust file is compiled
+// into, from the manifest of the package that contains it and Cargo's
+// directory conventions
+type RustTargetResolver struct {
+	packages map[string]string        // directory -> package root; empty outside any package
+	manifest map[string]*cargoPackage // package root -> package and targets
+	mutex    sync.Mutex
+}
+
+// cargoPackage holds the package and the targets a Cargo.toml declares explicitly
+type cargoPackage struct {
+	info   *types.RustPackage
+	hasLib bool                 // A [lib] section, or src/lib.rs
+	build  string               // Build script relative to the package; empty when disabled
+	roots  map[string]cargoRoot // Root file relative to the package, e.g. "tools/gen.rs"
+}
+
+// cargoRoot is a target declared in Cargo.toml
+type cargoRoot struct {
+	kind types.RustTarget
+	name string
+}
+
+// NewRustTargetResolver creates a resolver
+func NewRustTargetResolver() *RustTargetResolver {
+	return &RustTargetResolver{
+		packages: make(map[string]string),
+		manifest: make(map[string]*cargoPackage),
+	}
+}
+
+// TargetFor returns the target of the Rust file at filePath, or an empty
+// target when the file is outside any Cargo package. Files in tests,
+// examples and benches belong to those targets, src/main.rs and src/bin to
+// binaries, the build script to the build target, and the other files of src
+// to the library when the package has one. Files below the root of a target
+// declared in Cargo.toml belong to it; anything else counts as a binary.
+func (r *RustTargetResolver) TargetFor(filePath string) types.RustTarget {
+	target, _, _ := r.Resolve(filePath)
+	return target
+}
+
+// Resolve returns the target of the Rust file at filePath as TargetFor
+// does, with the name of the target and the package it belongs to. The
+// library and the default binary are named after the package, the other
+// targets after their file or directory unless Cargo.toml names them. The
+// package is nil outside any Cargo package.
+func (r *RustTargetResolver) Resolve(filePath string) (types.RustTarget, string, *types.RustPackage) {
+	if abs, err := filepath.Abs(filePath); err == nil {
+		filePath = abs
+	}
+
+	r.mutex.Lock()
+	defer r.mutex.Unlock()
+	root := r.packageRoot(filepath.Dir(filePath))
+	if root == "" {
+		return "", "", nil
+	}
+	pkg := r.manifest[root]
+	rel, err := filepath.Rel(root, filePath)
+	if err != nil {
+		return "", "", nil
+	}
+	rel = filepath.ToSlash(rel)
+
+	if declared, found := pkg.roots[rel]; found {
+		return declared.kind, pkg.targetName(declared), pkg.info
+	}
+	if rel == pkg.build {
+		return types.RustTargetBuild, "build-script-build", pkg.info
+	}
+	first, rest, _ := strings.Cut(rel, "/")
+	switch first {
+	case "tests":
+		return types.RustTargetTest, conventionalTargetName(rest), pkg.info
+	case "examples":
+		return types.RustTargetExample, conventionalTargetName(rest), pkg.info
+	case "benches":
+		return types.RustTargetBench, conventionalTargetName(rest), pkg.info
+	case "src":
+		switch {
+		case rest == "main.rs":
+			return types.RustTargetBinary, pkg.info.Name, pkg.info
+		case strings.HasPrefix(rest, "bin/"):
+			return types.RustTargetBinary, conventionalTargetName(strings.TrimPrefix(rest, "bin/")), pkg.info
+		case pkg.hasLib:
+			return types.RustTargetLibrary, pkg.info.Name, pkg.info
+		}
+		return types.RustTargetBinary, pkg.info.Name, pkg.info
+	}
+
+	// A module of a target whose root Cargo.toml places elsewhere
+	best := ""
+	target := cargoRoot{kind: types.RustTargetBinary}
+	for file, declared := range pkg.roots {
+		dir := pathDir(file)
+		if dir != "" && strings.HasPrefix(rel, dir+"/") && len(dir) > len(best) {
+			best, target = dir, declared
+		}
+	}
+	return target.kind, pkg.targetName(target), pkg.info
+}
+
+// targetName returns the name of a declared target; a library declared
+// without one takes the name of the package
+func (p *cargoPackage) targetName(target cargoRoot) string {
+	if target.name == "" {
+		return p.info.Name
+	}
+	return target.name
+}
+
+// conventionalTargetName names a target Cargo discovers in a directory such
+// as src/bin or tests: "cli.rs" and "cli/main.rs" both make "cli"
+func conventionalTargetName(rel string) string {
+	first, _, _ := strings.Cut(rel, "/")
+	return strings.TrimSuffix(first, ".rs")
+}
+
+// packageRoot returns the directory of the closest Cargo.toml with a
+// [package] section at or above dir, reading and caching its targets
+func (r *RustTargetResolver) packageRoot(dir string) string {
+	if root, found := r.packages[dir]; found {
+		return root
+	}
+
+	root := ""
+	if pkg, err := readCargoPackage(dir); err == nil && pkg != nil {
+		r.manifest[dir] = pkg
+		root = dir
+	} else if parent := filepath.Dir(dir); parent != dir {
+		root = r.packageRoot(parent)
+	}
+	r.packages[dir] = root
+	return root
+}
+
+// readCargoPackage reads the package and targets of the Cargo.toml in dir
+// with a minimal line-based reader. It returns nil when the manifest has no
+// [package] section, as with a virtual workspace manifest.
+func readCargoPackage(dir string) (*cargoPackage, error) {
+	manifestPath := filepath.Join(dir, "Cargo.toml")
+	file, err := os.Open(manifestPath)
+	if err != nil {
+		return nil, err
+	}
+	defer file.Close()
+
+	sections := map[string]types.RustTarget{
+		"[lib]":       types.RustTargetLibrary,
+		"[[bin]]":     types.RustTargetBinary,
+		"[[test]]":    types.RustTargetTest,
+		"[[example]]": types.RustTargetExample,
+		"[[bench]]":   types.RustTargetBench,
+	}
+	pkg := &cargoPackage{
+		info:  &types.RustPackage{Name: filepath.Base(dir), Manifest: manifestPath},
+		build: "build.rs",
+		roots: make(map[string]cargoRoot),
+	}
+	isPackage := false
+	section := ""
+	var targetName, targetPath string
+	flushTarget := func() {
+		if kind, isTarget := sections[section]; isTarget && targetPath != "" {
+			if targetName == "" && kind != types.RustTargetLibrary {
+				targetName = strings.TrimSuffix(filepath.Base(targetPath), ".rs")
+			}
+			pkg.roots[targetPath] = cargoRoot{kind: kind, name: targetName}
+		}
+		targetName, targetPath = "", ""
+	}
+
+	scanner := bufio.NewScanner(file)
+	for scanner.Scan() {
+		line := strings.TrimSpace(scanner.Text())
+		if line == "" || strings.HasPrefix(line, "#") {
+			continue
+		}
+		if strings.HasPrefix(line, "[") {
+			flushTarget()
+			section = line
+			isPackage = isPackage || section == "[package]"
+			pkg.hasLib = pkg.hasLib || section == "[lib]"
+			continue
+		}
+
+		key, value, found := strings.Cut(line, "=")
+		if !found {
+			continue
+		}
+		key, value = strings.TrimSpace(key), tomlStringValue(strings.TrimSpace(value))
+		switch {
+		case section == "[package]" && key == "name":
+			pkg.info.Name = value
+		case section == "[package]" && key == "build":
+			switch value {
+			case "false":
+				pkg.build = ""
+			case "true":
+				pkg.build = "build.rs"
+			default:
+				pkg.build = filepath.ToSlash(filepath.Clean(value))
+			}
+		case key == "name":
+			targetName = value
+		case key == "path":
+			targetPath = filepath.ToSlash(filepath.Clean(value))
+		}
+	}
+	flushTarget()
+	if err := scanner.Err(); err != nil {
+		return nil, err
+	}
+	if !isPackage {
+		return nil, nil
+	}
+
+	if _, err := os.Stat(filepath.Join(dir, "src", "lib.rs")); err == nil {
+		pkg.hasLib = true
+	}
+	return pkg, nil
+}
+
+// pathDir returns the directory of a slash-separated relative path, or an
+// empty string for a file at the top level
+func pathDir(path string) string {
+	if idx := strings.LastIndex(path, "/"); idx >= 0 {
+		return path[:idx]
+	}
+	return ""
+}
@@ -0,0 +1,114 @@
+package scanner
+
+import (
+	"path/filepath"
+	"testing"
+
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+// TestRustTargetResolver tests Cargo's directory conventions, targets
+// declared in Cargo.toml and packages without a library
+func TestRustTargetResolver(t *testing.T) {
+	root := t.TempDir()
+	writeEditionTestFile(t, filepath.Join(root, "Cargo.toml"), "[workspace]\nmembers = [\"sdk\", \"app\", \"core\"]\n")
+	writeEditionTestFile(t, filepath.Join(root, "sdk", "Cargo.toml"), `[package]
+name = "sdk"
+
+[[bin]]
+name = "gen"
+path = "tools/gen.rs" # code generator
+`)
+	writeEditionTestFile(t, filepath.Join(root, "sdk", "src", "lib.rs"), "pub mod net;\n")
+	writeEditionTestFile(t, filepath.Join(root, "app", "Cargo.toml"), "[package]\nname = \"app\"\n")
+	writeEditionTestFile(t, filepath.Join(root, "core", "Cargo.toml"), "[package]\nname = \"core\"\n\n[lib]\npath = \"lib/core.rs\"\n")
+
+	resolver := NewRustTargetResolver()
+	tests := []struct {
+		file string
+		want types.RustTarget
+	}{
+		{filepath.Join(root, "sdk", "src", "lib.rs"), types.RustTargetLibrary},
+		{filepath.Join(root, "sdk", "src", "net", "http.rs"), types.RustTargetLibrary},
+		{filepath.Join(root, "sdk", "src", "main.rs"), types.RustTargetBinary},
+		{filepath.Join(root, "sdk", "src", "bin", "cli.rs"), types.RustTargetBinary},
+		{filepath.Join(root, "sdk", "tests", "api.rs"), types.RustTargetTest},
+		{filepath.Join(root, "sdk", "examples", "demo.rs"), types.RustTargetExample},
+		{filepath.Join(root, "sdk", "benches", "parse.rs"), types.RustTargetBench},
+		{filepath.Join(root, "sdk", "tools", "gen.rs"), types.RustTargetBinary},
+		{filepath.Join(root, "sdk", "build.rs"), types.RustTargetBuild},
+		{filepath.Join(root, "app", "src", "util.rs"), types.RustTargetBinary},
+		{filepath.Join(root, "core", "lib", "parse.rs"), types.RustTargetLibrary},
+		{filepath.Join(root, "scripts", "check.rs"), ""},
+	}
+	for _, tt := range tests {
+		if got := resolver.TargetFor(tt.file); got != tt.want {
+			t.Errorf("TargetFor(%s) = %q, want %q", tt.file, got, tt.want)
+		}
+	}
+}
+
+// TestRustTargetResolverNames tests the target names and packages reported
+// alongside the target
+func TestRustTargetResolverNames(t *testing.T) {
+	root := t.TempDir()
+	writeEditionTestFile(t, filepath.Join(root, "Cargo.toml"), "[workspace]\nmembers = [\"svc\"]\n")
+	writeEditionTestFile(t, filepath.Join(root, "svc", "Cargo.toml"), `[package]
+name = "svc"
+build = "codegen.rs"
+
+[[bin]]
+name = "admin"
+path = "tools/admin.rs"
+`)
+	writeEditionTestFile(t, filepath.Join(root, "svc", "src", "lib.rs"), "pub mod net;\n")
+
+	resolver := NewRustTargetResolver()
+	tests := []struct {
+		file   string
+		target types.RustTarget
+		name   string
+	}{
+		{filepath.Join(root, "svc", "src", "net.rs"), types.RustTargetLibrary, "svc"},
+		{filepath.Join(root, "svc", "src", "main.rs"), types.RustTargetBinary, "svc"},
+		{filepath.Join(root, "svc", "src", "bin", "worker.rs"), types.RustTargetBinary, "worker"},
+		{filepath.Join(root, "svc", "src", "bin", "sync", "main.rs"), types.RustTargetBinary, "sync"},
+		{filepath.Join(root, "svc", "tools", "admin.rs"), types.RustTargetBinary, "admin"},
+		{filepath.Join(root, "svc", "tests", "api.rs"), types.RustTargetTest, "api"},
+		{filepath.Join(root, "svc", "codegen.rs"), types.RustTargetBuild, "build-script-build"},
+		{filepath.Join(root, "svc", "build.rs"), types.RustTargetBinary, "svc"},
+	}
+	for _, tt := range tests {
+		target, name, pkg := resolver.Resolve(tt.file)
+		if target != tt.target || name != tt.name {
+			t.Errorf("Resolve(%s) = %q %q, want %q %q", tt.file, target, name, tt.target, tt.name)
+		}
+		if pkg == nil || pkg.Name != "svc" || pkg.Manifest != filepath.Join(root, "svc", "Cargo.toml") {
+			t.Errorf("Resolve(%s) package = %+v, want svc/Cargo.toml", tt.file, pkg)
+		}
+	}
+
+	if _, _, pkg := resolver.Resolve(filepath.Join(root, "scripts", "check.rs")); pkg != nil {
+		t.Errorf("Expected no package for a file under the workspace root, got %+v", pkg)
+	}
+}
+
+// TestRustASTAnalyzerResolvesTarget tests that analyzed files carry their
+// target, package and crate name
+func TestRustASTAnalyzerResolvesTarget(t *testing.T) {
+	root := t.TempDir()
+	writeEditionTestFile(t, filepath.Join(root, "Cargo.toml"), "[package]\nname = \"my-svc\"\n")
+	path := filepath.Join(root, "src", "lib.rs")
+	writeEditionTestFile(t, path, "pub fn run() {}\n")
+
+	info, err := NewRustASTAnalyzer(false).AnalyzeRustFile(path, []byte("pub fn run() {}\n"))
+	if err != nil {
+		t.Fatalf("Failed to analyze %s: %v", path, err)
+	}
+	if info.Target != types.RustTargetLibrary || info.TargetName != "my-svc" || info.Package == nil || info.Package.Name != "my-svc" {
+		t.Errorf("Expected the library of my-svc, got %q %q %+v", info.Target, info.TargetName, info.Package)
+	}
+	if info.CrateName != "my_svc" {
+		t.Errorf("Expected crate name my_svc, got %q", info.CrateName)
+	}
+}
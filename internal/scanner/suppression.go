@@ -0,0 +1,209 @@
+package scanner
+
+import (
+	goscanner "go/scanner"
+	"go/token"
+	"regexp"
+	"sort"
+	"strings"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/rules"
+	"github.com/ericfisherdev/goclean/internal/rustsyntax"
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+// suppressionRegex matches `goclean:ignore RULE[,RULE] [reason]` and
+// `goclean:ignore-next-line ...` at the start of a //, /* or # comment
+var suppressionRegex = regexp.MustCompile(`^(?://|/\*|#)[/!*]?[ \t]*goclean:(ignore-next-line|ignore)\b(.*)`)
+
+// commentSpan is the byte range of a comment, delimiters included
+type commentSpan struct {
+	start int
+	end   int
+}
+
+// ParseSuppressions finds the inline suppression directives of a source file.
+// Directives are only read from comments found by lexing the file in its
+// language, so text in string literals is never a directive. A directive
+// after code on its line applies to that line. A directive on a line of its
+// own, and any ignore-next-line directive, applies to the next line of code;
+// blank lines, comments and Rust attributes are skipped.
+func ParseSuppressions(content []byte, language string) []*models.Suppression {
+	source := string(content)
+	lineStarts := []int{0}
+	for i := 0; i < len(source); i++ {
+		if source[i] == '\n' {
+			lineStarts = append(lineStarts, i+1)
+		}
+	}
+	lineOf := func(offset int) int {
+		return sort.Search(len(lineStarts), func(i int) bool { return lineStarts[i] > offset })
+	}
+
+	// Blank out comments to tell lines of code from the rest, and keep the
+	// first directive of each line
+	code := []byte(source)
+	directives := make(map[int]commentSpan)
+	for _, span := range commentSpans(source, language, lineStarts) {
+		for i := span.start; i < span.end; i++ {
+			if code[i] != '\n' {
+				code[i] = ' '
+			}
+		}
+		line := lineOf(span.start)
+		if _, found := directives[line]; !found && suppressionRegex.MatchString(source[span.start:span.end]) {
+			directives[line] = span
+		}
+	}
+
+	var suppressions []*models.Suppression
+	var pending []*models.Suppression // waiting for the next line of code
+	for i, codeLine := range strings.Split(string(code), "\n") {
+		lineNum := i + 1
+		span, found := directives[lineNum]
+		if !found {
+			if isSuppressionTarget(codeLine) {
+				for _, s := range pending {
+					s.TargetLine = lineNum
+				}
+				pending = nil
+			}
+			continue
+		}
+
+		match := suppressionRegex.FindStringSubmatch(source[span.start:span.end])
+		suppression := parseSuppressionArgs(match[2])
+		suppression.Line = lineNum
+		suppressions = append(suppressions, suppression)
+
+		// A trailing directive follows code, which is the target of pending directives
+		ownLine := strings.TrimSpace(codeLine[:span.start-lineStarts[i]]) == ""
+		if !ownLine {
+			for _, s := range pending {
+				s.TargetLine = lineNum
+			}
+			pending = nil
+		}
+		if match[1] == "ignore" && !ownLine {
+			suppression.TargetLine = lineNum
+		} else {
+			pending = append(pending, suppression)
+		}
+	}
+	return suppressions
+}
+
+// parseSuppressionArgs splits the text after the directive into rule IDs and
+// an optional, possibly quoted, reason
+func parseSuppressionArgs(args string) *models.Suppression {
+	args = strings.TrimSpace(strings.TrimSuffix(strings.TrimSpace(args), "*/"))
+	suppression := &models.Suppression{}
+	if args == "" {
+		return suppression
+	}
+
+	// The rule list may have spaces around its commas
+	fields := strings.Fields(args)
+	ruleList, next := fields[0], 1
+	for next < len(fields) && (strings.HasSuffix(ruleList, ",") || strings.HasPrefix(fields[next], ",")) {
+		ruleList += fields[next]
+		next++
+	}
+	reason := strings.Join(fields[next:], " ")
+	for _, rule := range strings.Split(ruleList, ",") {
+		rule = strings.TrimSpace(rule)
+		if rule == "" {
+			continue
+		}
+		if _, deprecated := rules.Lookup(rule); deprecated {
+			suppression.Deprecated = append(suppression.Deprecated, rule)
+		}
+		suppression.Rules = append(suppression.Rules, rules.Resolve(rule)...)
+	}
+	suppression.Reason = strings.Trim(strings.TrimSpace(reason), `"`)
+	return suppression
+}
+
+// isSuppressionTarget reports whether a line, with its comments blanked out,
+// holds code a pending directive applies to. Rust attributes are skipped so a
+// directive above them applies to the item they annotate.
+func isSuppressionTarget(code string) bool {
+	trimmed := strings.TrimSpace(code)
+	return trimmed != "" && !strings.HasPrefix(trimmed, "#[") && !strings.HasPrefix(trimmed, "#![")
+}
+
+// commentSpans returns the comments of a source file in order, from the Go
+// and Rust tokenizers, or from a lexer of //, /* */ and # comments that skips
+// quoted strings for other languages
+func commentSpans(source, language string, lineStarts []int) []commentSpan {
+	var spans []commentSpan
+	switch language {
+	case "Go":
+		file := token.NewFileSet().AddFile("", -1, len(source))
+		var lexer goscanner.Scanner
+		lexer.Init(file, []byte(source), nil, goscanner.ScanComments)
+		for {
+			pos, tok, _ := lexer.Scan()
+			if tok == token.EOF {
+				break
+			}
+			if tok == token.COMMENT {
+				start := file.Offset(pos)
+				spans = append(spans, commentSpan{start: start, end: commentEnd(source, start)})
+			}
+		}
+	case "Rust":
+		_, comments := rustsyntax.Tokenize(source, types.DefaultRustEdition)
+		for _, comment := range comments {
+			start := lineStarts[comment.Line-1] + comment.Column - 1
+			spans = append(spans, commentSpan{start: start, end: start + len(comment.Text)})
+		}
+	default:
+		for i := 0; i < len(source); i++ {
+			switch c := source[i]; {
+			case c == '#' || strings.HasPrefix(source[i:], "//") || strings.HasPrefix(source[i:], "/*"):
+				end := commentEnd(source, i)
+				spans = append(spans, commentSpan{start: i, end: end})
+				i = end - 1
+			case c == '"' || c == '\'' || c == '`':
+				i = quotedStringEnd(source, i) - 1
+			}
+		}
+	}
+	return spans
+}
+
+// commentEnd returns the offset after the comment starting at start: the end
+// of its line, or the end of a /* */ block
+func commentEnd(source string, start int) int {
+	if strings.HasPrefix(source[start:], "/*") {
+		if end := strings.Index(source[start+2:], "*/"); end >= 0 {
+			return start + 2 + end + 2
+		}
+		return len(source)
+	}
+	if end := strings.IndexByte(source[start:], '\n'); end >= 0 {
+		return start + end
+	}
+	return len(source)
+}
+
+// quotedStringEnd returns the offset after the string opened by the quote at
+// start. Escapes are skipped, and only backquoted strings span lines.
+func quotedStringEnd(source string, start int) int {
+	quote := source[start]
+	for i := start + 1; i < len(source); i++ {
+		switch source[i] {
+		case '\\':
+			i++
+		case quote:
+			return i + 1
+		case '\n':
+			if quote != '`' {
+				return i
+			}
+		}
+	}
+	return len(source)
+}
@@ -0,0 +1,112 @@
+package scanner
+
+import (
+	"reflect"
+	"testing"
+)
+
+func TestParseSuppressions(t *testing.T) {
+	content := []byte(`x := 1 // goclean:ignore magic-number
+// goclean:ignore function-length, parameter-count "legacy code"
+// goclean:ignore-next-line cyclomatic-complexity
+
+#[inline]
+fn run() {} // goclean:ignore-next-line todo-tracker
+// TODO: later
+let y = 2; /* goclean:ignore magic-number */
+# goclean:ignore
+`)
+
+	suppressions := ParseSuppressions(content, "")
+	expected := []struct {
+		rules      []string
+		reason     string
+		line       int
+		targetLine int
+	}{
+		{[]string{"magic-number"}, "", 1, 1},
+		{[]string{"function-length", "parameter-count"}, "legacy code", 2, 6},
+		{[]string{"cyclomatic-complexity"}, "", 3, 6},
+		{[]string{"todo-tracker"}, "", 6, 8},
+		{[]string{"magic-number"}, "", 8, 8},
+		{nil, "", 9, 0},
+	}
+	if len(suppressions) != len(expected) {
+		t.Fatalf("Expected %d suppressions, got %d", len(expected), len(suppressions))
+	}
+	for i, want := range expected {
+		got := suppressions[i]
+		if !reflect.DeepEqual(got.Rules, want.rules) || got.Reason != want.reason {
+			t.Errorf("Suppression %d: expected rules %v reason %q, got %v %q", i, want.rules, want.reason, got.Rules, got.Reason)
+		}
+		if got.Line != want.line || got.TargetLine != want.targetLine {
+			t.Errorf("Suppression %d: expected line %d targeting %d, got %d targeting %d", i, want.line, want.targetLine, got.Line, got.TargetLine)
+		}
+	}
+}
+
+func TestParseSuppressionsResolvesDeprecatedRules(t *testing.T) {
+	suppressions := ParseSuppressions([]byte("pub fn run() {} // goclean:ignore rust-missing-documentation, magic-number\n"), "Rust")
+	if len(suppressions) != 1 {
+		t.Fatalf("Expected 1 suppression, got %d", len(suppressions))
+	}
+
+	got := suppressions[0]
+	if !reflect.DeepEqual(got.Deprecated, []string{"rust-missing-documentation"}) {
+		t.Errorf("Expected the deprecated rule to be recorded, got %v", got.Deprecated)
+	}
+	if len(got.Rules) != 8 || got.Rules[0] != "rust-missing-function-documentation" || got.Rules[7] != "magic-number" {
+		t.Errorf("Expected the deprecated rule to resolve to its replacements, got %v", got.Rules)
+	}
+}
+
+// TestParseSuppressionsSkipsOnlyComments tests that a line starting with *
+// is code unless it is inside a block comment
+func TestParseSuppressionsSkipsOnlyComments(t *testing.T) {
+	content := []byte(`package main
+
+func set(ptr *int) {
+	// goclean:ignore-next-line magic-number
+	*ptr = 42
+	// goclean:ignore-next-line magic-number
+	/*
+	 * The answer
+	 */
+	*ptr = 43
+}
+`)
+
+	suppressions := ParseSuppressions(content, "Go")
+	if len(suppressions) != 2 {
+		t.Fatalf("Expected 2 suppressions, got %d", len(suppressions))
+	}
+	if suppressions[0].TargetLine != 5 {
+		t.Errorf("Expected the first directive to target line 5, got %d", suppressions[0].TargetLine)
+	}
+	if suppressions[1].TargetLine != 10 {
+		t.Errorf("Expected the second directive to skip the block comment and target line 10, got %d", suppressions[1].TargetLine)
+	}
+}
+
+// TestParseSuppressionsIgnoresStringLiterals tests that directive text in
+// string literals is not a directive
+func TestParseSuppressionsIgnoresStringLiterals(t *testing.T) {
+	tests := []struct {
+		language string
+		content  string
+	}{
+		{"Go", "package main\n\nvar usage = \"add // goclean:ignore magic-number to silence it\"\nvar raw = `/* goclean:ignore magic-number */`\n"},
+		{"Rust", "const USAGE: &str = \"// goclean:ignore magic-number\";\nconst RAW: &str = r#\"# goclean:ignore magic-number\"#;\n"},
+		{"Python", "usage = 'use # goclean:ignore magic-number'\n"},
+	}
+	for _, tt := range tests {
+		if suppressions := ParseSuppressions([]byte(tt.content), tt.language); len(suppressions) != 0 {
+			t.Errorf("%s: expected no suppressions from string literals, got %d", tt.language, len(suppressions))
+		}
+	}
+
+	suppressions := ParseSuppressions([]byte("let s = \"a // b\"; // goclean:ignore magic-number\n"), "Rust")
+	if len(suppressions) != 1 || suppressions[0].TargetLine != 1 {
+		t.Errorf("Expected the comment after the string to suppress line 1, got %+v", suppressions)
+	}
+}
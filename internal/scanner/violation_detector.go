@@ -1,17 +1,29 @@
 package scanner
 
 import (
+	"fmt"
+	"strings"
+	"time"
+
 	"github.com/ericfisherdev/goclean/internal/models"
-	"github.com/ericfisherdev/goclean/internal/types"
+	"github.com/ericfisherdev/goclean/internal/rules"
 	"github.com/ericfisherdev/goclean/internal/violations"
+	"github.com/ericfisherdev/goclean/pkg/severity"
 )
 
 // ViolationDetector manages violation detection during scanning
 type ViolationDetector struct {
-	registry                 *violations.DetectorRegistry
-	duplicationDetector      *violations.DuplicationDetector
-	rustDuplicationDetector  *violations.RustDuplicationDetector
-	config                   *violations.DetectorConfig
+	registry         *violations.DetectorRegistry
+	cloneDetector    *violations.CloneDetector
+	couplingDetector *violations.CouplingDetector
+	publicAPI        *violations.PublicAPIDetector
+	maintainability  *violations.MaintainabilityDetector
+	docCoverage      *violations.DocCoverageDetector
+	primitives       *violations.PrimitiveObsessionDetector
+	plugins          []*violations.PluginDetector
+	config           *violations.DetectorConfig
+	policy           severity.Policy
+	policyInputs     severity.Inputs
 }
 
 // NewViolationDetector creates a new violation detector
@@ -26,6 +38,7 @@ func NewViolationDetector(config *violations.DetectorConfig) *ViolationDetector
 	registry.RegisterDetector(violations.NewFunctionDetector(config))
 	registry.RegisterDetector(violations.NewNamingDetector(config))
 	registry.RegisterDetector(violations.NewStructureDetector(config))
+	registry.RegisterDetector(violations.NewCognitiveComplexityDetector(config))
 	
 	// Register Go-specific detectors
 	registry.RegisterDetector(violations.NewGoStandardNamingDetector(config))
@@ -38,22 +51,58 @@ func NewViolationDetector(config *violations.DetectorConfig) *ViolationDetector
 	registry.RegisterDetector(violations.NewRustStructureDetector(config))
 	registry.RegisterDetector(violations.NewRustOwnershipDetector(config))
 	registry.RegisterDetector(violations.NewRustErrorHandlingDetector(config))
+	registry.RegisterDetector(violations.NewRustUnwrapDensityDetector(config))
+	registry.RegisterDetector(violations.NewRustLibraryPanicDetector(config))
+	registry.RegisterDetector(violations.NewRustSafetyCommentDetector(config))
+	registry.RegisterDetector(violations.NewRustPointerArithmeticDetector(config))
+	registry.RegisterDetector(violations.NewRustConcurrencyDetector(config))
+	registry.RegisterDetector(violations.NewRustAsyncDetector(config))
+	registry.RegisterDetector(violations.NewRustPatternMatchingDetector(config))
+	registry.RegisterDetector(violations.NewRustMacroDetector(config))
+	registry.RegisterDetector(violations.NewRustImportsDetector(config))
+	registry.RegisterDetector(violations.NewRustCommentedCodeDetector(config))
 	
 	// Register advanced detectors
 	registry.RegisterDetector(violations.NewMagicNumberDetector(config))
 	registry.RegisterDetector(violations.NewCommentedCodeDetector(config))
 	registry.RegisterDetector(violations.NewTodoTrackerDetector(config))
+	registry.RegisterDetector(violations.NewUntrackedTodoDetector(config))
 	registry.RegisterDetector(violations.NewDocumentationDetector(config))
+	registry.RegisterDetector(violations.NewPastedCodeDetector(config))
+	
+	// Register custom script rules
+	if len(config.ScriptRules) > 0 {
+		registry.RegisterDetector(violations.NewScriptRuleDetector(config.ScriptRules))
+	}
 	
-	// Create duplication detectors separately (needs special handling)
-	duplicationDetector := violations.NewDuplicationDetector(config)
-	rustDuplicationDetector := violations.NewRustDuplicationDetector(config)
+	// Register external detector plugins
+	var plugins []*violations.PluginDetector
+	for _, spec := range config.Plugins {
+		detector := violations.NewPluginDetector(spec)
+		registry.RegisterDetector(detector)
+		plugins = append(plugins, detector)
+	}
+	
+	// Create the clone, coupling, public API, maintainability, documentation
+	// coverage and primitive obsession detectors separately (they collect
+	// results across the whole scan)
+	cloneDetector := violations.NewCloneDetector(config)
+	couplingDetector := violations.NewCouplingDetector(config)
+	publicAPI := violations.NewPublicAPIDetector(config)
+	maintainability := violations.NewMaintainabilityDetector(config)
+	docCoverage := violations.NewDocCoverageDetector(config)
+	primitives := violations.NewPrimitiveObsessionDetector(config)
 	
 	return &ViolationDetector{
-		registry:                registry,
-		duplicationDetector:     duplicationDetector,
-		rustDuplicationDetector: rustDuplicationDetector,
-		config:                  config,
+		registry:         registry,
+		cloneDetector:    cloneDetector,
+		couplingDetector: couplingDetector,
+		publicAPI:        publicAPI,
+		maintainability:  maintainability,
+		docCoverage:      docCoverage,
+		primitives:       primitives,
+		plugins:          plugins,
+		config:           config,
 	}
 }
 
@@ -63,29 +112,294 @@ func (vd *ViolationDetector) DetectViolations(result *models.ScanResult) {
 		return
 	}
 	
+	// Rust files carry their syntax tree in RustASTInfo
+	astInfo := result.ASTInfo
+	if astInfo == nil {
+		astInfo = result.RustASTInfo
+	}
+
 	// Run standard detectors on the AST info (Go or Rust)
-	violations := vd.registry.DetectAll(result.File, result.ASTInfo)
+	violations := vd.registry.DetectAll(result.File, astInfo)
 	
-	// Run duplication detectors (needs special handling as they compare across files)
-	if result.ASTInfo != nil {
-		if goAstInfo, ok := result.ASTInfo.(*types.GoASTInfo); ok {
-			dupViolations := vd.duplicationDetector.Detect(result.File, goAstInfo)
-			violations = append(violations, dupViolations...)
-		} else if rustAstInfo, ok := result.ASTInfo.(*types.RustASTInfo); ok {
-			rustDupViolations := vd.rustDuplicationDetector.Detect(result.File, rustAstInfo)
-			violations = append(violations, rustDupViolations...)
-		}
+	// Index the file for clone detection, which reports once all files are in,
+	// and run coupling detection (both compare across files)
+	if astInfo != nil {
+		start := time.Now()
+		vd.cloneDetector.Detect(result.File, astInfo)
+		vd.registry.RecordTiming(vd.cloneDetector.Name(), time.Since(start))
+
+		start = time.Now()
+		violations = append(violations, vd.couplingDetector.Detect(result.File, astInfo)...)
+		vd.registry.RecordTiming(vd.couplingDetector.Name(), time.Since(start))
+
+		start = time.Now()
+		vd.publicAPI.Detect(result.File, astInfo)
+		vd.registry.RecordTiming(vd.publicAPI.Name(), time.Since(start))
+
+		start = time.Now()
+		violations = append(violations, vd.maintainability.Detect(result.File, astInfo)...)
+		vd.registry.RecordTiming(vd.maintainability.Name(), time.Since(start))
+
+		start = time.Now()
+		vd.docCoverage.Detect(result.File, astInfo)
+		vd.registry.RecordTiming(vd.docCoverage.Name(), time.Since(start))
+
+		start = time.Now()
+		vd.primitives.Detect(result.File, astInfo)
+		vd.registry.RecordTiming(vd.primitives.Name(), time.Since(start))
 	}
 	
+	// Rules with a configured severity override the classified one
+	classifier := vd.config.GetSeverityClassifier()
+	classifier.ApplyRuleSeverities(violations)
+	vd.applySeverityPolicy(result.File, violations)
+	vd.config.Remediation.Assign(violations)
+
+	// Identical findings in one file get an occurrence suffix to keep fingerprints unique
+	occurrences := make(map[string]int)
+	assignFingerprints(violations, occurrences)
+
+	// Hide violations suppressed by goclean:ignore directives
+	kept, suppressed, directives := vd.applySuppressions(result, violations)
+	classifier.ApplyRuleSeverities(directives)
+	vd.applySeverityPolicy(result.File, directives)
+	vd.config.Remediation.Assign(directives)
+	assignFingerprints(directives, occurrences)
+
+	// Collapse repeated findings of a rule into grouped violations
+	kept = vd.config.Grouping.Group(kept)
+
 	// Add violations to the result
-	result.Violations = violations
+	result.Violations = append(kept, directives...)
+	result.Suppressed = suppressed
+}
+
+// SetSeverityPolicy makes the detector ask policy for the final severity of
+// every violation, after the configured rule severities. A nil policy keeps
+// the rule severities.
+func (vd *ViolationDetector) SetSeverityPolicy(policy severity.Policy, inputs severity.Inputs) {
+	vd.policy = policy
+	vd.policyInputs = inputs
+}
+
+// applySeverityPolicy applies the severity policy, if any, to the violations found in file
+func (vd *ViolationDetector) applySeverityPolicy(file *models.FileInfo, found []*models.Violation) {
+	violations.ApplySeverityPolicy(vd.policy, vd.policyInputs, file, found)
+}
+
+// assignFingerprints sets the fingerprint of violations that have none
+func assignFingerprints(violations []*models.Violation, occurrences map[string]int) {
+	for _, violation := range violations {
+		if violation.Fingerprint != "" {
+			continue
+		}
+		fingerprint := models.ComputeFingerprint(violation)
+		occurrences[fingerprint]++
+		if n := occurrences[fingerprint]; n > 1 {
+			fingerprint = fmt.Sprintf("%s-%d", fingerprint, n)
+		}
+		violation.Fingerprint = fingerprint
+	}
+}
+
+// applySuppressions separates violations matched by inline directives. It
+// also reports directives that name no rule and, when configured, directives
+// that matched no violation.
+func (vd *ViolationDetector) applySuppressions(result *models.ScanResult, found []*models.Violation) (kept, suppressed, directives []*models.Violation) {
+	if len(result.Suppressions) == 0 {
+		return found, nil, nil
+	}
+
+	kept, suppressed = suppress(result.Suppressions, found)
+	for _, suppression := range result.Suppressions {
+		switch {
+		case len(suppression.Rules) == 0:
+			directives = append(directives, suppressionViolation(result.File.Path, suppression, "invalid-suppression",
+				"goclean:ignore directive does not name a rule",
+				"Add the ID of the rule to suppress, e.g. // goclean:ignore function-length reason"))
+		case !suppression.Used && vd.config.ReportUnusedSuppressions:
+			directives = append(directives, suppressionViolation(result.File.Path, suppression, "unused-suppression",
+				fmt.Sprintf("Suppression of %s matches no violation", strings.Join(suppression.Rules, ", ")),
+				"Remove the directive, or move it next to the line of the violation it should suppress"))
+		}
+		for _, id := range suppression.Deprecated {
+			deprecation, _ := rules.Lookup(id)
+			directives = append(directives, suppressionViolation(result.File.Path, suppression, "deprecated-rule",
+				"goclean:ignore directive names a deprecated rule: "+deprecation.Warning(),
+				fmt.Sprintf("Replace %s with the matching rule of: %s", id, strings.Join(deprecation.Replacements, ", "))))
+		}
+	}
+	return kept, suppressed, directives
+}
+
+// suppress separates the violations matched by suppressions and marks those suppressions used
+func suppress(suppressions []*models.Suppression, found []*models.Violation) (kept, suppressed []*models.Violation) {
+	kept = make([]*models.Violation, 0, len(found))
+	for _, violation := range found {
+		matched := false
+		for _, suppression := range suppressions {
+			if suppression.Matches(violation) {
+				suppression.Used = true
+				matched = true
+			}
+		}
+		if matched {
+			violation.Triage = models.TriageSuppressed
+			suppressed = append(suppressed, violation)
+		} else {
+			kept = append(kept, violation)
+		}
+	}
+	return kept, suppressed
+}
+
+// DetectClones adds the clones found across all files of the scan to the
+// results. Call it once every file was detected: clones are matched then, so
+// they do not depend on the order in which workers finished.
+func (vd *ViolationDetector) DetectClones(results []*models.ScanResult) {
+	vd.addScanViolations(results, vd.cloneDetector.Violations())
+}
+
+// DetectCircularDependencies adds the modules depending on themselves
+// through other modules to the results. Call it once every file was
+// detected, as cycles span the files of several modules.
+func (vd *ViolationDetector) DetectCircularDependencies(results []*models.ScanResult) {
+	vd.addScanViolations(results, vd.couplingDetector.CircularDependencies())
 }
 
-// ResetDuplicationCache resets the duplication detectors' caches
+// DetectUnusedPublicAPI adds the pub items that no file of the scan uses to
+// the results. Call it once every file was detected, as uses span files.
+func (vd *ViolationDetector) DetectUnusedPublicAPI(results []*models.ScanResult) {
+	vd.addScanViolations(results, vd.publicAPI.Violations())
+}
+
+// DetectPrimitiveObsession adds the primitive parameters and fields that
+// recur under one name across too many functions and structs to the
+// results. Call it once every file was detected, as the uses span files.
+func (vd *ViolationDetector) DetectPrimitiveObsession(results []*models.ScanResult) {
+	vd.addScanViolations(results, vd.primitives.Violations())
+}
+
+// addScanViolations adds violations found across all files of the scan to
+// the results of their files, applying rule severities, the severity
+// policy, remediation estimates and suppressions
+func (vd *ViolationDetector) addScanViolations(results []*models.ScanResult, found []*models.Violation) {
+	byFile := make(map[string][]*models.Violation)
+	for _, violation := range found {
+		byFile[violation.File] = append(byFile[violation.File], violation)
+	}
+	if len(byFile) == 0 {
+		return
+	}
+
+	classifier := vd.config.GetSeverityClassifier()
+	for _, result := range results {
+		if result == nil || result.File == nil || len(byFile[result.File.Path]) == 0 {
+			continue
+		}
+		fileViolations := byFile[result.File.Path]
+		classifier.ApplyRuleSeverities(fileViolations)
+		vd.applySeverityPolicy(result.File, fileViolations)
+		vd.config.Remediation.Assign(fileViolations)
+		assignFingerprints(fileViolations, make(map[string]int))
+
+		kept, suppressed := suppress(result.Suppressions, fileViolations)
+		if len(suppressed) > 0 {
+			// Directives used only by these violations were reported unused before
+			result.Violations = withoutUsedDirectives(result.Violations, result.Suppressions)
+		}
+		result.Violations = append(result.Violations, kept...)
+		result.Suppressed = append(result.Suppressed, suppressed...)
+	}
+}
+
+// withoutUsedDirectives drops the unused-suppression findings of directives that are now used
+func withoutUsedDirectives(found []*models.Violation, suppressions []*models.Suppression) []*models.Violation {
+	used := make(map[int]bool)
+	for _, suppression := range suppressions {
+		if suppression.Used {
+			used[suppression.Line] = true
+		}
+	}
+
+	kept := make([]*models.Violation, 0, len(found))
+	for _, violation := range found {
+		if violation.Rule == "unused-suppression" && used[violation.Line] {
+			continue
+		}
+		kept = append(kept, violation)
+	}
+	return kept
+}
+
+// suppressionViolation reports a problem with an inline suppression directive
+func suppressionViolation(path string, suppression *models.Suppression, rule, message, suggestion string) *models.Violation {
+	return &models.Violation{
+		Type:       models.ViolationTypeSuppression,
+		Severity:   models.SeverityLow,
+		Message:    message,
+		File:       path,
+		Line:       suppression.Line,
+		Column:     1,
+		Rule:       rule,
+		Suggestion: suggestion,
+	}
+}
+
+// ResetDuplicationCache resets the clone index, the recorded module
+// dependencies, pub items, maintainability indexes, documentation coverage
+// and primitive parameters and fields
 // This should be called at the start of each new scan
 func (vd *ViolationDetector) ResetDuplicationCache() {
-	vd.duplicationDetector.Reset()
-	vd.rustDuplicationDetector.Reset()
+	vd.cloneDetector.Reset()
+	vd.couplingDetector.Reset()
+	vd.publicAPI.Reset()
+	vd.maintainability.Reset()
+	vd.docCoverage.Reset()
+	vd.primitives.Reset()
+}
+
+// CloneGroups returns the clone groups found across all files of the current scan
+func (vd *ViolationDetector) CloneGroups() []*models.CloneGroup {
+	return vd.cloneDetector.Groups()
+}
+
+// Coupling returns the coupling between the modules of the current scan
+func (vd *ViolationDetector) Coupling() []*models.ModuleCoupling {
+	return vd.couplingDetector.Coupling()
+}
+
+// Maintainability returns the maintainability index of the files of the current scan
+func (vd *ViolationDetector) Maintainability() []*models.FileMaintainability {
+	return vd.maintainability.Maintainability()
+}
+
+// DocCoverage returns the documentation coverage of the current scan
+func (vd *ViolationDetector) DocCoverage() *models.DocCoverage {
+	return vd.docCoverage.Coverage()
+}
+
+// PrimitiveObsession returns the primitive parameters and fields recurring
+// across the signatures of the current scan
+func (vd *ViolationDetector) PrimitiveObsession() []*models.PrimitiveCluster {
+	return vd.primitives.Clusters()
+}
+
+// EnableTimings makes the detector measure the time each detector takes
+func (vd *ViolationDetector) EnableTimings() {
+	vd.registry.EnableTimings()
+}
+
+// Timings returns the time each detector took during the scan, slowest first
+func (vd *ViolationDetector) Timings() []violations.DetectorTiming {
+	return vd.registry.Timings()
+}
+
+// ClosePlugins stops the plugin processes started during a scan
+func (vd *ViolationDetector) ClosePlugins() {
+	for _, detector := range vd.plugins {
+		detector.Close()
+	}
 }
 
 // GetConfig returns the detector configuration
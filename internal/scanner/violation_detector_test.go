@@ -0,0 +1,350 @@
+package scanner
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/types"
+	"github.com/ericfisherdev/goclean/internal/violations"
+)
+
+// TestViolationDetectorReportsRustClones tests that the duplicated validation
+// methods of User and Employee are reported as one clone with both locations
+func TestViolationDetectorReportsRustClones(t *testing.T) {
+	path := "../../testdata/rust/duplication_issues.rs"
+	content, err := os.ReadFile(path)
+	if err != nil {
+		t.Fatalf("Failed to read test file: %v", err)
+	}
+
+	astInfo := &types.RustASTInfo{FilePath: path}
+	NewRustSyntaxParser(content).Parse(astInfo)
+	if len(astInfo.Tokens) == 0 {
+		t.Fatal("Expected the parser to expose tokens")
+	}
+
+	result := &models.ScanResult{
+		File:        &models.FileInfo{Path: path, Language: "Rust"},
+		RustASTInfo: astInfo,
+	}
+	detector := NewViolationDetector(nil)
+	detector.DetectViolations(result)
+	detector.DetectClones([]*models.ScanResult{result})
+
+	// Employee::validate_name is on lines 85-90, User::validate_name on lines 61-66
+	found := false
+	for _, v := range result.Violations {
+		if v.Rule != "rust-code-clone" || v.Line > 85 || v.EndLine < 90 {
+			continue
+		}
+		var originalStart, originalEnd int
+		at := strings.LastIndex(v.Message, path+":")
+		if at < 0 {
+			t.Fatalf("Expected message to reference the original location, got %q", v.Message)
+		}
+		if _, err := fmt.Sscanf(v.Message[at+len(path)+1:], "%d-%d", &originalStart, &originalEnd); err != nil {
+			t.Fatalf("Failed to parse original location from %q: %v", v.Message, err)
+		}
+		if originalStart <= 61 && originalEnd >= 66 {
+			found = true
+		}
+	}
+	if !found {
+		t.Errorf("Expected a clone of User::validate_name, got %v", result.Violations)
+	}
+}
+
+// TestViolationDetectorEstimatesCloneRemediation tests that clones, reported
+// once every file was detected, add their remediation to the technical debt
+func TestViolationDetectorEstimatesCloneRemediation(t *testing.T) {
+	path := "../../testdata/rust/duplication_issues.rs"
+	content, err := os.ReadFile(path)
+	if err != nil {
+		t.Fatalf("Failed to read test file: %v", err)
+	}
+
+	astInfo := &types.RustASTInfo{FilePath: path}
+	NewRustSyntaxParser(content).Parse(astInfo)
+	result := &models.ScanResult{
+		File:        &models.FileInfo{Path: path, Language: "Rust"},
+		RustASTInfo: astInfo,
+	}
+	detector := NewViolationDetector(nil)
+	detector.DetectViolations(result)
+	before := models.ComputeTechnicalDebt(result.Violations).Minutes
+	detector.DetectClones([]*models.ScanResult{result})
+
+	var clones []*models.Violation
+	for _, v := range result.Violations {
+		if v.Rule == "rust-code-clone" {
+			clones = append(clones, v)
+		}
+	}
+	if len(clones) == 0 {
+		t.Fatal("Expected clones in the duplication test file")
+	}
+	minutes := violations.DefaultRemediationMinutes[models.CategoryDuplication]
+	for _, clone := range clones {
+		if clone.Remediation != minutes {
+			t.Errorf("Expected the duplication estimate on clone at line %d, got %d minutes", clone.Line, clone.Remediation)
+		}
+	}
+	if after := models.ComputeTechnicalDebt(result.Violations).Minutes; after != before+minutes*len(clones) {
+		t.Errorf("Expected %d clones to add %d minutes to %d, got %d", len(clones), minutes*len(clones), before, after)
+	}
+}
+
+// TestViolationDetectorChecksRustSyntaxNodes tests that nesting depth and the
+// names of statics and type aliases are checked on the syntax tree, keeping
+// else-if chains flat and foreign statics out
+func TestViolationDetectorChecksRustSyntaxNodes(t *testing.T) {
+	source := `static counter: u32 = 0;
+type byte_count = usize;
+
+extern "C" {
+    static errno: i32;
+}
+
+fn deep(items: &[Option<u32>]) -> u32 {
+    let mut total = 0;
+    for item in items {
+        if let Some(value) = item {
+            match value {
+                0 => {}
+                _ => {
+                    while total < *value {
+                        total += 1;
+                    }
+                }
+            }
+        }
+    }
+    total
+}
+
+fn chained(n: u32) -> u32 {
+    if n == 0 {
+        0
+    } else if n == 1 {
+        1
+    } else if n == 2 {
+        2
+    } else if n == 3 {
+        3
+    } else {
+        4
+    }
+}
+`
+	path := "src/nodes.rs"
+	astInfo := &types.RustASTInfo{FilePath: path}
+	NewRustSyntaxParser([]byte(source)).Parse(astInfo)
+	result := &models.ScanResult{
+		File:        &models.FileInfo{Path: path, Language: "Rust"},
+		RustASTInfo: astInfo,
+	}
+	NewViolationDetector(nil).DetectViolations(result)
+
+	found := make(map[string][]*models.Violation)
+	for _, v := range result.Violations {
+		found[v.Rule] = append(found[v.Rule], v)
+	}
+	if nesting := found["rust-nesting-depth"]; len(nesting) != 1 || nesting[0].Line != 8 || !strings.Contains(nesting[0].Message, "(4, max: 3)") {
+		t.Errorf("Expected only deep to nest too deeply, at depth 4, got %v", nesting)
+	}
+	if statics := found[violations.RustInvalidStaticNaming]; len(statics) != 1 || statics[0].Line != 1 || statics[0].Fix == nil {
+		t.Errorf("Expected counter, and not the foreign errno, with a rename fix, got %v", statics)
+	}
+	if aliases := found[violations.RustInvalidTypeAlias]; len(aliases) != 1 || aliases[0].Line != 2 {
+		t.Errorf("Expected the byte_count alias, got %v", aliases)
+	}
+}
+
+// TestViolationDetectorAppliesSuppressions tests that goclean:ignore hides only
+// the named rule on its target line and that unused and rule-less directives
+// are reported
+func TestViolationDetectorAppliesSuppressions(t *testing.T) {
+	source := `package sample
+
+// goclean:ignore parameter-count "mirrors the C API"
+func mix(a, b, c, d, e, f int) int { return a + b + c + d + e + f }
+
+func blend(a, b, c, d, e, f int) int { return a * b * c * d * e * f } // goclean:ignore function-length
+
+// goclean:ignore
+func plain() {}
+`
+	path := filepath.Join(t.TempDir(), "sample.go")
+	if err := os.WriteFile(path, []byte(source), 0644); err != nil {
+		t.Fatalf("Failed to write test file: %v", err)
+	}
+	astInfo, err := NewASTAnalyzer(false).AnalyzeGoFile(path, []byte(source))
+	if err != nil {
+		t.Fatalf("Failed to analyze test file: %v", err)
+	}
+
+	config := violations.DefaultDetectorConfig()
+	config.MaxParameters = 4
+	config.ReportUnusedSuppressions = true
+	result := &models.ScanResult{
+		File:         &models.FileInfo{Path: path, Language: "Go"},
+		ASTInfo:      astInfo,
+		Suppressions: ParseSuppressions([]byte(source), "Go"),
+	}
+	NewViolationDetector(config).DetectViolations(result)
+
+	found := make(map[string]int)
+	for _, v := range result.Violations {
+		if v.Rule == "parameter-count" && v.Line == 4 {
+			t.Error("Expected the parameter count violation of mix to be suppressed")
+		}
+		found[fmt.Sprintf("%s:%d", v.Rule, v.Line)]++
+	}
+	for _, key := range []string{"parameter-count:6", "unused-suppression:6", "invalid-suppression:8"} {
+		if found[key] != 1 {
+			t.Errorf("Expected one %s violation, got %d", key, found[key])
+		}
+	}
+
+	if len(result.Suppressed) != 1 || result.Suppressed[0].Rule != "parameter-count" {
+		t.Fatalf("Expected the parameter count violation in Suppressed, got %v", result.Suppressed)
+	}
+	if result.Suppressed[0].Fingerprint == "" {
+		t.Error("Expected suppressed violations to keep their fingerprint")
+	}
+	if result.Suppressed[0].Triage != models.TriageSuppressed {
+		t.Errorf("Expected suppressed violations to be marked suppressed, got %q", result.Suppressed[0].Triage)
+	}
+}
+
+// TestViolationDetectorSuppressesClones tests that clones, which are reported
+// after every file was detected, honor goclean:ignore directives
+func TestViolationDetectorSuppressesClones(t *testing.T) {
+	body := ` + "`" + `(name string, email string) error {
+	if len(name) < 2 || len(name) > 50 {
+		return errors.New("invalid name length")
+	}
+	if !strings.Contains(email, "@") {
+		return errors.New("invalid email")
+	}
+	return nil
+}
+` + "`" + `
+	source := "package sample\n\nfunc checkUser" + body + "\n// goclean:ignore code-clone \"kept apart on purpose\"\nfunc checkAdmin" + body
+	path := filepath.Join(t.TempDir(), "sample.go")
+	if err := os.WriteFile(path, []byte(source), 0644); err != nil {
+		t.Fatalf("Failed to write test file: %v", err)
+	}
+	astInfo, err := NewASTAnalyzer(false).AnalyzeGoFile(path, []byte(source))
+	if err != nil {
+		t.Fatalf("Failed to analyze test file: %v", err)
+	}
+
+	config := violations.DefaultDetectorConfig()
+	config.MinCloneTokens = 30
+	config.ReportUnusedSuppressions = true
+	result := &models.ScanResult{
+		File:         &models.FileInfo{Path: path, Language: "Go"},
+		ASTInfo:      astInfo,
+		Suppressions: ParseSuppressions([]byte(source), "Go"),
+	}
+	detector := NewViolationDetector(config)
+	detector.DetectViolations(result)
+	detector.DetectClones([]*models.ScanResult{result})
+
+	for _, v := range result.Violations {
+		if v.Rule == "code-clone" || v.Rule == "unused-suppression" {
+			t.Errorf("Expected no %s violation, got %q on line %d", v.Rule, v.Message, v.Line)
+		}
+	}
+	if len(result.Suppressed) != 1 || result.Suppressed[0].Rule != "code-clone" || result.Suppressed[0].Line != 14 {
+		t.Fatalf("Expected the clone of checkUser in Suppressed, got %v", result.Suppressed)
+	}
+	if result.Suppressed[0].Fingerprint == "" {
+		t.Error("Expected suppressed clones to have a fingerprint")
+	}
+}
+
+func TestViolationDetectorReportsDeprecatedSuppressionRules(t *testing.T) {
+	source := `package sample
+
+func run() {} // goclean:ignore rust-missing-documentation
+`
+	path := filepath.Join(t.TempDir(), "sample.go")
+	if err := os.WriteFile(path, []byte(source), 0644); err != nil {
+		t.Fatalf("Failed to write test file: %v", err)
+	}
+	astInfo, err := NewASTAnalyzer(false).AnalyzeGoFile(path, []byte(source))
+	if err != nil {
+		t.Fatalf("Failed to analyze test file: %v", err)
+	}
+
+	result := &models.ScanResult{
+		File:         &models.FileInfo{Path: path, Language: "Go"},
+		ASTInfo:      astInfo,
+		Suppressions: ParseSuppressions([]byte(source), "Go"),
+	}
+	NewViolationDetector(violations.DefaultDetectorConfig()).DetectViolations(result)
+
+	var deprecated []*models.Violation
+	for _, v := range result.Violations {
+		if v.Rule == "deprecated-rule" {
+			deprecated = append(deprecated, v)
+		}
+	}
+	if len(deprecated) != 1 || deprecated[0].Line != 3 {
+		t.Fatalf("Expected one deprecated-rule violation on line 3, got %v", deprecated)
+	}
+	if !strings.Contains(deprecated[0].Message, "split into") || !strings.Contains(deprecated[0].Suggestion, "rust-missing-function-documentation") {
+		t.Errorf("Expected the migration to be explained, got %q / %q", deprecated[0].Message, deprecated[0].Suggestion)
+	}
+}
+
+// TestViolationDetectorAppliesRuleSeverities tests that configured rule
+// severities replace the classified severity of matching violations only
+func TestViolationDetectorAppliesRuleSeverities(t *testing.T) {
+	source := `package sample
+
+func mix(a, b, c, d, e, f int) int { return a + b + c + d + e + f }
+
+// goclean:ignore function-length
+func plain() {}
+`
+	path := filepath.Join(t.TempDir(), "sample.go")
+	if err := os.WriteFile(path, []byte(source), 0644); err != nil {
+		t.Fatalf("Failed to write test file: %v", err)
+	}
+	astInfo, err := NewASTAnalyzer(false).AnalyzeGoFile(path, []byte(source))
+	if err != nil {
+		t.Fatalf("Failed to analyze test file: %v", err)
+	}
+
+	config := violations.DefaultDetectorConfig()
+	config.MaxParameters = 4
+	config.ReportUnusedSuppressions = true
+	config.SeverityConfig.RuleSeverities = map[string]models.Severity{
+		"parameter-count":    models.SeverityCritical,
+		"unused-suppression": models.SeverityInfo,
+	}
+	result := &models.ScanResult{
+		File:         &models.FileInfo{Path: path, Language: "Go"},
+		ASTInfo:      astInfo,
+		Suppressions: ParseSuppressions([]byte(source), "Go"),
+	}
+	NewViolationDetector(config).DetectViolations(result)
+
+	found := make(map[string]models.Severity)
+	for _, v := range result.Violations {
+		found[v.Rule] = v.Severity
+	}
+	if severity, ok := found["parameter-count"]; !ok || severity != models.SeverityCritical {
+		t.Errorf("Expected parameter-count at Critical, got %v", found)
+	}
+	if severity, ok := found["unused-suppression"]; !ok || severity != models.SeverityInfo {
+		t.Errorf("Expected unused-suppression at Info, got %v", found)
+	}
+}
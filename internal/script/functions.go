@@ -0,0 +1,248 @@
+package script
+
+import (
+	"fmt"
+	"strings"
+	"unicode/utf8"
+)
+
+// builtin is a function scripts can call. It receives its arguments
+// unevaluated so that it can apply lambdas to list elements.
+type builtin struct {
+	minArgs int
+	maxArgs int
+	call    func(ev *evaluator, args []node) (interface{}, error)
+}
+
+// arity describes the number of arguments a builtin takes
+func (b *builtin) arity() string {
+	if b.minArgs == b.maxArgs {
+		if b.minArgs == 1 {
+			return "1 argument"
+		}
+		return fmt.Sprintf("%d arguments", b.minArgs)
+	}
+	return fmt.Sprintf("%d to %d arguments", b.minArgs, b.maxArgs)
+}
+
+// builtins are the functions of the language, by name
+var builtins map[string]*builtin
+
+func init() {
+	builtins = map[string]*builtin{
+		"len":         {1, 1, builtinLen},
+		"lower":       {1, 1, stringFunction(strings.ToLower)},
+		"upper":       {1, 1, stringFunction(strings.ToUpper)},
+		"trim":        {1, 1, stringFunction(strings.TrimSpace)},
+		"str":         {1, 1, builtinStr},
+		"contains":    {2, 2, stringPredicate(strings.Contains)},
+		"starts_with": {2, 2, stringPredicate(strings.HasPrefix)},
+		"ends_with":   {2, 2, stringPredicate(strings.HasSuffix)},
+		"matches":     {2, 2, builtinMatches},
+		"any":         {2, 2, builtinAny},
+		"all":         {2, 2, builtinAll},
+		"count":       {1, 2, builtinCount},
+		"filter":      {2, 2, builtinFilter},
+		"sum":         {1, 2, builtinSum},
+	}
+}
+
+// evalArgs evaluates the arguments of a call
+func evalArgs(ev *evaluator, args []node) ([]interface{}, error) {
+	values := make([]interface{}, len(args))
+	for i, arg := range args {
+		value, err := ev.eval(arg)
+		if err != nil {
+			return nil, err
+		}
+		values[i] = value
+	}
+	return values, nil
+}
+
+// builtinLen returns the length of a string in characters, of a list or of
+// an object
+func builtinLen(ev *evaluator, args []node) (interface{}, error) {
+	value, err := ev.eval(args[0])
+	if err != nil {
+		return nil, err
+	}
+	switch v := value.(type) {
+	case string:
+		return float64(utf8.RuneCountInString(v)), nil
+	case []interface{}:
+		return float64(len(v)), nil
+	case map[string]interface{}:
+		return float64(len(v)), nil
+	default:
+		return nil, fmt.Errorf("len needs a string, list or object, not %s", typeName(value))
+	}
+}
+
+// builtinStr formats any value as a string
+func builtinStr(ev *evaluator, args []node) (interface{}, error) {
+	value, err := ev.eval(args[0])
+	if err != nil {
+		return nil, err
+	}
+	return Format(value), nil
+}
+
+// stringFunction wraps a function of one string
+func stringFunction(fn func(string) string) func(*evaluator, []node) (interface{}, error) {
+	return func(ev *evaluator, args []node) (interface{}, error) {
+		value, err := ev.eval(args[0])
+		if err != nil {
+			return nil, err
+		}
+		text, ok := value.(string)
+		if !ok {
+			return nil, fmt.Errorf("expected a string, got %s", typeName(value))
+		}
+		return fn(text), nil
+	}
+}
+
+// stringPredicate wraps a test of a string against another
+func stringPredicate(fn func(string, string) bool) func(*evaluator, []node) (interface{}, error) {
+	return func(ev *evaluator, args []node) (interface{}, error) {
+		values, err := evalArgs(ev, args)
+		if err != nil {
+			return nil, err
+		}
+		text, textOK := values[0].(string)
+		other, otherOK := values[1].(string)
+		if !textOK || !otherOK {
+			return nil, fmt.Errorf("expected two strings, got %s and %s", typeName(values[0]), typeName(values[1]))
+		}
+		return fn(text, other), nil
+	}
+}
+
+// builtinMatches reports whether a string matches a regular expression
+func builtinMatches(ev *evaluator, args []node) (interface{}, error) {
+	values, err := evalArgs(ev, args)
+	if err != nil {
+		return nil, err
+	}
+	text, textOK := values[0].(string)
+	pattern, patternOK := values[1].(string)
+	if !textOK || !patternOK {
+		return nil, fmt.Errorf("matches needs a string and a pattern, not %s and %s", typeName(values[0]), typeName(values[1]))
+	}
+	compiled, err := ev.program.regexp(pattern)
+	if err != nil {
+		return nil, err
+	}
+	return compiled.MatchString(text), nil
+}
+
+// eachElement evaluates the list argument of a call and applies the lambda
+// argument to each element until visit returns false
+func eachElement(ev *evaluator, name string, args []node, visit func(item, result interface{}) (bool, error)) error {
+	value, err := ev.eval(args[0])
+	if err != nil {
+		return err
+	}
+	list, ok := value.([]interface{})
+	if !ok {
+		return fmt.Errorf("%s needs a list, not %s", name, typeName(value))
+	}
+
+	var lambda *lambdaNode
+	if len(args) > 1 {
+		if lambda, ok = args[1].(*lambdaNode); !ok {
+			return fmt.Errorf("%s needs a function like x -> x.lines > 10 as second argument", name)
+		}
+	}
+
+	for _, item := range list {
+		result := normalize(item)
+		if lambda != nil {
+			if result, err = ev.apply(lambda, item); err != nil {
+				return err
+			}
+		}
+		more, err := visit(item, result)
+		if err != nil || !more {
+			return err
+		}
+	}
+	return nil
+}
+
+// predicateResult checks that a lambda produced a boolean
+func predicateResult(name string, result interface{}) (bool, error) {
+	value, ok := result.(bool)
+	if !ok {
+		return false, fmt.Errorf("the function of %s must return a boolean, not %s", name, typeName(result))
+	}
+	return value, nil
+}
+
+// builtinAny reports whether the function is true for some element
+func builtinAny(ev *evaluator, args []node) (interface{}, error) {
+	found := false
+	err := eachElement(ev, "any", args, func(item, result interface{}) (bool, error) {
+		matched, err := predicateResult("any", result)
+		found = matched
+		return !matched, err
+	})
+	return found, err
+}
+
+// builtinAll reports whether the function is true for every element
+func builtinAll(ev *evaluator, args []node) (interface{}, error) {
+	every := true
+	err := eachElement(ev, "all", args, func(item, result interface{}) (bool, error) {
+		matched, err := predicateResult("all", result)
+		every = matched
+		return matched, err
+	})
+	return every, err
+}
+
+// builtinCount counts the elements for which the function is true, or all
+// elements without a function
+func builtinCount(ev *evaluator, args []node) (interface{}, error) {
+	count := 0
+	err := eachElement(ev, "count", args, func(item, result interface{}) (bool, error) {
+		if len(args) == 1 {
+			count++
+			return true, nil
+		}
+		matched, err := predicateResult("count", result)
+		if matched {
+			count++
+		}
+		return true, err
+	})
+	return float64(count), err
+}
+
+// builtinFilter returns the elements for which the function is true
+func builtinFilter(ev *evaluator, args []node) (interface{}, error) {
+	kept := []interface{}{}
+	err := eachElement(ev, "filter", args, func(item, result interface{}) (bool, error) {
+		matched, err := predicateResult("filter", result)
+		if matched {
+			kept = append(kept, item)
+		}
+		return true, err
+	})
+	return kept, err
+}
+
+// builtinSum adds the elements, or the values the function returns for them
+func builtinSum(ev *evaluator, args []node) (interface{}, error) {
+	total := 0.0
+	err := eachElement(ev, "sum", args, func(item, result interface{}) (bool, error) {
+		number, ok := result.(float64)
+		if !ok {
+			return false, fmt.Errorf("sum needs numbers, not %s", typeName(result))
+		}
+		total += number
+		return true, nil
+	})
+	return total, err
+}
@@ -0,0 +1,399 @@
+package script
+
+import (
+	"fmt"
+	"math"
+	"strings"
+)
+
+// node is an element of the syntax tree of an expression
+type node interface {
+	eval(ev *evaluator) (interface{}, error)
+	// variables adds the free variables of the node to found; bound holds
+	// the lambda parameters in scope
+	variables(found map[string]bool, bound []string)
+}
+
+type literalNode struct {
+	value interface{}
+}
+
+type identNode struct {
+	name string
+}
+
+type memberNode struct {
+	object node
+	name   string
+}
+
+type indexNode struct {
+	object node
+	index  node
+}
+
+type listNode struct {
+	items []node
+}
+
+type unaryNode struct {
+	op      string
+	operand node
+}
+
+type binaryNode struct {
+	op          string
+	left, right node
+}
+
+type callNode struct {
+	name     string
+	function *builtin
+	args     []node
+}
+
+type lambdaNode struct {
+	param string
+	body  node
+}
+
+func (n *literalNode) eval(ev *evaluator) (interface{}, error) {
+	return n.value, nil
+}
+
+func (n *identNode) eval(ev *evaluator) (interface{}, error) {
+	return ev.lookup(n.name)
+}
+
+// eval returns a field of an object, or null when it has no such field
+func (n *memberNode) eval(ev *evaluator) (interface{}, error) {
+	object, err := ev.eval(n.object)
+	if err != nil {
+		return nil, err
+	}
+	fields, ok := object.(map[string]interface{})
+	if !ok {
+		return nil, fmt.Errorf("cannot read .%s of a %s", n.name, typeName(object))
+	}
+	return fields[n.name], nil
+}
+
+// eval returns an element of a list, counting from the end for negative
+// indexes, a field of an object or a character of a string
+func (n *indexNode) eval(ev *evaluator) (interface{}, error) {
+	object, err := ev.eval(n.object)
+	if err != nil {
+		return nil, err
+	}
+	index, err := ev.eval(n.index)
+	if err != nil {
+		return nil, err
+	}
+
+	switch container := object.(type) {
+	case map[string]interface{}:
+		key, ok := index.(string)
+		if !ok {
+			return nil, fmt.Errorf("object keys are strings, not %s", typeName(index))
+		}
+		return container[key], nil
+	case []interface{}:
+		position, err := listIndex(index, len(container))
+		if err != nil {
+			return nil, err
+		}
+		return container[position], nil
+	case string:
+		runes := []rune(container)
+		position, err := listIndex(index, len(runes))
+		if err != nil {
+			return nil, err
+		}
+		return string(runes[position]), nil
+	default:
+		return nil, fmt.Errorf("cannot index a %s", typeName(object))
+	}
+}
+
+// listIndex checks an index into a sequence of length elements
+func listIndex(index interface{}, length int) (int, error) {
+	number, ok := index.(float64)
+	if !ok || number != math.Trunc(number) {
+		return 0, fmt.Errorf("index %s is not a whole number", Format(index))
+	}
+	position := int(number)
+	if position < 0 {
+		position += length
+	}
+	if position < 0 || position >= length {
+		return 0, fmt.Errorf("index %d out of range for length %d", int(number), length)
+	}
+	return position, nil
+}
+
+func (n *listNode) eval(ev *evaluator) (interface{}, error) {
+	items := make([]interface{}, 0, len(n.items))
+	for _, item := range n.items {
+		value, err := ev.eval(item)
+		if err != nil {
+			return nil, err
+		}
+		items = append(items, value)
+	}
+	return items, nil
+}
+
+func (n *unaryNode) eval(ev *evaluator) (interface{}, error) {
+	operand, err := ev.eval(n.operand)
+	if err != nil {
+		return nil, err
+	}
+	switch n.op {
+	case "!":
+		value, ok := operand.(bool)
+		if !ok {
+			return nil, fmt.Errorf("! needs a boolean, not %s", typeName(operand))
+		}
+		return !value, nil
+	default:
+		value, ok := operand.(float64)
+		if !ok {
+			return nil, fmt.Errorf("- needs a number, not %s", typeName(operand))
+		}
+		return -value, nil
+	}
+}
+
+func (n *binaryNode) eval(ev *evaluator) (interface{}, error) {
+	left, err := ev.eval(n.left)
+	if err != nil {
+		return nil, err
+	}
+
+	// The logical operators evaluate their right side only when needed
+	if n.op == "&&" || n.op == "||" {
+		value, ok := left.(bool)
+		if !ok {
+			return nil, fmt.Errorf("%s needs booleans, not %s", n.op, typeName(left))
+		}
+		if value == (n.op == "||") {
+			return value, nil
+		}
+		right, err := ev.eval(n.right)
+		if err != nil {
+			return nil, err
+		}
+		if _, ok := right.(bool); !ok {
+			return nil, fmt.Errorf("%s needs booleans, not %s", n.op, typeName(right))
+		}
+		return right, nil
+	}
+
+	right, err := ev.eval(n.right)
+	if err != nil {
+		return nil, err
+	}
+	switch n.op {
+	case "==":
+		return equal(left, right), nil
+	case "!=":
+		return !equal(left, right), nil
+	case "<", "<=", ">", ">=":
+		return compare(n.op, left, right)
+	case "=~", "!~":
+		text, textOK := left.(string)
+		pattern, patternOK := right.(string)
+		if !textOK || !patternOK {
+			return nil, fmt.Errorf("%s needs a string and a pattern, not %s and %s", n.op, typeName(left), typeName(right))
+		}
+		compiled, err := ev.program.regexp(pattern)
+		if err != nil {
+			return nil, err
+		}
+		return compiled.MatchString(text) == (n.op == "=~"), nil
+	case "in":
+		return contains(right, left)
+	case "+":
+		return add(left, right)
+	default:
+		return arithmetic(n.op, left, right)
+	}
+}
+
+// compare orders two numbers or two strings
+func compare(op string, left, right interface{}) (bool, error) {
+	var order int
+	switch l := left.(type) {
+	case float64:
+		r, ok := right.(float64)
+		if !ok {
+			return false, fmt.Errorf("cannot compare a number with %s", typeName(right))
+		}
+		order = compareOrdered(l, r)
+	case string:
+		r, ok := right.(string)
+		if !ok {
+			return false, fmt.Errorf("cannot compare a string with %s", typeName(right))
+		}
+		order = strings.Compare(l, r)
+	default:
+		return false, fmt.Errorf("cannot order a %s", typeName(left))
+	}
+
+	switch op {
+	case "<":
+		return order < 0, nil
+	case "<=":
+		return order <= 0, nil
+	case ">":
+		return order > 0, nil
+	default:
+		return order >= 0, nil
+	}
+}
+
+func compareOrdered(a, b float64) int {
+	switch {
+	case a < b:
+		return -1
+	case a > b:
+		return 1
+	}
+	return 0
+}
+
+// contains reports whether a list holds a value, a string holds a substring
+// or an object has a field
+func contains(container, value interface{}) (bool, error) {
+	switch c := container.(type) {
+	case []interface{}:
+		for _, item := range c {
+			if equal(item, value) {
+				return true, nil
+			}
+		}
+		return false, nil
+	case string:
+		text, ok := value.(string)
+		if !ok {
+			return false, fmt.Errorf("cannot look for a %s in a string", typeName(value))
+		}
+		return strings.Contains(c, text), nil
+	case map[string]interface{}:
+		key, ok := value.(string)
+		if !ok {
+			return false, fmt.Errorf("object keys are strings, not %s", typeName(value))
+		}
+		_, found := c[key]
+		return found, nil
+	default:
+		return false, fmt.Errorf("cannot look inside a %s", typeName(container))
+	}
+}
+
+// add sums numbers, joins lists and concatenates anything with a string
+func add(left, right interface{}) (interface{}, error) {
+	l, leftNumber := left.(float64)
+	r, rightNumber := right.(float64)
+	if leftNumber && rightNumber {
+		return l + r, nil
+	}
+
+	leftList, leftIsList := left.([]interface{})
+	rightList, rightIsList := right.([]interface{})
+	if leftIsList && rightIsList {
+		joined := make([]interface{}, 0, len(leftList)+len(rightList))
+		return append(append(joined, leftList...), rightList...), nil
+	}
+
+	_, leftString := left.(string)
+	_, rightString := right.(string)
+	if leftString || rightString {
+		joined := Format(left) + Format(right)
+		if len(joined) > maxStringLength {
+			return nil, fmt.Errorf("string longer than %d bytes", maxStringLength)
+		}
+		return joined, nil
+	}
+	return nil, fmt.Errorf("cannot add %s and %s", typeName(left), typeName(right))
+}
+
+// arithmetic applies -, *, / or % to two numbers
+func arithmetic(op string, left, right interface{}) (interface{}, error) {
+	l, leftOK := left.(float64)
+	r, rightOK := right.(float64)
+	if !leftOK || !rightOK {
+		return nil, fmt.Errorf("%s needs numbers, not %s and %s", op, typeName(left), typeName(right))
+	}
+	switch op {
+	case "-":
+		return l - r, nil
+	case "*":
+		return l * r, nil
+	case "/":
+		if r == 0 {
+			return nil, fmt.Errorf("division by zero")
+		}
+		return l / r, nil
+	default:
+		if r == 0 {
+			return nil, fmt.Errorf("division by zero")
+		}
+		return math.Mod(l, r), nil
+	}
+}
+
+func (n *callNode) eval(ev *evaluator) (interface{}, error) {
+	return n.function.call(ev, n.args)
+}
+
+// eval is never called: lambdas are only arguments of builtins, which
+// apply them
+func (n *lambdaNode) eval(ev *evaluator) (interface{}, error) {
+	return nil, fmt.Errorf("a function argument %s -> ... cannot be used as a value", n.param)
+}
+
+func (n *literalNode) variables(found map[string]bool, bound []string) {}
+
+func (n *identNode) variables(found map[string]bool, bound []string) {
+	for _, name := range bound {
+		if name == n.name {
+			return
+		}
+	}
+	found[n.name] = true
+}
+
+func (n *memberNode) variables(found map[string]bool, bound []string) {
+	n.object.variables(found, bound)
+}
+
+func (n *indexNode) variables(found map[string]bool, bound []string) {
+	n.object.variables(found, bound)
+	n.index.variables(found, bound)
+}
+
+func (n *listNode) variables(found map[string]bool, bound []string) {
+	for _, item := range n.items {
+		item.variables(found, bound)
+	}
+}
+
+func (n *unaryNode) variables(found map[string]bool, bound []string) {
+	n.operand.variables(found, bound)
+}
+
+func (n *binaryNode) variables(found map[string]bool, bound []string) {
+	n.left.variables(found, bound)
+	n.right.variables(found, bound)
+}
+
+func (n *callNode) variables(found map[string]bool, bound []string) {
+	for _, arg := range n.args {
+		arg.variables(found, bound)
+	}
+}
+
+func (n *lambdaNode) variables(found map[string]bool, bound []string) {
+	n.body.variables(found, append(bound[:len(bound):len(bound)], n.param))
+}
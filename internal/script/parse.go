@@ -0,0 +1,353 @@
+package script
+
+import (
+	"fmt"
+	"strconv"
+	"strings"
+	"unicode"
+)
+
+// tokenKind classifies the tokens of an expression
+type tokenKind int
+
+const (
+	tokenEOF tokenKind = iota
+	tokenNumber
+	tokenString
+	tokenIdent
+	tokenOperator
+)
+
+// token is a lexical element of an expression
+type token struct {
+	kind  tokenKind
+	text  string
+	value interface{} // Parsed value of numbers and strings
+	pos   int
+}
+
+// operators lists the operator tokens, longest first so "<=" wins over "<"
+var operators = []string{"->", "==", "!=", "<=", ">=", "=~", "!~", "&&", "||",
+	"(", ")", "[", "]", ",", ".", "!", "<", ">", "+", "-", "*", "/", "%"}
+
+// binaryPrecedence gives the binding strength of binary operators
+var binaryPrecedence = map[string]int{
+	"||": 1, "or": 1,
+	"&&": 2, "and": 2,
+	"==": 3, "!=": 3, "<": 3, "<=": 3, ">": 3, ">=": 3, "=~": 3, "!~": 3, "in": 3,
+	"+": 4, "-": 4,
+	"*": 5, "/": 5, "%": 5,
+}
+
+// tokenize splits an expression into tokens
+func tokenize(source string) ([]token, error) {
+	var tokens []token
+	for i := 0; i < len(source); {
+		c := rune(source[i])
+		switch {
+		case unicode.IsSpace(c):
+			i++
+		case c >= '0' && c <= '9':
+			start := i
+			for i < len(source) && (source[i] >= '0' && source[i] <= '9' || source[i] == '.' || source[i] == '_') {
+				i++
+			}
+			text := source[start:i]
+			number, err := strconv.ParseFloat(strings.ReplaceAll(text, "_", ""), 64)
+			if err != nil {
+				return nil, fmt.Errorf("invalid number %q at column %d", text, start+1)
+			}
+			tokens = append(tokens, token{kind: tokenNumber, text: text, value: number, pos: start})
+		case c == '"' || c == '\'':
+			end := closingQuote(source, i)
+			if end < 0 {
+				return nil, fmt.Errorf("unterminated string at column %d", i+1)
+			}
+			text := source[i : end+1]
+			value, err := unquote(text)
+			if err != nil {
+				return nil, fmt.Errorf("invalid string %s at column %d", text, i+1)
+			}
+			tokens = append(tokens, token{kind: tokenString, text: text, value: value, pos: i})
+			i = end + 1
+		case c == '_' || unicode.IsLetter(c):
+			start := i
+			for i < len(source) && (source[i] == '_' || unicode.IsLetter(rune(source[i])) || unicode.IsDigit(rune(source[i]))) {
+				i++
+			}
+			tokens = append(tokens, token{kind: tokenIdent, text: source[start:i], pos: start})
+		default:
+			operator := ""
+			for _, candidate := range operators {
+				if strings.HasPrefix(source[i:], candidate) {
+					operator = candidate
+					break
+				}
+			}
+			if operator == "" {
+				return nil, fmt.Errorf("unexpected character %q at column %d", c, i+1)
+			}
+			tokens = append(tokens, token{kind: tokenOperator, text: operator, pos: i})
+			i += len(operator)
+		}
+	}
+	return append(tokens, token{kind: tokenEOF, pos: len(source)}), nil
+}
+
+// closingQuote returns the index of the quote ending the string starting at
+// start, or -1 when the string is not terminated
+func closingQuote(source string, start int) int {
+	quote := source[start]
+	for i := start + 1; i < len(source); i++ {
+		switch source[i] {
+		case '\\':
+			i++
+		case quote:
+			return i
+		}
+	}
+	return -1
+}
+
+// unquote decodes a double- or single-quoted string with Go escapes
+func unquote(text string) (string, error) {
+	if text[0] == '"' {
+		return strconv.Unquote(text)
+	}
+	var body strings.Builder
+	for i := 1; i < len(text)-1; i++ {
+		switch {
+		case text[i] == '\\' && text[i+1] == '\'':
+			body.WriteByte('\'')
+			i++
+		case text[i] == '\\':
+			body.WriteString(text[i : i+2])
+			i++
+		case text[i] == '"':
+			body.WriteString(`\"`)
+		default:
+			body.WriteByte(text[i])
+		}
+	}
+	return strconv.Unquote(`"` + body.String() + `"`)
+}
+
+// parser builds the syntax tree of an expression
+type parser struct {
+	tokens []token
+	pos    int
+}
+
+// parse parses a complete expression
+func parse(source string) (node, error) {
+	tokens, err := tokenize(source)
+	if err != nil {
+		return nil, err
+	}
+	p := &parser{tokens: tokens}
+	root, err := p.expression(1)
+	if err != nil {
+		return nil, err
+	}
+	if next := p.peek(); next.kind != tokenEOF {
+		return nil, p.unexpected(next)
+	}
+	return root, nil
+}
+
+func (p *parser) peek() token {
+	return p.tokens[p.pos]
+}
+
+func (p *parser) next() token {
+	t := p.tokens[p.pos]
+	if t.kind != tokenEOF {
+		p.pos++
+	}
+	return t
+}
+
+// accept consumes the next token when it is the given operator or keyword
+func (p *parser) accept(text string) bool {
+	if t := p.peek(); (t.kind == tokenOperator || t.kind == tokenIdent) && t.text == text {
+		p.pos++
+		return true
+	}
+	return false
+}
+
+func (p *parser) expect(text string) error {
+	if !p.accept(text) {
+		return fmt.Errorf("expected %q at column %d", text, p.peek().pos+1)
+	}
+	return nil
+}
+
+func (p *parser) unexpected(t token) error {
+	if t.kind == tokenEOF {
+		return fmt.Errorf("unexpected end of expression")
+	}
+	return fmt.Errorf("unexpected %q at column %d", t.text, t.pos+1)
+}
+
+// expression parses binary operations binding at least as strongly as
+// minPrecedence
+func (p *parser) expression(minPrecedence int) (node, error) {
+	left, err := p.unary()
+	if err != nil {
+		return nil, err
+	}
+	for {
+		t := p.peek()
+		precedence, isBinary := binaryPrecedence[t.text]
+		if t.kind != tokenOperator && t.kind != tokenIdent || !isBinary || precedence < minPrecedence {
+			return left, nil
+		}
+		p.next()
+		right, err := p.expression(precedence + 1)
+		if err != nil {
+			return nil, err
+		}
+		left = &binaryNode{op: normalizeOperator(t.text), left: left, right: right}
+	}
+}
+
+// normalizeOperator maps keyword operators to their symbols
+func normalizeOperator(op string) string {
+	switch op {
+	case "and":
+		return "&&"
+	case "or":
+		return "||"
+	case "not":
+		return "!"
+	}
+	return op
+}
+
+// unary parses prefix operators
+func (p *parser) unary() (node, error) {
+	for _, op := range []string{"!", "not", "-"} {
+		if p.accept(op) {
+			operand, err := p.unary()
+			if err != nil {
+				return nil, err
+			}
+			return &unaryNode{op: normalizeOperator(op), operand: operand}, nil
+		}
+	}
+	return p.postfix()
+}
+
+// postfix parses member access and indexing after a primary expression
+func (p *parser) postfix() (node, error) {
+	value, err := p.primary()
+	if err != nil {
+		return nil, err
+	}
+	for {
+		switch {
+		case p.accept("."):
+			name := p.next()
+			if name.kind != tokenIdent {
+				return nil, p.unexpected(name)
+			}
+			value = &memberNode{object: value, name: name.text}
+		case p.accept("["):
+			index, err := p.expression(1)
+			if err != nil {
+				return nil, err
+			}
+			if err := p.expect("]"); err != nil {
+				return nil, err
+			}
+			value = &indexNode{object: value, index: index}
+		default:
+			return value, nil
+		}
+	}
+}
+
+// primary parses literals, variables, calls, lists and parentheses
+func (p *parser) primary() (node, error) {
+	t := p.next()
+	switch t.kind {
+	case tokenNumber, tokenString:
+		return &literalNode{value: t.value}, nil
+	case tokenIdent:
+		switch t.text {
+		case "true":
+			return &literalNode{value: true}, nil
+		case "false":
+			return &literalNode{value: false}, nil
+		case "null", "nil":
+			return &literalNode{value: nil}, nil
+		}
+		if p.accept("(") {
+			return p.call(t)
+		}
+		return &identNode{name: t.text}, nil
+	case tokenOperator:
+		switch t.text {
+		case "(":
+			inner, err := p.expression(1)
+			if err != nil {
+				return nil, err
+			}
+			return inner, p.expect(")")
+		case "[":
+			list := &listNode{}
+			for !p.accept("]") {
+				if len(list.items) > 0 {
+					if err := p.expect(","); err != nil {
+						return nil, err
+					}
+				}
+				item, err := p.expression(1)
+				if err != nil {
+					return nil, err
+				}
+				list.items = append(list.items, item)
+			}
+			return list, nil
+		}
+	}
+	return nil, p.unexpected(t)
+}
+
+// call parses the arguments of a builtin function call. Arguments of the
+// form "x -> expression" are lambdas.
+func (p *parser) call(name token) (node, error) {
+	function, found := builtins[name.text]
+	if !found {
+		return nil, fmt.Errorf("unknown function %s at column %d", name.text, name.pos+1)
+	}
+
+	call := &callNode{name: name.text, function: function}
+	for !p.accept(")") {
+		if len(call.args) > 0 {
+			if err := p.expect(","); err != nil {
+				return nil, err
+			}
+		}
+		if t := p.peek(); t.kind == tokenIdent && p.tokens[p.pos+1].text == "->" {
+			p.pos += 2
+			body, err := p.expression(1)
+			if err != nil {
+				return nil, err
+			}
+			call.args = append(call.args, &lambdaNode{param: t.text, body: body})
+			continue
+		}
+		arg, err := p.expression(1)
+		if err != nil {
+			return nil, err
+		}
+		call.args = append(call.args, arg)
+	}
+
+	if len(call.args) < function.minArgs || len(call.args) > function.maxArgs {
+		return nil, fmt.Errorf("%s takes %s at column %d", name.text, function.arity(), name.pos+1)
+	}
+	return call, nil
+}
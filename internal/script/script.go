@@ -0,0 +1,314 @@
+// Package script implements the expression language of custom rules. It is
+// a small language without side effects over the values encoding/json
+// produces; an evaluation cannot reach files, the network or the process,
+// and it stops when it runs out of fuel, one unit per evaluated node.
+package script
+
+import (
+	"errors"
+	"fmt"
+	"math"
+	"reflect"
+	"regexp"
+	"sort"
+	"strconv"
+	"strings"
+	"sync"
+)
+
+// DefaultFuel is the number of steps one evaluation may take by default
+const DefaultFuel = 10000
+
+// maxStringLength bounds the strings an evaluation builds
+const maxStringLength = 1 << 16
+
+// ErrOutOfFuel is returned when an evaluation exceeds its step budget
+var ErrOutOfFuel = errors.New("script exceeded its step budget")
+
+// Program is a compiled expression
+type Program struct {
+	source  string
+	root    node
+	mutex   sync.Mutex
+	regexps map[string]*regexp.Regexp // Patterns compiled by earlier evaluations
+}
+
+// Compile parses an expression
+func Compile(source string) (*Program, error) {
+	root, err := parse(source)
+	if err != nil {
+		return nil, err
+	}
+	return &Program{source: source, root: root, regexps: make(map[string]*regexp.Regexp)}, nil
+}
+
+// String returns the source of the program
+func (p *Program) String() string {
+	return p.source
+}
+
+// Variables returns the names of the variables the program reads, sorted
+func (p *Program) Variables() []string {
+	found := make(map[string]bool)
+	p.root.variables(found, nil)
+
+	names := make([]string, 0, len(found))
+	for name := range found {
+		names = append(names, name)
+	}
+	sort.Strings(names)
+	return names
+}
+
+// Eval evaluates the program with the variables of env, taking at most fuel
+// steps. Values are nil, bool, float64, int, string, []interface{} and
+// map[string]interface{}; integers are returned as float64.
+func (p *Program) Eval(env map[string]interface{}, fuel int) (interface{}, error) {
+	ev := &evaluator{program: p, env: env, fuel: fuel}
+	return ev.eval(p.root)
+}
+
+// EvalBool evaluates a program that must produce a boolean
+func (p *Program) EvalBool(env map[string]interface{}, fuel int) (bool, error) {
+	value, err := p.Eval(env, fuel)
+	if err != nil {
+		return false, err
+	}
+	result, ok := value.(bool)
+	if !ok {
+		return false, fmt.Errorf("expected a boolean, got %s", Format(value))
+	}
+	return result, nil
+}
+
+// regexp returns a compiled pattern, caching it for later evaluations
+func (p *Program) regexp(pattern string) (*regexp.Regexp, error) {
+	p.mutex.Lock()
+	defer p.mutex.Unlock()
+
+	if compiled, found := p.regexps[pattern]; found {
+		return compiled, nil
+	}
+	compiled, err := regexp.Compile(pattern)
+	if err != nil {
+		return nil, fmt.Errorf("invalid pattern %q: %w", pattern, err)
+	}
+	p.regexps[pattern] = compiled
+	return compiled, nil
+}
+
+// Template is text with {expression} placeholders; "{{" and "}}" stand for
+// literal braces
+type Template struct {
+	source string
+	parts  []templatePart
+}
+
+// templatePart is literal text or a placeholder
+type templatePart struct {
+	text    string
+	program *Program
+}
+
+// CompileTemplate parses the placeholders of a template
+func CompileTemplate(source string) (*Template, error) {
+	template := &Template{source: source}
+	var text strings.Builder
+	for i := 0; i < len(source); i++ {
+		switch {
+		case strings.HasPrefix(source[i:], "{{"), strings.HasPrefix(source[i:], "}}"):
+			text.WriteByte(source[i])
+			i++
+		case source[i] == '{':
+			end := closingBrace(source, i)
+			if end < 0 {
+				return nil, fmt.Errorf("unterminated placeholder at column %d", i+1)
+			}
+			program, err := Compile(source[i+1 : end])
+			if err != nil {
+				return nil, fmt.Errorf("placeholder at column %d: %w", i+1, err)
+			}
+			if text.Len() > 0 {
+				template.parts = append(template.parts, templatePart{text: text.String()})
+				text.Reset()
+			}
+			template.parts = append(template.parts, templatePart{program: program})
+			i = end
+		default:
+			text.WriteByte(source[i])
+		}
+	}
+	if text.Len() > 0 {
+		template.parts = append(template.parts, templatePart{text: text.String()})
+	}
+	return template, nil
+}
+
+// closingBrace returns the index of the brace ending the placeholder
+// starting at start, skipping braces in strings, or -1
+func closingBrace(source string, start int) int {
+	for i := start + 1; i < len(source); i++ {
+		switch source[i] {
+		case '"', '\'':
+			end := closingQuote(source, i)
+			if end < 0 {
+				return -1
+			}
+			i = end
+		case '}':
+			return i
+		}
+	}
+	return -1
+}
+
+// Variables returns the names of the variables the placeholders read, sorted
+func (t *Template) Variables() []string {
+	found := make(map[string]bool)
+	for _, part := range t.parts {
+		if part.program != nil {
+			part.program.root.variables(found, nil)
+		}
+	}
+
+	names := make([]string, 0, len(found))
+	for name := range found {
+		names = append(names, name)
+	}
+	sort.Strings(names)
+	return names
+}
+
+// Execute fills in the placeholders, with fuel shared by all of them
+func (t *Template) Execute(env map[string]interface{}, fuel int) (string, error) {
+	var out strings.Builder
+	for _, part := range t.parts {
+		if part.program == nil {
+			out.WriteString(part.text)
+			continue
+		}
+		ev := &evaluator{program: part.program, env: env, fuel: fuel}
+		value, err := ev.eval(part.program.root)
+		if err != nil {
+			return "", err
+		}
+		fuel = ev.fuel
+		out.WriteString(Format(value))
+	}
+	return out.String(), nil
+}
+
+// Format renders a value for messages: whole numbers without a fraction,
+// lists in brackets and null as an empty string
+func Format(value interface{}) string {
+	switch v := value.(type) {
+	case nil:
+		return ""
+	case string:
+		return v
+	case bool:
+		return strconv.FormatBool(v)
+	case float64:
+		if v == math.Trunc(v) && math.Abs(v) < 1e15 {
+			return strconv.FormatInt(int64(v), 10)
+		}
+		return strconv.FormatFloat(v, 'g', -1, 64)
+	case []interface{}:
+		items := make([]string, len(v))
+		for i, item := range v {
+			items[i] = Format(item)
+		}
+		return "[" + strings.Join(items, ", ") + "]"
+	default:
+		return fmt.Sprint(v)
+	}
+}
+
+// evaluator holds the state of one evaluation
+type evaluator struct {
+	program *Program
+	env     map[string]interface{}
+	locals  []local // Lambda parameters in scope, innermost last
+	fuel    int
+}
+
+// local binds a lambda parameter
+type local struct {
+	name  string
+	value interface{}
+}
+
+// eval evaluates a node, charging one unit of fuel
+func (ev *evaluator) eval(n node) (interface{}, error) {
+	ev.fuel--
+	if ev.fuel < 0 {
+		return nil, ErrOutOfFuel
+	}
+	value, err := n.eval(ev)
+	if err != nil {
+		return nil, err
+	}
+	return normalize(value), nil
+}
+
+// lookup returns the value of a variable
+func (ev *evaluator) lookup(name string) (interface{}, error) {
+	for i := len(ev.locals) - 1; i >= 0; i-- {
+		if ev.locals[i].name == name {
+			return ev.locals[i].value, nil
+		}
+	}
+	if value, found := ev.env[name]; found {
+		return value, nil
+	}
+	return nil, fmt.Errorf("unknown variable %s", name)
+}
+
+// apply evaluates a lambda for one value
+func (ev *evaluator) apply(lambda *lambdaNode, value interface{}) (interface{}, error) {
+	ev.locals = append(ev.locals, local{name: lambda.param, value: value})
+	defer func() { ev.locals = ev.locals[:len(ev.locals)-1] }()
+	return ev.eval(lambda.body)
+}
+
+// normalize converts integers to float64 so values compare consistently
+func normalize(value interface{}) interface{} {
+	switch v := value.(type) {
+	case int:
+		return float64(v)
+	case int64:
+		return float64(v)
+	case []string:
+		items := make([]interface{}, len(v))
+		for i, item := range v {
+			items[i] = item
+		}
+		return items
+	}
+	return value
+}
+
+// equal compares two values
+func equal(a, b interface{}) bool {
+	return reflect.DeepEqual(normalize(a), normalize(b))
+}
+
+// typeName names the type of a value in error messages
+func typeName(value interface{}) string {
+	switch value.(type) {
+	case nil:
+		return "null"
+	case bool:
+		return "boolean"
+	case float64:
+		return "number"
+	case string:
+		return "string"
+	case []interface{}:
+		return "list"
+	case map[string]interface{}:
+		return "object"
+	default:
+		return fmt.Sprintf("%T", value)
+	}
+}
@@ -0,0 +1,145 @@
+package script
+
+import (
+	"errors"
+	"reflect"
+	"strings"
+	"testing"
+)
+
+// testEnv describes a function the way the custom rule detector does
+func testEnv() map[string]interface{} {
+	return map[string]interface{}{
+		"name":       "ServeHTTP",
+		"lines":      42,
+		"complexity": 7,
+		"exported":   true,
+		"parameters": []string{"w", "r"},
+		"file":       map[string]interface{}{"path": "internal/api/server.go", "language": "go"},
+		"functions": []interface{}{
+			map[string]interface{}{"name": "ServeHTTP", "lines": 42},
+			map[string]interface{}{"name": "helper", "lines": 5},
+			map[string]interface{}{"name": "render", "lines": 12},
+		},
+	}
+}
+
+func TestEval(t *testing.T) {
+	testCases := []struct {
+		source   string
+		expected interface{}
+	}{
+		{`lines > 40 && exported`, true},
+		{`lines > 40 and not exported`, false},
+		{`complexity * 2 + 1`, 15.0},
+		{`name =~ "^Serve" && name !~ "Test"`, true},
+		{`ends_with(file.path, "_test.go") || starts_with(file.path, 'internal/')`, true},
+		{`"r" in parameters && len(parameters) == 2`, true},
+		{`count(functions, f -> f.lines > 10)`, 2.0},
+		{`any(functions, f -> f.name == lower(f.name) && f.lines < 10)`, true},
+		{`all(functions, f -> f.lines > 10)`, false},
+		{`sum(functions, f -> f.lines) / len(functions)`, 59.0 / 3},
+		{`filter(functions, f -> f.lines > 10)[-1].name`, "render"},
+		{`file["language"] + "-" + lines`, "go-42"},
+		{`matches(upper(name), "HTTP$")`, true},
+		{`[1, 2] + [3] == [1, 2, 3]`, true},
+		{`file.owner == null`, true},
+	}
+	for _, tc := range testCases {
+		program, err := Compile(tc.source)
+		if err != nil {
+			t.Errorf("Compile(%s) failed: %v", tc.source, err)
+			continue
+		}
+		value, err := program.Eval(testEnv(), DefaultFuel)
+		if err != nil {
+			t.Errorf("Eval(%s) failed: %v", tc.source, err)
+			continue
+		}
+		if !reflect.DeepEqual(value, tc.expected) {
+			t.Errorf("Eval(%s) = %v, expected %v", tc.source, value, tc.expected)
+		}
+	}
+}
+
+func TestCompileErrors(t *testing.T) {
+	testCases := map[string]string{
+		`lines >`:             "unexpected end of expression",
+		`lines > 4 )`:         `unexpected ")" at column 11`,
+		`shell("rm -rf /")`:   "unknown function shell",
+		`len(name, lines)`:    "len takes 1 argument",
+		`name == "unfinished`: "unterminated string",
+		`lines # 2`:           "unexpected character '#'",
+	}
+	for source, expected := range testCases {
+		if _, err := Compile(source); err == nil || !strings.Contains(err.Error(), expected) {
+			t.Errorf("Compile(%s): expected an error containing %q, got %v", source, expected, err)
+		}
+	}
+}
+
+func TestEvalErrors(t *testing.T) {
+	testCases := map[string]string{
+		`lines && exported`:            "&& needs booleans",
+		`undefined > 1`:                "unknown variable undefined",
+		`lines / 0`:                    "division by zero",
+		`name < 3`:                     "cannot compare a string",
+		`parameters[5]`:                "out of range",
+		`any(lines, x -> x)`:           "any needs a list",
+		`any(functions, f -> f.lines)`: "must return a boolean",
+	}
+	for source, expected := range testCases {
+		program, err := Compile(source)
+		if err != nil {
+			t.Errorf("Compile(%s) failed: %v", source, err)
+			continue
+		}
+		if _, err := program.Eval(testEnv(), DefaultFuel); err == nil || !strings.Contains(err.Error(), expected) {
+			t.Errorf("Eval(%s): expected an error containing %q, got %v", source, expected, err)
+		}
+	}
+}
+
+func TestEvalStopsWhenOutOfFuel(t *testing.T) {
+	program, err := Compile(`count(functions, f -> any(functions, g -> any(functions, h -> h.lines > f.lines + g.lines)))`)
+	if err != nil {
+		t.Fatalf("Compile failed: %v", err)
+	}
+	if _, err := program.Eval(testEnv(), 20); !errors.Is(err, ErrOutOfFuel) {
+		t.Errorf("Expected ErrOutOfFuel, got %v", err)
+	}
+	if _, err := program.Eval(testEnv(), DefaultFuel); err != nil {
+		t.Errorf("Expected the default fuel to suffice, got %v", err)
+	}
+}
+
+func TestVariablesExcludeLambdaParameters(t *testing.T) {
+	program, err := Compile(`lines > 10 && any(functions, f -> f.lines > lines) && len(name) > 3`)
+	if err != nil {
+		t.Fatalf("Compile failed: %v", err)
+	}
+	if got := program.Variables(); !reflect.DeepEqual(got, []string{"functions", "lines", "name"}) {
+		t.Errorf("Unexpected variables %v", got)
+	}
+}
+
+func TestTemplate(t *testing.T) {
+	template, err := CompileTemplate(`{name} has {lines} lines and {count(functions, f -> f.lines > 10)} long siblings {{sic}}`)
+	if err != nil {
+		t.Fatalf("CompileTemplate failed: %v", err)
+	}
+	message, err := template.Execute(testEnv(), DefaultFuel)
+	if err != nil {
+		t.Fatalf("Execute failed: %v", err)
+	}
+	if message != "ServeHTTP has 42 lines and 2 long siblings {sic}" {
+		t.Errorf("Unexpected message %q", message)
+	}
+	if got := template.Variables(); !reflect.DeepEqual(got, []string{"functions", "lines", "name"}) {
+		t.Errorf("Unexpected variables %v", got)
+	}
+
+	if _, err := CompileTemplate(`{name`); err == nil {
+		t.Error("Expected an error for an unterminated placeholder")
+	}
+}
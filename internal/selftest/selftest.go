@@ -0,0 +1,152 @@
+// Package selftest runs the detectors over the bundled test corpus and checks
+// that the expected violations still fire, so that a configuration that
+// accidentally disables rules is noticed.
+package selftest
+
+import (
+	"encoding/json"
+	"fmt"
+	"os"
+	"path/filepath"
+	"sort"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/scanner"
+	"github.com/ericfisherdev/goclean/internal/violations"
+)
+
+// ManifestName is the file in the corpus directory listing the expected violations
+const ManifestName = "selftest.json"
+
+// Expectation lists the violation types expected in one corpus file
+type Expectation struct {
+	File  string   `json:"file"`  // Slash path relative to the corpus directory
+	Types []string `json:"types"` // Violation types that must be reported at least once
+}
+
+// Manifest lists the expectations of a corpus
+type Manifest struct {
+	Files []Expectation `json:"files"`
+}
+
+// FileResult is the outcome of the self-test for one corpus file
+type FileResult struct {
+	File       string
+	Expected   []string
+	Missing    []string // Expected violation types that were not reported
+	Violations int      // Violations reported in the file
+	Found      bool     // The file was found and scanned
+}
+
+// Passed reports whether every expected violation type was reported
+func (r *FileResult) Passed() bool {
+	return r.Found && len(r.Missing) == 0
+}
+
+// Report is the outcome of a self-test run
+type Report struct {
+	Corpus string
+	Files  []*FileResult
+}
+
+// Failed returns the number of corpus files whose expectations were not met
+func (r *Report) Failed() int {
+	failed := 0
+	for _, file := range r.Files {
+		if !file.Passed() {
+			failed++
+		}
+	}
+	return failed
+}
+
+// LoadManifest reads the expectations of the corpus in dir
+func LoadManifest(dir string) (*Manifest, error) {
+	data, err := os.ReadFile(filepath.Join(dir, ManifestName))
+	if err != nil {
+		return nil, fmt.Errorf("failed to read self-test manifest: %w", err)
+	}
+	var manifest Manifest
+	if err := json.Unmarshal(data, &manifest); err != nil {
+		return nil, fmt.Errorf("failed to parse %s: %w", ManifestName, err)
+	}
+	if len(manifest.Files) == 0 {
+		return nil, fmt.Errorf("%s lists no corpus files", ManifestName)
+	}
+	return &manifest, nil
+}
+
+// Locate returns the first candidate directory containing a self-test manifest
+func Locate(candidates ...string) (string, error) {
+	for _, dir := range candidates {
+		if dir == "" {
+			continue
+		}
+		if info, err := os.Stat(filepath.Join(dir, ManifestName)); err == nil && !info.IsDir() {
+			return dir, nil
+		}
+	}
+	return "", fmt.Errorf("no test corpus with a %s found; pass its directory with --corpus", ManifestName)
+}
+
+// Run scans the corpus in dir with the detector configuration and checks the
+// reported violations against the manifest
+func Run(dir string, config *violations.DetectorConfig) (*Report, error) {
+	manifest, err := LoadManifest(dir)
+	if err != nil {
+		return nil, err
+	}
+
+	paths := make([]string, 0, len(manifest.Files))
+	for _, expectation := range manifest.Files {
+		paths = append(paths, filepath.Join(dir, filepath.FromSlash(expectation.File)))
+	}
+	engine := scanner.NewEngineWithConfig(paths, nil, []string{".go", ".rs"}, false, false, config.AggressiveMode, nil)
+	engine.SetViolationDetectorConfig(config)
+	_, results, err := engine.Scan()
+	if err != nil {
+		return nil, fmt.Errorf("self-test scan failed: %w", err)
+	}
+
+	reported := make(map[string][]*models.Violation)
+	scanned := make(map[string]bool)
+	for _, result := range results {
+		if result.File == nil {
+			continue
+		}
+		file := corpusPath(dir, result.File.Path)
+		scanned[file] = true
+		reported[file] = append(reported[file], result.Violations...)
+	}
+
+	report := &Report{Corpus: dir}
+	for _, expectation := range manifest.Files {
+		found := make(map[string]bool)
+		for _, violation := range reported[expectation.File] {
+			found[string(violation.Type)] = true
+		}
+
+		result := &FileResult{
+			File:       expectation.File,
+			Expected:   expectation.Types,
+			Violations: len(reported[expectation.File]),
+			Found:      scanned[expectation.File],
+		}
+		for _, violationType := range expectation.Types {
+			if !found[violationType] {
+				result.Missing = append(result.Missing, violationType)
+			}
+		}
+		sort.Strings(result.Missing)
+		report.Files = append(report.Files, result)
+	}
+	return report, nil
+}
+
+// corpusPath converts a scanned file path into a slash path relative to the corpus directory
+func corpusPath(dir, path string) string {
+	if rel, err := filepath.Rel(dir, path); err == nil {
+		path = rel
+	}
+	return filepath.ToSlash(path)
+}
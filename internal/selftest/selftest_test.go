@@ -0,0 +1,99 @@
+package selftest
+
+import (
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+
+	"github.com/ericfisherdev/goclean/internal/violations"
+)
+
+// writeCorpus creates a corpus with a long Go function and the given manifest
+func writeCorpus(t *testing.T, manifest string) string {
+	t.Helper()
+	dir := t.TempDir()
+	if err := os.MkdirAll(filepath.Join(dir, "samples"), 0755); err != nil {
+		t.Fatalf("Failed to create corpus: %v", err)
+	}
+
+	body := strings.Repeat("\tprintln(\"line\")\n", 40)
+	source := "package samples\n\n// Long is too long\nfunc Long() {\n" + body + "}\n"
+	if err := os.WriteFile(filepath.Join(dir, "samples", "long.go"), []byte(source), 0644); err != nil {
+		t.Fatalf("Failed to write corpus file: %v", err)
+	}
+	if err := os.WriteFile(filepath.Join(dir, ManifestName), []byte(manifest), 0644); err != nil {
+		t.Fatalf("Failed to write manifest: %v", err)
+	}
+	return dir
+}
+
+func TestRun(t *testing.T) {
+	dir := writeCorpus(t, `{"files": [
+		{"file": "samples/long.go", "types": ["function_length"]},
+		{"file": "samples/long.go", "types": ["function_length", "nesting_depth"]},
+		{"file": "samples/missing.go", "types": ["naming_convention"]}
+	]}`)
+
+	report, err := Run(dir, violations.DefaultDetectorConfig())
+	if err != nil {
+		t.Fatalf("Run failed: %v", err)
+	}
+	if len(report.Files) != 3 || report.Failed() != 2 {
+		t.Fatalf("Expected 2 of 3 files to fail, got %d of %d", report.Failed(), len(report.Files))
+	}
+	if !report.Files[0].Passed() || report.Files[0].Violations == 0 {
+		t.Errorf("Expected the long function to be reported, got %+v", report.Files[0])
+	}
+	if missing := report.Files[1].Missing; len(missing) != 1 || missing[0] != "nesting_depth" {
+		t.Errorf("Expected nesting_depth to be missing, got %v", missing)
+	}
+	if report.Files[2].Found {
+		t.Error("Expected the missing file not to be found")
+	}
+}
+
+func TestRunDetectsDisabledRules(t *testing.T) {
+	dir := writeCorpus(t, `{"files": [{"file": "samples/long.go", "types": ["function_length"]}]}`)
+
+	config := violations.DefaultDetectorConfig()
+	config.MaxFunctionLines = 1000
+	report, err := Run(dir, config)
+	if err != nil {
+		t.Fatalf("Run failed: %v", err)
+	}
+	if report.Failed() != 1 {
+		t.Error("Expected the self-test to fail when the threshold hides the violation")
+	}
+}
+
+func TestRunBundledCorpus(t *testing.T) {
+	report, err := Run(filepath.Join("..", "..", "testdata"), violations.DefaultDetectorConfig())
+	if err != nil {
+		t.Fatalf("Run failed: %v", err)
+	}
+	for _, file := range report.Files {
+		if !file.Passed() {
+			t.Errorf("%s: found=%v, missing %v", file.File, file.Found, file.Missing)
+		}
+	}
+}
+
+func TestLoadManifestRejectsEmpty(t *testing.T) {
+	dir := writeCorpus(t, `{"files": []}`)
+	if _, err := LoadManifest(dir); err == nil {
+		t.Error("Expected an error for a manifest without files")
+	}
+}
+
+func TestLocate(t *testing.T) {
+	dir := writeCorpus(t, `{"files": []}`)
+
+	found, err := Locate("", t.TempDir(), dir)
+	if err != nil || found != dir {
+		t.Errorf("Expected %s, got %q (%v)", dir, found, err)
+	}
+	if _, err := Locate(t.TempDir()); err == nil {
+		t.Error("Expected an error when no candidate has a manifest")
+	}
+}
@@ -0,0 +1,161 @@
+// Package thirdparty classifies vendored and third-party files, such as
+// vendor directories and git submodules, so that their violations do not
+// count against the project's own quality metrics.
+package thirdparty
+
+import (
+	"bufio"
+	"os"
+	"path/filepath"
+	"strings"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+)
+
+// Classifier decides which files are third-party code
+type Classifier struct {
+	root     string   // Directory that patterns are matched relative to
+	patterns []string // Directory patterns such as "/vendor/", matched anywhere in a path
+	roots    []string // Absolute slash paths of submodules, with a trailing slash
+}
+
+// Stats counts the third-party violations a classifier handled
+type Stats struct {
+	Violations int // Violations in third-party files
+	Files      int // Third-party files with violations
+}
+
+// New creates a classifier for directory patterns such as "vendor/" or
+// "third_party/". A pattern matches a directory with that name, or path of
+// directories, at any depth below root.
+func New(root string, patterns []string) *Classifier {
+	c := &Classifier{root: normalize(root)}
+	for _, pattern := range patterns {
+		pattern = strings.Trim(filepath.ToSlash(strings.TrimSpace(pattern)), "/")
+		pattern = strings.TrimPrefix(pattern, "./")
+		if pattern != "" {
+			c.patterns = append(c.patterns, "/"+pattern+"/")
+		}
+	}
+	return c
+}
+
+// AddSubmodules marks the git submodules of the repository containing dir
+// as third-party. The submodules are read from the nearest .gitmodules file
+// in dir or its parents; it is not an error when there is none.
+func (c *Classifier) AddSubmodules(dir string) error {
+	gitmodules := findGitmodules(dir)
+	if gitmodules == "" {
+		return nil
+	}
+
+	file, err := os.Open(gitmodules)
+	if err != nil {
+		return err
+	}
+	defer file.Close()
+
+	root := filepath.Dir(gitmodules)
+	scanner := bufio.NewScanner(file)
+	for scanner.Scan() {
+		key, value, ok := strings.Cut(scanner.Text(), "=")
+		if !ok || strings.TrimSpace(key) != "path" {
+			continue
+		}
+		path := filepath.Join(root, filepath.FromSlash(strings.TrimSpace(value)))
+		c.roots = append(c.roots, normalize(path)+"/")
+	}
+	return scanner.Err()
+}
+
+// IsThirdParty reports whether a file is third-party code
+func (c *Classifier) IsThirdParty(path string) bool {
+	path = normalize(path)
+	for _, root := range c.roots {
+		if strings.HasPrefix(path, root) {
+			return true
+		}
+	}
+
+	// Directories above the root, such as a checkout under /src/vendor, do not count
+	if rel, err := filepath.Rel(filepath.FromSlash(c.root), filepath.FromSlash(path)); err == nil && !strings.HasPrefix(rel, "..") {
+		path = "/" + filepath.ToSlash(rel)
+	}
+	for _, pattern := range c.patterns {
+		if strings.Contains(path, pattern) {
+			return true
+		}
+	}
+	return false
+}
+
+// Downgrade lowers the severity of third-party violations to at most
+// maxSeverity. The violations stay in the results and totals.
+func (c *Classifier) Downgrade(results []*models.ScanResult, maxSeverity models.Severity) Stats {
+	var stats Stats
+	for _, result := range results {
+		if result.File == nil || len(result.Violations) == 0 || !c.IsThirdParty(result.File.Path) {
+			continue
+		}
+		stats.Files++
+		for _, violation := range result.Violations {
+			stats.Violations++
+			violation.Severity = min(violation.Severity, maxSeverity)
+		}
+	}
+	return stats
+}
+
+// Separate removes third-party violations from the results and the summary
+// totals and counts them in summary.ThirdParty instead
+func (c *Classifier) Separate(summary *models.ScanSummary, results []*models.ScanResult) Stats {
+	var stats Stats
+	for _, result := range results {
+		if result.File == nil || len(result.Violations) == 0 || !c.IsThirdParty(result.File.Path) {
+			continue
+		}
+		stats.Files++
+		for _, violation := range result.Violations {
+			stats.Violations++
+			if summary != nil {
+				summary.RemoveViolation(violation)
+			}
+		}
+		result.Violations = result.Violations[:0]
+	}
+	if summary != nil {
+		summary.ThirdParty = stats.Violations
+	}
+	return stats
+}
+
+// findGitmodules returns the nearest .gitmodules file in dir or its parents
+func findGitmodules(dir string) string {
+	dir, err := filepath.Abs(dir)
+	if err != nil {
+		return ""
+	}
+	for {
+		candidate := filepath.Join(dir, ".gitmodules")
+		if info, err := os.Stat(candidate); err == nil && !info.IsDir() {
+			return candidate
+		}
+		parent := filepath.Dir(dir)
+		if parent == dir {
+			return ""
+		}
+		dir = parent
+	}
+}
+
+// normalize returns the absolute slash path of a file, with symlinks resolved
+// when possible
+func normalize(path string) string {
+	if absPath, err := filepath.Abs(path); err == nil {
+		path = absPath
+		if resolved, err := filepath.EvalSymlinks(absPath); err == nil {
+			path = resolved
+		}
+	}
+	return filepath.ToSlash(path)
+}
@@ -0,0 +1,88 @@
+package thirdparty
+
+import (
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+)
+
+func TestIsThirdParty(t *testing.T) {
+	root := filepath.Join(t.TempDir(), "vendor", "project")
+	gitmodules := "[submodule \"libs/json\"]\n\tpath = libs/json\n\turl = https://example.com/json.git\n"
+	if err := os.MkdirAll(root, 0755); err != nil {
+		t.Fatalf("Failed to create root: %v", err)
+	}
+	if err := os.WriteFile(filepath.Join(root, ".gitmodules"), []byte(gitmodules), 0644); err != nil {
+		t.Fatalf("Failed to write .gitmodules: %v", err)
+	}
+
+	c := New(root, []string{"vendor/", "third_party"})
+	if err := c.AddSubmodules(filepath.Join(root, "src")); err != nil {
+		t.Fatalf("AddSubmodules failed: %v", err)
+	}
+
+	tests := []struct {
+		path string
+		want bool
+	}{
+		{"src/main.go", false},
+		{"vendor/github.com/pkg/errors/errors.go", true},
+		{"src/third_party/zlib/zlib.c", true},
+		{"libs/json/src/lib.rs", true},
+		{"libs/jsonrpc/src/lib.rs", false},
+		{"src/vendored.go", false},
+	}
+	for _, tt := range tests {
+		if got := c.IsThirdParty(filepath.Join(root, tt.path)); got != tt.want {
+			t.Errorf("IsThirdParty(%s) = %v, want %v", tt.path, got, tt.want)
+		}
+	}
+}
+
+func thirdPartyResults() []*models.ScanResult {
+	return []*models.ScanResult{
+		{File: &models.FileInfo{Path: "src/main.go"}, Violations: []*models.Violation{
+			{Type: models.ViolationTypeNaming, Severity: models.SeverityHigh},
+		}},
+		{File: &models.FileInfo{Path: "vendor/lib/lib.go"}, Violations: []*models.Violation{
+			{Type: models.ViolationTypeNaming, Severity: models.SeverityCritical},
+			{Type: models.ViolationTypeMagicNumber, Severity: models.SeverityInfo},
+		}},
+	}
+}
+
+func TestDowngrade(t *testing.T) {
+	results := thirdPartyResults()
+	stats := New(".", []string{"vendor/"}).Downgrade(results, models.SeverityLow)
+
+	if stats.Violations != 2 || stats.Files != 1 {
+		t.Errorf("Expected 2 violations in 1 file, got %+v", stats)
+	}
+	if results[0].Violations[0].Severity != models.SeverityHigh {
+		t.Error("Expected project violations to keep their severity")
+	}
+	if results[1].Violations[0].Severity != models.SeverityLow || results[1].Violations[1].Severity != models.SeverityInfo {
+		t.Error("Expected third-party severities to be capped at low")
+	}
+}
+
+func TestSeparate(t *testing.T) {
+	results := thirdPartyResults()
+	summary := &models.ScanSummary{
+		TotalViolations: 3,
+		ViolationsByType: map[string]int{
+			string(models.ViolationTypeNaming):      2,
+			string(models.ViolationTypeMagicNumber): 1,
+		},
+	}
+
+	stats := New(".", []string{"vendor/"}).Separate(summary, results)
+	if stats.Violations != 2 || len(results[1].Violations) != 0 || len(results[0].Violations) != 1 {
+		t.Errorf("Expected the vendored violations to be removed, got %+v", stats)
+	}
+	if summary.TotalViolations != 1 || summary.ThirdParty != 2 || summary.ViolationsByType[string(models.ViolationTypeNaming)] != 1 {
+		t.Errorf("Unexpected summary: %+v", summary)
+	}
+}
@@ -98,6 +98,14 @@ type RustASTInfo struct {
 	Constants   []*RustConstantInfo
 	Uses        []*RustUseInfo
 	Macros      []*RustMacroInfo
+	Syntax      *RustNode          // Full syntax tree shared by all detectors
+	Comments    []*RustCommentInfo // Comments in source order
+	PanicSites  []*RustPanicSite   // Potential runtime panics in function bodies
+	Tokens      []RustTokenInfo    // Lexical tokens in source order
+	Edition     RustEdition        // Edition the file was parsed with
+	Target      RustTarget         // Cargo target of the file; empty outside a Cargo package
+	TargetName  string             // Name of the Cargo target, e.g. the binary name
+	Package     *RustPackage       // Cargo package of the file; nil outside a Cargo package
 }
 
 // RustFunctionInfo contains detailed information about a Rust function
@@ -117,6 +125,7 @@ type RustFunctionInfo struct {
 	LineCount    int
 	HasDocComments bool
 	Visibility   string // "pub", "pub(crate)", "pub(super)", "private"
+	Node         *RustNode // Syntax node when available
 }
 
 // RustParameterInfo contains information about Rust function parameters
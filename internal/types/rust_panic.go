@@ -0,0 +1,21 @@
+package types
+
+// Kinds of potential runtime panic sites in Rust code
+const (
+	RustPanicUnwrap     = "unwrap"
+	RustPanicExpect     = "expect"
+	RustPanicIndex      = "index"
+	RustPanicSlice      = "slice"
+	RustPanicArithmetic = "arithmetic"
+	RustPanicMacro      = "panic_macro"
+)
+
+// RustPanicSite is an expression inside a function body that can panic at runtime
+type RustPanicSite struct {
+	Kind     string `json:"kind"`
+	Name     string `json:"name"`     // Method, macro or operator text
+	Function string `json:"function"` // Innermost enclosing function
+	Line     int    `json:"line"`
+	Column   int    `json:"column"`
+	InTest   bool   `json:"in_test"` // Inside #[test] functions or #[cfg(test)] modules
+}
@@ -0,0 +1,215 @@
+package types
+
+// RustNodeKind identifies the syntactic category of a Rust syntax node
+type RustNodeKind string
+
+const (
+	// Item-level nodes
+	RustNodeFile        RustNodeKind = "file"
+	RustNodeFunction    RustNodeKind = "fn"
+	RustNodeStruct      RustNodeKind = "struct"
+	RustNodeUnion       RustNodeKind = "union"
+	RustNodeEnum        RustNodeKind = "enum"
+	RustNodeTrait       RustNodeKind = "trait"
+	RustNodeImpl        RustNodeKind = "impl"
+	RustNodeModule      RustNodeKind = "mod"
+	RustNodeConst       RustNodeKind = "const"
+	RustNodeStatic      RustNodeKind = "static"
+	RustNodeUse         RustNodeKind = "use"
+	RustNodeTypeAlias   RustNodeKind = "type"
+	RustNodeExternBlock RustNodeKind = "extern_block"
+	RustNodeMacroRules  RustNodeKind = "macro_rules"
+
+	// Expression and statement nodes
+	RustNodeBlock     RustNodeKind = "block"
+	RustNodeUnsafe    RustNodeKind = "unsafe_block"
+	RustNodeIf        RustNodeKind = "if"
+	RustNodeElse      RustNodeKind = "else"
+	RustNodeLetElse   RustNodeKind = "let_else" // Diverging else block of `let PATTERN = EXPR else { ... };`
+	RustNodeMatch     RustNodeKind = "match"
+	RustNodeMatchArm  RustNodeKind = "match_arm"
+	RustNodeLoop      RustNodeKind = "loop"
+	RustNodeWhile     RustNodeKind = "while"
+	RustNodeFor       RustNodeKind = "for"
+	RustNodeClosure   RustNodeKind = "closure"
+	RustNodeMacroCall RustNodeKind = "macro_call"
+	RustNodeAwait     RustNodeKind = "await"
+	RustNodeTry       RustNodeKind = "try"
+	RustNodeLogicalOp RustNodeKind = "logical_op" // Run of && / || operators in one expression; Name lists them
+)
+
+// RustNode is a node of the Rust syntax tree with accurate source spans.
+// Item nodes carry their name, modifiers and attributes; expression nodes
+// describe control flow so detectors can reason about nesting and structure.
+type RustNode struct {
+	Kind        RustNodeKind
+	Name        string   // Item name, macro name, impl target or operator text
+	Text        string   // Compact source text of the item header (signature, path, condition)
+	StartLine   int
+	StartColumn int
+	EndLine     int
+	EndColumn   int
+	Modifiers   []string // e.g. "pub", "pub(crate)", "async", "unsafe", "const", "mut", "extern"
+	Attributes  []string // Outer attributes such as "derive(Debug)" or "cfg(test)"
+	HasDoc      bool     // True when the item is preceded by doc comments
+	ABI         string   // Calling convention of extern items and blocks, e.g. "C"
+	Children    []*RustNode
+	Parent      *RustNode `json:"-"`
+}
+
+// RustCommentInfo describes a comment in a Rust source file
+type RustCommentInfo struct {
+	Text    string
+	Line    int
+	EndLine int
+	Column  int
+	IsDoc   bool
+	IsBlock bool
+}
+
+// RustTokenKind classifies the lexical tokens exposed to detectors
+type RustTokenKind string
+
+const (
+	RustTokenIdent    RustTokenKind = "ident"
+	RustTokenKeyword  RustTokenKind = "keyword"
+	RustTokenLifetime RustTokenKind = "lifetime"
+	RustTokenLiteral  RustTokenKind = "literal"
+	RustTokenPunct    RustTokenKind = "punct" // Operators and delimiters
+)
+
+// RustTokenInfo is a lexical token of a Rust source file; comments are not tokens
+type RustTokenInfo struct {
+	Kind   RustTokenKind
+	Text   string
+	Line   int
+	Column int
+}
+
+// RustEdition is a Rust language edition, such as "2021"
+type RustEdition string
+
+const (
+	RustEdition2015 RustEdition = "2015"
+	RustEdition2018 RustEdition = "2018"
+	RustEdition2021 RustEdition = "2021"
+	RustEdition2024 RustEdition = "2024"
+
+	// DefaultRustEdition is assumed for files outside any Cargo package
+	DefaultRustEdition = RustEdition2021
+)
+
+// AtLeast reports whether the edition is the given edition or a later one
+func (e RustEdition) AtLeast(edition RustEdition) bool {
+	return e >= edition
+}
+
+// RustTarget is the kind of Cargo target a Rust file is compiled into
+type RustTarget string
+
+const (
+	RustTargetLibrary RustTarget = "lib"
+	RustTargetBinary  RustTarget = "bin"
+	RustTargetTest    RustTarget = "test"
+	RustTargetExample RustTarget = "example"
+	RustTargetBench   RustTarget = "bench"
+	RustTargetBuild   RustTarget = "build"
+)
+
+// RustPackage identifies the Cargo package a Rust file belongs to
+type RustPackage struct {
+	Name     string // Package name from Cargo.toml
+	Manifest string // Path of the Cargo.toml
+}
+
+// HasModifier reports whether the node carries the given modifier
+func (n *RustNode) HasModifier(modifier string) bool {
+	for _, m := range n.Modifiers {
+		if m == modifier {
+			return true
+		}
+	}
+	return false
+}
+
+// HasAttribute reports whether the node carries an attribute starting with the given prefix
+func (n *RustNode) HasAttribute(prefix string) bool {
+	for _, attr := range n.Attributes {
+		if len(attr) >= len(prefix) && attr[:len(prefix)] == prefix {
+			return true
+		}
+	}
+	return false
+}
+
+// LineCount returns the number of source lines spanned by the node
+func (n *RustNode) LineCount() int {
+	if n.EndLine < n.StartLine {
+		return 1
+	}
+	return n.EndLine - n.StartLine + 1
+}
+
+// EnclosingItem returns the nearest ancestor of the given kind, or nil
+func (n *RustNode) EnclosingItem(kind RustNodeKind) *RustNode {
+	for p := n.Parent; p != nil; p = p.Parent {
+		if p.Kind == kind {
+			return p
+		}
+	}
+	return nil
+}
+
+// AddChild appends a child node and sets its parent
+func (n *RustNode) AddChild(child *RustNode) {
+	child.Parent = n
+	n.Children = append(n.Children, child)
+}
+
+// RustVisitor is the shared interface detectors use to walk the Rust syntax tree.
+// Visit is called for every node in depth-first order; returning false skips
+// the node's children.
+type RustVisitor interface {
+	Visit(node *RustNode) bool
+}
+
+// RustVisitorFunc adapts an ordinary function to the RustVisitor interface
+type RustVisitorFunc func(node *RustNode) bool
+
+// Visit calls f(node)
+func (f RustVisitorFunc) Visit(node *RustNode) bool {
+	return f(node)
+}
+
+// WalkRust traverses the syntax tree rooted at node in depth-first order
+func WalkRust(node *RustNode, visitor RustVisitor) {
+	if node == nil || visitor == nil {
+		return
+	}
+	if !visitor.Visit(node) {
+		return
+	}
+	for _, child := range node.Children {
+		WalkRust(child, visitor)
+	}
+}
+
+// InspectRust traverses the syntax tree calling fn for each node, like ast.Inspect
+func InspectRust(node *RustNode, fn func(*RustNode) bool) {
+	WalkRust(node, RustVisitorFunc(fn))
+}
+
+// FindRustNodes returns all nodes of the given kinds in depth-first order
+func FindRustNodes(root *RustNode, kinds ...RustNodeKind) []*RustNode {
+	var found []*RustNode
+	InspectRust(root, func(n *RustNode) bool {
+		for _, kind := range kinds {
+			if n.Kind == kind {
+				found = append(found, n)
+				break
+			}
+		}
+		return true
+	})
+	return found
+}
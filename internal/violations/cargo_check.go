@@ -0,0 +1,202 @@
+package violations
+
+import (
+	"encoding/json"
+	"fmt"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"strings"
+	"sync"
+)
+
+// Package-level cache of parsed cargo check results by project root, so
+// cargo runs once per project rather than once per file. A nil entry records
+// a project where cargo could not be run.
+var (
+	cargoCheckCache     = make(map[string]*cargoCheckResult)
+	cargoCheckCacheLock sync.Mutex
+)
+
+// runCargoCheck runs cargo check with JSON diagnostics in a project root. It
+// is a variable so tests can stand in for cargo.
+var runCargoCheck = func(projectRoot string) ([]byte, error) {
+	cmd := exec.Command("cargo", "check", "--message-format=json", "--all-targets")
+	cmd.Dir = projectRoot
+
+	// Diagnostics go to stdout; stderr only carries cargo's progress output
+	return cmd.Output()
+}
+
+// cargoCheckResult holds the rustc diagnostics of one cargo check run
+type cargoCheckResult struct {
+	compiles    bool
+	diagnostics []cargoDiagnostic
+}
+
+// cargoDiagnostic is a rustc diagnostic reduced to its primary span
+type cargoDiagnostic struct {
+	file    string
+	code    string
+	level   string
+	message string
+	line    int
+	column  int
+}
+
+// cargoCheckFor returns the cargo check result for the project of a file
+// when cargo check mode is enabled and the project compiles. Detectors fall
+// back to their own analysis when it returns nil.
+func cargoCheckFor(config *DetectorConfig, filePath string) *cargoCheckResult {
+	if config == nil || config.RustConfig == nil || !config.RustConfig.CargoCheck {
+		return nil
+	}
+
+	projectRoot := cargoProjectRoot(filePath)
+	if projectRoot == "" {
+		return nil
+	}
+
+	cargoCheckCacheLock.Lock()
+	defer cargoCheckCacheLock.Unlock()
+
+	// Holding the lock while cargo runs keeps files of the same project from
+	// starting concurrent builds
+	result, cached := cargoCheckCache[projectRoot]
+	if !cached {
+		// cargo exits non-zero when the project fails to compile, but still
+		// prints its diagnostics
+		output, err := runCargoCheck(projectRoot)
+		if len(output) > 0 {
+			result = parseCargoCheck(output)
+		} else if config.Verbose {
+			fmt.Printf("cargo check failed in %s: %v\n", projectRoot, err)
+		}
+		cargoCheckCache[projectRoot] = result
+	}
+
+	if result == nil || !result.compiles {
+		return nil
+	}
+	return result
+}
+
+// parseCargoCheck parses the JSON lines printed by cargo check. A project
+// compiles when the build finished successfully and rustc reported no errors.
+func parseCargoCheck(output []byte) *cargoCheckResult {
+	result := &cargoCheckResult{}
+	finished := false
+
+	for _, line := range strings.Split(string(output), "\n") {
+		line = strings.TrimSpace(line)
+		if line == "" {
+			continue
+		}
+
+		var message ClippyMessage
+		if err := json.Unmarshal([]byte(line), &message); err != nil {
+			// Skip lines that are not cargo messages
+			continue
+		}
+
+		switch message.Reason {
+		case "build-finished":
+			var build struct {
+				Success bool `json:"success"`
+			}
+			if err := json.Unmarshal([]byte(line), &build); err == nil {
+				finished = build.Success
+			}
+		case "compiler-message":
+			diagnostic, ok := newCargoDiagnostic(message.Message)
+			if !ok {
+				continue
+			}
+			result.diagnostics = append(result.diagnostics, diagnostic)
+		}
+	}
+
+	result.compiles = finished
+	for _, diagnostic := range result.diagnostics {
+		if diagnostic.level == "error" {
+			result.compiles = false
+		}
+	}
+	return result
+}
+
+// newCargoDiagnostic reduces a rustc diagnostic to its primary span
+func newCargoDiagnostic(diagnostic ClippyDiagnostic) (cargoDiagnostic, bool) {
+	for _, span := range diagnostic.Spans {
+		if !span.IsPrimary {
+			continue
+		}
+		result := cargoDiagnostic{
+			file:    filepath.ToSlash(span.FileName),
+			level:   diagnostic.Level,
+			message: diagnostic.Message,
+			line:    span.LineStart,
+			column:  span.ColumnStart,
+		}
+		if diagnostic.Code != nil {
+			result.code = diagnostic.Code.Code
+		}
+		return result, true
+	}
+	return cargoDiagnostic{}, false
+}
+
+// forFile returns the diagnostics with the given lint code whose primary span
+// is in the given file
+func (r *cargoCheckResult) forFile(filePath, code string) []cargoDiagnostic {
+	var diagnostics []cargoDiagnostic
+	for _, diagnostic := range r.diagnostics {
+		if diagnostic.code == code && r.sameFile(diagnostic.file, filePath) {
+			diagnostics = append(diagnostics, diagnostic)
+		}
+	}
+	return diagnostics
+}
+
+// sameFile reports whether a rustc file name refers to the given file. rustc
+// names files relative to the workspace root, which may be above the project
+// root of the file, so relative names are matched on whole path components.
+func (r *cargoCheckResult) sameFile(fileName, filePath string) bool {
+	target, err := filepath.Abs(filePath)
+	if err != nil {
+		target = filePath
+	}
+	target = filepath.ToSlash(target)
+
+	if filepath.IsAbs(fileName) {
+		return filepath.ToSlash(filepath.Clean(fileName)) == target
+	}
+	fileName = strings.TrimPrefix(fileName, "./")
+	return target == fileName || strings.HasSuffix(target, "/"+fileName)
+}
+
+// cargoProjectRoot finds the directory containing Cargo.toml starting from the given path
+func cargoProjectRoot(filePath string) string {
+	dir := filepath.Dir(filePath)
+
+	// Look for Cargo.toml up the directory tree
+	for {
+		if _, err := os.Stat(filepath.Join(dir, "Cargo.toml")); err == nil {
+			return dir
+		}
+
+		parent := filepath.Dir(dir)
+		if parent == dir {
+			// Reached filesystem root
+			return ""
+		}
+		dir = parent
+	}
+}
+
+// ClearCargoCheckCache clears the cached cargo check results
+func ClearCargoCheckCache() {
+	cargoCheckCacheLock.Lock()
+	defer cargoCheckCacheLock.Unlock()
+	cargoCheckCache = make(map[string]*cargoCheckResult)
+}
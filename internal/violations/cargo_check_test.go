@@ -0,0 +1,102 @@
+package violations
+
+import (
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+const cargoCheckOutput = `{"reason":"compiler-artifact","package_id":"demo 0.1.0","target":{"name":"demo"}}
+{"reason":"compiler-message","package_id":"demo 0.1.0","message":{"message":"unused ` + "`Result`" + ` that must be used","code":{"code":"unused_must_use"},"level":"warning","spans":[{"file_name":"src/main.rs","line_start":6,"line_end":6,"column_start":5,"is_primary":true}]}}
+{"reason":"compiler-message","package_id":"demo 0.1.0","message":{"message":"unused variable: ` + "`x`" + `","code":{"code":"unused_variables"},"level":"warning","spans":[{"file_name":"src/main.rs","line_start":7,"line_end":7,"column_start":9,"is_primary":true}]}}
+{"reason":"compiler-message","package_id":"demo 0.1.0","message":{"message":"unused ` + "`Result`" + ` that must be used","code":{"code":"unused_must_use"},"level":"warning","spans":[{"file_name":"src/lib.rs","line_start":2,"line_end":2,"column_start":5,"is_primary":true}]}}
+not json
+{"reason":"build-finished","success":true}`
+
+func TestParseCargoCheck(t *testing.T) {
+	result := parseCargoCheck([]byte(cargoCheckOutput))
+	if !result.compiles {
+		t.Error("Expected a successful build without errors to compile")
+	}
+	if len(result.diagnostics) != 3 {
+		t.Fatalf("Expected 3 diagnostics, got %d", len(result.diagnostics))
+	}
+
+	diagnostics := result.forFile("/work/demo/src/main.rs", "unused_must_use")
+	if len(diagnostics) != 1 || diagnostics[0].line != 6 || diagnostics[0].column != 5 {
+		t.Errorf("Expected one unused Result in main.rs, got %+v", diagnostics)
+	}
+	if diagnostics := result.forFile("/work/demo/other_src/main.rs", "unused_must_use"); len(diagnostics) != 0 {
+		t.Errorf("Expected file names to match on whole path components, got %+v", diagnostics)
+	}
+
+	failed := strings.Replace(cargoCheckOutput, `"level":"warning"`, `"level":"error"`, 1)
+	if parseCargoCheck([]byte(failed)).compiles {
+		t.Error("Expected a project with rustc errors not to compile")
+	}
+	unfinished := strings.Replace(cargoCheckOutput, `"success":true`, `"success":false`, 1)
+	if parseCargoCheck([]byte(unfinished)).compiles {
+		t.Error("Expected a failed build not to compile")
+	}
+}
+
+func TestRustErrorHandlingDetector_CargoCheck(t *testing.T) {
+	code := `fn save() -> Result<(), String> {
+    Ok(())
+}
+
+fn main() {
+    save();
+    let x = 1;
+    std::fs::write("a", "b");
+}`
+	root := t.TempDir()
+	if err := os.WriteFile(filepath.Join(root, "Cargo.toml"), []byte("[package]\nname = \"demo\"\n"), 0644); err != nil {
+		t.Fatalf("Failed to write Cargo.toml: %v", err)
+	}
+	if err := os.MkdirAll(filepath.Join(root, "src"), 0755); err != nil {
+		t.Fatalf("Failed to create src: %v", err)
+	}
+	filePath := filepath.Join(root, "src", "main.rs")
+	if err := os.WriteFile(filePath, []byte(code), 0644); err != nil {
+		t.Fatalf("Failed to write main.rs: %v", err)
+	}
+
+	runs := 0
+	original := runCargoCheck
+	runCargoCheck = func(projectRoot string) ([]byte, error) {
+		runs++
+		if projectRoot != root {
+			t.Errorf("Expected cargo to run in %s, got %s", root, projectRoot)
+		}
+		return []byte(cargoCheckOutput), nil
+	}
+	defer func() {
+		runCargoCheck = original
+		ClearCargoCheckCache()
+	}()
+
+	config := DefaultDetectorConfig()
+	config.RustConfig.CargoCheck = true
+	detector := NewRustErrorHandlingDetector(config)
+	info := &types.RustASTInfo{FilePath: filePath, Tokens: rustTestTokens(code)}
+
+	for i := 0; i < 2; i++ {
+		var results []*models.Violation
+		for _, v := range detector.Detect(&models.FileInfo{Path: filePath, Language: "rust"}, info) {
+			if v.Rule == "rust-unhandled-result" {
+				results = append(results, v)
+			}
+		}
+		if len(results) != 1 || results[0].Line != 6 || !strings.HasPrefix(results[0].Message, "rustc: unused `Result`") {
+			t.Fatalf("Expected only rustc's unused Result on line 6, got %+v", results)
+		}
+	}
+	if runs != 1 {
+		t.Errorf("Expected cargo check to run once per project, ran %d times", runs)
+	}
+}
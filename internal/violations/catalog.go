@@ -0,0 +1,255 @@
+package violations
+
+import (
+	"sort"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+)
+
+// Languages a rule applies to
+const (
+	LanguageGo   = "go"
+	LanguageRust = "rust"
+)
+
+// RuleInfo describes a rule reported by one of the registered detectors
+type RuleInfo struct {
+	ID        string
+	Detector  string // Name of the detector reporting the rule
+	Type      models.ViolationType
+	Category  string
+	Severity  models.Severity // Severity of a finding just over its threshold; size and complexity rules escalate further over it
+	Languages []string
+
+	// enabled reports whether the configuration turns the rule on; nil when it always runs
+	enabled func(config *DetectorConfig) bool
+}
+
+// Enabled reports whether detectors configured by config report the rule
+func (r RuleInfo) Enabled(config *DetectorConfig) bool {
+	return r.enabled == nil || r.enabled(config)
+}
+
+// Rule gates on the detector configuration
+var (
+	requiresPublicDocs  = func(config *DetectorConfig) bool { return config.RequireCommentsForPublic }
+	forbidsSingleLetter = func(config *DetectorConfig) bool { return !config.AllowSingleLetterVars }
+	concurrencyPack     = func(config *DetectorConfig) bool { return config.RustConfig != nil && config.RustConfig.EnableConcurrencyPack }
+	importsPack         = func(config *DetectorConfig) bool { return config.RustConfig != nil && config.RustConfig.EnableImportsPack }
+	halsteadVolume      = func(config *DetectorConfig) bool { return config.MaxHalsteadVolume > 0 }
+	halsteadDifficulty  = func(config *DetectorConfig) bool { return config.MaxHalsteadDifficulty > 0 }
+	halsteadEffort      = func(config *DetectorConfig) bool { return config.MaxHalsteadEffort > 0 }
+	magicStrings        = func(config *DetectorConfig) bool { return config.MaxStringRepeats > 0 }
+	primitiveObsession  = func(config *DetectorConfig) bool { return config.MaxPrimitiveRepeats > 0 }
+	godStructs          = godStructEnabled
+	unitLiterals        = func(config *DetectorConfig) bool { return config.UnitLiterals.Enabled }
+	unwrapDensity       = func(config *DetectorConfig) bool { return config.RustConfig != nil && config.RustConfig.MaxUnwrapsPerFunction > 0 }
+	fileUnwrapDensity   = func(config *DetectorConfig) bool { return config.RustConfig != nil && config.RustConfig.MaxUnwrapsPerFile > 0 }
+	unusedPublicAPI     = func(config *DetectorConfig) bool { return config.RustConfig != nil && config.RustConfig.DetectUnusedPublicAPI }
+	safetyComments      = func(config *DetectorConfig) bool { return config.RustConfig != nil && config.RustConfig.RequireUnsafeComments }
+	pointerArithmetic   = func(config *DetectorConfig) bool { return config.RustConfig != nil && config.RustConfig.DetectPointerArithmetic }
+	safetySection       = func(config *DetectorConfig) bool { return config.RustConfig != nil && config.RustConfig.RequireSafetySection }
+	errorsSection       = func(config *DetectorConfig) bool { return config.RustConfig != nil && config.RustConfig.RequireErrorsSection }
+	panicsSection       = func(config *DetectorConfig) bool { return config.RustConfig != nil && config.RustConfig.RequirePanicsSection }
+	docExamples         = func(config *DetectorConfig) bool { return config.RustConfig != nil && config.RustConfig.RequireDocExamples }
+	todoIssues          = func(config *DetectorConfig) bool { return config.TodoIssues.Enabled }
+	libraryPanics       = func(config *DetectorConfig) bool {
+		return config.RustConfig != nil && (config.RustConfig.LibraryPanicMacros == nil || len(config.RustConfig.LibraryPanicMacros) > 0)
+	}
+)
+
+// ruleCatalog lists the rules of the detectors registered by the scanner.
+// Keep it in sync when a detector starts reporting a new rule ID.
+var ruleCatalog = []RuleInfo{
+	// Go functions, structure and naming
+	{ID: "function-length", Detector: "Function Analysis", Type: models.ViolationTypeFunctionLength, Severity: models.SeverityLow, Languages: []string{LanguageGo}},
+	{ID: "cyclomatic-complexity", Detector: "Function Analysis", Type: models.ViolationTypeCyclomaticComplexity, Severity: models.SeverityLow, Languages: []string{LanguageGo}},
+	{ID: "parameter-count", Detector: "Function Analysis", Type: models.ViolationTypeParameterCount, Severity: models.SeverityLow, Languages: []string{LanguageGo}},
+	{ID: "nesting-depth", Detector: "Function Analysis", Type: models.ViolationTypeNestingDepth, Severity: models.SeverityLow, Languages: []string{LanguageGo}},
+	{ID: "cognitive-complexity", Detector: "Cognitive Complexity Analysis", Type: models.ViolationTypeCognitiveComplexity, Severity: models.SeverityLow, Languages: []string{LanguageGo}},
+	{ID: "type-size", Detector: "Code Structure Analysis", Type: models.ViolationTypeClassSize, Severity: models.SeverityLow, Languages: []string{LanguageGo}},
+	{ID: "struct-field-count", Detector: "Code Structure Analysis", Type: models.ViolationTypeClassSize, Severity: models.SeverityLow, Languages: []string{LanguageGo}},
+	{ID: "interface-method-count", Detector: "Code Structure Analysis", Type: models.ViolationTypeClassSize, Severity: models.SeverityLow, Languages: []string{LanguageGo}},
+	{ID: "god-object", Detector: "Code Structure Analysis", Type: models.ViolationTypeClassSize, Severity: models.SeverityLow, Languages: []string{LanguageGo}},
+	{ID: GodStruct, Detector: "Code Structure Analysis", Type: models.ViolationTypeClassSize, Severity: models.SeverityMedium, Languages: []string{LanguageGo}, enabled: godStructs},
+	{ID: "magic-numbers", Detector: "Code Structure Analysis", Type: models.ViolationTypeMagicNumber, Severity: models.SeverityLow, Languages: []string{LanguageGo}},
+	{ID: "non-descriptive-function-name", Detector: "Naming Convention Analysis", Type: models.ViolationTypeNaming, Severity: models.SeverityMedium, Languages: []string{LanguageGo}},
+	{ID: "non-descriptive-parameter", Detector: "Naming Convention Analysis", Type: models.ViolationTypeNaming, Severity: models.SeverityLow, Languages: []string{LanguageGo}},
+	{ID: "non-descriptive-type-name", Detector: "Naming Convention Analysis", Type: models.ViolationTypeNaming, Severity: models.SeverityMedium, Languages: []string{LanguageGo}},
+	{ID: "unclear-abbreviation", Detector: "Naming Convention Analysis", Type: models.ViolationTypeNaming, Severity: models.SeverityLow, Languages: []string{LanguageGo}},
+	{ID: "single-letter-parameter", Detector: "Naming Convention Analysis", Type: models.ViolationTypeNaming, Severity: models.SeverityLow, Languages: []string{LanguageGo}, enabled: forbidsSingleLetter},
+	{ID: "single-letter-variable", Detector: "Naming Convention Analysis", Type: models.ViolationTypeNaming, Severity: models.SeverityLow, Languages: []string{LanguageGo}, enabled: forbidsSingleLetter},
+	{ID: "go-function-case", Detector: "Naming Convention Analysis", Type: models.ViolationTypeNaming, Severity: models.SeverityLow, Languages: []string{LanguageGo}},
+	{ID: "go-type-case", Detector: "Naming Convention Analysis", Type: models.ViolationTypeNaming, Severity: models.SeverityMedium, Languages: []string{LanguageGo}},
+	{ID: "go-variable-case", Detector: "Naming Convention Analysis", Type: models.ViolationTypeNaming, Severity: models.SeverityLow, Languages: []string{LanguageGo}},
+	{ID: "go-constant-case", Detector: "Naming Convention Analysis", Type: models.ViolationTypeNaming, Severity: models.SeverityLow, Languages: []string{LanguageGo}},
+	{ID: goStandardNamingRule, Detector: "Go Standard Naming Conventions", Type: models.ViolationTypeNaming, Severity: models.SeverityMedium, Languages: []string{LanguageGo}},
+
+	// Go comments and documentation
+	{ID: "missing-documentation", Detector: "Documentation Quality Detector", Type: models.ViolationTypeMissingDocumentation, Severity: models.SeverityMedium, Languages: []string{LanguageGo}, enabled: requiresPublicDocs},
+	{ID: "documentation-style", Detector: "Documentation Quality Detector", Type: models.ViolationTypeDocumentation, Severity: models.SeverityInfo, Languages: []string{LanguageGo}, enabled: requiresPublicDocs},
+	{ID: "magic-number", Detector: "Magic Number Detector", Type: models.ViolationTypeMagicNumber, Severity: models.SeverityLow, Languages: []string{LanguageGo}},
+	{ID: "magic-string", Detector: "Magic Number Detector", Type: models.ViolationTypeMagicString, Severity: models.SeverityLow, Languages: []string{LanguageGo}, enabled: magicStrings},
+	{ID: UnitLiteral, Detector: "Magic Number Detector", Type: models.ViolationTypeMagicNumber, Severity: models.SeverityLow, Languages: []string{LanguageGo}, enabled: unitLiterals},
+	{ID: "commented-code", Detector: "Commented Code Detector", Type: models.ViolationTypeCommentedCode, Severity: models.SeverityLow, Languages: []string{LanguageGo}},
+	{ID: "todo-comment", Detector: "Technical Debt Tracker", Type: models.ViolationTypeTodo, Severity: models.SeverityLow, Languages: []string{LanguageGo}},
+	{ID: UntrackedTodo, Detector: "Untracked TODO Detector", Type: models.ViolationTypeTodo, Severity: models.SeverityLow, Languages: []string{LanguageGo, LanguageRust}, enabled: todoIssues},
+
+	// Rules of both languages
+	{ID: "code-clone", Detector: "Token Clone Detector", Type: models.ViolationTypeDuplication, Severity: models.SeverityLow, Languages: []string{LanguageGo}},
+	{ID: "rust-code-clone", Detector: "Token Clone Detector", Type: models.ViolationTypeDuplication, Severity: models.SeverityLow, Languages: []string{LanguageRust}},
+	{ID: "fan-out", Detector: "Coupling Detector", Type: models.ViolationTypeFanOut, Severity: models.SeverityLow, Languages: []string{LanguageGo}},
+	{ID: "rust-fan-out", Detector: "Coupling Detector", Type: models.ViolationTypeFanOut, Severity: models.SeverityLow, Languages: []string{LanguageRust}},
+	{ID: RustCircularDependency, Detector: "Coupling Detector", Type: models.ViolationTypeRustCircularDependency, Severity: models.SeverityMedium, Languages: []string{LanguageRust}},
+	{ID: RustUnusedPublicItem, Detector: "Unused Public API Detector", Type: models.ViolationTypeRustUnusedPublicItem, Severity: models.SeverityLow, Languages: []string{LanguageRust}, enabled: unusedPublicAPI},
+	{ID: RustUnusedReexport, Detector: "Unused Public API Detector", Type: models.ViolationTypeRustUnusedImport, Severity: models.SeverityLow, Languages: []string{LanguageRust}, enabled: unusedPublicAPI},
+	{ID: PrimitiveObsession, Detector: "Primitive Obsession Detector", Type: models.ViolationTypePrimitiveObsession, Severity: models.SeverityLow, Languages: []string{LanguageGo}, enabled: primitiveObsession},
+	{ID: RustPrimitiveObsession, Detector: "Primitive Obsession Detector", Type: models.ViolationTypePrimitiveObsession, Severity: models.SeverityLow, Languages: []string{LanguageRust}, enabled: primitiveObsession},
+	{ID: "maintainability-index", Detector: "Maintainability Index", Type: models.ViolationTypeMaintainability, Severity: models.SeverityLow, Languages: []string{LanguageGo}},
+	{ID: "rust-maintainability-index", Detector: "Maintainability Index", Type: models.ViolationTypeMaintainability, Severity: models.SeverityLow, Languages: []string{LanguageRust}},
+	{ID: "halstead-volume", Detector: "Maintainability Index", Type: models.ViolationTypeHalstead, Severity: models.SeverityLow, Languages: []string{LanguageGo}, enabled: halsteadVolume},
+	{ID: "halstead-difficulty", Detector: "Maintainability Index", Type: models.ViolationTypeHalstead, Severity: models.SeverityLow, Languages: []string{LanguageGo}, enabled: halsteadDifficulty},
+	{ID: "halstead-effort", Detector: "Maintainability Index", Type: models.ViolationTypeHalstead, Severity: models.SeverityLow, Languages: []string{LanguageGo}, enabled: halsteadEffort},
+	{ID: "rust-halstead-volume", Detector: "Maintainability Index", Type: models.ViolationTypeHalstead, Severity: models.SeverityLow, Languages: []string{LanguageRust}, enabled: halsteadVolume},
+	{ID: "rust-halstead-difficulty", Detector: "Maintainability Index", Type: models.ViolationTypeHalstead, Severity: models.SeverityLow, Languages: []string{LanguageRust}, enabled: halsteadDifficulty},
+	{ID: "rust-halstead-effort", Detector: "Maintainability Index", Type: models.ViolationTypeHalstead, Severity: models.SeverityLow, Languages: []string{LanguageRust}, enabled: halsteadEffort},
+	{ID: "pasted-code", Detector: "Pasted Code Detector", Type: models.ViolationTypePastedCode, Severity: models.SeverityInfo, Languages: []string{LanguageGo, LanguageRust},
+		enabled: func(config *DetectorConfig) bool { return config.DetectPastedCode }},
+	{ID: "invalid-suppression", Detector: "Suppression Directives", Type: models.ViolationTypeSuppression, Severity: models.SeverityLow, Languages: []string{LanguageGo, LanguageRust}},
+	{ID: "unused-suppression", Detector: "Suppression Directives", Type: models.ViolationTypeSuppression, Severity: models.SeverityLow, Languages: []string{LanguageGo, LanguageRust},
+		enabled: func(config *DetectorConfig) bool { return config.ReportUnusedSuppressions }},
+	{ID: "deprecated-rule", Detector: "Suppression Directives", Type: models.ViolationTypeSuppression, Severity: models.SeverityLow, Languages: []string{LanguageGo, LanguageRust}},
+
+	// Rust functions and structure
+	{ID: "rust-function-length", Detector: "Rust Function Analysis", Type: models.ViolationTypeFunctionLength, Severity: models.SeverityLow, Languages: []string{LanguageRust}},
+	{ID: "rust-cyclomatic-complexity", Detector: "Rust Function Analysis", Type: models.ViolationTypeCyclomaticComplexity, Severity: models.SeverityLow, Languages: []string{LanguageRust}},
+	{ID: "rust-nesting-depth", Detector: "Rust Function Analysis", Type: models.ViolationTypeNestingDepth, Severity: models.SeverityLow, Languages: []string{LanguageRust}},
+	{ID: "rust-parameter-count", Detector: "Rust Function Analysis", Type: models.ViolationTypeParameterCount, Severity: models.SeverityLow, Languages: []string{LanguageRust}},
+	{ID: "rust-unsafe-missing-documentation", Detector: "Rust Function Analysis", Type: models.ViolationTypeMissingDocumentation, Severity: models.SeverityHigh, Languages: []string{LanguageRust}},
+	{ID: "rust-async-complexity", Detector: "Rust Function Analysis", Type: models.ViolationTypeFunctionLength, Severity: models.SeverityMedium, Languages: []string{LanguageRust}},
+	{ID: "rust-cognitive-complexity", Detector: "Cognitive Complexity Analysis", Type: models.ViolationTypeCognitiveComplexity, Severity: models.SeverityLow, Languages: []string{LanguageRust}},
+	{ID: "rust-file-size", Detector: "Rust Code Structure Analysis", Type: models.ViolationTypeClassSize, Severity: models.SeverityLow, Languages: []string{LanguageRust}},
+	{ID: "rust-excessive-imports", Detector: "Rust Code Structure Analysis", Type: models.ViolationTypeStructure, Severity: models.SeverityMedium, Languages: []string{LanguageRust}},
+	{ID: "rust-struct-complexity", Detector: "Rust Code Structure Analysis", Type: models.ViolationTypeClassSize, Severity: models.SeverityLow, Languages: []string{LanguageRust}},
+	{ID: RustGodStruct, Detector: "Rust Code Structure Analysis", Type: models.ViolationTypeClassSize, Severity: models.SeverityMedium, Languages: []string{LanguageRust}, enabled: godStructs},
+	{ID: RustLongTuple, Detector: "Rust Code Structure Analysis", Type: models.ViolationTypeStructure, Severity: models.SeverityLow, Languages: []string{LanguageRust}},
+	{ID: "rust-struct-length", Detector: "Rust Code Structure Analysis", Type: models.ViolationTypeClassSize, Severity: models.SeverityLow, Languages: []string{LanguageRust}},
+	{ID: "rust-enum-complexity", Detector: "Rust Code Structure Analysis", Type: models.ViolationTypeClassSize, Severity: models.SeverityLow, Languages: []string{LanguageRust}},
+	{ID: "rust-enum-length", Detector: "Rust Code Structure Analysis", Type: models.ViolationTypeClassSize, Severity: models.SeverityMedium, Languages: []string{LanguageRust}},
+	{ID: "rust-error-enum-size", Detector: "Rust Code Structure Analysis", Type: models.ViolationTypeClassSize, Severity: models.SeverityLow, Languages: []string{LanguageRust}},
+	{ID: "rust-error-string-variants", Detector: "Rust Code Structure Analysis", Type: models.ViolationTypeRustInconsistentErrorType, Severity: models.SeverityLow, Languages: []string{LanguageRust}},
+	{ID: "rust-trait-complexity", Detector: "Rust Code Structure Analysis", Type: models.ViolationTypeClassSize, Severity: models.SeverityLow, Languages: []string{LanguageRust}},
+	{ID: "rust-trait-length", Detector: "Rust Code Structure Analysis", Type: models.ViolationTypeClassSize, Severity: models.SeverityMedium, Languages: []string{LanguageRust}},
+	{ID: "rust-impl-complexity", Detector: "Rust Code Structure Analysis", Type: models.ViolationTypeClassSize, Severity: models.SeverityLow, Languages: []string{LanguageRust}},
+	{ID: "rust-impl-length", Detector: "Rust Code Structure Analysis", Type: models.ViolationTypeClassSize, Severity: models.SeverityMedium, Languages: []string{LanguageRust}},
+	{ID: "rust-module-organization", Detector: "Rust Code Structure Analysis", Type: models.ViolationTypeStructure, Severity: models.SeverityMedium, Languages: []string{LanguageRust}},
+	{ID: "rust-module-nesting", Detector: "Rust Code Structure Analysis", Type: models.ViolationTypeStructure, Severity: models.SeverityLow, Languages: []string{LanguageRust}},
+	{ID: "rust-organization-pattern", Detector: "Rust Code Structure Analysis", Type: models.ViolationTypeStructure, Severity: models.SeverityLow, Languages: []string{LanguageRust}},
+	{ID: "rust-magic-number", Detector: "Rust Magic Number Detector", Type: models.ViolationTypeMagicNumber, Severity: models.SeverityLow, Languages: []string{LanguageRust}},
+	{ID: RustMacroArms, Detector: "Rust Macro Analysis", Type: models.ViolationTypeRustMacroComplexity, Severity: models.SeverityMedium, Languages: []string{LanguageRust}},
+	{ID: RustMacroNesting, Detector: "Rust Macro Analysis", Type: models.ViolationTypeRustMacroComplexity, Severity: models.SeverityMedium, Languages: []string{LanguageRust}},
+	{ID: RustMacroLength, Detector: "Rust Macro Analysis", Type: models.ViolationTypeRustMacroComplexity, Severity: models.SeverityMedium, Languages: []string{LanguageRust}},
+	{ID: "rust-magic-string", Detector: "Rust Magic Number Detector", Type: models.ViolationTypeMagicString, Severity: models.SeverityLow, Languages: []string{LanguageRust}, enabled: magicStrings},
+	{ID: RustCommentedCode, Detector: "Rust Commented Code Detector", Type: models.ViolationTypeCommentedCode, Severity: models.SeverityLow, Languages: []string{LanguageRust}},
+	{ID: RustUnitLiteral, Detector: "Rust Magic Number Detector", Type: models.ViolationTypeMagicNumber, Severity: models.SeverityLow, Languages: []string{LanguageRust}, enabled: unitLiterals},
+
+	// Rust naming and documentation
+	{ID: RustInvalidFunctionNaming, Detector: "Rust Naming Convention Analysis", Type: models.ViolationTypeNaming, Severity: models.SeverityMedium, Languages: []string{LanguageRust}},
+	{ID: RustInvalidStructNaming, Detector: "Rust Naming Convention Analysis", Type: models.ViolationTypeNaming, Severity: models.SeverityHigh, Languages: []string{LanguageRust}},
+	{ID: RustInvalidEnumNaming, Detector: "Rust Naming Convention Analysis", Type: models.ViolationTypeNaming, Severity: models.SeverityHigh, Languages: []string{LanguageRust}},
+	{ID: RustInvalidTraitNaming, Detector: "Rust Naming Convention Analysis", Type: models.ViolationTypeNaming, Severity: models.SeverityHigh, Languages: []string{LanguageRust}},
+	{ID: RustInvalidConstantNaming, Detector: "Rust Naming Convention Analysis", Type: models.ViolationTypeNaming, Severity: models.SeverityMedium, Languages: []string{LanguageRust}},
+	{ID: RustInvalidModuleNaming, Detector: "Rust Naming Convention Analysis", Type: models.ViolationTypeNaming, Severity: models.SeverityMedium, Languages: []string{LanguageRust}},
+	{ID: RustInvalidStaticNaming, Detector: "Rust Naming Convention Analysis", Type: models.ViolationTypeNaming, Severity: models.SeverityMedium, Languages: []string{LanguageRust}},
+	{ID: RustInvalidTypeAlias, Detector: "Rust Naming Convention Analysis", Type: models.ViolationTypeNaming, Severity: models.SeverityMedium, Languages: []string{LanguageRust}},
+	{ID: RustInvalidParameterNaming, Detector: "Rust Naming Convention Analysis", Type: models.ViolationTypeNaming, Severity: models.SeverityLow, Languages: []string{LanguageRust}},
+	{ID: RustNonDescriptiveName, Detector: "Rust Naming Convention Analysis", Type: models.ViolationTypeNaming, Severity: models.SeverityMedium, Languages: []string{LanguageRust}},
+	{ID: RustAcronymCasing, Detector: "Rust Naming Convention Analysis", Type: models.ViolationTypeNaming, Severity: models.SeverityLow, Languages: []string{LanguageRust}},
+	{ID: RustUnclearAbbreviation, Detector: "Rust Naming Convention Analysis", Type: models.ViolationTypeNaming, Severity: models.SeverityLow, Languages: []string{LanguageRust}},
+	{ID: "rust-missing-function-documentation", Detector: "Rust Documentation Quality", Type: models.ViolationTypeMissingDocumentation, Severity: models.SeverityMedium, Languages: []string{LanguageRust}, enabled: requiresPublicDocs},
+	{ID: "rust-missing-struct-documentation", Detector: "Rust Documentation Quality", Type: models.ViolationTypeMissingDocumentation, Severity: models.SeverityMedium, Languages: []string{LanguageRust}, enabled: requiresPublicDocs},
+	{ID: "rust-missing-enum-documentation", Detector: "Rust Documentation Quality", Type: models.ViolationTypeMissingDocumentation, Severity: models.SeverityMedium, Languages: []string{LanguageRust}, enabled: requiresPublicDocs},
+	{ID: "rust-missing-trait-documentation", Detector: "Rust Documentation Quality", Type: models.ViolationTypeMissingDocumentation, Severity: models.SeverityMedium, Languages: []string{LanguageRust}, enabled: requiresPublicDocs},
+	{ID: "rust-missing-module-documentation", Detector: "Rust Documentation Quality", Type: models.ViolationTypeMissingDocumentation, Severity: models.SeverityLow, Languages: []string{LanguageRust}, enabled: requiresPublicDocs},
+	{ID: "rust-missing-constant-documentation", Detector: "Rust Documentation Quality", Type: models.ViolationTypeMissingDocumentation, Severity: models.SeverityLow, Languages: []string{LanguageRust}, enabled: requiresPublicDocs},
+	{ID: "rust-missing-macro-documentation", Detector: "Rust Documentation Quality", Type: models.ViolationTypeMissingDocumentation, Severity: models.SeverityMedium, Languages: []string{LanguageRust}, enabled: requiresPublicDocs},
+	{ID: RustMissingSafetySection, Detector: "Rust Documentation Quality", Type: models.ViolationTypeMissingDocumentation, Severity: models.SeverityMedium, Languages: []string{LanguageRust}, enabled: safetySection},
+	{ID: RustMissingErrorsSection, Detector: "Rust Documentation Quality", Type: models.ViolationTypeMissingDocumentation, Severity: models.SeverityLow, Languages: []string{LanguageRust}, enabled: errorsSection},
+	{ID: RustMissingPanicsSection, Detector: "Rust Documentation Quality", Type: models.ViolationTypeMissingDocumentation, Severity: models.SeverityLow, Languages: []string{LanguageRust}, enabled: panicsSection},
+	{ID: RustMissingDocExample, Detector: "Rust Documentation Quality", Type: models.ViolationTypeMissingDocumentation, Severity: models.SeverityLow, Languages: []string{LanguageRust}, enabled: docExamples},
+
+	// Rust ownership and error handling
+	{ID: "rust-unnecessary-clone", Detector: "Rust Ownership Analysis", Type: models.ViolationTypeRustUnnecessaryClone, Severity: models.SeverityLow, Languages: []string{LanguageRust}},
+	{ID: "rust-pass-by-value", Detector: "Rust Ownership Analysis", Type: models.ViolationTypeRustInefficientBorrowing, Severity: models.SeverityLow, Languages: []string{LanguageRust}},
+	{ID: RustUnnecessaryOwnedParameter, Detector: "Rust Ownership Analysis", Type: models.ViolationTypeRustInefficientBorrowing, Severity: models.SeverityLow, Languages: []string{LanguageRust}},
+	{ID: RustUnnecessaryMutReference, Detector: "Rust Ownership Analysis", Type: models.ViolationTypeRustInefficientBorrowing, Severity: models.SeverityLow, Languages: []string{LanguageRust}},
+	{ID: "rust-inefficient-borrowing", Detector: "Rust Ownership Analysis", Type: models.ViolationTypeRustInefficientBorrowing, Severity: models.SeverityLow, Languages: []string{LanguageRust}},
+	{ID: "rust-complex-lifetime", Detector: "Rust Ownership Analysis", Type: models.ViolationTypeRustComplexLifetime, Severity: models.SeverityMedium, Languages: []string{LanguageRust}},
+	{ID: RustNeedlessLifetime, Detector: "Rust Ownership Analysis", Type: models.ViolationTypeRustComplexLifetime, Severity: models.SeverityLow, Languages: []string{LanguageRust}},
+	{ID: RustLifetimeBoundChain, Detector: "Rust Ownership Analysis", Type: models.ViolationTypeRustComplexLifetime, Severity: models.SeverityMedium, Languages: []string{LanguageRust}},
+	{ID: "rust-move-semantics", Detector: "Rust Ownership Analysis", Type: models.ViolationTypeRustMoveSemanticsViolation, Severity: models.SeverityLow, Languages: []string{LanguageRust}},
+	{ID: "rust-borrow-checker-bypass", Detector: "Rust Ownership Analysis", Type: models.ViolationTypeRustBorrowCheckerBypass, Severity: models.SeverityHigh, Languages: []string{LanguageRust}},
+	{ID: RustUnnecessarySmartPointer, Detector: "Rust Ownership Analysis", Type: models.ViolationTypeRustUnnecessaryAllocation, Severity: models.SeverityLow, Languages: []string{LanguageRust}},
+	{ID: RustStringConcatInLoop, Detector: "Rust Ownership Analysis", Type: models.ViolationTypeRustInefficientStringConcat, Severity: models.SeverityLow, Languages: []string{LanguageRust},
+		enabled: func(config *DetectorConfig) bool { return config.RustConfig == nil || config.RustConfig.DetectInefficientString }},
+	{ID: "rust-mutable-static", Detector: "Rust Ownership Analysis", Type: models.ViolationTypeRustRaceCondition, Severity: models.SeverityHigh, Languages: []string{LanguageRust},
+		enabled: func(config *DetectorConfig) bool { return config.RustConfig == nil || !config.RustConfig.AllowMutableStatics }},
+	{ID: RustMutableStaticAccess, Detector: "Rust Ownership Analysis", Type: models.ViolationTypeRustRaceCondition, Severity: models.SeverityMedium, Languages: []string{LanguageRust},
+		enabled: func(config *DetectorConfig) bool { return config.RustConfig == nil || !config.RustConfig.AllowMutableStatics }},
+	{ID: "rust-heap-allocation", Detector: "Rust Ownership Analysis", Type: models.ViolationTypeRustUnnecessaryAllocation, Severity: models.SeverityHigh, Languages: []string{LanguageRust},
+		enabled: func(config *DetectorConfig) bool { return config.RustConfig != nil && config.RustConfig.DetectHeapAllocations }},
+	{ID: "rust-overuse-unwrap", Detector: "Rust Error Handling Analysis", Type: models.ViolationTypeRustOveruseUnwrap, Severity: models.SeverityMedium, Languages: []string{LanguageRust}},
+	{ID: "rust-unwrap-count", Detector: "Rust Error Handling Analysis", Type: models.ViolationTypeRustOveruseUnwrap, Severity: models.SeverityMedium, Languages: []string{LanguageRust}},
+	{ID: "rust-missing-error-propagation", Detector: "Rust Error Handling Analysis", Type: models.ViolationTypeRustMissingErrorPropagation, Severity: models.SeverityMedium, Languages: []string{LanguageRust}},
+	{ID: RustManualErrorPropagation, Detector: "Rust Error Handling Analysis", Type: models.ViolationTypeRustMissingErrorPropagation, Severity: models.SeverityLow, Languages: []string{LanguageRust}},
+	{ID: "rust-inconsistent-error-type", Detector: "Rust Error Handling Analysis", Type: models.ViolationTypeRustInconsistentErrorType, Severity: models.SeverityLow, Languages: []string{LanguageRust}},
+	{ID: RustStringError, Detector: "Rust Error Handling Analysis", Type: models.ViolationTypeRustInconsistentErrorType, Severity: models.SeverityMedium, Languages: []string{LanguageRust}},
+	{ID: RustOpaquePublicError, Detector: "Rust Error Handling Analysis", Type: models.ViolationTypeRustInconsistentErrorType, Severity: models.SeverityMedium, Languages: []string{LanguageRust}},
+	{ID: RustSwallowedError, Detector: "Rust Error Handling Analysis", Type: models.ViolationTypeRustMissingErrorPropagation, Severity: models.SeverityMedium, Languages: []string{LanguageRust},
+		enabled: func(config *DetectorConfig) bool { return config.RustConfig == nil || config.RustConfig.EnforceResultPropagation }},
+	{ID: "rust-panic-prone-code", Detector: "Rust Error Handling Analysis", Type: models.ViolationTypeRustPanicProneCode, Severity: models.SeverityMedium, Languages: []string{LanguageRust}},
+	{ID: "rust-unhandled-result", Detector: "Rust Error Handling Analysis", Type: models.ViolationTypeRustUnhandledResult, Severity: models.SeverityMedium, Languages: []string{LanguageRust}},
+	{ID: RustUnsafeWithoutSafetyComment, Detector: "Rust Safety Comment Analysis", Type: models.ViolationTypeRustUnsafeWithoutComment, Severity: models.SeverityMedium, Languages: []string{LanguageRust}, enabled: safetyComments},
+	{ID: RustUnsafeSendSyncImpl, Detector: "Rust Safety Comment Analysis", Type: models.ViolationTypeRustUnsafeWithoutComment, Severity: models.SeverityHigh, Languages: []string{LanguageRust}, enabled: safetyComments},
+	{ID: RustUncheckedPointerArithmetic, Detector: "Rust Pointer Arithmetic Analysis", Type: models.ViolationTypeRustRawPointerAbuse, Severity: models.SeverityMedium, Languages: []string{LanguageRust}, enabled: pointerArithmetic},
+	{ID: RustLibraryPanic, Detector: "Rust Library Panic Analysis", Type: models.ViolationTypeRustPanicProneCode, Severity: models.SeverityMedium, Languages: []string{LanguageRust}, enabled: libraryPanics},
+	{ID: RustUnwrapDensity, Detector: "Rust Unwrap Density", Type: models.ViolationTypeRustOveruseUnwrap, Severity: models.SeverityLow, Languages: []string{LanguageRust}, enabled: unwrapDensity},
+	{ID: RustFileUnwrapDensity, Detector: "Rust Unwrap Density", Type: models.ViolationTypeRustOveruseUnwrap, Severity: models.SeverityLow, Languages: []string{LanguageRust}, enabled: fileUnwrapDensity},
+	{ID: "rust-improper-expect", Detector: "Rust Error Handling Analysis", Type: models.ViolationTypeRustImproperExpect, Severity: models.SeverityLow, Languages: []string{LanguageRust}},
+
+	// Rust concurrency rule pack
+	{ID: RustBlockingInAsync, Detector: "Rust Async Analysis", Type: models.ViolationTypeRustBlockingInAsync, Severity: models.SeverityHigh, Languages: []string{LanguageRust},
+		enabled: func(config *DetectorConfig) bool { return config.RustConfig != nil && config.RustConfig.DetectBlockingInAsync }},
+	{ID: RustSequentialAwait, Detector: "Rust Async Analysis", Type: models.ViolationTypeRustInefficientIteration, Severity: models.SeverityInfo, Languages: []string{LanguageRust},
+		enabled: func(config *DetectorConfig) bool { return config.RustConfig != nil && config.RustConfig.DetectSequentialAwaits }},
+	{ID: RustMatchOnBool, Detector: "Rust Pattern Matching Analysis", Type: models.ViolationTypeRustMatchOnBool, Severity: models.SeverityLow, Languages: []string{LanguageRust},
+		enabled: func(config *DetectorConfig) bool { return config.RustConfig != nil && config.RustConfig.EnablePatternMatchCheck }},
+	{ID: RustIfLetLadder, Detector: "Rust Pattern Matching Analysis", Type: models.ViolationTypeRustNestedPatternMatching, Severity: models.SeverityLow, Languages: []string{LanguageRust},
+		enabled: func(config *DetectorConfig) bool { return config.RustConfig != nil && config.RustConfig.EnablePatternMatchCheck }},
+	{ID: RustWildcardEnumArm, Detector: "Rust Pattern Matching Analysis", Type: models.ViolationTypeRustMissingMatchArm, Severity: models.SeverityMedium, Languages: []string{LanguageRust},
+		enabled: func(config *DetectorConfig) bool { return config.RustConfig != nil && config.RustConfig.EnablePatternMatchCheck && config.RustConfig.RequireExhaustiveMatch }},
+	{ID: RustMutexAcrossAwait, Detector: "Rust Concurrency Analysis", Type: models.ViolationTypeRustDeadlockProne, Severity: models.SeverityHigh, Languages: []string{LanguageRust}, enabled: concurrencyPack},
+	{ID: RustSharedMutexFanOut, Detector: "Rust Concurrency Analysis", Type: models.ViolationTypeRustDeadlockProne, Severity: models.SeverityMedium, Languages: []string{LanguageRust}, enabled: concurrencyPack},
+	{ID: RustSharedMutableStatic, Detector: "Rust Concurrency Analysis", Type: models.ViolationTypeRustRaceCondition, Severity: models.SeverityHigh, Languages: []string{LanguageRust}, enabled: concurrencyPack},
+	{ID: RustSpawnWithoutJoin, Detector: "Rust Concurrency Analysis", Type: models.ViolationTypeRustRaceCondition, Severity: models.SeverityMedium, Languages: []string{LanguageRust}, enabled: concurrencyPack},
+	{ID: RustUnboundedChannel, Detector: "Rust Concurrency Analysis", Type: models.ViolationTypeRustUnboundedChannel, Severity: models.SeverityMedium, Languages: []string{LanguageRust}, enabled: concurrencyPack},
+
+	// Rust imports rule pack
+	{ID: RustUnsortedImports, Detector: "Rust Import Analysis", Type: models.ViolationTypeRustModuleOrganization, Severity: models.SeverityLow, Languages: []string{LanguageRust}, enabled: importsPack},
+	{ID: RustDuplicateImport, Detector: "Rust Import Analysis", Type: models.ViolationTypeRustModuleOrganization, Severity: models.SeverityLow, Languages: []string{LanguageRust}, enabled: importsPack},
+	{ID: RustLocalImports, Detector: "Rust Import Analysis", Type: models.ViolationTypeRustModuleOrganization, Severity: models.SeverityLow, Languages: []string{LanguageRust}, enabled: importsPack},
+	{ID: RustReexportChain, Detector: "Rust Import Analysis", Type: models.ViolationTypeRustModuleOrganization, Severity: models.SeverityLow, Languages: []string{LanguageRust}, enabled: importsPack},
+}
+
+// Rules returns the rules of the registered detectors, sorted by ID. Clippy
+// lints and cargo check diagnostics are not listed: their IDs come from the
+// Rust toolchain.
+func Rules() []RuleInfo {
+	list := make([]RuleInfo, len(ruleCatalog))
+	copy(list, ruleCatalog)
+	for i := range list {
+		list[i].Category = list[i].Type.Category()
+	}
+	sort.Slice(list, func(i, j int) bool { return list[i].ID < list[j].ID })
+	return list
+}
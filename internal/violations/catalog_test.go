@@ -0,0 +1,50 @@
+package violations
+
+import (
+	"testing"
+)
+
+func TestRulesAreSortedAndComplete(t *testing.T) {
+	rules := Rules()
+	seen := make(map[string]bool)
+	for i, rule := range rules {
+		if i > 0 && rules[i-1].ID >= rule.ID {
+			t.Errorf("Expected rules sorted by unique ID, got %q before %q", rules[i-1].ID, rule.ID)
+		}
+		if rule.Detector == "" || rule.Category == "" || len(rule.Languages) == 0 {
+			t.Errorf("Rule %q is missing its detector, category or languages: %+v", rule.ID, rule)
+		}
+		seen[rule.ID] = true
+	}
+
+	for _, id := range []string{"function-length", "magic-number", "todo-comment", "rust-code-clone", RustMutexAcrossAwait} {
+		if !seen[id] {
+			t.Errorf("Expected rule %q in the catalog", id)
+		}
+	}
+}
+
+func TestRuleEnabledFollowsConfig(t *testing.T) {
+	rules := make(map[string]RuleInfo)
+	for _, rule := range Rules() {
+		rules[rule.ID] = rule
+	}
+
+	config := DefaultDetectorConfig()
+	if !rules["function-length"].Enabled(config) {
+		t.Error("Expected threshold rules to be enabled by default")
+	}
+	if rules["pasted-code"].Enabled(config) || rules[RustSpawnWithoutJoin].Enabled(config) {
+		t.Error("Expected opt-in rules to be disabled by default")
+	}
+
+	config.DetectPastedCode = true
+	config.RustConfig.EnableConcurrencyPack = true
+	config.RustConfig.AllowMutableStatics = true
+	if !rules["pasted-code"].Enabled(config) || !rules[RustSpawnWithoutJoin].Enabled(config) {
+		t.Error("Expected opt-in rules to be enabled when configured")
+	}
+	if rules["rust-mutable-static"].Enabled(config) {
+		t.Error("Expected rust-mutable-static to be disabled when mutable statics are allowed")
+	}
+}
@@ -0,0 +1,187 @@
+package violations
+
+import (
+	"fmt"
+	"sort"
+	"strings"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+// largeDependencyCycle is the number of modules above which a cycle is reported at high severity
+const largeDependencyCycle = 3
+
+// CircularDependencies reports the Rust modules recorded since the last Reset
+// that depend on themselves through other modules. Each module of a cycle is
+// reported where it depends on the next module of the shortest cycle through
+// it. Call it once every file was detected; Go needs no check, as the
+// compiler rejects import cycles.
+func (d *CouplingDetector) CircularDependencies() []*models.Violation {
+	d.mutex.Lock()
+	defer d.mutex.Unlock()
+
+	var violations []*models.Violation
+	for _, component := range d.dependencyComponents() {
+		members := make(map[string]bool, len(component))
+		for _, module := range component {
+			members[module] = true
+		}
+
+		severity := models.SeverityMedium
+		if len(component) > largeDependencyCycle {
+			severity = models.SeverityHigh
+		}
+		for _, module := range component {
+			if d.modules[module].language != "Rust" {
+				continue
+			}
+			cycle := d.shortestCycle(module, members)
+			next := cycle[1]
+			site := d.modules[module].deps[next]
+
+			message := fmt.Sprintf("Module '%s' is part of a circular dependency: %s", module, strings.Join(cycle, " -> "))
+			if len(component) > len(cycle)-1 {
+				message += fmt.Sprintf("; %d modules depend on each other: %s", len(component), strings.Join(component, ", "))
+			}
+			violations = append(violations, &models.Violation{
+				Type:     models.ViolationTypeRustCircularDependency,
+				Severity: severity,
+				Message:  message,
+				File:     site.file,
+				Line:     site.line,
+				Column:   1,
+				Rule:     RustCircularDependency,
+				Suggestion: fmt.Sprintf("Break the cycle at '%s' -> '%s': move what both modules need into a module of its own, "+
+					"or define a trait in '%s' for what it needs from '%s'", module, next, module, next),
+			})
+		}
+	}
+	return violations
+}
+
+// dependencyComponents returns the strongly connected components of more
+// than one module in the graph of scanned modules, each sorted, ordered by
+// their first module. Dependencies on modules outside the scan are left out.
+func (d *CouplingDetector) dependencyComponents() [][]string {
+	modules := make([]string, 0, len(d.modules))
+	for module := range d.modules {
+		modules = append(modules, module)
+	}
+	sort.Strings(modules)
+
+	// Tarjan's algorithm
+	index := make(map[string]int)
+	lowlink := make(map[string]int)
+	onStack := make(map[string]bool)
+	var stack []string
+	var components [][]string
+
+	var connect func(module string)
+	connect = func(module string) {
+		index[module] = len(index)
+		lowlink[module] = index[module]
+		stack = append(stack, module)
+		onStack[module] = true
+
+		for _, dep := range d.scannedDependencies(module) {
+			if _, visited := index[dep]; !visited {
+				connect(dep)
+				lowlink[module] = min(lowlink[module], lowlink[dep])
+			} else if onStack[dep] {
+				lowlink[module] = min(lowlink[module], index[dep])
+			}
+		}
+		if lowlink[module] != index[module] {
+			return
+		}
+
+		var component []string
+		for {
+			top := stack[len(stack)-1]
+			stack = stack[:len(stack)-1]
+			onStack[top] = false
+			component = append(component, top)
+			if top == module {
+				break
+			}
+		}
+		if len(component) > 1 {
+			sort.Strings(component)
+			components = append(components, component)
+		}
+	}
+	for _, module := range modules {
+		if _, visited := index[module]; !visited {
+			connect(module)
+		}
+	}
+
+	sort.Slice(components, func(i, j int) bool {
+		return components[i][0] < components[j][0]
+	})
+	return components
+}
+
+// scannedDependencies returns the dependencies of a module on other scanned
+// modules, sorted. Dependencies between a module and its ancestors, such as
+// a crate root calling its child modules which use items of the root, are
+// how Rust's module tree works and are left out.
+func (d *CouplingDetector) scannedDependencies(module string) []string {
+	var deps []string
+	for dep := range d.modules[module].deps {
+		if strings.HasPrefix(dep, module+"::") || strings.HasPrefix(module, dep+"::") {
+			continue
+		}
+		if _, scanned := d.modules[dep]; scanned {
+			deps = append(deps, dep)
+		}
+	}
+	sort.Strings(deps)
+	return deps
+}
+
+// shortestCycle returns the shortest path from a module back to itself
+// through the members of its component, starting and ending with the module
+func (d *CouplingDetector) shortestCycle(module string, members map[string]bool) []string {
+	parent := make(map[string]string)
+	queue := []string{module}
+	for len(queue) > 0 {
+		current := queue[0]
+		queue = queue[1:]
+		for _, dep := range d.scannedDependencies(current) {
+			if dep == module {
+				var path []string
+				for at := current; at != module; at = parent[at] {
+					path = append(path, at)
+				}
+				cycle := []string{module}
+				for i := len(path) - 1; i >= 0; i-- {
+					cycle = append(cycle, path[i])
+				}
+				return append(cycle, module)
+			}
+			if _, seen := parent[dep]; seen || !members[dep] {
+				continue
+			}
+			parent[dep] = current
+			queue = append(queue, dep)
+		}
+	}
+	return []string{module, module}
+}
+
+// rustDependencyLine returns the line first naming the last segment of a
+// module path, usually the `use` declaration importing it, or fallback
+func rustDependencyLine(tokens []types.RustTokenInfo, dep string, fallback int) int {
+	name := dep
+	if i := strings.LastIndex(dep, "::"); i >= 0 {
+		name = dep[i+2:]
+	}
+	for _, token := range tokens {
+		if token.Kind == types.RustTokenIdent && token.Text == name {
+			return token.Line
+		}
+	}
+	return fallback
+}
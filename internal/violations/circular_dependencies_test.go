@@ -0,0 +1,97 @@
+package violations
+
+import (
+	"path/filepath"
+	"testing"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+func TestCouplingDetector_CircularDependencies(t *testing.T) {
+	files := []struct {
+		name      string
+		source    string
+		functions []*types.RustFunctionInfo
+		modules   []*types.RustModuleInfo
+	}{
+		{"lib.rs", "mod orders;\n\npub fn init() {}\n\npub fn run() {\n    orders::place();\n}\n",
+			[]*types.RustFunctionInfo{{Name: "init", StartLine: 3, EndLine: 3}, {Name: "run", StartLine: 5, EndLine: 7}},
+			[]*types.RustModuleInfo{{Name: "orders"}}},
+		{"orders.rs", "use crate::billing;\n\npub fn place() {\n    super::init();\n    billing::charge();\n}\n",
+			[]*types.RustFunctionInfo{{Name: "place", StartLine: 3, EndLine: 6}}, nil},
+		{"billing.rs", "use crate::audit::Log;\n\npub fn charge() {\n    Log::write();\n}\n",
+			[]*types.RustFunctionInfo{{Name: "charge", StartLine: 3, EndLine: 5}}, nil},
+		{"audit.rs", "pub fn write() {\n    crate::orders::place();\n}\n",
+			[]*types.RustFunctionInfo{{Name: "write", StartLine: 1, EndLine: 3}}, nil},
+		{"email.rs", "use crate::billing;\n\npub fn send() {\n    billing::charge();\n}\n",
+			[]*types.RustFunctionInfo{{Name: "send", StartLine: 3, EndLine: 5}}, nil},
+	}
+
+	detector := NewCouplingDetector(DefaultDetectorConfig())
+	for _, file := range files {
+		info := &types.RustASTInfo{Tokens: rustTestTokens(file.source), Functions: file.functions, Modules: file.modules}
+		detector.Detect(&models.FileInfo{Path: filepath.Join("shop", "src", file.name)}, info)
+	}
+
+	violations := detector.CircularDependencies()
+	expected := []struct {
+		file    string
+		line    int
+		message string
+	}{
+		{"audit.rs", 2, "Module 'shop::audit' is part of a circular dependency: shop::audit -> shop::orders -> shop::billing -> shop::audit"},
+		{"billing.rs", 1, "Module 'shop::billing' is part of a circular dependency: shop::billing -> shop::audit -> shop::orders -> shop::billing"},
+		{"orders.rs", 1, "Module 'shop::orders' is part of a circular dependency: shop::orders -> shop::billing -> shop::audit -> shop::orders"},
+	}
+	if len(violations) != len(expected) {
+		t.Fatalf("Expected %d circular dependency violations, got %+v", len(expected), violations)
+	}
+	for i, want := range expected {
+		v := violations[i]
+		if v.Rule != RustCircularDependency || v.Type != models.ViolationTypeRustCircularDependency || v.Severity != models.SeverityMedium ||
+			v.File != filepath.Join("shop", "src", want.file) || v.Line != want.line || v.Message != want.message {
+			t.Errorf("Unexpected violation %d: %+v", i, v)
+		}
+	}
+	if violations[2].Suggestion != "Break the cycle at 'shop::orders' -> 'shop::billing': move what both modules need into a module of its own, "+
+		"or define a trait in 'shop::orders' for what it needs from 'shop::billing'" {
+		t.Errorf("Unexpected suggestion %q", violations[2].Suggestion)
+	}
+
+	detector.Reset()
+	if violations := detector.CircularDependencies(); len(violations) != 0 {
+		t.Errorf("Expected no cycles after Reset, got %+v", violations)
+	}
+}
+
+func TestCouplingDetector_LargeDependencyCycle(t *testing.T) {
+	detector := NewCouplingDetector(DefaultDetectorConfig())
+	// a and b depend on each other, and both on c, d and e, which depend on a
+	for module, deps := range map[string][]string{
+		"app::a": {"app::b", "app::c", "app::d", "app::e"},
+		"app::b": {"app::a"},
+		"app::c": {"app::a"},
+		"app::d": {"app::a"},
+		"app::e": {"app::a"},
+	} {
+		lines := make(map[string]int)
+		for i, dep := range deps {
+			lines[dep] = i + 1
+		}
+		detector.record(module, "Rust", module+".rs", lines, 0)
+	}
+
+	violations := detector.CircularDependencies()
+	if len(violations) != 5 {
+		t.Fatalf("Expected every module of the cycle to be reported, got %+v", violations)
+	}
+	expected := "Module 'app::b' is part of a circular dependency: app::b -> app::a -> app::b; " +
+		"5 modules depend on each other: app::a, app::b, app::c, app::d, app::e"
+	if violations[1].Message != expected || violations[1].Severity != models.SeverityHigh {
+		t.Errorf("Expected %q at high severity, got %+v", expected, violations[1])
+	}
+	if violations[0].File != "app::a.rs" || violations[0].Line != 1 {
+		t.Errorf("Expected a to be reported where it depends on b, got %s:%d", violations[0].File, violations[0].Line)
+	}
+}
@@ -3,7 +3,6 @@ package violations
 import (
 	"encoding/json"
 	"fmt"
-	"os"
 	"os/exec"
 	"path/filepath"
 	"strings"
@@ -129,7 +128,7 @@ func (c *ClippyIntegrator) Detect(fileInfo *models.FileInfo, astInfo interface{}
 	}
 
 	// Find the project root (directory containing Cargo.toml)
-	projectRoot := c.findCargoProjectRoot(fileInfo.Path)
+	projectRoot := cargoProjectRoot(fileInfo.Path)
 	if projectRoot == "" {
 		// No Cargo.toml found, skip clippy integration
 		return []*models.Violation{}
@@ -171,28 +170,6 @@ func (c *ClippyIntegrator) Detect(fileInfo *models.FileInfo, astInfo interface{}
 	return violations
 }
 
-// findCargoProjectRoot finds the directory containing Cargo.toml starting from the given path
-func (c *ClippyIntegrator) findCargoProjectRoot(filePath string) string {
-	dir := filepath.Dir(filePath)
-	
-	// Look for Cargo.toml up the directory tree
-	for {
-		cargoPath := filepath.Join(dir, "Cargo.toml")
-		if _, err := os.Stat(cargoPath); err == nil {
-			return dir
-		}
-		
-		parent := filepath.Dir(dir)
-		if parent == dir {
-			// Reached filesystem root
-			break
-		}
-		dir = parent
-	}
-	
-	return ""
-}
-
 // runClippy executes cargo clippy with JSON output and core lint categories
 func (c *ClippyIntegrator) runClippy(projectRoot string) ([]byte, error) {
 	// Build the clippy command with specific lint categories
@@ -39,9 +39,7 @@ func TestClippyIntegrator_DetectNonRustFile(t *testing.T) {
 	}
 }
 
-func TestClippyIntegrator_FindCargoProjectRoot(t *testing.T) {
-	integrator := NewClippyIntegrator(DefaultDetectorConfig())
-	
+func TestCargoProjectRoot(t *testing.T) {
 	// Create a temporary directory structure
 	tempDir := t.TempDir()
 	
@@ -65,21 +63,19 @@ edition = "2021"
 	
 	// Test finding from subdirectory
 	testFile := filepath.Join(subDir, "test.rs")
-	projectRoot := integrator.findCargoProjectRoot(testFile)
+	projectRoot := cargoProjectRoot(testFile)
 	
 	if projectRoot != tempDir {
 		t.Errorf("Expected project root '%s', got '%s'", tempDir, projectRoot)
 	}
 }
 
-func TestClippyIntegrator_FindCargoProjectRootNoCargoToml(t *testing.T) {
-	integrator := NewClippyIntegrator(DefaultDetectorConfig())
-	
+func TestCargoProjectRootNoCargoToml(t *testing.T) {
 	// Create a temporary directory without Cargo.toml
 	tempDir := t.TempDir()
 	testFile := filepath.Join(tempDir, "test.rs")
 	
-	projectRoot := integrator.findCargoProjectRoot(testFile)
+	projectRoot := cargoProjectRoot(testFile)
 	
 	if projectRoot != "" {
 		t.Errorf("Expected empty project root for directory without Cargo.toml, got '%s'", projectRoot)
@@ -0,0 +1,747 @@
+package violations
+
+import (
+	"fmt"
+	"go/scanner"
+	"go/token"
+	"hash/fnv"
+	"os"
+	"path/filepath"
+	"runtime"
+	"sort"
+	"sync"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+// Clone detection constants
+const (
+	DefaultMinCloneTokens = 50
+
+	// maxCloneBucket caps the occurrences matched per window hash so highly
+	// repetitive code cannot make detection quadratic
+	maxCloneBucket = 64
+
+	cloneHashBase uint64 = 1099511628211
+)
+
+// Placeholders substituted for identifiers and literals, so copies with
+// renamed variables or changed constants still match
+const (
+	cloneIdentPlaceholder   = "$id"
+	cloneLiteralPlaceholder = "$lit"
+)
+
+// CloneToken is a normalized token with its 1-based source line
+type CloneToken struct {
+	Value string
+	Line  int
+}
+
+// CloneFragment locates one copy of a cloned token sequence
+type CloneFragment struct {
+	File      string
+	StartLine int
+	EndLine   int
+}
+
+// ClonePair is a token sequence found at two locations
+type ClonePair struct {
+	Original    CloneFragment // Occurrence in the file with the earlier path, or earlier in the same file
+	Duplicate   CloneFragment // Later occurrence
+	Tokens      int
+	Granularity models.CloneGranularity
+}
+
+// cloneShardCount is the number of separately locked window maps, so workers
+// indexing files concurrently rarely wait for each other
+const cloneShardCount = 32
+
+// CloneMinimums are the fewest tokens a clone must have at each
+// granularity, so that small repetition within a file can be tolerated while
+// copies across modules are not. Zero values use the index's minimum.
+type CloneMinimums struct {
+	IntraFile   int
+	IntraModule int
+	CrossModule int
+}
+
+// CloneIndex finds clones with a Rabin-Karp rolling hash over fixed-size
+// windows of normalized tokens. Files are inserted concurrently while they are
+// parsed; matching waits until all files are in, so the clones found do not
+// depend on the order in which files were inserted. Matching windows are
+// verified token by token and extended to the longest common sequence, so
+// each clone is reported once however many windows it spans. The index spans
+// every file inserted since the last Reset, so clones are found across files
+// and directories.
+type CloneIndex struct {
+	minTokens int // Window size: the smallest minimum of any granularity
+	minimums  map[models.CloneGranularity]int
+	shards    [cloneShardCount]cloneShard
+	files     []*cloneFile
+	records   []cloneRecord
+	resolved  bool
+	mutex     sync.Mutex // Guards files, records and resolved
+}
+
+// cloneShard holds the windows whose hashes fall into it
+type cloneShard struct {
+	windows map[uint64][]clonePosition
+	mutex   sync.Mutex
+}
+
+// cloneFile is an indexed file with its tokens hashed to symbols
+type cloneFile struct {
+	path    string
+	tokens  []CloneToken
+	symbols []uint64
+	hashes  []uint64
+	pairs   []ClonePair
+}
+
+// clonePosition is the start of a window in an indexed file
+type clonePosition struct {
+	file   int
+	offset int
+}
+
+// cloneWindow is a window hash with its position, collected before a shard is locked
+type cloneWindow struct {
+	hash     uint64
+	position clonePosition
+}
+
+// cloneSpan is the token range [start, end) of an indexed file
+type cloneSpan struct {
+	file  int
+	start int
+	end   int
+}
+
+// cloneRecord is a clone pair in token offsets, kept for grouping
+type cloneRecord struct {
+	original  cloneSpan
+	duplicate cloneSpan
+}
+
+// cloneDiagonal identifies the alignment of two token streams; consecutive
+// matching windows on one diagonal belong to the same clone
+type cloneDiagonal struct {
+	file  int
+	shift int
+}
+
+// NewCloneIndex creates an index reporting clones of at least minTokens tokens
+func NewCloneIndex(minTokens int) *CloneIndex {
+	return NewCloneIndexWithMinimums(minTokens, CloneMinimums{})
+}
+
+// NewCloneIndexWithMinimums creates an index reporting clones of at least
+// minTokens tokens, or of the minimum set for their granularity
+func NewCloneIndexWithMinimums(minTokens int, minimums CloneMinimums) *CloneIndex {
+	if minTokens <= 0 {
+		minTokens = DefaultMinCloneTokens
+	}
+	idx := &CloneIndex{minimums: map[models.CloneGranularity]int{
+		models.CloneIntraFile:   minimums.IntraFile,
+		models.CloneIntraModule: minimums.IntraModule,
+		models.CloneCrossModule: minimums.CrossModule,
+	}}
+	for granularity, minimum := range idx.minimums {
+		if minimum <= 0 {
+			idx.minimums[granularity] = minTokens
+		}
+	}
+	idx.minTokens = min(idx.minimums[models.CloneIntraFile], idx.minimums[models.CloneIntraModule], idx.minimums[models.CloneCrossModule])
+	idx.Reset()
+	return idx
+}
+
+// Reset clears all indexed files
+func (idx *CloneIndex) Reset() {
+	idx.mutex.Lock()
+	defer idx.mutex.Unlock()
+
+	for i := range idx.shards {
+		shard := &idx.shards[i]
+		shard.mutex.Lock()
+		shard.windows = make(map[uint64][]clonePosition)
+		shard.mutex.Unlock()
+	}
+	idx.files = nil
+	idx.records = nil
+	idx.resolved = false
+}
+
+// Insert indexes the tokens of a file. It is safe to call from several
+// goroutines; clones are matched when Pairs or Groups is called.
+func (idx *CloneIndex) Insert(path string, tokens []CloneToken) {
+	symbols := cloneSymbols(tokens)
+	file := &cloneFile{path: path, tokens: tokens, symbols: symbols, hashes: rollingHashes(symbols, idx.minTokens)}
+
+	idx.mutex.Lock()
+	fileID := len(idx.files)
+	idx.files = append(idx.files, file)
+	idx.resolved = false
+	idx.mutex.Unlock()
+
+	// Lock each shard once per file rather than once per window
+	var batches [cloneShardCount][]cloneWindow
+	for offset, hash := range file.hashes {
+		shard := hash % cloneShardCount
+		batches[shard] = append(batches[shard], cloneWindow{hash: hash, position: clonePosition{file: fileID, offset: offset}})
+	}
+	for i, batch := range batches {
+		if len(batch) == 0 {
+			continue
+		}
+		shard := &idx.shards[i]
+		shard.mutex.Lock()
+		for _, window := range batch {
+			shard.windows[window.hash] = append(shard.windows[window.hash], window.position)
+		}
+		shard.mutex.Unlock()
+	}
+}
+
+// Pairs returns the clone pairs among all inserted files, ordered by the
+// path of the duplicate and then by its position. Call it once every file is
+// inserted.
+func (idx *CloneIndex) Pairs() []ClonePair {
+	idx.mutex.Lock()
+	defer idx.mutex.Unlock()
+
+	idx.resolve()
+	var pairs []ClonePair
+	for _, file := range idx.files {
+		pairs = append(pairs, file.pairs...)
+	}
+	return pairs
+}
+
+// resolve matches the windows of all inserted files once no more are being
+// inserted. Files are ordered by path first, so of two copies the one with
+// the earlier path is the original whatever order the files were inserted in.
+// The caller must hold idx.mutex.
+func (idx *CloneIndex) resolve() {
+	if idx.resolved {
+		return
+	}
+	idx.resolved = true
+
+	order := make([]int, len(idx.files))
+	for i := range order {
+		order[i] = i
+	}
+	sort.SliceStable(order, func(i, j int) bool {
+		return idx.files[order[i]].path < idx.files[order[j]].path
+	})
+	rank := make([]int, len(order))
+	files := make([]*cloneFile, len(order))
+	for r, id := range order {
+		rank[id] = r
+		files[r] = idx.files[id]
+	}
+	idx.files = files
+
+	// Renumber the windows by rank, then sort each bucket and cap it at its
+	// earliest positions
+	var wg sync.WaitGroup
+	for i := range idx.shards {
+		wg.Add(1)
+		go func(shard *cloneShard) {
+			defer wg.Done()
+			shard.mutex.Lock()
+			defer shard.mutex.Unlock()
+			for hash, bucket := range shard.windows {
+				for n := range bucket {
+					bucket[n].file = rank[bucket[n].file]
+				}
+				sort.Slice(bucket, func(a, b int) bool {
+					return bucket[a].before(bucket[b])
+				})
+				if len(bucket) > maxCloneBucket {
+					shard.windows[hash] = bucket[:maxCloneBucket]
+				}
+			}
+		}(&idx.shards[i])
+	}
+	wg.Wait()
+
+	// Files are matched independently and their records joined in path order
+	records := make([][]cloneRecord, len(idx.files))
+	jobs := make(chan int)
+	for i := 0; i < runtime.GOMAXPROCS(0); i++ {
+		wg.Add(1)
+		go func() {
+			defer wg.Done()
+			for fileID := range jobs {
+				records[fileID] = idx.match(fileID)
+			}
+		}()
+	}
+	for fileID := range idx.files {
+		jobs <- fileID
+	}
+	close(jobs)
+	wg.Wait()
+
+	idx.records = nil
+	for _, fileRecords := range records {
+		idx.records = append(idx.records, fileRecords...)
+	}
+}
+
+// match finds the clones of a file with code earlier in the path order or
+// earlier in the same file. The caller must hold idx.mutex.
+func (idx *CloneIndex) match(fileID int) []cloneRecord {
+	file := idx.files[fileID]
+	file.pairs = nil
+
+	var records []cloneRecord
+	covered := make(map[cloneDiagonal]int) // End offset of the clone last reported on a diagonal
+	for offset, hash := range file.hashes {
+		for _, pos := range idx.shards[hash%cloneShardCount].windows[hash] {
+			if !pos.before(clonePosition{file: fileID, offset: offset}) {
+				break // Buckets are sorted, so no earlier position follows
+			}
+			diagonal := cloneDiagonal{file: pos.file, shift: offset - pos.offset}
+			if covered[diagonal] > offset {
+				continue
+			}
+
+			other := idx.files[pos.file]
+			limit := len(other.symbols) - pos.offset
+			if pos.file == fileID {
+				// Copies within a file must not overlap
+				limit = offset - pos.offset
+			}
+			length := matchLength(other.symbols[pos.offset:], file.symbols[offset:], limit)
+			// Later windows of a match too short for its granularity are
+			// shorter still, so the diagonal is covered either way
+			covered[diagonal] = offset + length
+			granularity := models.CloneGranularityOf(other.path, file.path)
+			if length < idx.minimums[granularity] {
+				continue
+			}
+
+			records = append(records, cloneRecord{
+				original:  cloneSpan{file: pos.file, start: pos.offset, end: pos.offset + length},
+				duplicate: cloneSpan{file: fileID, start: offset, end: offset + length},
+			})
+			file.pairs = append(file.pairs, ClonePair{
+				Original:    other.fragment(pos.offset, length),
+				Duplicate:   file.fragment(offset, length),
+				Tokens:      length,
+				Granularity: granularity,
+			})
+		}
+	}
+	return records
+}
+
+// before orders positions by file and offset
+func (p clonePosition) before(other clonePosition) bool {
+	if p.file != other.file {
+		return p.file < other.file
+	}
+	return p.offset < other.offset
+}
+
+// Groups merges the clone pairs of all inserted files into clone groups.
+// Copies that overlap within a file count as one member, so code repeated in
+// three places forms one group of three rather than three pairs. Groups are
+// ranked by weight, so duplicated complex code comes before duplicated simple
+// code.
+func (idx *CloneIndex) Groups() []*models.CloneGroup {
+	idx.mutex.Lock()
+	defer idx.mutex.Unlock()
+
+	idx.resolve()
+	spans := make([]cloneSpan, 0, 2*len(idx.records))
+	for _, record := range idx.records {
+		spans = append(spans, record.original, record.duplicate)
+	}
+
+	parent := make([]int, len(spans))
+	for i := range parent {
+		parent[i] = i
+	}
+	find := func(i int) int {
+		for parent[i] != i {
+			parent[i] = parent[parent[i]]
+			i = parent[i]
+		}
+		return i
+	}
+	union := func(a, b int) {
+		parent[find(a)] = find(b)
+	}
+
+	// Both sides of a pair belong together, and so do spans covering the same code
+	for i := 0; i < len(spans); i += 2 {
+		union(i, i+1)
+	}
+	order := make([]int, len(spans))
+	for i := range order {
+		order[i] = i
+	}
+	sort.Slice(order, func(i, j int) bool {
+		return spans[order[i]].before(spans[order[j]])
+	})
+	for i, a := range order {
+		for _, b := range order[i+1:] {
+			if spans[b].file != spans[a].file || spans[b].start >= spans[a].end {
+				break
+			}
+			if spans[a].sameCode(spans[b]) {
+				union(a, b)
+			}
+		}
+	}
+
+	components := make(map[int][]int)
+	tokens := make(map[int]int)
+	shared := make(map[int]cloneSpan)
+	for i, record := range idx.records {
+		root := find(2 * i)
+		length := record.original.end - record.original.start
+		if current, ok := tokens[root]; !ok || length < current {
+			tokens[root] = length
+			shared[root] = record.original
+		}
+	}
+	for _, i := range order {
+		root := find(i)
+		components[root] = append(components[root], i)
+	}
+
+	groups := make([]*models.CloneGroup, 0, len(components))
+	for root, members := range components {
+		group := &models.CloneGroup{Tokens: tokens[root]}
+		var current cloneSpan
+		for n, i := range members {
+			span := spans[i]
+			if n > 0 && span.file == current.file && span.start < current.end {
+				if span.end > current.end {
+					current.end = span.end
+				}
+				continue
+			}
+			if n > 0 {
+				group.Members = append(group.Members, idx.location(current))
+			}
+			current = span
+		}
+		group.Members = append(group.Members, idx.location(current))
+		if len(group.Members) < 2 {
+			continue
+		}
+		sort.Slice(group.Members, func(i, j int) bool {
+			a, b := group.Members[i], group.Members[j]
+			if a.File != b.File {
+				return a.File < b.File
+			}
+			return a.StartLine < b.StartLine
+		})
+
+		files := make([]string, 0, len(group.Members))
+		for _, member := range group.Members {
+			if lines := member.EndLine - member.StartLine + 1; lines > group.Lines {
+				group.Lines = lines
+			}
+			files = append(files, member.File)
+		}
+		group.Granularity = models.CloneGranularityOf(files...)
+		group.DuplicatedTokens = group.Tokens * (len(group.Members) - 1)
+		group.Complexity = idx.complexity(shared[root])
+		group.Weight = group.DuplicatedTokens * group.Complexity
+		groups = append(groups, group)
+	}
+
+	models.RankCloneGroups(groups)
+	return groups
+}
+
+// complexity returns the decision points in the code of a span, plus one
+func (idx *CloneIndex) complexity(span cloneSpan) int {
+	complexity := 1
+	for _, tok := range idx.files[span.file].tokens[span.start:span.end] {
+		if cloneDecisionTokens[tok.Value] {
+			complexity++
+		}
+	}
+	return complexity
+}
+
+// location converts a span to source lines
+func (idx *CloneIndex) location(span cloneSpan) models.CloneLocation {
+	fragment := idx.files[span.file].fragment(span.start, span.end-span.start)
+	return models.CloneLocation{
+		File:      fragment.File,
+		StartLine: fragment.StartLine,
+		EndLine:   fragment.EndLine,
+	}
+}
+
+// before orders spans by indexed file and start offset
+func (s cloneSpan) before(other cloneSpan) bool {
+	if s.file != other.file {
+		return s.file < other.file
+	}
+	return s.start < other.start
+}
+
+// sameCode reports whether two spans of one file overlap by at least half of the shorter
+func (s cloneSpan) sameCode(other cloneSpan) bool {
+	overlap := min(s.end, other.end) - max(s.start, other.start)
+	shorter := min(s.end-s.start, other.end-other.start)
+	return overlap > 0 && 2*overlap >= shorter
+}
+
+// cloneSymbols hashes token values to symbols. The normalized vocabulary
+// is small, so unlike interning this needs no table shared between files,
+// and distinct values practically never collide.
+func cloneSymbols(tokens []CloneToken) []uint64 {
+	symbols := make([]uint64, len(tokens))
+	for i, tok := range tokens {
+		hash := fnv.New64a()
+		hash.Write([]byte(tok.Value))
+		symbols[i] = hash.Sum64()
+	}
+	return symbols
+}
+
+// fragment returns the source lines covered by length tokens from start
+func (f *cloneFile) fragment(start, length int) CloneFragment {
+	return CloneFragment{
+		File:      f.path,
+		StartLine: f.tokens[start].Line,
+		EndLine:   f.tokens[start+length-1].Line,
+	}
+}
+
+// rollingHashes returns the hash of every window of size tokens
+func rollingHashes(symbols []uint64, size int) []uint64 {
+	if len(symbols) < size {
+		return nil
+	}
+
+	// power is base^(size-1), the weight of the symbol leaving the window
+	power := uint64(1)
+	for i := 1; i < size; i++ {
+		power *= cloneHashBase
+	}
+
+	hashes := make([]uint64, 0, len(symbols)-size+1)
+	var hash uint64
+	for i, symbol := range symbols {
+		if i >= size {
+			hash -= symbols[i-size] * power
+		}
+		hash = hash*cloneHashBase + symbol
+		if i >= size-1 {
+			hashes = append(hashes, hash)
+		}
+	}
+	return hashes
+}
+
+// matchLength counts the leading symbols a and b share, up to limit
+func matchLength(a, b []uint64, limit int) int {
+	n := 0
+	for n < limit && n < len(a) && n < len(b) && a[n] == b[n] {
+		n++
+	}
+	return n
+}
+
+// cloneDecisionTokens are the normalized Go and Rust tokens that add a path
+// through the code. Match arms are counted by their arrows.
+var cloneDecisionTokens = map[string]bool{
+	"if": true, "for": true, "while": true, "loop": true, "case": true,
+	"=>": true, "&&": true, "||": true, "?": true,
+}
+
+// GoCloneTokens tokenizes Go source for clone detection. The package clause
+// and import declarations are skipped because every file repeats them.
+func GoCloneTokens(src []byte) []CloneToken {
+	fset := token.NewFileSet()
+	file := fset.AddFile("", fset.Base(), len(src))
+
+	var s scanner.Scanner
+	s.Init(file, src, nil, 0)
+
+	var tokens []CloneToken
+	skipping := false
+	depth := 0
+	for {
+		pos, tok, _ := s.Scan()
+		if tok == token.EOF {
+			break
+		}
+
+		if skipping || tok == token.PACKAGE || tok == token.IMPORT {
+			skipping = true
+			switch tok {
+			case token.LPAREN:
+				depth++
+			case token.RPAREN:
+				depth--
+			case token.SEMICOLON:
+				skipping = depth > 0
+			}
+			continue
+		}
+		// Semicolons are mostly inserted automatically at line ends, so they
+		// would make matching depend on formatting
+		if tok == token.SEMICOLON {
+			continue
+		}
+
+		value := tok.String()
+		switch {
+		case tok == token.IDENT:
+			value = cloneIdentPlaceholder
+		case tok.IsLiteral():
+			value = cloneLiteralPlaceholder
+		}
+		tokens = append(tokens, CloneToken{Value: value, Line: file.Line(pos)})
+	}
+	return tokens
+}
+
+// RustCloneTokens normalizes the tokens of a parsed Rust file for clone
+// detection. `use` declarations are skipped because files repeat them.
+func RustCloneTokens(tokens []types.RustTokenInfo) []CloneToken {
+	normalized := make([]CloneToken, 0, len(tokens))
+	skipping := false
+	for _, tok := range tokens {
+		if skipping || tok.Kind == types.RustTokenKeyword && tok.Text == "use" {
+			skipping = tok.Text != ";"
+			continue
+		}
+
+		value := tok.Text
+		switch tok.Kind {
+		case types.RustTokenIdent, types.RustTokenLifetime:
+			value = cloneIdentPlaceholder
+		case types.RustTokenLiteral:
+			value = cloneLiteralPlaceholder
+		}
+		normalized = append(normalized, CloneToken{Value: value, Line: tok.Line})
+	}
+	return normalized
+}
+
+// CloneDetector detects duplicated code by matching normalized token
+// sequences across all files of a scan
+type CloneDetector struct {
+	config        *DetectorConfig
+	codeExtractor *CodeExtractor
+	index         *CloneIndex
+}
+
+// NewCloneDetector creates a new token clone detector
+func NewCloneDetector(config *DetectorConfig) *CloneDetector {
+	if config == nil {
+		config = DefaultDetectorConfig()
+	}
+	return &CloneDetector{
+		config:        config,
+		codeExtractor: NewCodeExtractor(),
+		index:         NewCloneIndexWithMinimums(config.MinCloneTokens, config.CloneMinimums),
+	}
+}
+
+// Name returns the name of this detector
+func (d *CloneDetector) Name() string {
+	return "Token Clone Detector"
+}
+
+// Description returns a description of what this detector checks for
+func (d *CloneDetector) Description() string {
+	return "Detects duplicated code by matching token sequences, ignoring formatting, comments, identifier names and literal values"
+}
+
+// Detect indexes the file. Clones are reported by Violations once every
+// file of the scan is indexed, so it returns none.
+func (d *CloneDetector) Detect(fileInfo *models.FileInfo, astInfo interface{}) []*models.Violation {
+	switch info := astInfo.(type) {
+	case *types.GoASTInfo:
+		if info == nil {
+			return nil
+		}
+		src, err := os.ReadFile(fileInfo.Path)
+		if err != nil {
+			return nil
+		}
+		d.index.Insert(fileInfo.Path, GoCloneTokens(src))
+	case *types.RustASTInfo:
+		if info == nil {
+			return nil
+		}
+		d.index.Insert(fileInfo.Path, RustCloneTokens(info.Tokens))
+	}
+	return nil
+}
+
+// Violations reports the clones among all files detected since the last
+// Reset, each on its later copy. They are ordered by file path and line.
+func (d *CloneDetector) Violations() []*models.Violation {
+	var violations []*models.Violation
+	for _, pair := range d.index.Pairs() {
+		dup := pair.Duplicate
+		rule := "code-clone"
+		if filepath.Ext(dup.File) == ".rs" {
+			rule = "rust-code-clone"
+		}
+		violations = append(violations, &models.Violation{
+			Type:     models.ViolationTypeDuplication,
+			Severity: d.getSeverity(dup.EndLine - dup.StartLine + 1),
+			// Positions stay out of the message, which the fingerprint hashes
+			Message: fmt.Sprintf("Code clone of %d tokens duplicates %s (%s)", pair.Tokens, pair.Original.File, pair.Granularity),
+			Description: fmt.Sprintf("Lines %d-%d duplicate %s:%d-%d",
+				dup.StartLine, dup.EndLine, pair.Original.File, pair.Original.StartLine, pair.Original.EndLine),
+			File:        dup.File,
+			Line:        dup.StartLine,
+			Column:      1,
+			EndLine:     dup.EndLine,
+			Rule:        rule,
+			Suggestion:  "Extract the duplicated logic into a shared function, method or trait implementation",
+			CodeSnippet: d.extractCodeSnippet(dup.File, dup.StartLine, dup.EndLine),
+		})
+	}
+	return violations
+}
+
+// Reset clears the clone index; call it at the start of each scan
+func (d *CloneDetector) Reset() {
+	d.index.Reset()
+}
+
+// Groups returns the clone groups of all files detected since the last Reset
+func (d *CloneDetector) Groups() []*models.CloneGroup {
+	return d.index.Groups()
+}
+
+func (d *CloneDetector) getSeverity(lineCount int) models.Severity {
+	if lineCount > 20 {
+		return models.SeverityHigh
+	}
+	if lineCount > 10 {
+		return models.SeverityMedium
+	}
+	return models.SeverityLow
+}
+
+// extractCodeSnippet extracts a code snippet for the violation with context
+func (d *CloneDetector) extractCodeSnippet(filePath string, startLine, endLine int) string {
+	if d.codeExtractor != nil {
+		if snippet, err := d.codeExtractor.ExtractSnippet(filePath, startLine, endLine); err == nil {
+			return snippet
+		}
+	}
+	return fmt.Sprintf("Lines %d-%d: <code snippet unavailable>", startLine, endLine)
+}
@@ -0,0 +1,316 @@
+package violations
+
+import (
+	"os"
+	"path/filepath"
+	"strings"
+	"sync"
+	"testing"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+const cloneOriginalSource = `package users
+
+import (
+	"errors"
+	"strings"
+)
+
+func validateUser(name string, email string) error {
+	if len(name) < 2 || len(name) > 50 {
+		return errors.New("invalid name length")
+	}
+	if !strings.Contains(email, "@") {
+		return errors.New("invalid email")
+	}
+	for _, r := range name {
+		if r < 'A' {
+			return errors.New("invalid character")
+		}
+	}
+	return nil
+}
+`
+
+// Same logic with renamed variables, other messages and different formatting
+const cloneRenamedSource = `package staff
+
+import "errors"
+import "strings"
+
+// checkEmployee mirrors validateUser
+func checkEmployee(fullName string, mail string) error {
+	if len(fullName) < 3 ||
+		len(fullName) > 80 {
+		return errors.New("bad name")
+	}
+	if !strings.Contains(mail, "@") { return errors.New("bad mail") }
+	for _, ch := range fullName {
+		if ch < 'a' {
+			return errors.New("bad character")
+		}
+	}
+	return nil
+}
+`
+
+func TestGoCloneTokens(t *testing.T) {
+	original := GoCloneTokens([]byte(cloneOriginalSource))
+	renamed := GoCloneTokens([]byte(cloneRenamedSource))
+
+	if len(original) == 0 || original[0].Value != "func" {
+		t.Fatalf("Expected package clause and imports to be skipped, got %v", original[:1])
+	}
+	if original[0].Line != 8 {
+		t.Errorf("Expected first token on line 8, got %d", original[0].Line)
+	}
+	if len(original) != len(renamed) {
+		t.Fatalf("Expected equal token counts, got %d and %d", len(original), len(renamed))
+	}
+	for i := range original {
+		if original[i].Value != renamed[i].Value {
+			t.Fatalf("Token %d differs: %q vs %q", i, original[i].Value, renamed[i].Value)
+		}
+	}
+}
+
+func TestRustCloneTokens(t *testing.T) {
+	tokens := []types.RustTokenInfo{
+		{Kind: types.RustTokenKeyword, Text: "use", Line: 1},
+		{Kind: types.RustTokenIdent, Text: "std", Line: 1},
+		{Kind: types.RustTokenPunct, Text: ";", Line: 1},
+		{Kind: types.RustTokenKeyword, Text: "fn", Line: 2},
+		{Kind: types.RustTokenIdent, Text: "parse", Line: 2},
+		{Kind: types.RustTokenLifetime, Text: "'a", Line: 2},
+		{Kind: types.RustTokenLiteral, Text: "42", Line: 3},
+	}
+
+	got := RustCloneTokens(tokens)
+	want := []string{"fn", cloneIdentPlaceholder, cloneIdentPlaceholder, cloneLiteralPlaceholder}
+	if len(got) != len(want) {
+		t.Fatalf("Expected %d tokens, got %d: %v", len(want), len(got), got)
+	}
+	for i, value := range want {
+		if got[i].Value != value {
+			t.Errorf("Token %d: expected %q, got %q", i, value, got[i].Value)
+		}
+	}
+}
+
+func TestCloneIndexFindsRenamedClone(t *testing.T) {
+	index := NewCloneIndex(30)
+
+	// The file inserted first is matched as the duplicate: the earlier path is the original
+	index.Insert("b/staff.go", GoCloneTokens([]byte(cloneRenamedSource)))
+	index.Insert("a/users.go", GoCloneTokens([]byte(cloneOriginalSource)))
+
+	pairs := index.Pairs()
+	if len(pairs) != 1 {
+		t.Fatalf("Expected 1 clone pair, got %d: %v", len(pairs), pairs)
+	}
+
+	pair := pairs[0]
+	if pair.Original.File != "a/users.go" || pair.Original.StartLine != 8 || pair.Original.EndLine != 21 {
+		t.Errorf("Unexpected original location: %+v", pair.Original)
+	}
+	if pair.Duplicate.File != "b/staff.go" || pair.Duplicate.StartLine != 7 || pair.Duplicate.EndLine != 19 {
+		t.Errorf("Unexpected duplicate location: %+v", pair.Duplicate)
+	}
+	if pair.Tokens != len(GoCloneTokens([]byte(cloneOriginalSource))) {
+		t.Errorf("Expected the clone to cover the whole function, got %d tokens", pair.Tokens)
+	}
+}
+
+func TestCloneIndexIgnoresShortSequences(t *testing.T) {
+	index := NewCloneIndex(DefaultMinCloneTokens)
+	source := []byte("package a\n\nfunc f(x int) int {\n\treturn x + 1\n}\n")
+
+	index.Insert("a.go", GoCloneTokens(source))
+	index.Insert("b.go", GoCloneTokens(source))
+	if pairs := index.Pairs(); len(pairs) != 0 {
+		t.Errorf("Expected sequences below the minimum to be ignored, got %v", pairs)
+	}
+}
+
+func TestCloneIndexSameFile(t *testing.T) {
+	index := NewCloneIndex(30)
+	body := strings.TrimPrefix(cloneOriginalSource, "package users\n\nimport (\n\t\"errors\"\n\t\"strings\"\n)\n")
+	source := cloneOriginalSource + strings.Replace(body, "validateUser", "validateAdmin", 1)
+
+	index.Insert("users.go", GoCloneTokens([]byte(source)))
+	pairs := index.Pairs()
+	if len(pairs) != 1 {
+		t.Fatalf("Expected 1 clone pair, got %d: %v", len(pairs), pairs)
+	}
+	if pairs[0].Original.EndLine >= pairs[0].Duplicate.StartLine {
+		t.Errorf("Expected non-overlapping copies, got %+v and %+v", pairs[0].Original, pairs[0].Duplicate)
+	}
+
+	index.Reset()
+	index.Insert("users.go", GoCloneTokens([]byte(cloneOriginalSource)))
+	if pairs := index.Pairs(); len(pairs) != 0 {
+		t.Errorf("Expected an empty index after Reset, got %v", pairs)
+	}
+}
+
+func TestCloneIndexConcurrentInsertIsDeterministic(t *testing.T) {
+	sources := map[string]string{
+		"a/users.go":  cloneOriginalSource,
+		"b/staff.go":  cloneRenamedSource,
+		"c/admins.go": strings.Replace(cloneOriginalSource, "package users", "package admins", 1),
+	}
+
+	sequential := NewCloneIndex(30)
+	for _, path := range []string{"a/users.go", "b/staff.go", "c/admins.go"} {
+		sequential.Insert(path, GoCloneTokens([]byte(sources[path])))
+	}
+	want := sequential.Pairs()
+
+	for run := 0; run < 5; run++ {
+		index := NewCloneIndex(30)
+		var wg sync.WaitGroup
+		for path, source := range sources {
+			wg.Add(1)
+			go func(path, source string) {
+				defer wg.Done()
+				index.Insert(path, GoCloneTokens([]byte(source)))
+			}(path, source)
+		}
+		wg.Wait()
+
+		got := index.Pairs()
+		if len(got) != len(want) {
+			t.Fatalf("Run %d: expected %d pairs, got %d", run, len(want), len(got))
+		}
+		for i := range want {
+			if got[i] != want[i] {
+				t.Errorf("Run %d, pair %d: expected %+v, got %+v", run, i, want[i], got[i])
+			}
+		}
+	}
+}
+
+func TestCloneIndexGroups(t *testing.T) {
+	index := NewCloneIndex(30)
+	index.Insert("c/admins.go", GoCloneTokens([]byte(strings.Replace(cloneOriginalSource, "package users", "package admins", 1))))
+	index.Insert("b/staff.go", GoCloneTokens([]byte(cloneRenamedSource)))
+	index.Insert("a/users.go", GoCloneTokens([]byte(cloneOriginalSource)))
+
+	groups := index.Groups()
+	if len(groups) != 1 {
+		t.Fatalf("Expected 1 clone group, got %d", len(groups))
+	}
+
+	group := groups[0]
+	tokens := len(GoCloneTokens([]byte(cloneOriginalSource)))
+	if group.ID != 1 || group.Tokens != tokens || group.Lines != 14 || group.DuplicatedTokens != 2*tokens {
+		t.Errorf("Unexpected group metrics: %+v", group)
+	}
+	// Three ifs, one || and one for
+	if group.Complexity != 6 || group.Weight != 6*group.DuplicatedTokens {
+		t.Errorf("Expected complexity 6 and weight %d, got %d and %d", 6*group.DuplicatedTokens, group.Complexity, group.Weight)
+	}
+
+	expected := []models.CloneLocation{
+		{File: "a/users.go", StartLine: 8, EndLine: 21},
+		{File: "b/staff.go", StartLine: 7, EndLine: 19},
+		{File: "c/admins.go", StartLine: 8, EndLine: 21},
+	}
+	if len(group.Members) != len(expected) {
+		t.Fatalf("Expected %d members, got %+v", len(expected), group.Members)
+	}
+	for i, member := range group.Members {
+		if member != expected[i] {
+			t.Errorf("Member %d: expected %+v, got %+v", i, expected[i], member)
+		}
+	}
+}
+
+func TestCloneIndexGranularityMinimums(t *testing.T) {
+	body := strings.TrimPrefix(cloneOriginalSource, "package users\n\nimport (\n\t\"errors\"\n\t\"strings\"\n)\n")
+	repeated := cloneOriginalSource + strings.Replace(body, "validateUser", "validateAdmin", 1)
+
+	// Copies within the module a/ are tolerated, copies from another module are not
+	index := NewCloneIndexWithMinimums(30, CloneMinimums{IntraFile: 1000, IntraModule: 1000})
+	index.Insert("a/users.go", GoCloneTokens([]byte(repeated)))
+	index.Insert("a/staff.go", GoCloneTokens([]byte(cloneRenamedSource)))
+	index.Insert("b/users.go", GoCloneTokens([]byte(cloneOriginalSource)))
+
+	pairs := index.Pairs()
+	if len(pairs) == 0 {
+		t.Fatal("Expected the cross-module copies to be reported")
+	}
+	for _, pair := range pairs {
+		if pair.Granularity != models.CloneCrossModule || filepath.Dir(pair.Original.File) == filepath.Dir(pair.Duplicate.File) {
+			t.Errorf("Expected only cross-module pairs, got %+v", pair)
+		}
+	}
+
+	groups := index.Groups()
+	if len(groups) != 1 || groups[0].Granularity != models.CloneCrossModule {
+		t.Errorf("Expected one cross-module group, got %+v", groups)
+	}
+
+	// Without the per-granularity minimums the copies within a/ are reported too
+	index = NewCloneIndex(30)
+	index.Insert("a/users.go", GoCloneTokens([]byte(repeated)))
+	index.Insert("a/staff.go", GoCloneTokens([]byte(cloneRenamedSource)))
+	found := make(map[models.CloneGranularity]bool)
+	for _, pair := range index.Pairs() {
+		found[pair.Granularity] = true
+	}
+	if !found[models.CloneIntraFile] || !found[models.CloneIntraModule] || found[models.CloneCrossModule] {
+		t.Errorf("Expected intra-file and intra-module pairs, got %v", found)
+	}
+}
+
+func TestCloneDetectorReportsBothLocations(t *testing.T) {
+	dir := t.TempDir()
+	first := filepath.Join(dir, "users.go")
+	second := filepath.Join(dir, "users_copy.go")
+	if err := os.WriteFile(first, []byte(cloneOriginalSource), 0644); err != nil {
+		t.Fatalf("Failed to write test file: %v", err)
+	}
+	if err := os.WriteFile(second, []byte(cloneRenamedSource), 0644); err != nil {
+		t.Fatalf("Failed to write test file: %v", err)
+	}
+
+	config := DefaultDetectorConfig()
+	config.MinCloneTokens = 30
+	detector := NewCloneDetector(config)
+
+	detector.Detect(&models.FileInfo{Path: second}, &types.GoASTInfo{})
+	detector.Detect(&models.FileInfo{Path: first}, &types.GoASTInfo{})
+	violations := detector.Violations()
+	if len(violations) != 1 {
+		t.Fatalf("Expected 1 violation, got %d", len(violations))
+	}
+
+	v := violations[0]
+	if v.Rule != "code-clone" || v.Type != models.ViolationTypeDuplication {
+		t.Errorf("Unexpected rule %q or type %q", v.Rule, v.Type)
+	}
+	if v.File != second || v.Line != 7 || v.EndLine != 19 {
+		t.Errorf("Unexpected violation location %s:%d-%d", v.File, v.Line, v.EndLine)
+	}
+	if !strings.Contains(v.Message, first) || strings.Contains(v.Message, ":8-21") {
+		t.Errorf("Expected a message naming the original without its lines, got %q", v.Message)
+	}
+	if !strings.Contains(v.Description, first+":8-21") {
+		t.Errorf("Expected the description to locate the original, got %q", v.Description)
+	}
+
+	// Moving the copy down keeps its fingerprint, so baselines still match it
+	if err := os.WriteFile(second, []byte("\n\n\n"+cloneRenamedSource), 0644); err != nil {
+		t.Fatalf("Failed to write test file: %v", err)
+	}
+	detector.Reset()
+	detector.Detect(&models.FileInfo{Path: second}, &types.GoASTInfo{})
+	detector.Detect(&models.FileInfo{Path: first}, &types.GoASTInfo{})
+	moved := detector.Violations()
+	if len(moved) != 1 || moved[0].Line != 10 || models.ComputeFingerprint(moved[0]) != models.ComputeFingerprint(v) {
+		t.Errorf("Expected the moved clone to keep its fingerprint, got %+v", moved)
+	}
+}
@@ -2,29 +2,46 @@
 package violations
 
 import (
+	"fmt"
 	"go/ast"
+	"go/parser"
 	"go/token"
+	"math"
+	"os"
 	"regexp"
 	"strings"
+	"unicode"
 
 	"github.com/ericfisherdev/goclean/internal/models"
 	"github.com/ericfisherdev/goclean/internal/types"
 )
 
-// CommentedCodeDetector detects blocks of commented-out code
+// CommentedCodeDetector detects blocks of commented-out code by parsing
+// them as Go
 type CommentedCodeDetector struct {
-	config      *DetectorConfig
-	codePattern *regexp.Regexp
+	config *DetectorConfig
+}
+
+// minCommentedCodeConfidence is the share of a comment's lines that must be
+// exceeded for the comment to be reported when no minimum is configured
+const minCommentedCodeConfidence = 0.5
+
+// goSnippetTemplates place a comment, or one line of it, where Go allows
+// declarations, statements and the headers of blocks whose braces are on
+// other lines. The first two hold declarations.
+var goSnippetTemplates = []string{
+	"package p\n%s\n",
+	"package p\n%s {}\n",
+	"package p\nfunc _() {\n%s\n}\n",
+	"package p\nfunc _() {\n%s {}\n}\n",
+	"package p\nfunc _() {\nif true {} %s {}\n}\n",
+	"package p\nfunc _() {\nswitch {\n%s\n}\n}\n",
 }
 
 // NewCommentedCodeDetector creates a new commented code detector
 func NewCommentedCodeDetector(config *DetectorConfig) *CommentedCodeDetector {
-	// Pattern to detect likely code in comments
-	codePattern := regexp.MustCompile(`(?i)(func |if |for |switch |case |return |var |const |type |import |package |fmt\.|log\.|:=|\{|\}|\(|\))`)
-	
 	return &CommentedCodeDetector{
-		config:      config,
-		codePattern: codePattern,
+		config: config,
 	}
 }
 
@@ -53,8 +70,18 @@ func (d *CommentedCodeDetector) Detect(fileInfo *models.FileInfo, astInfo interf
 	}
 	
 	// Extract all comments from the AST
+	var lines []string
 	for _, commentGroup := range goAstInfo.AST.Comments {
 		if violation := d.checkCommentGroup(commentGroup, goAstInfo.FileSet, fileInfo.Path); violation != nil {
+			// Comments on lines of their own can be deleted by goclean fix
+			if lines == nil {
+				if content, err := os.ReadFile(fileInfo.Path); err == nil {
+					lines = strings.Split(string(content), "\n")
+				}
+			}
+			start := goAstInfo.FileSet.Position(commentGroup.Pos())
+			end := goAstInfo.FileSet.Position(commentGroup.End())
+			violation.Fix = wholeLineRemovalFix(lines, start.Line, start.Column, end.Line, end.Column, "Delete the commented-out code")
 			violations = append(violations, violation)
 		}
 	}
@@ -102,72 +129,153 @@ func (d *CommentedCodeDetector) checkCommentGroup(group *ast.CommentGroup, fset
 	}
 	
 	fullText := commentText.String()
-	
-	// Check if this looks like code
-	if d.looksLikeCode(fullText) {
-		// Count the number of code-like patterns
-		matches := d.codePattern.FindAllString(fullText, -1)
-		if len(matches) >= 3 { // At least 3 code patterns to be considered commented code
-			snippet := fullText
-			if len(snippet) > 100 {
-				snippet = snippet[:97] + "..."
-			}
-			
-			return &models.Violation{
-				Type:        models.ViolationTypeCommentedCode,
-				Severity:    models.SeverityLow,
-				File:        filePath,
-				Line:        startLine,
-				Column:      0,
-				Message:     "Block of commented-out code detected",
-				Suggestion:  "Remove commented-out code. Use version control to preserve old code if needed",
-				CodeSnippet: snippet,
-			}
+	if len(fullText) < 20 {
+		return nil
+	}
+
+	confidence := codeConfidence(fullText, parsesAsGo)
+	if !reportsCommentedCode(d.config, confidence) {
+		return nil
+	}
+
+	snippet := fullText
+	if len(snippet) > 100 {
+		snippet = snippet[:97] + "..."
+	}
+	return &models.Violation{
+		Type:        models.ViolationTypeCommentedCode,
+		Severity:    models.SeverityLow,
+		File:        filePath,
+		Line:        startLine,
+		Column:      0,
+		Message:     "Block of commented-out code detected",
+		Description: fmt.Sprintf("%.0f%% of the comment parses as Go", confidence*100),
+		Rule:        "commented-code",
+		Suggestion:  "Remove commented-out code. Use version control to preserve old code if needed",
+		CodeSnippet: snippet,
+		Confidence:  math.Round(confidence*100) / 100,
+		Reasons:     commentedCodeReasons(d.config, fullText, parsesAsGo, "Go"),
+	}
+}
+
+// reportsCommentedCode reports whether a comment of the given confidence is
+// commented-out code: at least the configured minimum, or more than half
+func reportsCommentedCode(config *DetectorConfig, confidence float64) bool {
+	if config != nil && config.MinCommentedCodeConfidence > 0 {
+		return confidence >= config.MinCommentedCodeConfidence
+	}
+	return confidence > minCommentedCodeConfidence
+}
+
+// commentedCodeReasons lists, with --show-reason, whether the comment parsed
+// as a whole or which of its lines parsed and which did not
+func commentedCodeReasons(config *DetectorConfig, text string, parses func(string) bool, language string) []string {
+	if config == nil || !config.ShowReasons {
+		return nil
+	}
+	if parses(text) {
+		return []string{"The whole comment parses as " + language}
+	}
+
+	reasons := []string{"The comment does not parse as " + language + " as a whole"}
+	for _, line := range codeLines(text) {
+		if parses(line) {
+			reasons = append(reasons, fmt.Sprintf("Parses as %s: %s", language, line))
+		} else {
+			reasons = append(reasons, fmt.Sprintf("Does not parse: %s", line))
 		}
 	}
-	
-	return nil
+	return reasons
 }
 
-// looksLikeCode checks if text looks like commented-out code
-func (d *CommentedCodeDetector) looksLikeCode(text string) bool {
-	// Check for common code patterns
-	if d.codePattern.MatchString(text) {
-		// Additional checks to reduce false positives
-		
-		// If it's very short, it's probably not code
-		if len(text) < 20 {
-			return false
+// codeConfidence returns the share of the lines of a comment that parse as
+// code. A comment that parses as a whole is certainly code; otherwise each
+// line is tried on its own, without the braces that open or close blocks on
+// other lines, so code with a syntax error still scores high while prose
+// scores zero. Lines of only brackets and separators, or of a single word,
+// tell neither and are not counted.
+func codeConfidence(text string, parses func(string) bool) float64 {
+	if parses(text) {
+		return 1
+	}
+
+	lines := codeLines(text)
+	if len(lines) == 0 {
+		return 0
+	}
+	parsed := 0
+	for _, line := range lines {
+		if parses(line) {
+			parsed++
 		}
-		
-		// Check for balanced braces/parens (common in code)
-		openBraces := strings.Count(text, "{")
-		closeBraces := strings.Count(text, "}")
-		openParens := strings.Count(text, "(")
-		closeParens := strings.Count(text, ")")
-		
-		// If we have balanced braces or parens, it's likely code
-		if (openBraces > 0 && openBraces == closeBraces) ||
-		   (openParens > 0 && openParens == closeParens) {
-			return true
+	}
+	return float64(parsed) / float64(len(lines))
+}
+
+// codeLines returns the lines of a comment that are counted, trimmed of the
+// braces that open or close blocks on other lines
+func codeLines(text string) []string {
+	var lines []string
+	for _, line := range strings.Split(text, "\n") {
+		line = strings.TrimSpace(strings.TrimRight(strings.TrimLeft(line, "}) \t"), "{( \t"))
+		if strings.Trim(line, "{}()[];,") != "" && strings.TrimFunc(line, isWordRune) != "" {
+			lines = append(lines, line)
 		}
-		
-		// Check for assignment operators
-		if strings.Contains(text, ":=") || strings.Contains(text, "=") {
-			return true
+	}
+	return lines
+}
+
+// isWordRune reports whether r can be part of a name or number
+func isWordRune(r rune) bool {
+	return r == '_' || unicode.IsLetter(r) || unicode.IsDigit(r)
+}
+
+// parsesAsGo reports whether src parses as Go declarations, statements or
+// a block header, and is more than bare names and values, which prose such
+// as "e.g. this" or "Note: that" also parses as
+func parsesAsGo(src string) bool {
+	for i, template := range goSnippetTemplates {
+		file, err := parser.ParseFile(token.NewFileSet(), "", fmt.Sprintf(template, src), parser.SkipObjectResolution)
+		if err != nil {
+			continue
 		}
-		
-		// Check for function/method calls
-		if regexp.MustCompile(`\w+\(`).MatchString(text) {
-			return true
+		if i < 2 {
+			if len(file.Decls) > 0 {
+				return true
+			}
+			continue
+		}
+		if fn, ok := file.Decls[0].(*ast.FuncDecl); ok && fn.Body != nil {
+			for _, stmt := range fn.Body.List {
+				if isGoCodeStatement(stmt) {
+					return true
+				}
+			}
 		}
-		
-		return true
 	}
-	
 	return false
 }
 
+// isGoCodeStatement reports whether a statement does something: a bare name,
+// value or composite literal does not
+func isGoCodeStatement(stmt ast.Stmt) bool {
+	switch stmt := stmt.(type) {
+	case *ast.ExprStmt:
+		switch expr := stmt.X.(type) {
+		case *ast.CallExpr:
+			return true
+		case *ast.UnaryExpr:
+			return expr.Op == token.ARROW
+		}
+		return false
+	case *ast.LabeledStmt:
+		return isGoCodeStatement(stmt.Stmt)
+	case *ast.EmptyStmt:
+		return false
+	}
+	return true
+}
+
 // isDocumentation checks if text is likely documentation rather than commented code
 func (d *CommentedCodeDetector) isDocumentation(text string) bool {
 	// Common documentation patterns
@@ -0,0 +1,93 @@
+package violations
+
+import (
+	"go/parser"
+	"go/token"
+	"strings"
+	"testing"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+func TestCommentedCodeDetector_Detect(t *testing.T) {
+	src := `package main
+
+// Old implementation:
+// x := compute()
+// if x > 10 {
+// 	fmt.Println(x)
+// }
+
+// This is a normal comment explaining the next function (OK)
+func main() {
+	/*
+		for i := 0; i < 10; i++ {
+			total += i
+		fmt.Println(total)
+	*/
+}
+`
+	fset := token.NewFileSet()
+	file, err := parser.ParseFile(fset, "main.go", src, parser.ParseComments)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	detector := NewCommentedCodeDetector(DefaultDetectorConfig())
+	violations := detector.Detect(&models.FileInfo{Path: "main.go"}, &types.GoASTInfo{AST: file, FileSet: fset})
+	if len(violations) != 2 {
+		t.Fatalf("Expected the two blocks of code to be reported, got %d: %+v", len(violations), violations)
+	}
+	if v := violations[0]; v.Line != 3 || v.Confidence != 0.75 || v.Description != "75% of the comment parses as Go" {
+		t.Errorf("Expected the block from line 3 with confidence 0.75, got line %d, confidence %v, %q", v.Line, v.Confidence, v.Description)
+	}
+	if v := violations[1]; v.Line != 11 || v.Confidence != 1 {
+		t.Errorf("Expected the block with a missing brace at line 11 with confidence 1, got line %d, confidence %v", v.Line, v.Confidence)
+	}
+	if violations[0].Reasons != nil {
+		t.Errorf("Expected no reasons without show_reasons, got %v", violations[0].Reasons)
+	}
+
+	config := DefaultDetectorConfig()
+	config.MinCommentedCodeConfidence = 0.8
+	config.ShowReasons = true
+	violations = NewCommentedCodeDetector(config).Detect(&models.FileInfo{Path: "main.go"}, &types.GoASTInfo{AST: file, FileSet: fset})
+	if len(violations) != 1 || violations[0].Line != 11 {
+		t.Fatalf("Expected only the block at line 11 to reach a confidence of 0.8, got %+v", violations)
+	}
+	expected := []string{
+		"The comment does not parse as Go as a whole",
+		"Parses as Go: for i := 0; i < 10; i++",
+		"Parses as Go: total += i",
+		"Parses as Go: fmt.Println(total)",
+	}
+	if reasons := violations[0].Reasons; strings.Join(reasons, "\n") != strings.Join(expected, "\n") {
+		t.Errorf("Expected reasons %q, got %q", expected, reasons)
+	}
+}
+
+func TestParsesAsGo(t *testing.T) {
+	tests := []struct {
+		src      string
+		expected bool
+	}{
+		{"x := compute()", true},
+		{"return nil", true},
+		{"type Point struct", true},
+		{"else", true},
+		{"case http.MethodGet:", true},
+		{"fmt.Println(x)", true},
+		{"Deprecated", false},
+		{"Note: that", false},
+		{"e.g. the default", false},
+		{"go ahead", false},
+		{"We use a two-pointer approach", false},
+	}
+
+	for _, tt := range tests {
+		if result := parsesAsGo(tt.src); result != tt.expected {
+			t.Errorf("parsesAsGo(%q) = %v, want %v", tt.src, result, tt.expected)
+		}
+	}
+}
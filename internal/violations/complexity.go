@@ -0,0 +1,319 @@
+package violations
+
+import (
+	"fmt"
+	"go/ast"
+	"go/token"
+	"strings"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+// CognitiveComplexityDetector detects functions that are hard to understand.
+// Unlike cyclomatic complexity, cognitive complexity (as defined by SonarSource)
+// charges extra for nesting and ignores flat, switch-style branching, so
+// readable dispatch functions are not penalized while deeply nested ones are.
+type CognitiveComplexityDetector struct {
+	config        *DetectorConfig
+	codeExtractor *CodeExtractor
+}
+
+// NewCognitiveComplexityDetector creates a new cognitive complexity detector
+func NewCognitiveComplexityDetector(config *DetectorConfig) *CognitiveComplexityDetector {
+	if config == nil {
+		config = DefaultDetectorConfig()
+	}
+	return &CognitiveComplexityDetector{
+		config:        config,
+		codeExtractor: NewCodeExtractor(),
+	}
+}
+
+// Name returns the name of this detector
+func (d *CognitiveComplexityDetector) Name() string {
+	return "Cognitive Complexity Analysis"
+}
+
+// Description returns a description of what this detector checks for
+func (d *CognitiveComplexityDetector) Description() string {
+	return "Detects functions whose nested control flow makes them hard to understand"
+}
+
+// Detect analyzes Go and Rust functions and returns cognitive complexity violations
+func (d *CognitiveComplexityDetector) Detect(fileInfo *models.FileInfo, astInfo interface{}) []*models.Violation {
+	var violations []*models.Violation
+
+	if d.config.MaxCognitiveComplexity <= 0 {
+		return violations
+	}
+
+	switch info := astInfo.(type) {
+	case *types.GoASTInfo:
+		if info == nil {
+			return violations
+		}
+		for _, fn := range info.Functions {
+			if fn == nil || fn.ASTNode == nil {
+				continue
+			}
+			score := GoCognitiveComplexity(fn.ASTNode)
+			if violation := d.check(fileInfo.Path, fn.Name, score, fn.StartLine, fn.StartColumn, fn.EndLine, false); violation != nil {
+				violations = append(violations, violation)
+			}
+		}
+	case *types.RustASTInfo:
+		if info == nil {
+			return violations
+		}
+		for _, fn := range info.Functions {
+			if fn == nil || fn.Node == nil {
+				continue
+			}
+			score := RustCognitiveComplexity(fn.Node)
+			if violation := d.check(fileInfo.Path, fn.Name, score, fn.StartLine, fn.StartColumn, fn.EndLine, true); violation != nil {
+				violations = append(violations, violation)
+			}
+		}
+	}
+
+	return violations
+}
+
+// check creates a violation when the score exceeds the configured threshold
+func (d *CognitiveComplexityDetector) check(filePath, name string, score, startLine, startColumn, endLine int, rust bool) *models.Violation {
+	if score <= d.config.MaxCognitiveComplexity {
+		return nil
+	}
+
+	message := fmt.Sprintf("Function '%s' has high cognitive complexity (%d, max: %d)", name, score, d.config.MaxCognitiveComplexity)
+	rule := "cognitive-complexity"
+	if rust {
+		message = "Rust f" + message[1:]
+		rule = "rust-cognitive-complexity"
+	}
+
+	return &models.Violation{
+		Type:        models.ViolationTypeCognitiveComplexity,
+		Severity:    d.getSeverity(score),
+		Message:     message,
+		File:        filePath,
+		Line:        startLine,
+		Column:      startColumn,
+		EndLine:     endLine,
+		Rule:        rule,
+		Suggestion:  d.getSuggestion(name, score),
+		CodeSnippet: d.extractCodeSnippet(filePath, startLine, endLine),
+	}
+}
+
+func (d *CognitiveComplexityDetector) getSeverity(score int) models.Severity {
+	if score > d.config.MaxCognitiveComplexity*2 {
+		return models.SeverityHigh
+	}
+	if score > int(float64(d.config.MaxCognitiveComplexity)*1.5) {
+		return models.SeverityMedium
+	}
+	return models.SeverityLow
+}
+
+func (d *CognitiveComplexityDetector) getSuggestion(funcName string, score int) string {
+	return fmt.Sprintf("Function '%s' has cognitive complexity of %d. "+
+		"Reduce nesting with early returns and guard clauses, "+
+		"and extract deeply nested blocks into well-named helper functions.", funcName, score)
+}
+
+// extractCodeSnippet extracts a code snippet for the violation with context
+func (d *CognitiveComplexityDetector) extractCodeSnippet(filePath string, startLine, endLine int) string {
+	if d.codeExtractor != nil {
+		if snippet, err := d.codeExtractor.ExtractSnippet(filePath, startLine, endLine); err == nil {
+			return snippet
+		}
+	}
+	return fmt.Sprintf("Lines %d-%d: <code snippet unavailable>", startLine, endLine)
+}
+
+// GoCognitiveComplexity computes the cognitive complexity of a Go function:
+// +1 for each break in linear flow (if, else if, else, loops, switch/select,
+// labeled jumps, logical operator sequences), plus the current nesting level
+// for structures that nest.
+func GoCognitiveComplexity(fn *ast.FuncDecl) int {
+	if fn == nil || fn.Body == nil {
+		return 0
+	}
+	counter := &goCognitiveCounter{}
+	counter.walk(fn.Body, 0)
+	return counter.total
+}
+
+type goCognitiveCounter struct {
+	total int
+}
+
+func (c *goCognitiveCounter) walk(n ast.Node, nesting int) {
+	if n == nil {
+		return
+	}
+
+	switch node := n.(type) {
+	case *ast.IfStmt:
+		c.total += 1 + nesting
+		c.ifChain(node, nesting)
+	case *ast.ForStmt:
+		c.total += 1 + nesting
+		c.walk(node.Init, nesting)
+		c.walk(node.Cond, nesting)
+		c.walk(node.Post, nesting)
+		c.walk(node.Body, nesting+1)
+	case *ast.RangeStmt:
+		c.total += 1 + nesting
+		c.walk(node.X, nesting)
+		c.walk(node.Body, nesting+1)
+	case *ast.SwitchStmt:
+		c.total += 1 + nesting
+		c.walk(node.Init, nesting)
+		c.walk(node.Tag, nesting)
+		c.walk(node.Body, nesting+1)
+	case *ast.TypeSwitchStmt:
+		c.total += 1 + nesting
+		c.walk(node.Init, nesting)
+		c.walk(node.Assign, nesting)
+		c.walk(node.Body, nesting+1)
+	case *ast.SelectStmt:
+		c.total += 1 + nesting
+		c.walk(node.Body, nesting+1)
+	case *ast.FuncLit:
+		// Closures increase nesting without adding a structural increment
+		c.walk(node.Body, nesting+1)
+	case *ast.BranchStmt:
+		if node.Label != nil {
+			c.total++ // goto and labeled break/continue
+		}
+	case *ast.BinaryExpr:
+		if node.Op == token.LAND || node.Op == token.LOR {
+			c.logical(node, nesting)
+			return
+		}
+		c.walk(node.X, nesting)
+		c.walk(node.Y, nesting)
+	default:
+		ast.Inspect(n, func(child ast.Node) bool {
+			if child == n {
+				return true
+			}
+			c.walk(child, nesting)
+			return false
+		})
+	}
+}
+
+// ifChain counts an if statement's condition, body and else/else-if branches;
+// the caller has already counted the leading if
+func (c *goCognitiveCounter) ifChain(node *ast.IfStmt, nesting int) {
+	c.walk(node.Init, nesting)
+	c.walk(node.Cond, nesting)
+	c.walk(node.Body, nesting+1)
+
+	switch elseNode := node.Else.(type) {
+	case *ast.IfStmt:
+		c.total++ // else if: no nesting increment
+		c.ifChain(elseNode, nesting)
+	case *ast.BlockStmt:
+		c.total++
+		c.walk(elseNode, nesting+1)
+	}
+}
+
+// logical counts each sequence of like logical operators once, e.g. a && b && c is +1
+// while a && b || c is +2
+func (c *goCognitiveCounter) logical(expr *ast.BinaryExpr, nesting int) {
+	var operators []string
+	var operands []ast.Expr
+
+	var flatten func(e ast.Expr)
+	flatten = func(e ast.Expr) {
+		if b, ok := e.(*ast.BinaryExpr); ok && (b.Op == token.LAND || b.Op == token.LOR) {
+			flatten(b.X)
+			operators = append(operators, b.Op.String())
+			flatten(b.Y)
+			return
+		}
+		operands = append(operands, e)
+	}
+	flatten(expr)
+
+	c.total += countOperatorSequences(operators)
+	for _, operand := range operands {
+		c.walk(operand, nesting)
+	}
+}
+
+// RustCognitiveComplexity computes the cognitive complexity of a Rust function from its
+// syntax node. Match expressions count once regardless of the number of arms.
+func RustCognitiveComplexity(fn *types.RustNode) int {
+	if fn == nil {
+		return 0
+	}
+	counter := &rustCognitiveCounter{}
+	counter.children(fn, 0)
+	return counter.total
+}
+
+type rustCognitiveCounter struct {
+	total int
+}
+
+func (c *rustCognitiveCounter) children(node *types.RustNode, nesting int) {
+	for _, child := range node.Children {
+		c.node(child, nesting)
+	}
+}
+
+func (c *rustCognitiveCounter) node(node *types.RustNode, nesting int) {
+	switch node.Kind {
+	case types.RustNodeFunction:
+		return // nested functions are measured on their own
+	case types.RustNodeIf:
+		c.total += 1 + nesting
+		c.ifChain(node, nesting)
+	case types.RustNodeMatch, types.RustNodeLoop, types.RustNodeWhile, types.RustNodeFor, types.RustNodeLetElse:
+		c.total += 1 + nesting
+		c.children(node, nesting+1)
+	case types.RustNodeClosure:
+		c.children(node, nesting+1)
+	case types.RustNodeLogicalOp:
+		c.total += countOperatorSequences(strings.Fields(node.Name))
+	default:
+		c.children(node, nesting)
+	}
+}
+
+// ifChain counts the branches of an if expression; else-if links are direct If children
+func (c *rustCognitiveCounter) ifChain(node *types.RustNode, nesting int) {
+	for _, child := range node.Children {
+		switch child.Kind {
+		case types.RustNodeIf:
+			c.total++
+			c.ifChain(child, nesting)
+		case types.RustNodeElse:
+			c.total++
+			c.children(child, nesting+1)
+		default:
+			c.node(child, nesting+1)
+		}
+	}
+}
+
+// countOperatorSequences returns the number of runs of identical operators
+func countOperatorSequences(operators []string) int {
+	if len(operators) == 0 {
+		return 0
+	}
+	sequences := 1
+	for i := 1; i < len(operators); i++ {
+		if operators[i] != operators[i-1] {
+			sequences++
+		}
+	}
+	return sequences
+}
@@ -0,0 +1,164 @@
+package violations
+
+import (
+	"go/ast"
+	"go/parser"
+	"go/token"
+	"testing"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+const cognitiveTestSource = `package sample
+
+func nested(items []int, limit int) int {
+	total := 0
+	for _, item := range items {
+		if item > 0 && item < limit {
+			for i := 0; i < item; i++ {
+				if i%2 == 0 {
+					total += i
+				} else {
+					total--
+				}
+			}
+		} else if item == 0 || item == limit {
+			continue
+		}
+	}
+	switch {
+	case total > 100:
+		return 100
+	case total < 0:
+		return 0
+	}
+	return total
+}
+
+func flat(kind string) int {
+	switch kind {
+	case "a":
+		return 1
+	case "b":
+		return 2
+	case "c":
+		return 3
+	case "d":
+		return 4
+	default:
+		return 0
+	}
+}
+`
+
+func parseCognitiveTestFuncs(t *testing.T) map[string]*ast.FuncDecl {
+	t.Helper()
+	file, err := parser.ParseFile(token.NewFileSet(), "sample.go", cognitiveTestSource, 0)
+	if err != nil {
+		t.Fatalf("Failed to parse test source: %v", err)
+	}
+	funcs := make(map[string]*ast.FuncDecl)
+	for _, decl := range file.Decls {
+		if fn, ok := decl.(*ast.FuncDecl); ok {
+			funcs[fn.Name.Name] = fn
+		}
+	}
+	return funcs
+}
+
+func TestGoCognitiveComplexity(t *testing.T) {
+	funcs := parseCognitiveTestFuncs(t)
+
+	// range +1, if +2, && +1, for +3, if +4, else +1, else if +1, || +1, switch +1
+	if got := GoCognitiveComplexity(funcs["nested"]); got != 15 {
+		t.Errorf("Expected cognitive complexity 15 for nested, got %d", got)
+	}
+	// A switch counts once regardless of its cases
+	if got := GoCognitiveComplexity(funcs["flat"]); got != 1 {
+		t.Errorf("Expected cognitive complexity 1 for flat, got %d", got)
+	}
+}
+
+func TestRustCognitiveComplexity(t *testing.T) {
+	fn := &types.RustNode{Kind: types.RustNodeFunction, Name: "process"}
+	loop := &types.RustNode{Kind: types.RustNodeFor}
+	body := &types.RustNode{Kind: types.RustNodeBlock}
+	ifNode := &types.RustNode{Kind: types.RustNodeIf}
+	ifBody := &types.RustNode{Kind: types.RustNodeBlock}
+	closure := &types.RustNode{Kind: types.RustNodeClosure}
+	inner := &types.RustNode{Kind: types.RustNodeFunction, Name: "helper"}
+
+	fn.AddChild(loop)
+	loop.AddChild(body)
+	body.AddChild(ifNode)
+	ifNode.AddChild(&types.RustNode{Kind: types.RustNodeLogicalOp, Name: "&& ||"})
+	ifNode.AddChild(ifBody)
+	ifBody.AddChild(&types.RustNode{Kind: types.RustNodeMatch})
+	ifNode.AddChild(&types.RustNode{Kind: types.RustNodeElse})
+	fn.AddChild(closure)
+	closure.AddChild(&types.RustNode{Kind: types.RustNodeIf})
+	fn.AddChild(inner)
+	inner.AddChild(&types.RustNode{Kind: types.RustNodeLoop})
+
+	// for +1, if +2, && || +2, match +3, else +1, if in closure +2; nested fn ignored
+	if got := RustCognitiveComplexity(fn); got != 11 {
+		t.Errorf("Expected cognitive complexity 11, got %d", got)
+	}
+}
+
+func TestCognitiveComplexityDetector_Detect(t *testing.T) {
+	funcs := parseCognitiveTestFuncs(t)
+	detector := NewCognitiveComplexityDetector(&DetectorConfig{MaxCognitiveComplexity: 5})
+
+	astInfo := &types.GoASTInfo{
+		Functions: []*types.FunctionInfo{
+			{Name: "nested", StartLine: 3, EndLine: 25, ASTNode: funcs["nested"]},
+			{Name: "flat", StartLine: 27, EndLine: 40, ASTNode: funcs["flat"]},
+		},
+	}
+
+	violations := detector.Detect(&models.FileInfo{Path: "sample.go"}, astInfo)
+	if len(violations) != 1 {
+		t.Fatalf("Expected 1 violation, got %d", len(violations))
+	}
+
+	v := violations[0]
+	if v.Type != models.ViolationTypeCognitiveComplexity {
+		t.Errorf("Expected cognitive complexity violation, got %s", v.Type)
+	}
+	if v.Rule != "cognitive-complexity" {
+		t.Errorf("Expected rule 'cognitive-complexity', got %s", v.Rule)
+	}
+	if v.Severity != models.SeverityHigh {
+		t.Errorf("Expected high severity for score 15 with max 5, got %v", v.Severity)
+	}
+	if v.Line != 3 {
+		t.Errorf("Expected line 3, got %d", v.Line)
+	}
+}
+
+func TestCognitiveComplexityDetector_Rust(t *testing.T) {
+	fn := &types.RustNode{Kind: types.RustNodeFunction, Name: "run"}
+	loop := &types.RustNode{Kind: types.RustNodeLoop}
+	fn.AddChild(loop)
+	loop.AddChild(&types.RustNode{Kind: types.RustNodeIf})
+
+	astInfo := &types.RustASTInfo{
+		Functions: []*types.RustFunctionInfo{
+			{Name: "run", StartLine: 1, EndLine: 10, Node: fn},
+		},
+	}
+
+	detector := NewCognitiveComplexityDetector(&DetectorConfig{MaxCognitiveComplexity: 2})
+	violations := detector.Detect(&models.FileInfo{Path: "lib.rs"}, astInfo)
+	if len(violations) != 1 {
+		t.Fatalf("Expected 1 violation, got %d", len(violations))
+	}
+	if violations[0].Rule != "rust-cognitive-complexity" {
+		t.Errorf("Expected rule 'rust-cognitive-complexity', got %s", violations[0].Rule)
+	}
+	if violations[0].Severity != models.SeverityLow {
+		t.Errorf("Expected low severity for score 3 with max 2, got %v", violations[0].Severity)
+	}
+}
@@ -0,0 +1,552 @@
+package violations
+
+import (
+	"bufio"
+	"fmt"
+	"go/ast"
+	"os"
+	"path/filepath"
+	"sort"
+	"strconv"
+	"strings"
+	"sync"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+// DefaultMaxFanOut is the number of distinct modules a function may call
+const DefaultMaxFanOut = 5
+
+// RustCircularDependency is the rule of Rust modules depending on themselves through other modules
+const RustCircularDependency = "rust-circular-dependency"
+
+// rustStandardCrates are the crates of the Rust standard library
+var rustStandardCrates = map[string]bool{"std": true, "core": true, "alloc": true}
+
+// CouplingDetector reports functions that call into many modules (fan-out)
+// and records the dependencies between modules across all files of a scan,
+// from which it reports circular dependencies. Standard library modules are
+// not counted.
+type CouplingDetector struct {
+	config    *DetectorConfig
+	mutex     sync.Mutex
+	modules   map[string]*moduleDependencies
+	goModules map[string]string // Directory to the module path of its go.mod, "" when there is none
+}
+
+// moduleDependencies accumulates the dependencies of one module
+type moduleDependencies struct {
+	language  string
+	files     int
+	deps      map[string]dependencySite // Dependency to where the module first depends on it
+	maxFanOut int
+}
+
+// dependencySite is the file and line of an import or call of another module
+type dependencySite struct {
+	file string
+	line int
+}
+
+// before reports whether s comes before other, so the site kept for a
+// dependency does not depend on the order in which files were scanned
+func (s dependencySite) before(other dependencySite) bool {
+	if s.file != other.file {
+		return s.file < other.file
+	}
+	return s.line < other.line
+}
+
+// NewCouplingDetector creates a new fan-out and coupling detector
+func NewCouplingDetector(config *DetectorConfig) *CouplingDetector {
+	if config == nil {
+		config = DefaultDetectorConfig()
+	}
+	return &CouplingDetector{
+		config:    config,
+		modules:   make(map[string]*moduleDependencies),
+		goModules: make(map[string]string),
+	}
+}
+
+// Name returns the name of this detector
+func (d *CouplingDetector) Name() string {
+	return "Coupling Detector"
+}
+
+// Description returns a description of what this detector checks for
+func (d *CouplingDetector) Description() string {
+	return "Detects functions that call into many modules and measures afferent and efferent coupling between modules"
+}
+
+// Detect reports the functions of a file with too high a fan-out and records
+// the module dependencies of the file
+func (d *CouplingDetector) Detect(fileInfo *models.FileInfo, astInfo interface{}) []*models.Violation {
+	switch info := astInfo.(type) {
+	case *types.GoASTInfo:
+		if info == nil || info.AST == nil {
+			return nil
+		}
+		return d.detectGo(fileInfo.Path, info)
+	case *types.RustASTInfo:
+		if info == nil {
+			return nil
+		}
+		return d.detectRust(fileInfo.Path, info)
+	default:
+		return nil
+	}
+}
+
+// Reset clears the recorded dependencies; call it at the start of each scan
+func (d *CouplingDetector) Reset() {
+	d.mutex.Lock()
+	defer d.mutex.Unlock()
+	d.modules = make(map[string]*moduleDependencies)
+}
+
+// Coupling returns the coupling of every module seen since the last Reset,
+// ordered by module
+func (d *CouplingDetector) Coupling() []*models.ModuleCoupling {
+	d.mutex.Lock()
+	defer d.mutex.Unlock()
+
+	modules := make([]*models.ModuleCoupling, 0, len(d.modules))
+	for name, module := range d.modules {
+		deps := make([]string, 0, len(module.deps))
+		for dep := range module.deps {
+			deps = append(deps, dep)
+		}
+		sort.Strings(deps)
+		modules = append(modules, &models.ModuleCoupling{
+			Module:       name,
+			Language:     module.language,
+			Files:        module.files,
+			Efferent:     len(deps),
+			MaxFanOut:    module.maxFanOut,
+			Dependencies: deps,
+		})
+	}
+	models.LinkCoupling(modules)
+	return modules
+}
+
+// record adds the dependencies, by the line they are first used on, and the
+// largest fan-out of one file to its module
+func (d *CouplingDetector) record(module, language, path string, deps map[string]int, maxFanOut int) {
+	d.mutex.Lock()
+	defer d.mutex.Unlock()
+
+	entry, ok := d.modules[module]
+	if !ok {
+		entry = &moduleDependencies{language: language, deps: make(map[string]dependencySite)}
+		d.modules[module] = entry
+	}
+	entry.files++
+	entry.maxFanOut = max(entry.maxFanOut, maxFanOut)
+	for dep, line := range deps {
+		if dep == module {
+			continue
+		}
+		site := dependencySite{file: path, line: line}
+		if recorded, ok := entry.deps[dep]; !ok || site.before(recorded) {
+			entry.deps[dep] = site
+		}
+	}
+}
+
+// detectGo counts the imported packages called by each Go function. The
+// module of a file is the import path of its package.
+func (d *CouplingDetector) detectGo(path string, info *types.GoASTInfo) []*models.Violation {
+	module, modulePath := d.goPackagePath(path)
+
+	imports := make(map[string]string) // Name used in the file to import path
+	fileDeps := make(map[string]int)   // Import path to the line of its import
+	for _, spec := range info.AST.Imports {
+		importPath, err := strconv.Unquote(spec.Path.Value)
+		if err != nil || importPath == module || isGoStandardPackage(importPath, modulePath) {
+			continue
+		}
+		fileDeps[importPath] = 1
+		if info.FileSet != nil {
+			fileDeps[importPath] = info.FileSet.Position(spec.Pos()).Line
+		}
+		name := goImportName(importPath)
+		if spec.Name != nil {
+			name = spec.Name.Name
+		}
+		imports[name] = importPath
+	}
+
+	var violations []*models.Violation
+	maxFanOut := 0
+	for _, fn := range info.Functions {
+		if fn.ASTNode == nil || fn.ASTNode.Body == nil {
+			continue
+		}
+		called := make(map[string]bool)
+		ast.Inspect(fn.ASTNode.Body, func(n ast.Node) bool {
+			call, ok := n.(*ast.CallExpr)
+			if !ok {
+				return true
+			}
+			if selector, ok := call.Fun.(*ast.SelectorExpr); ok {
+				if pkg, ok := selector.X.(*ast.Ident); ok && imports[pkg.Name] != "" {
+					called[imports[pkg.Name]] = true
+				}
+			}
+			return true
+		})
+
+		maxFanOut = max(maxFanOut, len(called))
+		if len(called) > d.config.MaxFanOut {
+			violations = append(violations, d.fanOutViolation(path, fn.Name, fn.StartLine, fn.StartColumn, fn.EndLine, called, "fan-out"))
+		}
+	}
+
+	d.record(module, "Go", path, fileDeps, maxFanOut)
+	return violations
+}
+
+// detectRust counts the modules called through paths such as
+// `cache::get(...)` or imported functions in each Rust function. The module
+// of a file is derived from its path below `src`, e.g. `shop::billing` for
+// shop/src/billing.rs.
+func (d *CouplingDetector) detectRust(path string, info *types.RustASTInfo) []*models.Violation {
+	module := rustModulePath(path)
+	crate, _, _ := strings.Cut(module, "::")
+
+	resolver := &rustPathResolver{
+		module:   module,
+		crate:    crate,
+		imports:  rustImports(info.Tokens),
+		children: make(map[string]bool),
+	}
+	for _, child := range info.Modules {
+		resolver.children[child.Name] = true
+	}
+
+	// Imported types and glob imports are dependencies even when no function calls them
+	fileDeps := make(map[string]int) // Module to the line first naming it
+	for _, imported := range resolver.imports {
+		segments := strings.Split(imported, "::")
+		if last := segments[len(segments)-1]; last == "*" || isRustTypeName(last) {
+			if dep := resolver.resolve(segments[:len(segments)-1]); dep != "" {
+				fileDeps[dep] = rustDependencyLine(info.Tokens, dep, 1)
+			}
+		}
+	}
+
+	var violations []*models.Violation
+	maxFanOut := 0
+	for _, fn := range info.Functions {
+		called := make(map[string]bool)
+		for _, segments := range rustCallPaths(info.Tokens, fn.StartLine, fn.EndLine) {
+			if dep := resolver.resolveCall(segments); dep != "" && dep != module {
+				called[dep] = true
+				if _, ok := fileDeps[dep]; !ok {
+					fileDeps[dep] = rustDependencyLine(info.Tokens, dep, fn.StartLine)
+				}
+			}
+		}
+
+		maxFanOut = max(maxFanOut, len(called))
+		if len(called) > d.config.MaxFanOut {
+			violations = append(violations, d.fanOutViolation(path, fn.Name, fn.StartLine, fn.StartColumn, fn.EndLine, called, "rust-fan-out"))
+		}
+	}
+
+	d.record(module, "Rust", path, fileDeps, maxFanOut)
+	return violations
+}
+
+// fanOutViolation reports a function calling into too many modules
+func (d *CouplingDetector) fanOutViolation(path, name string, line, column, endLine int, called map[string]bool, rule string) *models.Violation {
+	modules := make([]string, 0, len(called))
+	for module := range called {
+		modules = append(modules, module)
+	}
+	sort.Strings(modules)
+
+	severity := models.SeverityLow
+	if len(modules) > d.config.MaxFanOut*2 {
+		severity = models.SeverityHigh
+	} else if len(modules) > int(float64(d.config.MaxFanOut)*1.5) {
+		severity = models.SeverityMedium
+	}
+
+	return &models.Violation{
+		Type:     models.ViolationTypeFanOut,
+		Severity: severity,
+		Message: fmt.Sprintf("Function '%s' calls into %d modules (max: %d): %s",
+			name, len(modules), d.config.MaxFanOut, strings.Join(modules, ", ")),
+		File:       path,
+		Line:       line,
+		Column:     column,
+		EndLine:    endLine,
+		Rule:       rule,
+		Suggestion: fmt.Sprintf("Split '%s' so each part works with fewer modules, or put the modules it coordinates behind one interface", name),
+	}
+}
+
+// goPackagePath returns the import path of the package of a Go file and the
+// path of its module, from the nearest go.mod. Without a go.mod the package
+// is named by its directory.
+func (d *CouplingDetector) goPackagePath(path string) (string, string) {
+	dir := filepath.Dir(path)
+	if abs, err := filepath.Abs(dir); err == nil {
+		dir = abs
+	}
+	for current := dir; ; current = filepath.Dir(current) {
+		if modulePath, ok := d.goModulePath(current); ok {
+			rel, err := filepath.Rel(current, dir)
+			if err != nil || rel == "." {
+				return modulePath, modulePath
+			}
+			return modulePath + "/" + filepath.ToSlash(rel), modulePath
+		}
+		if filepath.Dir(current) == current {
+			return filepath.ToSlash(dir), ""
+		}
+	}
+}
+
+// goModulePath returns the module path declared by the go.mod in dir
+func (d *CouplingDetector) goModulePath(dir string) (string, bool) {
+	d.mutex.Lock()
+	modulePath, cached := d.goModules[dir]
+	d.mutex.Unlock()
+	if !cached {
+		modulePath = readGoModulePath(filepath.Join(dir, "go.mod"))
+		d.mutex.Lock()
+		d.goModules[dir] = modulePath
+		d.mutex.Unlock()
+	}
+	return modulePath, modulePath != ""
+}
+
+// readGoModulePath returns the module directive of a go.mod file, or "" when it cannot be read
+func readGoModulePath(path string) string {
+	file, err := os.Open(path)
+	if err != nil {
+		return ""
+	}
+	defer file.Close()
+
+	scanner := bufio.NewScanner(file)
+	for scanner.Scan() {
+		if modulePath, ok := strings.CutPrefix(strings.TrimSpace(scanner.Text()), "module "); ok {
+			return strings.Trim(strings.TrimSpace(modulePath), `"`)
+		}
+	}
+	return ""
+}
+
+// isGoStandardPackage reports whether an import path belongs to the standard
+// library, whose first element has no dot, rather than to the scanned module
+func isGoStandardPackage(importPath, modulePath string) bool {
+	if modulePath != "" && (importPath == modulePath || strings.HasPrefix(importPath, modulePath+"/")) {
+		return false
+	}
+	first, _, _ := strings.Cut(importPath, "/")
+	return !strings.Contains(first, ".")
+}
+
+// goImportName guesses the package name of an import path from its last
+// element, skipping major version suffixes such as /v2 and .v3
+func goImportName(importPath string) string {
+	elements := strings.Split(importPath, "/")
+	name := elements[len(elements)-1]
+	if len(elements) > 1 && len(name) > 1 && name[0] == 'v' && strings.Trim(name[1:], "0123456789") == "" {
+		name = elements[len(elements)-2]
+	}
+	if dot := strings.Index(name, ".v"); dot > 0 {
+		name = name[:dot]
+	}
+	name = strings.TrimPrefix(name, "go-")
+	return strings.ReplaceAll(name, "-", "_")
+}
+
+// rustModulePath derives the module path of a Rust file from its location
+// below the crate's src directory. The crate is named after the directory
+// containing src; lib.rs, main.rs and mod.rs name their directory's module.
+func rustModulePath(path string) string {
+	parts := strings.Split(filepath.ToSlash(filepath.Clean(path)), "/")
+	src := -1
+	for i := len(parts) - 2; i >= 0; i-- {
+		if parts[i] == "src" {
+			src = i
+			break
+		}
+	}
+
+	crate := "crate"
+	if src > 0 {
+		crate = strings.ReplaceAll(parts[src-1], "-", "_")
+	}
+	segments := []string{crate}
+	if src >= 0 {
+		segments = append(segments, parts[src+1:len(parts)-1]...)
+	}
+	stem := strings.TrimSuffix(parts[len(parts)-1], ".rs")
+	if stem != "mod" && stem != "lib" && !(stem == "main" && len(segments) == 1) {
+		segments = append(segments, stem)
+	}
+	return strings.Join(segments, "::")
+}
+
+// isRustTypeName reports whether a path segment names a type or trait rather than a module
+func isRustTypeName(segment string) bool {
+	return segment != "" && segment[0] >= 'A' && segment[0] <= 'Z'
+}
+
+// rustImports maps the names brought into scope by the `use` declarations of
+// a file to their full paths, expanding grouped imports
+func rustImports(tokens []types.RustTokenInfo) map[string]string {
+	imports := make(map[string]string)
+	for i := 0; i < len(tokens); i++ {
+		if tokens[i].Kind != types.RustTokenKeyword || tokens[i].Text != "use" {
+			continue
+		}
+		i = expandRustUseTree(tokens, i+1, nil, imports)
+	}
+	return imports
+}
+
+// expandRustUseTree records the imports of the use tree starting at i below
+// prefix and returns the index of the token ending the tree
+func expandRustUseTree(tokens []types.RustTokenInfo, i int, prefix []string, imports map[string]string) int {
+	path := append([]string(nil), prefix...)
+	for ; i < len(tokens); i++ {
+		switch text := tokens[i].Text; text {
+		case "::":
+		case "{":
+			for i++; i < len(tokens) && tokens[i].Text != "}"; i++ {
+				i = expandRustUseTree(tokens, i, path, imports)
+				if i >= len(tokens) || tokens[i].Text == "}" {
+					break
+				}
+			}
+			return i + 1
+		case "as":
+			if i+1 < len(tokens) && len(path) > 0 {
+				imports[tokens[i+1].Text] = strings.Join(path, "::")
+			}
+			return i + 2
+		case ",", ";", "}":
+			if len(path) > len(prefix) {
+				switch last := path[len(path)-1]; {
+				case last == "self" && len(path) > 1:
+					path = path[:len(path)-1]
+					imports[path[len(path)-1]] = strings.Join(path, "::")
+				case last == "*":
+					// Glob imports bring no single name into scope
+					imports[strings.Join(path, "::")] = strings.Join(path, "::")
+				default:
+					imports[last] = strings.Join(path, "::")
+				}
+			}
+			return i
+		default:
+			path = append(path, text)
+		}
+	}
+	return i
+}
+
+// rustCallPaths returns the path segments of the calls between two lines,
+// e.g. [cache get] for `cache::get(key)` and [send] for `send(mail)`. Method
+// calls are left out because their receiver type is unknown.
+func rustCallPaths(tokens []types.RustTokenInfo, startLine, endLine int) [][]string {
+	var paths [][]string
+	first := sort.Search(len(tokens), func(i int) bool { return tokens[i].Line >= startLine })
+	for i := first; i < len(tokens) && tokens[i].Line <= endLine; i++ {
+		tok := tokens[i]
+		if tok.Kind != types.RustTokenIdent && tok.Kind != types.RustTokenKeyword {
+			continue
+		}
+		if i > 0 && (tokens[i-1].Text == "." || tokens[i-1].Text == "::" || tokens[i-1].Text == "fn") {
+			continue
+		}
+
+		segments := []string{tok.Text}
+		j := i + 1
+		for j+1 < len(tokens) && tokens[j].Text == "::" && tokens[j+1].Kind != types.RustTokenPunct {
+			segments = append(segments, tokens[j+1].Text)
+			j += 2
+		}
+		if j < len(tokens) && tokens[j].Text == "(" {
+			paths = append(paths, segments)
+		}
+		i = j - 1
+	}
+	return paths
+}
+
+// rustPathResolver resolves call paths of one file to the modules they call
+type rustPathResolver struct {
+	module   string
+	crate    string
+	imports  map[string]string
+	children map[string]bool // Modules declared in the file
+}
+
+// resolveCall returns the module a call path calls into, or "" for calls of
+// local functions, methods of types and the standard library
+func (r *rustPathResolver) resolveCall(segments []string) string {
+	// Imported functions, and associated functions of imported types such as `Pool::connect()`
+	if imported, ok := r.imports[segments[0]]; ok && (len(segments) == 1 || isRustTypeName(segments[0])) {
+		segments = append(strings.Split(imported, "::"), segments[1:]...)
+	} else if len(segments) == 1 {
+		return ""
+	}
+
+	// Keep the leading module segments, dropping the function and any type it belongs to
+	modules := segments[:len(segments)-1]
+	for i, segment := range modules {
+		if isRustTypeName(segment) {
+			modules = modules[:i]
+			break
+		}
+	}
+	return r.resolve(modules)
+}
+
+// resolve converts a module path as written in the file to a full module path
+func (r *rustPathResolver) resolve(segments []string) string {
+	if len(segments) == 0 || isRustTypeName(segments[0]) {
+		return ""
+	}
+	switch first := segments[0]; {
+	case first == "crate" || first == "self" || first == "super":
+	case r.children[first]:
+		return r.absolute(append([]string{"self"}, segments...))
+	case r.imports[first] != "":
+		return r.absolute(append(strings.Split(r.imports[first], "::"), segments[1:]...))
+	}
+	return r.absolute(segments)
+}
+
+// absolute resolves the crate, self and super prefixes of a path. Other
+// paths start with an external crate.
+func (r *rustPathResolver) absolute(segments []string) string {
+	var base []string
+	switch segments[0] {
+	case "crate":
+		base, segments = []string{r.crate}, segments[1:]
+	case "self":
+		base, segments = strings.Split(r.module, "::"), segments[1:]
+	case "super":
+		base = strings.Split(r.module, "::")
+		for len(segments) > 0 && segments[0] == "super" && len(base) > 1 {
+			base, segments = base[:len(base)-1], segments[1:]
+		}
+	}
+
+	path := append(base, segments...)
+	if len(path) == 0 || rustStandardCrates[path[0]] {
+		return ""
+	}
+	return strings.Join(path, "::")
+}
@@ -0,0 +1,220 @@
+package violations
+
+import (
+	"go/ast"
+	"go/parser"
+	"go/token"
+	"os"
+	"path/filepath"
+	"regexp"
+	"strings"
+	"testing"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+const couplingHandlerSource = `package handler
+
+import (
+	"fmt"
+
+	"example.com/shop/audit"
+	"example.com/shop/cache"
+	"example.com/shop/db"
+	"example.com/shop/email"
+	"example.com/shop/metrics"
+	mq "github.com/acme/queue/v2"
+)
+
+func ProcessRequest(id string) {
+	user := cache.Get(id)
+	db.Save(user)
+	email.Send(user)
+	metrics.Inc("requests")
+	audit.Log(fmt.Sprint(id))
+	mq.Publish(id)
+}
+
+func Lookup(id string) string {
+	return cache.Get(id)
+}
+`
+
+const couplingRustSource = `use crate::cache;
+use crate::db::Pool;
+use crate::email::send;
+use crate::{metrics, audit::{self, Level}};
+use serde_json;
+
+mod local;
+
+fn process_request(id: u32) {
+    let user = cache::get(id);
+    Pool::connect().save(&user);
+    send(&user);
+    metrics::inc("requests");
+    audit::log(Level::Info);
+    serde_json::to_string(&user);
+    local::helper();
+    std::mem::drop(user);
+    Vec::new();
+}
+`
+
+// writeGoPackage writes a Go file below dir and returns its path and AST info
+func writeGoPackage(t *testing.T, dir, rel, source string) (string, *types.GoASTInfo) {
+	t.Helper()
+	path := filepath.Join(dir, filepath.FromSlash(rel))
+	if err := os.MkdirAll(filepath.Dir(path), 0755); err != nil {
+		t.Fatalf("Failed to create package directory: %v", err)
+	}
+	if err := os.WriteFile(path, []byte(source), 0644); err != nil {
+		t.Fatalf("Failed to write %s: %v", rel, err)
+	}
+
+	fset := token.NewFileSet()
+	file, err := parser.ParseFile(fset, path, source, 0)
+	if err != nil {
+		t.Fatalf("Failed to parse %s: %v", rel, err)
+	}
+	info := &types.GoASTInfo{FilePath: path, AST: file, FileSet: fset}
+	for _, decl := range file.Decls {
+		if fn, ok := decl.(*ast.FuncDecl); ok {
+			info.Functions = append(info.Functions, &types.FunctionInfo{
+				Name:      fn.Name.Name,
+				StartLine: fset.Position(fn.Pos()).Line,
+				EndLine:   fset.Position(fn.End()).Line,
+				ASTNode:   fn,
+			})
+		}
+	}
+	return path, info
+}
+
+// rustTestTokens splits Rust source into tokens for the tests of token-based rules
+func rustTestTokens(source string) []types.RustTokenInfo {
+	tokenRegex := regexp.MustCompile(`"[^"]*"|'[^']'|\d\w*(?:\.\d\w*)?|::|->|=>|==|!=|<=|>=|&&|\|\||[-+*/%]=|'[A-Za-z_]\w*|[A-Za-z_]\w*|\S`)
+	keywords := map[string]bool{
+		"use": true, "fn": true, "let": true, "mod": true, "crate": true, "self": true, "Self": true, "super": true,
+		"as": true, "mut": true, "pub": true, "struct": true, "enum": true, "impl": true, "return": true,
+		"if": true, "else": true, "match": true, "for": true, "while": true, "loop": true, "move": true,
+		"true": true, "false": true,
+	}
+
+	var tokens []types.RustTokenInfo
+	for n, line := range strings.Split(source, "\n") {
+		for _, span := range tokenRegex.FindAllStringIndex(line, -1) {
+			text := line[span[0]:span[1]]
+			kind := types.RustTokenPunct
+			switch {
+			case keywords[text]:
+				kind = types.RustTokenKeyword
+			case text[0] == '\'' && len(text) > 1 && text[len(text)-1] != '\'':
+				kind = types.RustTokenLifetime
+			case text[0] == '"' || text[0] == '\'' || text[0] >= '0' && text[0] <= '9':
+				kind = types.RustTokenLiteral
+			case text[0] == '_' || text[0] >= 'A' && text[0] <= 'Z' || text[0] >= 'a' && text[0] <= 'z':
+				kind = types.RustTokenIdent
+			}
+			tokens = append(tokens, types.RustTokenInfo{Kind: kind, Text: text, Line: n + 1, Column: span[0] + 1})
+		}
+	}
+	return tokens
+}
+
+func TestCouplingDetectorGo(t *testing.T) {
+	dir := t.TempDir()
+	if err := os.WriteFile(filepath.Join(dir, "go.mod"), []byte("module example.com/shop\n\ngo 1.23\n"), 0644); err != nil {
+		t.Fatalf("Failed to write go.mod: %v", err)
+	}
+	handlerPath, handler := writeGoPackage(t, dir, "handler/handler.go", couplingHandlerSource)
+	cachePath, cache := writeGoPackage(t, dir, "cache/cache.go", "package cache\n\nfunc Get(id string) string { return id }\n")
+
+	detector := NewCouplingDetector(DefaultDetectorConfig())
+	violations := detector.Detect(&models.FileInfo{Path: handlerPath}, handler)
+	if len(violations) != 1 {
+		t.Fatalf("Expected 1 fan-out violation, got %d", len(violations))
+	}
+	violation := violations[0]
+	if violation.Type != models.ViolationTypeFanOut || violation.Rule != "fan-out" || violation.Line != 14 {
+		t.Errorf("Unexpected violation: %+v", violation)
+	}
+	if !strings.Contains(violation.Message, "calls into 6 modules (max: 5)") || strings.Contains(violation.Message, "fmt") {
+		t.Errorf("Expected 6 modules without the standard library, got %q", violation.Message)
+	}
+	if len(detector.Detect(&models.FileInfo{Path: cachePath}, cache)) != 0 {
+		t.Error("Expected no violations for a package without calls")
+	}
+
+	modules := detector.Coupling()
+	if len(modules) != 2 {
+		t.Fatalf("Expected 2 modules, got %d", len(modules))
+	}
+	cacheModule, handlerModule := modules[0], modules[1]
+	if cacheModule.Module != "example.com/shop/cache" || cacheModule.Afferent != 1 || cacheModule.Efferent != 0 || cacheModule.Instability != 0 {
+		t.Errorf("Unexpected cache coupling: %+v", cacheModule)
+	}
+	if handlerModule.Module != "example.com/shop/handler" || handlerModule.Efferent != 6 || handlerModule.Afferent != 0 ||
+		handlerModule.Instability != 1 || handlerModule.MaxFanOut != 6 {
+		t.Errorf("Unexpected handler coupling: %+v", handlerModule)
+	}
+
+	detector.Reset()
+	if modules := detector.Coupling(); len(modules) != 0 {
+		t.Errorf("Expected no modules after Reset, got %d", len(modules))
+	}
+}
+
+func TestCouplingDetectorRust(t *testing.T) {
+	info := &types.RustASTInfo{
+		Tokens:    rustTestTokens(couplingRustSource),
+		Functions: []*types.RustFunctionInfo{{Name: "process_request", StartLine: 9, EndLine: 19}},
+		Modules:   []*types.RustModuleInfo{{Name: "local"}},
+	}
+
+	detector := NewCouplingDetector(DefaultDetectorConfig())
+	violations := detector.Detect(&models.FileInfo{Path: filepath.Join("shop", "src", "handler.rs")}, info)
+	if len(violations) != 1 {
+		t.Fatalf("Expected 1 fan-out violation, got %d", len(violations))
+	}
+	expected := "calls into 7 modules (max: 5): serde_json, shop::audit, shop::cache, shop::db, shop::email, shop::handler::local, shop::metrics"
+	if violations[0].Rule != "rust-fan-out" || !strings.Contains(violations[0].Message, expected) {
+		t.Errorf("Expected %q, got %q", expected, violations[0].Message)
+	}
+
+	modules := detector.Coupling()
+	if len(modules) != 1 || modules[0].Module != "shop::handler" || modules[0].Efferent != 7 {
+		t.Errorf("Unexpected coupling: %+v", modules)
+	}
+}
+
+func TestRustModulePath(t *testing.T) {
+	tests := map[string]string{
+		"shop/src/lib.rs":          "shop",
+		"shop/src/main.rs":         "shop",
+		"shop/src/billing.rs":      "shop::billing",
+		"shop/src/billing/mod.rs":  "shop::billing",
+		"my-app/src/api/orders.rs": "my_app::api::orders",
+		"script.rs":                "crate::script",
+	}
+	for path, want := range tests {
+		if got := rustModulePath(filepath.FromSlash(path)); got != want {
+			t.Errorf("rustModulePath(%q) = %q, want %q", path, got, want)
+		}
+	}
+}
+
+func TestGoImportName(t *testing.T) {
+	tests := map[string]string{
+		"example.com/shop/cache":      "cache",
+		"github.com/acme/queue/v2":    "queue",
+		"gopkg.in/yaml.v3":            "yaml",
+		"github.com/mattn/go-sqlite3": "sqlite3",
+	}
+	for path, want := range tests {
+		if got := goImportName(path); got != want {
+			t.Errorf("goImportName(%q) = %q, want %q", path, got, want)
+		}
+	}
+}
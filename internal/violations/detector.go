@@ -2,6 +2,8 @@
 package violations
 
 import (
+	"time"
+
 	"github.com/ericfisherdev/goclean/internal/models"
 )
 
@@ -20,6 +22,7 @@ type Detector interface {
 // DetectorRegistry manages all available detectors
 type DetectorRegistry struct {
 	detectors []Detector
+	timings   *timingTable // Set by EnableTimings
 }
 
 // NewDetectorRegistry creates a new detector registry
@@ -44,7 +47,13 @@ func (r *DetectorRegistry) DetectAll(fileInfo *models.FileInfo, astInfo interfac
 	var allViolations []*models.Violation
 	
 	for _, detector := range r.detectors {
+		if r.timings == nil {
+			allViolations = append(allViolations, detector.Detect(fileInfo, astInfo)...)
+			continue
+		}
+		start := time.Now()
 		violations := detector.Detect(fileInfo, astInfo)
+		r.RecordTiming(detector.Name(), time.Since(start))
 		allViolations = append(allViolations, violations...)
 	}
 	
@@ -56,12 +65,27 @@ type DetectorConfig struct {
 	// Function thresholds
 	MaxFunctionLines      int
 	MaxCyclomaticComplexity int
+	MaxCognitiveComplexity  int
 	MaxParameters        int
 	MaxNestingDepth      int
 	
 	// Code structure thresholds
 	MaxClassLines        int
 	MaxMethods          int
+	MinCloneTokens       int
+	CloneMinimums        CloneMinimums // Clone minimums per granularity; zero values use MinCloneTokens
+	MaxFanOut            int // Distinct modules one function may call
+	MinMaintainabilityIndex int // Lowest maintainability index a function may have
+	MaxHalsteadVolume     int // Highest Halstead volume of a function; 0 disables the check
+	MaxHalsteadDifficulty int // Highest Halstead difficulty of a function; 0 disables the check
+	MaxHalsteadEffort     int // Highest Halstead effort of a function; 0 disables the check
+	MaxStringRepeats      int // Uses of a string literal allowed in one file; 0 disables magic strings
+	MaxPrimitiveRepeats   int // Functions and structs a primitive parameter or field may recur in; 0 disables primitive obsession
+	MaxGodStructFields    int // Fields of a struct with too many methods; 0 disables god structs
+	MaxGodStructMethods   int // Methods of a struct with too many fields; 0 disables god structs
+	MagicNumbers          MagicNumberAllowlist // Contexts in which magic numbers are allowed
+	UnitLiterals          UnitLiteralConfig    // Bare numbers that look like durations, sizes or ports
+	TodoIssues            TodoIssueConfig      // Issue references required on TODO markers
 	
 	// Naming convention rules
 	AllowSingleLetterVars bool
@@ -73,6 +97,16 @@ type DetectorConfig struct {
 	SkipTestFiles        bool
 	Verbose              bool
 	
+	// Inline suppressions
+	ReportUnusedSuppressions bool
+	
+	// Commented-out code
+	MinCommentedCodeConfidence float64 // Share of a comment that must parse as code; 0 reports comments more than half code
+	ShowReasons                bool    // Record on commented-code findings which lines parsed, for --show-reason
+	
+	// Opt-in heuristics reported at info severity
+	DetectPastedCode     bool
+	
 	// Severity classification config
 	SeverityConfig *SeverityConfig
 	
@@ -81,6 +115,18 @@ type DetectorConfig struct {
 	
 	// Clippy configuration
 	ClippyConfig *ClippyDetectorConfig
+	
+	// External detector plugins
+	Plugins []PluginSpec
+	
+	// Custom rules written as script expressions
+	ScriptRules []ScriptRuleSpec
+	
+	// Collapsing of repeated findings in one file
+	Grouping GroupingConfig
+	
+	// Estimated time to fix violations
+	Remediation RemediationConfig
 }
 
 // RustDetectorConfig provides Rust-specific detector configuration
@@ -95,6 +141,12 @@ type RustDetectorConfig struct {
 	AllowUnwrap             bool
 	AllowExpect             bool
 	EnforceResultPropagation bool
+	MaxUnwrapsPerFunction   int // unwrap and expect calls in a function outside tests; 0 disables the check
+	MaxUnwrapsPerFile       int // unwrap and expect calls in a file outside tests; 0 disables the check
+	LibraryPanicMacros      []string // Macros reported in pub functions of library code; nil uses the defaults, empty disables the check
+	AllowPanicsInBinaries   bool
+	AllowPanicsInTests      bool
+	AllowStringErrorsInBinaries bool // Result<_, String> in binaries and examples
 	
 	// Pattern matching
 	EnablePatternMatchCheck  bool
@@ -104,25 +156,60 @@ type RustDetectorConfig struct {
 	// Trait and impl
 	MaxTraitBounds          int
 	MaxImplMethods          int
+	MaxImplLines            int // Lines of an impl block; 0 uses MaxClassLines
 	DetectOrphanInstances   bool
 	
 	// Unsafe code
 	AllowUnsafe             bool
 	RequireUnsafeComments   bool
+	SafetyCommentMarker     string // Text a comment above unsafe code must contain; empty uses DefaultSafetyCommentMarker
 	DetectTransmuteUsage    bool
+	DetectPointerArithmetic bool // Raw pointer offsets in unsafe blocks without a visible bounds check
 	
 	// Performance
 	DetectInefficientString bool
 	DetectBoxedPrimitives   bool
 	DetectBlockingInAsync   bool
+	DetectSequentialAwaits  bool     // .await inside for and while loops of async fns
+	BlockingFunctions       []string // Known-blocking functions reported in async fns besides the built-in ones
+	DetectHeapAllocations   bool
+	
+	// Embedded and no_std code
+	AllowMutableStatics     bool
+	DowngradeMutableStatics bool // Report static mut declarations and accesses at low severity
+	SkipInternalDocs        bool // Only require docs on items visible outside the crate
+	
+	// Documentation sections of pub functions
+	RequireSafetySection    bool // # Safety on unsafe fns
+	RequireErrorsSection    bool // # Errors on fns returning a Result
+	RequirePanicsSection    bool // # Panics on fns that can panic
+	
+	// Doc examples of pub functions and types
+	RequireDocExamples      bool     // A ``` block in the doc comments
+	DocExamplePaths         []string // Globs of the files RequireDocExamples applies to; empty for all
+	
+	// Type-accurate analysis
+	CargoCheck              bool // Use rustc diagnostics from cargo check when the project compiles
+	
+	// Rule packs
+	EnableConcurrencyPack   bool
+	EnableImportsPack       bool
+	MaxLocalImports         int      // Use declarations in a function body
+	MaxReexportDepth        int      // Chained pub use declarations
+	UnboundedChannelAPIs    []string // Constructors of unbounded channels besides the built-in ones
 	
 	// Macro analysis
 	MaxMacroComplexity      int
 	AllowRecursiveMacros    bool
+	MaxMacroArms            int // Rule arms of a macro_rules! definition
+	MaxMacroNesting         int // Token-tree nesting depth inside a macro arm
+	MaxMacroLines           int // Lines of a macro_rules! definition
 	
 	// Module structure
 	MaxModuleDepth          int
 	MaxFileLines            int
+	MaxTupleArity           int // Elements of a tuple in a return or field type
+	DetectUnusedPublicAPI   bool // Report pub items nothing in the scanned workspace uses
 	
 	// Naming conventions
 	EnforceSnakeCase        bool
@@ -135,6 +222,11 @@ type RustDetectorConfig struct {
 	MaxTraitMethods         int
 	MaxAssociatedTypes      int
 	MaxComplexTraitParams   int
+	
+	// Enum analysis
+	MaxEnumVariants         int
+	MaxErrorVariants        int
+	SkipEnumAttributes      []string // Enums with one of these attributes or derives are not counted
 }
 
 // ClippyDetectorConfig provides clippy-specific detector configuration
@@ -151,10 +243,14 @@ func DefaultDetectorConfig() *DetectorConfig {
 	return &DetectorConfig{
 		MaxFunctionLines:      25,
 		MaxCyclomaticComplexity: 8,
+		MaxCognitiveComplexity:  15,
 		MaxParameters:        4,
 		MaxNestingDepth:      3,
 		MaxClassLines:        150,
 		MaxMethods:          20,
+		MinCloneTokens:       DefaultMinCloneTokens,
+		MaxFanOut:            DefaultMaxFanOut,
+		MinMaintainabilityIndex: DefaultMinMaintainabilityIndex,
 		AllowSingleLetterVars: true,
 		RequireCamelCase:     true,
 		RequireCommentsForPublic: true,
@@ -180,6 +276,10 @@ func DefaultRustDetectorConfig() *RustDetectorConfig {
 		AllowUnwrap:             false,
 		AllowExpect:             false,
 		EnforceResultPropagation: true,
+		LibraryPanicMacros:      DefaultLibraryPanicMacros(),
+		AllowPanicsInBinaries:   true,
+		AllowPanicsInTests:      true,
+		AllowStringErrorsInBinaries: true,
 		
 		// Pattern matching
 		EnablePatternMatchCheck:  true,
@@ -194,20 +294,49 @@ func DefaultRustDetectorConfig() *RustDetectorConfig {
 		// Unsafe code
 		AllowUnsafe:             true,  // Allow but track
 		RequireUnsafeComments:   true,
+		SafetyCommentMarker:     DefaultSafetyCommentMarker,
 		DetectTransmuteUsage:    true,
+		DetectPointerArithmetic: true,
 		
 		// Performance
 		DetectInefficientString: true,
 		DetectBoxedPrimitives:   true,
 		DetectBlockingInAsync:   true,
+		DetectSequentialAwaits:  true,
+		DetectHeapAllocations:   false,
+		
+		// Embedded and no_std code
+		AllowMutableStatics:     false,
+		DowngradeMutableStatics: false,
+		SkipInternalDocs:        false,
+		
+		// Documentation sections
+		RequireSafetySection:    true,
+		RequireErrorsSection:    false,
+		RequirePanicsSection:    false,
+		RequireDocExamples:      false,
+		
+		// Type-accurate analysis
+		CargoCheck:              false,
+		
+		// Rule packs
+		EnableConcurrencyPack:   false,
+		EnableImportsPack:       false,
+		MaxLocalImports:         DefaultMaxLocalImports,
+		MaxReexportDepth:        DefaultMaxReexportDepth,
 		
 		// Macro analysis
 		MaxMacroComplexity:      10,
 		AllowRecursiveMacros:    false,
+		MaxMacroArms:            DefaultMaxMacroArms,
+		MaxMacroNesting:         DefaultMaxMacroNesting,
+		MaxMacroLines:           DefaultMaxMacroLines,
 		
 		// Module structure
-		MaxModuleDepth:          5,
+		MaxModuleDepth:          RustMaxModuleDepth,
 		MaxFileLines:            500,
+		MaxTupleArity:           RustMaxTupleArity,
+		DetectUnusedPublicAPI:   false,
 		
 		// Naming conventions
 		EnforceSnakeCase:        true,
@@ -220,6 +349,11 @@ func DefaultRustDetectorConfig() *RustDetectorConfig {
 		MaxTraitMethods:         8,
 		MaxAssociatedTypes:      4,
 		MaxComplexTraitParams:   2,
+		
+		// Enum analysis defaults
+		MaxEnumVariants:         RustMaxEnumVariants,
+		MaxErrorVariants:        RustMaxErrorVariants,
+		SkipEnumAttributes:      DefaultSkipEnumAttributes(),
 	}
 }
 
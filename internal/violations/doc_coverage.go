@@ -0,0 +1,156 @@
+package violations
+
+import (
+	"go/ast"
+	"go/token"
+	"strings"
+	"sync"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+// DocCoverageDetector counts the public items of every file and those with
+// documentation, for the documentation coverage of the reports. Public items
+// are exported Go functions, methods and types, and the Rust functions,
+// structs, enums, traits, modules, constants and macros the documentation
+// detector checks. It reports no violations itself.
+type DocCoverageDetector struct {
+	config *DetectorConfig
+	mutex  sync.Mutex
+	files  []models.FileDocCoverage
+}
+
+// NewDocCoverageDetector creates a new documentation coverage detector
+func NewDocCoverageDetector(config *DetectorConfig) *DocCoverageDetector {
+	if config == nil {
+		config = DefaultDetectorConfig()
+	}
+	return &DocCoverageDetector{config: config}
+}
+
+// Name returns the name of this detector
+func (d *DocCoverageDetector) Name() string {
+	return "Documentation Coverage"
+}
+
+// Description returns a description of what this detector checks for
+func (d *DocCoverageDetector) Description() string {
+	return "Computes the share of public items with documentation, by module and in total"
+}
+
+// Detect records the public and documented items of a file
+func (d *DocCoverageDetector) Detect(fileInfo *models.FileInfo, astInfo interface{}) []*models.Violation {
+	file := models.FileDocCoverage{File: fileInfo.Path}
+	switch info := astInfo.(type) {
+	case *types.GoASTInfo:
+		if info == nil || info.AST == nil || strings.HasSuffix(fileInfo.Path, "_test.go") {
+			return nil
+		}
+		countGoDocs(&file, info.AST)
+	case *types.RustASTInfo:
+		if info == nil {
+			return nil
+		}
+		d.countRustDocs(&file, info)
+	default:
+		return nil
+	}
+
+	if file.Public > 0 {
+		d.record(file)
+	}
+	return nil
+}
+
+// countGoDocs counts the exported functions, methods and types of a Go file
+func countGoDocs(file *models.FileDocCoverage, root *ast.File) {
+	for _, decl := range root.Decls {
+		switch decl := decl.(type) {
+		case *ast.FuncDecl:
+			if decl.Name.IsExported() {
+				countDoc(file, decl.Doc != nil)
+			}
+		case *ast.GenDecl:
+			if decl.Tok != token.TYPE {
+				continue
+			}
+			for _, spec := range decl.Specs {
+				if typeSpec, ok := spec.(*ast.TypeSpec); ok && typeSpec.Name.IsExported() {
+					countDoc(file, typeSpec.Doc != nil || decl.Doc != nil)
+				}
+			}
+		}
+	}
+}
+
+// countRustDocs counts the public items of a Rust file that require
+// documentation, honouring skip_internal_docs
+func (d *DocCoverageDetector) countRustDocs(file *models.FileDocCoverage, info *types.RustASTInfo) {
+	for _, fn := range info.Functions {
+		if fn != nil && requiresRustDocs(d.config, fn.IsPublic, fn.Visibility) {
+			countDoc(file, fn.HasDocComments)
+		}
+	}
+	for _, item := range info.Structs {
+		if item != nil && requiresRustDocs(d.config, item.IsPublic, item.Visibility) {
+			countDoc(file, item.HasDocComments)
+		}
+	}
+	for _, item := range info.Enums {
+		if item != nil && requiresRustDocs(d.config, item.IsPublic, item.Visibility) {
+			countDoc(file, item.HasDocComments)
+		}
+	}
+	for _, item := range info.Traits {
+		if item != nil && requiresRustDocs(d.config, item.IsPublic, item.Visibility) {
+			countDoc(file, item.HasDocComments)
+		}
+	}
+	for _, item := range info.Modules {
+		if item != nil && requiresRustDocs(d.config, item.IsPublic, item.Visibility) {
+			countDoc(file, item.HasDocComments)
+		}
+	}
+	for _, item := range info.Constants {
+		if item != nil && requiresRustDocs(d.config, item.IsPublic, item.Visibility) {
+			countDoc(file, item.HasDocComments)
+		}
+	}
+	for _, item := range info.Macros {
+		if item != nil && item.IsPublic {
+			countDoc(file, item.HasDocComments)
+		}
+	}
+}
+
+// countDoc counts one public item
+func countDoc(file *models.FileDocCoverage, documented bool) {
+	file.Public++
+	if documented {
+		file.Documented++
+	}
+}
+
+// record adds the counts of a file
+func (d *DocCoverageDetector) record(file models.FileDocCoverage) {
+	d.mutex.Lock()
+	defer d.mutex.Unlock()
+	d.files = append(d.files, file)
+}
+
+// Reset clears the recorded files; call it at the start of each scan
+func (d *DocCoverageDetector) Reset() {
+	d.mutex.Lock()
+	defer d.mutex.Unlock()
+	d.files = nil
+}
+
+// Coverage returns the documentation coverage of the files seen since the
+// last Reset, or nil when none had public items
+func (d *DocCoverageDetector) Coverage() *models.DocCoverage {
+	d.mutex.Lock()
+	defer d.mutex.Unlock()
+
+	return models.ComputeDocCoverage(d.files)
+}
@@ -0,0 +1,63 @@
+package violations
+
+import (
+	"go/parser"
+	"go/token"
+	"testing"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+func TestDocCoverageDetector(t *testing.T) {
+	src := `package shapes
+
+// Shape is anything with an area
+type Shape interface{ Area() float64 }
+
+type Square struct{ Side float64 }
+
+// Area returns the area of the square
+func (s Square) Area() float64 { return s.Side * s.Side }
+
+func NewSquare(side float64) Square { return Square{side} }
+
+func helper() {}
+`
+	fset := token.NewFileSet()
+	file, err := parser.ParseFile(fset, "shapes.go", src, parser.ParseComments)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	config := DefaultDetectorConfig()
+	detector := NewDocCoverageDetector(config)
+	detector.Detect(&models.FileInfo{Path: "pkg/shapes/shapes.go"}, &types.GoASTInfo{AST: file, FileSet: fset})
+	detector.Detect(&models.FileInfo{Path: "pkg/shapes/shapes_test.go"}, &types.GoASTInfo{AST: file, FileSet: fset})
+
+	rust := &types.RustASTInfo{
+		Functions: []*types.RustFunctionInfo{
+			{Name: "parse", IsPublic: true, Visibility: "pub", HasDocComments: true},
+			{Name: "tokens", IsPublic: true, Visibility: "pub(crate)"},
+			{Name: "helper", Visibility: "private"},
+		},
+		Structs: []*types.RustStructInfo{{Name: "Parser", IsPublic: true, Visibility: "pub"}},
+		Macros:  []*types.RustMacroInfo{{Name: "token", IsPublic: true, HasDocComments: true}},
+	}
+	detector.Detect(&models.FileInfo{Path: "src/parser.rs"}, rust)
+
+	coverage := detector.Coverage()
+	if coverage == nil || coverage.Documented != 4 || coverage.Public != 8 || coverage.Percent != 50 {
+		t.Fatalf("Expected 4 of 8 public items documented, got %+v", coverage)
+	}
+	if len(coverage.Modules) != 2 || coverage.Modules[0].Module != "pkg/shapes" || coverage.Modules[0].Public != 4 {
+		t.Errorf("Expected the test file to be left out of pkg/shapes, got %+v", coverage.Modules)
+	}
+
+	config.RustConfig.SkipInternalDocs = true
+	detector.Reset()
+	detector.Detect(&models.FileInfo{Path: "src/parser.rs"}, rust)
+	if coverage := detector.Coverage(); coverage.Public != 3 {
+		t.Errorf("Expected pub(crate) items to be left out with skip_internal_docs, got %+v", coverage)
+	}
+}
@@ -111,6 +111,7 @@ func (d *DocumentationDetector) checkPackageDoc(file *ast.File, fset *token.File
 			Line:        pos.Line,
 			Column:      pos.Column,
 			Message:     fmt.Sprintf("Package '%s' is missing documentation", file.Name.Name),
+			Rule:        "missing-documentation",
 			Suggestion:  fmt.Sprintf("Add a package comment starting with 'Package %s ...'", file.Name.Name),
 			CodeSnippet: fmt.Sprintf("package %s", file.Name.Name),
 		}
@@ -129,6 +130,7 @@ func (d *DocumentationDetector) checkPackageDoc(file *ast.File, fset *token.File
 			Line:        pos.Line,
 			Column:      pos.Column,
 			Message:     "Package documentation should start with 'Package [name]'",
+			Rule:        "documentation-style",
 			Suggestion:  fmt.Sprintf("Update documentation to start with '%s'", expectedPrefix),
 			CodeSnippet: truncateString(doc, 50),
 		}
@@ -166,6 +168,7 @@ func (d *DocumentationDetector) checkFunctionDoc(fn *types.FunctionInfo, file *a
 			Line:        fn.StartLine,
 			Column:      0,
 			Message:     fmt.Sprintf("Exported function '%s' is missing documentation", fn.Name),
+			Rule:        "missing-documentation",
 			Suggestion:  fmt.Sprintf("Add a comment starting with '%s ...' before the function", fn.Name),
 			CodeSnippet: fmt.Sprintf("func %s(...)", fn.Name),
 		}
@@ -181,6 +184,7 @@ func (d *DocumentationDetector) checkFunctionDoc(fn *types.FunctionInfo, file *a
 			Line:        fn.StartLine - 1,
 			Column:      0,
 			Message:     fmt.Sprintf("Function documentation should start with the function name '%s'", fn.Name),
+			Rule:        "documentation-style",
 			Suggestion:  "Follow Go documentation conventions: comments should start with the name being documented",
 			CodeSnippet: truncateString(doc, 50),
 		}
@@ -207,6 +211,7 @@ func (d *DocumentationDetector) checkTypeDoc(genDecl *ast.GenDecl, typeSpec *ast
 			Line:        pos.Line,
 			Column:      pos.Column,
 			Message:     fmt.Sprintf("Exported type '%s' is missing documentation", typeName),
+			Rule:        "missing-documentation",
 			Suggestion:  fmt.Sprintf("Add a comment starting with '%s ...' before the type definition", typeName),
 			CodeSnippet: fmt.Sprintf("type %s ...", typeName),
 		}
@@ -224,6 +229,7 @@ func (d *DocumentationDetector) checkTypeDoc(genDecl *ast.GenDecl, typeSpec *ast
 			Line:        docPos.Line,
 			Column:      docPos.Column,
 			Message:     fmt.Sprintf("Type documentation should start with the type name '%s'", typeName),
+			Rule:        "documentation-style",
 			Suggestion:  "Follow Go documentation conventions: comments should start with the name being documented",
 			CodeSnippet: truncateString(doc, 50),
 		}
@@ -258,6 +264,7 @@ func (d *DocumentationDetector) checkMethodDoc(funcDecl *ast.FuncDecl, fset *tok
 			Line:        pos.Line,
 			Column:      pos.Column,
 			Message:     fmt.Sprintf("Exported method '%s' on '%s' is missing documentation", methodName, receiverType),
+			Rule:        "missing-documentation",
 			Suggestion:  fmt.Sprintf("Add a comment starting with '%s ...' before the method", methodName),
 			CodeSnippet: fmt.Sprintf("func (...) %s(...)", methodName),
 		}
@@ -275,6 +282,7 @@ func (d *DocumentationDetector) checkMethodDoc(funcDecl *ast.FuncDecl, fset *tok
 			Line:        docPos.Line,
 			Column:      docPos.Column,
 			Message:     fmt.Sprintf("Method documentation should start with the method name '%s'", methodName),
+			Rule:        "documentation-style",
 			Suggestion:  "Follow Go documentation conventions: comments should start with the name being documented",
 			CodeSnippet: truncateString(doc, 50),
 		}
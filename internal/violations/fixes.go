@@ -0,0 +1,224 @@
+package violations
+
+import (
+	"fmt"
+	"go/ast"
+	"go/token"
+	"strings"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+// renameSuggestion is the suggestion of naming violations that have a mechanical fix
+func renameSuggestion(name string) string {
+	return fmt.Sprintf("Rename to '%s'", name)
+}
+
+// addRenameFixes attaches a fix renaming oldName to newName to the violations
+// that suggest that rename. Identifier tokens between startLine and endLine
+// are renamed; zero bounds cover the whole file. Only tokens that can name
+// the binding are renamed: fields and methods after '.', paths of other
+// modules after '::' and keys of struct literals are left alone, and a
+// shorthand field such as `Point { x }` keeps its field name. No fix is
+// attached when the new name is already used in that range, when a path such
+// as `self::name` or `Self::name` may name the binding, or when a format
+// string captures it, as renaming those could break the code.
+func addRenameFixes(violations []*models.Violation, tokens []types.RustTokenInfo, oldName, newName string, startLine, endLine int) {
+	if oldName == newName || newName == "" {
+		return
+	}
+
+	var edits []models.TextEdit
+	var open []int // Indexes of the unclosed brackets
+	for i, token := range tokens {
+		if token.Kind == types.RustTokenPunct {
+			switch token.Text {
+			case "{", "(", "[":
+				open = append(open, i)
+			case "}", ")", "]":
+				if len(open) > 0 {
+					open = open[:len(open)-1]
+				}
+			}
+			continue
+		}
+		if (startLine > 0 && token.Line < startLine) || (endLine > 0 && token.Line > endLine) {
+			continue
+		}
+		if token.Kind == types.RustTokenLiteral && capturesFormatArgument(token.Text, oldName) {
+			return
+		}
+		if token.Kind != types.RustTokenIdent {
+			continue
+		}
+		if token.Text == newName {
+			return
+		}
+		if token.Text != oldName {
+			continue
+		}
+
+		newText := newName
+		prev, next := tokenText(tokens, i-1), tokenText(tokens, i+1)
+		switch {
+		case prev == ".":
+			continue // A field or method of some value
+		case prev == "::":
+			switch tokenText(tokens, i-2) {
+			case "self", "Self", "super", "crate":
+				return // May name the binding through its module or type
+			}
+			continue // An item of another module
+		case len(open) > 0 && tokens[open[len(open)-1]].Text == "{" && (prev == "{" || prev == ","):
+			if next == ":" {
+				continue // A field key of a struct literal, pattern or declaration
+			}
+			if (next == "}" || next == ",") && isRustTypeName(tokenText(tokens, open[len(open)-1]-1)) {
+				newText = oldName + ": " + newName // Keep the field name of the shorthand
+			}
+		}
+		edits = append(edits, models.TextEdit{
+			Line:      token.Line,
+			Column:    token.Column,
+			EndLine:   token.Line,
+			EndColumn: token.Column + len(oldName),
+			NewText:   newText,
+		})
+	}
+	if len(edits) == 0 {
+		return
+	}
+
+	for _, violation := range violations {
+		if violation.Suggestion == renameSuggestion(newName) {
+			violation.Fix = &models.Fix{
+				Description: fmt.Sprintf("Rename '%s' to '%s'", oldName, newName),
+				Edits:       edits,
+			}
+		}
+	}
+}
+
+// tokenText returns the text of the token at index i, or "" outside the tokens
+func tokenText(tokens []types.RustTokenInfo, i int) string {
+	if i < 0 || i >= len(tokens) {
+		return ""
+	}
+	return tokens[i].Text
+}
+
+// capturesFormatArgument reports whether a string literal may capture name
+// as an inline format argument, such as "{name}" or "{name:?}"
+func capturesFormatArgument(literal, name string) bool {
+	return strings.Contains(literal, "{"+name+"}") || strings.Contains(literal, "{"+name+":")
+}
+
+// hasOutOfLineModules reports whether the file declares modules in other
+// files, such as `mod tests;`. Those modules see the private items of the
+// file through super::, so renaming the items only here could break them.
+func hasOutOfLineModules(tokens []types.RustTokenInfo) bool {
+	for i := 0; i+2 < len(tokens); i++ {
+		if tokens[i].Text == "mod" && tokens[i+1].Kind == types.RustTokenIdent && tokens[i+2].Text == ";" {
+			return true
+		}
+	}
+	return false
+}
+
+// wholeLineRemovalFix returns a fix deleting the lines from startLine to
+// endLine, or nil when other code shares those lines. The start column and
+// the exclusive end column delimit the text to remove.
+func wholeLineRemovalFix(lines []string, startLine, startColumn, endLine, endColumn int, description string) *models.Fix {
+	if startLine < 1 || endLine > len(lines) || startLine > endLine {
+		return nil
+	}
+	first, last := lines[startLine-1], lines[endLine-1]
+	if startColumn < 1 || startColumn-1 > len(first) || endColumn-1 > len(last) || endColumn < 1 {
+		return nil
+	}
+	if strings.TrimSpace(first[:startColumn-1]) != "" || strings.TrimSpace(last[endColumn-1:]) != "" {
+		return nil
+	}
+	return &models.Fix{
+		Description: description,
+		Edits:       []models.TextEdit{models.DeleteLinesEdit(startLine, endLine)},
+	}
+}
+
+// httpStatusConstants maps HTTP status codes to their net/http constant names
+var httpStatusConstants = map[string]string{
+	"200": "StatusOK",
+	"201": "StatusCreated",
+	"202": "StatusAccepted",
+	"204": "StatusNoContent",
+	"301": "StatusMovedPermanently",
+	"302": "StatusFound",
+	"304": "StatusNotModified",
+	"307": "StatusTemporaryRedirect",
+	"308": "StatusPermanentRedirect",
+	"400": "StatusBadRequest",
+	"401": "StatusUnauthorized",
+	"403": "StatusForbidden",
+	"404": "StatusNotFound",
+	"405": "StatusMethodNotAllowed",
+	"409": "StatusConflict",
+	"410": "StatusGone",
+	"422": "StatusUnprocessableEntity",
+	"429": "StatusTooManyRequests",
+	"500": "StatusInternalServerError",
+	"501": "StatusNotImplemented",
+	"502": "StatusBadGateway",
+	"503": "StatusServiceUnavailable",
+	"504": "StatusGatewayTimeout",
+}
+
+// httpStatusFix returns a fix replacing an HTTP status code literal with its
+// net/http constant. Only literals passed to calls, compared, returned or
+// used as field values are replaced, where they are most likely status codes.
+func httpStatusFix(lit *ast.BasicLit, parent ast.Node, fset *token.FileSet, httpPackage string) *models.Fix {
+	constant, ok := httpStatusConstants[lit.Value]
+	if !ok {
+		return nil
+	}
+	switch p := parent.(type) {
+	case *ast.CallExpr, *ast.CaseClause, *ast.ReturnStmt, *ast.KeyValueExpr:
+	case *ast.BinaryExpr:
+		if p.Op != token.EQL && p.Op != token.NEQ {
+			return nil
+		}
+	default:
+		return nil
+	}
+
+	pos := fset.Position(lit.Pos())
+	replacement := httpPackage + "." + constant
+	return &models.Fix{
+		Description: fmt.Sprintf("Replace %s with %s", lit.Value, replacement),
+		Edits: []models.TextEdit{{
+			Line:      pos.Line,
+			Column:    pos.Column,
+			EndLine:   pos.Line,
+			EndColumn: pos.Column + len(lit.Value),
+			NewText:   replacement,
+		}},
+	}
+}
+
+// importedPackageName returns the name under which file imports path, or ""
+// when the package is not imported or only for its side effects
+func importedPackageName(file *ast.File, path string) string {
+	for _, spec := range file.Imports {
+		if strings.Trim(spec.Path.Value, "\"`") != path {
+			continue
+		}
+		if spec.Name == nil {
+			return path[strings.LastIndex(path, "/")+1:]
+		}
+		if spec.Name.Name == "_" || spec.Name.Name == "." {
+			return ""
+		}
+		return spec.Name.Name
+	}
+	return ""
+}
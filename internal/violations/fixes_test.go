@@ -0,0 +1,183 @@
+package violations
+
+import (
+	"go/parser"
+	"go/token"
+	"reflect"
+	"strings"
+	"testing"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+func TestRustNamingDetectorAddsRenameFixes(t *testing.T) {
+	ident := func(text string, line, column int) types.RustTokenInfo {
+		return types.RustTokenInfo{Kind: types.RustTokenIdent, Text: text, Line: line, Column: column}
+	}
+	astInfo := &types.RustASTInfo{
+		FilePath: "lib.rs",
+		Functions: []*types.RustFunctionInfo{{
+			Name:       "getValue",
+			StartLine:  1,
+			EndLine:    3,
+			Visibility: "private",
+			Parameters: []types.RustParameterInfo{{Name: "inputValue", Type: "u32"}},
+		}},
+		Tokens: []types.RustTokenInfo{
+			ident("getValue", 1, 4),
+			ident("inputValue", 1, 13),
+			ident("inputValue", 2, 5),
+			ident("getValue", 5, 5),
+			ident("inputValue", 6, 9), // Another binding outside the function
+		},
+	}
+
+	violations := NewRustNamingDetector(DefaultDetectorConfig()).Detect(&models.FileInfo{Path: "lib.rs"}, astInfo)
+
+	fixes := make(map[string][]models.TextEdit)
+	for _, v := range violations {
+		if v.Fix != nil {
+			fixes[v.Fix.Description] = v.Fix.Edits
+		}
+	}
+	expected := map[string][]models.TextEdit{
+		"Rename 'getValue' to 'get_value'": {
+			{Line: 1, Column: 4, EndLine: 1, EndColumn: 12, NewText: "get_value"},
+			{Line: 5, Column: 5, EndLine: 5, EndColumn: 13, NewText: "get_value"},
+		},
+		"Rename 'inputValue' to 'input_value'": {
+			{Line: 1, Column: 13, EndLine: 1, EndColumn: 23, NewText: "input_value"},
+			{Line: 2, Column: 5, EndLine: 2, EndColumn: 15, NewText: "input_value"},
+		},
+	}
+	if !reflect.DeepEqual(fixes, expected) {
+		t.Errorf("Unexpected fixes: %+v", fixes)
+	}
+}
+
+func TestRustNamingDetectorSkipsPublicRenames(t *testing.T) {
+	astInfo := &types.RustASTInfo{
+		FilePath:  "lib.rs",
+		Functions: []*types.RustFunctionInfo{{Name: "getValue", StartLine: 1, EndLine: 3, IsPublic: true, Visibility: "pub"}},
+		Tokens:    []types.RustTokenInfo{{Kind: types.RustTokenIdent, Text: "getValue", Line: 1, Column: 8}},
+	}
+
+	for _, v := range NewRustNamingDetector(DefaultDetectorConfig()).Detect(&models.FileInfo{Path: "lib.rs"}, astInfo) {
+		if v.Fix != nil {
+			t.Errorf("Expected no fix for a public function, got %+v", v.Fix)
+		}
+	}
+}
+
+func TestAddRenameFixesOnlyRenamesTheBinding(t *testing.T) {
+	source := `fn build(myParam: u32, other: Other) -> Foo {
+    let total = myParam + other.myParam;
+    other::myParam();
+    Foo { myParam: myParam, count: total };
+    Foo { myParam }
+}`
+	violation := &models.Violation{Suggestion: renameSuggestion("my_param")}
+	addRenameFixes([]*models.Violation{violation}, rustTestTokens(source), "myParam", "my_param", 1, 6)
+
+	rename := func(line, column int, newText string) models.TextEdit {
+		return models.TextEdit{Line: line, Column: column, EndLine: line, EndColumn: column + len("myParam"), NewText: newText}
+	}
+	// The field access, the other module's item and the struct literal key are kept
+	expected := []models.TextEdit{
+		rename(1, 10, "my_param"),
+		rename(2, 17, "my_param"),
+		rename(4, 20, "my_param"),
+		rename(5, 11, "myParam: my_param"),
+	}
+	if violation.Fix == nil || !reflect.DeepEqual(violation.Fix.Edits, expected) {
+		t.Errorf("Expected only the uses of the parameter to be renamed, got %+v", violation.Fix)
+	}
+}
+
+func TestAddRenameFixesSkipsUnresolvableUses(t *testing.T) {
+	sources := map[string]string{
+		"format capture": "fn show(myParam: u32) {\n    println!(\"{myParam}\");\n}",
+		"Self path":      "const myLimit: u32 = 3;\nfn limit() -> u32 { Self::myLimit }",
+		"crate path":     "const myLimit: u32 = 3;\nfn limit() -> u32 { crate::myLimit }",
+	}
+	for name, source := range sources {
+		oldName, newName := "myParam", "my_param"
+		if strings.Contains(source, "myLimit") {
+			oldName, newName = "myLimit", "MY_LIMIT"
+		}
+		violation := &models.Violation{Suggestion: renameSuggestion(newName)}
+		addRenameFixes([]*models.Violation{violation}, rustTestTokens(source), oldName, newName, 0, 0)
+		if violation.Fix != nil {
+			t.Errorf("%s: expected no fix, got %+v", name, violation.Fix)
+		}
+	}
+}
+
+func TestRustNamingDetectorSkipsItemsVisibleToOtherFiles(t *testing.T) {
+	source := "mod tests;\n\nfn getValue() -> u32 { 1 }\n"
+	astInfo := &types.RustASTInfo{
+		FilePath:  "lib.rs",
+		Functions: []*types.RustFunctionInfo{{Name: "getValue", StartLine: 3, EndLine: 3, Visibility: "private"}},
+		Tokens:    rustTestTokens(source),
+	}
+
+	for _, v := range NewRustNamingDetector(DefaultDetectorConfig()).Detect(&models.FileInfo{Path: "lib.rs"}, astInfo) {
+		if v.Fix != nil {
+			t.Errorf("Expected no fix for an item a module in another file can use, got %+v", v.Fix)
+		}
+	}
+}
+
+func TestMagicNumberDetectorAddsHTTPStatusFix(t *testing.T) {
+	source := `package api
+
+import "net/http"
+
+func handle(w http.ResponseWriter, code int) int {
+	w.WriteHeader(404)
+	return code * 404
+}
+`
+	fset := token.NewFileSet()
+	astFile, err := parser.ParseFile(fset, "handler.go", source, parser.ParseComments)
+	if err != nil {
+		t.Fatalf("Failed to parse source: %v", err)
+	}
+
+	violations := NewMagicNumberDetector(DefaultDetectorConfig()).Detect(
+		&models.FileInfo{Path: "/test/api/handler.go", Language: "Go"},
+		&types.GoASTInfo{AST: astFile, FileSet: fset},
+	)
+
+	found := false
+	for _, v := range violations {
+		switch v.Line {
+		case 6:
+			found = true
+			expected := []models.TextEdit{{Line: 6, Column: 16, EndLine: 6, EndColumn: 19, NewText: "http.StatusNotFound"}}
+			if v.Fix == nil || !reflect.DeepEqual(v.Fix.Edits, expected) {
+				t.Errorf("Expected a fix replacing 404 with http.StatusNotFound, got %+v", v.Fix)
+			}
+		case 7:
+			if v.Fix != nil {
+				t.Errorf("Expected no fix for arithmetic with 404, got %+v", v.Fix)
+			}
+		}
+	}
+	if !found {
+		t.Error("Expected the status code passed to WriteHeader to be reported")
+	}
+}
+
+func TestWholeLineRemovalFix(t *testing.T) {
+	lines := []string{"x := 1", "\t// if y {", "\t// }", "z := 2 // return x()"}
+
+	fix := wholeLineRemovalFix(lines, 2, 2, 3, 6, "Delete")
+	if fix == nil || !reflect.DeepEqual(fix.Edits, []models.TextEdit{models.DeleteLinesEdit(2, 3)}) {
+		t.Errorf("Expected a fix deleting lines 2 to 3, got %+v", fix)
+	}
+	if fix := wholeLineRemovalFix(lines, 4, 8, 4, 21, "Delete"); fix != nil {
+		t.Errorf("Expected no fix for a comment after code, got %+v", fix)
+	}
+}
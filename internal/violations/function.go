@@ -116,7 +116,7 @@ func (d *FunctionDetector) checkFunction(fn *types.FunctionInfo, filePath string
 			Line:        fn.StartLine,
 			Column:      fn.StartColumn,
 			Rule:        "parameter-count",
-			Suggestion:  d.getParameterCountSuggestion(fn.Name, paramCount),
+			Suggestion:  d.getParameterCountSuggestion(fn),
 			CodeSnippet: codeSnippet,
 		})
 	}
@@ -308,10 +308,14 @@ func (d *FunctionDetector) getComplexitySuggestion(funcName string, complexity i
 		"using early returns, or simplifying conditional logic.", funcName, complexity)
 }
 
-func (d *FunctionDetector) getParameterCountSuggestion(funcName string, paramCount int) string {
+// getParameterCountSuggestion suggests a parameter struct in place of the
+// parameters of a function, with a nested struct for each set of parameters
+// whose names start with the same word
+func (d *FunctionDetector) getParameterCountSuggestion(fn *types.FunctionInfo) string {
+	groups := groupParameters(goParameterFields(fn.Parameters))
 	return fmt.Sprintf("Function '%s' has %d parameters. "+
-		"Consider grouping related parameters into a struct, "+
-		"using options pattern, or splitting the function.", funcName, paramCount)
+		"Consider passing them as a parameter struct, using the options pattern, "+
+		"or splitting the function:\n\n%s", fn.Name, len(fn.Parameters), goParameterStruct(fn.Name, fn.IsExported, groups))
 }
 
 func (d *FunctionDetector) getNestingDepthSuggestion(funcName string, depth int) string {
@@ -141,6 +141,39 @@ func TestFunctionDetector_TooManyParameters(t *testing.T) {
 		t.Error("Expected parameter count violation")
 	}
 }
+func TestFunctionDetector_ParameterStructSuggestion(t *testing.T) {
+	detector := NewFunctionDetector(&DetectorConfig{MaxParameters: 4})
+	astInfo := &types.GoASTInfo{
+		Functions: []*types.FunctionInfo{
+			{
+				Name:       "Connect",
+				StartLine:  10,
+				LineCount:  5,
+				IsExported: true,
+				Parameters: []types.ParameterInfo{
+					{Name: "fromHost", Type: "string"},
+					{Name: "fromPort", Type: "int"},
+					{Name: "retries", Type: "int"},
+					{Name: "toHost", Type: "string"},
+					{Name: "toPort", Type: "int"},
+					{Name: "verbose", Type: "bool"},
+					{Name: "attempts", Type: "int"},
+				},
+			},
+		},
+	}
+	violations := detector.Detect(&models.FileInfo{Path: "test.go"}, astInfo)
+	if len(violations) != 1 {
+		t.Fatalf("Expected 1 parameter count violation, got %+v", violations)
+	}
+	want := "Function 'Connect' has 7 parameters. Consider passing them as a parameter struct, using the options pattern, or splitting the function:\n\n" +
+		"type FromParams struct {\n\tHost string\n\tPort int\n}\n\n" +
+		"type ToParams struct {\n\tHost string\n\tPort int\n}\n\n" +
+		"type ConnectParams struct {\n\tFrom     FromParams\n\tRetries  int\n\tAttempts int\n\tTo       ToParams\n\tVerbose  bool\n}"
+	if violations[0].Suggestion != want {
+		t.Errorf("Expected suggestion\n%s\ngot\n%s", want, violations[0].Suggestion)
+	}
+}
 func TestFunctionDetector_MissingDocumentation(t *testing.T) {
 	config := &DetectorConfig{
 		RequireCommentsForPublic: true,
@@ -0,0 +1,192 @@
+package violations
+
+import (
+	"fmt"
+	"sort"
+	"strings"
+	"unicode"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+// Rules of the god struct check
+const (
+	GodStruct     = "god-struct"
+	RustGodStruct = "rust-god-struct"
+)
+
+// fieldCluster is a group of fields whose names share their first word,
+// such as db_connection and db_timeout
+type fieldCluster struct {
+	prefix string
+	fields []string
+}
+
+// godStruct describes a struct measured by the god struct check
+type godStruct struct {
+	name      string
+	fields    []string
+	methods   int
+	line      int
+	column    int
+	endLine   int
+	endColumn int
+}
+
+// godStructEnabled reports whether both god struct thresholds are set
+func godStructEnabled(config *DetectorConfig) bool {
+	return config.MaxGodStructFields > 0 && config.MaxGodStructMethods > 0
+}
+
+// godStructViolation reports a struct with more fields and more methods than
+// allowed, or returns nil. Holding much state and much behavior together is
+// the mark of a type with several responsibilities, which the clusters of
+// its field names often show.
+func godStructViolation(config *DetectorConfig, s godStruct, rule, filePath string) *models.Violation {
+	maxFields, maxMethods := config.MaxGodStructFields, config.MaxGodStructMethods
+	if !godStructEnabled(config) || len(s.fields) <= maxFields || s.methods <= maxMethods {
+		return nil
+	}
+
+	severity := models.SeverityMedium
+	if len(s.fields) >= maxFields*2 || s.methods >= maxMethods*2 {
+		severity = models.SeverityHigh
+	}
+
+	suggestion := fmt.Sprintf("Split '%s' into smaller types, each owning the fields and methods of one responsibility", s.name)
+	if clusters := responsibilityClusters(s.fields); len(clusters) > 1 {
+		described := make([]string, len(clusters))
+		for i, cluster := range clusters {
+			described[i] = fmt.Sprintf("%s (%d)", cluster.prefix, len(cluster.fields))
+		}
+		suggestion = fmt.Sprintf("Its fields form %d responsibility clusters: %s. Consider extracting each cluster into its own type",
+			len(clusters), strings.Join(described, ", "))
+	}
+
+	return &models.Violation{
+		Type:        models.ViolationTypeClassSize,
+		Severity:    severity,
+		Message:     fmt.Sprintf("Struct '%s' has %d fields and %d methods (max: %d fields, %d methods), potential God object", s.name, len(s.fields), s.methods, maxFields, maxMethods),
+		File:        filePath,
+		Line:        s.line,
+		Column:      s.column,
+		EndLine:     s.endLine,
+		EndColumn:   s.endColumn,
+		Rule:        rule,
+		Suggestion:  suggestion,
+		CodeSnippet: fmt.Sprintf("struct %s { /* %d fields, %d methods */ }", s.name, len(s.fields), s.methods),
+	}
+}
+
+// responsibilityClusters groups field names by their first word and returns
+// the groups of two or more fields, largest first. A plural first word joins
+// its singular, so users and user_sessions form one group.
+func responsibilityClusters(fields []string) []fieldCluster {
+	words := make(map[string]bool)
+	for _, field := range fields {
+		words[fieldFirstWord(field)] = true
+	}
+
+	var clusters []*fieldCluster
+	byPrefix := make(map[string]*fieldCluster)
+	for _, field := range fields {
+		prefix := fieldFirstWord(field)
+		if singular := strings.TrimSuffix(prefix, "s"); singular != prefix && words[singular] {
+			prefix = singular
+		}
+		cluster, ok := byPrefix[prefix]
+		if !ok {
+			cluster = &fieldCluster{prefix: prefix}
+			byPrefix[prefix] = cluster
+			clusters = append(clusters, cluster)
+		}
+		cluster.fields = append(cluster.fields, field)
+	}
+
+	var result []fieldCluster
+	for _, cluster := range clusters {
+		if len(cluster.fields) > 1 && cluster.prefix != "" {
+			result = append(result, *cluster)
+		}
+	}
+	sort.SliceStable(result, func(i, j int) bool { return len(result[i].fields) > len(result[j].fields) })
+	return result
+}
+
+// fieldFirstWord returns the first word of a snake_case or camelCase field
+// name in lower case: "db" for db_pool_size, dbPoolSize and DBPoolSize
+func fieldFirstWord(name string) string {
+	name = strings.TrimLeft(name, "_")
+	if underscore := strings.Index(name, "_"); underscore >= 0 {
+		return strings.ToLower(name[:underscore])
+	}
+
+	runes := []rune(name)
+	for i := 1; i < len(runes); i++ {
+		if !unicode.IsUpper(runes[i]) {
+			continue
+		}
+		// A capital starts a word after a lower-case letter, or ends an acronym before one
+		if !unicode.IsUpper(runes[i-1]) || (i+1 < len(runes) && unicode.IsLower(runes[i+1])) {
+			return strings.ToLower(string(runes[:i]))
+		}
+	}
+	return strings.ToLower(name)
+}
+
+// rustStructFieldNames returns the field names of a struct with named fields,
+// reading the tokens from its `struct Name {` header to the closing brace
+func rustStructFieldNames(tokens []types.RustTokenInfo, structInfo *types.RustStructInfo) []string {
+	start := -1
+	for i := 0; i+1 < len(tokens); i++ {
+		if tokens[i].Line == structInfo.StartLine && tokens[i].Text == "struct" && tokens[i+1].Text == structInfo.Name {
+			start = i + 2
+			break
+		}
+	}
+	if start < 0 {
+		return nil
+	}
+
+	var fields []string
+	depth := 0
+	for i := start; i < len(tokens); i++ {
+		text := tokens[i].Text
+		switch {
+		case text == ";" && depth == 0:
+			return fields // A unit or tuple struct
+		case isRustOpenDelimiter(text):
+			if depth == 0 && text != "{" {
+				return fields
+			}
+			depth++
+		case isRustCloseDelimiter(text):
+			depth--
+			if depth == 0 {
+				return fields
+			}
+		case depth == 1 && tokens[i].Kind == types.RustTokenIdent && i+1 < len(tokens) && tokens[i+1].Text == ":":
+			fields = append(fields, text)
+		}
+	}
+	return fields
+}
+
+// rustInherentMethodCount adds up the methods of the inherent impl blocks of a type
+func rustInherentMethodCount(impls []*types.RustImplInfo, typeName string) int {
+	methods := 0
+	for _, impl := range impls {
+		if impl == nil || impl.TraitName != "" {
+			continue
+		}
+		target := impl.TargetType
+		if generic := strings.Index(target, "<"); generic >= 0 {
+			target = target[:generic]
+		}
+		if strings.TrimSpace(target) == typeName {
+			methods += impl.MethodCount
+		}
+	}
+	return methods
+}
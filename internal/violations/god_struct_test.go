@@ -0,0 +1,144 @@
+package violations
+
+import (
+	"reflect"
+	"strings"
+	"testing"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+func TestResponsibilityClusters(t *testing.T) {
+	fields := []string{
+		"db_connection", "db_pool_size", "users", "user_sessions", "user_permissions",
+		"log_level", "log_file", "request_count", "smtpHost", "smtpPort", "cache_ttl",
+	}
+	clusters := responsibilityClusters(fields)
+
+	var described []string
+	for _, cluster := range clusters {
+		described = append(described, cluster.prefix+":"+strings.Join(cluster.fields, ","))
+	}
+	expected := []string{
+		"user:users,user_sessions,user_permissions",
+		"db:db_connection,db_pool_size",
+		"log:log_level,log_file",
+		"smtp:smtpHost,smtpPort",
+	}
+	if !reflect.DeepEqual(described, expected) {
+		t.Errorf("Expected clusters %v, got %v", expected, described)
+	}
+}
+
+func TestFieldFirstWord(t *testing.T) {
+	tests := map[string]string{
+		"db_pool_size": "db",
+		"dbPoolSize":   "db",
+		"DBPoolSize":   "db",
+		"_private_key": "private",
+		"Logger":       "logger",
+		"HTTPClient":   "http",
+	}
+	for name, expected := range tests {
+		if word := fieldFirstWord(name); word != expected {
+			t.Errorf("fieldFirstWord(%q) = %q, expected %q", name, word, expected)
+		}
+	}
+}
+
+func TestStructureDetector_GodStruct(t *testing.T) {
+	code := `package main
+
+type Manager struct {
+	dbConn    string
+	dbTimeout int
+	cacheSize int
+	cacheTTL  int
+	Logger
+}
+
+func (m *Manager) Connect()    {}
+func (m *Manager) Disconnect() {}
+func (m *Manager) Get()        {}
+`
+	astInfo := parseGoCode(t, code)
+	config := DefaultDetectorConfig()
+	detector := NewStructureDetector(config)
+
+	if found := godStructRules(detector.Detect(&models.FileInfo{Path: "manager.go"}, astInfo)); len(found) != 0 {
+		t.Fatalf("Expected god structs to be disabled by default, got %+v", found)
+	}
+
+	config.MaxGodStructFields = 4
+	config.MaxGodStructMethods = 2
+	found := godStructRules(detector.Detect(&models.FileInfo{Path: "manager.go"}, astInfo))
+	if len(found) != 1 {
+		t.Fatalf("Expected 1 god struct violation, got %+v", found)
+	}
+	violation := found[0]
+	if violation.Rule != GodStruct || violation.Line != 3 || violation.Severity != models.SeverityMedium {
+		t.Errorf("Unexpected violation %+v", violation)
+	}
+	if violation.Message != "Struct 'Manager' has 5 fields and 3 methods (max: 4 fields, 2 methods), potential God object" {
+		t.Errorf("Unexpected message %q", violation.Message)
+	}
+	if !strings.HasPrefix(violation.Suggestion, "Its fields form 2 responsibility clusters: db (2), cache (2).") {
+		t.Errorf("Unexpected suggestion %q", violation.Suggestion)
+	}
+}
+
+func TestRustStructureDetector_GodStruct(t *testing.T) {
+	source := `pub struct ApplicationManager {
+    db_connection: String,
+    db_pool_size: usize,
+    pub(crate) log_level: String,
+    log_file: String,
+    handlers: HashMap<String, Box<dyn Fn(&str) -> Result<(), Error>>>,
+}
+
+struct Point(u32, u32);`
+	astInfo := &types.RustASTInfo{
+		Tokens: rustTestTokens(source),
+		Structs: []*types.RustStructInfo{
+			{Name: "ApplicationManager", StartLine: 1, EndLine: 7, StartColumn: 1, FieldCount: 5},
+			{Name: "Point", StartLine: 9, EndLine: 9, StartColumn: 1},
+		},
+		Impls: []*types.RustImplInfo{
+			{TargetType: "ApplicationManager", MethodCount: 30},
+			{TargetType: "ApplicationManager<T>", MethodCount: 2},
+			{TargetType: "ApplicationManager", TraitName: "Default", MethodCount: 1},
+		},
+	}
+
+	if fields := rustStructFieldNames(astInfo.Tokens, astInfo.Structs[0]); !reflect.DeepEqual(fields,
+		[]string{"db_connection", "db_pool_size", "log_level", "log_file", "handlers"}) {
+		t.Errorf("Unexpected field names %v", fields)
+	}
+	if fields := rustStructFieldNames(astInfo.Tokens, astInfo.Structs[1]); len(fields) != 0 {
+		t.Errorf("Expected no named fields in a tuple struct, got %v", fields)
+	}
+	if methods := rustInherentMethodCount(astInfo.Impls, "ApplicationManager"); methods != 32 {
+		t.Errorf("Expected 32 inherent methods, got %d", methods)
+	}
+
+	config := DefaultDetectorConfig()
+	config.MaxGodStructFields = 4
+	config.MaxGodStructMethods = 15
+	violations := NewRustStructureDetector(config).checkStructComplexity(astInfo, "src/app.rs")
+	found := godStructRules(violations)
+	if len(found) != 1 || found[0].Rule != RustGodStruct || found[0].Severity != models.SeverityHigh {
+		t.Fatalf("Expected 1 high severity god struct violation, got %+v", found)
+	}
+}
+
+// godStructRules returns the god struct violations among others
+func godStructRules(violations []*models.Violation) []*models.Violation {
+	var found []*models.Violation
+	for _, v := range violations {
+		if v.Rule == GodStruct || v.Rule == RustGodStruct {
+			found = append(found, v)
+		}
+	}
+	return found
+}
@@ -0,0 +1,100 @@
+package violations
+
+import (
+	"fmt"
+	"regexp"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+)
+
+// groupedValuePattern matches the parts of a message that differ between
+// near-identical findings: quoted text and numbers
+var groupedValuePattern = regexp.MustCompile("'[^']*'|\"[^\"]*\"|`[^`]*`|0[xXbBoO][0-9a-fA-F_]+|[0-9][0-9_.]*")
+
+// GroupingConfig collapses repeated findings of a rule in one file, such as
+// the magic numbers of a lookup table, into a single grouped violation
+type GroupingConfig struct {
+	MinOccurrences int            // Findings that form a group for every rule; 0 never groups
+	Rules          map[string]int // Per-rule thresholds overriding MinOccurrences; 0 never groups the rule
+}
+
+// threshold returns the number of findings of a rule that form a group, or 0
+func (c *GroupingConfig) threshold(rule string) int {
+	if threshold, found := c.Rules[rule]; found {
+		return threshold
+	}
+	return c.MinOccurrences
+}
+
+// Group collapses the violations of one file that share a rule and a message
+// up to quoted values and numbers, when there are at least as many as the
+// threshold of the rule. A group is reported in place of its first finding,
+// at the highest severity of its members and with their total remediation
+// time, and lists every finding as an occurrence.
+func (c *GroupingConfig) Group(violations []*models.Violation) []*models.Violation {
+	if c.MinOccurrences == 0 && len(c.Rules) == 0 {
+		return violations
+	}
+
+	members := make(map[string][]*models.Violation)
+	for _, violation := range violations {
+		if c.threshold(violation.Rule) > 1 {
+			key := groupKey(violation)
+			members[key] = append(members[key], violation)
+		}
+	}
+
+	grouped := make([]*models.Violation, 0, len(violations))
+	emitted := make(map[string]bool)
+	for _, violation := range violations {
+		threshold := c.threshold(violation.Rule)
+		if threshold <= 1 {
+			grouped = append(grouped, violation)
+			continue
+		}
+		key := groupKey(violation)
+		group := members[key]
+		if len(group) < threshold {
+			grouped = append(grouped, violation)
+			continue
+		}
+		if !emitted[key] {
+			emitted[key] = true
+			grouped = append(grouped, groupedViolation(key, group))
+		}
+	}
+	return grouped
+}
+
+// groupKey identifies the near-identical findings of a rule
+func groupKey(violation *models.Violation) string {
+	return violation.Rule + "\x00" + string(violation.Type) + "\x00" + groupedValuePattern.ReplaceAllString(violation.Message, "_")
+}
+
+// groupedViolation reports a group of findings as one violation. Its
+// fingerprint does not depend on the number of findings or their values, so
+// the group keeps its identity as findings are added or removed.
+func groupedViolation(key string, group []*models.Violation) *models.Violation {
+	first := group[0]
+	violation := *first
+	violation.Message = fmt.Sprintf("%s (%d similar findings in this file)", first.Message, len(group))
+	violation.Fix = nil
+	violation.Occurrences = make([]models.Occurrence, 0, len(group))
+	violation.Remediation = 0
+	for _, member := range group {
+		violation.Severity = max(violation.Severity, member.Severity)
+		violation.Remediation += member.Remediation
+		violation.Occurrences = append(violation.Occurrences, models.Occurrence{
+			Line:    member.Line,
+			Column:  member.Column,
+			EndLine: member.EndLine,
+		})
+	}
+	violation.Fingerprint = models.ComputeFingerprint(&models.Violation{
+		Rule:    first.Rule,
+		Type:    first.Type,
+		File:    first.File,
+		Message: "group: " + key,
+	})
+	return &violation
+}
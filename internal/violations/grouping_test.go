@@ -0,0 +1,83 @@
+package violations
+
+import (
+	"fmt"
+	"testing"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+)
+
+// magicNumbers returns magic number findings on consecutive lines
+func magicNumbers(values ...int) []*models.Violation {
+	var found []*models.Violation
+	for i, value := range values {
+		found = append(found, &models.Violation{
+			Rule:        "magic-number",
+			Type:        models.ViolationTypeMagicNumber,
+			Severity:    models.SeverityLow,
+			File:        "table.go",
+			Line:        i + 10,
+			Column:      5,
+			Message:     fmt.Sprintf("Magic number '%d' detected", value),
+			Remediation: 5,
+		})
+	}
+	return found
+}
+
+func TestGroupCollapsesRepeatedFindings(t *testing.T) {
+	found := magicNumbers(3, 17, 42)
+	found[1].Severity = models.SeverityMedium
+	other := &models.Violation{Rule: "todo-comment", File: "table.go", Line: 1, Message: "TODO found"}
+	found = append([]*models.Violation{other}, found...)
+
+	config := &GroupingConfig{MinOccurrences: 3}
+	grouped := config.Group(found)
+	if len(grouped) != 2 || grouped[0] != other {
+		t.Fatalf("Expected the todo and one group, got %d violations", len(grouped))
+	}
+
+	group := grouped[1]
+	if group.Message != "Magic number '3' detected (3 similar findings in this file)" {
+		t.Errorf("Unexpected group message %q", group.Message)
+	}
+	if group.Severity != models.SeverityMedium {
+		t.Errorf("Expected the highest severity of the group, got %v", group.Severity)
+	}
+	if group.Remediation != 15 {
+		t.Errorf("Expected the total remediation time of the group, got %d", group.Remediation)
+	}
+	if len(group.Occurrences) != 3 || group.Occurrences[2].Line != 12 {
+		t.Errorf("Expected every finding as an occurrence, got %+v", group.Occurrences)
+	}
+	if found[1].Occurrences != nil {
+		t.Error("Expected the grouped findings to be left unchanged")
+	}
+
+	// Adding a finding keeps the identity of the group
+	regrouped := config.Group(magicNumbers(3, 17, 42, 99))
+	if len(regrouped) != 1 || regrouped[0].Fingerprint != group.Fingerprint {
+		t.Errorf("Expected a stable group fingerprint, got %+v", regrouped)
+	}
+}
+
+func TestGroupThresholds(t *testing.T) {
+	tests := []struct {
+		name     string
+		config   GroupingConfig
+		expected int
+	}{
+		{"disabled", GroupingConfig{}, 3},
+		{"below threshold", GroupingConfig{MinOccurrences: 4}, 3},
+		{"rule threshold", GroupingConfig{Rules: map[string]int{"magic-number": 2}}, 1},
+		{"rule excluded", GroupingConfig{MinOccurrences: 2, Rules: map[string]int{"magic-number": 0}}, 3},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if grouped := tt.config.Group(magicNumbers(1, 2, 3)); len(grouped) != tt.expected {
+				t.Errorf("Expected %d violations, got %d", tt.expected, len(grouped))
+			}
+		})
+	}
+}
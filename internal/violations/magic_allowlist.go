@@ -0,0 +1,130 @@
+package violations
+
+import (
+	"math"
+	"path"
+	"path/filepath"
+	"strconv"
+	"strings"
+)
+
+// maxEpsilon is the largest float counted as an epsilon in comparisons
+const maxEpsilon = 1e-3
+
+// rustNumericSuffixes are the type suffixes of Rust numeric literals
+var rustNumericSuffixes = []string{
+	"u128", "i128", "usize", "isize", "u16", "u32", "u64", "i16", "i32", "i64", "f32", "f64", "u8", "i8",
+}
+
+// MagicNumberAllowlist sets the contexts in which the magic-number rules
+// allow numbers they would otherwise report. The zero value allows nothing
+// beyond the built-in acceptable values.
+type MagicNumberAllowlist struct {
+	InIndex     bool                // Index and slice bounds, as in buf[12]
+	InTests     bool                // Test files, and Rust files under tests/ or benches/
+	PowersOfTwo bool                // 16, 256, 65536 and so on
+	Epsilon     bool                // Small floats in comparisons, as in diff < 1e-9
+	Values      []string            // Numbers allowed in every file, such as "42" or "0x7f"
+	Files       map[string][]string // Numbers allowed in the files matching a glob
+}
+
+// allowsValue reports whether a numeric literal is allowed in a file
+// whatever its context. Values are compared by number, so "0x10" allows 16.
+func (a *MagicNumberAllowlist) allowsValue(literal, filePath string) bool {
+	value, ok := magicNumberValue(literal)
+	if !ok {
+		return false
+	}
+	if a.PowersOfTwo && isPowerOfTwo(value) {
+		return true
+	}
+	if allowlistContains(a.Values, value) {
+		return true
+	}
+	for pattern, values := range a.Files {
+		if matchesPathGlob(pattern, filePath) && allowlistContains(values, value) {
+			return true
+		}
+	}
+	return false
+}
+
+// allowsEpsilon reports whether a literal compared against is an allowed epsilon
+func (a *MagicNumberAllowlist) allowsEpsilon(literal string) bool {
+	if !a.Epsilon {
+		return false
+	}
+	value, ok := magicNumberValue(literal)
+	return ok && value != 0 && math.Abs(value) <= maxEpsilon
+}
+
+// allowsTestFile reports whether the numbers of a file are allowed as test code
+func (a *MagicNumberAllowlist) allowsTestFile(filePath string) bool {
+	if !a.InTests {
+		return false
+	}
+	normalized := filepath.ToSlash(filePath)
+	name := path.Base(normalized)
+	return strings.HasSuffix(name, "_test.go") || strings.HasSuffix(name, "_test.rs") || name == "tests.rs" ||
+		strings.Contains("/"+normalized, "/tests/") || strings.Contains("/"+normalized, "/benches/")
+}
+
+// allowlistContains reports whether one of the values equals value
+func allowlistContains(values []string, value float64) bool {
+	for _, allowed := range values {
+		if parsed, ok := magicNumberValue(allowed); ok && parsed == value {
+			return true
+		}
+	}
+	return false
+}
+
+// magicNumberValue parses a Go or Rust numeric literal, including hex,
+// octal and binary integers, digit separators and Rust type suffixes
+func magicNumberValue(literal string) (float64, bool) {
+	text := strings.ReplaceAll(strings.TrimSpace(literal), "_", "")
+	isHex := strings.HasPrefix(strings.ToLower(text), "0x")
+	for _, suffix := range rustNumericSuffixes {
+		// f32 and f64 are hex digits, so hex literals only carry integer suffixes
+		if strings.HasSuffix(text, suffix) && len(text) > len(suffix) && !(isHex && suffix[0] == 'f') {
+			text = strings.TrimSuffix(text, suffix)
+			break
+		}
+	}
+	if value, err := strconv.ParseInt(text, 0, 64); err == nil {
+		return float64(value), true
+	}
+	if value, err := strconv.ParseFloat(text, 64); err == nil {
+		return value, true
+	}
+	return 0, false
+}
+
+// isPowerOfTwo reports whether value is a power of two of at least 2
+func isPowerOfTwo(value float64) bool {
+	fraction, _ := math.Frexp(value)
+	return value >= 2 && fraction == 0.5
+}
+
+// matchesPathGlob matches a glob against a file path. Globs with a slash
+// match the end of the path, so "internal/geo/*.go" matches
+// "./internal/geo/earth.go", and other globs match the file name. As in
+// custom test patterns, "**" is read as "*".
+func matchesPathGlob(pattern, filePath string) bool {
+	glob := strings.ReplaceAll(filepath.ToSlash(pattern), "**", "*")
+	normalized := filepath.ToSlash(filePath)
+	if !strings.Contains(glob, "/") {
+		matched, _ := path.Match(glob, path.Base(normalized))
+		return matched
+	}
+	for {
+		if matched, _ := path.Match(glob, normalized); matched {
+			return true
+		}
+		slash := strings.Index(normalized, "/")
+		if slash < 0 {
+			return false
+		}
+		normalized = normalized[slash+1:]
+	}
+}
@@ -0,0 +1,130 @@
+package violations
+
+import (
+	"go/parser"
+	"go/token"
+	"testing"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+// magicNumberMessages returns the magic-number findings of Go source scanned as filePath
+func magicNumberMessages(t *testing.T, config *DetectorConfig, filePath, source string) []string {
+	t.Helper()
+	fset := token.NewFileSet()
+	astFile, err := parser.ParseFile(fset, "test.go", source, 0)
+	if err != nil {
+		t.Fatalf("Failed to parse source: %v", err)
+	}
+
+	var messages []string
+	detector := NewMagicNumberDetector(config)
+	for _, v := range detector.Detect(&models.FileInfo{Path: filePath}, &types.GoASTInfo{AST: astFile, FileSet: fset}) {
+		if v.Type == models.ViolationTypeMagicNumber {
+			messages = append(messages, v.CodeSnippet)
+		}
+	}
+	return messages
+}
+
+func TestMagicNumberAllowlistContexts(t *testing.T) {
+	source := `package geo
+
+func distance(points []float64, diff float64) float64 {
+	first := points[37]
+	window := points[13:29]
+	if diff < 0.000001 {
+		return 6371 * first * window[0]
+	}
+	return float64(256) * diff * 73
+}`
+
+	if found := magicNumberMessages(t, DefaultDetectorConfig(), "internal/geo/earth.go", source); len(found) != 7 {
+		t.Fatalf("Expected 7 magic numbers without an allowlist, got %v", found)
+	}
+
+	config := DefaultDetectorConfig()
+	config.MagicNumbers = MagicNumberAllowlist{
+		InIndex:     true,
+		PowersOfTwo: true,
+		Epsilon:     true,
+		Files:       map[string][]string{"internal/geo/*.go": {"6_371"}},
+	}
+	found := magicNumberMessages(t, config, "./internal/geo/earth.go", source)
+	if len(found) != 1 || found[0] != "73" {
+		t.Errorf("Expected only 73 to be reported, got %v", found)
+	}
+
+	// The per-file values only apply to the matching files
+	found = magicNumberMessages(t, config, "internal/maps/earth.go", source)
+	if len(found) != 2 || found[0] != "6371" {
+		t.Errorf("Expected 6371 and 73 outside internal/geo, got %v", found)
+	}
+}
+
+func TestMagicNumberAllowlistTests(t *testing.T) {
+	source := `package geo
+
+func TestDistance(t *testing.T) { check(t, 6371) }`
+
+	config := DefaultDetectorConfig()
+	if found := magicNumberMessages(t, config, "geo/earth_test.go", source); len(found) != 1 {
+		t.Fatalf("Expected the test file to be checked by default, got %v", found)
+	}
+	config.MagicNumbers.InTests = true
+	if found := magicNumberMessages(t, config, "geo/earth_test.go", source); len(found) != 0 {
+		t.Errorf("Expected test files to be allowed their numbers, got %v", found)
+	}
+
+	allowlist := MagicNumberAllowlist{InTests: true}
+	for _, path := range []string{"crate/tests/integration.rs", "src/parser/tests.rs", "benches/scan.rs"} {
+		if !allowlist.allowsTestFile(path) {
+			t.Errorf("Expected %s to be a test file", path)
+		}
+	}
+	if allowlist.allowsTestFile("src/testsuite.rs") {
+		t.Error("Expected src/testsuite.rs not to be a test file")
+	}
+}
+
+func TestMagicNumberValue(t *testing.T) {
+	tests := map[string]float64{
+		"42":      42,
+		"1_000":   1000,
+		"0x7f":    127,
+		"0xff_u8": 255,
+		"0o17":    15,
+		"0b1010":  10,
+		"64usize": 64,
+		"2.5f32":  2.5,
+		"1e-9":    1e-9,
+	}
+	for literal, expected := range tests {
+		if value, ok := magicNumberValue(literal); !ok || value != expected {
+			t.Errorf("magicNumberValue(%q) = %v, %v, expected %v", literal, value, ok, expected)
+		}
+	}
+	if _, ok := magicNumberValue("0xf64"); !ok {
+		t.Error("Expected 0xf64 to be read as a hex number")
+	}
+}
+
+func TestMatchesPathGlob(t *testing.T) {
+	tests := []struct {
+		pattern  string
+		path     string
+		expected bool
+	}{
+		{"internal/geo/*.go", "internal/geo/earth.go", true},
+		{"internal/geo/*.go", "/home/dev/project/internal/geo/earth.go", true},
+		{"internal/geo/*.go", "internal/geography/earth.go", false},
+		{"*_table.rs", "src/crc/crc_table.rs", true},
+		{"src/**/*.rs", "src/crc/crc_table.rs", true},
+	}
+	for _, tt := range tests {
+		if got := matchesPathGlob(tt.pattern, tt.path); got != tt.expected {
+			t.Errorf("matchesPathGlob(%q, %q) = %v, expected %v", tt.pattern, tt.path, got, tt.expected)
+		}
+	}
+}
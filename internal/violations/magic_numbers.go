@@ -36,6 +36,9 @@ type MagicNumberDetector struct {
 
 // NewMagicNumberDetector creates a new magic number detector
 func NewMagicNumberDetector(config *DetectorConfig) *MagicNumberDetector {
+	if config == nil {
+		config = DefaultDetectorConfig()
+	}
 	return &MagicNumberDetector{
 		config:        config,
 		codeExtractor: NewCodeExtractor(),
@@ -50,7 +53,7 @@ func (d *MagicNumberDetector) Name() string {
 
 // Description returns a description of what this detector checks for
 func (d *MagicNumberDetector) Description() string {
-	return "Detects hardcoded numeric literals that should be constants"
+	return "Detects hardcoded numeric literals, and repeated string literals, that should be constants"
 }
 
 // Detect analyzes the provided file information and returns violations
@@ -67,14 +70,28 @@ func (d *MagicNumberDetector) Detect(fileInfo *models.FileInfo, astInfo interfac
 		return violations
 	}
 	
+	// HTTP status codes can be replaced by net/http constants
+	httpPackage := importedPackageName(goAstInfo.AST, "net/http")
+	
+	// Test files may be allowed their numbers as a whole
+	countNumbers := !d.config.MagicNumbers.allowsTestFile(fileInfo.Path)
+	
 	// Walk the AST to find magic numbers with context awareness
 	// Use a custom visitor to track parent and grandparent nodes
 	d.walkWithFullContext(goAstInfo.AST, nil, nil, func(n ast.Node, parent ast.Node, grandparent ast.Node) bool {
 		switch x := n.(type) {
 		case *ast.BasicLit:
-			if x.Kind == token.INT || x.Kind == token.FLOAT {
+			if countNumbers && (x.Kind == token.INT || x.Kind == token.FLOAT) {
+				// A number that looks like a duration, size or port is reported as such
+				if violation := d.checkUnitLiteral(x, parent, grandparent, goAstInfo.FileSet, fileInfo.Path); violation != nil {
+					violations = append(violations, violation)
+					return true
+				}
 				// Check if this is a magic number with context
 				if violation := d.checkMagicNumberWithFullContext(x, parent, grandparent, goAstInfo.FileSet, fileInfo.Path); violation != nil {
+					if httpPackage != "" {
+						violation.Fix = httpStatusFix(x, parent, goAstInfo.FileSet, httpPackage)
+					}
 					violations = append(violations, violation)
 				}
 			}
@@ -82,6 +99,11 @@ func (d *MagicNumberDetector) Detect(fileInfo *models.FileInfo, astInfo interfac
 		return true
 	})
 	
+	if d.config.MaxStringRepeats > 0 {
+		literals := goStringLiterals(goAstInfo.AST, goAstInfo.FileSet)
+		violations = append(violations, magicStringViolations(literals, d.config.MaxStringRepeats, fileInfo.Path, "magic-string")...)
+	}
+	
 	return violations
 }
 
@@ -117,6 +139,11 @@ func (d *MagicNumberDetector) checkMagicNumberWithFullContext(lit *ast.BasicLit,
 		}
 	}
 	
+	// Check the configured allowlist
+	if d.isAllowedByConfig(lit, parent, filePath) {
+		return nil
+	}
+	
 	// Determine context for whitelist checking
 	context := d.determineContext(lit, parent, grandparent, filePath)
 	
@@ -136,6 +163,28 @@ func (d *MagicNumberDetector) checkMagicNumberWithFullContext(lit *ast.BasicLit,
 	return d.createMagicNumberViolation(lit, fset, filePath, context)
 }
 
+// isAllowedByConfig checks the configured allowlist: allowed values, powers
+// of two, index and slice bounds, and epsilons in comparisons
+func (d *MagicNumberDetector) isAllowedByConfig(lit *ast.BasicLit, parent ast.Node, filePath string) bool {
+	allowlist := &d.config.MagicNumbers
+	if allowlist.allowsValue(lit.Value, filePath) {
+		return true
+	}
+	
+	switch p := parent.(type) {
+	case *ast.IndexExpr:
+		return allowlist.InIndex && p.Index == lit
+	case *ast.SliceExpr:
+		return allowlist.InIndex && (p.Low == lit || p.High == lit || p.Max == lit)
+	case *ast.BinaryExpr:
+		switch p.Op {
+		case token.LSS, token.LEQ, token.GTR, token.GEQ:
+			return allowlist.allowsEpsilon(lit.Value)
+		}
+	}
+	return false
+}
+
 // checkMagicNumber checks if a literal is a magic number
 func (d *MagicNumberDetector) checkMagicNumber(lit *ast.BasicLit, fset *token.FileSet, filePath string) *models.Violation {
 	// Parse the value
@@ -176,6 +225,7 @@ func (d *MagicNumberDetector) checkMagicNumber(lit *ast.BasicLit, fset *token.Fi
 		Line:        pos.Line,
 		Column:      pos.Column,
 		Message:     fmt.Sprintf("Magic number '%s' detected", value),
+		Rule:        "magic-number",
 		Suggestion:  "Consider extracting this value to a named constant for better readability and maintainability",
 		CodeSnippet: codeSnippet,
 	}
@@ -591,6 +641,7 @@ func (d *MagicNumberDetector) createMagicNumberViolation(lit *ast.BasicLit, fset
 		Line:        pos.Line,
 		Column:      pos.Column,
 		Message:     fmt.Sprintf("Magic number '%s' detected%s", lit.Value, contextMsg),
+		Rule:        "magic-number",
 		Suggestion:  "Consider extracting this value to a named constant for better readability and maintainability",
 		CodeSnippet: codeSnippet,
 	}
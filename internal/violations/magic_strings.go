@@ -0,0 +1,228 @@
+package violations
+
+import (
+	"fmt"
+	"go/ast"
+	"go/token"
+	"regexp"
+	"sort"
+	"strconv"
+	"strings"
+	"unicode"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+// Kinds of suspicious string literals, reported from their second use
+const (
+	magicStringURL       = "URL"
+	magicStringStatus    = "status string"
+	magicStringConfigKey = "config key"
+)
+
+// minMagicStringLength is the shortest string literal counted as a magic string
+const minMagicStringLength = 3
+
+// magicStatusStrings are state and status values that belong in named
+// constants or an enum
+var magicStatusStrings = map[string]bool{
+	"active": true, "inactive": true, "pending": true, "running": true, "stopped": true,
+	"success": true, "succeeded": true, "failed": true, "failure": true, "error": true,
+	"done": true, "completed": true, "cancelled": true, "canceled": true,
+	"enabled": true, "disabled": true, "open": true, "closed": true,
+	"approved": true, "rejected": true, "draft": true, "published": true, "archived": true,
+}
+
+// magicConfigKeyPattern matches dotted, snake and kebab keys such as
+// "db.host", "max_retries" or "log-level"
+var magicConfigKeyPattern = regexp.MustCompile(`^[A-Za-z][A-Za-z0-9]*(?:[._-][A-Za-z0-9]+)+$`)
+
+// stringLiteral is a string literal of a source file with its position
+type stringLiteral struct {
+	value  string
+	line   int
+	column int
+}
+
+// magicStringViolations reports the string literals of a file used more
+// than maxRepeats times, and the suspicious ones (URLs, status strings and
+// config keys) used more than once. Each string is reported once, at its
+// first use. Prose, format strings and short strings are not counted.
+func magicStringViolations(literals []stringLiteral, maxRepeats int, filePath, rule string) []*models.Violation {
+	uses := make(map[string][]stringLiteral)
+	for _, literal := range literals {
+		if isMagicStringCandidate(literal.value) {
+			uses[literal.value] = append(uses[literal.value], literal)
+		}
+	}
+
+	var violations []*models.Violation
+	for value, found := range uses {
+		kind := magicStringKind(value)
+		limit := maxRepeats
+		if kind != "" {
+			limit = 1
+		}
+		if len(found) <= limit {
+			continue
+		}
+
+		described := fmt.Sprintf("String literal %q", value)
+		suggestion := "Extract the string to a named constant so its uses cannot drift apart"
+		switch kind {
+		case magicStringURL:
+			described = fmt.Sprintf("URL %q", value)
+			suggestion = "Move the URL to a named constant or to configuration"
+		case magicStringStatus, magicStringConfigKey:
+			described = fmt.Sprintf("String literal %q (%s)", value, kind)
+		}
+		first := found[0]
+		violations = append(violations, &models.Violation{
+			Type:        models.ViolationTypeMagicString,
+			Severity:    models.SeverityLow,
+			File:        filePath,
+			Line:        first.line,
+			Column:      first.column,
+			Message:     fmt.Sprintf("%s is used %d times in this file", described, len(found)),
+			Rule:        rule,
+			Suggestion:  suggestion,
+			CodeSnippet: strconv.Quote(value),
+		})
+	}
+
+	sort.Slice(violations, func(i, j int) bool {
+		if violations[i].Line != violations[j].Line {
+			return violations[i].Line < violations[j].Line
+		}
+		return violations[i].Column < violations[j].Column
+	})
+	return violations
+}
+
+// isMagicStringCandidate reports whether a string may be a magic string: a
+// single word of at least three characters with a letter, so messages,
+// separators and format strings are left alone
+func isMagicStringCandidate(value string) bool {
+	if len(value) < minMagicStringLength || strings.ContainsAny(value, "%{}") {
+		return false
+	}
+	hasLetter := false
+	for _, r := range value {
+		if unicode.IsSpace(r) {
+			return false
+		}
+		hasLetter = hasLetter || unicode.IsLetter(r)
+	}
+	return hasLetter
+}
+
+// magicStringKind returns the kind of a suspicious string, or "" for other strings
+func magicStringKind(value string) string {
+	switch {
+	case strings.Contains(value, "://"):
+		return magicStringURL
+	case magicStatusStrings[strings.ToLower(value)]:
+		return magicStringStatus
+	case magicConfigKeyPattern.MatchString(value):
+		return magicStringConfigKey
+	}
+	return ""
+}
+
+// goStringLiterals collects the string literals of a Go file outside
+// imports, struct tags and constant declarations
+func goStringLiterals(file *ast.File, fset *token.FileSet) []stringLiteral {
+	var literals []stringLiteral
+	tags := make(map[*ast.BasicLit]bool)
+	ast.Inspect(file, func(n ast.Node) bool {
+		switch x := n.(type) {
+		case *ast.ImportSpec:
+			return false
+		case *ast.GenDecl:
+			return x.Tok != token.CONST
+		case *ast.Field:
+			if x.Tag != nil {
+				tags[x.Tag] = true
+			}
+		case *ast.BasicLit:
+			if x.Kind != token.STRING || tags[x] {
+				return true
+			}
+			value, err := strconv.Unquote(x.Value)
+			if err != nil {
+				return true
+			}
+			pos := fset.Position(x.Pos())
+			literals = append(literals, stringLiteral{value: value, line: pos.Line, column: pos.Column})
+		}
+		return true
+	})
+	return literals
+}
+
+// rustStringLiterals collects the string literals of a Rust file outside
+// attributes and const and static items. Byte and C strings are skipped.
+func rustStringLiterals(tokens []types.RustTokenInfo) []stringLiteral {
+	var literals []stringLiteral
+	for i := 0; i < len(tokens); i++ {
+		tok := tokens[i]
+		switch {
+		case tok.Kind == types.RustTokenPunct && tok.Text == "#":
+			// Attributes such as #[serde(rename = "id")] name things by string
+			start := i + 1
+			if start < len(tokens) && tokens[start].Text == "!" {
+				start++
+			}
+			if start < len(tokens) && tokens[start].Text == "[" {
+				i = rustItemEnd(tokens, start, "]")
+			}
+		case tok.Kind == types.RustTokenKeyword && (tok.Text == "const" || tok.Text == "static"):
+			// A const fn is not a constant item
+			if i+1 < len(tokens) && tokens[i+1].Text != "fn" && tokens[i+1].Text != "unsafe" && tokens[i+1].Text != "async" {
+				i = rustItemEnd(tokens, i+1, ";")
+			}
+		case tok.Kind == types.RustTokenLiteral:
+			if value, ok := rustStringValue(tok.Text); ok {
+				literals = append(literals, stringLiteral{value: value, line: tok.Line, column: tok.Column})
+			}
+		}
+	}
+	return literals
+}
+
+// rustItemEnd returns the index of the first closing token outside nested
+// brackets from start, or the last index when there is none
+func rustItemEnd(tokens []types.RustTokenInfo, start int, closing string) int {
+	depth := 0
+	for i := start; i < len(tokens); i++ {
+		switch tokens[i].Text {
+		case "(", "[", "{":
+			depth++
+		case ")", "]", "}":
+			depth--
+		}
+		if tokens[i].Text == closing && depth <= 0 {
+			return i
+		}
+	}
+	return len(tokens) - 1
+}
+
+// rustStringValue returns the contents of a Rust string or raw string
+// literal, and false for other literals. Escapes are left as written.
+func rustStringValue(text string) (string, bool) {
+	if strings.HasPrefix(text, "r") {
+		hashes := len(text) - len(strings.TrimLeft(text[1:], "#")) - 1
+		body := strings.TrimPrefix(text[1+hashes:], `"`)
+		suffix := `"` + strings.Repeat("#", hashes)
+		if len(body) == len(text)-1-hashes || !strings.HasSuffix(body, suffix) {
+			return "", false
+		}
+		return strings.TrimSuffix(body, suffix), true
+	}
+	if len(text) >= 2 && text[0] == '"' && text[len(text)-1] == '"' {
+		return text[1 : len(text)-1], true
+	}
+	return "", false
+}
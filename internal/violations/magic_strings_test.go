@@ -0,0 +1,181 @@
+package violations
+
+import (
+	"go/parser"
+	"go/token"
+	"strings"
+	"testing"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+func TestMagicStringDetection_Go(t *testing.T) {
+	source := `package main
+
+import "fmt"
+
+const mode = "strict"
+
+type user struct {
+	Name string ` + "`json:\"name\"`" + `
+}
+
+func handle(kind string) {
+	if kind == "widget" || kind == "gadget" {
+		fmt.Println("widget")
+	}
+	fmt.Println("gadget", "widget")
+	fetch("https://example.com/api")
+	fetch("https://example.com/api")
+	setState("pending")
+	setState("pending")
+	fmt.Printf("%d items\n", 3)
+	fmt.Printf("%d items\n", 4)
+	fmt.Printf("%d items\n", 5)
+	fmt.Println("strict", "strict", "strict")
+}`
+	fset := token.NewFileSet()
+	astFile, err := parser.ParseFile(fset, "test.go", source, parser.ParseComments)
+	if err != nil {
+		t.Fatalf("Failed to parse source: %v", err)
+	}
+
+	config := DefaultDetectorConfig()
+	config.MaxStringRepeats = 2
+	detector := NewMagicNumberDetector(config)
+	fileInfo := &models.FileInfo{Path: "/test/example.go", Language: "Go"}
+	found := detector.Detect(fileInfo, &types.GoASTInfo{AST: astFile, FileSet: fset})
+
+	var messages []string
+	for _, v := range found {
+		if v.Type != models.ViolationTypeMagicString {
+			continue
+		}
+		if v.Rule != "magic-string" || v.Severity != models.SeverityLow {
+			t.Errorf("Unexpected rule or severity: %+v", v)
+		}
+		messages = append(messages, v.Message)
+	}
+
+	expected := []string{
+		`String literal "widget" is used 3 times in this file`,
+		`URL "https://example.com/api" is used 2 times in this file`,
+		`String literal "pending" (status string) is used 2 times in this file`,
+		`String literal "strict" is used 3 times in this file`,
+	}
+	if strings.Join(messages, "\n") != strings.Join(expected, "\n") {
+		t.Errorf("Expected violations\n%s\ngot\n%s", strings.Join(expected, "\n"), strings.Join(messages, "\n"))
+	}
+}
+
+func TestMagicStringDetection_DisabledByDefault(t *testing.T) {
+	source := `package main
+
+func f() { g("widget", "widget", "widget", "widget", "pending", "pending") }`
+	fset := token.NewFileSet()
+	astFile, err := parser.ParseFile(fset, "test.go", source, 0)
+	if err != nil {
+		t.Fatalf("Failed to parse source: %v", err)
+	}
+
+	detector := NewMagicNumberDetector(DefaultDetectorConfig())
+	for _, v := range detector.Detect(&models.FileInfo{Path: "a.go"}, &types.GoASTInfo{AST: astFile, FileSet: fset}) {
+		if v.Type == models.ViolationTypeMagicString {
+			t.Errorf("Expected no magic strings without magic_string_repeats, got %s", v.Message)
+		}
+	}
+}
+
+func TestRustStringLiterals(t *testing.T) {
+	tok := func(kind types.RustTokenKind, text string, line int) types.RustTokenInfo {
+		return types.RustTokenInfo{Kind: kind, Text: text, Line: line, Column: 1}
+	}
+	tokens := []types.RustTokenInfo{
+		// #[serde(rename = "id")]
+		tok(types.RustTokenPunct, "#", 1), tok(types.RustTokenPunct, "[", 1),
+		tok(types.RustTokenIdent, "serde", 1), tok(types.RustTokenPunct, "(", 1),
+		tok(types.RustTokenIdent, "rename", 1), tok(types.RustTokenPunct, "=", 1),
+		tok(types.RustTokenLiteral, `"id"`, 1), tok(types.RustTokenPunct, ")", 1),
+		tok(types.RustTokenPunct, "]", 1),
+		// const NAME: &str = "name";
+		tok(types.RustTokenKeyword, "const", 2), tok(types.RustTokenIdent, "NAME", 2),
+		tok(types.RustTokenPunct, ":", 2), tok(types.RustTokenPunct, "&", 2),
+		tok(types.RustTokenIdent, "str", 2), tok(types.RustTokenPunct, "=", 2),
+		tok(types.RustTokenLiteral, `"name"`, 2), tok(types.RustTokenPunct, ";", 2),
+		// const fn f() { g("open", r#"raw"#, b"bytes", 42) }
+		tok(types.RustTokenKeyword, "const", 3), tok(types.RustTokenKeyword, "fn", 3),
+		tok(types.RustTokenIdent, "f", 3), tok(types.RustTokenPunct, "(", 3),
+		tok(types.RustTokenPunct, ")", 3), tok(types.RustTokenPunct, "{", 3),
+		tok(types.RustTokenIdent, "g", 4), tok(types.RustTokenPunct, "(", 4),
+		tok(types.RustTokenLiteral, `"open"`, 4), tok(types.RustTokenPunct, ",", 4),
+		tok(types.RustTokenLiteral, `r#"raw"#`, 4), tok(types.RustTokenPunct, ",", 4),
+		tok(types.RustTokenLiteral, `b"bytes"`, 4), tok(types.RustTokenPunct, ",", 4),
+		tok(types.RustTokenLiteral, "42", 4), tok(types.RustTokenPunct, ")", 4),
+		tok(types.RustTokenPunct, "}", 5),
+	}
+
+	literals := rustStringLiterals(tokens)
+	if len(literals) != 2 || literals[0].value != "open" || literals[1].value != "raw" {
+		t.Errorf("Expected the open and raw strings only, got %+v", literals)
+	}
+}
+
+func TestRustStringValue(t *testing.T) {
+	tests := []struct {
+		text     string
+		expected string
+		ok       bool
+	}{
+		{`"plain"`, "plain", true},
+		{`r"raw"`, "raw", true},
+		{`r##"has "# inside"##`, `has "# inside`, true},
+		{`b"bytes"`, "", false},
+		{`'c'`, "", false},
+		{"42", "", false},
+	}
+
+	for _, tt := range tests {
+		value, ok := rustStringValue(tt.text)
+		if value != tt.expected || ok != tt.ok {
+			t.Errorf("rustStringValue(%s) = %q, %v, expected %q, %v", tt.text, value, ok, tt.expected, tt.ok)
+		}
+	}
+}
+
+func TestIsMagicStringCandidate(t *testing.T) {
+	tests := []struct {
+		value    string
+		expected bool
+	}{
+		{"widget", true},
+		{"db.host", true},
+		{"ok", false},
+		{"---", false},
+		{"hello world", false},
+		{"%s:%d", false},
+		{"{name}", false},
+	}
+
+	for _, tt := range tests {
+		if got := isMagicStringCandidate(tt.value); got != tt.expected {
+			t.Errorf("isMagicStringCandidate(%q) = %v, expected %v", tt.value, got, tt.expected)
+		}
+	}
+}
+
+func TestMagicStringKind(t *testing.T) {
+	tests := map[string]string{
+		"https://example.com": magicStringURL,
+		"Pending":             magicStringStatus,
+		"log-level":           magicStringConfigKey,
+		"db.host":             magicStringConfigKey,
+		"widget":              "",
+	}
+
+	for value, expected := range tests {
+		if got := magicStringKind(value); got != expected {
+			t.Errorf("magicStringKind(%q) = %q, expected %q", value, got, expected)
+		}
+	}
+}
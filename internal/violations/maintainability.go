@@ -0,0 +1,224 @@
+package violations
+
+import (
+	"fmt"
+	"math"
+	"os"
+	"sort"
+	"sync"
+
+	"github.com/ericfisherdev/goclean/internal/metrics"
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+// DefaultMinMaintainabilityIndex is the lowest maintainability index a
+// function may have
+const DefaultMinMaintainabilityIndex = 20
+
+// MaintainabilityDetector computes the Halstead measures and maintainability
+// index of every function, reports the functions below the index threshold
+// or above a Halstead threshold and records the metrics of each file for the
+// reports
+type MaintainabilityDetector struct {
+	config *DetectorConfig
+	mutex  sync.Mutex
+	files  []*models.FileMaintainability
+}
+
+// NewMaintainabilityDetector creates a new maintainability index detector
+func NewMaintainabilityDetector(config *DetectorConfig) *MaintainabilityDetector {
+	if config == nil {
+		config = DefaultDetectorConfig()
+	}
+	return &MaintainabilityDetector{config: config}
+}
+
+// Name returns the name of this detector
+func (d *MaintainabilityDetector) Name() string {
+	return "Maintainability Index"
+}
+
+// Description returns a description of what this detector checks for
+func (d *MaintainabilityDetector) Description() string {
+	return "Computes the Halstead measures and maintainability index of functions and files, from Halstead volume, cyclomatic complexity and lines of code"
+}
+
+// Detect reports the functions of a file whose maintainability index is
+// below the threshold or whose Halstead measures are above theirs, and
+// records the metrics of the file
+func (d *MaintainabilityDetector) Detect(fileInfo *models.FileInfo, astInfo interface{}) []*models.Violation {
+	var functions []models.FunctionMaintainability
+	rulePrefix := ""
+	switch info := astInfo.(type) {
+	case *types.GoASTInfo:
+		if info == nil {
+			return nil
+		}
+		src, err := os.ReadFile(fileInfo.Path)
+		if err != nil {
+			return nil
+		}
+		tokens := metrics.GoTokens(src)
+		for _, fn := range info.Functions {
+			if fn.ASTNode != nil && fn.ASTNode.Body == nil {
+				continue
+			}
+			functions = append(functions, functionMaintainability(tokens, fn.Name, fn.StartLine, fn.EndLine, fn.LineCount, fn.Complexity))
+		}
+	case *types.RustASTInfo:
+		if info == nil {
+			return nil
+		}
+		rulePrefix = "rust-"
+		tokens := metrics.RustTokens(info.Tokens)
+		for _, fn := range info.Functions {
+			functions = append(functions, functionMaintainability(tokens, fn.Name, fn.StartLine, fn.EndLine, fn.LineCount, fn.Complexity))
+		}
+	default:
+		return nil
+	}
+	if len(functions) == 0 {
+		return nil
+	}
+
+	var violations []*models.Violation
+	total := 0.0
+	for _, fn := range functions {
+		total += fn.Index
+		if fn.Index < float64(d.config.MinMaintainabilityIndex) {
+			violations = append(violations, d.maintainabilityViolation(fileInfo.Path, fn, rulePrefix+"maintainability-index"))
+		}
+		violations = append(violations, d.halsteadViolations(fileInfo.Path, fn, rulePrefix)...)
+	}
+	d.record(&models.FileMaintainability{
+		File:      fileInfo.Path,
+		Language:  fileInfo.Language,
+		Index:     round1(total / float64(len(functions))),
+		Functions: functions,
+	})
+	return violations
+}
+
+// functionMaintainability computes the index of a function from its tokens
+func functionMaintainability(tokens []metrics.Token, name string, startLine, endLine, lines, complexity int) models.FunctionMaintainability {
+	halstead := metrics.CountHalstead(tokens, startLine, endLine)
+	volume := halstead.Volume()
+	return models.FunctionMaintainability{
+		Name:       name,
+		Line:       startLine,
+		EndLine:    endLine,
+		Lines:      lines,
+		Complexity: complexity,
+		Volume:     round1(volume),
+		Index:      metrics.MaintainabilityIndex(volume, complexity, lines),
+		Halstead: models.HalsteadMetrics{
+			DistinctOperators: halstead.DistinctOperators,
+			DistinctOperands:  halstead.DistinctOperands,
+			Operators:         halstead.Operators,
+			Operands:          halstead.Operands,
+			Volume:            round1(volume),
+			Difficulty:        round1(halstead.Difficulty()),
+			Effort:            round1(halstead.Effort()),
+		},
+	}
+}
+
+// round1 rounds a metric to one decimal
+func round1(value float64) float64 {
+	return math.Round(value*10) / 10
+}
+
+// maintainabilityViolation reports a function that is hard to maintain
+func (d *MaintainabilityDetector) maintainabilityViolation(path string, fn models.FunctionMaintainability, rule string) *models.Violation {
+	threshold := float64(d.config.MinMaintainabilityIndex)
+	severity := models.SeverityLow
+	if fn.Index < threshold/2 {
+		severity = models.SeverityHigh
+	} else if fn.Index < threshold*0.75 {
+		severity = models.SeverityMedium
+	}
+
+	return &models.Violation{
+		Type:     models.ViolationTypeMaintainability,
+		Severity: severity,
+		Message: fmt.Sprintf("Function '%s' has a maintainability index of %.1f (min: %d): %d lines, complexity %d, Halstead volume %.0f",
+			fn.Name, fn.Index, d.config.MinMaintainabilityIndex, fn.Lines, fn.Complexity, fn.Volume),
+		File:       path,
+		Line:       fn.Line,
+		Column:     1,
+		EndLine:    fn.EndLine,
+		Rule:       rule,
+		Suggestion: fmt.Sprintf("Split '%s' into smaller functions with fewer branches; the index falls as length, complexity and volume grow", fn.Name),
+	}
+}
+
+// halsteadViolations reports the Halstead measures of a function above their
+// thresholds; a threshold of 0 disables the check
+func (d *MaintainabilityDetector) halsteadViolations(path string, fn models.FunctionMaintainability, rulePrefix string) []*models.Violation {
+	checks := []struct {
+		rule      string
+		measure   string
+		value     float64
+		threshold int
+	}{
+		{"halstead-volume", "volume", fn.Halstead.Volume, d.config.MaxHalsteadVolume},
+		{"halstead-difficulty", "difficulty", fn.Halstead.Difficulty, d.config.MaxHalsteadDifficulty},
+		{"halstead-effort", "effort", fn.Halstead.Effort, d.config.MaxHalsteadEffort},
+	}
+
+	var violations []*models.Violation
+	for _, check := range checks {
+		if check.threshold <= 0 || check.value <= float64(check.threshold) {
+			continue
+		}
+		threshold := float64(check.threshold)
+		severity := models.SeverityLow
+		if check.value > threshold*2 {
+			severity = models.SeverityHigh
+		} else if check.value > threshold*1.5 {
+			severity = models.SeverityMedium
+		}
+		violations = append(violations, &models.Violation{
+			Type:     models.ViolationTypeHalstead,
+			Severity: severity,
+			Message: fmt.Sprintf("Function '%s' has a Halstead %s of %.0f (max: %d): %d operators and %d operands, %d and %d distinct",
+				fn.Name, check.measure, check.value, check.threshold,
+				fn.Halstead.Operators, fn.Halstead.Operands, fn.Halstead.DistinctOperators, fn.Halstead.DistinctOperands),
+			File:       path,
+			Line:       fn.Line,
+			Column:     1,
+			EndLine:    fn.EndLine,
+			Rule:       rulePrefix + check.rule,
+			Suggestion: fmt.Sprintf("Split '%s' into smaller functions and name intermediate values; fewer distinct operators and repeated operands lower the %s", fn.Name, check.measure),
+		})
+	}
+	return violations
+}
+
+// record adds the index of a file
+func (d *MaintainabilityDetector) record(file *models.FileMaintainability) {
+	d.mutex.Lock()
+	defer d.mutex.Unlock()
+	d.files = append(d.files, file)
+}
+
+// Reset clears the recorded files; call it at the start of each scan
+func (d *MaintainabilityDetector) Reset() {
+	d.mutex.Lock()
+	defer d.mutex.Unlock()
+	d.files = nil
+}
+
+// Maintainability returns the index of every file with functions seen
+// since the last Reset, ordered by file
+func (d *MaintainabilityDetector) Maintainability() []*models.FileMaintainability {
+	d.mutex.Lock()
+	defer d.mutex.Unlock()
+
+	files := append([]*models.FileMaintainability(nil), d.files...)
+	sort.SliceStable(files, func(i, j int) bool {
+		return files[i].File < files[j].File
+	})
+	return files
+}
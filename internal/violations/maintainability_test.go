@@ -0,0 +1,87 @@
+package violations
+
+import (
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+func TestMaintainabilityDetector(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "calc.go")
+	src := "package calc\n\nfunc add(a, b int) int {\n\treturn a + b\n}\n"
+	if err := os.WriteFile(path, []byte(src), 0644); err != nil {
+		t.Fatal(err)
+	}
+	fileInfo := &models.FileInfo{Path: path, Language: "Go"}
+	astInfo := &types.GoASTInfo{Functions: []*types.FunctionInfo{
+		{Name: "add", StartLine: 3, EndLine: 5, LineCount: 3, Complexity: 1},
+	}}
+
+	detector := NewMaintainabilityDetector(DefaultDetectorConfig())
+	if violations := detector.Detect(fileInfo, astInfo); len(violations) != 0 {
+		t.Errorf("Expected a short function to pass the default threshold, got %v", violations)
+	}
+
+	files := detector.Maintainability()
+	if len(files) != 1 || len(files[0].Functions) != 1 {
+		t.Fatalf("Expected one file with one function, got %+v", files)
+	}
+	if fn := files[0].Functions[0]; fn.Index != 78.0 || fn.Volume != 43.2 || files[0].Index != fn.Index {
+		t.Errorf("Unexpected maintainability %+v of file index %v", fn, files[0].Index)
+	}
+
+	config := DefaultDetectorConfig()
+	config.MinMaintainabilityIndex = 90
+	violations := NewMaintainabilityDetector(config).Detect(fileInfo, astInfo)
+	if len(violations) != 1 || violations[0].Rule != "maintainability-index" || violations[0].Severity != models.SeverityLow {
+		t.Fatalf("Expected a low maintainability-index violation, got %v", violations)
+	}
+	if violations[0].Line != 3 || violations[0].EndLine != 5 {
+		t.Errorf("Expected the violation to span the function, got lines %d-%d", violations[0].Line, violations[0].EndLine)
+	}
+
+	detector.Reset()
+	if files := detector.Maintainability(); len(files) != 0 {
+		t.Errorf("Expected Reset to clear the recorded files, got %+v", files)
+	}
+}
+
+func TestHalsteadThresholds(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "calc.go")
+	src := "package calc\n\nfunc add(a, b int) int {\n\treturn a + b\n}\n"
+	if err := os.WriteFile(path, []byte(src), 0644); err != nil {
+		t.Fatal(err)
+	}
+	fileInfo := &models.FileInfo{Path: path, Language: "Go"}
+	astInfo := &types.GoASTInfo{Functions: []*types.FunctionInfo{
+		{Name: "add", StartLine: 3, EndLine: 5, LineCount: 3, Complexity: 1},
+	}}
+
+	detector := NewMaintainabilityDetector(DefaultDetectorConfig())
+	if violations := detector.Detect(fileInfo, astInfo); len(violations) != 0 {
+		t.Errorf("Expected Halstead checks to be off by default, got %v", violations)
+	}
+	halstead := detector.Maintainability()[0].Functions[0].Halstead
+	expected := models.HalsteadMetrics{DistinctOperators: 6, DistinctOperands: 4, Operators: 6, Operands: 7, Volume: 43.2, Difficulty: 5.3, Effort: 226.7}
+	if halstead != expected {
+		t.Errorf("Expected %+v, got %+v", expected, halstead)
+	}
+
+	config := DefaultDetectorConfig()
+	config.MaxHalsteadVolume = 100
+	config.MaxHalsteadDifficulty = 5
+	config.MaxHalsteadEffort = 100
+	violations := NewMaintainabilityDetector(config).Detect(fileInfo, astInfo)
+	if len(violations) != 2 {
+		t.Fatalf("Expected difficulty and effort violations, got %v", violations)
+	}
+	if violations[0].Rule != "halstead-difficulty" || violations[0].Severity != models.SeverityLow {
+		t.Errorf("Expected a low halstead-difficulty violation, got %+v", violations[0])
+	}
+	if violations[1].Rule != "halstead-effort" || violations[1].Severity != models.SeverityHigh {
+		t.Errorf("Expected a high halstead-effort violation, got %+v", violations[1])
+	}
+}
@@ -0,0 +1,333 @@
+package violations
+
+import (
+	"fmt"
+	"strings"
+	"unicode"
+	"unicode/utf8"
+
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+// paramField is a parameter of a function as a field of the struct that
+// replaces its parameter list
+type paramField struct {
+	name     string
+	typeName string
+}
+
+// paramGroup is a run of parameters kept together in a parameter struct:
+// parameters sharing the first word of their name, such as fromAddr and
+// fromPort, which become a nested struct named after that word, or
+// parameters of the same type
+type paramGroup struct {
+	prefix string // Shared first word; empty for a group by type
+	fields []paramField
+}
+
+// groupParameters groups parameters by the first word of their name, then the
+// remaining ones by type, in the order the groups first appear. When a single
+// name group would hold every parameter, the parameters are grouped by type.
+func groupParameters(params []paramField) []paramGroup {
+	names := make([]string, len(params))
+	for i, param := range params {
+		names[i] = param.name
+	}
+	clusters := responsibilityClusters(names)
+	if len(clusters) == 1 && len(clusters[0].fields) == len(params) {
+		clusters = nil
+	}
+	clusterOf := make(map[string]string)
+	for _, cluster := range clusters {
+		for _, field := range cluster.fields {
+			clusterOf[field] = cluster.prefix
+		}
+	}
+
+	var groups []paramGroup
+	seen := make(map[string]bool)
+	for _, param := range params {
+		prefix, named := clusterOf[param.name]
+		key := "type " + param.typeName
+		if named {
+			key = "name " + prefix
+		}
+		if seen[key] {
+			continue
+		}
+		seen[key] = true
+
+		group := paramGroup{prefix: prefix}
+		for _, other := range params {
+			otherPrefix, otherNamed := clusterOf[other.name]
+			if named && otherNamed && otherPrefix == prefix || !named && !otherNamed && other.typeName == param.typeName {
+				group.fields = append(group.fields, other)
+			}
+		}
+		groups = append(groups, group)
+	}
+	return groups
+}
+
+// groupedFieldName returns the name of a parameter within the nested struct
+// of its name group, e.g. port for fromPort or from_port and httpClient for
+// toHTTPClient. A parameter named just the shared word keeps its name.
+func groupedFieldName(name string) string {
+	trimmed := strings.TrimLeft(name, "_")
+	rest := []rune(strings.TrimLeft(trimmed[len(fieldFirstWord(trimmed)):], "_"))
+	if len(rest) == 0 {
+		return name
+	}
+	upper := 0
+	for upper < len(rest) && unicode.IsUpper(rest[upper]) {
+		upper++
+	}
+	if upper > 1 && upper < len(rest) {
+		upper-- // The last capital starts the next word
+	}
+	for i := 0; i < max(upper, 1); i++ {
+		rest[i] = unicode.ToLower(rest[i])
+	}
+	return string(rest)
+}
+
+// upperFirst capitalizes the first letter of a name
+func upperFirst(name string) string {
+	first, size := utf8.DecodeRuneInString(name)
+	return string(unicode.ToUpper(first)) + name[size:]
+}
+
+// goParameterFields returns the parameters of a Go function as fields.
+// Unnamed parameters are numbered, and a variadic parameter becomes a slice.
+func goParameterFields(params []types.ParameterInfo) []paramField {
+	fields := make([]paramField, len(params))
+	for i, param := range params {
+		name := param.Name
+		if name == "" || name == "_" {
+			name = fmt.Sprintf("param%d", i+1)
+		}
+		typeName := param.Type
+		if variadic, ok := strings.CutPrefix(typeName, "..."); ok {
+			typeName = "[]" + variadic
+		}
+		fields[i] = paramField{name: name, typeName: typeName}
+	}
+	return fields
+}
+
+// goParameterStruct renders a parameter struct for a Go function, with a
+// nested struct per name group. Fields and types are exported when the
+// function is.
+func goParameterStruct(funcName string, exported bool, groups []paramGroup) string {
+	export := func(name string) string {
+		if exported {
+			return upperFirst(name)
+		}
+		return name
+	}
+
+	var nested []string
+	var fields []paramField
+	for _, group := range groups {
+		if group.prefix == "" {
+			for _, field := range group.fields {
+				fields = append(fields, paramField{name: export(field.name), typeName: field.typeName})
+			}
+			continue
+		}
+		typeName := export(group.prefix + "Params")
+		var members []paramField
+		for _, field := range group.fields {
+			members = append(members, paramField{name: export(groupedFieldName(field.name)), typeName: field.typeName})
+		}
+		nested = append(nested, goStructText(typeName, members))
+		fields = append(fields, paramField{name: export(group.prefix), typeName: typeName})
+	}
+	return strings.Join(append(nested, goStructText(export(funcName+"Params"), fields)), "\n\n")
+}
+
+// goStructText renders a Go struct declaration with gofmt alignment
+func goStructText(name string, fields []paramField) string {
+	width := 0
+	for _, field := range fields {
+		width = max(width, len(field.name))
+	}
+	var b strings.Builder
+	fmt.Fprintf(&b, "type %s struct {\n", name)
+	for _, field := range fields {
+		fmt.Fprintf(&b, "\t%-*s %s\n", width, field.name, field.typeName)
+	}
+	b.WriteString("}")
+	return b.String()
+}
+
+// rustParameterFields returns the parameters of a Rust function as fields.
+// Parameters bound by a pattern rather than a name are numbered.
+func rustParameterFields(params []types.RustParameterInfo) []paramField {
+	fields := make([]paramField, len(params))
+	for i, param := range params {
+		name := param.Name
+		if !isRustIdentifier(name) || name == "_" {
+			name = fmt.Sprintf("param%d", i+1)
+		}
+		fields[i] = paramField{name: name, typeName: param.Type}
+	}
+	return fields
+}
+
+// isRustIdentifier reports whether a parameter pattern is a plain name
+func isRustIdentifier(name string) bool {
+	for i, r := range name {
+		if r != '_' && !unicode.IsLetter(r) && (i == 0 || !unicode.IsDigit(r)) {
+			return false
+		}
+	}
+	return name != ""
+}
+
+// rustOptionalParameters returns the fields whose type is an Option
+func rustOptionalParameters(fields []paramField) map[string]bool {
+	optional := make(map[string]bool)
+	for _, field := range fields {
+		if rustBaseType(field.typeName) == "Option" {
+			optional[field.name] = true
+		}
+	}
+	return optional
+}
+
+// rustStructName returns the CamelCase type name for a snake_case function
+// name and a suffix, e.g. SendEmailParams for send_email
+func rustStructName(funcName, suffix string) string {
+	var b strings.Builder
+	for _, word := range strings.Split(funcName, "_") {
+		if word != "" {
+			b.WriteString(upperFirst(word))
+		}
+	}
+	return b.String() + suffix
+}
+
+// rustParameterStruct renders a parameter struct for a Rust function, with a
+// nested struct per name group. Structs holding references take a lifetime.
+func rustParameterStruct(funcName, visibility string, groups []paramGroup) string {
+	var nested []string
+	var fields []paramField
+	for _, group := range groups {
+		if group.prefix == "" {
+			fields = append(fields, group.fields...)
+			continue
+		}
+		var members []paramField
+		for _, field := range group.fields {
+			members = append(members, paramField{name: groupedFieldName(field.name), typeName: field.typeName})
+		}
+		typeName := rustStructName(group.prefix, "Params")
+		text, borrows := rustStructText(visibility, visibility, typeName, members)
+		if borrows {
+			typeName += "<'a>"
+		}
+		nested = append(nested, text)
+		fields = append(fields, paramField{name: group.prefix, typeName: typeName})
+	}
+	text, _ := rustStructText(visibility, visibility, rustStructName(funcName, "Params"), fields)
+	return strings.Join(append(nested, text), "\n\n")
+}
+
+// rustBuilder renders a builder for a Rust function whose Option parameters
+// become setters, while the others are required by its constructor. The
+// function becomes a method of the builder.
+func rustBuilder(fn *types.RustFunctionInfo, visibility string, fields []paramField, optional map[string]bool) string {
+	name := rustStructName(fn.Name, "Builder")
+	text, borrows := rustStructText(visibility, "", name, fields)
+	lifetime := ""
+	if borrows {
+		lifetime = "<'a>"
+	}
+
+	var required []string
+	for _, field := range fields {
+		if !optional[field.name] {
+			required = append(required, field.name+": "+rustBorrowedType(field.typeName))
+		}
+	}
+	var b strings.Builder
+	b.WriteString(text)
+	fmt.Fprintf(&b, "\n\nimpl%s %s%s {\n", lifetime, name, lifetime)
+	fmt.Fprintf(&b, "    %sfn new(%s) -> Self { .. }\n", visibility, strings.Join(required, ", "))
+	for _, field := range fields {
+		if args := rustTypeArgs(field.typeName); optional[field.name] && len(args) == 1 {
+			fmt.Fprintf(&b, "    %sfn %s(mut self, %s: %s) -> Self { .. }\n", visibility, field.name, field.name, rustBorrowedType(args[0]))
+		}
+	}
+	returns := ""
+	if fn.ReturnType != "" {
+		returns = " -> " + fn.ReturnType
+	}
+	fmt.Fprintf(&b, "    %sfn %s(self)%s { .. }\n}", visibility, fn.Name, returns)
+	return b.String()
+}
+
+// rustStructText renders a Rust struct declaration, and reports whether it
+// borrows, taking a lifetime for its references
+func rustStructText(visibility, fieldVisibility, name string, fields []paramField) (string, bool) {
+	var body strings.Builder
+	borrows := false
+	for _, field := range fields {
+		typeName := rustBorrowedType(field.typeName)
+		borrows = borrows || strings.Contains(typeName, "'a")
+		fmt.Fprintf(&body, "    %s%s: %s,\n", fieldVisibility, field.name, typeName)
+	}
+	if borrows {
+		name += "<'a>"
+	}
+	return fmt.Sprintf("%sstruct %s {\n%s}", visibility, name, body.String()), borrows
+}
+
+// rustItemVisibility returns the visibility of a function followed by a
+// space, for the items suggested in its place, or an empty string
+func rustItemVisibility(fn *types.RustFunctionInfo) string {
+	switch {
+	case fn.Visibility != "" && fn.Visibility != "private":
+		return fn.Visibility + " "
+	case fn.IsPublic:
+		return "pub "
+	}
+	return ""
+}
+
+// rustBorrowedType gives the references of a type that have no lifetime the
+// lifetime 'a, as in Option<&'a str>. References among the arguments of a
+// closure or fn pointer type keep their elided lifetime.
+func rustBorrowedType(typeName string) string {
+	var b strings.Builder
+	var closures []bool // For each open parenthesis, whether it opens closure arguments
+	for i := 0; i < len(typeName); i++ {
+		c := typeName[i]
+		b.WriteByte(c)
+		switch c {
+		case '(':
+			before := strings.TrimSpace(typeName[:i])
+			closures = append(closures, strings.HasSuffix(before, "Fn") || strings.HasSuffix(before, "FnMut") ||
+				strings.HasSuffix(before, "FnOnce") || strings.HasSuffix(before, "fn"))
+		case ')':
+			if len(closures) > 0 {
+				closures = closures[:len(closures)-1]
+			}
+		case '&':
+			inClosure := false
+			for _, closure := range closures {
+				inClosure = inClosure || closure
+			}
+			rest := typeName[i+1:]
+			if inClosure || strings.HasPrefix(rest, "'") || strings.HasPrefix(rest, "&") {
+				continue
+			}
+			b.WriteString("'a ")
+			for i+1 < len(typeName) && typeName[i+1] == ' ' {
+				i++
+			}
+		}
+	}
+	return b.String()
+}
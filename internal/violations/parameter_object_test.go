@@ -0,0 +1,67 @@
+package violations
+
+import (
+	"reflect"
+	"testing"
+)
+
+func TestGroupParameters(t *testing.T) {
+	params := []paramField{
+		{"from_host", "String"}, {"from_port", "u16"}, {"retries", "u32"},
+		{"to_host", "String"}, {"to_port", "u16"}, {"verbose", "bool"}, {"attempts", "u32"},
+	}
+	var got [][]string
+	for _, group := range groupParameters(params) {
+		names := []string{group.prefix}
+		for _, field := range group.fields {
+			names = append(names, field.name)
+		}
+		got = append(got, names)
+	}
+	want := [][]string{
+		{"from", "from_host", "from_port"},
+		{"", "retries", "attempts"},
+		{"to", "to_host", "to_port"},
+		{"", "verbose"},
+	}
+	if !reflect.DeepEqual(got, want) {
+		t.Errorf("Expected groups %v, got %v", want, got)
+	}
+
+	// A name group holding every parameter is no grouping
+	groups := groupParameters([]paramField{{"userName", "string"}, {"userAge", "int"}, {"userEmail", "string"}})
+	if len(groups) != 2 || groups[0].prefix != "" || len(groups[0].fields) != 2 {
+		t.Errorf("Expected the parameters to be grouped by type, got %+v", groups)
+	}
+}
+
+func TestGroupedFieldName(t *testing.T) {
+	for name, want := range map[string]string{
+		"fromPort":     "port",
+		"from_port":    "port",
+		"fromHTTPS":    "https",
+		"toHTTPClient": "httpClient",
+		"from":         "from",
+		"_to_addr":     "addr",
+	} {
+		if got := groupedFieldName(name); got != want {
+			t.Errorf("groupedFieldName(%q) = %q, want %q", name, got, want)
+		}
+	}
+}
+
+func TestRustBorrowedType(t *testing.T) {
+	for typeName, want := range map[string]string{
+		"&str":                 "&'a str",
+		"&mut Vec<u8>":         "&'a mut Vec<u8>",
+		"&'static str":         "&'static str",
+		"Option<&Path>":        "Option<&'a Path>",
+		"Box<dyn Fn(&str)>":    "Box<dyn Fn(&str)>",
+		"(&str, &[u8])":        "(&'a str, &'a [u8])",
+		"HashMap<String, u32>": "HashMap<String, u32>",
+	} {
+		if got := rustBorrowedType(typeName); got != want {
+			t.Errorf("rustBorrowedType(%q) = %q, want %q", typeName, got, want)
+		}
+	}
+}
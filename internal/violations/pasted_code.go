@@ -0,0 +1,261 @@
+package violations
+
+import (
+	"fmt"
+	"os"
+	"regexp"
+	"strings"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+// Pasted code detection constants
+const (
+	// minPastedBlockLines is the size from which a top-level block counts as a
+	// large insertion worth reviewing
+	minPastedBlockLines = 10
+
+	// minStyleSamples is the fewest multi-word identifiers needed to tell
+	// which naming style code follows
+	minStyleSamples = 4
+)
+
+var (
+	// pastedMarkerPattern matches comments that credit another source or a code generator
+	pastedMarkerPattern = regexp.MustCompile(`(?i)(copied from|copy-pasted|taken from|adapted from|borrowed from|stack ?overflow|stackexchange|generated (by|with|using)|\b(chatgpt|copilot|gpt-\d)\b|https?://)`)
+
+	// generatedSourcePattern matches the header of files written by code generators
+	generatedSourcePattern = regexp.MustCompile(`^//\s*Code generated .* DO NOT EDIT\.$|@generated`)
+
+	pastedStringPattern = regexp.MustCompile(`"(?:[^"\\]|\\.)*"|'(?:[^'\\]|\\.)'|` + "`[^`]*`")
+	snakeCasePattern    = regexp.MustCompile(`\b[a-z][a-z0-9]*(?:_[a-z0-9]+)+\b`)
+	camelCasePattern    = regexp.MustCompile(`\b[a-z][a-z0-9]*[A-Z][A-Za-z0-9]*\b`)
+)
+
+// PastedCodeDetector flags large blocks of code that may have been pasted
+// from Stack Overflow, an AI assistant or another project. Its evidence is
+// circumstantial, so it is opt-in and reports at info severity for a human
+// to review.
+type PastedCodeDetector struct {
+	config *DetectorConfig
+}
+
+// pastedBlock is a top-level declaration with the comments directly above it
+type pastedBlock struct {
+	start  int    // First line, including the comments above the declaration
+	end    int    // Last line
+	marker string // First comment in or above the block that credits another source
+	style  namingStyle
+}
+
+// namingStyle counts the multi-word identifiers of code by naming convention
+type namingStyle struct {
+	snake int
+	camel int
+}
+
+// NewPastedCodeDetector creates a new pasted code detector
+func NewPastedCodeDetector(config *DetectorConfig) *PastedCodeDetector {
+	return &PastedCodeDetector{config: config}
+}
+
+// Name returns the name of this detector
+func (d *PastedCodeDetector) Name() string {
+	return "Pasted Code Detector"
+}
+
+// Description returns a description of what this detector checks for
+func (d *PastedCodeDetector) Description() string {
+	return "Flags large blocks credited to another source in comments or named unlike the rest of their file"
+}
+
+// Detect reports the large top-level blocks of a Go or Rust file that carry
+// a marker comment or follow another naming convention than the rest of it
+func (d *PastedCodeDetector) Detect(fileInfo *models.FileInfo, astInfo interface{}) []*models.Violation {
+	var violations []*models.Violation
+	if d.config == nil || !d.config.DetectPastedCode {
+		return violations
+	}
+	switch info := astInfo.(type) {
+	case *types.GoASTInfo:
+		if info == nil {
+			return violations
+		}
+	case *types.RustASTInfo:
+		if info == nil {
+			return violations
+		}
+	default:
+		return violations
+	}
+
+	content, err := os.ReadFile(fileInfo.Path)
+	if err != nil {
+		return violations
+	}
+	lines := strings.Split(string(content), "\n")
+	if isGeneratedSource(lines) {
+		return violations
+	}
+
+	blocks, total := topLevelBlocks(lines)
+	for _, block := range blocks {
+		size := block.end - block.start + 1
+		if size < minPastedBlockLines {
+			continue
+		}
+
+		var evidence []string
+		if block.marker != "" {
+			evidence = append(evidence, fmt.Sprintf("comment %q", block.marker))
+		}
+		rest := namingStyle{snake: total.snake - block.style.snake, camel: total.camel - block.style.camel}
+		if own, others := block.style.dominant(), rest.dominant(); own != "" && others != "" && own != others {
+			evidence = append(evidence, fmt.Sprintf("names are %s while the rest of the file uses %s", own, others))
+		}
+		if len(evidence) == 0 {
+			continue
+		}
+
+		violations = append(violations, &models.Violation{
+			Type:       models.ViolationTypePastedCode,
+			Severity:   models.SeverityInfo,
+			Message:    fmt.Sprintf("Block of %d lines may be pasted from elsewhere: %s", size, strings.Join(evidence, "; ")),
+			File:       fileInfo.Path,
+			Line:       block.start,
+			Column:     1,
+			EndLine:    block.end,
+			Rule:       "pasted-code",
+			Suggestion: "Check the block's license and correctness, then adapt it to the conventions of this file",
+		})
+	}
+	return violations
+}
+
+// isGeneratedSource reports whether a file starts with a code generator header
+func isGeneratedSource(lines []string) bool {
+	for _, line := range lines[:min(len(lines), 5)] {
+		if generatedSourcePattern.MatchString(strings.TrimSpace(line)) {
+			return true
+		}
+	}
+	return false
+}
+
+// topLevelBlocks splits source into its top-level declarations, tracking
+// braces and parentheses, and counts the naming styles of the whole file
+func topLevelBlocks(lines []string) ([]*pastedBlock, namingStyle) {
+	var (
+		blocks        []*pastedBlock
+		total         namingStyle
+		block         *pastedBlock
+		depth         int
+		commentStart  int // First line of the comments above the next declaration
+		pendingMarker string
+		inComment     bool
+	)
+	for i, line := range lines {
+		code, comment := splitComment(line, &inComment)
+		comment = strings.TrimSpace(comment)
+
+		if block == nil {
+			// Rust attributes belong to the declaration below them, like comments
+			trimmed := strings.TrimSpace(code)
+			if trimmed == "" || strings.HasPrefix(trimmed, "#[") && strings.HasSuffix(trimmed, "]") {
+				if trimmed == "" && comment == "" && !inComment {
+					commentStart, pendingMarker = 0, ""
+					continue
+				}
+				if commentStart == 0 {
+					commentStart = i + 1
+				}
+				if pendingMarker == "" && pastedMarkerPattern.MatchString(comment) {
+					pendingMarker = comment
+				}
+				continue
+			}
+			block = &pastedBlock{start: i + 1, marker: pendingMarker}
+			if commentStart > 0 {
+				block.start = commentStart
+			}
+			commentStart, pendingMarker = 0, ""
+		}
+
+		if block.marker == "" && pastedMarkerPattern.MatchString(comment) {
+			block.marker = comment
+		}
+		block.style.snake += len(snakeCasePattern.FindAllString(code, -1))
+		block.style.camel += len(camelCasePattern.FindAllString(code, -1))
+
+		depth += strings.Count(code, "{") + strings.Count(code, "(") - strings.Count(code, "}") - strings.Count(code, ")")
+		if depth <= 0 {
+			depth = 0
+			block.end = i + 1
+			total.snake += block.style.snake
+			total.camel += block.style.camel
+			blocks = append(blocks, block)
+			block = nil
+		}
+	}
+	if block != nil {
+		block.end = len(lines)
+		total.snake += block.style.snake
+		total.camel += block.style.camel
+		blocks = append(blocks, block)
+	}
+	return blocks, total
+}
+
+// splitComment separates the code of a line from its comment text. String
+// and character literals are blanked first so URLs in them are not taken for
+// comments. inComment carries an open block comment to the next line.
+func splitComment(line string, inComment *bool) (code, comment string) {
+	line = pastedStringPattern.ReplaceAllString(line, `""`)
+
+	var codeText, commentText strings.Builder
+	for line != "" {
+		if *inComment {
+			end := strings.Index(line, "*/")
+			if end < 0 {
+				commentText.WriteString(line)
+				break
+			}
+			commentText.WriteString(line[:end])
+			line = line[end+2:]
+			*inComment = false
+			continue
+		}
+
+		lineComment := strings.Index(line, "//")
+		blockComment := strings.Index(line, "/*")
+		if lineComment >= 0 && (blockComment < 0 || lineComment < blockComment) {
+			codeText.WriteString(line[:lineComment])
+			commentText.WriteString(strings.TrimLeft(line[lineComment:], "/!"))
+			break
+		}
+		if blockComment < 0 {
+			codeText.WriteString(line)
+			break
+		}
+		codeText.WriteString(line[:blockComment])
+		line = line[blockComment+2:]
+		*inComment = true
+	}
+	return codeText.String(), commentText.String()
+}
+
+// dominant returns the naming style at least three in four identifiers
+// follow, or "" when the code has too few identifiers or mixes styles
+func (s namingStyle) dominant() string {
+	if s.snake+s.camel < minStyleSamples {
+		return ""
+	}
+	switch {
+	case s.snake >= 3*s.camel:
+		return "snake_case"
+	case s.camel >= 3*s.snake:
+		return "camelCase"
+	}
+	return ""
+}
@@ -0,0 +1,123 @@
+package violations
+
+import (
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+const pastedCodeSource = `package sample
+
+// parseHeader reads the name and value of a header line
+func parseHeader(line string) (string, string) {
+	name, value, _ := strings.Cut(line, ":")
+	return name, value
+}
+
+// Adapted from https://stackoverflow.com/a/12345
+func splitQuery(query string) map[string]string {
+	params := make(map[string]string)
+	for _, pair := range strings.Split(query, "&") {
+		key, value, found := strings.Cut(pair, "=")
+		if !found {
+			continue
+		}
+		params[key] = value
+	}
+	return params
+}
+
+func normalizeHeaders(headerLines []string) []string {
+	var headerNames []string
+	for _, headerLine := range headerLines {
+		headerName, _ := parseHeader(headerLine)
+		headerNames = append(headerNames, headerName)
+	}
+	return headerNames
+}
+
+func encode_values(raw_values []string) string {
+	var out_parts []string
+	for _, raw_value := range raw_values {
+		trimmed_value := strings.TrimSpace(raw_value)
+		if trimmed_value == "" {
+			continue
+		}
+		out_parts = append(out_parts, trimmed_value)
+	}
+	return strings.Join(out_parts, ",")
+}
+`
+
+func detectPastedCode(t *testing.T, source string, enabled bool) []*models.Violation {
+	t.Helper()
+	path := filepath.Join(t.TempDir(), "sample.go")
+	if err := os.WriteFile(path, []byte(source), 0644); err != nil {
+		t.Fatalf("Failed to write test file: %v", err)
+	}
+	config := DefaultDetectorConfig()
+	config.DetectPastedCode = enabled
+	return NewPastedCodeDetector(config).Detect(&models.FileInfo{Path: path}, &types.GoASTInfo{})
+}
+
+func TestPastedCodeDetectorIsOptIn(t *testing.T) {
+	if violations := detectPastedCode(t, pastedCodeSource, false); len(violations) != 0 {
+		t.Errorf("Expected no violations unless enabled, got %d", len(violations))
+	}
+}
+
+func TestPastedCodeDetectorFindsMarkersAndStyleShifts(t *testing.T) {
+	violations := detectPastedCode(t, pastedCodeSource, true)
+	if len(violations) != 2 {
+		t.Fatalf("Expected 2 violations, got %d: %v", len(violations), violations)
+	}
+
+	marked := violations[0]
+	if marked.Rule != "pasted-code" || marked.Severity != models.SeverityInfo || marked.Type != models.ViolationTypePastedCode {
+		t.Errorf("Unexpected rule %q, severity %v or type %q", marked.Rule, marked.Severity, marked.Type)
+	}
+	if marked.Line != 9 || marked.EndLine != 20 {
+		t.Errorf("Expected the commented block at lines 9-20, got %d-%d", marked.Line, marked.EndLine)
+	}
+	if !strings.Contains(marked.Message, "stackoverflow.com") {
+		t.Errorf("Expected the marker comment in the message, got %q", marked.Message)
+	}
+
+	shifted := violations[1]
+	if shifted.Line != 31 || !strings.Contains(shifted.Message, "names are snake_case while the rest of the file uses camelCase") {
+		t.Errorf("Expected encode_values to be reported for its naming, got line %d: %q", shifted.Line, shifted.Message)
+	}
+}
+
+func TestPastedCodeDetectorIgnoresSmallAndGeneratedCode(t *testing.T) {
+	small := "package sample\n\n// Copied from the standard library\nfunc abs(x int) int {\n\tif x < 0 {\n\t\treturn -x\n\t}\n\treturn x\n}\n"
+	if violations := detectPastedCode(t, small, true); len(violations) != 0 {
+		t.Errorf("Expected blocks under %d lines to be ignored, got %v", minPastedBlockLines, violations)
+	}
+
+	generated := "// Code generated by protoc-gen-go. DO NOT EDIT.\n" + pastedCodeSource
+	if violations := detectPastedCode(t, generated, true); len(violations) != 0 {
+		t.Errorf("Expected generated files to be ignored, got %v", violations)
+	}
+}
+
+func TestSplitComment(t *testing.T) {
+	inComment := false
+	code, comment := splitComment(`url := "https://example.com" // see the docs`, &inComment)
+	if strings.Contains(code, "example.com") || strings.TrimSpace(comment) != "see the docs" {
+		t.Errorf("Expected the string blanked and the comment split off, got %q and %q", code, comment)
+	}
+
+	code, comment = splitComment(`x := 1 /* taken from`, &inComment)
+	if strings.TrimSpace(code) != "x := 1" || !inComment {
+		t.Errorf("Expected an open block comment, got %q, %q, %v", code, comment, inComment)
+	}
+	code, comment = splitComment(`the manual */ y := 2`, &inComment)
+	if strings.TrimSpace(code) != "y := 2" || strings.TrimSpace(comment) != "the manual" || inComment {
+		t.Errorf("Expected the block comment closed, got %q, %q, %v", code, comment, inComment)
+	}
+}
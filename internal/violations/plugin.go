@@ -0,0 +1,438 @@
+package violations
+
+import (
+	"bufio"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"io"
+	"os"
+	"os/exec"
+	"strings"
+	"sync"
+	"time"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/sandbox"
+	"github.com/ericfisherdev/goclean/internal/types"
+	"github.com/ericfisherdev/goclean/pkg/plugin"
+)
+
+// DefaultPluginTimeout is the longest goclean waits for a plugin to answer
+// for one file
+const DefaultPluginTimeout = 30 * time.Second
+
+// PluginSpec configures an external detector plugin
+type PluginSpec struct {
+	Name      string
+	Command   string
+	Args      []string
+	Languages []string        // Languages whose files are sent to the plugin; all when empty
+	Timeout   time.Duration   // Longest wait for the answer on one file; DefaultPluginTimeout when 0
+	Sandbox   *sandbox.Limits // Limits of the plugin process; it runs unrestricted when nil
+}
+
+// PluginDetector runs an external plugin as a detector. The plugin process
+// starts with the first file and answers one request at a time until Close.
+type PluginDetector struct {
+	spec    PluginSpec
+	mutex   sync.Mutex
+	process *pluginProcess
+	failed  bool // Set when the plugin broke; it is not restarted until Close
+}
+
+// pluginProcess is a running plugin with pipes to its standard streams
+type pluginProcess struct {
+	cmd     *exec.Cmd
+	stdin   io.WriteCloser
+	stdout  *bufio.Reader
+	encoder *json.Encoder
+}
+
+// NewPluginDetector creates a detector running the plugin of a spec
+func NewPluginDetector(spec PluginSpec) *PluginDetector {
+	return &PluginDetector{spec: spec}
+}
+
+// Name returns the name of this detector
+func (d *PluginDetector) Name() string {
+	return "Plugin " + d.spec.Name
+}
+
+// Description returns a description of what this detector checks for
+func (d *PluginDetector) Description() string {
+	return fmt.Sprintf("Reports the violations found by the external plugin %s", d.spec.Command)
+}
+
+// Detect sends a Go or Rust file to the plugin and returns the violations it
+// reports. A plugin that fails to start or answer is reported on standard
+// error once and skipped for the rest of the scan.
+func (d *PluginDetector) Detect(fileInfo *models.FileInfo, astInfo interface{}) []*models.Violation {
+	var violations []*models.Violation
+
+	request := newPluginRequest(fileInfo, astInfo)
+	if request == nil || !d.handles(request.File.Language) {
+		return violations
+	}
+	content, err := os.ReadFile(fileInfo.Path)
+	if err != nil {
+		return violations
+	}
+	request.File.Content = string(content)
+
+	response, err := d.roundTrip(request)
+	if err != nil {
+		return violations
+	}
+	if response.Error != "" {
+		fmt.Fprintf(os.Stderr, "Warning: plugin %s failed on %s: %s\n", d.spec.Name, fileInfo.Path, response.Error)
+		return violations
+	}
+
+	for _, found := range response.Violations {
+		violations = append(violations, d.toViolation(fileInfo.Path, found))
+	}
+	return violations
+}
+
+// Close stops the plugin process. The next Detect starts it again.
+func (d *PluginDetector) Close() {
+	d.mutex.Lock()
+	defer d.mutex.Unlock()
+
+	if d.process != nil {
+		d.process.stop(d.timeout())
+		d.process = nil
+	}
+	d.failed = false
+}
+
+// handles reports whether files of a language are sent to the plugin
+func (d *PluginDetector) handles(language string) bool {
+	if len(d.spec.Languages) == 0 {
+		return true
+	}
+	for _, handled := range d.spec.Languages {
+		if strings.EqualFold(handled, language) {
+			return true
+		}
+	}
+	return false
+}
+
+// timeout returns the longest wait for the answer on one file
+func (d *PluginDetector) timeout() time.Duration {
+	if d.spec.Timeout > 0 {
+		return d.spec.Timeout
+	}
+	return DefaultPluginTimeout
+}
+
+// roundTrip sends a request to the plugin, starting it when needed, and
+// reads its response. Requests are serialized because the protocol answers
+// in order.
+func (d *PluginDetector) roundTrip(request *plugin.Request) (*plugin.Response, error) {
+	d.mutex.Lock()
+	defer d.mutex.Unlock()
+
+	if d.failed {
+		return nil, fmt.Errorf("plugin %s has failed", d.spec.Name)
+	}
+	if d.process == nil {
+		process, err := startPlugin(d.spec)
+		if err != nil {
+			return nil, d.fail(err)
+		}
+		d.process = process
+	}
+
+	type result struct {
+		response *plugin.Response
+		err      error
+	}
+	process := d.process
+	done := make(chan result, 1)
+	go func() {
+		response, err := process.exchange(request)
+		done <- result{response, err}
+	}()
+
+	select {
+	case r := <-done:
+		if r.err != nil {
+			return nil, d.fail(r.err)
+		}
+		return r.response, nil
+	case <-time.After(d.timeout()):
+		return nil, d.fail(fmt.Errorf("no answer for %s within %v", request.File.Path, d.timeout()))
+	}
+}
+
+// fail reports a broken plugin, stops it and keeps it from running again
+// during the scan. It is called with the mutex held.
+func (d *PluginDetector) fail(err error) error {
+	fmt.Fprintf(os.Stderr, "Warning: plugin %s disabled for this scan: %v\n", d.spec.Name, err)
+	if d.process != nil {
+		d.process.kill()
+		d.process = nil
+	}
+	d.failed = true
+	return err
+}
+
+// startPlugin starts the command of a plugin with pipes to its standard
+// input and output, in the sandbox when the spec has limits. Its standard
+// error goes to goclean's.
+func startPlugin(spec PluginSpec) (*pluginProcess, error) {
+	cmd, err := pluginCommand(spec)
+	if err != nil {
+		return nil, err
+	}
+	cmd.Stderr = os.Stderr
+
+	stdin, err := cmd.StdinPipe()
+	if err != nil {
+		return nil, err
+	}
+	stdout, err := cmd.StdoutPipe()
+	if err != nil {
+		return nil, err
+	}
+	if err := cmd.Start(); err != nil {
+		return nil, fmt.Errorf("starting %s: %w", spec.Command, err)
+	}
+
+	return &pluginProcess{
+		cmd:     cmd,
+		stdin:   stdin,
+		stdout:  bufio.NewReader(stdout),
+		encoder: json.NewEncoder(stdin),
+	}, nil
+}
+
+// pluginCommand returns the command running a plugin. Plugins with limits
+// run in the sandbox, and fail to start when the platform cannot enforce
+// their limits unless they allow running without them.
+func pluginCommand(spec PluginSpec) (*exec.Cmd, error) {
+	if spec.Sandbox == nil {
+		return exec.Command(spec.Command, spec.Args...), nil
+	}
+	if !sandbox.Available() {
+		if !spec.Sandbox.AllowUnsandboxed {
+			return nil, fmt.Errorf("sandboxing %s: %w on this platform; set sandbox.allow_unsandboxed to run it anyway",
+				spec.Command, sandbox.ErrUnenforced)
+		}
+		fmt.Fprintf(os.Stderr, "Warning: plugin %s runs without a sandbox, which is not supported on this platform\n", spec.Name)
+		return exec.Command(spec.Command, spec.Args...), nil
+	}
+	cmd, err := sandbox.Command(*spec.Sandbox, spec.Command, spec.Args...)
+	if errors.Is(err, sandbox.ErrUnenforced) {
+		return nil, fmt.Errorf("sandboxing %s: %w; set sandbox.allow_unsandboxed to run it with the restrictions available", spec.Command, err)
+	}
+	if err != nil {
+		return nil, fmt.Errorf("sandboxing %s: %w", spec.Command, err)
+	}
+	if missing := sandbox.Check(*spec.Sandbox).Missing; len(missing) > 0 {
+		fmt.Fprintf(os.Stderr, "Warning: plugin %s runs without %s, which this system cannot enforce\n", spec.Name, strings.Join(missing, " and "))
+	}
+	return cmd, nil
+}
+
+// PluginEnforcement describes the sandbox restrictions enforced on the
+// plugin of a spec, for verbose output
+func PluginEnforcement(spec PluginSpec) string {
+	switch {
+	case spec.Sandbox == nil:
+		return "sandbox disabled"
+	case !sandbox.Available():
+		if spec.Sandbox.AllowUnsandboxed {
+			return "no sandbox on this platform"
+		}
+		return "no sandbox on this platform; the plugin will not start"
+	}
+	enforcement := sandbox.Check(*spec.Sandbox)
+	if len(enforcement.Missing) > 0 && !spec.Sandbox.AllowUnsandboxed {
+		return enforcement.String() + "; the plugin will not start"
+	}
+	return enforcement.String()
+}
+
+// exchange writes one request line and reads the response line
+func (p *pluginProcess) exchange(request *plugin.Request) (*plugin.Response, error) {
+	if err := p.encoder.Encode(request); err != nil {
+		return nil, fmt.Errorf("writing request: %w", err)
+	}
+	line, err := p.stdout.ReadBytes('\n')
+	if err != nil {
+		return nil, fmt.Errorf("reading response: %w", err)
+	}
+
+	var response plugin.Response
+	if err := json.Unmarshal(line, &response); err != nil {
+		return nil, fmt.Errorf("invalid response: %w", err)
+	}
+	return &response, nil
+}
+
+// stop closes the plugin's input so it exits, killing it when it does not
+// exit within timeout
+func (p *pluginProcess) stop(timeout time.Duration) {
+	p.stdin.Close()
+
+	exited := make(chan struct{})
+	go func() {
+		p.cmd.Wait()
+		close(exited)
+	}()
+	select {
+	case <-exited:
+	case <-time.After(timeout):
+		p.cmd.Process.Kill()
+		<-exited
+	}
+}
+
+// kill ends the plugin process at once
+func (p *pluginProcess) kill() {
+	p.cmd.Process.Kill()
+	p.cmd.Wait()
+}
+
+// toViolation converts a plugin finding into a violation of a file. Missing
+// rules, severities and lines fall back to the plugin name, low severity and
+// the first line.
+func (d *PluginDetector) toViolation(path string, found plugin.Violation) *models.Violation {
+	violation := &models.Violation{
+		Type:       models.ViolationTypePlugin,
+		Severity:   models.SeverityLow,
+		Message:    found.Message,
+		File:       path,
+		Line:       max(found.Line, 1),
+		Column:     max(found.Column, 1),
+		EndLine:    found.EndLine,
+		EndColumn:  found.EndColumn,
+		Rule:       found.Rule,
+		Suggestion: found.Suggestion,
+	}
+	if found.Type != "" {
+		violation.Type = models.ViolationType(found.Type)
+	}
+	if severity, err := models.ParseSeverity(found.Severity); err == nil {
+		violation.Severity = severity
+	}
+	if violation.Rule == "" {
+		violation.Rule = d.spec.Name
+	}
+	return violation
+}
+
+// newPluginRequest describes a parsed Go or Rust file to plugins, or returns
+// nil for files without syntax information
+func newPluginRequest(fileInfo *models.FileInfo, astInfo interface{}) *plugin.Request {
+	request := &plugin.Request{
+		Protocol:  plugin.ProtocolVersion,
+		File:      plugin.File{Path: fileInfo.Path, Lines: fileInfo.Lines},
+		Functions: []plugin.Function{},
+		Types:     []plugin.Type{},
+		Imports:   []plugin.Import{},
+	}
+
+	switch info := astInfo.(type) {
+	case *types.GoASTInfo:
+		if info == nil {
+			return nil
+		}
+		request.File.Language = LanguageGo
+		describeGoFile(request, info)
+	case *types.RustASTInfo:
+		if info == nil {
+			return nil
+		}
+		request.File.Language = LanguageRust
+		describeRustFile(request, info)
+	default:
+		return nil
+	}
+	return request
+}
+
+// describeGoFile adds the functions, types and imports of a Go file to a request
+func describeGoFile(request *plugin.Request, info *types.GoASTInfo) {
+	for _, function := range info.Functions {
+		parameters := make([]string, 0, len(function.Parameters))
+		for _, parameter := range function.Parameters {
+			parameters = append(parameters, parameter.Name)
+		}
+		request.Functions = append(request.Functions, plugin.Function{
+			Name:       function.Name,
+			Receiver:   function.ReceiverType,
+			StartLine:  function.StartLine,
+			EndLine:    function.EndLine,
+			Lines:      function.LineCount,
+			Complexity: function.Complexity,
+			Parameters: parameters,
+			Exported:   function.IsExported,
+		})
+	}
+	for _, typeInfo := range info.Types {
+		members := typeInfo.FieldCount
+		if typeInfo.Kind == "interface" {
+			members = typeInfo.MethodCount
+		}
+		request.Types = append(request.Types, plugin.Type{
+			Name:      typeInfo.Name,
+			Kind:      typeInfo.Kind,
+			StartLine: typeInfo.StartLine,
+			EndLine:   typeInfo.EndLine,
+			Members:   members,
+			Exported:  typeInfo.IsExported,
+		})
+	}
+	for _, imported := range info.Imports {
+		request.Imports = append(request.Imports, plugin.Import{Path: imported.Path, Alias: imported.Alias, Line: imported.Line})
+	}
+}
+
+// describeRustFile adds the functions, types and use declarations of a Rust
+// file to a request. Methods get the type of their impl block as receiver.
+func describeRustFile(request *plugin.Request, info *types.RustASTInfo) {
+	for _, function := range info.Functions {
+		parameters := make([]string, 0, len(function.Parameters))
+		for _, parameter := range function.Parameters {
+			parameters = append(parameters, parameter.Name)
+		}
+		receiver := ""
+		for _, impl := range info.Impls {
+			if function.StartLine >= impl.StartLine && function.EndLine <= impl.EndLine {
+				receiver = impl.TargetType
+			}
+		}
+		request.Functions = append(request.Functions, plugin.Function{
+			Name:       function.Name,
+			Receiver:   receiver,
+			StartLine:  function.StartLine,
+			EndLine:    function.EndLine,
+			Lines:      function.LineCount,
+			Complexity: function.Complexity,
+			Parameters: parameters,
+			Exported:   function.IsPublic,
+			Async:      function.IsAsync,
+			Unsafe:     function.IsUnsafe,
+		})
+	}
+	for _, structInfo := range info.Structs {
+		request.Types = append(request.Types, plugin.Type{Name: structInfo.Name, Kind: "struct", StartLine: structInfo.StartLine,
+			EndLine: structInfo.EndLine, Members: structInfo.FieldCount, Exported: structInfo.IsPublic})
+	}
+	for _, enumInfo := range info.Enums {
+		request.Types = append(request.Types, plugin.Type{Name: enumInfo.Name, Kind: "enum", StartLine: enumInfo.StartLine,
+			EndLine: enumInfo.EndLine, Members: enumInfo.VariantCount, Exported: enumInfo.IsPublic})
+	}
+	for _, traitInfo := range info.Traits {
+		request.Types = append(request.Types, plugin.Type{Name: traitInfo.Name, Kind: "trait", StartLine: traitInfo.StartLine,
+			EndLine: traitInfo.EndLine, Members: traitInfo.MethodCount, Exported: traitInfo.IsPublic})
+	}
+	for _, use := range info.Uses {
+		request.Imports = append(request.Imports, plugin.Import{Path: use.Path, Alias: use.Alias, Line: use.Line})
+	}
+}
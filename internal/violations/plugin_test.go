@@ -0,0 +1,155 @@
+package violations
+
+import (
+	"errors"
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/sandbox"
+	"github.com/ericfisherdev/goclean/internal/types"
+	"github.com/ericfisherdev/goclean/pkg/plugin"
+)
+
+// TestPluginHelperProcess is not a real test: the plugin tests run the test
+// binary with it selected to get a plugin speaking the protocol
+func TestPluginHelperProcess(t *testing.T) {
+	if os.Getenv("GOCLEAN_TEST_PLUGIN") != "1" {
+		return
+	}
+	plugin.Serve(plugin.DetectorFunc(func(request *plugin.Request) ([]plugin.Violation, error) {
+		if strings.Contains(request.File.Content, "reject me") {
+			return nil, errors.New("cannot analyze file")
+		}
+		var violations []plugin.Violation
+		for _, imported := range request.Imports {
+			if imported.Path == "unsafe" {
+				violations = append(violations, plugin.Violation{Rule: "no-unsafe-import", Severity: "high",
+					Message: "Package unsafe is not allowed", Line: imported.Line})
+			}
+		}
+		for _, function := range request.Functions {
+			violations = append(violations, plugin.Violation{Message: function.Name + " seen", Line: function.StartLine})
+		}
+		return violations, nil
+	}))
+	os.Exit(0)
+}
+
+// helperPluginSpec returns a plugin spec running TestPluginHelperProcess
+func helperPluginSpec(t *testing.T) PluginSpec {
+	t.Setenv("GOCLEAN_TEST_PLUGIN", "1")
+	return PluginSpec{Name: "house-rules", Command: os.Args[0], Args: []string{"-test.run=^TestPluginHelperProcess$"}}
+}
+
+func writePluginSource(t *testing.T, source string) *models.FileInfo {
+	t.Helper()
+	path := filepath.Join(t.TempDir(), "sample.go")
+	if err := os.WriteFile(path, []byte(source), 0644); err != nil {
+		t.Fatalf("Failed to write test file: %v", err)
+	}
+	return &models.FileInfo{Path: path, Language: "Go"}
+}
+
+func TestPluginDetectorReportsPluginViolations(t *testing.T) {
+	detector := NewPluginDetector(helperPluginSpec(t))
+	defer detector.Close()
+
+	fileInfo := writePluginSource(t, "package sample\n\nimport \"unsafe\"\n")
+	astInfo := &types.GoASTInfo{
+		Imports:   []*types.ImportInfo{{Path: "unsafe", Line: 3}},
+		Functions: []*types.FunctionInfo{{Name: "Convert", StartLine: 5}},
+	}
+	violations := detector.Detect(fileInfo, astInfo)
+	if len(violations) != 2 {
+		t.Fatalf("Expected 2 violations, got %d", len(violations))
+	}
+
+	unsafeImport := violations[0]
+	if unsafeImport.Rule != "no-unsafe-import" || unsafeImport.Severity != models.SeverityHigh || unsafeImport.Line != 3 {
+		t.Errorf("Unexpected rule %q, severity %v or line %d", unsafeImport.Rule, unsafeImport.Severity, unsafeImport.Line)
+	}
+	if unsafeImport.Type != models.ViolationTypePlugin || unsafeImport.File != fileInfo.Path {
+		t.Errorf("Expected a plugin violation of %s, got type %q in %s", fileInfo.Path, unsafeImport.Type, unsafeImport.File)
+	}
+
+	// Findings without rule or severity fall back to the plugin name and low
+	function := violations[1]
+	if function.Rule != "house-rules" || function.Severity != models.SeverityLow || function.Line != 5 {
+		t.Errorf("Unexpected defaults: rule %q, severity %v, line %d", function.Rule, function.Severity, function.Line)
+	}
+}
+
+func TestPluginDetectorKeepsRunningAfterFileErrors(t *testing.T) {
+	detector := NewPluginDetector(helperPluginSpec(t))
+	defer detector.Close()
+
+	astInfo := &types.GoASTInfo{Functions: []*types.FunctionInfo{{Name: "Run", StartLine: 3}}}
+	if violations := detector.Detect(writePluginSource(t, "package sample // reject me\n"), astInfo); len(violations) != 0 {
+		t.Errorf("Expected no violations for a file the plugin rejects, got %d", len(violations))
+	}
+	if violations := detector.Detect(writePluginSource(t, "package sample\n"), astInfo); len(violations) != 1 {
+		t.Errorf("Expected the plugin to answer the next file, got %d violations", len(violations))
+	}
+}
+
+func TestPluginDetectorSkipsOtherLanguagesAndBrokenPlugins(t *testing.T) {
+	spec := helperPluginSpec(t)
+	spec.Languages = []string{"rust"}
+	detector := NewPluginDetector(spec)
+	defer detector.Close()
+
+	fileInfo := writePluginSource(t, "package sample\n")
+	astInfo := &types.GoASTInfo{Functions: []*types.FunctionInfo{{Name: "Run", StartLine: 3}}}
+	if violations := detector.Detect(fileInfo, astInfo); len(violations) != 0 {
+		t.Errorf("Expected Go files not to be sent to a Rust plugin, got %d violations", len(violations))
+	}
+	if detector.process != nil {
+		t.Error("Expected the plugin not to be started without a file of its languages")
+	}
+
+	broken := NewPluginDetector(PluginSpec{Name: "missing", Command: filepath.Join(t.TempDir(), "missing-plugin")})
+	defer broken.Close()
+	for i := 0; i < 2; i++ {
+		if violations := broken.Detect(fileInfo, astInfo); len(violations) != 0 {
+			t.Errorf("Expected no violations from a plugin that cannot start, got %d", len(violations))
+		}
+	}
+	if !broken.failed {
+		t.Error("Expected the plugin to be disabled after it failed to start")
+	}
+}
+
+func TestPluginCommandRefusesUnenforcedSandbox(t *testing.T) {
+	if sandbox.Available() && len(sandbox.Check(sandbox.Limits{}).Missing) == 0 {
+		t.Skip("The platform enforces every restriction")
+	}
+
+	spec := PluginSpec{Name: "house-rules", Command: "true", Sandbox: &sandbox.Limits{}}
+	if _, err := pluginCommand(spec); !errors.Is(err, sandbox.ErrUnenforced) {
+		t.Fatalf("Expected ErrUnenforced, got %v", err)
+	}
+	if !strings.HasSuffix(PluginEnforcement(spec), "the plugin will not start") {
+		t.Errorf("Expected the enforcement to say the plugin will not start, got %q", PluginEnforcement(spec))
+	}
+
+	spec.Sandbox.AllowUnsandboxed = true
+	if _, err := pluginCommand(spec); err != nil {
+		t.Errorf("Expected the plugin to start with allow_unsandboxed, got %v", err)
+	}
+}
+
+func TestPluginEnforcement(t *testing.T) {
+	if got := PluginEnforcement(PluginSpec{Name: "house-rules"}); got != "sandbox disabled" {
+		t.Errorf("Expected the sandbox to be disabled without limits, got %q", got)
+	}
+	if !sandbox.Available() {
+		t.Skip("Sandboxing is not supported on this platform")
+	}
+	spec := PluginSpec{Name: "house-rules", Sandbox: &sandbox.Limits{Memory: 1 << 30, Network: true, Filesystem: true}}
+	if got := PluginEnforcement(spec); got != "resource limits" {
+		t.Errorf("Expected resource limits only, got %q", got)
+	}
+}
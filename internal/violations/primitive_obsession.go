@@ -0,0 +1,336 @@
+package violations
+
+import (
+	"fmt"
+	"go/ast"
+	"sort"
+	"strings"
+	"sync"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+// Rules of the primitive obsession detector
+const (
+	PrimitiveObsession     = "primitive-obsession"
+	RustPrimitiveObsession = "rust-primitive-obsession"
+)
+
+// goPrimitiveTypes are the Go types a recurring name is checked for; bool is
+// left out, as a flag seldom deserves a type of its own
+var goPrimitiveTypes = map[string]bool{
+	"string": true, "byte": true, "rune": true, "float32": true, "float64": true,
+	"int": true, "int8": true, "int16": true, "int32": true, "int64": true,
+	"uint": true, "uint8": true, "uint16": true, "uint32": true, "uint64": true,
+}
+
+// rustPrimitiveTypes are the Rust types a recurring name is checked for;
+// str stands for String, as &str and String carry the same values
+var rustPrimitiveTypes = map[string]bool{
+	"String": true, "str": true, "char": true, "f32": true, "f64": true,
+	"i8": true, "i16": true, "i32": true, "i64": true, "i128": true, "isize": true,
+	"u8": true, "u16": true, "u32": true, "u64": true, "u128": true, "usize": true,
+}
+
+// primitiveGenericNames say nothing of the value they hold, so their
+// recurrence reveals no concept of the domain
+var primitiveGenericNames = map[string]bool{
+	"value": true, "val": true, "data": true, "name": true, "text": true, "msg": true, "message": true,
+	"len": true, "length": true, "count": true, "index": true, "idx": true, "size": true, "key": true,
+	"input": true, "output": true, "result": true, "buf": true, "buffer": true, "arg": true, "args": true,
+	"other": true, "item": true, "format": true, "prefix": true, "suffix": true, "path": true,
+}
+
+// primitiveKey identifies the uses of one name with one primitive type
+type primitiveKey struct {
+	language string
+	name     string // Words of the name in lower case, joined by underscores
+	typeName string
+}
+
+// PrimitiveObsessionDetector records the parameters and fields of primitive
+// types across the files of a scan, and reports the names that recur with
+// the same type in more functions and structs than allowed. Such a name,
+// like user_id: u64 or email: String, is a concept of the domain passed
+// around as a bare primitive, which a newtype would name, validate once and
+// keep from being swapped with another value of the same primitive.
+type PrimitiveObsessionDetector struct {
+	config *DetectorConfig
+	mutex  sync.Mutex
+	uses   map[primitiveKey][]primitiveUse
+}
+
+// primitiveUse is a parameter or field of a primitive type
+type primitiveUse struct {
+	name     string // As written
+	typeName string // Primitive type, references removed
+	use      models.PrimitiveUse
+}
+
+// NewPrimitiveObsessionDetector creates a new primitive obsession detector
+func NewPrimitiveObsessionDetector(config *DetectorConfig) *PrimitiveObsessionDetector {
+	if config == nil {
+		config = DefaultDetectorConfig()
+	}
+	return &PrimitiveObsessionDetector{config: config, uses: make(map[primitiveKey][]primitiveUse)}
+}
+
+// Name returns the name of this detector
+func (d *PrimitiveObsessionDetector) Name() string {
+	return "Primitive Obsession Detector"
+}
+
+// Description returns a description of what this detector checks for
+func (d *PrimitiveObsessionDetector) Description() string {
+	return "Finds parameters and fields of primitive types recurring under one name across signatures"
+}
+
+// Detect records the primitive parameters and fields of a file when the
+// check is enabled. It reports nothing: call Violations once every file
+// was detected.
+func (d *PrimitiveObsessionDetector) Detect(fileInfo *models.FileInfo, astInfo interface{}) []*models.Violation {
+	if d.config.MaxPrimitiveRepeats <= 0 {
+		return nil
+	}
+
+	var found []primitiveUse
+	var language string
+	switch info := astInfo.(type) {
+	case *types.GoASTInfo:
+		if info == nil || strings.HasSuffix(fileInfo.Path, "_test.go") {
+			return nil
+		}
+		found, language = goPrimitiveUses(fileInfo.Path, info), LanguageGo
+	case *types.RustASTInfo:
+		if info == nil {
+			return nil
+		}
+		found, language = rustPrimitiveUses(fileInfo.Path, info), LanguageRust
+	default:
+		return nil
+	}
+
+	d.mutex.Lock()
+	defer d.mutex.Unlock()
+	for _, use := range found {
+		words := identifierWords(use.name)
+		name := strings.Join(words, "_")
+		if len(name) < 3 || len(words) == 1 && primitiveGenericNames[name] {
+			continue
+		}
+		key := primitiveKey{language: language, name: name, typeName: use.typeName}
+		d.uses[key] = append(d.uses[key], use)
+	}
+	return nil
+}
+
+// goPrimitiveUses returns the parameters of the functions of a Go file and
+// the fields of its structs whose type is a primitive
+func goPrimitiveUses(filePath string, info *types.GoASTInfo) []primitiveUse {
+	var found []primitiveUse
+	for _, fn := range info.Functions {
+		if fn == nil {
+			continue
+		}
+		owner := fn.Name
+		if fn.ReceiverType != "" {
+			owner = strings.TrimPrefix(fn.ReceiverType, "*") + "." + fn.Name
+		}
+		for _, param := range fn.Parameters {
+			if goPrimitiveTypes[param.Type] {
+				found = append(found, primitiveUse{name: param.Name, typeName: param.Type, use: models.PrimitiveUse{
+					File: filePath, Line: fn.StartLine, Column: fn.StartColumn, Owner: owner, Kind: "parameter",
+				}})
+			}
+		}
+	}
+
+	for _, typeInfo := range info.Types {
+		if typeInfo == nil || typeInfo.ASTNode == nil {
+			continue
+		}
+		structType, ok := typeInfo.ASTNode.Type.(*ast.StructType)
+		if !ok || structType.Fields == nil {
+			continue
+		}
+		for _, field := range structType.Fields.List {
+			ident, ok := field.Type.(*ast.Ident)
+			if !ok || !goPrimitiveTypes[ident.Name] {
+				continue
+			}
+			for _, name := range field.Names {
+				line, column := typeInfo.StartLine, typeInfo.StartColumn
+				if info.FileSet != nil {
+					pos := info.FileSet.Position(name.Pos())
+					line, column = pos.Line, pos.Column
+				}
+				found = append(found, primitiveUse{name: name.Name, typeName: ident.Name, use: models.PrimitiveUse{
+					File: filePath, Line: line, Column: column, Owner: typeInfo.Name, Kind: "field",
+				}})
+			}
+		}
+	}
+	return found
+}
+
+// rustPrimitiveUses returns the parameters of the functions of a Rust file
+// and the named fields of its structs whose type is a primitive, taken by
+// value or by reference. Methods of trait impls are skipped, as the trait
+// fixes their signature.
+func rustPrimitiveUses(filePath string, info *types.RustASTInfo) []primitiveUse {
+	var found []primitiveUse
+	for _, fn := range info.Functions {
+		if fn == nil || rustInTraitImpl(info.Impls, fn.StartLine) {
+			continue
+		}
+		for _, param := range fn.Parameters {
+			if primitive := rustPrimitiveType(param.Type); primitive != "" {
+				found = append(found, primitiveUse{name: param.Name, typeName: primitive, use: models.PrimitiveUse{
+					File: filePath, Line: fn.StartLine, Column: fn.StartColumn, Owner: fn.Name, Kind: "parameter",
+				}})
+			}
+		}
+	}
+
+	tokens := info.Tokens
+	for _, structInfo := range info.Structs {
+		if structInfo == nil {
+			continue
+		}
+		open := rustStructBody(tokens, structInfo)
+		if open < 0 || tokens[open].Text != "{" {
+			continue
+		}
+		for _, field := range rustListEntries(tokens, open) {
+			colon := rustTopLevelIndex(field, ":")
+			if colon <= 0 {
+				continue
+			}
+			if primitive := rustPrimitiveType(rustTypeText(field[colon+1:])); primitive != "" {
+				name := field[colon-1]
+				found = append(found, primitiveUse{name: name.Text, typeName: primitive, use: models.PrimitiveUse{
+					File: filePath, Line: name.Line, Column: name.Column, Owner: structInfo.Name, Kind: "field",
+				}})
+			}
+		}
+	}
+	return found
+}
+
+// rustPrimitiveType returns the primitive a Rust type is, behind references
+// and paths such as std::string::String, or "" for other types. str is
+// returned as String.
+func rustPrimitiveType(typeName string) string {
+	if strings.ContainsAny(typeName, "<[(") {
+		return ""
+	}
+	base := rustBaseType(typeName)
+	if !rustPrimitiveTypes[base] {
+		return ""
+	}
+	if base == "str" {
+		return "String"
+	}
+	return base
+}
+
+// Reset clears the recorded uses; call it at the start of each scan
+func (d *PrimitiveObsessionDetector) Reset() {
+	d.mutex.Lock()
+	defer d.mutex.Unlock()
+	d.uses = make(map[primitiveKey][]primitiveUse)
+}
+
+// Clusters returns the names recorded since the last Reset that recur with
+// one primitive type in more functions and structs than allowed, most
+// recurring first. A cluster is named as written at its first use.
+func (d *PrimitiveObsessionDetector) Clusters() []*models.PrimitiveCluster {
+	d.mutex.Lock()
+	defer d.mutex.Unlock()
+
+	var clusters []*models.PrimitiveCluster
+	for key, found := range d.uses {
+		cluster := &models.PrimitiveCluster{Type: key.typeName, Language: key.language}
+		names := make(map[models.PrimitiveUse]string, len(found))
+		for _, use := range found {
+			cluster.Uses = append(cluster.Uses, use.use)
+			names[use.use] = use.name
+		}
+		if cluster.Signatures() <= d.config.MaxPrimitiveRepeats {
+			continue
+		}
+		models.SortPrimitiveUses(cluster.Uses)
+		cluster.Name = names[cluster.Uses[0]]
+		cluster.Newtype = newtypeName(key.language, cluster.Name)
+		clusters = append(clusters, cluster)
+	}
+	models.SortPrimitiveClusters(clusters)
+	return clusters
+}
+
+// Violations reports each cluster once, at its first use, with the other
+// uses in the description
+func (d *PrimitiveObsessionDetector) Violations() []*models.Violation {
+	var violations []*models.Violation
+	for _, cluster := range d.Clusters() {
+		first := cluster.Uses[0]
+		declared := fmt.Sprintf("%s: %s", cluster.Name, cluster.Type)
+		rule := RustPrimitiveObsession
+		newtype := fmt.Sprintf("pub struct %s(%s);", cluster.Newtype, cluster.Type)
+		if cluster.Language == LanguageGo {
+			declared = fmt.Sprintf("%s %s", cluster.Name, cluster.Type)
+			rule = PrimitiveObsession
+			newtype = fmt.Sprintf("type %s %s", cluster.Newtype, cluster.Type)
+		}
+
+		violations = append(violations, &models.Violation{
+			Type:        models.ViolationTypePrimitiveObsession,
+			Severity:    models.SeverityLow,
+			Message:     fmt.Sprintf("'%s' recurs in %d functions and structs (max: %d)", declared, cluster.Signatures(), d.config.MaxPrimitiveRepeats),
+			Description: primitiveUsesDescription(cluster.Uses),
+			File:        first.File,
+			Line:        first.Line,
+			Column:      first.Column,
+			Rule:        rule,
+			Suggestion: fmt.Sprintf("Introduce a type such as `%s` and use it wherever '%s' appears, "+
+				"so it cannot be swapped with another %s and is validated in one place", newtype, cluster.Name, cluster.Type),
+			CodeSnippet: declared,
+		})
+	}
+	sort.SliceStable(violations, func(i, j int) bool {
+		if violations[i].File != violations[j].File {
+			return violations[i].File < violations[j].File
+		}
+		return violations[i].Line < violations[j].Line
+	})
+	return violations
+}
+
+// primitiveUsesDescription lists where a cluster recurs, up to five uses
+func primitiveUsesDescription(uses []models.PrimitiveUse) string {
+	const shown = 5
+	described := make([]string, 0, shown)
+	for _, use := range uses[:min(len(uses), shown)] {
+		described = append(described, fmt.Sprintf("a %s of %s (%s:%d)", use.Kind, use.Owner, use.File, use.Line))
+	}
+	description := "Declared as " + strings.Join(described, ", ")
+	if len(uses) > shown {
+		description += fmt.Sprintf(" and %d more", len(uses)-shown)
+	}
+	return description
+}
+
+// newtypeName turns a parameter or field name into the name of a type:
+// UserId in Rust and UserID in Go, which keeps common initialisms upper case
+func newtypeName(language, name string) string {
+	var b strings.Builder
+	for _, word := range identifierWords(name) {
+		if _, ok := commonInitialisms[strings.ToUpper(word)]; ok && language == LanguageGo {
+			b.WriteString(strings.ToUpper(word))
+			continue
+		}
+		b.WriteString(upperFirst(word))
+	}
+	return b.String()
+}
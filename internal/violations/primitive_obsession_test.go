@@ -0,0 +1,114 @@
+package violations
+
+import (
+	"strings"
+	"testing"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+func TestPrimitiveObsessionDetector(t *testing.T) {
+	goInfo := parseGoCode(t, `package users
+
+type Account struct {
+	UserID uint64
+	Email  string
+	Active bool
+}
+
+func Load(userID uint64) {}
+
+func (s *Store) Delete(userID uint64, value string) {}
+`)
+	goInfo.Functions[0].Parameters = []types.ParameterInfo{{Name: "userID", Type: "uint64"}}
+	goInfo.Functions[1].Parameters = []types.ParameterInfo{{Name: "userID", Type: "uint64"}, {Name: "value", Type: "string"}}
+
+	source := `pub struct Order {
+    pub user_id: u64,
+    email: String,
+}
+
+pub fn ship(user_id: u64, email: &str) {}
+
+fn notify(email: &String, to: &str) {}`
+	rustInfo := &types.RustASTInfo{
+		Tokens: rustTestTokens(source),
+		Functions: []*types.RustFunctionInfo{
+			{Name: "ship", StartLine: 6, EndLine: 6, StartColumn: 1, Parameters: []types.RustParameterInfo{
+				{Name: "user_id", Type: "u64"}, {Name: "email", Type: "&str", IsRef: true},
+			}},
+			{Name: "notify", StartLine: 8, EndLine: 8, StartColumn: 1, Parameters: []types.RustParameterInfo{
+				{Name: "email", Type: "&String", IsRef: true}, {Name: "to", Type: "&str", IsRef: true},
+			}},
+		},
+		Structs: []*types.RustStructInfo{{Name: "Order", StartLine: 1, EndLine: 4, StartColumn: 1}},
+	}
+
+	config := DefaultDetectorConfig()
+	detector := NewPrimitiveObsessionDetector(config)
+	detector.Detect(&models.FileInfo{Path: "internal/users/users.go"}, goInfo)
+	detector.Detect(&models.FileInfo{Path: "src/orders.rs"}, rustInfo)
+	if clusters := detector.Clusters(); len(clusters) != 0 {
+		t.Fatalf("Expected nothing recorded without primitive_repeats, got %d clusters", len(clusters))
+	}
+
+	config.MaxPrimitiveRepeats = 2
+	detector.Detect(&models.FileInfo{Path: "internal/users/users.go"}, goInfo)
+	detector.Detect(&models.FileInfo{Path: "internal/users/users_test.go"}, goInfo)
+	detector.Detect(&models.FileInfo{Path: "src/orders.rs"}, rustInfo)
+
+	// user_id: u64 recurs in two signatures only, and the test file is skipped
+	clusters := detector.Clusters()
+	if len(clusters) != 2 {
+		t.Fatalf("Expected 2 clusters, got %d", len(clusters))
+	}
+	if c := clusters[0]; c.Name != "UserID" || c.Type != "uint64" || c.Newtype != "UserID" || c.Signatures() != 3 || c.Files() != 1 {
+		t.Errorf("Expected UserID uint64 in 3 signatures, got %+v", c)
+	}
+	if c := clusters[1]; c.Name != "email" || c.Type != "String" || c.Newtype != "Email" || c.Signatures() != 3 {
+		t.Errorf("Expected email: String in 3 signatures with &str and &String, got %+v", c)
+	}
+
+	violations := detector.Violations()
+	if len(violations) != 2 {
+		t.Fatalf("Expected 2 violations, got %d", len(violations))
+	}
+	goViolation, rustViolation := violations[0], violations[1]
+	if goViolation.Rule != PrimitiveObsession || goViolation.Line != 4 || goViolation.Column != 2 {
+		t.Errorf("Expected primitive-obsession at 4:2, got %s at %d:%d", goViolation.Rule, goViolation.Line, goViolation.Column)
+	}
+	if goViolation.Message != "'UserID uint64' recurs in 3 functions and structs (max: 2)" {
+		t.Errorf("Unexpected message: %s", goViolation.Message)
+	}
+	if !strings.Contains(goViolation.Description, "a parameter of Store.Delete (internal/users/users.go:11)") {
+		t.Errorf("Expected the description to list the method, got %s", goViolation.Description)
+	}
+	if !strings.Contains(goViolation.Suggestion, "`type UserID uint64`") {
+		t.Errorf("Expected a defined type in the suggestion, got %s", goViolation.Suggestion)
+	}
+	if rustViolation.Rule != RustPrimitiveObsession || rustViolation.Line != 3 || rustViolation.Column != 5 {
+		t.Errorf("Expected rust-primitive-obsession at 3:5, got %s at %d:%d", rustViolation.Rule, rustViolation.Line, rustViolation.Column)
+	}
+	if !strings.Contains(rustViolation.Suggestion, "`pub struct Email(String);`") {
+		t.Errorf("Expected a newtype in the suggestion, got %s", rustViolation.Suggestion)
+	}
+
+	detector.Reset()
+	if clusters := detector.Clusters(); len(clusters) != 0 {
+		t.Errorf("Expected no clusters after Reset, got %d", len(clusters))
+	}
+}
+
+func TestNewtypeName(t *testing.T) {
+	for _, tc := range []struct{ language, name, want string }{
+		{LanguageRust, "user_id", "UserId"},
+		{LanguageGo, "userID", "UserID"},
+		{LanguageGo, "homepageUrl", "HomepageURL"},
+		{LanguageRust, "email", "Email"},
+	} {
+		if got := newtypeName(tc.language, tc.name); got != tc.want {
+			t.Errorf("newtypeName(%s, %q) = %q, want %q", tc.language, tc.name, got, tc.want)
+		}
+	}
+}
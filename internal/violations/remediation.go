@@ -0,0 +1,62 @@
+package violations
+
+import (
+	"github.com/ericfisherdev/goclean/internal/models"
+)
+
+// DefaultRemediationMinutes is the estimated time to fix a violation of a
+// category, in minutes. Categories not listed take 15 minutes.
+var DefaultRemediationMinutes = map[string]int{
+	models.CategoryComplexity:      30,
+	models.CategoryNaming:          5,
+	models.CategoryDocumentation:   10,
+	models.CategoryMaintainability: 10,
+	models.CategoryDuplication:     45,
+	models.CategoryStructure:       60,
+	models.CategorySuppression:     5,
+	models.CategoryGeneral:         15,
+
+	string(models.RustCategoryNaming):          5,
+	string(models.RustCategorySafety):          30,
+	string(models.RustCategoryOwnership):       20,
+	string(models.RustCategoryPerformance):     15,
+	string(models.RustCategoryErrorHandling):   15,
+	string(models.RustCategoryPatternMatching): 10,
+	string(models.RustCategoryTraits):          20,
+	string(models.RustCategoryMacros):          20,
+	string(models.RustCategoryAsync):           30,
+	string(models.RustCategoryModules):         20,
+}
+
+// defaultRemediation is the estimate of categories without their own
+const defaultRemediation = 15
+
+// RemediationConfig estimates the time to fix violations
+type RemediationConfig struct {
+	Rules map[string]int // Minutes by rule ID, replacing the estimate of the rule's category
+}
+
+// Estimate returns the minutes to fix a violation. Violations without a
+// rule ID are matched by type.
+func (c *RemediationConfig) Estimate(violation *models.Violation) int {
+	rule := violation.Rule
+	if rule == "" {
+		rule = string(violation.Type)
+	}
+	if minutes, found := c.Rules[rule]; found {
+		return minutes
+	}
+	if minutes, found := DefaultRemediationMinutes[violation.Type.Category()]; found {
+		return minutes
+	}
+	return defaultRemediation
+}
+
+// Assign sets the estimate on violations that have none
+func (c *RemediationConfig) Assign(violations []*models.Violation) {
+	for _, violation := range violations {
+		if violation.Remediation == 0 {
+			violation.Remediation = c.Estimate(violation)
+		}
+	}
+}
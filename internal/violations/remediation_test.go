@@ -0,0 +1,27 @@
+package violations
+
+import (
+	"testing"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+)
+
+func TestRemediationEstimates(t *testing.T) {
+	config := &RemediationConfig{Rules: map[string]int{"magic-number": 2, "todo_marker": 0}}
+
+	found := []*models.Violation{
+		{Rule: "magic-number", Type: models.ViolationTypeMagicNumber},
+		{Rule: "function-length", Type: models.ViolationTypeFunctionLength},
+		{Type: models.ViolationTypeTodo},
+		{Rule: "plugin-rule", Type: models.ViolationTypePlugin},
+		{Rule: "cyclomatic-complexity", Type: models.ViolationTypeCyclomaticComplexity, Remediation: 90},
+	}
+	config.Assign(found)
+
+	expected := []int{2, DefaultRemediationMinutes[models.CategoryComplexity], 0, DefaultRemediationMinutes[models.CategoryGeneral], 90}
+	for i, violation := range found {
+		if violation.Remediation != expected[i] {
+			t.Errorf("Violation %d: expected %d minutes, got %d", i, expected[i], violation.Remediation)
+		}
+	}
+}
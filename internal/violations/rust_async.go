@@ -0,0 +1,299 @@
+package violations
+
+import (
+	"fmt"
+	"strings"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+// Rules of the async detector
+const (
+	RustBlockingInAsync = "rust-blocking-in-async"
+	RustSequentialAwait = "rust-sequential-await"
+)
+
+// defaultRustBlockingFunctions block the thread they run on
+var defaultRustBlockingFunctions = []string{
+	"std::thread::sleep",
+	"std::fs::read", "std::fs::read_to_string", "std::fs::read_dir", "std::fs::write", "std::fs::copy",
+	"std::fs::rename", "std::fs::remove_file", "std::fs::remove_dir_all", "std::fs::create_dir_all",
+	"std::fs::metadata", "std::fs::File::open", "std::fs::File::create",
+	"std::net::TcpStream::connect", "std::net::TcpListener::bind", "std::net::UdpSocket::bind",
+	"std::io::stdin", "reqwest::blocking::get",
+	".blocking_lock", ".blocking_read", ".blocking_write", ".blocking_recv", ".blocking_send",
+}
+
+// rustBlockingOffloads run their closure argument where blocking is allowed
+var rustBlockingOffloads = map[string]bool{"spawn_blocking": true, "block_in_place": true}
+
+// RustAsyncDetector reports calls that block the thread inside async fn
+// bodies. A blocked executor thread stalls every task scheduled on it, so a
+// single std::thread::sleep in a request handler can stall a whole service.
+// As advice, it also reports loops that await on every iteration.
+type RustAsyncDetector struct {
+	config   *DetectorConfig
+	blocking *rustCallMatcher
+}
+
+// NewRustAsyncDetector creates a new Rust async detector
+func NewRustAsyncDetector(config *DetectorConfig) *RustAsyncDetector {
+	if config == nil {
+		config = DefaultDetectorConfig()
+	}
+
+	functions := defaultRustBlockingFunctions
+	if config.RustConfig != nil {
+		functions = append(append([]string(nil), functions...), config.RustConfig.BlockingFunctions...)
+	}
+	return &RustAsyncDetector{
+		config:   config,
+		blocking: newRustCallMatcher(functions),
+	}
+}
+
+// Name returns the name of this detector
+func (d *RustAsyncDetector) Name() string {
+	return "Rust Async Analysis"
+}
+
+// Description returns a description of what this detector checks for
+func (d *RustAsyncDetector) Description() string {
+	return "Detects blocking calls such as std::thread::sleep, std::fs and std::net I/O and .blocking_lock() inside async functions, and loops that await one iteration at a time"
+}
+
+// Detect analyzes the async functions of a Rust file
+func (d *RustAsyncDetector) Detect(fileInfo *models.FileInfo, astInfo interface{}) []*models.Violation {
+	rust := d.config.RustConfig
+	if rust == nil {
+		return nil
+	}
+	info, ok := astInfo.(*types.RustASTInfo)
+	if !ok || info == nil {
+		return nil
+	}
+
+	var violations []*models.Violation
+	for _, fn := range info.Functions {
+		if !fn.IsAsync {
+			continue
+		}
+		if rust.DetectBlockingInAsync {
+			violations = append(violations, d.blockingCalls(fileInfo.Path, info, fn)...)
+		}
+		if rust.DetectSequentialAwaits {
+			violations = append(violations, d.sequentialAwaits(fileInfo.Path, info, fn)...)
+		}
+	}
+	return violations
+}
+
+// blockingCalls reports the blocking calls in the body of an async fn. Calls
+// that are awaited, and calls inside spawn_blocking or block_in_place, are
+// not reported.
+func (d *RustAsyncDetector) blockingCalls(filePath string, info *types.RustASTInfo, fn *types.RustFunctionInfo) []*models.Violation {
+	tokens := info.Tokens
+	_, _, body, end := rustFunctionSpan(tokens, fn)
+	if body < 0 {
+		return nil
+	}
+
+	var violations []*models.Violation
+	for i := body + 1; i < end; i++ {
+		if rustBlockingOffloads[tokens[i].Text] && i+1 < end && tokens[i+1].Text == "(" {
+			i = rustClosingParen(tokens, i+1)
+			continue
+		}
+		if inner := innermostRustFunction(info.Functions, tokens[i].Line); inner != fn {
+			continue
+		}
+		call, start, open := d.blocking.match(tokens, i)
+		if call == "" {
+			continue
+		}
+		i = open
+		if closing := rustClosingParen(tokens, open); closing+2 < len(tokens) && tokens[closing+1].Text == "." && tokens[closing+2].Text == "await" {
+			continue
+		}
+		violations = append(violations, &models.Violation{
+			Type:       models.ViolationTypeRustBlockingInAsync,
+			Severity:   models.SeverityHigh,
+			Message:    fmt.Sprintf("Blocking call %s() in async fn '%s'", call, fn.Name),
+			File:       filePath,
+			Line:       tokens[start].Line,
+			Column:     tokens[start].Column,
+			Rule:       RustBlockingInAsync,
+			Suggestion: rustBlockingSuggestion(call),
+		})
+	}
+	return violations
+}
+
+// sequentialAwaits reports the for and while loops of an async fn that await
+// in their body, so each iteration waits for the previous one to finish.
+// Awaits in nested async blocks are not counted, and nested loops are
+// reported on their own.
+func (d *RustAsyncDetector) sequentialAwaits(filePath string, info *types.RustASTInfo, fn *types.RustFunctionInfo) []*models.Violation {
+	if info.Syntax == nil {
+		return nil
+	}
+
+	tokens := info.Tokens
+	var violations []*models.Violation
+	for _, loop := range types.FindRustNodes(info.Syntax, types.RustNodeFor, types.RustNodeWhile) {
+		if innermostRustFunction(info.Functions, loop.StartLine) != fn {
+			continue
+		}
+		start := rustTokenIndex(tokens, loop.StartLine, loop.StartColumn)
+		for start < len(tokens) && tokens[start].Text != "for" && tokens[start].Text != "while" {
+			start++ // Skip a loop label
+		}
+		if start >= len(tokens) {
+			continue
+		}
+		await := rustLoopAwait(tokens, start)
+		if await < 0 {
+			continue
+		}
+
+		keyword := tokens[start]
+		violations = append(violations, &models.Violation{
+			Type:        models.ViolationTypeRustInefficientIteration,
+			Severity:    models.SeverityInfo,
+			Message:     fmt.Sprintf("%s loop in async fn '%s' awaits on every iteration", keyword.Text, fn.Name),
+			Description: fmt.Sprintf("The .await on line %d runs the iterations one at a time", tokens[await].Line),
+			File:        filePath,
+			Line:        keyword.Line,
+			Column:      keyword.Column,
+			Rule:        RustSequentialAwait,
+			Suggestion: "Run the iterations concurrently with futures::future::join_all, FuturesUnordered or StreamExt::buffer_unordered, " +
+				"or set detect_sequential_awaits: false where the order matters",
+		})
+	}
+	return violations
+}
+
+// rustLoopBody returns the index of the brace opening the body of the loop
+// whose keyword is at start, skipping parentheses and brackets in the header
+func rustLoopBody(tokens []types.RustTokenInfo, start int) int {
+	i := start + 1
+	for i < len(tokens) && tokens[i].Text != "{" {
+		if tokens[i].Text == "(" || tokens[i].Text == "[" {
+			i = rustGroupEnd(tokens, i)
+			continue
+		}
+		i++
+	}
+	return i
+}
+
+// rustLoopAwait returns the index of the first .await in the body of the loop
+// whose keyword is at start, or -1. The headers of nested loops run on every
+// iteration and are searched; their bodies are not.
+func rustLoopAwait(tokens []types.RustTokenInfo, start int) int {
+	body := rustLoopBody(tokens, start)
+	if body >= len(tokens) {
+		return -1
+	}
+	end := rustGroupEnd(tokens, body) - 1
+	for i := body + 1; i < end; i++ {
+		switch text := tokens[i].Text; {
+		case text == "." && tokens[i+1].Text == "await":
+			return i + 1
+		case text == "async" && (tokens[i+1].Text == "{" || tokens[i+1].Text == "move"):
+			for i < end && tokens[i].Text != "{" {
+				i++
+			}
+			i = rustGroupEnd(tokens, i) - 1
+		case (text == "for" || text == "while") && tokens[i+1].Text != "<":
+			nested := min(rustLoopBody(tokens, i), end)
+			for j := i; j+1 < nested; j++ {
+				if tokens[j].Text == "." && tokens[j+1].Text == "await" {
+					return j + 1
+				}
+			}
+			if nested < end {
+				i = rustGroupEnd(tokens, nested) - 1
+			}
+		}
+	}
+	return -1
+}
+
+// rustCallMatcher matches calls against a list of functions. Paths match
+// calls written with at least their last two segments, e.g. thread::sleep
+// for std::thread::sleep, unless the path has a single segment; entries
+// starting with a dot match method calls.
+type rustCallMatcher struct {
+	paths   map[string]bool // Every written form of the paths, e.g. fs::read and std::fs::read
+	methods map[string]bool
+}
+
+// newRustCallMatcher creates a matcher of the given functions
+func newRustCallMatcher(functions []string) *rustCallMatcher {
+	m := &rustCallMatcher{
+		paths:   make(map[string]bool),
+		methods: make(map[string]bool),
+	}
+	for _, function := range functions {
+		if method, ok := strings.CutPrefix(function, "."); ok {
+			m.methods[method] = true
+			continue
+		}
+		segments := strings.Split(function, "::")
+		for first := 0; first < len(segments)-1 || first == 0; first++ {
+			m.paths[strings.Join(segments[first:], "::")] = true
+		}
+	}
+	return m
+}
+
+// match returns the written path or .method of a matching call starting at
+// token i, the index of its first token and the index of its opening
+// parenthesis. The path is empty when no matching call starts there. A
+// turbofish, as in unbounded_channel::<Event>(), is not part of the path.
+func (m *rustCallMatcher) match(tokens []types.RustTokenInfo, i int) (string, int, int) {
+	if tokens[i].Text == "." {
+		if i+2 < len(tokens) && m.methods[tokens[i+1].Text] && tokens[i+2].Text == "(" {
+			return "." + tokens[i+1].Text, i + 1, i + 2
+		}
+		return "", 0, 0
+	}
+	if tokens[i].Kind != types.RustTokenIdent || i > 0 && (tokens[i-1].Text == "::" || tokens[i-1].Text == ".") {
+		return "", 0, 0
+	}
+
+	var segments []string
+	k := i
+	for ; k+1 < len(tokens) && tokens[k].Kind == types.RustTokenIdent; k += 2 {
+		segments = append(segments, tokens[k].Text)
+		if tokens[k+1].Text != "::" {
+			k++
+			break
+		}
+	}
+	if k < len(tokens) && tokens[k].Text == "<" && tokens[k-1].Text == "::" {
+		k = rustGroupEnd(tokens, k)
+	}
+	path := strings.Join(segments, "::")
+	if k >= len(tokens) || tokens[k].Text != "(" || !m.paths[path] {
+		return "", 0, 0
+	}
+	return path, i, k
+}
+
+// rustBlockingSuggestion names the async replacement of a blocking call
+func rustBlockingSuggestion(call string) string {
+	switch {
+	case strings.HasSuffix(call, "sleep"):
+		return "Use tokio::time::sleep(...).await, which suspends the task instead of the thread"
+	case strings.HasPrefix(call, ".blocking_"):
+		return fmt.Sprintf("Await the async form, .%s().await, instead", strings.TrimPrefix(call, ".blocking_"))
+	case strings.Contains(call, "fs::") || strings.HasPrefix(call, "File::"):
+		return "Use tokio::fs with .await, or run the call in tokio::task::spawn_blocking"
+	case strings.Contains(call, "net::") || strings.HasPrefix(call, "Tcp") || strings.HasPrefix(call, "Udp"):
+		return "Use the tokio::net types with .await instead of std::net"
+	}
+	return "Await an async equivalent, or run the call in tokio::task::spawn_blocking"
+}
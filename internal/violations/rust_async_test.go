@@ -0,0 +1,119 @@
+package violations
+
+import (
+	"testing"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+func TestRustAsyncDetector(t *testing.T) {
+	source := `async fn handler(state: Arc<State>) -> Result<String> {
+    std::thread::sleep(Duration::from_millis(10));
+    let config = fs::read_to_string("config.toml")?;
+    let cached = tokio::fs::read_to_string("cache").await?;
+    let guard = state.cache.blocking_lock();
+    let body = tokio::task::spawn_blocking(move || std::fs::read("body")).await?;
+    legacy_client::fetch(&config);
+    Ok(config)
+}
+
+fn sync_handler() {
+    std::thread::sleep(Duration::from_millis(10));
+}`
+	astInfo := &types.RustASTInfo{
+		Tokens: rustTestTokens(source),
+		Functions: []*types.RustFunctionInfo{
+			{Name: "handler", StartLine: 1, StartColumn: 1, EndLine: 9, IsAsync: true},
+			{Name: "sync_handler", StartLine: 11, StartColumn: 1, EndLine: 13},
+		},
+	}
+	fileInfo := &models.FileInfo{Path: "src/handler.rs"}
+
+	config := DefaultDetectorConfig()
+	violations := NewRustAsyncDetector(config).Detect(fileInfo, astInfo)
+	if len(violations) != 3 {
+		t.Fatalf("Expected the sleep, the std read and the blocking lock, got %+v", violations)
+	}
+	for i, want := range []struct{ line, column int }{{2, 5}, {3, 18}, {5, 29}} {
+		if v := violations[i]; v.Line != want.line || v.Column != want.column || v.Rule != RustBlockingInAsync {
+			t.Errorf("Expected violation %d at %d:%d, got %+v", i, want.line, want.column, v)
+		}
+	}
+	if v := violations[0]; v.Message != "Blocking call std::thread::sleep() in async fn 'handler'" ||
+		v.Suggestion != "Use tokio::time::sleep(...).await, which suspends the task instead of the thread" {
+		t.Errorf("Unexpected sleep violation %+v", v)
+	}
+	if v := violations[2]; v.Message != "Blocking call .blocking_lock() in async fn 'handler'" ||
+		v.Suggestion != "Await the async form, .lock().await, instead" {
+		t.Errorf("Unexpected lock violation %+v", v)
+	}
+
+	config.RustConfig.BlockingFunctions = []string{"legacy_client::fetch"}
+	if violations := NewRustAsyncDetector(config).Detect(fileInfo, astInfo); len(violations) != 4 || violations[3].Line != 7 {
+		t.Errorf("Expected the configured blocking function to be reported, got %+v", violations)
+	}
+
+	config.RustConfig.DetectBlockingInAsync = false
+	if violations := NewRustAsyncDetector(config).Detect(fileInfo, astInfo); len(violations) != 0 {
+		t.Errorf("Expected no violations with detect_blocking_in_async off, got %d", len(violations))
+	}
+}
+
+func TestRustAsyncDetector_SequentialAwaits(t *testing.T) {
+	source := `async fn sync_all(ids: Vec<u64>, rx: &mut Receiver<u64>, batches: Vec<Vec<u64>>) {
+    for id in ids {
+        fetch(id).await;
+    }
+    while let Some(id) = rx.recv().await {
+        println!("{}", id);
+    }
+    let mut futures = Vec::new();
+    for id in 0..3 {
+        futures.push(async move { fetch(id).await });
+    }
+    'outer: for batch in batches {
+        for id in batch {
+            fetch(id).await;
+        }
+    }
+}`
+	root := &types.RustNode{Kind: types.RustNodeFile, StartLine: 1}
+	root.AddChild(&types.RustNode{Kind: types.RustNodeFor, StartLine: 2, StartColumn: 5, EndLine: 4, EndColumn: 5})
+	root.AddChild(&types.RustNode{Kind: types.RustNodeWhile, StartLine: 5, StartColumn: 5, EndLine: 7, EndColumn: 5})
+	root.AddChild(&types.RustNode{Kind: types.RustNodeFor, StartLine: 9, StartColumn: 5, EndLine: 11, EndColumn: 5})
+	outer := &types.RustNode{Kind: types.RustNodeFor, StartLine: 12, StartColumn: 5, EndLine: 16, EndColumn: 5}
+	outer.AddChild(&types.RustNode{Kind: types.RustNodeFor, StartLine: 13, StartColumn: 9, EndLine: 15, EndColumn: 9})
+	root.AddChild(outer)
+	astInfo := &types.RustASTInfo{
+		Tokens:    rustTestTokens(source),
+		Syntax:    root,
+		Functions: []*types.RustFunctionInfo{{Name: "sync_all", StartLine: 1, StartColumn: 1, EndLine: 17, IsAsync: true}},
+	}
+	fileInfo := &models.FileInfo{Path: "src/sync.rs"}
+
+	config := DefaultDetectorConfig()
+	violations := NewRustAsyncDetector(config).Detect(fileInfo, astInfo)
+	if len(violations) != 2 {
+		t.Fatalf("Expected the loops awaiting in their body, got %+v", violations)
+	}
+	for i, want := range []struct{ line, column int }{{2, 5}, {13, 9}} {
+		if v := violations[i]; v.Line != want.line || v.Column != want.column || v.Rule != RustSequentialAwait || v.Severity != models.SeverityInfo {
+			t.Errorf("Expected violation %d at %d:%d, got %+v", i, want.line, want.column, v)
+		}
+	}
+	if v := violations[0]; v.Message != "for loop in async fn 'sync_all' awaits on every iteration" ||
+		v.Description != "The .await on line 3 runs the iterations one at a time" {
+		t.Errorf("Unexpected violation %+v", v)
+	}
+
+	astInfo.Functions[0].IsAsync = false
+	if violations := NewRustAsyncDetector(config).Detect(fileInfo, astInfo); len(violations) != 0 {
+		t.Errorf("Expected loops outside async functions to be ignored, got %+v", violations)
+	}
+	astInfo.Functions[0].IsAsync = true
+	config.RustConfig.DetectSequentialAwaits = false
+	if violations := NewRustAsyncDetector(config).Detect(fileInfo, astInfo); len(violations) != 0 {
+		t.Errorf("Expected no violations with detect_sequential_awaits off, got %d", len(violations))
+	}
+}
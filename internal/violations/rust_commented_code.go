@@ -1,28 +1,53 @@
 package violations
 
 import (
+	"fmt"
+	"math"
 	"regexp"
 	"strings"
 
 	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/rustsyntax"
 	"github.com/ericfisherdev/goclean/internal/types"
 )
 
-// RustCommentedCodeDetector detects blocks of commented-out Rust code
+// RustCommentedCode is the rule of commented-out Rust code
+const RustCommentedCode = "rust-commented-code"
+
+// RustCommentedCodeDetector detects blocks of commented-out Rust code by
+// reading them as Rust
 type RustCommentedCodeDetector struct {
-	config      *DetectorConfig
-	codePattern *regexp.Regexp
+	config *DetectorConfig
+}
+
+// rustStatementKeywords start a statement or an item, so they cannot follow
+// a name or a value, and mark the text they start as code
+var rustStatementKeywords = map[string]bool{
+	"let": true, "fn": true, "struct": true, "enum": true, "trait": true, "impl": true, "mod": true,
+	"use": true, "static": true, "pub": true, "return": true, "while": true, "loop": true, "match": true,
+}
+
+// rustCodeKeywords mark the text they appear in as code; the others, such
+// as in, as and self, are common in prose
+var rustCodeKeywords = map[string]bool{
+	"if": true, "else": true, "for": true, "const": true, "unsafe": true, "extern": true,
+	"break": true, "continue": true, "async": true, "move": true,
+}
+
+// rustOpeningBrackets pairs each closing bracket with its opening one
+var rustOpeningBrackets = map[string]string{")": "(", "]": "[", "}": "{"}
+
+// rustCommentBlock is a block comment, or a run of line comments on
+// consecutive lines, without its comment markers
+type rustCommentBlock struct {
+	text string
+	line int
 }
 
 // NewRustCommentedCodeDetector creates a new Rust commented code detector
 func NewRustCommentedCodeDetector(config *DetectorConfig) *RustCommentedCodeDetector {
-	// Pattern to detect likely Rust code in comments
-	// Rust-specific keywords and patterns
-	codePattern := regexp.MustCompile(`(?i)(fn |if |for |while |loop |match |let |mut |const |static |struct |enum |trait |impl |pub |mod |use |extern |async |await |unsafe |return |\{|\}|\(|\)|::|->|&mut |&|println!|vec!|Some\(|None|Ok\(|Err\()`)
-	
 	return &RustCommentedCodeDetector{
-		config:      config,
-		codePattern: codePattern,
+		config: config,
 	}
 }
 
@@ -50,208 +75,175 @@ func (d *RustCommentedCodeDetector) Detect(fileInfo *models.FileInfo, astInfo in
 		return violations
 	}
 	
-	// Since we don't have access to raw comments in the current RustASTInfo,
-	// we'll parse the file content directly for comments
-	content, err := d.readFileContent(fileInfo.Path)
-	if err != nil {
-		return violations
+	for _, block := range d.commentBlocks(rustAstInfo.Comments) {
+		if violation := d.createViolation(block.text, block.line, fileInfo.Path); violation != nil {
+			violations = append(violations, violation)
+		}
 	}
 	
-	lines := strings.Split(content, "\n")
-	violations = append(violations, d.analyzeComments(lines, fileInfo.Path)...)
-	
 	return violations
 }
 
-// readFileContent reads the content of a file (simplified implementation)
-func (d *RustCommentedCodeDetector) readFileContent(filePath string) (string, error) {
-	// In a real implementation, we would read the file here
-	// For now, we'll return empty to avoid file system access in this context
-	// TODO: Implement proper file reading or pass content through the interface
-	return "", nil
-}
+// commentBlocks groups the comments of a file that are not doc comments into
+// blocks, leaving out lines that read as documentation
+func (d *RustCommentedCodeDetector) commentBlocks(comments []*types.RustCommentInfo) []rustCommentBlock {
+	var blocks []rustCommentBlock
+	var lines []string
+	start, last, column := 0, 0, 0
+	flush := func() {
+		if len(lines) > 0 {
+			blocks = append(blocks, rustCommentBlock{text: d.withoutDocumentation(lines), line: start})
+		}
+		lines = nil
+	}
 
-// analyzeComments analyzes lines for commented-out Rust code
-func (d *RustCommentedCodeDetector) analyzeComments(lines []string, filePath string) []*models.Violation {
-	var violations []*models.Violation
-	var currentComment strings.Builder
-	var commentStartLine int
-	var inBlockComment bool
-	
-	for i, line := range lines {
-		lineNum := i + 1
-		trimmed := strings.TrimSpace(line)
-		
-		// Handle single-line comments
-		if strings.HasPrefix(trimmed, "//") {
-			if currentComment.Len() == 0 {
-				commentStartLine = lineNum
-			}
-			
-			// Extract comment content
-			commentText := strings.TrimPrefix(trimmed, "//")
-			commentText = strings.TrimSpace(commentText)
-			
-			// Skip obvious documentation patterns
-			if !d.isDocumentation(commentText) {
-				currentComment.WriteString(commentText)
-				currentComment.WriteString("\n")
-			}
-		} else if strings.Contains(trimmed, "/*") && strings.Contains(trimmed, "*/") {
-			// Single-line block comment
-			start := strings.Index(trimmed, "/*")
-			end := strings.Index(trimmed, "*/") + 2
-			if start < end {
-				commentText := trimmed[start+2 : end-2]
-				commentText = strings.TrimSpace(commentText)
-				
-				if !d.isDocumentation(commentText) && d.looksLikeRustCode(commentText) {
-					violation := d.createViolation(commentText, lineNum, filePath)
-					if violation != nil {
-						violations = append(violations, violation)
-					}
-				}
-			}
-		} else if strings.Contains(trimmed, "/*") {
-			// Start of multi-line block comment
-			inBlockComment = true
-			commentStartLine = lineNum
-			
-			start := strings.Index(trimmed, "/*")
-			commentText := trimmed[start+2:]
-			commentText = strings.TrimSpace(commentText)
-			
-			if !d.isDocumentation(commentText) {
-				currentComment.WriteString(commentText)
-				currentComment.WriteString("\n")
-			}
-		} else if inBlockComment {
-			if strings.Contains(trimmed, "*/") {
-				// End of multi-line block comment
-				inBlockComment = false
-				end := strings.Index(trimmed, "*/")
-				commentText := trimmed[:end]
-				commentText = strings.TrimSpace(commentText)
-				
-				if !d.isDocumentation(commentText) {
-					currentComment.WriteString(commentText)
-				}
-				
-				// Check accumulated comment
-				fullComment := currentComment.String()
-				if violation := d.createViolation(fullComment, commentStartLine, filePath); violation != nil {
-					violations = append(violations, violation)
-				}
-				
-				currentComment.Reset()
-			} else {
-				// Middle of multi-line block comment
-				if !d.isDocumentation(trimmed) {
-					currentComment.WriteString(trimmed)
-					currentComment.WriteString("\n")
-				}
-			}
-		} else {
-			// Not a comment line, check accumulated single-line comments
-			if currentComment.Len() > 0 {
-				fullComment := currentComment.String()
-				if violation := d.createViolation(fullComment, commentStartLine, filePath); violation != nil {
-					violations = append(violations, violation)
-				}
-				currentComment.Reset()
-			}
+	for _, comment := range comments {
+		if comment == nil || comment.IsDoc {
+			flush()
+			continue
+		}
+		if comment.IsBlock {
+			flush()
+			text := strings.TrimSuffix(strings.TrimPrefix(comment.Text, "/*"), "*/")
+			blocks = append(blocks, rustCommentBlock{text: d.withoutDocumentation(strings.Split(text, "\n")), line: comment.Line})
+			continue
+		}
+		if len(lines) > 0 && (comment.Line != last+1 || comment.Column != column) {
+			flush()
+		}
+		if len(lines) == 0 {
+			start, column = comment.Line, comment.Column
 		}
+		lines = append(lines, strings.TrimPrefix(comment.Text, "//"))
+		last = comment.Line
 	}
-	
-	// Handle any remaining comment at end of file
-	if currentComment.Len() > 0 {
-		fullComment := currentComment.String()
-		if violation := d.createViolation(fullComment, commentStartLine, filePath); violation != nil {
-			violations = append(violations, violation)
+	flush()
+	return blocks
+}
+
+// withoutDocumentation joins the trimmed lines of a comment that do not
+// read as documentation
+func (d *RustCommentedCodeDetector) withoutDocumentation(lines []string) string {
+	var kept []string
+	for _, line := range lines {
+		line = strings.TrimSpace(line)
+		if line != "" && !d.isDocumentation(line) {
+			kept = append(kept, line)
 		}
 	}
-	
-	return violations
+	return strings.Join(kept, "\n")
 }
 
-// createViolation creates a violation if the comment looks like Rust code
+// createViolation creates a violation if enough of the comment parses as
+// Rust
 func (d *RustCommentedCodeDetector) createViolation(commentText string, line int, filePath string) *models.Violation {
-	if !d.looksLikeRustCode(commentText) {
+	if len(strings.TrimSpace(commentText)) < 15 {
 		return nil
 	}
-	
-	// Count the number of Rust code-like patterns
-	matches := d.codePattern.FindAllString(commentText, -1)
-	if len(matches) >= 2 { // At least 2 Rust patterns to be considered commented code
-		snippet := commentText
-		if len(snippet) > 100 {
-			snippet = snippet[:97] + "..."
-		}
-		
-		return &models.Violation{
-			Type:        models.ViolationTypeCommentedCode,
-			Severity:    models.SeverityLow,
-			File:        filePath,
-			Line:        line,
-			Column:      0,
-			Message:     "Block of commented-out Rust code detected",
-			Suggestion:  "Remove commented-out code. Use version control to preserve old code if needed",
-			CodeSnippet: snippet,
-		}
+
+	confidence := codeConfidence(commentText, parsesAsRust)
+	if !reportsCommentedCode(d.config, confidence) {
+		return nil
+	}
+
+	snippet := commentText
+	if len(snippet) > 100 {
+		snippet = snippet[:97] + "..."
+	}
+	return &models.Violation{
+		Type:        models.ViolationTypeCommentedCode,
+		Severity:    models.SeverityLow,
+		File:        filePath,
+		Line:        line,
+		Column:      0,
+		Message:     "Block of commented-out Rust code detected",
+		Description: fmt.Sprintf("%.0f%% of the comment parses as Rust", confidence*100),
+		Rule:        RustCommentedCode,
+		Suggestion:  "Remove commented-out code. Use version control to preserve old code if needed",
+		CodeSnippet: snippet,
+		Confidence:  math.Round(confidence*100) / 100,
+		Reasons:     commentedCodeReasons(d.config, commentText, parsesAsRust, "Rust"),
 	}
-	
-	return nil
 }
 
-// looksLikeRustCode checks if text looks like commented-out Rust code
-func (d *RustCommentedCodeDetector) looksLikeRustCode(text string) bool {
-	// Check for common Rust patterns
-	if d.codePattern.MatchString(text) {
-		// Additional checks to reduce false positives
-		
-		// If it's very short, it's probably not code
-		if len(text) < 15 {
+// parsesAsRust reports whether src reads as Rust: it lexes, its brackets
+// balance, no name or value follows another without an operator between
+// them, as they do in prose, and it holds a statement, an item, a call or a
+// macro rather than only names
+func parsesAsRust(src string) bool {
+	tokens, _ := rustsyntax.Tokenize(src, types.DefaultRustEdition)
+	if len(tokens) < 2 {
+		return false
+	}
+
+	var open []string
+	code := false
+	for i, tok := range tokens {
+		if tok.Unterminated {
 			return false
 		}
-		
-		// Check for balanced braces/parens (common in code)
-		openBraces := strings.Count(text, "{")
-		closeBraces := strings.Count(text, "}")
-		openParens := strings.Count(text, "(")
-		closeParens := strings.Count(text, ")")
-		
-		// If we have balanced braces or parens, it's likely code
-		if (openBraces > 0 && openBraces == closeBraces) ||
-		   (openParens > 0 && openParens == closeParens) {
-			return true
-		}
-		
-		// Check for Rust-specific assignment patterns
-		if strings.Contains(text, "let ") || strings.Contains(text, "= ") {
-			return true
-		}
-		
-		// Check for function/method calls with Rust syntax
-		if regexp.MustCompile(`\w+\(`).MatchString(text) ||
-		   regexp.MustCompile(`\w+::\w+`).MatchString(text) {
-			return true
+		if i > 0 && !rustTokenMayFollow(tokens[i-1], tok) {
+			return false
 		}
-		
-		// Check for Rust-specific operators and syntax
-		if strings.Contains(text, "->") || strings.Contains(text, "::") ||
-		   strings.Contains(text, "&mut") || strings.Contains(text, "&") {
-			return true
+		if isRustSnippetKeyword(tok) && (rustStatementKeywords[tok.Text] || rustCodeKeywords[tok.Text]) {
+			code = true
 		}
-		
-		// Check for Rust macros
-		if strings.Contains(text, "!") && regexp.MustCompile(`\w+!`).MatchString(text) {
-			return true
+		previousWord := i > 0 && isRustSnippetWord(tokens[i-1])
+		switch tok.Kind {
+		case rustsyntax.Open:
+			// A call, or a block after a name as in `impl Point {`
+			if previousWord && tok.Text != "[" {
+				code = true
+			}
+			open = append(open, tok.Text)
+		case rustsyntax.Close:
+			if len(open) == 0 || open[len(open)-1] != rustOpeningBrackets[tok.Text] {
+				return false
+			}
+			open = open[:len(open)-1]
+		case rustsyntax.Punct:
+			switch {
+			case tok.Text == ";", strings.Contains(tok.Text, "="):
+				code = true
+			case tok.Text == "!":
+				// A macro call, as in println!(...)
+				if previousWord && i+1 < len(tokens) && tokens[i+1].Kind == rustsyntax.Open {
+					code = true
+				}
+			}
 		}
-		
+	}
+	return code && len(open) == 0
+}
+
+// rustTokenMayFollow reports whether next may follow prev in Rust. Two names
+// or values are never adjacent, statements and items cannot start right
+// after one, and a lifetime only follows punctuation, break or continue.
+func rustTokenMayFollow(prev, next rustsyntax.Token) bool {
+	if next.Kind == rustsyntax.Lifetime {
+		return prev.Kind == rustsyntax.Punct || prev.Kind == rustsyntax.Open || prev.Kind == rustsyntax.Close ||
+			prev.Text == "break" || prev.Text == "continue"
+	}
+	if !isRustSnippetWord(prev) && prev.Kind != rustsyntax.Literal {
 		return true
 	}
-	
-	return false
+	switch {
+	case isRustSnippetWord(next), next.Kind == rustsyntax.Literal:
+		return false
+	case isRustSnippetKeyword(next):
+		return !rustStatementKeywords[next.Text]
+	}
+	return true
+}
+
+// isRustSnippetWord reports whether tok is a name rather than a keyword
+func isRustSnippetWord(tok rustsyntax.Token) bool {
+	return tok.Kind == rustsyntax.Ident && !rustsyntax.IsKeyword(tok.Text, types.DefaultRustEdition)
+}
+
+// isRustSnippetKeyword reports whether tok is a keyword
+func isRustSnippetKeyword(tok rustsyntax.Token) bool {
+	return tok.Kind == rustsyntax.Ident && rustsyntax.IsKeyword(tok.Text, types.DefaultRustEdition)
 }
 
 // isDocumentation checks if text is likely documentation rather than commented code
@@ -87,10 +87,7 @@ func TestRustCommentedCodeDetector_Detect_ValidRustAST(t *testing.T) {
 	}
 }
 
-func TestRustCommentedCodeDetector_LooksLikeRustCode(t *testing.T) {
-	config := DefaultDetectorConfig()
-	detector := NewRustCommentedCodeDetector(config)
-	
+func TestParsesAsRust(t *testing.T) {
 	tests := []struct {
 		name     string
 		text     string
@@ -146,13 +143,43 @@ func TestRustCommentedCodeDetector_LooksLikeRustCode(t *testing.T) {
 			text:     "This is just a regular English sentence explaining something.",
 			expected: false,
 		},
+		{
+			name:     "Sentence with a contraction",
+			text:     "let's not do this",
+			expected: false,
+		},
+		{
+			name:     "Missing semicolons",
+			text:     "let x = 10\nlet y = 20",
+			expected: false,
+		},
+		{
+			name:     "Unbalanced braces",
+			text:     "fn broken() { let x = 10;",
+			expected: false,
+		},
+		{
+			name:     "Lifetimes and char literals",
+			text:     "fn first<'a>(s: &'a str) -> char { s.chars().next().unwrap_or('x') }",
+			expected: true,
+		},
+		{
+			name:     "Unterminated string",
+			text:     "let greeting = \"hello;",
+			expected: false,
+		},
+		{
+			name:     "Raw string holding a quote",
+			text:     "let pattern = r#\"say \"hi\"\"#;",
+			expected: true,
+		},
 	}
 	
 	for _, tt := range tests {
 		t.Run(tt.name, func(t *testing.T) {
-			result := detector.looksLikeRustCode(tt.text)
+			result := parsesAsRust(tt.text)
 			if result != tt.expected {
-				t.Errorf("looksLikeRustCode(%q) = %v, want %v", tt.text, result, tt.expected)
+				t.Errorf("parsesAsRust(%q) = %v, want %v", tt.text, result, tt.expected)
 			}
 		})
 	}
@@ -286,4 +313,30 @@ func TestRustCommentedCodeDetector_CreateViolation(t *testing.T) {
 			}
 		})
 	}
-}
\ No newline at end of file
+}
+
+func TestRustCommentedCodeDetector_Detect_Comments(t *testing.T) {
+	detector := NewRustCommentedCodeDetector(DefaultDetectorConfig())
+	rustAstInfo := &types.RustASTInfo{Comments: []*types.RustCommentInfo{
+		{Text: "// Violation: Commented out code block", Line: 6, Column: 5},
+		{Text: "// let old_variable = 42;", Line: 7, Column: 5},
+		{Text: "// if old_variable > 40 {", Line: 8, Column: 5},
+		{Text: "//     println!(\"Old logic\");", Line: 9, Column: 5},
+		{Text: "// }", Line: 10, Column: 5},
+		{Text: "// This is a normal comment explaining the next line (OK)", Line: 22, Column: 5},
+		{Text: "/*\n    fn broken_function() {\n        let x = 10\n        let y = 20  // Missing semicolons\n        return x + y\n    }\n    */", Line: 146, EndLine: 152, Column: 5, IsBlock: true},
+		{Text: "/* This is documentation about the result (OK)\n     * The result represents the midpoint\n     */", Line: 122, EndLine: 125, Column: 5, IsBlock: true},
+		{Text: "/// let documented = example();", Line: 160, Column: 1, IsDoc: true},
+	}}
+
+	violations := detector.Detect(&models.FileInfo{Path: "src/lib.rs"}, rustAstInfo)
+	if len(violations) != 2 {
+		t.Fatalf("Expected the two blocks of code to be reported, got %d: %+v", len(violations), violations)
+	}
+	if v := violations[0]; v.Line != 6 || v.Rule != RustCommentedCode || v.Confidence != 0.75 {
+		t.Errorf("Expected the line comments from line 6 with confidence 0.75, got line %d, rule %s, confidence %v", v.Line, v.Rule, v.Confidence)
+	}
+	if v := violations[1]; v.Line != 146 || v.Confidence != 1 {
+		t.Errorf("Expected the block with syntax errors at line 146 with confidence 1, got line %d, confidence %v", v.Line, v.Confidence)
+	}
+}
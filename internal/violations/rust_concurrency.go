@@ -0,0 +1,607 @@
+package violations
+
+import (
+	"fmt"
+	"os"
+	"regexp"
+	"strings"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+// Rules of the concurrency rule pack
+const (
+	RustMutexAcrossAwait    = "rust-mutex-across-await"
+	RustSharedMutexFanOut   = "rust-shared-mutex-fan-out"
+	RustSharedMutableStatic = "rust-shared-mutable-static"
+	RustSpawnWithoutJoin    = "rust-spawn-without-join"
+	RustUnboundedChannel    = "rust-unbounded-channel"
+)
+
+// defaultRustUnboundedChannels create channels without a capacity, whose
+// queue grows for as long as the receiver falls behind
+var defaultRustUnboundedChannels = []string{
+	"tokio::sync::mpsc::unbounded_channel",
+	"futures::channel::mpsc::unbounded",
+	"crossbeam::channel::unbounded", "crossbeam_channel::unbounded",
+	"flume::unbounded", "async_channel::unbounded", "kanal::unbounded", "kanal::unbounded_async",
+}
+
+// minSharedMutexClones is the number of clones of one Arc<Mutex> handed to
+// spawned tasks at which every task is likely to contend for the lock
+const minSharedMutexClones = 3
+
+var (
+	// An Arc<Mutex> or Arc<RwLock> bound to a name
+	rustSharedMutexPattern = regexp.MustCompile(`^let\s+(?:mut\s+)?(\w+)\b[^=]*=\s*(?:std::sync::)?Arc::new\(\s*(?:[\w:]+::)?(?:Mutex|RwLock)::new\(`)
+
+	rustSpawnCallPattern     = regexp.MustCompile(`\b(?:thread|task|tokio)::spawn(?:_blocking|_local)?\s*\(`)
+	rustDiscardedSpawnPrefix = regexp.MustCompile(`^(?:let\s+_\s*(?::[^=]*)?=\s*)?(?:\w+::)*$`)
+	rustBoundSpawnPrefix     = regexp.MustCompile(`^let\s+(?:mut\s+)?(\w+)\b[^=]*=\s*(?:\w+::)*$`)
+	rustStaticMutPattern     = regexp.MustCompile(`^(?:pub(?:\([^)]*\))?\s+)?static\s+mut\s+(\w+)`)
+	rustLoopPattern          = regexp.MustCompile(`^(?:'\w+:\s*)?(?:for|while|loop)\b`)
+)
+
+// RustConcurrencyDetector detects concurrency smells in async and threaded
+// Rust code. Its rules form the concurrency rule pack, which is off unless
+// enabled in the configuration.
+type RustConcurrencyDetector struct {
+	config        *DetectorConfig
+	codeExtractor *CodeExtractor
+	channels      *rustCallMatcher // Constructors of unbounded channels
+}
+
+// NewRustConcurrencyDetector creates a new Rust concurrency detector
+func NewRustConcurrencyDetector(config *DetectorConfig) *RustConcurrencyDetector {
+	if config == nil {
+		config = DefaultDetectorConfig()
+	}
+	channels := defaultRustUnboundedChannels
+	if config.RustConfig != nil {
+		channels = append(append([]string(nil), channels...), config.RustConfig.UnboundedChannelAPIs...)
+	}
+	return &RustConcurrencyDetector{
+		config:        config,
+		codeExtractor: NewCodeExtractor(),
+		channels:      newRustCallMatcher(channels),
+	}
+}
+
+// Name returns the name of this detector
+func (d *RustConcurrencyDetector) Name() string {
+	return "Rust Concurrency Analysis"
+}
+
+// Description returns a description of what this detector checks for
+func (d *RustConcurrencyDetector) Description() string {
+	return "Detects lock guards held across .await, Arc<Mutex> values shared by many spawned tasks, mutable statics used by several functions, spawned tasks that are never joined and unbounded channels"
+}
+
+// Detect analyzes Rust code for concurrency smells when the concurrency rule pack is enabled
+func (d *RustConcurrencyDetector) Detect(fileInfo *models.FileInfo, astInfo interface{}) []*models.Violation {
+	var violations []*models.Violation
+
+	if d.config.RustConfig == nil || !d.config.RustConfig.EnableConcurrencyPack {
+		return violations
+	}
+
+	rustAstInfo, ok := astInfo.(*types.RustASTInfo)
+	if !ok || rustAstInfo == nil {
+		return violations
+	}
+
+	content, err := os.ReadFile(fileInfo.Path)
+	if err != nil {
+		return violations
+	}
+	code := rustCodeLines(string(content))
+
+	violations = append(violations, d.detectMutexAcrossAwait(fileInfo.Path, rustAstInfo.Tokens)...)
+	violations = append(violations, d.detectSharedMutexFanOut(fileInfo.Path, code)...)
+	violations = append(violations, d.detectSharedMutableStatics(fileInfo.Path, code, rustAstInfo.Functions)...)
+	violations = append(violations, d.detectSpawnWithoutJoin(fileInfo.Path, code, rustAstInfo.Functions)...)
+	violations = append(violations, d.detectUnboundedChannels(fileInfo.Path, rustAstInfo.Tokens)...)
+
+	return violations
+}
+
+// detectMutexAcrossAwait finds blocking lock guards that are still in scope
+// when the code awaits. The task may then be suspended while holding the
+// lock, blocking every other task and thread that needs it. A guard lives
+// from its let or if let binding to the end of its block or a drop() of it;
+// awaits inside nested async blocks belong to another future and are skipped.
+func (d *RustConcurrencyDetector) detectMutexAcrossAwait(filePath string, tokens []types.RustTokenInfo) []*models.Violation {
+	type heldGuard struct {
+		name  string
+		at    types.RustTokenInfo
+		depth int
+	}
+
+	var violations []*models.Violation
+	var guards []heldGuard
+	depth := 0
+
+	for i := 0; i < len(tokens); i++ {
+		token := tokens[i]
+		switch {
+		case token.Text == "{":
+			depth++
+		case token.Text == "}":
+			depth--
+			held := guards[:0]
+			for _, guard := range guards {
+				if guard.depth <= depth {
+					held = append(held, guard)
+				}
+			}
+			guards = held
+		case token.Text == "async" && i+1 < len(tokens) && (tokens[i+1].Text == "{" || tokens[i+1].Text == "move"):
+			for i < len(tokens) && tokens[i].Text != "{" {
+				i++
+			}
+			if i < len(tokens) {
+				i = rustGroupEnd(tokens, i) - 1
+			}
+		case token.Text == "drop" && i+3 < len(tokens) && tokens[i+1].Text == "(" && tokens[i+3].Text == ")":
+			held := guards[:0]
+			for _, guard := range guards {
+				if guard.name != tokens[i+2].Text {
+					held = append(held, guard)
+				}
+			}
+			guards = held
+		case token.Text == "." && i+1 < len(tokens) && tokens[i+1].Text == "await":
+			for _, guard := range guards {
+				violations = append(violations, &models.Violation{
+					Type:        models.ViolationTypeRustDeadlockProne,
+					Severity:    models.SeverityHigh,
+					Message:     fmt.Sprintf("Lock guard '%s' is held across an .await", guard.name),
+					Description: fmt.Sprintf("The .await on line %d runs while the guard is held", token.Line),
+					File:        filePath,
+					Line:        guard.at.Line,
+					Column:      guard.at.Column,
+					Rule:        RustMutexAcrossAwait,
+					Suggestion:  "Drop the guard before awaiting, limit it to an inner block, or use an async mutex such as tokio::sync::Mutex",
+					CodeSnippet: d.extractCodeSnippet(filePath, guard.at.Line, token.Line),
+				})
+			}
+			guards = nil // Each guard is reported once
+		case token.Text == "let":
+			if name := rustGuardBinding(tokens, i); name != "" {
+				guards = append(guards, heldGuard{name: name, at: token, depth: depth})
+			}
+		case (token.Text == "if" || token.Text == "while") && i+1 < len(tokens) && tokens[i+1].Text == "let":
+			// The guard of `if let Ok(guard) = m.lock() {` lives in the block that follows
+			if name := rustGuardBinding(tokens, i+1); name != "" {
+				guards = append(guards, heldGuard{name: name, at: token, depth: depth + 1})
+			}
+			i++
+		}
+	}
+
+	return violations
+}
+
+// rustGuardMethods return a lock guard of a std or parking_lot lock
+var rustGuardMethods = map[string]bool{"lock": true, "read": true, "write": true, "try_lock": true, "try_read": true, "try_write": true}
+
+// rustGuardBinding returns the name a let at index i binds a blocking lock
+// guard to: `let g = m.lock().unwrap();`, `let g = m.lock();` for parking_lot,
+// `if let Ok(g) = m.lock() {` or a binding annotated with a guard type. Async
+// locks, which are awaited, and guards dereferenced in the initializer, which
+// are dropped at the end of the statement, are not guards.
+func rustGuardBinding(tokens []types.RustTokenInfo, i int) string {
+	j := i + 1
+	if j+1 < len(tokens) && (tokens[j].Text == "Ok" || tokens[j].Text == "Some") && tokens[j+1].Text == "(" {
+		j += 2
+	}
+	if j < len(tokens) && tokens[j].Text == "mut" {
+		j++
+	}
+	if j+1 >= len(tokens) || tokens[j].Kind != types.RustTokenIdent {
+		return ""
+	}
+	name := tokens[j].Text
+	j++
+	if tokens[j].Text == ")" {
+		j++
+	}
+
+	annotated := false
+	if j < len(tokens) && tokens[j].Text == ":" {
+		for j < len(tokens) && tokens[j].Text != "=" && tokens[j].Text != ";" {
+			annotated = annotated || strings.HasSuffix(tokens[j].Text, "Guard")
+			j++
+		}
+	}
+	if j+1 >= len(tokens) || tokens[j].Text != "=" || tokens[j+1].Text == "*" {
+		return ""
+	}
+
+	// The initializer ends at the semicolon of a let or the block of an if let
+	end := j + 1
+	for end < len(tokens) && tokens[end].Text != ";" && tokens[end].Text != "{" {
+		if tokens[end].Text == "(" || tokens[end].Text == "[" {
+			end = rustGroupEnd(tokens, end)
+		} else {
+			end++
+		}
+	}
+	init := tokens[j+1 : end]
+	if annotated {
+		return name
+	}
+
+	n := len(init)
+	switch {
+	case n > 0 && init[n-1].Text == "?":
+		n--
+	case n >= 4 && init[n-4].Text == "." && init[n-3].Text == "unwrap" && init[n-2].Text == "(" && init[n-1].Text == ")":
+		n -= 4
+	case n >= 4 && init[n-1].Text == ")":
+		for k := n - 2; k >= 2; k-- {
+			if init[k].Text == "(" && init[k-1].Text == "expect" && init[k-2].Text == "." {
+				n = k - 2
+				break
+			}
+		}
+	}
+	if n < 4 || init[n-4].Text != "." || !rustGuardMethods[init[n-3].Text] || init[n-2].Text != "(" || init[n-1].Text != ")" {
+		return ""
+	}
+	return name
+}
+
+// detectSharedMutexFanOut finds Arc<Mutex> values that are cloned into many
+// spawned tasks, or into tasks spawned in a loop, so that all of them
+// serialize on one lock
+func (d *RustConcurrencyDetector) detectSharedMutexFanOut(filePath string, code []string) []*models.Violation {
+	type sharedMutex struct {
+		name         string
+		line         int
+		depth        int
+		loops        int // Loops open where the value is bound
+		clonePattern *regexp.Regexp
+		clones       int
+		spawns       bool
+		loopClone    bool // Cloned inside a loop opened after the binding
+		loopSpawn    bool // A task is spawned inside such a loop
+	}
+
+	var violations []*models.Violation
+	var open []*sharedMutex
+	blocks := &rustBlocks{}
+
+	report := func(m *sharedMutex) {
+		var message string
+		switch {
+		case m.loopClone && m.loopSpawn:
+			message = fmt.Sprintf("Arc<Mutex> '%s' is cloned into tasks spawned in a loop, which all contend for one lock", m.name)
+		case m.spawns && m.clones >= minSharedMutexClones:
+			message = fmt.Sprintf("Arc<Mutex> '%s' is cloned %d times for spawned tasks, which all contend for one lock", m.name, m.clones)
+		default:
+			return
+		}
+		violations = append(violations, &models.Violation{
+			Type:        models.ViolationTypeRustDeadlockProne,
+			Severity:    models.SeverityMedium,
+			Message:     message,
+			File:        filePath,
+			Line:        m.line,
+			Column:      indentColumn(code[m.line-1]),
+			Rule:        RustSharedMutexFanOut,
+			Suggestion:  "Shard the state, pass messages over a channel, or use atomics or a concurrent map instead of one shared lock",
+			CodeSnippet: d.extractCodeSnippet(filePath, m.line, m.line),
+		})
+	}
+
+	for i, line := range code {
+		trimmed := strings.TrimSpace(line)
+
+		spawn := rustSpawnCallPattern.MatchString(trimmed)
+		for _, m := range open {
+			inLoop := blocks.loops > m.loops
+			if m.clonePattern.MatchString(trimmed) {
+				m.clones++
+				m.loopClone = m.loopClone || inLoop
+			}
+			if spawn {
+				m.spawns = true
+				m.loopSpawn = m.loopSpawn || inLoop
+			}
+		}
+
+		if match := rustSharedMutexPattern.FindStringSubmatch(trimmed); match != nil {
+			name := regexp.QuoteMeta(match[1])
+			open = append(open, &sharedMutex{
+				name:         match[1],
+				line:         i + 1,
+				depth:        blocks.depth(),
+				loops:        blocks.loops,
+				clonePattern: regexp.MustCompile(`Arc::clone\(\s*&` + name + `\s*\)|\b` + name + `\.clone\(\)`),
+			})
+		}
+
+		blocks.advance(trimmed)
+		inScope := open[:0]
+		for _, m := range open {
+			if blocks.depth() < m.depth {
+				report(m)
+			} else {
+				inScope = append(inScope, m)
+			}
+		}
+		open = inScope
+	}
+	for _, m := range open {
+		report(m)
+	}
+
+	return violations
+}
+
+// detectSharedMutableStatics finds `static mut` items used by more than one
+// function. Nothing synchronizes those accesses, so callers on different
+// threads or in interrupt handlers race.
+func (d *RustConcurrencyDetector) detectSharedMutableStatics(filePath string, code []string, functions []*types.RustFunctionInfo) []*models.Violation {
+	var violations []*models.Violation
+
+	for i, line := range code {
+		match := rustStaticMutPattern.FindStringSubmatch(strings.TrimSpace(line))
+		if match == nil {
+			continue
+		}
+
+		usePattern := regexp.MustCompile(`\b` + regexp.QuoteMeta(match[1]) + `\b`)
+		seen := make(map[*types.RustFunctionInfo]bool)
+		var users []string
+		for j, other := range code {
+			if j == i || !usePattern.MatchString(other) {
+				continue
+			}
+			if fn := innermostRustFunction(functions, j+1); fn != nil && !seen[fn] {
+				seen[fn] = true
+				users = append(users, fn.Name)
+			}
+		}
+		if len(users) < 2 {
+			continue
+		}
+
+		violations = append(violations, &models.Violation{
+			Type:        models.ViolationTypeRustRaceCondition,
+			Severity:    models.SeverityHigh,
+			Message:     fmt.Sprintf("Mutable static '%s' is accessed from %d functions (%s)", match[1], len(users), strings.Join(users, ", ")),
+			File:        filePath,
+			Line:        i + 1,
+			Column:      strings.Index(line, "static") + 1,
+			Rule:        RustSharedMutableStatic,
+			Suggestion:  "Replace the static mut with an atomic type or a Mutex so that concurrent accesses are synchronized",
+			CodeSnippet: d.extractCodeSnippet(filePath, i+1, i+1),
+		})
+	}
+
+	return violations
+}
+
+// detectSpawnWithoutJoin finds threads and tasks whose handle is discarded or
+// never used. Nobody waits for them, and their panics and errors are lost.
+func (d *RustConcurrencyDetector) detectSpawnWithoutJoin(filePath string, code []string, functions []*types.RustFunctionInfo) []*models.Violation {
+	var violations []*models.Violation
+
+	for i, line := range code {
+		trimmed := strings.TrimSpace(line)
+		loc := rustSpawnCallPattern.FindStringIndex(trimmed)
+		if loc == nil {
+			continue
+		}
+
+		prefix := trimmed[:loc[0]]
+		fn := innermostRustFunction(functions, i+1)
+		var message string
+		if rustDiscardedSpawnPrefix.MatchString(prefix) {
+			if fn != nil && strings.Contains(fn.ReturnType, "JoinHandle") {
+				continue // The handle is returned to the caller
+			}
+			message = "Spawned task is never joined because its handle is discarded"
+		} else {
+			match := rustBoundSpawnPrefix.FindStringSubmatch(prefix)
+			if match == nil || isRustNameUsedAfter(code, match[1], i+1, fn) {
+				continue
+			}
+			message = fmt.Sprintf("Spawned task handle '%s' is never joined", match[1])
+		}
+
+		violations = append(violations, &models.Violation{
+			Type:        models.ViolationTypeRustRaceCondition,
+			Severity:    models.SeverityMedium,
+			Message:     message,
+			File:        filePath,
+			Line:        i + 1,
+			Column:      strings.Index(line, trimmed[loc[0]:loc[1]]) + 1,
+			Rule:        RustSpawnWithoutJoin,
+			Suggestion:  "Keep the JoinHandle and join or await it, or collect the tasks in a JoinSet so that shutdown waits for them",
+			CodeSnippet: d.extractCodeSnippet(filePath, i+1, i+1),
+		})
+	}
+
+	return violations
+}
+
+// detectUnboundedChannels finds the construction of unbounded channels. A
+// producer that outpaces its consumer fills such a channel until the process
+// runs out of memory, where a bounded channel would make it wait.
+func (d *RustConcurrencyDetector) detectUnboundedChannels(filePath string, tokens []types.RustTokenInfo) []*models.Violation {
+	var violations []*models.Violation
+
+	for i := range tokens {
+		call, start, _ := d.channels.match(tokens, i)
+		if call == "" {
+			continue
+		}
+		line := tokens[start].Line
+		violations = append(violations, &models.Violation{
+			Type:     models.ViolationTypeRustUnboundedChannel,
+			Severity: models.SeverityMedium,
+			Message:  fmt.Sprintf("Unbounded channel created with %s()", call),
+			File:     filePath,
+			Line:     line,
+			Column:   tokens[start].Column,
+			Rule:     RustUnboundedChannel,
+			Suggestion: "Use a bounded channel with a capacity, such as tokio::sync::mpsc::channel(n) or crossbeam::channel::bounded(n), " +
+				"so that a slow consumer makes the producers wait instead of queueing without limit",
+			CodeSnippet: d.extractCodeSnippet(filePath, line, line),
+		})
+	}
+
+	return violations
+}
+
+// rustBlocks tracks the open blocks of Rust code line by line
+type rustBlocks struct {
+	loopBodies []bool // For each open block, whether it is a loop body
+	loops      int    // Number of open loop bodies
+}
+
+// depth returns the number of open blocks
+func (b *rustBlocks) depth() int {
+	return len(b.loopBodies)
+}
+
+// advance opens and closes blocks for the braces of a trimmed code line. The
+// first brace of a line starting with for, while or loop opens a loop body.
+func (b *rustBlocks) advance(trimmed string) {
+	isLoop := rustLoopPattern.MatchString(trimmed)
+	for _, c := range trimmed {
+		switch c {
+		case '{':
+			b.loopBodies = append(b.loopBodies, isLoop)
+			if isLoop {
+				b.loops++
+			}
+			isLoop = false
+		case '}':
+			if n := len(b.loopBodies); n > 0 {
+				if b.loopBodies[n-1] {
+					b.loops--
+				}
+				b.loopBodies = b.loopBodies[:n-1]
+			}
+		}
+	}
+}
+
+// rustCodeLines splits Rust source into lines with comments removed and the
+// contents of string and character literals blanked, so that braces and names
+// inside them are not mistaken for code. Columns are preserved.
+func rustCodeLines(content string) []string {
+	lines := strings.Split(content, "\n")
+	inBlockComment, inString := false, false
+
+	for i, line := range lines {
+		out := []byte(line)
+		for j := 0; j < len(out); j++ {
+			switch {
+			case inBlockComment:
+				out[j] = ' '
+				if strings.HasPrefix(line[j:], "*/") {
+					out[j+1] = ' '
+					inBlockComment = false
+					j++
+				}
+			case inString:
+				if line[j] == '"' {
+					inString = false
+					continue
+				}
+				out[j] = ' '
+				if line[j] == '\\' && j+1 < len(out) {
+					out[j+1] = ' '
+					j++
+				}
+			case strings.HasPrefix(line[j:], "//"):
+				out = out[:j]
+			case strings.HasPrefix(line[j:], "/*"):
+				out[j], out[j+1] = ' ', ' '
+				inBlockComment = true
+				j++
+			case line[j] == '"':
+				inString = true
+			case line[j] == '\'':
+				if end := rustCharLiteralEnd(line, j); end > j {
+					for k := j + 1; k < end; k++ {
+						out[k] = ' '
+					}
+					j = end
+				}
+			}
+		}
+		lines[i] = string(out)
+	}
+
+	return lines
+}
+
+// rustCharLiteralEnd returns the index of the quote closing the character
+// literal that starts at start, or -1 for a lifetime or loop label
+func rustCharLiteralEnd(line string, start int) int {
+	if start+2 < len(line) && line[start+1] != '\\' && line[start+2] == '\'' {
+		return start + 2
+	}
+	if start+3 < len(line) && line[start+1] == '\\' {
+		if end := strings.IndexByte(line[start+3:], '\''); end >= 0 {
+			return start + 3 + end
+		}
+	}
+	return -1
+}
+
+// innermostRustFunction returns the smallest function containing line
+func innermostRustFunction(functions []*types.RustFunctionInfo, line int) *types.RustFunctionInfo {
+	var innermost *types.RustFunctionInfo
+	for _, fn := range functions {
+		if fn == nil || line < fn.StartLine || line > fn.EndLine {
+			continue
+		}
+		if innermost == nil || fn.EndLine-fn.StartLine < innermost.EndLine-innermost.StartLine {
+			innermost = fn
+		}
+	}
+	return innermost
+}
+
+// isRustNameUsedAfter reports whether name appears after the given 1-based
+// line, up to the end of fn or of the file when fn is nil
+func isRustNameUsedAfter(code []string, name string, line int, fn *types.RustFunctionInfo) bool {
+	end := len(code)
+	if fn != nil && fn.EndLine < end {
+		end = fn.EndLine
+	}
+	usePattern := regexp.MustCompile(`\b` + regexp.QuoteMeta(name) + `\b`)
+	for _, other := range code[min(line, end):end] {
+		if usePattern.MatchString(other) {
+			return true
+		}
+	}
+	return false
+}
+
+// indentColumn returns the 1-based column of the first non-blank character
+func indentColumn(line string) int {
+	return len(line) - len(strings.TrimLeft(line, " \t")) + 1
+}
+
+// extractCodeSnippet extracts a code snippet for the violation with context
+func (d *RustConcurrencyDetector) extractCodeSnippet(filePath string, startLine, endLine int) string {
+	if d.codeExtractor == nil {
+		return fmt.Sprintf("Line %d: <code snippet unavailable>", startLine)
+	}
+
+	snippet, err := d.codeExtractor.ExtractSnippet(filePath, startLine, endLine)
+	if err != nil {
+		return fmt.Sprintf("Line %d: <code snippet unavailable>", startLine)
+	}
+
+	return snippet
+}
@@ -0,0 +1,258 @@
+package violations
+
+import (
+	"reflect"
+	"strings"
+	"testing"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+// detectConcurrency runs the concurrency rule pack on code and returns the
+// reported lines by rule
+func detectConcurrency(t *testing.T, code string, functions []*types.RustFunctionInfo) (map[string][]int, []*models.Violation) {
+	t.Helper()
+	filePath := createTempFileWithContent(t, code, ".rs")
+	defer removeTempFile(t, filePath)
+
+	config := DefaultDetectorConfig()
+	config.RustConfig.EnableConcurrencyPack = true
+	violations := NewRustConcurrencyDetector(config).Detect(
+		&models.FileInfo{Path: filePath, Language: "rust"},
+		&types.RustASTInfo{FilePath: filePath, Functions: functions, Tokens: rustTestTokens(code)},
+	)
+
+	lines := make(map[string][]int)
+	for _, v := range violations {
+		lines[v.Rule] = append(lines[v.Rule], v.Line)
+	}
+	return lines, violations
+}
+
+func TestRustConcurrencyDetector_DisabledByDefault(t *testing.T) {
+	code := `async fn update(state: Arc<Mutex<u32>>) {
+    let value = state.lock().unwrap();
+    notify().await;
+}`
+	filePath := createTempFileWithContent(t, code, ".rs")
+	defer removeTempFile(t, filePath)
+
+	violations := NewRustConcurrencyDetector(nil).Detect(
+		&models.FileInfo{Path: filePath, Language: "rust"},
+		&types.RustASTInfo{FilePath: filePath},
+	)
+	if len(violations) != 0 {
+		t.Errorf("Expected no violations without the concurrency pack, got %d", len(violations))
+	}
+}
+
+func TestRustConcurrencyDetector_MutexAcrossAwait(t *testing.T) {
+	code := `async fn update(state: Arc<Mutex<Vec<u32>>>, client: Client) {
+    let mut items = state.lock().unwrap();
+    let extra = client.fetch().await;
+    items.push(extra);
+}
+
+async fn scoped(state: Arc<Mutex<Vec<u32>>>, client: Client) {
+    {
+        let items = state.lock().unwrap();
+        println!("{}", items.len());
+    }
+    client.fetch().await;
+}
+
+async fn dropped(state: Arc<Mutex<Vec<u32>>>, client: Client) {
+    let items = state.lock().unwrap();
+    let count = items.len();
+    drop(items);
+    client.send(count).await;
+}
+
+async fn async_lock(state: Arc<tokio::sync::Mutex<u32>>, client: Client) {
+    let value = state.lock().await;
+    let copied = *state.lock().unwrap();
+    client.send(*value + copied).await;
+}`
+
+	lines, violations := detectConcurrency(t, code, nil)
+	if got := lines[RustMutexAcrossAwait]; !reflect.DeepEqual(got, []int{2}) {
+		t.Fatalf("Expected a guard held across await on line 2, got %v", got)
+	}
+	for _, v := range violations {
+		if v.Rule == RustMutexAcrossAwait && !strings.Contains(v.Message, "'items'") {
+			t.Errorf("Expected the message to name the guard, got %q", v.Message)
+		}
+	}
+}
+
+func TestRustConcurrencyDetector_MutexAcrossAwaitScopes(t *testing.T) {
+	code := `async fn parking(state: Arc<parking_lot::Mutex<u32>>, client: Client) {
+    let count = state.lock();
+    client.send(*count).await;
+}
+
+async fn conditional(state: Arc<Mutex<u32>>, client: Client) {
+    if let Ok(mut value) = state.try_lock() {
+        *value += 1;
+        client.notify().await;
+    }
+    client.flush().await;
+}
+
+async fn annotated(state: Arc<RwLock<u32>>, client: Client) {
+    let snapshot: RwLockReadGuard<'_, u32> = read_state(&state);
+    let task = async move {
+        client.notify().await;
+    };
+    drop(snapshot);
+    task.await;
+}
+
+async fn expected(state: Arc<Mutex<u32>>, client: Client) {
+    let value = state.lock().expect("poisoned");
+    let len = state.lock().unwrap().len();
+    client.send(*value + len).await;
+}`
+
+	lines, violations := detectConcurrency(t, code, nil)
+	if got := lines[RustMutexAcrossAwait]; !reflect.DeepEqual(got, []int{2, 7, 23}) {
+		t.Fatalf("Expected the parking_lot guard, the if let guard and the expected guard, got %v", got)
+	}
+	for _, v := range violations {
+		if v.Rule == RustMutexAcrossAwait && v.Line == 7 && (v.Message != "Lock guard 'value' is held across an .await" ||
+			v.Description != "The .await on line 9 runs while the guard is held") {
+			t.Errorf("Unexpected message for the if let guard: %q, %q", v.Message, v.Description)
+		}
+	}
+}
+
+func TestRustConcurrencyDetector_SharedMutexFanOut(t *testing.T) {
+	code := `fn serve(listener: Listener) {
+    let state = Arc::new(Mutex::new(Stats::default()));
+    for conn in listener.incoming() {
+        let state = Arc::clone(&state);
+        thread::spawn(move || handle(conn, state));
+    }
+}
+
+async fn start() {
+    let cache = Arc::new(RwLock::new(HashMap::new()));
+    let a = tokio::spawn(refresh(cache.clone()));
+    let b = tokio::spawn(serve(cache.clone()));
+    let c = tokio::spawn(report(cache.clone()));
+    let _ = tokio::join!(a, b, c);
+}
+
+fn pair() {
+    let counter = Arc::new(Mutex::new(0));
+    let shared = Arc::clone(&counter);
+    let worker = thread::spawn(move || bump(shared));
+    worker.join().unwrap();
+}`
+
+	lines, _ := detectConcurrency(t, code, nil)
+	if got := lines[RustSharedMutexFanOut]; !reflect.DeepEqual(got, []int{2, 10}) {
+		t.Errorf("Expected fan-out violations on lines 2 and 10, got %v", got)
+	}
+}
+
+func TestRustConcurrencyDetector_SharedMutableStatics(t *testing.T) {
+	code := `static mut COUNTER: u32 = 0;
+static mut LOCAL: u32 = 0;
+
+fn increment() {
+    unsafe { COUNTER += 1; }
+    unsafe { LOCAL += 1; }
+}
+
+fn read() -> u32 {
+    unsafe { COUNTER }
+}`
+	functions := []*types.RustFunctionInfo{
+		{Name: "increment", StartLine: 4, EndLine: 7},
+		{Name: "read", StartLine: 9, EndLine: 11, ReturnType: "u32"},
+	}
+
+	lines, violations := detectConcurrency(t, code, functions)
+	if got := lines[RustSharedMutableStatic]; !reflect.DeepEqual(got, []int{1}) {
+		t.Fatalf("Expected a shared mutable static on line 1, got %v", got)
+	}
+	for _, v := range violations {
+		if v.Rule == RustSharedMutableStatic && !strings.Contains(v.Message, "(increment, read)") {
+			t.Errorf("Expected the message to list the functions, got %q", v.Message)
+		}
+	}
+}
+
+func TestRustConcurrencyDetector_SpawnWithoutJoin(t *testing.T) {
+	code := `fn fire_and_forget() {
+    thread::spawn(|| work());
+    let _ = tokio::spawn(async { work().await });
+    let handle = std::thread::spawn(|| work());
+    let joined = thread::spawn(|| work());
+    joined.join().unwrap();
+}
+
+fn start_worker() -> JoinHandle<()> {
+    thread::spawn(|| work())
+}`
+	functions := []*types.RustFunctionInfo{
+		{Name: "fire_and_forget", StartLine: 1, EndLine: 7},
+		{Name: "start_worker", StartLine: 9, EndLine: 11, ReturnType: "JoinHandle<()>"},
+	}
+
+	lines, _ := detectConcurrency(t, code, functions)
+	if got := lines[RustSpawnWithoutJoin]; !reflect.DeepEqual(got, []int{2, 3, 4}) {
+		t.Errorf("Expected unjoined spawns on lines 2, 3 and 4, got %v", got)
+	}
+}
+
+func TestRustCodeLines(t *testing.T) {
+	code := "let s = \"{ \\\" }\"; // {\n/* {\n } */ let c = '{'; let r: &'a str = s;"
+
+	lines := rustCodeLines(code)
+	for i, line := range lines {
+		if strings.ContainsAny(line, "{}") {
+			t.Errorf("Expected no braces in line %d, got %q", i+1, line)
+		}
+	}
+	if !strings.Contains(lines[2], "let r: &'a str = s;") {
+		t.Errorf("Expected lifetimes to be kept, got %q", lines[2])
+	}
+}
+
+func TestRustConcurrencyDetector_UnboundedChannels(t *testing.T) {
+	code := `use tokio::sync::mpsc;
+
+fn start() {
+    let (tx, rx) = mpsc::unbounded_channel::<Event>();
+    let (jobs, queue) = crossbeam::channel::unbounded();
+    let (done_tx, done_rx) = mpsc::channel(64);
+    let make = mpsc::unbounded_channel;
+    let (events, sink) = bus::unbounded_pair();
+}`
+	lines, violations := detectConcurrency(t, code, []*types.RustFunctionInfo{{Name: "start", StartLine: 3, EndLine: 9}})
+	if got := lines[RustUnboundedChannel]; len(got) != 2 || got[0] != 4 || got[1] != 5 {
+		t.Fatalf("Expected unbounded channels on lines 4 and 5, got %v", got)
+	}
+	for _, v := range violations {
+		if v.Rule == RustUnboundedChannel && v.Line == 4 &&
+			(v.Column != 20 || v.Message != "Unbounded channel created with mpsc::unbounded_channel()" || v.Type != models.ViolationTypeRustUnboundedChannel) {
+			t.Errorf("Unexpected violation %+v", v)
+		}
+	}
+
+	filePath := createTempFileWithContent(t, code, ".rs")
+	defer removeTempFile(t, filePath)
+	config := DefaultDetectorConfig()
+	config.RustConfig.EnableConcurrencyPack = true
+	config.RustConfig.UnboundedChannelAPIs = []string{"bus::unbounded_pair"}
+	violations = NewRustConcurrencyDetector(config).Detect(
+		&models.FileInfo{Path: filePath, Language: "rust"},
+		&types.RustASTInfo{FilePath: filePath, Tokens: rustTestTokens(code)},
+	)
+	if len(violations) != 3 || violations[2].Line != 8 {
+		t.Errorf("Expected the configured API to be reported as well, got %+v", violations)
+	}
+}
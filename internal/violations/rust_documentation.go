@@ -3,11 +3,32 @@ package violations
 
 import (
 	"fmt"
+	"strings"
 
 	"github.com/ericfisherdev/goclean/internal/models"
 	"github.com/ericfisherdev/goclean/internal/types"
 )
 
+// Rules for the sections of the documentation of pub functions
+const (
+	RustMissingSafetySection = "rust-missing-safety-section" // unsafe fn without # Safety
+	RustMissingErrorsSection = "rust-missing-errors-section" // fn returning a Result without # Errors
+	RustMissingPanicsSection = "rust-missing-panics-section" // fn that can panic without # Panics
+)
+
+// RustMissingDocExample is the rule for documented pub functions and types
+// without an example code block
+const RustMissingDocExample = "rust-missing-doc-example"
+
+// rustExampleItems names the items whose docs should carry an example
+var rustExampleItems = map[types.RustNodeKind]string{
+	types.RustNodeFunction: "function",
+	types.RustNodeStruct:   "struct",
+	types.RustNodeUnion:    "union",
+	types.RustNodeEnum:     "enum",
+	types.RustNodeTrait:    "trait",
+}
+
 // RustDocumentationDetector checks for missing or poor quality documentation in Rust code
 type RustDocumentationDetector struct {
 	config        *DetectorConfig
@@ -52,6 +73,12 @@ func (d *RustDocumentationDetector) Detect(fileInfo *models.FileInfo, astInfo in
 	// Check public functions (excluding those already checked by RustFunctionDetector)
 	violations = append(violations, d.checkPublicFunctions(rustAstInfo, fileInfo.Path)...)
 
+	// Check the # Safety, # Errors and # Panics sections of documented public functions
+	violations = append(violations, d.checkDocSections(rustAstInfo, fileInfo.Path)...)
+
+	// Check for an example in the docs of public functions and types
+	violations = append(violations, d.checkDocExamples(rustAstInfo, fileInfo.Path)...)
+
 	// Check public structs
 	violations = append(violations, d.checkPublicStructs(rustAstInfo, fileInfo.Path)...)
 
@@ -83,7 +110,7 @@ func (d *RustDocumentationDetector) checkPublicFunctions(rustAstInfo *types.Rust
 	}
 
 	for _, fn := range rustAstInfo.Functions {
-		if fn == nil || !fn.IsPublic {
+		if fn == nil || !requiresRustDocs(d.config, fn.IsPublic, fn.Visibility) {
 			continue
 		}
 
@@ -107,6 +134,177 @@ func (d *RustDocumentationDetector) checkPublicFunctions(rustAstInfo *types.Rust
 	return violations
 }
 
+// checkDocSections checks that the documentation of pub functions has the
+// sections rustdoc readers look for: # Safety on unsafe functions, # Errors
+// on functions returning a Result and # Panics on functions that can panic.
+// Undocumented functions are left to rust-missing-function-documentation.
+func (d *RustDocumentationDetector) checkDocSections(rustAstInfo *types.RustASTInfo, filePath string) []*models.Violation {
+	var violations []*models.Violation
+
+	rust := d.config.RustConfig
+	if rust == nil || !rust.RequireSafetySection && !rust.RequireErrorsSection && !rust.RequirePanicsSection {
+		return violations
+	}
+
+	panics := make(map[*types.RustFunctionInfo]*types.RustPanicSite)
+	for _, site := range rustAstInfo.PanicSites {
+		if site.InTest || !isDocumentedPanic(site) {
+			continue
+		}
+		if fn := rustEnclosingFunction(rustAstInfo.Functions, site); fn != nil && panics[fn] == nil {
+			panics[fn] = site
+		}
+	}
+
+	for _, fn := range rustAstInfo.Functions {
+		if fn == nil || fn.Visibility != "pub" {
+			continue
+		}
+		docs := rustDocText(fn, rustAstInfo.Comments)
+		if docs == "" {
+			continue
+		}
+
+		missing := func(rule, message, suggestion string, severity models.Severity) {
+			violations = append(violations, &models.Violation{
+				Type:        models.ViolationTypeMissingDocumentation,
+				Severity:    severity,
+				Message:     message,
+				File:        filePath,
+				Line:        fn.StartLine,
+				Column:      fn.StartColumn,
+				Rule:        rule,
+				Suggestion:  suggestion,
+				CodeSnippet: d.extractCodeSnippet(filePath, fn.StartLine, fn.StartLine),
+			})
+		}
+		if rust.RequireSafetySection && fn.IsUnsafe && !strings.Contains(docs, "# Safety") {
+			missing(RustMissingSafetySection,
+				fmt.Sprintf("Public unsafe function '%s' has no # Safety section", fn.Name),
+				"Add a # Safety section listing the conditions callers must uphold for the call to be sound",
+				models.SeverityMedium)
+		}
+		if rust.RequireErrorsSection && isRustResultType(fn.ReturnType) && !strings.Contains(docs, "# Errors") {
+			missing(RustMissingErrorsSection,
+				fmt.Sprintf("Public function '%s' returns a Result but has no # Errors section", fn.Name),
+				"Add an # Errors section describing when the function returns an error",
+				models.SeverityLow)
+		}
+		if site := panics[fn]; rust.RequirePanicsSection && site != nil && !strings.Contains(docs, "# Panics") {
+			missing(RustMissingPanicsSection,
+				fmt.Sprintf("Public function '%s' can panic (%s on line %d) but has no # Panics section", fn.Name, rustPanicSiteName(site), site.Line),
+				"Add a # Panics section describing the inputs or states that make the function panic, or return an error instead",
+				models.SeverityLow)
+		}
+	}
+
+	return violations
+}
+
+// checkDocExamples reports the documented pub functions and types whose doc
+// comments have no ``` code block, in the files doc_example_paths selects.
+// Test code is skipped, and undocumented items are left to the
+// missing documentation rules.
+func (d *RustDocumentationDetector) checkDocExamples(rustAstInfo *types.RustASTInfo, filePath string) []*models.Violation {
+	var violations []*models.Violation
+
+	rust := d.config.RustConfig
+	if rust == nil || !rust.RequireDocExamples || rustAstInfo.Syntax == nil || isRustTestsPath(filePath) {
+		return violations
+	}
+	if len(rust.DocExamplePaths) > 0 && !matchesAnyPathGlob(rust.DocExamplePaths, filePath) {
+		return violations
+	}
+
+	types.InspectRust(rustAstInfo.Syntax, func(node *types.RustNode) bool {
+		if node.Kind == types.RustNodeModule && node.HasAttribute("cfg(test)") {
+			return false
+		}
+		item, found := rustExampleItems[node.Kind]
+		if !found || !node.HasModifier("pub") {
+			return node.Kind != types.RustNodeFunction
+		}
+		docs := rustNodeDocText(node.StartLine, len(node.Attributes), rustAstInfo.Comments)
+		if docs == "" || strings.Contains(docs, "```") {
+			return node.Kind != types.RustNodeFunction
+		}
+
+		violations = append(violations, &models.Violation{
+			Type:        models.ViolationTypeMissingDocumentation,
+			Severity:    models.SeverityLow,
+			Message:     fmt.Sprintf("Public %s '%s' has no example in its documentation", item, node.Name),
+			File:        filePath,
+			Line:        node.StartLine,
+			Column:      node.StartColumn,
+			Rule:        RustMissingDocExample,
+			Suggestion:  "Add an # Examples section with a ``` code block showing how to use it; rustdoc runs it as a doctest",
+			CodeSnippet: d.extractCodeSnippet(filePath, node.StartLine, node.StartLine),
+		})
+		return node.Kind != types.RustNodeFunction
+	})
+	return violations
+}
+
+// matchesAnyPathGlob reports whether a file path matches one of the globs
+func matchesAnyPathGlob(patterns []string, filePath string) bool {
+	for _, pattern := range patterns {
+		if matchesPathGlob(pattern, filePath) {
+			return true
+		}
+	}
+	return false
+}
+
+// isDocumentedPanic reports whether a panic site is one a # Panics section
+// should describe: an unwrap, an expect, a panic! or an assertion. Indexing
+// and arithmetic are left out, as nearly every function has some.
+func isDocumentedPanic(site *types.RustPanicSite) bool {
+	switch site.Kind {
+	case types.RustPanicUnwrap, types.RustPanicExpect:
+		return true
+	case types.RustPanicMacro:
+		return site.Name == "panic" || strings.HasPrefix(site.Name, "assert")
+	}
+	return false
+}
+
+// rustPanicSiteName names a panic site in messages, as unwrap() or panic!()
+func rustPanicSiteName(site *types.RustPanicSite) string {
+	if site.Kind == types.RustPanicMacro {
+		return site.Name + "!()"
+	}
+	return site.Name + "()"
+}
+
+// rustDocText returns the outer doc comments right above a function, joined
+// by newlines, or an empty string when it has none
+func rustDocText(fn *types.RustFunctionInfo, comments []*types.RustCommentInfo) string {
+	attributes := 0
+	if fn.Node != nil {
+		attributes = len(fn.Node.Attributes)
+	}
+	return rustNodeDocText(fn.StartLine, attributes, comments)
+}
+
+// rustNodeDocText returns the outer doc comments right above the item at
+// startLine, past its attributes, joined by newlines
+func rustNodeDocText(startLine, attributes int, comments []*types.RustCommentInfo) string {
+	above := startLine - 1 - attributes
+	var docs []string
+	for i := len(comments) - 1; i >= 0; i-- {
+		comment := comments[i]
+		if comment.Line >= startLine {
+			continue
+		}
+		if !comment.IsDoc || comment.EndLine != above {
+			break
+		}
+		docs = append([]string{comment.Text}, docs...)
+		above = comment.Line - 1
+	}
+	return strings.Join(docs, "\n")
+}
+
 // checkPublicStructs checks documentation for public structs
 func (d *RustDocumentationDetector) checkPublicStructs(rustAstInfo *types.RustASTInfo, filePath string) []*models.Violation {
 	var violations []*models.Violation
@@ -116,7 +314,7 @@ func (d *RustDocumentationDetector) checkPublicStructs(rustAstInfo *types.RustAS
 	}
 
 	for _, structInfo := range rustAstInfo.Structs {
-		if structInfo == nil || !structInfo.IsPublic {
+		if structInfo == nil || !requiresRustDocs(d.config, structInfo.IsPublic, structInfo.Visibility) {
 			continue
 		}
 
@@ -148,7 +346,7 @@ func (d *RustDocumentationDetector) checkPublicEnums(rustAstInfo *types.RustASTI
 	}
 
 	for _, enumInfo := range rustAstInfo.Enums {
-		if enumInfo == nil || !enumInfo.IsPublic {
+		if enumInfo == nil || !requiresRustDocs(d.config, enumInfo.IsPublic, enumInfo.Visibility) {
 			continue
 		}
 
@@ -180,7 +378,7 @@ func (d *RustDocumentationDetector) checkPublicTraits(rustAstInfo *types.RustAST
 	}
 
 	for _, traitInfo := range rustAstInfo.Traits {
-		if traitInfo == nil || !traitInfo.IsPublic {
+		if traitInfo == nil || !requiresRustDocs(d.config, traitInfo.IsPublic, traitInfo.Visibility) {
 			continue
 		}
 
@@ -212,7 +410,7 @@ func (d *RustDocumentationDetector) checkPublicModules(rustAstInfo *types.RustAS
 	}
 
 	for _, moduleInfo := range rustAstInfo.Modules {
-		if moduleInfo == nil || !moduleInfo.IsPublic {
+		if moduleInfo == nil || !requiresRustDocs(d.config, moduleInfo.IsPublic, moduleInfo.Visibility) {
 			continue
 		}
 
@@ -244,7 +442,7 @@ func (d *RustDocumentationDetector) checkPublicConstants(rustAstInfo *types.Rust
 	}
 
 	for _, constantInfo := range rustAstInfo.Constants {
-		if constantInfo == nil || !constantInfo.IsPublic {
+		if constantInfo == nil || !requiresRustDocs(d.config, constantInfo.IsPublic, constantInfo.Visibility) {
 			continue
 		}
 
@@ -299,6 +497,16 @@ func (d *RustDocumentationDetector) checkPublicMacros(rustAstInfo *types.RustAST
 	return violations
 }
 
+// requiresRustDocs reports whether an item with the given visibility must be
+// documented. Crate-internal items such as pub(crate) are exempt when
+// SkipInternalDocs is set.
+func requiresRustDocs(config *DetectorConfig, isPublic bool, visibility string) bool {
+	if !isPublic {
+		return false
+	}
+	return config.RustConfig == nil || !config.RustConfig.SkipInternalDocs || visibility == "pub"
+}
+
 // Helper methods for generating messages and suggestions
 
 func (d *RustDocumentationDetector) getMissingDocumentationMessage(itemType, name, visibility string) string {
@@ -457,6 +457,50 @@ func TestRustDocumentationDetector_IgnorePrivateItems(t *testing.T) {
 	}
 }
 
+// TestRustDocumentationDetector_SkipInternalDocs tests that crate-internal items
+// need no docs when SkipInternalDocs is set
+func TestRustDocumentationDetector_SkipInternalDocs(t *testing.T) {
+	config := DefaultDetectorConfig()
+	config.RequireCommentsForPublic = true
+	config.RustConfig.SkipInternalDocs = true
+	detector := NewRustDocumentationDetector(config)
+
+	fileInfo := &models.FileInfo{
+		Path:     "test.rs",
+		Language: "rust",
+	}
+
+	rustAstInfo := &types.RustASTInfo{
+		FilePath: "test.rs",
+		Functions: []*types.RustFunctionInfo{
+			{
+				Name:           "crate_helper",
+				StartLine:      10,
+				StartColumn:    5,
+				IsPublic:       true,
+				HasDocComments: false,
+				Visibility:     "pub(crate)",
+			},
+		},
+		Structs: []*types.RustStructInfo{
+			{
+				Name:           "Driver",
+				StartLine:      20,
+				StartColumn:    5,
+				IsPublic:       true,
+				HasDocComments: false,
+				Visibility:     "pub",
+			},
+		},
+	}
+
+	violations := detector.Detect(fileInfo, rustAstInfo)
+
+	if len(violations) != 1 || violations[0].Line != 20 {
+		t.Fatalf("Expected only the exported struct to be reported, got %d violations", len(violations))
+	}
+}
+
 // TestRustDocumentationDetector_IgnoreDocumentedItems tests that documented items don't generate violations
 func TestRustDocumentationDetector_IgnoreDocumentedItems(t *testing.T) {
 	config := DefaultDetectorConfig()
@@ -619,4 +663,141 @@ func findSubstring(s, substr string) bool {
 		}
 	}
 	return false
-}
\ No newline at end of file
+}
+// TestRustDocumentationDetector_DocSections tests the # Safety, # Errors and # Panics rules
+func TestRustDocumentationDetector_DocSections(t *testing.T) {
+	rustAstInfo := &types.RustASTInfo{
+		Functions: []*types.RustFunctionInfo{
+			{Name: "read_raw", StartLine: 3, EndLine: 3, Visibility: "pub", IsPublic: true, IsUnsafe: true, HasDocComments: true},
+			{Name: "parse", StartLine: 8, EndLine: 11, Visibility: "pub", IsPublic: true, ReturnType: "io::Result<Config>", HasDocComments: true},
+			{Name: "load", StartLine: 17, EndLine: 20, Visibility: "pub", IsPublic: true, ReturnType: "Result<(), Error>", HasDocComments: true},
+			{Name: "undocumented", StartLine: 22, EndLine: 22, Visibility: "pub", IsPublic: true, IsUnsafe: true},
+		},
+		Comments: []*types.RustCommentInfo{
+			{Text: "/// Reads a byte.", Line: 1, EndLine: 1, IsDoc: true},
+			{Text: "/// Must be aligned.", Line: 2, EndLine: 2, IsDoc: true},
+			{Text: "/// Parses the configuration.", Line: 6, EndLine: 6, IsDoc: true},
+			{Text: "/// Uses the defaults when the file is empty.", Line: 7, EndLine: 7, IsDoc: true},
+			{Text: "/// Loads the state.", Line: 13, EndLine: 13, IsDoc: true},
+			{Text: "///", Line: 14, EndLine: 14, IsDoc: true},
+			{Text: "/// # Errors", Line: 15, EndLine: 15, IsDoc: true},
+			{Text: "/// # Panics", Line: 16, EndLine: 16, IsDoc: true},
+		},
+		PanicSites: []*types.RustPanicSite{
+			{Kind: types.RustPanicIndex, Name: "[", Function: "parse", Line: 9},
+			{Kind: types.RustPanicExpect, Name: "expect", Function: "parse", Line: 10},
+			{Kind: types.RustPanicUnwrap, Name: "unwrap", Function: "load", Line: 18},
+		},
+	}
+	fileInfo := &models.FileInfo{Path: "src/config.rs", Language: "rust"}
+
+	sections := func(config *DetectorConfig) []*models.Violation {
+		var found []*models.Violation
+		for _, v := range NewRustDocumentationDetector(config).Detect(fileInfo, rustAstInfo) {
+			switch v.Rule {
+			case RustMissingSafetySection, RustMissingErrorsSection, RustMissingPanicsSection:
+				found = append(found, v)
+			}
+		}
+		return found
+	}
+
+	config := DefaultDetectorConfig()
+	violations := sections(config)
+	if len(violations) != 1 || violations[0].Rule != RustMissingSafetySection || violations[0].Line != 3 {
+		t.Fatalf("Expected only the # Safety section to be required by default, got %+v", violations)
+	}
+
+	config.RustConfig.RequireSafetySection = false
+	config.RustConfig.RequireErrorsSection = true
+	config.RustConfig.RequirePanicsSection = true
+	violations = sections(config)
+	if len(violations) != 2 {
+		t.Fatalf("Expected the # Errors and # Panics sections of parse, got %+v", violations)
+	}
+	if v := violations[0]; v.Rule != RustMissingErrorsSection || v.Message != "Public function 'parse' returns a Result but has no # Errors section" {
+		t.Errorf("Unexpected # Errors violation %+v", v)
+	}
+	if v := violations[1]; v.Rule != RustMissingPanicsSection || v.Message != "Public function 'parse' can panic (expect() on line 10) but has no # Panics section" {
+		t.Errorf("Unexpected # Panics violation %+v", v)
+	}
+}
+
+func TestRustDocumentationDetector_DocExamples(t *testing.T) {
+	// 1  /// Parses input.
+	// 2  ///
+	// 3  /// ```
+	// 4  /// let value = parse("a");
+	// 5  /// ```
+	// 6  pub fn parse() {}
+	// 7
+	// 8  /// A parser.
+	// 9  #[derive(Debug)]
+	// 10 pub struct Parser;
+	// 11
+	// 12 pub enum Undocumented {}
+	// 13
+	// 14 /// Internal helper.
+	// 15 fn helper() {}
+	// 16
+	// 17 #[cfg(test)]
+	// 18 mod tests {
+	// 19     /// Test fixture.
+	// 20     pub fn fixture() {}
+	// 21 }
+	root := &types.RustNode{Kind: types.RustNodeFile, StartLine: 1}
+	root.AddChild(&types.RustNode{Kind: types.RustNodeFunction, Name: "parse", StartLine: 6, StartColumn: 1, Modifiers: []string{"pub"}})
+	root.AddChild(&types.RustNode{Kind: types.RustNodeStruct, Name: "Parser", StartLine: 10, StartColumn: 1, Modifiers: []string{"pub"},
+		Attributes: []string{"derive(Debug)"}})
+	root.AddChild(&types.RustNode{Kind: types.RustNodeEnum, Name: "Undocumented", StartLine: 12, StartColumn: 1, Modifiers: []string{"pub"}})
+	root.AddChild(&types.RustNode{Kind: types.RustNodeFunction, Name: "helper", StartLine: 15, StartColumn: 1})
+	tests := &types.RustNode{Kind: types.RustNodeModule, Name: "tests", StartLine: 18, Attributes: []string{"cfg(test)"}}
+	tests.AddChild(&types.RustNode{Kind: types.RustNodeFunction, Name: "fixture", StartLine: 20, StartColumn: 5, Modifiers: []string{"pub"}})
+	root.AddChild(tests)
+
+	rustAstInfo := &types.RustASTInfo{
+		Syntax: root,
+		Comments: []*types.RustCommentInfo{
+			{Text: "/// Parses input.", Line: 1, EndLine: 1, IsDoc: true},
+			{Text: "///", Line: 2, EndLine: 2, IsDoc: true},
+			{Text: "/// ```", Line: 3, EndLine: 3, IsDoc: true},
+			{Text: "/// let value = parse(\"a\");", Line: 4, EndLine: 4, IsDoc: true},
+			{Text: "/// ```", Line: 5, EndLine: 5, IsDoc: true},
+			{Text: "/// A parser.", Line: 8, EndLine: 8, IsDoc: true},
+			{Text: "/// Internal helper.", Line: 14, EndLine: 14, IsDoc: true},
+			{Text: "/// Test fixture.", Line: 19, EndLine: 19, Column: 5, IsDoc: true},
+		},
+	}
+
+	config := DefaultDetectorConfig()
+	examples := func(path string) []*models.Violation {
+		var found []*models.Violation
+		for _, v := range NewRustDocumentationDetector(config).Detect(&models.FileInfo{Path: path, Language: "rust"}, rustAstInfo) {
+			if v.Rule == RustMissingDocExample {
+				found = append(found, v)
+			}
+		}
+		return found
+	}
+
+	if violations := examples("src/lib.rs"); len(violations) != 0 {
+		t.Errorf("Expected the rule to be opt-in, got %+v", violations)
+	}
+
+	config.RustConfig.RequireDocExamples = true
+	violations := examples("src/lib.rs")
+	if len(violations) != 1 {
+		t.Fatalf("Expected only the documented Parser without an example, got %+v", violations)
+	}
+	if v := violations[0]; v.Line != 10 || v.Severity != models.SeverityLow || v.Message != "Public struct 'Parser' has no example in its documentation" {
+		t.Errorf("Unexpected example violation %+v", v)
+	}
+
+	config.RustConfig.DocExamplePaths = []string{"src/lib.rs"}
+	if violations := examples("sdk/src/lib.rs"); len(violations) != 1 {
+		t.Errorf("Expected a configured path to match the end of the file path, got %+v", violations)
+	}
+	if violations := examples("sdk/src/parser.rs"); len(violations) != 0 {
+		t.Errorf("Expected files outside the configured paths to be skipped, got %+v", violations)
+	}
+}
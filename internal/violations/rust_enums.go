@@ -0,0 +1,203 @@
+package violations
+
+import (
+	"fmt"
+	"strings"
+
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+// DefaultSkipEnumAttributes returns the attributes and derives of generated
+// enums, whose variants mirror an external schema rather than a design choice
+func DefaultSkipEnumAttributes() []string {
+	return []string{"automatically_derived", "EnumIter", "EnumString", "FromPrimitive", "Enumeration"}
+}
+
+// rustEnumKeyword returns the index of the `enum Name` keyword of an enum, or -1
+func rustEnumKeyword(tokens []types.RustTokenInfo, enumInfo *types.RustEnumInfo) int {
+	for i := 0; i+1 < len(tokens); i++ {
+		if tokens[i].Line >= enumInfo.StartLine && tokens[i].Line <= enumInfo.EndLine &&
+			tokens[i].Text == "enum" && tokens[i+1].Text == enumInfo.Name {
+			return i
+		}
+	}
+	return -1
+}
+
+// rustItemAttributes returns the identifiers in the outer attributes of the
+// item whose keyword is at index item, such as derive, Debug and EnumIter in
+// #[derive(Debug, strum::EnumIter)]. Visibility before the keyword is skipped.
+func rustItemAttributes(tokens []types.RustTokenInfo, item int) []string {
+	var names []string
+	for j := item - 1; j >= 0; j-- {
+		switch tokens[j].Text {
+		case "pub", "crate", "super", "self", "in", "(", ")":
+			continue
+		case "]":
+		default:
+			return names
+		}
+
+		depth := 0
+		open := -1
+		for k := j; k >= 0 && open < 0; k-- {
+			switch tokens[k].Text {
+			case "]":
+				depth++
+			case "[":
+				depth--
+				if depth == 0 {
+					open = k
+				}
+			}
+		}
+		if open < 1 || tokens[open-1].Text != "#" {
+			return names
+		}
+		for k := open + 1; k < j; k++ {
+			if tokens[k].Kind == types.RustTokenIdent {
+				names = append(names, tokens[k].Text)
+			}
+		}
+		j = open - 1
+	}
+	return names
+}
+
+// hasSkippedAttribute reports whether the item whose keyword is at index
+// item has one of the skipped attributes or derives
+func hasSkippedAttribute(tokens []types.RustTokenInfo, item int, skipped []string) bool {
+	if item < 0 || len(skipped) == 0 {
+		return false
+	}
+	for _, name := range rustItemAttributes(tokens, item) {
+		for _, skip := range skipped {
+			if name == skip {
+				return true
+			}
+		}
+	}
+	return false
+}
+
+// rustEnumVariantNames returns the variant names of an enum, reading the
+// tokens from its `enum Name {` header to the closing brace
+func rustEnumVariantNames(tokens []types.RustTokenInfo, keyword int) []string {
+	var variants []string
+	depth := 0
+	for i := keyword + 2; i < len(tokens); i++ {
+		text := tokens[i].Text
+		switch {
+		case isRustOpenDelimiter(text):
+			depth++
+		case isRustCloseDelimiter(text):
+			depth--
+			if depth == 0 {
+				return variants
+			}
+		case depth == 1 && tokens[i].Kind == types.RustTokenIdent:
+			// A variant follows the opening brace, a comma or an attribute
+			if prev := tokens[i-1].Text; prev == "{" || prev == "," || prev == "]" {
+				variants = append(variants, text)
+			}
+		}
+	}
+	return variants
+}
+
+// enumSplitSuggestion suggests splitting an enum by the domains its variant
+// names share, such as the User and Order events of an ApplicationEvent enum
+func enumSplitSuggestion(name string, variants []string) string {
+	clusters := responsibilityClusters(variants)
+	if len(clusters) < 2 {
+		return "Consider splitting large enum into smaller enums or using trait objects for behavior-based variants"
+	}
+	described := make([]string, len(clusters))
+	for i, cluster := range clusters {
+		described[i] = fmt.Sprintf("%s (%d)", cluster.prefix, len(cluster.fields))
+	}
+	return fmt.Sprintf("Its variants fall into %d domains: %s. Consider one enum per domain, wrapped by variants of '%s'",
+		len(clusters), strings.Join(described, ", "), name)
+}
+
+// isRustErrorEnum reports whether an enum is an error type: its name ends in
+// Error, or it derives Error as with thiserror
+func isRustErrorEnum(tokens []types.RustTokenInfo, keyword int, name string) bool {
+	if strings.HasSuffix(name, "Error") {
+		return true
+	}
+	for _, attribute := range rustItemAttributes(tokens, keyword) {
+		if attribute == "Error" {
+			return true
+		}
+	}
+	return false
+}
+
+// rustStringOnlyVariants returns the variants of an enum whose payload holds
+// nothing but strings, such as Parse(String) or Io { message: String }
+func rustStringOnlyVariants(tokens []types.RustTokenInfo, keyword int) []string {
+	open := keyword + 2
+	for open < len(tokens) && tokens[open].Text != "{" {
+		open++
+	}
+	if open >= len(tokens) {
+		return nil
+	}
+
+	var variants []string
+	end := rustGroupEnd(tokens, open) - 1
+	for i := open + 1; i < end; i++ {
+		text := tokens[i].Text
+		switch {
+		case text == "#" && i+1 < end && tokens[i+1].Text == "[":
+			i = rustGroupEnd(tokens, i+1) - 1
+		case isRustOpenDelimiter(text):
+			i = rustGroupEnd(tokens, i) - 1
+		case tokens[i].Kind == types.RustTokenIdent && i+1 < end && (tokens[i-1].Text == "{" || tokens[i-1].Text == "," || tokens[i-1].Text == "]"):
+			if payload := tokens[i+1].Text; payload == "(" || payload == "{" {
+				closing := rustGroupEnd(tokens, i+1) - 1
+				if isRustStringPayload(tokens[i+2:closing]) {
+					variants = append(variants, text)
+				}
+				i = closing
+			}
+		}
+	}
+	return variants
+}
+
+// isRustStringPayload reports whether the fields of a tuple or struct variant
+// are all of type String
+func isRustStringPayload(fields []types.RustTokenInfo) bool {
+	count := 0
+	for i, field := range fields {
+		switch {
+		case field.Text == "String":
+			count++
+		case field.Text == "," || field.Text == ":" || field.Text == "pub":
+		case field.Text == "::" || field.Text == "std" || field.Text == "string":
+		case field.Kind == types.RustTokenIdent && i+1 < len(fields) && fields[i+1].Text == ":":
+			// A field name
+		default:
+			return false
+		}
+	}
+	return count > 0
+}
+
+// errorEnumSplitSuggestion suggests splitting an error enum into the error
+// types of the modules that produce its variants
+func errorEnumSplitSuggestion(name string, variants []string) string {
+	clusters := responsibilityClusters(variants)
+	if len(clusters) < 2 {
+		return fmt.Sprintf("Give each module or operation its own error type with only the failures it can produce, "+
+			"and wrap them in variants of '%s' with #[from]", name)
+	}
+	described := make([]string, len(clusters))
+	for i, cluster := range clusters {
+		described[i] = fmt.Sprintf("%s (%d)", cluster.prefix, len(cluster.fields))
+	}
+	return fmt.Sprintf("Its variants fall into %d domains: %s. Define an error type per module, wrapped by variants of '%s' with #[from]",
+		len(clusters), strings.Join(described, ", "), name)
+}
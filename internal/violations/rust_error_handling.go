@@ -12,6 +12,15 @@ import (
 	"github.com/ericfisherdev/goclean/internal/types"
 )
 
+// Rules of the error handling detector
+const (
+	RustStringError       = "rust-string-error"
+	RustOpaquePublicError = "rust-opaque-public-error"
+	RustSwallowedError    = "rust-swallowed-error"
+
+	RustManualErrorPropagation = "rust-manual-error-propagation"
+)
+
 // RustErrorHandlingDetector detects error handling violations in Rust code
 type RustErrorHandlingDetector struct {
 	config        *DetectorConfig
@@ -36,7 +45,7 @@ func (d *RustErrorHandlingDetector) Name() string {
 
 // Description returns a description of what this detector checks for
 func (d *RustErrorHandlingDetector) Description() string {
-	return "Detects error handling violations in Rust code including overuse of unwrap() and expect(), missing error propagation, and panic-prone patterns"
+	return "Detects error handling violations in Rust code including overuse of unwrap() and expect(), missing error propagation, panic-prone patterns, errors typed as strings and errors discarded into an Option or bool"
 }
 
 // Detect analyzes Rust code for error handling violations
@@ -64,11 +73,22 @@ func (d *RustErrorHandlingDetector) Detect(fileInfo *models.FileInfo, astInfo in
 
 	// Analyze content for error handling violations
 	violations = append(violations, d.detectUnwrapOveruse(fileInfo.Path, lines)...)
-	violations = append(violations, d.detectMissingErrorPropagation(fileInfo.Path, lines)...)
+	manual := d.detectManualErrorPropagation(fileInfo.Path, rustAstInfo.Tokens, lines)
+	violations = append(violations, manual...)
+	violations = append(violations, d.detectMissingErrorPropagation(fileInfo.Path, lines, violationLines(manual))...)
 	violations = append(violations, d.detectInconsistentErrorTypes(fileInfo.Path, lines)...)
 	violations = append(violations, d.detectPanicProneCode(fileInfo.Path, lines)...)
-	violations = append(violations, d.detectUnhandledResults(fileInfo.Path, lines)...)
+	if check := cargoCheckFor(d.config, fileInfo.Path); check != nil {
+		violations = append(violations, d.unhandledResultsFromRustc(fileInfo.Path, check)...)
+	} else {
+		violations = append(violations, d.detectUnhandledResults(fileInfo.Path, lines, newRustTypeInference(rustAstInfo))...)
+	}
 	violations = append(violations, d.detectImproperExpect(fileInfo.Path, lines)...)
+	violations = append(violations, d.detectStringErrors(fileInfo.Path, rustAstInfo)...)
+	violations = append(violations, d.detectOpaquePublicErrors(fileInfo.Path, rustAstInfo)...)
+	if d.config.RustConfig == nil || d.config.RustConfig.EnforceResultPropagation {
+		violations = append(violations, d.detectSwallowedErrors(fileInfo.Path, rustAstInfo)...)
+	}
 
 	return violations
 }
@@ -136,8 +156,9 @@ func (d *RustErrorHandlingDetector) detectUnwrapOveruse(filePath string, lines [
 	return violations
 }
 
-// detectMissingErrorPropagation identifies places where ? operator should be used
-func (d *RustErrorHandlingDetector) detectMissingErrorPropagation(filePath string, lines []string) []*models.Violation {
+// detectMissingErrorPropagation identifies places where ? operator should be used.
+// Lines in covered are already reported as manual error propagation.
+func (d *RustErrorHandlingDetector) detectMissingErrorPropagation(filePath string, lines []string, covered map[int]bool) []*models.Violation {
 	var violations []*models.Violation
 
 	// Simpler patterns for error propagation opportunities
@@ -160,8 +181,8 @@ func (d *RustErrorHandlingDetector) detectMissingErrorPropagation(filePath strin
 	for lineNum, line := range lines {
 		trimmedLine := strings.TrimSpace(line)
 		
-		// Skip comments and empty lines
-		if strings.HasPrefix(trimmedLine, "//") || trimmedLine == "" {
+		// Skip comments, empty lines and reported manual propagation
+		if strings.HasPrefix(trimmedLine, "//") || trimmedLine == "" || covered[lineNum+1] {
 			continue
 		}
 
@@ -187,6 +208,213 @@ func (d *RustErrorHandlingDetector) detectMissingErrorPropagation(filePath strin
 	return violations
 }
 
+// detectManualErrorPropagation finds match expressions whose arms are only
+// Ok(v) => v and Err(e) => return Err(e), and if let Err(e) = ... statements
+// whose body is only return Err(e), which the ? operator expresses. An error
+// converted with e.into() counts too, as ? converts it with From. The fix
+// replaces the expression with its scrutinee followed by ? when the
+// scrutinee fits on one line.
+func (d *RustErrorHandlingDetector) detectManualErrorPropagation(filePath string, tokens []types.RustTokenInfo, lines []string) []*models.Violation {
+	var violations []*models.Violation
+
+	for i := 0; i+3 < len(tokens); i++ {
+		var form string
+		var scrutinee, open, closing int
+		switch {
+		case tokens[i].Text == "match":
+			form, scrutinee = "match", i+1
+			open = rustScrutineeBrace(tokens, scrutinee)
+			if open >= len(tokens) {
+				continue
+			}
+			closing = rustGroupEnd(tokens, open) - 1
+			if !rustPropagatingArms(tokens, open, closing) {
+				continue
+			}
+		case tokens[i].Text == "if" && tokens[i+1].Text == "let":
+			names, next, ok := rustTokensAt(tokens, i+2, "Err", "(", "$", ")", "=")
+			if !ok {
+				continue
+			}
+			form, scrutinee = "if let", next
+			open = rustScrutineeBrace(tokens, scrutinee)
+			if open >= len(tokens) {
+				continue
+			}
+			closing = rustGroupEnd(tokens, open) - 1
+			end, ok := rustErrReturn(tokens, open+1, names[0])
+			if end < len(tokens) && tokens[end].Text == ";" {
+				end++
+			}
+			if !ok || end != closing || closing+1 < len(tokens) && tokens[closing+1].Text == "else" {
+				continue
+			}
+		default:
+			continue
+		}
+		if scrutinee >= open {
+			continue
+		}
+
+		start, last := tokens[i], tokens[closing]
+		violation := &models.Violation{
+			Type:        models.ViolationTypeRustMissingErrorPropagation,
+			Severity:    models.SeverityLow,
+			Message:     fmt.Sprintf("%s only propagates the error of a Result", form),
+			File:        filePath,
+			Line:        start.Line,
+			Column:      start.Column,
+			EndLine:     last.Line,
+			EndColumn:   last.Column + 1,
+			Rule:        RustManualErrorPropagation,
+			Suggestion:  "Use the ? operator, which returns the error and converts it with From",
+			CodeSnippet: d.extractCodeSnippet(filePath, start.Line, last.Line),
+		}
+		if replacement := rustPropagationText(tokens[scrutinee:open], lines); replacement != "" {
+			if form == "if let" {
+				replacement += ";"
+			}
+			violation.Suggestion = fmt.Sprintf("Replace it with %s", replacement)
+			violation.Fix = &models.Fix{
+				Description: "Propagate the error with ?",
+				Edits: []models.TextEdit{{
+					Line:      start.Line,
+					Column:    start.Column,
+					EndLine:   last.Line,
+					EndColumn: last.Column + 1,
+					NewText:   replacement,
+				}},
+			}
+		}
+		violations = append(violations, violation)
+		i = closing
+	}
+	return violations
+}
+
+// rustScrutineeBrace returns the index of the brace opening the body of a
+// match or if let whose scrutinee starts at token i, skipping the groups of
+// the scrutinee
+func rustScrutineeBrace(tokens []types.RustTokenInfo, i int) int {
+	for i < len(tokens) && tokens[i].Text != "{" {
+		if tokens[i].Text == "(" || tokens[i].Text == "[" {
+			i = rustGroupEnd(tokens, i)
+			continue
+		}
+		i++
+	}
+	return i
+}
+
+// rustPropagatingArms reports whether the arms of a match between the braces
+// at open and closing are Ok(v) => v and Err(e) => return Err(e), in either
+// order. The return may be wrapped in a block.
+func rustPropagatingArms(tokens []types.RustTokenInfo, open, closing int) bool {
+	okArm, errArm := false, false
+	for i := open + 1; i < closing; {
+		if names, next, ok := rustTokensAt(tokens, i, "Ok", "(", "$", ")", "=>", "$"); ok && !okArm && names[0] == names[1] {
+			okArm, i = true, next
+		} else if names, next, ok := rustTokensAt(tokens, i, "Err", "(", "$", ")", "=>"); ok && !errArm {
+			block := next < closing && tokens[next].Text == "{"
+			if block {
+				next++
+			}
+			end, returns := rustErrReturn(tokens, next, names[0])
+			if block {
+				if end < closing && tokens[end].Text == ";" {
+					end++
+				}
+				if end >= closing || tokens[end].Text != "}" {
+					return false
+				}
+				end++
+			}
+			if !returns {
+				return false
+			}
+			errArm, i = true, end
+		} else {
+			return false
+		}
+
+		// A comma separates the arms, except after a block
+		if i < closing && tokens[i].Text == "," {
+			i++
+		} else if i != closing && tokens[i-1].Text != "}" {
+			return false
+		}
+	}
+	return okArm && errArm
+}
+
+// rustErrReturn reports whether the tokens from index i spell return Err(e),
+// or return Err(e.into()), for the error named e. It returns the index after
+// the closing parenthesis.
+func rustErrReturn(tokens []types.RustTokenInfo, i int, e string) (int, bool) {
+	names, next, ok := rustTokensAt(tokens, i, "return", "Err", "(", "$")
+	if !ok || names[0] != e {
+		return i, false
+	}
+	if _, converted, into := rustTokensAt(tokens, next, ".", "into", "(", ")"); into {
+		next = converted
+	}
+	if next >= len(tokens) || tokens[next].Text != ")" {
+		return i, false
+	}
+	return next + 1, true
+}
+
+// rustTokensAt reports whether the tokens from index i spell pattern, where
+// "$" stands for any identifier. It returns the identifiers matched by "$"
+// and the index after the match.
+func rustTokensAt(tokens []types.RustTokenInfo, i int, pattern ...string) ([]string, int, bool) {
+	var names []string
+	for _, want := range pattern {
+		if i >= len(tokens) {
+			return nil, i, false
+		}
+		switch {
+		case want == "$" && tokens[i].Kind == types.RustTokenIdent:
+			names = append(names, tokens[i].Text)
+		case want != tokens[i].Text:
+			return nil, i, false
+		}
+		i++
+	}
+	return names, i, true
+}
+
+// rustPropagationText returns the scrutinee followed by ?, read from the
+// source lines, or an empty string when it spans several lines. A scrutinee
+// other than a chain of paths, calls and method calls is parenthesized.
+func rustPropagationText(scrutinee []types.RustTokenInfo, lines []string) string {
+	text := rustSourceText(lines, scrutinee)
+	if text == "" {
+		return ""
+	}
+
+	for i := 0; i < len(scrutinee); i++ {
+		switch tok := scrutinee[i]; {
+		case tok.Text == "(" || tok.Text == "[" || tok.Text == "<" && i > 0 && scrutinee[i-1].Text == "::":
+			i = rustGroupEnd(scrutinee, i) - 1
+		case tok.Kind == types.RustTokenPunct && tok.Text != "." && tok.Text != "::" && tok.Text != "?":
+			return "(" + text + ")?"
+		}
+	}
+	return text + "?"
+}
+
+// violationLines returns the lines the violations span
+func violationLines(violations []*models.Violation) map[int]bool {
+	lines := make(map[int]bool)
+	for _, v := range violations {
+		for line := v.Line; line <= max(v.Line, v.EndLine); line++ {
+			lines[line] = true
+		}
+	}
+	return lines
+}
+
 // detectInconsistentErrorTypes identifies inconsistent error type usage
 func (d *RustErrorHandlingDetector) detectInconsistentErrorTypes(filePath string, lines []string) []*models.Violation {
 	var violations []*models.Violation
@@ -296,8 +524,10 @@ func (d *RustErrorHandlingDetector) detectPanicProneCode(filePath string, lines
 	return violations
 }
 
-// detectUnhandledResults identifies Result types that aren't properly handled
-func (d *RustErrorHandlingDetector) detectUnhandledResults(filePath string, lines []string) []*models.Violation {
+// detectUnhandledResults identifies Result types that aren't properly handled.
+// Statements whose type is inferred are reported exactly when it is a Result;
+// the others fall back to the call-name heuristic.
+func (d *RustErrorHandlingDetector) detectUnhandledResults(filePath string, lines []string, inference *rustTypeInference) []*models.Violation {
 	var violations []*models.Violation
 
 	// Pattern for unhandled Result calls
@@ -313,27 +543,63 @@ func (d *RustErrorHandlingDetector) detectUnhandledResults(filePath string, line
 
 		// Look for function calls ending with semicolon (potential unhandled Results)
 		if unhandledPattern.MatchString(trimmedLine) {
-			// Check if this looks like a Result-returning function call
-			if d.looksLikeResultCall(trimmedLine) {
-				codeSnippet := d.extractCodeSnippet(filePath, lineNum+1, lineNum+1)
-				violations = append(violations, &models.Violation{
-					Type:        models.ViolationTypeRustUnhandledResult,
-					Severity:    models.SeverityMedium,
-					Message:     "Potentially unhandled Result - consider using ? operator or explicit error handling",
-					File:        filePath,
-					Line:        lineNum + 1,
-					Column:      1,
-					Rule:        "rust-unhandled-result",
-					Suggestion:  "Handle the Result with match, if let, or ? operator",
-					CodeSnippet: codeSnippet,
-				})
+			// Use the inferred type of the statement, or guess from the call
+			message := "Potentially unhandled Result - consider using ? operator or explicit error handling"
+			if resultType, known := inference.statementType(lineNum + 1); known {
+				if !isRustResultType(resultType) {
+					continue
+				}
+				message = fmt.Sprintf("Unhandled %s - consider using ? operator or explicit error handling", resultType)
+			} else if !d.looksLikeResultCall(trimmedLine) {
+				continue
 			}
+
+			codeSnippet := d.extractCodeSnippet(filePath, lineNum+1, lineNum+1)
+			violations = append(violations, &models.Violation{
+				Type:        models.ViolationTypeRustUnhandledResult,
+				Severity:    models.SeverityMedium,
+				Message:     message,
+				File:        filePath,
+				Line:        lineNum + 1,
+				Column:      1,
+				Rule:        "rust-unhandled-result",
+				Suggestion:  "Handle the Result with match, if let, or ? operator",
+				CodeSnippet: codeSnippet,
+			})
 		}
 	}
 
 	return violations
 }
 
+// unhandledResultsFromRustc reports the unused Results rustc found with its
+// unused_must_use lint. Other must_use values, such as iterators and futures,
+// are left to rustc's own warnings.
+func (d *RustErrorHandlingDetector) unhandledResultsFromRustc(filePath string, check *cargoCheckResult) []*models.Violation {
+	var violations []*models.Violation
+
+	for _, diagnostic := range check.forFile(filePath, "unused_must_use") {
+		if !strings.Contains(diagnostic.message, "Result") {
+			continue
+		}
+
+		codeSnippet := d.extractCodeSnippet(filePath, diagnostic.line, diagnostic.line)
+		violations = append(violations, &models.Violation{
+			Type:        models.ViolationTypeRustUnhandledResult,
+			Severity:    models.SeverityMedium,
+			Message:     fmt.Sprintf("rustc: %s - consider using ? operator or explicit error handling", diagnostic.message),
+			File:        filePath,
+			Line:        diagnostic.line,
+			Column:      diagnostic.column,
+			Rule:        "rust-unhandled-result",
+			Suggestion:  "Handle the Result with match, if let, or ? operator",
+			CodeSnippet: codeSnippet,
+		})
+	}
+
+	return violations
+}
+
 // detectImproperExpect identifies expect() calls without descriptive messages
 func (d *RustErrorHandlingDetector) detectImproperExpect(filePath string, lines []string) []*models.Violation {
 	var violations []*models.Violation
@@ -373,6 +639,269 @@ func (d *RustErrorHandlingDetector) detectImproperExpect(filePath string, lines
 	return violations
 }
 
+// detectStringErrors finds functions returning Result<_, String> and Err
+// values built from format! or a converted string literal. Callers can only
+// print such errors, not match on them. Test code is skipped, and so are
+// binaries and examples unless allow_string_errors_in_binaries is off.
+func (d *RustErrorHandlingDetector) detectStringErrors(filePath string, info *types.RustASTInfo) []*models.Violation {
+	var violations []*models.Violation
+
+	switch rustFileTarget(info, filePath) {
+	case rustTargetTest, rustTargetBench:
+		return violations
+	case rustTargetBinary, rustTargetExample:
+		if d.config.RustConfig == nil || d.config.RustConfig.AllowStringErrorsInBinaries {
+			return violations
+		}
+	}
+
+	tests := rustTestFunctions(info)
+	reported := make(map[*types.RustFunctionInfo]bool)
+	for _, fn := range info.Functions {
+		if tests[fn] || !isRustResultType(fn.ReturnType) {
+			continue
+		}
+		args := rustTypeArgs(fn.ReturnType)
+		if len(args) != 2 || rustBaseType(args[1]) != "String" && rustBaseType(args[1]) != "str" {
+			continue
+		}
+		reported[fn] = true
+		violations = append(violations, &models.Violation{
+			Type:        models.ViolationTypeRustInconsistentErrorType,
+			Severity:    models.SeverityMedium,
+			Message:     fmt.Sprintf("Function '%s' returns errors as %s", fn.Name, args[1]),
+			File:        filePath,
+			Line:        fn.StartLine,
+			Column:      fn.StartColumn,
+			Rule:        RustStringError,
+			Suggestion:  rustStringErrorSuggestion,
+			CodeSnippet: d.extractCodeSnippet(filePath, fn.StartLine, fn.StartLine),
+		})
+	}
+
+	tokens := info.Tokens
+	for i := 0; i+2 < len(tokens); i++ {
+		if tokens[i].Text != "Err" || tokens[i+1].Text != "(" {
+			continue
+		}
+		construction := rustStringErrorValue(tokens, i+2)
+		fn := innermostRustFunction(info.Functions, tokens[i].Line)
+		if construction == "" || fn == nil || reported[fn] || tests[fn] {
+			continue
+		}
+		violations = append(violations, &models.Violation{
+			Type:        models.ViolationTypeRustInconsistentErrorType,
+			Severity:    models.SeverityMedium,
+			Message:     fmt.Sprintf("Error of function '%s' is built from a string with %s", fn.Name, construction),
+			File:        filePath,
+			Line:        tokens[i].Line,
+			Column:      tokens[i].Column,
+			Rule:        RustStringError,
+			Suggestion:  rustStringErrorSuggestion,
+			CodeSnippet: d.extractCodeSnippet(filePath, tokens[i].Line, tokens[i].Line),
+		})
+	}
+
+	return violations
+}
+
+// detectOpaquePublicErrors finds pub functions of library targets returning
+// anyhow::Error or a boxed dyn Error. Callers of the library can report such
+// errors but not match on their variants. Only files the scanner placed in
+// the library target of a Cargo package are checked.
+func (d *RustErrorHandlingDetector) detectOpaquePublicErrors(filePath string, info *types.RustASTInfo) []*models.Violation {
+	var violations []*models.Violation
+	if info.Target != types.RustTargetLibrary {
+		return violations
+	}
+
+	tests := rustTestFunctions(info)
+	for _, fn := range info.Functions {
+		if fn.Visibility != "pub" || tests[fn] {
+			continue
+		}
+		errType := rustOpaqueErrorType(fn.ReturnType, info.Uses)
+		if errType == "" {
+			continue
+		}
+		violations = append(violations, &models.Violation{
+			Type:        models.ViolationTypeRustInconsistentErrorType,
+			Severity:    models.SeverityMedium,
+			Message:     fmt.Sprintf("Public function '%s' of a library returns %s", fn.Name, errType),
+			File:        filePath,
+			Line:        fn.StartLine,
+			Column:      fn.StartColumn,
+			Rule:        RustOpaquePublicError,
+			Suggestion:  "Return an error enum of the library, deriving thiserror::Error for example, so that callers can match on its variants; anyhow and Box<dyn Error> suit binaries",
+			CodeSnippet: d.extractCodeSnippet(filePath, fn.StartLine, fn.StartLine),
+		})
+	}
+
+	return violations
+}
+
+// detectSwallowedErrors finds functions returning Option or bool that turn a
+// Result into their return value with .ok(), or branch on .is_ok() or
+// .is_err(), so the error is dropped without being propagated or logged. A
+// bool function returning .is_ok() is reported too; .ok() inside closures
+// and in functions returning other types is not. Test code is skipped.
+func (d *RustErrorHandlingDetector) detectSwallowedErrors(filePath string, info *types.RustASTInfo) []*models.Violation {
+	var violations []*models.Violation
+
+	tests := rustTestFunctions(info)
+	tokens := info.Tokens
+	for _, fn := range info.Functions {
+		returns := rustBaseType(fn.ReturnType)
+		if tests[fn] || returns != "Option" && returns != "bool" {
+			continue
+		}
+		_, _, body, end := rustFunctionSpan(tokens, fn)
+		if body < 0 {
+			continue
+		}
+
+		for i := body + 1; i+4 < end; i++ {
+			if tokens[i].Text != "." || tokens[i+2].Text != "(" || tokens[i+3].Text != ")" {
+				continue
+			}
+			method := tokens[i+1].Text
+			returned := i+4 == end-1 || tokens[i+4].Text == ";" && rustReturnStatement(tokens, body, i)
+			switch {
+			case method == "ok" && returns == "Option" && (returned || tokens[i+4].Text == "?"):
+			case (method == "is_ok" || method == "is_err") && (tokens[i+4].Text == "{" || returns == "bool" && returned):
+			default:
+				continue
+			}
+			if innermostRustFunction(info.Functions, tokens[i].Line) != fn {
+				continue
+			}
+
+			violations = append(violations, &models.Violation{
+				Type:        models.ViolationTypeRustMissingErrorPropagation,
+				Severity:    models.SeverityMedium,
+				Message:     fmt.Sprintf("Function '%s' discards an error with .%s() and returns %s", fn.Name, method, returns),
+				File:        filePath,
+				Line:        tokens[i+1].Line,
+				Column:      tokens[i+1].Column,
+				Rule:        RustSwallowedError,
+				Suggestion:  "Return a Result and propagate the error with ?, or log it before discarding it, as in .map_err(|e| log::warn!(\"{e}\")).ok()",
+				CodeSnippet: d.extractCodeSnippet(filePath, tokens[i+1].Line, tokens[i+1].Line),
+			})
+		}
+	}
+	return violations
+}
+
+// rustReturnStatement reports whether the expression ending before token i
+// belongs to a return statement, searching back no further than token body
+func rustReturnStatement(tokens []types.RustTokenInfo, body, i int) bool {
+	for j := i - 1; j > body; j-- {
+		switch tokens[j].Text {
+		case "return":
+			return true
+		case ";", "{", "}":
+			return false
+		}
+	}
+	return false
+}
+
+// rustOpaqueErrorType returns the error type of a Result return type when it
+// is anyhow::Error or a boxed dyn Error, or an empty string
+func rustOpaqueErrorType(returnType string, uses []*types.RustUseInfo) string {
+	if !isRustResultType(returnType) {
+		return ""
+	}
+	base, _, _ := strings.Cut(strings.TrimSpace(returnType), "<")
+	args := rustTypeArgs(returnType)
+	if len(args) == 1 && (base == "anyhow::Result" || base == "Result" && rustImportsName(uses, "anyhow", "Result")) {
+		return "anyhow::Error"
+	}
+	if len(args) != 2 {
+		return ""
+	}
+
+	errType := args[1]
+	if errType == "anyhow::Error" || errType == "Error" && rustImportsName(uses, "anyhow", "Error") {
+		return "anyhow::Error"
+	}
+	if inner, found := strings.CutPrefix(errType, "Box<"); found {
+		bounds := strings.Fields(strings.NewReplacer("+", " ", ">", " ").Replace(inner))
+		if len(bounds) > 1 && bounds[0] == "dyn" && lastRustSegment(bounds[1]) == "Error" {
+			return errType
+		}
+	}
+	return ""
+}
+
+// rustImportsName reports whether a use declaration imports name from the
+// root of crate, on its own or in a group, without renaming it
+func rustImportsName(uses []*types.RustUseInfo, crate, name string) bool {
+	for _, use := range uses {
+		path := strings.ReplaceAll(use.Path, " ", "")
+		if path == crate+"::"+name && use.Alias == "" {
+			return true
+		}
+		group, found := strings.CutPrefix(path, crate+"::{")
+		if !found {
+			continue
+		}
+		for _, item := range strings.Split(strings.TrimSuffix(group, "}"), ",") {
+			if item == name {
+				return true
+			}
+		}
+	}
+	return false
+}
+
+// rustStringErrorSuggestion is the suggestion of the string error rule
+const rustStringErrorSuggestion = "Define an error type, such as an enum deriving thiserror::Error, so that callers can match on the failure instead of parsing a message"
+
+// rustStringErrorValue returns how the value starting at token i builds a
+// string: format!, String::from, or .to_string() or .to_owned() of a string
+// literal. It returns an empty string for other values.
+func rustStringErrorValue(tokens []types.RustTokenInfo, i int) string {
+	if i+3 >= len(tokens) {
+		return ""
+	}
+	switch {
+	case tokens[i].Text == "format" && tokens[i+1].Text == "!":
+		return "format!"
+	case tokens[i].Text == "String" && tokens[i+1].Text == "::" && tokens[i+2].Text == "from" && tokens[i+3].Text == "(":
+		return "String::from"
+	case strings.HasPrefix(tokens[i].Text, "\"") && tokens[i+1].Text == "." &&
+		(tokens[i+2].Text == "to_string" || tokens[i+2].Text == "to_owned") && tokens[i+3].Text == "(":
+		return "." + tokens[i+2].Text + "()"
+	}
+	return ""
+}
+
+// rustTestFunctions returns the #[test] functions of a file and the
+// functions of its #[cfg(test)] modules
+func rustTestFunctions(info *types.RustASTInfo) map[*types.RustFunctionInfo]bool {
+	tests := make(map[*types.RustFunctionInfo]bool)
+	var modules []*types.RustNode
+	if info.Syntax != nil {
+		for _, module := range types.FindRustNodes(info.Syntax, types.RustNodeModule) {
+			if module.HasAttribute("cfg(test)") {
+				modules = append(modules, module)
+			}
+		}
+	}
+	for _, fn := range info.Functions {
+		if fn.Node != nil && (fn.Node.HasAttribute("test") || fn.Node.HasAttribute("tokio::test")) {
+			tests[fn] = true
+		}
+		for _, module := range modules {
+			if fn.StartLine >= module.StartLine && fn.EndLine <= module.EndLine {
+				tests[fn] = true
+			}
+		}
+	}
+	return tests
+}
+
 // Helper methods
 
 func (d *RustErrorHandlingDetector) isProblematicUnwrap(line string) bool {
@@ -1,6 +1,7 @@
 package violations
 
 import (
+	"strings"
 	"testing"
 
 	"github.com/ericfisherdev/goclean/internal/models"
@@ -333,4 +334,218 @@ func TestRustErrorHandlingDetector_InvalidAstInfo(t *testing.T) {
 // Helper functions are defined in rust_ownership_test.go
 // func createTempFileWithContent(t *testing.T, content, extension string) string
 // func removeTempFile(t *testing.T, filePath string)
-// func containsIgnoreCase(s, substr string) bool
\ No newline at end of file
+// func containsIgnoreCase(s, substr string) bool
+func TestRustErrorHandlingDetector_StringErrors(t *testing.T) {
+	code := `fn parse(input: &str) -> Result<u32, String> {
+    input.parse().map_err(|e| format!("bad number: {}", e))
+}
+
+fn load(path: &Path) -> Result<Config, Box<dyn Error>> {
+    if !path.exists() {
+        return Err(format!("missing {}", path.display()).into());
+    }
+    Err("not implemented".to_string().into())
+}
+
+fn check(value: u32) -> Result<(), ConfigError> {
+    if value == 0 {
+        return Err(ConfigError::Zero);
+    }
+    Ok(())
+}
+
+#[test]
+fn parses() -> Result<(), String> {
+    Err(String::from("skipped"))
+}`
+	root := &types.RustNode{Kind: types.RustNodeFile, StartLine: 1}
+	testFn := &types.RustNode{Kind: types.RustNodeFunction, Name: "parses", StartLine: 20, EndLine: 22, Attributes: []string{"test"}}
+	root.AddChild(testFn)
+	info := &types.RustASTInfo{
+		Tokens: rustTestTokens(code),
+		Syntax: root,
+		Functions: []*types.RustFunctionInfo{
+			{Name: "parse", StartLine: 1, StartColumn: 1, EndLine: 3, ReturnType: "Result<u32, String>"},
+			{Name: "load", StartLine: 5, StartColumn: 1, EndLine: 10, ReturnType: "Result<Config, Box<dyn Error>>"},
+			{Name: "check", StartLine: 12, StartColumn: 1, EndLine: 17, ReturnType: "Result<(), ConfigError>"},
+			{Name: "parses", StartLine: 20, StartColumn: 1, EndLine: 22, ReturnType: "Result<(), String>", Node: testFn},
+		},
+	}
+
+	detector := NewRustErrorHandlingDetector(nil)
+	violations := detector.detectStringErrors("src/config.rs", info)
+	if len(violations) != 3 {
+		t.Fatalf("Expected the String error type and two string errors, got %+v", violations)
+	}
+	for i, want := range []struct{ line, column int }{{1, 1}, {7, 16}, {9, 5}} {
+		if v := violations[i]; v.Line != want.line || v.Column != want.column || v.Rule != RustStringError {
+			t.Errorf("Expected violation %d at %d:%d, got %+v", i, want.line, want.column, v)
+		}
+	}
+	if violations[0].Message != "Function 'parse' returns errors as String" ||
+		violations[2].Message != "Error of function 'load' is built from a string with .to_string()" {
+		t.Errorf("Unexpected messages %q and %q", violations[0].Message, violations[2].Message)
+	}
+
+	if violations := detector.detectStringErrors("src/main.rs", info); len(violations) != 0 {
+		t.Errorf("Expected binaries to be allowed string errors by default, got %+v", violations)
+	}
+	config := DefaultDetectorConfig()
+	config.RustConfig.AllowStringErrorsInBinaries = false
+	if violations := NewRustErrorHandlingDetector(config).detectStringErrors("examples/demo.rs", info); len(violations) != 3 {
+		t.Errorf("Expected examples to be reported with allow_string_errors_in_binaries off, got %+v", violations)
+	}
+}
+
+func TestRustErrorHandlingDetector_OpaquePublicErrors(t *testing.T) {
+	info := &types.RustASTInfo{
+		Target: types.RustTargetLibrary,
+		Uses:   []*types.RustUseInfo{{Path: "anyhow::{Context, Result}"}},
+		Functions: []*types.RustFunctionInfo{
+			{Name: "connect", StartLine: 3, StartColumn: 1, Visibility: "pub", ReturnType: "Result<Client, Box<dyn std::error::Error + Send + Sync>>"},
+			{Name: "load", StartLine: 7, StartColumn: 1, Visibility: "pub", ReturnType: "Result<Config>"},
+			{Name: "fetch", StartLine: 11, StartColumn: 1, Visibility: "pub", ReturnType: "Result<Vec<u8>, anyhow::Error>"},
+			{Name: "parse", StartLine: 15, StartColumn: 1, Visibility: "pub", ReturnType: "Result<Config, ParseError>"},
+			{Name: "read", StartLine: 19, StartColumn: 1, Visibility: "pub(crate)", ReturnType: "Result<String, Box<dyn Error>>"},
+		},
+	}
+
+	detector := NewRustErrorHandlingDetector(nil)
+	violations := detector.detectOpaquePublicErrors("src/client.rs", info)
+	if len(violations) != 3 {
+		t.Fatalf("Expected the public functions returning anyhow or boxed errors, got %+v", violations)
+	}
+	for i, want := range []string{
+		"Public function 'connect' of a library returns Box<dyn std::error::Error + Send + Sync>",
+		"Public function 'load' of a library returns anyhow::Error",
+		"Public function 'fetch' of a library returns anyhow::Error",
+	} {
+		if v := violations[i]; v.Message != want || v.Rule != RustOpaquePublicError {
+			t.Errorf("Expected %q, got %+v", want, v)
+		}
+	}
+
+	for _, target := range []types.RustTarget{types.RustTargetBinary, types.RustTargetTest, ""} {
+		info.Target = target
+		if violations := detector.detectOpaquePublicErrors("src/client.rs", info); len(violations) != 0 {
+			t.Errorf("Expected no violations for target %q, got %+v", target, violations)
+		}
+	}
+}
+
+func TestRustErrorHandlingDetector_SwallowedErrors(t *testing.T) {
+	code := `fn swallows_errors() -> Option<String> {
+    let file = File::open("data.txt");
+    if file.is_ok() {
+        Some(String::from("Success"))
+    } else {
+        None
+    }
+}
+
+fn port(value: &str) -> Option<u16> {
+    let port = value.parse::<u16>().ok()?;
+    Some(port)
+}
+
+fn exists(path: &Path) -> bool {
+    fs::metadata(path).is_ok()
+}
+
+fn numbers(lines: &[String]) -> Option<Vec<u32>> {
+    Some(lines.iter().filter_map(|line| line.parse().ok()).collect())
+}
+
+fn load(path: &Path) -> Result<String, io::Error> {
+    if fs::metadata(path).is_err() {
+        return Err(io::ErrorKind::NotFound.into());
+    }
+    fs::read_to_string(path)
+}`
+	info := &types.RustASTInfo{
+		Tokens: rustTestTokens(code),
+		Functions: []*types.RustFunctionInfo{
+			{Name: "swallows_errors", StartLine: 1, StartColumn: 1, EndLine: 8, ReturnType: "Option<String>"},
+			{Name: "port", StartLine: 10, StartColumn: 1, EndLine: 13, ReturnType: "Option<u16>"},
+			{Name: "exists", StartLine: 15, StartColumn: 1, EndLine: 17, ReturnType: "bool"},
+			{Name: "numbers", StartLine: 19, StartColumn: 1, EndLine: 21, ReturnType: "Option<Vec<u32>>"},
+			{Name: "load", StartLine: 23, StartColumn: 1, EndLine: 28, ReturnType: "Result<String, io::Error>"},
+		},
+	}
+
+	config := DefaultDetectorConfig()
+	violations := NewRustErrorHandlingDetector(config).detectSwallowedErrors("src/files.rs", info)
+	if len(violations) != 3 {
+		t.Fatalf("Expected the is_ok branch, the ok()? and the returned is_ok, got %+v", violations)
+	}
+	for i, want := range []struct{ line, column int }{{3, 13}, {11, 37}, {16, 24}} {
+		if v := violations[i]; v.Line != want.line || v.Column != want.column || v.Rule != RustSwallowedError {
+			t.Errorf("Expected violation %d at %d:%d, got %+v", i, want.line, want.column, v)
+		}
+	}
+	if v := violations[0]; v.Message != "Function 'swallows_errors' discards an error with .is_ok() and returns Option" ||
+		v.Type != models.ViolationTypeRustMissingErrorPropagation {
+		t.Errorf("Unexpected violation %+v", v)
+	}
+}
+
+func TestRustErrorHandlingDetector_ManualErrorPropagation(t *testing.T) {
+	code := `fn manual_error_propagation() -> Result<String, std::io::Error> {
+    let file = File::open("test.txt");
+    let mut file = match file {
+        Ok(f) => f,
+        Err(e) => return Err(e),
+    };
+    if let Err(e) = file.sync_all() {
+        return Err(e.into());
+    }
+    let size = match file.metadata().map(|m| m.len()) {
+        Err(err) => { return Err(err); }
+        Ok(size) => size,
+    };
+    let name = match lookup(&file) {
+        Ok(name) => name.trim().to_string(),
+        Err(e) => return Err(e),
+    };
+    Ok(name)
+}`
+	filePath := createTempFileWithContent(t, code, ".rs")
+	defer removeTempFile(t, filePath)
+	info := &types.RustASTInfo{FilePath: filePath, Tokens: rustTestTokens(code)}
+
+	detector := NewRustErrorHandlingDetector(nil)
+	violations := detector.detectManualErrorPropagation(filePath, info.Tokens, strings.Split(code, "\n"))
+	if len(violations) != 3 {
+		t.Fatalf("Expected the two matches and the if let, got %+v", violations)
+	}
+	for i, want := range []struct {
+		line, column, endLine int
+		replacement           string
+	}{
+		{3, 20, 6, "file?"},
+		{7, 5, 9, "file.sync_all()?;"},
+		{10, 16, 13, "file.metadata().map(|m| m.len())?"},
+	} {
+		v := violations[i]
+		if v.Line != want.line || v.Column != want.column || v.EndLine != want.endLine || v.Rule != RustManualErrorPropagation {
+			t.Errorf("Expected violation %d at %d:%d to line %d, got %+v", i, want.line, want.column, want.endLine, v)
+			continue
+		}
+		if v.Fix == nil || len(v.Fix.Edits) != 1 || v.Fix.Edits[0].NewText != want.replacement || v.Fix.Edits[0].EndColumn != 6 {
+			t.Errorf("Expected violation %d to be fixed with %q, got %+v", i, want.replacement, v.Fix)
+		}
+	}
+	if v := violations[0]; v.Message != "match only propagates the error of a Result" || v.Suggestion != "Replace it with file?" {
+		t.Errorf("Unexpected violation %+v", v)
+	}
+
+	var generic []int
+	for _, v := range detector.Detect(&models.FileInfo{Path: filePath}, info) {
+		if v.Rule == "rust-missing-error-propagation" {
+			generic = append(generic, v.Line)
+		}
+	}
+	if len(generic) != 1 || generic[0] != 16 {
+		t.Errorf("Expected only the unreported return Err on line 16 to remain, got lines %v", generic)
+	}
+}
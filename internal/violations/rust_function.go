@@ -69,20 +69,21 @@ func (d *RustFunctionDetector) Detect(fileInfo *models.FileInfo, astInfo interfa
 // checkRustFunction analyzes a single Rust function for violations
 func (d *RustFunctionDetector) checkRustFunction(fn *types.RustFunctionInfo, filePath string) []*models.Violation {
 	var violations []*models.Violation
+	lineCount := rustFunctionLines(fn)
 
 	// Check function length
-	if fn.LineCount > d.config.MaxFunctionLines {
+	if lineCount > d.config.MaxFunctionLines {
 		codeSnippet := d.extractCodeSnippet(filePath, fn.StartLine, fn.EndLine)
 		violations = append(violations, &models.Violation{
 			Type:        models.ViolationTypeFunctionLength,
-			Severity:    d.getSeverityForFunctionLength(fn.LineCount),
-			Message:     fmt.Sprintf("Rust function '%s' is too long (%d lines, max: %d)", fn.Name, fn.LineCount, d.config.MaxFunctionLines),
+			Severity:    d.getSeverityForFunctionLength(lineCount),
+			Message:     fmt.Sprintf("Rust function '%s' is too long (%d lines, max: %d)", fn.Name, lineCount, d.config.MaxFunctionLines),
 			File:        filePath,
 			Line:        fn.StartLine,
 			Column:      fn.StartColumn,
 			EndLine:     fn.EndLine,
 			Rule:        "rust-function-length",
-			Suggestion:  d.getRustFunctionLengthSuggestion(fn.Name, fn.LineCount),
+			Suggestion:  d.getRustFunctionLengthSuggestion(fn.Name, lineCount),
 			CodeSnippet: codeSnippet,
 		})
 	}
@@ -104,6 +105,25 @@ func (d *RustFunctionDetector) checkRustFunction(fn *types.RustFunctionInfo, fil
 		})
 	}
 
+	// Check nesting depth on the syntax tree
+	if fn.Node != nil {
+		if depth := rustNestingDepth(fn.Node); depth > d.config.MaxNestingDepth {
+			codeSnippet := d.extractCodeSnippet(filePath, fn.StartLine, fn.EndLine)
+			violations = append(violations, &models.Violation{
+				Type:        models.ViolationTypeNestingDepth,
+				Severity:    d.getSeverityForNestingDepth(depth),
+				Message:     fmt.Sprintf("Rust function '%s' has excessive nesting depth (%d, max: %d)", fn.Name, depth, d.config.MaxNestingDepth),
+				File:        filePath,
+				Line:        fn.StartLine,
+				Column:      fn.StartColumn,
+				EndLine:     fn.EndLine,
+				Rule:        "rust-nesting-depth",
+				Suggestion:  d.getRustNestingDepthSuggestion(fn.Name, depth),
+				CodeSnippet: codeSnippet,
+			})
+		}
+	}
+
 	// Check parameter count
 	paramCount := len(fn.Parameters)
 	if paramCount > d.config.MaxParameters {
@@ -116,13 +136,13 @@ func (d *RustFunctionDetector) checkRustFunction(fn *types.RustFunctionInfo, fil
 			Line:        fn.StartLine,
 			Column:      fn.StartColumn,
 			Rule:        "rust-parameter-count",
-			Suggestion:  d.getRustParameterCountSuggestion(fn.Name, paramCount),
+			Suggestion:  d.getRustParameterCountSuggestion(fn),
 			CodeSnippet: codeSnippet,
 		})
 	}
 
 	// Check for missing documentation on public functions
-	if d.config.RequireCommentsForPublic && fn.IsPublic && !fn.HasDocComments {
+	if d.config.RequireCommentsForPublic && requiresRustDocs(d.config, fn.IsPublic, fn.Visibility) && !fn.HasDocComments {
 		codeSnippet := d.extractCodeSnippet(filePath, fn.StartLine, fn.StartLine)
 		violations = append(violations, &models.Violation{
 			Type:        models.ViolationTypeMissingDocumentation,
@@ -131,7 +151,7 @@ func (d *RustFunctionDetector) checkRustFunction(fn *types.RustFunctionInfo, fil
 			File:        filePath,
 			Line:        fn.StartLine,
 			Column:      fn.StartColumn,
-			Rule:        "rust-missing-documentation",
+			Rule:        "rust-missing-function-documentation",
 			Suggestion:  fmt.Sprintf("Add doc comments (///) describing what function '%s' does", fn.Name),
 			CodeSnippet: codeSnippet,
 		})
@@ -154,18 +174,18 @@ func (d *RustFunctionDetector) checkRustFunction(fn *types.RustFunctionInfo, fil
 	}
 
 	// Check for overly long async functions (they tend to be more complex)
-	if fn.IsAsync && fn.LineCount > d.config.MaxFunctionLines/2 {
+	if fn.IsAsync && lineCount > d.config.MaxFunctionLines/2 {
 		codeSnippet := d.extractCodeSnippet(filePath, fn.StartLine, fn.EndLine)
 		violations = append(violations, &models.Violation{
 			Type:        models.ViolationTypeFunctionLength,
 			Severity:    models.SeverityMedium,
-			Message:     fmt.Sprintf("Async Rust function '%s' is complex (%d lines). Consider breaking it down", fn.Name, fn.LineCount),
+			Message:     fmt.Sprintf("Async Rust function '%s' is complex (%d lines). Consider breaking it down", fn.Name, lineCount),
 			File:        filePath,
 			Line:        fn.StartLine,
 			Column:      fn.StartColumn,
 			EndLine:     fn.EndLine,
 			Rule:        "rust-async-complexity",
-			Suggestion:  d.getRustAsyncComplexitySuggestion(fn.Name, fn.LineCount),
+			Suggestion:  d.getRustAsyncComplexitySuggestion(fn.Name, lineCount),
 			CodeSnippet: codeSnippet,
 		})
 	}
@@ -173,6 +193,51 @@ func (d *RustFunctionDetector) checkRustFunction(fn *types.RustFunctionInfo, fil
 	return violations
 }
 
+// rustNestingKinds are the control flow nodes that nest the code inside them
+var rustNestingKinds = map[types.RustNodeKind]bool{
+	types.RustNodeIf:      true,
+	types.RustNodeLetElse: true,
+	types.RustNodeMatch:   true,
+	types.RustNodeLoop:    true,
+	types.RustNodeWhile:   true,
+	types.RustNodeFor:     true,
+	types.RustNodeClosure: true,
+	types.RustNodeUnsafe:  true,
+}
+
+// rustFunctionLines returns the lines a function spans, from its syntax node
+// when it has one
+func rustFunctionLines(fn *types.RustFunctionInfo) int {
+	if fn.Node != nil {
+		return fn.Node.LineCount()
+	}
+	return fn.LineCount
+}
+
+// rustNestingDepth returns the deepest nesting of control flow in a function
+// node. Else branches, including else-if chains, stay at the depth of their
+// if, and nested functions are left to their own check.
+func rustNestingDepth(fn *types.RustNode) int {
+	var deepest func(node *types.RustNode, depth int) int
+	deepest = func(node *types.RustNode, depth int) int {
+		found := depth
+		for _, child := range node.Children {
+			if child.Kind == types.RustNodeFunction {
+				continue
+			}
+			childDepth := depth
+			// The parser makes else-if a direct child of its if, outside the body block
+			elseIf := child.Kind == types.RustNodeIf && node.Kind == types.RustNodeIf
+			if rustNestingKinds[child.Kind] && !elseIf {
+				childDepth++
+			}
+			found = max(found, deepest(child, childDepth))
+		}
+		return found
+	}
+	return deepest(fn, 0)
+}
+
 // generateRustFunctionSignature creates a code snippet showing the Rust function signature
 func (d *RustFunctionDetector) generateRustFunctionSignature(fn *types.RustFunctionInfo) string {
 	var signature strings.Builder
@@ -258,6 +323,16 @@ func (d *RustFunctionDetector) getSeverityForParameterCount(paramCount int) mode
 	return models.SeverityLow
 }
 
+func (d *RustFunctionDetector) getSeverityForNestingDepth(depth int) models.Severity {
+	if depth > d.config.MaxNestingDepth*2 {
+		return models.SeverityHigh
+	}
+	if depth > int(float64(d.config.MaxNestingDepth)*1.5) {
+		return models.SeverityMedium
+	}
+	return models.SeverityLow
+}
+
 // Rust-specific suggestion generation methods
 
 func (d *RustFunctionDetector) getRustFunctionLengthSuggestion(funcName string, lineCount int) string {
@@ -271,10 +346,32 @@ func (d *RustFunctionDetector) getRustComplexitySuggestion(funcName string, comp
 		"or extracting complex logic into separate functions or methods.", funcName, complexity)
 }
 
-func (d *RustFunctionDetector) getRustParameterCountSuggestion(funcName string, paramCount int) string {
+// getRustParameterCountSuggestion suggests a builder in place of the
+// parameters of a function when two or more of them are optional, and a
+// parameter struct otherwise, with a nested struct for each set of parameters
+// whose names start with the same word
+func (d *RustFunctionDetector) getRustParameterCountSuggestion(fn *types.RustFunctionInfo) string {
+	fields := rustParameterFields(fn.Parameters)
+	groups := groupParameters(fields)
+	visibility := rustItemVisibility(fn)
+	if optional := rustOptionalParameters(fields); len(optional) >= 2 {
+		var ordered []paramField
+		for _, group := range groups {
+			ordered = append(ordered, group.fields...)
+		}
+		return fmt.Sprintf("Function '%s' has %d parameters, %d of them optional. "+
+			"Consider a builder that takes the required ones and sets the optional ones:\n\n%s",
+			fn.Name, len(fields), len(optional), rustBuilder(fn, visibility, ordered, optional))
+	}
 	return fmt.Sprintf("Function '%s' has %d parameters. "+
-		"Consider using a builder pattern, grouping related parameters into structs, "+
-		"or using Rust's Default trait for optional parameters.", funcName, paramCount)
+		"Consider passing them as a parameter struct, deriving Default for optional parameters, "+
+		"or splitting the function:\n\n%s", fn.Name, len(fields), rustParameterStruct(fn.Name, visibility, groups))
+}
+
+func (d *RustFunctionDetector) getRustNestingDepthSuggestion(funcName string, depth int) string {
+	return fmt.Sprintf("Function '%s' has nesting depth of %d. "+
+		"Consider early returns with the ? operator, let-else for refutable patterns, "+
+		"or extracting nested logic into separate functions.", funcName, depth)
 }
 
 func (d *RustFunctionDetector) getRustAsyncComplexitySuggestion(funcName string, lineCount int) string {
@@ -438,4 +438,39 @@ func TestRustFunctionDetector_Severity(t *testing.T) {
 	if severity := detector.getSeverityForParameterCount(8); severity != models.SeverityHigh {
 		t.Errorf("Expected High severity for 8 parameters, got %v", severity)
 	}
-}
\ No newline at end of file
+}
+func TestRustFunctionDetector_ParameterCountSuggestion(t *testing.T) {
+	detector := NewRustFunctionDetector(DefaultDetectorConfig())
+	fn := &types.RustFunctionInfo{
+		Name:       "send_email",
+		ReturnType: "Result<(), Error>",
+		IsPublic:   true,
+		Visibility: "pub",
+		Parameters: []types.RustParameterInfo{
+			{Name: "to_address", Type: "&str", IsRef: true},
+			{Name: "to_name", Type: "&str", IsRef: true},
+			{Name: "subject", Type: "String"},
+			{Name: "body", Type: "String"},
+			{Name: "cc", Type: "Option<String>"},
+		},
+	}
+	want := "Function 'send_email' has 5 parameters. Consider passing them as a parameter struct, deriving Default for optional parameters, or splitting the function:\n\n" +
+		"pub struct ToParams<'a> {\n    pub address: &'a str,\n    pub name: &'a str,\n}\n\n" +
+		"pub struct SendEmailParams<'a> {\n    pub to: ToParams<'a>,\n    pub subject: String,\n    pub body: String,\n    pub cc: Option<String>,\n}"
+	if got := detector.getRustParameterCountSuggestion(fn); got != want {
+		t.Errorf("Expected suggestion\n%s\ngot\n%s", want, got)
+	}
+
+	// Optional parameters call for a builder
+	fn.Parameters = append(fn.Parameters, types.RustParameterInfo{Name: "bcc", Type: "Option<String>"})
+	want = "Function 'send_email' has 6 parameters, 2 of them optional. Consider a builder that takes the required ones and sets the optional ones:\n\n" +
+		"pub struct SendEmailBuilder<'a> {\n    to_address: &'a str,\n    to_name: &'a str,\n    subject: String,\n    body: String,\n    cc: Option<String>,\n    bcc: Option<String>,\n}\n\n" +
+		"impl<'a> SendEmailBuilder<'a> {\n" +
+		"    pub fn new(to_address: &'a str, to_name: &'a str, subject: String, body: String) -> Self { .. }\n" +
+		"    pub fn cc(mut self, cc: String) -> Self { .. }\n" +
+		"    pub fn bcc(mut self, bcc: String) -> Self { .. }\n" +
+		"    pub fn send_email(self) -> Result<(), Error> { .. }\n}"
+	if got := detector.getRustParameterCountSuggestion(fn); got != want {
+		t.Errorf("Expected suggestion\n%s\ngot\n%s", want, got)
+	}
+}
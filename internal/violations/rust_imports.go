@@ -0,0 +1,603 @@
+package violations
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"regexp"
+	"sort"
+	"strings"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+// Rules of the imports rule pack
+const (
+	RustUnsortedImports = "rust-unsorted-imports"
+	RustDuplicateImport = "rust-duplicate-import"
+	RustLocalImports    = "rust-local-imports"
+	RustReexportChain   = "rust-reexport-chain"
+)
+
+// Default limits of the imports rule pack
+const (
+	DefaultMaxLocalImports  = 3
+	DefaultMaxReexportDepth = 2
+)
+
+// maxReexportHops bounds the re-exports followed from one pub use, so that
+// cyclic re-exports end
+const maxReexportHops = 16
+
+// Import groups, in the order they should appear
+const (
+	rustImportGroupStd = iota
+	rustImportGroupExternal
+	rustImportGroupCrate
+)
+
+// A simple re-export on a line of its own, such as `pub use self::inner::Config;`
+var rustSimpleReexportPattern = regexp.MustCompile(`(?m)^\s*pub(?:\([^)]*\))?\s+use\s+([\w:]+)(?:\s+as\s+(\w+))?\s*;`)
+
+// rustUseItem is a use declaration found in the token stream
+type rustUseItem struct {
+	tree       string // The use tree without spaces, e.g. "std::{fmt,io}"
+	public     bool
+	attributed bool // Preceded by an attribute such as #[cfg(test)]
+	line       int  // Start, at the visibility or the use keyword
+	column     int
+	endLine    int // End, after the semicolon
+	endColumn  int
+	first      int    // Index of the first token
+	last       int    // Index of the semicolon
+	scope      string // Function or module body the item is declared in
+}
+
+// RustImportsDetector checks the hygiene of use declarations: sorting and
+// grouping, duplicates, imports inside function bodies and chains of
+// re-exports. Its rules form the imports rule pack, which is off unless
+// enabled in the configuration.
+type RustImportsDetector struct {
+	config *DetectorConfig
+}
+
+// NewRustImportsDetector creates a new Rust imports detector
+func NewRustImportsDetector(config *DetectorConfig) *RustImportsDetector {
+	if config == nil {
+		config = DefaultDetectorConfig()
+	}
+	return &RustImportsDetector{config: config}
+}
+
+// Name returns the name of this detector
+func (d *RustImportsDetector) Name() string {
+	return "Rust Import Analysis"
+}
+
+// Description returns a description of what this detector checks for
+func (d *RustImportsDetector) Description() string {
+	return "Detects unsorted and ungrouped use declarations, duplicate imports, imports inside function bodies and long chains of re-exports"
+}
+
+// Detect analyzes the use declarations of a Rust file when the imports rule pack is enabled
+func (d *RustImportsDetector) Detect(fileInfo *models.FileInfo, astInfo interface{}) []*models.Violation {
+	var violations []*models.Violation
+
+	rust := d.config.RustConfig
+	if rust == nil || !rust.EnableImportsPack {
+		return violations
+	}
+
+	rustAstInfo, ok := astInfo.(*types.RustASTInfo)
+	if !ok || rustAstInfo == nil {
+		return violations
+	}
+
+	items := rustUseItems(rustAstInfo)
+	if len(items) == 0 {
+		return violations
+	}
+
+	// Fixes need the source lines; findings are reported without them
+	var lines []string
+	if content, err := os.ReadFile(fileInfo.Path); err == nil {
+		lines = strings.Split(string(content), "\n")
+	}
+
+	violations = append(violations, d.detectUnsortedImports(fileInfo.Path, items, lines)...)
+	violations = append(violations, d.detectDuplicateImports(fileInfo.Path, items, lines)...)
+	violations = append(violations, d.detectLocalImports(fileInfo.Path, items, rustAstInfo.Functions)...)
+	violations = append(violations, d.detectReexportChains(fileInfo.Path, items)...)
+
+	return violations
+}
+
+// detectUnsortedImports reports runs of consecutive use declarations that
+// are not grouped as std, external crates and the current crate, or not
+// sorted within their group. A run of use declarations on lines of their
+// own, with only blank lines between them, can be rewritten by goclean fix.
+func (d *RustImportsDetector) detectUnsortedImports(filePath string, items []rustUseItem, lines []string) []*models.Violation {
+	var violations []*models.Violation
+
+	for start := 0; start < len(items); {
+		end := start + 1
+		for end < len(items) && items[end].first == items[end-1].last+1 && !items[end].attributed && !items[start].attributed {
+			end++
+		}
+		run := items[start:end]
+		start = end
+
+		for i := 1; i < len(run); i++ {
+			if !rustUseLess(run[i], run[i-1]) {
+				continue
+			}
+			previous := run[i-1]
+			for j := i - 2; j >= 0 && rustUseLess(run[i], run[j]); j-- {
+				previous = run[j]
+			}
+			violations = append(violations, &models.Violation{
+				Type:        models.ViolationTypeRustModuleOrganization,
+				Severity:    models.SeverityLow,
+				File:        filePath,
+				Line:        run[i].line,
+				Column:      run[i].column,
+				EndLine:     run[len(run)-1].endLine,
+				Message:     fmt.Sprintf("Imports are not sorted and grouped: '%s' should come before '%s'", run[i].tree, previous.tree),
+				Rule:        RustUnsortedImports,
+				Suggestion:  "Group imports as std, external crates and the current crate, separated by blank lines, and sort each group",
+				CodeSnippet: "use " + run[i].tree + ";",
+				Fix:         sortedImportsFix(run, lines),
+			})
+			break
+		}
+	}
+	return violations
+}
+
+// detectDuplicateImports reports use declarations that import a path
+// already imported in the same scope. A declaration whose every import is a
+// duplicate can be deleted by goclean fix.
+func (d *RustImportsDetector) detectDuplicateImports(filePath string, items []rustUseItem, lines []string) []*models.Violation {
+	var violations []*models.Violation
+
+	seen := make(map[string]int) // Scope and imported path to the line of the first import
+	for _, item := range items {
+		if item.attributed {
+			continue
+		}
+		leaves := rustUseLeaves(item.tree)
+		var duplicates []string
+		firstLine := 0
+		for _, leaf := range leaves {
+			key := item.scope + "|" + leaf
+			if line, found := seen[key]; found {
+				duplicates = append(duplicates, leaf)
+				if firstLine == 0 {
+					firstLine = line
+				}
+				continue
+			}
+			seen[key] = item.line
+		}
+		if len(duplicates) == 0 {
+			continue
+		}
+
+		violation := &models.Violation{
+			Type:        models.ViolationTypeRustModuleOrganization,
+			Severity:    models.SeverityLow,
+			File:        filePath,
+			Line:        item.line,
+			Column:      item.column,
+			EndLine:     item.endLine,
+			Message:     fmt.Sprintf("Duplicate import of '%s'", strings.Join(duplicates, "', '")),
+			Description: fmt.Sprintf("Already imported on line %d", firstLine),
+			Rule:        RustDuplicateImport,
+			Suggestion:  "Remove the duplicate import",
+			CodeSnippet: "use " + item.tree + ";",
+		}
+		if len(duplicates) == len(leaves) {
+			violation.Fix = wholeLineRemovalFix(lines, item.line, item.column, item.endLine, item.endColumn, "Delete the duplicate import")
+		}
+		violations = append(violations, violation)
+	}
+	return violations
+}
+
+// detectLocalImports reports functions declaring more use items in their
+// body than allowed. A few local imports keep a name close to its only use,
+// but many hide the dependencies of the module.
+func (d *RustImportsDetector) detectLocalImports(filePath string, items []rustUseItem, functions []*types.RustFunctionInfo) []*models.Violation {
+	var violations []*models.Violation
+
+	maxLocal := positiveOr(d.config.RustConfig.MaxLocalImports, DefaultMaxLocalImports)
+	counts := make(map[string]int)
+	for _, item := range items {
+		counts[item.scope]++
+	}
+	for _, function := range functions {
+		count := counts[rustFunctionScope(function)]
+		if count <= maxLocal {
+			continue
+		}
+		violations = append(violations, &models.Violation{
+			Type:        models.ViolationTypeRustModuleOrganization,
+			Severity:    models.SeverityLow,
+			File:        filePath,
+			Line:        function.StartLine,
+			Column:      function.StartColumn,
+			EndLine:     function.EndLine,
+			Message:     fmt.Sprintf("Function '%s' has %d use declarations in its body (max: %d)", function.Name, count, maxLocal),
+			Rule:        RustLocalImports,
+			Suggestion:  "Move the imports to the top of the module, where readers look for the dependencies",
+			CodeSnippet: fmt.Sprintf("fn %s", function.Name),
+		})
+	}
+	return violations
+}
+
+// detectReexportChains reports re-exports of names that are re-exported
+// again by the modules they come from, more times than allowed. Only paths
+// within the crate are followed.
+func (d *RustImportsDetector) detectReexportChains(filePath string, items []rustUseItem) []*models.Violation {
+	var violations []*models.Violation
+
+	srcDir, module, ok := rustCrateModule(filePath)
+	if !ok {
+		return violations
+	}
+	maxDepth := positiveOr(d.config.RustConfig.MaxReexportDepth, DefaultMaxReexportDepth)
+
+	for _, item := range items {
+		if !item.public || item.scope != "" {
+			continue
+		}
+		for _, leaf := range rustUseLeaves(item.tree) {
+			path, _, _ := strings.Cut(leaf, " as ")
+			chain := rustReexportChain(srcDir, module, path)
+			if len(chain) <= maxDepth {
+				continue
+			}
+			name := lastRustSegment(chain[len(chain)-1])
+			violations = append(violations, &models.Violation{
+				Type:        models.ViolationTypeRustModuleOrganization,
+				Severity:    models.SeverityLow,
+				File:        filePath,
+				Line:        item.line,
+				Column:      item.column,
+				EndLine:     item.endLine,
+				Message:     fmt.Sprintf("Re-export of '%s' goes through %d pub use declarations (max: %d): %s", name, len(chain), maxDepth, strings.Join(chain, " -> ")),
+				Rule:        RustReexportChain,
+				Suggestion:  fmt.Sprintf("Re-export '%s' from the module that defines it, so readers find it in one step", name),
+				CodeSnippet: "pub use " + item.tree + ";",
+			})
+		}
+	}
+	return violations
+}
+
+// rustUseItems finds the use declarations of a file with the function or
+// inline module body they are declared in. Use declarations in macro bodies
+// are skipped.
+func rustUseItems(astInfo *types.RustASTInfo) []rustUseItem {
+	var items []rustUseItem
+	tokens := astInfo.Tokens
+	for i := 0; i < len(tokens); i++ {
+		if tokens[i].Kind != types.RustTokenKeyword || tokens[i].Text != "use" {
+			continue
+		}
+
+		first := i
+		if i > 0 && tokens[i-1].Text == ")" {
+			// pub(crate), pub(super) and pub(in path)
+			for j := i - 1; j > 0; j-- {
+				if tokens[j].Text == "(" {
+					if tokens[j-1].Text == "pub" {
+						first = j - 1
+					}
+					break
+				}
+			}
+		} else if i > 0 && tokens[i-1].Text == "pub" {
+			first = i - 1
+		}
+
+		last := i + 1
+		var tree strings.Builder
+		for ; last < len(tokens) && tokens[last].Text != ";"; last++ {
+			if tokens[last].Text == "as" {
+				tree.WriteString(" as ")
+				continue
+			}
+			tree.WriteString(tokens[last].Text)
+		}
+		if last >= len(tokens) || strings.Contains(tree.String(), "$") {
+			i = last
+			continue
+		}
+
+		items = append(items, rustUseItem{
+			tree:       tree.String(),
+			public:     first < i,
+			attributed: first > 0 && tokens[first-1].Text == "]",
+			line:       tokens[first].Line,
+			column:     tokens[first].Column,
+			endLine:    tokens[last].Line,
+			endColumn:  tokens[last].Column + 1,
+			first:      first,
+			last:       last,
+			scope:      rustUseScope(astInfo, tokens[i].Line),
+		})
+		i = last
+	}
+	return items
+}
+
+// rustUseScope names the innermost function or inline module containing a
+// line, or returns "" for the top level of the file
+func rustUseScope(astInfo *types.RustASTInfo, line int) string {
+	scope, start, end := "", 0, 0
+	for _, function := range astInfo.Functions {
+		if function.StartLine <= line && line <= function.EndLine && (scope == "" || function.StartLine >= start && function.EndLine <= end) {
+			scope, start, end = rustFunctionScope(function), function.StartLine, function.EndLine
+		}
+	}
+	if scope != "" {
+		return scope
+	}
+	for _, module := range astInfo.Modules {
+		if module.StartLine < line && line <= module.EndLine && (scope == "" || module.StartLine >= start && module.EndLine <= end) {
+			scope, start, end = fmt.Sprintf("mod %s:%d", module.Name, module.StartLine), module.StartLine, module.EndLine
+		}
+	}
+	return scope
+}
+
+// rustFunctionScope names the body of a function
+func rustFunctionScope(function *types.RustFunctionInfo) string {
+	return fmt.Sprintf("fn %s:%d", function.Name, function.StartLine)
+}
+
+// rustImportGroup returns the group of a use tree by its first segment
+func rustImportGroup(tree string) int {
+	first := strings.TrimPrefix(tree, "::")
+	if end := strings.IndexAny(first, ":{ "); end >= 0 {
+		first = first[:end]
+	}
+	switch first {
+	case "std", "core", "alloc":
+		return rustImportGroupStd
+	case "crate", "self", "super":
+		return rustImportGroupCrate
+	}
+	return rustImportGroupExternal
+}
+
+// rustUseLess orders use declarations by group, then by path
+func rustUseLess(a, b rustUseItem) bool {
+	groupA, groupB := rustImportGroup(a.tree), rustImportGroup(b.tree)
+	if groupA != groupB {
+		return groupA < groupB
+	}
+	return a.tree < b.tree
+}
+
+// sortedImportsFix returns a fix rewriting a run of use declarations in
+// order, with a blank line between groups, or nil when other code or
+// comments share their lines
+func sortedImportsFix(run []rustUseItem, lines []string) *models.Fix {
+	startLine, endLine := run[0].line, run[len(run)-1].endLine
+	if lines == nil || endLine > len(lines) {
+		return nil
+	}
+
+	covered := make(map[int]bool)
+	for _, item := range run {
+		first, last := lines[item.line-1], lines[item.endLine-1]
+		if item.column-1 > len(first) || item.endColumn-1 > len(last) {
+			return nil
+		}
+		rest := strings.TrimSpace(last[item.endColumn-1:])
+		if strings.TrimSpace(first[:item.column-1]) != "" || (rest != "" && !strings.HasPrefix(rest, "//")) {
+			return nil
+		}
+		for line := item.line; line <= item.endLine; line++ {
+			if covered[line] {
+				return nil
+			}
+			covered[line] = true
+		}
+	}
+	for line := startLine; line <= endLine; line++ {
+		if !covered[line] && strings.TrimSpace(lines[line-1]) != "" {
+			return nil
+		}
+	}
+
+	sorted := append([]rustUseItem(nil), run...)
+	sort.SliceStable(sorted, func(i, j int) bool { return rustUseLess(sorted[i], sorted[j]) })
+	var text []string
+	for i, item := range sorted {
+		if i > 0 && rustImportGroup(item.tree) != rustImportGroup(sorted[i-1].tree) {
+			text = append(text, "")
+		}
+		text = append(text, lines[item.line-1:item.endLine]...)
+	}
+
+	return &models.Fix{
+		Description: "Sort and group the imports",
+		Edits: []models.TextEdit{{
+			Line:      startLine,
+			Column:    1,
+			EndLine:   endLine + 1,
+			EndColumn: 1,
+			NewText:   strings.Join(text, "\n") + "\n",
+		}},
+	}
+}
+
+// rustUseLeaves expands a use tree such as "std::{fmt,io::{self,Read}}" into
+// the paths it imports: "std::fmt", "std::io" and "std::io::Read". Aliases
+// are kept, as in "std::io as stdio".
+func rustUseLeaves(tree string) []string {
+	open := strings.Index(tree, "{")
+	if open < 0 || !strings.HasSuffix(tree, "}") {
+		return []string{tree}
+	}
+
+	prefix := strings.TrimSuffix(tree[:open], "::")
+	body := tree[open+1 : len(tree)-1]
+	var parts []string
+	depth, start := 0, 0
+	for i := 0; i < len(body); i++ {
+		switch body[i] {
+		case '{':
+			depth++
+		case '}':
+			depth--
+		case ',':
+			if depth == 0 {
+				parts = append(parts, body[start:i])
+				start = i + 1
+			}
+		}
+	}
+	parts = append(parts, body[start:])
+
+	var leaves []string
+	for _, part := range parts {
+		if part == "" {
+			continue
+		}
+		for _, leaf := range rustUseLeaves(part) {
+			switch {
+			case leaf == "self" || strings.HasPrefix(leaf, "self as "):
+				leaves = append(leaves, prefix+strings.TrimPrefix(leaf, "self"))
+			case prefix == "":
+				leaves = append(leaves, leaf)
+			default:
+				leaves = append(leaves, prefix+"::"+leaf)
+			}
+		}
+	}
+	return leaves
+}
+
+// lastRustSegment returns the last segment of a path such as "crate::config::Config"
+func lastRustSegment(path string) string {
+	if index := strings.LastIndex(path, "::"); index >= 0 {
+		return path[index+len("::"):]
+	}
+	return path
+}
+
+// rustCrateModule returns the src directory of the crate of a Rust file and
+// the module path of the file within the crate
+func rustCrateModule(filePath string) (string, []string, bool) {
+	parts := strings.Split(filepath.ToSlash(filepath.Clean(filePath)), "/")
+	for i := len(parts) - 2; i >= 0; i-- {
+		if parts[i] != "src" {
+			continue
+		}
+		module := append([]string{}, parts[i+1:len(parts)-1]...)
+		stem := strings.TrimSuffix(parts[len(parts)-1], ".rs")
+		if stem != "mod" && !(len(module) == 0 && (stem == "lib" || stem == "main")) {
+			module = append(module, stem)
+		}
+		return filepath.FromSlash(strings.Join(parts[:i+1], "/")), module, true
+	}
+	return "", nil, false
+}
+
+// rustReexportChain follows a re-exported path through the modules of the
+// crate while they re-export the name again, and returns each path of the
+// chain from the crate root. Paths into other crates end the chain.
+func rustReexportChain(srcDir string, module []string, path string) []string {
+	var chain []string
+	visited := make(map[string]bool)
+	for len(chain) < maxReexportHops {
+		target, name, ok := resolveRustPath(module, path)
+		if !ok {
+			if len(chain) == 0 {
+				chain = append(chain, path)
+			}
+			return chain
+		}
+		absolute := strings.Join(append(append([]string{"crate"}, target...), name), "::")
+		if visited[absolute] {
+			return chain
+		}
+		visited[absolute] = true
+		chain = append(chain, absolute)
+
+		next, found := rustModuleReexport(srcDir, target, name)
+		if !found {
+			return chain
+		}
+		module, path = target, next
+	}
+	return chain
+}
+
+// resolveRustPath splits a crate, self or super path into the module
+// segments below the crate root and the imported name
+func resolveRustPath(module []string, path string) ([]string, string, bool) {
+	segments := strings.Split(path, "::")
+	var resolved []string
+	switch segments[0] {
+	case "crate":
+		segments = segments[1:]
+	case "self":
+		resolved = append(resolved, module...)
+		segments = segments[1:]
+	case "super":
+		resolved = append(resolved, module...)
+		for len(segments) > 0 && segments[0] == "super" {
+			if len(resolved) == 0 {
+				return nil, "", false
+			}
+			resolved = resolved[:len(resolved)-1]
+			segments = segments[1:]
+		}
+	default:
+		return nil, "", false
+	}
+	if len(segments) == 0 {
+		return nil, "", false
+	}
+	name := segments[len(segments)-1]
+	if name == "*" || name == "self" || name == "" {
+		return nil, "", false
+	}
+	return append(resolved, segments[:len(segments)-1]...), name, true
+}
+
+// rustModuleReexport returns the path a module re-exports a name from, if it does
+func rustModuleReexport(srcDir string, module []string, name string) (string, bool) {
+	var candidates []string
+	if len(module) == 0 {
+		candidates = []string{filepath.Join(srcDir, "lib.rs"), filepath.Join(srcDir, "main.rs")}
+	} else {
+		dir := filepath.Join(append([]string{srcDir}, module...)...)
+		candidates = []string{dir + ".rs", filepath.Join(dir, "mod.rs")}
+	}
+
+	for _, candidate := range candidates {
+		content, err := os.ReadFile(candidate)
+		if err != nil {
+			continue
+		}
+		for _, match := range rustSimpleReexportPattern.FindAllStringSubmatch(string(content), -1) {
+			exported := match[2]
+			if exported == "" {
+				exported = lastRustSegment(match[1])
+			}
+			if exported == name {
+				return match[1], true
+			}
+		}
+		return "", false
+	}
+	return "", false
+}
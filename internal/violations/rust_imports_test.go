@@ -0,0 +1,170 @@
+package violations
+
+import (
+	"os"
+	"path/filepath"
+	"reflect"
+	"strings"
+	"testing"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+const rustImportsSource = `use std::io;
+use crate::config::Config;
+use serde::Deserialize;
+use std::fmt;
+
+fn load() {
+    use std::fs;
+    use std::path::Path;
+    use std::env;
+    use std::fs;
+}
+`
+
+// writeRustFiles writes Rust sources under a temporary crate and returns its directory
+func writeRustFiles(t *testing.T, files map[string]string) string {
+	t.Helper()
+	dir := t.TempDir()
+	for name, content := range files {
+		path := filepath.Join(dir, filepath.FromSlash(name))
+		if err := os.MkdirAll(filepath.Dir(path), 0755); err != nil {
+			t.Fatalf("Failed to create %s: %v", filepath.Dir(path), err)
+		}
+		if err := os.WriteFile(path, []byte(content), 0644); err != nil {
+			t.Fatalf("Failed to write %s: %v", path, err)
+		}
+	}
+	return dir
+}
+
+func TestRustImportsDetector(t *testing.T) {
+	dir := writeRustFiles(t, map[string]string{"src/lib.rs": rustImportsSource})
+	fileInfo := &models.FileInfo{Path: filepath.Join(dir, "src", "lib.rs")}
+	astInfo := &types.RustASTInfo{
+		Tokens:    rustTestTokens(rustImportsSource),
+		Functions: []*types.RustFunctionInfo{{Name: "load", StartLine: 6, EndLine: 11, StartColumn: 1}},
+	}
+
+	config := DefaultDetectorConfig()
+	detector := NewRustImportsDetector(config)
+	if found := detector.Detect(fileInfo, astInfo); len(found) != 0 {
+		t.Fatalf("Expected no violations without the imports pack, got %+v", found)
+	}
+
+	config.RustConfig.EnableImportsPack = true
+	found := detector.Detect(fileInfo, astInfo)
+	if len(found) != 4 {
+		t.Fatalf("Expected 4 violations, got %d: %+v", len(found), found)
+	}
+
+	unsorted := found[0]
+	if unsorted.Rule != RustUnsortedImports || unsorted.Line != 3 {
+		t.Errorf("Unexpected unsorted imports violation %+v", unsorted)
+	}
+	if unsorted.Message != "Imports are not sorted and grouped: 'serde::Deserialize' should come before 'crate::config::Config'" {
+		t.Errorf("Unexpected message %q", unsorted.Message)
+	}
+	expected := models.TextEdit{
+		Line:      1,
+		Column:    1,
+		EndLine:   5,
+		EndColumn: 1,
+		NewText:   "use std::fmt;\nuse std::io;\n\nuse serde::Deserialize;\n\nuse crate::config::Config;\n",
+	}
+	if unsorted.Fix == nil || len(unsorted.Fix.Edits) != 1 || unsorted.Fix.Edits[0] != expected {
+		t.Errorf("Unexpected sorting fix %+v", unsorted.Fix)
+	}
+
+	if found[1].Rule != RustUnsortedImports || found[1].Line != 9 || found[1].Message != "Imports are not sorted and grouped: 'std::env' should come before 'std::fs'" {
+		t.Errorf("Unexpected unsorted imports violation in the function body %+v", found[1])
+	}
+
+	duplicate := found[2]
+	if duplicate.Rule != RustDuplicateImport || duplicate.Line != 10 || duplicate.Message != "Duplicate import of 'std::fs'" ||
+		duplicate.Description != "Already imported on line 7" {
+		t.Errorf("Unexpected duplicate import violation %+v", duplicate)
+	}
+	if duplicate.Fix == nil || duplicate.Fix.Edits[0] != models.DeleteLinesEdit(10, 10) {
+		t.Errorf("Expected the duplicate line to be deleted, got %+v", duplicate.Fix)
+	}
+
+	if found[3].Rule != RustLocalImports || found[3].Message != "Function 'load' has 4 use declarations in its body (max: 3)" {
+		t.Errorf("Unexpected local imports violation %+v", found[3])
+	}
+}
+
+func TestRustImportsFixNeedsOwnLines(t *testing.T) {
+	source := "use std::io; use serde::Serialize;\nuse anyhow::Result; // errors\n"
+	dir := writeRustFiles(t, map[string]string{"src/lib.rs": source})
+	config := DefaultDetectorConfig()
+	config.RustConfig.EnableImportsPack = true
+
+	found := NewRustImportsDetector(config).Detect(
+		&models.FileInfo{Path: filepath.Join(dir, "src", "lib.rs")},
+		&types.RustASTInfo{Tokens: rustTestTokens(source)},
+	)
+	if len(found) != 1 || found[0].Rule != RustUnsortedImports {
+		t.Fatalf("Expected 1 unsorted imports violation, got %+v", found)
+	}
+	if found[0].Fix != nil {
+		t.Errorf("Expected no fix for imports sharing a line, got %+v", found[0].Fix)
+	}
+}
+
+func TestRustReexportChain(t *testing.T) {
+	dir := writeRustFiles(t, map[string]string{
+		"src/lib.rs":   "pub use crate::a::Config;\n",
+		"src/a/mod.rs": "mod b;\nmod c;\n\npub use self::b::Config;\n",
+		"src/a/b.rs":   "pub(crate) use super::c::Config;\n",
+		"src/a/c.rs":   "pub struct Config;\n",
+	})
+	fileInfo := &models.FileInfo{Path: filepath.Join(dir, "src", "lib.rs")}
+	astInfo := &types.RustASTInfo{Tokens: rustTestTokens("pub use crate::a::Config;\n")}
+
+	config := DefaultDetectorConfig()
+	config.RustConfig.EnableImportsPack = true
+	found := NewRustImportsDetector(config).Detect(fileInfo, astInfo)
+	if len(found) != 1 || found[0].Rule != RustReexportChain {
+		t.Fatalf("Expected 1 re-export chain violation, got %+v", found)
+	}
+	if found[0].Message != "Re-export of 'Config' goes through 3 pub use declarations (max: 2): crate::a::Config -> crate::a::b::Config -> crate::a::c::Config" {
+		t.Errorf("Unexpected message %q", found[0].Message)
+	}
+
+	config.RustConfig.MaxReexportDepth = 3
+	if found := NewRustImportsDetector(config).Detect(fileInfo, astInfo); len(found) != 0 {
+		t.Errorf("Expected the chain to be allowed at depth 3, got %+v", found)
+	}
+}
+
+func TestRustUseLeaves(t *testing.T) {
+	tests := map[string][]string{
+		"std::fmt":                    {"std::fmt"},
+		"std::{fmt,io::{self,Read},}": {"std::fmt", "std::io", "std::io::Read"},
+		"std::{self as stdlib,fs}":    {"std as stdlib", "std::fs"},
+		"{anyhow::Result,serde::*}":   {"anyhow::Result", "serde::*"},
+	}
+	for tree, expected := range tests {
+		if leaves := rustUseLeaves(tree); !reflect.DeepEqual(leaves, expected) {
+			t.Errorf("rustUseLeaves(%q) = %v, expected %v", tree, leaves, expected)
+		}
+	}
+}
+
+func TestRustCrateModule(t *testing.T) {
+	tests := map[string]string{
+		"/work/shop/src/lib.rs":          "",
+		"/work/shop/src/orders/mod.rs":   "orders",
+		"/work/shop/src/orders/tax.rs":   "orders::tax",
+		"/work/shop/src/bin/tool/cli.rs": "bin::tool::cli",
+	}
+	for path, expected := range tests {
+		srcDir, module, ok := rustCrateModule(filepath.FromSlash(path))
+		if !ok || filepath.ToSlash(srcDir) != "/work/shop/src" || strings.Join(module, "::") != expected {
+			t.Errorf("rustCrateModule(%q) = %q, %v, %v", path, srcDir, module, ok)
+		}
+	}
+}
@@ -0,0 +1,202 @@
+package violations
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"strings"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+// RustLibraryPanic is the rule of the library panic detector
+const RustLibraryPanic = "rust-library-panic"
+
+// Cargo targets a Rust file can belong to
+const (
+	rustTargetLibrary = string(types.RustTargetLibrary)
+	rustTargetBinary  = string(types.RustTargetBinary)
+	rustTargetTest    = string(types.RustTargetTest)
+	rustTargetExample = string(types.RustTargetExample)
+	rustTargetBench   = string(types.RustTargetBench)
+)
+
+// DefaultLibraryPanicMacros returns the macros reported in the public
+// functions of library code by default
+func DefaultLibraryPanicMacros() []string {
+	return []string{"panic", "todo", "unimplemented", "unreachable"}
+}
+
+// RustLibraryPanicDetector reports panicking macros such as panic! and todo!
+// inside pub functions of library targets, where the caller cannot recover
+// from them. Binaries and tests are allowed to panic unless configured
+// otherwise; examples and benches always are.
+type RustLibraryPanicDetector struct {
+	config *DetectorConfig
+}
+
+// NewRustLibraryPanicDetector creates a new Rust library panic detector
+func NewRustLibraryPanicDetector(config *DetectorConfig) *RustLibraryPanicDetector {
+	if config == nil {
+		config = DefaultDetectorConfig()
+	}
+	return &RustLibraryPanicDetector{config: config}
+}
+
+// Name returns the name of this detector
+func (d *RustLibraryPanicDetector) Name() string {
+	return "Rust Library Panic Analysis"
+}
+
+// Description returns a description of what this detector checks for
+func (d *RustLibraryPanicDetector) Description() string {
+	return "Detects panic!, todo!, unimplemented! and unreachable! inside pub functions of library code"
+}
+
+// Detect reports the configured macros inside pub functions of a Rust file
+func (d *RustLibraryPanicDetector) Detect(fileInfo *models.FileInfo, astInfo interface{}) []*models.Violation {
+	rust := d.config.RustConfig
+	if rust == nil {
+		return nil
+	}
+	info, ok := astInfo.(*types.RustASTInfo)
+	if !ok || info == nil {
+		return nil
+	}
+
+	macros := rust.LibraryPanicMacros
+	if macros == nil {
+		macros = DefaultLibraryPanicMacros()
+	}
+	reported := make(map[string]bool, len(macros))
+	for _, macro := range macros {
+		reported[strings.TrimSuffix(strings.TrimSpace(macro), "!")] = true
+	}
+	if len(reported) == 0 {
+		return nil
+	}
+
+	target := rustFileTarget(info, fileInfo.Path)
+	switch {
+	case target == rustTargetExample || target == rustTargetBench:
+		return nil
+	case target == rustTargetBinary && rust.AllowPanicsInBinaries:
+		return nil
+	case target == rustTargetTest && rust.AllowPanicsInTests:
+		return nil
+	}
+
+	var violations []*models.Violation
+	for _, site := range info.PanicSites {
+		if site.Kind != types.RustPanicMacro || !reported[site.Name] || site.InTest && rust.AllowPanicsInTests {
+			continue
+		}
+		fn := rustEnclosingFunction(info.Functions, site)
+		if fn == nil || fn.Visibility != "pub" {
+			continue
+		}
+		violations = append(violations, &models.Violation{
+			Type:       models.ViolationTypeRustPanicProneCode,
+			Severity:   libraryPanicSeverity(site.Name),
+			Message:    fmt.Sprintf("%s!() in public function '%s' of %s code", site.Name, fn.Name, rustTargetDescription(target)),
+			File:       fileInfo.Path,
+			Line:       site.Line,
+			Column:     site.Column,
+			Rule:       RustLibraryPanic,
+			Suggestion: libraryPanicSuggestion(site.Name),
+		})
+	}
+	return violations
+}
+
+// libraryPanicSeverity rates unreachable! as low, as it states an invariant
+// rather than a failure the caller could cause
+func libraryPanicSeverity(macro string) models.Severity {
+	if macro == "unreachable" {
+		return models.SeverityLow
+	}
+	return models.SeverityMedium
+}
+
+// libraryPanicSuggestion suggests what to use instead of a panicking macro
+func libraryPanicSuggestion(macro string) string {
+	switch macro {
+	case "panic":
+		return "Return a Result with an error the caller can handle, or document the panic in a # Panics section"
+	case "todo", "unimplemented":
+		return "Finish the implementation, or keep the function out of the public API until it is ready"
+	case "unreachable":
+		return "Return an error if caller input can reach this branch, or explain the invariant that rules it out"
+	}
+	return "Return a Result with an error the caller can handle, or document the panic in a # Panics section"
+}
+
+// rustTargetDescription names a target in messages
+func rustTargetDescription(target string) string {
+	switch target {
+	case rustTargetBinary:
+		return "binary"
+	case rustTargetTest:
+		return "test"
+	}
+	return "library"
+}
+
+// rustFileTarget returns the Cargo target the scanner resolved from the
+// package manifest, or the target guessed from the path of the file. A
+// build script runs as a binary and is treated as one.
+func rustFileTarget(info *types.RustASTInfo, path string) string {
+	if info != nil && info.Target == types.RustTargetBuild {
+		return rustTargetBinary
+	}
+	if info != nil && info.Target != "" {
+		return string(info.Target)
+	}
+	return rustTarget(path)
+}
+
+// rustTarget returns the Cargo target a file belongs to from its location:
+// the tests, examples and benches directories, src/main.rs and src/bin, or
+// the library. Modules of a crate without src/lib.rs belong to its binary.
+// Files outside a src directory count as library code.
+func rustTarget(path string) string {
+	parts := strings.Split(filepath.ToSlash(filepath.Clean(path)), "/")
+	src := -1
+	for i := len(parts) - 2; i >= 0 && src < 0; i-- {
+		if parts[i] == "src" {
+			src = i
+		}
+	}
+	for _, part := range parts[src+1 : len(parts)-1] {
+		switch part {
+		case "tests":
+			return rustTargetTest
+		case "examples":
+			return rustTargetExample
+		case "benches":
+			return rustTargetBench
+		}
+	}
+
+	name := parts[len(parts)-1]
+	switch {
+	case src < 0 && name == "main.rs":
+		return rustTargetBinary
+	case src < 0:
+		return rustTargetLibrary
+	case src == len(parts)-2 && name == "main.rs":
+		return rustTargetBinary
+	case src < len(parts)-2 && parts[src+1] == "bin":
+		return rustTargetBinary
+	}
+
+	srcDir := filepath.FromSlash(strings.Join(parts[:src+1], "/"))
+	if _, err := os.Stat(filepath.Join(srcDir, "lib.rs")); err == nil {
+		return rustTargetLibrary
+	}
+	if _, err := os.Stat(filepath.Join(srcDir, "main.rs")); err == nil {
+		return rustTargetBinary
+	}
+	return rustTargetLibrary
+}
@@ -0,0 +1,98 @@
+package violations
+
+import (
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+func TestRustLibraryPanicDetector(t *testing.T) {
+	astInfo := &types.RustASTInfo{
+		Functions: []*types.RustFunctionInfo{
+			{Name: "parse", StartLine: 1, EndLine: 10, Visibility: "pub"},
+			{Name: "helper", StartLine: 12, EndLine: 15, Visibility: "private"},
+			{Name: "internal", StartLine: 17, EndLine: 20, Visibility: "pub(crate)"},
+			{Name: "parses", StartLine: 24, EndLine: 27, Visibility: "private"},
+		},
+		PanicSites: []*types.RustPanicSite{
+			{Kind: types.RustPanicMacro, Name: "panic", Function: "parse", Line: 3, Column: 9},
+			{Kind: types.RustPanicMacro, Name: "assert", Function: "parse", Line: 4, Column: 5},
+			{Kind: types.RustPanicUnwrap, Name: "unwrap", Function: "parse", Line: 5, Column: 20},
+			{Kind: types.RustPanicMacro, Name: "unreachable", Function: "parse", Line: 8, Column: 14},
+			{Kind: types.RustPanicMacro, Name: "todo", Function: "helper", Line: 13, Column: 5},
+			{Kind: types.RustPanicMacro, Name: "todo", Function: "internal", Line: 18, Column: 5},
+			{Kind: types.RustPanicMacro, Name: "panic", Function: "parses", Line: 25, Column: 9, InTest: true},
+		},
+	}
+
+	config := DefaultDetectorConfig()
+	detector := NewRustLibraryPanicDetector(config)
+	violations := detector.Detect(&models.FileInfo{Path: "src/parser.rs"}, astInfo)
+	if len(violations) != 2 {
+		t.Fatalf("Expected panic! and unreachable! in the pub function, got %+v", violations)
+	}
+	if v := violations[0]; v.Rule != RustLibraryPanic || v.Line != 3 || v.Severity != models.SeverityMedium ||
+		v.Message != "panic!() in public function 'parse' of library code" {
+		t.Errorf("Unexpected panic! violation %+v", v)
+	}
+	if v := violations[1]; v.Line != 8 || v.Severity != models.SeverityLow {
+		t.Errorf("Expected unreachable! to be low severity, got %+v", v)
+	}
+
+	for _, path := range []string{"src/main.rs", "src/bin/tool.rs", "tests/parser.rs", "examples/demo.rs", "benches/parse.rs"} {
+		if violations := detector.Detect(&models.FileInfo{Path: path}, astInfo); len(violations) != 0 {
+			t.Errorf("Expected %s to be allowed to panic, got %+v", path, violations)
+		}
+	}
+
+	config.RustConfig.LibraryPanicMacros = []string{"assert!"}
+	violations = detector.Detect(&models.FileInfo{Path: "src/parser.rs"}, astInfo)
+	if len(violations) != 1 || violations[0].Line != 4 {
+		t.Errorf("Expected only the configured assert! to be reported, got %+v", violations)
+	}
+
+	config.RustConfig.LibraryPanicMacros = []string{"panic"}
+	config.RustConfig.AllowPanicsInBinaries = false
+	violations = detector.Detect(&models.FileInfo{Path: "src/main.rs"}, astInfo)
+	if len(violations) != 1 || violations[0].Message != "panic!() in public function 'parse' of binary code" {
+		t.Errorf("Expected binaries to be reported once allowed panics are turned off, got %+v", violations)
+	}
+
+	config.RustConfig.LibraryPanicMacros = []string{}
+	if violations := detector.Detect(&models.FileInfo{Path: "src/parser.rs"}, astInfo); len(violations) != 0 {
+		t.Errorf("Expected an empty macro list to disable the rule, got %+v", violations)
+	}
+}
+
+func TestRustTarget(t *testing.T) {
+	binary := filepath.Join(t.TempDir(), "tool", "src")
+	if err := os.MkdirAll(binary, 0o755); err != nil {
+		t.Fatal(err)
+	}
+	if err := os.WriteFile(filepath.Join(binary, "main.rs"), []byte("fn main() {}"), 0o644); err != nil {
+		t.Fatal(err)
+	}
+
+	tests := map[string]string{
+		"src/lib.rs":                     rustTargetLibrary,
+		"src/net/http.rs":                rustTargetLibrary,
+		"src/bin.rs":                     rustTargetLibrary,
+		"src/main.rs":                    rustTargetBinary,
+		"src/bin/tool.rs":                rustTargetBinary,
+		"src/bin/tool/args.rs":           rustTargetBinary,
+		filepath.Join(binary, "args.rs"): rustTargetBinary,
+		"tests/cli.rs":                   rustTargetTest,
+		"crate/examples/demo.rs":         rustTargetExample,
+		"crate/benches/parse.rs":         rustTargetBench,
+		"error_handling_bad.rs":          rustTargetLibrary,
+		"main.rs":                        rustTargetBinary,
+	}
+	for path, want := range tests {
+		if got := rustTarget(path); got != want {
+			t.Errorf("rustTarget(%q) = %q, want %q", path, got, want)
+		}
+	}
+}
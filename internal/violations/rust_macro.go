@@ -0,0 +1,171 @@
+package violations
+
+import (
+	"fmt"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+// Rules of the macro_rules! complexity analysis
+const (
+	RustMacroArms    = "rust-macro-arms"
+	RustMacroNesting = "rust-macro-nesting"
+	RustMacroLength  = "rust-macro-length"
+)
+
+// Default limits of macro_rules! definitions
+const (
+	DefaultMaxMacroArms    = 8
+	DefaultMaxMacroNesting = 4
+	DefaultMaxMacroLines   = 60
+)
+
+// rustMacroMetrics are the measures of one macro_rules! definition
+type rustMacroMetrics struct {
+	name    string
+	line    int
+	column  int
+	arms    int // Rule arms, one per `=>` at the top of the body
+	nesting int // Deepest token-tree nesting inside an arm
+	lines   int
+}
+
+// RustMacroDetector reports macro_rules! definitions with too many rule
+// arms, deeply nested token trees or long bodies. Macros escape the
+// function-level checks, so they are measured on their own.
+type RustMacroDetector struct {
+	config *DetectorConfig
+}
+
+// NewRustMacroDetector creates a new Rust macro detector
+func NewRustMacroDetector(config *DetectorConfig) *RustMacroDetector {
+	if config == nil {
+		config = DefaultDetectorConfig()
+	}
+	return &RustMacroDetector{config: config}
+}
+
+// Name returns the name of this detector
+func (d *RustMacroDetector) Name() string {
+	return "Rust Macro Analysis"
+}
+
+// Description returns a description of what this detector checks for
+func (d *RustMacroDetector) Description() string {
+	return "Detects macro_rules! definitions with too many rule arms, deeply nested token trees or long bodies"
+}
+
+// Detect measures the macro_rules! definitions of a Rust file
+func (d *RustMacroDetector) Detect(fileInfo *models.FileInfo, astInfo interface{}) []*models.Violation {
+	var violations []*models.Violation
+
+	rustAstInfo, ok := astInfo.(*types.RustASTInfo)
+	if !ok || rustAstInfo == nil {
+		return violations
+	}
+
+	maxArms, maxNesting, maxLines := DefaultMaxMacroArms, DefaultMaxMacroNesting, DefaultMaxMacroLines
+	if rust := d.config.RustConfig; rust != nil {
+		maxArms = positiveOr(rust.MaxMacroArms, maxArms)
+		maxNesting = positiveOr(rust.MaxMacroNesting, maxNesting)
+		maxLines = positiveOr(rust.MaxMacroLines, maxLines)
+	}
+
+	for _, macro := range rustMacroRules(rustAstInfo.Tokens) {
+		if macro.arms > maxArms {
+			violations = append(violations, d.violation(fileInfo.Path, macro, RustMacroArms, macro.arms, maxArms,
+				fmt.Sprintf("Macro '%s' has %d rule arms (max: %d)", macro.name, macro.arms, maxArms),
+				"Split the macro into smaller macros, or move the shared logic into functions the arms call"))
+		}
+		if macro.nesting > maxNesting {
+			violations = append(violations, d.violation(fileInfo.Path, macro, RustMacroNesting, macro.nesting, maxNesting,
+				fmt.Sprintf("Macro '%s' nests token trees %d deep (max: %d)", macro.name, macro.nesting, maxNesting),
+				"Flatten the repetitions, or let the macro expand to calls of helper macros or functions"))
+		}
+		if macro.lines > maxLines {
+			violations = append(violations, d.violation(fileInfo.Path, macro, RustMacroLength, macro.lines, maxLines,
+				fmt.Sprintf("Macro '%s' is %d lines long (max: %d)", macro.name, macro.lines, maxLines),
+				"Keep the macro a thin layer over functions, which are type-checked and easier to test"))
+		}
+	}
+
+	return violations
+}
+
+// violation builds a macro violation, of high severity at twice the limit
+// and medium otherwise
+func (d *RustMacroDetector) violation(filePath string, macro rustMacroMetrics, rule string, value, limit int, message, suggestion string) *models.Violation {
+	severity := models.SeverityMedium
+	if value >= limit*2 {
+		severity = models.SeverityHigh
+	}
+	return &models.Violation{
+		Type:        models.ViolationTypeRustMacroComplexity,
+		Severity:    severity,
+		File:        filePath,
+		Line:        macro.line,
+		Column:      macro.column,
+		EndLine:     macro.line + macro.lines - 1,
+		Message:     message,
+		Rule:        rule,
+		Suggestion:  suggestion,
+		CodeSnippet: "macro_rules! " + macro.name,
+	}
+}
+
+// rustMacroRules measures the macro_rules! definitions in a token stream
+func rustMacroRules(tokens []types.RustTokenInfo) []rustMacroMetrics {
+	var macros []rustMacroMetrics
+	for i := 0; i+3 < len(tokens); i++ {
+		if tokens[i].Text != "macro_rules" || tokens[i+1].Text != "!" || tokens[i+2].Kind != types.RustTokenIdent {
+			continue
+		}
+		open := i + 3
+		if !isRustOpenDelimiter(tokens[open].Text) {
+			continue
+		}
+
+		macro := rustMacroMetrics{name: tokens[i+2].Text, line: tokens[i].Line, column: tokens[i].Column}
+		depth := 0
+		end := len(tokens) - 1
+		for j := open; j < len(tokens); j++ {
+			switch {
+			case isRustOpenDelimiter(tokens[j].Text):
+				depth++
+				// The body is depth 1 and the matcher and transcriber of an arm depth 2
+				macro.nesting = max(macro.nesting, depth-2)
+			case isRustCloseDelimiter(tokens[j].Text):
+				depth--
+			case tokens[j].Text == "=>" && depth == 1:
+				macro.arms++
+			}
+			if depth == 0 {
+				end = j
+				break
+			}
+		}
+		macro.lines = tokens[end].Line - macro.line + 1
+		macros = append(macros, macro)
+		i = end
+	}
+	return macros
+}
+
+// isRustOpenDelimiter reports whether a token opens a token tree
+func isRustOpenDelimiter(text string) bool {
+	return text == "(" || text == "[" || text == "{"
+}
+
+// isRustCloseDelimiter reports whether a token closes a token tree
+func isRustCloseDelimiter(text string) bool {
+	return text == ")" || text == "]" || text == "}"
+}
+
+// positiveOr returns value when it is positive, and fallback otherwise
+func positiveOr(value, fallback int) int {
+	if value > 0 {
+		return value
+	}
+	return fallback
+}
@@ -0,0 +1,73 @@
+package violations
+
+import (
+	"strings"
+	"testing"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+func TestRustMacroRules(t *testing.T) {
+	source := `macro_rules! hashmap {
+    () => { HashMap::new() };
+    ($($key:expr => $value:expr),* $(,)?) => {{
+        let mut map = HashMap::new();
+        $( map.insert($key, $value); )*
+        map
+    }};
+}
+
+fn main() { let m = hashmap!(1 => 2); }`
+
+	macros := rustMacroRules(rustTestTokens(source))
+	if len(macros) != 1 {
+		t.Fatalf("Expected 1 macro, got %+v", macros)
+	}
+	macro := macros[0]
+	if macro.name != "hashmap" || macro.line != 1 || macro.lines != 8 {
+		t.Errorf("Unexpected macro position %+v", macro)
+	}
+	if macro.arms != 2 {
+		t.Errorf("Expected 2 arms, got %d", macro.arms)
+	}
+	// {{ ... $( map.insert( ... ) )* }} nests three token trees inside the transcriber
+	if macro.nesting != 3 {
+		t.Errorf("Expected nesting 3, got %d", macro.nesting)
+	}
+}
+
+func TestRustMacroDetector(t *testing.T) {
+	var arms []string
+	for i := 0; i < 9; i++ {
+		arms = append(arms, "    (op) => { 1 };")
+	}
+	source := "macro_rules! ops {\n" + strings.Join(arms, "\n") + "\n}\n\n" +
+		"macro_rules! deep { ($x:expr) => { f(g(h(i(j($x))))) }; }\n\n" +
+		"macro_rules! small { () => {}; }"
+
+	config := DefaultDetectorConfig()
+	detector := NewRustMacroDetector(config)
+	astInfo := &types.RustASTInfo{Tokens: rustTestTokens(source)}
+	found := detector.Detect(&models.FileInfo{Path: "src/lib.rs"}, astInfo)
+
+	if len(found) != 2 {
+		t.Fatalf("Expected 2 violations, got %d: %+v", len(found), found)
+	}
+	if found[0].Rule != RustMacroArms || found[0].Line != 1 || found[0].Severity != models.SeverityMedium {
+		t.Errorf("Unexpected arms violation %+v", found[0])
+	}
+	if found[0].Message != "Macro 'ops' has 9 rule arms (max: 8)" {
+		t.Errorf("Unexpected message %q", found[0].Message)
+	}
+	if found[1].Rule != RustMacroNesting || found[1].Type != models.ViolationTypeRustMacroComplexity {
+		t.Errorf("Unexpected nesting violation %+v", found[1])
+	}
+
+	// Lower limits apply from the configuration
+	config.RustConfig.MaxMacroLines = 5
+	found = detector.Detect(&models.FileInfo{Path: "src/lib.rs"}, astInfo)
+	if len(found) != 3 || found[1].Rule != RustMacroLength || found[1].Severity != models.SeverityHigh {
+		t.Errorf("Expected an 11-line macro to be reported at high severity, got %+v", found)
+	}
+}
@@ -60,7 +60,7 @@ func (d *RustMagicNumberDetector) Name() string {
 
 // Description returns a description of what this detector checks for
 func (d *RustMagicNumberDetector) Description() string {
-	return "Detects hardcoded numeric literals in Rust code that should be named constants"
+	return "Detects hardcoded numeric literals, and repeated string literals, in Rust code that should be named constants"
 }
 
 // Detect analyzes the provided Rust file information and returns violations
@@ -84,8 +84,17 @@ func (d *RustMagicNumberDetector) Detect(fileInfo *models.FileInfo, astInfo inte
 		return violations
 	}
 
-	// Analyze numeric literals in the file content
-	violations = append(violations, d.analyzeNumericLiterals(content, fileInfo.Path)...)
+	// Analyze numeric literals in the file content, unless test files are allowed their numbers
+	if !d.config.MagicNumbers.allowsTestFile(fileInfo.Path) {
+		unitLiterals := rustUnitLiteralViolations(&d.config.UnitLiterals, rustAstInfo.Tokens, fileInfo.Path)
+		violations = append(violations, withoutUnitLiterals(d.analyzeNumericLiterals(content, fileInfo.Path), unitLiterals)...)
+		violations = append(violations, unitLiterals...)
+	}
+
+	if d.config.MaxStringRepeats > 0 {
+		literals := rustStringLiterals(rustAstInfo.Tokens)
+		violations = append(violations, magicStringViolations(literals, d.config.MaxStringRepeats, fileInfo.Path, "rust-magic-string")...)
+	}
 
 	return violations
 }
@@ -245,6 +254,15 @@ func (d *RustMagicNumberDetector) checkRustMagicNumber(match NumericMatch, lineN
 		return nil
 	}
 
+	// Check the configured allowlist. Indexing is always allowed in Rust.
+	allowlist := &d.config.MagicNumbers
+	if allowlist.allowsValue(value, filePath) {
+		return nil
+	}
+	if strings.ContainsAny(lineContent, "<>") && allowlist.allowsEpsilon(value) {
+		return nil
+	}
+
 	// Create violation
 	return &models.Violation{
 		Type:        models.ViolationTypeMagicNumber,
@@ -0,0 +1,78 @@
+package violations
+
+import (
+	"strings"
+
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+// rustInlineModule is a `mod name { ... }` block of a file, with the names
+// of the inline modules enclosing it
+type rustInlineModule struct {
+	path   []string // Names from the outermost inline module of the file
+	line   int
+	column int
+	leaf   bool // No inline module is nested in it
+	open   int  // Index of the opening brace
+	close  int  // Index of the closing brace, or the token count when unclosed
+}
+
+// rustInlineModules returns the inline modules of a file in source order.
+// Declarations of modules in their own file, as in `mod name;`, are skipped.
+func rustInlineModules(tokens []types.RustTokenInfo) []*rustInlineModule {
+	var modules, open []*rustInlineModule
+	var openDepths []int
+	depth := 0
+	for i := 0; i < len(tokens); i++ {
+		text := tokens[i].Text
+		switch {
+		case text == "mod" && i+2 < len(tokens) && tokens[i+1].Kind == types.RustTokenIdent && tokens[i+2].Text == "{":
+			var path []string
+			if n := len(open); n > 0 {
+				path = append(path, open[n-1].path...)
+				open[n-1].leaf = false
+			}
+			module := &rustInlineModule{
+				path:   append(path, tokens[i+1].Text),
+				line:   tokens[i].Line,
+				column: tokens[i].Column,
+				leaf:   true,
+				open:   i + 2,
+				close:  len(tokens),
+			}
+			modules = append(modules, module)
+			open = append(open, module)
+			openDepths = append(openDepths, depth)
+			depth++
+			i += 2
+		case isRustOpenDelimiter(text):
+			depth++
+		case isRustCloseDelimiter(text):
+			depth--
+			if n := len(open); n > 0 && openDepths[n-1] == depth {
+				open[n-1].close = i
+				open, openDepths = open[:n-1], openDepths[:n-1]
+			}
+		}
+	}
+	return modules
+}
+
+// rustModuleAt returns the path of the innermost inline module containing
+// the token at index i, or nil at the top level of the file
+func rustModuleAt(modules []*rustInlineModule, i int) []string {
+	var path []string
+	for _, module := range modules {
+		if module.open < i && i < module.close {
+			path = module.path
+		}
+	}
+	return path
+}
+
+// rustFileModuleSegments returns the module path of a file from its location
+// below src, without the crate: [net http] for src/net/http.rs
+func rustFileModuleSegments(path string) []string {
+	segments := strings.Split(rustModulePath(path), "::")
+	return segments[1:]
+}
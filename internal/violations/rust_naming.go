@@ -92,40 +92,70 @@ func (d *RustNamingDetector) Detect(fileInfo *models.FileInfo, astInfo interface
 		return violations
 	}
 
+	// Casing violations of items private to the file get rename fixes, unless
+	// modules in other files can see the items through super::
+	tokens := rustAstInfo.Tokens
+	renameItems := !hasOutOfLineModules(tokens)
+
 	// Check function names
 	for _, function := range rustAstInfo.Functions {
 		if function != nil {
-			violations = append(violations, d.checkFunctionNaming(function, fileInfo.Path)...)
-			// Check parameter names
-			violations = append(violations, d.checkParameterNaming(function, fileInfo.Path)...)
+			functionViolations := d.checkFunctionNaming(function, fileInfo.Path)
+			if renameItems && !function.IsPublic && !isRustMethod(function) {
+				addRenameFixes(functionViolations, tokens, function.Name, d.fixableRename(function.Name, d.conventionChecker.ToSnakeCase), 0, 0)
+			}
+			violations = append(violations, functionViolations...)
+
+			// Check parameter names; they are renamed within the function
+			parameterViolations := d.checkParameterNaming(function, fileInfo.Path)
+			for _, param := range function.Parameters {
+				addRenameFixes(parameterViolations, tokens, param.Name, d.fixableRename(param.Name, d.conventionChecker.ToSnakeCase), function.StartLine, function.EndLine)
+			}
+			violations = append(violations, parameterViolations...)
 		}
 	}
 
 	// Check struct names
 	for _, structInfo := range rustAstInfo.Structs {
 		if structInfo != nil {
-			violations = append(violations, d.checkStructNaming(structInfo, fileInfo.Path)...)
+			structViolations := d.checkStructNaming(structInfo, fileInfo.Path)
+			if renameItems && !structInfo.IsPublic {
+				addRenameFixes(structViolations, tokens, structInfo.Name, d.fixableRename(structInfo.Name, d.conventionChecker.ToPascalCase), 0, 0)
+			}
+			violations = append(violations, structViolations...)
 		}
 	}
 
 	// Check enum names
 	for _, enumInfo := range rustAstInfo.Enums {
 		if enumInfo != nil {
-			violations = append(violations, d.checkEnumNaming(enumInfo, fileInfo.Path)...)
+			enumViolations := d.checkEnumNaming(enumInfo, fileInfo.Path)
+			if renameItems && !enumInfo.IsPublic {
+				addRenameFixes(enumViolations, tokens, enumInfo.Name, d.fixableRename(enumInfo.Name, d.conventionChecker.ToPascalCase), 0, 0)
+			}
+			violations = append(violations, enumViolations...)
 		}
 	}
 
 	// Check trait names
 	for _, traitInfo := range rustAstInfo.Traits {
 		if traitInfo != nil {
-			violations = append(violations, d.checkTraitNaming(traitInfo, fileInfo.Path)...)
+			traitViolations := d.checkTraitNaming(traitInfo, fileInfo.Path)
+			if renameItems && !traitInfo.IsPublic {
+				addRenameFixes(traitViolations, tokens, traitInfo.Name, d.fixableRename(traitInfo.Name, d.conventionChecker.ToPascalCase), 0, 0)
+			}
+			violations = append(violations, traitViolations...)
 		}
 	}
 
 	// Check constant names
 	for _, constant := range rustAstInfo.Constants {
 		if constant != nil {
-			violations = append(violations, d.checkConstantNaming(constant, fileInfo.Path)...)
+			constantViolations := d.checkConstantNaming(constant, fileInfo.Path)
+			if renameItems && !constant.IsPublic {
+				addRenameFixes(constantViolations, tokens, constant.Name, d.fixableRename(constant.Name, d.conventionChecker.ToScreamingSnakeCase), 0, 0)
+			}
+			violations = append(violations, constantViolations...)
 		}
 	}
 
@@ -136,9 +166,58 @@ func (d *RustNamingDetector) Detect(fileInfo *models.FileInfo, astInfo interface
 		}
 	}
 
+	// Check statics and type aliases on the syntax tree
+	types.InspectRust(rustAstInfo.Syntax, func(node *types.RustNode) bool {
+		var nodeViolations []*models.Violation
+		var convert func(string) string
+		switch node.Kind {
+		case types.RustNodeStatic:
+			nodeViolations, convert = d.checkStaticNaming(node, fileInfo.Path), d.conventionChecker.ToScreamingSnakeCase
+		case types.RustNodeTypeAlias:
+			nodeViolations, convert = d.checkTypeAliasNaming(node, fileInfo.Path), d.conventionChecker.ToPascalCase
+		default:
+			return true
+		}
+		if renameItems && !isPublicRustNode(node) {
+			addRenameFixes(nodeViolations, tokens, node.Name, d.fixableRename(node.Name, convert), 0, 0)
+		}
+		violations = append(violations, nodeViolations...)
+		return true
+	})
+
 	return violations
 }
 
+// isRustMethod reports whether a function is declared in an impl or trait.
+// Its calls go through values and types the tokens cannot resolve, so it is
+// not renamed.
+func isRustMethod(function *types.RustFunctionInfo) bool {
+	if function.Node == nil {
+		return false
+	}
+	return function.Node.EnclosingItem(types.RustNodeImpl) != nil || function.Node.EnclosingItem(types.RustNodeTrait) != nil
+}
+
+// isPublicRustNode reports whether an item node is visible outside its module
+func isPublicRustNode(node *types.RustNode) bool {
+	for _, modifier := range node.Modifiers {
+		if strings.HasPrefix(modifier, "pub") {
+			return true
+		}
+	}
+	return false
+}
+
+// fixableRename returns the conventional form of name, or "" when renaming
+// would not help: the name is already conventional or becomes a keyword
+func (d *RustNamingDetector) fixableRename(name string, convert func(string) string) string {
+	newName := convert(name)
+	if newName == name || d.conventionChecker.isKeyword(newName) {
+		return ""
+	}
+	return newName
+}
+
 // checkFunctionNaming analyzes Rust function names for violations
 func (d *RustNamingDetector) checkFunctionNaming(fn *types.RustFunctionInfo, filePath string) []*models.Violation {
 	var violations []*models.Violation
@@ -168,7 +247,7 @@ func (d *RustNamingDetector) checkFunctionNaming(fn *types.RustFunctionInfo, fil
 			Line:        fn.StartLine,
 			Column:      fn.StartColumn,
 			Rule:        RustInvalidFunctionNaming,
-			Suggestion:  fmt.Sprintf("Rename to '%s'", d.conventionChecker.ToSnakeCase(fn.Name)),
+			Suggestion:  renameSuggestion(d.conventionChecker.ToSnakeCase(fn.Name)),
 			CodeSnippet: d.generateFunctionSnippet(fn),
 		})
 	}
@@ -226,7 +305,7 @@ func (d *RustNamingDetector) checkParameterNaming(fn *types.RustFunctionInfo, fi
 				Line:        fn.StartLine,
 				Column:      fn.StartColumn,
 				Rule:        RustInvalidParameterNaming,
-				Suggestion:  fmt.Sprintf("Rename to '%s'", d.conventionChecker.ToSnakeCase(param.Name)),
+				Suggestion:  renameSuggestion(d.conventionChecker.ToSnakeCase(param.Name)),
 				CodeSnippet: d.generateParameterSnippet(fn, param),
 			})
 		}
@@ -279,7 +358,7 @@ func (d *RustNamingDetector) checkStructNaming(structInfo *types.RustStructInfo,
 			Line:        structInfo.StartLine,
 			Column:      structInfo.StartColumn,
 			Rule:        RustInvalidStructNaming,
-			Suggestion:  fmt.Sprintf("Rename to '%s'", d.conventionChecker.ToPascalCase(structInfo.Name)),
+			Suggestion:  renameSuggestion(d.conventionChecker.ToPascalCase(structInfo.Name)),
 			CodeSnippet: fmt.Sprintf("struct %s", structInfo.Name),
 		})
 	}
@@ -331,7 +410,7 @@ func (d *RustNamingDetector) checkEnumNaming(enumInfo *types.RustEnumInfo, fileP
 			Line:        enumInfo.StartLine,
 			Column:      enumInfo.StartColumn,
 			Rule:        RustInvalidEnumNaming,
-			Suggestion:  fmt.Sprintf("Rename to '%s'", d.conventionChecker.ToPascalCase(enumInfo.Name)),
+			Suggestion:  renameSuggestion(d.conventionChecker.ToPascalCase(enumInfo.Name)),
 			CodeSnippet: fmt.Sprintf("enum %s", enumInfo.Name),
 		})
 	}
@@ -368,7 +447,7 @@ func (d *RustNamingDetector) checkTraitNaming(traitInfo *types.RustTraitInfo, fi
 			Line:        traitInfo.StartLine,
 			Column:      traitInfo.StartColumn,
 			Rule:        RustInvalidTraitNaming,
-			Suggestion:  fmt.Sprintf("Rename to '%s'", d.conventionChecker.ToPascalCase(traitInfo.Name)),
+			Suggestion:  renameSuggestion(d.conventionChecker.ToPascalCase(traitInfo.Name)),
 			CodeSnippet: fmt.Sprintf("trait %s", traitInfo.Name),
 		})
 	}
@@ -390,7 +469,7 @@ func (d *RustNamingDetector) checkConstantNaming(constant *types.RustConstantInf
 			Line:        constant.Line,
 			Column:      constant.Column,
 			Rule:        RustInvalidConstantNaming,
-			Suggestion:  fmt.Sprintf("Rename to '%s'", d.conventionChecker.ToScreamingSnakeCase(constant.Name)),
+			Suggestion:  renameSuggestion(d.conventionChecker.ToScreamingSnakeCase(constant.Name)),
 			CodeSnippet: fmt.Sprintf("const %s", constant.Name),
 		})
 	}
@@ -398,6 +477,47 @@ func (d *RustNamingDetector) checkConstantNaming(constant *types.RustConstantInf
 	return violations
 }
 
+// checkStaticNaming analyzes the name of a static item. Statics declared in
+// extern blocks or exported to C keep the name the foreign code uses.
+func (d *RustNamingDetector) checkStaticNaming(node *types.RustNode, filePath string) []*models.Violation {
+	if node.Name == "" || (node.Parent != nil && node.Parent.Kind == types.RustNodeExternBlock) ||
+		node.HasAttribute("no_mangle") || node.HasAttribute("unsafe(no_mangle)") || node.HasAttribute("export_name") {
+		return nil
+	}
+	if d.conventionChecker.IsValidStaticName(node.Name) {
+		return nil
+	}
+	return []*models.Violation{{
+		Type:        models.ViolationTypeNaming,
+		Severity:    models.SeverityMedium,
+		Message:     fmt.Sprintf("Static '%s' does not follow Rust naming conventions (should be SCREAMING_SNAKE_CASE)", node.Name),
+		File:        filePath,
+		Line:        node.StartLine,
+		Column:      node.StartColumn,
+		Rule:        RustInvalidStaticNaming,
+		Suggestion:  renameSuggestion(d.conventionChecker.ToScreamingSnakeCase(node.Name)),
+		CodeSnippet: fmt.Sprintf("static %s", node.Name),
+	}}
+}
+
+// checkTypeAliasNaming analyzes the name of a type alias or associated type
+func (d *RustNamingDetector) checkTypeAliasNaming(node *types.RustNode, filePath string) []*models.Violation {
+	if node.Name == "" || d.conventionChecker.IsValidTypeName(node.Name) {
+		return nil
+	}
+	return []*models.Violation{{
+		Type:        models.ViolationTypeNaming,
+		Severity:    models.SeverityMedium,
+		Message:     fmt.Sprintf("Type alias '%s' does not follow Rust naming conventions (should be PascalCase)", node.Name),
+		File:        filePath,
+		Line:        node.StartLine,
+		Column:      node.StartColumn,
+		Rule:        RustInvalidTypeAlias,
+		Suggestion:  renameSuggestion(d.conventionChecker.ToPascalCase(node.Name)),
+		CodeSnippet: fmt.Sprintf("type %s", node.Name),
+	}}
+}
+
 // checkModuleNaming analyzes Rust module names for violations
 func (d *RustNamingDetector) checkModuleNaming(module *types.RustModuleInfo, filePath string) []*models.Violation {
 	var violations []*models.Violation
@@ -412,7 +532,7 @@ func (d *RustNamingDetector) checkModuleNaming(module *types.RustModuleInfo, fil
 			Line:        module.StartLine,
 			Column:      module.StartColumn,
 			Rule:        RustInvalidModuleNaming,
-			Suggestion:  fmt.Sprintf("Rename to '%s'", d.conventionChecker.ToSnakeCase(module.Name)),
+			Suggestion:  renameSuggestion(d.conventionChecker.ToSnakeCase(module.Name)),
 			CodeSnippet: fmt.Sprintf("mod %s", module.Name),
 		})
 	}
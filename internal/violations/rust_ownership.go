@@ -6,12 +6,50 @@ import (
 	"io"
 	"os"
 	"regexp"
+	"sort"
 	"strings"
 
 	"github.com/ericfisherdev/goclean/internal/models"
 	"github.com/ericfisherdev/goclean/internal/types"
 )
 
+// RustMutableStaticAccess is the rule reporting each use of a static mut
+const RustMutableStaticAccess = "rust-mutable-static-access"
+
+// RustUnnecessaryOwnedParameter is the rule reporting String and Vec
+// parameters taken by value that are only read
+const RustUnnecessaryOwnedParameter = "rust-unnecessary-owned-parameter"
+
+// rustReadOnlyMethods are String, Vec and slice methods that only read their receiver
+var rustReadOnlyMethods = map[string]bool{
+	"len": true, "is_empty": true, "capacity": true, "contains": true, "starts_with": true, "ends_with": true,
+	"get": true, "first": true, "last": true, "iter": true, "chunks": true, "windows": true, "binary_search": true,
+	"as_str": true, "as_bytes": true, "as_slice": true, "chars": true, "char_indices": true, "bytes": true,
+	"find": true, "rfind": true, "split": true, "split_whitespace": true, "lines": true,
+	"trim": true, "trim_start": true, "trim_end": true, "parse": true, "eq": true,
+	"to_uppercase": true, "to_lowercase": true,
+}
+
+// RustUnnecessaryMutReference is the rule reporting &mut parameters that are never written through
+const RustUnnecessaryMutReference = "rust-unnecessary-mut-reference"
+
+// RustNeedlessLifetime is the rule reporting lifetime parameters that elision would infer
+const RustNeedlessLifetime = "rust-needless-lifetime"
+
+// RustLifetimeBoundChain is the rule reporting long chains of outlives bounds
+const RustLifetimeBoundChain = "rust-lifetime-bound-chain"
+
+// maxRustLifetimeChain is the longest chain of outlives bounds, counted in
+// bounds, that is not reported: 'c: 'b, 'b: 'a is fine, a third link is not
+const maxRustLifetimeChain = 2
+
+// RustUnnecessarySmartPointer is the rule reporting Box, Rc and Arc
+// allocations that nothing needs
+const RustUnnecessarySmartPointer = "rust-unnecessary-smart-pointer"
+
+// RustStringConcatInLoop is the rule reporting strings built by concatenation in a loop
+const RustStringConcatInLoop = "rust-string-concat-in-loop"
+
 // RustOwnershipDetector detects ownership and borrowing violations in Rust code
 type RustOwnershipDetector struct {
 	config        *DetectorConfig
@@ -36,7 +74,7 @@ func (d *RustOwnershipDetector) Name() string {
 
 // Description returns a description of what this detector checks for
 func (d *RustOwnershipDetector) Description() string {
-	return "Detects ownership and borrowing violations in Rust code including unnecessary clones, inefficient borrowing patterns, and complex lifetime annotations"
+	return "Detects ownership and borrowing violations in Rust code including unnecessary clones, inefficient borrowing patterns, complex lifetime annotations, mutable statics and, when enabled, heap allocations"
 }
 
 // Detect analyzes Rust code for ownership violations
@@ -61,19 +99,43 @@ func (d *RustOwnershipDetector) Detect(fileInfo *models.FileInfo, astInfo interf
 	}
 
 	lines := strings.Split(content, "\n")
+	inference := newRustTypeInference(rustAstInfo)
 
-	// Analyze content for ownership violations
-	violations = append(violations, d.detectUnnecessaryClones(fileInfo.Path, lines)...)
+	// Analyze content for ownership violations. With a compiling project under
+	// cargo check mode, clones are only reported where the types are known.
+	if check := cargoCheckFor(d.config, fileInfo.Path); check != nil {
+		violations = append(violations, d.detectCopyClones(fileInfo.Path, lines, inference)...)
+		violations = append(violations, d.noopClonesFromRustc(fileInfo.Path, check)...)
+	} else {
+		violations = append(violations, d.detectUnnecessaryClones(fileInfo.Path, lines, inference)...)
+	}
+	violations = append(violations, d.detectCopyPassedByReference(fileInfo.Path, rustAstInfo)...)
+	violations = append(violations, d.detectOwnedParameters(fileInfo.Path, rustAstInfo)...)
+	violations = append(violations, d.detectUnusedMutableReferences(fileInfo.Path, rustAstInfo)...)
 	violations = append(violations, d.detectInefficientBorrowing(fileInfo.Path, lines)...)
 	violations = append(violations, d.detectComplexLifetimes(fileInfo.Path, lines)...)
+	violations = append(violations, d.detectLifetimeAnnotations(fileInfo.Path, rustAstInfo)...)
 	violations = append(violations, d.detectMoveSemanticsViolations(fileInfo.Path, lines)...)
 	violations = append(violations, d.detectBorrowCheckerBypass(fileInfo.Path, lines)...)
+	rustConfig := d.config.RustConfig
+	if rustConfig == nil || rustConfig.DetectInefficientString {
+		violations = append(violations, d.detectStringConcatInLoops(fileInfo.Path, rustAstInfo, inference)...)
+	}
+	violations = append(violations, d.detectUnnecessarySmartPointers(fileInfo.Path, rustAstInfo, inference)...)
+	if rustConfig == nil || !rustConfig.AllowMutableStatics {
+		violations = append(violations, d.detectMutableStatics(fileInfo.Path, lines, rustAstInfo)...)
+	}
+	if rustConfig != nil && rustConfig.DetectHeapAllocations {
+		violations = append(violations, d.detectHeapAllocations(fileInfo.Path, lines)...)
+	}
 
 	return violations
 }
 
-// detectUnnecessaryClones identifies unnecessary use of .clone()
-func (d *RustOwnershipDetector) detectUnnecessaryClones(filePath string, lines []string) []*models.Violation {
+// detectUnnecessaryClones identifies unnecessary use of .clone(). Clones of
+// values whose inferred type is Copy are always reported; clones of Rc and
+// Arc are kept, as they share the value rather than copy it.
+func (d *RustOwnershipDetector) detectUnnecessaryClones(filePath string, lines []string, inference *rustTypeInference) []*models.Violation {
 	var violations []*models.Violation
 
 	// Pattern to find all clone usages
@@ -89,6 +151,15 @@ func (d *RustOwnershipDetector) detectUnnecessaryClones(filePath string, lines [
 
 		// Find all clone usages in the line
 		if clonePattern.MatchString(trimmedLine) {
+			receiver, receiverType := d.cloneReceiver(inference, lineNum+1)
+			if target := rustDerefType(receiverType); receiverType != "" && inference.isCopyType(target) {
+				violations = append(violations, d.cloneOnCopyViolation(filePath, line, lineNum+1, receiver, receiverType))
+				continue
+			}
+			if base := rustBaseType(receiverType); base == "Rc" || base == "Arc" {
+				continue
+			}
+
 			// Analyze if this clone is likely unnecessary
 			if d.isLikelyUnnecessaryClone(trimmedLine) {
 				context := d.getCloneContext(trimmedLine)
@@ -111,6 +182,378 @@ func (d *RustOwnershipDetector) detectUnnecessaryClones(filePath string, lines [
 	return violations
 }
 
+// detectCopyClones reports only the clones whose receiver is inferred to be
+// of a Copy type, leaving out the textual heuristics
+func (d *RustOwnershipDetector) detectCopyClones(filePath string, lines []string, inference *rustTypeInference) []*models.Violation {
+	var violations []*models.Violation
+
+	for lineNum, line := range lines {
+		if !strings.Contains(line, ".clone()") || strings.HasPrefix(strings.TrimSpace(line), "//") {
+			continue
+		}
+		receiver, receiverType := d.cloneReceiver(inference, lineNum+1)
+		if receiverType != "" && inference.isCopyType(rustDerefType(receiverType)) {
+			violations = append(violations, d.cloneOnCopyViolation(filePath, line, lineNum+1, receiver, receiverType))
+		}
+	}
+
+	return violations
+}
+
+// noopClonesFromRustc reports the clones rustc found to do nothing with its
+// noop_method_call lint, such as cloning a reference to a type that is not Clone
+func (d *RustOwnershipDetector) noopClonesFromRustc(filePath string, check *cargoCheckResult) []*models.Violation {
+	var violations []*models.Violation
+
+	for _, diagnostic := range check.forFile(filePath, "noop_method_call") {
+		codeSnippet := d.extractCodeSnippet(filePath, diagnostic.line, diagnostic.line)
+		violations = append(violations, &models.Violation{
+			Type:        models.ViolationTypeRustUnnecessaryClone,
+			Severity:    models.SeverityLow,
+			Message:     fmt.Sprintf("rustc: %s", diagnostic.message),
+			File:        filePath,
+			Line:        diagnostic.line,
+			Column:      diagnostic.column,
+			Rule:        "rust-unnecessary-clone",
+			Suggestion:  "Remove the call, or derive Clone on the referenced type if a copy was intended",
+			CodeSnippet: codeSnippet,
+		})
+	}
+
+	return violations
+}
+
+// cloneReceiver returns the variable cloned on a line and its inferred type
+func (d *RustOwnershipDetector) cloneReceiver(inference *rustTypeInference, line int) (string, string) {
+	tokens := inference.tokens
+	for i := sort.Search(len(tokens), func(i int) bool { return tokens[i].Line >= line }); i+3 < len(tokens) && tokens[i].Line == line; i++ {
+		if tokens[i].Kind != types.RustTokenIdent || tokens[i+1].Text != "." || tokens[i+2].Text != "clone" || tokens[i+3].Text != "(" {
+			continue
+		}
+		if i > 0 && tokens[i-1].Text == "." {
+			continue // Field of another value
+		}
+		return tokens[i].Text, inference.typeOf(tokens[i].Text, line)
+	}
+	return "", ""
+}
+
+// cloneOnCopyViolation reports a clone of a value whose type is Copy
+func (d *RustOwnershipDetector) cloneOnCopyViolation(filePath, line string, lineNum int, receiver, receiverType string) *models.Violation {
+	replacement := receiver
+	if strings.HasPrefix(receiverType, "&") {
+		replacement = "*" + receiver
+	}
+	return &models.Violation{
+		Type:        models.ViolationTypeRustUnnecessaryClone,
+		Severity:    models.SeverityLow,
+		Message:     fmt.Sprintf("clone() on '%s' of Copy type %s - the value is copied implicitly", receiver, rustDerefType(receiverType)),
+		File:        filePath,
+		Line:        lineNum,
+		Column:      strings.Index(line, ".clone()") + 1,
+		Rule:        "rust-unnecessary-clone",
+		Suggestion:  fmt.Sprintf("Use '%s' instead of '%s.clone()'", replacement, receiver),
+		CodeSnippet: d.extractCodeSnippet(filePath, lineNum, lineNum),
+	}
+}
+
+// detectCopyPassedByReference identifies parameters borrowing scalar Copy
+// types such as &u32, which are as cheap to pass by value. Trait methods are
+// skipped because the trait fixes their signature, and so are references
+// with a named lifetime, which may tie the parameter to the return value.
+func (d *RustOwnershipDetector) detectCopyPassedByReference(filePath string, info *types.RustASTInfo) []*models.Violation {
+	var violations []*models.Violation
+
+	for _, fn := range info.Functions {
+		if d.inTraitItem(info, fn.StartLine) {
+			continue
+		}
+		for _, param := range fn.Parameters {
+			if !strings.HasPrefix(param.Type, "&") || strings.HasPrefix(param.Type, "&mut") || strings.HasPrefix(param.Type, "&'") {
+				continue
+			}
+			target := rustDerefType(param.Type)
+			if !rustCopyPrimitives[target] {
+				continue
+			}
+			violations = append(violations, &models.Violation{
+				Type:        models.ViolationTypeRustInefficientBorrowing,
+				Severity:    models.SeverityLow,
+				Message:     fmt.Sprintf("Parameter '%s' of '%s' borrows Copy type %s - pass it by value", param.Name, fn.Name, target),
+				File:        filePath,
+				Line:        fn.StartLine,
+				Column:      fn.StartColumn,
+				Rule:        "rust-pass-by-value",
+				Suggestion:  fmt.Sprintf("Take '%s: %s' by value; copying a %s costs no more than passing a reference to it", param.Name, target, target),
+				CodeSnippet: d.extractCodeSnippet(filePath, fn.StartLine, fn.StartLine),
+			})
+		}
+	}
+
+	return violations
+}
+
+// detectOwnedParameters identifies String and Vec parameters taken by value
+// whose function only calls read-only methods on them, borrows them or
+// formats them, so a &str or a slice would do. Trait methods are skipped,
+// as are mut parameters, which the function may modify.
+func (d *RustOwnershipDetector) detectOwnedParameters(filePath string, info *types.RustASTInfo) []*models.Violation {
+	var violations []*models.Violation
+
+	tokens := info.Tokens
+	for _, fn := range info.Functions {
+		if d.inTraitItem(info, fn.StartLine) {
+			continue
+		}
+		open, closing, body, end := rustFunctionSpan(tokens, fn)
+		if body < 0 {
+			continue
+		}
+
+		for _, param := range fn.Parameters {
+			borrowed := ""
+			switch rustBaseType(param.Type) {
+			case "String":
+				borrowed = "&str"
+			case "Vec":
+				if args := rustTypeArgs(param.Type); len(args) == 1 {
+					borrowed = "&[" + args[0] + "]"
+				}
+			}
+			if borrowed == "" || param.IsMutable || strings.HasPrefix(param.Type, "&") || !rustOnlyReads(tokens[body+1:end], param.Name) {
+				continue
+			}
+
+			line, column := fn.StartLine, fn.StartColumn
+			for k := open + 1; k+1 < closing; k++ {
+				if tokens[k].Text == param.Name && tokens[k+1].Text == ":" {
+					line, column = tokens[k].Line, tokens[k].Column
+					break
+				}
+			}
+			violations = append(violations, &models.Violation{
+				Type:        models.ViolationTypeRustInefficientBorrowing,
+				Severity:    models.SeverityLow,
+				Message:     fmt.Sprintf("Parameter '%s' of '%s' takes %s by value but is only read", param.Name, fn.Name, param.Type),
+				File:        filePath,
+				Line:        line,
+				Column:      column,
+				Rule:        RustUnnecessaryOwnedParameter,
+				Suggestion:  fmt.Sprintf("Take '%s: %s' so callers keep ownership and need not clone or allocate", param.Name, borrowed),
+				CodeSnippet: d.extractCodeSnippet(filePath, line, line),
+			})
+		}
+	}
+
+	return violations
+}
+
+// detectUnusedMutableReferences identifies &mut parameters, including &mut
+// self, that the function never writes through: it only reads fields, calls
+// read-only methods or formats them. Trait methods are skipped, as the trait
+// fixes their signature. Callers can pass a &mut where a & is expected, so
+// private functions get a fix removing the mut.
+func (d *RustOwnershipDetector) detectUnusedMutableReferences(filePath string, info *types.RustASTInfo) []*models.Violation {
+	var violations []*models.Violation
+
+	tokens := info.Tokens
+	for _, fn := range info.Functions {
+		if d.inTraitItem(info, fn.StartLine) {
+			continue
+		}
+		open, closing, body, end := rustFunctionSpan(tokens, fn)
+		if body < 0 {
+			continue
+		}
+
+		for k := open + 2; k+1 < closing; k++ {
+			if tokens[k].Text != "mut" {
+				continue
+			}
+			amp := k - 1
+			if tokens[amp].Kind == types.RustTokenLifetime {
+				amp--
+			}
+			if tokens[amp].Text != "&" {
+				continue
+			}
+			var name *types.RustTokenInfo
+			switch {
+			case tokens[k+1].Text == "self":
+				name = &tokens[k+1]
+			case tokens[amp-1].Text == ":" && amp > open+1 && tokens[amp-2].Kind == types.RustTokenIdent:
+				name = &tokens[amp-2]
+			default:
+				continue
+			}
+			if !rustOnlyReadsThrough(tokens[body+1:end], name.Text) {
+				continue
+			}
+
+			violation := &models.Violation{
+				Type:        models.ViolationTypeRustInefficientBorrowing,
+				Severity:    models.SeverityLow,
+				Message:     fmt.Sprintf("Parameter '%s' of '%s' is borrowed mutably but never written through", name.Text, fn.Name),
+				File:        filePath,
+				Line:        name.Line,
+				Column:      name.Column,
+				Rule:        RustUnnecessaryMutReference,
+				Suggestion:  fmt.Sprintf("Take '%s' by shared reference, without mut, so callers can lend it while it is borrowed elsewhere", name.Text),
+				CodeSnippet: d.extractCodeSnippet(filePath, name.Line, name.Line),
+			}
+			if !fn.IsPublic {
+				violation.Fix = &models.Fix{
+					Description: fmt.Sprintf("Borrow '%s' immutably", name.Text),
+					Edits: []models.TextEdit{{
+						Line:      tokens[k].Line,
+						Column:    tokens[k].Column,
+						EndLine:   tokens[k+1].Line,
+						EndColumn: tokens[k+1].Column,
+					}},
+				}
+			}
+			violations = append(violations, violation)
+		}
+	}
+
+	return violations
+}
+
+// rustFunctionSpan returns the token indexes of the opening and closing
+// parentheses of a function's parameter list, of the opening brace of its
+// body and of the first token after it. body is -1 for a function without one.
+func rustFunctionSpan(tokens []types.RustTokenInfo, fn *types.RustFunctionInfo) (open, closing, body, end int) {
+	open = rustTokenIndex(tokens, fn.StartLine, fn.StartColumn)
+	for open+1 < len(tokens) && (tokens[open].Text != "fn" || tokens[open+1].Text != fn.Name) {
+		open++
+	}
+	for depth := 0; open < len(tokens) && (depth > 0 || tokens[open].Text != "("); open++ {
+		switch tokens[open].Text {
+		case "<":
+			depth++
+		case ">":
+			depth--
+		}
+	}
+	closing = rustClosingParen(tokens, open)
+	body = closing
+	for body < len(tokens) && tokens[body].Text != "{" && tokens[body].Text != ";" {
+		body++
+	}
+	end = rustTokenIndex(tokens, fn.EndLine+1, 0)
+	if body >= end || tokens[body].Text != "{" {
+		body = -1
+	}
+	return open, closing, body, end
+}
+
+// rustAssignOperators assign to the place on their left
+var rustAssignOperators = map[string]bool{
+	"=": true, "+=": true, "-=": true, "*=": true, "/=": true, "%=": true,
+	"^=": true, "&=": true, "|=": true, "<<=": true, ">>=": true,
+}
+
+// rustOnlyReadsThrough reports whether a reference binding is used in the
+// tokens of a function body, and only to read: its fields and indexes are
+// never assigned to or borrowed mutably, it only calls read-only methods, and
+// the reference itself is not passed on, rebound, returned or cast
+func rustOnlyReadsThrough(tokens []types.RustTokenInfo, name string) bool {
+	used := false
+	for j, token := range tokens {
+		if token.Text != name || j > 0 && (tokens[j-1].Text == "." || tokens[j-1].Text == "::") {
+			continue
+		}
+		used = true
+
+		prev := ""
+		if j > 0 {
+			prev = tokens[j-1].Text
+		}
+		if prev == "mut" {
+			return false
+		}
+
+		// Follow the fields and indexes after the name up to the first method
+		// call, which borrows the value for a read-only method and returns
+		// something else
+		k := j + 1
+		for k+1 < len(tokens) {
+			if tokens[k].Text == "[" {
+				k = rustGroupEnd(tokens, k)
+				continue
+			}
+			if tokens[k].Text != "." {
+				break
+			}
+			if tokens[k+1].Kind == types.RustTokenLiteral || k+2 >= len(tokens) || tokens[k+2].Text != "(" {
+				k += 2 // Field or tuple index
+				continue
+			}
+			if !rustReadOnlyMethods[tokens[k+1].Text] {
+				return false
+			}
+			k = rustClosingParen(tokens, k+2) + 1
+			break
+		}
+
+		next := ""
+		if k < len(tokens) {
+			next = tokens[k].Text
+		}
+		switch {
+		case k == j+1 && next == "as" || rustAssignOperators[next] && tokens[k-1].Text != ")":
+			return false
+		case k == j+1 && prev != "*" && prev != "&" && !rustInMacroArgs(tokens, j) && next != "==" && next != "!=" && prev != "==" && prev != "!=":
+			return false // The reference itself is passed on or returned
+		}
+	}
+	return used
+}
+
+// rustOnlyReads reports whether a binding is used in the tokens of a function
+// body, and only by read-only method calls, indexing, comparisons, borrows
+// and macro arguments. Any other use may move it.
+func rustOnlyReads(tokens []types.RustTokenInfo, name string) bool {
+	used := false
+	for j, token := range tokens {
+		if token.Text != name || token.Kind != types.RustTokenIdent || j > 0 && (tokens[j-1].Text == "." || tokens[j-1].Text == "::") {
+			continue
+		}
+		used = true
+
+		prev, next := "", ""
+		if j > 0 {
+			prev = tokens[j-1].Text
+		}
+		if j+1 < len(tokens) {
+			next = tokens[j+1].Text
+		}
+		switch {
+		case next == "." && j+3 < len(tokens) && rustReadOnlyMethods[tokens[j+2].Text] && tokens[j+3].Text == "(":
+		case prev == "&" || next == "[" || next == "==" || next == "!=" || prev == "==" || prev == "!=":
+		case (prev == "(" || prev == ",") && (next == ")" || next == ",") && rustInMacroArgs(tokens, j):
+		default:
+			return false
+		}
+	}
+	return used
+}
+
+// inTraitItem reports whether a line is inside a trait definition or a trait impl
+func (d *RustOwnershipDetector) inTraitItem(info *types.RustASTInfo, line int) bool {
+	for _, impl := range info.Impls {
+		if impl.TraitName != "" && line >= impl.StartLine && line <= impl.EndLine {
+			return true
+		}
+	}
+	for _, trait := range info.Traits {
+		if line >= trait.StartLine && line <= trait.EndLine {
+			return true
+		}
+	}
+	return false
+}
+
 // detectInefficientBorrowing identifies inefficient borrowing patterns
 func (d *RustOwnershipDetector) detectInefficientBorrowing(filePath string, lines []string) []*models.Violation {
 	var violations []*models.Violation
@@ -220,6 +663,175 @@ func (d *RustOwnershipDetector) detectComplexLifetimes(filePath string, lines []
 	return violations
 }
 
+// detectLifetimeAnnotations identifies, from the tokens of each function's
+// signature, a single lifetime parameter that elision would infer, and
+// outlives bounds in its generics and where clause that chain more than
+// maxRustLifetimeChain lifetimes together
+func (d *RustOwnershipDetector) detectLifetimeAnnotations(filePath string, info *types.RustASTInfo) []*models.Violation {
+	var violations []*models.Violation
+
+	tokens := info.Tokens
+	for _, fn := range info.Functions {
+		if d.inTraitItem(info, fn.StartLine) {
+			continue
+		}
+		open, closing, body, end := rustFunctionSpan(tokens, fn)
+		if body < 0 {
+			continue
+		}
+		start := open - 1
+		for start > 0 && (tokens[start].Text != fn.Name || tokens[start-1].Text != "fn") {
+			start--
+		}
+		generics := tokens[start+1 : open]
+		var clause []types.RustTokenInfo
+		for k := closing + 1; k < body; k++ {
+			if tokens[k].Text == "where" {
+				clause = tokens[k+1 : body]
+				break
+			}
+		}
+
+		if lifetime := rustNeedlessLifetime(tokens, generics, open, closing, end); lifetime != nil {
+			violations = append(violations, &models.Violation{
+				Type:        models.ViolationTypeRustComplexLifetime,
+				Severity:    models.SeverityLow,
+				Message:     fmt.Sprintf("Lifetime %s of '%s' can be elided", lifetime.Text, fn.Name),
+				File:        filePath,
+				Line:        lifetime.Line,
+				Column:      lifetime.Column,
+				Rule:        RustNeedlessLifetime,
+				Suggestion:  fmt.Sprintf("Remove %s from the generics and the signature; with a single borrowed input the compiler infers the same lifetime", lifetime.Text),
+				CodeSnippet: d.extractCodeSnippet(filePath, lifetime.Line, lifetime.Line),
+			})
+		}
+
+		bounds := make(map[string][]string)
+		first := make(map[string]types.RustTokenInfo)
+		for _, part := range [][]types.RustTokenInfo{generics, clause} {
+			for k := 0; k+2 < len(part); k++ {
+				if part[k].Kind != types.RustTokenLifetime || part[k+1].Text != ":" {
+					continue
+				}
+				if _, seen := first[part[k].Text]; !seen {
+					first[part[k].Text] = part[k]
+				}
+				for b := k + 2; b < len(part) && part[b].Kind == types.RustTokenLifetime; b += 2 {
+					bounds[part[k].Text] = append(bounds[part[k].Text], part[b].Text)
+					if b+1 >= len(part) || part[b+1].Text != "+" {
+						break
+					}
+				}
+			}
+		}
+		var chain []string
+		for lifetime := range bounds {
+			longest := rustLongestBoundChain(bounds, lifetime, make(map[string]bool))
+			if len(longest) > len(chain) || len(longest) == len(chain) && rustTokenBefore(first[longest[0]], first[chain[0]]) {
+				chain = longest
+			}
+		}
+		if len(chain)-1 <= maxRustLifetimeChain {
+			continue
+		}
+		head := first[chain[0]]
+		violations = append(violations, &models.Violation{
+			Type:        models.ViolationTypeRustComplexLifetime,
+			Severity:    models.SeverityMedium,
+			Message:     fmt.Sprintf("Lifetime bounds of '%s' chain %d lifetimes: %s", fn.Name, len(chain), strings.Join(chain, ": ")),
+			File:        filePath,
+			Line:        head.Line,
+			Column:      head.Column,
+			Rule:        RustLifetimeBoundChain,
+			Suggestion:  "Give references that live equally long the same lifetime, or restructure so fewer lifetimes have to outlive each other",
+			CodeSnippet: d.extractCodeSnippet(filePath, head.Line, head.Line),
+		})
+	}
+
+	return violations
+}
+
+// rustNeedlessLifetime returns the declaration of a function's only lifetime
+// parameter when it annotates its only borrowed parameter and, optionally,
+// its return type, and appears nowhere else. Elision then infers the same
+// signature without it.
+func rustNeedlessLifetime(tokens, generics []types.RustTokenInfo, open, closing, end int) *types.RustTokenInfo {
+	var declared *types.RustTokenInfo
+	for k := range generics {
+		if generics[k].Kind != types.RustTokenLifetime || k == 0 || generics[k-1].Text != "<" && generics[k-1].Text != "," {
+			continue
+		}
+		if declared != nil {
+			return nil
+		}
+		declared = &generics[k]
+	}
+	if declared == nil {
+		return nil
+	}
+
+	borrowed, uses := 0, 0
+	for k := open + 1; k < closing; k++ {
+		switch {
+		case tokens[k].Text == "&" || tokens[k].Kind == types.RustTokenLifetime && tokens[k-1].Text != "&":
+			borrowed++
+		case tokens[k].Text == "impl" || tokens[k].Text == "dyn":
+			return nil
+		}
+		if tokens[k].Text == declared.Text {
+			uses++
+		}
+	}
+	if borrowed != 1 || uses != 1 {
+		return nil
+	}
+
+	// Besides its declaration, the lifetime may only annotate the return type
+	declarations := 0
+	for _, token := range generics {
+		if token.Text == declared.Text {
+			declarations++
+		}
+	}
+	if declarations != 1 {
+		return nil
+	}
+	afterReturn := false
+	for k := closing + 1; k < end; k++ {
+		switch {
+		case tokens[k].Text == "where" || tokens[k].Text == "{":
+			afterReturn = true
+		case tokens[k].Text == declared.Text && afterReturn:
+			return nil
+		}
+	}
+	return declared
+}
+
+// rustTokenBefore reports whether a token comes before another in the source
+func rustTokenBefore(a, b types.RustTokenInfo) bool {
+	return a.Line < b.Line || a.Line == b.Line && a.Column < b.Column
+}
+
+// rustLongestBoundChain returns the longest chain of lifetimes from a
+// lifetime through the lifetimes it must outlive, e.g. ['c 'b 'a] for
+// 'c: 'b and 'b: 'a. Cycles are not followed.
+func rustLongestBoundChain(bounds map[string][]string, lifetime string, visiting map[string]bool) []string {
+	visiting[lifetime] = true
+	defer delete(visiting, lifetime)
+
+	var longest []string
+	for _, outlived := range bounds[lifetime] {
+		if visiting[outlived] {
+			continue
+		}
+		if chain := rustLongestBoundChain(bounds, outlived, visiting); len(chain) > len(longest) {
+			longest = chain
+		}
+	}
+	return append([]string{lifetime}, longest...)
+}
+
 // detectMoveSemanticsViolations identifies improper use of move semantics
 func (d *RustOwnershipDetector) detectMoveSemanticsViolations(filePath string, lines []string) []*models.Violation {
 	var violations []*models.Violation
@@ -320,6 +932,349 @@ func (d *RustOwnershipDetector) detectBorrowCheckerBypass(filePath string, lines
 	return violations
 }
 
+// detectMutableStatics identifies `static mut` items, which are shared global
+// state that every access must reach through unsafe code, and the places of
+// the file that access them
+func (d *RustOwnershipDetector) detectMutableStatics(filePath string, lines []string, rustAstInfo *types.RustASTInfo) []*models.Violation {
+	var violations []*models.Violation
+
+	staticMutPattern := regexp.MustCompile(`^(pub(\([^)]*\))?\s+)?static\s+mut\s+(\w+)\s*(:\s*([^=;]+))?`)
+	statics := make(map[string]string)
+
+	for lineNum, line := range lines {
+		match := staticMutPattern.FindStringSubmatch(strings.TrimSpace(line))
+		if match == nil {
+			continue
+		}
+		statics[match[3]] = strings.TrimSpace(match[5])
+
+		codeSnippet := d.extractCodeSnippet(filePath, lineNum+1, lineNum+1)
+		violations = append(violations, &models.Violation{
+			Type:        models.ViolationTypeRustRaceCondition,
+			Severity:    d.mutableStaticSeverity(models.SeverityHigh),
+			Message:     fmt.Sprintf("Mutable static '%s' is unsynchronized global state", match[3]),
+			File:        filePath,
+			Line:        lineNum + 1,
+			Column:      strings.Index(line, "static") + 1,
+			Rule:        "rust-mutable-static",
+			Suggestion:  mutableStaticSuggestion(statics[match[3]]),
+			CodeSnippet: codeSnippet,
+		})
+	}
+
+	return append(violations, d.detectMutableStaticAccesses(filePath, statics, rustAstInfo)...)
+}
+
+// detectMutableStaticAccesses reports every use of the file's `static mut`
+// items outside their declaration. Field names and other paths ending in the
+// same name are not accesses.
+func (d *RustOwnershipDetector) detectMutableStaticAccesses(filePath string, statics map[string]string, rustAstInfo *types.RustASTInfo) []*models.Violation {
+	var violations []*models.Violation
+	if len(statics) == 0 {
+		return violations
+	}
+
+	tokens := rustAstInfo.Tokens
+	for i, token := range tokens {
+		staticType, ok := statics[token.Text]
+		if !ok || token.Kind != types.RustTokenIdent {
+			continue
+		}
+		if i > 0 && tokens[i-1].Text == "." || i > 1 && tokens[i-1].Text == "mut" && tokens[i-2].Text == "static" {
+			continue
+		}
+
+		message := fmt.Sprintf("Mutable static '%s' is accessed outside any function", token.Text)
+		if fn := innermostRustFunction(rustAstInfo.Functions, token.Line); fn != nil {
+			message = fmt.Sprintf("Mutable static '%s' is accessed in '%s'", token.Text, fn.Name)
+		}
+		violations = append(violations, &models.Violation{
+			Type:        models.ViolationTypeRustRaceCondition,
+			Severity:    d.mutableStaticSeverity(models.SeverityMedium),
+			Message:     message,
+			File:        filePath,
+			Line:        token.Line,
+			Column:      token.Column,
+			Rule:        RustMutableStaticAccess,
+			Suggestion:  mutableStaticSuggestion(staticType),
+			CodeSnippet: d.extractCodeSnippet(filePath, token.Line, token.Line),
+		})
+	}
+
+	return violations
+}
+
+// mutableStaticSeverity lowers the severity of mutable static violations to
+// low in crates that share them with foreign code
+func (d *RustOwnershipDetector) mutableStaticSeverity(severity models.Severity) models.Severity {
+	if rust := d.config.RustConfig; rust != nil && rust.DowngradeMutableStatics {
+		return models.SeverityLow
+	}
+	return severity
+}
+
+// rustAtomicTypes maps the primitive types of std::sync::atomic to their atomic counterparts
+var rustAtomicTypes = map[string]string{
+	"bool": "AtomicBool", "usize": "AtomicUsize", "isize": "AtomicIsize",
+	"u8": "AtomicU8", "u16": "AtomicU16", "u32": "AtomicU32", "u64": "AtomicU64",
+	"i8": "AtomicI8", "i16": "AtomicI16", "i32": "AtomicI32", "i64": "AtomicI64",
+}
+
+// mutableStaticSuggestion suggests the atomic for a static of a primitive
+// type, and a lock or cell for anything else
+func mutableStaticSuggestion(staticType string) string {
+	if atomic, ok := rustAtomicTypes[staticType]; ok {
+		return fmt.Sprintf("Replace the static mut with a static %s, or set downgrade_mutable_statics for FFI code that shares it with C", atomic)
+	}
+	return "Use an atomic type, a Mutex or OnceLock instead of static mut, or set the embedded profile for firmware that relies on it"
+}
+
+// detectHeapAllocations identifies allocating constructors and macros, which
+// no_std and embedded code usually has to avoid
+func (d *RustOwnershipDetector) detectHeapAllocations(filePath string, lines []string) []*models.Violation {
+	var violations []*models.Violation
+
+	allocationPattern := regexp.MustCompile(`\b(Box|Vec|String|Rc|Arc|VecDeque|BTreeMap|HashMap)::(new|with_capacity|from)\b|\b(vec|format)!|\.to_(string|owned|vec)\(\)`)
+
+	for lineNum, line := range lines {
+		trimmedLine := strings.TrimSpace(line)
+
+		// Skip comments and empty lines
+		if strings.HasPrefix(trimmedLine, "//") || trimmedLine == "" {
+			continue
+		}
+
+		match := allocationPattern.FindString(line)
+		if match == "" {
+			continue
+		}
+
+		codeSnippet := d.extractCodeSnippet(filePath, lineNum+1, lineNum+1)
+		violations = append(violations, &models.Violation{
+			Type:        models.ViolationTypeRustUnnecessaryAllocation,
+			Severity:    models.SeverityHigh,
+			Message:     fmt.Sprintf("Heap allocation with %s", strings.TrimPrefix(match, ".")),
+			File:        filePath,
+			Line:        lineNum + 1,
+			Column:      d.findPatternColumn(line, allocationPattern),
+			Rule:        "rust-heap-allocation",
+			Suggestion:  "Use fixed-size arrays, borrowed slices or heapless collections instead of allocating",
+			CodeSnippet: codeSnippet,
+		})
+	}
+
+	return violations
+}
+
+// detectStringConcatInLoops identifies strings built up inside loops with
+// `s = s + ...` or `s += ...`, and Vec<String> buffers that are pushed to and
+// joined again on every iteration. Only bindings declared before the loop are
+// considered, as those are the ones that grow from one iteration to the next.
+func (d *RustOwnershipDetector) detectStringConcatInLoops(filePath string, rustAstInfo *types.RustASTInfo, inference *rustTypeInference) []*models.Violation {
+	var violations []*models.Violation
+	if rustAstInfo.Syntax == nil {
+		return violations
+	}
+
+	tokens := rustAstInfo.Tokens
+	reported := make(map[int]bool)
+	for _, loop := range types.FindRustNodes(rustAstInfo.Syntax, types.RustNodeFor, types.RustNodeWhile, types.RustNodeLoop) {
+		start := rustTokenIndex(tokens, loop.StartLine, loop.StartColumn)
+		end := rustTokenIndex(tokens, loop.EndLine, loop.EndColumn)
+		for i := start; i+3 < end; i++ {
+			token := tokens[i]
+			if reported[i] || token.Kind != types.RustTokenIdent || i > 0 && (tokens[i-1].Text == "." || tokens[i-1].Text == "::") {
+				continue
+			}
+
+			var message, suggestion string
+			switch typ := rustBaseType(inference.typeOf(token.Text, loop.StartLine)); {
+			case typ == "String" && tokens[i+1].Text == "=" && tokens[i+2].Text == token.Text && tokens[i+3].Text == "+":
+				message = fmt.Sprintf("String '%s' is rebuilt with + on every iteration of a loop", token.Text)
+				suggestion = fmt.Sprintf("Append with %s.push_str(...) instead, or collect the parts and join them once after the loop", token.Text)
+			case typ == "String" && tokens[i+1].Text == "+=":
+				message = fmt.Sprintf("String '%s' is concatenated with += in a loop", token.Text)
+				suggestion = fmt.Sprintf("Reserve the space up front with String::with_capacity and append with %s.push_str(...), "+
+					"or collect the parts and join them once after the loop", token.Text)
+			case typ == "Vec" && tokens[i+1].Text == "." && (tokens[i+2].Text == "join" || tokens[i+2].Text == "concat") &&
+				tokens[i+3].Text == "(" && rustPushesTo(tokens[start:end], token.Text):
+				message = fmt.Sprintf("Vec '%s' is concatenated with .%s() on every iteration of a loop", token.Text, tokens[i+2].Text)
+				suggestion = fmt.Sprintf("Call %s.%s() once after the loop, or append each part to a String as it is produced", token.Text, tokens[i+2].Text)
+			default:
+				continue
+			}
+
+			reported[i] = true
+			violations = append(violations, &models.Violation{
+				Type:        models.ViolationTypeRustInefficientStringConcat,
+				Severity:    models.SeverityLow,
+				Message:     message,
+				File:        filePath,
+				Line:        token.Line,
+				Column:      token.Column,
+				Rule:        RustStringConcatInLoop,
+				Suggestion:  suggestion,
+				CodeSnippet: d.extractCodeSnippet(filePath, token.Line, token.Line),
+			})
+		}
+	}
+
+	sort.Slice(violations, func(i, j int) bool {
+		if violations[i].Line != violations[j].Line {
+			return violations[i].Line < violations[j].Line
+		}
+		return violations[i].Column < violations[j].Column
+	})
+	return violations
+}
+
+// rustPushesTo reports whether the tokens push or extend onto the named binding
+func rustPushesTo(tokens []types.RustTokenInfo, name string) bool {
+	for i := 0; i+2 < len(tokens); i++ {
+		if tokens[i].Text == name && tokens[i+1].Text == "." && (tokens[i+2].Text == "push" || tokens[i+2].Text == "extend") {
+			return true
+		}
+	}
+	return false
+}
+
+// detectUnnecessarySmartPointers identifies Box::new, Rc::new and Arc::new
+// calls that put a scalar Copy value on the heap, unless detect_boxed_primitives
+// is off, and Rc and Arc let bindings that are never cloned, moved or
+// returned, so the value has a single owner in a single function. Values
+// coerced to a trait object are skipped.
+func (d *RustOwnershipDetector) detectUnnecessarySmartPointers(filePath string, rustAstInfo *types.RustASTInfo, inference *rustTypeInference) []*models.Violation {
+	var violations []*models.Violation
+
+	boxedPrimitives := d.config.RustConfig == nil || d.config.RustConfig.DetectBoxedPrimitives
+	tokens := rustAstInfo.Tokens
+	for i := 0; i+3 < len(tokens); i++ {
+		pointer := tokens[i].Text
+		if pointer != "Box" && pointer != "Rc" && pointer != "Arc" || tokens[i+1].Text != "::" || tokens[i+2].Text != "new" || tokens[i+3].Text != "(" {
+			continue
+		}
+		fn := innermostRustFunction(rustAstInfo.Functions, tokens[i].Line)
+		if fn == nil {
+			continue
+		}
+		closing := rustClosingParen(tokens, i+3)
+		fnEnd := rustTokenIndex(tokens, fn.EndLine+1, 0)
+
+		var message, suggestion string
+		if typ := inference.exprType(tokens[i+4:min(closing, len(tokens))], tokens[i].Line); rustCopyPrimitives[typ] && typ != "()" {
+			if !boxedPrimitives || strings.Contains(fn.ReturnType, "dyn") || rustCoercedToDyn(tokens, i, closing) {
+				continue
+			}
+			message = fmt.Sprintf("%s::new puts a Copy value of type %s on the heap", pointer, typ)
+			suggestion = fmt.Sprintf("Keep the %s on the stack and pass it by value; copying it is as cheap as copying the %s", typ, pointer)
+		} else if name := rustLetName(tokens, i); pointer != "Box" && name != "" && closing < fnEnd && !rustPointerEscapes(tokens[closing+1:fnEnd], name) {
+			message = fmt.Sprintf("%s '%s' is only used inside '%s'", pointer, name, fn.Name)
+			suggestion = "Own the value directly and lend it with &; Rc is only needed when several owners share it"
+			if pointer == "Arc" {
+				suggestion = "Own the value directly and lend it with &; Arc is only needed when the value is shared across threads"
+			}
+		} else {
+			continue
+		}
+
+		violations = append(violations, &models.Violation{
+			Type:        models.ViolationTypeRustUnnecessaryAllocation,
+			Severity:    models.SeverityLow,
+			Message:     message,
+			File:        filePath,
+			Line:        tokens[i].Line,
+			Column:      tokens[i].Column,
+			Rule:        RustUnnecessarySmartPointer,
+			Suggestion:  suggestion,
+			CodeSnippet: d.extractCodeSnippet(filePath, tokens[i].Line, tokens[i].Line),
+		})
+	}
+
+	return violations
+}
+
+// rustLetName returns the name bound by `let [mut] name = ` right before the
+// token at i, or ""
+func rustLetName(tokens []types.RustTokenInfo, i int) string {
+	if i < 3 || tokens[i-1].Text != "=" || tokens[i-2].Kind != types.RustTokenIdent {
+		return ""
+	}
+	if tokens[i-3].Text == "let" || tokens[i-3].Text == "mut" && i > 3 && tokens[i-4].Text == "let" {
+		return tokens[i-2].Text
+	}
+	return ""
+}
+
+// rustCoercedToDyn reports whether the statement holding the call from start
+// to closing casts or annotates it as a trait object
+func rustCoercedToDyn(tokens []types.RustTokenInfo, start, closing int) bool {
+	for k := start - 1; k >= 0 && tokens[k].Text != ";" && tokens[k].Text != "{" && tokens[k].Text != "}"; k-- {
+		if tokens[k].Text == "dyn" {
+			return true
+		}
+	}
+	for k := closing + 1; k < len(tokens) && tokens[k].Text != ";" && tokens[k].Text != "}"; k++ {
+		if tokens[k].Text == "dyn" {
+			return true
+		}
+	}
+	return false
+}
+
+// rustPointerEscapes reports whether the tokens after a binding's declaration
+// clone, downgrade, move or return it, or capture it in a move closure, so
+// that something other than the binding may hold the pointer. Arguments of
+// macros such as println! are borrowed and do not count.
+func rustPointerEscapes(tokens []types.RustTokenInfo, name string) bool {
+	for j, token := range tokens {
+		if token.Kind == types.RustTokenKeyword && token.Text == "move" {
+			return true
+		}
+		if token.Text != name || token.Kind != types.RustTokenIdent || j > 0 && (tokens[j-1].Text == "." || tokens[j-1].Text == "::") {
+			continue
+		}
+
+		prev, next := "", ""
+		if j > 0 {
+			prev = tokens[j-1].Text
+		}
+		if j+1 < len(tokens) {
+			next = tokens[j+1].Text
+		}
+		switch {
+		case next == "." && j+2 < len(tokens) && tokens[j+2].Text == "clone":
+			return true
+		case prev == "&" && j > 2 && tokens[j-2].Text == "(" && (tokens[j-3].Text == "clone" || tokens[j-3].Text == "downgrade"):
+			return true
+		case prev == "return" || prev == "=" || prev == ":":
+			return true
+		case (prev == "(" || prev == "," || prev == "{") && (next == ")" || next == "," || next == "}") && !rustInMacroArgs(tokens, j):
+			return true
+		case prev == ";" && next == "}":
+			return true
+		}
+	}
+	return false
+}
+
+// rustInMacroArgs reports whether the token at j is inside the arguments of
+// a macro call
+func rustInMacroArgs(tokens []types.RustTokenInfo, j int) bool {
+	depth := 0
+	for k := j - 1; k > 0; k-- {
+		switch tokens[k].Text {
+		case ")", "]", "}":
+			depth++
+		case "(", "[", "{":
+			if depth == 0 {
+				return tokens[k-1].Text == "!"
+			}
+			depth--
+		}
+	}
+	return false
+}
+
 // Helper methods
 
 func (d *RustOwnershipDetector) isLikelyUnnecessaryClone(line string) bool {
@@ -344,6 +344,51 @@ unsafe {
 	}
 }
 
+func TestRustOwnershipDetector_EmbeddedRules(t *testing.T) {
+	code := `static mut TICKS: u32 = 0;
+pub static LABEL: &str = "sensor";
+
+fn sample() -> Vec<u8> {
+    // vec![0; 4] would allocate
+    let buffer = Vec::with_capacity(16);
+    let name = LABEL.to_string();
+    buffer
+}`
+
+	filePath := createTempFileWithContent(t, code, ".rs")
+	defer removeTempFile(t, filePath)
+	fileInfo := &models.FileInfo{Path: filePath, Language: "rust"}
+	rustAstInfo := &types.RustASTInfo{FilePath: filePath}
+
+	countRules := func(config *DetectorConfig) map[string][]int {
+		lines := make(map[string][]int)
+		for _, v := range NewRustOwnershipDetector(config).Detect(fileInfo, rustAstInfo) {
+			lines[v.Rule] = append(lines[v.Rule], v.Line)
+		}
+		return lines
+	}
+
+	// By default mutable statics are reported and allocations are not
+	defaults := countRules(DefaultDetectorConfig())
+	if got := defaults["rust-mutable-static"]; len(got) != 1 || got[0] != 1 {
+		t.Errorf("Expected a mutable static violation on line 1, got %v", got)
+	}
+	if got := defaults["rust-heap-allocation"]; len(got) != 0 {
+		t.Errorf("Expected no heap allocation violations by default, got %v", got)
+	}
+
+	embedded := DefaultDetectorConfig()
+	embedded.RustConfig.AllowMutableStatics = true
+	embedded.RustConfig.DetectHeapAllocations = true
+	rules := countRules(embedded)
+	if got := rules["rust-mutable-static"]; len(got) != 0 {
+		t.Errorf("Expected mutable statics to be allowed, got %v", got)
+	}
+	if got := rules["rust-heap-allocation"]; len(got) != 2 || got[0] != 6 || got[1] != 7 {
+		t.Errorf("Expected heap allocations on lines 6 and 7, got %v", got)
+	}
+}
+
 func TestRustOwnershipDetector_NilAstInfo(t *testing.T) {
 	detector := NewRustOwnershipDetector(nil)
 
@@ -400,4 +445,303 @@ func removeTempFile(t *testing.T, filePath string) {
 
 func containsIgnoreCase(s, substr string) bool {
 	return strings.Contains(strings.ToLower(s), strings.ToLower(substr))
-}
\ No newline at end of file
+}
+func TestRustOwnershipDetector_MutableStaticAccesses(t *testing.T) {
+	code := `static mut COUNTER: u32 = 0;
+static mut NAMES: Vec<String> = Vec::new();
+
+fn bump(stats: &mut Stats) {
+    unsafe {
+        COUNTER += 1;
+        stats.COUNTER = COUNTER;
+    }
+}`
+
+	filePath := createTempFileWithContent(t, code, ".rs")
+	defer removeTempFile(t, filePath)
+	fileInfo := &models.FileInfo{Path: filePath, Language: "rust"}
+	rustAstInfo := &types.RustASTInfo{
+		FilePath:  filePath,
+		Tokens:    rustTestTokens(code),
+		Functions: []*types.RustFunctionInfo{{Name: "bump", StartLine: 4, EndLine: 9}},
+	}
+
+	config := DefaultDetectorConfig()
+	var statics, accesses []*models.Violation
+	for _, v := range NewRustOwnershipDetector(config).Detect(fileInfo, rustAstInfo) {
+		switch v.Rule {
+		case "rust-mutable-static":
+			statics = append(statics, v)
+		case RustMutableStaticAccess:
+			accesses = append(accesses, v)
+		}
+	}
+	if len(statics) != 2 || statics[0].Suggestion != "Replace the static mut with a static AtomicU32, or set downgrade_mutable_statics for FFI code that shares it with C" {
+		t.Errorf("Expected both statics with an atomic suggested for COUNTER, got %+v", statics)
+	}
+	if len(accesses) != 2 {
+		t.Fatalf("Expected the two accesses to COUNTER but not the field, got %+v", accesses)
+	}
+	if v := accesses[1]; v.Line != 7 || v.Column != 25 || v.Severity != models.SeverityMedium ||
+		v.Message != "Mutable static 'COUNTER' is accessed in 'bump'" {
+		t.Errorf("Unexpected access violation %+v", v)
+	}
+
+	config.RustConfig.DowngradeMutableStatics = true
+	for _, v := range NewRustOwnershipDetector(config).Detect(fileInfo, rustAstInfo) {
+		if (v.Rule == "rust-mutable-static" || v.Rule == RustMutableStaticAccess) && v.Severity != models.SeverityLow {
+			t.Errorf("Expected downgraded mutable statics to be low severity, got %+v", v)
+		}
+	}
+}
+
+func TestRustOwnershipDetector_StringConcatInLoops(t *testing.T) {
+	code := `fn build(parts: Vec<String>) -> String {
+    let mut result = String::new();
+    for part in parts {
+        result = result + &part;
+    }
+    let mut line = String::new();
+    let mut fields = Vec::new();
+    while let Some(field) = next() {
+        line += &field;
+        fields.push(field);
+        println!("{}", fields.join(","));
+        let mut row = String::new();
+        row += "x";
+    }
+    result
+}`
+
+	filePath := createTempFileWithContent(t, code, ".rs")
+	defer removeTempFile(t, filePath)
+	root := &types.RustNode{Kind: types.RustNodeFile, StartLine: 1}
+	root.AddChild(&types.RustNode{Kind: types.RustNodeFor, StartLine: 3, StartColumn: 5, EndLine: 5, EndColumn: 5})
+	root.AddChild(&types.RustNode{Kind: types.RustNodeWhile, StartLine: 8, StartColumn: 5, EndLine: 14, EndColumn: 5})
+	rustAstInfo := &types.RustASTInfo{
+		FilePath:  filePath,
+		Syntax:    root,
+		Tokens:    rustTestTokens(code),
+		Functions: []*types.RustFunctionInfo{{Name: "build", StartLine: 1, EndLine: 16}},
+	}
+
+	var violations []*models.Violation
+	for _, v := range NewRustOwnershipDetector(nil).Detect(&models.FileInfo{Path: filePath, Language: "rust"}, rustAstInfo) {
+		if v.Rule == RustStringConcatInLoop {
+			violations = append(violations, v)
+		}
+	}
+	if len(violations) != 3 {
+		t.Fatalf("Expected result, line and fields but not the per-iteration row, got %+v", violations)
+	}
+	for i, want := range []struct{ line, column int }{{4, 9}, {9, 9}, {11, 24}} {
+		if v := violations[i]; v.Line != want.line || v.Column != want.column || v.Type != models.ViolationTypeRustInefficientStringConcat {
+			t.Errorf("Expected violation %d at %d:%d, got %+v", i, want.line, want.column, v)
+		}
+	}
+	if violations[0].Message != "String 'result' is rebuilt with + on every iteration of a loop" ||
+		violations[2].Message != "Vec 'fields' is concatenated with .join() on every iteration of a loop" {
+		t.Errorf("Unexpected messages %q and %q", violations[0].Message, violations[2].Message)
+	}
+}
+
+func TestRustOwnershipDetector_UnnecessarySmartPointers(t *testing.T) {
+	code := `fn unnecessary_box() -> Box<i32> {
+    Box::new(42)
+}
+fn unnecessary_rc() {
+    let data = Rc::new(vec![1, 2, 3]);
+    println!("{:?}", data);
+}
+fn shared() -> Rc<Vec<i32>> {
+    let data = Rc::new(vec![1, 2, 3]);
+    let other = Rc::clone(&data);
+    consume(other);
+    data
+}
+fn spawned() {
+    let counter = Arc::new(Mutex::new(0));
+    thread::spawn(move || *counter.lock().unwrap() += 1);
+}
+fn boxed() -> Box<dyn Display> {
+    Box::new(7)
+}`
+
+	filePath := createTempFileWithContent(t, code, ".rs")
+	defer removeTempFile(t, filePath)
+	rustAstInfo := &types.RustASTInfo{
+		FilePath: filePath,
+		Tokens:   rustTestTokens(code),
+		Functions: []*types.RustFunctionInfo{
+			{Name: "unnecessary_box", StartLine: 1, EndLine: 3, ReturnType: "Box<i32>"},
+			{Name: "unnecessary_rc", StartLine: 4, EndLine: 7},
+			{Name: "shared", StartLine: 8, EndLine: 13, ReturnType: "Rc<Vec<i32>>"},
+			{Name: "spawned", StartLine: 14, EndLine: 17},
+			{Name: "boxed", StartLine: 18, EndLine: 20, ReturnType: "Box<dyn Display>"},
+		},
+	}
+
+	var violations []*models.Violation
+	for _, v := range NewRustOwnershipDetector(nil).Detect(&models.FileInfo{Path: filePath, Language: "rust"}, rustAstInfo) {
+		if v.Rule == RustUnnecessarySmartPointer {
+			violations = append(violations, v)
+		}
+	}
+	if len(violations) != 2 {
+		t.Fatalf("Expected the boxed i32 and the local Rc only, got %+v", violations)
+	}
+	if v := violations[0]; v.Line != 2 || v.Column != 5 || v.Message != "Box::new puts a Copy value of type i32 on the heap" {
+		t.Errorf("Unexpected Box violation %+v", v)
+	}
+	if v := violations[1]; v.Line != 5 || v.Column != 16 || v.Message != "Rc 'data' is only used inside 'unnecessary_rc'" ||
+		v.Type != models.ViolationTypeRustUnnecessaryAllocation {
+		t.Errorf("Unexpected Rc violation %+v", v)
+	}
+}
+
+func TestRustOwnershipDetector_OwnedParameters(t *testing.T) {
+	code := `fn takes_ownership_unnecessarily(s: String) {
+    println!("Length: {}", s.len());
+}
+fn total(values: Vec<u32>, name: String) -> usize {
+    if name == "empty" || values.is_empty() {
+        return 0;
+    }
+    store(name);
+    values.iter().count()
+}
+fn grow(mut items: Vec<String>, unused: String) {
+    items.push(String::new());
+}`
+
+	filePath := createTempFileWithContent(t, code, ".rs")
+	defer removeTempFile(t, filePath)
+	rustAstInfo := &types.RustASTInfo{
+		FilePath: filePath,
+		Tokens:   rustTestTokens(code),
+		Functions: []*types.RustFunctionInfo{
+			{Name: "takes_ownership_unnecessarily", StartLine: 1, StartColumn: 1, EndLine: 3,
+				Parameters: []types.RustParameterInfo{{Name: "s", Type: "String"}}},
+			{Name: "total", StartLine: 4, StartColumn: 1, EndLine: 10,
+				Parameters: []types.RustParameterInfo{{Name: "values", Type: "Vec<u32>"}, {Name: "name", Type: "String"}}},
+			{Name: "grow", StartLine: 11, StartColumn: 1, EndLine: 13,
+				Parameters: []types.RustParameterInfo{{Name: "items", Type: "Vec<String>", IsMutable: true}, {Name: "unused", Type: "String"}}},
+		},
+	}
+
+	var violations []*models.Violation
+	for _, v := range NewRustOwnershipDetector(nil).Detect(&models.FileInfo{Path: filePath, Language: "rust"}, rustAstInfo) {
+		if v.Rule == RustUnnecessaryOwnedParameter {
+			violations = append(violations, v)
+		}
+	}
+	if len(violations) != 2 {
+		t.Fatalf("Expected s and values but not the moved, mutated or unused parameters, got %+v", violations)
+	}
+	if v := violations[0]; v.Line != 1 || v.Column != 34 || v.Suggestion != "Take 's: &str' so callers keep ownership and need not clone or allocate" {
+		t.Errorf("Unexpected violation for s: %+v", v)
+	}
+	if v := violations[1]; v.Message != "Parameter 'values' of 'total' takes Vec<u32> by value but is only read" ||
+		v.Suggestion != "Take 'values: &[u32]' so callers keep ownership and need not clone or allocate" {
+		t.Errorf("Unexpected violation for values: %+v", v)
+	}
+}
+
+func TestRustOwnershipDetector_UnusedMutableReferences(t *testing.T) {
+	code := `fn takes_mut_unnecessarily(data: &mut Vec<i32>) -> usize {
+    data.len()
+}
+impl Counter {
+    pub fn total(&mut self, extra: &mut u32) -> u32 {
+        let sum = self.values.iter().sum::<u32>();
+        *extra += 1;
+        sum + self.count
+    }
+    fn bump(&mut self, out: &mut String) {
+        self.count += 1;
+        out.push_str("x");
+    }
+}`
+
+	filePath := createTempFileWithContent(t, code, ".rs")
+	defer removeTempFile(t, filePath)
+	rustAstInfo := &types.RustASTInfo{
+		FilePath: filePath,
+		Tokens:   rustTestTokens(code),
+		Functions: []*types.RustFunctionInfo{
+			{Name: "takes_mut_unnecessarily", StartLine: 1, StartColumn: 1, EndLine: 3},
+			{Name: "total", StartLine: 5, StartColumn: 5, EndLine: 9, IsPublic: true},
+			{Name: "bump", StartLine: 10, StartColumn: 5, EndLine: 13},
+		},
+	}
+
+	var violations []*models.Violation
+	for _, v := range NewRustOwnershipDetector(nil).Detect(&models.FileInfo{Path: filePath, Language: "rust"}, rustAstInfo) {
+		if v.Rule == RustUnnecessaryMutReference {
+			violations = append(violations, v)
+		}
+	}
+	if len(violations) != 2 {
+		t.Fatalf("Expected data and the self of total only, got %+v", violations)
+	}
+	if v := violations[0]; v.Line != 1 || v.Column != 28 || v.Fix == nil || len(v.Fix.Edits) != 1 {
+		t.Fatalf("Expected a fix for the private function, got %+v", v)
+	}
+	if edit := violations[0].Fix.Edits[0]; edit.Line != 1 || edit.Column != 35 || edit.EndColumn != 39 || edit.NewText != "" {
+		t.Errorf("Expected the fix to delete 'mut ', got %+v", edit)
+	}
+	if v := violations[1]; v.Message != "Parameter 'self' of 'total' is borrowed mutably but never written through" || v.Fix != nil {
+		t.Errorf("Expected self of the public method without a fix, got %+v", v)
+	}
+}
+
+func TestRustOwnershipDetector_LifetimeAnnotations(t *testing.T) {
+	code := `fn first_word<'a>(s: &'a str) -> &'a str {
+    s
+}
+fn longest<'a>(x: &'a str, y: &'a str) -> &'a str {
+    x
+}
+fn complex_lifetime<'a, 'b, 'c, 'd>(x: &'a str, y: &'b str, z: &'c str, w: &'d str) -> &'a str
+where
+    'b: 'a,
+    'c: 'b,
+    'd: 'c,
+{
+    x
+}
+fn short_chain<'a, 'b: 'a, 'c: 'b>(x: &'a str, y: &'b str, z: &'c str) -> &'a str {
+    x
+}`
+
+	filePath := createTempFileWithContent(t, code, ".rs")
+	defer removeTempFile(t, filePath)
+	rustAstInfo := &types.RustASTInfo{
+		FilePath: filePath,
+		Tokens:   rustTestTokens(code),
+		Functions: []*types.RustFunctionInfo{
+			{Name: "first_word", StartLine: 1, StartColumn: 1, EndLine: 3},
+			{Name: "longest", StartLine: 4, StartColumn: 1, EndLine: 6},
+			{Name: "complex_lifetime", StartLine: 7, StartColumn: 1, EndLine: 14},
+			{Name: "short_chain", StartLine: 15, StartColumn: 1, EndLine: 17},
+		},
+	}
+
+	var needless, chains []*models.Violation
+	for _, v := range NewRustOwnershipDetector(nil).Detect(&models.FileInfo{Path: filePath, Language: "rust"}, rustAstInfo) {
+		switch v.Rule {
+		case RustNeedlessLifetime:
+			needless = append(needless, v)
+		case RustLifetimeBoundChain:
+			chains = append(chains, v)
+		}
+	}
+	if len(needless) != 1 || needless[0].Line != 1 || needless[0].Column != 15 || needless[0].Severity != models.SeverityLow ||
+		needless[0].Message != "Lifetime 'a of 'first_word' can be elided" {
+		t.Errorf("Expected only the lifetime of first_word to be needless, got %+v", needless)
+	}
+	if len(chains) != 1 || chains[0].Line != 11 || chains[0].Column != 5 || chains[0].Severity != models.SeverityMedium ||
+		chains[0].Message != "Lifetime bounds of 'complex_lifetime' chain 4 lifetimes: 'd: 'c: 'b: 'a" {
+		t.Errorf("Expected only the four lifetime chain of complex_lifetime, got %+v", chains)
+	}
+}
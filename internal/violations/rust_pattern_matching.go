@@ -0,0 +1,489 @@
+package violations
+
+import (
+	"fmt"
+	"os"
+	"strings"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+// Rules of the pattern matching detector
+const (
+	RustMatchOnBool     = "rust-match-on-bool"
+	RustWildcardEnumArm = "rust-wildcard-enum-arm"
+	RustIfLetLadder     = "rust-if-let-ladder"
+)
+
+// RustPatternMatchingDetector reports match expressions that an if/else
+// states more plainly, wildcard arms that hide the variants of an enum of
+// the same crate, and if let chains that a single match states more plainly.
+// It runs while enable_pattern_match_check is on.
+type RustPatternMatchingDetector struct {
+	config *DetectorConfig
+}
+
+// rustMatchArm is an arm of a match expression
+type rustMatchArm struct {
+	pattern []types.RustTokenInfo // Up to the =>, with the guard
+	body    []types.RustTokenInfo // Without the trailing comma
+}
+
+// rustIfLetChain is an if let expression with its else if let links, and
+// the if let chains that make up the whole body of one of its branches
+type rustIfLetChain struct {
+	scrutinees []string // Each scrutinee tested, in order
+	end        int      // Index after the last token of the chain
+}
+
+// NewRustPatternMatchingDetector creates a new Rust pattern matching detector
+func NewRustPatternMatchingDetector(config *DetectorConfig) *RustPatternMatchingDetector {
+	if config == nil {
+		config = DefaultDetectorConfig()
+	}
+	return &RustPatternMatchingDetector{config: config}
+}
+
+// Name returns the name of this detector
+func (d *RustPatternMatchingDetector) Name() string {
+	return "Rust Pattern Matching Analysis"
+}
+
+// Description returns a description of what this detector checks for
+func (d *RustPatternMatchingDetector) Description() string {
+	return "Detects match expressions on a bool whose only arms are true and false, wildcard arms in matches on enums of the same crate and if let chains that test the same values again"
+}
+
+// Detect analyzes the match expressions of a Rust file
+func (d *RustPatternMatchingDetector) Detect(fileInfo *models.FileInfo, astInfo interface{}) []*models.Violation {
+	rust := d.config.RustConfig
+	if rust == nil || !rust.EnablePatternMatchCheck {
+		return nil
+	}
+	info, ok := astInfo.(*types.RustASTInfo)
+	if !ok || info == nil {
+		return nil
+	}
+
+	var lines []string
+	if content, err := os.ReadFile(fileInfo.Path); err == nil {
+		lines = strings.Split(string(content), "\n")
+	}
+	violations := d.detectMatchOnBool(fileInfo.Path, info.Tokens, lines)
+	violations = append(violations, d.detectIfLetLadders(fileInfo.Path, info.Tokens)...)
+	if rust.RequireExhaustiveMatch {
+		violations = append(violations, d.detectWildcardEnumArms(fileInfo.Path, info)...)
+	}
+	return violations
+}
+
+// detectMatchOnBool finds match expressions whose arms are exactly true and
+// false, without guards. The fix rewrites one written on single-line arms as
+// an if/else, with the true arm first.
+func (d *RustPatternMatchingDetector) detectMatchOnBool(filePath string, tokens []types.RustTokenInfo, lines []string) []*models.Violation {
+	var violations []*models.Violation
+
+	for i := 0; i+1 < len(tokens); i++ {
+		if tokens[i].Text != "match" {
+			continue
+		}
+		open := rustScrutineeBrace(tokens, i+1)
+		if open >= len(tokens) || open == i+1 {
+			continue
+		}
+		closing := rustGroupEnd(tokens, open) - 1
+		arms := rustBoolArms(tokens, open, closing)
+		if arms == nil {
+			continue
+		}
+
+		start, last := tokens[i], tokens[closing]
+		violation := &models.Violation{
+			Type:        models.ViolationTypeRustMatchOnBool,
+			Severity:    models.SeverityLow,
+			Message:     "match on a bool can be written as if/else",
+			File:        filePath,
+			Line:        start.Line,
+			Column:      start.Column,
+			EndLine:     last.Line,
+			EndColumn:   last.Column + 1,
+			Rule:        RustMatchOnBool,
+			Suggestion:  "Use if/else, which states a two-way choice more plainly",
+			CodeSnippet: rustSourceLines(lines, start.Line, last.Line),
+		}
+		condition := rustSourceText(lines, tokens[i+1:open])
+		whenTrue := rustSourceText(lines, arms["true"])
+		whenFalse := rustSourceText(lines, arms["false"])
+		if condition != "" && whenTrue != "" && whenFalse != "" {
+			violation.Fix = &models.Fix{
+				Description: "Rewrite the match as if/else",
+				Edits: []models.TextEdit{{
+					Line:      start.Line,
+					Column:    start.Column,
+					EndLine:   last.Line,
+					EndColumn: last.Column + 1,
+					NewText:   "if " + condition + " " + rustBlockText(arms["true"], whenTrue) + " else " + rustBlockText(arms["false"], whenFalse),
+				}},
+			}
+		}
+		violations = append(violations, violation)
+	}
+	return violations
+}
+
+// detectWildcardEnumArms finds `_ =>` arms in matches whose other arms name
+// the variants of an enum defined in the same crate. When a variant is added,
+// the wildcard takes it silently instead of the compiler asking for an arm.
+// Enums of other crates, and #[non_exhaustive] enums of the file, may keep
+// their wildcard, as may matches whose enum cannot be resolved.
+func (d *RustPatternMatchingDetector) detectWildcardEnumArms(filePath string, info *types.RustASTInfo) []*models.Violation {
+	var violations []*models.Violation
+
+	tokens := info.Tokens
+	uses := rustUseItems(info)
+	for i := 0; i+1 < len(tokens); i++ {
+		if tokens[i].Text != "match" {
+			continue
+		}
+		open := rustScrutineeBrace(tokens, i+1)
+		if open >= len(tokens) {
+			continue
+		}
+		arms := rustMatchArms(tokens, open, rustGroupEnd(tokens, open)-1)
+
+		var wildcard *types.RustTokenInfo
+		enum, consistent := "", true
+		named := make(map[string]bool)
+		for _, arm := range arms {
+			if len(arm.pattern) == 1 && arm.pattern[0].Text == "_" {
+				wildcard = &arm.pattern[0]
+				continue
+			}
+			for _, alternative := range rustPatternAlternatives(arm.pattern) {
+				path, variant := rustVariantPath(alternative)
+				if enum == "" {
+					enum = path
+				}
+				consistent = consistent && path != "" && path == enum
+				named[variant] = true
+			}
+		}
+		if wildcard == nil || enum == "" || !consistent || !rustCrateLocalPath(enum, info, uses) {
+			continue
+		}
+
+		name := lastRustSegment(enum)
+		var hidden []string
+		if definition := rustFileEnum(info, name); definition != nil && enum == name {
+			keyword := rustEnumKeyword(tokens, definition)
+			if hasSkippedAttribute(tokens, keyword, []string{"non_exhaustive"}) {
+				continue
+			}
+			for _, variant := range rustEnumVariantNames(tokens, keyword) {
+				if !named[variant] {
+					hidden = append(hidden, variant)
+				}
+			}
+			if len(hidden) == 0 {
+				continue
+			}
+		}
+
+		violation := &models.Violation{
+			Type:       models.ViolationTypeRustMissingMatchArm,
+			Severity:   models.SeverityMedium,
+			Message:    fmt.Sprintf("Wildcard arm in a match on enum '%s' of this crate", name),
+			File:       filePath,
+			Line:       wildcard.Line,
+			Column:     wildcard.Column,
+			Rule:       RustWildcardEnumArm,
+			Suggestion: "List the remaining variants instead of _, so the compiler points at this match when a variant is added",
+		}
+		if len(hidden) > 0 {
+			violation.Description = fmt.Sprintf("The wildcard covers %s", strings.Join(hidden, ", "))
+		}
+		violations = append(violations, violation)
+	}
+	return violations
+}
+
+// detectIfLetLadders finds if let chains, including the if let chains making
+// up a whole branch, that test several values and come back to one of them,
+// as in if let Some(x) = a { if let Some(y) = b {..} } else if let Some(y) = b,
+// or that test one value three times or more. A match on the value, or on a
+// tuple of the values, lists every combination in one place.
+func (d *RustPatternMatchingDetector) detectIfLetLadders(filePath string, tokens []types.RustTokenInfo) []*models.Violation {
+	var violations []*models.Violation
+
+	reported := 0
+	for i := 0; i+1 < len(tokens); i++ {
+		if i < reported || tokens[i].Text != "if" || tokens[i+1].Text != "let" || i > 0 && tokens[i-1].Text == "else" {
+			continue
+		}
+		chain := rustReadIfLetChain(tokens, i)
+
+		var values []string
+		tests := make(map[string]int)
+		for _, scrutinee := range chain.scrutinees {
+			if tests[scrutinee] == 0 {
+				values = append(values, scrutinee)
+			}
+			tests[scrutinee]++
+		}
+		repeated := false
+		for _, count := range tests {
+			repeated = repeated || count > 1
+		}
+		if len(values) == 1 && len(chain.scrutinees) < 3 || len(values) > 1 && !repeated {
+			continue
+		}
+
+		subject := values[0]
+		if len(values) > 1 {
+			subject = "(" + strings.Join(values, ", ") + ")"
+		}
+		reported = chain.end
+		violations = append(violations, &models.Violation{
+			Type:       models.ViolationTypeRustNestedPatternMatching,
+			Severity:   models.SeverityLow,
+			Message:    fmt.Sprintf("if let chain makes %d tests on %s", len(chain.scrutinees), strings.Join(values, " and ")),
+			File:       filePath,
+			Line:       tokens[i].Line,
+			Column:     tokens[i].Column,
+			EndLine:    tokens[chain.end-1].Line,
+			EndColumn:  tokens[chain.end-1].Column + len(tokens[chain.end-1].Text),
+			Rule:       RustIfLetLadder,
+			Suggestion: fmt.Sprintf("Use a single match on %s with an arm per case", subject),
+		})
+	}
+	return violations
+}
+
+// rustReadIfLetChain reads the if let chain whose if keyword is at index i.
+// The chain stops at an else if without let.
+func rustReadIfLetChain(tokens []types.RustTokenInfo, i int) rustIfLetChain {
+	var chain rustIfLetChain
+	for {
+		equals := i + 2
+		for equals < len(tokens) && tokens[equals].Text != "=" {
+			if isRustOpenDelimiter(tokens[equals].Text) {
+				equals = rustGroupEnd(tokens, equals)
+			} else {
+				equals++
+			}
+		}
+		open := rustScrutineeBrace(tokens, equals+1)
+		if open >= len(tokens) {
+			chain.end = len(tokens)
+			return chain
+		}
+		chain.scrutinees = append(chain.scrutinees, rustTypeText(tokens[equals+1:open]))
+		chain.end = rustGroupEnd(tokens, open)
+		chain.readBranch(tokens, open)
+
+		next := chain.end
+		switch {
+		case next+1 < len(tokens) && tokens[next].Text == "else" && tokens[next+1].Text == "{":
+			chain.end = rustGroupEnd(tokens, next+1)
+			chain.readBranch(tokens, next+1)
+			return chain
+		case next+2 < len(tokens) && tokens[next].Text == "else" && tokens[next+1].Text == "if" && tokens[next+2].Text == "let":
+			i = next + 1
+		default:
+			return chain
+		}
+	}
+}
+
+// readBranch adds the scrutinees of an if let chain making up the whole
+// block whose opening brace is at index open
+func (c *rustIfLetChain) readBranch(tokens []types.RustTokenInfo, open int) {
+	if open+2 >= len(tokens) || tokens[open+1].Text != "if" || tokens[open+2].Text != "let" {
+		return
+	}
+	inner := rustReadIfLetChain(tokens, open+1)
+	if inner.end < len(tokens) && tokens[inner.end].Text == "}" && inner.end+1 == rustGroupEnd(tokens, open) {
+		c.scrutinees = append(c.scrutinees, inner.scrutinees...)
+	}
+}
+
+// rustMatchArms returns the arms of the match between the braces at open and
+// closing, or nil when they cannot be read
+func rustMatchArms(tokens []types.RustTokenInfo, open, closing int) []rustMatchArm {
+	var arms []rustMatchArm
+	for i := open + 1; i < closing; {
+		arrow := i
+		for arrow < closing && tokens[arrow].Text != "=>" {
+			if isRustOpenDelimiter(tokens[arrow].Text) {
+				arrow = rustGroupEnd(tokens, arrow)
+			} else {
+				arrow++
+			}
+		}
+		body := arrow + 1
+		if arrow == i || body >= closing {
+			return nil
+		}
+
+		end := body
+		if tokens[body].Text == "{" {
+			end = rustGroupEnd(tokens, body)
+		} else {
+			for end < closing && tokens[end].Text != "," {
+				if isRustOpenDelimiter(tokens[end].Text) {
+					end = rustGroupEnd(tokens, end)
+				} else {
+					end++
+				}
+			}
+		}
+		if end == body || end > closing {
+			return nil
+		}
+		arms = append(arms, rustMatchArm{pattern: tokens[i:arrow], body: tokens[body:end]})
+
+		i = end
+		if i < closing && tokens[i].Text == "," {
+			i++
+		}
+	}
+	return arms
+}
+
+// rustBoolArms returns the bodies of the true and false arms of the match
+// between the braces at open and closing, or nil unless those are its only
+// arms
+func rustBoolArms(tokens []types.RustTokenInfo, open, closing int) map[string][]types.RustTokenInfo {
+	arms := rustMatchArms(tokens, open, closing)
+	if len(arms) != 2 {
+		return nil
+	}
+	bodies := make(map[string][]types.RustTokenInfo)
+	for _, arm := range arms {
+		pattern := arm.pattern[0].Text
+		if len(arm.pattern) != 1 || pattern != "true" && pattern != "false" {
+			return nil
+		}
+		bodies[pattern] = arm.body
+	}
+	if len(bodies) != 2 {
+		return nil
+	}
+	return bodies
+}
+
+// rustPatternAlternatives splits the pattern of an arm at its top-level |
+// and drops its guard
+func rustPatternAlternatives(pattern []types.RustTokenInfo) [][]types.RustTokenInfo {
+	var alternatives [][]types.RustTokenInfo
+	start := 0
+	for i := 0; i <= len(pattern); i++ {
+		switch {
+		case i == len(pattern) || pattern[i].Text == "if":
+			return append(alternatives, pattern[start:i])
+		case pattern[i].Text == "|":
+			alternatives = append(alternatives, pattern[start:i])
+			start = i + 1
+		case isRustOpenDelimiter(pattern[i].Text):
+			i = rustGroupEnd(pattern, i) - 1
+		}
+	}
+	return alternatives
+}
+
+// rustVariantPath returns the enum path and the variant named by a pattern
+// such as Status::Error or crate::model::Status::Error(code), or empty
+// strings when the pattern does not start with a path to a variant
+func rustVariantPath(pattern []types.RustTokenInfo) (string, string) {
+	var segments []string
+	for i := 0; i < len(pattern) && pattern[i].Kind != types.RustTokenPunct; i += 2 {
+		segments = append(segments, pattern[i].Text)
+		if i+1 >= len(pattern) || pattern[i+1].Text != "::" {
+			break
+		}
+	}
+	if len(segments) < 2 {
+		return "", ""
+	}
+	return strings.Join(segments[:len(segments)-1], "::"), segments[len(segments)-1]
+}
+
+// rustCrateLocalPath reports whether a path names an item of the crate of the
+// file: a path from crate, self, super or Self, an enum defined in the file,
+// or a path whose first segment is a module declared in the file or imported
+// from the crate. Paths from other crates and unresolved names are not.
+func rustCrateLocalPath(path string, info *types.RustASTInfo, uses []rustUseItem) bool {
+	first, _, qualified := strings.Cut(path, "::")
+	switch first {
+	case "crate", "self", "super", "Self":
+		return true
+	}
+	if !qualified && rustFileEnum(info, first) != nil {
+		return true
+	}
+	for i := 0; i+1 < len(info.Tokens); i++ {
+		if info.Tokens[i].Text == "mod" && info.Tokens[i+1].Text == first {
+			return true
+		}
+	}
+
+	for _, item := range uses {
+		for _, leaf := range rustUseLeaves(item.tree) {
+			target, alias, aliased := strings.Cut(leaf, " as ")
+			if !aliased {
+				alias = lastRustSegment(target)
+			}
+			if alias != first {
+				continue
+			}
+			root, _, _ := strings.Cut(target, "::")
+			return root == "crate" || root == "self" || root == "super"
+		}
+	}
+	return false
+}
+
+// rustFileEnum returns the enum of the file with the given name, or nil
+func rustFileEnum(info *types.RustASTInfo, name string) *types.RustEnumInfo {
+	for _, enum := range info.Enums {
+		if enum != nil && enum.Name == name {
+			return enum
+		}
+	}
+	return nil
+}
+
+// rustSourceText returns the source text of a run of tokens, read from the
+// source lines. It is empty when the run spans several lines.
+func rustSourceText(lines []string, run []types.RustTokenInfo) string {
+	if len(run) == 0 {
+		return ""
+	}
+	first, last := run[0], run[len(run)-1]
+	if first.Line != last.Line || first.Line < 1 || first.Line > len(lines) {
+		return ""
+	}
+	line := lines[first.Line-1]
+	end := last.Column - 1 + len(last.Text)
+	if first.Column < 1 || end > len(line) || first.Column-1 > end {
+		return ""
+	}
+	return line[first.Column-1 : end]
+}
+
+// rustSourceLines returns the source lines from startLine to endLine
+func rustSourceLines(lines []string, startLine, endLine int) string {
+	if startLine < 1 || endLine > len(lines) || startLine > endLine {
+		return ""
+	}
+	return strings.Join(lines[startLine-1:endLine], "\n")
+}
+
+// rustBlockText wraps the text of an arm body in braces unless it is a block
+func rustBlockText(body []types.RustTokenInfo, text string) string {
+	if body[0].Text == "{" && rustGroupEnd(body, 0) == len(body) {
+		return text
+	}
+	return "{ " + text + " }"
+}
@@ -0,0 +1,179 @@
+package violations
+
+import (
+	"strings"
+	"testing"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+func TestRustPatternMatchingDetector_MatchOnBool(t *testing.T) {
+	source := `fn match_on_bool(flag: bool) -> &'static str {
+    match flag {
+        true => "yes",
+        false => "no",
+    }
+}
+
+fn describe(count: usize, verbose: bool) -> String {
+    let label = match count > 1 { false => { "item" } true => "items" };
+    match verbose {
+        true if count > 10 => format!("{} {}", count, label),
+        _ => label.to_string(),
+    }
+}`
+	lines := strings.Split(source, "\n")
+	tokens := rustTestTokens(source)
+
+	detector := NewRustPatternMatchingDetector(nil)
+	violations := detector.detectMatchOnBool("src/lib.rs", tokens, lines)
+	if len(violations) != 2 {
+		t.Fatalf("Expected the two matches on true and false, got %+v", violations)
+	}
+	for i, want := range []struct {
+		line, column, endLine, endColumn int
+		replacement                      string
+	}{
+		{2, 5, 5, 6, `if flag { "yes" } else { "no" }`},
+		{9, 17, 9, 72, `if count > 1 { "items" } else { "item" }`},
+	} {
+		v := violations[i]
+		if v.Line != want.line || v.Column != want.column || v.EndLine != want.endLine || v.EndColumn != want.endColumn ||
+			v.Rule != RustMatchOnBool || v.Type != models.ViolationTypeRustMatchOnBool {
+			t.Errorf("Expected violation %d from %d:%d to %d:%d, got %+v", i, want.line, want.column, want.endLine, want.endColumn, v)
+			continue
+		}
+		if v.Fix == nil || len(v.Fix.Edits) != 1 || v.Fix.Edits[0].NewText != want.replacement {
+			t.Errorf("Expected violation %d to be fixed with %q, got %+v", i, want.replacement, v.Fix)
+		}
+	}
+
+	config := DefaultDetectorConfig()
+	config.RustConfig.EnablePatternMatchCheck = false
+	if violations := NewRustPatternMatchingDetector(config).Detect(&models.FileInfo{Path: "src/lib.rs"}, &types.RustASTInfo{Tokens: tokens}); len(violations) != 0 {
+		t.Errorf("Expected no violations with enable_pattern_match_check off, got %+v", violations)
+	}
+}
+
+func TestRustPatternMatchingDetector_WildcardEnumArms(t *testing.T) {
+	source := `use std::cmp::Ordering;
+use crate::model::{Level, Kind as EventKind};
+
+enum Status {
+    Success,
+    Warning,
+    Error,
+    Critical,
+}
+
+#[non_exhaustive]
+enum Mode {
+    Fast,
+    Safe,
+}
+
+fn non_exhaustive_match(status: Status, mode: Mode, order: Ordering, level: Level, kind: EventKind) {
+    match status {
+        Status::Success => println!("OK"),
+        Status::Error | Status::Critical => println!("Error"),
+        _ => println!("Other"),
+    }
+    match mode {
+        Mode::Fast => println!("fast"),
+        _ => println!("other"),
+    }
+    match order {
+        Ordering::Less => println!("less"),
+        _ => println!("not less"),
+    }
+    match level {
+        Level::Debug => println!("debug"),
+        _ => {}
+    }
+    match kind {
+        EventKind::Click => println!("click"),
+        other if other.is_key() => println!("key"),
+        _ => {}
+    }
+}`
+	info := &types.RustASTInfo{
+		Tokens: rustTestTokens(source),
+		Enums: []*types.RustEnumInfo{
+			{Name: "Status", StartLine: 4, EndLine: 9, StartColumn: 1, VariantCount: 4},
+			{Name: "Mode", StartLine: 11, EndLine: 15, StartColumn: 1, VariantCount: 2},
+		},
+	}
+
+	detector := NewRustPatternMatchingDetector(nil)
+	violations := detector.detectWildcardEnumArms("src/status.rs", info)
+	if len(violations) != 2 {
+		t.Fatalf("Expected the wildcards on Status and Level, got %+v", violations)
+	}
+	if v := violations[0]; v.Line != 21 || v.Column != 9 || v.Rule != RustWildcardEnumArm ||
+		v.Message != "Wildcard arm in a match on enum 'Status' of this crate" || v.Description != "The wildcard covers Warning" {
+		t.Errorf("Unexpected Status violation %+v", v)
+	}
+	if v := violations[1]; v.Line != 33 || v.Description != "" {
+		t.Errorf("Unexpected Level violation %+v", v)
+	}
+
+	config := DefaultDetectorConfig()
+	config.RustConfig.RequireExhaustiveMatch = false
+	if violations := NewRustPatternMatchingDetector(config).Detect(&models.FileInfo{Path: "src/status.rs"}, info); len(violations) != 0 {
+		t.Errorf("Expected no violations with require_exhaustive_match off, got %+v", violations)
+	}
+}
+
+func TestRustPatternMatchingDetector_IfLetLadders(t *testing.T) {
+	source := `fn multiple_if_lets(opt1: Option<i32>, opt2: Option<i32>) -> i32 {
+    if let Some(x) = opt1 {
+        if let Some(y) = opt2 {
+            x + y
+        } else {
+            x
+        }
+    } else if let Some(y) = opt2 {
+        y
+    } else {
+        0
+    }
+}
+
+fn shape(value: &Value, fallback: Option<&str>) -> &str {
+    if let Value::Circle(_) = value {
+        "circle"
+    } else if let Value::Square(_) = value {
+        "square"
+    } else if let Value::Line(_) = value {
+        "line"
+    } else if let Some(name) = fallback {
+        name
+    } else {
+        "unknown"
+    }
+}
+
+fn first(a: Option<i32>, b: Option<i32>) -> i32 {
+    if let Some(x) = a {
+        x
+    } else if let Some(y) = b {
+        y
+    } else {
+        0
+    }
+}`
+	detector := NewRustPatternMatchingDetector(nil)
+	violations := detector.detectIfLetLadders("src/lib.rs", rustTestTokens(source))
+	if len(violations) != 2 {
+		t.Fatalf("Expected the ladders of multiple_if_lets and shape, got %+v", violations)
+	}
+	if v := violations[0]; v.Line != 2 || v.Column != 5 || v.EndLine != 12 || v.Rule != RustIfLetLadder ||
+		v.Message != "if let chain makes 3 tests on opt1 and opt2" || v.Suggestion != "Use a single match on (opt1, opt2) with an arm per case" {
+		t.Errorf("Unexpected violation %+v", v)
+	}
+	if v := violations[1]; v.Line != 16 || v.Message != "if let chain makes 4 tests on value and fallback" ||
+		v.Suggestion != "Use a single match on (value, fallback) with an arm per case" {
+		t.Errorf("Unexpected violation %+v", v)
+	}
+}
@@ -0,0 +1,181 @@
+package violations
+
+import (
+	"fmt"
+	"sort"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+// RustUncheckedPointerArithmetic is the rule of the pointer arithmetic detector
+const RustUncheckedPointerArithmetic = "rust-unchecked-pointer-arithmetic"
+
+// rustPointerOffsetMethods move a raw pointer by a count of elements or bytes
+// without checking that it stays inside its allocation
+var rustPointerOffsetMethods = map[string]bool{
+	"add": true, "sub": true, "offset": true,
+	"byte_add": true, "byte_sub": true, "byte_offset": true,
+}
+
+// rustBoundsOperators compare an offset against a bound or range over one
+var rustBoundsOperators = map[string]bool{"<": true, "<=": true, ">": true, ">=": true, "..": true, "..=": true}
+
+// rustBoundsCalls are macros and methods that check or clamp an offset
+var rustBoundsCalls = map[string]bool{
+	"assert": true, "assert_eq": true, "assert_ne": true,
+	"debug_assert": true, "debug_assert_eq": true, "debug_assert_ne": true,
+	"min": true, "clamp": true,
+}
+
+// rustLengthNames name the length of a buffer in a bounds check
+var rustLengthNames = map[string]bool{"len": true, "capacity": true, "is_empty": true}
+
+// RustPointerArithmeticDetector reports .add() and .offset() calls inside
+// unsafe blocks when nothing earlier in the function compares the offset
+// with a bound. It is a heuristic meant to surface code for manual review:
+// a check it does not recognize, or one made by the caller, is not seen.
+type RustPointerArithmeticDetector struct {
+	config *DetectorConfig
+}
+
+// NewRustPointerArithmeticDetector creates a new Rust pointer arithmetic detector
+func NewRustPointerArithmeticDetector(config *DetectorConfig) *RustPointerArithmeticDetector {
+	if config == nil {
+		config = DefaultDetectorConfig()
+	}
+	return &RustPointerArithmeticDetector{config: config}
+}
+
+// Name returns the name of this detector
+func (d *RustPointerArithmeticDetector) Name() string {
+	return "Rust Pointer Arithmetic Analysis"
+}
+
+// Description returns a description of what this detector checks for
+func (d *RustPointerArithmeticDetector) Description() string {
+	return "Detects raw pointer arithmetic in unsafe blocks without a visible bounds check earlier in the function"
+}
+
+// Detect reports the pointer offsets of a Rust file's unsafe blocks that no
+// preceding comparison, range or assertion in the same function bounds
+func (d *RustPointerArithmeticDetector) Detect(fileInfo *models.FileInfo, astInfo interface{}) []*models.Violation {
+	rust := d.config.RustConfig
+	if rust == nil || !rust.DetectPointerArithmetic {
+		return nil
+	}
+	info, ok := astInfo.(*types.RustASTInfo)
+	if !ok || info == nil || info.Syntax == nil {
+		return nil
+	}
+
+	tokens := info.Tokens
+	var violations []*models.Violation
+	seen := make(map[int]bool)
+	for _, block := range types.FindRustNodes(info.Syntax, types.RustNodeUnsafe) {
+		start := rustTokenIndex(tokens, block.StartLine, block.StartColumn)
+		end := rustTokenIndex(tokens, block.EndLine, block.EndColumn)
+		for i := start; i+2 < end; i++ {
+			if seen[i] || tokens[i].Text != "." || !rustPointerOffsetMethods[tokens[i+1].Text] || tokens[i+2].Text != "(" {
+				continue
+			}
+			seen[i] = true
+
+			fn := innermostRustFunction(info.Functions, tokens[i].Line)
+			from := 0
+			if fn != nil {
+				from = rustTokenIndex(tokens, fn.StartLine, 0)
+			}
+			if hasRustBoundsEvidence(tokens[from:i], tokens[i+3:rustClosingParen(tokens, i+2)]) {
+				continue
+			}
+			violations = append(violations, pointerArithmeticViolation(fileInfo.Path, tokens, i, fn))
+		}
+	}
+	return violations
+}
+
+// pointerArithmeticViolation reports the offset call whose dot is at index i
+func pointerArithmeticViolation(path string, tokens []types.RustTokenInfo, i int, fn *types.RustFunctionInfo) *models.Violation {
+	call := "." + tokens[i+1].Text + "()"
+	if i > 0 && tokens[i-1].Kind == types.RustTokenIdent {
+		call = tokens[i-1].Text + call
+	}
+	message := fmt.Sprintf("Raw pointer arithmetic %s has no visible bounds check", call)
+	if fn != nil {
+		message = fmt.Sprintf("Raw pointer arithmetic %s in '%s' has no visible bounds check", call, fn.Name)
+	}
+	return &models.Violation{
+		Type:     models.ViolationTypeRustRawPointerAbuse,
+		Severity: models.SeverityMedium,
+		Message:  message,
+		File:     path,
+		Line:     tokens[i+1].Line,
+		Column:   tokens[i+1].Column,
+		Rule:     RustUncheckedPointerArithmetic,
+		Suggestion: "Check the offset against the length of the allocation first, with an assert! or an early return, " +
+			"or index a slice so that the bounds are checked for you",
+	}
+}
+
+// hasRustBoundsEvidence reports whether a statement before the call compares
+// one of the names its offset is computed from, such as `if i < len {`,
+// `for i in 0..n {` or `assert!(i < self.len)`. An offset of literals only
+// needs a statement comparing a length, and a remainder is bounded by itself.
+func hasRustBoundsEvidence(before, args []types.RustTokenInfo) bool {
+	names := make(map[string]bool)
+	for k, token := range args {
+		if token.Text == "%" {
+			return true
+		}
+		if token.Kind == types.RustTokenIdent && (k == 0 || args[k-1].Text != "as") {
+			names[token.Text] = true
+		}
+	}
+
+	statement := 0
+	for k := 0; k <= len(before); k++ {
+		if k < len(before) && before[k].Text != ";" && before[k].Text != "{" && before[k].Text != "}" {
+			continue
+		}
+		compares, mentions := false, false
+		for _, token := range before[statement:k] {
+			switch {
+			case rustBoundsOperators[token.Text] || rustBoundsCalls[token.Text]:
+				compares = true
+			case len(names) > 0 && names[token.Text], len(names) == 0 && rustLengthNames[token.Text]:
+				mentions = true
+			}
+		}
+		if compares && mentions {
+			return true
+		}
+		statement = k + 1
+	}
+	return false
+}
+
+// rustTokenIndex returns the index of the first token at or after a position
+func rustTokenIndex(tokens []types.RustTokenInfo, line, column int) int {
+	return sort.Search(len(tokens), func(i int) bool {
+		return tokens[i].Line > line || tokens[i].Line == line && tokens[i].Column >= column
+	})
+}
+
+// rustClosingParen returns the index of the parenthesis closing the one at
+// open, or the token count when it is unclosed
+func rustClosingParen(tokens []types.RustTokenInfo, open int) int {
+	depth := 0
+	for i := open; i < len(tokens); i++ {
+		switch tokens[i].Text {
+		case "(":
+			depth++
+		case ")":
+			depth--
+			if depth == 0 {
+				return i
+			}
+		}
+	}
+	return len(tokens)
+}
@@ -0,0 +1,59 @@
+package violations
+
+import (
+	"testing"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+func TestRustPointerArithmeticDetector(t *testing.T) {
+	source := `fn copy(src: *const u8, dst: *mut u8, len: usize, i: usize) {
+    if i < len {
+        unsafe { *dst.add(i) = *src.add(i); }
+    }
+    let j = i + 1;
+    unsafe { dst.offset(j as isize).write(0); }
+}
+
+fn first(buf: &[u8]) -> u8 {
+    assert!(!buf.is_empty());
+    unsafe { *buf.as_ptr().add(0) }
+}
+
+fn wrap(ptr: *const u8, k: usize, n: usize) -> u8 {
+    unsafe { *ptr.add(1) + *ptr.add(k % n) }
+}`
+	root := &types.RustNode{Kind: types.RustNodeFile, StartLine: 1}
+	for _, block := range [][2]int{{3, 9}, {6, 5}, {11, 5}, {15, 5}} {
+		root.AddChild(&types.RustNode{Kind: types.RustNodeUnsafe, StartLine: block[0], StartColumn: block[1], EndLine: block[0], EndColumn: 80})
+	}
+	astInfo := &types.RustASTInfo{
+		Syntax: root,
+		Tokens: rustTestTokens(source),
+		Functions: []*types.RustFunctionInfo{
+			{Name: "copy", StartLine: 1, EndLine: 7},
+			{Name: "first", StartLine: 9, EndLine: 12},
+			{Name: "wrap", StartLine: 14, EndLine: 16},
+		},
+	}
+
+	config := DefaultDetectorConfig()
+	detector := NewRustPointerArithmeticDetector(config)
+	violations := detector.Detect(&models.FileInfo{Path: "src/copy.rs"}, astInfo)
+	if len(violations) != 2 {
+		t.Fatalf("Expected the unchecked offset of j and the literal offset in wrap, got %+v", violations)
+	}
+	if v := violations[0]; v.Rule != RustUncheckedPointerArithmetic || v.Line != 6 || v.Column != 18 ||
+		v.Message != "Raw pointer arithmetic dst.offset() in 'copy' has no visible bounds check" {
+		t.Errorf("Unexpected offset violation %+v", v)
+	}
+	if v := violations[1]; v.Line != 15 || v.Column != 19 || v.Message != "Raw pointer arithmetic ptr.add() in 'wrap' has no visible bounds check" {
+		t.Errorf("Unexpected add violation %+v", v)
+	}
+
+	config.RustConfig.DetectPointerArithmetic = false
+	if violations := detector.Detect(&models.FileInfo{Path: "src/copy.rs"}, astInfo); len(violations) != 0 {
+		t.Errorf("Expected no violations when the rule is off, got %+v", violations)
+	}
+}
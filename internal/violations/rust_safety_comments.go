@@ -0,0 +1,185 @@
+package violations
+
+import (
+	"fmt"
+	"strings"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+// Rules of the safety comment detector
+const (
+	RustUnsafeWithoutSafetyComment = "rust-unsafe-missing-safety-comment"   // unsafe blocks and unsafe fns
+	RustUnsafeSendSyncImpl         = "rust-unsafe-send-sync-missing-safety" // unsafe impl Send and unsafe impl Sync
+)
+
+// DefaultSafetyCommentMarker is the text a comment above unsafe code must contain
+const DefaultSafetyCommentMarker = "SAFETY:"
+
+// RustSafetyCommentDetector reports unsafe blocks and unsafe functions that
+// are not preceded by a comment with the safety marker, as in
+// `// SAFETY: the index was checked above`. An unsafe function may instead
+// document its contract in a `# Safety` section of its doc comments.
+// Undocumented `unsafe impl Send` and `unsafe impl Sync` have a rule of their
+// own, as a wrong one makes every use of the type across threads unsound.
+type RustSafetyCommentDetector struct {
+	config *DetectorConfig
+}
+
+// NewRustSafetyCommentDetector creates a new Rust safety comment detector
+func NewRustSafetyCommentDetector(config *DetectorConfig) *RustSafetyCommentDetector {
+	if config == nil {
+		config = DefaultDetectorConfig()
+	}
+	return &RustSafetyCommentDetector{config: config}
+}
+
+// Name returns the name of this detector
+func (d *RustSafetyCommentDetector) Name() string {
+	return "Rust Safety Comment Analysis"
+}
+
+// Description returns a description of what this detector checks for
+func (d *RustSafetyCommentDetector) Description() string {
+	return "Detects unsafe blocks, unsafe functions and unsafe Send or Sync impls without a SAFETY comment explaining why they are sound"
+}
+
+// Detect reports the unsafe blocks, functions and Send or Sync impls of a
+// Rust file that no safety comment justifies, with the span of each
+func (d *RustSafetyCommentDetector) Detect(fileInfo *models.FileInfo, astInfo interface{}) []*models.Violation {
+	rust := d.config.RustConfig
+	if rust == nil || !rust.RequireUnsafeComments {
+		return nil
+	}
+	info, ok := astInfo.(*types.RustASTInfo)
+	if !ok || info == nil || info.Syntax == nil {
+		return nil
+	}
+	marker := rust.SafetyCommentMarker
+	if marker == "" {
+		marker = DefaultSafetyCommentMarker
+	}
+
+	var violations []*models.Violation
+	types.InspectRust(info.Syntax, func(node *types.RustNode) bool {
+		if trait := rustThreadSafetyTrait(node, info.Impls); trait != "" {
+			if !HasRustSafetyComment(node, info.Comments, marker) {
+				violations = append(violations, sendSyncViolation(fileInfo.Path, node, trait, marker))
+			}
+			return true
+		}
+
+		var message string
+		switch {
+		case node.Kind == types.RustNodeUnsafe:
+			message = "Unsafe block has no " + marker + " comment"
+			if fn := node.EnclosingItem(types.RustNodeFunction); fn != nil {
+				message = fmt.Sprintf("Unsafe block in '%s' has no %s comment", fn.Name, marker)
+			}
+		case isRustUnsafeFunction(node):
+			message = fmt.Sprintf("Unsafe function '%s' has no %s comment or # Safety section", node.Name, marker)
+		default:
+			return true
+		}
+		if HasRustSafetyComment(node, info.Comments, marker) {
+			return true
+		}
+
+		violations = append(violations, &models.Violation{
+			Type:      models.ViolationTypeRustUnsafeWithoutComment,
+			Severity:  models.SeverityMedium,
+			Message:   message,
+			File:      fileInfo.Path,
+			Line:      node.StartLine,
+			Column:    node.StartColumn,
+			EndLine:   node.EndLine,
+			EndColumn: node.EndColumn,
+			Rule:      RustUnsafeWithoutSafetyComment,
+			Suggestion: fmt.Sprintf("Add a `// %s` comment right above it stating the invariants that make it sound "+
+				"and why they hold here", marker),
+		})
+		return true
+	})
+	return violations
+}
+
+// sendSyncViolation reports an unsafe impl of Send or Sync without a safety comment
+func sendSyncViolation(path string, node *types.RustNode, trait, marker string) *models.Violation {
+	promise := "can be moved to another thread"
+	if trait == "Sync" {
+		promise = "can be shared between threads"
+	}
+	return &models.Violation{
+		Type:      models.ViolationTypeRustUnsafeWithoutComment,
+		Severity:  models.SeverityHigh,
+		Message:   fmt.Sprintf("unsafe impl %s for '%s' has no %s comment", trait, node.Name, marker),
+		File:      path,
+		Line:      node.StartLine,
+		Column:    node.StartColumn,
+		EndLine:   node.EndLine,
+		EndColumn: node.EndColumn,
+		Rule:      RustUnsafeSendSyncImpl,
+		Suggestion: fmt.Sprintf("Add a `// %s` comment explaining why '%s' %s, such as what owns its raw pointers "+
+			"and what synchronizes access to them", marker, node.Name, promise),
+	}
+}
+
+// rustThreadSafetyTrait returns "Send" or "Sync" when a node is an unsafe
+// impl of that trait, looking its trait up among the impls of the file
+func rustThreadSafetyTrait(node *types.RustNode, impls []*types.RustImplInfo) string {
+	if node.Kind != types.RustNodeImpl || !node.HasModifier("unsafe") {
+		return ""
+	}
+	for _, impl := range impls {
+		if impl == nil || impl.StartLine != node.StartLine || impl.StartColumn != node.StartColumn {
+			continue
+		}
+		if trait := lastRustSegment(impl.TraitName); trait == "Send" || trait == "Sync" {
+			return trait
+		}
+	}
+	return ""
+}
+
+// isRustUnsafeFunction reports whether a node declares an unsafe fn; the
+// functions of an extern block are declarations of foreign code
+func isRustUnsafeFunction(node *types.RustNode) bool {
+	return node.Kind == types.RustNodeFunction && node.HasModifier("unsafe") &&
+		(node.Parent == nil || node.Parent.Kind != types.RustNodeExternBlock)
+}
+
+// HasRustSafetyComment reports whether the comments right above a node, or
+// before it on its first line, contain the marker, regardless of case. An
+// empty marker uses DefaultSafetyCommentMarker. Attributes may separate the
+// comments from an item, and an unsafe function or impl may carry a # Safety
+// section in its doc comments instead.
+func HasRustSafetyComment(node *types.RustNode, comments []*types.RustCommentInfo, marker string) bool {
+	if marker == "" {
+		marker = DefaultSafetyCommentMarker
+	}
+	marker = strings.ToUpper(marker)
+	above := node.StartLine - len(node.Attributes) - 1
+	for i := len(comments) - 1; i >= 0; i-- {
+		comment := comments[i]
+		sameLine := comment.Line == node.StartLine && comment.Column < node.StartColumn
+		switch {
+		case sameLine || comment.EndLine == above:
+		case comment.Line >= node.StartLine:
+			continue
+		default:
+			return false
+		}
+		if strings.Contains(strings.ToUpper(comment.Text), marker) {
+			return true
+		}
+		documented := node.Kind == types.RustNodeFunction || node.Kind == types.RustNodeImpl
+		if documented && comment.IsDoc && strings.Contains(comment.Text, "# Safety") {
+			return true
+		}
+		if !sameLine {
+			above = comment.Line - 1
+		}
+	}
+	return false
+}
@@ -0,0 +1,119 @@
+package violations
+
+import (
+	"testing"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+func TestRustSafetyCommentDetector(t *testing.T) {
+	// 1  fn read(ptr: *const u8) -> u8 {
+	// 2      // SAFETY: the caller passes a valid pointer
+	// 3      let a = unsafe { *ptr };
+	// 4
+	// 5      // Reads the next byte
+	// 6      let b = unsafe { *ptr.add(1) };
+	// 7      a + b
+	// 8  }
+	// 9
+	// 10 /// Reads a byte.
+	// 11 ///
+	// 12 /// # Safety
+	// 13 /// `ptr` must be valid for reads.
+	// 14 #[inline]
+	// 15 pub unsafe fn read_raw(ptr: *const u8) -> u8 { *ptr }
+	// 16
+	// 17 // Safety: checked by the caller
+	// 18
+	// 19 unsafe fn write_raw(ptr: *mut u8) { /* SAFETY: inside */ unsafe { *ptr = 0 } }
+	// 20
+	// 21 extern "C" { fn abs(x: i32) -> i32; }
+	root := &types.RustNode{Kind: types.RustNodeFile, StartLine: 1}
+	read := &types.RustNode{Kind: types.RustNodeFunction, Name: "read", StartLine: 1, EndLine: 8}
+	read.AddChild(&types.RustNode{Kind: types.RustNodeUnsafe, StartLine: 3, StartColumn: 13, EndLine: 3, EndColumn: 27})
+	read.AddChild(&types.RustNode{Kind: types.RustNodeUnsafe, StartLine: 6, StartColumn: 13, EndLine: 6, EndColumn: 35})
+	root.AddChild(read)
+	root.AddChild(&types.RustNode{Kind: types.RustNodeFunction, Name: "read_raw", StartLine: 15, StartColumn: 1, EndLine: 15,
+		Modifiers: []string{"pub", "unsafe"}, Attributes: []string{"inline"}})
+	write := &types.RustNode{Kind: types.RustNodeFunction, Name: "write_raw", StartLine: 19, StartColumn: 1, EndLine: 19, EndColumn: 80,
+		Modifiers: []string{"unsafe"}}
+	write.AddChild(&types.RustNode{Kind: types.RustNodeUnsafe, StartLine: 19, StartColumn: 59, EndLine: 19, EndColumn: 78})
+	root.AddChild(write)
+	extern := &types.RustNode{Kind: types.RustNodeExternBlock, StartLine: 21, EndLine: 21}
+	extern.AddChild(&types.RustNode{Kind: types.RustNodeFunction, Name: "abs", StartLine: 21, Modifiers: []string{"unsafe"}})
+	root.AddChild(extern)
+
+	astInfo := &types.RustASTInfo{
+		Syntax: root,
+		Comments: []*types.RustCommentInfo{
+			{Text: "// SAFETY: the caller passes a valid pointer", Line: 2, EndLine: 2, Column: 5},
+			{Text: "// Reads the next byte", Line: 5, EndLine: 5, Column: 5},
+			{Text: "/// Reads a byte.", Line: 10, EndLine: 10, Column: 1, IsDoc: true},
+			{Text: "///", Line: 11, EndLine: 11, Column: 1, IsDoc: true},
+			{Text: "/// # Safety", Line: 12, EndLine: 12, Column: 1, IsDoc: true},
+			{Text: "/// `ptr` must be valid for reads.", Line: 13, EndLine: 13, Column: 1, IsDoc: true},
+			{Text: "// Safety: checked by the caller", Line: 17, EndLine: 17, Column: 1},
+			{Text: "/* SAFETY: inside */", Line: 19, EndLine: 19, Column: 38, IsBlock: true},
+		},
+	}
+
+	config := DefaultDetectorConfig()
+	detector := NewRustSafetyCommentDetector(config)
+	violations := detector.Detect(&models.FileInfo{Path: "src/raw.rs"}, astInfo)
+	if len(violations) != 2 {
+		t.Fatalf("Expected the undocumented block and function, got %+v", violations)
+	}
+	block := violations[0]
+	if block.Rule != RustUnsafeWithoutSafetyComment || block.Line != 6 || block.Column != 13 || block.EndLine != 6 || block.EndColumn != 35 ||
+		block.Message != "Unsafe block in 'read' has no SAFETY: comment" {
+		t.Errorf("Unexpected block violation %+v", block)
+	}
+	if fn := violations[1]; fn.Line != 19 || fn.Message != "Unsafe function 'write_raw' has no SAFETY: comment or # Safety section" {
+		t.Errorf("Expected a blank line to separate write_raw from its comment, got %+v", fn)
+	}
+
+	config.RustConfig.SafetyCommentMarker = "Reads the next"
+	violations = detector.Detect(&models.FileInfo{Path: "src/raw.rs"}, astInfo)
+	if len(violations) != 3 || violations[0].Line != 3 {
+		t.Errorf("Expected the configured marker to replace SAFETY:, got %+v", violations)
+	}
+
+	config.RustConfig.RequireUnsafeComments = false
+	if violations := detector.Detect(&models.FileInfo{Path: "src/raw.rs"}, astInfo); len(violations) != 0 {
+		t.Errorf("Expected no violations when safety comments are not required, got %+v", violations)
+	}
+}
+
+func TestRustSafetyCommentDetector_SendSync(t *testing.T) {
+	// 1 // SAFETY: the pointer is owned and only accessed through &mut self
+	// 2 unsafe impl Send for Buffer {}
+	// 3 unsafe impl std::marker::Sync for Buffer {}
+	// 4 unsafe impl GlobalAlloc for Arena {}
+	root := &types.RustNode{Kind: types.RustNodeFile, StartLine: 1}
+	for line := 2; line <= 4; line++ {
+		root.AddChild(&types.RustNode{Kind: types.RustNodeImpl, Name: "Buffer", StartLine: line, StartColumn: 1, EndLine: line, EndColumn: 30,
+			Modifiers: []string{"unsafe"}})
+	}
+	astInfo := &types.RustASTInfo{
+		Syntax: root,
+		Impls: []*types.RustImplInfo{
+			{StartLine: 2, StartColumn: 1, TargetType: "Buffer", TraitName: "Send"},
+			{StartLine: 3, StartColumn: 1, TargetType: "Buffer", TraitName: "std::marker::Sync"},
+			{StartLine: 4, StartColumn: 1, TargetType: "Arena", TraitName: "GlobalAlloc"},
+		},
+		Comments: []*types.RustCommentInfo{
+			{Text: "// SAFETY: the pointer is owned and only accessed through &mut self", Line: 1, EndLine: 1, Column: 1},
+		},
+	}
+
+	violations := NewRustSafetyCommentDetector(DefaultDetectorConfig()).Detect(&models.FileInfo{Path: "src/buffer.rs"}, astInfo)
+	if len(violations) != 1 {
+		t.Fatalf("Expected only the undocumented Sync impl, got %+v", violations)
+	}
+	v := violations[0]
+	if v.Rule != RustUnsafeSendSyncImpl || v.Line != 3 || v.Severity != models.SeverityHigh ||
+		v.Message != "unsafe impl Sync for 'Buffer' has no SAFETY: comment" {
+		t.Errorf("Unexpected Sync violation %+v", v)
+	}
+}
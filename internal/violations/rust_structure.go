@@ -4,6 +4,7 @@ package violations
 import (
 	"fmt"
 	"regexp"
+	"sort"
 	"strings"
 
 	"github.com/ericfisherdev/goclean/internal/models"
@@ -16,8 +17,12 @@ const (
 	RustMaxTraitMethods     = 8   // Maximum number of methods in a trait
 	RustMaxImplMethods      = 20  // Maximum number of methods in an impl block
 	RustMaxEnumVariants     = 15  // Maximum number of variants in an enum
+	RustMaxErrorVariants    = 10  // Maximum number of variants in an error enum
+	RustMaxTupleArity       = 2   // Maximum number of elements in a returned tuple or a tuple field
 	RustMaxModuleItems      = 50  // Maximum number of items in a module
+	RustMaxModuleDepth      = 5   // Maximum nesting of a module below the crate root
 	RustMaxFileLines        = 1000 // Maximum lines in a single file
+	RustImplInventorySize   = 10  // Methods listed in the report of a large impl block
 	
 	// Severity calculation thresholds
 	RustHighComplexityThreshold   = 2.0  // Multiplier for high severity
@@ -92,6 +97,9 @@ func (d *RustStructureDetector) Detect(fileInfo *models.FileInfo, astInfo interf
 	// Check struct complexity
 	violations = append(violations, d.checkStructComplexity(rustAstInfo, fileInfo.Path)...)
 
+	// Check tuples that should be structs with named fields
+	violations = append(violations, d.checkLongTuples(rustAstInfo, fileInfo.Path)...)
+
 	// Check enum complexity
 	violations = append(violations, d.checkEnumComplexity(rustAstInfo, fileInfo.Path)...)
 
@@ -104,6 +112,9 @@ func (d *RustStructureDetector) Detect(fileInfo *models.FileInfo, astInfo interf
 	// Check module organization
 	violations = append(violations, d.checkModuleOrganization(rustAstInfo, fileInfo.Path, content)...)
 
+	// Check module nesting depth
+	violations = append(violations, d.checkModuleDepth(rustAstInfo, fileInfo.Path)...)
+
 	return violations
 }
 
@@ -190,6 +201,22 @@ func (d *RustStructureDetector) checkStructComplexity(rustAstInfo *types.RustAST
 			})
 		}
 
+		// Check for a god struct with too many fields and methods
+		if godStructEnabled(d.config) {
+			s := godStruct{
+				name:      structInfo.Name,
+				fields:    rustStructFieldNames(rustAstInfo.Tokens, structInfo),
+				methods:   rustInherentMethodCount(rustAstInfo.Impls, structInfo.Name),
+				line:      structInfo.StartLine,
+				column:    structInfo.StartColumn,
+				endLine:   structInfo.EndLine,
+				endColumn: structInfo.EndColumn,
+			}
+			if violation := godStructViolation(d.config, s, RustGodStruct, filePath); violation != nil {
+				violations = append(violations, violation)
+			}
+		}
+
 		// Check if struct is too long (lines)
 		structLines := structInfo.EndLine - structInfo.StartLine + 1
 		if structLines > d.config.MaxClassLines {
@@ -218,22 +245,35 @@ func (d *RustStructureDetector) checkEnumComplexity(rustAstInfo *types.RustASTIn
 		return violations
 	}
 
+	maxVariants, skipAttributes := d.enumLimits()
 	for _, enumInfo := range rustAstInfo.Enums {
 		if enumInfo == nil {
 			continue
 		}
 
-		// Check variant count
-		if enumInfo.VariantCount > RustMaxEnumVariants {
+		keyword := rustEnumKeyword(rustAstInfo.Tokens, enumInfo)
+		generated := hasSkippedAttribute(rustAstInfo.Tokens, keyword, skipAttributes)
+		isError := !generated && isRustErrorEnum(rustAstInfo.Tokens, keyword, enumInfo.Name)
+		if isError {
+			violations = append(violations, d.checkErrorEnum(rustAstInfo.Tokens, keyword, enumInfo, filePath)...)
+		}
+
+		// Check variant count, unless the enum is generated or already
+		// reported as an error enum
+		if enumInfo.VariantCount > maxVariants && !generated && !(isError && enumInfo.VariantCount > d.errorEnumLimit()) {
+			suggestion := "Consider splitting large enum into smaller enums or using trait objects for behavior-based variants"
+			if keyword >= 0 {
+				suggestion = enumSplitSuggestion(enumInfo.Name, rustEnumVariantNames(rustAstInfo.Tokens, keyword))
+			}
 			violations = append(violations, &models.Violation{
 				Type:        models.ViolationTypeClassSize,
-				Severity:    d.getRustEnumComplexitySeverity(enumInfo.VariantCount),
-				Message:     fmt.Sprintf("Enum '%s' has too many variants (%d, max: %d)", enumInfo.Name, enumInfo.VariantCount, RustMaxEnumVariants),
+				Severity:    d.getRustEnumComplexitySeverity(enumInfo.VariantCount, maxVariants),
+				Message:     fmt.Sprintf("Enum '%s' has too many variants (%d, max: %d)", enumInfo.Name, enumInfo.VariantCount, maxVariants),
 				File:        filePath,
 				Line:        enumInfo.StartLine,
 				Column:      enumInfo.StartColumn,
 				Rule:        "rust-enum-complexity",
-				Suggestion:  "Consider splitting large enum into smaller enums or using trait objects for behavior-based variants",
+				Suggestion:  suggestion,
 				CodeSnippet: fmt.Sprintf("enum %s { /* %d variants */ }", enumInfo.Name, enumInfo.VariantCount),
 			})
 		}
@@ -258,6 +298,50 @@ func (d *RustStructureDetector) checkEnumComplexity(rustAstInfo *types.RustASTIn
 	return violations
 }
 
+// checkErrorEnum reports an error enum with more variants than an error type
+// should have, and one whose variants carry nothing but message strings
+func (d *RustStructureDetector) checkErrorEnum(tokens []types.RustTokenInfo, keyword int, enumInfo *types.RustEnumInfo, filePath string) []*models.Violation {
+	var violations []*models.Violation
+
+	var variants []string
+	if keyword >= 0 {
+		variants = rustEnumVariantNames(tokens, keyword)
+	}
+	if maxVariants := d.errorEnumLimit(); enumInfo.VariantCount > maxVariants {
+		violations = append(violations, &models.Violation{
+			Type:        models.ViolationTypeClassSize,
+			Severity:    d.getRustEnumComplexitySeverity(enumInfo.VariantCount, maxVariants),
+			Message:     fmt.Sprintf("Error enum '%s' has too many variants (%d, max: %d)", enumInfo.Name, enumInfo.VariantCount, maxVariants),
+			Description: "Callers of a function returning it must handle failures the function cannot produce",
+			File:        filePath,
+			Line:        enumInfo.StartLine,
+			Column:      enumInfo.StartColumn,
+			Rule:        "rust-error-enum-size",
+			Suggestion:  errorEnumSplitSuggestion(enumInfo.Name, variants),
+			CodeSnippet: fmt.Sprintf("enum %s { /* %d variants */ }", enumInfo.Name, enumInfo.VariantCount),
+		})
+	}
+
+	if keyword < 0 {
+		return violations
+	}
+	if stringly := rustStringOnlyVariants(tokens, keyword); len(stringly) >= 2 {
+		violations = append(violations, &models.Violation{
+			Type:        models.ViolationTypeRustInconsistentErrorType,
+			Severity:    models.SeverityLow,
+			Message:     fmt.Sprintf("Error enum '%s' has %d variants carrying only a String", enumInfo.Name, len(stringly)),
+			Description: fmt.Sprintf("Variants: %s", strings.Join(stringly, ", ")),
+			File:        filePath,
+			Line:        enumInfo.StartLine,
+			Column:      enumInfo.StartColumn,
+			Rule:        "rust-error-string-variants",
+			Suggestion: "Give the variants typed fields, such as the source error wrapped with #[from] or the value that failed, " +
+				"so that callers can act on the failure without parsing its message",
+		})
+	}
+	return violations
+}
+
 // checkTraitComplexity analyzes trait complexity
 func (d *RustStructureDetector) checkTraitComplexity(rustAstInfo *types.RustASTInfo, filePath string) []*models.Violation {
 	var violations []*models.Violation
@@ -306,7 +390,9 @@ func (d *RustStructureDetector) checkTraitComplexity(rustAstInfo *types.RustASTI
 	return violations
 }
 
-// checkImplComplexity analyzes implementation block complexity
+// checkImplComplexity analyzes implementation block complexity. An impl
+// block is measured as a whole, so one made of many short methods is
+// reported even when none of them is long.
 func (d *RustStructureDetector) checkImplComplexity(rustAstInfo *types.RustASTInfo, filePath string) []*models.Violation {
 	var violations []*models.Violation
 
@@ -314,13 +400,20 @@ func (d *RustStructureDetector) checkImplComplexity(rustAstInfo *types.RustASTIn
 		return violations
 	}
 
+	maxMethods, maxLines := d.implLimits()
 	for _, implInfo := range rustAstInfo.Impls {
 		if implInfo == nil {
 			continue
 		}
 
+		implLines := implInfo.EndLine - implInfo.StartLine + 1
+		if implInfo.MethodCount <= maxMethods && implLines <= maxLines {
+			continue
+		}
+		inventory := implMethodInventory(rustAstInfo.Functions, implInfo)
+
 		// Check method count
-		if implInfo.MethodCount > RustMaxImplMethods {
+		if implInfo.MethodCount > maxMethods {
 			implType := "impl"
 			if implInfo.TraitName != "" {
 				implType = fmt.Sprintf("impl %s for", implInfo.TraitName)
@@ -328,29 +421,28 @@ func (d *RustStructureDetector) checkImplComplexity(rustAstInfo *types.RustASTIn
 
 			violations = append(violations, &models.Violation{
 				Type:        models.ViolationTypeClassSize,
-				Severity:    d.getRustImplComplexitySeverity(implInfo.MethodCount),
-				Message:     fmt.Sprintf("%s %s has too many methods (%d, max: %d)", implType, implInfo.TargetType, implInfo.MethodCount, RustMaxImplMethods),
+				Severity:    d.getRustImplComplexitySeverity(implInfo.MethodCount, maxMethods),
+				Message:     fmt.Sprintf("%s %s has too many methods (%d, max: %d)", implType, implInfo.TargetType, implInfo.MethodCount, maxMethods),
 				File:        filePath,
 				Line:        implInfo.StartLine,
 				Column:      implInfo.StartColumn,
 				Rule:        "rust-impl-complexity",
-				Suggestion:  "Consider splitting large implementation into multiple impl blocks or extracting functionality into separate modules",
+				Suggestion:  inventory + "Consider splitting large implementation into multiple impl blocks or extracting functionality into separate modules",
 				CodeSnippet: fmt.Sprintf("%s %s { /* %d methods */ }", implType, implInfo.TargetType, implInfo.MethodCount),
 			})
 		}
 
 		// Check if impl block is too long
-		implLines := implInfo.EndLine - implInfo.StartLine + 1
-		if implLines > d.config.MaxClassLines {
+		if implLines > maxLines {
 			violations = append(violations, &models.Violation{
 				Type:        models.ViolationTypeClassSize,
 				Severity:    models.SeverityMedium,
-				Message:     fmt.Sprintf("Implementation for '%s' is too long (%d lines)", implInfo.TargetType, implLines),
+				Message:     fmt.Sprintf("Implementation for '%s' is too long (%d lines, max: %d)", implInfo.TargetType, implLines, maxLines),
 				File:        filePath,
 				Line:        implInfo.StartLine,
 				Column:      implInfo.StartColumn,
 				Rule:        "rust-impl-length",
-				Suggestion:  "Consider breaking down large implementation into smaller, more focused impl blocks",
+				Suggestion:  inventory + "Consider breaking down large implementation into smaller, more focused impl blocks",
 				CodeSnippet: fmt.Sprintf("impl %s spans %d lines", implInfo.TargetType, implLines),
 			})
 		}
@@ -359,6 +451,70 @@ func (d *RustStructureDetector) checkImplComplexity(rustAstInfo *types.RustASTIn
 	return violations
 }
 
+// enumLimits returns the configured variants allowed in an enum and the
+// attributes of the generated enums that are not counted
+func (d *RustStructureDetector) enumLimits() (maxVariants int, skipAttributes []string) {
+	maxVariants, skipAttributes = RustMaxEnumVariants, DefaultSkipEnumAttributes()
+	if rust := d.config.RustConfig; rust != nil {
+		maxVariants = positiveOr(rust.MaxEnumVariants, RustMaxEnumVariants)
+		if rust.SkipEnumAttributes != nil {
+			skipAttributes = rust.SkipEnumAttributes
+		}
+	}
+	return maxVariants, skipAttributes
+}
+
+// errorEnumLimit returns the configured variants allowed in an error enum
+func (d *RustStructureDetector) errorEnumLimit() int {
+	if rust := d.config.RustConfig; rust != nil {
+		return positiveOr(rust.MaxErrorVariants, RustMaxErrorVariants)
+	}
+	return RustMaxErrorVariants
+}
+
+// implLimits returns the configured methods and lines allowed in an impl
+// block. Without a line limit for impl blocks, the class line limit applies.
+func (d *RustStructureDetector) implLimits() (maxMethods, maxLines int) {
+	maxMethods, maxLines = RustMaxImplMethods, d.config.MaxClassLines
+	if rust := d.config.RustConfig; rust != nil {
+		maxMethods = positiveOr(rust.MaxImplMethods, RustMaxImplMethods)
+		maxLines = positiveOr(rust.MaxImplLines, d.config.MaxClassLines)
+	}
+	return maxMethods, maxLines
+}
+
+// implMethodInventory lists the methods of an impl block, longest first, so
+// the report shows what could move out. Functions nested in a method are
+// part of it. Returns "" when the methods are unknown.
+func implMethodInventory(functions []*types.RustFunctionInfo, implInfo *types.RustImplInfo) string {
+	var methods []*types.RustFunctionInfo
+	for _, fn := range functions {
+		if fn == nil || fn.StartLine <= implInfo.StartLine || fn.EndLine > implInfo.EndLine {
+			continue
+		}
+		if n := len(methods); n > 0 && fn.StartLine <= methods[n-1].EndLine {
+			continue
+		}
+		methods = append(methods, fn)
+	}
+	if len(methods) == 0 {
+		return ""
+	}
+
+	sort.SliceStable(methods, func(i, j int) bool {
+		return methods[i].EndLine-methods[i].StartLine > methods[j].EndLine-methods[j].StartLine
+	})
+	listed := make([]string, 0, RustImplInventorySize)
+	for _, method := range methods[:min(len(methods), RustImplInventorySize)] {
+		listed = append(listed, fmt.Sprintf("%s (%d lines)", method.Name, method.EndLine-method.StartLine+1))
+	}
+	inventory := "Methods by length: " + strings.Join(listed, ", ")
+	if hidden := len(methods) - len(listed); hidden > 0 {
+		inventory += fmt.Sprintf(" and %d more", hidden)
+	}
+	return inventory + ". "
+}
+
 // checkModuleOrganization analyzes module organization and structure
 func (d *RustStructureDetector) checkModuleOrganization(rustAstInfo *types.RustASTInfo, filePath, content string) []*models.Violation {
 	var violations []*models.Violation
@@ -403,6 +559,48 @@ func (d *RustStructureDetector) checkModuleOrganization(rustAstInfo *types.RustA
 	return violations
 }
 
+// checkModuleDepth reports the innermost modules nested deeper below the
+// crate root than allowed, counting the directories below src and the inline
+// modules of the file. A file without inline modules is reported itself.
+func (d *RustStructureDetector) checkModuleDepth(rustAstInfo *types.RustASTInfo, filePath string) []*models.Violation {
+	var violations []*models.Violation
+
+	maxDepth := RustMaxModuleDepth
+	if rust := d.config.RustConfig; rust != nil {
+		maxDepth = positiveOr(rust.MaxModuleDepth, RustMaxModuleDepth)
+	}
+	crate := strings.SplitN(rustModulePath(filePath), "::", 2)[0]
+	fileSegments := rustFileModuleSegments(filePath)
+
+	report := func(segments []string, line, column int) {
+		path := strings.Join(append([]string{crate}, segments...), "::")
+		violations = append(violations, &models.Violation{
+			Type:     models.ViolationTypeStructure,
+			Severity: models.SeverityLow,
+			Message:  fmt.Sprintf("Module '%s' is nested too deeply (%d levels, max: %d)", path, len(segments), maxDepth),
+			File:     filePath,
+			Line:     line,
+			Column:   column,
+			Rule:     "rust-module-nesting",
+			Suggestion: fmt.Sprintf("Flatten the module tree: move '%s' closer to the crate root, "+
+				"or re-export its items with `pub use` from a shallower module", segments[len(segments)-1]),
+		})
+	}
+
+	modules := rustInlineModules(rustAstInfo.Tokens)
+	if len(modules) == 0 && len(fileSegments) > maxDepth {
+		report(fileSegments, 1, 1)
+	}
+	for _, module := range modules {
+		segments := append(append([]string(nil), fileSegments...), module.path...)
+		if module.leaf && len(segments) > maxDepth {
+			report(segments, module.line, module.column)
+		}
+	}
+
+	return violations
+}
+
 // OrganizationIssue represents an issue with code organization
 type OrganizationIssue struct {
 	message    string
@@ -575,11 +773,14 @@ func (d *RustStructureDetector) getRustStructComplexitySeverity(fieldCount int)
 	return models.SeverityLow
 }
 
-func (d *RustStructureDetector) getRustEnumComplexitySeverity(variantCount int) models.Severity {
-	if variantCount > 30 { // RustMaxEnumVariants (15) * 2.0
+func (d *RustStructureDetector) getRustEnumComplexitySeverity(variantCount, maxVariants int) models.Severity {
+	highThreshold := int(float64(maxVariants) * RustHighComplexityThreshold)
+	mediumThreshold := int(float64(maxVariants) * RustMediumComplexityThreshold)
+	
+	if variantCount > highThreshold {
 		return models.SeverityHigh
 	}
-	if variantCount > 22 { // RustMaxEnumVariants (15) * 1.5 (rounded)
+	if variantCount > mediumThreshold {
 		return models.SeverityMedium
 	}
 	return models.SeverityLow
@@ -598,9 +799,9 @@ func (d *RustStructureDetector) getRustTraitComplexitySeverity(methodCount int)
 	return models.SeverityLow
 }
 
-func (d *RustStructureDetector) getRustImplComplexitySeverity(methodCount int) models.Severity {
-	highThreshold := int(float64(RustMaxImplMethods) * RustHighComplexityThreshold)
-	mediumThreshold := int(float64(RustMaxImplMethods) * RustMediumComplexityThreshold)
+func (d *RustStructureDetector) getRustImplComplexitySeverity(methodCount, maxMethods int) models.Severity {
+	highThreshold := int(float64(maxMethods) * RustHighComplexityThreshold)
+	mediumThreshold := int(float64(maxMethods) * RustMediumComplexityThreshold)
 	
 	if methodCount > highThreshold {
 		return models.SeverityHigh
@@ -1,6 +1,7 @@
 package violations
 
 import (
+	"reflect"
 	"strings"
 	"testing"
 
@@ -484,6 +485,270 @@ func TestRustStructureDetector_checkLargeImpl(t *testing.T) {
 	}
 }
 
+// TestRustStructureDetector_EnumVariantLimit tests the configured variant limit, generated enums and the split suggestion
+func TestRustStructureDetector_EnumVariantLimit(t *testing.T) {
+	source := `#[derive(Debug, Clone)]
+pub enum ApplicationEvent {
+    UserCreated(UserId),
+    UserDeleted { id: UserId },
+    #[serde(rename = "order")]
+    OrderPlaced,
+    OrderShipped,
+    OrderCancelled = 5,
+    Shutdown,
+}
+
+#[derive(Debug, strum::EnumIter)]
+pub(crate) enum Country {
+    Austria,
+    Belgium,
+    Chile,
+    Denmark,
+}`
+	rustAstInfo := &types.RustASTInfo{
+		Tokens: rustTestTokens(source),
+		Enums: []*types.RustEnumInfo{
+			{Name: "ApplicationEvent", StartLine: 2, EndLine: 10, StartColumn: 1, VariantCount: 6},
+			{Name: "Country", StartLine: 13, EndLine: 18, StartColumn: 1, VariantCount: 4},
+		},
+	}
+
+	keyword := rustEnumKeyword(rustAstInfo.Tokens, rustAstInfo.Enums[0])
+	expected := []string{"UserCreated", "UserDeleted", "OrderPlaced", "OrderShipped", "OrderCancelled", "Shutdown"}
+	if variants := rustEnumVariantNames(rustAstInfo.Tokens, keyword); !reflect.DeepEqual(variants, expected) {
+		t.Errorf("Expected variants %v, got %v", expected, variants)
+	}
+
+	config := DefaultDetectorConfig()
+	config.RustConfig.MaxEnumVariants = 3
+	violations := NewRustStructureDetector(config).checkEnumComplexity(rustAstInfo, "events.rs")
+	if len(violations) != 1 {
+		t.Fatalf("Expected the generated enum to be skipped, got %+v", violations)
+	}
+	violation := violations[0]
+	if violation.Message != "Enum 'ApplicationEvent' has too many variants (6, max: 3)" || violation.Severity != models.SeverityMedium {
+		t.Errorf("Unexpected violation %+v", violation)
+	}
+	if violation.Suggestion != "Its variants fall into 2 domains: order (3), user (2). Consider one enum per domain, wrapped by variants of 'ApplicationEvent'" {
+		t.Errorf("Unexpected suggestion %q", violation.Suggestion)
+	}
+
+	config.RustConfig.SkipEnumAttributes = []string{}
+	if violations := NewRustStructureDetector(config).checkEnumComplexity(rustAstInfo, "events.rs"); len(violations) != 2 {
+		t.Errorf("Expected both enums without skipped attributes, got %+v", violations)
+	}
+}
+
+// TestRustStructureDetector_ErrorEnums tests the variant limit of error enums and String-only variants
+func TestRustStructureDetector_ErrorEnums(t *testing.T) {
+	source := `#[derive(Debug, thiserror::Error)]
+pub enum Failure {
+    #[error("config: {0}")]
+    ConfigMissing(String),
+    ConfigInvalid { message: String },
+    DbConnect(#[from] sqlx::Error),
+    DbQuery(std::string::String),
+    NetTimeout { after: Duration, host: String },
+    NetDown,
+}
+
+#[derive(Debug)]
+pub enum StoreError {
+    Io(std::io::Error),
+    Corrupt(String),
+}`
+	rustAstInfo := &types.RustASTInfo{
+		Tokens: rustTestTokens(source),
+		Enums: []*types.RustEnumInfo{
+			{Name: "Failure", StartLine: 2, EndLine: 10, StartColumn: 1, VariantCount: 6},
+			{Name: "StoreError", StartLine: 13, EndLine: 16, StartColumn: 1, VariantCount: 2},
+		},
+	}
+
+	keyword := rustEnumKeyword(rustAstInfo.Tokens, rustAstInfo.Enums[0])
+	expected := []string{"ConfigMissing", "ConfigInvalid", "DbQuery"}
+	if variants := rustStringOnlyVariants(rustAstInfo.Tokens, keyword); !reflect.DeepEqual(variants, expected) {
+		t.Errorf("Expected String-only variants %v, got %v", expected, variants)
+	}
+
+	config := DefaultDetectorConfig()
+	config.RustConfig.MaxEnumVariants = 5
+	config.RustConfig.MaxErrorVariants = 4
+	violations := NewRustStructureDetector(config).checkEnumComplexity(rustAstInfo, "error.rs")
+	if len(violations) != 2 {
+		t.Fatalf("Expected the error enum size and its String variants, got %+v", violations)
+	}
+	if v := violations[0]; v.Rule != "rust-error-enum-size" || v.Message != "Error enum 'Failure' has too many variants (6, max: 4)" ||
+		v.Suggestion != "Its variants fall into 3 domains: config (2), db (2), net (2). Define an error type per module, wrapped by variants of 'Failure' with #[from]" {
+		t.Errorf("Unexpected size violation %+v", v)
+	}
+	if v := violations[1]; v.Rule != "rust-error-string-variants" || v.Line != 2 || v.Severity != models.SeverityLow ||
+		v.Message != "Error enum 'Failure' has 3 variants carrying only a String" {
+		t.Errorf("Unexpected String variants violation %+v", v)
+	}
+
+	config.RustConfig.MaxErrorVariants = 6
+	violations = NewRustStructureDetector(config).checkEnumComplexity(rustAstInfo, "error.rs")
+	if len(violations) != 2 || violations[1].Rule != "rust-enum-complexity" {
+		t.Errorf("Expected the generic limit to apply below the error enum limit, got %+v", violations)
+	}
+}
+
+func TestRustStructureDetector_LongTuples(t *testing.T) {
+	source := `pub fn parse_url(url: &str) -> (String, u16, String) { todo!() }
+
+fn split(line: &str) -> Result<Vec<(String, String, u32)>, Error> where Error: Debug { todo!() }
+
+fn pair() -> (u8, u8) { (0, 0) }
+
+fn callback() -> Box<dyn Fn(u8, u8, u8) -> u8> { todo!() }
+
+impl Iterator for Rows {
+    fn next(&mut self) -> Option<(u32, u32, u32)> { None }
+}
+
+pub struct Color(pub u8, pub u8, pub u8);
+
+struct Span(usize, (u32, u32, u32,));
+
+struct Route<T> {
+    #[serde(skip)]
+    endpoints: (String, u16, String),
+    handler: T,
+}`
+	rustAstInfo := &types.RustASTInfo{
+		Tokens: rustTestTokens(source),
+		Functions: []*types.RustFunctionInfo{
+			{Name: "parse_url", StartLine: 1, EndLine: 1, StartColumn: 1},
+			{Name: "split", StartLine: 3, EndLine: 3, StartColumn: 1},
+			{Name: "pair", StartLine: 5, EndLine: 5, StartColumn: 1},
+			{Name: "callback", StartLine: 7, EndLine: 7, StartColumn: 1},
+			{Name: "next", StartLine: 10, EndLine: 10, StartColumn: 5},
+		},
+		Impls: []*types.RustImplInfo{{TargetType: "Rows", TraitName: "Iterator", StartLine: 9, EndLine: 11}},
+		Structs: []*types.RustStructInfo{
+			{Name: "Color", StartLine: 13, EndLine: 13, StartColumn: 1},
+			{Name: "Span", StartLine: 15, EndLine: 15, StartColumn: 1},
+			{Name: "Route", StartLine: 17, EndLine: 21, StartColumn: 1},
+		},
+	}
+
+	config := DefaultDetectorConfig()
+	violations := NewRustStructureDetector(config).checkLongTuples(rustAstInfo, "src/url.rs")
+	expected := []struct {
+		line, column int
+		message      string
+	}{
+		{1, 32, "Function 'parse_url' returns a tuple of 3 elements (max: 2)"},
+		{3, 25, "Function 'split' returns a tuple of 3 elements (max: 2)"},
+		{13, 1, "Tuple struct 'Color' has 3 positional fields (max: 2)"},
+		{15, 20, "Field 1 of struct 'Span' is a tuple of 3 elements (max: 2)"},
+		{19, 5, "Field 'endpoints' of struct 'Route' is a tuple of 3 elements (max: 2)"},
+	}
+	if len(violations) != len(expected) {
+		t.Fatalf("Expected %d long tuples, got %+v", len(expected), violations)
+	}
+	for i, want := range expected {
+		if v := violations[i]; v.Line != want.line || v.Column != want.column || v.Message != want.message || v.Rule != RustLongTuple {
+			t.Errorf("Expected violation %d at %d:%d with %q, got %+v", i, want.line, want.column, want.message, v)
+		}
+	}
+
+	config.RustConfig.MaxTupleArity = 3
+	if violations := NewRustStructureDetector(config).checkLongTuples(rustAstInfo, "src/url.rs"); len(violations) != 0 {
+		t.Errorf("Expected no violations with max_tuple_arity 3, got %+v", violations)
+	}
+}
+
+// TestRustStructureDetector_ImplLimitsAndInventory tests configured impl limits and the method inventory
+func TestRustStructureDetector_ImplLimitsAndInventory(t *testing.T) {
+	config := DefaultDetectorConfig()
+	config.RustConfig.MaxImplMethods = 2
+	config.RustConfig.MaxImplLines = 30
+	detector := NewRustStructureDetector(config)
+
+	rustAstInfo := &types.RustASTInfo{
+		Impls: []*types.RustImplInfo{
+			{StartLine: 10, EndLine: 50, StartColumn: 1, TargetType: "Parser", MethodCount: 3},
+			{StartLine: 60, EndLine: 70, StartColumn: 1, TargetType: "Lexer", MethodCount: 1},
+		},
+		Functions: []*types.RustFunctionInfo{
+			{Name: "main", StartLine: 1, EndLine: 8},
+			{Name: "new", StartLine: 11, EndLine: 15},
+			{Name: "parse", StartLine: 17, EndLine: 40},
+			{Name: "helper", StartLine: 20, EndLine: 25},
+			{Name: "reset", StartLine: 42, EndLine: 49},
+			{Name: "next", StartLine: 61, EndLine: 69},
+		},
+	}
+
+	violations := detector.checkImplComplexity(rustAstInfo, "parser.rs")
+	if len(violations) != 2 {
+		t.Fatalf("Expected a method count and a length violation, got %+v", violations)
+	}
+	if violations[0].Rule != "rust-impl-complexity" || violations[0].Message != "impl Parser has too many methods (3, max: 2)" {
+		t.Errorf("Unexpected method count violation %+v", violations[0])
+	}
+	if violations[1].Rule != "rust-impl-length" || violations[1].Message != "Implementation for 'Parser' is too long (41 lines, max: 30)" {
+		t.Errorf("Unexpected length violation %+v", violations[1])
+	}
+	for _, violation := range violations {
+		if !strings.HasPrefix(violation.Suggestion, "Methods by length: parse (24 lines), reset (8 lines), new (5 lines). ") {
+			t.Errorf("Expected the method inventory in the suggestion, got %q", violation.Suggestion)
+		}
+	}
+}
+
+// TestRustStructureDetector_ModuleDepth tests the nesting depth of file and inline modules
+func TestRustStructureDetector_ModuleDepth(t *testing.T) {
+	source := `mod level1 {
+    pub mod level2 {
+        pub mod level3 {
+            pub fn handle() {}
+        }
+        mod tests;
+    }
+    mod sibling {
+        fn helper() {}
+    }
+}`
+	rustAstInfo := &types.RustASTInfo{Tokens: rustTestTokens(source)}
+
+	modules := rustInlineModules(rustAstInfo.Tokens)
+	var paths []string
+	for _, module := range modules {
+		paths = append(paths, strings.Join(module.path, "::"))
+	}
+	expected := []string{"level1", "level1::level2", "level1::level2::level3", "level1::sibling"}
+	if !reflect.DeepEqual(paths, expected) {
+		t.Fatalf("Expected inline modules %v, got %v", expected, paths)
+	}
+
+	config := DefaultDetectorConfig()
+	detector := NewRustStructureDetector(config)
+	if violations := detector.checkModuleDepth(rustAstInfo, "/work/app/src/net/http.rs"); len(violations) != 0 {
+		t.Errorf("Expected no violations within the default depth, got %+v", violations)
+	}
+
+	config.RustConfig.MaxModuleDepth = 4
+	violations := detector.checkModuleDepth(rustAstInfo, "/work/app/src/net/http.rs")
+	if len(violations) != 1 {
+		t.Fatalf("Expected only the deepest module to be reported, got %+v", violations)
+	}
+	violation := violations[0]
+	if violation.Rule != "rust-module-nesting" || violation.Line != 3 || violation.Column != 13 ||
+		violation.Message != "Module 'app::net::http::level1::level2::level3' is nested too deeply (5 levels, max: 4)" {
+		t.Errorf("Unexpected violation %+v", violation)
+	}
+
+	flat := &types.RustASTInfo{Tokens: rustTestTokens("pub fn decode() {}")}
+	violations = detector.checkModuleDepth(flat, "/work/app/src/codec/text/utf/wide/decode.rs")
+	if len(violations) != 1 || violations[0].Line != 1 ||
+		violations[0].Message != "Module 'app::codec::text::utf::wide::decode' is nested too deeply (5 levels, max: 4)" {
+		t.Errorf("Expected the file module to be reported, got %+v", violations)
+	}
+}
+
 // TestRustStructureDetector_ValidRustASTInfo tests detection with valid Rust AST info
 func TestRustStructureDetector_ValidRustASTInfo(t *testing.T) {
 	config := DefaultDetectorConfig()
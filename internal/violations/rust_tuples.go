@@ -0,0 +1,196 @@
+package violations
+
+import (
+	"fmt"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+// RustLongTuple is the rule of the long tuple check
+const RustLongTuple = "rust-long-tuple"
+
+// rustClosureTraits take their parameters in parentheses that are not a tuple
+var rustClosureTraits = map[string]bool{"Fn": true, "FnMut": true, "FnOnce": true, "fn": true}
+
+// checkLongTuples reports tuples with more elements than the configured arity
+// in the return types of functions and the field types of structs, and tuple
+// structs with more positional fields than that. Callers read their elements
+// as .0, .1 and .2, which say nothing of what each one holds. Methods of
+// trait impls are skipped, as the trait fixes their signature.
+func (d *RustStructureDetector) checkLongTuples(info *types.RustASTInfo, filePath string) []*models.Violation {
+	var violations []*models.Violation
+
+	maxArity := d.tupleArityLimit()
+	tokens := info.Tokens
+	for _, fn := range info.Functions {
+		if fn == nil || rustInTraitImpl(info.Impls, fn.StartLine) {
+			continue
+		}
+		returns := rustReturnTypeTokens(tokens, fn)
+		if arity := rustTupleArity(returns); arity > maxArity {
+			violations = append(violations, &models.Violation{
+				Type:       models.ViolationTypeStructure,
+				Severity:   models.SeverityLow,
+				Message:    fmt.Sprintf("Function '%s' returns a tuple of %d elements (max: %d)", fn.Name, arity, maxArity),
+				File:       filePath,
+				Line:       returns[0].Line,
+				Column:     returns[0].Column,
+				Rule:       RustLongTuple,
+				Suggestion: "Return a struct with a named field per element, so callers read them by name rather than position",
+			})
+		}
+	}
+
+	for _, structInfo := range info.Structs {
+		if structInfo == nil {
+			continue
+		}
+		open := rustStructBody(tokens, structInfo)
+		if open < 0 {
+			continue
+		}
+		fields := rustListEntries(tokens, open)
+		if tokens[open].Text == "(" && len(fields) > maxArity {
+			violations = append(violations, &models.Violation{
+				Type:       models.ViolationTypeStructure,
+				Severity:   models.SeverityLow,
+				Message:    fmt.Sprintf("Tuple struct '%s' has %d positional fields (max: %d)", structInfo.Name, len(fields), maxArity),
+				File:       filePath,
+				Line:       structInfo.StartLine,
+				Column:     structInfo.StartColumn,
+				Rule:       RustLongTuple,
+				Suggestion: fmt.Sprintf("Name the fields of '%s', so they are read by name rather than position", structInfo.Name),
+			})
+		}
+		for i, field := range fields {
+			name, fieldType, at := fmt.Sprintf("%d", i), field, field[0]
+			if colon := rustTopLevelIndex(field, ":"); tokens[open].Text == "{" && colon > 0 {
+				name, fieldType, at = fmt.Sprintf("'%s'", field[colon-1].Text), field[colon+1:], field[colon-1]
+			}
+			arity := rustTupleArity(fieldType)
+			if arity <= maxArity {
+				continue
+			}
+			violations = append(violations, &models.Violation{
+				Type:       models.ViolationTypeStructure,
+				Severity:   models.SeverityLow,
+				Message:    fmt.Sprintf("Field %s of struct '%s' is a tuple of %d elements (max: %d)", name, structInfo.Name, arity, maxArity),
+				File:       filePath,
+				Line:       at.Line,
+				Column:     at.Column,
+				Rule:       RustLongTuple,
+				Suggestion: "Use a struct with a named field per element, so they are read by name rather than position",
+			})
+		}
+	}
+	return violations
+}
+
+// tupleArityLimit returns the configured elements allowed in a tuple
+func (d *RustStructureDetector) tupleArityLimit() int {
+	if rust := d.config.RustConfig; rust != nil {
+		return positiveOr(rust.MaxTupleArity, RustMaxTupleArity)
+	}
+	return RustMaxTupleArity
+}
+
+// rustInTraitImpl reports whether a line is inside an impl of a trait
+func rustInTraitImpl(impls []*types.RustImplInfo, line int) bool {
+	for _, impl := range impls {
+		if impl != nil && impl.TraitName != "" && line >= impl.StartLine && line <= impl.EndLine {
+			return true
+		}
+	}
+	return false
+}
+
+// rustReturnTypeTokens returns the tokens of the return type of a function,
+// up to its body, its where clause or the semicolon of a declaration
+func rustReturnTypeTokens(tokens []types.RustTokenInfo, fn *types.RustFunctionInfo) []types.RustTokenInfo {
+	_, closing, _, _ := rustFunctionSpan(tokens, fn)
+	start := closing + 2
+	if start >= len(tokens) || tokens[closing+1].Text != "->" {
+		return nil
+	}
+	end := start
+	for end < len(tokens) {
+		text := tokens[end].Text
+		if text == "{" || text == ";" || text == "where" {
+			break
+		}
+		if text == "(" || text == "[" || text == "<" {
+			end = rustGroupEnd(tokens, end)
+		} else {
+			end++
+		}
+	}
+	return tokens[start:end]
+}
+
+// rustStructBody returns the index of the parenthesis opening the fields of a
+// tuple struct or the brace opening those of a struct with named fields, or
+// -1 for a unit struct or a struct whose header is not found
+func rustStructBody(tokens []types.RustTokenInfo, structInfo *types.RustStructInfo) int {
+	for i := 0; i+1 < len(tokens); i++ {
+		if tokens[i].Line != structInfo.StartLine || tokens[i].Text != "struct" || tokens[i+1].Text != structInfo.Name {
+			continue
+		}
+		for j := i + 2; j < len(tokens); j++ {
+			switch tokens[j].Text {
+			case "(", "{":
+				return j
+			case ";":
+				return -1
+			case "<":
+				j = rustGroupEnd(tokens, j) - 1
+			case "where":
+				for j < len(tokens) && tokens[j].Text != "{" && tokens[j].Text != ";" {
+					j++
+				}
+				if j < len(tokens) && tokens[j].Text == "{" {
+					return j
+				}
+				return -1
+			}
+		}
+		return -1
+	}
+	return -1
+}
+
+// rustListEntries splits the tokens between the delimiter opened at index
+// open and its closer at the commas outside nested groups. A trailing comma
+// adds no entry.
+func rustListEntries(tokens []types.RustTokenInfo, open int) [][]types.RustTokenInfo {
+	var entries [][]types.RustTokenInfo
+	closing := rustGroupEnd(tokens, open) - 1
+	start := open + 1
+	for i := start; i <= closing && i < len(tokens); i++ {
+		text := tokens[i].Text
+		switch {
+		case i == closing || text == ",":
+			if i > start {
+				entries = append(entries, tokens[start:i])
+			}
+			start = i + 1
+		case isRustOpenDelimiter(text) || text == "<":
+			i = rustGroupEnd(tokens, i) - 1
+		}
+	}
+	return entries
+}
+
+// rustTupleArity returns the number of elements of the longest tuple in a
+// type, as in Vec<(String, u16, String)>, or 0 without tuples. The parameter
+// lists of Fn traits and fn pointers are not tuples.
+func rustTupleArity(tokens []types.RustTokenInfo) int {
+	longest := 0
+	for i, tok := range tokens {
+		if tok.Text != "(" || i > 0 && rustClosureTraits[tokens[i-1].Text] {
+			continue
+		}
+		longest = max(longest, len(rustListEntries(tokens, i)))
+	}
+	return longest
+}
@@ -0,0 +1,607 @@
+package violations
+
+import (
+	"sort"
+	"strings"
+
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+// rustCopyPrimitives are the built-in scalar types, all of which implement Copy
+var rustCopyPrimitives = map[string]bool{
+	"i8": true, "i16": true, "i32": true, "i64": true, "i128": true, "isize": true,
+	"u8": true, "u16": true, "u32": true, "u64": true, "u128": true, "usize": true,
+	"f32": true, "f64": true, "bool": true, "char": true, "()": true,
+}
+
+// rustPathKeywords are the keywords that start a path or value expression
+var rustPathKeywords = map[string]bool{"self": true, "Self": true, "crate": true, "super": true, "true": true, "false": true}
+
+// rustKnownFunctionTypes are the return types of common standard library
+// functions, keyed by their last two path segments
+var rustKnownFunctionTypes = map[string]string{
+	"String::new":           "String",
+	"String::from":          "String",
+	"String::with_capacity": "String",
+	"Vec::new":              "Vec<_>",
+	"Vec::with_capacity":    "Vec<_>",
+	"HashMap::new":          "HashMap<_, _>",
+	"HashSet::new":          "HashSet<_>",
+	"Box::new":              "Box<_>",
+	"Rc::new":               "Rc<_>",
+	"Arc::new":              "Arc<_>",
+	"Rc::clone":             "Rc<_>",
+	"Arc::clone":            "Arc<_>",
+	"Instant::now":          "Instant",
+	"Duration::from_secs":   "Duration",
+	"Duration::from_millis": "Duration",
+	"fs::read_to_string":    "io::Result<String>",
+	"fs::read":              "io::Result<Vec<u8>>",
+	"fs::write":             "io::Result<()>",
+	"fs::create_dir":        "io::Result<()>",
+	"fs::create_dir_all":    "io::Result<()>",
+	"fs::remove_file":       "io::Result<()>",
+	"fs::remove_dir_all":    "io::Result<()>",
+	"fs::rename":            "io::Result<()>",
+	"fs::copy":              "io::Result<u64>",
+	"File::open":            "io::Result<File>",
+	"File::create":          "io::Result<File>",
+	"TcpStream::connect":    "io::Result<TcpStream>",
+	"TcpListener::bind":     "io::Result<TcpListener>",
+	"env::var":              "Result<String, VarError>",
+}
+
+// rustKnownMethodTypes are the return types of common standard library
+// methods that do not depend on the receiver
+var rustKnownMethodTypes = map[string]string{
+	"len":         "usize",
+	"count":       "usize",
+	"capacity":    "usize",
+	"is_empty":    "bool",
+	"contains":    "bool",
+	"contains_key":"bool",
+	"starts_with": "bool",
+	"ends_with":   "bool",
+	"is_some":     "bool",
+	"is_none":     "bool",
+	"is_ok":       "bool",
+	"is_err":      "bool",
+	"to_string":   "String",
+	"to_uppercase":"String",
+	"to_lowercase":"String",
+	"as_str":      "&str",
+	"trim":        "&str",
+	"parse":       "Result<_, _>",
+	"write_all":   "io::Result<()>",
+	"flush":       "io::Result<()>",
+	"sync_all":    "io::Result<()>",
+	"read_exact":  "io::Result<()>",
+	"read_to_end": "io::Result<usize>",
+	"set_len":     "io::Result<()>",
+	"shutdown":    "io::Result<()>",
+}
+
+// rustKnownMacroTypes are the types of the expressions of common macros
+var rustKnownMacroTypes = map[string]string{
+	"vec":     "Vec<_>",
+	"format":  "String",
+	"matches": "bool",
+}
+
+// rustTypeInference is a best-effort local type inference for one Rust file.
+// It types literals, parameters and let bindings, and the calls of the file's
+// functions and of well-known standard library functions and methods. Types
+// it cannot work out are "", so rules fall back to their textual heuristics.
+type rustTypeInference struct {
+	tokens      []types.RustTokenInfo
+	copyTypes   map[string]bool   // Types of the file that derive Copy
+	returnTypes map[string]string // Return types of the file's functions by name
+	scopes      []*rustTypeScope
+}
+
+// rustTypeScope holds the bindings of one function in source order
+type rustTypeScope struct {
+	fn       *types.RustFunctionInfo
+	bindings []rustBinding
+}
+
+// rustBinding is a parameter or let binding; parameters are on line 0
+type rustBinding struct {
+	name string
+	typ  string
+	line int
+}
+
+// newRustTypeInference infers the types of the bindings of every function of a file
+func newRustTypeInference(info *types.RustASTInfo) *rustTypeInference {
+	inference := &rustTypeInference{
+		tokens:      info.Tokens,
+		copyTypes:   rustCopyDerives(info.Tokens),
+		returnTypes: make(map[string]string),
+	}
+	for _, fn := range info.Functions {
+		if fn.ReturnType != "" {
+			inference.returnTypes[fn.Name] = fn.ReturnType
+		}
+	}
+
+	for _, fn := range info.Functions {
+		scope := &rustTypeScope{fn: fn}
+		for _, param := range fn.Parameters {
+			scope.bindings = append(scope.bindings, rustBinding{name: param.Name, typ: param.Type})
+		}
+		inference.scopes = append(inference.scopes, scope)
+	}
+	for _, scope := range inference.scopes {
+		inference.inferLets(scope)
+	}
+	return inference
+}
+
+// inferLets records the let bindings of a function, typing each from its
+// annotation or its initializer. Bindings of unknown type are kept too, so
+// they shadow earlier bindings of the same name.
+func (r *rustTypeInference) inferLets(scope *rustTypeScope) {
+	tokens := r.tokens
+	first := sort.Search(len(tokens), func(i int) bool { return tokens[i].Line >= scope.fn.StartLine })
+	for i := first; i < len(tokens) && tokens[i].Line <= scope.fn.EndLine; i++ {
+		if tokens[i].Kind != types.RustTokenKeyword || tokens[i].Text != "let" {
+			continue
+		}
+		j := i + 1
+		if j < len(tokens) && tokens[j].Text == "mut" {
+			j++
+		}
+		if j+1 >= len(tokens) || tokens[j].Kind != types.RustTokenIdent {
+			continue
+		}
+		binding := rustBinding{name: tokens[j].Text, line: tokens[i].Line}
+		switch tokens[j+1].Text {
+		case ":":
+			end := rustTypeEnd(tokens, j+2)
+			binding.typ = rustTypeText(tokens[j+2 : end])
+		case "=":
+			end := rustExprEnd(tokens, j+2)
+			binding.typ = r.exprType(tokens[j+2:end], binding.line)
+		}
+		scope.bindings = append(scope.bindings, binding)
+	}
+}
+
+// typeOf returns the type of the binding a name refers to on a line
+func (r *rustTypeInference) typeOf(name string, line int) string {
+	var innermost *rustTypeScope
+	for _, scope := range r.scopes {
+		if line < scope.fn.StartLine || line > scope.fn.EndLine {
+			continue
+		}
+		if innermost == nil || scope.fn.EndLine-scope.fn.StartLine < innermost.fn.EndLine-innermost.fn.StartLine {
+			innermost = scope
+		}
+	}
+	if innermost == nil {
+		return ""
+	}
+	typ := ""
+	for _, binding := range innermost.bindings {
+		if binding.line >= line {
+			break
+		}
+		if binding.name == name {
+			typ = binding.typ
+		}
+	}
+	return typ
+}
+
+// statementType returns the type of the expression statement on a line,
+// e.g. `io::Result<()>` for `file.flush();`. It reports false when the line
+// is not a single expression statement or its type is unknown.
+func (r *rustTypeInference) statementType(line int) (string, bool) {
+	start := sort.Search(len(r.tokens), func(i int) bool { return r.tokens[i].Line >= line })
+	end := start
+	for end < len(r.tokens) && r.tokens[end].Line == line {
+		end++
+	}
+	if end-start < 2 || r.tokens[end-1].Text != ";" {
+		return "", false
+	}
+	if first := r.tokens[start]; first.Kind == types.RustTokenKeyword && !rustPathKeywords[first.Text] {
+		return "", false
+	}
+	typ := r.exprType(r.tokens[start:end-1], line)
+	return typ, typ != ""
+}
+
+// exprType infers the type of an expression, or "" when it is unknown
+func (r *rustTypeInference) exprType(tokens []types.RustTokenInfo, line int) string {
+	if len(tokens) == 0 {
+		return ""
+	}
+	if first := tokens[0]; first.Kind == types.RustTokenKeyword && !rustPathKeywords[first.Text] ||
+		first.Text == "|" || first.Text == "||" {
+		return "" // Closures and if, match or loop expressions
+	}
+	if cast := rustTopLevelIndex(tokens, "as"); cast >= 0 {
+		return rustTypeText(tokens[cast+1:])
+	}
+	operators := []string{"==", "!=", "<=", ">=", "&&", "||", "<", ">"}
+	for i := 1; i < len(tokens); i++ {
+		if tokens[i].Text == "<" && tokens[i-1].Text == "::" {
+			operators = operators[:6] // < and > delimit turbofish generics
+			break
+		}
+	}
+	for _, op := range operators {
+		if rustTopLevelIndex(tokens, op) > 0 {
+			return "bool"
+		}
+	}
+	switch tokens[0].Text {
+	case "&":
+		if len(tokens) > 1 && tokens[1].Text == "mut" {
+			return rustPrefixType("&mut ", r.exprType(tokens[2:], line))
+		}
+		return rustPrefixType("&", r.exprType(tokens[1:], line))
+	case "-", "!":
+		return r.exprType(tokens[1:], line)
+	}
+
+	typ, pos := r.primaryType(tokens, line)
+	if pos == 0 {
+		return ""
+	}
+	return r.postfixType(tokens, pos, typ)
+}
+
+// primaryType types the leading literal, path, call, macro or struct literal
+// of an expression and returns the index after it, or 0 when there is none
+func (r *rustTypeInference) primaryType(tokens []types.RustTokenInfo, line int) (string, int) {
+	tok := tokens[0]
+	switch {
+	case tok.Kind == types.RustTokenLiteral:
+		return rustLiteralType(tok.Text), 1
+	case tok.Text == "true" || tok.Text == "false":
+		return "bool", 1
+	case tok.Kind != types.RustTokenIdent && tok.Kind != types.RustTokenKeyword:
+		return "", 0
+	}
+
+	segments := []string{tok.Text}
+	pos := 1
+	for pos+1 < len(tokens) && tokens[pos].Text == "::" {
+		if tokens[pos+1].Text == "<" {
+			// Skip turbofish generics such as Vec::<u8>::new
+			pos = rustGroupEnd(tokens, pos+1)
+			continue
+		}
+		segments = append(segments, tokens[pos+1].Text)
+		pos += 2
+	}
+	last := segments[len(segments)-1]
+
+	if pos < len(tokens) {
+		switch tokens[pos].Text {
+		case "!":
+			if pos+1 < len(tokens) {
+				return rustKnownMacroTypes[last], rustGroupEnd(tokens, pos+1)
+			}
+		case "(":
+			return r.callType(segments), rustGroupEnd(tokens, pos)
+		case "{":
+			if isRustTypeName(last) {
+				return last, rustGroupEnd(tokens, pos)
+			}
+		}
+	}
+	if len(segments) == 1 {
+		return r.typeOf(last, line), pos
+	}
+	return "", pos
+}
+
+// postfixType applies method calls, `?` and arithmetic after a primary expression
+func (r *rustTypeInference) postfixType(tokens []types.RustTokenInfo, pos int, typ string) string {
+	for pos < len(tokens) {
+		switch text := tokens[pos].Text; {
+		case text == "?":
+			typ = rustUnwrapType(typ)
+			pos++
+		case text == "." && pos+1 < len(tokens) && tokens[pos+1].Kind == types.RustTokenIdent:
+			method := tokens[pos+1].Text
+			pos += 2
+			if pos+1 < len(tokens) && tokens[pos].Text == "::" && tokens[pos+1].Text == "<" {
+				pos = rustGroupEnd(tokens, pos+1)
+			}
+			if pos >= len(tokens) || tokens[pos].Text != "(" {
+				return "" // Field access
+			}
+			typ = r.methodType(typ, method)
+			pos = rustGroupEnd(tokens, pos)
+		case text == "+" || text == "-" || text == "*" || text == "/" || text == "%":
+			if rustCopyPrimitives[typ] {
+				return typ
+			}
+			return ""
+		default:
+			return ""
+		}
+	}
+	return typ
+}
+
+// callType returns the return type of a function called through a path
+func (r *rustTypeInference) callType(segments []string) string {
+	name := segments[len(segments)-1]
+	if len(segments) == 1 {
+		if typ, ok := r.returnTypes[name]; ok {
+			return typ
+		}
+		if isRustTypeName(name) && name != "Some" && name != "Ok" && name != "Err" {
+			return name // Tuple struct constructor
+		}
+		return ""
+	}
+	owner := segments[len(segments)-2]
+	if typ, ok := rustKnownFunctionTypes[owner+"::"+name]; ok {
+		return typ
+	}
+	if owner == "Self" || isRustTypeName(owner) {
+		if typ, ok := r.returnTypes[name]; ok {
+			return strings.ReplaceAll(typ, "Self", owner)
+		}
+	}
+	return ""
+}
+
+// methodType returns the return type of a method called on a receiver type
+func (r *rustTypeInference) methodType(receiver, method string) string {
+	if typ, ok := r.returnTypes[method]; ok {
+		return typ
+	}
+	switch method {
+	case "clone":
+		return rustDerefType(receiver)
+	case "to_owned":
+		if target := rustDerefType(receiver); target != "str" {
+			return target
+		}
+		return "String"
+	case "unwrap", "expect", "unwrap_or", "unwrap_or_else", "unwrap_or_default":
+		return rustUnwrapType(receiver)
+	}
+	return rustKnownMethodTypes[method]
+}
+
+// isCopyType reports whether a type is known to implement Copy
+func (r *rustTypeInference) isCopyType(typ string) bool {
+	if strings.HasPrefix(typ, "&") && !strings.HasPrefix(typ, "&mut ") {
+		return true
+	}
+	return rustCopyPrimitives[typ] || r.copyTypes[typ]
+}
+
+// rustCopyDerives returns the structs and enums of a file with #[derive(Copy)]
+func rustCopyDerives(tokens []types.RustTokenInfo) map[string]bool {
+	copyTypes := make(map[string]bool)
+	pending := false
+	for i := 0; i < len(tokens); i++ {
+		switch tok := tokens[i]; {
+		case tok.Text == "derive" && i+1 < len(tokens) && tokens[i+1].Text == "(":
+			end := rustGroupEnd(tokens, i+1)
+			for _, derived := range tokens[i+1 : end] {
+				pending = pending || derived.Text == "Copy"
+			}
+			i = end - 1
+		case tok.Kind == types.RustTokenKeyword && (tok.Text == "struct" || tok.Text == "enum" || tok.Text == "union"):
+			if pending && i+1 < len(tokens) {
+				copyTypes[tokens[i+1].Text] = true
+			}
+			pending = false
+		case tok.Text == "fn" || tok.Text == "impl" || tok.Text == ";" || tok.Text == "{":
+			pending = false
+		}
+	}
+	return copyTypes
+}
+
+// rustLiteralType returns the type of a literal token
+func rustLiteralType(text string) string {
+	switch {
+	case strings.HasPrefix(text, `b"`) || strings.HasPrefix(text, `br`):
+		return "&[u8]"
+	case strings.HasPrefix(text, `"`) || strings.HasPrefix(text, `r"`) || strings.HasPrefix(text, `r#`):
+		return "&str"
+	case strings.HasPrefix(text, "b'"):
+		return "u8"
+	case strings.HasPrefix(text, "'"):
+		return "char"
+	case text == "" || text[0] < '0' || text[0] > '9':
+		return ""
+	}
+
+	number := strings.ReplaceAll(text, "_", "")
+	for suffix := range rustCopyPrimitives {
+		if suffix != "bool" && suffix != "char" && suffix != "()" && strings.HasSuffix(number, suffix) &&
+			(!strings.HasPrefix(number, "0x") || suffix[0] == 'i' || suffix[0] == 'u') {
+			return suffix
+		}
+	}
+	if !strings.HasPrefix(number, "0x") && strings.ContainsAny(number, ".eE") {
+		return "f64"
+	}
+	return "i32"
+}
+
+// rustUnwrapType returns the value type of an Option or Result, or ""
+func rustUnwrapType(typ string) string {
+	switch rustBaseType(typ) {
+	case "Option", "Result":
+		if args := rustTypeArgs(typ); len(args) > 0 && args[0] != "_" {
+			return args[0]
+		}
+	}
+	return ""
+}
+
+// isRustResultType reports whether a type is a Result, including aliases such as io::Result
+func isRustResultType(typ string) bool {
+	return rustBaseType(typ) == "Result"
+}
+
+// rustBaseType returns the name of a type without references, path and
+// generic arguments, e.g. Result for &io::Result<String>
+func rustBaseType(typ string) string {
+	typ = rustDerefType(rustDerefType(typ))
+	typ, _, _ = strings.Cut(typ, "<")
+	if sep := strings.LastIndex(typ, "::"); sep >= 0 {
+		typ = typ[sep+2:]
+	}
+	return strings.TrimSpace(typ)
+}
+
+// rustDerefType removes one level of shared or mutable reference from a type
+func rustDerefType(typ string) string {
+	if !strings.HasPrefix(typ, "&") {
+		return typ
+	}
+	typ = strings.TrimPrefix(typ, "&")
+	if strings.HasPrefix(typ, "'") {
+		// Lifetime, e.g. &'a str
+		if space := strings.Index(typ, " "); space > 0 {
+			typ = typ[space+1:]
+		}
+	}
+	return strings.TrimPrefix(typ, "mut ")
+}
+
+// rustTypeArgs splits the top-level generic arguments of a type
+func rustTypeArgs(typ string) []string {
+	open := strings.Index(typ, "<")
+	closing := strings.LastIndex(typ, ">")
+	if open < 0 || closing < open {
+		return nil
+	}
+	var args []string
+	depth, start := 0, open+1
+	for i := open + 1; i < closing; i++ {
+		switch typ[i] {
+		case '<', '(', '[':
+			depth++
+		case '>', ')', ']':
+			depth--
+		case ',':
+			if depth == 0 {
+				args = append(args, strings.TrimSpace(typ[start:i]))
+				start = i + 1
+			}
+		}
+	}
+	return append(args, strings.TrimSpace(typ[start:closing]))
+}
+
+// rustPrefixType adds a reference prefix to a known type
+func rustPrefixType(prefix, typ string) string {
+	if typ == "" {
+		return ""
+	}
+	return prefix + typ
+}
+
+// rustTypeText renders type tokens as compact text, e.g. Result<(), io::Error>
+func rustTypeText(tokens []types.RustTokenInfo) string {
+	var b strings.Builder
+	for i, tok := range tokens {
+		if i > 0 {
+			prev := tokens[i-1]
+			wordLike := func(t types.RustTokenInfo) bool { return t.Kind != types.RustTokenPunct }
+			if prev.Text == "," || wordLike(prev) && wordLike(tok) {
+				b.WriteByte(' ')
+			}
+		}
+		b.WriteString(tok.Text)
+	}
+	return b.String()
+}
+
+// rustTypeEnd returns the index of the `=` or `;` ending a type annotation
+func rustTypeEnd(tokens []types.RustTokenInfo, i int) int {
+	depth := 0
+	for ; i < len(tokens); i++ {
+		switch tokens[i].Text {
+		case "<", "(", "[":
+			depth++
+		case ">", ")", "]":
+			depth--
+		case "=", ";":
+			if depth <= 0 {
+				return i
+			}
+		}
+	}
+	return i
+}
+
+// rustExprEnd returns the index of the `;` ending an initializer, or of the
+// `else` of a let-else statement
+func rustExprEnd(tokens []types.RustTokenInfo, i int) int {
+	depth := 0
+	for ; i < len(tokens); i++ {
+		switch tokens[i].Text {
+		case "(", "[", "{":
+			depth++
+		case ")", "]", "}":
+			if depth == 0 {
+				return i
+			}
+			depth--
+		case ";", "else":
+			if depth == 0 {
+				return i
+			}
+		}
+	}
+	return i
+}
+
+// rustGroupEnd returns the index after the group opened at i by (, [, { or <
+func rustGroupEnd(tokens []types.RustTokenInfo, i int) int {
+	closers := map[string]string{"(": ")", "[": "]", "{": "}", "<": ">"}
+	open := tokens[i].Text
+	closer, ok := closers[open]
+	if !ok {
+		return i + 1
+	}
+	depth := 0
+	for ; i < len(tokens); i++ {
+		switch tokens[i].Text {
+		case open:
+			depth++
+		case closer:
+			depth--
+			if depth == 0 {
+				return i + 1
+			}
+		}
+	}
+	return i
+}
+
+// rustTopLevelIndex returns the index of a token outside any parentheses,
+// brackets or braces, or -1
+func rustTopLevelIndex(tokens []types.RustTokenInfo, text string) int {
+	depth := 0
+	for i, tok := range tokens {
+		switch tok.Text {
+		case "(", "[", "{":
+			depth++
+		case ")", "]", "}":
+			depth--
+		case text:
+			if depth == 0 && tok.Kind != types.RustTokenLiteral {
+				return i
+			}
+		}
+	}
+	return -1
+}
@@ -0,0 +1,176 @@
+package violations
+
+import (
+	"strings"
+	"testing"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+const typeInferenceSource = `#[derive(Clone, Copy)]
+struct Point {
+    x: i32,
+}
+
+fn load(path: &str) -> io::Result<String> {
+    fs::read_to_string(path)
+}
+
+fn run(count: u32, name: &String) {
+    let total = count * 2;
+    let ratio = 1.5;
+    let labels: Vec<String> = Vec::new();
+    let point = Point { x: 1 };
+    let text = load("a.txt")?;
+    let size = text.len() as u64;
+    let owned = name.clone();
+    let large = size > 10;
+    let pick = if large { 1 } else { 2 };
+}`
+
+func TestRustTypeInference(t *testing.T) {
+	inference := newRustTypeInference(&types.RustASTInfo{
+		Tokens: rustTestTokens(typeInferenceSource),
+		Functions: []*types.RustFunctionInfo{
+			{Name: "load", StartLine: 6, EndLine: 8, ReturnType: "io::Result<String>",
+				Parameters: []types.RustParameterInfo{{Name: "path", Type: "&str"}}},
+			{Name: "run", StartLine: 10, EndLine: 20,
+				Parameters: []types.RustParameterInfo{{Name: "count", Type: "u32"}, {Name: "name", Type: "&String"}}},
+		},
+	})
+
+	tests := map[string]string{
+		"count":  "u32",
+		"total":  "u32",
+		"ratio":  "f64",
+		"labels": "Vec<String>",
+		"point":  "Point",
+		"text":   "String",
+		"size":   "u64",
+		"owned":  "String",
+		"large":  "bool",
+		"pick":   "",
+	}
+	for name, want := range tests {
+		if got := inference.typeOf(name, 20); got != want {
+			t.Errorf("typeOf(%q) = %q, want %q", name, got, want)
+		}
+	}
+	if got := inference.typeOf("total", 11); got != "" {
+		t.Errorf("Expected total to be unknown on its own line, got %q", got)
+	}
+	if got := inference.typeOf("path", 15); got != "" {
+		t.Errorf("Expected parameters of other functions to be out of scope, got %q", got)
+	}
+
+	for typ, want := range map[string]bool{"Point": true, "&String": true, "u8": true, "String": false, "&mut u8": false} {
+		if got := inference.isCopyType(typ); got != want {
+			t.Errorf("isCopyType(%q) = %v, want %v", typ, got, want)
+		}
+	}
+}
+
+func TestRustLiteralType(t *testing.T) {
+	tests := map[string]string{
+		`"text"`: "&str",
+		"'c'":    "char",
+		"42":     "i32",
+		"42u8":   "u8",
+		"1_000":  "i32",
+		"2.5":    "f64",
+		"1e3":    "f64",
+		"2.5f32": "f32",
+		"0xff":   "i32",
+	}
+	for literal, want := range tests {
+		if got := rustLiteralType(literal); got != want {
+			t.Errorf("rustLiteralType(%q) = %q, want %q", literal, got, want)
+		}
+	}
+}
+
+func TestRustOwnershipDetector_InferredTypes(t *testing.T) {
+	code := `fn area(width: &u32, name: &str) -> u32 {
+    let copy = width.clone();
+    let label = String::from(name);
+    let other = label.clone();
+    let shared = Rc::new(5);
+    let again = shared.clone();
+    copy
+}`
+	filePath := createTempFileWithContent(t, code, ".rs")
+	defer removeTempFile(t, filePath)
+
+	info := &types.RustASTInfo{
+		FilePath: filePath,
+		Tokens:   rustTestTokens(code),
+		Functions: []*types.RustFunctionInfo{{
+			Name: "area", StartLine: 1, EndLine: 8, StartColumn: 1, ReturnType: "u32",
+			Parameters: []types.RustParameterInfo{{Name: "width", Type: "&u32", IsRef: true}, {Name: "name", Type: "&str", IsRef: true}},
+		}},
+	}
+	violations := NewRustOwnershipDetector(nil).Detect(&models.FileInfo{Path: filePath, Language: "rust"}, info)
+
+	byLine := make(map[int]*models.Violation)
+	for _, v := range violations {
+		byLine[v.Line] = v
+	}
+	if len(violations) != 3 {
+		t.Fatalf("Expected 3 violations, got %d: %+v", len(violations), byLine)
+	}
+	if v := byLine[1]; v == nil || v.Rule != "rust-pass-by-value" || !strings.Contains(v.Message, "'width'") {
+		t.Errorf("Expected width to be reported as a Copy type passed by reference, got %+v", v)
+	}
+	if v := byLine[2]; v == nil || !strings.Contains(v.Message, "Copy type u32") || !strings.Contains(v.Suggestion, "'*width'") {
+		t.Errorf("Expected a clone of a Copy type on line 2, got %+v", v)
+	}
+	if v := byLine[4]; v == nil || v.Rule != "rust-unnecessary-clone" {
+		t.Errorf("Expected the String clone on line 4, got %+v", v)
+	}
+	if v := byLine[6]; v != nil {
+		t.Errorf("Expected the Rc clone to be kept, got %+v", v)
+	}
+}
+
+func TestRustErrorHandlingDetector_InferredResults(t *testing.T) {
+	code := `fn save(v: i32) -> Result<(), String> {
+    Ok(())
+}
+
+fn count(v: i32) -> usize {
+    1
+}
+
+fn main() {
+    save(1);
+    count(2);
+    std::fs::write("a", "b");
+}`
+	filePath := createTempFileWithContent(t, code, ".rs")
+	defer removeTempFile(t, filePath)
+
+	info := &types.RustASTInfo{
+		FilePath: filePath,
+		Tokens:   rustTestTokens(code),
+		Functions: []*types.RustFunctionInfo{
+			{Name: "save", StartLine: 1, EndLine: 3, ReturnType: "Result<(), String>"},
+			{Name: "count", StartLine: 5, EndLine: 7, ReturnType: "usize"},
+			{Name: "main", StartLine: 9, EndLine: 13},
+		},
+	}
+	violations := NewRustErrorHandlingDetector(nil).Detect(&models.FileInfo{Path: filePath, Language: "rust"}, info)
+
+	var lines []int
+	for _, v := range violations {
+		if v.Rule == "rust-unhandled-result" {
+			lines = append(lines, v.Line)
+			if v.Line == 12 && !strings.Contains(v.Message, "io::Result<()>") {
+				t.Errorf("Expected the inferred type in the message, got %q", v.Message)
+			}
+		}
+	}
+	if len(lines) != 2 || lines[0] != 10 || lines[1] != 12 {
+		t.Errorf("Expected unhandled Results on lines 10 and 12, got %v", lines)
+	}
+}
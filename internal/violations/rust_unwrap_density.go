@@ -0,0 +1,261 @@
+package violations
+
+import (
+	"fmt"
+	"path/filepath"
+	"sort"
+	"strings"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+// Rules of the unwrap density detector
+const (
+	RustUnwrapDensity     = "rust-unwrap-density"      // unwrap and expect calls in one function
+	RustFileUnwrapDensity = "rust-file-unwrap-density" // unwrap and expect calls in one file
+)
+
+// Receivers of unwrap and expect calls
+const (
+	unwrapOnLock  = "lock"  // A poisoned Mutex or RwLock, which only fails after another thread panicked
+	unwrapOnIO    = "io"    // A file, stream or socket operation, which fails at runtime
+	unwrapOnOther = "other" // Anything else, such as an Option or a parse
+)
+
+// rustLockMethods acquire a Mutex or RwLock guard when called without arguments
+var rustLockMethods = map[string]bool{"lock": true, "read": true, "write": true}
+
+// rustIOFunctions are the std::fs, std::io and std::net functions and methods
+// whose Result reports an operating system error
+var rustIOFunctions = map[string]bool{
+	"open": true, "create": true, "create_new": true, "read": true, "write": true,
+	"read_to_string": true, "read_to_end": true, "read_exact": true, "read_line": true, "read_dir": true,
+	"write_all": true, "flush": true, "sync_all": true, "set_len": true, "metadata": true,
+	"create_dir": true, "create_dir_all": true, "remove_file": true, "remove_dir_all": true,
+	"rename": true, "copy": true, "canonicalize": true,
+	"connect": true, "bind": true, "accept": true, "incoming": true, "set_nonblocking": true,
+}
+
+// RustUnwrapDensityDetector counts the unwrap and expect calls of functions
+// and files outside test code. Calls on a poisoned lock are counted apart
+// from calls on fallible I/O, as they only fail once another thread
+// panicked. Each threshold is off while zero.
+type RustUnwrapDensityDetector struct {
+	config *DetectorConfig
+}
+
+// rustUnwrapCounts counts unwrap and expect calls by receiver
+type rustUnwrapCounts struct {
+	lock  int
+	io    int
+	other int
+}
+
+// counted returns the calls counted against the thresholds, those not on a lock
+func (c rustUnwrapCounts) counted() int {
+	return c.io + c.other
+}
+
+// describe breaks the counted calls down by receiver and names the lock calls left out
+func (c rustUnwrapCounts) describe() string {
+	var parts []string
+	if c.io > 0 {
+		parts = append(parts, fmt.Sprintf("%d on fallible I/O", c.io))
+	}
+	if c.other > 0 {
+		parts = append(parts, fmt.Sprintf("%d on other results and options", c.other))
+	}
+	description := strings.Join(parts, ", ")
+	if c.lock > 0 {
+		description += fmt.Sprintf("; %d on lock guards not counted", c.lock)
+	}
+	return description
+}
+
+// NewRustUnwrapDensityDetector creates a new Rust unwrap density detector
+func NewRustUnwrapDensityDetector(config *DetectorConfig) *RustUnwrapDensityDetector {
+	if config == nil {
+		config = DefaultDetectorConfig()
+	}
+	return &RustUnwrapDensityDetector{config: config}
+}
+
+// Name returns the name of this detector
+func (d *RustUnwrapDensityDetector) Name() string {
+	return "Rust Unwrap Density"
+}
+
+// Description returns a description of what this detector checks for
+func (d *RustUnwrapDensityDetector) Description() string {
+	return "Detects functions and files with many unwrap() and expect() calls outside test code, counting lock guards apart from fallible I/O"
+}
+
+// Detect counts the unwrap and expect calls of a Rust file outside
+// #[cfg(test)] modules, #[test] functions and the tests directory
+func (d *RustUnwrapDensityDetector) Detect(fileInfo *models.FileInfo, astInfo interface{}) []*models.Violation {
+	rust := d.config.RustConfig
+	if rust == nil || rust.MaxUnwrapsPerFunction <= 0 && rust.MaxUnwrapsPerFile <= 0 {
+		return nil
+	}
+	info, ok := astInfo.(*types.RustASTInfo)
+	if !ok || info == nil || isRustTestsPath(fileInfo.Path) {
+		return nil
+	}
+
+	var file rustUnwrapCounts
+	byFunction := make(map[*types.RustFunctionInfo]*rustUnwrapCounts)
+	var functions []*types.RustFunctionInfo
+	firstLine := 0
+	for _, site := range info.PanicSites {
+		if site.InTest || site.Kind != types.RustPanicUnwrap && site.Kind != types.RustPanicExpect {
+			continue
+		}
+		receiver := rustUnwrapReceiver(info.Tokens, site)
+		countUnwrap(&file, receiver)
+		if firstLine == 0 {
+			firstLine = site.Line
+		}
+
+		fn := rustEnclosingFunction(info.Functions, site)
+		if fn == nil {
+			continue
+		}
+		if byFunction[fn] == nil {
+			byFunction[fn] = &rustUnwrapCounts{}
+			functions = append(functions, fn)
+		}
+		countUnwrap(byFunction[fn], receiver)
+	}
+
+	var violations []*models.Violation
+	if limit := rust.MaxUnwrapsPerFunction; limit > 0 {
+		sort.SliceStable(functions, func(i, j int) bool { return functions[i].StartLine < functions[j].StartLine })
+		for _, fn := range functions {
+			counts := byFunction[fn]
+			if counts.counted() <= limit {
+				continue
+			}
+			violations = append(violations, &models.Violation{
+				Type:     models.ViolationTypeRustOveruseUnwrap,
+				Severity: unwrapDensitySeverity(*counts),
+				Message: fmt.Sprintf("Function '%s' calls unwrap() or expect() %d times (max: %d): %s",
+					fn.Name, counts.counted(), limit, counts.describe()),
+				File:       fileInfo.Path,
+				Line:       fn.StartLine,
+				Column:     fn.StartColumn,
+				Rule:       RustUnwrapDensity,
+				Suggestion: unwrapDensitySuggestion(*counts),
+			})
+		}
+	}
+	if limit := rust.MaxUnwrapsPerFile; limit > 0 && file.counted() > limit {
+		violations = append(violations, &models.Violation{
+			Type:       models.ViolationTypeRustOveruseUnwrap,
+			Severity:   unwrapDensitySeverity(file),
+			Message:    fmt.Sprintf("File calls unwrap() or expect() %d times outside tests (max: %d): %s", file.counted(), limit, file.describe()),
+			File:       fileInfo.Path,
+			Line:       firstLine,
+			Column:     1,
+			Rule:       RustFileUnwrapDensity,
+			Suggestion: unwrapDensitySuggestion(file),
+		})
+	}
+	return violations
+}
+
+// countUnwrap adds a call on the given receiver to the counts
+func countUnwrap(counts *rustUnwrapCounts, receiver string) {
+	switch receiver {
+	case unwrapOnLock:
+		counts.lock++
+	case unwrapOnIO:
+		counts.io++
+	default:
+		counts.other++
+	}
+}
+
+// unwrapDensitySeverity rates counts with calls on fallible I/O as medium,
+// as they panic on ordinary runtime conditions such as a missing file
+func unwrapDensitySeverity(counts rustUnwrapCounts) models.Severity {
+	if counts.io > 0 {
+		return models.SeverityMedium
+	}
+	return models.SeverityLow
+}
+
+// unwrapDensitySuggestion suggests how to handle the errors the calls ignore
+func unwrapDensitySuggestion(counts rustUnwrapCounts) string {
+	if counts.io > 0 {
+		return "Return the I/O errors with ? and a Result, adding context where the caller needs it; " +
+			"keep unwrap() for invariants and explain them with expect()"
+	}
+	return "Propagate errors with ?, handle a missing value with if let, match or unwrap_or, " +
+		"and keep expect() with a message for invariants that cannot fail"
+}
+
+// rustUnwrapReceiver classifies the call an unwrap or expect is applied to by
+// the method or function called right before it, as `lock` in
+// `state.lock().unwrap()` and `open` in `File::open(path).unwrap()`
+func rustUnwrapReceiver(tokens []types.RustTokenInfo, site *types.RustPanicSite) string {
+	at := sort.Search(len(tokens), func(i int) bool {
+		return tokens[i].Line > site.Line || tokens[i].Line == site.Line && tokens[i].Column >= site.Column
+	})
+	if at >= len(tokens) || at < 2 || tokens[at].Text != site.Name || tokens[at-1].Text != "." || tokens[at-2].Text != ")" {
+		return unwrapOnOther
+	}
+
+	depth := 0
+	open := -1
+	for k := at - 2; k >= 0 && open < 0; k-- {
+		switch tokens[k].Text {
+		case ")":
+			depth++
+		case "(":
+			depth--
+			if depth == 0 {
+				open = k
+			}
+		}
+	}
+	if open < 1 || tokens[open-1].Kind != types.RustTokenIdent {
+		return unwrapOnOther
+	}
+
+	called := tokens[open-1].Text
+	noArguments := open+1 == at-2
+	switch {
+	case rustLockMethods[called] && noArguments && open >= 2 && tokens[open-2].Text == ".":
+		return unwrapOnLock
+	case rustIOFunctions[called]:
+		return unwrapOnIO
+	}
+	return unwrapOnOther
+}
+
+// rustEnclosingFunction returns the innermost function named by a panic site
+// that contains its line
+func rustEnclosingFunction(functions []*types.RustFunctionInfo, site *types.RustPanicSite) *types.RustFunctionInfo {
+	var enclosing *types.RustFunctionInfo
+	for _, fn := range functions {
+		if fn == nil || fn.Name != site.Function || site.Line < fn.StartLine || site.Line > fn.EndLine {
+			continue
+		}
+		if enclosing == nil || fn.StartLine >= enclosing.StartLine {
+			enclosing = fn
+		}
+	}
+	return enclosing
+}
+
+// isRustTestsPath reports whether a file lies in a tests directory, where
+// Cargo keeps integration tests
+func isRustTestsPath(path string) bool {
+	for _, part := range strings.Split(filepath.ToSlash(filepath.Dir(path)), "/") {
+		if part == "tests" {
+			return true
+		}
+	}
+	return false
+}
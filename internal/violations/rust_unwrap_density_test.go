@@ -0,0 +1,83 @@
+package violations
+
+import (
+	"testing"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+func TestRustUnwrapDensityDetector(t *testing.T) {
+	source := `use std::fs::File;
+use std::io::Read;
+
+pub fn load(path: &str, state: &Mutex<State>) -> String {
+    let mut file = File::open(path).unwrap();
+    let mut text = String::new();
+    file.read_to_string(&mut text).expect("readable");
+    let guard = state.lock().unwrap();
+    let cache = guard.cache.read().unwrap();
+    let first = text.lines().next().unwrap();
+    first.to_string()
+}
+
+pub fn parse(text: &str) -> u32 {
+    let n: u32 = text.trim().parse().unwrap();
+    n
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn loads() {
+        super::parse("1").unwrap();
+        super::parse("2").unwrap();
+    }
+}`
+	functions := []*types.RustFunctionInfo{
+		{Name: "load", StartLine: 4, EndLine: 12, StartColumn: 1},
+		{Name: "parse", StartLine: 14, EndLine: 17, StartColumn: 1},
+		{Name: "loads", StartLine: 22, EndLine: 25, StartColumn: 5},
+	}
+	astInfo := &types.RustASTInfo{Tokens: rustTestTokens(source), Functions: functions}
+	for i, token := range astInfo.Tokens {
+		if (token.Text != "unwrap" && token.Text != "expect") || astInfo.Tokens[i-1].Text != "." {
+			continue
+		}
+		site := &types.RustPanicSite{Kind: token.Text, Name: token.Text, Line: token.Line, Column: token.Column, InTest: token.Line > 19}
+		for _, fn := range functions {
+			if fn.StartLine <= token.Line && token.Line <= fn.EndLine {
+				site.Function = fn.Name
+			}
+		}
+		astInfo.PanicSites = append(astInfo.PanicSites, site)
+	}
+
+	config := DefaultDetectorConfig()
+	detector := NewRustUnwrapDensityDetector(config)
+	fileInfo := &models.FileInfo{Path: "src/loader.rs"}
+	if violations := detector.Detect(fileInfo, astInfo); len(violations) != 0 {
+		t.Fatalf("Expected unwrap density to be disabled by default, got %+v", violations)
+	}
+
+	config.RustConfig.MaxUnwrapsPerFunction = 2
+	config.RustConfig.MaxUnwrapsPerFile = 3
+	violations := detector.Detect(fileInfo, astInfo)
+	if len(violations) != 2 {
+		t.Fatalf("Expected a function and a file violation, got %+v", violations)
+	}
+	function := violations[0]
+	if function.Rule != RustUnwrapDensity || function.Line != 4 || function.Severity != models.SeverityMedium ||
+		function.Message != "Function 'load' calls unwrap() or expect() 3 times (max: 2): 2 on fallible I/O, 1 on other results and options; 2 on lock guards not counted" {
+		t.Errorf("Unexpected function violation %+v", function)
+	}
+	file := violations[1]
+	if file.Rule != RustFileUnwrapDensity || file.Line != 5 ||
+		file.Message != "File calls unwrap() or expect() 4 times outside tests (max: 3): 2 on fallible I/O, 2 on other results and options; 2 on lock guards not counted" {
+		t.Errorf("Unexpected file violation %+v", file)
+	}
+
+	if violations := detector.Detect(&models.FileInfo{Path: "tests/loader.rs"}, astInfo); len(violations) != 0 {
+		t.Errorf("Expected files in the tests directory to be skipped, got %+v", violations)
+	}
+}
@@ -0,0 +1,306 @@
+package violations
+
+import (
+	"fmt"
+	"os"
+	"strings"
+	"sync"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/script"
+	"github.com/ericfisherdev/goclean/pkg/plugin"
+)
+
+// Nodes a script rule can check
+const (
+	ScriptMatchFile     = "file"
+	ScriptMatchFunction = "function"
+	ScriptMatchType     = "type"
+	ScriptMatchImport   = "import"
+)
+
+// scriptFileVariables are available to every script rule
+var scriptFileVariables = []string{"file", "functions", "types", "imports"}
+
+// scriptNodeVariables are the fields of the checked node, available as
+// variables of the rules matching it
+var scriptNodeVariables = map[string][]string{
+	ScriptMatchFile:     {"path", "language", "lines"},
+	ScriptMatchFunction: {"name", "receiver", "start_line", "end_line", "lines", "complexity", "parameters", "exported", "async", "unsafe"},
+	ScriptMatchType:     {"name", "kind", "start_line", "end_line", "members", "exported"},
+	ScriptMatchImport:   {"path", "alias", "line"},
+}
+
+// ScriptRuleSpec defines a custom rule as an expression over the parsed
+// files, functions, types or imports
+type ScriptRuleSpec struct {
+	ID         string
+	Match      string // ScriptMatchFile, ScriptMatchFunction, ScriptMatchType or ScriptMatchImport
+	When       string // Expression that is true for the nodes violating the rule
+	Message    string // Message with {expression} placeholders; the rule ID when empty
+	Severity   models.Severity
+	Suggestion string
+	Languages  []string // Languages the rule checks; all when empty
+	Fuel       int      // Steps one evaluation may take; script.DefaultFuel when 0
+}
+
+// scriptRule is a compiled script rule
+type scriptRule struct {
+	spec    ScriptRuleSpec
+	when    *script.Program
+	message *script.Template
+}
+
+// ScriptRuleDetector reports the nodes for which custom script rules hold.
+// A rule whose evaluation fails is reported once and skipped for the rest
+// of the scan.
+type ScriptRuleDetector struct {
+	rules  []*scriptRule
+	mutex  sync.Mutex
+	failed map[string]bool
+}
+
+// ValidateScriptRule compiles the expressions of a rule and checks that they
+// only read variables of the nodes the rule matches
+func ValidateScriptRule(spec ScriptRuleSpec) error {
+	_, err := compileScriptRule(spec)
+	return err
+}
+
+// compileScriptRule parses the condition and message of a rule
+func compileScriptRule(spec ScriptRuleSpec) (*scriptRule, error) {
+	nodeVariables, found := scriptNodeVariables[spec.Match]
+	if !found {
+		return nil, fmt.Errorf("script rule %s: unknown match %q (use file, function, type or import)", spec.ID, spec.Match)
+	}
+	known := make(map[string]bool)
+	for _, name := range append(append([]string{}, scriptFileVariables...), nodeVariables...) {
+		known[name] = true
+	}
+
+	when, err := script.Compile(spec.When)
+	if err != nil {
+		return nil, fmt.Errorf("script rule %s: when: %w", spec.ID, err)
+	}
+	messageSource := spec.Message
+	if messageSource == "" {
+		messageSource = spec.ID
+	}
+	message, err := script.CompileTemplate(messageSource)
+	if err != nil {
+		return nil, fmt.Errorf("script rule %s: message: %w", spec.ID, err)
+	}
+
+	for _, name := range append(when.Variables(), message.Variables()...) {
+		if !known[name] {
+			return nil, fmt.Errorf("script rule %s: unknown variable %s for %s rules", spec.ID, name, spec.Match)
+		}
+	}
+	return &scriptRule{spec: spec, when: when, message: message}, nil
+}
+
+// NewScriptRuleDetector compiles script rules into a detector. Invalid
+// rules are reported on standard error and left out.
+func NewScriptRuleDetector(specs []ScriptRuleSpec) *ScriptRuleDetector {
+	detector := &ScriptRuleDetector{failed: make(map[string]bool)}
+	for _, spec := range specs {
+		rule, err := compileScriptRule(spec)
+		if err != nil {
+			fmt.Fprintf(os.Stderr, "Warning: %v\n", err)
+			continue
+		}
+		detector.rules = append(detector.rules, rule)
+	}
+	return detector
+}
+
+// Name returns the name of this detector
+func (d *ScriptRuleDetector) Name() string {
+	return "Script Rules"
+}
+
+// Description returns a description of what this detector checks for
+func (d *ScriptRuleDetector) Description() string {
+	return "Reports the nodes matching custom rules written as script expressions"
+}
+
+// Detect evaluates the script rules on the nodes of a Go or Rust file
+func (d *ScriptRuleDetector) Detect(fileInfo *models.FileInfo, astInfo interface{}) []*models.Violation {
+	var violations []*models.Violation
+
+	request := newPluginRequest(fileInfo, astInfo)
+	if request == nil {
+		return violations
+	}
+	fileEnv := scriptFileEnv(request)
+
+	for _, rule := range d.rules {
+		if !rule.handles(request.File.Language) || d.hasFailed(rule.spec.ID) {
+			continue
+		}
+		for _, node := range scriptNodes(rule.spec.Match, request) {
+			env := make(map[string]interface{}, len(fileEnv)+len(node.fields))
+			for name, value := range fileEnv {
+				env[name] = value
+			}
+			for name, value := range node.fields {
+				env[name] = value
+			}
+
+			violation, err := rule.evaluate(env, fileInfo.Path, node)
+			if err != nil {
+				d.fail(rule.spec.ID, fmt.Errorf("%s:%d: %w", fileInfo.Path, node.line, err))
+				break
+			}
+			if violation != nil {
+				violations = append(violations, violation)
+			}
+		}
+	}
+	return violations
+}
+
+// hasFailed reports whether a rule was disabled after an evaluation error
+func (d *ScriptRuleDetector) hasFailed(id string) bool {
+	d.mutex.Lock()
+	defer d.mutex.Unlock()
+	return d.failed[id]
+}
+
+// fail reports a failing rule once and disables it for the rest of the scan
+func (d *ScriptRuleDetector) fail(id string, err error) {
+	d.mutex.Lock()
+	defer d.mutex.Unlock()
+
+	if !d.failed[id] {
+		fmt.Fprintf(os.Stderr, "Warning: script rule %s disabled for this scan: %v\n", id, err)
+		d.failed[id] = true
+	}
+}
+
+// handles reports whether a rule checks files of a language
+func (r *scriptRule) handles(language string) bool {
+	if len(r.spec.Languages) == 0 {
+		return true
+	}
+	for _, handled := range r.spec.Languages {
+		if strings.EqualFold(handled, language) {
+			return true
+		}
+	}
+	return false
+}
+
+// evaluate returns the violation of a node, or nil when the rule holds
+func (r *scriptRule) evaluate(env map[string]interface{}, path string, node scriptNode) (*models.Violation, error) {
+	fuel := r.spec.Fuel
+	if fuel <= 0 {
+		fuel = script.DefaultFuel
+	}
+	violated, err := r.when.EvalBool(env, fuel)
+	if err != nil || !violated {
+		return nil, err
+	}
+	message, err := r.message.Execute(env, fuel)
+	if err != nil {
+		return nil, err
+	}
+
+	return &models.Violation{
+		Type:       models.ViolationTypeScriptRule,
+		Severity:   r.spec.Severity,
+		Message:    message,
+		File:       path,
+		Line:       max(node.line, 1),
+		Column:     1,
+		EndLine:    node.endLine,
+		Rule:       r.spec.ID,
+		Suggestion: r.spec.Suggestion,
+	}, nil
+}
+
+// scriptNode is a file, function, type or import a rule checks
+type scriptNode struct {
+	fields  map[string]interface{}
+	line    int
+	endLine int
+}
+
+// scriptFileEnv returns the variables describing a whole file
+func scriptFileEnv(request *plugin.Request) map[string]interface{} {
+	functions := make([]interface{}, 0, len(request.Functions))
+	for _, function := range request.Functions {
+		functions = append(functions, scriptFunction(function))
+	}
+	typeList := make([]interface{}, 0, len(request.Types))
+	for _, typeInfo := range request.Types {
+		typeList = append(typeList, scriptType(typeInfo))
+	}
+	imports := make([]interface{}, 0, len(request.Imports))
+	for _, imported := range request.Imports {
+		imports = append(imports, scriptImport(imported))
+	}
+
+	return map[string]interface{}{
+		"file":      scriptFile(request.File),
+		"functions": functions,
+		"types":     typeList,
+		"imports":   imports,
+	}
+}
+
+// scriptNodes returns the nodes of a file a rule matching kind checks
+func scriptNodes(kind string, request *plugin.Request) []scriptNode {
+	var nodes []scriptNode
+	switch kind {
+	case ScriptMatchFile:
+		nodes = append(nodes, scriptNode{fields: scriptFile(request.File), line: 1})
+	case ScriptMatchFunction:
+		for _, function := range request.Functions {
+			nodes = append(nodes, scriptNode{fields: scriptFunction(function), line: function.StartLine, endLine: function.EndLine})
+		}
+	case ScriptMatchType:
+		for _, typeInfo := range request.Types {
+			nodes = append(nodes, scriptNode{fields: scriptType(typeInfo), line: typeInfo.StartLine, endLine: typeInfo.EndLine})
+		}
+	case ScriptMatchImport:
+		for _, imported := range request.Imports {
+			nodes = append(nodes, scriptNode{fields: scriptImport(imported), line: imported.Line})
+		}
+	}
+	return nodes
+}
+
+func scriptFile(file plugin.File) map[string]interface{} {
+	return map[string]interface{}{"path": file.Path, "language": file.Language, "lines": file.Lines}
+}
+
+func scriptFunction(function plugin.Function) map[string]interface{} {
+	return map[string]interface{}{
+		"name":       function.Name,
+		"receiver":   function.Receiver,
+		"start_line": function.StartLine,
+		"end_line":   function.EndLine,
+		"lines":      function.Lines,
+		"complexity": function.Complexity,
+		"parameters": function.Parameters,
+		"exported":   function.Exported,
+		"async":      function.Async,
+		"unsafe":     function.Unsafe,
+	}
+}
+
+func scriptType(typeInfo plugin.Type) map[string]interface{} {
+	return map[string]interface{}{
+		"name":       typeInfo.Name,
+		"kind":       typeInfo.Kind,
+		"start_line": typeInfo.StartLine,
+		"end_line":   typeInfo.EndLine,
+		"members":    typeInfo.Members,
+		"exported":   typeInfo.Exported,
+	}
+}
+
+func scriptImport(imported plugin.Import) map[string]interface{} {
+	return map[string]interface{}{"path": imported.Path, "alias": imported.Alias, "line": imported.Line}
+}
@@ -0,0 +1,98 @@
+package violations
+
+import (
+	"strings"
+	"testing"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+// scriptRuleAST returns a Go file with a long exported handler, a short
+// helper, a struct and an import
+func scriptRuleAST() *types.GoASTInfo {
+	return &types.GoASTInfo{
+		Functions: []*types.FunctionInfo{
+			{Name: "ServeHTTP", StartLine: 10, EndLine: 60, LineCount: 51, Complexity: 9, IsExported: true},
+			{Name: "helper", StartLine: 62, EndLine: 66, LineCount: 5, Complexity: 1},
+		},
+		Types:   []*types.TypeInfo{{Name: "Server", Kind: "struct", StartLine: 3, EndLine: 8, FieldCount: 12, IsExported: true}},
+		Imports: []*types.ImportInfo{{Path: "unsafe", Line: 1}},
+	}
+}
+
+func TestScriptRuleDetector(t *testing.T) {
+	detector := NewScriptRuleDetector([]ScriptRuleSpec{
+		{ID: "long-handler", Match: ScriptMatchFunction, When: `exported && lines > 40`,
+			Message: "Handler {name} has {lines} lines", Severity: models.SeverityMedium},
+		{ID: "wide-struct", Match: ScriptMatchType, When: `kind == "struct" && members > 10`},
+		{ID: "no-unsafe", Match: ScriptMatchImport, When: `path == "unsafe"`, Languages: []string{"rust"}},
+		{ID: "many-functions", Match: ScriptMatchFile, When: `count(functions, f -> f.complexity > 5) > 0`,
+			Message: "{path} has complex functions"},
+	})
+	fileInfo := &models.FileInfo{Path: "server.go", Language: "Go"}
+
+	found := make(map[string]*models.Violation)
+	for _, violation := range detector.Detect(fileInfo, scriptRuleAST()) {
+		found[violation.Rule] = violation
+	}
+	if len(found) != 3 {
+		t.Fatalf("Expected 3 violations, got %v", found)
+	}
+
+	handler := found["long-handler"]
+	if handler == nil || handler.Message != "Handler ServeHTTP has 51 lines" || handler.Line != 10 || handler.EndLine != 60 {
+		t.Errorf("Unexpected long-handler violation %+v", handler)
+	}
+	if handler != nil && (handler.Severity != models.SeverityMedium || handler.Type != models.ViolationTypeScriptRule) {
+		t.Errorf("Expected a medium script rule violation, got %v %s", handler.Severity, handler.Type)
+	}
+	if wide := found["wide-struct"]; wide == nil || wide.Message != "wide-struct" || wide.Line != 3 {
+		t.Errorf("Expected the rule ID as default message on line 3, got %+v", wide)
+	}
+	if file := found["many-functions"]; file == nil || file.Message != "server.go has complex functions" || file.Line != 1 {
+		t.Errorf("Unexpected file violation %+v", file)
+	}
+}
+
+func TestScriptRuleDetectorDisablesFailingRules(t *testing.T) {
+	detector := NewScriptRuleDetector([]ScriptRuleSpec{
+		{ID: "broken", Match: ScriptMatchFunction, When: `lines / (complexity - 1) > 2`},
+		{ID: "hungry", Match: ScriptMatchFile, When: `count(functions, f -> true) > 0`, Fuel: 2},
+	})
+	fileInfo := &models.FileInfo{Path: "server.go", Language: "Go"}
+
+	// ServeHTTP passes; helper divides by zero and disables the rule
+	violations := detector.Detect(fileInfo, scriptRuleAST())
+	if len(violations) != 1 || violations[0].Rule != "broken" {
+		t.Fatalf("Expected only the violation found before the failure, got %v", violations)
+	}
+	if !detector.hasFailed("broken") || !detector.hasFailed("hungry") {
+		t.Error("Expected both rules to be disabled")
+	}
+	if violations := detector.Detect(fileInfo, scriptRuleAST()); len(violations) != 0 {
+		t.Errorf("Expected disabled rules to be skipped, got %v", violations)
+	}
+}
+
+func TestValidateScriptRule(t *testing.T) {
+	testCases := []struct {
+		spec     ScriptRuleSpec
+		expected string
+	}{
+		{ScriptRuleSpec{ID: "ok", Match: ScriptMatchImport, When: `path =~ "^internal/"`, Message: "{path} on line {line}"}, ""},
+		{ScriptRuleSpec{ID: "typo", Match: ScriptMatchFunction, When: `linez > 40`}, "unknown variable linez for function rules"},
+		{ScriptRuleSpec{ID: "wrong-node", Match: ScriptMatchType, When: `true`, Message: "{complexity}"}, "unknown variable complexity"},
+		{ScriptRuleSpec{ID: "syntax", Match: ScriptMatchFile, When: `lines >`}, "script rule syntax: when: unexpected end"},
+		{ScriptRuleSpec{ID: "kind", Match: "statement", When: `true`}, `unknown match "statement"`},
+	}
+	for _, tc := range testCases {
+		err := ValidateScriptRule(tc.spec)
+		switch {
+		case tc.expected == "" && err != nil:
+			t.Errorf("Expected %s to be valid, got %v", tc.spec.ID, err)
+		case tc.expected != "" && (err == nil || !strings.Contains(err.Error(), tc.expected)):
+			t.Errorf("Expected an error containing %q for %s, got %v", tc.expected, tc.spec.ID, err)
+		}
+	}
+}
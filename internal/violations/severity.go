@@ -25,6 +25,9 @@ type SeverityConfig struct {
 
 	// Violation type specific severity overrides
 	ViolationTypeWeights map[models.ViolationType]float64 `yaml:"violation_type_weights"`
+
+	// Fixed severities by rule ID, replacing the classified severity
+	RuleSeverities map[string]models.Severity `yaml:"rule_severities"`
 }
 
 // ViolationContext provides context information for severity calculation
@@ -62,6 +65,7 @@ func DefaultSeverityConfig() *SeverityConfig {
 		ViolationTypeWeights: map[models.ViolationType]float64{
 			models.ViolationTypeFunctionLength:         1.0,
 			models.ViolationTypeCyclomaticComplexity:  1.2, // More critical
+			models.ViolationTypeCognitiveComplexity:   1.2,
 			models.ViolationTypeParameterCount:        0.8,
 			models.ViolationTypeNestingDepth:         1.1,
 			models.ViolationTypeNaming:               0.7,
@@ -101,6 +105,23 @@ func (s *SeverityClassifier) ClassifySeverityFloat(violationType models.Violatio
 	return finalSeverity
 }
 
+// ApplyRuleSeverities sets the configured severity on violations of
+// overridden rules. Violations without a rule ID are matched by type.
+func (s *SeverityClassifier) ApplyRuleSeverities(violations []*models.Violation) {
+	if len(s.config.RuleSeverities) == 0 {
+		return
+	}
+	for _, violation := range violations {
+		rule := violation.Rule
+		if rule == "" {
+			rule = string(violation.Type)
+		}
+		if severity, ok := s.config.RuleSeverities[rule]; ok {
+			violation.Severity = severity
+		}
+	}
+}
+
 // calculateBaseSeverity determines base severity level based on threshold multipliers
 func (s *SeverityClassifier) calculateBaseSeverity(actualValue, threshold int) models.Severity {
 	ratio := float64(actualValue) / float64(threshold)
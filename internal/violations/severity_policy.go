@@ -0,0 +1,35 @@
+package violations
+
+import (
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/pkg/severity"
+)
+
+// ApplySeverityPolicy sets the severity the policy returns on the violations
+// found in file and returns how many severities changed. A nil policy
+// changes nothing. Apply it after the rule severities, so the policy has the
+// final word.
+func ApplySeverityPolicy(policy severity.Policy, inputs severity.Inputs, file *models.FileInfo, found []*models.Violation) int {
+	if policy == nil || file == nil {
+		return 0
+	}
+	changed := 0
+	for _, violation := range found {
+		view := &severity.Violation{
+			Rule:     violation.Rule,
+			Type:     string(violation.Type),
+			Category: violation.Type.Category(),
+			Severity: severity.Level(violation.Severity),
+			Message:  violation.Message,
+			File:     violation.File,
+			Line:     violation.Line,
+			EndLine:  violation.EndLine,
+		}
+		level := models.Severity(policy.Severity(view, inputs.Context(file.Path, file.Language, violation.Line, violation.EndLine)))
+		if level != violation.Severity {
+			violation.Severity = level
+			changed++
+		}
+	}
+	return changed
+}
@@ -0,0 +1,75 @@
+package violations
+
+import (
+	"strings"
+	"testing"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/pkg/severity"
+)
+
+func TestApplySeverityPolicy(t *testing.T) {
+	payments := &models.Violation{File: "payments/charge.go", Line: 10, EndLine: 11, Severity: models.SeverityLow}
+	untouched := &models.Violation{File: "payments/charge.go", Line: 50, EndLine: 52, Severity: models.SeverityLow}
+	test := &models.Violation{File: "api/handler_test.go", Line: 3, Severity: models.SeverityMedium}
+
+	// Escalate changed payments code, lower test code
+	policy := severity.PolicyFunc(func(violation *severity.Violation, context severity.Context) severity.Level {
+		switch {
+		case context.TestFile:
+			return severity.Info
+		case strings.HasPrefix(context.File, "payments/") && context.Changed:
+			return severity.Critical
+		}
+		return violation.Severity
+	})
+	inputs := severity.Inputs{
+		IsTestFile: func(path string) bool { return strings.HasSuffix(path, "_test.go") },
+		Touches: func(path string, start, end int) bool {
+			return path == "payments/charge.go" && start <= 12 && end >= 8
+		},
+	}
+
+	changed := ApplySeverityPolicy(policy, inputs, &models.FileInfo{Path: "payments/charge.go"}, []*models.Violation{payments, untouched})
+	changed += ApplySeverityPolicy(policy, inputs, &models.FileInfo{Path: "api/handler_test.go"}, []*models.Violation{test})
+	if changed != 2 {
+		t.Errorf("Expected 2 changed severities, got %d", changed)
+	}
+	if payments.Severity != models.SeverityCritical {
+		t.Errorf("Expected the changed payments violation to be critical, got %v", payments.Severity)
+	}
+	if untouched.Severity != models.SeverityLow {
+		t.Errorf("Expected the unchanged payments violation to keep its severity, got %v", untouched.Severity)
+	}
+	if test.Severity != models.SeverityInfo {
+		t.Errorf("Expected the test file violation to be info, got %v", test.Severity)
+	}
+}
+
+func TestPolicyContextWithoutInputs(t *testing.T) {
+	var got severity.Context
+	var seen *severity.Violation
+	policy := severity.PolicyFunc(func(violation *severity.Violation, context severity.Context) severity.Level {
+		got, seen = context, violation
+		return violation.Severity
+	})
+	file := &models.FileInfo{Path: "main.go", Language: "Go"}
+	found := []*models.Violation{{File: "main.go", Line: 1, Rule: "function-length", Type: models.ViolationTypeFunctionLength, Severity: models.SeverityHigh}}
+
+	if changed := ApplySeverityPolicy(policy, severity.Inputs{}, file, found); changed != 0 {
+		t.Errorf("Expected no changed severities, got %d", changed)
+	}
+	if got != (severity.Context{File: "main.go", Language: "Go"}) {
+		t.Errorf("Expected only the file without inputs, got %+v", got)
+	}
+	if seen == nil || seen.Rule != "function-length" || seen.Severity != severity.High {
+		t.Errorf("Expected the violation's rule and severity, got %+v", seen)
+	}
+}
+
+func TestApplySeverityPolicyWithoutPolicy(t *testing.T) {
+	found := []*models.Violation{{File: "main.go", Line: 1, Severity: models.SeverityLow}}
+	if changed := ApplySeverityPolicy(nil, severity.Inputs{}, &models.FileInfo{Path: "main.go"}, found); changed != 0 {
+		t.Errorf("Expected a nil policy to change nothing, got %d", changed)
+	}
+}
@@ -82,6 +82,9 @@ func (d *StructureDetector) Detect(fileInfo *models.FileInfo, astInfo interface{
 	// Check for god objects (types with too many methods)
 	violations = append(violations, d.checkForGodObjects(goAstInfo, fileInfo.Path)...)
 
+	// Check for god structs (structs with too many fields and methods)
+	violations = append(violations, d.checkForGodStructs(goAstInfo, fileInfo.Path)...)
+
 	// Check for magic numbers
 	violations = append(violations, d.checkForMagicNumbers(goAstInfo, fileInfo.Path)...)
 
@@ -197,6 +200,76 @@ func (d *StructureDetector) checkForGodObjects(goAstInfo *types.GoASTInfo, fileP
 	return violations
 }
 
+// checkForGodStructs identifies structs holding too many fields and too many
+// methods, and the responsibility clusters their field names form
+func (d *StructureDetector) checkForGodStructs(goAstInfo *types.GoASTInfo, filePath string) []*models.Violation {
+	var violations []*models.Violation
+
+	if !godStructEnabled(d.config) {
+		return violations
+	}
+
+	methodCounts := make(map[string]int)
+	for _, fn := range goAstInfo.Functions {
+		if fn.IsMethod && fn.ReceiverType != "" {
+			methodCounts[d.cleanReceiverType(fn.ReceiverType)]++
+		}
+	}
+
+	for _, typeInfo := range goAstInfo.Types {
+		if typeInfo == nil || typeInfo.Kind != "struct" || typeInfo.ASTNode == nil {
+			continue
+		}
+		structType, ok := typeInfo.ASTNode.Type.(*ast.StructType)
+		if !ok || structType.Fields == nil {
+			continue
+		}
+
+		var fields []string
+		for _, field := range structType.Fields.List {
+			for _, name := range field.Names {
+				fields = append(fields, name.Name)
+			}
+			if len(field.Names) == 0 {
+				// Embedded fields are named after their type
+				fields = append(fields, embeddedFieldName(field.Type))
+			}
+		}
+
+		s := godStruct{
+			name:      typeInfo.Name,
+			fields:    fields,
+			methods:   methodCounts[typeInfo.Name],
+			line:      typeInfo.StartLine,
+			column:    typeInfo.StartColumn,
+			endLine:   typeInfo.EndLine,
+			endColumn: typeInfo.EndColumn,
+		}
+		if violation := godStructViolation(d.config, s, GodStruct, filePath); violation != nil {
+			violations = append(violations, violation)
+		}
+	}
+
+	return violations
+}
+
+// embeddedFieldName returns the name of an embedded field, which is the name of its type
+func embeddedFieldName(expr ast.Expr) string {
+	switch t := expr.(type) {
+	case *ast.Ident:
+		return t.Name
+	case *ast.StarExpr:
+		return embeddedFieldName(t.X)
+	case *ast.SelectorExpr:
+		return t.Sel.Name
+	case *ast.IndexExpr:
+		return embeddedFieldName(t.X)
+	case *ast.IndexListExpr:
+		return embeddedFieldName(t.X)
+	}
+	return ""
+}
+
 // checkForMagicNumbers identifies hardcoded numeric literals that should be constants
 func (d *StructureDetector) checkForMagicNumbers(goAstInfo *types.GoASTInfo, filePath string) []*models.Violation {
 	var violations []*models.Violation
@@ -0,0 +1,66 @@
+package violations
+
+import (
+	"sort"
+	"sync"
+	"time"
+)
+
+// DetectorTiming is the time one detector spent over a scan
+type DetectorTiming struct {
+	Name     string
+	Files    int           // Files the detector ran on
+	Duration time.Duration // Wall time summed over the files, across workers
+}
+
+// timingTable accumulates detector timings from concurrent workers
+type timingTable struct {
+	mutex   sync.Mutex
+	timings map[string]*DetectorTiming
+}
+
+// EnableTimings makes the registry measure the time each detector takes
+func (r *DetectorRegistry) EnableTimings() {
+	if r.timings == nil {
+		r.timings = &timingTable{timings: make(map[string]*DetectorTiming)}
+	}
+}
+
+// RecordTiming adds the time a detector took on one file. It does nothing
+// unless timings are enabled.
+func (r *DetectorRegistry) RecordTiming(name string, duration time.Duration) {
+	if r.timings == nil {
+		return
+	}
+	r.timings.mutex.Lock()
+	defer r.timings.mutex.Unlock()
+
+	timing := r.timings.timings[name]
+	if timing == nil {
+		timing = &DetectorTiming{Name: name}
+		r.timings.timings[name] = timing
+	}
+	timing.Files++
+	timing.Duration += duration
+}
+
+// Timings returns the recorded detector timings, slowest first
+func (r *DetectorRegistry) Timings() []DetectorTiming {
+	if r.timings == nil {
+		return nil
+	}
+	r.timings.mutex.Lock()
+	defer r.timings.mutex.Unlock()
+
+	timings := make([]DetectorTiming, 0, len(r.timings.timings))
+	for _, timing := range r.timings.timings {
+		timings = append(timings, *timing)
+	}
+	sort.Slice(timings, func(i, j int) bool {
+		if timings[i].Duration != timings[j].Duration {
+			return timings[i].Duration > timings[j].Duration
+		}
+		return timings[i].Name < timings[j].Name
+	})
+	return timings
+}
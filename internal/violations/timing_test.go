@@ -0,0 +1,33 @@
+package violations
+
+import (
+	"testing"
+	"time"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+)
+
+func TestDetectorRegistryTimings(t *testing.T) {
+	registry := NewDetectorRegistry()
+	registry.RegisterDetector(NewFunctionDetector(nil))
+	registry.DetectAll(&models.FileInfo{Path: "a.go"}, nil)
+	if timings := registry.Timings(); timings != nil {
+		t.Fatalf("Expected no timings before EnableTimings, got %v", timings)
+	}
+
+	registry.EnableTimings()
+	registry.DetectAll(&models.FileInfo{Path: "a.go"}, nil)
+	registry.DetectAll(&models.FileInfo{Path: "b.go"}, nil)
+	registry.RecordTiming("Clone Detector", time.Hour)
+
+	timings := registry.Timings()
+	if len(timings) != 2 {
+		t.Fatalf("Expected 2 timings, got %v", timings)
+	}
+	if timings[0].Name != "Clone Detector" || timings[0].Files != 1 {
+		t.Errorf("Expected the slowest detector first, got %+v", timings[0])
+	}
+	if timings[1].Name != NewFunctionDetector(nil).Name() || timings[1].Files != 2 {
+		t.Errorf("Expected the function detector to have run on 2 files, got %+v", timings[1])
+	}
+}
@@ -95,6 +95,7 @@ func (d *TodoTrackerDetector) checkCommentGroupForMarkers(group *ast.CommentGrou
 				Line:        pos.Line,
 				Column:      pos.Column,
 				Message:     fmt.Sprintf("%s marker found: %s", marker, d.getMarkerDescription(marker)),
+				Rule:        "todo-comment",
 				Suggestion:  d.getMarkerSuggestion(marker),
 				CodeSnippet: fmt.Sprintf("%s: %s", marker, description),
 			}
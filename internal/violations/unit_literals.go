@@ -0,0 +1,471 @@
+package violations
+
+import (
+	"fmt"
+	"go/ast"
+	"go/token"
+	"math"
+	"strings"
+	"unicode"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+// Rules of the unit literal check
+const (
+	UnitLiteral     = "unit-literal"
+	RustUnitLiteral = "rust-unit-literal"
+)
+
+// Kinds of quantity a bare literal can look like
+const (
+	UnitKindDuration = "duration"
+	UnitKindSize     = "size"
+	UnitKindPort     = "port"
+)
+
+// DefaultUnitLiteralMinValue is the smallest literal checked when no minimum is configured
+const DefaultUnitLiteralMinValue = 60
+
+// Byte and second multiples the suggestions are written in
+const (
+	kibibyte      = 1024
+	mebibyte      = 1024 * kibibyte
+	gibibyte      = 1024 * mebibyte
+	secondsPerMin = 60
+	secondsPerHr  = 60 * secondsPerMin
+	secondsPerDay = 24 * secondsPerHr
+	maxPort       = 65535
+)
+
+// UnitLiteralConfig enables the check for bare numeric literals that look
+// like durations, sizes or ports. The zero value disables it.
+type UnitLiteralConfig struct {
+	Enabled  bool
+	MinValue int      // Smallest literal checked; 0 uses DefaultUnitLiteralMinValue
+	Kinds    []string // Kinds reported, of duration, size and port; empty reports all
+}
+
+// unitKindWords are the identifier words that give a literal its kind, as
+// timeout does in request_timeout
+var unitKindWords = map[string][]string{
+	UnitKindDuration: {"timeout", "ttl", "interval", "delay", "duration", "expiry", "expiration", "period", "deadline", "backoff", "lifetime", "age"},
+	UnitKindSize:     {"size", "capacity", "buffer", "quota", "chunk", "memory"},
+	UnitKindPort:     {"port"},
+}
+
+// unitWords name a unit. A literal held by an identifier with one, such as
+// timeout_secs or Duration::from_millis, already states its unit.
+var unitWords = map[string]bool{
+	"ns": true, "nanos": true, "us": true, "micros": true, "ms": true, "millis": true, "secs": true, "seconds": true,
+	"mins": true, "minutes": true, "hours": true, "days": true, "bytes": true,
+	"kb": true, "kib": true, "mb": true, "mib": true, "gb": true, "gib": true,
+}
+
+// wellKnownPorts are ports recognized without a port in the context
+var wellKnownPorts = map[int64]bool{
+	3306: true, 5432: true, 5672: true, 6379: true, 8080: true, 8443: true, 9092: true, 9200: true, 27017: true,
+}
+
+// kindOf returns the kind of quantity a literal looks like in its context,
+// or "" when it is not reported
+func (c *UnitLiteralConfig) kindOf(value int64, context string) string {
+	minValue := int64(c.MinValue)
+	if minValue <= 0 {
+		minValue = DefaultUnitLiteralMinValue
+	}
+	if !c.Enabled || value < minValue {
+		return ""
+	}
+	kind := classifyUnitLiteral(value, context)
+	if kind == "" || len(c.Kinds) == 0 {
+		return kind
+	}
+	for _, enabled := range c.Kinds {
+		if enabled == kind {
+			return kind
+		}
+	}
+	return ""
+}
+
+// classifyUnitLiteral returns the kind of quantity a literal looks like. A
+// context naming a kind decides it; without one only unmistakable values
+// count: whole hours, whole mebibytes and well-known ports.
+func classifyUnitLiteral(value int64, context string) string {
+	words := identifierWords(context)
+	for _, word := range words {
+		if unitWords[word] {
+			return ""
+		}
+	}
+	for _, word := range words {
+		for _, kind := range []string{UnitKindDuration, UnitKindSize, UnitKindPort} {
+			for _, kindWord := range unitKindWords[kind] {
+				if word != kindWord && word != kindWord+"s" {
+					continue
+				}
+				if kind == UnitKindPort && value > maxPort {
+					return ""
+				}
+				return kind
+			}
+		}
+	}
+
+	switch {
+	case value%secondsPerHr == 0:
+		return UnitKindDuration
+	case value%mebibyte == 0:
+		return UnitKindSize
+	case wellKnownPorts[value]:
+		return UnitKindPort
+	}
+	return ""
+}
+
+// identifierWords splits a snake_case or camelCase identifier, or a path such
+// as time.Duration, into lower-case words
+func identifierWords(name string) []string {
+	var words []string
+	runes := []rune(name)
+	start := 0
+	for i := 0; i <= len(runes); i++ {
+		boundary := i == len(runes) || !unicode.IsLetter(runes[i]) && !unicode.IsDigit(runes[i])
+		// A capital starts a word after a lower-case letter, or ends an acronym before one
+		capital := i > start && i < len(runes) && unicode.IsUpper(runes[i]) &&
+			(!unicode.IsUpper(runes[i-1]) || (i+1 < len(runes) && unicode.IsLower(runes[i+1])))
+		if !boundary && !capital {
+			continue
+		}
+		if i > start {
+			words = append(words, strings.ToLower(string(runes[start:i])))
+		}
+		start = i
+		if boundary {
+			start = i + 1
+		}
+	}
+	return words
+}
+
+// unitLiteralValue parses an integer literal, or reports false for floats
+func unitLiteralValue(literal string) (int64, bool) {
+	text := strings.ToLower(literal)
+	if !strings.HasPrefix(text, "0x") && (strings.ContainsAny(text, ".e") || strings.HasSuffix(text, "f32") || strings.HasSuffix(text, "f64")) {
+		return 0, false
+	}
+	value, ok := magicNumberValue(literal)
+	if !ok || value != math.Trunc(value) || value > math.MaxInt64 {
+		return 0, false
+	}
+	return int64(value), true
+}
+
+// unitLiteralViolation reports a literal of a kind. name is the identifier
+// holding the literal, used to suggest a constant name, or "" when unknown.
+func unitLiteralViolation(kind, literal string, value int64, name string, rust bool, filePath string, line, column int) *models.Violation {
+	message := fmt.Sprintf("Bare literal '%s' looks like a %s but does not state its unit", literal, kind)
+	if kind == UnitKindPort {
+		message = fmt.Sprintf("Bare literal '%s' looks like a port number but is not named", literal)
+	}
+	rule := UnitLiteral
+	if rust {
+		rule = RustUnitLiteral
+	}
+	return &models.Violation{
+		Type:        models.ViolationTypeMagicNumber,
+		Severity:    models.SeverityLow,
+		Message:     message,
+		File:        filePath,
+		Line:        line,
+		Column:      column,
+		Rule:        rule,
+		Suggestion:  unitLiteralSuggestion(kind, value, name, rust),
+		CodeSnippet: literal,
+	}
+}
+
+// unitLiteralSuggestion suggests a constant whose name or type states the
+// unit, written in the largest whole unit of the value
+func unitLiteralSuggestion(kind string, value int64, name string, rust bool) string {
+	words := identifierWords(name)
+	if len(words) == 0 {
+		words = map[string][]string{
+			UnitKindDuration: {"timeout"},
+			UnitKindSize:     {"max", "size"},
+			UnitKindPort:     {"default", "port"},
+		}[kind]
+	}
+	if kind == UnitKindSize {
+		words = append(words, "bytes")
+	}
+
+	if rust {
+		constName := strings.ToUpper(strings.Join(words, "_"))
+		switch kind {
+		case UnitKindDuration:
+			return fmt.Sprintf("Name the duration, as in `const %s: Duration = Duration::from_secs(%s);`", constName,
+				unitProduct(value, []int64{secondsPerDay, secondsPerHr, secondsPerMin}, []string{"24 * 60 * 60", "60 * 60", "60"}))
+		case UnitKindSize:
+			return fmt.Sprintf("Name the size with its unit, as in `const %s: usize = %s;`", constName, byteProduct(value))
+		default:
+			return fmt.Sprintf("Name the port, as in `const %s: u16 = %d;`, or read it from configuration", constName, value)
+		}
+	}
+
+	constName := words[0]
+	if name != "" && !strings.Contains(name, "_") && kind != UnitKindSize {
+		constName = name
+	} else {
+		for _, word := range words[1:] {
+			constName += strings.ToUpper(word[:1]) + word[1:]
+		}
+	}
+	switch kind {
+	case UnitKindDuration:
+		return fmt.Sprintf("Name the duration as a time.Duration, as in `const %s = %s`", constName,
+			unitProduct(value, []int64{secondsPerDay, secondsPerHr, secondsPerMin, 1}, []string{"24 * time.Hour", "time.Hour", "time.Minute", "time.Second"}))
+	case UnitKindSize:
+		return fmt.Sprintf("Name the size with its unit, as in `const %s = %s`", constName, byteProduct(value))
+	default:
+		return fmt.Sprintf("Name the port, as in `const %s = %d`, or read it from configuration", constName, value)
+	}
+}
+
+// byteProduct writes a byte count in its largest whole binary unit, as in 10 * 1024 * 1024
+func byteProduct(value int64) string {
+	return unitProduct(value, []int64{gibibyte, mebibyte, kibibyte}, []string{"1024 * 1024 * 1024", "1024 * 1024", "1024"})
+}
+
+// unitProduct writes value as a multiple of the first unit dividing it, or
+// as the bare value when none does
+func unitProduct(value int64, units []int64, spelled []string) string {
+	for i, unit := range units {
+		if value%unit != 0 {
+			continue
+		}
+		if value == unit {
+			return spelled[i]
+		}
+		return fmt.Sprintf("%d * %s", value/unit, spelled[i])
+	}
+	return fmt.Sprintf("%d", value)
+}
+
+// goUnitContext returns the identifier that gives a Go literal its meaning,
+// and whether that identifier holds the literal rather than taking it as an
+// argument or comparing against it. ok is false for literals that are not
+// checked: constant declarations and factors of products, which already
+// name or spell out their value.
+func goUnitContext(lit *ast.BasicLit, parent, grandparent ast.Node) (name string, holds bool, ok bool) {
+	switch p := parent.(type) {
+	case *ast.ValueSpec:
+		if genDecl, isDecl := grandparent.(*ast.GenDecl); isDecl && genDecl.Tok == token.CONST {
+			return "", false, false
+		}
+		for i, value := range p.Values {
+			if value == lit && i < len(p.Names) {
+				return p.Names[i].Name, true, true
+			}
+		}
+	case *ast.AssignStmt:
+		for i, value := range p.Rhs {
+			if value == lit && i < len(p.Lhs) {
+				return goExprName(p.Lhs[i]), true, true
+			}
+		}
+	case *ast.KeyValueExpr:
+		if p.Value == lit {
+			return goExprName(p.Key), true, true
+		}
+	case *ast.CallExpr:
+		if product, isBinary := grandparent.(*ast.BinaryExpr); isBinary && isProductOperator(product.Op) {
+			return "", false, false
+		}
+		return goExprName(p.Fun), false, true
+	case *ast.BinaryExpr:
+		if isProductOperator(p.Op) {
+			return "", false, false
+		}
+		other := p.X
+		if p.X == lit {
+			other = p.Y
+		}
+		return goExprName(other), false, true
+	}
+	return "", false, true
+}
+
+// isProductOperator reports whether a literal combined by op is a factor
+func isProductOperator(op token.Token) bool {
+	return op == token.MUL || op == token.QUO || op == token.SHL
+}
+
+// goExprName returns the name of an identifier, selector or call
+func goExprName(expr ast.Expr) string {
+	switch e := expr.(type) {
+	case *ast.Ident:
+		return e.Name
+	case *ast.SelectorExpr:
+		return e.Sel.Name
+	case *ast.CallExpr:
+		return goExprName(e.Fun)
+	}
+	return ""
+}
+
+// checkUnitLiteral reports a Go integer literal that looks like a duration,
+// size or port, or returns nil
+func (d *MagicNumberDetector) checkUnitLiteral(lit *ast.BasicLit, parent, grandparent ast.Node, fset *token.FileSet, filePath string) *models.Violation {
+	if !d.config.UnitLiterals.Enabled || lit.Kind != token.INT {
+		return nil
+	}
+	value, ok := unitLiteralValue(lit.Value)
+	if !ok {
+		return nil
+	}
+	name, holds, ok := goUnitContext(lit, parent, grandparent)
+	if !ok {
+		return nil
+	}
+	kind := d.config.UnitLiterals.kindOf(value, name)
+	if kind == "" {
+		return nil
+	}
+	if !holds {
+		name = ""
+	}
+	pos := fset.Position(lit.Pos())
+	return unitLiteralViolation(kind, lit.Value, value, name, false, filePath, pos.Line, pos.Column)
+}
+
+// rustUnitLiteralViolations reports the Rust integer literals that look like
+// durations, sizes or ports
+func rustUnitLiteralViolations(config *UnitLiteralConfig, tokens []types.RustTokenInfo, filePath string) []*models.Violation {
+	if !config.Enabled {
+		return nil
+	}
+	var violations []*models.Violation
+	for i, tok := range tokens {
+		if tok.Kind != types.RustTokenLiteral || tok.Text == "" || tok.Text[0] < '0' || tok.Text[0] > '9' {
+			continue
+		}
+		value, ok := unitLiteralValue(tok.Text)
+		if !ok {
+			continue
+		}
+		name, holds, ok := rustUnitContext(tokens, i)
+		if !ok {
+			continue
+		}
+		kind := config.kindOf(value, name)
+		if kind == "" {
+			continue
+		}
+		if !holds {
+			name = ""
+		}
+		violations = append(violations, unitLiteralViolation(kind, tok.Text, value, name, true, filePath, tok.Line, tok.Column))
+	}
+	return violations
+}
+
+// rustUnitContext mirrors goUnitContext on the tokens around a Rust literal:
+// a struct field or let binding holds it, and a call or comparison takes it
+func rustUnitContext(tokens []types.RustTokenInfo, i int) (name string, holds bool, ok bool) {
+	if i > 0 && isRustProductOperator(tokens[i-1].Text) || i+1 < len(tokens) && isRustProductOperator(tokens[i+1].Text) {
+		return "", false, false
+	}
+	if i < 2 {
+		return "", false, true
+	}
+	before := tokens[i-2]
+	switch tokens[i-1].Text {
+	case ":":
+		if before.Kind == types.RustTokenIdent {
+			return before.Text, true, true
+		}
+	case "=":
+		return rustBindingName(tokens, i-1)
+	case "(", ",":
+		return rustCalleeName(tokens, i), false, true
+	case "<", ">", "<=", ">=", "==", "!=":
+		if before.Kind == types.RustTokenIdent {
+			return before.Text, false, true
+		}
+	}
+	return "", false, true
+}
+
+// rustBindingName returns the name assigned by the `=` at index eq, with ok
+// false for const and static items
+func rustBindingName(tokens []types.RustTokenInfo, eq int) (name string, holds bool, ok bool) {
+	if tokens[eq-1].Kind == types.RustTokenIdent {
+		name = tokens[eq-1].Text
+	}
+	for j := eq - 1; j >= 0; j-- {
+		switch tokens[j].Text {
+		case "const", "static":
+			return "", false, false
+		case "let", ";", "{", "}":
+			return name, true, true
+		case ":":
+			// A type annotation follows the name, as in let limit: u64 = ...
+			if j > 0 && tokens[j-1].Kind == types.RustTokenIdent {
+				name = tokens[j-1].Text
+			}
+		}
+	}
+	return name, true, true
+}
+
+// rustCalleeName returns the function called with the argument at index i,
+// or "" when the argument is not in a call
+func rustCalleeName(tokens []types.RustTokenInfo, i int) string {
+	depth := 0
+	for j := i - 1; j >= 0; j-- {
+		text := tokens[j].Text
+		switch {
+		case isRustCloseDelimiter(text):
+			depth++
+		case isRustOpenDelimiter(text):
+			if depth > 0 {
+				depth--
+				continue
+			}
+			if text == "(" && j > 0 && tokens[j-1].Kind == types.RustTokenIdent {
+				return tokens[j-1].Text
+			}
+			return ""
+		case text == ";" && depth == 0:
+			return ""
+		}
+	}
+	return ""
+}
+
+// isRustProductOperator reports whether a literal next to text is a factor
+func isRustProductOperator(text string) bool {
+	return text == "*" || text == "/" || text == "<<"
+}
+
+// withoutUnitLiterals drops the magic number violations of literals also
+// reported as unit literals, which say more about the same number
+func withoutUnitLiterals(violations, unitLiterals []*models.Violation) []*models.Violation {
+	if len(unitLiterals) == 0 {
+		return violations
+	}
+	reported := make(map[[2]int]bool, len(unitLiterals))
+	for _, v := range unitLiterals {
+		reported[[2]int{v.Line, v.Column}] = true
+	}
+	var kept []*models.Violation
+	for _, v := range violations {
+		if !reported[[2]int{v.Line, v.Column}] {
+			kept = append(kept, v)
+		}
+	}
+	return kept
+}
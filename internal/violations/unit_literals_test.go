@@ -0,0 +1,174 @@
+package violations
+
+import (
+	"path/filepath"
+	"reflect"
+	"testing"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+func TestIdentifierWords(t *testing.T) {
+	tests := map[string][]string{
+		"cacheTTL":      {"cache", "ttl"},
+		"max_file_size": {"max", "file", "size"},
+		"HTTPPort":      {"http", "port"},
+		"time.Duration": {"time", "duration"},
+		"from_secs":     {"from", "secs"},
+		"":              nil,
+	}
+	for name, expected := range tests {
+		if words := identifierWords(name); !reflect.DeepEqual(words, expected) {
+			t.Errorf("identifierWords(%q) = %v, expected %v", name, words, expected)
+		}
+	}
+}
+
+func TestClassifyUnitLiteral(t *testing.T) {
+	tests := []struct {
+		value    int64
+		context  string
+		expected string
+	}{
+		{90, "requestTimeout", UnitKindDuration},
+		{300, "cache_ttl", UnitKindDuration},
+		{300, "Duration", UnitKindDuration},
+		{4096, "with_capacity", UnitKindSize},
+		{443, "listen_port", UnitKindPort},
+		{70000, "port", ""},
+		{3600, "timeout_secs", ""},
+		{3600, "from_millis", ""},
+		{7200, "", UnitKindDuration},
+		{10485760, "", UnitKindSize},
+		{5432, "", UnitKindPort},
+		{1500, "retries", ""},
+	}
+	for _, tt := range tests {
+		if kind := classifyUnitLiteral(tt.value, tt.context); kind != tt.expected {
+			t.Errorf("classifyUnitLiteral(%d, %q) = %q, expected %q", tt.value, tt.context, kind, tt.expected)
+		}
+	}
+}
+
+func TestMagicNumberDetector_UnitLiterals(t *testing.T) {
+	code := `package main
+
+import "time"
+
+const maxUploadBytes = 10 * 1024 * 1024
+
+type Server struct {
+	Port    int
+	Timeout time.Duration
+}
+
+func configure() {
+	cacheTTL := 3600
+	timeoutSecs := 90
+	limit := 10485760
+	server := Server{Port: 8080, Timeout: 5 * time.Second}
+	if cacheTTL > 86400 {
+		println(timeoutSecs, limit, server.Port)
+	}
+}
+`
+	astInfo := parseGoCode(t, code)
+	config := DefaultDetectorConfig()
+	detector := NewMagicNumberDetector(config)
+
+	if found := unitLiteralRules(detector.Detect(&models.FileInfo{Path: "server.go"}, astInfo)); len(found) != 0 {
+		t.Fatalf("Expected unit literals to be disabled by default, got %+v", found)
+	}
+
+	config.UnitLiterals.Enabled = true
+	violations := detector.Detect(&models.FileInfo{Path: "server.go"}, astInfo)
+	found := unitLiteralRules(violations)
+	expected := []struct {
+		line       int
+		message    string
+		suggestion string
+	}{
+		{13, "Bare literal '3600' looks like a duration but does not state its unit", "Name the duration as a time.Duration, as in `const cacheTTL = time.Hour`"},
+		{15, "Bare literal '10485760' looks like a size but does not state its unit", "Name the size with its unit, as in `const limitBytes = 10 * 1024 * 1024`"},
+		{16, "Bare literal '8080' looks like a port number but is not named", "Name the port, as in `const Port = 8080`, or read it from configuration"},
+		{17, "Bare literal '86400' looks like a duration but does not state its unit", "Name the duration as a time.Duration, as in `const timeout = 24 * time.Hour`"},
+	}
+	if len(found) != len(expected) {
+		t.Fatalf("Expected %d unit literal violations, got %+v", len(expected), found)
+	}
+	for i, want := range expected {
+		if found[i].Rule != UnitLiteral || found[i].Line != want.line || found[i].Message != want.message || found[i].Suggestion != want.suggestion {
+			t.Errorf("Unexpected violation %d: %+v", i, found[i])
+		}
+	}
+	for _, v := range violations {
+		if v.Rule == "magic-number" && (v.Line == 13 || v.Line == 15) {
+			t.Errorf("Expected no magic number violation for a unit literal, got %+v", v)
+		}
+	}
+
+	config.UnitLiterals.Kinds = []string{UnitKindSize}
+	if found := unitLiteralRules(detector.Detect(&models.FileInfo{Path: "server.go"}, astInfo)); len(found) != 1 || found[0].Line != 15 {
+		t.Errorf("Expected only the size to be reported, got %+v", found)
+	}
+}
+
+func TestRustMagicNumberDetector_UnitLiterals(t *testing.T) {
+	source := `pub fn defaults() -> Config {
+    let timeout = Duration::from_secs(7200);
+    let buffer = vec![0u8; 4 * 1024 * 1024];
+    Config {
+        cache_ttl: 3600,
+        max_file_size: 10485760,
+        retry_delay_ms: 1500,
+        port: 8080u16,
+    }
+}
+
+const DEFAULT_TIMEOUT: u64 = 86400;
+`
+	dir := writeRustFiles(t, map[string]string{"src/config.rs": source})
+	fileInfo := &models.FileInfo{Path: filepath.Join(dir, "src", "config.rs")}
+	astInfo := &types.RustASTInfo{Tokens: rustTestTokens(source)}
+
+	config := DefaultDetectorConfig()
+	config.UnitLiterals.Enabled = true
+	config.UnitLiterals.MinValue = 1000
+	violations := NewRustMagicNumberDetector(config).Detect(fileInfo, astInfo)
+	found := unitLiteralRules(violations)
+	expected := []struct {
+		line       int
+		suggestion string
+	}{
+		{5, "Name the duration, as in `const CACHE_TTL: Duration = Duration::from_secs(60 * 60);`"},
+		{6, "Name the size with its unit, as in `const MAX_FILE_SIZE_BYTES: usize = 10 * 1024 * 1024;`"},
+		{8, "Name the port, as in `const PORT: u16 = 8080;`, or read it from configuration"},
+	}
+	if len(found) != len(expected) {
+		t.Fatalf("Expected %d unit literal violations, got %+v", len(expected), found)
+	}
+	for i, want := range expected {
+		if found[i].Rule != RustUnitLiteral || found[i].Line != want.line || found[i].Suggestion != want.suggestion {
+			t.Errorf("Unexpected violation %d: %+v", i, found[i])
+		}
+	}
+	for _, v := range violations {
+		for _, unit := range found {
+			if v.Rule == "rust-magic-number" && v.Line == unit.Line && v.Column == unit.Column {
+				t.Errorf("Expected no magic number violation for a unit literal, got %+v", v)
+			}
+		}
+	}
+}
+
+// unitLiteralRules returns the unit literal violations among others
+func unitLiteralRules(violations []*models.Violation) []*models.Violation {
+	var found []*models.Violation
+	for _, v := range violations {
+		if v.Rule == UnitLiteral || v.Rule == RustUnitLiteral {
+			found = append(found, v)
+		}
+	}
+	return found
+}
@@ -0,0 +1,138 @@
+package violations
+
+import (
+	"fmt"
+	"regexp"
+	"strings"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+// UntrackedTodo is the rule of markers that reference no issue
+const UntrackedTodo = "untracked-todo"
+
+// DefaultTodoIssuePattern accepts a GitHub issue or a Jira key in
+// parentheses, as in TODO(#1234) or TODO(JIRA-123)
+const DefaultTodoIssuePattern = `\((#[0-9]+|[A-Z][A-Z0-9]*-[0-9]+)\)`
+
+// defaultTodoIssueMarkers are the markers that need an issue reference when
+// none are configured
+var defaultTodoIssueMarkers = []string{"TODO", "FIXME"}
+
+// TodoIssueConfig requires TODO markers to reference the issue that tracks
+// them. The zero value disables the check.
+type TodoIssueConfig struct {
+	Enabled bool
+	Pattern string   // Regular expression the text after the marker must start with; empty uses DefaultTodoIssuePattern
+	Markers []string // Markers that need a reference; empty uses TODO and FIXME
+}
+
+// UntrackedTodoDetector reports TODO and FIXME comments in Go and Rust files
+// that do not name an issue, so every marker left in the code is tracked
+type UntrackedTodoDetector struct {
+	config        *DetectorConfig
+	markerPattern *regexp.Regexp
+	issuePattern  *regexp.Regexp
+}
+
+// NewUntrackedTodoDetector creates a new untracked TODO detector
+func NewUntrackedTodoDetector(config *DetectorConfig) *UntrackedTodoDetector {
+	if config == nil {
+		config = DefaultDetectorConfig()
+	}
+
+	markers := config.TodoIssues.Markers
+	if len(markers) == 0 {
+		markers = defaultTodoIssueMarkers
+	}
+	quoted := make([]string, len(markers))
+	for i, marker := range markers {
+		quoted[i] = regexp.QuoteMeta(marker)
+	}
+
+	// The configuration is validated on load; fall back to the default for
+	// detectors built without it
+	issuePattern, err := regexp.Compile(`^(?:` + config.TodoIssues.Pattern + `)`)
+	if config.TodoIssues.Pattern == "" || err != nil {
+		issuePattern = regexp.MustCompile(`^(?:` + DefaultTodoIssuePattern + `)`)
+	}
+
+	return &UntrackedTodoDetector{
+		config:        config,
+		markerPattern: regexp.MustCompile(`^(` + strings.Join(quoted, "|") + `)\b`),
+		issuePattern:  issuePattern,
+	}
+}
+
+// Name returns the name of this detector
+func (d *UntrackedTodoDetector) Name() string {
+	return "Untracked TODO Detector"
+}
+
+// Description returns a description of what this detector checks for
+func (d *UntrackedTodoDetector) Description() string {
+	return "Reports TODO and FIXME comments that do not reference an issue"
+}
+
+// Detect analyzes the comments of a Go or Rust file
+func (d *UntrackedTodoDetector) Detect(fileInfo *models.FileInfo, astInfo interface{}) []*models.Violation {
+	if !d.config.TodoIssues.Enabled {
+		return nil
+	}
+
+	var violations []*models.Violation
+	switch info := astInfo.(type) {
+	case *types.GoASTInfo:
+		if info == nil || info.AST == nil || info.FileSet == nil {
+			return nil
+		}
+		for _, group := range info.AST.Comments {
+			for _, comment := range group.List {
+				pos := info.FileSet.Position(comment.Pos())
+				violations = append(violations, d.checkComment(comment.Text, pos.Line, pos.Column, fileInfo.Path)...)
+			}
+		}
+	case *types.RustASTInfo:
+		if info == nil {
+			return nil
+		}
+		for _, comment := range info.Comments {
+			if comment != nil {
+				violations = append(violations, d.checkComment(comment.Text, comment.Line, comment.Column, fileInfo.Path)...)
+			}
+		}
+	}
+	return violations
+}
+
+// checkComment reports the lines of a comment, starting at line and column,
+// that open with a marker not followed by an issue reference. Markers in the
+// middle of a sentence are not reported.
+func (d *UntrackedTodoDetector) checkComment(text string, line, column int, filePath string) []*models.Violation {
+	var violations []*models.Violation
+	for offset, commentLine := range strings.Split(text, "\n") {
+		body := strings.TrimLeft(commentLine, " \t/*!")
+		match := d.markerPattern.FindStringSubmatch(body)
+		if match == nil || d.issuePattern.MatchString(body[len(match[1]):]) {
+			continue
+		}
+
+		markerColumn := 1 + len(commentLine) - len(body)
+		if offset == 0 {
+			markerColumn += column - 1
+		}
+		violations = append(violations, &models.Violation{
+			Type:        models.ViolationTypeTodo,
+			Severity:    models.SeverityLow,
+			File:        filePath,
+			Line:        line + offset,
+			Column:      markerColumn,
+			Message:     fmt.Sprintf("%s marker does not reference an issue", match[1]),
+			Rule:        UntrackedTodo,
+			Suggestion:  "Name the issue that tracks it, as in TODO(#1234) or TODO(JIRA-123), or resolve it now",
+			CodeSnippet: strings.TrimSpace(strings.TrimSuffix(body, "*/")),
+		})
+	}
+	return violations
+}
@@ -0,0 +1,66 @@
+package violations
+
+import (
+	"go/parser"
+	"go/token"
+	"testing"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+func TestUntrackedTodoDetector(t *testing.T) {
+	src := `package main
+
+// TODO: handle errors
+// TODO(#1234): add retries
+// FIXME(JIRA-123): flaky on CI
+func main() {
+	// TODO(user): add flags
+	// see the TODO list in the README
+	// HACK: not a marker that needs a reference
+
+	/*
+	 * FIXME parse the arguments
+	 */
+}
+`
+	fset := token.NewFileSet()
+	file, err := parser.ParseFile(fset, "main.go", src, parser.ParseComments)
+	if err != nil {
+		t.Fatal(err)
+	}
+	goInfo := &types.GoASTInfo{AST: file, FileSet: fset}
+	fileInfo := &models.FileInfo{Path: "main.go"}
+
+	config := DefaultDetectorConfig()
+	if violations := NewUntrackedTodoDetector(config).Detect(fileInfo, goInfo); len(violations) != 0 {
+		t.Fatalf("Expected no violations while the rule is off, got %d", len(violations))
+	}
+
+	config.TodoIssues.Enabled = true
+	violations := NewUntrackedTodoDetector(config).Detect(fileInfo, goInfo)
+	if len(violations) != 3 {
+		t.Fatalf("Expected 3 untracked markers, got %d: %+v", len(violations), violations)
+	}
+	for i, want := range []struct{ line, column int }{{3, 4}, {7, 5}, {12, 5}} {
+		if v := violations[i]; v.Line != want.line || v.Column != want.column || v.Rule != UntrackedTodo {
+			t.Errorf("Expected violation %d at %d:%d, got %s at %d:%d", i, want.line, want.column, v.Rule, v.Line, v.Column)
+		}
+	}
+	if violations[2].Message != "FIXME marker does not reference an issue" {
+		t.Errorf("Unexpected message %q", violations[2].Message)
+	}
+
+	rust := &types.RustASTInfo{Comments: []*types.RustCommentInfo{
+		{Text: "// TODO: Implement this function properly", Line: 34, Column: 1},
+		{Text: "// HACK: This is a temporary hack", Line: 36, Column: 5},
+		{Text: "// TODO #42 add error handling", Line: 39, Column: 5},
+	}}
+	config.TodoIssues.Pattern = `\s*#[0-9]+`
+	config.TodoIssues.Markers = []string{"TODO", "HACK"}
+	violations = NewUntrackedTodoDetector(config).Detect(&models.FileInfo{Path: "src/lib.rs"}, rust)
+	if len(violations) != 2 || violations[0].Line != 34 || violations[1].Line != 36 || violations[1].Column != 8 {
+		t.Errorf("Expected the configured markers and pattern to apply to Rust comments, got %+v", violations)
+	}
+}
@@ -0,0 +1,406 @@
+package violations
+
+import (
+	"fmt"
+	"sort"
+	"strings"
+	"sync"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+// Rules of the unused public API detector
+const (
+	RustUnusedPublicItem = "rust-unused-public-item" // pub items that nothing in the workspace uses
+	RustUnusedReexport   = "rust-unused-reexport"    // pub use re-exports that nothing imports through
+)
+
+// rustPublicItemKinds names the items declared by a keyword whose uses are tracked
+var rustPublicItemKinds = map[string]string{
+	"fn":     "function",
+	"struct": "struct",
+	"enum":   "enum",
+	"union":  "union",
+	"trait":  "trait",
+	"type":   "type alias",
+	"const":  "constant",
+	"static": "static",
+}
+
+// rustExternallyUsedAttributes mark items used from outside Rust code, or by
+// the test harness and the compiler, rather than by other items
+var rustExternallyUsedAttributes = map[string]bool{
+	"no_mangle": true, "export_name": true, "wasm_bindgen": true, "pyfunction": true, "pyclass": true,
+	"proc_macro": true, "proc_macro_derive": true, "proc_macro_attribute": true, "test": true, "bench": true,
+}
+
+// PublicAPIDetector records the pub items and re-exports of the Rust files
+// of a scan, and reports those that no file uses. Items are matched by name:
+// an item is used when its name appears anywhere in the workspace other than
+// where an item of that name is declared, which includes a `pub use`
+// re-exporting it. Re-exports are matched by path: a re-export is used when
+// a path resolves through the module re-exporting it.
+type PublicAPIDetector struct {
+	config    *DetectorConfig
+	mutex     sync.Mutex
+	items     map[string][]rustPublicItem // File to its pub items
+	uses      map[string]int              // Identifier to its occurrences, declarations included
+	reexports map[string][]rustReexport   // File to its re-exports
+	paths     map[string]bool             // Absolute paths named in the scan, with their prefixes
+}
+
+// rustPublicItem is a pub item declared in a file
+type rustPublicItem struct {
+	kind   string
+	name   string
+	line   int
+	column int
+}
+
+// rustReexport is a name a module re-exports with `pub use`
+type rustReexport struct {
+	module string // Absolute path of the re-exporting module
+	name   string // Name the module exports, the alias if there is one
+	target string // Path as written in the use declaration
+	line   int
+	column int
+}
+
+// NewPublicAPIDetector creates a new unused public API detector
+func NewPublicAPIDetector(config *DetectorConfig) *PublicAPIDetector {
+	if config == nil {
+		config = DefaultDetectorConfig()
+	}
+	return &PublicAPIDetector{
+		config:    config,
+		items:     make(map[string][]rustPublicItem),
+		uses:      make(map[string]int),
+		reexports: make(map[string][]rustReexport),
+		paths:     make(map[string]bool),
+	}
+}
+
+// Name returns the name of this detector
+func (d *PublicAPIDetector) Name() string {
+	return "Unused Public API Detector"
+}
+
+// Description returns a description of what this detector checks for
+func (d *PublicAPIDetector) Description() string {
+	return "Detects pub items and re-exports of Rust crates that nothing in the workspace uses"
+}
+
+// Detect records the pub items, re-exports, identifiers and paths of a Rust
+// file when the rules are enabled. It reports nothing: call Violations once
+// every file was detected.
+func (d *PublicAPIDetector) Detect(fileInfo *models.FileInfo, astInfo interface{}) []*models.Violation {
+	info, ok := astInfo.(*types.RustASTInfo)
+	if !ok || info == nil || d.config.RustConfig == nil || !d.config.RustConfig.DetectUnusedPublicAPI {
+		return nil
+	}
+
+	items := rustPublicItems(info.Tokens)
+	selfTypes := rustImplSelfTypes(info.Tokens)
+	reexports, paths := rustReexportsAndPaths(fileInfo.Path, info)
+
+	d.mutex.Lock()
+	defer d.mutex.Unlock()
+	if len(items) > 0 {
+		d.items[fileInfo.Path] = items
+	}
+	if len(reexports) > 0 {
+		d.reexports[fileInfo.Path] = reexports
+	}
+	for i, token := range info.Tokens {
+		if token.Kind == types.RustTokenIdent && !selfTypes[i] {
+			d.uses[token.Text]++
+		}
+	}
+	for _, path := range paths {
+		segments := strings.Split(path, "::")
+		for n := 1; n <= len(segments); n++ {
+			d.paths[strings.Join(segments[:n], "::")] = true
+		}
+	}
+	return nil
+}
+
+// Reset clears the recorded items and uses; call it at the start of each scan
+func (d *PublicAPIDetector) Reset() {
+	d.mutex.Lock()
+	defer d.mutex.Unlock()
+	d.items = make(map[string][]rustPublicItem)
+	d.uses = make(map[string]int)
+	d.reexports = make(map[string][]rustReexport)
+	d.paths = make(map[string]bool)
+}
+
+// Violations reports the pub items recorded since the last Reset whose name
+// appears nowhere but in declarations, and the re-exports no path resolves
+// through, ordered by file path and line
+func (d *PublicAPIDetector) Violations() []*models.Violation {
+	d.mutex.Lock()
+	defer d.mutex.Unlock()
+
+	violations := append(d.unusedItems(), d.unusedReexports()...)
+	sort.SliceStable(violations, func(i, j int) bool {
+		if violations[i].File != violations[j].File {
+			return violations[i].File < violations[j].File
+		}
+		return violations[i].Line < violations[j].Line
+	})
+	return violations
+}
+
+// unusedItems reports the pub items whose name appears nowhere but in declarations
+func (d *PublicAPIDetector) unusedItems() []*models.Violation {
+	declarations := make(map[string]int)
+	for _, items := range d.items {
+		for _, item := range items {
+			declarations[item.name]++
+		}
+	}
+
+	var violations []*models.Violation
+	for file, items := range d.items {
+		for _, item := range items {
+			if d.uses[item.name] > declarations[item.name] {
+				continue
+			}
+			violations = append(violations, &models.Violation{
+				Type:     models.ViolationTypeRustUnusedPublicItem,
+				Severity: models.SeverityLow,
+				Message:  fmt.Sprintf("Public %s '%s' is never used in the workspace", item.kind, item.name),
+				File:     file,
+				Line:     item.line,
+				Column:   item.column,
+				Rule:     RustUnusedPublicItem,
+				Suggestion: fmt.Sprintf("Remove '%s' if nothing needs it, make it pub(crate) until something does, "+
+					"or re-export it with `pub use` if it belongs to the crate's API", item.name),
+			})
+		}
+	}
+	return violations
+}
+
+// unusedReexports reports the re-exports that no path of the scan names,
+// directly, through a glob import of their module or as the prefix of a
+// longer path such as a call of an associated function
+func (d *PublicAPIDetector) unusedReexports() []*models.Violation {
+	var violations []*models.Violation
+	for file, reexports := range d.reexports {
+		for _, reexport := range reexports {
+			if d.paths[reexport.module+"::"+reexport.name] || d.paths[reexport.module+"::*"] {
+				continue
+			}
+			violations = append(violations, &models.Violation{
+				Type:     models.ViolationTypeRustUnusedImport,
+				Severity: models.SeverityLow,
+				Message:  fmt.Sprintf("Re-export '%s' of '%s' is never used through that path", reexport.name, reexport.module),
+				File:     file,
+				Line:     reexport.line,
+				Column:   reexport.column,
+				Rule:     RustUnusedReexport,
+				Suggestion: fmt.Sprintf("Remove the re-export of '%s', or make it a private use if this module needs the name; "+
+					"code elsewhere imports it from where it is defined", reexport.target),
+			})
+		}
+	}
+	return violations
+}
+
+// rustReexportsAndPaths returns the re-exports of a file, except globs and
+// those in function bodies, and the absolute paths its use declarations and
+// code may name. A relative path is resolved against both the extern crates
+// and the module it appears in, as either may hold its first segment.
+func rustReexportsAndPaths(filePath string, info *types.RustASTInfo) ([]rustReexport, []string) {
+	tokens := info.Tokens
+	modules := rustInlineModules(tokens)
+	fileModule := strings.Split(rustModulePath(filePath), "::")
+	moduleAt := func(i int) []string {
+		return append(append([]string(nil), fileModule...), rustModuleAt(modules, i)...)
+	}
+
+	var reexports []rustReexport
+	var paths []string
+	imports := make(map[string][]string)
+	useTokens := make(map[int]bool)
+	for _, item := range rustUseItems(info) {
+		module := moduleAt(item.first)
+		for i := item.first; i <= item.last; i++ {
+			useTokens[i] = true
+		}
+		for _, leaf := range rustUseLeaves(item.tree) {
+			target, alias, _ := strings.Cut(leaf, " as ")
+			name := alias
+			if name == "" {
+				name = lastRustSegment(target)
+			}
+			segments := strings.Split(target, "::")
+			if name != "*" && name != "_" {
+				imports[name] = segments
+			}
+			paths = append(paths, absoluteRustPaths(module, segments, nil)...)
+
+			if item.public && name != "*" && !strings.HasPrefix(item.scope, "fn ") {
+				reexports = append(reexports, rustReexport{
+					module: strings.Join(module, "::"),
+					name:   name,
+					target: target,
+					line:   item.line,
+					column: item.column,
+				})
+			}
+		}
+	}
+
+	for i := 0; i < len(tokens); i++ {
+		if useTokens[i] || i > 0 && tokens[i-1].Text == "::" ||
+			tokens[i].Kind != types.RustTokenIdent && tokens[i].Kind != types.RustTokenKeyword {
+			continue
+		}
+		segments := []string{tokens[i].Text}
+		for i+2 < len(tokens) && tokens[i+1].Text == "::" && tokens[i+2].Kind != types.RustTokenPunct {
+			segments = append(segments, tokens[i+2].Text)
+			i += 2
+		}
+		if len(segments) > 1 {
+			paths = append(paths, absoluteRustPaths(moduleAt(i), segments, imports)...)
+		}
+	}
+	return reexports, paths
+}
+
+// absoluteRustPaths returns the absolute paths a path may name from a
+// module, whose segments start with the crate. A first segment imported by
+// the file is replaced with its import.
+func absoluteRustPaths(module, segments []string, imports map[string][]string) []string {
+	join := func(prefix []string, rest []string) string {
+		return strings.Join(append(append([]string(nil), prefix...), rest...), "::")
+	}
+	switch segments[0] {
+	case "crate":
+		return []string{join(module[:1], segments[1:])}
+	case "self":
+		return []string{join(module, segments[1:])}
+	case "super":
+		parent := module
+		for len(segments) > 0 && segments[0] == "super" {
+			if len(parent) <= 1 {
+				return nil
+			}
+			parent, segments = parent[:len(parent)-1], segments[1:]
+		}
+		return []string{join(parent, segments)}
+	}
+	if imported, ok := imports[segments[0]]; ok {
+		return absoluteRustPaths(module, append(append([]string(nil), imported...), segments[1:]...), nil)
+	}
+	return []string{join(nil, segments), join(module, segments)}
+}
+
+// rustPublicItems returns the items of a file declared with a plain `pub`,
+// in source order. Items restricted to the crate or a module, `pub use`
+// re-exports, modules, fields, main and items with an attribute marking
+// them used from outside Rust code are left out.
+func rustPublicItems(tokens []types.RustTokenInfo) []rustPublicItem {
+	var items []rustPublicItem
+	for i, token := range tokens {
+		if token.Text != "pub" || i+1 >= len(tokens) || tokens[i+1].Text == "(" {
+			continue
+		}
+
+		j := i + 1
+		for j+1 < len(tokens) && isRustItemQualifier(tokens, j) {
+			j++
+		}
+		kind, ok := rustPublicItemKinds[tokens[j].Text]
+		if !ok || j+1 >= len(tokens) {
+			continue
+		}
+		if tokens[j].Text == "static" && tokens[j+1].Text == "mut" {
+			j++
+		}
+		name := tokens[j+1]
+		if name.Kind != types.RustTokenIdent || name.Text == "main" || isUsedOutsideRust(tokens, i) {
+			continue
+		}
+		items = append(items, rustPublicItem{kind: kind, name: name.Text, line: token.Line, column: token.Column})
+	}
+	return items
+}
+
+// isRustItemQualifier reports whether the token at index i qualifies the
+// item keyword after it, as in `pub const unsafe extern "C" fn`
+func isRustItemQualifier(tokens []types.RustTokenInfo, i int) bool {
+	switch tokens[i].Text {
+	case "async", "unsafe", "extern":
+		return true
+	case "const":
+		next := tokens[i+1].Text
+		return next == "fn" || next == "async" || next == "unsafe" || next == "extern"
+	}
+	return tokens[i].Kind == types.RustTokenLiteral && tokens[i-1].Text == "extern"
+}
+
+// isUsedOutsideRust reports whether the item whose visibility is at index
+// item has an attribute marking it used from outside Rust code
+func isUsedOutsideRust(tokens []types.RustTokenInfo, item int) bool {
+	for _, name := range rustItemAttributes(tokens, item) {
+		if rustExternallyUsedAttributes[name] {
+			return true
+		}
+	}
+	return false
+}
+
+// rustImplSelfTypes returns the indexes of the type names that impl blocks
+// implement, as Parser in `impl Parser` and `impl<T> Display for Parser<T>`.
+// Implementing methods on a type does not use it.
+func rustImplSelfTypes(tokens []types.RustTokenInfo) map[int]bool {
+	selfTypes := make(map[int]bool)
+	for i := 0; i < len(tokens); i++ {
+		if tokens[i].Text != "impl" || !isRustItemStart(tokens, i) {
+			continue
+		}
+
+		// The self type follows `for` or, for an inherent impl, the generics
+		depth, self := 0, -1
+		for j := i + 1; j < len(tokens); j++ {
+			text := tokens[j].Text
+			if depth == 0 && (text == "{" || text == "where" || text == ";") {
+				break
+			}
+			switch {
+			case text == "<":
+				depth++
+			case text == ">":
+				depth--
+			case text == ">>":
+				depth -= 2
+			case depth == 0 && text == "for":
+				self = -1
+			case depth == 0 && tokens[j].Kind == types.RustTokenIdent:
+				self = j
+			}
+		}
+		if self >= 0 {
+			selfTypes[self] = true
+		}
+	}
+	return selfTypes
+}
+
+// isRustItemStart reports whether the token at index i starts an item rather
+// than a type, such as the `impl` of an impl block rather than of `impl Trait`
+func isRustItemStart(tokens []types.RustTokenInfo, i int) bool {
+	if i == 0 {
+		return true
+	}
+	switch tokens[i-1].Text {
+	case "{", "}", ";", "]", "unsafe", "default":
+		return true
+	}
+	return false
+}
@@ -0,0 +1,185 @@
+package violations
+
+import (
+	"testing"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+	"github.com/ericfisherdev/goclean/internal/types"
+)
+
+func TestPublicAPIDetector(t *testing.T) {
+	files := map[string]string{
+		"src/lib.rs": `pub mod parser;
+pub use parser::Parser;
+
+pub fn run(input: &str) -> usize {
+    input.len()
+}`,
+		"src/parser.rs": `pub struct Parser {
+    pub input: String,
+}
+
+impl Parser {
+    pub fn new(input: &str) -> Self {
+        Parser { input: input.to_string() }
+    }
+
+    pub fn reset(&mut self) {}
+}
+
+pub const MAX_DEPTH: usize = 64;
+
+pub(crate) fn helper() {}
+
+pub struct Token;
+
+impl std::fmt::Display for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result { Ok(()) }
+}
+
+#[no_mangle]
+pub extern "C" fn parser_version() -> u32 { 1 }
+
+pub const unsafe fn raw_len() -> usize { 0 }`,
+		"src/main.rs": `use demo::Parser;
+
+pub fn main() {
+    let parser = Parser::new("x");
+    println!("{}", parser.input);
+}`,
+	}
+
+	config := DefaultDetectorConfig()
+	detector := NewPublicAPIDetector(config)
+	detect := func() []*models.Violation {
+		detector.Reset()
+		for path, source := range files {
+			detector.Detect(&models.FileInfo{Path: path}, &types.RustASTInfo{Tokens: rustTestTokens(source)})
+		}
+		var found []*models.Violation
+		for _, v := range detector.Violations() {
+			if v.Rule == RustUnusedPublicItem {
+				found = append(found, v)
+			}
+		}
+		return found
+	}
+
+	if violations := detect(); len(violations) != 0 {
+		t.Fatalf("Expected unused public API detection to be disabled by default, got %+v", violations)
+	}
+
+	config.RustConfig.DetectUnusedPublicAPI = true
+	violations := detect()
+	expected := []struct {
+		file    string
+		line    int
+		message string
+	}{
+		{"src/lib.rs", 4, "Public function 'run' is never used in the workspace"},
+		{"src/parser.rs", 10, "Public function 'reset' is never used in the workspace"},
+		{"src/parser.rs", 13, "Public constant 'MAX_DEPTH' is never used in the workspace"},
+		{"src/parser.rs", 17, "Public struct 'Token' is never used in the workspace"},
+		{"src/parser.rs", 26, "Public function 'raw_len' is never used in the workspace"},
+	}
+	if len(violations) != len(expected) {
+		t.Fatalf("Expected %d unused items, got %+v", len(expected), violations)
+	}
+	for i, want := range expected {
+		v := violations[i]
+		if v.File != want.file || v.Line != want.line || v.Message != want.message {
+			t.Errorf("Unexpected violation %d: %+v", i, v)
+		}
+	}
+}
+
+func TestRustImplSelfTypes(t *testing.T) {
+	source := `impl<T: Into<Vec<u8>>> Wrapper<T> where T: Clone {
+    fn nodes(&self) -> impl Iterator<Item = Node> { self.items.iter() }
+}
+impl fmt::Display for Wrapper<u8> {}`
+	tokens := rustTestTokens(source)
+
+	var names []string
+	for i, token := range tokens {
+		if rustImplSelfTypes(tokens)[i] {
+			names = append(names, token.Text)
+		}
+	}
+	if len(names) != 2 || names[0] != "Wrapper" || names[1] != "Wrapper" {
+		t.Errorf("Expected only the implemented types, got %v", names)
+	}
+}
+
+func TestPublicAPIDetector_UnusedReexports(t *testing.T) {
+	files := map[string]string{
+		"/work/demo/src/lib.rs": `pub mod parser;
+pub mod prelude;
+
+pub use parser::{Parser, Token};
+
+mod internal {
+    pub use super::parser::Token;
+}
+
+pub fn first_token() -> Option<internal::Token> {
+    None
+}`,
+		"/work/demo/src/prelude.rs": `pub use crate::parser::Parser;
+pub use crate::parser::Token as Tok;
+pub use crate::parser::MAX_DEPTH;
+pub use crate::parser::*;`,
+		"/work/demo/src/main.rs": `use demo::prelude::Parser;
+
+fn main() {
+    let depth = demo::prelude::MAX_DEPTH;
+    let parser = Parser::new(depth);
+}`,
+	}
+
+	config := DefaultDetectorConfig()
+	config.RustConfig.DetectUnusedPublicAPI = true
+	detector := NewPublicAPIDetector(config)
+	detect := func() []*models.Violation {
+		detector.Reset()
+		for path, source := range files {
+			detector.Detect(&models.FileInfo{Path: path}, &types.RustASTInfo{Tokens: rustTestTokens(source)})
+		}
+		var found []*models.Violation
+		for _, v := range detector.Violations() {
+			if v.Rule == RustUnusedReexport {
+				found = append(found, v)
+			}
+		}
+		return found
+	}
+
+	violations := detect()
+	expected := []struct {
+		file    string
+		line    int
+		message string
+	}{
+		{"/work/demo/src/lib.rs", 4, "Re-export 'Parser' of 'demo' is never used through that path"},
+		{"/work/demo/src/lib.rs", 4, "Re-export 'Token' of 'demo' is never used through that path"},
+		{"/work/demo/src/prelude.rs", 2, "Re-export 'Tok' of 'demo::prelude' is never used through that path"},
+	}
+	if len(violations) != len(expected) {
+		t.Fatalf("Expected %d unused re-exports, got %+v", len(expected), violations)
+	}
+	for i, want := range expected {
+		v := violations[i]
+		if v.File != want.file || v.Line != want.line || v.Message != want.message {
+			t.Errorf("Unexpected violation %d: %+v", i, v)
+		}
+	}
+	if violations[2].Suggestion != "Remove the re-export of 'crate::parser::Token', or make it a private use if this module needs the name; "+
+		"code elsewhere imports it from where it is defined" {
+		t.Errorf("Unexpected suggestion %q", violations[2].Suggestion)
+	}
+
+	files["/work/demo/src/cli.rs"] = "use crate::prelude::*;"
+	if violations := detect(); len(violations) != 2 || violations[0].File != "/work/demo/src/lib.rs" {
+		t.Errorf("Expected a glob import to use every re-export of the prelude, got %+v", violations)
+	}
+}
@@ -0,0 +1,114 @@
+//go:build linux
+
+package watch
+
+import (
+	"encoding/binary"
+	"io/fs"
+	"os"
+	"path/filepath"
+	"strings"
+	"syscall"
+)
+
+// inotifyMask selects the events that can change the watched files
+const inotifyMask = syscall.IN_CREATE | syscall.IN_DELETE | syscall.IN_CLOSE_WRITE | syscall.IN_MODIFY |
+	syscall.IN_MOVED_FROM | syscall.IN_MOVED_TO | syscall.IN_DELETE_SELF | syscall.IN_MOVE_SELF
+
+// inotifyNotifier watches every directory below the roots with inotify,
+// adding directories as they are created
+type inotifyNotifier struct {
+	fd      int
+	file    *os.File
+	dirs    map[int32]string // watch descriptor -> directory
+	changes chan struct{}
+}
+
+// newNotifier starts watching the directories below roots. A root that is
+// a file is watched through its directory, so saves that replace it are seen.
+func newNotifier(roots []string) (notifier, error) {
+	fd, err := syscall.InotifyInit1(syscall.IN_CLOEXEC | syscall.IN_NONBLOCK)
+	if err != nil {
+		return nil, os.NewSyscallError("inotify_init1", err)
+	}
+	// A non-blocking descriptor makes reads return when the file is closed
+	n := &inotifyNotifier{
+		fd:      fd,
+		file:    os.NewFile(uintptr(fd), "inotify"),
+		dirs:    make(map[int32]string),
+		changes: make(chan struct{}, 1),
+	}
+	for _, root := range roots {
+		if info, err := os.Stat(root); err == nil && !info.IsDir() {
+			root = filepath.Dir(root)
+		}
+		if err := n.addTree(root); err != nil {
+			n.file.Close()
+			return nil, err
+		}
+	}
+	go n.read()
+	return n, nil
+}
+
+// Changes receives after the files below the roots changed
+func (n *inotifyNotifier) Changes() <-chan struct{} {
+	return n.changes
+}
+
+// Close stops watching
+func (n *inotifyNotifier) Close() error {
+	return n.file.Close()
+}
+
+// addTree watches dir and the directories below it, skipping hidden ones
+// such as .git whose churn would only cause empty rescans
+func (n *inotifyNotifier) addTree(dir string) error {
+	return filepath.WalkDir(dir, func(path string, entry fs.DirEntry, err error) error {
+		if err != nil || !entry.IsDir() {
+			return nil
+		}
+		if path != dir && strings.HasPrefix(entry.Name(), ".") {
+			return filepath.SkipDir
+		}
+		wd, err := syscall.InotifyAddWatch(n.fd, path, inotifyMask)
+		if err != nil {
+			return os.NewSyscallError("inotify_add_watch", err)
+		}
+		n.dirs[int32(wd)] = path
+		return nil
+	})
+}
+
+// read signals Changes after every batch of events until the notifier is
+// closed, then closes Changes
+func (n *inotifyNotifier) read() {
+	defer close(n.changes)
+	buf := make([]byte, 64*1024)
+	for {
+		count, err := n.file.Read(buf)
+		if err != nil {
+			return
+		}
+		for offset := 0; offset+syscall.SizeofInotifyEvent <= count; {
+			wd := int32(binary.NativeEndian.Uint32(buf[offset:]))
+			mask := binary.NativeEndian.Uint32(buf[offset+4:])
+			nameLen := int(binary.NativeEndian.Uint32(buf[offset+12:]))
+			name := strings.TrimRight(string(buf[offset+syscall.SizeofInotifyEvent:offset+syscall.SizeofInotifyEvent+nameLen]), "\x00")
+			offset += syscall.SizeofInotifyEvent + nameLen
+
+			// Files written into a new directory before its watch exists are
+			// found by the rescan this batch triggers
+			created := mask&(syscall.IN_CREATE|syscall.IN_MOVED_TO) != 0
+			if mask&syscall.IN_ISDIR != 0 && created && !strings.HasPrefix(name, ".") {
+				if dir, ok := n.dirs[wd]; ok {
+					_ = n.addTree(filepath.Join(dir, name))
+				}
+			}
+		}
+		select {
+		case n.changes <- struct{}{}:
+		default:
+		}
+	}
+}
@@ -0,0 +1,14 @@
+//go:build !linux
+
+package watch
+
+import (
+	"fmt"
+	"runtime"
+)
+
+// newNotifier reports that file system notifications are not implemented
+// on this platform, so the watcher polls
+func newNotifier(roots []string) (notifier, error) {
+	return nil, fmt.Errorf("file system notifications are not supported on %s", runtime.GOOS)
+}
@@ -0,0 +1,244 @@
+// Package watch rescans source files as they change, for an inner-loop
+// workflow where diagnostics follow each save.
+//
+// A watcher started with WithNotifications waits for file system
+// notifications, through inotify on Linux, and polls every interval on other
+// platforms or when notifications cannot be set up, such as when the inotify
+// watch limit is reached. Notifications only wake the watcher: each wake-up
+// lists the watched files and compares their modification time and size, so
+// an editor's atomic save, reported as a remove followed by a create, is seen
+// once as a modification.
+package watch
+
+import (
+	"context"
+	"fmt"
+	"slices"
+	"sort"
+	"time"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+)
+
+// DefaultInterval is how often the watched paths are checked for changes
+// when they are polled
+const DefaultInterval = 500 * time.Millisecond
+
+// settleDelay is how long a notification waits for the other writes of the
+// same save before the files are listed
+const settleDelay = 50 * time.Millisecond
+
+// ListFunc discovers the files to watch
+type ListFunc func() ([]*models.FileInfo, error)
+
+// ScanFunc scans the given files
+type ScanFunc func(paths []string) ([]*models.ScanResult, error)
+
+// AnalyzeFunc detects the violations of every watched file again from their
+// latest results, such as scanner.Engine.Reanalyze does, so the detectors
+// that compare files see the unchanged files next to the changed ones
+type AnalyzeFunc func(results []*models.ScanResult) error
+
+// Update describes the files that changed since the previous poll
+type Update struct {
+	Results []*models.ScanResult // Results of the added and modified files, and of the files whose violations changed with them, by path
+	Removed []string             // Files that no longer exist or are no longer watched
+}
+
+// Watcher detects changed files by comparing their modification time and
+// size between polls, and keeps the latest result of every file.
+type Watcher struct {
+	list     ListFunc
+	scan     ScanFunc
+	analyze  AnalyzeFunc
+	interval time.Duration
+	roots    []string // Paths watched for notifications; polled when empty
+	files    map[string]fileState
+	results  map[string]*models.ScanResult
+}
+
+// notifier reports file system changes below the watched roots
+type notifier interface {
+	Changes() <-chan struct{} // Receives after a change; closed when notifications stop
+	Close() error
+}
+
+// fileState is what a poll remembers about a file
+type fileState struct {
+	modTime time.Time
+	size    int64
+}
+
+// New creates a watcher that lists files with list, rescans changed ones
+// with scan and, after every change, analyzes all watched files with
+// analyze. A nil analyze only reports the rescanned files.
+func New(list ListFunc, scan ScanFunc, analyze AnalyzeFunc, interval time.Duration) *Watcher {
+	if interval <= 0 {
+		interval = DefaultInterval
+	}
+	return &Watcher{
+		list:     list,
+		scan:     scan,
+		analyze:  analyze,
+		interval: interval,
+		files:    make(map[string]fileState),
+		results:  make(map[string]*models.ScanResult),
+	}
+}
+
+// WithNotifications makes Run wait for file system notifications below the
+// given files and directories instead of polling every interval, where the
+// platform supports them
+func (w *Watcher) WithNotifications(roots ...string) *Watcher {
+	w.roots = roots
+	return w
+}
+
+// Poll rescans the files added or modified since the previous poll, then
+// analyzes them together with the unchanged files. The first poll scans
+// every file.
+func (w *Watcher) Poll() (*Update, error) {
+	files, err := w.list()
+	if err != nil {
+		return nil, fmt.Errorf("failed to list files: %w", err)
+	}
+
+	update := &Update{}
+	current := make(map[string]fileState, len(files))
+	var changed []string
+	for _, file := range files {
+		state := fileState{modTime: file.ModifiedTime, size: file.Size}
+		current[file.Path] = state
+		if previous, ok := w.files[file.Path]; !ok || previous != state {
+			changed = append(changed, file.Path)
+		}
+	}
+	for path := range w.files {
+		if _, ok := current[path]; !ok {
+			update.Removed = append(update.Removed, path)
+		}
+	}
+	sort.Strings(update.Removed)
+
+	if len(changed) > 0 {
+		results, err := w.scan(changed)
+		if err != nil {
+			return nil, fmt.Errorf("failed to scan changed files: %w", err)
+		}
+		update.Results = results
+	}
+	if err := w.reanalyze(update); err != nil {
+		return nil, err
+	}
+	sort.SliceStable(update.Results, func(i, j int) bool {
+		return update.Results[i].File.Path < update.Results[j].File.Path
+	})
+
+	// Only remember the new state once it was scanned, so failed scans are retried
+	w.files = current
+	return update, nil
+}
+
+// reanalyze caches the results of the update, analyzes every cached result
+// and adds the unchanged files whose violations changed to the update
+func (w *Watcher) reanalyze(update *Update) error {
+	for _, path := range update.Removed {
+		delete(w.results, path)
+	}
+	rescanned := make(map[string]bool, len(update.Results))
+	for _, result := range update.Results {
+		w.results[result.File.Path] = result
+		rescanned[result.File.Path] = true
+	}
+	if w.analyze == nil || (len(update.Results) == 0 && len(update.Removed) == 0) {
+		return nil
+	}
+
+	all := make([]*models.ScanResult, 0, len(w.results))
+	before := make(map[string][]string)
+	for path, result := range w.results {
+		all = append(all, result)
+		if !rescanned[path] {
+			before[path] = violationKeys(result)
+		}
+	}
+	sort.Slice(all, func(i, j int) bool {
+		return all[i].File.Path < all[j].File.Path
+	})
+	if err := w.analyze(all); err != nil {
+		return fmt.Errorf("failed to analyze watched files: %w", err)
+	}
+
+	for path, keys := range before {
+		if !slices.Equal(keys, violationKeys(w.results[path])) {
+			update.Results = append(update.Results, w.results[path])
+		}
+	}
+	return nil
+}
+
+// violationKeys identifies the violations of a result and where they are
+func violationKeys(result *models.ScanResult) []string {
+	keys := make([]string, 0, len(result.Violations))
+	for _, violation := range result.Violations {
+		keys = append(keys, fmt.Sprintf("%s:%d", violation.Fingerprint, violation.Line))
+	}
+	return keys
+}
+
+// Run polls until ctx is done, passing every non-empty update to handle.
+// Polls follow file system notifications when WithNotifications set up
+// roots and the platform delivers them, and the interval otherwise. Errors,
+// including the reason notifications are unavailable, are passed to onError
+// and watching continues.
+func (w *Watcher) Run(ctx context.Context, handle func(*Update), onError func(error)) {
+	var changes <-chan struct{}
+	if len(w.roots) > 0 {
+		if notifications, err := newNotifier(w.roots); err == nil {
+			defer notifications.Close()
+			changes = notifications.Changes()
+		} else {
+			onError(fmt.Errorf("polling every %s: %w", w.interval, err))
+		}
+	}
+	ticker := time.NewTicker(w.interval)
+	defer ticker.Stop()
+	tick := ticker.C
+	if changes != nil {
+		ticker.Stop()
+		tick = nil
+	}
+
+	for {
+		update, err := w.Poll()
+		switch {
+		case err != nil:
+			onError(err)
+		case len(update.Results) > 0 || len(update.Removed) > 0:
+			handle(update)
+		}
+
+		select {
+		case <-ctx.Done():
+			return
+		case <-tick:
+		case _, open := <-changes:
+			if !open {
+				onError(fmt.Errorf("file system notifications stopped, polling every %s", w.interval))
+				changes = nil
+				ticker.Reset(w.interval)
+				tick = ticker.C
+				continue
+			}
+			select {
+			case <-ctx.Done():
+				return
+			case <-time.After(settleDelay):
+			}
+			select {
+			case <-changes:
+			default:
+			}
+		}
+	}
+}
@@ -0,0 +1,227 @@
+package watch
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"io/fs"
+	"os"
+	"path/filepath"
+	"reflect"
+	"testing"
+	"time"
+
+	"github.com/ericfisherdev/goclean/internal/models"
+)
+
+// fakeTree is a file listing whose files can be changed between polls
+type fakeTree struct {
+	files   map[string]*models.FileInfo
+	scanned [][]string
+	scanErr error
+}
+
+func (f *fakeTree) list() ([]*models.FileInfo, error) {
+	var files []*models.FileInfo
+	for _, file := range f.files {
+		files = append(files, file)
+	}
+	return files, nil
+}
+
+func (f *fakeTree) scan(paths []string) ([]*models.ScanResult, error) {
+	if f.scanErr != nil {
+		return nil, f.scanErr
+	}
+	f.scanned = append(f.scanned, paths)
+	var results []*models.ScanResult
+	for _, path := range paths {
+		results = append(results, &models.ScanResult{File: &models.FileInfo{Path: path}})
+	}
+	return results, nil
+}
+
+func (f *fakeTree) touch(path string, size int64) {
+	f.files[path] = &models.FileInfo{Path: path, Size: size, ModifiedTime: time.Unix(int64(len(f.scanned)+1), 0)}
+}
+
+func resultPaths(update *Update) []string {
+	var paths []string
+	for _, result := range update.Results {
+		paths = append(paths, result.File.Path)
+	}
+	return paths
+}
+
+func TestPoll(t *testing.T) {
+	tree := &fakeTree{files: make(map[string]*models.FileInfo)}
+	tree.touch("b.go", 10)
+	tree.touch("a.go", 20)
+	watcher := New(tree.list, tree.scan, nil, 0)
+
+	update, err := watcher.Poll()
+	if err != nil {
+		t.Fatalf("Poll failed: %v", err)
+	}
+	if got := resultPaths(update); !reflect.DeepEqual(got, []string{"a.go", "b.go"}) {
+		t.Errorf("Expected the first poll to scan every file, got %v", got)
+	}
+
+	update, _ = watcher.Poll()
+	if len(update.Results) != 0 || len(update.Removed) != 0 || len(tree.scanned) != 1 {
+		t.Errorf("Expected no rescan without changes, got %+v", update)
+	}
+
+	tree.touch("a.go", 25)
+	delete(tree.files, "b.go")
+	tree.touch("c.go", 5)
+	update, _ = watcher.Poll()
+	if got := resultPaths(update); !reflect.DeepEqual(got, []string{"a.go", "c.go"}) {
+		t.Errorf("Expected only the changed files to be rescanned, got %v", got)
+	}
+	if !reflect.DeepEqual(update.Removed, []string{"b.go"}) {
+		t.Errorf("Expected b.go to be removed, got %v", update.Removed)
+	}
+}
+
+func TestPollReanalyzesUnchangedFiles(t *testing.T) {
+	tree := &fakeTree{files: make(map[string]*models.FileInfo)}
+	tree.touch("a.go", 1)
+	tree.touch("b.go", 1)
+	// Like clone detection, each file's violation depends on the other watched files
+	var analyzed [][]string
+	analyze := func(results []*models.ScanResult) error {
+		var paths []string
+		for _, result := range results {
+			paths = append(paths, result.File.Path)
+			result.Violations = []*models.Violation{{Fingerprint: fmt.Sprintf("of-%d-files", len(results))}}
+		}
+		analyzed = append(analyzed, paths)
+		return nil
+	}
+	watcher := New(tree.list, tree.scan, analyze, 0)
+	watcher.Poll()
+
+	tree.touch("c.go", 1)
+	update, err := watcher.Poll()
+	if err != nil {
+		t.Fatalf("Poll failed: %v", err)
+	}
+	if got := analyzed[len(analyzed)-1]; !reflect.DeepEqual(got, []string{"a.go", "b.go", "c.go"}) {
+		t.Errorf("Expected every watched file to be analyzed, got %v", got)
+	}
+	if got := resultPaths(update); !reflect.DeepEqual(got, []string{"a.go", "b.go", "c.go"}) {
+		t.Errorf("Expected the files whose violations changed with c.go to be reported, got %v", got)
+	}
+
+	tree.touch("a.go", 2)
+	update, _ = watcher.Poll()
+	if got := resultPaths(update); !reflect.DeepEqual(got, []string{"a.go"}) {
+		t.Errorf("Expected only a.go when no other violations changed, got %v", got)
+	}
+
+	delete(tree.files, "c.go")
+	update, _ = watcher.Poll()
+	if got := resultPaths(update); !reflect.DeepEqual(got, []string{"a.go", "b.go"}) || len(update.Removed) != 1 {
+		t.Errorf("Expected removing c.go to reanalyze the other files, got %v removing %v", got, update.Removed)
+	}
+	if len(tree.scanned) != 3 {
+		t.Errorf("Expected unchanged files not to be rescanned, got scans %v", tree.scanned)
+	}
+}
+
+func TestPollRetriesFailedScans(t *testing.T) {
+	tree := &fakeTree{files: make(map[string]*models.FileInfo), scanErr: errors.New("parser crashed")}
+	tree.touch("a.go", 1)
+	watcher := New(tree.list, tree.scan, nil, 0)
+
+	if _, err := watcher.Poll(); err == nil {
+		t.Fatal("Expected the scan error to be returned")
+	}
+	tree.scanErr = nil
+	update, err := watcher.Poll()
+	if err != nil || len(update.Results) != 1 {
+		t.Errorf("Expected the file to be scanned on the next poll, got %v, %v", update, err)
+	}
+}
+
+func TestRunStopsWithContext(t *testing.T) {
+	tree := &fakeTree{files: make(map[string]*models.FileInfo)}
+	tree.touch("a.go", 1)
+	watcher := New(tree.list, tree.scan, nil, time.Millisecond)
+
+	ctx, cancel := context.WithCancel(context.Background())
+	updates := 0
+	watcher.Run(ctx, func(update *Update) {
+		updates++
+		cancel()
+	}, func(err error) {
+		t.Errorf("Unexpected error: %v", err)
+	})
+	if updates != 1 {
+		t.Errorf("Expected one update before stopping, got %d", updates)
+	}
+}
+
+// TestRunWakesOnNotifications tests that file system notifications, not the
+// interval, trigger rescans, including of files in new directories
+func TestRunWakesOnNotifications(t *testing.T) {
+	root := t.TempDir()
+	probe, err := newNotifier([]string{root})
+	if err != nil {
+		t.Skipf("File system notifications unavailable: %v", err)
+	}
+	probe.Close()
+
+	list := func() ([]*models.FileInfo, error) {
+		var files []*models.FileInfo
+		err := filepath.WalkDir(root, func(path string, entry fs.DirEntry, err error) error {
+			if err != nil || entry.IsDir() {
+				return err
+			}
+			info, err := entry.Info()
+			if err != nil {
+				return err
+			}
+			files = append(files, &models.FileInfo{Path: path, Size: info.Size(), ModifiedTime: info.ModTime()})
+			return nil
+		})
+		return files, err
+	}
+	scan := func(paths []string) ([]*models.ScanResult, error) {
+		var results []*models.ScanResult
+		for _, path := range paths {
+			results = append(results, &models.ScanResult{File: &models.FileInfo{Path: path}})
+		}
+		return results, nil
+	}
+
+	write := func(path string) {
+		if err := os.MkdirAll(filepath.Dir(path), 0755); err != nil {
+			t.Fatalf("Failed to create directory: %v", err)
+		}
+		if err := os.WriteFile(path, []byte("package main\n"), 0644); err != nil {
+			t.Fatalf("Failed to write %s: %v", path, err)
+		}
+	}
+	changes := []string{filepath.Join(root, "a.go"), filepath.Join(root, "pkg", "b.go")}
+	write(changes[0])
+
+	ctx, cancel := context.WithTimeout(context.Background(), 10*time.Second)
+	defer cancel()
+	var updated []string
+	New(list, scan, nil, time.Hour).WithNotifications(root).Run(ctx, func(update *Update) {
+		updated = append(updated, resultPaths(update)...)
+		if len(updated) == len(changes) {
+			cancel()
+			return
+		}
+		write(changes[len(updated)])
+	}, func(err error) {
+		t.Errorf("Unexpected error: %v", err)
+	})
+
+	if !reflect.DeepEqual(updated, changes) {
+		t.Errorf("Expected updates for %v, got %v", changes, updated)
+	}
+}
@@ -0,0 +1,150 @@
+// Package plugin defines the protocol between goclean and detector plugins,
+// so organizations can ship their own rules without forking goclean.
+//
+// A plugin is an executable listed under plugins in goclean.yaml. goclean
+// starts it once per scan and writes one JSON Request per line to its
+// standard input, one for each scanned file of the plugin's languages. The
+// plugin answers each request with one JSON Response line on its standard
+// output, in order. Standard error is passed through to goclean's.
+//
+// Plugins written in Go implement Detector and call Serve from main:
+//
+//	func main() {
+//		if err := plugin.Serve(myDetector{}); err != nil {
+//			log.Fatal(err)
+//		}
+//	}
+package plugin
+
+import (
+	"bufio"
+	"encoding/json"
+	"fmt"
+	"io"
+	"os"
+)
+
+// ProtocolVersion is the version of the request and response format. It
+// changes only when a field is removed or changes meaning.
+const ProtocolVersion = 1
+
+// maxRequestSize bounds one request line, which carries a whole source file
+const maxRequestSize = 64 * 1024 * 1024
+
+// Detector is implemented by plugins to report violations in one file
+type Detector interface {
+	// Detect returns the violations in the file of a request. An error is
+	// reported by goclean as a failure of the plugin for that file.
+	Detect(request *Request) ([]Violation, error)
+}
+
+// DetectorFunc adapts a function to the Detector interface
+type DetectorFunc func(request *Request) ([]Violation, error)
+
+// Detect calls f(request)
+func (f DetectorFunc) Detect(request *Request) ([]Violation, error) {
+	return f(request)
+}
+
+// Request asks a plugin for the violations in one file
+type Request struct {
+	Protocol  int        `json:"protocol"`
+	File      File       `json:"file"`
+	Functions []Function `json:"functions"`
+	Types     []Type     `json:"types"`
+	Imports   []Import   `json:"imports"`
+}
+
+// File is the scanned file with its source
+type File struct {
+	Path     string `json:"path"`
+	Language string `json:"language"` // "go" or "rust"
+	Lines    int    `json:"lines"`
+	Content  string `json:"content"`
+}
+
+// Function is a function or method with the metrics goclean measured
+type Function struct {
+	Name       string   `json:"name"`
+	Receiver   string   `json:"receiver,omitempty"` // Receiver or impl type of methods
+	StartLine  int      `json:"start_line"`
+	EndLine    int      `json:"end_line"`
+	Lines      int      `json:"lines"`
+	Complexity int      `json:"complexity"` // Cyclomatic complexity
+	Parameters []string `json:"parameters"` // Parameter names
+	Exported   bool     `json:"exported"`   // Exported in Go, pub in Rust
+	Async      bool     `json:"async,omitempty"`
+	Unsafe     bool     `json:"unsafe,omitempty"`
+}
+
+// Type is a type declaration
+type Type struct {
+	Name      string `json:"name"`
+	Kind      string `json:"kind"` // struct, interface or alias in Go; struct, enum or trait in Rust
+	StartLine int    `json:"start_line"`
+	EndLine   int    `json:"end_line"`
+	Members   int    `json:"members"` // Fields of structs, methods of interfaces and traits, variants of enums
+	Exported  bool   `json:"exported"`
+}
+
+// Import is an import in Go or a use declaration in Rust
+type Import struct {
+	Path  string `json:"path"`
+	Alias string `json:"alias,omitempty"`
+	Line  int    `json:"line"`
+}
+
+// Response carries the violations a plugin found in the file of a request
+type Response struct {
+	Violations []Violation `json:"violations"`
+	Error      string      `json:"error,omitempty"`
+}
+
+// Violation is a finding of a plugin. Rule and Message are required; the
+// severity defaults to low and the line to the first line of the file.
+type Violation struct {
+	Rule       string `json:"rule"`               // Rule ID, used in suppressions and severity overrides
+	Type       string `json:"type,omitempty"`     // Violation type; defaults to "plugin"
+	Severity   string `json:"severity,omitempty"` // info, low, medium, high or critical
+	Message    string `json:"message"`
+	Line       int    `json:"line,omitempty"`
+	Column     int    `json:"column,omitempty"`
+	EndLine    int    `json:"end_line,omitempty"`
+	EndColumn  int    `json:"end_column,omitempty"`
+	Suggestion string `json:"suggestion,omitempty"`
+}
+
+// Serve answers the requests goclean writes to standard input until it
+// closes the input
+func Serve(detector Detector) error {
+	return ServeIO(os.Stdin, os.Stdout, detector)
+}
+
+// ServeIO answers the requests read from in on out until in is exhausted
+func ServeIO(in io.Reader, out io.Writer, detector Detector) error {
+	scanner := bufio.NewScanner(in)
+	scanner.Buffer(make([]byte, 0, 64*1024), maxRequestSize)
+	encoder := json.NewEncoder(out)
+
+	for scanner.Scan() {
+		var response Response
+		var request Request
+		if err := json.Unmarshal(scanner.Bytes(), &request); err != nil {
+			response.Error = fmt.Sprintf("invalid request: %v", err)
+		} else if request.Protocol != ProtocolVersion {
+			response.Error = fmt.Sprintf("unsupported protocol version %d, plugin speaks %d", request.Protocol, ProtocolVersion)
+		} else if violations, err := detector.Detect(&request); err != nil {
+			response.Error = err.Error()
+		} else {
+			response.Violations = violations
+		}
+		if response.Violations == nil {
+			response.Violations = []Violation{}
+		}
+
+		if err := encoder.Encode(&response); err != nil {
+			return fmt.Errorf("writing response: %w", err)
+		}
+	}
+	return scanner.Err()
+}
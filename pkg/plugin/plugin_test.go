@@ -0,0 +1,59 @@
+package plugin
+
+import (
+	"bytes"
+	"encoding/json"
+	"errors"
+	"strings"
+	"testing"
+)
+
+func TestServeIOAnswersEachRequest(t *testing.T) {
+	detector := DetectorFunc(func(request *Request) ([]Violation, error) {
+		if strings.Contains(request.File.Content, "broken") {
+			return nil, errors.New("cannot analyze")
+		}
+		var violations []Violation
+		for _, function := range request.Functions {
+			violations = append(violations, Violation{Rule: "house-rule", Message: function.Name, Line: function.StartLine})
+		}
+		return violations, nil
+	})
+
+	input := strings.Join([]string{
+		`{"protocol":1,"file":{"path":"a.go","language":"go","content":"package a"},"functions":[{"name":"Run","start_line":3}]}`,
+		`{"protocol":1,"file":{"path":"b.go","language":"go","content":"broken"}}`,
+		`{"protocol":2,"file":{"path":"c.go"}}`,
+		`not json`,
+	}, "\n") + "\n"
+	var output bytes.Buffer
+	if err := ServeIO(strings.NewReader(input), &output, detector); err != nil {
+		t.Fatalf("ServeIO failed: %v", err)
+	}
+
+	var responses []Response
+	decoder := json.NewDecoder(&output)
+	for decoder.More() {
+		var response Response
+		if err := decoder.Decode(&response); err != nil {
+			t.Fatalf("Invalid response: %v", err)
+		}
+		responses = append(responses, response)
+	}
+	if len(responses) != 4 {
+		t.Fatalf("Expected one response per request, got %d", len(responses))
+	}
+
+	if len(responses[0].Violations) != 1 || responses[0].Violations[0].Message != "Run" || responses[0].Violations[0].Line != 3 {
+		t.Errorf("Unexpected violations %+v", responses[0].Violations)
+	}
+	if responses[1].Error != "cannot analyze" || responses[1].Violations == nil {
+		t.Errorf("Expected the detector error with an empty violation list, got %+v", responses[1])
+	}
+	if !strings.Contains(responses[2].Error, "unsupported protocol version 2") {
+		t.Errorf("Expected a protocol version error, got %q", responses[2].Error)
+	}
+	if !strings.HasPrefix(responses[3].Error, "invalid request") {
+		t.Errorf("Expected an invalid request error, got %q", responses[3].Error)
+	}
+}
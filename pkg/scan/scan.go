@@ -0,0 +1,104 @@
+// Package scan runs goclean from other programs, so tools embedding it can
+// scan source trees with their own severity policy without the CLI.
+//
+//	violations, err := scan.Run([]string{"./src"},
+//		scan.WithExclude("vendor/"),
+//		scan.WithSeverityPolicy(stricterPayments, severity.Inputs{Touches: changes.Touches}),
+//	)
+//
+// Scans use the default detector thresholds and rules.
+package scan
+
+import (
+	"fmt"
+
+	"github.com/ericfisherdev/goclean/internal/scanner"
+	"github.com/ericfisherdev/goclean/pkg/severity"
+)
+
+// Option configures a scan
+type Option func(*options)
+
+// options are the settings of a scan
+type options struct {
+	exclude   []string
+	fileTypes []string
+	policy    severity.Policy
+	inputs    severity.Inputs
+}
+
+// WithExclude skips files and directories matching the patterns, such as "vendor/"
+func WithExclude(patterns ...string) Option {
+	return func(o *options) {
+		o.exclude = append(o.exclude, patterns...)
+	}
+}
+
+// WithFileTypes only scans files with the given extensions, such as ".go"
+// and ".rs". Without it every supported language is scanned.
+func WithFileTypes(extensions ...string) Option {
+	return func(o *options) {
+		o.fileTypes = append(o.fileTypes, extensions...)
+	}
+}
+
+// WithSeverityPolicy makes the scan ask policy for the final severity of
+// every violation, after the rule severities. inputs tells the policy which
+// files are tests or third-party code and which lines changed.
+func WithSeverityPolicy(policy severity.Policy, inputs severity.Inputs) Option {
+	return func(o *options) {
+		o.policy = policy
+		o.inputs = inputs
+	}
+}
+
+// Violation is a finding of the scan
+type Violation struct {
+	Rule        string         // Rule ID, as used in suppressions and severity overrides
+	Type        string         // Violation type, such as function_length
+	Category    string         // Category of the type, such as Complexity
+	Severity    severity.Level // Final severity, after the rule severities and the policy
+	Message     string
+	Suggestion  string
+	File        string
+	Line        int
+	Column      int
+	EndLine     int
+	Fingerprint string // Stable identifier, matching the fingerprints of goclean reports
+}
+
+// Run scans the files and directories at paths and returns their
+// violations, in the order the files were discovered
+func Run(paths []string, opts ...Option) ([]Violation, error) {
+	var o options
+	for _, opt := range opts {
+		opt(&o)
+	}
+
+	engine := scanner.NewEngine(paths, o.exclude, o.fileTypes, false)
+	engine.SetSeverityPolicy(o.policy, o.inputs)
+	_, results, err := engine.Scan()
+	if err != nil {
+		return nil, fmt.Errorf("scan failed: %w", err)
+	}
+
+	var found []Violation
+	for _, result := range results {
+		for _, violation := range result.Violations {
+			found = append(found, Violation{
+				Rule:        violation.Rule,
+				Type:        string(violation.Type),
+				Category:    violation.Type.Category(),
+				Severity:    severity.Level(violation.Severity),
+				Message:     violation.Message,
+				Suggestion:  violation.Suggestion,
+				File:        violation.File,
+				Line:        violation.Line,
+				Column:      violation.Column,
+				EndLine:     violation.EndLine,
+				Fingerprint: violation.Fingerprint,
+			})
+		}
+	}
+	return found, nil
+}
@@ -0,0 +1,49 @@
+package scan
+
+import (
+	"os"
+	"path/filepath"
+	"sync"
+	"testing"
+
+	"github.com/ericfisherdev/goclean/pkg/severity"
+)
+
+func TestRunAppliesSeverityPolicy(t *testing.T) {
+	dir := t.TempDir()
+	source := "package payments\n\nfunc mix(a, b, c, d, e, f, g int) int { return a + b + c + d + e + f + g }\n"
+	if err := os.WriteFile(filepath.Join(dir, "charge.go"), []byte(source), 0644); err != nil {
+		t.Fatalf("Failed to create test file: %v", err)
+	}
+
+	var mutex sync.Mutex
+	var contexts []severity.Context
+	policy := severity.PolicyFunc(func(v *severity.Violation, ctx severity.Context) severity.Level {
+		mutex.Lock()
+		contexts = append(contexts, ctx)
+		mutex.Unlock()
+		if ctx.Changed {
+			return severity.Critical
+		}
+		return v.Severity
+	})
+
+	found, err := Run([]string{dir}, WithFileTypes(".go"), WithSeverityPolicy(policy, severity.Inputs{
+		Touches: func(path string, start, end int) bool { return true },
+	}))
+	if err != nil {
+		t.Fatalf("Run failed: %v", err)
+	}
+	var parameters *Violation
+	for i := range found {
+		if found[i].Rule == "parameter-count" {
+			parameters = &found[i]
+		}
+	}
+	if parameters == nil || parameters.Severity != severity.Critical || parameters.Fingerprint == "" {
+		t.Fatalf("Expected the policy to raise the parameter-count violation to Critical, got %+v", found)
+	}
+	if len(contexts) == 0 || !contexts[0].InDiff || contexts[0].Language != "Go" {
+		t.Errorf("Expected the policy to see the file and diff context, got %+v", contexts)
+	}
+}
@@ -0,0 +1,116 @@
+// Package severity lets programs embedding goclean decide the final severity
+// of each violation, for organization-specific rules such as raising every
+// finding under payments/, without changing the detectors.
+//
+// A policy is passed to a scan with scan.WithSeverityPolicy, together with
+// the Inputs that classify files and changes. The scan asks it for the
+// severity of every violation once the configured rule severities are
+// applied, both for the violations of single files and for those found
+// across files, such as clones. The scan calls the policy from its workers,
+// so it must be safe for concurrent use.
+//
+// The goclean scan command caps the severity of vendored and third-party
+// code (scan.third_party) after the policy, so that cap wins over a policy
+// that raises third-party findings.
+//
+// This policy raises changed code under payments/ to at least High:
+//
+//	stricterPayments := severity.PolicyFunc(func(v *severity.Violation, ctx severity.Context) severity.Level {
+//		if strings.HasPrefix(ctx.File, "payments/") && ctx.Changed && !ctx.TestFile {
+//			return max(v.Severity, severity.High)
+//		}
+//		return v.Severity
+//	})
+package severity
+
+// Level is the severity of a violation, from Info to Critical
+type Level int
+
+// Severity levels in increasing order, matching those of the reports
+const (
+	Info Level = iota
+	Low
+	Medium
+	High
+	Critical
+)
+
+// String returns the name of the level as reports show it
+func (l Level) String() string {
+	switch l {
+	case Info:
+		return "Info"
+	case Low:
+		return "Low"
+	case Medium:
+		return "Medium"
+	case High:
+		return "High"
+	case Critical:
+		return "Critical"
+	default:
+		return "Unknown"
+	}
+}
+
+// Policy decides the final severity of each violation
+type Policy interface {
+	// Severity returns the severity to report the violation at. Returning
+	// violation.Severity keeps the severity the detectors assigned.
+	Severity(violation *Violation, context Context) Level
+}
+
+// PolicyFunc adapts a function to the Policy interface
+type PolicyFunc func(violation *Violation, context Context) Level
+
+// Severity calls f(violation, context)
+func (f PolicyFunc) Severity(violation *Violation, context Context) Level {
+	return f(violation, context)
+}
+
+// Violation is the finding a policy decides the severity of
+type Violation struct {
+	Rule     string // Rule ID, as used in suppressions and severity overrides
+	Type     string // Violation type, such as function_length
+	Category string // Category of the type, such as Complexity
+	Severity Level  // Severity assigned by the detectors and the rule overrides
+	Message  string
+	File     string
+	Line     int
+	EndLine  int
+}
+
+// Context describes the file a violation was found in and how the change
+// under review touches it
+type Context struct {
+	File       string // Path of the file
+	Language   string // Language of the file, as the scanner detected it
+	TestFile   bool   // The file matches the test file patterns
+	ThirdParty bool   // The file is vendored or third-party code
+	InDiff     bool   // The scan is compared with a base revision
+	Changed    bool   // The violation's lines changed since the base revision; false when InDiff is false
+}
+
+// Inputs classifies files and changes for a policy. Nil fields leave the
+// matching Context fields false.
+type Inputs struct {
+	IsTestFile   func(path string) bool
+	IsThirdParty func(path string) bool
+	Touches      func(path string, start, end int) bool // Set when the scan is compared with a base revision
+}
+
+// Context returns the context of a violation at lines start to end of the
+// file at path
+func (in Inputs) Context(path, language string, start, end int) Context {
+	context := Context{File: path, Language: language, InDiff: in.Touches != nil}
+	if in.IsTestFile != nil {
+		context.TestFile = in.IsTestFile(path)
+	}
+	if in.IsThirdParty != nil {
+		context.ThirdParty = in.IsThirdParty(path)
+	}
+	if in.Touches != nil {
+		context.Changed = in.Touches(path, start, end)
+	}
+	return context
+}
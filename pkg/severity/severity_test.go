@@ -0,0 +1,52 @@
+package severity
+
+import "testing"
+
+func TestPolicyFuncRaisesChangedCode(t *testing.T) {
+	policy := PolicyFunc(func(violation *Violation, context Context) Level {
+		if context.Changed {
+			return max(violation.Severity, High)
+		}
+		return violation.Severity
+	})
+
+	cases := []struct {
+		severity Level
+		changed  bool
+		want     Level
+	}{
+		{Low, true, High},
+		{Critical, true, Critical},
+		{Low, false, Low},
+	}
+	for _, c := range cases {
+		got := policy.Severity(&Violation{Rule: "function-length", Severity: c.severity}, Context{Changed: c.changed})
+		if got != c.want {
+			t.Errorf("Expected %v for %v with changed=%v, got %v", c.want, c.severity, c.changed, got)
+		}
+	}
+}
+
+func TestLevelString(t *testing.T) {
+	names := map[Level]string{Info: "Info", Low: "Low", Medium: "Medium", High: "High", Critical: "Critical", Level(9): "Unknown"}
+	for level, name := range names {
+		if level.String() != name {
+			t.Errorf("Expected %q, got %q", name, level.String())
+		}
+	}
+}
+
+func TestInputsContext(t *testing.T) {
+	inputs := Inputs{
+		IsTestFile: func(path string) bool { return path == "api/handler_test.go" },
+		Touches:    func(path string, start, end int) bool { return start <= 12 && end >= 8 },
+	}
+	got := inputs.Context("api/handler_test.go", "Go", 10, 11)
+	want := Context{File: "api/handler_test.go", Language: "Go", TestFile: true, InDiff: true, Changed: true}
+	if got != want {
+		t.Errorf("Expected %+v, got %+v", want, got)
+	}
+	if got := (Inputs{}).Context("main.go", "Go", 1, 1); got != (Context{File: "main.go", Language: "Go"}) {
+		t.Errorf("Expected only the file without inputs, got %+v", got)
+	}
+}